@@ -133,6 +133,7 @@ async fn main() {
                 None,
                 Duration::from_secs(10),
                 None,
+                None,
             );
 
             let client = match client {
@@ -0,0 +1,260 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Periodically export meta data to an object storage and restore a meta dir from it.
+//!
+//! Every backup consists of two objects:
+//! - `<created_at_ms>.data`: the exported lines, the same format as `--export`.
+//! - `<created_at_ms>.manifest.json`: a [`BackupManifest`] that is written after the data,
+//!   a backup without manifest is incomplete and is never restored.
+
+use std::io::BufRead;
+use std::io::Cursor;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use anyhow::anyhow;
+use common_base::base::tokio;
+use opendal::services::Fs;
+use opendal::services::S3;
+use opendal::Operator;
+use serde::Deserialize;
+use serde::Serialize;
+use sha2::Digest;
+use sha2::Sha256;
+use url::Url;
+
+use crate::grpc::for_each_exported_line;
+use crate::snapshot::import_data_from;
+use crate::Config;
+
+const MANIFEST_SUFFIX: &str = ".manifest.json";
+const BACKUP_FORMAT_VERSION: u64 = 1;
+
+/// Describes a complete backup and how to validate it before restoring.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BackupManifest {
+    pub version: u64,
+    pub created_at_ms: u64,
+    /// Path of the data object, relative to the backup storage root.
+    pub data: String,
+    pub lines: u64,
+    pub size: u64,
+    /// Hex encoded sha256 of the data object.
+    pub sha256: String,
+}
+
+/// Export the meta data of the node at `grpc_api_address` to `backup_storage`.
+///
+/// If `backup_interval` is not 0, keep exporting every `backup_interval` seconds.
+pub async fn backup(config: &Config) -> anyhow::Result<()> {
+    let op = build_operator(&config.backup_storage)?;
+    eprintln!("    From: online meta-service: {}", config.grpc_api_address);
+    eprintln!("    To:   {}", config.backup_storage);
+
+    loop {
+        match backup_once(&op, &config.grpc_api_address).await {
+            Ok(manifest) => {
+                eprintln!(
+                    "    Backup {} done: lines: {}, size: {}, sha256: {}",
+                    manifest.data, manifest.lines, manifest.size, manifest.sha256
+                );
+            }
+            Err(e) => {
+                // A scheduled backup keeps going and retries at the next tick.
+                if config.backup_interval == 0 {
+                    return Err(e);
+                }
+                eprintln!("    Backup failed: {}", e);
+            }
+        }
+
+        if config.backup_interval == 0 {
+            return Ok(());
+        }
+        tokio::time::sleep(Duration::from_secs(config.backup_interval)).await;
+    }
+}
+
+async fn backup_once(op: &Operator, addr: &str) -> anyhow::Result<BackupManifest> {
+    let mut data = Vec::new();
+    let mut lines = 0;
+    let mut hasher = Sha256::new();
+
+    for_each_exported_line(addr, |line| {
+        let l = format!("{}\n", line);
+        hasher.update(l.as_bytes());
+        data.extend_from_slice(l.as_bytes());
+        lines += 1;
+        Ok(())
+    })
+    .await?;
+
+    let created_at_ms = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as u64;
+    let manifest = BackupManifest {
+        version: BACKUP_FORMAT_VERSION,
+        created_at_ms,
+        data: format!("{}.data", created_at_ms),
+        lines,
+        size: data.len() as u64,
+        sha256: format!("{:x}", hasher.finalize()),
+    };
+
+    op.write(&manifest.data, data).await?;
+    op.write(
+        &format!("{}{}", created_at_ms, MANIFEST_SUFFIX),
+        serde_json::to_vec_pretty(&manifest)?,
+    )
+    .await?;
+
+    Ok(manifest)
+}
+
+/// Rebuild the meta dir from the latest backup created no later than `restore_point`,
+/// or from the latest backup if `restore_point` is empty.
+pub async fn restore(config: &Config) -> anyhow::Result<()> {
+    let op = build_operator(&config.backup_storage)?;
+    let point = parse_restore_point(&config.restore_point)?;
+
+    let manifest = find_manifest(&op, point).await?.ok_or_else(|| {
+        anyhow!(
+            "no backup found in {} before '{}'",
+            config.backup_storage,
+            config.restore_point
+        )
+    })?;
+    eprintln!("    From: {}/{}", config.backup_storage, manifest.data);
+
+    let data = op.read(&manifest.data).await?;
+    verify(&manifest, &data)?;
+
+    let lines = Cursor::new(data).lines();
+    import_data_from(config, lines).await
+}
+
+async fn find_manifest(
+    op: &Operator,
+    point: Option<u64>,
+) -> anyhow::Result<Option<BackupManifest>> {
+    let mut candidates = vec![];
+    for entry in op.list("/").await? {
+        let Some(ts) = entry.name().strip_suffix(MANIFEST_SUFFIX) else {
+            continue;
+        };
+        let Ok(ts) = ts.parse::<u64>() else {
+            continue;
+        };
+        if point.map_or(true, |p| ts <= p) {
+            candidates.push((ts, entry.path().to_string()));
+        }
+    }
+
+    let Some((_, path)) = candidates.into_iter().max() else {
+        return Ok(None);
+    };
+
+    let manifest: BackupManifest = serde_json::from_slice(&op.read(&path).await?)?;
+    if manifest.version > BACKUP_FORMAT_VERSION {
+        return Err(anyhow!(
+            "unsupported backup version {} in {}",
+            manifest.version,
+            path
+        ));
+    }
+    Ok(Some(manifest))
+}
+
+fn verify(manifest: &BackupManifest, data: &[u8]) -> anyhow::Result<()> {
+    if data.len() as u64 != manifest.size {
+        return Err(anyhow!(
+            "backup {} is corrupted: size {} != {} in manifest",
+            manifest.data,
+            data.len(),
+            manifest.size
+        ));
+    }
+
+    let sha256 = format!("{:x}", Sha256::digest(data));
+    if sha256 != manifest.sha256 {
+        return Err(anyhow!(
+            "backup {} is corrupted: sha256 {} != {} in manifest",
+            manifest.data,
+            sha256,
+            manifest.sha256
+        ));
+    }
+
+    let lines = data.iter().filter(|b| **b == b'\n').count() as u64;
+    if lines != manifest.lines {
+        return Err(anyhow!(
+            "backup {} is corrupted: {} lines != {} in manifest",
+            manifest.data,
+            lines,
+            manifest.lines
+        ));
+    }
+    Ok(())
+}
+
+/// Accepts unix timestamp in milliseconds or RFC3339 datetime.
+fn parse_restore_point(s: &str) -> anyhow::Result<Option<u64>> {
+    if s.is_empty() {
+        return Ok(None);
+    }
+    if let Ok(ms) = s.parse::<u64>() {
+        return Ok(Some(ms));
+    }
+    let dt = chrono::DateTime::parse_from_rfc3339(s)
+        .map_err(|e| anyhow!("invalid restore point '{}': {}", s, e))?;
+    Ok(Some(dt.timestamp_millis() as u64))
+}
+
+/// Build an operator from `fs:///path/to/dir` or `s3://bucket/root?endpoint=..&region=..`.
+///
+/// S3 credentials are loaded from the environment, e.g. `AWS_ACCESS_KEY_ID`.
+fn build_operator(uri: &str) -> anyhow::Result<Operator> {
+    let url = Url::parse(uri).map_err(|e| anyhow!("invalid backup storage '{}': {}", uri, e))?;
+
+    let op = match url.scheme() {
+        "fs" => {
+            let mut builder = Fs::default();
+            builder.root(url.path());
+            Operator::new(builder)?.finish()
+        }
+        "s3" => {
+            let bucket = url
+                .host_str()
+                .ok_or_else(|| anyhow!("missing bucket in backup storage '{}'", uri))?;
+            let mut builder = S3::default();
+            builder.bucket(bucket);
+            builder.root(url.path());
+            for (k, v) in url.query_pairs() {
+                match k.as_ref() {
+                    "endpoint" => builder.endpoint(&v),
+                    "region" => builder.region(&v),
+                    _ => return Err(anyhow!("unknown s3 option '{}' in '{}'", k, uri)),
+                };
+            }
+            Operator::new(builder)?.finish()
+        }
+        scheme => {
+            return Err(anyhow!(
+                "unsupported backup storage scheme '{}', expect fs or s3",
+                scheme
+            ));
+        }
+    };
+    Ok(op)
+}
@@ -22,6 +22,36 @@ use common_meta_types::protobuf::Empty;
 use tokio_stream::StreamExt;
 
 pub async fn export_meta(addr: &str, save: String) -> anyhow::Result<()> {
+    let file: Option<File> = if !save.is_empty() {
+        eprintln!("    To:   File: {}", save);
+        Some(File::create(&save)?)
+    } else {
+        eprintln!("    To:   <stdout>");
+        None
+    };
+
+    for_each_exported_line(addr, |line| {
+        if file.as_ref().is_none() {
+            println!("{}", line);
+        } else {
+            file.as_ref()
+                .unwrap()
+                .write_all(format!("{}\n", line).as_bytes())?;
+        }
+        Ok(())
+    })
+    .await?;
+
+    if file.as_ref().is_some() {
+        file.as_ref().unwrap().sync_all()?;
+    }
+
+    Ok(())
+}
+
+/// Export all data from a running meta node and feed every validated json line to `f`.
+pub async fn for_each_exported_line<F>(addr: &str, mut f: F) -> anyhow::Result<()>
+where F: FnMut(&str) -> anyhow::Result<()> {
     let client = MetaGrpcClient::try_create(
         vec![addr.to_string()],
         "root",
@@ -30,6 +60,7 @@ pub async fn export_meta(addr: &str, save: String) -> anyhow::Result<()> {
         None,
         Duration::from_secs(10),
         None,
+        None,
     )?;
 
     let (mut grpc_client, _server_version) = client.make_client().await?;
@@ -38,14 +69,6 @@ pub async fn export_meta(addr: &str, save: String) -> anyhow::Result<()> {
 
     let mut stream = exported.into_inner();
 
-    let file: Option<File> = if !save.is_empty() {
-        eprintln!("    To:   File: {}", save);
-        Some(File::create(&save)?)
-    } else {
-        eprintln!("    To:   <stdout>");
-        None
-    };
-
     while let Some(chunk_res) = stream.next().await {
         let chunk = chunk_res?;
 
@@ -61,19 +84,9 @@ pub async fn export_meta(addr: &str, save: String) -> anyhow::Result<()> {
                 }
             }
 
-            if file.as_ref().is_none() {
-                println!("{}", line);
-            } else {
-                file.as_ref()
-                    .unwrap()
-                    .write_all(format!("{}\n", line).as_bytes())?;
-            }
+            f(line)?;
         }
     }
 
-    if file.as_ref().is_some() {
-        file.as_ref().unwrap().sync_all()?;
-    }
-
     Ok(())
 }
@@ -14,6 +14,7 @@
 
 #![allow(clippy::uninlined_format_args)]
 
+mod backup;
 mod grpc;
 use common_tracing::OTLPConfig;
 use common_tracing::QueryLogConfig;
@@ -27,6 +28,8 @@ use std::time::Duration;
 
 use clap::Parser;
 use common_base::base::tokio;
+use common_meta_api::kv_migration::kv_migrations;
+use common_meta_api::kv_migration::KVMigrator;
 use common_meta_client::MetaGrpcClient;
 use common_meta_kvapi::kvapi::KVApi;
 use common_meta_raft_store::config::RaftConfig;
@@ -60,6 +63,28 @@ pub struct Config {
     #[clap(long)]
     pub export: bool,
 
+    /// Export data from the running meta-service at `grpc_api_address` to `backup_storage`.
+    #[clap(long)]
+    pub backup: bool,
+
+    /// Rebuild the meta dir from a backup in `backup_storage`.
+    #[clap(long)]
+    pub restore: bool,
+
+    /// Where backups are stored: `fs:///path/to/dir` or `s3://bucket/root?endpoint=..&region=..`.
+    /// S3 credentials are loaded from environment variables such as `AWS_ACCESS_KEY_ID`.
+    #[clap(long, default_value = "")]
+    pub backup_storage: String,
+
+    /// Backup every `backup_interval` seconds, 0 means backup only once.
+    #[clap(long, default_value = "0")]
+    pub backup_interval: u64,
+
+    /// Restore from the latest backup created no later than this point in time,
+    /// in unix milliseconds or RFC3339. If empty, restore from the latest backup.
+    #[clap(long, default_value = "")]
+    pub restore_point: String,
+
     #[clap(
         long,
         env = "METASRV_GRPC_API_ADDRESS",
@@ -169,11 +194,19 @@ async fn main() -> anyhow::Result<()> {
                 Ok(())
             }
 
+            "migrate-kv" => {
+                migrate_kv(&config).await?;
+                Ok(())
+            }
+
             _ => {
                 eprintln!("valid commands are");
                 eprintln!("  --cmd bench-client-conn-num");
                 eprintln!("    Keep create new connections to metasrv.");
                 eprintln!("    Requires --grpc-api-address.");
+                eprintln!("  --cmd migrate-kv");
+                eprintln!("    Rewrite the meta data into the layouts of this version, resumable.");
+                eprintln!("    Requires --grpc-api-address.");
 
                 Err(anyhow::anyhow!("unknown cmd: {}", config.cmd))
             }
@@ -192,6 +225,18 @@ async fn main() -> anyhow::Result<()> {
         return snapshot::import_data(&config).await;
     }
 
+    if config.backup {
+        eprintln!();
+        eprintln!("Backup:");
+        return backup::backup(&config).await;
+    }
+
+    if config.restore {
+        eprintln!();
+        eprintln!("Restore:");
+        return backup::restore(&config).await;
+    }
+
     Err(anyhow::anyhow!("Nothing to do"))
 }
 
@@ -221,6 +266,7 @@ async fn bench_client_num_conn(conf: &Config) -> anyhow::Result<()> {
             None,
             Duration::from_secs(10),
             None,
+            None,
         )?;
 
         let res = client.get_kv("foo").await;
@@ -230,6 +276,43 @@ async fn bench_client_num_conn(conf: &Config) -> anyhow::Result<()> {
     }
 }
 
+async fn migrate_kv(conf: &Config) -> anyhow::Result<()> {
+    let addr = &conf.grpc_api_address;
+
+    let client = MetaGrpcClient::try_create(
+        vec![addr.to_string()],
+        "root",
+        "xxx",
+        None,
+        None,
+        Duration::from_secs(10),
+        None,
+        None,
+    )?;
+
+    let migrator = KVMigrator::new(client.as_ref());
+
+    for migration in kv_migrations() {
+        loop {
+            let progress = migrator.run_batch(migration.as_ref()).await?;
+            println!(
+                "{}: scanned: {}, migrated: {}, skipped: {}, last_key: {:?}, finished: {}",
+                migration.name(),
+                progress.scanned,
+                progress.migrated,
+                progress.skipped,
+                progress.last_key,
+                progress.finished
+            );
+
+            if progress.finished {
+                break;
+            }
+        }
+    }
+    Ok(())
+}
+
 async fn show_status(conf: &Config) -> anyhow::Result<()> {
     let addr = &conf.grpc_api_address;
 
@@ -241,6 +324,7 @@ async fn show_status(conf: &Config) -> anyhow::Result<()> {
         None,
         Duration::from_secs(10),
         None,
+        None,
     )?;
 
     let res = client.get_cluster_status().await?;
@@ -76,6 +76,22 @@ pub async fn export_data(config: &Config) -> anyhow::Result<()> {
 }
 
 pub async fn import_data(config: &Config) -> anyhow::Result<()> {
+    let restore = config.db.clone();
+
+    if restore.is_empty() {
+        import_data_from(config, io::stdin().lines()).await
+    } else {
+        let file = File::open(restore)?;
+        import_data_from(config, BufReader::new(file).lines()).await
+    }
+}
+
+/// Rebuild the meta dir from lines of exported data, and initialize a new cluster
+/// if `initial_cluster` is specified.
+pub async fn import_data_from<B: BufRead + 'static>(
+    config: &Config,
+    lines: Lines<B>,
+) -> anyhow::Result<()> {
     let raft_dir = config.raft_dir.clone().unwrap_or_default();
     eprintln!("    Into Meta Dir: '{}'", raft_dir);
 
@@ -84,7 +100,8 @@ pub async fn import_data(config: &Config) -> anyhow::Result<()> {
     init_sled_db(raft_dir.clone());
 
     clear(config)?;
-    let max_log_id = import_from_stdin_or_file(config).await?;
+    let max_log_id = import_lines(config, lines).await?;
+    upgrade(config).await?;
 
     if config.initial_cluster.is_empty() {
         return Ok(());
@@ -261,30 +278,6 @@ async fn import_v002(
     Ok(max_log_id)
 }
 
-/// Read every line from stdin or restore file, deserialize it into tree_name, key and value.
-/// Insert them into sled db and flush.
-///
-/// Finally upgrade the data in raft_dir to the latest version.
-async fn import_from_stdin_or_file(config: &Config) -> anyhow::Result<Option<LogId>> {
-    let restore = config.db.clone();
-
-    let max_log_id = if restore.is_empty() {
-        let lines = io::stdin().lines();
-
-        import_lines(config, lines).await?
-    } else {
-        let file = File::open(restore)?;
-        let reader = BufReader::new(file);
-        let lines = reader.lines();
-
-        import_lines(config, lines).await?
-    };
-
-    upgrade(config).await?;
-
-    Ok(max_log_id)
-}
-
 /// Upgrade the data in raft_dir to the latest version.
 async fn upgrade(config: &Config) -> anyhow::Result<()> {
     let raft_config: RaftConfig = config.clone().into();
@@ -31,6 +31,7 @@ use databend_query::api::HttpService;
 use databend_query::api::RpcService;
 use databend_query::clusters::ClusterDiscovery;
 use databend_query::local;
+use databend_query::log_history::LogHistory;
 use databend_query::metrics::MetricService;
 use databend_query::servers::FlightSQLServer;
 use databend_query::servers::HttpHandler;
@@ -231,6 +232,9 @@ pub async fn start_services(conf: &InnerConfig) -> Result<()> {
         );
     }
 
+    // Persist query logs and metrics into the history tables.
+    LogHistory::init(conf)?;
+
     // Print information to users.
     println!("Databend Query");
     println!();
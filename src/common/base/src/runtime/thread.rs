@@ -14,6 +14,7 @@
 
 use std::thread::Builder;
 use std::thread::JoinHandle;
+use std::time::Duration;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -47,6 +48,20 @@ impl<T> ThreadJoinHandle<T> {
 }
 
 impl Thread {
+    /// The CPU time consumed by the current thread.
+    pub fn cpu_time() -> Duration {
+        let mut ts = libc::timespec {
+            tv_sec: 0,
+            tv_nsec: 0,
+        };
+
+        // SAFETY: `ts` is a valid timespec and CLOCK_THREAD_CPUTIME_ID is always supported.
+        match unsafe { libc::clock_gettime(libc::CLOCK_THREAD_CPUTIME_ID, &mut ts) } {
+            0 => Duration::new(ts.tv_sec as u64, ts.tv_nsec as u32),
+            _ => Duration::ZERO,
+        }
+    }
+
     pub fn named_spawn<F, T>(mut name: Option<String>, f: F) -> ThreadJoinHandle<T>
     where
         F: FnOnce() -> T,
@@ -19,6 +19,7 @@ use common_base::base::GlobalInstance;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
+use crate::notification_client::NotificationClient;
 use crate::task_client::TaskClient;
 
 pub const CLOUD_REQUEST_TIMEOUT_SEC: u64 = 5; // 5 seconds
@@ -30,13 +31,18 @@ pub const QUERY_ID: &str = "X-DATABEND-QUERY-ID";
 
 pub struct CloudControlApiProvider {
     pub task_client: Arc<TaskClient>,
+    pub notification_client: Arc<NotificationClient>,
 }
 
 impl CloudControlApiProvider {
     pub async fn new(endpoint: String) -> Result<Arc<CloudControlApiProvider>> {
         let endpoint = Self::get_endpoint(endpoint).await?;
-        let task_client = TaskClient::new(endpoint).await?;
-        Ok(Arc::new(CloudControlApiProvider { task_client }))
+        let task_client = TaskClient::new(endpoint.clone()).await?;
+        let notification_client = NotificationClient::new(endpoint).await?;
+        Ok(Arc::new(CloudControlApiProvider {
+            task_client,
+            notification_client,
+        }))
     }
 
     async fn get_endpoint(endpoint: String) -> Result<tonic::transport::Endpoint> {
@@ -65,4 +71,8 @@ impl CloudControlApiProvider {
     pub fn get_task_client(&self) -> Arc<TaskClient> {
         self.task_client.clone()
     }
+
+    pub fn get_notification_client(&self) -> Arc<NotificationClient> {
+        self.notification_client.clone()
+    }
 }
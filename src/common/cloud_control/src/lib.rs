@@ -14,6 +14,8 @@
 
 pub mod client_config;
 pub mod cloud_api;
+pub mod notification_client;
+pub mod notification_utils;
 pub mod task_client;
 pub mod task_utils;
 
@@ -24,6 +26,8 @@ pub mod task_utils;
 pub mod pb {
     // taskproto is proto package name.
     tonic::include_proto!("taskproto");
+    // notificationproto is proto package name.
+    tonic::include_proto!("notificationproto");
 }
 
 pub use prost;
@@ -0,0 +1,90 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use tonic::transport::Channel;
+use tonic::transport::Endpoint;
+use tonic::Request;
+
+use crate::pb::notification_service_client::NotificationServiceClient;
+use crate::pb::AlterNotificationRequest;
+use crate::pb::AlterNotificationResponse;
+use crate::pb::CreateNotificationRequest;
+use crate::pb::CreateNotificationResponse;
+use crate::pb::DropNotificationRequest;
+use crate::pb::DropNotificationResponse;
+use crate::pb::GetNotificationRequest;
+use crate::pb::GetNotificationResponse;
+use crate::pb::ListNotificationRequest;
+use crate::pb::ListNotificationResponse;
+
+pub struct NotificationClient {
+    pub client: NotificationServiceClient<Channel>,
+}
+
+impl NotificationClient {
+    // TODO: add auth interceptor
+    pub async fn new(endpoint: Endpoint) -> Result<Arc<NotificationClient>> {
+        let channel = endpoint.connect_lazy();
+        let client = NotificationServiceClient::new(channel);
+        Ok(Arc::new(NotificationClient { client }))
+    }
+
+    pub async fn create_notification(
+        &self,
+        req: Request<CreateNotificationRequest>,
+    ) -> Result<CreateNotificationResponse> {
+        let mut client = self.client.clone();
+        let resp = client.create_notification(req).await?;
+        Ok(resp.into_inner())
+    }
+
+    pub async fn drop_notification(
+        &self,
+        req: Request<DropNotificationRequest>,
+    ) -> Result<DropNotificationResponse> {
+        let mut client = self.client.clone();
+        let resp = client.drop_notification(req).await?;
+        Ok(resp.into_inner())
+    }
+
+    pub async fn get_notification(
+        &self,
+        req: Request<GetNotificationRequest>,
+    ) -> Result<GetNotificationResponse> {
+        let mut client = self.client.clone();
+        let resp = client.get_notification(req).await?;
+        Ok(resp.into_inner())
+    }
+
+    pub async fn list_notifications(
+        &self,
+        req: Request<ListNotificationRequest>,
+    ) -> Result<ListNotificationResponse> {
+        let mut client = self.client.clone();
+        let resp = client.list_notification(req).await?;
+        Ok(resp.into_inner())
+    }
+
+    pub async fn alter_notification(
+        &self,
+        req: Request<AlterNotificationRequest>,
+    ) -> Result<AlterNotificationResponse> {
+        let mut client = self.client.clone();
+        let resp = client.alter_notification(req).await?;
+        Ok(resp.into_inner())
+    }
+}
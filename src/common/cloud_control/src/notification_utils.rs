@@ -0,0 +1,127 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::pb::EmailConfig;
+use crate::pb::RetryPolicy;
+use crate::pb::WebhookConfig;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum NotificationType {
+    Webhook = 0,
+    Email = 1,
+}
+
+impl Display for NotificationType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            NotificationType::Webhook => write!(f, "WEBHOOK"),
+            NotificationType::Email => write!(f, "EMAIL"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Notification {
+    pub notification_id: u64,
+    pub name: String,
+    pub notification_type: NotificationType,
+    pub enabled: bool,
+    pub webhook: Option<WebhookConfig>,
+    pub email: Option<EmailConfig>,
+    pub retry_policy: Option<RetryPolicy>,
+    pub comments: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Notification {
+    /// The options of the integration, the authorization header and the signing secret
+    /// are masked.
+    pub fn format_options(&self) -> String {
+        match self.notification_type {
+            NotificationType::Webhook => {
+                let Some(webhook) = &self.webhook else {
+                    return String::new();
+                };
+                let mut res = format!("url = '{}', method = '{}'", webhook.url, webhook.method);
+                if webhook.authorization_header.is_some() {
+                    res.push_str(", authorization_header = '******'");
+                }
+                if webhook.signing_secret.is_some() {
+                    res.push_str(", signing_secret = '******'");
+                }
+                res
+            }
+            NotificationType::Email => match &self.email {
+                Some(email) => format!("recipients = '{}'", email.recipients.join(", ")),
+                None => String::new(),
+            },
+        }
+    }
+}
+
+// convert from crate::pb::Notification to struct Notification
+impl TryFrom<crate::pb::Notification> for Notification {
+    type Error = ErrorCode;
+
+    fn try_from(value: crate::pb::Notification) -> Result<Self> {
+        let notification_type = match value.notification_type {
+            0 => NotificationType::Webhook,
+            1 => NotificationType::Email,
+            t => {
+                return Err(ErrorCode::IllegalCloudControlMessageFormat(format!(
+                    "Illegal notification type {t}"
+                )));
+            }
+        };
+
+        let created_at = DateTime::parse_from_rfc3339(&value.created_time)
+            .map_err(|e| {
+                ErrorCode::IllegalCloudControlMessageFormat(format!(
+                    "illegal created_time message {}, {e}",
+                    value.created_time
+                ))
+            })?
+            .with_timezone(&Utc);
+        let updated_at = DateTime::parse_from_rfc3339(&value.updated_time)
+            .map_err(|e| {
+                ErrorCode::IllegalCloudControlMessageFormat(format!(
+                    "illegal updated_time message {}, {e}",
+                    value.updated_time
+                ))
+            })?
+            .with_timezone(&Utc);
+
+        Ok(Notification {
+            notification_id: value.notification_id,
+            name: value.name,
+            notification_type,
+            enabled: value.enabled,
+            webhook: value.webhook,
+            email: value.email,
+            retry_policy: value.retry_policy,
+            comments: value.comments,
+            created_at,
+            updated_at,
+        })
+    }
+}
@@ -115,6 +115,7 @@ build_exceptions! {
     PermissionDenied(1063),
     UnmarshalError(1064),
     SemanticError(1065),
+    ReadOnlyModePermissionDenied(1066),
     UnknownException(1067),
     TokioError(1068),
     HttpNotFound(1072),
@@ -127,6 +128,7 @@ build_exceptions! {
     InvalidTimestamp(1080),
     InvalidClusterKeys(1081),
     UnknownFragmentExchange(1082),
+    ChecksumMismatch(1083),
     TenantIsEmpty(1101),
     IndexOutOfBounds(1102),
     LayoutError(1103),
@@ -149,6 +151,7 @@ build_exceptions! {
     UnknownCatalog(1119),
     UnknownCatalogType(1120),
     UnmatchMaskPolicyReturnType(1121),
+    ColumnReferencedByConstraint(1122),
 
     // Data Related Errors
 
@@ -170,6 +173,11 @@ build_exceptions! {
     ///
     /// For example: try to with 3 columns into a table with 4 columns.
     TableSchemaMismatch(1303),
+    /// CheckConstraintViolated is used when the written rows don't satisfy
+    /// a CHECK constraint of the table.
+    CheckConstraintViolated(1304),
+    UnknownConstraint(1305),
+    ConstraintAlreadyExists(1306),
 
     // License related errors starts here
 
@@ -254,6 +262,8 @@ build_exceptions! {
     CatalogNotFound(2320),
     /// data mask error codes
     DatamaskAlreadyExists(2321),
+    UndropTableRetentionTimeExpired(2322),
+    UndropDbRetentionTimeExpired(2323),
 
 
     // Cluster error codes.
@@ -278,6 +288,22 @@ build_exceptions! {
     IllegalConnection(2511),
     ConnectionAlreadyExists(2512),
 
+    // Sequence error codes.
+    UnknownSequence(2513),
+    IllegalSequence(2514),
+    SequenceAlreadyExists(2515),
+
+    // Subject key error codes.
+    UnknownSubjectKey(2516),
+    IllegalSubjectKey(2517),
+    SubjectKeyAlreadyExists(2518),
+
+    // Secret error codes.
+    UnknownSecret(2519),
+    IllegalSecret(2520),
+    SecretAlreadyExists(2521),
+    SecretProviderFailure(2522),
+
     // User defined function error codes.
     IllegalUDFFormat(2601),
     UnknownUDF(2602),
@@ -39,6 +39,9 @@ pub struct RpcClientConf {
     /// None disables auto-sync.
     pub auto_sync_interval: Option<Duration>,
     pub unhealthy_endpoint_evict_time: Duration,
+    /// Allow the reads of read-only paths to be served by a follower whose data is at most this stale.
+    /// None always reads from the leader.
+    pub follower_read_max_staleness: Option<Duration>,
 }
 
 impl RpcClientConf {
@@ -27,16 +27,36 @@ use crate::hashjoin_hashtable::new_header;
 use crate::hashjoin_hashtable::remove_header_tag;
 use crate::traits::hash_join_fast_string_hash;
 use crate::RowPtr;
+use crate::StringView;
 
-pub const STRING_EARLY_SIZE: usize = 4;
 pub struct StringRawEntry {
     pub row_ptr: RowPtr,
-    pub length: u32,
-    pub early: [u8; STRING_EARLY_SIZE],
-    pub key: *mut u8,
+    /// The view of the key: a short key is stored inside it,
+    /// otherwise the payload of the view is the address of the key.
+    pub key: StringView,
     pub next: u64,
 }
 
+impl StringRawEntry {
+    /// Returns whether the key of the entry equals `key`.
+    ///
+    /// Only a long key with the same length and prefix is read from its address.
+    #[inline]
+    fn key_equals(&self, key: &[u8]) -> bool {
+        if !self.key.prefix_equals(key) {
+            return false;
+        }
+        if self.key.is_inlined() {
+            return self.key.inlined() == key;
+        }
+        // # Safety
+        // A long key is copied to the string space that lives as long as the hash table.
+        let key_ref =
+            unsafe { std::slice::from_raw_parts(self.key.payload() as *const u8, self.key.len()) };
+        key == key_ref
+    }
+}
+
 pub struct HashJoinStringHashTable<A: Allocator + Clone = MmapAllocator> {
     pub(crate) pointers: Box<[u64], A>,
     pub(crate) atomic_pointers: *mut AtomicU64,
@@ -232,23 +252,8 @@ where A: Allocator + Clone + 'static
                 break;
             }
             let raw_entry = unsafe { &*(ptr as *mut StringRawEntry) };
-            // Compare `early` and the length of the string, the size of `early` is 4.
-            let min_len = std::cmp::min(
-                STRING_EARLY_SIZE,
-                std::cmp::min(key.len(), raw_entry.length as usize),
-            );
-            if raw_entry.length as usize == key.len()
-                && key[0..min_len] == raw_entry.early[0..min_len]
-            {
-                let key_ref = unsafe {
-                    std::slice::from_raw_parts(
-                        raw_entry.key as *const u8,
-                        raw_entry.length as usize,
-                    )
-                };
-                if key == key_ref {
-                    return true;
-                }
+            if raw_entry.key_equals(key) {
+                return true;
             }
             ptr = raw_entry.next;
         }
@@ -269,29 +274,17 @@ where A: Allocator + Clone + 'static
                 break;
             }
             let raw_entry = unsafe { &*(ptr as *mut StringRawEntry) };
-            // Compare `early` and the length of the string, the size of `early` is 4.
-            let min_len = std::cmp::min(STRING_EARLY_SIZE, key.len());
-            if raw_entry.length as usize == key.len()
-                && key[0..min_len] == raw_entry.early[0..min_len]
-            {
-                let key_ref = unsafe {
-                    std::slice::from_raw_parts(
-                        raw_entry.key as *const u8,
-                        raw_entry.length as usize,
+            if raw_entry.key_equals(key) {
+                // # Safety
+                // occupied is less than the capacity of vec_ptr.
+                unsafe {
+                    std::ptr::copy_nonoverlapping(
+                        &raw_entry.row_ptr as *const RowPtr,
+                        vec_ptr.add(occupied),
+                        1,
                     )
                 };
-                if key == key_ref {
-                    // # Safety
-                    // occupied is less than the capacity of vec_ptr.
-                    unsafe {
-                        std::ptr::copy_nonoverlapping(
-                            &raw_entry.row_ptr as *const RowPtr,
-                            vec_ptr.add(occupied),
-                            1,
-                        )
-                    };
-                    occupied += 1;
-                }
+                occupied += 1;
             }
             ptr = raw_entry.next;
         }
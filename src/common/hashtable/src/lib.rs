@@ -35,6 +35,7 @@ mod dictionary_string_hashtable;
 mod partitioned_hashtable;
 mod short_string_hashtable;
 mod string_hashtable;
+mod string_view;
 #[allow(dead_code)]
 mod table1;
 mod table_empty;
@@ -107,9 +108,10 @@ pub type LookupHashMapIterMut<'a, K, const CAPACITY: usize, V> =
 pub use hashjoin_hashtable::RawEntry;
 pub use hashjoin_hashtable::RowPtr;
 pub use hashjoin_string_hashtable::StringRawEntry;
-pub use hashjoin_string_hashtable::STRING_EARLY_SIZE;
 pub use keys_ref::KeysRef;
 pub use partitioned_hashtable::hash2bucket;
+pub use string_view::StringView;
+pub use string_view::STRING_VIEW_INLINE_SIZE;
 pub type HashJoinHashMap<K> = hashjoin_hashtable::HashJoinHashTable<K>;
 pub type StringHashJoinHashMap = hashjoin_string_hashtable::HashJoinStringHashTable;
 pub use traits::HashJoinHashtableLike;
@@ -0,0 +1,95 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The max length of a string stored inside its view.
+pub const STRING_VIEW_INLINE_SIZE: usize = 12;
+
+/// The view of a string, a.k.a. German string: the length, the first 4 bytes,
+/// and either the remaining bytes if the string is not longer than 12 bytes, or
+/// a payload locating the string, such as its offset in a data buffer or its address.
+///
+/// Two strings with different lengths or prefixes are told apart by their views
+/// without touching the bytes they locate.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(C)]
+pub struct StringView {
+    len: u32,
+    prefix: [u8; 4],
+    // The bytes `4..12` of an inlined string, or the payload locating the string.
+    payload: [u8; 8],
+}
+
+impl StringView {
+    /// Create the view of `value`, `payload` is only kept if `value` is not inlined.
+    #[inline]
+    pub fn new(value: &[u8], payload: u64) -> Self {
+        let mut view = StringView {
+            len: value.len() as u32,
+            ..Default::default()
+        };
+        if value.len() <= STRING_VIEW_INLINE_SIZE {
+            let mut inlined = [0u8; STRING_VIEW_INLINE_SIZE];
+            inlined[..value.len()].copy_from_slice(value);
+            view.prefix.copy_from_slice(&inlined[..4]);
+            view.payload.copy_from_slice(&inlined[4..]);
+        } else {
+            view.prefix.copy_from_slice(&value[..4]);
+            view.payload = payload.to_le_bytes();
+        }
+        view
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    pub fn is_inlined(&self) -> bool {
+        self.len() <= STRING_VIEW_INLINE_SIZE
+    }
+
+    /// The first 4 bytes of the string, padded with zeros.
+    #[inline]
+    pub fn prefix(&self) -> [u8; 4] {
+        self.prefix
+    }
+
+    /// The string stored inside the view, only valid if the view is inlined.
+    #[inline]
+    pub fn inlined(&self) -> &[u8] {
+        debug_assert!(self.is_inlined());
+        // Safety: `prefix` and `payload` are laid out next to each other by `repr(C)`,
+        // and an inlined string is not longer than the 12 bytes of them.
+        unsafe { std::slice::from_raw_parts(self.prefix.as_ptr(), self.len()) }
+    }
+
+    /// The payload locating the string, only valid if the view is not inlined.
+    #[inline]
+    pub fn payload(&self) -> u64 {
+        u64::from_le_bytes(self.payload)
+    }
+
+    /// Returns whether `key` has the length and the prefix of the viewed string.
+    #[inline]
+    pub fn prefix_equals(&self, key: &[u8]) -> bool {
+        let prefix_len = std::cmp::min(4, key.len());
+        self.len() == key.len() && self.prefix[..prefix_len] == key[..prefix_len]
+    }
+}
@@ -20,13 +20,19 @@ use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 use bumpalo::Bump;
+use common_hashtable::hash_join_fast_string_hash;
 use common_hashtable::DictionaryKeys;
 use common_hashtable::DictionaryStringHashMap;
+use common_hashtable::HashJoinHashtableLike;
 use common_hashtable::HashMap;
 use common_hashtable::HashtableEntryMutRefLike;
 use common_hashtable::HashtableLike;
+use common_hashtable::RowPtr;
 use common_hashtable::ShortStringHashMap;
 use common_hashtable::StackHashMap;
+use common_hashtable::StringHashJoinHashMap;
+use common_hashtable::StringRawEntry;
+use common_hashtable::StringView;
 use rand::Rng;
 
 macro_rules! simple_test {
@@ -181,3 +187,44 @@ fn test_dictionary_hash_map() {
         }
     }
 }
+
+#[test]
+fn test_string_hash_join_map() {
+    // Short keys are stored inside the views, long ones are read from their addresses.
+    let keys: Vec<&[u8]> = vec![
+        b"",
+        b"abc",
+        b"abcdefghijkl",
+        b"abcdefghijklm",
+        b"abcdefghijklmn",
+    ];
+    let mut hashtable = StringHashJoinHashMap::with_build_row_num(keys.len());
+    let mut entries = keys
+        .iter()
+        .enumerate()
+        .map(|(index, key)| StringRawEntry {
+            row_ptr: RowPtr {
+                chunk_index: 0,
+                row_index: index as u32,
+            },
+            key: StringView::new(key, key.as_ptr() as u64),
+            next: 0,
+        })
+        .collect::<Vec<_>>();
+    for (key, entry) in keys.iter().zip(entries.iter_mut()) {
+        hashtable.insert(key, entry);
+    }
+
+    for key in keys.iter() {
+        let mut hashes = vec![hash_join_fast_string_hash(key)];
+        hashtable.probe(&mut hashes, None);
+        assert!(hashtable.next_contains(key, hashes[0]));
+    }
+
+    let missing: Vec<&[u8]> = vec![b"abd", b"abcdefghijkz", b"abcdefghijklz", b"zbcdefghijklm"];
+    for key in missing.iter() {
+        let mut hashes = vec![hash_join_fast_string_hash(key)];
+        hashtable.probe(&mut hashes, None);
+        assert!(!hashtable.next_contains(key, hashes[0]));
+    }
+}
@@ -53,6 +53,14 @@ impl StatBuffer for u64 {
     }
 }
 
+impl StatBuffer for u128 {
+    type Buffer = [u8; 16];
+
+    fn buffer() -> Self::Buffer {
+        [0; 16]
+    }
+}
+
 impl StatBuffer for i8 {
     type Buffer = [u8; 1];
 
@@ -85,6 +93,14 @@ impl StatBuffer for i64 {
     }
 }
 
+impl StatBuffer for i128 {
+    type Buffer = [u8; 16];
+
+    fn buffer() -> Self::Buffer {
+        [0; 16]
+    }
+}
+
 impl StatBuffer for f32 {
     type Buffer = [u8; 4];
 
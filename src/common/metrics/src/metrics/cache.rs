@@ -16,9 +16,11 @@ use lazy_static::lazy_static;
 use prometheus_client::encoding::EncodeLabelSet;
 
 use crate::register_counter_family;
+use crate::register_gauge_family;
 use crate::register_histogram_family_in_milliseconds;
 use crate::Counter;
 use crate::Family;
+use crate::Gauge;
 use crate::Histogram;
 
 #[derive(Clone, Debug, EncodeLabelSet, Hash, PartialEq, Eq)]
@@ -26,6 +28,11 @@ struct CacheLabels {
     cache_name: String,
 }
 
+#[derive(Clone, Debug, EncodeLabelSet, Hash, PartialEq, Eq)]
+struct ScratchLabels {
+    consumer: String,
+}
+
 lazy_static! {
     static ref CACHE_ACCESS_COUNT: Family<CacheLabels, Counter> =
         register_counter_family("cache_access_count");
@@ -39,6 +46,12 @@ lazy_static! {
         register_counter_family("cache_population_pending_count");
     static ref CACHE_POPULATION_OVERFLOW_COUNT: Family<CacheLabels, Counter> =
         register_counter_family("cache_population_overflow_count");
+    static ref SCRATCH_USED_BYTES: Family<ScratchLabels, Gauge> =
+        register_gauge_family("scratch_used_bytes");
+    static ref SCRATCH_REJECTED_COUNT: Family<ScratchLabels, Counter> =
+        register_counter_family("scratch_rejected_count");
+    static ref SCRATCH_EVICTED_BYTES: Family<ScratchLabels, Counter> =
+        register_counter_family("scratch_evicted_bytes");
 }
 
 pub fn metrics_inc_cache_access_count(c: u64, cache_name: &str) {
@@ -90,3 +103,27 @@ pub fn metrics_inc_cache_population_overflow_count(c: i64, cache_name: &str) {
         })
         .inc_by(c as u64);
 }
+
+pub fn metrics_set_scratch_used_bytes(c: u64, consumer: &str) {
+    SCRATCH_USED_BYTES
+        .get_or_create(&ScratchLabels {
+            consumer: consumer.to_string(),
+        })
+        .set(c as i64);
+}
+
+pub fn metrics_inc_scratch_rejected_count(c: u64, consumer: &str) {
+    SCRATCH_REJECTED_COUNT
+        .get_or_create(&ScratchLabels {
+            consumer: consumer.to_string(),
+        })
+        .inc_by(c);
+}
+
+pub fn metrics_inc_scratch_evicted_bytes(c: u64, consumer: &str) {
+    SCRATCH_EVICTED_BYTES
+        .get_or_create(&ScratchLabels {
+            consumer: consumer.to_string(),
+        })
+        .inc_by(c);
+}
@@ -21,6 +21,8 @@ use crate::Histogram;
 
 lazy_static! {
 // Common metrics.
+static ref STORAGE_HEDGED_READS: Counter = register_counter("storage_hedged_reads");
+static ref STORAGE_HEDGED_READ_WINS: Counter = register_counter("storage_hedged_read_wins");
 static ref OMIT_FILTER_ROWGROUPS: Counter = register_counter("omit_filter_rowgroups");
 static ref OMIT_FILTER_ROWS: Counter = register_counter("omit_filter_rows");
 
@@ -218,9 +220,28 @@ static ref MERGE_INTO_MATCHED_OPERATION_MILLISECONDS: Histogram =
     static ref AGG_INDEX_WRITE_BYTES: Counter = register_counter("fuse_aggregate_index_write_bytes");
     static ref AGG_INDEX_WRITE_MILLISECONDS: Histogram =
         register_histogram_in_milliseconds("fuse_aggregate_index_write_milliseconds");
+
+    // Row TTL metrics.
+    static ref ROW_TTL_EXPIRED_ROWS: Counter = register_counter("fuse_row_ttl_expired_rows");
+    static ref ROW_TTL_DELETE_BATCHES: Counter = register_counter("fuse_row_ttl_delete_batches");
+    static ref ROW_TTL_MILLISECONDS: Histogram =
+        register_histogram_in_milliseconds("fuse_row_ttl_milliseconds");
+
+    // Auto analyze metrics.
+    static ref AUTO_ANALYZE_TABLES: Counter = register_counter("fuse_auto_analyze_tables");
+    static ref AUTO_ANALYZE_MILLISECONDS: Histogram =
+        register_histogram_in_milliseconds("fuse_auto_analyze_milliseconds");
 }
 
 /// Common metrics.
+pub fn metrics_inc_storage_hedged_reads(c: u64) {
+    STORAGE_HEDGED_READS.inc_by(c);
+}
+
+pub fn metrics_inc_storage_hedged_read_wins(c: u64) {
+    STORAGE_HEDGED_READ_WINS.inc_by(c);
+}
+
 pub fn metrics_inc_omit_filter_rowgroups(c: u64) {
     OMIT_FILTER_ROWGROUPS.inc_by(c);
 }
@@ -665,3 +686,25 @@ pub fn metrics_inc_agg_index_write_bytes(c: u64) {
 pub fn metrics_inc_agg_index_write_milliseconds(c: u64) {
     AGG_INDEX_WRITE_MILLISECONDS.observe(c as f64);
 }
+
+/// Row TTL metrics.
+pub fn metrics_inc_row_ttl_expired_rows(c: u64) {
+    ROW_TTL_EXPIRED_ROWS.inc_by(c);
+}
+
+pub fn metrics_inc_row_ttl_delete_batches(c: u64) {
+    ROW_TTL_DELETE_BATCHES.inc_by(c);
+}
+
+pub fn metrics_inc_row_ttl_milliseconds(c: u64) {
+    ROW_TTL_MILLISECONDS.observe(c as f64);
+}
+
+/// Auto analyze metrics.
+pub fn metrics_inc_auto_analyze_tables(c: u64) {
+    AUTO_ANALYZE_TABLES.inc_by(c);
+}
+
+pub fn metrics_inc_auto_analyze_milliseconds(c: u64) {
+    AUTO_ANALYZE_MILLISECONDS.observe(c as f64);
+}
@@ -17,6 +17,7 @@ use lazy_static::lazy_static;
 use crate::register_counter;
 use crate::register_counter_family;
 use crate::register_histogram_family_in_milliseconds;
+use crate::register_histogram_in_milliseconds;
 use crate::Counter;
 use crate::Family;
 use crate::Histogram;
@@ -52,6 +53,12 @@ lazy_static! {
     static ref EXCHANGE_WRITE_BYTES: Counter = register_counter("transform_exchange_write_bytes");
     static ref EXCHANGE_READ_COUNT: Counter = register_counter("transform_exchange_read_count");
     static ref EXCHANGE_READ_BYTES: Counter = register_counter("transform_exchange_read_bytes");
+    static ref EXCHANGE_COMPRESS_INPUT_BYTES: Counter =
+        register_counter("transform_exchange_compress_input_bytes");
+    static ref EXCHANGE_COMPRESS_OUTPUT_BYTES: Counter =
+        register_counter("transform_exchange_compress_output_bytes");
+    static ref EXCHANGE_COMPRESS_MILLISECONDS: Histogram =
+        register_histogram_in_milliseconds("transform_exchange_compress_milliseconds");
 }
 
 pub fn metrics_inc_aggregate_partial_spill_count() {
@@ -148,3 +155,12 @@ pub fn metrics_inc_exchange_read_count(v: usize) {
 pub fn metrics_inc_exchange_read_bytes(c: usize) {
     EXCHANGE_READ_BYTES.inc_by(c as u64);
 }
+
+pub fn metrics_inc_exchange_compress_bytes(input: usize, output: usize) {
+    EXCHANGE_COMPRESS_INPUT_BYTES.inc_by(input as u64);
+    EXCHANGE_COMPRESS_OUTPUT_BYTES.inc_by(output as u64);
+}
+
+pub fn metrics_inc_exchange_compress_milliseconds(c: u64) {
+    EXCHANGE_COMPRESS_MILLISECONDS.observe(c as f64);
+}
@@ -44,6 +44,41 @@ pub struct StorageConfig {
     pub num_cpus: u64,
     pub allow_insecure: bool,
     pub params: StorageParams,
+    pub network: StorageNetworkConfig,
+}
+
+/// Config for the http clients and the IO of storage services.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageNetworkConfig {
+    /// Max idle connections kept in the pool for each endpoint, `0` to disable pooling.
+    pub pool_max_idle_per_host: usize,
+    /// Seconds an idle connection is kept in the pool.
+    pub pool_idle_timeout_secs: u64,
+    /// Interval in seconds of the TCP keep-alive probes, `0` to disable keep-alive.
+    pub tcp_keepalive_secs: u64,
+    /// Seconds to wait for a connection to be established.
+    pub connect_timeout_secs: u64,
+    /// Seconds an IO operation may take before it fails with timeout.
+    pub io_timeout_secs: u64,
+    /// A read slower than this percentile of the recent reads is sent again, and the
+    /// response arriving first is used. `0` to disable hedged reads.
+    pub hedged_read_percentile: u64,
+    /// Min milliseconds to wait before a read is sent again.
+    pub hedged_read_min_delay_ms: u64,
+}
+
+impl Default for StorageNetworkConfig {
+    fn default() -> Self {
+        Self {
+            pool_max_idle_per_host: 1024,
+            pool_idle_timeout_secs: 90,
+            tcp_keepalive_secs: 60,
+            connect_timeout_secs: 30,
+            io_timeout_secs: 60,
+            hedged_read_percentile: 0,
+            hedged_read_min_delay_ms: 50,
+        }
+    }
 }
 
 // TODO: This config should be moved out of common-storage crate.
@@ -24,6 +24,10 @@ use thiserror::Error;
 pub struct CopyStatus {
     /// Key is file path.
     pub files: DashMap<String, FileStatus>,
+    /// The rows rejected by the CHECK constraints with `ON_ERROR = QUARANTINE`,
+    /// key is the constraint name.
+    #[serde(default)]
+    pub rejected_rows: DashMap<String, Vec<QuarantinedRecord>>,
 }
 
 impl CopyStatus {
@@ -38,20 +42,68 @@ impl CopyStatus {
         };
     }
 
+    pub fn add_rejected_rows(&self, constraint: &str, rows: Vec<QuarantinedRecord>) {
+        self.rejected_rows
+            .entry(constraint.to_string())
+            .or_default()
+            .extend(rows);
+    }
+
     pub fn merge(&self, other: CopyStatus) {
         for (k, v) in other.files.into_iter() {
             self.add_chunk(&k, v);
         }
+        for (k, v) in other.rejected_rows.into_iter() {
+            self.add_rejected_rows(&k, v);
+        }
     }
 }
 
+/// Max number of errors kept in [`FileStatus::load_errors`] of each file.
+const MAX_LOAD_ERRORS_PER_FILE: usize = 1000;
+
 #[derive(Default, Clone, Serialize, Deserialize)]
 pub struct FileStatus {
     pub num_rows_loaded: usize,
     pub error: Option<FileErrorsInfo>,
+    /// Errors of the `SET_NULL` on error mode, to be logged into `system.load_errors`.
+    #[serde(default)]
+    pub load_errors: Vec<FileErrorInfo>,
+    /// Records skipped by the `QUARANTINE` on error mode, to be written into the quarantine table.
+    #[serde(default)]
+    pub quarantined: Vec<QuarantinedRecord>,
 }
 
 impl FileStatus {
+    /// Records an error which does not stop the loading, see `OnErrorMode::SetNull`.
+    pub fn add_load_error(&mut self, error: FileParseError, line: usize) {
+        if self.load_errors.len() < MAX_LOAD_ERRORS_PER_FILE {
+            self.load_errors.push(FileErrorInfo {
+                error: error.clone(),
+                line,
+            });
+        }
+        self.add_error(error, line);
+    }
+
+    /// Records a skipped record, see `OnErrorMode::Quarantine`.
+    ///
+    /// `record` is the raw bytes of the record and its offset in the file, if available.
+    pub fn add_quarantined(
+        &mut self,
+        error: FileParseError,
+        line: usize,
+        record: Option<(&[u8], Option<usize>)>,
+    ) {
+        self.quarantined.push(QuarantinedRecord {
+            line: Some(line),
+            offset: record.and_then(|(_, offset)| offset),
+            record: record.map(|(data, _)| String::from_utf8_lossy(data).into_owned()),
+            error: error.to_string(),
+        });
+        self.add_error(error, line);
+    }
+
     pub fn add_error(&mut self, error: FileParseError, line: usize) {
         match &mut self.error {
             None => {
@@ -76,9 +128,24 @@ impl FileStatus {
             (Some(e1), Some(e2)) => e1.merge(e2),
             _ => {}
         }
+        self.load_errors.extend(other.load_errors);
+        self.load_errors.truncate(MAX_LOAD_ERRORS_PER_FILE);
+        self.quarantined.extend(other.quarantined);
     }
 }
 
+/// A record which fails to be loaded with `ON_ERROR = QUARANTINE`.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct QuarantinedRecord {
+    /// The line of the record in the file, starting from 0.
+    pub line: Option<usize>,
+    /// The offset of the record in the (decompressed) file.
+    pub offset: Option<usize>,
+    /// The raw record, or the values of the row if it is rejected after being decoded.
+    pub record: Option<String>,
+    pub error: String,
+}
+
 #[derive(Clone, Serialize, Deserialize)]
 pub struct FileErrorsInfo {
     pub num_errors: usize,
@@ -108,6 +175,10 @@ pub enum FileParseError {
     NumberOfColumnsMismatch { table: usize, file: usize },
     #[error("Invalid JSON row: {message}")]
     InvalidNDJsonRow { message: String },
+    #[error("Invalid protobuf message: {message}")]
+    InvalidProtobufRow { message: String },
+    #[error("Invalid XML row: {message}")]
+    InvalidXmlRow { message: String },
     #[error(
         "Invalid value '{column_data}' for column {column_index} ({column_name} {column_type}): {decode_error}"
     )]
@@ -139,6 +210,18 @@ pub enum FileParseError {
 }
 
 impl FileParseError {
+    /// Returns the name of the column the error occurs at, if any.
+    pub fn column_name(&self) -> Option<&str> {
+        match self {
+            FileParseError::ColumnDecodeError { column_name, .. }
+            | FileParseError::ColumnMissingError { column_name, .. }
+            | FileParseError::ColumnDataNotDrained { column_name, .. } => {
+                Some(column_name.as_str())
+            }
+            _ => None,
+        }
+    }
+
     pub fn to_error_code(&self, mode: &OnErrorMode, file_path: &str, line: usize) -> ErrorCode {
         let pos: String = format!("at file '{}', line {}", file_path, line);
         let message = match mode {
@@ -0,0 +1,211 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use std::time::Instant;
+
+use async_trait::async_trait;
+use common_base::base::tokio::time::sleep;
+use common_metrics::storage::metrics_inc_storage_hedged_read_wins;
+use common_metrics::storage::metrics_inc_storage_hedged_reads;
+use futures::future::select;
+use futures::future::Either;
+use opendal::raw::Accessor;
+use opendal::raw::Layer;
+use opendal::raw::LayeredAccessor;
+use opendal::raw::OpList;
+use opendal::raw::OpRead;
+use opendal::raw::OpWrite;
+use opendal::raw::RpList;
+use opendal::raw::RpRead;
+use opendal::raw::RpWrite;
+use opendal::Result;
+use parking_lot::Mutex;
+
+/// The number of the recent reads the hedging delay is computed by.
+const LATENCY_WINDOW: usize = 1024;
+/// The hedging delay is computed again after this number of reads.
+const RECOMPUTE_INTERVAL: u64 = 64;
+
+/// HedgedReadLayer sends a read again if it's slower than the given percentile of the
+/// recent reads, and uses the response arriving first.
+///
+/// The tail latency of object storage mostly comes from a few slow requests, which the
+/// duplicated request rarely hits again. Only the time to the response is hedged, the
+/// body is read from the winner.
+#[derive(Clone, Debug)]
+pub struct HedgedReadLayer {
+    percentile: u64,
+    min_delay: Duration,
+}
+
+impl HedgedReadLayer {
+    /// Reads are never hedged if `percentile` is 0.
+    pub fn new(percentile: u64, min_delay: Duration) -> Self {
+        HedgedReadLayer {
+            percentile,
+            min_delay,
+        }
+    }
+}
+
+impl<A: Accessor> Layer<A> for HedgedReadLayer {
+    type LayeredAccessor = HedgedReadAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccessor {
+        HedgedReadAccessor {
+            inner,
+            latencies: LatencyTracker::new(self.percentile),
+            min_delay: self.min_delay,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct HedgedReadAccessor<A> {
+    inner: A,
+    latencies: LatencyTracker,
+    min_delay: Duration,
+}
+
+impl<A: Accessor> HedgedReadAccessor<A> {
+    async fn timed_read(&self, path: &str, args: OpRead) -> Result<(RpRead, A::Reader)> {
+        let start = Instant::now();
+        let res = self.inner.read(path, args).await;
+        if res.is_ok() {
+            self.latencies.record(start.elapsed());
+        }
+        res
+    }
+}
+
+#[async_trait]
+impl<A: Accessor> LayeredAccessor for HedgedReadAccessor<A> {
+    type Inner = A;
+    type Reader = A::Reader;
+    type BlockingReader = A::BlockingReader;
+    type Writer = A::Writer;
+    type BlockingWriter = A::BlockingWriter;
+    type Pager = A::Pager;
+    type BlockingPager = A::BlockingPager;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    #[async_backtrace::framed]
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        let Some(delay) = self.latencies.hedging_delay() else {
+            return self.timed_read(path, args).await;
+        };
+
+        let first = Box::pin(self.timed_read(path, args.clone()));
+        let timer = Box::pin(sleep(delay.max(self.min_delay)));
+        let first = match select(first, timer).await {
+            Either::Left((res, _)) => return res,
+            Either::Right((_, first)) => first,
+        };
+
+        metrics_inc_storage_hedged_reads(1);
+        let second = Box::pin(self.timed_read(path, args));
+        match select(first, second).await {
+            Either::Left((Ok(res), _)) => Ok(res),
+            Either::Right((Ok(res), _)) => {
+                metrics_inc_storage_hedged_read_wins(1);
+                Ok(res)
+            }
+            // Wait for the other one if the read arriving first failed.
+            Either::Left((Err(_), second)) => second.await,
+            Either::Right((Err(_), first)) => first.await,
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        self.inner.write(path, args).await
+    }
+
+    #[async_backtrace::framed]
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
+        self.inner.list(path, args).await
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        self.inner.blocking_read(path, args)
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        self.inner.blocking_write(path, args)
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingPager)> {
+        self.inner.blocking_list(path, args)
+    }
+}
+
+/// LatencyTracker keeps the latencies of the recent reads, and the latency of the
+/// percentile among them.
+#[derive(Debug)]
+struct LatencyTracker {
+    percentile: u64,
+    /// Latencies of the recent reads in microseconds.
+    samples: Mutex<VecDeque<u64>>,
+    recorded: AtomicU64,
+    /// Latency of the percentile in microseconds, 0 until enough reads are recorded.
+    threshold: AtomicU64,
+}
+
+impl LatencyTracker {
+    fn new(percentile: u64) -> Self {
+        LatencyTracker {
+            percentile: percentile.min(100),
+            samples: Mutex::new(VecDeque::with_capacity(LATENCY_WINDOW)),
+            recorded: AtomicU64::new(0),
+            threshold: AtomicU64::new(0),
+        }
+    }
+
+    fn record(&self, latency: Duration) {
+        if self.percentile == 0 {
+            return;
+        }
+
+        let mut samples = self.samples.lock();
+        if samples.len() == LATENCY_WINDOW {
+            samples.pop_front();
+        }
+        samples.push_back(latency.as_micros() as u64);
+
+        let recorded = self.recorded.fetch_add(1, Ordering::Relaxed) + 1;
+        if recorded % RECOMPUTE_INTERVAL == 0 {
+            let mut sorted = samples.iter().copied().collect::<Vec<_>>();
+            drop(samples);
+            sorted.sort_unstable();
+            let idx = (sorted.len() as u64 * self.percentile / 100) as usize;
+            let threshold = sorted[idx.min(sorted.len() - 1)].max(1);
+            self.threshold.store(threshold, Ordering::Relaxed);
+        }
+    }
+
+    /// The delay after which a read is sent again, `None` if reads are not hedged.
+    fn hedging_delay(&self) -> Option<Duration> {
+        match self.threshold.load(Ordering::Relaxed) {
+            0 => None,
+            v => Some(Duration::from_micros(v)),
+        }
+    }
+}
@@ -33,6 +33,7 @@
 mod config;
 pub use config::ShareTableConfig;
 pub use config::StorageConfig;
+pub use config::StorageNetworkConfig;
 
 mod operator;
 pub use operator::init_operator;
@@ -42,6 +43,7 @@ pub mod metrics;
 pub use crate::metrics::StorageMetrics;
 pub use crate::metrics::StorageMetricsLayer;
 
+mod hedged_read_layer;
 mod runtime_layer;
 
 mod column_node;
@@ -57,8 +59,13 @@ pub mod parquet_rs;
 pub use parquet_rs::read_metadata_async;
 pub use parquet_rs::read_parquet_schema_async_rs;
 
+mod presigned;
+pub use presigned::put_presigned_url;
+pub use presigned::unload_presigned_url;
+
 mod stage;
 pub use stage::init_stage_operator;
+pub use stage::StageFileFormats;
 pub use stage::StageFileInfo;
 pub use stage::StageFileStatus;
 pub use stage::StageFilesInfo;
@@ -70,5 +77,6 @@ mod statistics;
 pub use copy::CopyStatus;
 pub use copy::FileParseError;
 pub use copy::FileStatus;
+pub use copy::QuarantinedRecord;
 pub use statistics::Datum;
 pub use statistics::F64;
@@ -53,10 +53,14 @@ use opendal::Builder;
 use opendal::Operator;
 use storage_encryption::get_storage_encryption_handler;
 
+use crate::hedged_read_layer::HedgedReadLayer;
 use crate::runtime_layer::RuntimeLayer;
 use crate::StorageConfig;
+use crate::StorageNetworkConfig;
 
 static PROMETHEUS_CLIENT_LAYER_INSTANCE: OnceCell<PrometheusClientLayer> = OnceCell::new();
+static STORAGE_NETWORK_CONFIG: OnceCell<StorageNetworkConfig> = OnceCell::new();
+static HTTP_CLIENT_INSTANCE: OnceCell<HttpClient> = OnceCell::new();
 
 /// init_operator will init an opendal operator based on storage config.
 pub fn init_operator(cfg: &StorageParams) -> Result<Operator> {
@@ -90,6 +94,7 @@ pub fn init_operator(cfg: &StorageParams) -> Result<Operator> {
 }
 
 pub fn build_operator<B: Builder>(builder: B) -> Result<Operator> {
+    let network = network_config();
     let ob = Operator::new(builder)?;
 
     let op = ob
@@ -99,11 +104,16 @@ pub fn build_operator<B: Builder>(builder: B) -> Result<Operator> {
         // storage operator so that all underlying storage operations
         // will send to storage runtime.
         .layer(RuntimeLayer::new(GlobalIORuntime::instance().inner()))
+        // Send slow reads again to cut the tail latency, disabled if percentile is 0.
+        .layer(HedgedReadLayer::new(
+            network.hedged_read_percentile,
+            Duration::from_millis(network.hedged_read_min_delay_ms),
+        ))
         .layer(
             TimeoutLayer::new()
                 // Return timeout error if the operation failed to finish in
-                // 60s
-                .with_timeout(Duration::from_secs(60))
+                // io_timeout_secs
+                .with_timeout(Duration::from_secs(network.io_timeout_secs))
                 // Return timeout error if the request speed is less than
                 // 1 KiB/s.
                 .with_speed(1024),
@@ -130,6 +140,32 @@ fn load_prometheus_client_layer() -> PrometheusClientLayer {
         .clone()
 }
 
+/// The network config of the storage services, the default one is used if the
+/// data operator is not initiated.
+fn network_config() -> StorageNetworkConfig {
+    STORAGE_NETWORK_CONFIG.get().cloned().unwrap_or_default()
+}
+
+/// The http client shared by the operators of all storage services.
+///
+/// reqwest keeps a connection pool for each endpoint in the client, so the operators
+/// built for each query, e.g. those of stages, reuse the kept-alive connections
+/// instead of connecting again.
+fn http_client() -> Result<HttpClient> {
+    let client = HTTP_CLIENT_INSTANCE.get_or_try_init(|| {
+        let network = network_config();
+        let mut builder = reqwest::ClientBuilder::new()
+            .pool_max_idle_per_host(network.pool_max_idle_per_host)
+            .pool_idle_timeout(Duration::from_secs(network.pool_idle_timeout_secs))
+            .connect_timeout(Duration::from_secs(network.connect_timeout_secs));
+        if network.tcp_keepalive_secs > 0 {
+            builder = builder.tcp_keepalive(Duration::from_secs(network.tcp_keepalive_secs));
+        }
+        HttpClient::build(builder)
+    })?;
+    Ok(client.clone())
+}
+
 /// init_azblob_operator will init an opendal azblob operator.
 pub fn init_azblob_operator(cfg: &StorageAzblobConfig) -> Result<impl Builder> {
     let mut builder = services::Azblob::default();
@@ -147,6 +183,8 @@ pub fn init_azblob_operator(cfg: &StorageAzblobConfig) -> Result<impl Builder> {
     builder.account_name(&cfg.account_name);
     builder.account_key(&cfg.account_key);
 
+    builder.http_client(http_client()?);
+
     Ok(builder)
 }
 
@@ -171,7 +209,14 @@ fn init_gcs_operator(cfg: &StorageGcsConfig) -> Result<impl Builder> {
         .endpoint(&cfg.endpoint_url)
         .bucket(&cfg.bucket)
         .root(&cfg.root)
-        .credential(&cfg.credential);
+        .credential(&cfg.credential)
+        .http_client(http_client()?);
+
+    // Fetch the tokens of the service account from the metadata server, used by
+    // workload identity.
+    if !cfg.service_account.is_empty() {
+        builder.service_account(&cfg.service_account);
+    }
 
     Ok(builder)
 }
@@ -278,28 +323,7 @@ fn init_s3_operator(cfg: &StorageS3Config) -> Result<impl Builder> {
         builder.allow_anonymous();
     }
 
-    let http_builder = {
-        let mut builder = reqwest::ClientBuilder::new();
-
-        // Pool max idle per host controls connection pool size.
-        // Default to no limit, set to `0` for disable it.
-        let pool_max_idle_per_host = env::var("_DATABEND_INTERNAL_POOL_MAX_IDLE_PER_HOST")
-            .ok()
-            .and_then(|v| v.parse::<usize>().ok())
-            .unwrap_or(usize::MAX);
-        builder = builder.pool_max_idle_per_host(pool_max_idle_per_host);
-
-        // Connect timeout default to 30s.
-        let connect_timeout = env::var("_DATABEND_INTERNAL_CONNECT_TIMEOUT")
-            .ok()
-            .and_then(|v| v.parse::<u64>().ok())
-            .unwrap_or(30);
-        builder = builder.connect_timeout(Duration::from_secs(connect_timeout));
-
-        builder
-    };
-
-    builder.http_client(HttpClient::build(http_builder)?);
+    builder.http_client(http_client()?);
 
     Ok(builder)
 }
@@ -317,6 +341,8 @@ fn init_obs_operator(cfg: &StorageObsConfig) -> Result<impl Builder> {
     builder.access_key_id(&cfg.access_key_id);
     builder.secret_access_key(&cfg.secret_access_key);
 
+    builder.http_client(http_client()?);
+
     Ok(builder)
 }
 
@@ -332,7 +358,8 @@ fn init_oss_operator(cfg: &StorageOssConfig) -> Result<impl Builder> {
         .bucket(&cfg.bucket)
         .root(&cfg.root)
         .server_side_encryption(&cfg.server_side_encryption)
-        .server_side_encryption_key_id(&cfg.server_side_encryption_key_id);
+        .server_side_encryption_key_id(&cfg.server_side_encryption_key_id)
+        .http_client(http_client()?);
 
     Ok(builder)
 }
@@ -368,7 +395,8 @@ fn init_cos_operator(cfg: &StorageCosConfig) -> Result<impl Builder> {
         .secret_id(&cfg.secret_id)
         .secret_key(&cfg.secret_key)
         .bucket(&cfg.bucket)
-        .root(&cfg.root);
+        .root(&cfg.root)
+        .http_client(http_client()?);
 
     Ok(builder)
 }
@@ -396,6 +424,9 @@ impl DataOperator {
 
     #[async_backtrace::framed]
     pub async fn init(conf: &StorageConfig) -> common_exception::Result<()> {
+        // Must be set before any operator is built, the shared http client is built by it.
+        let _ = STORAGE_NETWORK_CONFIG.set(conf.network.clone());
+
         GlobalInstance::set(Self::try_create(&conf.params).await?);
 
         Ok(())
@@ -0,0 +1,57 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::storage::StorageParams;
+
+/// Returns the presigned url to unload the data to, if the location is an HTTP url.
+///
+/// A presigned url grants to write exactly the object it is signed for, so the data
+/// is unloaded into a single file, which is uploaded by one `PUT` request.
+pub fn unload_presigned_url(params: &StorageParams) -> Option<String> {
+    match params {
+        StorageParams::Http(cfg) => cfg
+            .paths
+            .first()
+            .map(|path| format!("{}{}", cfg.endpoint_url, path)),
+        _ => None,
+    }
+}
+
+/// Uploads the data to the presigned url.
+///
+/// The url is not included in the errors, since it carries the signature.
+pub async fn put_presigned_url(url: &str, data: Vec<u8>) -> Result<()> {
+    let resp = reqwest::Client::new()
+        .put(url)
+        .body(data)
+        .send()
+        .await
+        .map_err(|err| {
+            ErrorCode::StorageOther(format!(
+                "failed to upload to the presigned url: {}",
+                err.without_url()
+            ))
+        })?;
+
+    let status = resp.status();
+    if !status.is_success() {
+        let body = resp.text().await.unwrap_or_default();
+        return Err(ErrorCode::StorageOther(format!(
+            "failed to upload to the presigned url, status: {status}, response: {body}"
+        )));
+    }
+    Ok(())
+}
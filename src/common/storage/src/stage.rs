@@ -18,6 +18,8 @@ use chrono::DateTime;
 use chrono::Utc;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_app::principal::FileFormatOverrides;
+use common_meta_app::principal::FileFormatParams;
 use common_meta_app::principal::StageInfo;
 use common_meta_app::principal::StageType;
 use common_meta_app::principal::UserIdentity;
@@ -252,6 +254,59 @@ impl StageFilesInfo {
     }
 }
 
+/// The file format of each file of a COPY, which may be overridden by the patterns of
+/// `FILE_FORMAT_OVERRIDES`, matching the paths relative to the location like `PATTERN`.
+#[derive(Clone, Debug)]
+pub struct StageFileFormats {
+    root: String,
+    default: FileFormatParams,
+    overrides: Vec<(Regex, FileFormatParams)>,
+}
+
+impl StageFileFormats {
+    pub fn try_create(
+        files_info: &StageFilesInfo,
+        default: &FileFormatParams,
+        overrides: &FileFormatOverrides,
+    ) -> Result<Self> {
+        let overrides = overrides
+            .files
+            .iter()
+            .map(
+                |(pattern, params)| match Regex::new(&format!("^{pattern}$")) {
+                    Ok(r) => Ok((r, params.clone())),
+                    Err(e) => Err(ErrorCode::SyntaxException(format!(
+                        "Pattern format invalid, got:{}, error:{:?}",
+                        pattern, e
+                    ))),
+                },
+            )
+            .collect::<Result<Vec<_>>>()?;
+        let root = if files_info.path == "/" {
+            "".to_string()
+        } else {
+            files_info.path.clone()
+        };
+        Ok(Self {
+            root,
+            default: default.clone(),
+            overrides,
+        })
+    }
+
+    pub fn has_overrides(&self) -> bool {
+        !self.overrides.is_empty()
+    }
+
+    pub fn get(&self, path: &str) -> &FileFormatParams {
+        let relative = path.strip_prefix(self.root.as_str()).unwrap_or(path);
+        self.overrides
+            .iter()
+            .find(|(pattern, _)| pattern.is_match(relative))
+            .map_or(&self.default, |(_, params)| params)
+    }
+}
+
 fn check_file(path: &str, mode: EntryMode, pattern: &Option<Regex>) -> bool {
     if mode.is_file() {
         pattern.as_ref().map_or(true, |p| p.is_match(path))
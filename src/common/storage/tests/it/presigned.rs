@@ -0,0 +1,37 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_meta_app::storage::StorageFsConfig;
+use common_meta_app::storage::StorageHttpConfig;
+use common_meta_app::storage::StorageParams;
+use common_storage::unload_presigned_url;
+
+#[test]
+fn test_unload_presigned_url() {
+    let params = StorageParams::Http(StorageHttpConfig {
+        endpoint_url: "https://bucket.s3.amazonaws.com".to_string(),
+        paths: vec!["/export/data.csv?X-Amz-Signature=abc%2Bdef".to_string()],
+    });
+    assert_eq!(
+        unload_presigned_url(&params),
+        Some(
+            "https://bucket.s3.amazonaws.com/export/data.csv?X-Amz-Signature=abc%2Bdef".to_string()
+        )
+    );
+
+    let params = StorageParams::Fs(StorageFsConfig {
+        root: "/tmp".to_string(),
+    });
+    assert_eq!(unload_presigned_url(&params), None);
+}
@@ -0,0 +1,345 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Online migration of meta kv layouts.
+//!
+//! A [`KVMigration`] rewrites every value under a key prefix into a new layout.
+//! [`KVMigrator`] applies it in small batches while the cluster keeps serving:
+//! - Every value is rewritten with a txn conditioned on the seq it was read with.
+//!   If a writer updated the record in the meantime, the latest value is read and converted again.
+//! - The progress is persisted in `__fd_kv_migration/<name>` after every batch,
+//!   thus a migration can be stopped at any time and resumed by any node.
+//!
+//! The migrations required by the current binary are listed by [`kv_migrations`],
+//! `databend-metactl --cmd migrate-kv` runs them against a running meta-service.
+
+use std::marker::PhantomData;
+
+use common_meta_app::schema::DatabaseId;
+use common_meta_app::schema::DatabaseMeta;
+use common_meta_app::schema::TableId;
+use common_meta_app::schema::TableMeta;
+use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::Key;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::txn_condition::Target;
+use common_meta_types::txn_op::Request;
+use common_meta_types::ConditionResult;
+use common_meta_types::InvalidReply;
+use common_meta_types::MatchSeq;
+use common_meta_types::MetaError;
+use common_meta_types::MetaNetworkError;
+use common_meta_types::Operation;
+use common_meta_types::SeqV;
+use common_meta_types::TxnCondition;
+use common_meta_types::TxnOp;
+use common_meta_types::TxnPutRequest;
+use common_meta_types::TxnRequest;
+use common_proto_conv::FromToProto;
+use common_proto_conv::VER;
+use futures_util::future;
+use futures_util::StreamExt;
+use futures_util::TryStreamExt;
+use log::info;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::kv_app_error::KVAppError;
+use crate::send_txn;
+use crate::serialize_struct;
+
+pub const PREFIX_KV_MIGRATION: &str = "__fd_kv_migration";
+
+/// Default number of records to migrate in one batch.
+pub const DEFAULT_MIGRATION_BATCH_SIZE: usize = 256;
+
+/// Describes how to convert the values under a key prefix into a new layout.
+pub trait KVMigration: Send + Sync {
+    /// Unique name of this migration, progress is persisted with it.
+    fn name(&self) -> &str;
+
+    /// The prefix of the keys to migrate.
+    fn prefix(&self) -> &str;
+
+    /// Convert a value into the new layout.
+    ///
+    /// Returns `None` if the value is already in the new layout.
+    fn migrate_value(&self, key: &str, value: &[u8]) -> Result<Option<Vec<u8>>, MetaError>;
+}
+
+/// The migrations to run to rewrite the meta data into the layouts of the current binary, in order.
+///
+/// The names contain the target version so that a new version starts new migrations.
+pub fn kv_migrations() -> Vec<Box<dyn KVMigration>> {
+    vec![
+        Box::new(PbVersionMigration::<DatabaseMeta>::new(
+            format!("database_meta_v{}", VER),
+            format!("{}/", DatabaseId::PREFIX),
+            VER,
+        )),
+        Box::new(PbVersionMigration::<TableMeta>::new(
+            format!("table_meta_v{}", VER),
+            format!("{}/", TableId::PREFIX),
+            VER,
+        )),
+    ]
+}
+
+/// Re-encode protobuf values whose `ver` is smaller than `min_ver` with the current version.
+pub struct PbVersionMigration<T> {
+    name: String,
+    prefix: String,
+    min_ver: u64,
+    _p: PhantomData<fn() -> T>,
+}
+
+impl<T> PbVersionMigration<T> {
+    pub fn new(name: impl ToString, prefix: impl ToString, min_ver: u64) -> Self {
+        Self {
+            name: name.to_string(),
+            prefix: prefix.to_string(),
+            min_ver,
+            _p: PhantomData,
+        }
+    }
+}
+
+impl<T> KVMigration for PbVersionMigration<T>
+where
+    T: FromToProto + 'static,
+    T::PB: common_protos::prost::Message + Default,
+{
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    fn migrate_value(&self, key: &str, value: &[u8]) -> Result<Option<Vec<u8>>, MetaError> {
+        let p: T::PB = common_protos::prost::Message::decode(value).map_err(|e| {
+            let inv = InvalidReply::new(format!("migrate {}", key), &e);
+            MetaNetworkError::InvalidReply(inv)
+        })?;
+        if T::get_pb_ver(&p) >= self.min_ver {
+            return Ok(None);
+        }
+
+        let v = T::from_pb(p).map_err(|e| {
+            let inv = InvalidReply::new(format!("migrate {}", key), &e);
+            MetaNetworkError::InvalidReply(inv)
+        })?;
+        Ok(Some(serialize_struct(&v)?))
+    }
+}
+
+/// The persisted progress of a migration.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct KVMigrationProgress {
+    /// The last key that has been processed. Keys are processed in ascending order.
+    pub last_key: Option<String>,
+    /// Number of records that have been examined.
+    pub scanned: u64,
+    /// Number of records that have been rewritten.
+    pub migrated: u64,
+    /// Number of records that are concurrently removed by others before being migrated.
+    pub skipped: u64,
+    pub finished: bool,
+}
+
+pub struct KVMigrator<'a, KV: ?Sized> {
+    kv_api: &'a KV,
+    batch_size: usize,
+}
+
+impl<'a, KV> KVMigrator<'a, KV>
+where KV: kvapi::KVApi<Error = MetaError> + ?Sized
+{
+    pub fn new(kv_api: &'a KV) -> Self {
+        Self {
+            kv_api,
+            batch_size: DEFAULT_MIGRATION_BATCH_SIZE,
+        }
+    }
+
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Load the persisted progress of migration `name` and its seq.
+    pub async fn progress(&self, name: &str) -> Result<(u64, KVMigrationProgress), KVAppError> {
+        let res = self.kv_api.get_kv(&progress_key(name)).await?;
+        match res {
+            None => Ok((0, KVMigrationProgress::default())),
+            Some(seq_v) => {
+                let progress = serde_json::from_slice(&seq_v.data).map_err(|e| {
+                    let inv = InvalidReply::new("load kv migration progress", &e);
+                    MetaError::from(MetaNetworkError::InvalidReply(inv))
+                })?;
+                Ok((seq_v.seq, progress))
+            }
+        }
+    }
+
+    /// Run the migration to the end, one batch at a time.
+    pub async fn run(
+        &self,
+        migration: &dyn KVMigration,
+    ) -> Result<KVMigrationProgress, KVAppError> {
+        loop {
+            let progress = self.run_batch(migration).await?;
+            if progress.finished {
+                return Ok(progress);
+            }
+        }
+    }
+
+    /// Migrate at most `batch_size` records after the last processed key and persist the progress.
+    ///
+    /// If another migrator persisted its progress concurrently, this batch is discarded
+    /// and the progress of the other one is returned.
+    pub async fn run_batch(
+        &self,
+        migration: &dyn KVMigration,
+    ) -> Result<KVMigrationProgress, KVAppError> {
+        let name = migration.name();
+        let (progress_seq, mut progress) = self.progress(name).await?;
+        if progress.finished {
+            return Ok(progress);
+        }
+
+        // Keys are listed in ascending order:
+        // skip the processed ones and stop reading once a batch is collected.
+        let last_key = progress.last_key.clone();
+        let batch = self
+            .kv_api
+            .list_kv(migration.prefix())
+            .await?
+            .try_skip_while(move |item| {
+                let processed = last_key.as_ref().map_or(false, |last| &item.key <= last);
+                future::ready(Ok(processed))
+            })
+            .take(self.batch_size)
+            // Safe unwrap(): list_kv() does not return None value
+            .map_ok(|item| (item.key, SeqV::from(item.value.unwrap())))
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        progress.finished = batch.len() < self.batch_size;
+
+        for (key, seq_v) in batch {
+            progress.scanned += 1;
+
+            match self.migrate_record(migration, &key, seq_v).await? {
+                RecordMigration::Migrated => progress.migrated += 1,
+                RecordMigration::Removed => progress.skipped += 1,
+                RecordMigration::Unchanged => {}
+            }
+
+            progress.last_key = Some(key);
+        }
+
+        let value = serde_json::to_vec(&progress).map_err(|e| {
+            let inv = InvalidReply::new("save kv migration progress", &e);
+            MetaError::from(MetaNetworkError::InvalidReply(inv))
+        })?;
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(
+                &progress_key(name),
+                MatchSeq::Exact(progress_seq),
+                Operation::Update(value),
+                None,
+            ))
+            .await?;
+
+        if !res.is_changed() {
+            let (_, current) = self.progress(name).await?;
+            return Ok(current);
+        }
+
+        info!(
+            "kv migration {}: scanned: {}, migrated: {}, skipped: {}, last_key: {:?}, finished: {}",
+            name,
+            progress.scanned,
+            progress.migrated,
+            progress.skipped,
+            progress.last_key,
+            progress.finished
+        );
+
+        Ok(progress)
+    }
+
+    /// Rewrite one record into the new layout.
+    ///
+    /// If the record is updated concurrently, the latest value is read and converted again.
+    async fn migrate_record(
+        &self,
+        migration: &dyn KVMigration,
+        key: &str,
+        mut seq_v: SeqV,
+    ) -> Result<RecordMigration, KVAppError> {
+        loop {
+            let value = match migration.migrate_value(key, &seq_v.data)? {
+                Some(value) => value,
+                None => return Ok(RecordMigration::Unchanged),
+            };
+
+            let txn_req = TxnRequest {
+                condition: vec![TxnCondition {
+                    key: key.to_string(),
+                    expected: ConditionResult::Eq as i32,
+                    target: Some(Target::Seq(seq_v.seq)),
+                }],
+                if_then: vec![TxnOp {
+                    request: Some(Request::Put(TxnPutRequest {
+                        key: key.to_string(),
+                        value,
+                        prev_value: false,
+                        expire_at: seq_v.meta.as_ref().and_then(|m| m.expire_at),
+                    })),
+                }],
+                else_then: vec![],
+            };
+
+            let (succ, _) = send_txn(self.kv_api, txn_req).await?;
+            if succ {
+                return Ok(RecordMigration::Migrated);
+            }
+
+            seq_v = match self.kv_api.get_kv(key).await? {
+                Some(latest) => latest,
+                None => return Ok(RecordMigration::Removed),
+            };
+        }
+    }
+}
+
+/// The outcome of migrating one record.
+enum RecordMigration {
+    Migrated,
+    /// Already in the new layout.
+    Unchanged,
+    /// Removed concurrently before being migrated.
+    Removed,
+}
+
+fn progress_key(name: &str) -> String {
+    kvapi::KeyBuilder::new_prefixed(PREFIX_KV_MIGRATION)
+        .push_str(name)
+        .done()
+}
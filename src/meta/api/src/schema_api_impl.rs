@@ -14,6 +14,7 @@
 
 use std::cmp::min;
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::fmt::Display;
 use std::sync::Arc;
 
@@ -37,11 +38,14 @@ use common_meta_app::app_error::StreamVersionMismatched;
 use common_meta_app::app_error::TableAlreadyExists;
 use common_meta_app::app_error::TableLockExpired;
 use common_meta_app::app_error::TableVersionMismatched;
+use common_meta_app::app_error::TenantQuotaExceeded;
 use common_meta_app::app_error::TxnRetryMaxTimes;
 use common_meta_app::app_error::UndropDbHasNoHistory;
+use common_meta_app::app_error::UndropDbRetentionTimeExpired;
 use common_meta_app::app_error::UndropDbWithNoDropTime;
 use common_meta_app::app_error::UndropTableAlreadyExists;
 use common_meta_app::app_error::UndropTableHasNoHistory;
+use common_meta_app::app_error::UndropTableRetentionTimeExpired;
 use common_meta_app::app_error::UndropTableWithNoDropTime;
 use common_meta_app::app_error::UnknownCatalog;
 use common_meta_app::app_error::UnknownIndex;
@@ -162,9 +166,13 @@ use common_meta_app::schema::UpsertTableOptionReply;
 use common_meta_app::schema::UpsertTableOptionReq;
 use common_meta_app::schema::VirtualColumnMeta;
 use common_meta_app::schema::VirtualColumnNameIdent;
+use common_meta_app::schema::OPT_KEY_DATA_RETENTION_TIME_IN_DAYS;
 use common_meta_app::share::ShareGrantObject;
 use common_meta_app::share::ShareNameIdent;
+use common_meta_app::share::ShareSpec;
 use common_meta_app::share::ShareTableInfoMap;
+use common_meta_app::tenant::TenantQuota;
+use common_meta_app::tenant::TenantQuotaIdent;
 use common_meta_kvapi::kvapi;
 use common_meta_kvapi::kvapi::Key;
 use common_meta_kvapi::kvapi::UpsertKVReq;
@@ -483,6 +491,20 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
                 }
             }
 
+            // drop the tables of other databases that depend on this db, in the same txn
+            if !req.dependent_table_ids.is_empty() {
+                drop_dependent_tables(
+                    self,
+                    &tenant_dbname.tenant,
+                    db_id,
+                    &req.dependent_table_ids,
+                    &mut condition,
+                    &mut if_then,
+                    &mut spec_vec,
+                )
+                .await?;
+            }
+
             let txn_req = TxnRequest {
                 condition,
                 if_then,
@@ -581,6 +603,18 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
                         UndropDbWithNoDropTime::new(&name_key.db_name),
                     )));
                 }
+                // the dropped db may have been garbage collected
+                if is_drop_time_out_of_retention_time(
+                    &db_meta.drop_on,
+                    &Utc::now(),
+                    &db_meta.options,
+                ) {
+                    return Err(KVAppError::AppError(
+                        AppError::UndropDbRetentionTimeExpired(UndropDbRetentionTimeExpired::new(
+                            &name_key.db_name,
+                        )),
+                    ));
+                }
                 db_meta.drop_on = None;
 
                 let txn_req = TxnRequest {
@@ -840,7 +874,11 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
                         let db_meta = db_meta.unwrap();
                         // if include drop db, then no need to fill out of retention time db
                         if !include_drop_db
-                            && is_drop_time_out_of_retention_time(&db_meta.drop_on, &now)
+                            && is_drop_time_out_of_retention_time(
+                                &db_meta.drop_on,
+                                &now,
+                                &db_meta.options,
+                            )
                         {
                             continue;
                         }
@@ -1587,6 +1625,9 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
                     (0, tb_count)
                 }
             };
+            // the txn asserts the table count, so the quota can not be exceeded concurrently
+            check_max_tables_quota(self, &tb_count_key.tenant, tb_count, "create_table").await?;
+
             // Create table by inserting these record:
             // (db_id, table_name) -> table_id
             // (table_id) -> table_meta
@@ -1780,6 +1821,8 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
                     (0, tb_count)
                 }
             };
+            check_max_tables_quota(self, &tb_count_key.tenant, tb_count, "undrop_table").await?;
+
             // add drop_on time on table meta
             // (db_id, table_name) -> table_id
 
@@ -1798,6 +1841,18 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
                         UndropTableWithNoDropTime::new(&tenant_dbname_tbname.table_name),
                     )));
                 }
+                // the dropped table may have been garbage collected
+                if is_drop_time_out_of_retention_time(
+                    &tb_meta.drop_on,
+                    &Utc::now(),
+                    &tb_meta.options,
+                ) {
+                    return Err(KVAppError::AppError(
+                        AppError::UndropTableRetentionTimeExpired(
+                            UndropTableRetentionTimeExpired::new(&tenant_dbname_tbname.table_name),
+                        ),
+                    ));
+                }
                 tb_meta.drop_on = None;
 
                 let txn_req = TxnRequest {
@@ -2216,7 +2271,11 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
 
                         // Safe unwrap() because: tb_meta_seq > 0
                         let tb_meta = tb_meta.unwrap();
-                        if is_drop_time_out_of_retention_time(&tb_meta.drop_on, &now) {
+                        if is_drop_time_out_of_retention_time(
+                            &tb_meta.drop_on,
+                            &now,
+                            &tb_meta.options,
+                        ) {
                             continue;
                         }
 
@@ -3834,14 +3893,19 @@ async fn list_table_copied_files(
     Ok(copied_files)
 }
 
-// Return true if drop time is out of `DATA_RETENTION_TIME_IN_DAYS option,
+// Return true if drop time is out of `DATA_RETENTION_TIME_IN_DAYS` option,
 // use DEFAULT_DATA_RETENTION_SECONDS by default.
 fn is_drop_time_out_of_retention_time(
     drop_on: &Option<DateTime<Utc>>,
     now: &DateTime<Utc>,
+    options: &BTreeMap<String, String>,
 ) -> bool {
     if let Some(drop_on) = drop_on {
-        return now.timestamp() - drop_on.timestamp() >= DEFAULT_DATA_RETENTION_SECONDS;
+        let retention_seconds = options
+            .get(OPT_KEY_DATA_RETENTION_TIME_IN_DAYS)
+            .and_then(|days| days.parse::<i64>().ok())
+            .map_or(DEFAULT_DATA_RETENTION_SECONDS, |days| days * 24 * 60 * 60);
+        return now.timestamp() - drop_on.timestamp() >= retention_seconds;
     }
 
     false
@@ -3955,6 +4019,146 @@ async fn count_tables(
     Ok(count)
 }
 
+/// Mark the given tables of databases other than `db_id` as dropped, appending the
+/// conditions and operations to the txn of dropping the database `db_id`.
+///
+/// Tables that no longer exist are skipped.
+async fn drop_dependent_tables(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    tenant: &str,
+    db_id: u64,
+    table_ids: &[u64],
+    condition: &mut Vec<TxnCondition>,
+    if_then: &mut Vec<TxnOp>,
+    spec_vec: &mut Vec<ShareSpec>,
+) -> Result<(), KVAppError> {
+    let mut dependent_db_ids = BTreeSet::new();
+    let mut dropped = 0;
+    for table_id in table_ids {
+        let tbid = TableId {
+            table_id: *table_id,
+        };
+        let (tb_meta_seq, tb_meta): (_, Option<TableMeta>) = get_pb_value(kv_api, &tbid).await?;
+        let mut tb_meta = match tb_meta {
+            Some(tb_meta) if tb_meta_seq != 0 && tb_meta.drop_on.is_none() => tb_meta,
+            _ => continue,
+        };
+
+        let table_id_to_name = TableIdToName {
+            table_id: *table_id,
+        };
+        let (_, table_name_opt): (_, Option<DBIdTableName>) =
+            get_pb_value(kv_api, &table_id_to_name).await?;
+        let Some(dbid_tbname) = table_name_opt else {
+            continue;
+        };
+        if dbid_tbname.db_id == db_id {
+            continue;
+        }
+        let (tb_id_seq, id) = get_u64_value(kv_api, &dbid_tbname).await?;
+        if tb_id_seq == 0 || id != *table_id {
+            continue;
+        }
+
+        let (db_meta_seq, db_meta) =
+            get_db_by_id_or_err(kv_api, dbid_tbname.db_id, "drop_dependent_tables").await?;
+        // cannot operate on shared database
+        if let Some(from_share) = db_meta.from_share {
+            return Err(KVAppError::AppError(AppError::ShareHasNoGrantedPrivilege(
+                ShareHasNoGrantedPrivilege::new(&from_share.tenant, &from_share.share_name),
+            )));
+        }
+        if dependent_db_ids.insert(dbid_tbname.db_id) {
+            let dbid = DatabaseId {
+                db_id: dbid_tbname.db_id,
+            };
+            // assert db_meta seq so that no other txn can delete this db
+            condition.push(txn_cond_seq(&dbid, Eq, db_meta_seq));
+            if_then.push(txn_op_put(&dbid, serialize_struct(&db_meta)?));
+        }
+
+        tb_meta.drop_on = Some(Utc::now());
+        condition.push(txn_cond_seq(&dbid_tbname, Eq, tb_id_seq));
+        condition.push(txn_cond_seq(&tbid, Eq, tb_meta_seq));
+        if_then.push(txn_op_del(&dbid_tbname)); // (db_id, tb_name) -> tb_id
+        if_then.push(txn_op_put(&tbid, serialize_struct(&tb_meta)?)); // (tenant, db_id, tb_id) -> tb_meta
+        dropped += 1;
+
+        for share_id in &db_meta.shared_by {
+            let res = remove_table_from_share(
+                kv_api,
+                *share_id,
+                *table_id,
+                tenant.to_string(),
+                condition,
+                if_then,
+            )
+            .await;
+            match res {
+                Ok((share_name, share_meta, _)) => {
+                    spec_vec.push(
+                        convert_share_meta_to_spec(kv_api, &share_name, *share_id, share_meta)
+                            .await?,
+                    );
+                }
+                Err(e) => match e {
+                    // ignore UnknownShareId error
+                    KVAppError::AppError(AppError::UnknownShareId(_)) => {
+                        error!(
+                            "UnknownShareId {} when drop_dependent_tables tenant:{} table_id:{} shared by",
+                            share_id, tenant, table_id
+                        );
+                    }
+                    _ => return Err(e),
+                },
+            }
+        }
+    }
+
+    if dropped > 0 {
+        // update table count atomically
+        let tb_count_key = CountTablesKey {
+            tenant: tenant.to_string(),
+        };
+        let (tb_count_seq, tb_count) = get_u64_value(kv_api, &tb_count_key).await?;
+        if tb_count_seq > 0 {
+            condition.push(txn_cond_seq(&tb_count_key, Eq, tb_count_seq));
+            if_then.push(txn_op_put(
+                &tb_count_key,
+                serialize_u64(tb_count.saturating_sub(dropped))?,
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Returns an error if creating one more table exceeds the `max_tables` quota of the tenant.
+async fn check_max_tables_quota(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    tenant: &str,
+    tb_count: u64,
+    context: &str,
+) -> Result<(), KVAppError> {
+    let key = TenantQuotaIdent::new(tenant).to_string_key();
+    let Some(seq_v) = kv_api.get_kv(&key).await? else {
+        return Ok(());
+    };
+    let quota: TenantQuota = match serde_json::from_slice(&seq_v.data) {
+        Ok(quota) => quota,
+        Err(e) => {
+            error!("invalid quota of tenant {}: {}", tenant, e);
+            return Ok(());
+        }
+    };
+
+    if quota.max_tables != 0 && tb_count >= quota.max_tables as u64 {
+        return Err(KVAppError::AppError(AppError::TenantQuotaExceeded(
+            TenantQuotaExceeded::new(tenant, format!("max tables {}", quota.max_tables), context),
+        )));
+    }
+    Ok(())
+}
+
 async fn get_share_table_info_map(
     kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
     table_meta: &TableMeta,
@@ -106,6 +106,7 @@ use common_meta_app::schema::UpdateVirtualColumnReq;
 use common_meta_app::schema::UpsertTableCopiedFileReq;
 use common_meta_app::schema::UpsertTableOptionReq;
 use common_meta_app::schema::VirtualColumnNameIdent;
+use common_meta_app::schema::OPT_KEY_DATA_RETENTION_TIME_IN_DAYS;
 use common_meta_app::share::AddShareAccountsReq;
 use common_meta_app::share::CreateShareReq;
 use common_meta_app::share::GrantShareObjectReq;
@@ -281,6 +282,10 @@ impl SchemaApiTestSuite {
         suite
             .database_drop_out_of_retention_time_history(&b.build().await)
             .await?;
+        suite.undrop_out_of_retention_time(&b.build().await).await?;
+        suite
+            .database_drop_with_dependent_tables(&b.build().await)
+            .await?;
 
         suite.table_create_get_drop(&b.build().await).await?;
         suite
@@ -675,6 +680,7 @@ impl SchemaApiTestSuite {
                     tenant: tenant.to_string(),
                     db_name: "db2".to_string(),
                 },
+                dependent_table_ids: vec![],
             })
             .await?;
         }
@@ -697,6 +703,7 @@ impl SchemaApiTestSuite {
                     tenant: tenant.to_string(),
                     db_name: "db2".to_string(),
                 },
+                dependent_table_ids: vec![],
             })
             .await?;
         }
@@ -749,6 +756,7 @@ impl SchemaApiTestSuite {
             mt.drop_database(DropDatabaseReq {
                 if_exists: false,
                 name_ident: db_name1.clone(),
+                dependent_table_ids: vec![],
             })
             .await?;
 
@@ -885,6 +893,7 @@ impl SchemaApiTestSuite {
                     tenant: tenant1.to_string(),
                     db_name: "db2".to_string(),
                 },
+                dependent_table_ids: vec![],
             })
             .await?;
         }
@@ -907,6 +916,7 @@ impl SchemaApiTestSuite {
                     tenant: tenant1.to_string(),
                     db_name: "db2".to_string(),
                 },
+                dependent_table_ids: vec![],
             })
             .await?;
         }
@@ -1119,6 +1129,51 @@ impl SchemaApiTestSuite {
         Ok(())
     }
 
+    #[minitrace::trace]
+    async fn database_drop_with_dependent_tables<MT>(&self, mt: &MT) -> anyhow::Result<()>
+    where MT: SchemaApi + kvapi::AsKVApi<Error = MetaError> {
+        let mut util1 = Util::new(mt, "tenant1", "db1", "tb1", "FUSE");
+        let mut util2 = Util::new(mt, "tenant1", "db2", "v1", "VIEW");
+        let mut util3 = Util::new(mt, "tenant1", "db2", "tb2", "FUSE");
+
+        info!("--- prepare dbs and tables");
+        {
+            util1.create_db().await?;
+            util1.create_table().await?;
+            util2.create_db().await?;
+            util2.create_table().await?;
+            util3.create_table().await?;
+        }
+
+        info!("--- drop db1 with the dependent table db2.v1 and an unknown table id");
+        {
+            mt.drop_database(DropDatabaseReq {
+                if_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: "tenant1".to_string(),
+                    db_name: "db1".to_string(),
+                },
+                dependent_table_ids: vec![util2.table_id, 9999],
+            })
+            .await?;
+        }
+
+        info!("--- db2.v1 is dropped in the same txn, db2.tb2 is kept");
+        {
+            let res = mt.list_tables(ListTableReq::new("tenant1", "db2")).await?;
+            let names = res.iter().map(|t| t.name.clone()).collect::<Vec<_>>();
+            assert_eq!(vec!["tb2".to_string()], names);
+
+            let (_, table_meta) = mt.get_table_by_id(util2.table_id).await?;
+            assert!(table_meta.drop_on.is_some());
+
+            let res = mt.get_database(GetDatabaseReq::new("tenant1", "db1")).await;
+            assert!(res.is_err());
+        }
+
+        Ok(())
+    }
+
     #[minitrace::trace]
     async fn database_drop_undrop_list_history<MT: SchemaApi>(
         &self,
@@ -1170,6 +1225,7 @@ impl SchemaApiTestSuite {
             mt.drop_database(DropDatabaseReq {
                 if_exists: false,
                 name_ident: db_name_ident.clone(),
+                dependent_table_ids: vec![],
             })
             .await?;
             let res = mt
@@ -1210,6 +1266,7 @@ impl SchemaApiTestSuite {
             mt.drop_database(DropDatabaseReq {
                 if_exists: false,
                 name_ident: db_name_ident.clone(),
+                dependent_table_ids: vec![],
             })
             .await?;
             let res = mt
@@ -1290,6 +1347,7 @@ impl SchemaApiTestSuite {
             mt.drop_database(DropDatabaseReq {
                 if_exists: false,
                 name_ident: new_db_name_ident.clone(),
+                dependent_table_ids: vec![],
             })
             .await?;
             let res = mt
@@ -2872,6 +2930,155 @@ impl SchemaApiTestSuite {
         Ok(())
     }
 
+    #[minitrace::trace]
+    async fn undrop_out_of_retention_time<MT: SchemaApi + kvapi::AsKVApi<Error = MetaError>>(
+        self,
+        mt: &MT,
+    ) -> anyhow::Result<()> {
+        let tenant = "tenant1_undrop_out_of_retention_time";
+        let db1_name = "db1_undrop_out_of_retention_time";
+        let db2_name = "db2_undrop_out_of_retention_time";
+        let tb1_name = "tb1_undrop_out_of_retention_time";
+        let db1_name_ident = DatabaseNameIdent {
+            tenant: tenant.to_string(),
+            db_name: db1_name.to_string(),
+        };
+        let db2_name_ident = DatabaseNameIdent {
+            tenant: tenant.to_string(),
+            db_name: db2_name.to_string(),
+        };
+        let tbl_name_ident = TableNameIdent {
+            tenant: tenant.to_string(),
+            db_name: db2_name.to_string(),
+            table_name: tb1_name.to_string(),
+        };
+
+        info!("--- undrop db dropped out of retention time");
+        {
+            let drop_on = Some(Utc::now() - Duration::days(2));
+            self.create_out_of_retention_time_db(mt, db1_name_ident.clone(), drop_on, true)
+                .await?;
+
+            let res = mt
+                .undrop_database(UndropDatabaseReq {
+                    name_ident: db1_name_ident.clone(),
+                })
+                .await;
+            assert!(res.is_err());
+            assert_eq!(
+                ErrorCode::UndropDbRetentionTimeExpired("").code(),
+                ErrorCode::from(res.unwrap_err()).code()
+            );
+        }
+
+        info!("--- undrop table dropped out of retention time");
+        {
+            let res = mt
+                .create_database(CreateDatabaseReq {
+                    if_not_exists: false,
+                    name_ident: db2_name_ident.clone(),
+                    meta: DatabaseMeta::default(),
+                })
+                .await?;
+
+            let drop_on = Some(Utc::now() - Duration::days(2));
+            self.create_out_of_retention_time_table(
+                mt,
+                tbl_name_ident.clone(),
+                DBIdTableName {
+                    db_id: res.db_id,
+                    table_name: tb1_name.to_string(),
+                },
+                drop_on,
+                true,
+            )
+            .await?;
+
+            let res = mt
+                .undrop_table(UndropTableReq {
+                    name_ident: tbl_name_ident.clone(),
+                })
+                .await;
+            assert!(res.is_err());
+            assert_eq!(
+                ErrorCode::UndropTableRetentionTimeExpired("").code(),
+                ErrorCode::from(res.unwrap_err()).code()
+            );
+        }
+
+        info!("--- undrop table dropped within retention time");
+        {
+            let drop_on = Some(Utc::now() - Duration::hours(1));
+            let res = mt
+                .get_database(GetDatabaseReq::new(tenant, db2_name))
+                .await?;
+            self.create_out_of_retention_time_table(
+                mt,
+                tbl_name_ident.clone(),
+                DBIdTableName {
+                    db_id: res.ident.db_id,
+                    table_name: tb1_name.to_string(),
+                },
+                drop_on,
+                true,
+            )
+            .await?;
+
+            mt.undrop_table(UndropTableReq {
+                name_ident: tbl_name_ident.clone(),
+            })
+            .await?;
+
+            let got = mt.get_table((tenant, db2_name, tb1_name).into()).await?;
+            assert!(got.meta.drop_on.is_none());
+        }
+
+        info!("--- undrop table dropped within the data_retention_time_in_days option");
+        {
+            let res = mt
+                .get_database(GetDatabaseReq::new(tenant, db2_name))
+                .await?;
+            let got = mt.get_table((tenant, db2_name, tb1_name).into()).await?;
+            mt.drop_table_by_id(DropTableByIdReq {
+                if_exists: false,
+                tenant: tenant.to_string(),
+                tb_id: got.ident.table_id,
+            })
+            .await?;
+
+            let drop_on = Some(Utc::now() - Duration::days(2));
+            let (table_id, mut drop_data) = self
+                .create_out_of_retention_time_table(
+                    mt,
+                    tbl_name_ident.clone(),
+                    DBIdTableName {
+                        db_id: res.ident.db_id,
+                        table_name: tb1_name.to_string(),
+                    },
+                    drop_on,
+                    true,
+                )
+                .await?;
+
+            drop_data.options.insert(
+                OPT_KEY_DATA_RETENTION_TIME_IN_DAYS.to_string(),
+                "7".to_string(),
+            );
+            let data = serialize_struct(&drop_data)?;
+            upsert_test_data(mt.as_kv_api(), &TableId { table_id }, data).await?;
+
+            mt.undrop_table(UndropTableReq {
+                name_ident: tbl_name_ident.clone(),
+            })
+            .await?;
+
+            let got = mt.get_table((tenant, db2_name, tb1_name).into()).await?;
+            assert!(got.meta.drop_on.is_none());
+        }
+
+        Ok(())
+    }
+
     #[minitrace::trace]
     async fn create_out_of_retention_time_db<MT: SchemaApi + kvapi::AsKVApi<Error = MetaError>>(
         self,
@@ -3607,6 +3814,7 @@ impl SchemaApiTestSuite {
                     tenant: tenant.to_string(),
                     db_name: "db1".to_string(),
                 },
+                dependent_table_ids: vec![],
             })
             .await?;
         }
@@ -3705,6 +3913,7 @@ impl SchemaApiTestSuite {
                     tenant: tenant.to_string(),
                     db_name: "db2".to_string(),
                 },
+                dependent_table_ids: vec![],
             })
             .await?;
             // change db meta to make this db drop time outof filter time
@@ -6310,6 +6519,7 @@ where MT: SchemaApi + kvapi::AsKVApi<Error = MetaError>
                 tenant: self.tenant(),
                 db_name: self.db_name(),
             },
+            dependent_table_ids: vec![],
         };
 
         self.mt.drop_database(req).await?;
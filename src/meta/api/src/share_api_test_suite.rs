@@ -1726,6 +1726,7 @@ impl ShareApiTestSuite {
                     tenant: tenant.to_string(),
                     db_name: db_name.to_string(),
                 },
+                dependent_table_ids: vec![],
             })
             .await?;
 
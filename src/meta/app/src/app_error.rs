@@ -132,6 +132,20 @@ impl UndropDbWithNoDropTime {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("UndropDbRetentionTimeExpired: undrop {db_name} out of retention time")]
+pub struct UndropDbRetentionTimeExpired {
+    db_name: String,
+}
+
+impl UndropDbRetentionTimeExpired {
+    pub fn new(db_name: impl Into<String>) -> Self {
+        Self {
+            db_name: db_name.into(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
 #[error("UndropDbHasNoHistory: undrop {db_name} has no db id history")]
 pub struct UndropDbHasNoHistory {
@@ -220,6 +234,20 @@ impl UndropTableWithNoDropTime {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("UndropTableRetentionTimeExpired: undrop {table_name} out of retention time")]
+pub struct UndropTableRetentionTimeExpired {
+    table_name: String,
+}
+
+impl UndropTableRetentionTimeExpired {
+    pub fn new(table_name: impl Into<String>) -> Self {
+        Self {
+            table_name: table_name.into(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
 #[error("DropTableWithDropTime: drop {table_name} with drop_on time")]
 pub struct DropTableWithDropTime {
@@ -765,6 +793,28 @@ impl TxnRetryMaxTimes {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("TenantQuotaExceeded: {tenant} exceeds {quota} while {context}")]
+pub struct TenantQuotaExceeded {
+    tenant: String,
+    quota: String,
+    context: String,
+}
+
+impl TenantQuotaExceeded {
+    pub fn new(
+        tenant: impl Into<String>,
+        quota: impl Into<String>,
+        context: impl Into<String>,
+    ) -> Self {
+        Self {
+            tenant: tenant.into(),
+            quota: quota.into(),
+            context: context.into(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
 #[error("CreateIndexWithDropTime: create {index_name} with drop time")]
 pub struct CreateIndexWithDropTime {
@@ -897,6 +947,9 @@ pub enum AppError {
     #[error(transparent)]
     UndropTableWithNoDropTime(#[from] UndropTableWithNoDropTime),
 
+    #[error(transparent)]
+    UndropTableRetentionTimeExpired(#[from] UndropTableRetentionTimeExpired),
+
     #[error(transparent)]
     DropTableWithDropTime(#[from] DropTableWithDropTime),
 
@@ -918,6 +971,9 @@ pub enum AppError {
     #[error(transparent)]
     UndropDbWithNoDropTime(#[from] UndropDbWithNoDropTime),
 
+    #[error(transparent)]
+    UndropDbRetentionTimeExpired(#[from] UndropDbRetentionTimeExpired),
+
     #[error(transparent)]
     UndropDbHasNoHistory(#[from] UndropDbHasNoHistory),
 
@@ -939,6 +995,9 @@ pub enum AppError {
     #[error(transparent)]
     TxnRetryMaxTimes(#[from] TxnRetryMaxTimes),
 
+    #[error(transparent)]
+    TenantQuotaExceeded(#[from] TenantQuotaExceeded),
+
     // share api errors
     #[error(transparent)]
     ShareAlreadyExists(#[from] ShareAlreadyExists),
@@ -1259,12 +1318,27 @@ impl AppErrorMessage for TxnRetryMaxTimes {
     }
 }
 
+impl AppErrorMessage for TenantQuotaExceeded {
+    fn message(&self) -> String {
+        format!("Tenant '{}' quota exceeded: {}", self.tenant, self.quota)
+    }
+}
+
 impl AppErrorMessage for UndropTableWithNoDropTime {
     fn message(&self) -> String {
         format!("Undrop table '{}' with no drop_on time", self.table_name)
     }
 }
 
+impl AppErrorMessage for UndropTableRetentionTimeExpired {
+    fn message(&self) -> String {
+        format!(
+            "Undrop table '{}' is not allowed, it was dropped out of the retention time",
+            self.table_name
+        )
+    }
+}
+
 impl AppErrorMessage for DropTableWithDropTime {
     fn message(&self) -> String {
         format!("Drop table '{}' with drop_on time", self.table_name)
@@ -1277,6 +1351,15 @@ impl AppErrorMessage for UndropDbWithNoDropTime {
     }
 }
 
+impl AppErrorMessage for UndropDbRetentionTimeExpired {
+    fn message(&self) -> String {
+        format!(
+            "Undrop db '{}' is not allowed, it was dropped out of the retention time",
+            self.db_name
+        )
+    }
+}
+
 impl AppErrorMessage for DropDbWithDropTime {
     fn message(&self) -> String {
         format!("Drop db '{}' with drop_on time", self.db_name)
@@ -1380,6 +1463,12 @@ impl From<AppError> for ErrorCode {
             AppError::UndropDbWithNoDropTime(err) => {
                 ErrorCode::UndropDbWithNoDropTime(err.message())
             }
+            AppError::UndropDbRetentionTimeExpired(err) => {
+                ErrorCode::UndropDbRetentionTimeExpired(err.message())
+            }
+            AppError::UndropTableRetentionTimeExpired(err) => {
+                ErrorCode::UndropTableRetentionTimeExpired(err.message())
+            }
             AppError::TableAlreadyExists(err) => ErrorCode::TableAlreadyExists(err.message()),
             AppError::ViewAlreadyExists(err) => ErrorCode::ViewAlreadyExists(err.message()),
             AppError::CreateTableWithDropTime(err) => {
@@ -1427,6 +1516,7 @@ impl From<AppError> for ErrorCode {
                 ErrorCode::CannotShareDatabaseCreatedFromShare(err.message())
             }
             AppError::TxnRetryMaxTimes(err) => ErrorCode::TxnRetryMaxTimes(err.message()),
+            AppError::TenantQuotaExceeded(err) => ErrorCode::TenantQuotaExceeded(err.message()),
             AppError::DuplicatedUpsertFiles(err) => ErrorCode::DuplicatedUpsertFiles(err.message()),
             AppError::CreateIndexWithDropTime(err) => {
                 ErrorCode::CreateIndexWithDropTime(err.message())
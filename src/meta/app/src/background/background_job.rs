@@ -242,6 +242,30 @@ impl BackgroundJobInfo {
             created_at: Utc::now(),
         }
     }
+
+    pub fn new_row_ttl_job(job_params: BackgroundJobParams, creator: UserIdentity) -> Self {
+        Self {
+            job_status: Option::from(BackgroundJobStatus::new(&job_params)),
+            job_params: Some(job_params),
+            task_type: BackgroundTaskType::ROW_TTL,
+            last_updated: Some(Utc::now()),
+            message: "".to_string(),
+            creator: Some(creator),
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn new_analyze_job(job_params: BackgroundJobParams, creator: UserIdentity) -> Self {
+        Self {
+            job_status: Option::from(BackgroundJobStatus::new(&job_params)),
+            job_params: Some(job_params),
+            task_type: BackgroundTaskType::ANALYZE,
+            last_updated: Some(Utc::now()),
+            message: "".to_string(),
+            creator: Some(creator),
+            created_at: Utc::now(),
+        }
+    }
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, Eq, PartialEq)]
@@ -62,6 +62,8 @@ pub enum BackgroundTaskType {
     #[default]
     COMPACTION = 0,
     VACUUM = 1,
+    ROW_TTL = 2,
+    ANALYZE = 3,
 }
 
 impl Display for BackgroundTaskType {
@@ -151,6 +153,44 @@ impl BackgroundTaskInfo {
             created_at: now,
         }
     }
+
+    pub fn new_row_ttl_task(
+        creator: BackgroundJobIdent,
+        manual_trigger: Option<ManualTriggerParams>,
+        message: String,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            last_updated: Some(now),
+            task_type: BackgroundTaskType::ROW_TTL,
+            task_state: BackgroundTaskState::STARTED,
+            message,
+            compaction_task_stats: None,
+            vacuum_stats: None,
+            manual_trigger,
+            creator: Some(creator),
+            created_at: now,
+        }
+    }
+
+    pub fn new_analyze_task(
+        creator: BackgroundJobIdent,
+        manual_trigger: Option<ManualTriggerParams>,
+        message: String,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            last_updated: Some(now),
+            task_type: BackgroundTaskType::ANALYZE,
+            task_state: BackgroundTaskState::STARTED,
+            message,
+            compaction_task_stats: None,
+            vacuum_stats: None,
+            manual_trigger,
+            creator: Some(creator),
+            created_at: now,
+        }
+    }
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -36,6 +36,14 @@ const OPT_NULL_DISPLAY: &str = "null_display";
 const OPT_ESCAPE: &str = "escape";
 const OPT_QUOTE: &str = "quote";
 const OPT_ROW_TAG: &str = "row_tag";
+const OPT_ROW_PATH: &str = "row_path";
+const OPT_COLUMN_PATHS: &str = "column_paths";
+const OPT_DESCRIPTOR_SET: &str = "descriptor_set";
+const OPT_MESSAGE_NAME: &str = "message_name";
+const OPT_SHEET_NAME: &str = "sheet_name";
+const OPT_TIMESTAMP_FORMAT: &str = "timestamp_format";
+const OPT_DATE_FORMAT: &str = "date_format";
+const OPT_BINARY_FORMAT: &str = "binary_format";
 const OPT_ERROR_ON_COLUMN_COUNT_MISMATCH: &str = "error_on_column_count_mismatch";
 const MISSING_FIELD_AS: &str = "missing_field_as";
 const NULL_FIELD_AS: &str = "null_field_as";
@@ -111,6 +119,8 @@ pub enum FileFormatParams {
     Json(JsonFileFormatParams),
     Xml(XmlFileFormatParams),
     Parquet(ParquetFileFormatParams),
+    Protobuf(ProtobufFileFormatParams),
+    Xlsx(XlsxFileFormatParams),
 }
 
 impl FileFormatParams {
@@ -122,6 +132,8 @@ impl FileFormatParams {
             FileFormatParams::Json(_) => StageFileFormatType::Json,
             FileFormatParams::Xml(_) => StageFileFormatType::Xml,
             FileFormatParams::Parquet(_) => StageFileFormatType::Parquet,
+            FileFormatParams::Protobuf(_) => StageFileFormatType::Protobuf,
+            FileFormatParams::Xlsx(_) => StageFileFormatType::Xlsx,
         }
     }
 
@@ -139,6 +151,12 @@ impl FileFormatParams {
                 Ok(FileFormatParams::Json(JsonFileFormatParams::default()))
             }
             StageFileFormatType::Xml => Ok(FileFormatParams::Xml(XmlFileFormatParams::default())),
+            StageFileFormatType::Protobuf => Ok(FileFormatParams::Protobuf(
+                ProtobufFileFormatParams::default(),
+            )),
+            StageFileFormatType::Xlsx => {
+                Ok(FileFormatParams::Xlsx(XlsxFileFormatParams::default()))
+            }
             _ => Err(ErrorCode::IllegalFileFormat(format!(
                 "Unsupported file format type: {:?}",
                 format_type
@@ -154,6 +172,9 @@ impl FileFormatParams {
             FileFormatParams::Json(v) => v.compression,
             FileFormatParams::Xml(v) => v.compression,
             FileFormatParams::Parquet(_) => StageFileCompression::None,
+            FileFormatParams::Protobuf(v) => v.compression,
+            // xlsx files are zip archives already.
+            FileFormatParams::Xlsx(_) => StageFileCompression::None,
         }
     }
 
@@ -164,16 +185,43 @@ impl FileFormatParams {
             StageFileFormatType::Xml => {
                 let default = XmlFileFormatParams::default();
                 let row_tag = ast.take_string(OPT_ROW_TAG, default.row_tag);
+                let row_path = ast.take_string(OPT_ROW_PATH, default.row_path);
+                let column_paths = match ast.options.remove(OPT_COLUMN_PATHS) {
+                    Some(v) => XmlFileFormatParams::parse_column_paths(&v)?,
+                    None => default.column_paths,
+                };
                 let compression = ast.take_compression()?;
                 FileFormatParams::Xml(XmlFileFormatParams {
                     compression,
                     row_tag,
+                    row_path,
+                    column_paths,
                 })
             }
             StageFileFormatType::Json => {
                 let compression = ast.take_compression()?;
                 FileFormatParams::Json(JsonFileFormatParams { compression })
             }
+            StageFileFormatType::Protobuf => {
+                let default = ProtobufFileFormatParams::default();
+                let compression = ast.take_compression()?;
+                let descriptor_set = ast.take_string(OPT_DESCRIPTOR_SET, default.descriptor_set);
+                let message_name = ast.take_string(OPT_MESSAGE_NAME, default.message_name);
+                FileFormatParams::Protobuf(ProtobufFileFormatParams {
+                    compression,
+                    descriptor_set,
+                    message_name,
+                })
+            }
+            StageFileFormatType::Xlsx => {
+                let default = XlsxFileFormatParams::default();
+                let sheet_name = ast.take_string(OPT_SHEET_NAME, default.sheet_name);
+                let headers = ast.take_u64(OPT_SKIP_HEADER, default.headers)?;
+                FileFormatParams::Xlsx(XlsxFileFormatParams {
+                    sheet_name,
+                    headers,
+                })
+            }
             StageFileFormatType::NdJson => {
                 let compression = ast.take_compression()?;
                 let missing_field_as = ast.options.remove(MISSING_FIELD_AS);
@@ -271,6 +319,20 @@ impl FileFormatParams {
             }
             FileFormatParams::Xml(p) => {
                 check_str_len(&p.row_tag, 1, 1014, "XML", "row_tag")?;
+                if !p.row_path.is_empty() {
+                    check_xml_path(&p.row_path, true)?;
+                }
+                for path in p.column_paths.values() {
+                    check_xml_path(path, false)?;
+                }
+            }
+            FileFormatParams::Protobuf(p) => {
+                if p.descriptor_set.is_empty() {
+                    return Err(ErrorCode::InvalidArgument(
+                        "option descriptor_set for PROTOBUF is required",
+                    ));
+                }
+                check_str_len(&p.message_name, 1, 1024, "PROTOBUF", "message_name")?;
             }
             _ => {}
         }
@@ -368,6 +430,13 @@ impl TsvFileFormatParams {
 pub struct XmlFileFormatParams {
     pub compression: StageFileCompression,
     pub row_tag: String,
+    /// The path of the row elements from the root, such as `/feed/items/item`,
+    /// or `//item` for the elements at any depth. Takes precedence over `row_tag` if not empty.
+    pub row_path: String,
+    /// Maps the columns to the paths relative to the row elements, such as `author/name`
+    /// or `@id` for an attribute. The columns not in the map are read from the children
+    /// or attributes of the same name.
+    pub column_paths: BTreeMap<String, String>,
 }
 
 impl XmlFileFormatParams {
@@ -377,6 +446,29 @@ impl XmlFileFormatParams {
             _ => unreachable!(),
         }
     }
+
+    /// Parses the `COLUMN_PATHS` option, such as `'id = @id, author = author/name'`.
+    pub fn parse_column_paths(option: &str) -> Result<BTreeMap<String, String>> {
+        let mut column_paths = BTreeMap::new();
+        for item in option.split(',').filter(|item| !item.trim().is_empty()) {
+            let (column, path) = item.split_once('=').ok_or_else(|| {
+                ErrorCode::InvalidArgument(format!(
+                    "invalid column_paths item '{}', expect '<column> = <path>'",
+                    item.trim()
+                ))
+            })?;
+            column_paths.insert(column.trim().to_string(), path.trim().to_string());
+        }
+        Ok(column_paths)
+    }
+
+    fn column_paths_to_string(&self) -> String {
+        self.column_paths
+            .iter()
+            .map(|(column, path)| format!("{column} = {path}"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
 }
 
 impl Default for XmlFileFormatParams {
@@ -384,6 +476,8 @@ impl Default for XmlFileFormatParams {
         XmlFileFormatParams {
             compression: StageFileCompression::None,
             row_tag: "row".to_string(),
+            row_path: "".to_string(),
+            column_paths: BTreeMap::new(),
         }
     }
 }
@@ -511,6 +605,132 @@ impl NdJsonFileFormatParams {
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ParquetFileFormatParams {}
 
+/// Length-delimited protobuf messages, decoded with the descriptors of the messages.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProtobufFileFormatParams {
+    pub compression: StageFileCompression,
+    /// The base64 encoded `FileDescriptorSet`, such as the output of `protoc --descriptor_set_out`.
+    pub descriptor_set: String,
+    /// The full name of the message of the rows, such as `package.Message`.
+    pub message_name: String,
+}
+
+impl ProtobufFileFormatParams {
+    pub fn downcast_unchecked(params: &FileFormatParams) -> &ProtobufFileFormatParams {
+        match params {
+            FileFormatParams::Protobuf(p) => p,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl Default for ProtobufFileFormatParams {
+    fn default() -> Self {
+        ProtobufFileFormatParams {
+            compression: StageFileCompression::None,
+            descriptor_set: "".to_string(),
+            message_name: "".to_string(),
+        }
+    }
+}
+
+/// Excel workbooks, of which one sheet is loaded.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct XlsxFileFormatParams {
+    /// The name of the sheet to load, the first sheet if empty.
+    pub sheet_name: String,
+    /// The number of header rows to skip, the last one names the columns.
+    pub headers: u64,
+}
+
+impl XlsxFileFormatParams {
+    pub fn downcast_unchecked(params: &FileFormatParams) -> &XlsxFileFormatParams {
+        match params {
+            FileFormatParams::Xlsx(p) => p,
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// Overrides of the file format of one COPY, for some of the files or columns.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileFormatOverrides {
+    /// The file format of the files matching each pattern, the first match wins.
+    pub files: Vec<(String, FileFormatParams)>,
+    /// The format options of columns, by column name.
+    pub columns: BTreeMap<String, ColumnFormatParams>,
+}
+
+impl FileFormatOverrides {
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty() && self.columns.is_empty()
+    }
+}
+
+/// How binary values are encoded as text.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BinaryFormat {
+    Hex,
+    Base64,
+}
+
+impl FromStr for BinaryFormat {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "hex" => Ok(BinaryFormat::Hex),
+            "base64" => Ok(BinaryFormat::Base64),
+            _ => Err(ErrorCode::InvalidArgument(format!(
+                "binary_format must be 'hex' or 'base64', got '{s}'"
+            ))),
+        }
+    }
+}
+
+impl Display for BinaryFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BinaryFormat::Hex => write!(f, "hex"),
+            BinaryFormat::Base64 => write!(f, "base64"),
+        }
+    }
+}
+
+/// The format options of one column, taking precedence over the ones of the file format.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ColumnFormatParams {
+    /// The strftime-like format of Timestamp values, such as `%d/%m/%Y %H:%M`.
+    pub timestamp_format: Option<String>,
+    /// The strftime-like format of Date values, such as `%d/%m/%Y`.
+    pub date_format: Option<String>,
+    /// How the values of String columns are encoded.
+    pub binary_format: Option<BinaryFormat>,
+    /// The text of NULL, instead of the one of the file format.
+    pub null_display: Option<String>,
+}
+
+impl ColumnFormatParams {
+    pub fn try_create(mut options: BTreeMap<String, String>) -> Result<Self> {
+        let params = ColumnFormatParams {
+            timestamp_format: options.remove(OPT_TIMESTAMP_FORMAT),
+            date_format: options.remove(OPT_DATE_FORMAT),
+            binary_format: options
+                .remove(OPT_BINARY_FORMAT)
+                .map(|v| BinaryFormat::from_str(&v))
+                .transpose()?,
+            null_display: options.remove(OPT_NULL_DISPLAY),
+        };
+        if !options.is_empty() {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "Unsupported column format options: {}",
+                options.keys().cloned().collect::<Vec<_>>().join(", ")
+            )));
+        }
+        Ok(params)
+    }
+}
+
 impl Display for FileFormatParams {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -545,7 +765,14 @@ impl Display for FileFormatParams {
                     f,
                     "TYPE = XML, COMPRESSION = {:?}, ROW_TAG = '{}'",
                     params.compression, params.row_tag
-                )
+                )?;
+                if !params.row_path.is_empty() {
+                    write!(f, ", ROW_PATH = '{}'", params.row_path)?;
+                }
+                if !params.column_paths.is_empty() {
+                    write!(f, ", COLUMN_PATHS = '{}'", params.column_paths_to_string())?;
+                }
+                Ok(())
             }
             FileFormatParams::Json(params) => {
                 write!(f, "TYPE = JSON, COMPRESSION = {:?}", params.compression)
@@ -556,6 +783,21 @@ impl Display for FileFormatParams {
             FileFormatParams::Parquet(_) => {
                 write!(f, "TYPE = PARQUET")
             }
+            FileFormatParams::Protobuf(params) => {
+                write!(
+                    f,
+                    "TYPE = PROTOBUF, COMPRESSION = {:?}, MESSAGE_NAME = '{}'",
+                    params.compression, params.message_name
+                )
+            }
+            FileFormatParams::Xlsx(params) => {
+                write!(
+                    f,
+                    "TYPE = XLSX, SHEET_NAME = '{}', SKIP_HEADER = {}",
+                    escape_string(&params.sheet_name),
+                    params.headers
+                )
+            }
         }
     }
 }
@@ -577,6 +819,37 @@ pub fn check_str_len(
     }
 }
 
+/// Checks the XPath-like paths of XML, made of element names separated by `/`,
+/// where an attribute `@name` may only be the last step, and `*` matches any element.
+///
+/// Only row paths are absolute, starting with `/`, or `//` to match at any depth.
+pub fn check_xml_path(path: &str, is_row_path: bool) -> Result<()> {
+    let steps = if is_row_path {
+        match path.strip_prefix("//").or_else(|| path.strip_prefix('/')) {
+            Some(steps) => steps,
+            None => {
+                return Err(ErrorCode::InvalidArgument(format!(
+                    "row_path for XML must start with '/', got '{path}'"
+                )));
+            }
+        }
+    } else {
+        path
+    };
+    let steps: Vec<&str> = steps.split('/').collect();
+    for (i, step) in steps.iter().enumerate() {
+        let is_attribute = step.starts_with('@');
+        if step.is_empty()
+            || (is_attribute && (is_row_path || i != steps.len() - 1 || step.len() == 1))
+        {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "invalid path '{path}' for XML"
+            )));
+        }
+    }
+    Ok(())
+}
+
 /// `\r\n` or u8
 pub fn check_record_delimiter(option: &str) -> Result<()> {
     match option.len() {
@@ -20,9 +20,12 @@ mod network_policy;
 mod ownership_info;
 mod principal_identity;
 mod role_info;
+mod secret;
+mod subject_key;
 mod user_auth;
 mod user_defined_file_format;
 mod user_defined_function;
+mod user_defined_sequence;
 mod user_grant;
 mod user_identity;
 mod user_info;
@@ -38,6 +41,8 @@ pub use ownership_info::OwnershipInfo;
 pub use principal_identity::PrincipalIdentity;
 pub use role_info::RoleInfo;
 pub use role_info::RoleInfoSerdeError;
+pub use secret::*;
+pub use subject_key::SubjectKey;
 pub use user_auth::AuthInfo;
 pub use user_auth::AuthType;
 pub use user_auth::PasswordHashMethod;
@@ -46,6 +51,8 @@ pub use user_defined_function::LambdaUDF;
 pub use user_defined_function::UDFDefinition;
 pub use user_defined_function::UDFServer;
 pub use user_defined_function::UserDefinedFunction;
+pub use user_defined_sequence::SequenceRange;
+pub use user_defined_sequence::UserDefinedSequence;
 pub use user_grant::GrantEntry;
 pub use user_grant::GrantObject;
 pub use user_grant::GrantObjectByID;
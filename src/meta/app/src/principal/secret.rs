@@ -0,0 +1,78 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use itertools::Itertools;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Credentials given as `'secret://<name>'` are resolved from the secret of the name
+/// when they are used, so only the reference is persisted in meta.
+pub const SECRET_REFERENCE_PREFIX: &str = "secret://";
+
+pub const SECRET_PROVIDER_VAULT: &str = "vault";
+pub const SECRET_PROVIDER_AWS_SECRETS_MANAGER: &str = "aws_secrets_manager";
+
+/// How long a resolved secret value is cached if `CACHE_TTL` is not given, in seconds.
+pub const DEFAULT_SECRET_CACHE_TTL_SECS: u64 = 300;
+
+/// A secret kept in an external secrets manager.
+///
+/// Only where to find the value is stored, the value is fetched from the provider
+/// every time the cached one expires, so rotating it in the provider takes effect
+/// without altering the objects that refer to it.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct UserDefinedSecret {
+    pub name: String,
+    /// `vault` or `aws_secrets_manager`.
+    pub provider: String,
+    /// Where to find the value in the provider, e.g. the path and the key of it.
+    pub provider_params: BTreeMap<String, String>,
+    pub cache_ttl_secs: u64,
+}
+
+impl UserDefinedSecret {
+    pub fn new(
+        name: &str,
+        provider: String,
+        provider_params: BTreeMap<String, String>,
+        cache_ttl_secs: u64,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            provider: provider.to_lowercase(),
+            provider_params: provider_params
+                .into_iter()
+                .map(|(k, v)| (k.to_lowercase(), v))
+                .collect::<BTreeMap<_, _>>(),
+            cache_ttl_secs,
+        }
+    }
+
+    pub fn provider_params_display(&self) -> String {
+        self.provider_params
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, v))
+            .join(" ")
+    }
+
+    /// Returns the name of the secret the given credential refers to.
+    pub fn parse_reference(value: &str) -> Option<&str> {
+        value
+            .strip_prefix(SECRET_REFERENCE_PREFIX)
+            .filter(|name| !name.is_empty())
+    }
+}
@@ -0,0 +1,41 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The data key of a subject (e.g. a customer), which encrypts the values of the
+/// crypto-shredded columns belonging to the subject.
+///
+/// The key is kept wrapped by the key encryption key of the tenant. Dropping it makes
+/// the data of the subject unreadable, without rewriting any block.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct SubjectKey {
+    pub subject: String,
+    /// The wrapped data key, or the raw key for the key encryption key of the tenant.
+    pub key: Vec<u8>,
+    pub created_on: DateTime<Utc>,
+}
+
+impl SubjectKey {
+    pub fn new(subject: &str, key: Vec<u8>) -> Self {
+        Self {
+            subject: subject.to_string(),
+            key,
+            created_on: Utc::now(),
+        }
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A sequence generates unique, increasing integer values,
+/// e.g. the values of `AUTO_INCREMENT` columns.
+///
+/// Values are handed out in ranges: a query node reserves `cache` values at a time
+/// by advancing `next_value` in the meta service, then serves them from its local cache.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct UserDefinedSequence {
+    pub name: String,
+    pub start: u64,
+    pub increment: u64,
+    /// Number of values a query node reserves from the meta service at a time.
+    pub cache: u64,
+    /// The first value that has not been reserved yet.
+    pub next_value: u64,
+    pub comment: Option<String>,
+    pub create_on: DateTime<Utc>,
+    pub update_on: Option<DateTime<Utc>>,
+}
+
+impl UserDefinedSequence {
+    pub fn new(
+        name: &str,
+        start: u64,
+        increment: u64,
+        cache: u64,
+        comment: Option<String>,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            start,
+            increment,
+            cache,
+            next_value: start,
+            comment,
+            create_on: Utc::now(),
+            update_on: None,
+        }
+    }
+
+    /// Reserves the next `count` values of this sequence.
+    ///
+    /// Returns `None` if the sequence would overflow.
+    pub fn reserve(&mut self, count: u64) -> Option<SequenceRange> {
+        let next_value = self
+            .increment
+            .checked_mul(count)
+            .and_then(|len| self.next_value.checked_add(len))?;
+        let range = SequenceRange {
+            next: self.next_value,
+            increment: self.increment,
+            remaining: count,
+        };
+        self.next_value = next_value;
+        self.update_on = Some(Utc::now());
+        Some(range)
+    }
+}
+
+/// Values reserved from a sequence: `next`, `next + increment`, ... (`remaining` values).
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SequenceRange {
+    pub next: u64,
+    pub increment: u64,
+    pub remaining: u64,
+}
+
+impl Iterator for SequenceRange {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let value = self.next;
+        self.next += self.increment;
+        self.remaining -= 1;
+        Some(value)
+    }
+}
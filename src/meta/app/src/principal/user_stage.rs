@@ -174,6 +174,8 @@ pub enum StageFileFormatType {
     Orc,
     Parquet,
     Xml,
+    Protobuf,
+    Xlsx,
     None,
 }
 
@@ -193,11 +195,13 @@ impl FromStr for StageFileFormatType {
             "PARQUET" => Ok(StageFileFormatType::Parquet),
             "XML" => Ok(StageFileFormatType::Xml),
             "JSON" => Ok(StageFileFormatType::Json),
+            "PROTOBUF" => Ok(StageFileFormatType::Protobuf),
+            "XLSX" => Ok(StageFileFormatType::Xlsx),
             "ORC" | "AVRO" => Err(format!(
-                "File format type '{s}' not implemented yet', must be one of ( CSV | TSV | NDJSON | PARQUET | XML)"
+                "File format type '{s}' not implemented yet', must be one of ( CSV | TSV | NDJSON | PARQUET | XML | PROTOBUF | XLSX)"
             )),
             _ => Err(format!(
-                "Unknown file format type '{s}', must be one of ( CSV | TSV | NDJSON | PARQUET | XML)"
+                "Unknown file format type '{s}', must be one of ( CSV | TSV | NDJSON | PARQUET | XML | PROTOBUF | XLSX)"
             )),
         }
     }
@@ -397,6 +401,12 @@ pub enum OnErrorMode {
     Continue,
     SkipFileNum(u64),
     AbortNum(u64),
+    /// Loads the values that fail to be converted as NULL, and logs the errors
+    /// into `system.load_errors`.
+    SetNull,
+    /// Skips the records that fail to be loaded, and writes them with the errors
+    /// into the quarantine table `<table>_quarantine` of the target table.
+    Quarantine,
 }
 
 impl Default for OnErrorMode {
@@ -425,6 +435,12 @@ impl Display for OnErrorMode {
                     write!(f, "abort_{}", n)
                 }
             }
+            OnErrorMode::SetNull => {
+                write!(f, "set_null")
+            }
+            OnErrorMode::Quarantine => {
+                write!(f, "quarantine")
+            }
         }
     }
 }
@@ -436,6 +452,8 @@ impl FromStr for OnErrorMode {
             "" | "ABORT" => Ok(OnErrorMode::AbortNum(1)),
             "CONTINUE" => Ok(OnErrorMode::Continue),
             "SKIP_FILE" => Ok(OnErrorMode::SkipFileNum(1)),
+            "SET_NULL" => Ok(OnErrorMode::SetNull),
+            "QUARANTINE" => Ok(OnErrorMode::Quarantine),
             v => {
                 if v.starts_with("ABORT_") {
                     let num_str = v.replace("ABORT_", "");
@@ -446,7 +464,7 @@ impl FromStr for OnErrorMode {
                         }
                         Ok(n) => Ok(OnErrorMode::AbortNum(n)),
                         Err(_) => Err(format!(
-                            "Unknown OnError mode:{:?}, must one of {{ CONTINUE | SKIP_FILE | SKIP_FILE_<num> | ABORT | ABORT_<num> }}",
+                            "Unknown OnError mode:{:?}, must one of {{ CONTINUE | SKIP_FILE | SKIP_FILE_<num> | ABORT | ABORT_<num> | SET_NULL | QUARANTINE }}",
                             v
                         )),
                     }
@@ -460,7 +478,7 @@ impl FromStr for OnErrorMode {
                         }
                         Ok(n) => Ok(OnErrorMode::SkipFileNum(n)),
                         Err(_) => Err(format!(
-                            "Unknown OnError mode:{:?}, must one of {{ CONTINUE | SKIP_FILE | SKIP_FILE_<num> | ABORT | ABORT_<num> }}",
+                            "Unknown OnError mode:{:?}, must one of {{ CONTINUE | SKIP_FILE | SKIP_FILE_<num> | ABORT | ABORT_<num> | SET_NULL | QUARANTINE }}",
                             v
                         )),
                     }
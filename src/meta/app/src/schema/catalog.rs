@@ -58,6 +58,15 @@ impl CatalogOption {
             CatalogOption::Iceberg(_) => CatalogType::Iceberg,
         }
     }
+
+    /// The storage params of the catalog, whose credentials could be references to secrets.
+    pub fn storage_params_mut(&mut self) -> Option<&mut StorageParams> {
+        match self {
+            CatalogOption::Default => None,
+            CatalogOption::Hive(v) => v.storage_params.as_deref_mut(),
+            CatalogOption::Iceberg(v) => Some(v.storage_params.as_mut()),
+        }
+    }
 }
 
 /// Option for creating a iceberg catalog
@@ -21,6 +21,7 @@ use std::ops::Deref;
 
 use chrono::DateTime;
 use chrono::Utc;
+use common_meta_types::MetaId;
 
 use crate::schema::Ownership;
 use crate::share::ShareNameIdent;
@@ -239,6 +240,10 @@ pub struct RenameDatabaseReply {}
 pub struct DropDatabaseReq {
     pub if_exists: bool,
     pub name_ident: DatabaseNameIdent,
+    /// Tables of other databases that depend on this database, e.g. streams and views
+    /// over its tables, to be dropped in the same transaction.
+    #[serde(default)]
+    pub dependent_table_ids: Vec<MetaId>,
 }
 
 impl Display for DropDatabaseReq {
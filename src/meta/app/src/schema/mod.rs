@@ -107,6 +107,7 @@ pub use table::UpsertTableCopiedFileReply;
 pub use table::UpsertTableCopiedFileReq;
 pub use table::UpsertTableOptionReply;
 pub use table::UpsertTableOptionReq;
+pub use table::OPT_KEY_DATA_RETENTION_TIME_IN_DAYS;
 pub use virtual_column::CreateVirtualColumnReply;
 pub use virtual_column::CreateVirtualColumnReq;
 pub use virtual_column::DropVirtualColumnReply;
@@ -210,6 +210,10 @@ pub struct TableStatistics {
     pub number_of_blocks: Option<u64>,
 }
 
+/// Table option of the number of days a dropped table is retained before it can be
+/// garbage collected. A table dropped for longer than it can't be undropped.
+pub const OPT_KEY_DATA_RETENTION_TIME_IN_DAYS: &str = "data_retention_time_in_days";
+
 /// The essential state that defines what a table is.
 ///
 /// It is what a meta store just needs to save.
@@ -100,6 +100,26 @@ impl StorageParams {
         self
     }
 
+    /// The credentials of this storage params, which could be references to secrets.
+    pub fn credentials_mut(&mut self) -> Vec<&mut String> {
+        match self {
+            StorageParams::Azblob(v) => vec![&mut v.account_name, &mut v.account_key],
+            StorageParams::Ftp(v) => vec![&mut v.username, &mut v.password],
+            StorageParams::Gcs(v) => vec![&mut v.credential],
+            StorageParams::Obs(v) => vec![&mut v.access_key_id, &mut v.secret_access_key],
+            StorageParams::Oss(v) => vec![&mut v.access_key_id, &mut v.access_key_secret],
+            StorageParams::S3(v) => vec![
+                &mut v.access_key_id,
+                &mut v.secret_access_key,
+                &mut v.security_token,
+                &mut v.master_key,
+            ],
+            StorageParams::Webhdfs(v) => vec![&mut v.delegation],
+            StorageParams::Cos(v) => vec![&mut v.secret_id, &mut v.secret_key],
+            _ => vec![],
+        }
+    }
+
     pub fn is_fs(&self) -> bool {
         matches!(self, StorageParams::Fs(_))
     }
@@ -298,6 +318,9 @@ pub struct StorageGcsConfig {
     pub bucket: String,
     pub root: String,
     pub credential: String,
+    /// The service account to fetch tokens for from the metadata server, e.g. the
+    /// one bound by GKE workload identity. Tokens are refreshed before they expire.
+    pub service_account: String,
 }
 
 impl Default for StorageGcsConfig {
@@ -307,6 +330,7 @@ impl Default for StorageGcsConfig {
             bucket: String::new(),
             root: String::new(),
             credential: String::new(),
+            service_account: String::new(),
         }
     }
 }
@@ -318,6 +342,7 @@ impl Debug for StorageGcsConfig {
             .field("bucket", &self.bucket)
             .field("root", &self.root)
             .field("credential", &mask_string(&self.credential, 3))
+            .field("service_account", &self.service_account)
             .finish()
     }
 }
@@ -15,3 +15,4 @@
 mod quota;
 
 pub use quota::TenantQuota;
+pub use quota::TenantQuotaIdent;
@@ -31,6 +31,54 @@ pub struct TenantQuota {
 
     // The max number of users can be created in the tenant.
     pub max_users: u32,
+
+    // The max tables can be created in the tenant, enforced by the meta service.
+    pub max_tables: u32,
+
+    // The max bytes of table data can be stored in the tenant.
+    pub max_storage_bytes: u64,
+
+    // The max queries can run at the same time on a query node of the tenant.
+    pub max_concurrent_queries: u32,
+}
+
+/// The key of the quota of a tenant, the value is the json of [`TenantQuota`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct TenantQuotaIdent {
+    pub tenant: String,
+}
+
+impl TenantQuotaIdent {
+    pub fn new(tenant: impl Into<String>) -> Self {
+        Self {
+            tenant: tenant.into(),
+        }
+    }
+}
+
+mod kvapi_key_impl {
+    use common_meta_kvapi::kvapi;
+
+    use super::TenantQuotaIdent;
+
+    /// __fd_quotas/<tenant>
+    impl kvapi::Key for TenantQuotaIdent {
+        const PREFIX: &'static str = "__fd_quotas";
+
+        fn to_string_key(&self) -> String {
+            kvapi::KeyBuilder::new_prefixed(Self::PREFIX)
+                .push_str(&self.tenant)
+                .done()
+        }
+
+        fn from_str_key(s: &str) -> Result<Self, kvapi::KeyError> {
+            let mut p = kvapi::KeyParser::new_prefixed(s, Self::PREFIX)?;
+            let tenant = p.next_str()?;
+            p.done()?;
+
+            Ok(TenantQuotaIdent { tenant })
+        }
+    }
 }
 
 impl TryFrom<Vec<u8>> for TenantQuota {
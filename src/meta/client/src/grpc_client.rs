@@ -15,6 +15,7 @@
 use std::fmt::Debug;
 use std::fmt::Display;
 use std::fmt::Formatter;
+use std::future::Future;
 use std::sync::atomic::AtomicU64;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
@@ -22,6 +23,7 @@ use std::time::Duration;
 use std::time::Instant;
 
 use common_arrow::arrow_format::flight::data::BasicAuth;
+use common_base::base::tokio;
 use common_base::base::tokio::select;
 use common_base::base::tokio::sync::mpsc;
 use common_base::base::tokio::sync::mpsc::Receiver;
@@ -103,6 +105,29 @@ use crate::MIN_METASRV_SEMVER;
 
 const RPC_RETRIES: usize = 2;
 const AUTH_TOKEN_KEY: &str = "auth-token-bin";
+const FOLLOWER_READ_KEY: &str = "follower-read-max-staleness-ms";
+
+tokio::task_local! {
+    /// Present if the kv reads sent by the current task may be served by a meta-service follower.
+    static FOLLOWER_READ: ();
+}
+
+/// Run `fut` with the kv reads it sends allowed to be served by a meta-service follower,
+/// if the client is configured with `follower_read_max_staleness`.
+///
+/// Only read-only paths, such as resolving the tables a query reads, should run in it.
+/// DDL must not: it reads the metadata and then updates it with a transaction
+/// that requires the data read to be up to date, which only the leader guarantees.
+///
+/// Tasks spawned by `fut` do not inherit it and read from the leader.
+pub async fn with_follower_read<F: Future>(fut: F) -> F::Output {
+    FOLLOWER_READ.scope((), fut).await
+}
+
+/// Whether the current task is running inside [`with_follower_read`].
+pub(crate) fn follower_read_enabled() -> bool {
+    FOLLOWER_READ.try_with(|_| ()).is_ok()
+}
 
 pub(crate) type RealClient = MetaServiceClient<InterceptedService<Channel, AuthInterceptor>>;
 
@@ -179,12 +204,15 @@ impl ClientHandle {
     {
         static META_REQUEST_ID: AtomicU64 = AtomicU64::new(1);
 
+        let follower_read = follower_read_enabled();
+
         let request_future = async move {
             let (tx, rx) = oneshot::channel();
             let req = message::ClientWorkerRequest {
                 request_id: META_REQUEST_ID.fetch_add(1, Ordering::Relaxed),
                 resp_tx: tx,
                 req: req.into(),
+                follower_read,
                 span: Span::enter_with_local_parent(std::any::type_name::<
                     message::ClientWorkerRequest,
                 >()),
@@ -282,6 +310,11 @@ pub struct MetaGrpcClient {
     unhealthy_endpoints: Mutex<TtlHashMap<String, ()>>,
     auto_sync_interval: Option<Duration>,
 
+    /// If set, kv reads sent inside [`with_follower_read`] may be served by a meta-service follower
+    /// whose data is at most this stale.
+    /// All other reads, writes and transactions are always handled by the leader.
+    follower_read_max_staleness: Option<Duration>,
+
     /// Dedicated runtime to support meta client background tasks.
     ///
     /// In order not to let a blocking operation(such as calling the new PipelinePullingExecutor) in a tokio runtime block meta-client background tasks.
@@ -299,6 +332,10 @@ impl Debug for MetaGrpcClient {
         de.field("current_endpoints", &self.current_endpoint);
         de.field("unhealthy_endpoints", &self.unhealthy_endpoints);
         de.field("auto_sync_interval", &self.auto_sync_interval);
+        de.field(
+            "follower_read_max_staleness",
+            &self.follower_read_max_staleness,
+        );
         de.finish()
     }
 }
@@ -322,6 +359,7 @@ impl MetaGrpcClient {
             conf.timeout,
             conf.auto_sync_interval,
             conf.unhealthy_endpoint_evict_time,
+            conf.follower_read_max_staleness,
             conf.tls_conf.clone(),
         )
     }
@@ -334,6 +372,7 @@ impl MetaGrpcClient {
         timeout: Option<Duration>,
         auto_sync_interval: Option<Duration>,
         unhealthy_endpoint_evict_time: Duration,
+        follower_read_max_staleness: Option<Duration>,
         conf: Option<RpcClientTlsConfig>,
     ) -> Result<Arc<ClientHandle>, MetaClientError> {
         Self::endpoints_non_empty(&endpoints)?;
@@ -364,6 +403,7 @@ impl MetaGrpcClient {
             current_endpoint: Arc::new(Mutex::new(None)),
             unhealthy_endpoints: Mutex::new(TtlHashMap::new(unhealthy_endpoint_evict_time)),
             auto_sync_interval,
+            follower_read_max_staleness,
             username: username.to_string(),
             password: password.to_string(),
             rt: rt.clone(),
@@ -408,6 +448,7 @@ impl MetaGrpcClient {
 
             let request_id = req.request_id;
             let resp_tx = req.resp_tx;
+            let follower_read = req.follower_read;
             let req = req.req;
             let req_name = req.name();
             let req_str = format!("{:?}", req);
@@ -440,7 +481,7 @@ impl MetaGrpcClient {
                     }
                     message::Request::StreamGet(r) => {
                         let strm = self
-                            .kv_read_v1(MetaGrpcReadReq::GetKV(r.into_inner()))
+                            .kv_read_v1(MetaGrpcReadReq::GetKV(r.into_inner()), follower_read)
                             .timed_ge(threshold(), info_spent("MetaGrpcClient::kv_read_v1(GetKV)"))
                             .await;
                         message::Response::StreamGet(strm)
@@ -454,7 +495,7 @@ impl MetaGrpcClient {
                     }
                     message::Request::StreamMGet(r) => {
                         let strm = self
-                            .kv_read_v1(MetaGrpcReadReq::MGetKV(r.into_inner()))
+                            .kv_read_v1(MetaGrpcReadReq::MGetKV(r.into_inner()), follower_read)
                             .timed_ge(
                                 threshold(),
                                 info_spent("MetaGrpcClient::kv_read_v1(MGetKV)"),
@@ -471,7 +512,7 @@ impl MetaGrpcClient {
                     }
                     message::Request::StreamList(r) => {
                         let strm = self
-                            .kv_read_v1(MetaGrpcReadReq::ListKV(r.into_inner()))
+                            .kv_read_v1(MetaGrpcReadReq::ListKV(r.into_inner()), follower_read)
                             .timed_ge(
                                 threshold(),
                                 info_spent("MetaGrpcClient::kv_read_v1(ListKV)"),
@@ -1031,6 +1072,7 @@ impl MetaGrpcClient {
     pub(crate) async fn kv_read_v1(
         &self,
         grpc_req: MetaGrpcReadReq,
+        follower_read: bool,
     ) -> Result<BoxStream<pb::StreamItem>, MetaError> {
         debug!(
             req = as_debug!(&grpc_req);
@@ -1093,7 +1135,16 @@ impl MetaGrpcClient {
             }
 
             let raft_req: RaftRequest = grpc_req.clone().into();
-            let req = traced_req(raft_req.clone());
+            let mut req = traced_req(raft_req.clone());
+
+            // A server that does not support follower read ignores it and reads from the leader.
+            if let Some(max_staleness) = self.follower_read_max_staleness.filter(|_| follower_read)
+            {
+                let v = max_staleness.as_millis().to_string();
+                // safe unwrap(): a decimal string is a valid ascii metadata value.
+                req.metadata_mut()
+                    .insert(FOLLOWER_READ_KEY, v.parse().unwrap());
+            }
 
             let result = client
                 .kv_read_v1(req)
@@ -22,11 +22,13 @@ use common_meta_kvapi::kvapi::MGetKVReq;
 use common_meta_kvapi::kvapi::UpsertKVReply;
 use common_meta_kvapi::kvapi::UpsertKVReq;
 use common_meta_types::MetaError;
+use common_meta_types::SeqV;
 use common_meta_types::TxnReply;
 use common_meta_types::TxnRequest;
 use futures::StreamExt;
 use futures::TryStreamExt;
 
+use crate::grpc_client::follower_read_enabled;
 use crate::ClientHandle;
 use crate::Streamed;
 
@@ -42,6 +44,17 @@ impl kvapi::KVApi for ClientHandle {
 
     #[minitrace::trace]
     async fn get_kv(&self, key: &str) -> Result<GetKVReply, Self::Error> {
+        // Only the stream API can be served by a follower.
+        if follower_read_enabled() {
+            let mut strm = self
+                .request(Streamed(GetKVReq {
+                    key: key.to_string(),
+                }))
+                .await?;
+            let item = strm.try_next().await?;
+            return Ok(item.and_then(|x| x.value).map(SeqV::from));
+        }
+
         let reply = self
             .request(GetKVReq {
                 key: key.to_string(),
@@ -53,6 +66,13 @@ impl kvapi::KVApi for ClientHandle {
     #[minitrace::trace]
     async fn mget_kv(&self, keys: &[String]) -> Result<MGetKVReply, Self::Error> {
         let keys = keys.to_vec();
+
+        if follower_read_enabled() {
+            let strm = self.request(Streamed(MGetKVReq { keys })).await?;
+            let items: Vec<_> = strm.try_collect().await?;
+            return Ok(items.into_iter().map(|x| x.value.map(SeqV::from)).collect());
+        }
+
         let reply = self.request(MGetKVReq { keys }).await?;
         Ok(reply)
     }
@@ -25,6 +25,7 @@ pub use common_meta_api::reply::reply_to_meta_result;
 pub use grpc_action::MetaGrpcReadReq;
 pub use grpc_action::MetaGrpcReq;
 pub use grpc_action::RequestFor;
+pub use grpc_client::with_follower_read;
 pub use grpc_client::ClientHandle;
 pub use grpc_client::MetaGrpcClient;
 pub use message::ClientWorkerRequest;
@@ -49,6 +49,11 @@ pub struct ClientWorkerRequest {
     /// Request body
     pub(crate) req: Request,
 
+    /// Whether a kv read of this request may be served by a meta-service follower.
+    ///
+    /// It is set if the request is sent inside [`with_follower_read`](crate::with_follower_read).
+    pub(crate) follower_read: bool,
+
     /// Tracing span for this request
     pub(crate) span: Span,
 }
@@ -58,6 +63,7 @@ impl fmt::Debug for ClientWorkerRequest {
         f.debug_struct("ClientWorkerRequest")
             .field("request_id", &self.request_id)
             .field("req", &self.req)
+            .field("follower_read", &self.follower_read)
             .finish()
     }
 }
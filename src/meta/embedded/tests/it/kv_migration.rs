@@ -0,0 +1,89 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_meta_api::kv_migration::KVMigration;
+use common_meta_api::kv_migration::KVMigrator;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_kvapi::kvapi::KVApi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::MetaError;
+
+/// Rewrite `v1:<x>` into `v2:<x>`.
+struct V1ToV2;
+
+impl KVMigration for V1ToV2 {
+    fn name(&self) -> &str {
+        "v1_to_v2"
+    }
+
+    fn prefix(&self) -> &str {
+        "__fd_test/"
+    }
+
+    fn migrate_value(&self, _key: &str, value: &[u8]) -> Result<Option<Vec<u8>>, MetaError> {
+        match value.strip_prefix(b"v1:") {
+            Some(x) => Ok(Some([b"v2:", x].concat())),
+            None => Ok(None),
+        }
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_kv_migration() -> anyhow::Result<()> {
+    let kv = MetaEmbedded::new_temp().await?;
+
+    for i in 0..5 {
+        kv.upsert_kv(UpsertKVReq::update(format!("__fd_test/{}", i), b"v1:a"))
+            .await?;
+    }
+    kv.upsert_kv(UpsertKVReq::update("__fd_test/5", b"v2:b"))
+        .await?;
+    kv.upsert_kv(UpsertKVReq::update("__fd_other/0", b"v1:c"))
+        .await?;
+
+    let migrator = KVMigrator::new(&kv).with_batch_size(2);
+
+    // Stop after the first batch, the progress is persisted.
+    let progress = migrator.run_batch(&V1ToV2).await?;
+    assert_eq!(2, progress.scanned);
+    assert_eq!(2, progress.migrated);
+    assert_eq!(Some("__fd_test/1".to_string()), progress.last_key);
+    assert!(!progress.finished);
+
+    let (_, loaded) = migrator.progress("v1_to_v2").await?;
+    assert_eq!(progress, loaded);
+
+    // Resume to the end.
+    let progress = migrator.run(&V1ToV2).await?;
+    assert_eq!(6, progress.scanned);
+    assert_eq!(5, progress.migrated);
+    assert_eq!(0, progress.skipped);
+    assert!(progress.finished);
+
+    for i in 0..5 {
+        let got = kv.get_kv(&format!("__fd_test/{}", i)).await?.unwrap();
+        assert_eq!(b"v2:a".to_vec(), got.data);
+    }
+    let got = kv.get_kv("__fd_test/5").await?.unwrap();
+    assert_eq!(b"v2:b".to_vec(), got.data);
+    let got = kv.get_kv("__fd_other/0").await?.unwrap();
+    assert_eq!(b"v1:c".to_vec(), got.data, "out of prefix");
+
+    // A finished migration is a no-op.
+    let again = migrator.run(&V1ToV2).await?;
+    assert_eq!(progress, again);
+
+    Ok(())
+}
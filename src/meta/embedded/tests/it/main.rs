@@ -15,4 +15,5 @@
 #![allow(clippy::diverging_sub_expression)]
 
 mod kv_api_impl;
+mod kv_migration;
 mod schema_api_impl;
@@ -165,6 +165,7 @@ impl FromToProto for StorageGcsConfig {
             endpoint_url: p.endpoint_url,
             bucket: p.bucket,
             root: p.root,
+            service_account: p.service_account,
         })
     }
 
@@ -176,6 +177,7 @@ impl FromToProto for StorageGcsConfig {
             endpoint_url: self.endpoint_url.clone(),
             bucket: self.bucket.clone(),
             root: self.root.clone(),
+            service_account: self.service_account.clone(),
         })
     }
 }
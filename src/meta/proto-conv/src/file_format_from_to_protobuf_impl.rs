@@ -41,6 +41,8 @@ impl FromToProto for mt::principal::StageFileFormatType {
             pb::StageFileFormatType::Orc => Ok(mt::principal::StageFileFormatType::Orc),
             pb::StageFileFormatType::Parquet => Ok(mt::principal::StageFileFormatType::Parquet),
             pb::StageFileFormatType::Xml => Ok(mt::principal::StageFileFormatType::Xml),
+            pb::StageFileFormatType::Protobuf => Ok(mt::principal::StageFileFormatType::Protobuf),
+            pb::StageFileFormatType::Xlsx => Ok(mt::principal::StageFileFormatType::Xlsx),
         }
     }
 
@@ -54,6 +56,8 @@ impl FromToProto for mt::principal::StageFileFormatType {
             mt::principal::StageFileFormatType::Orc => Ok(pb::StageFileFormatType::Orc),
             mt::principal::StageFileFormatType::Parquet => Ok(pb::StageFileFormatType::Parquet),
             mt::principal::StageFileFormatType::Xml => Ok(pb::StageFileFormatType::Xml),
+            mt::principal::StageFileFormatType::Protobuf => Ok(pb::StageFileFormatType::Protobuf),
+            mt::principal::StageFileFormatType::Xlsx => Ok(pb::StageFileFormatType::Xlsx),
             mt::principal::StageFileFormatType::None => Err(Incompatible {
                 reason: "StageFileFormatType::None cannot be converted to protobuf".to_string(),
             }),
@@ -243,6 +247,16 @@ impl FromToProto for mt::principal::FileFormatParams {
                     mt::principal::XmlFileFormatParams::from_pb(p)?,
                 ))
             }
+            Some(pb::file_format_params::Format::Protobuf(p)) => {
+                Ok(mt::principal::FileFormatParams::Protobuf(
+                    mt::principal::ProtobufFileFormatParams::from_pb(p)?,
+                ))
+            }
+            Some(pb::file_format_params::Format::Xlsx(p)) => {
+                Ok(mt::principal::FileFormatParams::Xlsx(
+                    mt::principal::XlsxFileFormatParams::from_pb(p)?,
+                ))
+            }
             None => Err(Incompatible {
                 reason: "FileFormatParams.format cannot be None".to_string(),
             }),
@@ -281,6 +295,16 @@ impl FromToProto for mt::principal::FileFormatParams {
                     mt::principal::XmlFileFormatParams::to_pb(p)?,
                 )),
             }),
+            Self::Protobuf(p) => Ok(Self::PB {
+                format: Some(pb::file_format_params::Format::Protobuf(
+                    mt::principal::ProtobufFileFormatParams::to_pb(p)?,
+                )),
+            }),
+            Self::Xlsx(p) => Ok(Self::PB {
+                format: Some(pb::file_format_params::Format::Xlsx(
+                    mt::principal::XlsxFileFormatParams::to_pb(p)?,
+                )),
+            }),
         }
     }
 }
@@ -386,6 +410,8 @@ impl FromToProto for mt::principal::XmlFileFormatParams {
         Ok(Self {
             compression,
             row_tag: p.row_tag,
+            row_path: p.row_path,
+            column_paths: p.column_paths,
         })
     }
 
@@ -396,6 +422,66 @@ impl FromToProto for mt::principal::XmlFileFormatParams {
             min_reader_ver: MIN_READER_VER,
             compression,
             row_tag: self.row_tag.clone(),
+            row_path: self.row_path.clone(),
+            column_paths: self.column_paths.clone(),
+        })
+    }
+}
+
+impl FromToProto for mt::principal::ProtobufFileFormatParams {
+    type PB = pb::ProtobufFileFormatParams;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+
+    fn from_pb(p: Self::PB) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+        let compression = mt::principal::StageFileCompression::from_pb(
+            FromPrimitive::from_i32(p.compression).ok_or_else(|| Incompatible {
+                reason: format!("invalid StageFileCompression: {}", p.compression),
+            })?,
+        )?;
+        Ok(Self {
+            compression,
+            descriptor_set: p.descriptor_set,
+            message_name: p.message_name,
+        })
+    }
+
+    fn to_pb(&self) -> Result<Self::PB, Incompatible> {
+        let compression = mt::principal::StageFileCompression::to_pb(&self.compression)? as i32;
+        Ok(Self::PB {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            compression,
+            descriptor_set: self.descriptor_set.clone(),
+            message_name: self.message_name.clone(),
+        })
+    }
+}
+
+impl FromToProto for mt::principal::XlsxFileFormatParams {
+    type PB = pb::XlsxFileFormatParams;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+
+    fn from_pb(p: Self::PB) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+        Ok(Self {
+            sheet_name: p.sheet_name,
+            headers: p.headers,
+        })
+    }
+
+    fn to_pb(&self) -> Result<Self::PB, Incompatible> {
+        Ok(Self::PB {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            sheet_name: self.sheet_name.clone(),
+            headers: self.headers,
         })
     }
 }
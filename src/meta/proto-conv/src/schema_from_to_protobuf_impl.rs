@@ -241,6 +241,7 @@ impl FromToProto for ex::TableDataType {
                         Box::into_inner(x),
                     )?)),
                     Dt24::BitmapT(_) => ex::TableDataType::Bitmap,
+                    Dt24::GeometryT(_) => ex::TableDataType::Geometry,
                     Dt24::TupleT(t) => {
                         reader_check_msg(t.ver, t.min_reader_ver)?;
 
@@ -260,6 +261,10 @@ impl FromToProto for ex::TableDataType {
                         ex::TableDataType::Decimal(ex::types::decimal::DecimalDataType::from_pb(x)?)
                     }
                     Dt24::EmptyMapT(_) => ex::TableDataType::EmptyMap,
+                    Dt24::TimestampPrecisionT(x) => {
+                        reader_check_msg(x.ver, x.min_reader_ver)?;
+                        ex::TableDataType::TimestampWithPrecision(x.precision as u8)
+                    }
                 };
                 Ok(x)
             }
@@ -286,6 +291,13 @@ impl FromToProto for ex::TableDataType {
                 new_pb_dt24(Dt24::DecimalT(x))
             }
             TableDataType::Timestamp => new_pb_dt24(Dt24::TimestampT(pb::Empty {})),
+            TableDataType::TimestampWithPrecision(precision) => {
+                new_pb_dt24(Dt24::TimestampPrecisionT(pb::TimestampPrecision {
+                    ver: VER,
+                    min_reader_ver: MIN_READER_VER,
+                    precision: *precision as u32,
+                }))
+            }
             TableDataType::Date => new_pb_dt24(Dt24::DateT(pb::Empty {})),
             TableDataType::Nullable(v) => {
                 let x = v.to_pb()?;
@@ -300,6 +312,7 @@ impl FromToProto for ex::TableDataType {
                 new_pb_dt24(Dt24::MapT(Box::new(x)))
             }
             TableDataType::Bitmap => new_pb_dt24(Dt24::BitmapT(pb::Empty {})),
+            TableDataType::Geometry => new_pb_dt24(Dt24::GeometryT(pb::Empty {})),
             TableDataType::Tuple {
                 fields_name,
                 fields_type,
@@ -350,6 +363,8 @@ impl FromToProto for ex::types::NumberDataType {
             Num::Int64Type(_) => Self::Int64,
             Num::Float32Type(_) => Self::Float32,
             Num::Float64Type(_) => Self::Float64,
+            Num::Uint128Type(_) => Self::UInt128,
+            Num::Int128Type(_) => Self::Int128,
         };
         Ok(x)
     }
@@ -366,6 +381,8 @@ impl FromToProto for ex::types::NumberDataType {
             ex::types::NumberDataType::Int64 => Num::Int64Type(pb::Empty {}),
             ex::types::NumberDataType::Float32 => Num::Float32Type(pb::Empty {}),
             ex::types::NumberDataType::Float64 => Num::Float64Type(pb::Empty {}),
+            ex::types::NumberDataType::UInt128 => Num::Uint128Type(pb::Empty {}),
+            ex::types::NumberDataType::Int128 => Num::Int128Type(pb::Empty {}),
         };
         Ok(pb::Number {
             ver: VER,
@@ -77,6 +77,12 @@ impl FromToProto for mt::principal::OnErrorMode {
             Some(pb::stage_info::on_error_mode::Mode::AbortNum(n)) => {
                 Ok(mt::principal::OnErrorMode::AbortNum(n))
             }
+            Some(pb::stage_info::on_error_mode::Mode::SetNull(_)) => {
+                Ok(mt::principal::OnErrorMode::SetNull)
+            }
+            Some(pb::stage_info::on_error_mode::Mode::Quarantine(_)) => {
+                Ok(mt::principal::OnErrorMode::Quarantine)
+            }
             None => Err(Incompatible {
                 reason: "OnErrorMode.mode cannot be None".to_string(),
             }),
@@ -94,6 +100,14 @@ impl FromToProto for mt::principal::OnErrorMode {
             mt::principal::OnErrorMode::AbortNum(n) => Ok(pb::stage_info::OnErrorMode {
                 mode: Some(pb::stage_info::on_error_mode::Mode::AbortNum(*n)),
             }),
+            mt::principal::OnErrorMode::SetNull => Ok(pb::stage_info::OnErrorMode {
+                mode: Some(pb::stage_info::on_error_mode::Mode::SetNull(pb::Empty {})),
+            }),
+            mt::principal::OnErrorMode::Quarantine => Ok(pb::stage_info::OnErrorMode {
+                mode: Some(pb::stage_info::on_error_mode::Mode::Quarantine(
+                    pb::Empty {},
+                )),
+            }),
         }
     }
 }
@@ -95,6 +95,20 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (63, "2023-10-30: Add: connection.proto"),
     (64, "2023-11-16: Add: user.proto/NDJsonFileFormatParams add field `missing_field_as` and `null_field_as`", ),
     (65, "2023-11-16: Retype: use Datetime<Utc> instead of u64 to in lvt.time", ),
+    (66, "2023-11-20: Add: sequence.proto"),
+    (67, "2023-11-22: Add: stage.proto/OnErrorMode add variant `SetNull`"),
+    (68, "2023-11-23: Add: background.proto/BackgroundTaskType add variant `ROW_TTL`"),
+    (69, "2023-11-24: Add: subject_key.proto"),
+    (70, "2023-11-27: Add: datatype.proto/DataType add variant `TimestampPrecision`"),
+    (71, "2023-11-28: Add: background.proto/BackgroundTaskType add variant `ANALYZE`"),
+    (72, "2023-11-29: Add: file_format.proto/FileFormatParams add variant `Protobuf`"),
+    (73, "2023-11-30: Add: file_format.proto/XmlFileFormatParams add field row_path and column_paths"),
+    (74, "2023-12-01: Add: file_format.proto/FileFormatParams add variant `Xlsx`"),
+    (75, "2023-12-04: Add: stage.proto/OnErrorMode add variant `Quarantine`"),
+    (76, "2023-12-05: Add: secret.proto"),
+    (77, "2023-12-06: Add: config.proto/GcsStorageConfig add `service_account`"),
+    (78, "2023-12-07: Add: datatype.proto/DataType add variant `Geometry`"),
+    (79, "2023-12-08: Add: datatype.proto/Number add variant `uint128_type` and `int128_type`"),
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
@@ -69,3 +69,17 @@ mod v062_table_lock_meta;
 mod v063_connection;
 mod v064_ndjson_format_params;
 mod v065_least_visible_time;
+mod v066_sequence;
+mod v067_copy_options_set_null;
+mod v068_background_job_row_ttl;
+mod v069_subject_key;
+mod v070_timestamp_precision;
+mod v071_background_job_analyze;
+mod v072_protobuf_format_params;
+mod v073_xml_format_row_path;
+mod v074_xlsx_format_params;
+mod v075_copy_options_quarantine;
+mod v076_secret;
+mod v077_gcs_service_account;
+mod v078_geometry;
+mod v079_int128;
@@ -232,6 +232,7 @@ pub(crate) fn test_gcs_stage_info() -> mt::principal::StageInfo {
                 bucket: "my_bucket".to_string(),
                 root: "/data/files".to_string(),
                 credential: "my_credential".to_string(),
+                service_account: "".to_string(),
             }),
         },
         is_from_uri: false,
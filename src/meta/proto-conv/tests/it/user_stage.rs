@@ -376,6 +376,7 @@ fn test_user_stage_gcs_v16() -> anyhow::Result<()> {
                 bucket: "my_bucket".to_string(),
                 root: "/data/files".to_string(),
                 credential: "my_credential".to_string(),
+                service_account: "".to_string(),
             }),
         },
         file_format_params: mt::principal::FileFormatParams::Json(
@@ -742,6 +743,7 @@ fn test_user_stage_gcs_v6() -> anyhow::Result<()> {
                 bucket: "my_bucket".to_string(),
                 root: "/data/files".to_string(),
                 credential: "my_credential".to_string(),
+                service_account: "".to_string(),
             }),
         },
         file_format_params: mt::principal::FileFormatParams::Json(
@@ -888,6 +890,7 @@ fn test_user_stage_gcs_v4() -> anyhow::Result<()> {
                 bucket: "my_bucket".to_string(),
                 root: "/data/files".to_string(),
                 credential: "my_credential".to_string(),
+                service_account: "".to_string(),
             }),
         },
         file_format_params: mt::principal::FileFormatParams::Json(
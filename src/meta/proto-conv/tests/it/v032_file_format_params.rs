@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
 use common_meta_app as mt;
 use common_meta_app::principal::CsvFileFormatParams;
 use common_meta_app::principal::JsonNullAs;
@@ -120,6 +122,8 @@ fn test_decode_v32_xml_file_format_params() -> anyhow::Result<()> {
         mt::principal::FileFormatParams::Xml(XmlFileFormatParams {
             compression: StageFileCompression::Gzip,
             row_tag: "row_tag".to_string(),
+            row_path: "".to_string(),
+            column_paths: BTreeMap::new(),
         })
     };
     common::test_load_old(func_name!(), file_format_params_v32.as_slice(), 0, want())?;
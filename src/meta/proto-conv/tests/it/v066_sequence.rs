@@ -0,0 +1,57 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use common_meta_app::principal::UserDefinedSequence;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+#[test]
+fn test_decode_v66_sequence() -> anyhow::Result<()> {
+    let user_defined_sequence_v66 = vec![
+        10, 4, 115, 101, 113, 49, 16, 1, 24, 2, 32, 10, 40, 21, 50, 12, 115, 111, 109, 101, 32, 99,
+        111, 109, 109, 101, 110, 116, 58, 23, 50, 48, 50, 51, 45, 49, 49, 45, 50, 48, 32, 49, 50,
+        58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 66, 23, 50, 48, 50, 51, 45, 49, 49, 45, 50, 48, 32,
+        49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 160, 6, 66, 168, 6, 24,
+    ];
+    let want = || UserDefinedSequence {
+        name: "seq1".to_string(),
+        start: 1,
+        increment: 2,
+        cache: 10,
+        next_value: 21,
+        comment: Some("some comment".to_string()),
+        create_on: Utc.with_ymd_and_hms(2023, 11, 20, 12, 0, 9).unwrap(),
+        update_on: Some(Utc.with_ymd_and_hms(2023, 11, 20, 12, 0, 9).unwrap()),
+    };
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(
+        func_name!(),
+        user_defined_sequence_v66.as_slice(),
+        66,
+        want(),
+    )?;
+    Ok(())
+}
@@ -0,0 +1,45 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use common_meta_app::principal::SubjectKey;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+#[test]
+fn test_decode_v69_subject_key() -> anyhow::Result<()> {
+    let subject_key_v69 = vec![
+        10, 5, 97, 108, 105, 99, 101, 18, 4, 1, 2, 3, 4, 26, 23, 50, 48, 50, 51, 45, 49, 49, 45,
+        50, 52, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 160, 6, 69, 168, 6, 24,
+    ];
+    let want = || SubjectKey {
+        subject: "alice".to_string(),
+        key: vec![1, 2, 3, 4],
+        created_on: Utc.with_ymd_and_hms(2023, 11, 24, 12, 0, 9).unwrap(),
+    };
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), subject_key_v69.as_slice(), 69, want())?;
+    Ok(())
+}
@@ -0,0 +1,76 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use common_meta_app::background::BackgroundJobParams;
+use common_meta_app::background::BackgroundJobState;
+use common_meta_app::background::BackgroundJobStatus;
+use common_meta_app::background::BackgroundJobType;
+use common_meta_app::background::BackgroundTaskType;
+use common_meta_app::background::ManualTriggerParams;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+#[test]
+fn test_decode_v71_background_job_analyze() -> anyhow::Result<()> {
+    let bytes = vec![
+        10, 78, 8, 1, 16, 100, 34, 19, 65, 109, 101, 114, 105, 99, 97, 47, 76, 111, 115, 95, 65,
+        110, 103, 101, 108, 101, 115, 42, 45, 10, 4, 49, 50, 51, 49, 18, 6, 160, 6, 71, 168, 6, 24,
+        26, 23, 49, 57, 55, 48, 45, 48, 49, 45, 48, 49, 32, 48, 48, 58, 48, 48, 58, 48, 48, 32, 85,
+        84, 67, 160, 6, 71, 168, 6, 24, 160, 6, 71, 168, 6, 24, 18, 37, 34, 4, 116, 101, 115, 116,
+        42, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85,
+        84, 67, 160, 6, 71, 168, 6, 24, 24, 3, 42, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32,
+        49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 218, 5, 23, 49, 57, 55, 48, 45, 48, 49, 45,
+        48, 49, 32, 48, 48, 58, 48, 48, 58, 48, 48, 32, 85, 84, 67, 160, 6, 71, 168, 6, 24,
+    ];
+
+    let want = || common_meta_app::background::BackgroundJobInfo {
+        job_params: Some(BackgroundJobParams {
+            job_type: BackgroundJobType::INTERVAL,
+            scheduled_job_interval: std::time::Duration::from_secs(100),
+            scheduled_job_cron: "".to_string(),
+            scheduled_job_timezone: Some(chrono_tz::America::Los_Angeles),
+            manual_trigger_params: Some(ManualTriggerParams {
+                id: "1231".to_string(),
+                trigger: Default::default(),
+                triggered_at: Default::default(),
+            }),
+        }),
+        last_updated: Some(Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap()),
+        task_type: BackgroundTaskType::ANALYZE,
+        message: "".to_string(),
+        creator: None,
+        created_at: Default::default(),
+        job_status: Some(BackgroundJobStatus {
+            job_state: BackgroundJobState::RUNNING,
+            last_task_id: Some("test".to_string()),
+            last_task_run_at: Some(Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap()),
+            next_task_scheduled_time: None,
+        }),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 71, want())
+}
@@ -0,0 +1,48 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_meta_app as mt;
+use common_meta_app::principal::ProtobufFileFormatParams;
+use common_meta_app::principal::StageFileCompression;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+#[test]
+fn test_decode_v72_protobuf_file_format_params() -> anyhow::Result<()> {
+    let file_format_params_v72 = vec![
+        58, 26, 8, 1, 18, 4, 67, 103, 65, 61, 26, 10, 116, 101, 115, 116, 46, 69, 118, 101, 110,
+        116, 160, 6, 72, 168, 6, 24,
+    ];
+
+    let want = || {
+        mt::principal::FileFormatParams::Protobuf(ProtobufFileFormatParams {
+            compression: StageFileCompression::Gzip,
+            descriptor_set: "CgA=".to_string(),
+            message_name: "test.Event".to_string(),
+        })
+    };
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), file_format_params_v72.as_slice(), 0, want())?;
+    Ok(())
+}
@@ -0,0 +1,51 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_meta_app as mt;
+use common_meta_app::principal::StageFileCompression;
+use common_meta_app::principal::XmlFileFormatParams;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+#[test]
+fn test_decode_v73_xml_file_format_params() -> anyhow::Result<()> {
+    let file_format_params_v73 = vec![
+        50, 36, 8, 1, 18, 3, 114, 111, 119, 26, 10, 47, 102, 101, 101, 100, 47, 105, 116, 101, 109,
+        34, 9, 10, 2, 105, 100, 18, 3, 64, 105, 100, 160, 6, 73, 168, 6, 24,
+    ];
+
+    let want = || {
+        mt::principal::FileFormatParams::Xml(XmlFileFormatParams {
+            compression: StageFileCompression::Gzip,
+            row_tag: "row".to_string(),
+            row_path: "/feed/item".to_string(),
+            column_paths: BTreeMap::from([("id".to_string(), "@id".to_string())]),
+        })
+    };
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), file_format_params_v73.as_slice(), 0, want())?;
+    Ok(())
+}
@@ -0,0 +1,47 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_meta_app::principal::UserDefinedSecret;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+#[test]
+fn test_decode_v76_secret() -> anyhow::Result<()> {
+    let user_defined_secret_v76 = vec![
+        10, 9, 109, 121, 95, 115, 101, 99, 114, 101, 116, 18, 5, 118, 97, 117, 108, 116, 26, 22,
+        10, 4, 112, 97, 116, 104, 18, 14, 115, 101, 99, 114, 101, 116, 47, 100, 97, 116, 97, 47,
+        115, 51, 32, 60, 160, 6, 76, 168, 6, 24,
+    ];
+    let want = || UserDefinedSecret {
+        name: "my_secret".to_string(),
+        provider: "vault".to_string(),
+        provider_params: BTreeMap::from([("path".to_string(), "secret/data/s3".to_string())]),
+        cache_ttl_secs: 60,
+    };
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), user_defined_secret_v76.as_slice(), 76, want())?;
+    Ok(())
+}
@@ -0,0 +1,49 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_meta_app::storage::StorageGcsConfig;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+#[test]
+fn test_decode_v77_gcs_service_account() -> anyhow::Result<()> {
+    let gcs_storage_config_v77 = vec![
+        10, 30, 104, 116, 116, 112, 115, 58, 47, 47, 115, 116, 111, 114, 97, 103, 101, 46, 103,
+        111, 111, 103, 108, 101, 97, 112, 105, 115, 46, 99, 111, 109, 18, 9, 109, 121, 95, 98, 117,
+        99, 107, 101, 116, 26, 11, 47, 100, 97, 116, 97, 47, 102, 105, 108, 101, 115, 42, 40, 109,
+        121, 45, 115, 97, 64, 109, 121, 45, 112, 114, 111, 106, 101, 99, 116, 46, 105, 97, 109, 46,
+        103, 115, 101, 114, 118, 105, 99, 101, 97, 99, 99, 111, 117, 110, 116, 46, 99, 111, 109,
+        160, 6, 77, 168, 6, 24,
+    ];
+    let want = || StorageGcsConfig {
+        endpoint_url: "https://storage.googleapis.com".to_string(),
+        bucket: "my_bucket".to_string(),
+        root: "/data/files".to_string(),
+        credential: "".to_string(),
+        service_account: "my-sa@my-project.iam.gserviceaccount.com".to_string(),
+    };
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), gcs_storage_config_v77.as_slice(), 77, want())?;
+    Ok(())
+}
@@ -15,6 +15,7 @@
 use std::io;
 use std::pin::Pin;
 use std::sync::Arc;
+use std::time::Duration;
 
 use common_arrow::arrow_format::flight::data::BasicAuth;
 use common_base::base::tokio::sync::mpsc;
@@ -97,6 +98,16 @@ impl MetaServiceImpl {
         Ok(claim)
     }
 
+    /// The max staleness a client accepts for a read served by a follower.
+    /// `None` if the client requires a read from the leader.
+    fn follower_read_max_staleness(metadata: &MetadataMap) -> Option<Duration> {
+        metadata
+            .get("follower-read-max-staleness-ms")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<u64>().ok())
+            .map(Duration::from_millis)
+    }
+
     #[minitrace::trace]
     async fn handle_kv_api(&self, request: Request<RaftRequest>) -> Result<RaftReply, Status> {
         let req: MetaGrpcReq = request.try_into()?;
@@ -136,9 +147,15 @@ impl MetaServiceImpl {
         &self,
         request: Request<RaftRequest>,
     ) -> Result<BoxStream<StreamItem>, Status> {
+        let max_staleness = Self::follower_read_max_staleness(request.metadata());
         let req: MetaGrpcReadReq = GrpcHelper::parse_req(request)?;
 
-        info!("{}: Received ReadRequest: {:?}", func_name!(), req);
+        info!(
+            "{}: Received ReadRequest: {:?}, follower read max staleness: {:?}",
+            func_name!(),
+            req,
+            max_staleness
+        );
 
         let req = ForwardRequest {
             forward_to_leader: 1,
@@ -147,11 +164,19 @@ impl MetaServiceImpl {
 
         let t0 = Instant::now();
 
-        let res = self
-            .meta_node
-            .handle_forwardable_request::<MetaGrpcReadReq>(req.clone())
-            .await
-            .map_err(GrpcHelper::internal_err);
+        let res = match max_staleness {
+            Some(max_staleness) => {
+                self.meta_node
+                    .handle_follower_read(req.clone(), max_staleness)
+                    .await
+            }
+            None => {
+                self.meta_node
+                    .handle_forwardable_request::<MetaGrpcReadReq>(req.clone())
+                    .await
+            }
+        };
+        let res = res.map_err(GrpcHelper::internal_err);
 
         let elapsed = t0.elapsed();
         info!("Handled(elapsed: {:?}) ReadRequest: {:?}", elapsed, req);
@@ -14,6 +14,7 @@
 
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
+use std::collections::VecDeque;
 use std::fmt::Debug;
 use std::net::Ipv4Addr;
 use std::sync::atomic::AtomicI32;
@@ -31,6 +32,7 @@ use common_base::base::tokio::time::Instant;
 use common_grpc::ConnectionFactory;
 use common_grpc::DNSResolver;
 use common_meta_client::reply_to_api_result;
+use common_meta_client::MetaGrpcReadReq;
 use common_meta_client::RequestFor;
 use common_meta_raft_store::config::RaftConfig;
 use common_meta_raft_store::ondisk::DataVersion;
@@ -42,6 +44,7 @@ use common_meta_sled_store::openraft::ChangeMembers;
 use common_meta_stoerr::MetaStorageError;
 use common_meta_types::protobuf::raft_service_client::RaftServiceClient;
 use common_meta_types::protobuf::raft_service_server::RaftServiceServer;
+use common_meta_types::protobuf::StreamItem;
 use common_meta_types::protobuf::WatchRequest;
 use common_meta_types::AppliedState;
 use common_meta_types::Cmd;
@@ -79,6 +82,7 @@ use openraft::Config;
 use openraft::Raft;
 use openraft::ServerState;
 use openraft::SnapshotPolicy;
+use tonic::codegen::BoxStream;
 
 use crate::configs::Config as MetaConfig;
 use crate::message::ForwardRequest;
@@ -175,6 +179,65 @@ pub struct MetaNode {
     pub running_rx: watch::Receiver<()>,
     pub join_handles: Mutex<Vec<JoinHandle<Result<(), AnyError>>>>,
     pub joined_tasks: AtomicI32,
+    /// The committed log indexes the leader sent to this node, and how fresh the applied state is.
+    /// It bounds how stale the local state machine may be when serving a follower read.
+    pub leader_commits: std::sync::Mutex<LeaderCommits>,
+}
+
+/// Tracks how fresh the applied state machine of a follower is, compared with the leader.
+///
+/// The leader sends its committed log index with every AppendEntries. Once this node has
+/// applied up to that index, its state machine is at least as new as the leader's committed
+/// state at the time the index was received.
+#[derive(Debug, Default)]
+pub struct LeaderCommits {
+    /// The committed indexes received from the leader but not yet applied locally,
+    /// and when they were received.
+    pending: VecDeque<(u64, Instant)>,
+    /// The time at which the leader's committed state is known to be applied locally.
+    applied_as_of: Option<Instant>,
+}
+
+impl LeaderCommits {
+    /// Keep at most this many unapplied commit indexes, older ones are dropped,
+    /// which only makes the freshness estimate more conservative.
+    const MAX_PENDING: usize = 1024;
+
+    fn record(&mut self, committed: u64, last_applied: Option<u64>) {
+        let now = Instant::now();
+        match self.pending.back_mut() {
+            // No new commit since the last AppendEntries, the leader state is unchanged.
+            Some((index, received_at)) if *index == committed => *received_at = now,
+            _ => self.pending.push_back((committed, now)),
+        }
+        if self.pending.len() > Self::MAX_PENDING {
+            self.pending.pop_front();
+        }
+        self.advance(last_applied);
+    }
+
+    fn advance(&mut self, last_applied: Option<u64>) {
+        let Some(last_applied) = last_applied else {
+            return;
+        };
+        while let Some((index, received_at)) = self.pending.front() {
+            if *index > last_applied {
+                break;
+            }
+            self.applied_as_of = Some(*received_at);
+            // Keep the last one, later AppendEntries may refresh it without new commits.
+            if self.pending.len() == 1 {
+                break;
+            }
+            self.pending.pop_front();
+        }
+    }
+
+    /// How long ago the leader's committed state, which is applied locally, was observed.
+    fn staleness(&mut self, last_applied: Option<u64>) -> Option<Duration> {
+        self.advance(last_applied);
+        self.applied_as_of.map(|t| t.elapsed())
+    }
 }
 
 impl Opened for MetaNode {
@@ -232,6 +295,7 @@ impl MetaNodeBuilder {
             running_rx: rx,
             join_handles: Mutex::new(Vec::new()),
             joined_tasks: AtomicI32::new(1),
+            leader_commits: std::sync::Mutex::new(LeaderCommits::default()),
         });
 
         if self.monitor_metrics {
@@ -1092,6 +1156,62 @@ impl MetaNode {
         }
     }
 
+    /// Handle a read request, serving it from the local state machine if `self` is a follower
+    /// that has heard from the leader within `max_staleness`.
+    ///
+    /// Otherwise the request is handled by the leader, the same as a consistent read.
+    #[minitrace::trace]
+    pub async fn handle_follower_read(
+        &self,
+        req: ForwardRequest<MetaGrpcReadReq>,
+        max_staleness: Duration,
+    ) -> Result<BoxStream<StreamItem>, MetaAPIError> {
+        if self.is_fresh_follower(max_staleness) {
+            debug!(req = as_debug!(&req); "serve follower read locally");
+
+            server_metrics::incr_follower_read();
+
+            let local = MetaLeader::new(self);
+            match local.handle(req.clone()).await {
+                Ok(strm) => return Ok(strm),
+                Err(e) => {
+                    warn!("follower read failed, fallback to leader read: {}", e);
+                }
+            }
+        }
+
+        self.handle_forwardable_request(req).await
+    }
+
+    /// Record the committed log index the leader sent with an accepted AppendEntries.
+    pub fn record_leader_commit(&self, leader_commit: Option<LogId>) {
+        let Some(leader_commit) = leader_commit else {
+            return;
+        };
+        let last_applied = self.raft.metrics().borrow().last_applied.map(|x| x.index);
+        let mut commits = self.leader_commits.lock().unwrap();
+        commits.record(leader_commit.index, last_applied);
+    }
+
+    /// Whether `self` is a follower or learner that has applied the leader's committed state
+    /// observed within `max_staleness`.
+    fn is_fresh_follower(&self, max_staleness: Duration) -> bool {
+        let (state, last_applied) = {
+            let metrics = self.raft.metrics();
+            let metrics = metrics.borrow();
+            (metrics.state, metrics.last_applied.map(|x| x.index))
+        };
+        if !matches!(state, ServerState::Follower | ServerState::Learner) {
+            return false;
+        }
+
+        let mut commits = self.leader_commits.lock().unwrap();
+        match commits.staleness(last_applied) {
+            Some(staleness) => staleness <= max_staleness,
+            None => false,
+        }
+    }
+
     /// Return a MetaLeader if `self` believes it is the leader.
     ///
     /// Otherwise it returns the leader in a ForwardToLeader error.
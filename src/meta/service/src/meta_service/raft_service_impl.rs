@@ -25,6 +25,8 @@ use common_meta_types::protobuf::RaftReply;
 use common_meta_types::protobuf::RaftRequest;
 use common_meta_types::protobuf::SnapshotChunkRequest;
 use common_meta_types::protobuf::StreamItem;
+use common_meta_types::AppendEntriesRequest;
+use common_meta_types::AppendEntriesResponse;
 use common_meta_types::InstallSnapshotRequest;
 use common_meta_types::SnapshotMeta;
 use common_meta_types::Vote;
@@ -178,7 +180,8 @@ impl RaftService for RaftServiceImpl {
         async {
             self.incr_meta_metrics_recv_bytes_from_peer(&request);
 
-            let ae_req = GrpcHelper::parse_req(request)?;
+            let ae_req: AppendEntriesRequest = GrpcHelper::parse_req(request)?;
+            let leader_commit = ae_req.leader_commit;
             let raft = &self.meta_node.raft;
 
             let resp = raft
@@ -186,6 +189,10 @@ impl RaftService for RaftServiceImpl {
                 .await
                 .map_err(GrpcHelper::internal_err)?;
 
+            if matches!(resp, AppendEntriesResponse::Success) {
+                self.meta_node.record_leader_commit(leader_commit);
+            }
+
             GrpcHelper::ok_response(resp)
         }
         .in_span(root)
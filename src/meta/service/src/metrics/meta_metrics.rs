@@ -56,6 +56,7 @@ pub mod server_metrics {
         proposals_pending: Gauge,
         proposals_failed: Counter,
         read_failed: Counter,
+        follower_read: Counter,
         watchers: Gauge,
     }
 
@@ -74,6 +75,7 @@ pub mod server_metrics {
                 proposals_pending: Gauge::default(),
                 proposals_failed: Counter::default(),
                 read_failed: Counter::default(),
+                follower_read: Counter::default(),
                 watchers: Gauge::default(),
             };
 
@@ -130,6 +132,11 @@ pub mod server_metrics {
                 "read failed",
                 metrics.read_failed.clone(),
             );
+            registry.register(
+                key!("follower_read"),
+                "read served by follower",
+                metrics.follower_read.clone(),
+            );
             registry.register(key!("watchers"), "watchers", metrics.watchers.clone());
             metrics
         }
@@ -190,6 +197,10 @@ pub mod server_metrics {
         SERVER_METRICS.read_failed.inc();
     }
 
+    pub fn incr_follower_read() {
+        SERVER_METRICS.follower_read.inc();
+    }
+
     pub fn incr_watchers(cnt: i64) {
         SERVER_METRICS.watchers.inc_by(cnt);
     }
@@ -153,6 +153,7 @@ fn make_client(addresses: Vec<String>) -> Result<Arc<ClientHandle>, MetaClientEr
         Some(Duration::from_secs(10)),
         Duration::from_secs(10),
         None,
+        None,
     )?;
 
     Ok(client)
@@ -164,6 +164,7 @@ async fn test_kv_api_restart_cluster_token_expired() -> anyhow::Result<()> {
         Some(Duration::from_secs(10)),
         Duration::from_secs(10),
         None,
+        None,
     )?;
 
     info!("--- test write on a fresh cluster");
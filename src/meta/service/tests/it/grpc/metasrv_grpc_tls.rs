@@ -56,6 +56,7 @@ async fn test_tls_server() -> anyhow::Result<()> {
         None,
         Some(Duration::from_secs(10)),
         Duration::from_secs(10),
+        None,
         Some(tls_conf),
     )?;
 
@@ -95,6 +96,7 @@ async fn test_tls_client_config_failure() -> anyhow::Result<()> {
         None,
         Some(Duration::from_secs(10)),
         Duration::from_secs(10),
+        None,
         Some(tls_conf),
     )
     .unwrap();
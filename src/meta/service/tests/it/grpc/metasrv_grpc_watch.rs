@@ -560,6 +560,7 @@ fn make_client(addr: impl ToString) -> anyhow::Result<Arc<ClientHandle>> {
         Some(Duration::from_secs(10)),
         Duration::from_secs(10),
         None,
+        None,
     )?;
 
     Ok(client)
@@ -13,20 +13,26 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
 
+use common_meta_client::MetaGrpcReadReq;
+use common_meta_kvapi::kvapi::GetKVReq;
 use common_meta_sled_store::openraft::error::RaftError;
 use common_meta_types::ClientWriteError;
 use common_meta_types::Cmd;
 use common_meta_types::ForwardToLeader;
 use common_meta_types::LogEntry;
 use common_meta_types::UpsertKV;
+use databend_meta::message::ForwardRequest;
 use databend_meta::meta_service::meta_leader::MetaLeader;
 use databend_meta::meta_service::MetaNode;
+use futures::TryStreamExt;
 use maplit::btreeset;
 use test_harness::test;
 
 use crate::testing::meta_service_test_harness;
 use crate::tests::meta_node::start_meta_node_cluster;
+use crate::tests::meta_node::timeout;
 use crate::tests::service::MetaSrvTestContext;
 
 #[test(harness = meta_service_test_harness)]
@@ -76,6 +82,61 @@ async fn test_meta_node_forward_to_leader() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test(harness = meta_service_test_harness)]
+#[minitrace::trace]
+async fn test_meta_node_follower_read() -> anyhow::Result<()> {
+    // - Start a leader, 2 followers and a non-voter;
+    // - Write to the leader.
+    // - Read from every node with a follower read, expect the written value,
+    //   either served locally or by the leader.
+
+    let (mut log_index, tcs) = start_meta_node_cluster(btreeset![0, 1, 2], btreeset![3]).await?;
+    let all = test_context_nodes(&tcs);
+
+    let leader_id = all[0].raft.metrics().borrow().current_leader.unwrap();
+    let leader = &all[leader_id as usize];
+
+    let key = "t-follower-read";
+    leader
+        .write(LogEntry {
+            txid: None,
+            time_ms: None,
+            cmd: Cmd::UpsertKV(UpsertKV::update(key, key.as_bytes())),
+        })
+        .await?;
+    log_index += 1;
+
+    for mn in all.iter() {
+        mn.raft
+            .wait(timeout())
+            .log(Some(log_index), "replicated the write")
+            .await?;
+    }
+
+    for max_staleness in [Duration::from_secs(60), Duration::ZERO] {
+        for mn in all.iter() {
+            let req = ForwardRequest {
+                forward_to_leader: 1,
+                body: MetaGrpcReadReq::GetKV(GetKVReq {
+                    key: key.to_string(),
+                }),
+            };
+
+            let strm = mn.handle_follower_read(req, max_staleness).await?;
+            let items = strm.try_collect::<Vec<_>>().await?;
+
+            assert_eq!(1, items.len());
+            assert_eq!(key, items[0].key);
+            assert_eq!(
+                key.as_bytes(),
+                items[0].value.as_ref().unwrap().data.as_slice()
+            );
+        }
+    }
+
+    Ok(())
+}
+
 fn test_context_nodes(tcs: &[MetaSrvTestContext]) -> Vec<Arc<MetaNode>> {
     tcs.iter().map(|tc| tc.meta_node()).collect::<Vec<_>>()
 }
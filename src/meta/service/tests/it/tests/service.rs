@@ -197,6 +197,7 @@ impl MetaSrvTestContext {
             Some(Duration::from_secs(10)),
             Duration::from_secs(10),
             None,
+            None,
         )?;
         Ok(client)
     }
@@ -253,6 +254,7 @@ impl kvapi::ApiBuilder<Arc<ClientHandle>> for MetaSrvBuilder {
             None,
             Duration::from_secs(10),
             None,
+            None,
         )
         .unwrap();
 
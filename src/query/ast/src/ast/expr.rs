@@ -193,7 +193,7 @@ pub enum Expr {
         name: Identifier,
         args: Vec<Expr>,
         params: Vec<Literal>,
-        window: Option<Window>,
+        window: Option<WindowDesc>,
         lambda: Option<Lambda>,
     },
     /// `CASE ... WHEN ... ELSE ...` expression
@@ -303,12 +303,16 @@ pub enum TypeName {
     Int64,
     Float32,
     Float64,
+    UInt128,
+    Int128,
     Decimal {
         precision: u8,
         scale: u8,
     },
     Date,
-    Timestamp,
+    Timestamp {
+        precision: Option<u8>,
+    },
     String,
     Array(Box<TypeName>),
     Map {
@@ -321,6 +325,7 @@ pub enum TypeName {
         fields_type: Vec<TypeName>,
     },
     Variant,
+    Geometry,
     Nullable(Box<TypeName>),
 }
 
@@ -345,6 +350,15 @@ pub enum TrimWhere {
     Trailing,
 }
 
+/// The `OVER` clause of a window function call, along with the optional
+/// `IGNORE NULLS` or `RESPECT NULLS` modifier preceding it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindowDesc {
+    /// `Some(true)` for `IGNORE NULLS`, `Some(false)` for `RESPECT NULLS`.
+    pub ignore_nulls: Option<bool>,
+    pub window: Window,
+}
+
 #[derive(Debug, Clone, PartialEq, EnumAsInner)]
 pub enum Window {
     WindowReference(WindowRef),
@@ -376,6 +390,7 @@ pub struct WindowFrame {
     pub units: WindowFrameUnits,
     pub start_bound: WindowFrameBound,
     pub end_bound: WindowFrameBound,
+    pub exclusion: WindowFrameExclusion,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, EnumAsInner)]
@@ -395,6 +410,20 @@ pub enum WindowFrameBound {
     Following(Option<Box<Expr>>),
 }
 
+/// Specifies the rows excluded from [WindowFrame] by the `EXCLUDE` clause.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum WindowFrameExclusion {
+    /// `EXCLUDE NO OTHERS`, which is the default.
+    #[default]
+    NoOthers,
+    /// `EXCLUDE CURRENT ROW`
+    CurrentRow,
+    /// `EXCLUDE GROUP`, excludes the current row and its peers.
+    Group,
+    /// `EXCLUDE TIES`, excludes the peers of the current row but not the row itself.
+    Ties,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Lambda {
     pub params: Vec<Identifier>,
@@ -817,14 +846,23 @@ impl Display for TypeName {
             TypeName::Float64 => {
                 write!(f, "Float64")?;
             }
+            TypeName::UInt128 => {
+                write!(f, "UInt128")?;
+            }
+            TypeName::Int128 => {
+                write!(f, "Int128")?;
+            }
             TypeName::Decimal { precision, scale } => {
                 write!(f, "Decimal({}, {})", precision, scale)?;
             }
             TypeName::Date => {
                 write!(f, "DATE")?;
             }
-            TypeName::Timestamp => {
+            TypeName::Timestamp { precision } => {
                 write!(f, "TIMESTAMP")?;
+                if let Some(precision) = precision {
+                    write!(f, "({})", precision)?;
+                }
             }
             TypeName::String => {
                 write!(f, "STRING")?;
@@ -869,6 +907,9 @@ impl Display for TypeName {
             TypeName::Variant => {
                 write!(f, "VARIANT")?;
             }
+            TypeName::Geometry => {
+                write!(f, "GEOMETRY")?;
+            }
             TypeName::Nullable(ty) => {
                 write!(f, "{} NULL", ty)?;
             }
@@ -993,7 +1034,13 @@ impl Display for WindowSpec {
                 " BETWEEN {} AND {}",
                 format_frame(&frame.start_bound),
                 format_frame(&frame.end_bound)
-            )?
+            )?;
+            match frame.exclusion {
+                WindowFrameExclusion::NoOthers => {}
+                WindowFrameExclusion::CurrentRow => write!(f, " EXCLUDE CURRENT ROW")?,
+                WindowFrameExclusion::Group => write!(f, " EXCLUDE GROUP")?,
+                WindowFrameExclusion::Ties => write!(f, " EXCLUDE TIES")?,
+            }
         }
         Ok(())
     }
@@ -1168,7 +1215,12 @@ impl Display for Expr {
             Expr::CountAll { window, .. } => {
                 write!(f, "COUNT(*)")?;
                 if let Some(window) = window {
-                    write!(f, " OVER ({window})")?;
+                    match window.ignore_nulls {
+                        Some(true) => write!(f, " IGNORE NULLS")?,
+                        Some(false) => write!(f, " RESPECT NULLS")?,
+                        None => {}
+                    }
+                    write!(f, " OVER ({})", window.window)?;
                 }
             }
             Expr::Tuple { exprs, .. } => {
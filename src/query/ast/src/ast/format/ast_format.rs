@@ -448,7 +448,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         name: &'ast Identifier,
         args: &'ast [Expr],
         _params: &'ast [Literal],
-        _over: &'ast Option<Window>,
+        _over: &'ast Option<WindowDesc>,
         _lambda: &'ast Option<Lambda>,
     ) {
         let mut children = Vec::with_capacity(args.len());
@@ -672,6 +672,19 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 FormatTreeNode::with_children(order_by_format_ctx, order_by_children);
             children.push(order_by_node);
         }
+        if let Some(limit_by) = &query.limit_by {
+            self.visit_expr(&limit_by.limit);
+            let mut limit_by_children = vec![self.children.pop().unwrap()];
+            for expr in limit_by.by.iter() {
+                self.visit_expr(expr);
+                limit_by_children.push(self.children.pop().unwrap());
+            }
+            let limit_by_format_ctx =
+                AstFormatContext::with_children("LimitBy".to_string(), limit_by_children.len());
+            let limit_by_node =
+                FormatTreeNode::with_children(limit_by_format_ctx, limit_by_children);
+            children.push(limit_by_node);
+        }
         if !query.limit.is_empty() {
             let limit_format_ctx =
                 AstFormatContext::with_children("LimitList".to_string(), query.limit.len());
@@ -712,6 +725,9 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             ExplainKind::Plan => "Plan",
             ExplainKind::Memo(_) => "Memo",
             ExplainKind::JOIN => "JOIN",
+            ExplainKind::Snapshot => "Snapshot",
+            ExplainKind::Prune => "Prune",
+            ExplainKind::Lineage => "Lineage",
             ExplainKind::AnalyzePlan => "Analyze",
         });
         let format_ctx = AstFormatContext::with_children(name, 1);
@@ -1364,12 +1380,17 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
 
     fn visit_create_table_source(&mut self, source: &'ast CreateTableSource) {
         match source {
-            CreateTableSource::Columns(columns) => {
-                let mut children = Vec::with_capacity(columns.len());
+            CreateTableSource::Columns(columns, constraints) => {
+                let mut children = Vec::with_capacity(columns.len() + constraints.len());
                 for column in columns.iter() {
                     self.visit_column_definition(column);
                     children.push(self.children.pop().unwrap());
                 }
+                for constraint in constraints.iter() {
+                    let name = format!("TableConstraint {constraint}");
+                    let format_ctx = AstFormatContext::new(name);
+                    children.push(FormatTreeNode::new(format_ctx));
+                }
                 let name = "ColumnsDefinition".to_string();
                 let format_ctx = AstFormatContext::with_children(name, children.len());
                 let node = FormatTreeNode::with_children(format_ctx, children);
@@ -1387,6 +1408,18 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let node = FormatTreeNode::with_children(format_ctx, vec![child]);
                 self.children.push(node);
             }
+            CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+            } => {
+                self.visit_table_ref(catalog, database, table);
+                let child = self.children.pop().unwrap();
+                let name = "CloneTable".to_string();
+                let format_ctx = AstFormatContext::with_children(name, 1);
+                let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+                self.children.push(node);
+            }
         }
     }
 
@@ -1536,6 +1569,21 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::new(action_name);
                 FormatTreeNode::new(action_format_ctx)
             }
+            AlterTableAction::PromoteReplica => {
+                let action_name = "Action PromoteReplica".to_string();
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::AddConstraint { constraint } => {
+                let action_name = format!("Action Add {constraint}");
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::DropConstraint { name } => {
+                let action_name = format!("Action Drop constraint {name}");
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
         };
 
         let name = "AlterTable".to_string();
@@ -2787,6 +2835,13 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             children.push(qualify_node);
         }
 
+        if let Some(gap_fill) = &stmt.gap_fill {
+            let gap_fill_name = format!("GapFill {}", gap_fill);
+            let gap_fill_format_ctx = AstFormatContext::new(gap_fill_name);
+            let gap_fill_node = FormatTreeNode::new(gap_fill_format_ctx);
+            children.push(gap_fill_node);
+        }
+
         let name = "SelectQuery".to_string();
         let format_ctx = AstFormatContext::with_children(name, children.len());
         let node = FormatTreeNode::with_children(format_ctx, children);
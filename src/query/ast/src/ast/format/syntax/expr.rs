@@ -278,9 +278,14 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
             .append(inline_comma(args.into_iter().map(pretty_expr)))
             .append(RcDoc::text(")"))
             .append(if let Some(window) = window {
-                RcDoc::text(" OVER (")
-                    .append(RcDoc::text(window.to_string()))
-                    .append(")")
+                match window.ignore_nulls {
+                    Some(true) => RcDoc::text(" IGNORE NULLS"),
+                    Some(false) => RcDoc::text(" RESPECT NULLS"),
+                    None => RcDoc::nil(),
+                }
+                .append(RcDoc::text(" OVER ("))
+                .append(RcDoc::text(window.window.to_string()))
+                .append(")")
             } else {
                 RcDoc::nil()
             }),
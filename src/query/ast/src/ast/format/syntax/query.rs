@@ -21,9 +21,11 @@ use crate::ast::format::syntax::interweave_comma;
 use crate::ast::format::syntax::parenthesized;
 use crate::ast::format::syntax::NEST_FACTOR;
 use crate::ast::Expr;
+use crate::ast::GapFill;
 use crate::ast::GroupBy;
 use crate::ast::JoinCondition;
 use crate::ast::JoinOperator;
+use crate::ast::LimitBy;
 use crate::ast::OrderByExpr;
 use crate::ast::Query;
 use crate::ast::SelectTarget;
@@ -39,6 +41,7 @@ pub(crate) fn pretty_query(query: Query) -> RcDoc<'static> {
     pretty_with(query.with)
         .append(pretty_body(query.body))
         .append(pretty_order_by(query.order_by))
+        .append(pretty_limit_by(query.limit_by))
         .append(pretty_limit(query.limit))
         .append(pretty_offset(query.offset))
         .group()
@@ -87,7 +90,8 @@ fn pretty_body(body: SetExpr) -> RcDoc<'static> {
         .append(pretty_selection(select_stmt.selection))
         .append(pretty_group_by(select_stmt.group_by))
         .append(pretty_having(select_stmt.having))
-        .append(pretty_window(select_stmt.window_list)),
+        .append(pretty_window(select_stmt.window_list))
+        .append(pretty_gap_fill(select_stmt.gap_fill)),
         SetExpr::Query(query) => parenthesized(pretty_query(*query)),
         SetExpr::SetOperation(set_operation) => pretty_body(*set_operation.left)
             .append(
@@ -303,6 +307,14 @@ fn pretty_window(window: Option<Vec<WindowDefinition>>) -> RcDoc<'static> {
     }
 }
 
+fn pretty_gap_fill(gap_fill: Option<GapFill>) -> RcDoc<'static> {
+    if let Some(gap_fill) = gap_fill {
+        RcDoc::line().append(RcDoc::text(gap_fill.to_string()))
+    } else {
+        RcDoc::nil()
+    }
+}
+
 fn pretty_window_def(def: WindowDefinition) -> RcDoc<'static> {
     RcDoc::text(def.name.to_string())
         .append(RcDoc::space())
@@ -475,6 +487,31 @@ fn pretty_order_by(order_by: Vec<OrderByExpr>) -> RcDoc<'static> {
     }
 }
 
+fn pretty_limit_by(limit_by: Option<LimitBy>) -> RcDoc<'static> {
+    if let Some(limit_by) = limit_by {
+        RcDoc::line()
+            .append(RcDoc::text("LIMIT").append(RcDoc::space().nest(NEST_FACTOR)))
+            .append(pretty_expr(limit_by.limit))
+            .append(
+                RcDoc::space().append(RcDoc::text("BY")).append(
+                    if limit_by.by.len() > 1 {
+                        RcDoc::line()
+                    } else {
+                        RcDoc::space()
+                    }
+                    .nest(NEST_FACTOR),
+                ),
+            )
+            .append(
+                interweave_comma(limit_by.by.into_iter().map(pretty_expr))
+                    .nest(NEST_FACTOR)
+                    .group(),
+            )
+    } else {
+        RcDoc::nil()
+    }
+}
+
 fn pretty_limit(limit: Vec<Expr>) -> RcDoc<'static> {
     if !limit.is_empty() {
         RcDoc::line()
@@ -39,6 +39,8 @@ pub struct Query {
     // The following clauses can only appear in top level of a subquery/query
     // `ORDER BY` clause
     pub order_by: Vec<OrderByExpr>,
+    // `LIMIT n BY` clause
+    pub limit_by: Option<LimitBy>,
     // `LIMIT` clause
     pub limit: Vec<Expr>,
     // `OFFSET` expr
@@ -94,6 +96,8 @@ pub struct SelectStmt {
     pub window_list: Option<Vec<WindowDefinition>>,
     // `QUALIFY` clause
     pub qualify: Option<Expr>,
+    // `GAP_FILL` clause
+    pub gap_fill: Option<GapFill>,
 }
 
 /// Group by Clause.
@@ -113,6 +117,34 @@ pub enum GroupBy {
     Rollup(Vec<Expr>),
 }
 
+/// Gap fill clause, densifies a time series in the result set.
+///
+/// GAP_FILL ( column, INTERVAL n unit [, start, end] ) [PARTITION BY column [, column]*] [FILL method]
+#[derive(Debug, Clone, PartialEq)]
+pub struct GapFill {
+    pub span: Span,
+    // The time column to be densified
+    pub time_column: Identifier,
+    // Width of the buckets, `INTERVAL n unit`
+    pub interval: Expr,
+    // Optional `start` and `end` bounds of the time range
+    pub range: Option<(Expr, Expr)>,
+    // Columns identifying a series, each series is filled independently
+    pub partition_by: Vec<Identifier>,
+    // How the other columns of the generated rows are filled
+    pub method: GapFillMethod,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GapFillMethod {
+    /// Fill with NULL
+    Null,
+    /// Last observation carried forward
+    Locf,
+    /// Linear interpolation between the surrounding observations
+    Linear,
+}
+
 /// A relational set expression, like `SELECT ... FROM ... {UNION|EXCEPT|INTERSECT} SELECT ... FROM ...`
 #[derive(Debug, Clone, PartialEq)]
 pub enum SetExpr {
@@ -141,6 +173,13 @@ pub struct OrderByExpr {
     pub nulls_first: Option<bool>,
 }
 
+/// `LIMIT n BY` clause, keeps at most `n` rows for each distinct value of `by`
+#[derive(Debug, Clone, PartialEq)]
+pub struct LimitBy {
+    pub limit: Expr,
+    pub by: Vec<Expr>,
+}
+
 /// One item of the comma-separated list following `SELECT`
 #[derive(Debug, Clone, PartialEq)]
 pub enum SelectTarget {
@@ -381,6 +420,7 @@ impl SetExpr {
                 with: None,
                 body: self,
                 order_by: vec![],
+                limit_by: None,
                 limit: vec![],
                 offset: None,
                 ignore_result: false,
@@ -389,6 +429,13 @@ impl SetExpr {
     }
 }
 
+impl Display for LimitBy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "LIMIT {} BY ", self.limit)?;
+        write_comma_separated_list(f, &self.by)
+    }
+}
+
 impl Display for OrderByExpr {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.expr)?;
@@ -410,6 +457,31 @@ impl Display for OrderByExpr {
     }
 }
 
+impl Display for GapFill {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "GAP_FILL({}, {}", self.time_column, self.interval)?;
+        if let Some((start, end)) = &self.range {
+            write!(f, ", {start}, {end}")?;
+        }
+        write!(f, ")")?;
+        if !self.partition_by.is_empty() {
+            write!(f, " PARTITION BY ")?;
+            write_comma_separated_list(f, &self.partition_by)?;
+        }
+        write!(f, " FILL {}", self.method)
+    }
+}
+
+impl Display for GapFillMethod {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GapFillMethod::Null => write!(f, "NULL"),
+            GapFillMethod::Locf => write!(f, "LOCF"),
+            GapFillMethod::Linear => write!(f, "LINEAR"),
+        }
+    }
+}
+
 impl Display for TableAlias {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", &self.name)?;
@@ -696,6 +768,11 @@ impl Display for SelectStmt {
             write!(f, " HAVING {having}")?;
         }
 
+        // GAP_FILL clause
+        if let Some(gap_fill) = &self.gap_fill {
+            write!(f, " {gap_fill}")?;
+        }
+
         Ok(())
     }
 }
@@ -781,6 +858,11 @@ impl Display for Query {
             write_comma_separated_list(f, &self.order_by)?;
         }
 
+        // LIMIT BY clause
+        if let Some(limit_by) = &self.limit_by {
+            write!(f, " {limit_by}")?;
+        }
+
         // LIMIT clause
         if !self.limit.is_empty() {
             write!(f, " LIMIT ")?;
@@ -87,6 +87,10 @@ pub struct CopyIntoTableStmt {
     pub hints: Option<Hint>,
 
     pub file_format: BTreeMap<String, String>,
+    /// The file format options of the files matching each pattern, the first match wins.
+    pub file_format_overrides: Vec<(String, BTreeMap<String, String>)>,
+    /// The format options of single columns, such as `timestamp_format`.
+    pub column_formats: Vec<(Identifier, BTreeMap<String, String>)>,
 
     // files to load
     pub files: Option<Vec<String>>,
@@ -111,6 +115,8 @@ impl CopyIntoTableStmt {
             CopyIntoTableOption::Files(v) => self.files = Some(v),
             CopyIntoTableOption::Pattern(v) => self.pattern = Some(v),
             CopyIntoTableOption::FileFormat(v) => self.file_format = v,
+            CopyIntoTableOption::FileFormatOverrides(v) => self.file_format_overrides = v,
+            CopyIntoTableOption::ColumnFormats(v) => self.column_formats = v,
             CopyIntoTableOption::ValidationMode(v) => self.validation_mode = v,
             CopyIntoTableOption::SizeLimit(v) => self.size_limit = v,
             CopyIntoTableOption::MaxFiles(v) => self.max_files = v,
@@ -182,6 +188,32 @@ impl Display for CopyIntoTableStmt {
             write!(f, ")")?;
         }
 
+        if !self.file_format_overrides.is_empty() {
+            write!(f, " FILE_FORMAT_OVERRIDES = (")?;
+            for (i, (pattern, file_format)) in self.file_format_overrides.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "'{pattern}' = (")?;
+                write_comma_separated_map(f, file_format)?;
+                write!(f, ")")?;
+            }
+            write!(f, ")")?;
+        }
+
+        if !self.column_formats.is_empty() {
+            write!(f, " COLUMN_FORMATS = (")?;
+            for (i, (column, options)) in self.column_formats.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{column} = (")?;
+                write_comma_separated_map(f, options)?;
+                write!(f, ")")?;
+            }
+            write!(f, ")")?;
+        }
+
         if !self.validation_mode.is_empty() {
             write!(f, "VALIDATION_MODE = {}", self.validation_mode)?;
         }
@@ -415,12 +447,19 @@ impl UriLocation {
             })
             .ok_or(common_exception::ErrorCode::BadArguments("invalid uri"))?;
 
-        let path = if parsed.path().is_empty() {
+        let mut path = if parsed.path().is_empty() {
             "/".to_string()
         } else {
             parsed.path().to_string()
         };
 
+        // The query of an HTTP url is a part of the file, such as the signature of a presigned url.
+        if protocol == "http" || protocol == "https" {
+            if let Some(query) = parsed.query() {
+                path = format!("{path}?{query}");
+            }
+        }
+
         Ok(Self {
             protocol,
             name,
@@ -475,6 +514,8 @@ pub enum CopyIntoTableOption {
     Files(Vec<String>),
     Pattern(String),
     FileFormat(BTreeMap<String, String>),
+    FileFormatOverrides(Vec<(String, BTreeMap<String, String>)>),
+    ColumnFormats(Vec<(Identifier, BTreeMap<String, String>)>),
     ValidationMode(String),
     SizeLimit(usize),
     MaxFiles(usize),
@@ -98,6 +98,8 @@ pub struct DropDatabaseStmt {
     pub if_exists: bool,
     pub catalog: Option<Identifier>,
     pub database: Identifier,
+    pub cascade: bool,
+    pub dry_run: bool,
 }
 
 impl Display for DropDatabaseStmt {
@@ -107,6 +109,12 @@ impl Display for DropDatabaseStmt {
             write!(f, "IF EXISTS ")?;
         }
         write_dot_separated_list(f, self.catalog.iter().chain(Some(&self.database)))?;
+        if self.cascade {
+            write!(f, " CASCADE")?;
+        }
+        if self.dry_run {
+            write!(f, " DRY RUN")?;
+        }
 
         Ok(())
     }
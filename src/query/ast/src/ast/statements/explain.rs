@@ -28,6 +28,15 @@ pub enum ExplainKind {
 
     JOIN,
 
+    // Explain the snapshots the tables are pinned to
+    Snapshot,
+
+    // Explain how the tables are pruned, by each index and predicate
+    Prune,
+
+    // Explain the source columns each output column is derived from
+    Lineage,
+
     // Explain analyze plan
     AnalyzePlan,
 }
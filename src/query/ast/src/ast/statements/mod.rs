@@ -27,14 +27,19 @@ mod insert;
 mod kill;
 mod merge_into;
 mod network_policy;
+mod notification;
 mod pipe;
 mod presign;
 mod replace;
+mod script;
+mod secret;
+mod sequence;
 mod share;
 mod show;
 mod stage;
 mod statement;
 mod stream;
+mod system;
 mod table;
 mod task;
 mod udf;
@@ -59,14 +64,19 @@ pub use insert::*;
 pub use kill::*;
 pub use merge_into::*;
 pub use network_policy::*;
+pub use notification::*;
 pub use pipe::*;
 pub use presign::*;
 pub use replace::*;
+pub use script::*;
+pub use secret::*;
+pub use sequence::*;
 pub use share::*;
 pub use show::*;
 pub use stage::*;
 pub use statement::*;
 pub use stream::*;
+pub use system::*;
 pub use table::*;
 pub use task::*;
 pub use udf::*;
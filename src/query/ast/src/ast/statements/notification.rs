@@ -0,0 +1,177 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use common_base::base::mask_string;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationType {
+    Webhook,
+    Email,
+}
+
+impl Display for NotificationType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NotificationType::Webhook => write!(f, "WEBHOOK"),
+            NotificationType::Email => write!(f, "EMAIL"),
+        }
+    }
+}
+
+/// `WEBHOOK = ( <key> = '<value>', ... )` or `EMAIL = ( <key> = '<value>', ... )`,
+/// the keys are checked by the binder.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotificationOptions {
+    pub notification_type: NotificationType,
+    pub options: BTreeMap<String, String>,
+}
+
+impl NotificationOptions {
+    /// Mask the credentials of the webhook, to be shown in the query log.
+    pub fn mask(&self) -> Self {
+        let options = self
+            .options
+            .iter()
+            .map(|(k, v)| match k.as_str() {
+                "authorization_header" | "signing_secret" => (k.clone(), mask_string(v, 3)),
+                _ => (k.clone(), v.clone()),
+            })
+            .collect();
+        Self {
+            notification_type: self.notification_type,
+            options,
+        }
+    }
+}
+
+impl Display for NotificationOptions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, " {} = (", self.notification_type)?;
+        for (i, (k, v)) in self.options.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, " {k} = '{v}'")?;
+        }
+        write!(f, " )")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateNotificationStmt {
+    pub if_not_exists: bool,
+    pub name: String,
+    pub enabled: bool,
+    pub options: NotificationOptions,
+    pub comments: String,
+}
+
+impl Display for CreateNotificationStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CREATE NOTIFICATION INTEGRATION")?;
+        if self.if_not_exists {
+            write!(f, " IF NOT EXISTS")?;
+        }
+        write!(f, " {}", self.name)?;
+        write!(f, " TYPE = {}", self.options.notification_type)?;
+        write!(f, " ENABLED = {}", self.enabled)?;
+        write!(f, "{}", self.options)?;
+        if !self.comments.is_empty() {
+            write!(f, " COMMENTS = '{}'", self.comments)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlterNotificationStmt {
+    pub if_exists: bool,
+    pub name: String,
+    pub options: AlterNotificationOptions,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AlterNotificationOptions {
+    Set {
+        enabled: Option<bool>,
+        options: Option<NotificationOptions>,
+        comments: Option<String>,
+    },
+}
+
+impl Display for AlterNotificationOptions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AlterNotificationOptions::Set {
+                enabled,
+                options,
+                comments,
+            } => {
+                write!(f, " SET")?;
+                if let Some(enabled) = enabled {
+                    write!(f, " ENABLED = {}", enabled)?;
+                }
+                if let Some(options) = options {
+                    write!(f, "{}", options)?;
+                }
+                if let Some(comments) = comments {
+                    write!(f, " COMMENTS = '{}'", comments)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Display for AlterNotificationStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ALTER NOTIFICATION INTEGRATION")?;
+        if self.if_exists {
+            write!(f, " IF EXISTS")?;
+        }
+        write!(f, " {}", self.name)?;
+        write!(f, "{}", self.options)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DropNotificationStmt {
+    pub if_exists: bool,
+    pub name: String,
+}
+
+impl Display for DropNotificationStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DROP NOTIFICATION INTEGRATION")?;
+        if self.if_exists {
+            write!(f, " IF EXISTS")?;
+        }
+        write!(f, " {}", self.name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DescribeNotificationStmt {
+    pub name: String,
+}
+
+impl Display for DescribeNotificationStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DESCRIBE NOTIFICATION INTEGRATION {}", self.name)
+    }
+}
@@ -0,0 +1,237 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use common_exception::Span;
+
+use crate::ast::Expr;
+use crate::ast::Identifier;
+use crate::ast::TypeName;
+
+/// `EXECUTE IMMEDIATE $$ <script> $$`, the script is parsed by the binder, see
+/// `crate::parser::parse_script`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExecuteImmediateStmt {
+    pub script: String,
+}
+
+impl Display for ExecuteImmediateStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "EXECUTE IMMEDIATE $${}$$", self.script)
+    }
+}
+
+/// A variable declared by `DECLARE` or `LET`, it is NULL if there is no default value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeclareVar {
+    pub span: Span,
+    pub name: Identifier,
+    pub data_type: Option<TypeName>,
+    pub default: Option<Expr>,
+}
+
+impl Display for DeclareVar {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.name)?;
+        if let Some(data_type) = &self.data_type {
+            write!(f, " {data_type}")?;
+        }
+        if let Some(default) = &self.default {
+            write!(f, " := {default}")?;
+        }
+        Ok(())
+    }
+}
+
+/// `[DECLARE <var>; ...] BEGIN <stmt>; ... [EXCEPTION WHEN OTHER THEN <stmt>; ...] END`
+///
+/// The variables are visible in the block only. The statements of the exception handler
+/// are run if any statement of the block fails, with the error in `SQLCODE` and `SQLERRM`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScriptBlock {
+    pub span: Span,
+    pub declares: Vec<DeclareVar>,
+    pub body: Vec<ScriptStatement>,
+    pub exception_handler: Option<Vec<ScriptStatement>>,
+}
+
+impl Display for ScriptBlock {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        if !self.declares.is_empty() {
+            write!(f, "DECLARE")?;
+            for declare in &self.declares {
+                write!(f, " {declare};")?;
+            }
+            write!(f, " ")?;
+        }
+        write!(f, "BEGIN")?;
+        write_statements(f, &self.body)?;
+        if let Some(handler) = &self.exception_handler {
+            write!(f, " EXCEPTION WHEN OTHER THEN")?;
+            write_statements(f, handler)?;
+        }
+        write!(f, " END")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScriptStatement {
+    Block(ScriptBlock),
+    /// `LET <var> [<type>] := <expr>`
+    Let {
+        span: Span,
+        declare: DeclareVar,
+    },
+    /// `<var> := <expr>`
+    Assign {
+        span: Span,
+        name: Identifier,
+        value: Expr,
+    },
+    If {
+        span: Span,
+        conditions: Vec<Expr>,
+        results: Vec<Vec<ScriptStatement>>,
+        else_result: Option<Vec<ScriptStatement>>,
+    },
+    While {
+        span: Span,
+        condition: Expr,
+        body: Vec<ScriptStatement>,
+    },
+    Loop {
+        span: Span,
+        body: Vec<ScriptStatement>,
+    },
+    /// `FOR <var> IN [REVERSE] <lower> TO <upper> DO ... END FOR`, both bounds are inclusive.
+    For {
+        span: Span,
+        variable: Identifier,
+        is_reverse: bool,
+        lower_bound: Expr,
+        upper_bound: Expr,
+        body: Vec<ScriptStatement>,
+    },
+    Break {
+        span: Span,
+    },
+    Continue {
+        span: Span,
+    },
+    Return {
+        span: Span,
+        value: Option<Expr>,
+    },
+    /// A SQL statement, the variables are referenced as `:<var>` in it.
+    RunStatement {
+        span: Span,
+        sql: String,
+    },
+}
+
+impl ScriptStatement {
+    pub fn span(&self) -> Span {
+        match self {
+            ScriptStatement::Block(block) => block.span,
+            ScriptStatement::Let { span, .. }
+            | ScriptStatement::Assign { span, .. }
+            | ScriptStatement::If { span, .. }
+            | ScriptStatement::While { span, .. }
+            | ScriptStatement::Loop { span, .. }
+            | ScriptStatement::For { span, .. }
+            | ScriptStatement::Break { span }
+            | ScriptStatement::Continue { span }
+            | ScriptStatement::Return { span, .. }
+            | ScriptStatement::RunStatement { span, .. } => *span,
+        }
+    }
+}
+
+fn write_statements(f: &mut Formatter, stmts: &[ScriptStatement]) -> std::fmt::Result {
+    for stmt in stmts {
+        write!(f, " {stmt};")?;
+    }
+    Ok(())
+}
+
+impl Display for ScriptStatement {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ScriptStatement::Block(block) => write!(f, "{block}"),
+            ScriptStatement::Let { declare, .. } => write!(f, "LET {declare}"),
+            ScriptStatement::Assign { name, value, .. } => write!(f, "{name} := {value}"),
+            ScriptStatement::If {
+                conditions,
+                results,
+                else_result,
+                ..
+            } => {
+                for (i, (condition, result)) in conditions.iter().zip(results).enumerate() {
+                    if i == 0 {
+                        write!(f, "IF")?;
+                    } else {
+                        write!(f, " ELSEIF")?;
+                    }
+                    write!(f, " {condition} THEN")?;
+                    write_statements(f, result)?;
+                }
+                if let Some(else_result) = else_result {
+                    write!(f, " ELSE")?;
+                    write_statements(f, else_result)?;
+                }
+                write!(f, " END IF")
+            }
+            ScriptStatement::While {
+                condition, body, ..
+            } => {
+                write!(f, "WHILE {condition} DO")?;
+                write_statements(f, body)?;
+                write!(f, " END WHILE")
+            }
+            ScriptStatement::Loop { body, .. } => {
+                write!(f, "LOOP")?;
+                write_statements(f, body)?;
+                write!(f, " END LOOP")
+            }
+            ScriptStatement::For {
+                variable,
+                is_reverse,
+                lower_bound,
+                upper_bound,
+                body,
+                ..
+            } => {
+                write!(f, "FOR {variable} IN ")?;
+                if *is_reverse {
+                    write!(f, "REVERSE ")?;
+                }
+                write!(f, "{lower_bound} TO {upper_bound} DO")?;
+                write_statements(f, body)?;
+                write!(f, " END FOR")
+            }
+            ScriptStatement::Break { .. } => write!(f, "BREAK"),
+            ScriptStatement::Continue { .. } => write!(f, "CONTINUE"),
+            ScriptStatement::Return { value, .. } => {
+                write!(f, "RETURN")?;
+                if let Some(value) = value {
+                    write!(f, " {value}")?;
+                }
+                Ok(())
+            }
+            ScriptStatement::RunStatement { sql, .. } => write!(f, "{sql}"),
+        }
+    }
+}
@@ -40,6 +40,9 @@ pub enum Statement {
     ExplainAnalyze {
         query: Box<Statement>,
     },
+    DescribeQuery {
+        query: Box<Query>,
+    },
 
     CopyIntoTable(CopyIntoTableStmt),
     CopyIntoLocation(CopyIntoLocationStmt),
@@ -212,6 +215,20 @@ pub enum Statement {
     DescribeConnection(DescribeConnectionStmt),
     ShowConnections(ShowConnectionsStmt),
 
+    // Sequence
+    CreateSequence(CreateSequenceStmt),
+    DropSequence(DropSequenceStmt),
+    ShowSequences(ShowSequencesStmt),
+
+    // Secret
+    CreateSecret(CreateSecretStmt),
+    DropSecret(DropSecretStmt),
+    DescribeSecret(DescribeSecretStmt),
+    ShowSecrets(ShowSecretsStmt),
+
+    // System
+    FlushCache(FlushCacheStmt),
+
     // UserDefinedFileFormat
     CreateFileFormat {
         if_not_exists: bool,
@@ -259,6 +276,15 @@ pub enum Statement {
     DropTask(DropTaskStmt),
     ShowTasks(ShowTasksStmt),
 
+    // notification integrations
+    CreateNotification(CreateNotificationStmt),
+    AlterNotification(AlterNotificationStmt),
+    DropNotification(DropNotificationStmt),
+    DescribeNotification(DescribeNotificationStmt),
+
+    // scripting
+    ExecuteImmediate(ExecuteImmediateStmt),
+
     // pipes
     CreatePipe(CreatePipeStmt),
     DescribePipe(DescribePipeStmt),
@@ -305,6 +331,19 @@ impl Statement {
                 attach_clone.uri_location.connection = attach_clone.uri_location.connection.mask();
                 format!("{}", Statement::AttachTable(attach_clone))
             }
+            Statement::CreateNotification(notification) => {
+                let mut notification_clone = notification.clone();
+                notification_clone.options = notification_clone.options.mask();
+                format!("{}", Statement::CreateNotification(notification_clone))
+            }
+            Statement::AlterNotification(notification) => {
+                let mut notification_clone = notification.clone();
+                let AlterNotificationOptions::Set { options, .. } = &mut notification_clone.options;
+                if let Some(options) = options {
+                    *options = options.mask();
+                }
+                format!("{}", Statement::AlterNotification(notification_clone))
+            }
             _ => format!("{}", self),
         }
     }
@@ -327,12 +366,16 @@ impl Display for Statement {
                     ExplainKind::AnalyzePlan => write!(f, " ANALYZE")?,
                     ExplainKind::JOIN => write!(f, " JOIN")?,
                     ExplainKind::Memo(_) => write!(f, " MEMO")?,
+                    ExplainKind::Snapshot => write!(f, " SNAPSHOT")?,
+                    ExplainKind::Prune => write!(f, " PRUNE")?,
+                    ExplainKind::Lineage => write!(f, " LINEAGE")?,
                 }
                 write!(f, " {query}")?;
             }
             Statement::ExplainAnalyze { query } => {
                 write!(f, "EXPLAIN ANALYZE {query}")?;
             }
+            Statement::DescribeQuery { query } => write!(f, "DESCRIBE {query}")?,
             Statement::Query(query) => write!(f, "{query}")?,
             Statement::Insert(insert) => write!(f, "{insert}")?,
             Statement::Replace(replace) => write!(f, "{replace}")?,
@@ -591,6 +634,11 @@ impl Display for Statement {
             Statement::ExecuteTask(stmt) => write!(f, "{stmt}")?,
             Statement::DropTask(stmt) => write!(f, "{stmt}")?,
             Statement::ShowTasks(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateNotification(stmt) => write!(f, "{stmt}")?,
+            Statement::AlterNotification(stmt) => write!(f, "{stmt}")?,
+            Statement::DropNotification(stmt) => write!(f, "{stmt}")?,
+            Statement::DescribeNotification(stmt) => write!(f, "{stmt}")?,
+            Statement::ExecuteImmediate(stmt) => write!(f, "{stmt}")?,
             Statement::DescribeTask(stmt) => write!(f, "{stmt}")?,
             Statement::CreatePipe(stmt) => write!(f, "{stmt}")?,
             Statement::DescribePipe(stmt) => write!(f, "{stmt}")?,
@@ -600,6 +648,14 @@ impl Display for Statement {
             Statement::DropConnection(stmt) => write!(f, "{stmt}")?,
             Statement::DescribeConnection(stmt) => write!(f, "{stmt}")?,
             Statement::ShowConnections(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateSequence(stmt) => write!(f, "{stmt}")?,
+            Statement::DropSequence(stmt) => write!(f, "{stmt}")?,
+            Statement::ShowSequences(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateSecret(stmt) => write!(f, "{stmt}")?,
+            Statement::DropSecret(stmt) => write!(f, "{stmt}")?,
+            Statement::DescribeSecret(stmt) => write!(f, "{stmt}")?,
+            Statement::ShowSecrets(stmt) => write!(f, "{stmt}")?,
+            Statement::FlushCache(stmt) => write!(f, "{stmt}")?,
         }
         Ok(())
     }
@@ -0,0 +1,53 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+/// The caches flushed by `ALTER SYSTEM FLUSH CACHE`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheKind {
+    All,
+    /// The query result cache of the tenant.
+    Result,
+    /// The plan cache.
+    Plan,
+    /// The caches of table meta, e.g. snapshots, segments and bloom indexes.
+    Meta,
+    /// The caches of table data.
+    Data,
+}
+
+impl Display for CacheKind {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            CacheKind::All => write!(f, "ALL"),
+            CacheKind::Result => write!(f, "RESULT"),
+            CacheKind::Plan => write!(f, "PLAN"),
+            CacheKind::Meta => write!(f, "META"),
+            CacheKind::Data => write!(f, "DATA"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlushCacheStmt {
+    pub kind: CacheKind,
+}
+
+impl Display for FlushCacheStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "ALTER SYSTEM FLUSH CACHE {}", self.kind)
+    }
+}
@@ -216,20 +216,29 @@ impl Display for AttachTableStmt {
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum CreateTableSource {
-    Columns(Vec<ColumnDefinition>),
+    Columns(Vec<ColumnDefinition>, Vec<TableConstraintDefinition>),
     Like {
         catalog: Option<Identifier>,
         database: Option<Identifier>,
         table: Identifier,
     },
+    Clone {
+        catalog: Option<Identifier>,
+        database: Option<Identifier>,
+        table: Identifier,
+    },
 }
 
 impl Display for CreateTableSource {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         match self {
-            CreateTableSource::Columns(columns) => {
+            CreateTableSource::Columns(columns, constraints) => {
                 write!(f, "(")?;
                 write_comma_separated_list(f, columns)?;
+                if !constraints.is_empty() {
+                    write!(f, ", ")?;
+                    write_comma_separated_list(f, constraints)?;
+                }
                 write!(f, ")")
             }
             CreateTableSource::Like {
@@ -240,6 +249,14 @@ impl Display for CreateTableSource {
                 write!(f, "LIKE ")?;
                 write_dot_separated_list(f, catalog.iter().chain(database).chain(Some(table)))
             }
+            CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+            } => {
+                write!(f, "CLONE ")?;
+                write_dot_separated_list(f, catalog.iter().chain(database).chain(Some(table)))
+            }
         }
     }
 }
@@ -365,6 +382,13 @@ pub enum AlterTableAction {
     SetOptions {
         set_options: BTreeMap<String, String>,
     },
+    PromoteReplica,
+    AddConstraint {
+        constraint: TableConstraintDefinition,
+    },
+    DropConstraint {
+        name: Identifier,
+    },
 }
 
 impl Display for AlterTableAction {
@@ -419,6 +443,15 @@ impl Display for AlterTableAction {
             AlterTableAction::RevertTo { point } => {
                 write!(f, "REVERT TO {}", point)?;
             }
+            AlterTableAction::PromoteReplica => {
+                write!(f, "PROMOTE REPLICA")?;
+            }
+            AlterTableAction::AddConstraint { constraint } => {
+                write!(f, "ADD {constraint}")?;
+            }
+            AlterTableAction::DropConstraint { name } => {
+                write!(f, "DROP CONSTRAINT {name}")?;
+            }
         };
         Ok(())
     }
@@ -725,6 +758,10 @@ pub enum ColumnExpr {
     Default(Box<Expr>),
     Virtual(Box<Expr>),
     Stored(Box<Expr>),
+    /// Values are generated by the sequence with the given name.
+    NextVal(Identifier),
+    /// Values are generated by a sequence created along with the table.
+    AutoIncrement,
 }
 
 impl Display for ColumnExpr {
@@ -739,6 +776,12 @@ impl Display for ColumnExpr {
             ColumnExpr::Stored(expr) => {
                 write!(f, " AS ({expr}) STORED")?;
             }
+            ColumnExpr::NextVal(sequence) => {
+                write!(f, " DEFAULT NEXTVAL({sequence})")?;
+            }
+            ColumnExpr::AutoIncrement => {
+                write!(f, " AUTO_INCREMENT")?;
+            }
         }
         Ok(())
     }
@@ -757,6 +800,7 @@ pub struct ColumnDefinition {
     pub expr: Option<ColumnExpr>,
     pub comment: Option<String>,
     pub nullable_constraint: Option<NullableConstraint>,
+    pub mask_policy: Option<String>,
 }
 
 impl Display for ColumnDefinition {
@@ -773,6 +817,9 @@ impl Display for ColumnDefinition {
         if let Some(expr) = &self.expr {
             write!(f, "{expr}")?;
         }
+        if let Some(mask_policy) = &self.mask_policy {
+            write!(f, " MASKING POLICY {mask_policy}")?;
+        }
         if let Some(comment) = &self.comment {
             write!(f, " COMMENT '{comment}'")?;
         }
@@ -780,6 +827,68 @@ impl Display for ColumnDefinition {
     }
 }
 
+/// A table level constraint, e.g. `CONSTRAINT c1 CHECK (a > 0)`, `PRIMARY KEY (a, b)` or
+/// `FOREIGN KEY (a) REFERENCES t (id)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableConstraintDefinition {
+    pub name: Option<Identifier>,
+    pub kind: TableConstraintKind,
+}
+
+impl Display for TableConstraintDefinition {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        if let Some(name) = &self.name {
+            write!(f, "CONSTRAINT {name} ")?;
+        }
+        write!(f, "{}", self.kind)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TableConstraintKind {
+    Check(Expr),
+    Unique(Vec<Identifier>),
+    PrimaryKey(Vec<Identifier>),
+    ForeignKey {
+        columns: Vec<Identifier>,
+        ref_database: Option<Identifier>,
+        ref_table: Identifier,
+        ref_columns: Vec<Identifier>,
+    },
+}
+
+impl Display for TableConstraintKind {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            TableConstraintKind::Check(expr) => write!(f, "CHECK ({expr})"),
+            TableConstraintKind::Unique(columns) => {
+                write!(f, "UNIQUE (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ")")
+            }
+            TableConstraintKind::PrimaryKey(columns) => {
+                write!(f, "PRIMARY KEY (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ")")
+            }
+            TableConstraintKind::ForeignKey {
+                columns,
+                ref_database,
+                ref_table,
+                ref_columns,
+            } => {
+                write!(f, "FOREIGN KEY (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ") REFERENCES ")?;
+                write_dot_separated_list(f, ref_database.iter().chain(Some(ref_table)))?;
+                write!(f, " (")?;
+                write_comma_separated_list(f, ref_columns)?;
+                write!(f, ")")
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum ModifyColumnAction {
     // (column name id, masking policy name)
@@ -22,8 +22,12 @@ pub struct CreateTaskStmt {
     pub if_not_exists: bool,
     pub name: String,
     pub warehouse_opts: WarehouseOptions,
-    pub schedule_opts: ScheduleOptions,
+    pub schedule_opts: Option<ScheduleOptions>,
+    /// The predecessor tasks, the task runs after all of them finish successfully.
+    pub after: Vec<String>,
     pub suspend_task_after_num_failures: Option<u64>,
+    /// The notification integration to send the failed runs to.
+    pub error_integration: Option<String>,
     pub comments: String,
     pub sql: String,
 }
@@ -38,12 +42,22 @@ impl Display for CreateTaskStmt {
 
         write!(f, "{}", self.warehouse_opts)?;
 
-        write!(f, "{}", self.schedule_opts)?;
+        if let Some(schedule_opts) = &self.schedule_opts {
+            write!(f, "{}", schedule_opts)?;
+        }
+
+        if !self.after.is_empty() {
+            write!(f, " AFTER {}", self.after.join(", "))?;
+        }
 
         if let Some(num) = self.suspend_task_after_num_failures {
             write!(f, " SUSPEND TASK AFTER {} FAILURES", num)?;
         }
 
+        if let Some(error_integration) = &self.error_integration {
+            write!(f, " ERROR_INTEGRATION = {}", error_integration)?;
+        }
+
         if !self.comments.is_empty() {
             write!(f, " COMMENTS = '{}'", self.comments)?;
         }
@@ -105,6 +119,7 @@ pub enum AlterTaskOptions {
         warehouse: Option<String>,
         schedule: Option<ScheduleOptions>,
         suspend_task_after_num_failures: Option<u64>,
+        error_integration: Option<String>,
         comments: Option<String>,
     },
     Unset {
@@ -112,6 +127,8 @@ pub enum AlterTaskOptions {
     },
     // Change SQL
     ModifyAs(String),
+    AddAfter(Vec<String>),
+    RemoveAfter(Vec<String>),
 }
 
 impl Display for AlterTaskOptions {
@@ -123,6 +140,7 @@ impl Display for AlterTaskOptions {
                 warehouse,
                 schedule,
                 suspend_task_after_num_failures,
+                error_integration,
                 comments,
             } => {
                 if let Some(wh) = warehouse {
@@ -134,6 +152,9 @@ impl Display for AlterTaskOptions {
                 if let Some(num) = suspend_task_after_num_failures {
                     write!(f, " SUSPEND TASK AFTER {} FAILURES", num)?;
                 }
+                if let Some(error_integration) = error_integration {
+                    write!(f, " ERROR_INTEGRATION = {}", error_integration)?;
+                }
                 if let Some(comments) = comments {
                     write!(f, " COMMENTS = '{}'", comments)?;
                 }
@@ -146,6 +167,10 @@ impl Display for AlterTaskOptions {
                 Ok(())
             }
             AlterTaskOptions::ModifyAs(sql) => write!(f, " AS {}", sql),
+            AlterTaskOptions::AddAfter(after) => write!(f, " ADD AFTER {}", after.join(", ")),
+            AlterTaskOptions::RemoveAfter(after) => {
+                write!(f, " REMOVE AFTER {}", after.join(", "))
+            }
         }
     }
 }
@@ -30,6 +30,8 @@ use crate::parser::expr::literal_u64;
 use crate::parser::query::query;
 use crate::parser::stage::file_format_clause;
 use crate::parser::stage::file_location;
+use crate::parser::stage::format_options;
+use crate::parser::stage::options;
 use crate::parser::statement::hint;
 use crate::parser::token::TokenKind::COPY;
 use crate::parser::token::TokenKind::*;
@@ -70,6 +72,8 @@ pub fn copy_into_table(i: Input) -> IResult<Statement> {
                 files: Default::default(),
                 pattern: Default::default(),
                 file_format: Default::default(),
+                file_format_overrides: Default::default(),
+                column_formats: Default::default(),
                 validation_mode: Default::default(),
                 size_limit: Default::default(),
                 max_files: Default::default(),
@@ -133,12 +137,23 @@ pub fn copy_into(i: Input) -> IResult<Statement> {
                 [ FILE_FORMAT = ( { TYPE = { CSV | JSON | PARQUET | TSV } [ formatTypeOptions ] } ) ]
                 [ FILES = ( '<file_name>' [ , '<file_name>' ] [ , ... ] ) ]
                 [ PATTERN = '<regex_pattern>' ]
+                [ FILE_FORMAT_OVERRIDES = ( '<regex_pattern>' = ( formatTypeOptions ) [ , ... ] ) ]
+                [ COLUMN_FORMATS = ( <column_name> = ( columnFormatOptions ) [ , ... ] ) ]
                 [ VALIDATION_MODE = RETURN_ROWS ]
                 [ copyOptions ]`"
     )(i)
 }
 
 fn copy_into_table_option(i: Input) -> IResult<CopyIntoTableOption> {
+    let file_format_override = map(
+        rule! { #literal_string ~ ^"=" ~ ^"(" ~ ^#format_options ~ ^")" },
+        |(pattern, _, _, options, _)| (pattern, options),
+    );
+    let column_format = map(
+        rule! { #ident ~ ^"=" ~ ^#options },
+        |(column, _, options)| (column, options),
+    );
+
     alt((
         map(
             rule! { FILES ~ "=" ~ "(" ~ #comma_separated_list0(literal_string) ~ ")" },
@@ -151,6 +166,14 @@ fn copy_into_table_option(i: Input) -> IResult<CopyIntoTableOption> {
         map(rule! { #file_format_clause }, |options| {
             CopyIntoTableOption::FileFormat(options)
         }),
+        map(
+            rule! { FILE_FORMAT_OVERRIDES ~ ^"=" ~ ^"(" ~ ^#comma_separated_list1(file_format_override) ~ ^")" },
+            |(_, _, _, overrides, _)| CopyIntoTableOption::FileFormatOverrides(overrides),
+        ),
+        map(
+            rule! { COLUMN_FORMATS ~ ^"=" ~ ^"(" ~ ^#comma_separated_list1(column_format) ~ ^")" },
+            |(_, _, _, formats, _)| CopyIntoTableOption::ColumnFormats(formats),
+        ),
         map(
             rule! { VALIDATION_MODE ~ "=" ~ #literal_string },
             |(_, _, validation_mode)| CopyIntoTableOption::ValidationMode(validation_mode),
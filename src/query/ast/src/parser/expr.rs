@@ -266,7 +266,7 @@ pub enum ExprElement {
         name: Identifier,
         args: Vec<Expr>,
         params: Vec<Literal>,
-        window: Option<Window>,
+        window: Option<WindowDesc>,
         lambda: Option<Lambda>,
     },
     /// `CASE ... WHEN ... ELSE ...` expression
@@ -902,14 +902,18 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
         rule! {
             #function_name
             ~ "(" ~ DISTINCT? ~ #comma_separated_list0(subexpr(0))? ~ ")"
+            ~ #window_null_treatment?
             ~ (OVER ~ #window_spec_ident)
         },
-        |(name, _, opt_distinct, opt_args, _, window)| ExprElement::FunctionCall {
+        |(name, _, opt_distinct, opt_args, _, ignore_nulls, window)| ExprElement::FunctionCall {
             distinct: opt_distinct.is_some(),
             name,
             args: opt_args.unwrap_or_default(),
             params: vec![],
-            window: Some(window.1),
+            window: Some(WindowDesc {
+                ignore_nulls,
+                window: window.1,
+            }),
             lambda: None,
         },
     );
@@ -1102,7 +1106,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
                 span: transform_span(span.0),
                 lit: Literal::String(date),
             }),
-            target_type: TypeName::Timestamp,
+            target_type: TypeName::Timestamp { precision: None },
         },
     );
 
@@ -1429,6 +1433,11 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
         TypeName::Int64,
         rule! { ( INT64 | SIGNED | BIGINT ) ~ ( "(" ~ ^#literal_u64 ~ ^")" )? },
     );
+    let ty_uint128 = value(
+        TypeName::UInt128,
+        rule! { UINT128 | #map(rule! { INT128 ~ UNSIGNED }, |(t, _)| t) },
+    );
+    let ty_int128 = value(TypeName::Int128, rule! { INT128 });
     let ty_float32 = value(TypeName::Float32, rule! { FLOAT32 | FLOAT });
     let ty_float64 = value(
         TypeName::Float64,
@@ -1486,15 +1495,28 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
         },
     );
     let ty_date = value(TypeName::Date, rule! { DATE });
-    let ty_datetime = map(
+    let ty_datetime = map_res(
         rule! { ( DATETIME | TIMESTAMP ) ~ ( "(" ~ ^#literal_u64 ~ ^")" )? },
-        |(_, _)| TypeName::Timestamp,
+        |(_, opt_precision)| {
+            Ok(TypeName::Timestamp {
+                precision: if let Some((_, precision, _)) = opt_precision {
+                    Some(
+                        precision
+                            .try_into()
+                            .map_err(|_| ErrorKind::Other("precision is too large"))?,
+                    )
+                } else {
+                    None
+                },
+            })
+        },
     );
     let ty_string = value(
         TypeName::String,
         rule! { ( STRING | VARCHAR | CHAR | CHARACTER | TEXT | BINARY | VARBINARY ) ~ ( "(" ~ ^#literal_u64 ~ ^")" )? },
     );
     let ty_variant = value(TypeName::Variant, rule! { VARIANT | JSON });
+    let ty_geometry = value(TypeName::Geometry, rule! { GEOMETRY });
     map(
         alt((
             rule! {
@@ -1507,6 +1529,8 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
             | #ty_int16
             | #ty_int32
             | #ty_int64
+            | #ty_uint128
+            | #ty_int128
             | #ty_float32
             | #ty_float64
             | #ty_decimal
@@ -1522,6 +1546,7 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
             | #ty_datetime
             | #ty_string
             | #ty_variant
+            | #ty_geometry
             | #ty_nullable
             ) ~ NULL? : "type name" },
         )),
@@ -17,12 +17,14 @@ use common_exception::Result;
 
 use crate::ast::Expr;
 use crate::ast::Identifier;
+use crate::ast::ScriptStatement;
 use crate::ast::Statement;
 use crate::error::display_parser_error;
 use crate::input::Dialect;
 use crate::input::Input;
 use crate::parser::expr::expr;
 use crate::parser::expr::values_with_placeholder;
+use crate::parser::script::script;
 use crate::parser::statement::statement;
 use crate::parser::token::Token;
 use crate::parser::token::TokenKind;
@@ -50,6 +52,11 @@ pub fn parse_expr(sql_tokens: &[Token], dialect: Dialect) -> Result<Expr> {
     run_parser(sql_tokens, dialect, false, expr)
 }
 
+/// Parse the script of `EXECUTE IMMEDIATE` into `ScriptStatement`s.
+pub fn parse_script(sql_tokens: &[Token], dialect: Dialect) -> Result<Vec<ScriptStatement>> {
+    run_parser(sql_tokens, dialect, false, script)
+}
+
 pub fn parse_comma_separated_exprs(sql_tokens: &[Token], dialect: Dialect) -> Result<Vec<Expr>> {
     run_parser(sql_tokens, dialect, true, |i| {
         comma_separated_list0(expr)(i)
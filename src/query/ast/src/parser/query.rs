@@ -59,6 +59,7 @@ pub enum SetOperationElement {
         having: Box<Option<Expr>>,
         window_list: Option<Vec<WindowDefinition>>,
         qualify: Box<Option<Expr>>,
+        gap_fill: Box<Option<GapFill>>,
     },
     SetOperation {
         op: SetOperator,
@@ -68,12 +69,19 @@ pub enum SetOperationElement {
     OrderBy {
         order_by: Vec<OrderByExpr>,
     },
+    LimitBy {
+        limit: Expr,
+        by: Vec<Expr>,
+    },
     Limit {
         limit: Vec<Expr>,
     },
     Offset {
         offset: Expr,
     },
+    Fetch {
+        limit: Expr,
+    },
     IgnoreResult,
     Group(SetExpr),
 }
@@ -106,6 +114,7 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
                 ~ ( HAVING ~ ^#expr )?
                 ~ ( WINDOW ~ ^#comma_separated_list1(window_clause) )?
                 ~ ( QUALIFY ~ ^#expr )?
+                ~ #gap_fill?
         },
         |(
             _select,
@@ -118,6 +127,7 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
             opt_having_block,
             opt_window_block,
             opt_qualify_block,
+            opt_gap_fill,
         )| {
             SetOperationElement::SelectStmt {
                 hints: opt_hints,
@@ -133,6 +143,7 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
                 having: Box::new(opt_having_block.map(|(_, having)| having)),
                 window_list: opt_window_block.map(|(_, windows)| windows),
                 qualify: Box::new(opt_qualify_block.map(|(_, qualify)| qualify)),
+                gap_fill: Box::new(opt_gap_fill),
             }
         },
     );
@@ -147,6 +158,7 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
                 ~ ( HAVING ~ ^#expr )?
                 ~ ( WINDOW ~ ^#comma_separated_list1(window_clause) )?
                 ~ ( QUALIFY ~ ^#expr )?
+                ~ #gap_fill?
         },
         |(
             opt_from_block,
@@ -159,6 +171,7 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
             opt_having_block,
             opt_window_block,
             opt_qualify_block,
+            opt_gap_fill,
         )| {
             SetOperationElement::SelectStmt {
                 hints: opt_hints,
@@ -174,6 +187,7 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
                 having: Box::new(opt_having_block.map(|(_, having)| having)),
                 window_list: opt_window_block.map(|(_, windows)| windows),
                 qualify: Box::new(opt_qualify_block.map(|(_, qualify)| qualify)),
+                gap_fill: Box::new(opt_gap_fill),
             }
         },
     );
@@ -190,6 +204,12 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
         },
         |(_, _, order_by)| SetOperationElement::OrderBy { order_by },
     );
+    let limit_by = map(
+        rule! {
+            LIMIT ~ #expr ~ BY ~ ^#comma_separated_list1(expr)
+        },
+        |(_, limit, _, by)| SetOperationElement::LimitBy { limit, by },
+    );
     let limit = map(
         rule! {
             LIMIT ~ ^#comma_separated_list1(expr)
@@ -198,9 +218,25 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
     );
     let offset = map(
         rule! {
-            OFFSET ~ ^#expr
+            OFFSET ~ ^#expr ~ ( ROW | ROWS )?
+        },
+        |(_, offset, _)| SetOperationElement::Offset { offset },
+    );
+    let fetch_count = alt((
+        value(None, rule! { ROW | ROWS }),
+        map(rule! { #expr ~ ^( ROW | ROWS ) }, |(count, _)| Some(count)),
+    ));
+    let fetch = map(
+        rule! {
+            FETCH ~ ^( FIRST | NEXT ) ~ ^#fetch_count ~ ^ONLY
+        },
+        |(_, _, limit, _)| SetOperationElement::Fetch {
+            // `FETCH FIRST ROW ONLY` returns one row.
+            limit: limit.unwrap_or(Expr::Literal {
+                span: None,
+                lit: Literal::UInt64(1),
+            }),
         },
-        |(_, offset)| SetOperationElement::Offset { offset },
     );
     let ignore_result = map(
         rule! {
@@ -223,8 +259,10 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
         | #select_stmt_from_first
         | #values
         | #order_by
+        | #limit_by
         | #limit
         | #offset
+        | #fetch
         | #ignore_result
     })(i)?;
     Ok((rest, WithSpan { span, elem }))
@@ -249,8 +287,10 @@ impl<'a, I: Iterator<Item = WithSpan<'a, SetOperationElement>>> PrattParser<I>
             },
             SetOperationElement::With(_) => Affix::Prefix(Precedence(5)),
             SetOperationElement::OrderBy { .. } => Affix::Postfix(Precedence(5)),
+            SetOperationElement::LimitBy { .. } => Affix::Postfix(Precedence(5)),
             SetOperationElement::Limit { .. } => Affix::Postfix(Precedence(5)),
             SetOperationElement::Offset { .. } => Affix::Postfix(Precedence(5)),
+            SetOperationElement::Fetch { .. } => Affix::Postfix(Precedence(5)),
             SetOperationElement::IgnoreResult => Affix::Postfix(Precedence(5)),
             _ => Affix::Nilfix,
         };
@@ -270,6 +310,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, SetOperationElement>>> PrattParser<I>
                 having,
                 window_list,
                 qualify,
+                gap_fill,
             } => SetExpr::Select(Box::new(SelectStmt {
                 span: transform_span(input.span.0),
                 hints,
@@ -281,6 +322,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, SetOperationElement>>> PrattParser<I>
                 having: *having,
                 window_list,
                 qualify: *qualify,
+                gap_fill: *gap_fill,
             })),
             SetOperationElement::Values(values) => SetExpr::Values {
                 span: transform_span(input.span.0),
@@ -333,6 +375,9 @@ impl<'a, I: Iterator<Item = WithSpan<'a, SetOperationElement>>> PrattParser<I>
                 if !query.order_by.is_empty() {
                     return Err("duplicated ORDER BY clause");
                 }
+                if query.limit_by.is_some() {
+                    return Err("ORDER BY must appear before LIMIT BY");
+                }
                 if !query.limit.is_empty() {
                     return Err("ORDER BY must appear before LIMIT");
                 }
@@ -341,6 +386,18 @@ impl<'a, I: Iterator<Item = WithSpan<'a, SetOperationElement>>> PrattParser<I>
                 }
                 query.order_by = order_by;
             }
+            SetOperationElement::LimitBy { limit, by } => {
+                if query.limit_by.is_some() {
+                    return Err("duplicated LIMIT BY clause");
+                }
+                if !query.limit.is_empty() {
+                    return Err("LIMIT BY must appear before LIMIT");
+                }
+                if query.offset.is_some() {
+                    return Err("LIMIT BY must appear before OFFSET");
+                }
+                query.limit_by = Some(LimitBy { limit, by });
+            }
             SetOperationElement::Limit { limit } => {
                 if query.limit.is_empty() && limit.len() > 2 {
                     return Err("[LIMIT n OFFSET m] or [LIMIT n,m]");
@@ -362,6 +419,12 @@ impl<'a, I: Iterator<Item = WithSpan<'a, SetOperationElement>>> PrattParser<I>
                 }
                 query.offset = Some(offset);
             }
+            SetOperationElement::Fetch { limit } => {
+                if !query.limit.is_empty() {
+                    return Err("FETCH should not appear with LIMIT");
+                }
+                query.limit = vec![limit];
+            }
             SetOperationElement::IgnoreResult => {
                 query.ignore_result = true;
             }
@@ -403,6 +466,36 @@ pub fn with(i: Input) -> IResult<With> {
     )(i)
 }
 
+pub fn gap_fill(i: Input) -> IResult<GapFill> {
+    let method = alt((
+        value(GapFillMethod::Null, rule! { NULL }),
+        value(GapFillMethod::Locf, rule! { LOCF }),
+        value(GapFillMethod::Linear, rule! { LINEAR }),
+    ));
+
+    map(
+        consumed(rule! {
+            GAP_FILL ~ ^"(" ~ ^#ident ~ ^"," ~ ^#expr ~ ( "," ~ ^#expr ~ ^"," ~ ^#expr )? ~ ^")"
+            ~ ( PARTITION ~ ^BY ~ ^#comma_separated_list1(ident) )?
+            ~ ( FILL ~ ^#method )?
+        }),
+        |(span, (_, _, time_column, _, interval, opt_range, _, opt_partition_by, opt_method))| {
+            GapFill {
+                span: transform_span(span.0),
+                time_column,
+                interval,
+                range: opt_range.map(|(_, start, _, end)| (start, end)),
+                partition_by: opt_partition_by
+                    .map(|(_, _, partition_by)| partition_by)
+                    .unwrap_or_default(),
+                method: opt_method
+                    .map(|(_, method)| method)
+                    .unwrap_or(GapFillMethod::Null),
+            }
+        },
+    )(i)
+}
+
 pub fn exclude_col(i: Input) -> IResult<Vec<Identifier>> {
     let var = map(
         rule! {
@@ -1030,13 +1123,31 @@ pub fn window_frame_between(i: Input) -> IResult<(WindowFrameBound, WindowFrameB
     ))(i)
 }
 
+pub fn window_frame_exclusion(i: Input) -> IResult<WindowFrameExclusion> {
+    let exclusion = alt((
+        value(WindowFrameExclusion::CurrentRow, rule! { CURRENT ~ ^ROW }),
+        value(WindowFrameExclusion::Group, rule! { GROUP }),
+        value(WindowFrameExclusion::Ties, rule! { TIES }),
+        value(WindowFrameExclusion::NoOthers, rule! { NO ~ ^OTHERS }),
+    ));
+    map(rule! { EXCLUDE ~ ^#exclusion }, |(_, exclusion)| exclusion)(i)
+}
+
+/// `IGNORE NULLS` or `RESPECT NULLS` before the `OVER` clause of a window function.
+pub fn window_null_treatment(i: Input) -> IResult<bool> {
+    alt((
+        value(true, rule! { IGNORE ~ ^NULLS }),
+        value(false, rule! { RESPECT ~ ^NULLS }),
+    ))(i)
+}
+
 pub fn window_spec(i: Input) -> IResult<WindowSpec> {
     map(
         rule! {
             (#ident )?
             ~ ( PARTITION ~ ^BY ~ ^#comma_separated_list1(subexpr(0)) )?
             ~ ( ORDER ~ ^BY ~ ^#comma_separated_list1(order_by_expr) )?
-            ~ ( (ROWS | RANGE) ~ ^#window_frame_between )?
+            ~ ( (ROWS | RANGE) ~ ^#window_frame_between ~ #window_frame_exclusion? )?
         },
         |(existing_window_name, opt_partition, opt_order, between)| WindowSpec {
             existing_window_name,
@@ -1053,6 +1164,7 @@ pub fn window_spec(i: Input) -> IResult<WindowSpec> {
                     units: unit,
                     start_bound: bw.0,
                     end_bound: bw.1,
+                    exclusion: x.2.unwrap_or_default(),
                 }
             }),
         },
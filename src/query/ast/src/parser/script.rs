@@ -0,0 +1,232 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use nom::combinator::consumed;
+use nom::combinator::map;
+use nom::multi::many0;
+use nom::Slice;
+
+use crate::ast::DeclareVar;
+use crate::ast::ScriptBlock;
+use crate::ast::ScriptStatement;
+use crate::input::Input;
+use crate::parser::expr::expr;
+use crate::parser::expr::literal_string;
+use crate::parser::expr::type_name;
+use crate::parser::token::TokenKind;
+use crate::parser::token::TokenKind::*;
+use crate::rule;
+use crate::util::ident;
+use crate::util::map_res;
+use crate::util::match_token;
+use crate::util::transform_span;
+use crate::util::IResult;
+use crate::Error;
+use crate::ErrorKind;
+
+/// The body of `EXECUTE IMMEDIATE`, either `$$ <script> $$` or `'<script>'`.
+pub fn script_string(i: Input) -> IResult<String> {
+    let code_string = map_res(match_token(LiteralCodeString), |token| {
+        let text = token.text();
+        Ok(text[2..text.len() - 2].to_string())
+    });
+    rule!(
+        #code_string
+        | #literal_string
+    )(i)
+}
+
+pub fn script(i: Input) -> IResult<Vec<ScriptStatement>> {
+    map(rule! { #script_statement+ ~ &EOI }, |(stmts, _)| stmts)(i)
+}
+
+fn script_statements(i: Input) -> IResult<Vec<ScriptStatement>> {
+    many0(script_statement)(i)
+}
+
+fn declare_var(i: Input) -> IResult<DeclareVar> {
+    map(
+        consumed(rule! {
+            #ident ~ #type_name? ~ ( ( ":" ~ "=" | DEFAULT ) ~ ^#expr )?
+        }),
+        |(span, (name, data_type, default))| DeclareVar {
+            span: transform_span(span.0),
+            name,
+            data_type,
+            default: default.map(|(_, expr)| expr),
+        },
+    )(i)
+}
+
+fn script_block(i: Input) -> IResult<ScriptBlock> {
+    map(
+        consumed(rule! {
+            ( DECLARE ~ ( #declare_var ~ ";" )+ )?
+            ~ BEGIN ~ #script_statements
+            ~ ( EXCEPTION ~ ^WHEN ~ ^OTHER ~ ^THEN ~ #script_statements )?
+            ~ ^END
+        }),
+        |(span, (declares, _, body, exception_handler, _))| ScriptBlock {
+            span: transform_span(span.0),
+            declares: declares
+                .map(|(_, declares)| declares.into_iter().map(|(var, _)| var).collect())
+                .unwrap_or_default(),
+            body,
+            exception_handler: exception_handler.map(|(_, _, _, _, stmts)| stmts),
+        },
+    )(i)
+}
+
+fn script_statement(i: Input) -> IResult<ScriptStatement> {
+    let block = map(script_block, ScriptStatement::Block);
+    let let_stmt = map(
+        consumed(rule! {
+            LET ~ ^#declare_var
+        }),
+        |(span, (_, declare))| ScriptStatement::Let {
+            span: transform_span(span.0),
+            declare,
+        },
+    );
+    let assign = map(
+        consumed(rule! {
+            #ident ~ ":" ~ "=" ~ ^#expr
+        }),
+        |(span, (name, _, _, value))| ScriptStatement::Assign {
+            span: transform_span(span.0),
+            name,
+            value,
+        },
+    );
+    let if_stmt = map(
+        consumed(rule! {
+            IF ~ ^#expr ~ ^THEN ~ #script_statements
+            ~ ( ELSEIF ~ ^#expr ~ ^THEN ~ #script_statements )*
+            ~ ( ELSE ~ #script_statements )?
+            ~ ^END ~ ^IF
+        }),
+        |(span, (_, condition, _, result, else_ifs, else_result, _, _))| {
+            let mut conditions = vec![condition];
+            let mut results = vec![result];
+            for (_, condition, _, result) in else_ifs {
+                conditions.push(condition);
+                results.push(result);
+            }
+            ScriptStatement::If {
+                span: transform_span(span.0),
+                conditions,
+                results,
+                else_result: else_result.map(|(_, stmts)| stmts),
+            }
+        },
+    );
+    let while_stmt = map(
+        consumed(rule! {
+            WHILE ~ ^#expr ~ ^DO ~ #script_statements ~ ^END ~ ^WHILE
+        }),
+        |(span, (_, condition, _, body, _, _))| ScriptStatement::While {
+            span: transform_span(span.0),
+            condition,
+            body,
+        },
+    );
+    let loop_stmt = map(
+        consumed(rule! {
+            LOOP ~ #script_statements ~ ^END ~ ^LOOP
+        }),
+        |(span, (_, body, _, _))| ScriptStatement::Loop {
+            span: transform_span(span.0),
+            body,
+        },
+    );
+    let for_stmt = map(
+        consumed(rule! {
+            FOR ~ ^#ident ~ ^IN ~ REVERSE? ~ ^#expr ~ ^TO ~ ^#expr
+            ~ ^DO ~ #script_statements ~ ^END ~ ^FOR
+        }),
+        |(span, (_, variable, _, is_reverse, lower_bound, _, upper_bound, _, body, _, _))| {
+            ScriptStatement::For {
+                span: transform_span(span.0),
+                variable,
+                is_reverse: is_reverse.is_some(),
+                lower_bound,
+                upper_bound,
+                body,
+            }
+        },
+    );
+    let break_stmt = map(consumed(rule! { BREAK }), |(span, _)| {
+        ScriptStatement::Break {
+            span: transform_span(span.0),
+        }
+    });
+    let continue_stmt = map(consumed(rule! { CONTINUE }), |(span, _)| {
+        ScriptStatement::Continue {
+            span: transform_span(span.0),
+        }
+    });
+    let return_stmt = map(
+        consumed(rule! {
+            RETURN ~ #expr?
+        }),
+        |(span, (_, value))| ScriptStatement::Return {
+            span: transform_span(span.0),
+            value,
+        },
+    );
+
+    map(
+        rule! {
+            ( #block
+            | #let_stmt
+            | #assign
+            | #if_stmt
+            | #while_stmt
+            | #loop_stmt
+            | #for_stmt
+            | #break_stmt
+            | #continue_stmt
+            | #return_stmt
+            | #run_statement
+            ) ~ ( ";" | &EOI )
+        },
+        |(stmt, _)| stmt,
+    )(i)
+}
+
+/// A SQL statement in the script, taken verbatim up to the next `;`. It's parsed
+/// when it's executed, after the `:<var>` references are replaced by the values.
+fn run_statement(i: Input) -> IResult<ScriptStatement> {
+    const STOP_TOKENS: &[TokenKind] = &[EOI, SemiColon, END, ELSE, ELSEIF, EXCEPTION];
+
+    if STOP_TOKENS.contains(&i.0[0].kind) {
+        return Err(nom::Err::Error(Error::from_error_kind(
+            i,
+            ErrorKind::Other("expected a statement"),
+        )));
+    }
+    let len =
+        i.0.iter()
+            .position(|token| token.kind == SemiColon || token.kind == EOI)
+            .unwrap_or(i.0.len());
+    let tokens = &i.0[..len];
+    let first = tokens.first().unwrap();
+    let last = tokens.last().unwrap();
+    let sql = first.source[first.span.start..last.span.end].to_string();
+
+    Ok((i.slice(len..), ScriptStatement::RunStatement {
+        span: transform_span(tokens),
+        sql,
+    }))
+}
@@ -71,7 +71,7 @@ pub fn connection_options(i: Input) -> IResult<BTreeMap<String, String>> {
 pub fn format_options(i: Input) -> IResult<BTreeMap<String, String>> {
     let option_type = map(
         rule! {
-            TYPE ~ "=" ~ ( TSV | CSV | NDJSON | PARQUET | JSON | XML )
+            TYPE ~ "=" ~ ( TSV | CSV | NDJSON | PARQUET | JSON | XML | PROTOBUF | XLSX )
         },
         |(_, _, v)| ("type".to_string(), v.text().to_string()),
     );
@@ -96,7 +96,12 @@ pub fn format_options(i: Input) -> IResult<BTreeMap<String, String>> {
                 | ESCAPE
                 | NULL_FIELD_AS
                 | MISSING_FIELD_AS
-                | ROW_TAG) ~ ^"=" ~ ^#literal_string
+                | ROW_TAG
+                | ROW_PATH
+                | COLUMN_PATHS
+                | DESCRIPTOR_SET
+                | MESSAGE_NAME
+                | SHEET_NAME) ~ ^"=" ~ ^#literal_string
         },
         |(k, _, v)| (k.text().to_string(), v),
     );
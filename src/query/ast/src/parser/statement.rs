@@ -38,6 +38,7 @@ use crate::parser::data_mask::data_mask_policy;
 use crate::parser::expr::subexpr;
 use crate::parser::expr::*;
 use crate::parser::query::*;
+use crate::parser::script::script_string;
 use crate::parser::share::share_endpoint_uri_location;
 use crate::parser::stage::*;
 use crate::parser::stream::stream_table;
@@ -59,10 +60,10 @@ pub enum CreateDatabaseOption {
     FromShare(ShareNameIdent),
 }
 
-pub fn statement(i: Input) -> IResult<StatementWithFormat> {
+pub fn statement_body(i: Input) -> IResult<Statement> {
     let explain = map_res(
         rule! {
-            EXPLAIN ~ ( AST | SYNTAX | PIPELINE | JOIN | GRAPH | FRAGMENTS | RAW | OPTIMIZED | MEMO )? ~ #statement
+            EXPLAIN ~ ( AST | SYNTAX | PIPELINE | JOIN | GRAPH | FRAGMENTS | RAW | OPTIMIZED | MEMO | SNAPSHOT | PRUNE | LINEAGE )? ~ #statement
         },
         |(_, opt_kind, statement)| {
             Ok(Statement::Explain {
@@ -84,6 +85,9 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
                     Some(TokenKind::RAW) => ExplainKind::Raw,
                     Some(TokenKind::OPTIMIZED) => ExplainKind::Optimized,
                     Some(TokenKind::MEMO) => ExplainKind::Memo("".to_string()),
+                    Some(TokenKind::SNAPSHOT) => ExplainKind::Snapshot,
+                    Some(TokenKind::PRUNE) => ExplainKind::Prune,
+                    Some(TokenKind::LINEAGE) => ExplainKind::Lineage,
                     None => ExplainKind::Plan,
                     _ => unreachable!(),
                 },
@@ -104,8 +108,10 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         rule! {
             CREATE ~ TASK ~ ( IF ~ ^NOT ~ ^EXISTS )?
             ~ #ident ~ #task_warehouse_option
-            ~ SCHEDULE ~ "=" ~ #task_schedule_option
+            ~ ( SCHEDULE ~ ^"=" ~ ^#task_schedule_option )?
+            ~ ( AFTER ~ ^#comma_separated_list1(ident) )?
             ~ (SUSPEND_TASK_AFTER_NUM_FAILURES ~ "=" ~ #literal_u64)?
+            ~ ( ERROR_INTEGRATION ~ ^"=" ~ ^#ident )?
             ~ ( (COMMENT | COMMENTS) ~ ^"=" ~ ^#literal_string )?
             ~ AS ~ #statement
         },
@@ -115,10 +121,10 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             opt_if_not_exists,
             task,
             warehouse_opts,
-            _,
-            _,
             schedule_opts,
+            after,
             suspend_opt,
+            error_integration,
             comment_opt,
             _,
             sql,
@@ -130,8 +136,12 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
                 if_not_exists: opt_if_not_exists.is_some(),
                 name: task.to_string(),
                 warehouse_opts,
-                schedule_opts,
+                schedule_opts: schedule_opts.map(|(_, _, schedule_opts)| schedule_opts),
+                after: after
+                    .map(|(_, tasks)| tasks.iter().map(|task| task.to_string()).collect())
+                    .unwrap_or_default(),
                 suspend_task_after_num_failures: suspend_opt.map(|(_, _, num)| num),
+                error_integration: error_integration.map(|(_, _, name)| name.to_string()),
                 comments: comment_opt.map(|v| v.2).unwrap_or_default(),
                 sql,
             })
@@ -182,6 +192,13 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         },
     );
 
+    let execute_immediate = map(
+        rule! {
+            EXECUTE ~ IMMEDIATE ~ #script_string
+        },
+        |(_, _, script)| Statement::ExecuteImmediate(ExecuteImmediateStmt { script }),
+    );
+
     let desc_task = map(
         rule! {
             ( DESC | DESCRIBE ) ~ TASK ~ #ident
@@ -193,6 +210,82 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         },
     );
 
+    let create_notification = map_res(
+        rule! {
+            CREATE ~ NOTIFICATION ~ INTEGRATION ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            ~ #ident ~ TYPE ~ ^"=" ~ ^#notification_type
+            ~ ENABLED ~ ^"=" ~ ^#literal_bool
+            ~ #notification_options
+            ~ ( (COMMENT | COMMENTS) ~ ^"=" ~ ^#literal_string )?
+        },
+        |(
+            _,
+            _,
+            _,
+            opt_if_not_exists,
+            name,
+            _,
+            _,
+            notification_type,
+            _,
+            _,
+            enabled,
+            options,
+            comment_opt,
+        )| {
+            if options.notification_type != notification_type {
+                return Err(ErrorKind::Other(
+                    "the options of the notification integration do not match its type",
+                ));
+            }
+            Ok(Statement::CreateNotification(CreateNotificationStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name: name.to_string(),
+                enabled,
+                options,
+                comments: comment_opt.map(|v| v.2).unwrap_or_default(),
+            }))
+        },
+    );
+
+    let alter_notification = map(
+        rule! {
+            ALTER ~ NOTIFICATION ~ INTEGRATION ~ ( IF ~ ^EXISTS )?
+            ~ #ident ~ #alter_notification_option
+        },
+        |(_, _, _, opt_if_exists, name, options)| {
+            Statement::AlterNotification(AlterNotificationStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+                options,
+            })
+        },
+    );
+
+    let drop_notification = map(
+        rule! {
+            DROP ~ NOTIFICATION ~ INTEGRATION ~ ( IF ~ ^EXISTS )?
+            ~ #ident
+        },
+        |(_, _, _, opt_if_exists, name)| {
+            Statement::DropNotification(DropNotificationStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+            })
+        },
+    );
+
+    let desc_notification = map(
+        rule! {
+            ( DESC | DESCRIBE ) ~ NOTIFICATION ~ INTEGRATION ~ #ident
+        },
+        |(_, _, _, name)| {
+            Statement::DescribeNotification(DescribeNotificationStmt {
+                name: name.to_string(),
+            })
+        },
+    );
+
     let insert = map(
         rule! {
             INSERT ~ #hint? ~ ( INTO | OVERWRITE ) ~ TABLE?
@@ -513,12 +606,15 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
     let drop_database = map(
         rule! {
             DROP ~ ( DATABASE | SCHEMA ) ~ ( IF ~ ^EXISTS )? ~ #dot_separated_idents_1_to_2
+            ~ ( CASCADE ~ ( DRY ~ ^RUN )? )?
         },
-        |(_, _, opt_if_exists, (catalog, database))| {
+        |(_, _, opt_if_exists, (catalog, database), opt_cascade)| {
             Statement::DropDatabase(DropDatabaseStmt {
                 if_exists: opt_if_exists.is_some(),
                 catalog,
                 database,
+                cascade: opt_cascade.is_some(),
+                dry_run: matches!(opt_cascade, Some((_, Some(_)))),
             })
         },
     );
@@ -601,6 +697,14 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             })
         },
     );
+    let describe_query = map(
+        rule! {
+            ( DESC | DESCRIBE ) ~ #query
+        },
+        |(_, query)| Statement::DescribeQuery {
+            query: Box::new(query),
+        },
+    );
     let describe_table = map(
         rule! {
             ( DESC | DESCRIBE ) ~ #dot_separated_idents_1_to_3
@@ -1265,6 +1369,103 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         |(_, _)| Statement::ShowConnections(ShowConnectionsStmt {}),
     );
 
+    // sequences
+    let create_sequence = map(
+        rule! {
+            CREATE ~ SEQUENCE ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ #ident
+            ~ ( START ~ WITH? ~ #literal_u64 )?
+            ~ ( INCREMENT ~ BY? ~ #literal_u64 )?
+            ~ ( CACHE ~ #literal_u64 )?
+            ~ ( COMMENT ~ "=" ~ #literal_string )?
+        },
+        |(_, _, opt_if_not_exists, name, opt_start, opt_increment, opt_cache, opt_comment)| {
+            Statement::CreateSequence(CreateSequenceStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name,
+                start: opt_start.map(|(_, _, start)| start),
+                increment: opt_increment.map(|(_, _, increment)| increment),
+                cache: opt_cache.map(|(_, cache)| cache),
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            })
+        },
+    );
+
+    let drop_sequence = map(
+        rule! {
+            DROP ~ SEQUENCE ~ ( IF ~ ^EXISTS )? ~ #ident
+        },
+        |(_, _, opt_if_exists, name)| {
+            Statement::DropSequence(DropSequenceStmt {
+                if_exists: opt_if_exists.is_some(),
+                name,
+            })
+        },
+    );
+
+    let show_sequences = map(
+        rule! {
+            SHOW ~ SEQUENCES
+        },
+        |(_, _)| Statement::ShowSequences(ShowSequencesStmt {}),
+    );
+
+    // secrets
+    let secret_param = connection_opt("=");
+    let create_secret = map(
+        rule! {
+            CREATE ~ SECRET ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ #ident
+            ~ PROVIDER ~ ^"=" ~ ^#literal_string
+            ~ ( CACHE_TTL ~ ^"=" ~ ^#literal_u64 )?
+            ~ #secret_param*
+        },
+        |(_, _, opt_if_not_exists, name, _, _, provider, opt_cache_ttl, options)| {
+            Statement::CreateSecret(CreateSecretStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name,
+                provider,
+                cache_ttl: opt_cache_ttl.map(|(_, _, cache_ttl)| cache_ttl),
+                provider_params: BTreeMap::from_iter(options),
+            })
+        },
+    );
+
+    let drop_secret = map(
+        rule! {
+            DROP ~ SECRET ~ ( IF ~ ^EXISTS )? ~ #ident
+        },
+        |(_, _, opt_if_exists, name)| {
+            Statement::DropSecret(DropSecretStmt {
+                if_exists: opt_if_exists.is_some(),
+                name,
+            })
+        },
+    );
+
+    let desc_secret = map(
+        rule! {
+            (DESC | DESCRIBE) ~ SECRET ~ #ident
+        },
+        |(_, _, name)| Statement::DescribeSecret(DescribeSecretStmt { name }),
+    );
+
+    let show_secrets = map(
+        rule! {
+            SHOW ~ SECRETS
+        },
+        |(_, _)| Statement::ShowSecrets(ShowSecretsStmt {}),
+    );
+
+    let flush_cache = map(
+        rule! {
+            ALTER ~ SYSTEM ~ FLUSH ~ CACHE ~ #cache_kind?
+        },
+        |(_, _, _, _, kind)| {
+            Statement::FlushCache(FlushCacheStmt {
+                kind: kind.unwrap_or(CacheKind::All),
+            })
+        },
+    );
+
     let call = map(
         rule! {
             CALL ~ #ident ~ "(" ~ #comma_separated_list0(parameter_to_string) ~ ")"
@@ -1645,7 +1846,7 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         },
     );
 
-    let statement_body = alt((
+    alt((
         rule!(
             #map(query, |query| Statement::Query(Box::new(query)))
             | #explain : "`EXPLAIN [PIPELINE | GRAPH] <statement>`"
@@ -1689,6 +1890,7 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             #show_tables : "`SHOW [FULL] TABLES [FROM <database>] [<show_limit>]`"
             | #show_columns : "`SHOW [FULL] COLUMNS FROM <table> [FROM|IN <catalog>.<database>] [<show_limit>]`"
             | #show_create_table : "`SHOW CREATE TABLE [<database>.]<table>`"
+            | #describe_query : "`DESCRIBE <query>`"
             | #describe_table : "`DESCRIBE [<database>.]<table>`"
             | #show_fields : "`SHOW FIELDS FROM [<database>.]<table>`"
             | #show_tables_status : "`SHOW TABLES STATUS [FROM <database>] [<show_limit>]`"
@@ -1795,15 +1997,26 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             #create_task : "`CREATE TASK [ IF NOT EXISTS ] <name>
   [ { WAREHOUSE = <string> }
   [ SCHEDULE = { <num> MINUTE | USING CRON <expr> <time_zone> } ]
+  [ AFTER <task> [ , <task> ... ] ]
   [ SUSPEND_TASK_AFTER_NUM_FAILURES = <num> ]
+  [ ERROR_INTEGRATION = <integration> ]
   [ COMMENT = '<string_literal>' ]
 AS
   <sql>`"
          | #drop_task : "`DROP TASK [ IF EXISTS ] <name>`"
-         | #alter_task : "`ALTER TASK [ IF EXISTS ] <name> SUSPEND | RESUME | SET <option> = <value>` | UNSET <option> | MODIFY AS <sql>`"
+         | #alter_task : "`ALTER TASK [ IF EXISTS ] <name> SUSPEND | RESUME | SET <option> = <value>` | UNSET <option> | MODIFY AS <sql> | { ADD | REMOVE } AFTER <task> [ , <task> ... ]`"
          | #show_tasks : "`SHOW TASKS [<show_limit>]`"
          | #desc_task : "`DESC | DESCRIBE TASK <name>`"
          | #execute_task: "`EXECUTE TASK <name>`"
+         | #execute_immediate: "`EXECUTE IMMEDIATE $$ <script> $$`"
+         | #create_notification : "`CREATE NOTIFICATION INTEGRATION [ IF NOT EXISTS ] <name>
+  TYPE = { WEBHOOK | EMAIL }
+  ENABLED = { TRUE | FALSE }
+  { WEBHOOK | EMAIL } = ( <key> = '<value>' [ , ... ] )
+  [ COMMENT = '<string_literal>' ]`"
+         | #alter_notification : "`ALTER NOTIFICATION INTEGRATION [ IF EXISTS ] <name> SET <option> = <value>`"
+         | #drop_notification : "`DROP NOTIFICATION INTEGRATION [ IF EXISTS ] <name>`"
+         | #desc_notification : "`DESC | DESCRIBE NOTIFICATION INTEGRATION <name>`"
         ),
         rule!(
             #create_pipe : "`CREATE PIPE [ IF NOT EXISTS ] <name>
@@ -1821,9 +2034,19 @@ AS
         | #drop_connection: "`DROP CONNECTION [IF EXISTS] <connection_name>`"
         | #desc_connection: "`DESC | DESCRIBE CONNECTION  <connection_name>`"
         | #show_connections: "`SHOW CONNECTIONS`"
+        | #create_sequence: "`CREATE SEQUENCE [IF NOT EXISTS] <sequence_name> [START [WITH] <value>] [INCREMENT [BY] <value>] [CACHE <value>] [COMMENT = '<comment>']`"
+        | #drop_sequence: "`DROP SEQUENCE [IF EXISTS] <sequence_name>`"
+        | #show_sequences: "`SHOW SEQUENCES`"
+        | #create_secret: "`CREATE SECRET [IF NOT EXISTS] <secret_name> PROVIDER = <provider> [CACHE_TTL = <seconds>] <provider_params>`"
+        | #drop_secret: "`DROP SECRET [IF EXISTS] <secret_name>`"
+        | #desc_secret: "`DESC | DESCRIBE SECRET <secret_name>`"
+        | #show_secrets: "`SHOW SECRETS`"
+        | #flush_cache: "`ALTER SYSTEM FLUSH CACHE [ALL | RESULT | PLAN | META | DATA]`"
         ),
-    ));
+    ))(i)
+}
 
+pub fn statement(i: Input) -> IResult<StatementWithFormat> {
     map(
         rule! {
             #statement_body ~ ( FORMAT ~ ^#ident )? ~ ";"? ~ &EOI
@@ -1980,6 +2203,8 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
         DefaultExpr(Box<Expr>),
         VirtualExpr(Box<Expr>),
         StoredExpr(Box<Expr>),
+        NextVal(Identifier),
+        AutoIncrement,
     }
 
     let nullable = alt((
@@ -1987,6 +2212,13 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
         value(ColumnConstraint::Nullable(false), rule! { NOT ~ ^NULL }),
     ));
     let expr = alt((
+        map(
+            rule! {
+                DEFAULT ~ NEXTVAL ~ ^"(" ~ ^#ident ~ ^")"
+            },
+            |(_, _, _, sequence, _)| ColumnConstraint::NextVal(sequence),
+        ),
+        value(ColumnConstraint::AutoIncrement, rule! { AUTO_INCREMENT }),
         map(
             rule! {
                 DEFAULT ~ ^#subexpr(NOT_PREC)
@@ -2007,6 +2239,13 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
         ),
     ));
 
+    let mask_policy = map(
+        rule! {
+            WITH? ~ MASKING ~ ^POLICY ~ ^#ident
+        },
+        |(_, _, _, mask_name)| mask_name.to_string(),
+    );
+
     let comment = map(
         rule! {
             COMMENT ~ #literal_string
@@ -2019,16 +2258,18 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
             #ident
             ~ #type_name
             ~ ( #nullable | #expr )*
+            ~ ( #mask_policy )?
             ~ ( #comment )?
-            : "`<column name> <type> [DEFAULT <expr>] [AS (<expr>) VIRTUAL] [AS (<expr>) STORED] [COMMENT '<comment>']`"
+            : "`<column name> <type> [DEFAULT <expr>] [AS (<expr>) VIRTUAL] [AS (<expr>) STORED] [MASKING POLICY <policy>] [COMMENT '<comment>']`"
         },
-        |(name, data_type, constraints, comment)| {
+        |(name, data_type, constraints, mask_policy, comment)| {
             let def = ColumnDefinition {
                 name,
                 data_type,
                 expr: None,
                 comment,
                 nullable_constraint: None,
+                mask_policy,
             };
             (def, constraints)
         },
@@ -2058,6 +2299,8 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
             ColumnConstraint::StoredExpr(stored_expr) => {
                 def.expr = Some(ColumnExpr::Stored(stored_expr))
             }
+            ColumnConstraint::NextVal(sequence) => def.expr = Some(ColumnExpr::NextVal(sequence)),
+            ColumnConstraint::AutoIncrement => def.expr = Some(ColumnExpr::AutoIncrement),
         }
     }
 
@@ -2330,11 +2573,31 @@ pub fn grant_option(i: Input) -> IResult<PrincipalIdentity> {
 }
 
 pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
+    #[derive(Clone)]
+    enum TableElement {
+        Column(ColumnDefinition),
+        Constraint(TableConstraintDefinition),
+    }
+
+    let element = alt((
+        map(rule! { #table_constraint_def }, TableElement::Constraint),
+        map(rule! { #column_def }, TableElement::Column),
+    ));
     let columns = map(
         rule! {
-            "(" ~ ^#comma_separated_list1(column_def) ~ ^")"
+            "(" ~ ^#comma_separated_list1(element) ~ ^")"
+        },
+        |(_, elements, _)| {
+            let mut columns = Vec::with_capacity(elements.len());
+            let mut constraints = vec![];
+            for element in elements {
+                match element {
+                    TableElement::Column(column) => columns.push(column),
+                    TableElement::Constraint(constraint) => constraints.push(constraint),
+                }
+            }
+            CreateTableSource::Columns(columns, constraints)
         },
-        |(_, columns, _)| CreateTableSource::Columns(columns),
     );
     let like = map(
         rule! {
@@ -2347,9 +2610,68 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
         },
     );
 
+    let clone = map(
+        rule! {
+            CLONE ~ #dot_separated_idents_1_to_3
+        },
+        |(_, (catalog, database, table))| CreateTableSource::Clone {
+            catalog,
+            database,
+            table,
+        },
+    );
+
     rule!(
         #columns
         | #like
+        | #clone
+    )(i)
+}
+
+pub fn table_constraint_def(i: Input) -> IResult<TableConstraintDefinition> {
+    let check = map(
+        rule! {
+            CHECK ~ "(" ~ ^#expr ~ ^")"
+        },
+        |(_, _, expr, _)| TableConstraintKind::Check(expr),
+    );
+    let unique = map(
+        rule! {
+            UNIQUE ~ "(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(_, _, columns, _)| TableConstraintKind::Unique(columns),
+    );
+    let primary_key = map(
+        rule! {
+            PRIMARY ~ KEY ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(_, _, _, columns, _)| TableConstraintKind::PrimaryKey(columns),
+    );
+
+    let foreign_key = map(
+        rule! {
+            FOREIGN ~ KEY ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
+            ~ ^REFERENCES ~ ^#dot_separated_idents_1_to_2
+            ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(_, _, _, columns, _, _, (ref_database, ref_table), _, ref_columns, _)| {
+            TableConstraintKind::ForeignKey {
+                columns,
+                ref_database,
+                ref_table,
+                ref_columns,
+            }
+        },
+    );
+
+    map(
+        rule! {
+            ( CONSTRAINT ~ #ident )? ~ ( #check | #unique | #primary_key | #foreign_key )
+        },
+        |(name, kind)| TableConstraintDefinition {
+            name: name.map(|(_, name)| name),
+            kind,
+        },
     )(i)
 }
 
@@ -2406,6 +2728,7 @@ pub fn modify_column_type(i: Input) -> IResult<ColumnDefinition> {
                 expr: None,
                 comment,
                 nullable_constraint: None,
+                mask_policy: None,
             };
             for constraint in constraints {
                 match constraint {
@@ -2550,6 +2873,27 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         |(_, _, _, set_options, _)| AlterTableAction::SetOptions { set_options },
     );
 
+    let promote_replica = map(
+        rule! {
+            PROMOTE ~ REPLICA
+        },
+        |(_, _)| AlterTableAction::PromoteReplica,
+    );
+
+    let add_constraint = map(
+        rule! {
+            ADD ~ #table_constraint_def
+        },
+        |(_, constraint)| AlterTableAction::AddConstraint { constraint },
+    );
+
+    let drop_constraint = map(
+        rule! {
+            DROP ~ CONSTRAINT ~ ^#ident
+        },
+        |(_, _, name)| AlterTableAction::DropConstraint { name },
+    );
+
     rule!(
         #rename_table
         | #rename_column
@@ -2561,6 +2905,9 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         | #recluster_table
         | #revert_table
         | #set_table_options
+        | #promote_replica
+        | #add_constraint
+        | #drop_constraint
     )(i)
 }
 
@@ -2761,13 +3108,17 @@ pub fn alter_task_option(i: Input) -> IResult<AlterTaskOptions> {
              ~ ( WAREHOUSE  ~ "=" ~  #literal_string )?
              ~ ( SCHEDULE ~ "=" ~ #task_schedule_option )?
              ~ ( SUSPEND_TASK_AFTER_NUM_FAILURES ~ "=" ~ #literal_u64 )?
+             ~ ( ERROR_INTEGRATION ~ "=" ~ #ident )?
              ~ ( COMMENT ~ "=" ~ #literal_string )?
         },
-        |(_, warehouse_opts, schedule_opts, suspend_opts, comment)| AlterTaskOptions::Set {
-            warehouse: warehouse_opts.map(|(_, _, warehouse)| warehouse),
-            schedule: schedule_opts.map(|(_, _, schedule)| schedule),
-            suspend_task_after_num_failures: suspend_opts.map(|(_, _, num)| num),
-            comments: comment.map(|(_, _, comment)| comment),
+        |(_, warehouse_opts, schedule_opts, suspend_opts, error_integration, comment)| {
+            AlterTaskOptions::Set {
+                warehouse: warehouse_opts.map(|(_, _, warehouse)| warehouse),
+                schedule: schedule_opts.map(|(_, _, schedule)| schedule),
+                suspend_task_after_num_failures: suspend_opts.map(|(_, _, num)| num),
+                error_integration: error_integration.map(|(_, _, name)| name.to_string()),
+                comments: comment.map(|(_, _, comment)| comment),
+            }
         },
     );
     let unset = map(
@@ -2776,12 +3127,65 @@ pub fn alter_task_option(i: Input) -> IResult<AlterTaskOptions> {
         },
         |_| AlterTaskOptions::Unset { warehouse: true },
     );
+    let add_after = map(
+        rule! {
+             ADD ~ AFTER ~ #comma_separated_list1(ident)
+        },
+        |(_, _, tasks)| {
+            AlterTaskOptions::AddAfter(tasks.iter().map(|task| task.to_string()).collect())
+        },
+    );
+    let remove_after = map(
+        rule! {
+             REMOVE ~ AFTER ~ #comma_separated_list1(ident)
+        },
+        |(_, _, tasks)| {
+            AlterTaskOptions::RemoveAfter(tasks.iter().map(|task| task.to_string()).collect())
+        },
+    );
     rule!(
         #suspend
         | #resume
         | #modify_as
         | #set
         | #unset
+        | #add_after
+        | #remove_after
+    )(i)
+}
+
+pub fn notification_type(i: Input) -> IResult<NotificationType> {
+    alt((
+        value(NotificationType::Webhook, rule! { WEBHOOK }),
+        value(NotificationType::Email, rule! { EMAIL }),
+    ))(i)
+}
+
+pub fn notification_options(i: Input) -> IResult<NotificationOptions> {
+    map(
+        rule! {
+            #notification_type ~ ^"=" ~ ^#connection_options
+        },
+        |(notification_type, _, options)| NotificationOptions {
+            notification_type,
+            options,
+        },
+    )(i)
+}
+
+pub fn alter_notification_option(i: Input) -> IResult<AlterNotificationOptions> {
+    map(
+        rule! {
+             SET
+             ~ ( ENABLED ~ "=" ~ #literal_bool )?
+             ~ #notification_options?
+             ~ ( COMMENT ~ "=" ~ #literal_string )?
+        },
+        |(_, enabled, options, comment)| AlterNotificationOptions::Set {
+            enabled: enabled.map(|(_, _, enabled)| enabled),
+            options,
+            comments: comment.map(|(_, _, comment)| comment),
+        },
     )(i)
 }
 
@@ -2856,6 +3260,16 @@ pub fn kill_target(i: Input) -> IResult<KillTarget> {
     ))(i)
 }
 
+pub fn cache_kind(i: Input) -> IResult<CacheKind> {
+    alt((
+        value(CacheKind::All, rule! { ALL }),
+        value(CacheKind::Result, rule! { RESULT }),
+        value(CacheKind::Plan, rule! { PLAN }),
+        value(CacheKind::Meta, rule! { META }),
+        value(CacheKind::Data, rule! { DATA }),
+    ))(i)
+}
+
 pub fn show_limit(i: Input) -> IResult<ShowLimit> {
     let limit_like = map(
         rule! {
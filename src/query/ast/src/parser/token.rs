@@ -151,6 +151,9 @@ pub enum TokenKind {
     #[regex(r#"'([^'\\]|\\.|'')*'"#)]
     QuotedString,
 
+    #[regex(r#"\$\$([^\$]|(\$[^\$]))*\$\$"#)]
+    LiteralCodeString,
+
     #[regex(r#"@([^\s`;'"()]|\\\s|\\'|\\"|\\\\)+"#)]
     AtString,
 
@@ -322,6 +325,8 @@ pub enum TokenKind {
     ARGS,
     #[token("AUTO", ignore(ascii_case))]
     AUTO,
+    #[token("AUTO_INCREMENT", ignore(ascii_case))]
+    AUTO_INCREMENT,
     #[token("SOME", ignore(ascii_case))]
     SOME,
     #[token("ALTER", ignore(ascii_case))]
@@ -364,12 +369,22 @@ pub enum TokenKind {
     BOTH,
     #[token("BY", ignore(ascii_case))]
     BY,
+    #[token("BEGIN", ignore(ascii_case))]
+    BEGIN,
+    #[token("BREAK", ignore(ascii_case))]
+    BREAK,
     #[token("BROTLI", ignore(ascii_case))]
     BROTLI,
     #[token("BZ2", ignore(ascii_case))]
     BZ2,
+    #[token("CACHE", ignore(ascii_case))]
+    CACHE,
+    #[token("CACHE_TTL", ignore(ascii_case))]
+    CACHE_TTL,
     #[token("CALL", ignore(ascii_case))]
     CALL,
+    #[token("CASCADE", ignore(ascii_case))]
+    CASCADE,
     #[token("CASE", ignore(ascii_case))]
     CASE,
     #[token("CAST", ignore(ascii_case))]
@@ -380,6 +395,10 @@ pub enum TokenKind {
     CATALOGS,
     #[token("CENTURY", ignore(ascii_case))]
     CENTURY,
+    #[token("CHECK", ignore(ascii_case))]
+    CHECK,
+    #[token("CLONE", ignore(ascii_case))]
+    CLONE,
     #[token("CLUSTER", ignore(ascii_case))]
     CLUSTER,
     #[token("COMMENT", ignore(ascii_case))]
@@ -392,14 +411,22 @@ pub enum TokenKind {
     CONNECTION,
     #[token("CONNECTIONS", ignore(ascii_case))]
     CONNECTIONS,
+    #[token("CONSTRAINT", ignore(ascii_case))]
+    CONSTRAINT,
     #[token("CONTENT_TYPE", ignore(ascii_case))]
     CONTENT_TYPE,
+    #[token("CONTINUE", ignore(ascii_case))]
+    CONTINUE,
     #[token("CHAR", ignore(ascii_case))]
     CHAR,
     #[token("COLUMN", ignore(ascii_case))]
     COLUMN,
     #[token("COLUMNS", ignore(ascii_case))]
     COLUMNS,
+    #[token("COLUMN_PATHS", ignore(ascii_case))]
+    COLUMN_PATHS,
+    #[token("COLUMN_FORMATS", ignore(ascii_case))]
+    COLUMN_FORMATS,
     #[token("CHARACTER", ignore(ascii_case))]
     CHARACTER,
     #[token("CONFLICT", ignore(ascii_case))]
@@ -450,6 +477,8 @@ pub enum TokenKind {
     DECADE,
     #[token("DECIMAL", ignore(ascii_case))]
     DECIMAL,
+    #[token("DECLARE", ignore(ascii_case))]
+    DECLARE,
     #[token("DEFAULT", ignore(ascii_case))]
     DEFAULT,
     #[token("DEFLATE", ignore(ascii_case))]
@@ -460,10 +489,14 @@ pub enum TokenKind {
     DESC,
     #[token("DESCRIBE", ignore(ascii_case))]
     DESCRIBE,
+    #[token("DESCRIPTOR_SET", ignore(ascii_case))]
+    DESCRIPTOR_SET,
     #[token("DISABLE_VARIANT_CHECK", ignore(ascii_case))]
     DISABLE_VARIANT_CHECK,
     #[token("DISTINCT", ignore(ascii_case))]
     DISTINCT,
+    #[token("DO", ignore(ascii_case))]
+    DO,
     #[token("DIV", ignore(ascii_case))]
     DIV,
     #[token("DOUBLE_SHA1_PASSWORD", ignore(ascii_case))]
@@ -484,12 +517,20 @@ pub enum TokenKind {
     DRY,
     #[token("EXCEPT", ignore(ascii_case))]
     EXCEPT,
+    #[token("EXCEPTION", ignore(ascii_case))]
+    EXCEPTION,
     #[token("EXCLUDE", ignore(ascii_case))]
     EXCLUDE,
     #[token("ELSE", ignore(ascii_case))]
     ELSE,
+    #[token("ELSEIF", ignore(ascii_case))]
+    ELSEIF,
+    #[token("EMAIL", ignore(ascii_case))]
+    EMAIL,
     #[token("ENABLE_VIRTUAL_HOST_STYLE", ignore(ascii_case))]
     ENABLE_VIRTUAL_HOST_STYLE,
+    #[token("ENABLED", ignore(ascii_case))]
+    ENABLED,
     #[token("END", ignore(ascii_case))]
     END,
     #[token("ENDPOINT", ignore(ascii_case))]
@@ -500,6 +541,8 @@ pub enum TokenKind {
     ENGINES,
     #[token("EPOCH", ignore(ascii_case))]
     EPOCH,
+    #[token("ERROR_INTEGRATION", ignore(ascii_case))]
+    ERROR_INTEGRATION,
     #[token("ERROR_ON_COLUMN_COUNT_MISMATCH", ignore(ascii_case))]
     ERROR_ON_COLUMN_COUNT_MISMATCH,
     #[token("ESCAPE", ignore(ascii_case))]
@@ -514,6 +557,8 @@ pub enum TokenKind {
     EXTRACT,
     #[token("FALSE", ignore(ascii_case))]
     FALSE,
+    #[token("FETCH", ignore(ascii_case))]
+    FETCH,
     #[token("FIELDS", ignore(ascii_case))]
     FIELDS,
     #[token("FIELD_DELIMITER", ignore(ascii_case))]
@@ -522,8 +567,12 @@ pub enum TokenKind {
     NAN_DISPLAY,
     #[token("NULL_DISPLAY", ignore(ascii_case))]
     NULL_DISPLAY,
+    #[token("FILL", ignore(ascii_case))]
+    FILL,
     #[token("FILE_FORMAT", ignore(ascii_case))]
     FILE_FORMAT,
+    #[token("FILE_FORMAT_OVERRIDES", ignore(ascii_case))]
+    FILE_FORMAT_OVERRIDES,
     #[token("FILE", ignore(ascii_case))]
     FILE,
     #[token("FILES", ignore(ascii_case))]
@@ -532,6 +581,8 @@ pub enum TokenKind {
     FINAL,
     #[token("FLASHBACK", ignore(ascii_case))]
     FLASHBACK,
+    #[token("FLUSH", ignore(ascii_case))]
+    FLUSH,
     #[token("FLOAT", ignore(ascii_case))]
     FLOAT,
     #[token("FLOAT32", ignore(ascii_case))]
@@ -542,6 +593,8 @@ pub enum TokenKind {
     FOR,
     #[token("FORCE", ignore(ascii_case))]
     FORCE,
+    #[token("FOREIGN", ignore(ascii_case))]
+    FOREIGN,
     #[token("FORMAT", ignore(ascii_case))]
     FORMAT,
     #[token("FOLLOWING", ignore(ascii_case))]
@@ -566,8 +619,12 @@ pub enum TokenKind {
     SET_VAR,
     #[token("FUSE", ignore(ascii_case))]
     FUSE,
+    #[token("GAP_FILL", ignore(ascii_case))]
+    GAP_FILL,
     #[token("GENERATED", ignore(ascii_case))]
     GENERATED,
+    #[token("GEOMETRY", ignore(ascii_case))]
+    GEOMETRY,
     #[token("GLOBAL", ignore(ascii_case))]
     GLOBAL,
     #[token("GRAPH", ignore(ascii_case))]
@@ -594,8 +651,12 @@ pub enum TokenKind {
     IDENTIFIED,
     #[token("IF", ignore(ascii_case))]
     IF,
+    #[token("IGNORE", ignore(ascii_case))]
+    IGNORE,
     #[token("IN", ignore(ascii_case))]
     IN,
+    #[token("INCREMENT", ignore(ascii_case))]
+    INCREMENT,
     #[token("INDEX", ignore(ascii_case))]
     INDEX,
     #[token("INNER", ignore(ascii_case))]
@@ -604,6 +665,8 @@ pub enum TokenKind {
     INSERT,
     #[token("INT", ignore(ascii_case))]
     INT,
+    #[token("INT128", ignore(ascii_case))]
+    INT128,
     #[token("INT16", ignore(ascii_case))]
     INT16,
     #[token("INT32", ignore(ascii_case))]
@@ -614,6 +677,8 @@ pub enum TokenKind {
     INT8,
     #[token("INTEGER", ignore(ascii_case))]
     INTEGER,
+    #[token("INTEGRATION", ignore(ascii_case))]
+    INTEGRATION,
     #[token("INTERVAL", ignore(ascii_case))]
     INTERVAL,
     #[token("INTO", ignore(ascii_case))]
@@ -640,6 +705,8 @@ pub enum TokenKind {
     LATERAL,
     #[token("LOCATION_PREFIX", ignore(ascii_case))]
     LOCATION_PREFIX,
+    #[token("LOOP", ignore(ascii_case))]
+    LOOP,
     #[token("SECONDARY", ignore(ascii_case))]
     SECONDARY,
     #[token("ROLES", ignore(ascii_case))]
@@ -651,12 +718,20 @@ pub enum TokenKind {
     LEADING,
     #[token("LEFT", ignore(ascii_case))]
     LEFT,
+    #[token("LET", ignore(ascii_case))]
+    LET,
+    #[token("LINEAGE", ignore(ascii_case))]
+    LINEAGE,
+    #[token("LINEAR", ignore(ascii_case))]
+    LINEAR,
     #[token("LIKE", ignore(ascii_case))]
     LIKE,
     #[token("LIMIT", ignore(ascii_case))]
     LIMIT,
     #[token("LIST", ignore(ascii_case))]
     LIST,
+    #[token("LOCF", ignore(ascii_case))]
+    LOCF,
     #[token("LZO", ignore(ascii_case))]
     LZO,
     #[token("MASKING", ignore(ascii_case))]
@@ -671,6 +746,10 @@ pub enum TokenKind {
     MEMO,
     #[token("MEMORY", ignore(ascii_case))]
     MEMORY,
+    #[token("META", ignore(ascii_case))]
+    META,
+    #[token("MESSAGE_NAME", ignore(ascii_case))]
+    MESSAGE_NAME,
     #[token("METRICS", ignore(ascii_case))]
     METRICS,
     #[token("MICROSECONDS", ignore(ascii_case))]
@@ -693,14 +772,22 @@ pub enum TokenKind {
     NATURAL,
     #[token("NETWORK", ignore(ascii_case))]
     NETWORK,
+    #[token("NEXT", ignore(ascii_case))]
+    NEXT,
+    #[token("NEXTVAL", ignore(ascii_case))]
+    NEXTVAL,
     #[token("NDJSON", ignore(ascii_case))]
     NDJSON,
+    #[token("NO", ignore(ascii_case))]
+    NO,
     #[token("NO_PASSWORD", ignore(ascii_case))]
     NO_PASSWORD,
     #[token("NONE", ignore(ascii_case))]
     NONE,
     #[token("NOT", ignore(ascii_case))]
     NOT,
+    #[token("NOTIFICATION", ignore(ascii_case))]
+    NOTIFICATION,
     #[token("NOTENANTSETTING", ignore(ascii_case))]
     NOTENANTSETTING,
     #[token("NULL", ignore(ascii_case))]
@@ -715,6 +802,8 @@ pub enum TokenKind {
     OFFSET,
     #[token("ON", ignore(ascii_case))]
     ON,
+    #[token("ONLY", ignore(ascii_case))]
+    ONLY,
     #[token("OPTIMIZE", ignore(ascii_case))]
     OPTIMIZE,
     #[token("OPTIONS", ignore(ascii_case))]
@@ -725,6 +814,10 @@ pub enum TokenKind {
     ORDER,
     #[token("OUTER", ignore(ascii_case))]
     OUTER,
+    #[token("OTHER", ignore(ascii_case))]
+    OTHER,
+    #[token("OTHERS", ignore(ascii_case))]
+    OTHERS,
     #[token("ON_ERROR", ignore(ascii_case))]
     ON_ERROR,
     #[token("OVER", ignore(ascii_case))]
@@ -739,6 +832,8 @@ pub enum TokenKind {
     PATTERN,
     #[token("PIPELINE", ignore(ascii_case))]
     PIPELINE,
+    #[token("PLAN", ignore(ascii_case))]
+    PLAN,
     #[token("PLAINTEXT_PASSWORD", ignore(ascii_case))]
     PLAINTEXT_PASSWORD,
     #[token("POLICIES", ignore(ascii_case))]
@@ -749,6 +844,12 @@ pub enum TokenKind {
     POSITION,
     #[token("PROCESSLIST", ignore(ascii_case))]
     PROCESSLIST,
+    #[token("PROMOTE", ignore(ascii_case))]
+    PROMOTE,
+    #[token("PROTOBUF", ignore(ascii_case))]
+    PROTOBUF,
+    #[token("PRUNE", ignore(ascii_case))]
+    PRUNE,
     #[token("PURGE", ignore(ascii_case))]
     PURGE,
     #[token("QUARTER", ignore(ascii_case))]
@@ -769,6 +870,8 @@ pub enum TokenKind {
     RECORD_DELIMITER,
     #[token("REFERENCE_USAGE", ignore(ascii_case))]
     REFERENCE_USAGE,
+    #[token("REFERENCES", ignore(ascii_case))]
+    REFERENCES,
     #[token("REFRESH", ignore(ascii_case))]
     REFRESH,
     #[token("REGEXP", ignore(ascii_case))]
@@ -777,6 +880,8 @@ pub enum TokenKind {
     RENAME,
     #[token("REPLACE", ignore(ascii_case))]
     REPLACE,
+    #[token("REPLICA", ignore(ascii_case))]
+    REPLICA,
     #[token("RETURN_FAILED_ONLY", ignore(ascii_case))]
     RETURN_FAILED_ONLY,
     #[token("MERGE", ignore(ascii_case))]
@@ -793,6 +898,8 @@ pub enum TokenKind {
     ROW,
     #[token("ROWS", ignore(ascii_case))]
     ROWS,
+    #[token("ROW_PATH", ignore(ascii_case))]
+    ROW_PATH,
     #[token("ROW_TAG", ignore(ascii_case))]
     ROW_TAG,
     #[token("GRANT", ignore(ascii_case))]
@@ -805,8 +912,12 @@ pub enum TokenKind {
     PRECISION,
     #[token("PRESIGN", ignore(ascii_case))]
     PRESIGN,
+    #[token("PRIMARY", ignore(ascii_case))]
+    PRIMARY,
     #[token("PRIVILEGES", ignore(ascii_case))]
     PRIVILEGES,
+    #[token("PROVIDER", ignore(ascii_case))]
+    PROVIDER,
     #[token("QUALIFY", ignore(ascii_case))]
     QUALIFY,
     #[token("REMOVE", ignore(ascii_case))]
@@ -815,8 +926,14 @@ pub enum TokenKind {
     RETAIN,
     #[token("REVOKE", ignore(ascii_case))]
     REVOKE,
+    #[token("REVERSE", ignore(ascii_case))]
+    REVERSE,
     #[token("RECURSIVE", ignore(ascii_case))]
     RECURSIVE,
+    #[token("RESPECT", ignore(ascii_case))]
+    RESPECT,
+    #[token("RESULT", ignore(ascii_case))]
+    RESULT,
     #[token("RETURN", ignore(ascii_case))]
     RETURN,
     #[token("RETURNS", ignore(ascii_case))]
@@ -839,6 +956,10 @@ pub enum TokenKind {
     SCHEMAS,
     #[token("SECOND", ignore(ascii_case))]
     SECOND,
+    #[token("SECRET", ignore(ascii_case))]
+    SECRET,
+    #[token("SECRETS", ignore(ascii_case))]
+    SECRETS,
     #[token("SELECT", ignore(ascii_case))]
     SELECT,
     #[token("PIVOT", ignore(ascii_case))]
@@ -847,6 +968,10 @@ pub enum TokenKind {
     UNPIVOT,
     #[token("SEGMENT", ignore(ascii_case))]
     SEGMENT,
+    #[token("SEQUENCE", ignore(ascii_case))]
+    SEQUENCE,
+    #[token("SEQUENCES", ignore(ascii_case))]
+    SEQUENCES,
     #[token("SET", ignore(ascii_case))]
     SET,
     #[token("UNSET", ignore(ascii_case))]
@@ -881,6 +1006,10 @@ pub enum TokenKind {
     SPLIT_SIZE,
     #[token("STAGE", ignore(ascii_case))]
     STAGE,
+    #[token("START", ignore(ascii_case))]
+    START,
+    #[token("SYSTEM", ignore(ascii_case))]
+    SYSTEM,
     #[token("SYNTAX", ignore(ascii_case))]
     SYNTAX,
     #[token("USAGE", ignore(ascii_case))]
@@ -893,6 +1022,8 @@ pub enum TokenKind {
     SHARE,
     #[token("SHARES", ignore(ascii_case))]
     SHARES,
+    #[token("SHEET_NAME", ignore(ascii_case))]
+    SHEET_NAME,
     #[token("SUPER", ignore(ascii_case))]
     SUPER,
     #[token("STATUS", ignore(ascii_case))]
@@ -931,6 +1062,8 @@ pub enum TokenKind {
     TENANT,
     #[token("THEN", ignore(ascii_case))]
     THEN,
+    #[token("TIES", ignore(ascii_case))]
+    TIES,
     #[token("TIMESTAMP", ignore(ascii_case))]
     TIMESTAMP,
     #[token("TIMEZONE_HOUR", ignore(ascii_case))]
@@ -967,6 +1100,8 @@ pub enum TokenKind {
     UNBOUNDED,
     #[token("UNION", ignore(ascii_case))]
     UNION,
+    #[token("UINT128", ignore(ascii_case))]
+    UINT128,
     #[token("UINT16", ignore(ascii_case))]
     UINT16,
     #[token("UINT32", ignore(ascii_case))]
@@ -977,6 +1112,8 @@ pub enum TokenKind {
     UINT8,
     #[token("UNDROP", ignore(ascii_case))]
     UNDROP,
+    #[token("UNIQUE", ignore(ascii_case))]
+    UNIQUE,
     #[token("UNSIGNED", ignore(ascii_case))]
     UNSIGNED,
     #[token("URL", ignore(ascii_case))]
@@ -1005,16 +1142,22 @@ pub enum TokenKind {
     VIEW,
     #[token("VIRTUAL", ignore(ascii_case))]
     VIRTUAL,
+    #[token("WEBHOOK", ignore(ascii_case))]
+    WEBHOOK,
     #[token("WHEN", ignore(ascii_case))]
     WHEN,
     #[token("WHERE", ignore(ascii_case))]
     WHERE,
+    #[token("WHILE", ignore(ascii_case))]
+    WHILE,
     #[token("WINDOW", ignore(ascii_case))]
     WINDOW,
     #[token("WITH", ignore(ascii_case))]
     WITH,
     #[token("XML", ignore(ascii_case))]
     XML,
+    #[token("XLSX", ignore(ascii_case))]
+    XLSX,
     #[token("XOR", ignore(ascii_case))]
     XOR,
     #[token("XZ", ignore(ascii_case))]
@@ -1077,6 +1220,8 @@ pub enum TokenKind {
     CRON,
     #[token("EXECUTE", ignore(ascii_case))]
     EXECUTE,
+    #[token("IMMEDIATE", ignore(ascii_case))]
+    IMMEDIATE,
     #[token("SUSPEND", ignore(ascii_case))]
     SUSPEND,
     #[token("RESUME", ignore(ascii_case))]
@@ -1098,7 +1243,12 @@ impl TokenKind {
     pub fn is_literal(&self) -> bool {
         matches!(
             self,
-            LiteralInteger | LiteralFloat | QuotedString | PGLiteralHex | MySQLLiteralHex
+            LiteralInteger
+                | LiteralFloat
+                | QuotedString
+                | LiteralCodeString
+                | PGLiteralHex
+                | MySQLLiteralHex
         )
     }
 
@@ -1107,6 +1257,7 @@ impl TokenKind {
             self,
             Ident
                 | QuotedString
+                | LiteralCodeString
                 | PGLiteralHex
                 | MySQLLiteralHex
                 | LiteralInteger
@@ -1202,7 +1353,7 @@ impl TokenKind {
             // | TokenKind::DEFERRABLE
             | TokenKind::DESC
             | TokenKind::DISTINCT
-            // | TokenKind::DO
+            | TokenKind::DO
             | TokenKind::ELSE
             | TokenKind::END
             | TokenKind::EXISTS
@@ -1289,7 +1440,7 @@ impl TokenKind {
             | TokenKind::CREATE
             | TokenKind::ATTACH
             | TokenKind::EXCEPT
-            // | TokenKind::FETCH
+            | TokenKind::FETCH
             | TokenKind::FOR
             | TokenKind::FROM
             // | TokenKind::GRANT
@@ -1304,6 +1455,7 @@ impl TokenKind {
             | TokenKind::ORDER
             | TokenKind::OVER
             | TokenKind::QUALIFY
+            | TokenKind::GAP_FILL
             | TokenKind::ROWS
             // | TokenKind::PRECISION
             // | TokenKind::RETURNING
@@ -1352,7 +1504,7 @@ impl TokenKind {
             // | TokenKind::DEFERRABLE
             | TokenKind::DESC
             | TokenKind::DISTINCT
-            // | TokenKind::DO
+            | TokenKind::DO
             | TokenKind::ELSE
             | TokenKind::END
             | TokenKind::FALSE
@@ -1406,7 +1558,7 @@ impl TokenKind {
             | TokenKind::CREATE
             | TokenKind::ATTACH
             | TokenKind::EXCEPT
-            // | TokenKind::FETCH
+            | TokenKind::FETCH
             | TokenKind::FOR
             | TokenKind::FROM
             // | TokenKind::GRANT
@@ -1425,6 +1577,7 @@ impl TokenKind {
             | TokenKind::OVER
             | TokenKind::PARTITION
             | TokenKind::QUALIFY
+            | TokenKind::GAP_FILL
             | TokenKind::ROWS
             | TokenKind::RANGE
             // | TokenKind::OVERLAPS
@@ -1442,6 +1595,7 @@ impl TokenKind {
             | TokenKind::POLICY
             | TokenKind::TASK
             | TokenKind::PIPE
+            | TokenKind::NOTIFICATION
             | TokenKind::STREAM
             if !after_as => true,
             _ => false
@@ -242,7 +242,7 @@ pub trait Visitor<'ast>: Sized {
         _name: &'ast Identifier,
         args: &'ast [Expr],
         _params: &'ast [Literal],
-        over: &'ast Option<Window>,
+        over: &'ast Option<WindowDesc>,
         lambda: &'ast Option<Lambda>,
     ) {
         for arg in args {
@@ -250,7 +250,7 @@ pub trait Visitor<'ast>: Sized {
         }
 
         if let Some(over) = over {
-            self.visit_window(over);
+            self.visit_window(&over.window);
         }
         if let Some(lambda) = lambda {
             walk_expr(self, &lambda.expr)
@@ -616,6 +616,16 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_alter_task(&mut self, _stmt: &'ast AlterTaskStmt) {}
 
+    fn visit_create_notification(&mut self, _stmt: &'ast CreateNotificationStmt) {}
+
+    fn visit_alter_notification(&mut self, _stmt: &'ast AlterNotificationStmt) {}
+
+    fn visit_drop_notification(&mut self, _stmt: &'ast DropNotificationStmt) {}
+
+    fn visit_describe_notification(&mut self, _stmt: &'ast DescribeNotificationStmt) {}
+
+    fn visit_execute_immediate(&mut self, _stmt: &'ast ExecuteImmediateStmt) {}
+
     fn visit_with(&mut self, with: &'ast With) {
         let With { ctes, .. } = with;
         for cte in ctes.iter() {
@@ -648,6 +658,7 @@ pub trait Visitor<'ast>: Sized {
             having,
             window_list,
             qualify,
+            gap_fill,
             ..
         } = stmt;
 
@@ -692,6 +703,14 @@ pub trait Visitor<'ast>: Sized {
         if let Some(qualify) = qualify {
             walk_expr(self, qualify);
         }
+
+        if let Some(gap_fill) = gap_fill {
+            walk_expr(self, &gap_fill.interval);
+            if let Some((start, end)) = &gap_fill.range {
+                walk_expr(self, start);
+                walk_expr(self, end);
+            }
+        }
     }
 
     fn visit_select_target(&mut self, target: &'ast SelectTarget) {
@@ -731,4 +750,15 @@ pub trait Visitor<'ast>: Sized {
     fn visit_drop_connection(&mut self, _stmt: &'ast DropConnectionStmt) {}
     fn visit_describe_connection(&mut self, _stmt: &'ast DescribeConnectionStmt) {}
     fn visit_show_connections(&mut self, _stmt: &'ast ShowConnectionsStmt) {}
+
+    fn visit_create_sequence(&mut self, _stmt: &'ast CreateSequenceStmt) {}
+    fn visit_drop_sequence(&mut self, _stmt: &'ast DropSequenceStmt) {}
+    fn visit_show_sequences(&mut self, _stmt: &'ast ShowSequencesStmt) {}
+
+    fn visit_create_secret(&mut self, _stmt: &'ast CreateSecretStmt) {}
+    fn visit_drop_secret(&mut self, _stmt: &'ast DropSecretStmt) {}
+    fn visit_describe_secret(&mut self, _stmt: &'ast DescribeSecretStmt) {}
+    fn visit_show_secrets(&mut self, _stmt: &'ast ShowSecretsStmt) {}
+
+    fn visit_flush_cache(&mut self, _stmt: &'ast FlushCacheStmt) {}
 }
@@ -257,7 +257,7 @@ pub trait VisitorMut: Sized {
         _name: &mut Identifier,
         args: &mut Vec<Expr>,
         _params: &mut Vec<Literal>,
-        over: &mut Option<Window>,
+        over: &mut Option<WindowDesc>,
         lambda: &mut Option<Lambda>,
     ) {
         for arg in args.iter_mut() {
@@ -265,7 +265,7 @@ pub trait VisitorMut: Sized {
         }
 
         if let Some(over) = over {
-            match over {
+            match &mut over.window {
                 Window::WindowReference(reference) => {
                     self.visit_identifier(&mut reference.window_name);
                 }
@@ -630,6 +630,16 @@ pub trait VisitorMut: Sized {
 
     fn visit_alter_task(&mut self, _stmt: &mut AlterTaskStmt) {}
 
+    fn visit_create_notification(&mut self, _stmt: &mut CreateNotificationStmt) {}
+
+    fn visit_alter_notification(&mut self, _stmt: &mut AlterNotificationStmt) {}
+
+    fn visit_drop_notification(&mut self, _stmt: &mut DropNotificationStmt) {}
+
+    fn visit_describe_notification(&mut self, _stmt: &mut DescribeNotificationStmt) {}
+
+    fn visit_execute_immediate(&mut self, _stmt: &mut ExecuteImmediateStmt) {}
+
     fn visit_with(&mut self, with: &mut With) {
         let With { ctes, .. } = with;
         for cte in ctes.iter_mut() {
@@ -662,6 +672,7 @@ pub trait VisitorMut: Sized {
             having,
             window_list,
             qualify,
+            gap_fill,
             ..
         } = stmt;
 
@@ -706,6 +717,14 @@ pub trait VisitorMut: Sized {
         if let Some(qualify) = qualify {
             Self::visit_expr(self, qualify);
         }
+
+        if let Some(gap_fill) = gap_fill {
+            Self::visit_expr(self, &mut gap_fill.interval);
+            if let Some((start, end)) = &mut gap_fill.range {
+                Self::visit_expr(self, start);
+                Self::visit_expr(self, end);
+            }
+        }
     }
 
     fn visit_select_target(&mut self, target: &mut SelectTarget) {
@@ -742,4 +761,15 @@ pub trait VisitorMut: Sized {
     fn visit_drop_connection(&mut self, _stmt: &mut DropConnectionStmt) {}
     fn visit_describe_connection(&mut self, _stmt: &mut DescribeConnectionStmt) {}
     fn visit_show_connections(&mut self, _stmt: &mut ShowConnectionsStmt) {}
+
+    fn visit_create_sequence(&mut self, _stmt: &mut CreateSequenceStmt) {}
+    fn visit_drop_sequence(&mut self, _stmt: &mut DropSequenceStmt) {}
+    fn visit_show_sequences(&mut self, _stmt: &mut ShowSequencesStmt) {}
+
+    fn visit_create_secret(&mut self, _stmt: &mut CreateSecretStmt) {}
+    fn visit_drop_secret(&mut self, _stmt: &mut DropSecretStmt) {}
+    fn visit_describe_secret(&mut self, _stmt: &mut DescribeSecretStmt) {}
+    fn visit_show_secrets(&mut self, _stmt: &mut ShowSecretsStmt) {}
+
+    fn visit_flush_cache(&mut self, _stmt: &mut FlushCacheStmt) {}
 }
@@ -153,6 +153,7 @@ pub fn walk_query<'a, V: Visitor<'a>>(visitor: &mut V, query: &'a Query) {
         with,
         body,
         order_by,
+        limit_by,
         limit,
         offset,
         ..
@@ -165,6 +166,12 @@ pub fn walk_query<'a, V: Visitor<'a>>(visitor: &mut V, query: &'a Query) {
     for order_by in order_by {
         visitor.visit_order_by(order_by);
     }
+    if let Some(limit_by) = limit_by {
+        visitor.visit_expr(&limit_by.limit);
+        for expr in &limit_by.by {
+            visitor.visit_expr(expr);
+        }
+    }
     for limit in limit {
         visitor.visit_expr(limit);
     }
@@ -359,6 +366,7 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
     match statement {
         Statement::Explain { kind, query } => visitor.visit_explain(kind, query),
         Statement::ExplainAnalyze { query } => visitor.visit_statement(query),
+        Statement::DescribeQuery { query } => visitor.visit_query(query),
         Statement::Query(query) => visitor.visit_query(query),
         Statement::Insert(insert) => visitor.visit_insert(insert),
         Statement::Replace(replace) => visitor.visit_replace(replace),
@@ -508,10 +516,23 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
         Statement::AlterTask(stmt) => visitor.visit_alter_task(stmt),
         Statement::ShowTasks(stmt) => visitor.visit_show_tasks(stmt),
         Statement::DescribeTask(stmt) => visitor.visit_describe_task(stmt),
+        Statement::CreateNotification(stmt) => visitor.visit_create_notification(stmt),
+        Statement::AlterNotification(stmt) => visitor.visit_alter_notification(stmt),
+        Statement::DropNotification(stmt) => visitor.visit_drop_notification(stmt),
+        Statement::DescribeNotification(stmt) => visitor.visit_describe_notification(stmt),
+        Statement::ExecuteImmediate(stmt) => visitor.visit_execute_immediate(stmt),
         Statement::CreateConnection(stmt) => visitor.visit_create_connection(stmt),
         Statement::DropConnection(stmt) => visitor.visit_drop_connection(stmt),
         Statement::DescribeConnection(stmt) => visitor.visit_describe_connection(stmt),
         Statement::ShowConnections(stmt) => visitor.visit_show_connections(stmt),
+        Statement::CreateSequence(stmt) => visitor.visit_create_sequence(stmt),
+        Statement::DropSequence(stmt) => visitor.visit_drop_sequence(stmt),
+        Statement::ShowSequences(stmt) => visitor.visit_show_sequences(stmt),
+        Statement::CreateSecret(stmt) => visitor.visit_create_secret(stmt),
+        Statement::DropSecret(stmt) => visitor.visit_drop_secret(stmt),
+        Statement::DescribeSecret(stmt) => visitor.visit_describe_secret(stmt),
+        Statement::ShowSecrets(stmt) => visitor.visit_show_secrets(stmt),
+        Statement::FlushCache(stmt) => visitor.visit_flush_cache(stmt),
         Statement::CreatePipe(_) => todo!(),
         Statement::AlterPipe(_) => todo!(),
         Statement::DropPipe(_) => todo!(),
@@ -97,6 +97,10 @@ fn test_statement() {
         r#"show create table a.b format TabSeparatedWithNamesAndTypes;"#,
         r#"explain pipeline select a from b;"#,
         r#"explain pipeline select a from t1 ignore_result;"#,
+        r#"explain snapshot select a from b;"#,
+        r#"explain prune select a from b;"#,
+        r#"explain lineage select a from b;"#,
+        r#"describe select a from b;"#,
         r#"describe a;"#,
         r#"describe a format TabSeparatedWithNamesAndTypes;"#,
         r#"create table a (c decimal(38, 0))"#,
@@ -107,6 +111,7 @@ fn test_statement() {
         r#"create table if not exists a.b (a string, b string, c string as (concat(a, ' ', b)) stored );"#,
         r#"create table if not exists a.b (a int, b int, c int generated always as (a + b) virtual );"#,
         r#"create table a.b like c.d;"#,
+        r#"create table a.b clone c.d;"#,
         r#"create table t like t2 engine = memory;"#,
         r#"create table if not exists a.b (a int) 's3://testbucket/admin/data/' connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='http://127.0.0.1:9900');"#,
         r#"create table if not exists a.b (a int) 's3://testbucket/admin/data/'
@@ -124,6 +129,8 @@ fn test_statement() {
         r#"create database t FROM SHARE a.s;"#,
         r#"drop database ctl.t;"#,
         r#"drop database if exists t;"#,
+        r#"drop database if exists t cascade;"#,
+        r#"drop database t cascade dry run;"#,
         r#"create table c(a DateTime null, b DateTime(3));"#,
         r#"create view v as select number % 3 as a from numbers(1000);"#,
         r#"alter view v as select number % 3 as a from numbers(1000);"#,
@@ -221,6 +228,11 @@ fn test_statement() {
         r#"ALTER TABLE t MODIFY COLUMN a int;"#,
         r#"ALTER TABLE t MODIFY COLUMN a DROP STORED;"#,
         r#"ALTER TABLE t SET OPTIONS(SNAPSHOT_LOCATION='1/7/_ss/101fd790dbbe4238a31a8f2e2f856179_v4.mpk',block_per_segment = 500);"#,
+        r#"ALTER TABLE t PROMOTE REPLICA;"#,
+        r#"CREATE TABLE t (a INT NOT NULL, b INT, CONSTRAINT c1 CHECK (a > 0), UNIQUE (b), PRIMARY KEY (a));"#,
+        r#"ALTER TABLE t ADD CONSTRAINT c1 CHECK (a > b);"#,
+        r#"ALTER TABLE t ADD CONSTRAINT fk FOREIGN KEY (a, b) REFERENCES db.t2 (x, y);"#,
+        r#"ALTER TABLE t DROP CONSTRAINT c1;"#,
         r#"ALTER DATABASE IF EXISTS ctl.c RENAME TO a;"#,
         r#"ALTER DATABASE c RENAME TO a;"#,
         r#"ALTER DATABASE ctl.c RENAME TO a;"#,
@@ -337,6 +349,11 @@ fn test_statement() {
                     error_on_column_count_mismatch = FALSE
                 )
                 size_limit=10;"#,
+        r#"COPY INTO mytable
+                FROM @my_stage
+                FILE_FORMAT = (type = CSV)
+                FILE_FORMAT_OVERRIDES = ('.*[.]psv' = (type = CSV, field_delimiter = '|'))
+                COLUMN_FORMATS = (created_at = (timestamp_format = '%d/%m/%Y %H:%M'), payload = (binary_format = 'hex'));"#,
         r#"COPY INTO 's3://mybucket/data.csv'
                 FROM mytable
                 FILE_FORMAT = (
@@ -491,6 +508,8 @@ fn test_statement() {
         r#"SHOW FILE FORMATS"#,
         r#"DROP FILE FORMAT my_csv"#,
         r#"SELECT * FROM t GROUP BY GROUPING SETS (a, b, c, d)"#,
+        r#"SELECT * FROM t GAP_FILL(ts, INTERVAL 1 HOUR) PARTITION BY a FILL LINEAR"#,
+        r#"SELECT * FROM t GAP_FILL(ts, INTERVAL '15' MINUTE, '2024-01-01', '2024-01-02')"#,
         r#"SELECT * FROM t GROUP BY GROUPING SETS (a, b, (c, d))"#,
         r#"SELECT * FROM t GROUP BY GROUPING SETS ((a, b), (c), (d, e))"#,
         r#"SELECT * FROM t GROUP BY GROUPING SETS ((a, b), (), (d, e))"#,
@@ -509,20 +528,46 @@ fn test_statement() {
         r#"CREATE TASK IF NOT EXISTS MyTask1 WAREHOUSE = 'MyWarehouse' SCHEDULE = 15 MINUTE SUSPEND_TASK_AFTER_NUM_FAILURES = 3 COMMENT = 'This is test task 1' AS SELECT * FROM MyTable1"#,
         r#"CREATE TASK IF NOT EXISTS MyTask1 SCHEDULE = USING CRON '0 6 * * *' 'America/Los_Angeles' COMMENT = 'serverless + cron' AS insert into t (c1, c2) values (1, 2), (3, 4)"#,
         r#"CREATE TASK IF NOT EXISTS MyTask1 SCHEDULE = USING CRON '0 12 * * *' AS VACUUM TABLE t"#,
+        r#"CREATE TASK IF NOT EXISTS MyTask2 AFTER MyTask1, MyTask0 AS VACUUM TABLE t"#,
         r#"ALTER TASK MyTask1 RESUME"#,
         r#"ALTER TASK MyTask1 SUSPEND"#,
         r#"ALTER TASK MyTask1 SET WAREHOUSE= 'MyWarehouse' SCHEDULE = USING CRON '0 6 * * *' 'America/Los_Angeles' COMMENT = 'serverless + cron'"#,
         r#"ALTER TASK MyTask1 SET WAREHOUSE= 'MyWarehouse' SCHEDULE = 13 MINUTE SUSPEND_TASK_AFTER_NUM_FAILURES = 10 COMMENT = 'serverless + cron'"#,
         r#"ALTER TASK MyTask2 MODIFY AS SELECT CURRENT_VERSION()"#,
+        r#"ALTER TASK MyTask2 ADD AFTER MyTask3"#,
+        r#"ALTER TASK MyTask2 REMOVE AFTER MyTask0, MyTask1"#,
         r#"DROP TASK MyTask1"#,
         r#"SHOW TASKS"#,
         r#"EXECUTE TASK MyTask"#,
         r#"DESC TASK MyTask"#,
+        r#"CREATE TASK IF NOT EXISTS MyTask3 SCHEDULE = 15 MINUTE ERROR_INTEGRATION = SampleNotification AS SELECT 1"#,
+        r#"ALTER TASK MyTask3 SET ERROR_INTEGRATION = SampleNotification"#,
+        // notification integrations
+        r#"CREATE NOTIFICATION INTEGRATION IF NOT EXISTS SampleNotification TYPE = WEBHOOK ENABLED = TRUE WEBHOOK = (url = 'https://example.com/hook', method = 'POST', signing_secret = 'secret', max_retries = '5') COMMENT = 'notify on-call'"#,
+        r#"ALTER NOTIFICATION INTEGRATION SampleNotification SET ENABLED = FALSE"#,
+        r#"ALTER NOTIFICATION INTEGRATION IF EXISTS MailNotification SET EMAIL = (recipients = 'oncall@example.com') COMMENT = 'email on-call'"#,
+        r#"DROP NOTIFICATION INTEGRATION IF EXISTS SampleNotification"#,
+        r#"DESC NOTIFICATION INTEGRATION SampleNotification"#,
+        r#"EXECUTE IMMEDIATE $$ BEGIN LET x := 1; RETURN x + 1; END $$"#,
         r#"CREATE CONNECTION IF NOT EXISTS my_conn STORAGE_TYPE='s3'"#,
         r#"CREATE CONNECTION IF NOT EXISTS my_conn STORAGE_TYPE='s3' any_arg='any_value'"#,
         r#"DROP CONNECTION IF EXISTS my_conn;"#,
         r#"DESC CONNECTION my_conn;"#,
         r#"SHOW CONNECTIONS;"#,
+        // sequences
+        r#"CREATE SEQUENCE IF NOT EXISTS seq1 START WITH 10 INCREMENT BY 2 CACHE 100 COMMENT = 'test';"#,
+        r#"DROP SEQUENCE IF EXISTS seq1;"#,
+        r#"SHOW SEQUENCES;"#,
+        r#"CREATE TABLE t (a UInt64 AUTO_INCREMENT, b Int32 DEFAULT NEXTVAL(seq1));"#,
+        r#"CREATE TABLE t (a STRING MASKING POLICY mask1 COMMENT 'name', b STRING WITH MASKING POLICY mask1);"#,
+        // secrets
+        r#"CREATE SECRET IF NOT EXISTS s3_key PROVIDER = 'vault' CACHE_TTL = 60 path = 'secret/data/s3' key = 'secret_access_key';"#,
+        r#"DROP SECRET IF EXISTS s3_key;"#,
+        r#"DESC SECRET s3_key;"#,
+        r#"SHOW SECRETS;"#,
+        // system
+        r#"ALTER SYSTEM FLUSH CACHE;"#,
+        r#"ALTER SYSTEM FLUSH CACHE plan;"#,
         // pipes
         r#"CREATE PIPE IF NOT EXISTS MyPipe1 AUTO_INGEST = TRUE COMMENT = 'This is test pipe 1' AS COPY INTO MyTable1 FROM '@~/MyStage1' FILE_FORMAT = (TYPE = 'CSV')"#,
         r#"CREATE PIPE pipe1 AS COPY INTO db1.MyTable1 FROM @~/mybucket/data.csv"#,
@@ -691,6 +736,8 @@ fn test_query() {
         r#"VALUES(1,'a'),(2,'b'),(null,'c') order by col0 limit 2"#,
         r#"select * from t left join lateral(select 1) on true, lateral(select 2)"#,
         r#"select * from t, lateral flatten(input => u.col) f"#,
+        r#"select * from t offset 3 rows fetch first 2 rows only"#,
+        r#"select a from t order by a limit 1 by b, c limit 10"#,
     ];
 
     for case in cases {
@@ -800,6 +847,8 @@ fn test_expr() {
         r#"COUNT() OVER (ORDER BY hire_date ROWS UNBOUNDED PRECEDING)"#,
         r#"COUNT() OVER (ORDER BY hire_date ROWS CURRENT ROW)"#,
         r#"COUNT() OVER (ORDER BY hire_date ROWS 3 PRECEDING)"#,
+        r#"LAG(salary, 1) IGNORE NULLS OVER (ORDER BY hire_date)"#,
+        r#"SUM(salary) OVER (ORDER BY hire_date ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING EXCLUDE CURRENT ROW)"#,
         r#"ARRAY_APPLY([1,2,3], x -> x + 1)"#,
         r#"ARRAY_FILTER(col, y -> y % 2 = 0)"#,
         r#"(current_timestamp, current_timestamp(), now())"#,
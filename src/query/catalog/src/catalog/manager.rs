@@ -35,6 +35,7 @@ use common_meta_app::schema::HiveCatalogOption;
 use common_meta_app::schema::ListCatalogReq;
 use common_meta_store::MetaStore;
 use common_meta_store::MetaStoreProvider;
+use common_users::UserApiProvider;
 
 use super::Catalog;
 use super::CatalogCreator;
@@ -167,6 +168,17 @@ impl CatalogManager {
             .get_catalog(GetCatalogReq::new(tenant, catalog_name))
             .await?;
 
+        self.load_catalog(tenant, info.as_ref().clone()).await
+    }
+
+    /// Build a catalog persisted in metasrv, whose storage params keep only the
+    /// references to the secrets of their credentials.
+    #[async_backtrace::framed]
+    async fn load_catalog(&self, tenant: &str, mut info: CatalogInfo) -> Result<Arc<dyn Catalog>> {
+        UserApiProvider::instance()
+            .resolve_catalog_secrets(tenant, &mut info.meta.catalog_option)
+            .await?;
+
         self.build_catalog(&info)
     }
 
@@ -233,7 +245,7 @@ impl CatalogManager {
         let infos = self.meta.list_catalogs(ListCatalogReq::new(tenant)).await?;
 
         for info in infos {
-            catalogs.push(self.build_catalog(&info)?);
+            catalogs.push(self.load_catalog(tenant, info.as_ref().clone()).await?);
         }
 
         Ok(catalogs)
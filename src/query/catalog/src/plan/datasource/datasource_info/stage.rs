@@ -20,6 +20,7 @@ use common_exception::Result;
 use common_expression::Scalar;
 use common_expression::TableSchema;
 use common_expression::TableSchemaRef;
+use common_meta_app::principal::FileFormatOverrides;
 use common_meta_app::principal::StageInfo;
 use common_storage::init_stage_operator;
 use common_storage::StageFileInfo;
@@ -33,6 +34,8 @@ pub struct StageTableInfo {
     pub stage_info: StageInfo,
     pub files_to_copy: Option<Vec<StageFileInfo>>,
     pub is_select: bool,
+    /// The file format options of COPY for some of the files or columns.
+    pub format_overrides: FileFormatOverrides,
 }
 
 impl StageTableInfo {
@@ -28,6 +28,7 @@ use common_expression::Scalar;
 use common_expression::TableDataType;
 use common_expression::Value;
 use common_expression::BLOCK_NAME_COLUMN_ID;
+use common_expression::FILE_NAME_COLUMN_ID;
 use common_expression::ROW_ID_COLUMN_ID;
 use common_expression::SEGMENT_NAME_COLUMN_ID;
 use common_expression::SNAPSHOT_NAME_COLUMN_ID;
@@ -117,6 +118,8 @@ pub enum InternalColumnType {
     BlockName,
     SegmentName,
     SnapshotName,
+    /// The path of the data file the row was read from.
+    FileName,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -143,6 +146,7 @@ impl InternalColumn {
             InternalColumnType::BlockName => TableDataType::String,
             InternalColumnType::SegmentName => TableDataType::String,
             InternalColumnType::SnapshotName => TableDataType::String,
+            InternalColumnType::FileName => TableDataType::String,
         }
     }
 
@@ -161,6 +165,7 @@ impl InternalColumn {
             InternalColumnType::BlockName => BLOCK_NAME_COLUMN_ID,
             InternalColumnType::SegmentName => SEGMENT_NAME_COLUMN_ID,
             InternalColumnType::SnapshotName => SNAPSHOT_NAME_COLUMN_ID,
+            InternalColumnType::FileName => FILE_NAME_COLUMN_ID,
         }
     }
 
@@ -188,14 +193,9 @@ impl InternalColumn {
                     Value::Column(UInt64Type::from_data(row_ids)),
                 )
             }
-            InternalColumnType::BlockName => {
-                let mut builder = StringColumnBuilder::with_capacity(1, meta.block_location.len());
-                builder.put_str(&meta.block_location);
-                builder.commit_row();
-                BlockEntry::new(
-                    DataType::String,
-                    Value::Scalar(Scalar::String(builder.build_scalar())),
-                )
+            // The data file of a fuse table row is its block.
+            InternalColumnType::BlockName | InternalColumnType::FileName => {
+                generate_location_column(&meta.block_location)
             }
             InternalColumnType::SegmentName => {
                 let mut builder =
@@ -225,3 +225,14 @@ impl InternalColumn {
         }
     }
 }
+
+/// Generates the constant value of a location internal column, such as `_block_name` or `_file_name`.
+pub fn generate_location_column(location: &str) -> BlockEntry {
+    let mut builder = StringColumnBuilder::with_capacity(1, location.len());
+    builder.put_str(location);
+    builder.commit_row();
+    BlockEntry::new(
+        DataType::String,
+        Value::Scalar(Scalar::String(builder.build_scalar())),
+    )
+}
@@ -28,6 +28,7 @@ pub use internal_column::*;
 pub use partition::*;
 pub use partition_statistics::PartStatistics;
 pub use projection::Projection;
+pub use pruning_statistics::PredicatePruning;
 pub use pruning_statistics::PruningStatistics;
 pub use pushdown::*;
 pub use stream_column::*;
@@ -36,6 +36,11 @@ pub trait PartInfo: Send + Sync {
 
     /// Used for partition distributed.
     fn hash(&self) -> u64;
+
+    /// The bucket of the rows in the partition, if the table is bucketed.
+    fn bucket(&self) -> Option<u32> {
+        None
+    }
 }
 
 impl Debug for Box<dyn PartInfo> {
@@ -75,6 +80,9 @@ pub enum PartitionsShuffleKind {
     Rand,
     // Bind the Partition to executor by broadcast
     Broadcast,
+    // Bind the Partition to executor by partition.bucket()%executor_nums, so that
+    // the same bucket of the tables is always routed to the same executor.
+    Bucket,
 }
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq)]
 pub struct Partitions {
@@ -150,6 +158,26 @@ impl Partitions {
 
                 return Ok(executor_part);
             }
+            PartitionsShuffleKind::Bucket => {
+                let mut executor_part = executors_sorted
+                    .iter()
+                    .map(|executor| (executor.clone(), vec![]))
+                    .collect::<HashMap<_, _>>();
+                for part in self.partitions.iter() {
+                    let bucket = part.bucket().unwrap_or_default() as usize;
+                    let executor = &executors_sorted[bucket % num_executors];
+                    executor_part.get_mut(executor).unwrap().push(part.clone());
+                }
+
+                return Ok(executor_part
+                    .into_iter()
+                    .map(|(executor, parts)| {
+                        let parts =
+                            Partitions::create(PartitionsShuffleKind::Seq, parts, self.is_lazy);
+                        (executor, parts)
+                    })
+                    .collect());
+            }
         };
 
         // parts_per_executor = num_parts / num_executors
@@ -25,6 +25,34 @@ pub struct PruningStatistics {
     /// Block bloom filter pruning stats.
     pub blocks_bloom_pruning_before: usize,
     pub blocks_bloom_pruning_after: usize,
+
+    /// Cluster key pruning stats of the segments and blocks kept by the range index,
+    /// the range pruning stats include them.
+    pub segments_cluster_pruning_before: usize,
+    pub segments_cluster_pruning_after: usize,
+    pub blocks_cluster_pruning_before: usize,
+    pub blocks_cluster_pruning_after: usize,
+}
+
+/// How a predicate of the filter prunes the table by itself, explained by EXPLAIN PRUNE.
+#[derive(Clone, Debug, Default)]
+pub struct PredicatePruning {
+    pub predicate: String,
+    /// The segments and blocks the range index prunes by this predicate only.
+    pub segments_range_pruned: usize,
+    pub blocks_range_pruned: usize,
+    /// Whether the bloom index or the cluster key can prune by this predicate.
+    pub bloom_prunable: bool,
+    pub cluster_key_prunable: bool,
+}
+
+impl PredicatePruning {
+    pub fn is_prunable(&self) -> bool {
+        self.segments_range_pruned > 0
+            || self.blocks_range_pruned > 0
+            || self.bloom_prunable
+            || self.cluster_key_prunable
+    }
 }
 
 impl PruningStatistics {
@@ -35,5 +63,9 @@ impl PruningStatistics {
         self.blocks_range_pruning_after += other.blocks_range_pruning_after;
         self.blocks_bloom_pruning_before += other.blocks_bloom_pruning_before;
         self.blocks_bloom_pruning_after += other.blocks_bloom_pruning_after;
+        self.segments_cluster_pruning_before += other.segments_cluster_pruning_before;
+        self.segments_cluster_pruning_after += other.segments_cluster_pruning_after;
+        self.blocks_cluster_pruning_before += other.blocks_cluster_pruning_before;
+        self.blocks_cluster_pruning_after += other.blocks_cluster_pruning_after;
     }
 }
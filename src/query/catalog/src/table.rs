@@ -35,6 +35,7 @@ use common_meta_app::schema::UpsertTableCopiedFileReq;
 use common_meta_types::MetaId;
 use common_pipeline_core::Pipeline;
 use common_storage::StorageMetrics;
+use common_users::UserApiProvider;
 use storages_common_table_meta::meta::SnapshotId;
 use storages_common_table_meta::meta::TableSnapshot;
 
@@ -42,6 +43,7 @@ use crate::plan::DataSourceInfo;
 use crate::plan::DataSourcePlan;
 use crate::plan::PartStatistics;
 use crate::plan::Partitions;
+use crate::plan::PredicatePruning;
 use crate::plan::PushDownInfo;
 use crate::plan::StreamColumn;
 use crate::statistics::BasicColumnStatistics;
@@ -144,6 +146,12 @@ pub trait Table: Sync + Send {
         false
     }
 
+    /// Whether the table engine can generate the internal column with id `column_id`,
+    /// such as `_row_id`, `_block_name` or `_file_name`.
+    fn support_internal_column(&self, _column_id: ColumnId) -> bool {
+        false
+    }
+
     #[async_backtrace::framed]
     async fn alter_table_cluster_keys(
         &self,
@@ -188,6 +196,18 @@ pub trait Table: Sync + Send {
         None
     }
 
+    /// Explains how each predicate of the push downs prunes the table by itself,
+    /// used by EXPLAIN PRUNE.
+    #[async_backtrace::framed]
+    async fn explain_pruning(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+    ) -> Result<Vec<PredicatePruning>> {
+        let (_, _) = (ctx, push_downs);
+        Ok(vec![])
+    }
+
     /// Assembly the pipeline of reading data from storage, according to the plan
     fn read_data(
         &self,
@@ -272,6 +292,15 @@ pub trait Table: Sync + Send {
         Ok(None)
     }
 
+    /// Returns the number of buckets if every block of the table holds the rows of
+    /// a single bucket of its cluster keys.
+    #[async_backtrace::framed]
+    async fn bucket_num(&self, ctx: Arc<dyn TableContext>) -> Result<Option<u32>> {
+        let _ = ctx;
+
+        Ok(None)
+    }
+
     #[async_backtrace::framed]
     async fn column_statistics_provider(&self) -> Result<Box<dyn ColumnStatisticsProvider>> {
         Ok(Box::new(DummyColumnStatisticsProvider))
@@ -288,6 +317,14 @@ pub trait Table: Sync + Send {
         )))
     }
 
+    /// Resolve the snapshot that the table currently points to, so that every read of the
+    /// table in a query sees the same data. Returns `None` if the table is not versioned,
+    /// or has no snapshot yet.
+    #[async_backtrace::framed]
+    async fn pin_snapshot(&self) -> Result<Option<PinnedSnapshot>> {
+        Ok(None)
+    }
+
     fn get_block_thresholds(&self) -> BlockThresholds {
         BlockThresholds {
             max_rows_per_block: DEFAULT_BLOCK_MAX_ROWS,
@@ -388,11 +425,18 @@ pub trait TableExt: Table {
         let tid = table_info.ident.table_id;
         let catalog = ctx.get_catalog(table_info.catalog()).await?;
         let (ident, meta) = catalog.get_table_meta_by_id(tid).await?;
+        let mut meta = meta.as_ref().clone();
+        // Only the references to the secrets of the credentials are persisted.
+        if let Some(storage_params) = meta.storage_params.as_mut() {
+            UserApiProvider::instance()
+                .resolve_storage_secrets(&ctx.get_tenant(), storage_params)
+                .await?;
+        }
         let table_info = TableInfo {
             ident,
             desc: "".to_owned(),
             name,
-            meta: meta.as_ref().clone(),
+            meta,
             tenant: "".to_owned(),
             db_type: DatabaseType::NormalDB,
         };
@@ -419,6 +463,14 @@ pub enum NavigationPoint {
     TimePoint(DateTime<Utc>),
 }
 
+/// The snapshot a table is pinned to, see [`Table::pin_snapshot`].
+pub struct PinnedSnapshot {
+    pub snapshot_id: String,
+    /// The table to read instead, if the original table may not read `snapshot_id`
+    /// when it is read again.
+    pub table: Option<Arc<dyn Table>>,
+}
+
 #[derive(Debug, Copy, Clone, Default)]
 pub struct TableStatistics {
     pub num_rows: Option<u64>,
@@ -429,6 +481,23 @@ pub struct TableStatistics {
     pub number_of_segments: Option<u64>,
 }
 
+impl TableStatistics {
+    /// Bytes taken in the storage, by the data and the indexes.
+    pub fn physical_size(&self) -> Option<u64> {
+        Some(self.data_size_compressed? + self.index_size.unwrap_or_default())
+    }
+
+    /// Ratio of the uncompressed size of the data to its compressed size.
+    pub fn compression_ratio(&self) -> Option<f64> {
+        match (self.data_size, self.data_size_compressed) {
+            (Some(size), Some(compressed)) if compressed > 0 => {
+                Some(size as f64 / compressed as f64)
+            }
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ColumnStatistics {
     pub min: Scalar,
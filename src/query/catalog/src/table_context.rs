@@ -123,6 +123,8 @@ pub trait TableContext: Send + Sync {
     fn get_aggregate_spill_progress_value(&self) -> ProgressValues;
     fn get_result_progress(&self) -> Arc<Progress>;
     fn get_result_progress_value(&self) -> ProgressValues;
+    fn get_network_progress(&self) -> Arc<Progress>;
+    fn get_network_progress_value(&self) -> ProgressValues;
     fn get_status_info(&self) -> String;
     fn set_status_info(&self, info: &str);
 
@@ -190,6 +192,16 @@ pub trait TableContext: Send + Sync {
     async fn get_table(&self, catalog: &str, database: &str, table: &str)
     -> Result<Arc<dyn Table>>;
 
+    /// Like `get_table`, and pins the table to its current snapshot, see
+    /// [`Table::pin_snapshot`]. The following `get_table` of the same table in the query
+    /// return the pinned table.
+    async fn get_pinned_table(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+    ) -> Result<Arc<dyn Table>>;
+
     async fn filter_out_copied_files(
         &self,
         catalog_name: &str,
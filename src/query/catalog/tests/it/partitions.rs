@@ -59,6 +59,35 @@ impl TestPartInfo {
     }
 }
 
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+struct TestBucketPartInfo {
+    pub loc: String,
+    pub bucket: u32,
+}
+
+#[typetag::serde(name = "test_bucket")]
+impl PartInfo for TestBucketPartInfo {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, info: &Box<dyn PartInfo>) -> bool {
+        info.as_any()
+            .downcast_ref::<TestBucketPartInfo>()
+            .is_some_and(|other| self == other)
+    }
+
+    fn hash(&self) -> u64 {
+        let mut s = DefaultHasher::new();
+        self.loc.hash(&mut s);
+        s.finish()
+    }
+
+    fn bucket(&self) -> Option<u32> {
+        Some(self.bucket)
+    }
+}
+
 fn gen_parts(kind: PartitionsShuffleKind, size: usize) -> Partitions {
     let mut parts = vec![];
     for i in 0..size {
@@ -213,6 +242,40 @@ fn test_partition_reshuffle() {
     }
 }
 
+#[test]
+fn test_partition_reshuffle_bucket() {
+    let executors = vec![
+        "node-2".to_string(),
+        "node-1".to_string(),
+        "node-3".to_string(),
+    ];
+
+    let parts = (0..8)
+        .map(|i| {
+            Arc::new(Box::new(TestBucketPartInfo {
+                loc: format!("{}", i),
+                bucket: i % 4,
+            }) as Box<dyn PartInfo>)
+        })
+        .collect::<Vec<_>>();
+    let partitions = Partitions::create(PartitionsShuffleKind::Bucket, parts, false);
+    let shuffle = partitions.reshuffle(executors).unwrap();
+
+    // Every executor gets the partitions, even if there are none of its buckets.
+    assert_eq!(shuffle.len(), 3);
+    // The buckets are routed to the sorted executors by bucket % executor_nums.
+    let buckets = |executor: &str| {
+        shuffle[executor]
+            .partitions
+            .iter()
+            .map(|part| part.bucket().unwrap())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(buckets("node-1"), vec![0, 3, 0, 3]);
+    assert_eq!(buckets("node-2"), vec![1, 1]);
+    assert_eq!(buckets("node-3"), vec![2, 2]);
+}
+
 #[test]
 fn test_split() {
     for seg in 0..1024 * 10 {
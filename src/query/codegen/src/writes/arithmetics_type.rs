@@ -95,17 +95,39 @@ pub trait ResultTypeOfUnary: Sized {{
         NumberDataType::Float64,
     ];
 
+    // 128-bit integers only mix with integers, the floats don't have a 128-bit result type.
+    let wide_integer_types = vec![NumberDataType::UInt128, NumberDataType::Int128];
+
+    let mut binary_types = vec![];
     for lhs in &number_types {
         for rhs in &number_types {
-            let add_mul = arithmetic_coercion(*lhs, *rhs, OP::Plus);
-            let minus = arithmetic_coercion(*lhs, *rhs, OP::Minus);
-            let intdiv = arithmetic_coercion(*lhs, *rhs, OP::IntDiv);
-            let modulo = arithmetic_coercion(*lhs, *rhs, OP::Modulo);
-            let least_super = arithmetic_coercion(*lhs, *rhs, OP::Super);
-
-            writeln!(
-                file,
-                "
+            binary_types.push((*lhs, *rhs));
+        }
+    }
+    let integer_types = number_types
+        .iter()
+        .filter(|ty| !ty.is_float())
+        .chain(wide_integer_types.iter())
+        .cloned()
+        .collect::<Vec<_>>();
+    for lhs in &integer_types {
+        for rhs in &integer_types {
+            if lhs.bit_width() == 128 || rhs.bit_width() == 128 {
+                binary_types.push((*lhs, *rhs));
+            }
+        }
+    }
+
+    for (lhs, rhs) in &binary_types {
+        let add_mul = arithmetic_coercion(*lhs, *rhs, OP::Plus);
+        let minus = arithmetic_coercion(*lhs, *rhs, OP::Minus);
+        let intdiv = arithmetic_coercion(*lhs, *rhs, OP::IntDiv);
+        let modulo = arithmetic_coercion(*lhs, *rhs, OP::Modulo);
+        let least_super = arithmetic_coercion(*lhs, *rhs, OP::Super);
+
+        writeln!(
+            file,
+            "
 impl ResultTypeOfBinary for ({}, {}) {{
     type AddMul = {};
     type Minus = {};
@@ -113,19 +135,18 @@ impl ResultTypeOfBinary for ({}, {}) {{
     type Modulo = {};
     type LeastSuper = {};
 }}",
-                to_primitive_str(*lhs),
-                to_primitive_str(*rhs),
-                to_primitive_str(add_mul),
-                to_primitive_str(minus),
-                to_primitive_str(intdiv),
-                to_primitive_str(modulo),
-                to_primitive_str(least_super),
-            )
-            .unwrap();
-        }
+            to_primitive_str(*lhs),
+            to_primitive_str(*rhs),
+            to_primitive_str(add_mul),
+            to_primitive_str(minus),
+            to_primitive_str(intdiv),
+            to_primitive_str(modulo),
+            to_primitive_str(least_super),
+        )
+        .unwrap();
     }
 
-    for arg in &number_types {
+    for arg in number_types.iter().chain(wide_integer_types.iter()) {
         let negate = neg_coercion(*arg);
         let sum = sum_coercion(*arg);
 
@@ -216,6 +237,8 @@ fn to_primitive_str(dt: NumberDataType) -> &'static str {
         NumberDataType::Int64 => "i64",
         NumberDataType::Float32 => "F32",
         NumberDataType::Float64 => "F64",
+        NumberDataType::UInt128 => "u128",
+        NumberDataType::Int128 => "i128",
     }
 }
 
@@ -256,6 +279,8 @@ fn neg_coercion(a: NumberDataType) -> NumberDataType {
 fn sum_coercion(a: NumberDataType) -> NumberDataType {
     if a.is_float() {
         NumberDataType::Float64
+    } else if a.bit_width() == 128 {
+        a
     } else if a.is_signed() {
         NumberDataType::Int64
     } else {
@@ -264,5 +289,5 @@ fn sum_coercion(a: NumberDataType) -> NumberDataType {
 }
 
 const fn next_bit_width(width: u8) -> u8 {
-    if width < 64 { width * 2 } else { 64 }
+    if width < 64 { width * 2 } else { width }
 }
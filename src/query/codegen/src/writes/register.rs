@@ -622,8 +622,11 @@ pub fn codegen_register() {
                 format!(
                     "({arm_pat}) => {{
                         let and_validity = {and_validity};
-                        let validity = ctx.validity.as_ref().map(|valid| valid & (&and_validity)).unwrap_or(and_validity);
-                        ctx.validity = Some(validity.clone());
+                        let len = and_validity.len();
+                        let validity = match ctx.narrow_validity(and_validity) {{
+                            Some(validity) => validity,
+                            None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+                        }};
                         let column = func({func_arg} ctx).into_column().unwrap();
                         Value::Column(NullableColumn {{ column, validity }})
                     }}"
@@ -730,8 +733,11 @@ pub fn codegen_register() {
                 format!(
                     "({arm_pat}) => {{
                         let and_validity = {and_validity};
-                        let validity = ctx.validity.as_ref().map(|valid| valid & (&and_validity)).unwrap_or(and_validity);
-                        ctx.validity = Some(validity.clone());
+                        let len = and_validity.len();
+                        let validity = match ctx.narrow_validity(and_validity) {{
+                            Some(validity) => validity,
+                            None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+                        }};
                         let nullable_column = func({func_arg} ctx).into_column().unwrap();
                         let combine_validity = common_arrow::arrow::bitmap::and(&validity, &nullable_column.validity);
                         Value::Column(NullableColumn {{ column: nullable_column.column, validity: combine_validity }})
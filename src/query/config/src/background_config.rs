@@ -32,6 +32,12 @@ pub struct BackgroundConfig {
     // Fs compaction related background config.
     #[clap(flatten)]
     pub compaction: BackgroundCompactionConfig,
+    // Row ttl related background config.
+    #[clap(flatten)]
+    pub row_ttl: BackgroundRowTtlConfig,
+    // Auto analyze related background config.
+    #[clap(flatten)]
+    pub auto_analyze: BackgroundAutoAnalyzeConfig,
 }
 
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Args)]
@@ -67,6 +73,48 @@ pub struct BackgroundCompactionConfig {
     pub scheduled_config: BackgroundScheduledConfig,
 }
 
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Args)]
+#[serde(default)]
+pub struct BackgroundRowTtlConfig {
+    // Delete the expired rows of the tables with the `row_ttl` option.
+    #[clap(long, value_name = "VALUE")]
+    pub enable_row_ttl: bool,
+
+    // The fixed interval to delete the expired rows of each table.
+    #[clap(long, value_name = "VALUE", default_value = "3600")]
+    pub row_ttl_interval_secs: u64,
+
+    // The maximum number of blocks rewritten by one deletion of the expired rows,
+    // a table is cleaned up by several deletions if it has more expired blocks.
+    #[clap(long, value_name = "VALUE", default_value = "1000")]
+    pub row_ttl_batch_blocks: u64,
+}
+
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Args)]
+#[serde(default)]
+pub struct BackgroundAutoAnalyzeConfig {
+    // Refresh the statistics of the fuse tables whose data changed significantly since
+    // they were last analyzed.
+    #[clap(long, value_name = "VALUE")]
+    pub enable_auto_analyze: bool,
+
+    // The fixed interval to look for the tables with stale statistics.
+    #[clap(long, value_name = "VALUE", default_value = "600")]
+    pub auto_analyze_interval_secs: u64,
+
+    // A table is analyzed once its number of rows changed by this percentage.
+    #[clap(long, value_name = "VALUE", default_value = "10")]
+    pub auto_analyze_change_percent: u64,
+
+    // A table is not analyzed if fewer rows changed, whatever the percentage is.
+    #[clap(long, value_name = "VALUE", default_value = "1000")]
+    pub auto_analyze_min_changed_rows: u64,
+
+    // The maximum number of tables analyzed by one run, the most changed ones first.
+    #[clap(long, value_name = "VALUE", default_value = "10")]
+    pub auto_analyze_max_tables: u64,
+}
+
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Args)]
 #[serde(default)]
 pub struct BackgroundScheduledConfig {
@@ -106,6 +154,8 @@ impl BackgroundScheduledConfig {
 pub struct InnerBackgroundConfig {
     pub enable: bool,
     pub compaction: InnerBackgroundCompactionConfig,
+    pub row_ttl: InnerBackgroundRowTtlConfig,
+    pub auto_analyze: InnerBackgroundAutoAnalyzeConfig,
 }
 
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -117,6 +167,22 @@ pub struct InnerBackgroundCompactionConfig {
     pub params: BackgroundJobParams,
 }
 
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InnerBackgroundRowTtlConfig {
+    pub enable: bool,
+    pub batch_blocks: u64,
+    pub params: BackgroundJobParams,
+}
+
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InnerBackgroundAutoAnalyzeConfig {
+    pub enable: bool,
+    pub change_percent: u64,
+    pub min_changed_rows: u64,
+    pub max_tables: u64,
+    pub params: BackgroundJobParams,
+}
+
 impl InnerBackgroundCompactionConfig {
     pub fn has_target_tables(&self) -> bool {
         self.target_tables.is_some() && !self.target_tables.as_ref().unwrap().is_empty()
@@ -130,6 +196,8 @@ impl TryInto<InnerBackgroundConfig> for BackgroundConfig {
         Ok(InnerBackgroundConfig {
             enable: self.enable,
             compaction: self.compaction.try_into()?,
+            row_ttl: self.row_ttl.into(),
+            auto_analyze: self.auto_analyze.into(),
         })
     }
 }
@@ -139,6 +207,56 @@ impl From<InnerBackgroundConfig> for BackgroundConfig {
         Self {
             enable: inner.enable,
             compaction: BackgroundCompactionConfig::from(inner.compaction),
+            row_ttl: BackgroundRowTtlConfig::from(inner.row_ttl),
+            auto_analyze: BackgroundAutoAnalyzeConfig::from(inner.auto_analyze),
+        }
+    }
+}
+
+impl From<BackgroundRowTtlConfig> for InnerBackgroundRowTtlConfig {
+    fn from(config: BackgroundRowTtlConfig) -> Self {
+        Self {
+            enable: config.enable_row_ttl,
+            batch_blocks: config.row_ttl_batch_blocks,
+            params: BackgroundJobParams::new_interval_job(std::time::Duration::from_secs(
+                config.row_ttl_interval_secs,
+            )),
+        }
+    }
+}
+
+impl From<InnerBackgroundRowTtlConfig> for BackgroundRowTtlConfig {
+    fn from(inner: InnerBackgroundRowTtlConfig) -> Self {
+        Self {
+            enable_row_ttl: inner.enable,
+            row_ttl_interval_secs: inner.params.scheduled_job_interval.as_secs(),
+            row_ttl_batch_blocks: inner.batch_blocks,
+        }
+    }
+}
+
+impl From<BackgroundAutoAnalyzeConfig> for InnerBackgroundAutoAnalyzeConfig {
+    fn from(config: BackgroundAutoAnalyzeConfig) -> Self {
+        Self {
+            enable: config.enable_auto_analyze,
+            change_percent: config.auto_analyze_change_percent,
+            min_changed_rows: config.auto_analyze_min_changed_rows,
+            max_tables: config.auto_analyze_max_tables,
+            params: BackgroundJobParams::new_interval_job(std::time::Duration::from_secs(
+                config.auto_analyze_interval_secs,
+            )),
+        }
+    }
+}
+
+impl From<InnerBackgroundAutoAnalyzeConfig> for BackgroundAutoAnalyzeConfig {
+    fn from(inner: InnerBackgroundAutoAnalyzeConfig) -> Self {
+        Self {
+            enable_auto_analyze: inner.enable,
+            auto_analyze_interval_secs: inner.params.scheduled_job_interval.as_secs(),
+            auto_analyze_change_percent: inner.change_percent,
+            auto_analyze_min_changed_rows: inner.min_changed_rows,
+            auto_analyze_max_tables: inner.max_tables,
         }
     }
 }
@@ -254,6 +372,50 @@ impl Debug for BackgroundCompactionConfig {
     }
 }
 
+impl Default for BackgroundRowTtlConfig {
+    fn default() -> Self {
+        Self {
+            enable_row_ttl: false,
+            row_ttl_interval_secs: 3600,
+            row_ttl_batch_blocks: 1000,
+        }
+    }
+}
+
+impl Debug for BackgroundRowTtlConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BackgroundRowTtlConfig")
+            .field("enable", &self.enable_row_ttl)
+            .field("interval_secs", &self.row_ttl_interval_secs)
+            .field("batch_blocks", &self.row_ttl_batch_blocks)
+            .finish()
+    }
+}
+
+impl Default for BackgroundAutoAnalyzeConfig {
+    fn default() -> Self {
+        Self {
+            enable_auto_analyze: false,
+            auto_analyze_interval_secs: 600,
+            auto_analyze_change_percent: 10,
+            auto_analyze_min_changed_rows: 1000,
+            auto_analyze_max_tables: 10,
+        }
+    }
+}
+
+impl Debug for BackgroundAutoAnalyzeConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BackgroundAutoAnalyzeConfig")
+            .field("enable", &self.enable_auto_analyze)
+            .field("interval_secs", &self.auto_analyze_interval_secs)
+            .field("change_percent", &self.auto_analyze_change_percent)
+            .field("min_changed_rows", &self.auto_analyze_min_changed_rows)
+            .field("max_tables", &self.auto_analyze_max_tables)
+            .finish()
+    }
+}
+
 impl Default for BackgroundScheduledConfig {
     fn default() -> Self {
         Self {
@@ -283,6 +445,8 @@ impl Default for InnerBackgroundConfig {
                 block_limit: None,
                 params: Default::default(),
             },
+            row_ttl: BackgroundRowTtlConfig::default().into(),
+            auto_analyze: BackgroundAutoAnalyzeConfig::default().into(),
         }
     }
 }
@@ -291,6 +455,8 @@ impl Debug for InnerBackgroundConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("InnerBackgroundConfig")
             .field("compaction", &self.compaction)
+            .field("row_ttl", &self.row_ttl)
+            .field("auto_analyze", &self.auto_analyze)
             .finish()
     }
 }
@@ -304,3 +470,23 @@ impl Debug for InnerBackgroundCompactionConfig {
             .finish()
     }
 }
+
+impl Debug for InnerBackgroundRowTtlConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InnerBackgroundRowTtlConfig")
+            .field("batch_blocks", &self.batch_blocks)
+            .field("params", &self.params)
+            .finish()
+    }
+}
+
+impl Debug for InnerBackgroundAutoAnalyzeConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InnerBackgroundAutoAnalyzeConfig")
+            .field("change_percent", &self.change_percent)
+            .field("min_changed_rows", &self.min_changed_rows)
+            .field("max_tables", &self.max_tables)
+            .field("params", &self.params)
+            .finish()
+    }
+}
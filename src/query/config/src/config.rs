@@ -43,6 +43,7 @@ use common_meta_app::storage::StorageS3Config as InnerStorageS3Config;
 use common_meta_app::storage::StorageWebhdfsConfig as InnerStorageWebhdfsConfig;
 use common_meta_app::tenant::TenantQuota;
 use common_storage::StorageConfig as InnerStorageConfig;
+use common_storage::StorageNetworkConfig as InnerStorageNetworkConfig;
 use common_tracing::Config as InnerLogConfig;
 use common_tracing::FileConfig as InnerFileLogConfig;
 use common_tracing::OTLPConfig as InnerOTLPLogConfig;
@@ -265,6 +266,10 @@ pub struct StorageConfig {
     #[clap(long = "storage-allow-insecure")]
     pub allow_insecure: bool,
 
+    // Http clients and IO config of the storage services.
+    #[clap(flatten)]
+    pub network: StorageNetworkConfig,
+
     // Fs storage backend config.
     #[clap(flatten)]
     pub fs: FsStorageConfig,
@@ -314,6 +319,7 @@ impl From<InnerStorageConfig> for StorageConfig {
             storage_num_cpus: inner.num_cpus,
             typ: "".to_string(),
             allow_insecure: inner.allow_insecure,
+            network: inner.network.into(),
             // use default for each config instead of using `..Default::default`
             // using `..Default::default` is calling `Self::default`
             // and `Self::default` relies on `InnerStorage::into()`
@@ -399,6 +405,7 @@ impl TryInto<InnerStorageConfig> for StorageConfig {
         Ok(InnerStorageConfig {
             num_cpus: self.storage_num_cpus,
             allow_insecure: self.allow_insecure,
+            network: self.network.try_into()?,
             params: {
                 match self.typ.as_str() {
                     "azblob" => StorageParams::Azblob(self.azblob.try_into()?),
@@ -551,6 +558,109 @@ impl From<InnerCatalogHiveConfig> for HiveCatalogConfig {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args)]
+#[serde(default)]
+pub struct StorageNetworkConfig {
+    /// Max idle connections kept in the pool for each endpoint, `0` to disable pooling
+    #[clap(
+        long = "storage-pool-max-idle-per-host",
+        value_name = "VALUE",
+        default_value = "1024"
+    )]
+    pub pool_max_idle_per_host: usize,
+
+    /// Seconds an idle connection is kept in the pool
+    #[clap(
+        long = "storage-pool-idle-timeout-secs",
+        value_name = "VALUE",
+        default_value = "90"
+    )]
+    pub pool_idle_timeout_secs: u64,
+
+    /// Interval in seconds of the TCP keep-alive probes, `0` to disable keep-alive
+    #[clap(
+        long = "storage-tcp-keepalive-secs",
+        value_name = "VALUE",
+        default_value = "60"
+    )]
+    pub tcp_keepalive_secs: u64,
+
+    /// Seconds to wait for a connection to be established
+    #[clap(
+        long = "storage-connect-timeout-secs",
+        value_name = "VALUE",
+        default_value = "30"
+    )]
+    pub connect_timeout_secs: u64,
+
+    /// Seconds an IO operation may take before it fails with timeout
+    #[clap(
+        long = "storage-io-timeout-secs",
+        value_name = "VALUE",
+        default_value = "60"
+    )]
+    pub io_timeout_secs: u64,
+
+    /// A read slower than this percentile of the recent reads is sent again, `0` to disable
+    #[clap(
+        long = "storage-hedged-read-percentile",
+        value_name = "VALUE",
+        default_value = "0"
+    )]
+    pub hedged_read_percentile: u64,
+
+    /// Min milliseconds to wait before a read is sent again
+    #[clap(
+        long = "storage-hedged-read-min-delay-ms",
+        value_name = "VALUE",
+        default_value = "50"
+    )]
+    pub hedged_read_min_delay_ms: u64,
+}
+
+impl Default for StorageNetworkConfig {
+    fn default() -> Self {
+        InnerStorageNetworkConfig::default().into()
+    }
+}
+
+impl From<InnerStorageNetworkConfig> for StorageNetworkConfig {
+    fn from(inner: InnerStorageNetworkConfig) -> Self {
+        Self {
+            pool_max_idle_per_host: inner.pool_max_idle_per_host,
+            pool_idle_timeout_secs: inner.pool_idle_timeout_secs,
+            tcp_keepalive_secs: inner.tcp_keepalive_secs,
+            connect_timeout_secs: inner.connect_timeout_secs,
+            io_timeout_secs: inner.io_timeout_secs,
+            hedged_read_percentile: inner.hedged_read_percentile,
+            hedged_read_min_delay_ms: inner.hedged_read_min_delay_ms,
+        }
+    }
+}
+
+impl TryInto<InnerStorageNetworkConfig> for StorageNetworkConfig {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<InnerStorageNetworkConfig> {
+        if self.hedged_read_percentile > 100 {
+            return Err(ErrorCode::InvalidConfig(format!(
+                "`hedged_read_percentile` must be in [0, 100], got {}",
+                self.hedged_read_percentile
+            )));
+        }
+
+        Ok(InnerStorageNetworkConfig {
+            pool_max_idle_per_host: self.pool_max_idle_per_host,
+            pool_idle_timeout_secs: self.pool_idle_timeout_secs,
+            tcp_keepalive_secs: self.tcp_keepalive_secs,
+            connect_timeout_secs: self.connect_timeout_secs,
+            io_timeout_secs: self.io_timeout_secs,
+            hedged_read_percentile: self.hedged_read_percentile,
+            hedged_read_min_delay_ms: self.hedged_read_min_delay_ms,
+        })
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args)]
 #[serde(default)]
 pub struct FsStorageConfig {
@@ -608,6 +718,16 @@ pub struct GcsStorageConfig {
 
     #[clap(long = "storage-gcs-credential", value_name = "VALUE", default_value_t)]
     pub credential: String,
+
+    /// The service account to fetch tokens for from the metadata server, e.g. the one
+    /// bound by GKE workload identity.
+    #[clap(
+        long = "storage-gcs-service-account",
+        value_name = "VALUE",
+        default_value_t
+    )]
+    #[serde(rename = "service_account")]
+    pub gcs_service_account: String,
 }
 
 impl Default for GcsStorageConfig {
@@ -623,6 +743,7 @@ impl Debug for GcsStorageConfig {
             .field("root", &self.gcs_root)
             .field("bucket", &self.gcs_bucket)
             .field("credential", &mask_string(&self.credential, 3))
+            .field("service_account", &self.gcs_service_account)
             .finish()
     }
 }
@@ -634,6 +755,7 @@ impl From<InnerStorageGcsConfig> for GcsStorageConfig {
             gcs_bucket: inner.bucket,
             gcs_root: inner.root,
             credential: inner.credential,
+            gcs_service_account: inner.service_account,
         }
     }
 }
@@ -647,6 +769,7 @@ impl TryInto<InnerStorageGcsConfig> for GcsStorageConfig {
             bucket: self.gcs_bucket,
             root: self.gcs_root,
             credential: self.credential,
+            service_account: self.gcs_service_account,
         })
     }
 }
@@ -1442,6 +1565,20 @@ pub struct QueryConfig {
 
     #[clap(long, value_name = "VALUE", default_value = "10000")]
     pub max_query_log_size: usize,
+
+    /// The hours of the resource usage rollups kept in `system.resource_usage`.
+    #[clap(long, value_name = "VALUE", default_value = "168")]
+    pub resource_usage_retention_hours: u64,
+
+    /// The days of query logs and metric snapshots kept in the `system_history` database.
+    /// 0 disables persisting them.
+    #[clap(long, value_name = "VALUE", default_value = "0")]
+    pub log_history_retention_days: u64,
+
+    /// How often query logs and metric snapshots are flushed into the `system_history` database.
+    #[clap(long, value_name = "VALUE", default_value = "60")]
+    pub log_history_flush_interval_secs: u64,
+
     /// Parquet file with smaller size will be read as a whole file, instead of column by column.
     /// For example:
     /// parquet_fast_read_bytes = 52428800
@@ -1459,6 +1596,14 @@ pub struct QueryConfig {
     #[clap(long)]
     pub management_mode: bool,
 
+    /// If in read-only mode, DDL/DML are refused and queries are served with cached table metadata.
+    #[clap(long)]
+    pub read_only: bool,
+
+    /// How long the table metadata is cached in read-only mode.
+    #[clap(long, value_name = "VALUE", default_value = "10")]
+    pub read_only_table_cache_ttl_secs: u64,
+
     /// Deprecated: jwt_key_file is deprecated, use jwt_key_files to add a list of available jwks url
     #[clap(long, value_name = "VALUE", default_value_t)]
     pub jwt_key_file: String,
@@ -1640,8 +1785,13 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             table_engine_memory_enabled: self.table_engine_memory_enabled,
             wait_timeout_mills: self.wait_timeout_mills,
             max_query_log_size: self.max_query_log_size,
+            resource_usage_retention_hours: self.resource_usage_retention_hours,
+            log_history_retention_days: self.log_history_retention_days,
+            log_history_flush_interval_secs: self.log_history_flush_interval_secs,
             databend_enterprise_license: self.databend_enterprise_license,
             management_mode: self.management_mode,
+            read_only: self.read_only,
+            read_only_table_cache_ttl_secs: self.read_only_table_cache_ttl_secs,
             parquet_fast_read_bytes: self.parquet_fast_read_bytes,
             max_storage_io_requests: self.max_storage_io_requests,
             jwt_key_file: self.jwt_key_file,
@@ -1717,8 +1867,13 @@ impl From<InnerQueryConfig> for QueryConfig {
             table_engine_memory_enabled: inner.table_engine_memory_enabled,
             wait_timeout_mills: inner.wait_timeout_mills,
             max_query_log_size: inner.max_query_log_size,
+            resource_usage_retention_hours: inner.resource_usage_retention_hours,
+            log_history_retention_days: inner.log_history_retention_days,
+            log_history_flush_interval_secs: inner.log_history_flush_interval_secs,
             databend_enterprise_license: inner.databend_enterprise_license,
             management_mode: inner.management_mode,
+            read_only: inner.read_only,
+            read_only_table_cache_ttl_secs: inner.read_only_table_cache_ttl_secs,
             parquet_fast_read_bytes: inner.parquet_fast_read_bytes,
             max_storage_io_requests: inner.max_storage_io_requests,
             jwt_key_file: inner.jwt_key_file,
@@ -2233,6 +2388,16 @@ pub struct MetaConfig {
     )]
     pub unhealth_endpoint_evict_time: u64,
 
+    /// Allow the catalog reads of queries to be served by a meta-service follower whose data is at most this stale, in milliseconds.
+    /// DDL always reads from the leader.
+    /// 0 disables follower read, all reads are served by the leader.
+    #[clap(
+        long = "meta-follower-read-max-staleness-ms",
+        value_name = "VALUE",
+        default_value = "0"
+    )]
+    pub follower_read_max_staleness_ms: u64,
+
     /// Certificate for client to identify meta rpc serve
     #[clap(
         long = "meta-rpc-tls-meta-server-root-ca-cert",
@@ -2289,6 +2454,7 @@ impl TryInto<InnerMetaConfig> for MetaConfig {
             client_timeout_in_second: self.client_timeout_in_second,
             auto_sync_interval: self.auto_sync_interval,
             unhealth_endpoint_evict_time: self.unhealth_endpoint_evict_time,
+            follower_read_max_staleness_ms: self.follower_read_max_staleness_ms,
             rpc_tls_meta_server_root_ca_cert: self.rpc_tls_meta_server_root_ca_cert,
             rpc_tls_meta_service_domain_name: self.rpc_tls_meta_service_domain_name,
         })
@@ -2305,6 +2471,7 @@ impl From<InnerMetaConfig> for MetaConfig {
             client_timeout_in_second: inner.client_timeout_in_second,
             auto_sync_interval: inner.auto_sync_interval,
             unhealth_endpoint_evict_time: inner.unhealth_endpoint_evict_time,
+            follower_read_max_staleness_ms: inner.follower_read_max_staleness_ms,
             rpc_tls_meta_server_root_ca_cert: inner.rpc_tls_meta_server_root_ca_cert,
             rpc_tls_meta_service_domain_name: inner.rpc_tls_meta_service_domain_name,
 
@@ -2330,6 +2497,10 @@ impl Debug for MetaConfig {
                 "unhealth_endpoint_evict_time",
                 &self.unhealth_endpoint_evict_time,
             )
+            .field(
+                "follower_read_max_staleness_ms",
+                &self.follower_read_max_staleness_ms,
+            )
             .field(
                 "rpc_tls_meta_server_root_ca_cert",
                 &self.rpc_tls_meta_server_root_ca_cert,
@@ -2543,6 +2714,14 @@ pub struct CacheConfig {
     )]
     pub table_prune_partitions_count: u64,
 
+    /// Max number of cached query plans. Set it to 0 to disable it.
+    #[clap(
+        long = "cache-plan-cache-count",
+        value_name = "VALUE",
+        default_value = "1024"
+    )]
+    pub plan_cache_count: u64,
+
     /// Type of data cache storage
     #[clap(
         long = "cache-data-cache-storage",
@@ -2578,6 +2757,11 @@ pub struct CacheConfig {
     #[serde(rename = "disk")]
     pub disk_cache_config: DiskCacheConfig,
 
+    /// Local scratch disk shared by the disk cache and spilling
+    #[clap(flatten)]
+    #[serde(rename = "scratch")]
+    pub scratch_config: ScratchConfig,
+
     /// Max size of in memory table column object cache. By default it is 0 (disabled)
     ///
     /// CAUTION: The cached items are deserialized table column objects, may take a lot of memory.
@@ -2643,6 +2827,34 @@ pub struct DiskCacheConfig {
     pub path: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct ScratchConfig {
+    /// Max bytes of the local scratch disk. Default 0, which disables it.
+    #[clap(
+        long = "cache-scratch-max-bytes",
+        value_name = "VALUE",
+        default_value = "0"
+    )]
+    pub max_bytes: u64,
+
+    /// Scratch disk root path, cleared on startup
+    #[clap(
+        long = "cache-scratch-path",
+        value_name = "VALUE",
+        default_value = "./.databend/_scratch"
+    )]
+    pub path: String,
+
+    /// Max bytes of the spilled files on the scratch disk
+    #[clap(
+        long = "cache-scratch-spill-max-bytes",
+        value_name = "VALUE",
+        default_value = "10737418240"
+    )]
+    pub spill_max_bytes: u64,
+}
+
 mod cache_config_converters {
     use log::warn;
 
@@ -2717,10 +2929,12 @@ mod cache_config_converters {
                 table_bloom_index_filter_count: value.table_bloom_index_filter_count,
                 table_bloom_index_filter_size: value.table_bloom_index_filter_size,
                 table_prune_partitions_count: value.table_prune_partitions_count,
+                plan_cache_count: value.plan_cache_count,
                 data_cache_storage: value.data_cache_storage.try_into()?,
                 table_data_cache_population_queue_size: value
                     .table_data_cache_population_queue_size,
                 disk_cache_config: value.disk_cache_config.try_into()?,
+                scratch_config: value.scratch_config.try_into()?,
                 table_data_deserialized_data_bytes: value.table_data_deserialized_data_bytes,
             })
         }
@@ -2738,10 +2952,12 @@ mod cache_config_converters {
                 table_bloom_index_filter_count: value.table_bloom_index_filter_count,
                 table_bloom_index_filter_size: value.table_bloom_index_filter_size,
                 table_prune_partitions_count: value.table_prune_partitions_count,
+                plan_cache_count: value.plan_cache_count,
                 data_cache_storage: value.data_cache_storage.into(),
                 table_data_cache_population_queue_size: value
                     .table_data_cache_population_queue_size,
                 disk_cache_config: value.disk_cache_config.into(),
+                scratch_config: value.scratch_config.into(),
                 table_data_deserialized_data_bytes: value.table_data_deserialized_data_bytes,
                 table_meta_segment_count: None,
             }
@@ -2767,6 +2983,27 @@ mod cache_config_converters {
         }
     }
 
+    impl TryFrom<ScratchConfig> for inner::ScratchConfig {
+        type Error = ErrorCode;
+        fn try_from(value: ScratchConfig) -> std::result::Result<Self, Self::Error> {
+            Ok(Self {
+                max_bytes: value.max_bytes,
+                path: value.path,
+                spill_max_bytes: value.spill_max_bytes,
+            })
+        }
+    }
+
+    impl From<inner::ScratchConfig> for ScratchConfig {
+        fn from(value: inner::ScratchConfig) -> Self {
+            Self {
+                max_bytes: value.max_bytes,
+                path: value.path,
+                spill_max_bytes: value.spill_max_bytes,
+            }
+        }
+    }
+
     impl TryFrom<CacheStorageTypeConfig> for inner::CacheStorageTypeConfig {
         type Error = ErrorCode;
         fn try_from(value: CacheStorageTypeConfig) -> std::result::Result<Self, Self::Error> {
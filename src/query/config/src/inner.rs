@@ -191,9 +191,17 @@ pub struct QueryConfig {
     pub table_engine_memory_enabled: bool,
     pub wait_timeout_mills: u64,
     pub max_query_log_size: usize,
+    /// The hours of the resource usage rollups kept in `system.resource_usage`.
+    pub resource_usage_retention_hours: u64,
+    /// The days of query logs and metric snapshots kept in `system_history`, 0 means disabled.
+    pub log_history_retention_days: u64,
+    pub log_history_flush_interval_secs: u64,
     pub databend_enterprise_license: Option<String>,
     /// If in management mode, only can do some meta level operations(database/table/user/stage etc.) with metasrv.
     pub management_mode: bool,
+    /// If in read-only mode, DDL/DML are refused and queries are served with cached table metadata.
+    pub read_only: bool,
+    pub read_only_table_cache_ttl_secs: u64,
 
     pub parquet_fast_read_bytes: Option<u64>,
     pub max_storage_io_requests: Option<u64>,
@@ -265,8 +273,13 @@ impl Default for QueryConfig {
             table_engine_memory_enabled: true,
             wait_timeout_mills: 5000,
             max_query_log_size: 10_000,
+            resource_usage_retention_hours: 168,
+            log_history_retention_days: 0,
+            log_history_flush_interval_secs: 60,
             databend_enterprise_license: None,
             management_mode: false,
+            read_only: false,
+            read_only_table_cache_ttl_secs: 10,
             parquet_fast_read_bytes: None,
             max_storage_io_requests: None,
             jwt_key_file: "".to_string(),
@@ -329,6 +342,9 @@ pub struct MetaConfig {
     /// 0 disables auto-sync. By default auto-sync is disabled.
     pub auto_sync_interval: u64,
     pub unhealth_endpoint_evict_time: u64,
+    /// The max staleness in milliseconds of a catalog read served by a meta-service follower.
+    /// 0 disables follower read.
+    pub follower_read_max_staleness_ms: u64,
     /// Certificate for client to identify meta rpc serve
     pub rpc_tls_meta_server_root_ca_cert: String,
     pub rpc_tls_meta_service_domain_name: String,
@@ -344,6 +360,7 @@ impl Default for MetaConfig {
             client_timeout_in_second: 10,
             auto_sync_interval: 0,
             unhealth_endpoint_evict_time: 120,
+            follower_read_max_staleness_ms: 0,
             rpc_tls_meta_server_root_ca_cert: "".to_string(),
             rpc_tls_meta_service_domain_name: "localhost".to_string(),
         }
@@ -403,6 +420,11 @@ impl MetaConfig {
                 None
             },
             unhealthy_endpoint_evict_time: Duration::from_secs(self.unhealth_endpoint_evict_time),
+            follower_read_max_staleness: if self.follower_read_max_staleness_ms > 0 {
+                Some(Duration::from_millis(self.follower_read_max_staleness_ms))
+            } else {
+                None
+            },
         }
     }
 }
@@ -420,6 +442,10 @@ impl Debug for MetaConfig {
                 "unhealth_endpoint_evict_time",
                 &self.unhealth_endpoint_evict_time,
             )
+            .field(
+                "follower_read_max_staleness_ms",
+                &self.follower_read_max_staleness_ms,
+            )
             .field(
                 "rpc_tls_meta_server_root_ca_cert",
                 &self.rpc_tls_meta_server_root_ca_cert,
@@ -517,6 +543,9 @@ pub struct CacheConfig {
     /// Max number of cached prune partitions objects. Set it to 0 to disable it.
     pub table_prune_partitions_count: u64,
 
+    /// Max number of cached query plans. Set it to 0 to disable it.
+    pub plan_cache_count: u64,
+
     /// Max number of cached bloom index filters. Set it to 0 to disable it.
     // One bloom index filter per column of data block being indexed will be generated if necessary.
     //
@@ -546,6 +575,9 @@ pub struct CacheConfig {
     /// Storage that hold the raw data caches
     pub disk_cache_config: DiskCacheConfig,
 
+    /// Local scratch disk shared by the disk cache and spilling
+    pub scratch_config: ScratchConfig,
+
     /// Max size of in memory table column object cache. By default it is 0 (disabled)
     ///
     /// CAUTION: The cache items are deserialized table column objects, may take a lot of memory.
@@ -595,6 +627,32 @@ impl Default for DiskCacheConfig {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScratchConfig {
+    /// Max bytes of the local scratch disk. Default 0, which disables it.
+    ///
+    /// If enabled, the disk cache and the spilled files share this budget, the spilled
+    /// files evict the disk cache if it's used up.
+    pub max_bytes: u64,
+
+    /// Scratch disk root path, cleared on startup
+    pub path: String,
+
+    /// Max bytes of the spilled files on the scratch disk. Spilling falls back to the
+    /// object storage if it's used up.
+    pub spill_max_bytes: u64,
+}
+
+impl Default for ScratchConfig {
+    fn default() -> Self {
+        Self {
+            max_bytes: 0,
+            path: "./.databend/_scratch".to_owned(),
+            spill_max_bytes: 10737418240,
+        }
+    }
+}
+
 impl Default for CacheConfig {
     fn default() -> Self {
         Self {
@@ -607,9 +665,11 @@ impl Default for CacheConfig {
             table_bloom_index_filter_count: 0,
             table_bloom_index_filter_size: 2147483648,
             table_prune_partitions_count: 256,
+            plan_cache_count: 1024,
             data_cache_storage: Default::default(),
             table_data_cache_population_queue_size: 0,
             disk_cache_config: Default::default(),
+            scratch_config: Default::default(),
             table_data_deserialized_data_bytes: 0,
         }
     }
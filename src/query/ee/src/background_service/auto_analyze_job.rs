@@ -0,0 +1,340 @@
+// Copyright 2023 Databend Cloud
+//
+// Licensed under the Elastic License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.elastic.co/licensing/elastic-license
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_base::base::tokio::time::Instant;
+use common_base::base::uuid::Uuid;
+use common_catalog::catalog::CATALOG_DEFAULT;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_config::InnerConfig;
+use common_exception::Result;
+use common_meta_api::BackgroundApi;
+use common_meta_app::background::BackgroundJobIdent;
+use common_meta_app::background::BackgroundJobInfo;
+use common_meta_app::background::BackgroundJobParams;
+use common_meta_app::background::BackgroundJobStatus;
+use common_meta_app::background::BackgroundTaskIdent;
+use common_meta_app::background::BackgroundTaskInfo;
+use common_meta_app::background::BackgroundTaskState;
+use common_meta_app::background::GetBackgroundJobReq;
+use common_meta_app::background::ManualTriggerParams;
+use common_meta_app::background::UpdateBackgroundJobParamsReq;
+use common_meta_app::background::UpdateBackgroundJobStatusReq;
+use common_meta_app::background::UpdateBackgroundTaskReq;
+use common_meta_store::MetaStore;
+use common_metrics::storage::metrics_inc_auto_analyze_milliseconds;
+use common_metrics::storage::metrics_inc_auto_analyze_tables;
+use common_storages_fuse::FuseTable;
+use common_users::UserApiProvider;
+use databend_query::sessions::QueryContext;
+use databend_query::sessions::Session;
+use databend_query::table_functions::SuggestedBackgroundTasksSource;
+use log::as_debug;
+use log::debug;
+use log::error;
+use log::info;
+use log::warn;
+
+use crate::background_service::job::Job;
+use crate::background_service::session::create_session;
+
+const EXPIRE_SEC: u64 = 60 * 60 * 24 * 7; // 7 days
+
+/// A fuse table whose statistics are stale.
+struct StaleTable {
+    database: String,
+    table: Arc<dyn Table>,
+    num_rows: u64,
+    changed_rows: u64,
+}
+
+/// Refreshes the statistics of the fuse tables whose data changed significantly since they
+/// were last analyzed, so that the optimizer does not depend on manual `ANALYZE TABLE`.
+///
+/// The change of a table is measured by the difference between its current number of rows
+/// and the number of rows its statistics are built from. Each run analyzes at most
+/// `max_tables` tables one by one, the most changed ones first; the others are left to the
+/// following runs.
+#[derive(Clone)]
+pub struct AutoAnalyzeJob {
+    conf: InnerConfig,
+    meta_api: Arc<MetaStore>,
+    creator: BackgroundJobIdent,
+}
+
+#[async_trait::async_trait]
+impl Job for AutoAnalyzeJob {
+    async fn run(&mut self) {
+        info!(background = true, job_name = as_debug!(&self.creator.clone()); "Auto analyze job started");
+        if let Err(e) = self.do_auto_analyze_job().await {
+            error!(job = "auto_analyze", background = true; "auto analyze job failed: {}", e);
+        }
+    }
+
+    async fn get_info(&self) -> Result<BackgroundJobInfo> {
+        let job = self
+            .meta_api
+            .get_background_job(GetBackgroundJobReq {
+                name: self.creator.clone(),
+            })
+            .await?;
+        Ok(job.info)
+    }
+
+    fn get_name(&self) -> BackgroundJobIdent {
+        self.creator.clone()
+    }
+
+    async fn update_job_status(&mut self, status: BackgroundJobStatus) -> Result<()> {
+        self.meta_api
+            .update_background_job_status(UpdateBackgroundJobStatusReq {
+                job_name: self.creator.clone(),
+                status: status.clone(),
+            })
+            .await?;
+        Ok(())
+    }
+
+    async fn update_job_params(&mut self, param: BackgroundJobParams) -> Result<()> {
+        self.meta_api
+            .update_background_job_params(UpdateBackgroundJobParamsReq {
+                job_name: self.creator.clone(),
+                params: param.clone(),
+            })
+            .await?;
+        Ok(())
+    }
+}
+
+impl AutoAnalyzeJob {
+    pub async fn create(config: &InnerConfig, name: String) -> Self {
+        let tenant = config.query.tenant_id.clone();
+        let creator = BackgroundJobIdent { tenant, name };
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        Self {
+            conf: config.clone(),
+            meta_api,
+            creator,
+        }
+    }
+
+    async fn do_auto_analyze_job(&mut self) -> Result<()> {
+        let session = create_session(&self.conf).await?;
+        let ctx = session.create_query_context().await?;
+
+        // guarantee at least once for manual job
+        let mut params = self.get_info().await?.job_params.unwrap_or_default();
+        let manual = params.manual_trigger_params.take();
+        self.update_job_params(params).await?;
+
+        let config = &self.conf.background.auto_analyze;
+        let mut stale_tables =
+            Self::get_stale_tables(ctx, config.change_percent, config.min_changed_rows).await?;
+        let total = stale_tables.len();
+        stale_tables.sort_by(|a, b| b.changed_rows.cmp(&a.changed_rows));
+        stale_tables.truncate(config.max_tables as usize);
+        if stale_tables.len() < total {
+            info!(
+                "auto analyze job throttled, {} of {} stale tables are left to the next run",
+                total - stale_tables.len(),
+                total
+            );
+        }
+
+        for stale in stale_tables {
+            let table_name = stale.table.name().to_string();
+            match self
+                .analyze_table(session.clone(), &stale, manual.clone())
+                .await
+            {
+                Ok(_) => {
+                    info!(
+                        "auto analyze job success, db: {}, table: {}, changed rows: {}",
+                        stale.database, table_name, stale.changed_rows
+                    );
+                }
+                Err(e) => {
+                    error!(
+                        "auto analyze job failed, db: {}, table: {}, err: {}",
+                        stale.database, table_name, e
+                    );
+                }
+            }
+        }
+        info!(
+            job = "auto_analyze",
+            background = true;
+            "auto analyze task is done"
+        );
+        Ok(())
+    }
+
+    async fn get_stale_tables(
+        ctx: Arc<QueryContext>,
+        change_percent: u64,
+        min_changed_rows: u64,
+    ) -> Result<Vec<StaleTable>> {
+        let tenant = ctx.get_tenant();
+        let catalog = ctx.get_catalog(CATALOG_DEFAULT).await?;
+        let mut tables = vec![];
+        for database in catalog.list_databases(&tenant).await? {
+            let db_name = database.name();
+            if db_name == "system" || db_name == "information_schema" {
+                continue;
+            }
+            for table in database.list_tables().await? {
+                if table.engine() != "FUSE" || table.is_read_only() {
+                    continue;
+                }
+                let num_rows = table.get_table_info().meta.statistics.number_of_rows;
+                let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+                let analyzed_rows = match fuse_table.analyzed_row_count().await {
+                    Ok(rows) => rows,
+                    Err(e) => {
+                        warn!(
+                            "auto analyze job skips table, db: {}, table: {}, err: {}",
+                            db_name,
+                            table.name(),
+                            e
+                        );
+                        continue;
+                    }
+                };
+                if Self::should_analyze(num_rows, analyzed_rows, change_percent, min_changed_rows) {
+                    tables.push(StaleTable {
+                        database: db_name.to_string(),
+                        table,
+                        num_rows,
+                        changed_rows: Self::changed_rows(num_rows, analyzed_rows),
+                    });
+                }
+            }
+        }
+        Ok(tables)
+    }
+
+    // tables never analyzed are considered to be changed by all of their rows.
+    pub fn changed_rows(num_rows: u64, analyzed_rows: Option<u64>) -> u64 {
+        num_rows.abs_diff(analyzed_rows.unwrap_or(0))
+    }
+
+    // a table is analyzed once both `change_percent` of its analyzed rows and
+    // `min_changed_rows` rows are changed.
+    pub fn should_analyze(
+        num_rows: u64,
+        analyzed_rows: Option<u64>,
+        change_percent: u64,
+        min_changed_rows: u64,
+    ) -> bool {
+        let changed_rows = Self::changed_rows(num_rows, analyzed_rows);
+        if changed_rows == 0 || changed_rows < min_changed_rows {
+            return false;
+        }
+        let analyzed_rows = analyzed_rows.unwrap_or(0);
+        changed_rows as u128 * 100 >= analyzed_rows as u128 * change_percent as u128
+    }
+
+    async fn analyze_table(
+        &mut self,
+        session: Arc<Session>,
+        stale: &StaleTable,
+        manual: Option<ManualTriggerParams>,
+    ) -> Result<()> {
+        let database = stale.database.as_str();
+        let table = stale.table.name();
+
+        let id = Uuid::new_v4().to_string();
+        let mut status = self.get_info().await?.job_status.unwrap_or_default();
+        status.last_task_id = Some(id.clone());
+        status.last_task_run_at = Some(Utc::now());
+        self.update_job_status(status).await?;
+
+        info!(job = "auto_analyze", background = true, id = id.clone(), database = database, table = table, changed_rows = stale.changed_rows; "start auto analyze");
+        let task_name = BackgroundTaskIdent {
+            tenant: self.creator.tenant.clone(),
+            task_id: id,
+        };
+        let mut info = BackgroundTaskInfo::new_analyze_task(
+            self.creator.clone(),
+            manual,
+            format!(
+                "db: {}, table: {}, rows: {}, changed rows: {}",
+                database, table, stale.num_rows, stale.changed_rows
+            ),
+        );
+        self.update_task(&task_name, &info).await?;
+
+        let start = Instant::now();
+        let res = Self::do_analyze_table(session, database, table).await;
+        metrics_inc_auto_analyze_tables(1);
+        metrics_inc_auto_analyze_milliseconds(start.elapsed().as_millis() as u64);
+
+        info.last_updated = Some(Utc::now());
+        match res {
+            Ok(_) => {
+                info.message = format!(
+                    "db: {}, table: {}, analyzed rows: {}, cost: {} ms",
+                    database,
+                    table,
+                    stale.num_rows,
+                    start.elapsed().as_millis()
+                );
+                info.task_state = BackgroundTaskState::DONE;
+                self.update_task(&task_name, &info).await?;
+                Ok(())
+            }
+            Err(e) => {
+                info.message = format!("auto analyze failed: {:?}", e);
+                info.task_state = BackgroundTaskState::FAILED;
+                self.update_task(&task_name, &info).await?;
+                Err(e)
+            }
+        }
+    }
+
+    async fn update_task(
+        &self,
+        task_name: &BackgroundTaskIdent,
+        info: &BackgroundTaskInfo,
+    ) -> Result<()> {
+        self.meta_api
+            .update_background_task(UpdateBackgroundTaskReq {
+                task_name: task_name.clone(),
+                task_info: info.clone(),
+                expire_at: Utc::now().timestamp() as u64 + EXPIRE_SEC,
+            })
+            .await?;
+        Ok(())
+    }
+
+    async fn do_analyze_table(session: Arc<Session>, database: &str, table: &str) -> Result<()> {
+        let sql = Self::get_analyze_table_sql(database, table);
+        debug!(
+            job = "auto_analyze",
+            background = true,
+            sql = sql.as_str();
+            "analyze table"
+        );
+        let ctx = session.create_query_context().await?;
+        SuggestedBackgroundTasksSource::do_execute_sql(ctx, sql).await?;
+        Ok(())
+    }
+
+    pub fn get_analyze_table_sql(database: &str, table: &str) -> String {
+        format!("ANALYZE TABLE `{}`.`{}`;", database, table)
+    }
+}
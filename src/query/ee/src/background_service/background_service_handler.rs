@@ -47,8 +47,10 @@ use log::warn;
 
 use crate::background_service::session::create_session;
 use crate::background_service::session::get_background_service_user;
+use crate::background_service::AutoAnalyzeJob;
 use crate::background_service::CompactionJob;
 use crate::background_service::JobScheduler;
+use crate::background_service::RowTtlJob;
 
 pub struct RealBackgroundService {
     conf: InnerConfig,
@@ -148,6 +150,21 @@ impl RealBackgroundService {
             .await?;
             scheduler.add_job(compactor_job).await?;
         }
+        if conf.background.row_ttl.enable {
+            let row_ttl_job =
+                RealBackgroundService::get_row_ttl_job(meta_api.clone(), conf, &user.identity())
+                    .await?;
+            scheduler.add_job(row_ttl_job).await?;
+        }
+        if conf.background.auto_analyze.enable {
+            let auto_analyze_job = RealBackgroundService::get_auto_analyze_job(
+                meta_api.clone(),
+                conf,
+                &user.identity(),
+            )
+            .await?;
+            scheduler.add_job(auto_analyze_job).await?;
+        }
 
         let rm = RealBackgroundService {
             conf: conf.clone(),
@@ -194,17 +211,76 @@ impl RealBackgroundService {
             creator.clone(),
         )
         .await?;
-        Self::update_compaction_job_params(meta.clone(), &id, conf).await?;
+        Self::update_job_params(meta.clone(), &id, conf.background.compaction.params.clone())
+            .await?;
         Self::suspend_job(meta.clone(), &id, false).await?;
 
         let job = CompactionJob::create(conf, id.name, finish_tx).await;
         Ok(job)
     }
 
-    async fn update_compaction_job_params(
+    pub fn get_row_ttl_job_name(tenant: String) -> String {
+        format!("{}-row-ttl-job", tenant)
+    }
+
+    async fn get_row_ttl_job(
         meta: Arc<MetaStore>,
-        id: &BackgroundJobIdent,
         conf: &InnerConfig,
+        creator: &UserIdentity,
+    ) -> Result<RowTtlJob> {
+        let name = RealBackgroundService::get_row_ttl_job_name(conf.query.tenant_id.clone());
+        let id = BackgroundJobIdent {
+            tenant: conf.query.tenant_id.clone(),
+            name,
+        };
+        let params = conf.background.row_ttl.params.clone();
+        let info = BackgroundJobInfo::new_row_ttl_job(params.clone(), creator.clone());
+        meta.create_background_job(CreateBackgroundJobReq {
+            if_not_exists: true,
+            job_name: id.clone(),
+            job_info: info,
+        })
+        .await?;
+        Self::update_job_params(meta.clone(), &id, params).await?;
+        Self::suspend_job(meta.clone(), &id, false).await?;
+
+        let job = RowTtlJob::create(conf, id.name).await;
+        Ok(job)
+    }
+
+    pub fn get_auto_analyze_job_name(tenant: String) -> String {
+        format!("{}-auto-analyze-job", tenant)
+    }
+
+    async fn get_auto_analyze_job(
+        meta: Arc<MetaStore>,
+        conf: &InnerConfig,
+        creator: &UserIdentity,
+    ) -> Result<AutoAnalyzeJob> {
+        let name = RealBackgroundService::get_auto_analyze_job_name(conf.query.tenant_id.clone());
+        let id = BackgroundJobIdent {
+            tenant: conf.query.tenant_id.clone(),
+            name,
+        };
+        let params = conf.background.auto_analyze.params.clone();
+        let info = BackgroundJobInfo::new_analyze_job(params.clone(), creator.clone());
+        meta.create_background_job(CreateBackgroundJobReq {
+            if_not_exists: true,
+            job_name: id.clone(),
+            job_info: info,
+        })
+        .await?;
+        Self::update_job_params(meta.clone(), &id, params).await?;
+        Self::suspend_job(meta.clone(), &id, false).await?;
+
+        let job = AutoAnalyzeJob::create(conf, id.name).await;
+        Ok(job)
+    }
+
+    async fn update_job_params(
+        meta: Arc<MetaStore>,
+        id: &BackgroundJobIdent,
+        params: BackgroundJobParams,
     ) -> Result<()> {
         // create job if not exist
         let info = meta
@@ -214,7 +290,7 @@ impl RealBackgroundService {
         if info.job_params.is_some() {
             meta.update_background_job_params(UpdateBackgroundJobParamsReq {
                 job_name: id.clone(),
-                params: conf.background.compaction.params.clone(),
+                params,
             })
             .await?;
         }
@@ -12,14 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod auto_analyze_job;
 mod background_service_handler;
 mod compaction_job;
 mod job;
 mod job_scheduler;
+mod row_ttl_job;
 mod session;
 
+pub use auto_analyze_job::AutoAnalyzeJob;
 pub use background_service_handler::RealBackgroundService;
 pub use compaction_job::should_continue_compaction;
 pub use compaction_job::CompactionJob;
 pub use job::Job;
 pub use job_scheduler::JobScheduler;
+pub use row_ttl_job::RowTtlJob;
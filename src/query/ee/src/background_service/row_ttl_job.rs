@@ -0,0 +1,364 @@
+// Copyright 2023 Databend Cloud
+//
+// Licensed under the Elastic License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.elastic.co/licensing/elastic-license
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use arrow_array::UInt64Array;
+use chrono::Utc;
+use common_base::base::tokio::time::Instant;
+use common_base::base::uuid::Uuid;
+use common_catalog::catalog::CATALOG_DEFAULT;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_config::InnerConfig;
+use common_exception::Result;
+use common_meta_api::BackgroundApi;
+use common_meta_app::background::BackgroundJobIdent;
+use common_meta_app::background::BackgroundJobInfo;
+use common_meta_app::background::BackgroundJobParams;
+use common_meta_app::background::BackgroundJobStatus;
+use common_meta_app::background::BackgroundTaskIdent;
+use common_meta_app::background::BackgroundTaskInfo;
+use common_meta_app::background::BackgroundTaskState;
+use common_meta_app::background::GetBackgroundJobReq;
+use common_meta_app::background::ManualTriggerParams;
+use common_meta_app::background::UpdateBackgroundJobParamsReq;
+use common_meta_app::background::UpdateBackgroundJobStatusReq;
+use common_meta_app::background::UpdateBackgroundTaskReq;
+use common_meta_store::MetaStore;
+use common_metrics::storage::metrics_inc_row_ttl_delete_batches;
+use common_metrics::storage::metrics_inc_row_ttl_expired_rows;
+use common_metrics::storage::metrics_inc_row_ttl_milliseconds;
+use common_sql::parse_row_ttl_to_string;
+use common_users::UserApiProvider;
+use databend_query::sessions::QueryContext;
+use databend_query::sessions::Session;
+use databend_query::table_functions::SuggestedBackgroundTasksSource;
+use log::as_debug;
+use log::debug;
+use log::error;
+use log::info;
+use storages_common_table_meta::table::OPT_KEY_ROW_TTL;
+
+use crate::background_service::job::Job;
+use crate::background_service::session::create_session;
+
+const EXPIRE_SEC: u64 = 60 * 60 * 24 * 7; // 7 days
+
+/// Deletes the expired rows of the fuse tables with the `row_ttl` option.
+///
+/// The predicate of a table is bound once per run with `now()` pinned to the start of the
+/// run, and the rows matching it are deleted by several deletions of at most `batch_blocks`
+/// blocks, until none of them is left.
+#[derive(Clone)]
+pub struct RowTtlJob {
+    conf: InnerConfig,
+    meta_api: Arc<MetaStore>,
+    creator: BackgroundJobIdent,
+}
+
+#[async_trait::async_trait]
+impl Job for RowTtlJob {
+    async fn run(&mut self) {
+        info!(background = true, job_name = as_debug!(&self.creator.clone()); "Row ttl job started");
+        if let Err(e) = self.do_row_ttl_job().await {
+            error!(job = "row_ttl", background = true; "row ttl job failed: {}", e);
+        }
+    }
+
+    async fn get_info(&self) -> Result<BackgroundJobInfo> {
+        let job = self
+            .meta_api
+            .get_background_job(GetBackgroundJobReq {
+                name: self.creator.clone(),
+            })
+            .await?;
+        Ok(job.info)
+    }
+
+    fn get_name(&self) -> BackgroundJobIdent {
+        self.creator.clone()
+    }
+
+    async fn update_job_status(&mut self, status: BackgroundJobStatus) -> Result<()> {
+        self.meta_api
+            .update_background_job_status(UpdateBackgroundJobStatusReq {
+                job_name: self.creator.clone(),
+                status: status.clone(),
+            })
+            .await?;
+        Ok(())
+    }
+
+    async fn update_job_params(&mut self, param: BackgroundJobParams) -> Result<()> {
+        self.meta_api
+            .update_background_job_params(UpdateBackgroundJobParamsReq {
+                job_name: self.creator.clone(),
+                params: param.clone(),
+            })
+            .await?;
+        Ok(())
+    }
+}
+
+impl RowTtlJob {
+    pub async fn create(config: &InnerConfig, name: String) -> Self {
+        let tenant = config.query.tenant_id.clone();
+        let creator = BackgroundJobIdent { tenant, name };
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        Self {
+            conf: config.clone(),
+            meta_api,
+            creator,
+        }
+    }
+
+    async fn do_row_ttl_job(&mut self) -> Result<()> {
+        let session = create_session(&self.conf).await?;
+        session
+            .get_settings()
+            .set_deletion_block_limit(self.conf.background.row_ttl.batch_blocks)?;
+        let ctx = session.create_query_context().await?;
+
+        // guarantee at least once for manual job
+        let mut params = self.get_info().await?.job_params.unwrap_or_default();
+        let manual = params.manual_trigger_params.take();
+        self.update_job_params(params).await?;
+
+        let now = Utc::now().timestamp_micros();
+        for (database, table) in Self::get_row_ttl_tables(ctx).await? {
+            match self
+                .expire_table(
+                    session.clone(),
+                    &database,
+                    table.clone(),
+                    now,
+                    manual.clone(),
+                )
+                .await
+            {
+                Ok(rows) => {
+                    info!(
+                        "row ttl job success, db: {}, table: {}, expired rows: {}",
+                        database,
+                        table.name(),
+                        rows
+                    );
+                }
+                Err(e) => {
+                    error!(
+                        "row ttl job failed, db: {}, table: {}, err: {}",
+                        database,
+                        table.name(),
+                        e
+                    );
+                }
+            }
+        }
+        info!(
+            job = "row_ttl",
+            background = true;
+            "row ttl task is done"
+        );
+        Ok(())
+    }
+
+    async fn get_row_ttl_tables(ctx: Arc<QueryContext>) -> Result<Vec<(String, Arc<dyn Table>)>> {
+        let tenant = ctx.get_tenant();
+        let catalog = ctx.get_catalog(CATALOG_DEFAULT).await?;
+        let mut tables = vec![];
+        for database in catalog.list_databases(&tenant).await? {
+            let db_name = database.name();
+            if db_name == "system" || db_name == "information_schema" {
+                continue;
+            }
+            for table in database.list_tables().await? {
+                if table.engine() == "FUSE" && table.options().contains_key(OPT_KEY_ROW_TTL) {
+                    tables.push((db_name.to_string(), table));
+                }
+            }
+        }
+        Ok(tables)
+    }
+
+    // returns the number of the deleted rows
+    async fn expire_table(
+        &mut self,
+        session: Arc<Session>,
+        database: &str,
+        table: Arc<dyn Table>,
+        now: i64,
+        manual: Option<ManualTriggerParams>,
+    ) -> Result<u64> {
+        let ctx = session.create_query_context().await?;
+        let predicate =
+            parse_row_ttl_to_string(ctx, table.schema(), &table.options()[OPT_KEY_ROW_TTL], now)?;
+        let mut expired_rows =
+            Self::do_count_expired_rows(session.clone(), database, table.name(), &predicate)
+                .await?;
+        if expired_rows == 0 {
+            return Ok(0);
+        }
+
+        let id = Uuid::new_v4().to_string();
+        let mut status = self.get_info().await?.job_status.unwrap_or_default();
+        status.last_task_id = Some(id.clone());
+        status.last_task_run_at = Some(Utc::now());
+        self.update_job_status(status).await?;
+
+        info!(job = "row_ttl", background = true, id = id.clone(), database = database, table = table.name(), expired_rows = expired_rows; "start row ttl");
+        let task_name = BackgroundTaskIdent {
+            tenant: self.creator.tenant.clone(),
+            task_id: id,
+        };
+        let mut info = BackgroundTaskInfo::new_row_ttl_task(
+            self.creator.clone(),
+            manual,
+            format!(
+                "db: {}, table: {}, expired rows: {}",
+                database,
+                table.name(),
+                expired_rows
+            ),
+        );
+        self.update_task(&task_name, &info).await?;
+
+        let start = Instant::now();
+        let mut deleted_rows = 0;
+        let mut batches = 0;
+        let res = loop {
+            if let Err(e) =
+                Self::do_delete_expired_rows(session.clone(), database, table.name(), &predicate)
+                    .await
+            {
+                break Err(e);
+            }
+            batches += 1;
+            let remaining_rows = match Self::do_count_expired_rows(
+                session.clone(),
+                database,
+                table.name(),
+                &predicate,
+            )
+            .await
+            {
+                Ok(rows) => rows,
+                Err(e) => break Err(e),
+            };
+            deleted_rows += expired_rows.saturating_sub(remaining_rows);
+            // stop if the last deletion made no progress, e.g. the table is being mutated
+            if remaining_rows == 0 || remaining_rows >= expired_rows {
+                break Ok(());
+            }
+            expired_rows = remaining_rows;
+        };
+
+        metrics_inc_row_ttl_expired_rows(deleted_rows);
+        metrics_inc_row_ttl_delete_batches(batches);
+        metrics_inc_row_ttl_milliseconds(start.elapsed().as_millis() as u64);
+
+        info.last_updated = Some(Utc::now());
+        match res {
+            Ok(_) => {
+                info.message = format!(
+                    "db: {}, table: {}, deleted rows: {}, batches: {}",
+                    database,
+                    table.name(),
+                    deleted_rows,
+                    batches
+                );
+                info.task_state = BackgroundTaskState::DONE;
+                self.update_task(&task_name, &info).await?;
+                Ok(deleted_rows)
+            }
+            Err(e) => {
+                info.message = format!("row ttl failed: {:?}", e);
+                info.task_state = BackgroundTaskState::FAILED;
+                self.update_task(&task_name, &info).await?;
+                Err(e)
+            }
+        }
+    }
+
+    async fn update_task(
+        &self,
+        task_name: &BackgroundTaskIdent,
+        info: &BackgroundTaskInfo,
+    ) -> Result<()> {
+        self.meta_api
+            .update_background_task(UpdateBackgroundTaskReq {
+                task_name: task_name.clone(),
+                task_info: info.clone(),
+                expire_at: Utc::now().timestamp() as u64 + EXPIRE_SEC,
+            })
+            .await?;
+        Ok(())
+    }
+
+    async fn do_count_expired_rows(
+        session: Arc<Session>,
+        database: &str,
+        table: &str,
+        predicate: &str,
+    ) -> Result<u64> {
+        let sql = Self::get_expired_rows_sql(database, table, predicate);
+        debug!(
+            job = "row_ttl",
+            background = true,
+            sql = sql.as_str();
+            "count expired rows"
+        );
+        let ctx = session.create_query_context().await?;
+        let res = SuggestedBackgroundTasksSource::do_execute_sql(ctx, sql).await?;
+        Ok(res.map_or(0, |res| {
+            res.column(0)
+                .as_any()
+                .downcast_ref::<UInt64Array>()
+                .unwrap()
+                .value(0)
+        }))
+    }
+
+    async fn do_delete_expired_rows(
+        session: Arc<Session>,
+        database: &str,
+        table: &str,
+        predicate: &str,
+    ) -> Result<()> {
+        let sql = Self::get_delete_expired_rows_sql(database, table, predicate);
+        debug!(
+            job = "row_ttl",
+            background = true,
+            sql = sql.as_str();
+            "delete expired rows"
+        );
+        let ctx = session.create_query_context().await?;
+        SuggestedBackgroundTasksSource::do_execute_sql(ctx, sql).await?;
+        Ok(())
+    }
+
+    pub fn get_expired_rows_sql(database: &str, table: &str, predicate: &str) -> String {
+        format!(
+            "SELECT count(*) FROM `{}`.`{}` WHERE {};",
+            database, table, predicate
+        )
+    }
+
+    pub fn get_delete_expired_rows_sql(database: &str, table: &str, predicate: &str) -> String {
+        format!(
+            "DELETE FROM `{}`.`{}` WHERE {};",
+            database, table, predicate
+        )
+    }
+}
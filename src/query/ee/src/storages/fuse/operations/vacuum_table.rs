@@ -129,10 +129,19 @@ pub async fn get_snapshot_referenced_files(
     segments_vec.into_iter().for_each(|(location, _)| {
         segments.insert(location);
     });
+
+    // 3. Files shared with the clones of this table are referenced as well
+    let shared_files = fuse_table.read_clone_shared_files(ctx).await?;
+    let mut blocks = locations_referenced.block_location;
+    let mut blocks_index = locations_referenced.bloom_location;
+    segments.extend(shared_files.segments);
+    blocks.extend(shared_files.locations.block_location);
+    blocks_index.extend(shared_files.locations.bloom_location);
+
     Ok(Some(SnapshotReferencedFiles {
         segments,
-        blocks: locations_referenced.block_location,
-        blocks_index: locations_referenced.bloom_location,
+        blocks,
+        blocks_index,
     }))
 }
 
@@ -149,7 +158,9 @@ async fn get_orphan_files_to_be_purged(
             if let Some(prefix) = prefix {
                 fuse_table
                     .list_files(prefix, |location, modified| {
-                        modified <= retention_time && !referenced_files.contains(&location)
+                        modified <= retention_time
+                            && !referenced_files.contains(&location)
+                            && fuse_table.is_owned_location(&location)
                     })
                     .await?
             } else {
@@ -0,0 +1,72 @@
+// Copyright 2023 Databend Cloud
+//
+// Licensed under the Elastic License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.elastic.co/licensing/elastic-license
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use enterprise_query::background_service::AutoAnalyzeJob;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_get_analyze_table_sql() -> Result<()> {
+    let sql = AutoAnalyzeJob::get_analyze_table_sql("db1", "tbl1");
+    assert_eq!(sql.trim(), "ANALYZE TABLE `db1`.`tbl1`;");
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_should_analyze() -> Result<()> {
+    // never analyzed
+    assert!(AutoAnalyzeJob::should_analyze(1000, None, 10, 1000));
+    assert!(!AutoAnalyzeJob::should_analyze(999, None, 10, 1000));
+    assert!(!AutoAnalyzeJob::should_analyze(0, None, 10, 0));
+
+    // not changed
+    assert!(!AutoAnalyzeJob::should_analyze(
+        100_000,
+        Some(100_000),
+        10,
+        0
+    ));
+
+    // changed by 10%, in both directions
+    assert!(AutoAnalyzeJob::should_analyze(
+        110_000,
+        Some(100_000),
+        10,
+        1000
+    ));
+    assert!(AutoAnalyzeJob::should_analyze(
+        90_000,
+        Some(100_000),
+        10,
+        1000
+    ));
+    assert!(!AutoAnalyzeJob::should_analyze(
+        109_999,
+        Some(100_000),
+        10,
+        1000
+    ));
+
+    // too few changed rows
+    assert!(!AutoAnalyzeJob::should_analyze(
+        1_500,
+        Some(1_000),
+        10,
+        1000
+    ));
+    assert!(AutoAnalyzeJob::should_analyze(2_000, Some(1_000), 10, 1000));
+
+    assert_eq!(AutoAnalyzeJob::changed_rows(90, Some(100)), 10);
+    assert_eq!(AutoAnalyzeJob::changed_rows(90, None), 90);
+    Ok(())
+}
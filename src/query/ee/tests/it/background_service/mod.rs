@@ -12,5 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod auto_analyze_job;
 mod compaction_job;
 mod job_scheduler;
+mod row_ttl_job;
@@ -0,0 +1,40 @@
+// Copyright 2023 Databend Cloud
+//
+// Licensed under the Elastic License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.elastic.co/licensing/elastic-license
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use enterprise_query::background_service::RowTtlJob;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_get_expired_rows_sql() -> Result<()> {
+    let sql = RowTtlJob::get_expired_rows_sql("db1", "tbl1", "ts < to_timestamp(1700000000000000)");
+    assert_eq!(
+        sql.trim(),
+        "SELECT count(*) FROM `db1`.`tbl1` WHERE ts < to_timestamp(1700000000000000);"
+    );
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_get_delete_expired_rows_sql() -> Result<()> {
+    let sql = RowTtlJob::get_delete_expired_rows_sql(
+        "db1",
+        "tbl1",
+        "ts < to_timestamp(1700000000000000)",
+    );
+    assert_eq!(
+        sql.trim(),
+        "DELETE FROM `db1`.`tbl1` WHERE ts < to_timestamp(1700000000000000);"
+    );
+    Ok(())
+}
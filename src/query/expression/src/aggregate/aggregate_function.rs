@@ -17,6 +17,7 @@ use std::fmt;
 use std::sync::Arc;
 
 use common_arrow::arrow::bitmap::Bitmap;
+use common_exception::ErrorCode;
 use common_exception::Result;
 
 use super::StateAddr;
@@ -69,6 +70,20 @@ pub trait AggregateFunction: fmt::Display + Sync + Send {
     // Used in aggregate_null_adaptor
     fn accumulate_row(&self, _place: StateAddr, _columns: &[Column], _row: usize) -> Result<()>;
 
+    /// If `retract_row` is supported, so that a sliding window frame can remove the rows
+    /// leaving the frame instead of aggregating the whole frame again.
+    fn support_retract(&self) -> bool {
+        false
+    }
+
+    /// Remove a row that has been added by `accumulate_row` from the state.
+    fn retract_row(&self, _place: StateAddr, _columns: &[Column], _row: usize) -> Result<()> {
+        Err(ErrorCode::Unimplemented(format!(
+            "{} does not support retract",
+            self.name()
+        )))
+    }
+
     // serialize  the state into binary array
     fn batch_serialize(
         &self,
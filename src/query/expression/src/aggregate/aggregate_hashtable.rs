@@ -22,7 +22,6 @@ use super::payload::Payload;
 use super::payload_flush::PayloadFlushState;
 use super::probe_state::ProbeState;
 use crate::aggregate::payload_row::row_match_columns;
-use crate::group_hash_columns;
 use crate::load;
 use crate::select_vector::SelectVector;
 use crate::types::DataType;
@@ -32,6 +31,9 @@ use crate::ColumnBuilder;
 use crate::StateAddr;
 
 const LOAD_FACTOR: f64 = 1.5;
+// Seed of the group keys hashes, distinct from the exchange seed so that the rows
+// scattered to one node are still spread over the whole hash table.
+const GROUP_HASH_SEED: u64 = 0x2545f4914f6cdd1d;
 // hashes layout:
 // [SALT][PAGE_NR][PAGE_OFFSET]
 // [SALT] are the high bits of the hash value, e.g. 16 for 64 bit hashes
@@ -87,7 +89,10 @@ impl AggregateHashTable {
         params: &[Vec<Column>],
         row_count: usize,
     ) -> Result<usize> {
-        let group_hashes = group_hash_columns(group_columns);
+        let mut group_hashes = Vec::with_capacity(row_count);
+        for column in group_columns {
+            column.hash_into(GROUP_HASH_SEED, &mut group_hashes);
+        }
         let new_group_count = self.probe_and_create(state, group_columns, row_count, &group_hashes);
 
         for i in 0..row_count {
@@ -22,6 +22,8 @@ use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
 use crate::types::DecimalDataType;
+use crate::types::GeometryType;
+use crate::types::IntervalType;
 use crate::types::NumberDataType;
 use crate::types::NumberType;
 use crate::types::StringType;
@@ -70,6 +72,12 @@ pub fn group_hash_column(c: &Column) -> Vec<u64> {
             let c = StringType::try_downcast_column(c).unwrap();
             StringType::iter_column(&c).map(|x| x.fast_hash()).collect()
         }
+        DataType::Geometry => {
+            let c = GeometryType::try_downcast_column(c).unwrap();
+            GeometryType::iter_column(&c)
+                .map(|x| x.fast_hash())
+                .collect()
+        }
         DataType::Bitmap => {
             let c = BitmapType::try_downcast_column(c).unwrap();
             BitmapType::iter_column(&c).map(|x| x.fast_hash()).collect()
@@ -83,6 +91,12 @@ pub fn group_hash_column(c: &Column) -> Vec<u64> {
 
         DataType::Timestamp => group_hash_type_column::<TimestampType>(c),
         DataType::Date => group_hash_type_column::<DateType>(c),
+        DataType::Interval => {
+            let c = IntervalType::try_downcast_column(c).unwrap();
+            IntervalType::iter_column(&c)
+                .map(|x| x.total_micros().fast_hash())
+                .collect()
+        }
         DataType::Nullable(_) => {
             let col = c.as_nullable().unwrap();
             let mut values = group_hash_column(&col.column);
@@ -25,6 +25,7 @@ use crate::types::ArgType;
 use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
+use crate::types::IntervalType;
 use crate::types::NumberDataType;
 use crate::types::NumberType;
 use crate::types::TimestampType;
@@ -118,8 +119,10 @@ impl Payload {
             },
             DataType::Timestamp => self.flush_type_column::<TimestampType>(col_offset, state),
             DataType::Date => self.flush_type_column::<DateType>(col_offset, state),
+            DataType::Interval => self.flush_type_column::<IntervalType>(col_offset, state),
             DataType::String => Column::String(self.flush_string_column(col_offset, state)),
             DataType::Bitmap => Column::Bitmap(self.flush_string_column(col_offset, state)),
+            DataType::Geometry => Column::Geometry(self.flush_string_column(col_offset, state)),
             DataType::Variant => Column::Variant(self.flush_string_column(col_offset, state)),
             DataType::Nullable(_) => unreachable!(),
             DataType::Array(_) => todo!(),
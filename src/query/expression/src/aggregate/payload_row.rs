@@ -26,6 +26,7 @@ use crate::types::ArgType;
 use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
+use crate::types::IntervalType;
 use crate::types::NumberColumn;
 use crate::types::NumberType;
 use crate::types::StringType;
@@ -40,7 +41,7 @@ pub fn rowformat_size(data_type: &DataType) -> usize {
         DataType::Null | DataType::EmptyArray | DataType::EmptyMap => 0,
         DataType::Boolean => 1,
         // use address instead
-        DataType::String | DataType::Bitmap | DataType::Variant => 4 + 8, // u32 len + address
+        DataType::String | DataType::Bitmap | DataType::Variant | DataType::Geometry => 4 + 8, /* u32 len + address */
         DataType::Number(n) => n.bit_width() as usize / 8,
         DataType::Decimal(n) => match n {
             crate::types::DecimalDataType::Decimal128(_) => 16,
@@ -48,6 +49,7 @@ pub fn rowformat_size(data_type: &DataType) -> usize {
         },
         DataType::Timestamp => 8,
         DataType::Date => 4,
+        DataType::Interval => 16,
         DataType::Nullable(_) => 4,
         DataType::Array(_) => todo!(),
         DataType::Map(_) => todo!(),
@@ -92,7 +94,7 @@ pub unsafe fn serialize_column_to_rowformat(
                 store(&v.get_bit(index), address[index].add(offset) as *mut u8);
             }
         }
-        Column::String(v) | Column::Bitmap(v) | Column::Variant(v) => {
+        Column::String(v) | Column::Bitmap(v) | Column::Variant(v) | Column::Geometry(v) => {
             for i in 0..rows {
                 let index = select_index.get_index(i);
                 let data = arena.alloc_slice_copy(v.index_unchecked(index));
@@ -117,6 +119,12 @@ pub unsafe fn serialize_column_to_rowformat(
                 store(&buffer[index], address[index].add(offset) as *mut u8);
             }
         }
+        Column::Interval(buffer) => {
+            for i in 0..rows {
+                let index = select_index.get_index(i);
+                store(&buffer[index], address[index].add(offset) as *mut u8);
+            }
+        }
         Column::Nullable(c) => serialize_column_to_rowformat(
             arena,
             &c.column,
@@ -133,6 +141,15 @@ pub unsafe fn serialize_column_to_rowformat(
         Column::Tuple(_fields) => {
             todo!("nested tuple/array/map not supported yet")
         }
+        Column::Constant(_) => serialize_column_to_rowformat(
+            arena,
+            &column.materialize(),
+            select_index,
+            rows,
+            address,
+            offset,
+            _scratch,
+        ),
     }
 }
 
@@ -175,6 +192,19 @@ pub unsafe fn row_match_column(
     no_match: &mut SelectVector,
     no_match_count: &mut usize,
 ) {
+    if col.is_constant() {
+        return row_match_column(
+            &col.materialize(),
+            address,
+            select_index,
+            count,
+            validity_offset,
+            col_offset,
+            no_match,
+            no_match_count,
+        );
+    }
+
     let (validity, col) = if let Column::Nullable(c) = col {
         (Some(&c.validity), &c.column)
     } else {
@@ -258,7 +288,18 @@ pub unsafe fn row_match_column(
             no_match,
             no_match_count,
         ),
-        Column::Bitmap(v) => row_match_string_column(
+        Column::Interval(_) => row_match_column_type::<IntervalType>(
+            col,
+            validity,
+            address,
+            select_index,
+            count,
+            validity_offset,
+            col_offset,
+            no_match,
+            no_match_count,
+        ),
+        Column::Bitmap(v) | Column::Geometry(v) => row_match_string_column(
             v,
             validity,
             address,
@@ -292,7 +333,7 @@ pub unsafe fn row_match_column(
             no_match,
             no_match_count,
         ),
-        Column::Nullable(_) => unreachable!(),
+        Column::Nullable(_) | Column::Constant(_) => unreachable!(),
         Column::Array(_) => todo!(),
         Column::Map(_) => todo!(),
         Column::Tuple(_) => todo!(),
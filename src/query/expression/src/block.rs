@@ -19,6 +19,7 @@ use std::ops::Range;
 
 use common_arrow::arrow::array::Array;
 use common_arrow::arrow::chunk::Chunk as ArrowChunk;
+use common_arrow::arrow::datatypes::Schema as ArrowSchema;
 use common_arrow::ArrayRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -26,6 +27,7 @@ use common_exception::Result;
 use crate::schema::DataSchema;
 use crate::types::AnyType;
 use crate::types::DataType;
+use crate::utils::arrow::rescale_timestamp_array;
 use crate::Column;
 use crate::ColumnBuilder;
 use crate::DataSchemaRef;
@@ -65,6 +67,13 @@ impl BlockEntry {
         Self { data_type, value }
     }
 
+    /// Returns the value as a column, a scalar becomes a constant column instead of being
+    /// repeated `num_rows` times.
+    pub fn to_column(&self, num_rows: usize) -> Column {
+        self.value
+            .convert_to_constant_column(&self.data_type, num_rows)
+    }
+
     pub fn remove_nullable(self) -> Self {
         match self.value {
             Value::Column(Column::Nullable(col)) => {
@@ -229,6 +238,16 @@ impl DataBlock {
         self.columns().iter().map(|entry| entry.memory_size()).sum()
     }
 
+    /// Hands the column buffers back to the column arena of the current thread,
+    /// called at block boundaries once the block is no longer needed.
+    pub fn recycle(self) {
+        for entry in self.columns {
+            if let Value::Column(column) = entry.value {
+                crate::utils::column_arena::recycle_column(column);
+            }
+        }
+    }
+
     pub fn convert_to_full(&self) -> Self {
         let columns = self
             .columns()
@@ -241,7 +260,7 @@ impl DataBlock {
                     BlockEntry::new(entry.data_type.clone(), Value::Column(col))
                 }
                 Value::Column(c) => {
-                    BlockEntry::new(entry.data_type.clone(), Value::Column(c.clone()))
+                    BlockEntry::new(entry.data_type.clone(), Value::Column(c.materialize()))
                 }
             })
             .collect();
@@ -252,6 +271,30 @@ impl DataBlock {
         }
     }
 
+    /// Same as `convert_to_full`, but takes the ownership of the columns instead of sharing them.
+    pub fn consume_convert_to_full(self) -> Self {
+        let num_rows = self.num_rows;
+        let columns = self
+            .columns
+            .into_iter()
+            .map(|entry| match entry.value {
+                Value::Scalar(s) => {
+                    let builder = ColumnBuilder::repeat(&s.as_ref(), num_rows, &entry.data_type);
+                    BlockEntry::new(entry.data_type, Value::Column(builder.build()))
+                }
+                Value::Column(c) if c.is_constant() => {
+                    BlockEntry::new(entry.data_type, Value::Column(c.materialize()))
+                }
+                Value::Column(_) => entry,
+            })
+            .collect();
+        Self {
+            columns,
+            num_rows,
+            meta: self.meta,
+        }
+    }
+
     pub fn slice(&self, range: Range<usize>) -> Self {
         let columns = self
             .columns()
@@ -572,6 +615,28 @@ impl TryFrom<DataBlock> for ArrowChunk<ArrayRef> {
     }
 }
 
+impl DataBlock {
+    /// Convert the block to an arrow chunk of the table schema, the timestamps are converted
+    /// to the time unit of the fields, see [`rescale_timestamp_array`].
+    pub fn to_arrow_chunk(self, schema: &ArrowSchema) -> Result<ArrowChunk<ArrayRef>> {
+        let chunk = ArrowChunk::try_from(self)?;
+        debug_assert_eq!(chunk.arrays().len(), schema.fields.len());
+        let arrays = chunk
+            .into_arrays()
+            .into_iter()
+            .zip(schema.fields.iter())
+            .map(|(array, field)| {
+                if array.data_type() == field.data_type() {
+                    array
+                } else {
+                    rescale_timestamp_array(array, field.data_type())
+                }
+            })
+            .collect();
+        Ok(ArrowChunk::try_new(arrays)?)
+    }
+}
+
 impl BlockEntry {
     pub fn memory_size(&self) -> usize {
         match &self.value {
@@ -21,11 +21,15 @@ use arrow_schema::Schema as ArrowSchema;
 
 use crate::infer_schema_type;
 use crate::types::DataType;
+use crate::types::NumberDataType;
 use crate::DataField;
 use crate::DataSchema;
 use crate::ARROW_EXT_TYPE_BITMAP;
 use crate::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use crate::ARROW_EXT_TYPE_EMPTY_MAP;
+use crate::ARROW_EXT_TYPE_GEOMETRY;
+use crate::ARROW_EXT_TYPE_INT128;
+use crate::ARROW_EXT_TYPE_UINT128;
 use crate::ARROW_EXT_TYPE_VARIANT;
 use crate::EXTENSION_KEY;
 
@@ -47,6 +51,9 @@ impl From<&DataField> for ArrowField {
             DataType::EmptyMap => Some(ARROW_EXT_TYPE_EMPTY_MAP.to_string()),
             DataType::Variant => Some(ARROW_EXT_TYPE_VARIANT.to_string()),
             DataType::Bitmap => Some(ARROW_EXT_TYPE_BITMAP.to_string()),
+            DataType::Geometry => Some(ARROW_EXT_TYPE_GEOMETRY.to_string()),
+            DataType::Number(NumberDataType::UInt128) => Some(ARROW_EXT_TYPE_UINT128.to_string()),
+            DataType::Number(NumberDataType::Int128) => Some(ARROW_EXT_TYPE_INT128.to_string()),
             _ => None,
         };
 
@@ -21,14 +21,20 @@ use arrow_schema::FieldRef;
 use arrow_schema::Fields;
 use arrow_schema::TimeUnit;
 
+use crate::types::timestamp::PRECISION_MILLI;
+use crate::types::timestamp::PRECISION_NANO;
+use crate::types::timestamp::PRECISION_SEC;
 use crate::types::DecimalDataType;
 use crate::types::NumberDataType;
-use crate::with_number_type;
+use crate::with_number_type_without_128;
 use crate::TableDataType;
 use crate::TableField;
 use crate::ARROW_EXT_TYPE_BITMAP;
 use crate::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use crate::ARROW_EXT_TYPE_EMPTY_MAP;
+use crate::ARROW_EXT_TYPE_GEOMETRY;
+use crate::ARROW_EXT_TYPE_INT128;
+use crate::ARROW_EXT_TYPE_UINT128;
 use crate::ARROW_EXT_TYPE_VARIANT;
 use crate::EXTENSION_KEY;
 
@@ -40,6 +46,13 @@ impl From<&TableField> for ArrowField {
             TableDataType::EmptyMap => Some(ARROW_EXT_TYPE_EMPTY_MAP.to_string()),
             TableDataType::Variant => Some(ARROW_EXT_TYPE_VARIANT.to_string()),
             TableDataType::Bitmap => Some(ARROW_EXT_TYPE_BITMAP.to_string()),
+            TableDataType::Geometry => Some(ARROW_EXT_TYPE_GEOMETRY.to_string()),
+            TableDataType::Number(NumberDataType::UInt128) => {
+                Some(ARROW_EXT_TYPE_UINT128.to_string())
+            }
+            TableDataType::Number(NumberDataType::Int128) => {
+                Some(ARROW_EXT_TYPE_INT128.to_string())
+            }
             _ => None,
         };
 
@@ -61,8 +74,12 @@ impl From<&TableDataType> for ArrowDataType {
             TableDataType::EmptyMap => ArrowDataType::Null,
             TableDataType::Boolean => ArrowDataType::Boolean,
             TableDataType::String => ArrowDataType::LargeBinary,
-            TableDataType::Number(ty) => with_number_type!(|TYPE| match ty {
+            TableDataType::Number(NumberDataType::UInt128 | NumberDataType::Int128) => {
+                ArrowDataType::Decimal128(38, 0)
+            }
+            TableDataType::Number(ty) => with_number_type_without_128!(|TYPE| match ty {
                 NumberDataType::TYPE => ArrowDataType::TYPE,
+                _ => unreachable!(),
             }),
             TableDataType::Decimal(DecimalDataType::Decimal128(size)) => {
                 ArrowDataType::Decimal128(size.precision, size.scale as i8)
@@ -71,6 +88,15 @@ impl From<&TableDataType> for ArrowDataType {
                 ArrowDataType::Decimal256(size.precision, size.scale as i8)
             }
             TableDataType::Timestamp => ArrowDataType::Timestamp(TimeUnit::Microsecond, None),
+            TableDataType::TimestampWithPrecision(precision) => {
+                let unit = match *precision {
+                    PRECISION_SEC => TimeUnit::Second,
+                    PRECISION_MILLI => TimeUnit::Millisecond,
+                    PRECISION_NANO => TimeUnit::Nanosecond,
+                    _ => TimeUnit::Microsecond,
+                };
+                ArrowDataType::Timestamp(unit, None)
+            }
             TableDataType::Date => ArrowDataType::Date32,
             TableDataType::Nullable(ty) => ty.as_ref().into(),
             TableDataType::Array(ty) => {
@@ -116,6 +142,7 @@ impl From<&TableDataType> for ArrowDataType {
 
             TableDataType::Bitmap => ArrowDataType::LargeBinary,
             TableDataType::Variant => ArrowDataType::LargeBinary,
+            TableDataType::Geometry => ArrowDataType::LargeBinary,
         }
     }
 }
@@ -16,7 +16,12 @@ use arrow_schema::ArrowError;
 use arrow_schema::DataType as ArrowDataType;
 use arrow_schema::Field as ArrowField;
 use arrow_schema::Schema as ArrowSchema;
+use arrow_schema::TimeUnit;
 
+use crate::types::timestamp::PRECISION_MICRO;
+use crate::types::timestamp::PRECISION_MILLI;
+use crate::types::timestamp::PRECISION_NANO;
+use crate::types::timestamp::PRECISION_SEC;
 use crate::types::DecimalDataType;
 use crate::types::DecimalSize;
 use crate::types::NumberDataType;
@@ -26,6 +31,9 @@ use crate::TableSchema;
 use crate::ARROW_EXT_TYPE_BITMAP;
 use crate::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use crate::ARROW_EXT_TYPE_EMPTY_MAP;
+use crate::ARROW_EXT_TYPE_GEOMETRY;
+use crate::ARROW_EXT_TYPE_INT128;
+use crate::ARROW_EXT_TYPE_UINT128;
 use crate::ARROW_EXT_TYPE_VARIANT;
 use crate::EXTENSION_KEY;
 
@@ -66,6 +74,9 @@ impl TryFrom<&ArrowField> for TableDataType {
             Some(ARROW_EXT_TYPE_EMPTY_MAP) => Some(TableDataType::EmptyMap),
             Some(ARROW_EXT_TYPE_VARIANT) => Some(TableDataType::Variant),
             Some(ARROW_EXT_TYPE_BITMAP) => Some(TableDataType::Bitmap),
+            Some(ARROW_EXT_TYPE_GEOMETRY) => Some(TableDataType::Geometry),
+            Some(ARROW_EXT_TYPE_UINT128) => Some(TableDataType::Number(NumberDataType::UInt128)),
+            Some(ARROW_EXT_TYPE_INT128) => Some(TableDataType::Number(NumberDataType::Int128)),
             _ => None,
         };
 
@@ -93,7 +104,14 @@ impl TryFrom<&ArrowField> for TableDataType {
                 TableDataType::Number(NumberDataType::Float32)
             }
             ArrowDataType::Float64 => TableDataType::Number(NumberDataType::Float64),
-            ArrowDataType::Timestamp(_unit, _tz) => TableDataType::Timestamp,
+            ArrowDataType::Timestamp(unit, _tz) => {
+                TableDataType::timestamp_with_precision(match unit {
+                    TimeUnit::Second => PRECISION_SEC,
+                    TimeUnit::Millisecond => PRECISION_MILLI,
+                    TimeUnit::Microsecond => PRECISION_MICRO,
+                    TimeUnit::Nanosecond => PRECISION_NANO,
+                })
+            }
             ArrowDataType::Date32 | ArrowDataType::Date64 => TableDataType::Date,
             ArrowDataType::Utf8
             | ArrowDataType::LargeUtf8
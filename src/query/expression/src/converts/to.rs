@@ -34,6 +34,10 @@ pub fn scalar_to_datavalue(scalar: &Scalar) -> DataValue {
                 DataValue::Float64(<OrderedFloat<f32> as Into<f32>>::into(*x) as f64)
             }
             crate::types::number::NumberScalar::Float64(x) => DataValue::Float64((*x).into()),
+            crate::types::number::NumberScalar::UInt128(_)
+            | crate::types::number::NumberScalar::Int128(_) => {
+                unimplemented!("128-bit integer type is not supported")
+            }
         },
         Scalar::Decimal(_) => unimplemented!("decimal type is not supported"),
         Scalar::Timestamp(x) => DataValue::Int64(*x),
@@ -50,6 +54,12 @@ pub fn scalar_to_datavalue(scalar: &Scalar) -> DataValue {
             let values = x.iter().map(scalar_to_datavalue).collect();
             DataValue::Struct(values)
         }
-        Scalar::EmptyMap | Scalar::Map(_) | Scalar::Bitmap(_) => unimplemented!(),
+        Scalar::EmptyMap
+        | Scalar::Map(_)
+        | Scalar::Bitmap(_)
+        | Scalar::Interval(_)
+        | Scalar::Geometry(_) => {
+            unimplemented!()
+        }
     }
 }
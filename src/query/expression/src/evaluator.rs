@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::ops::Not;
 
 use common_arrow::arrow::bitmap;
@@ -50,6 +51,25 @@ use crate::FunctionEval;
 use crate::FunctionRegistry;
 use crate::RemoteExpr;
 
+/// When a conjunct of `and_filters` leaves fewer selected rows than this ratio,
+/// the rest conjuncts are evaluated on the selected rows only.
+const SELECTION_VECTOR_RATIO: f64 = 0.3;
+
+/// Returns the indices of the selected rows if the bitmap is selective enough.
+fn selection_vector(bitmap: &Bitmap) -> Option<Vec<u32>> {
+    let selected = bitmap.len() - bitmap.unset_bits();
+    if selected as f64 >= bitmap.len() as f64 * SELECTION_VECTOR_RATIO {
+        return None;
+    }
+    let mut selection = Vec::with_capacity(selected);
+    for (row, flag) in bitmap.iter().enumerate() {
+        if flag {
+            selection.push(row as u32);
+        }
+    }
+    Some(selection)
+}
+
 pub struct Evaluator<'a> {
     input_columns: &'a DataBlock,
     func_ctx: &'a FunctionContext,
@@ -104,7 +124,11 @@ impl<'a> Evaluator<'a> {
 
         let result = match expr {
             Expr::Constant { scalar, .. } => Ok(Value::Scalar(scalar.clone())),
-            Expr::ColumnRef { id, .. } => Ok(self.input_columns.get_by_offset(*id).value.clone()),
+            Expr::ColumnRef { id, .. } => match &self.input_columns.get_by_offset(*id).value {
+                // Functions are evaluated on the scalar of a constant column directly.
+                Value::Column(Column::Constant(col)) => Ok(Value::Scalar(col.scalar.clone())),
+                value => Ok(value.clone()),
+            },
             Expr::Cast {
                 span,
                 is_try,
@@ -761,7 +785,7 @@ impl<'a> Evaluator<'a> {
         let mut validity = validity.unwrap_or_else(|| Bitmap::new_constant(true, num_rows));
         let mut conds = Vec::new();
         let mut flags = Vec::new();
-        let mut results = Vec::new();
+        let mut results = Vec::with_capacity(args.len() / 2 + 1);
         for cond_idx in (0..args.len() - 1).step_by(2) {
             let cond = self.partial_run(&args[cond_idx], Some(validity.clone()))?;
             match cond.try_downcast::<NullableType<BooleanType>>().unwrap() {
@@ -800,33 +824,85 @@ impl<'a> Evaluator<'a> {
                 .all_equal()
         );
 
-        // Pick the results from the result branches depending on the condition.
-        let mut output_builder = ColumnBuilder::with_capacity(&generics[0], len.unwrap_or(1));
-        for row_idx in 0..(len.unwrap_or(1)) {
-            unsafe {
-                let result = flags
-                    .iter()
-                    .position(|flag| flag.get_bit(row_idx))
-                    .map(|idx| results[idx].index_unchecked(row_idx))
-                    .unwrap_or(else_result.index_unchecked(row_idx));
-                output_builder.push(result);
+        let Some(len) = len else {
+            // All the arguments are scalars, so is the result.
+            let result = flags
+                .iter()
+                .position(|flag| flag.get_bit(0))
+                .map(|idx| &results[idx])
+                .unwrap_or(&else_result);
+            return Ok(result.clone());
+        };
+
+        // Compute the branch of each row once, the first true condition wins, so the
+        // flags are applied from the last branch to the first one.
+        let else_idx = results.len();
+        let mut branches = vec![else_idx as u32; len];
+        let mut used = vec![false; else_idx + 1];
+        for (idx, flag) in flags.iter().enumerate().rev() {
+            if flag.unset_bits() == flag.len() {
+                continue;
             }
+            for (branch, is_set) in branches.iter_mut().zip(flag.iter()) {
+                if is_set {
+                    *branch = idx as u32;
+                }
+            }
+        }
+        for branch in branches.iter() {
+            used[*branch as usize] = true;
         }
-        match len {
-            Some(_) => Ok(Value::Column(output_builder.build())),
-            None => Ok(Value::Scalar(output_builder.build_scalar())),
+
+        results.push(else_result);
+        if let Some(idx) = used.iter().position(|used| *used) {
+            if used.iter().filter(|used| **used).count() == 1 {
+                return Ok(Value::Column(
+                    results[idx].convert_to_full_column(&generics[0], len),
+                ));
+            }
         }
+
+        // Gather the rows of every branch into the output column at once.
+        let columns = results
+            .iter()
+            .zip(used.iter())
+            .map(|(result, used)| match result {
+                Value::Column(col) => col.clone(),
+                Value::Scalar(_) if !used => ColumnBuilder::with_capacity(&generics[0], 0).build(),
+                Value::Scalar(_) => result.convert_to_full_column(&generics[0], len),
+            })
+            .collect::<Vec<_>>();
+        let indices = branches
+            .iter()
+            .enumerate()
+            .map(|(row, branch)| (*branch, row as u32, 1))
+            .collect::<Vec<_>>();
+        Ok(Value::Column(Column::take_column_indices(
+            &columns,
+            generics[0].clone(),
+            &indices,
+            len,
+        )))
     }
 
     // `and_filters` is a special builtin function similar to `if` that conditionally evaluate its arguments.
-    fn eval_and_filters(
+    fn eval_and_filters(&self, args: &[Expr], validity: Option<Bitmap>) -> Result<Value<AnyType>> {
+        assert!(args.len() >= 2);
+        self.eval_conjuncts(args, validity)
+    }
+
+    fn eval_conjuncts(
         &self,
         args: &[Expr],
         mut validity: Option<Bitmap>,
     ) -> Result<Value<AnyType>> {
-        assert!(args.len() >= 2);
+        for (idx, arg) in args.iter().enumerate() {
+            if let Some(selection) = validity.as_ref().and_then(selection_vector) {
+                // Only a few rows are left, evaluate the rest conjuncts on the selected rows
+                // instead of the whole block.
+                return self.eval_conjuncts_on_selection(&args[idx..], selection);
+            }
 
-        for arg in args {
             let cond = self.partial_run(arg, validity.clone())?;
             match &cond {
                 Value::Scalar(Scalar::Null | Scalar::Boolean(false)) => {
@@ -863,6 +939,68 @@ impl<'a> Evaluator<'a> {
         }
     }
 
+    /// Evaluate the conjuncts on the rows in the selection vector, the other rows are false.
+    ///
+    /// Only the columns referenced by the conjuncts are gathered, and the result of the
+    /// selected rows is scattered back into a bitmap of the whole block.
+    fn eval_conjuncts_on_selection(
+        &self,
+        args: &[Expr],
+        selection: Vec<u32>,
+    ) -> Result<Value<AnyType>> {
+        if selection.is_empty() {
+            return Ok(Value::Scalar(Scalar::Boolean(false)));
+        }
+
+        let column_refs = args
+            .iter()
+            .flat_map(|arg| arg.column_refs().into_keys())
+            .collect::<HashSet<_>>();
+        let mut string_items_buf = None;
+        let columns = self
+            .input_columns
+            .columns()
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| match &entry.value {
+                Value::Column(column) if column_refs.contains(&index) => BlockEntry::new(
+                    entry.data_type.clone(),
+                    Value::Column(column.take(&selection, &mut string_items_buf)),
+                ),
+                // Not referenced, so it will never be read.
+                Value::Column(_) => BlockEntry::new(
+                    entry.data_type.clone(),
+                    Value::Scalar(Scalar::default_value(&entry.data_type)),
+                ),
+                Value::Scalar(_) => entry.clone(),
+            })
+            .collect();
+        let selected_block = DataBlock::new(columns, selection.len());
+        let evaluator = Evaluator::new(&selected_block, self.func_ctx, self.fn_registry);
+
+        let num_rows = self.input_columns.num_rows();
+        let mut result = MutableBitmap::from_len_zeroed(num_rows);
+        match evaluator.eval_conjuncts(args, None)? {
+            Value::Scalar(Scalar::Boolean(true)) => {
+                for row in selection {
+                    result.set(row as usize, true);
+                }
+            }
+            Value::Scalar(_) => {
+                return Ok(Value::Scalar(Scalar::Boolean(false)));
+            }
+            Value::Column(column) => {
+                let selected = column.as_boolean().unwrap();
+                for (row, flag) in selection.into_iter().zip(selected.iter()) {
+                    if flag {
+                        result.set(row as usize, true);
+                    }
+                }
+            }
+        }
+        Ok(Value::Column(Column::Boolean(result.into())))
+    }
+
     /// Evaluate a set-returning-function. Return multiple sets of results
     /// for each input row, along with the number of rows in each set.
     pub fn run_srf(
@@ -560,6 +560,29 @@ impl<'a> EvalContext<'a> {
         }
     }
 
+    /// Narrows the validity of the context down to the rows where all the nullable
+    /// arguments are valid, and returns the validity of the result.
+    ///
+    /// Returns `None` if all the rows are NULL, the kernel doesn't need to run at all.
+    /// If none of the rows is NULL, the validity of the context is left unset, so
+    /// that the kernel takes the dense path without per-row null checks.
+    pub fn narrow_validity(&mut self, args_validity: Bitmap) -> Option<Bitmap> {
+        let validity = match &self.validity {
+            Some(valid) => valid & (&args_validity),
+            None => args_validity,
+        };
+        let null_count = validity.unset_bits();
+        if null_count > 0 && null_count == validity.len() {
+            return None;
+        }
+        self.validity = if null_count == 0 {
+            None
+        } else {
+            Some(validity.clone())
+        };
+        Some(validity)
+    }
+
     pub fn render_error(
         &self,
         span: Span,
@@ -632,7 +655,9 @@ where F: Fn(&[ValueRef<AnyType>], &mut EvalContext) -> Value<AnyType> {
         }
         let results = f(&nonull_args, ctx);
         let bitmap = bitmap.unwrap_or_else(|| Bitmap::new_constant(true, len).make_mut());
-        if let Some((error_bitmap, _)) = ctx.errors.as_mut() {
+        // Without NULL rows, neither the errors nor the result validity need to be masked.
+        let all_valid = bitmap.unset_bits() == 0;
+        if let Some((error_bitmap, _)) = ctx.errors.as_mut().filter(|_| !all_valid) {
             // If the original value is NULL, we can ignore the error.
             let rhs: Bitmap = bitmap.clone().not().into();
             let res = error_bitmap.clone().bitor(&rhs);
@@ -653,6 +678,7 @@ where F: Fn(&[ValueRef<AnyType>], &mut EvalContext) -> Value<AnyType> {
             }
             Value::Column(column) => {
                 let result = match column {
+                    column @ Column::Nullable(_) if all_valid => column,
                     Column::Nullable(box nullable_column) => {
                         let validity = bitmap.into();
                         let validity =
@@ -24,18 +24,16 @@ use itertools::Itertools;
 use crate::kernels::take::BIT_MASK;
 use crate::kernels::utils::copy_advance_aligned;
 use crate::kernels::utils::set_vec_len_by_ptr;
-use crate::types::array::ArrayColumnBuilder;
+use crate::types::array::ArrayColumn;
 use crate::types::decimal::DecimalColumn;
-use crate::types::map::KvColumnBuilder;
 use crate::types::nullable::NullableColumn;
 use crate::types::number::NumberColumn;
 use crate::types::string::StringColumn;
 use crate::types::AnyType;
 use crate::types::ArgType;
-use crate::types::ArrayType;
 use crate::types::BitmapType;
 use crate::types::BooleanType;
-use crate::types::MapType;
+use crate::types::GeometryType;
 use crate::types::NumberType;
 use crate::types::StringType;
 use crate::types::ValueType;
@@ -93,6 +91,18 @@ impl DataBlock {
 }
 
 impl Column {
+    /// Concatenate non-empty columns of the same data type into one column.
+    ///
+    /// The sizes of the result, including the values of nested arrays and maps, are
+    /// computed before copying, thus every buffer is allocated only once.
+    pub fn concat(columns: &[Column]) -> Column {
+        debug_assert!(!columns.is_empty());
+        if columns.len() == 1 {
+            return columns[0].clone();
+        }
+        Self::concat_none_empty(columns.iter().cloned())
+    }
+
     pub fn concat_columns<I: Iterator<Item = Column> + TrustedLen + Clone>(
         mut columns: I,
     ) -> Result<Column> {
@@ -110,6 +120,19 @@ impl Column {
         let mut columns_iter_clone = columns.clone();
         let first_column = columns_iter_clone.next().unwrap();
         let capacity = columns_iter_clone.fold(first_column.len(), |acc, x| acc + x.len());
+        if columns.clone().any(|col| col.is_constant()) {
+            // Constant columns of the same value stay constant, otherwise they are expanded.
+            if let Column::Constant(first) = &first_column {
+                if columns
+                    .clone()
+                    .all(|col| matches!(&col, Column::Constant(c) if c.scalar == first.scalar))
+                {
+                    return first.with_len(capacity);
+                }
+            }
+            let columns = columns.map(|col| col.materialize()).collect::<Vec<_>>();
+            return Self::concat_none_empty(columns.into_iter());
+        }
         match first_column {
             Column::Null { .. } => Column::Null { len: capacity },
             Column::EmptyArray { .. } => Column::EmptyArray { len: capacity },
@@ -209,6 +232,26 @@ impl Column {
                         &[],
                     ))
                 }
+                NumberColumn::UInt128(_) => {
+                    let builder = Self::concat_primitive_types(
+                        columns.map(|col| col.into_number().unwrap().into_u_int128().unwrap()),
+                        capacity,
+                    );
+                    <NumberType<u128>>::upcast_column(<NumberType<u128>>::column_from_vec(
+                        builder,
+                        &[],
+                    ))
+                }
+                NumberColumn::Int128(_) => {
+                    let builder = Self::concat_primitive_types(
+                        columns.map(|col| col.into_number().unwrap().into_int128().unwrap()),
+                        capacity,
+                    );
+                    <NumberType<i128>>::upcast_column(<NumberType<i128>>::column_from_vec(
+                        builder,
+                        &[],
+                    ))
+                }
             }),
             Column::Decimal(col) => with_decimal_type!(|DECIMAL_TYPE| match col {
                 DecimalColumn::Decimal128(_, size) => {
@@ -270,34 +313,29 @@ impl Column {
                 .unwrap();
                 Column::Date(d)
             }
-            Column::Array(col) => {
-                let mut offsets = Vec::with_capacity(capacity + 1);
-                offsets.push(0);
-                let builder = ColumnBuilder::with_capacity(&col.values.data_type(), capacity);
-                let builder = ArrayColumnBuilder { builder, offsets };
-                Self::concat_value_types::<ArrayType<AnyType>>(builder, columns)
-            }
-            Column::Map(col) => {
-                let mut offsets = Vec::with_capacity(capacity + 1);
-                offsets.push(0);
-                let builder = ColumnBuilder::from_column(
-                    ColumnBuilder::with_capacity(&col.values.data_type(), capacity).build(),
+            Column::Interval(_) => {
+                let builder = Self::concat_primitive_types(
+                    columns.map(|col| col.into_interval().unwrap()),
+                    capacity,
                 );
-                let (key_builder, val_builder) = match builder {
-                    ColumnBuilder::Tuple(fields) => (fields[0].clone(), fields[1].clone()),
-                    _ => unreachable!(),
-                };
-                let builder = KvColumnBuilder {
-                    keys: key_builder,
-                    values: val_builder,
-                };
-                let builder = ArrayColumnBuilder { builder, offsets };
-                Self::concat_value_types::<MapType<AnyType, AnyType>>(builder, columns)
+                Column::Interval(builder.into())
             }
+            Column::Array(_) => Column::Array(Box::new(Self::concat_array_types(
+                columns.map(|col| *col.into_array().unwrap()),
+                capacity,
+            ))),
+            Column::Map(_) => Column::Map(Box::new(Self::concat_array_types(
+                columns.map(|col| *col.into_map().unwrap()),
+                capacity,
+            ))),
             Column::Bitmap(_) => BitmapType::upcast_column(Self::concat_string_types(
                 columns.map(|col| col.into_bitmap().unwrap()),
                 capacity,
             )),
+            Column::Geometry(_) => GeometryType::upcast_column(Self::concat_string_types(
+                columns.map(|col| col.into_geometry().unwrap()),
+                capacity,
+            )),
             Column::Nullable(_) => {
                 let column: Vec<Column> = columns
                     .clone()
@@ -327,6 +365,7 @@ impl Column {
                 columns.map(|col| col.into_variant().unwrap()),
                 capacity,
             )),
+            Column::Constant(_) => unreachable!(),
         }
     }
 
@@ -422,14 +461,31 @@ impl Column {
         }
     }
 
-    fn concat_value_types<T: ValueType>(
-        mut builder: T::ColumnBuilder,
-        columns: impl Iterator<Item = Column>,
-    ) -> Column {
-        let columns = columns.map(|c| T::try_downcast_column(&c).unwrap());
-        for col in columns {
-            T::append_column(&mut builder, &col);
+    /// Concatenate the offsets, and the values referenced by them all at once.
+    fn concat_array_types(
+        cols: impl Iterator<Item = ArrayColumn<AnyType>> + Clone,
+        num_rows: usize,
+    ) -> ArrayColumn<AnyType> {
+        let mut offsets: Vec<u64> = Vec::with_capacity(num_rows + 1);
+        offsets.push(0);
+        let mut values_len = 0;
+        for col in cols.clone() {
+            let start = col.offsets[0];
+            offsets.extend(col.offsets[1..].iter().map(|end| values_len + end - start));
+            values_len += col.offsets[col.offsets.len() - 1] - start;
+        }
+
+        let values = cols
+            .map(|col| {
+                let start = col.offsets[0] as usize;
+                let end = col.offsets[col.offsets.len() - 1] as usize;
+                col.values.slice(start..end)
+            })
+            .collect::<Vec<_>>();
+
+        ArrayColumn {
+            values: Self::concat(&values),
+            offsets: offsets.into(),
         }
-        T::upcast_column(T::build_column(builder))
     }
 }
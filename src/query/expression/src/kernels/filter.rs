@@ -17,6 +17,7 @@ use std::sync::Arc;
 use common_arrow::arrow::bitmap::utils::BitChunkIterExact;
 use common_arrow::arrow::bitmap::utils::BitChunksExact;
 use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
 use common_arrow::arrow::buffer::Buffer;
 use common_exception::Result;
 
@@ -26,22 +27,16 @@ use crate::kernels::utils::set_vec_len_by_ptr;
 use crate::kernels::utils::store_advance_aligned;
 use crate::kernels::utils::BitChunks;
 use crate::types::array::ArrayColumn;
-use crate::types::array::ArrayColumnBuilder;
 use crate::types::decimal::DecimalColumn;
-use crate::types::map::KvColumnBuilder;
 use crate::types::nullable::NullableColumn;
 use crate::types::number::NumberColumn;
 use crate::types::string::StringColumn;
 use crate::types::AnyType;
-use crate::types::ArrayType;
 use crate::types::BooleanType;
-use crate::types::MapType;
-use crate::types::ValueType;
 use crate::with_decimal_type;
 use crate::with_number_type;
 use crate::BlockEntry;
 use crate::Column;
-use crate::ColumnBuilder;
 use crate::DataBlock;
 use crate::Value;
 
@@ -134,35 +129,24 @@ impl Column {
                 let d = Self::filter_primitive_types(column, filter);
                 Column::Date(d)
             }
+            Column::Interval(column) => {
+                let d = Self::filter_primitive_types(column, filter);
+                Column::Interval(d)
+            }
             Column::Array(column) => {
-                let mut offsets = Vec::with_capacity(length + 1);
-                offsets.push(0);
-                let builder = ColumnBuilder::with_capacity(&column.values.data_type(), length);
-                let builder = ArrayColumnBuilder { builder, offsets };
-                Self::filter_scalar_types::<ArrayType<AnyType>>(column, builder, filter)
+                Column::Array(Box::new(Self::filter_array_types(column, filter, length)))
             }
             Column::Map(column) => {
-                let mut offsets = Vec::with_capacity(length + 1);
-                offsets.push(0);
-                let builder = ColumnBuilder::from_column(
-                    ColumnBuilder::with_capacity(&column.values.data_type(), length).build(),
-                );
-                let (key_builder, val_builder) = match builder {
-                    ColumnBuilder::Tuple(fields) => (fields[0].clone(), fields[1].clone()),
-                    _ => unreachable!(),
-                };
-                let builder = KvColumnBuilder {
-                    keys: key_builder,
-                    values: val_builder,
-                };
-                let builder = ArrayColumnBuilder { builder, offsets };
-                let column = ArrayColumn::try_downcast(column).unwrap();
-                Self::filter_scalar_types::<MapType<AnyType, AnyType>>(&column, builder, filter)
+                Column::Map(Box::new(Self::filter_array_types(column, filter, length)))
             }
             Column::Bitmap(column) => {
                 let column = Self::filter_string_scalars(column, filter);
                 Column::Bitmap(column)
             }
+            Column::Geometry(column) => {
+                let column = Self::filter_string_scalars(column, filter);
+                Column::Geometry(column)
+            }
 
             Column::Nullable(c) => {
                 let column = Self::filter(&c.column, filter);
@@ -177,63 +161,41 @@ impl Column {
                 let column = Self::filter_string_scalars(column, filter);
                 Column::Variant(column)
             }
+            Column::Constant(col) => col.with_len(length),
         }
     }
 
-    fn filter_scalar_types<T: ValueType>(
-        col: &T::Column,
-        mut builder: T::ColumnBuilder,
+    /// Filter the offsets row by row, and the values all at once with a bitmap that
+    /// selects the value ranges of the selected rows.
+    fn filter_array_types(
+        column: &ArrayColumn<AnyType>,
         filter: &Bitmap,
-    ) -> Column {
-        const CHUNK_SIZE: usize = 64;
-        let (mut slice, offset, mut length) = filter.as_slice();
-        let mut start_index: usize = 0;
-
-        if offset > 0 {
-            // If `offset` > 0, the valid bits of this byte start at `offset`, and the
-            // max num of valid bits is `8 - offset`, but we also need to ensure that
-            // we cannot iterate more than `length` bits.
-            let n = std::cmp::min(8 - offset, length);
-            start_index += n;
-            filter
-                .iter()
-                .enumerate()
-                .take(n)
-                .for_each(|(index, is_selected)| {
-                    if is_selected {
-                        T::push_item(&mut builder, T::index_column(col, index).unwrap());
-                    }
-                });
-            slice = &slice[1..];
-            length = if length >= n { length - n } else { 0 };
+        length: usize,
+    ) -> ArrayColumn<AnyType> {
+        let offsets = column.offsets.as_slice();
+        let first = offsets[0] as usize;
+        let last = offsets[offsets.len() - 1] as usize;
+
+        let mut new_offsets = Vec::with_capacity(length + 1);
+        new_offsets.push(0);
+        let mut values_filter = MutableBitmap::with_capacity(column.values.len());
+        values_filter.extend_constant(first, false);
+
+        let mut current = 0;
+        for (window, is_selected) in offsets.windows(2).zip(filter.iter()) {
+            let len = (window[1] - window[0]) as usize;
+            values_filter.extend_constant(len, is_selected);
+            if is_selected {
+                current += len as u64;
+                new_offsets.push(current);
+            }
         }
+        values_filter.extend_constant(column.values.len() - last, false);
 
-        let mut mask_chunks = BitChunksExact::<u64>::new(slice, length);
-
-        mask_chunks
-            .by_ref()
-            .enumerate()
-            .for_each(|(mask_index, mut mask)| {
-                while mask != 0 {
-                    let n = mask.trailing_zeros() as usize;
-                    let index = mask_index * CHUNK_SIZE + n + start_index;
-                    T::push_item(&mut builder, T::index_column(col, index).unwrap());
-                    mask = mask & (mask - 1);
-                }
-            });
-
-        let remainder_start = length - length % CHUNK_SIZE;
-        mask_chunks
-            .remainder_iter()
-            .enumerate()
-            .for_each(|(mask_index, is_selected)| {
-                if is_selected {
-                    let index = mask_index + remainder_start + start_index;
-                    T::push_item(&mut builder, T::index_column(col, index).unwrap());
-                }
-            });
-
-        T::upcast_column(T::build_column(builder))
+        ArrayColumn {
+            values: column.values.filter(&values_filter.into()),
+            offsets: new_offsets.into(),
+        }
     }
 
     /// low-level API using unsafe to improve performance.
@@ -0,0 +1,159 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::types::nullable::NullableColumn;
+use crate::types::number::NumberColumn;
+use crate::types::string::StringColumnBuilder;
+use crate::types::DataType;
+use crate::types::NumberDataType;
+use crate::Column;
+use crate::DataBlock;
+use crate::HashMethodKind;
+
+/// Interns the string group by keys into `u32` ids, so that they can be packed
+/// together with the other small keys into a fixed-width key.
+///
+/// The ids are only meaningful within a dictionary, which is why it has to be shared
+/// by all the processors that build or read the keys of the same hash tables.
+#[derive(Default)]
+pub struct GroupKeysDictionary {
+    inner: RwLock<DictionaryInner>,
+}
+
+#[derive(Default)]
+struct DictionaryInner {
+    ids: HashMap<Vec<u8>, u32>,
+    values: Vec<Vec<u8>>,
+}
+
+impl GroupKeysDictionary {
+    /// Returns the types of the group by keys with the string keys replaced by their ids,
+    /// if that allows to pack all the keys into a fixed-width key.
+    pub fn encoded_key_types(hash_key_types: &[DataType]) -> Option<Vec<DataType>> {
+        if hash_key_types.len() < 2
+            || !hash_key_types
+                .iter()
+                .any(|ty| ty.remove_nullable().is_string())
+        {
+            return None;
+        }
+
+        let encoded = hash_key_types
+            .iter()
+            .map(|ty| match ty.remove_nullable() {
+                DataType::String => {
+                    let id_type = DataType::Number(NumberDataType::UInt32);
+                    match ty.is_nullable() {
+                        true => id_type.wrap_nullable(),
+                        false => id_type,
+                    }
+                }
+                _ => ty.clone(),
+            })
+            .collect::<Vec<_>>();
+
+        match DataBlock::choose_hash_method_with_types(&encoded, false).ok()? {
+            HashMethodKind::KeysU8(_)
+            | HashMethodKind::KeysU16(_)
+            | HashMethodKind::KeysU32(_)
+            | HashMethodKind::KeysU64(_)
+            | HashMethodKind::KeysU128(_)
+            | HashMethodKind::KeysU256(_) => Some(encoded),
+            _ => None,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner.read().unwrap().values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Replaces the strings of a `String` or `Nullable(String)` column by their ids.
+    /// Columns of other types are returned as is.
+    pub fn encode(&self, column: &Column) -> Result<Column> {
+        match column {
+            Column::String(strings) => {
+                let mut ids = Vec::with_capacity(strings.len());
+                let mut missing = false;
+                {
+                    let inner = self.inner.read().unwrap();
+                    for value in strings.iter() {
+                        let id = inner.ids.get(value).copied();
+                        missing |= id.is_none();
+                        ids.push(id.unwrap_or_default());
+                    }
+                }
+
+                if missing {
+                    let mut inner = self.inner.write().unwrap();
+                    for (id, value) in ids.iter_mut().zip(strings.iter()) {
+                        *id = inner.intern(value)?;
+                    }
+                }
+
+                Ok(Column::Number(NumberColumn::UInt32(ids.into())))
+            }
+            Column::Nullable(box nullable) => Ok(Column::Nullable(Box::new(NullableColumn {
+                column: self.encode(&nullable.column)?,
+                validity: nullable.validity.clone(),
+            }))),
+            column => Ok(column.clone()),
+        }
+    }
+
+    /// Replaces the ids of a `UInt32` or `Nullable(UInt32)` column by their strings.
+    pub fn decode(&self, column: &Column) -> Column {
+        match column {
+            Column::Number(NumberColumn::UInt32(ids)) => {
+                let inner = self.inner.read().unwrap();
+                let data_capacity = ids.iter().map(|id| inner.values[*id as usize].len()).sum();
+                let mut builder = StringColumnBuilder::with_capacity(ids.len(), data_capacity);
+                for id in ids.iter() {
+                    builder.put_slice(&inner.values[*id as usize]);
+                    builder.commit_row();
+                }
+                Column::String(builder.build())
+            }
+            Column::Nullable(box nullable) => Column::Nullable(Box::new(NullableColumn {
+                column: self.decode(&nullable.column),
+                validity: nullable.validity.clone(),
+            })),
+            column => column.clone(),
+        }
+    }
+}
+
+impl DictionaryInner {
+    fn intern(&mut self, value: &[u8]) -> Result<u32> {
+        if let Some(id) = self.ids.get(value) {
+            return Ok(*id);
+        }
+
+        let id = u32::try_from(self.values.len()).map_err(|_| {
+            ErrorCode::Overflow("Too many distinct strings in the group by keys dictionary")
+        })?;
+        self.ids.insert(value.to_vec(), id);
+        self.values.push(value.to_vec());
+        Ok(id)
+    }
+}
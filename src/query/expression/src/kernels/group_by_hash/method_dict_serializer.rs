@@ -47,8 +47,13 @@ impl HashMethod for HashMethodDictionarySerializer {
         let mut dictionary_columns = Vec::with_capacity(group_columns.len());
         let mut serialize_columns = Vec::new();
         for (group_column, _) in group_columns {
-            match group_column {
-                Column::String(v) | Column::Variant(v) | Column::Bitmap(v) => {
+            // A constant string column must be keyed as the strings of the other blocks.
+            let group_column = group_column.materialize();
+            match &group_column {
+                Column::String(v)
+                | Column::Variant(v)
+                | Column::Bitmap(v)
+                | Column::Geometry(v) => {
                     debug_assert_eq!(v.len(), num_rows);
                     dictionary_columns.push(v.clone());
                 }
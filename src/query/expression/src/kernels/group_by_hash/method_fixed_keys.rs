@@ -80,7 +80,11 @@ where T: Clone + Default
             .map(|(_, t)| t.remove_nullable().numeric_byte_size().unwrap())
             .sum::<usize>();
 
-        let mut group_columns = group_columns.to_vec();
+        // The keys are packed from the buffers of the columns, a constant column is expanded.
+        let mut group_columns = group_columns
+            .iter()
+            .map(|(col, ty)| (col.materialize(), ty.clone()))
+            .collect::<Vec<_>>();
         group_columns.sort_by(|a, b| {
             let ta = a.1.remove_nullable();
             let tb = b.1.remove_nullable();
@@ -240,13 +244,14 @@ macro_rules! impl_hash_method_fixed_keys {
                 // faster path for single fixed keys
                 if group_columns.len() == 1 {
                     if group_columns[0].1.is_unsigned_numeric() {
-                        return Ok(KeysState::Column(group_columns[0].0.clone()));
+                        return Ok(KeysState::Column(group_columns[0].0.materialize()));
                     }
 
                     if group_columns[0].1.is_signed_numeric() {
-                        let col =
-                            NumberType::<$signed_ty>::try_downcast_column(&group_columns[0].0)
-                                .unwrap();
+                        let col = NumberType::<$signed_ty>::try_downcast_column(
+                            &group_columns[0].0.materialize(),
+                        )
+                        .unwrap();
                         let buffer =
                             unsafe { std::mem::transmute::<Buffer<$signed_ty>, Buffer<$ty>>(col) };
                         return Ok(KeysState::Column(NumberType::<$ty>::upcast_column(buffer)));
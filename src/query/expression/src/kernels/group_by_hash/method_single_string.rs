@@ -40,14 +40,16 @@ impl HashMethod for HashMethodSingleString {
         group_columns: &[(Column, DataType)],
         _rows: usize,
     ) -> Result<KeysState> {
-        Ok(KeysState::Column(group_columns[0].0.clone()))
+        // The keys are read as strings, a constant column is expanded.
+        Ok(KeysState::Column(group_columns[0].0.materialize()))
     }
 
     fn build_keys_iter<'a>(&self, keys_state: &'a KeysState) -> Result<Self::HashKeyIter<'a>> {
         match keys_state {
             KeysState::Column(Column::String(col))
             | KeysState::Column(Column::Variant(col))
-            | KeysState::Column(Column::Bitmap(col)) => Ok(col.iter()),
+            | KeysState::Column(Column::Bitmap(col))
+            | KeysState::Column(Column::Geometry(col)) => Ok(col.iter()),
             _ => unreachable!(),
         }
     }
@@ -60,7 +62,8 @@ impl HashMethod for HashMethodSingleString {
         match keys_state {
             KeysState::Column(Column::String(col))
             | KeysState::Column(Column::Variant(col))
-            | KeysState::Column(Column::Bitmap(col)) => {
+            | KeysState::Column(Column::Bitmap(col))
+            | KeysState::Column(Column::Geometry(col)) => {
                 hashes.extend(col.iter().map(hash_join_fast_string_hash));
                 let (data, offsets) = col.into_buffer();
                 Ok(Box::new(StringKeyAccessor::new(data, offsets)))
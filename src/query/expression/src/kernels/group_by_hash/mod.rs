@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod dictionary;
 mod method;
 mod method_dict_serializer;
 mod method_fixed_keys;
@@ -19,6 +20,7 @@ mod method_serializer;
 mod method_single_string;
 mod utils;
 
+pub use dictionary::*;
 pub use method::*;
 pub use method_dict_serializer::*;
 pub use method_fixed_keys::*;
@@ -76,7 +76,7 @@ pub unsafe fn serialize_column_binary(column: &Column, row: usize, row_space: &m
             })
         }
         Column::Boolean(v) => store_advance::<bool>(&v.get_bit(row), row_space),
-        Column::String(v) | Column::Bitmap(v) | Column::Variant(v) => {
+        Column::String(v) | Column::Bitmap(v) | Column::Variant(v) | Column::Geometry(v) => {
             let value = unsafe { v.index_unchecked(row) };
             let len = value.len();
             store_advance::<u64>(&(len as u64), row_space);
@@ -84,6 +84,11 @@ pub unsafe fn serialize_column_binary(column: &Column, row: usize, row_space: &m
         }
         Column::Timestamp(v) => store_advance::<i64>(&v[row], row_space),
         Column::Date(v) => store_advance::<i32>(&v[row], row_space),
+        Column::Interval(v) => {
+            store_advance::<i32>(&v[row].months, row_space);
+            store_advance::<i32>(&v[row].days, row_space);
+            store_advance::<i64>(&v[row].micros, row_space);
+        }
         Column::Array(array) | Column::Map(array) => {
             let data = array.index(row).unwrap();
             store_advance::<u64>(&(data.len() as u64), row_space);
@@ -103,5 +108,8 @@ pub unsafe fn serialize_column_binary(column: &Column, row: usize, row_space: &m
                 serialize_column_binary(inner_col, row, row_space);
             }
         }
+        Column::Constant(col) => {
+            serialize_column_binary(&col.row, 0, row_space);
+        }
     }
 }
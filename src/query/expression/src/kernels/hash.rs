@@ -0,0 +1,193 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::hash::Hasher;
+
+use ethnum::i256;
+use twox_hash::XxHash64;
+
+use crate::types::decimal::DecimalColumn;
+use crate::types::number::NumberColumn;
+use crate::types::number::F32;
+use crate::types::number::F64;
+use crate::types::string::StringColumn;
+use crate::with_number_mapped_type;
+use crate::Column;
+
+/// Hashed in place of the value for NULL rows.
+const NULL_HASH_MARKER: u64 = 0xd1cefa08eb382d69;
+
+/// Seed used to hash the elements of an array or map, each row then folds the
+/// hashes of its elements into its own running hash.
+const NESTED_HASH_SEED: u64 = 0x9e3779b97f4a7c15;
+
+impl Column {
+    /// Combines the hash of each row into `hashes`, so hashing several columns one after
+    /// another gives the hash of the composite key. If `hashes` is empty it is initialized
+    /// to `seed` for every row, otherwise it must have the same length as the column and
+    /// `seed` is ignored.
+    ///
+    /// Values are hashed with xxHash64 over a canonical encoding that does not depend on
+    /// the physical layout: a nullable column hashes its valid rows exactly like the
+    /// non-nullable column, sliced and constant columns hash like their materialized
+    /// counterpart, and `-0.0` and `NaN` are normalized for floats.
+    pub fn hash_into(&self, seed: u64, hashes: &mut Vec<u64>) {
+        if hashes.is_empty() {
+            hashes.resize(self.len(), seed);
+        }
+        assert_eq!(hashes.len(), self.len());
+        self.hash_rows(hashes);
+    }
+
+    fn hash_rows(&self, hashes: &mut [u64]) {
+        match self {
+            Column::Null { .. } => {
+                for h in hashes.iter_mut() {
+                    *h = NULL_HASH_MARKER.hash_seeded(*h);
+                }
+            }
+            Column::EmptyArray { .. } | Column::EmptyMap { .. } => {
+                for h in hashes.iter_mut() {
+                    *h = 0u64.hash_seeded(*h);
+                }
+            }
+            Column::Number(col) => with_number_mapped_type!(|NUM_TYPE| match col {
+                NumberColumn::NUM_TYPE(buffer) => hash_values(buffer.iter(), hashes),
+            }),
+            Column::Decimal(DecimalColumn::Decimal128(buffer, _)) => {
+                hash_values(buffer.iter(), hashes)
+            }
+            Column::Decimal(DecimalColumn::Decimal256(buffer, _)) => {
+                hash_values(buffer.iter(), hashes)
+            }
+            Column::Boolean(bitmap) => hash_values(bitmap.iter().map(|v| v as u8), hashes),
+            Column::String(col)
+            | Column::Bitmap(col)
+            | Column::Variant(col)
+            | Column::Geometry(col) => hash_strings(col, hashes),
+            Column::Timestamp(buffer) => hash_values(buffer.iter(), hashes),
+            Column::Date(buffer) => hash_values(buffer.iter(), hashes),
+            Column::Interval(buffer) => {
+                hash_values(buffer.iter().map(|v| v.total_micros()), hashes)
+            }
+            Column::Array(col) | Column::Map(col) => {
+                let start = col.offsets[0] as usize;
+                let end = col.offsets[col.len()] as usize;
+                let mut element_hashes = Vec::new();
+                col.values
+                    .slice(start..end)
+                    .hash_into(NESTED_HASH_SEED, &mut element_hashes);
+                for (h, offsets) in hashes.iter_mut().zip(col.offsets.windows(2)) {
+                    let (row_start, row_end) = (offsets[0] as usize, offsets[1] as usize);
+                    *h = ((row_end - row_start) as u64).hash_seeded(*h);
+                    for element_hash in &element_hashes[row_start - start..row_end - start] {
+                        *h = element_hash.hash_seeded(*h);
+                    }
+                }
+            }
+            Column::Nullable(col) => {
+                if col.validity.unset_bits() == 0 {
+                    col.column.hash_rows(hashes);
+                    return;
+                }
+                let seeds = hashes.to_vec();
+                col.column.hash_rows(hashes);
+                for ((h, seed), valid) in hashes.iter_mut().zip(seeds).zip(col.validity.iter()) {
+                    if !valid {
+                        *h = NULL_HASH_MARKER.hash_seeded(seed);
+                    }
+                }
+            }
+            Column::Tuple(fields) => {
+                for field in fields {
+                    field.hash_rows(hashes);
+                }
+            }
+            Column::Constant(_) => self.materialize().hash_rows(hashes),
+        }
+    }
+}
+
+fn hash_values<T: HashSeeded>(values: impl Iterator<Item = T>, hashes: &mut [u64]) {
+    for (h, v) in hashes.iter_mut().zip(values) {
+        *h = v.hash_seeded(*h);
+    }
+}
+
+fn hash_strings(col: &StringColumn, hashes: &mut [u64]) {
+    for (h, v) in hashes.iter_mut().zip(col.iter()) {
+        *h = hash_bytes(*h, v);
+    }
+}
+
+#[inline]
+fn hash_bytes(seed: u64, bytes: &[u8]) -> u64 {
+    let mut hasher = XxHash64::with_seed(seed);
+    hasher.write(bytes);
+    hasher.finish()
+}
+
+trait HashSeeded {
+    fn hash_seeded(&self, seed: u64) -> u64;
+}
+
+impl<T: HashSeeded> HashSeeded for &T {
+    #[inline]
+    fn hash_seeded(&self, seed: u64) -> u64 {
+        T::hash_seeded(*self, seed)
+    }
+}
+
+macro_rules! impl_hash_seeded_for_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl HashSeeded for $ty {
+                #[inline]
+                fn hash_seeded(&self, seed: u64) -> u64 {
+                    hash_bytes(seed, &self.to_le_bytes())
+                }
+            }
+        )*
+    };
+}
+
+impl_hash_seeded_for_integer!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, i256);
+
+impl HashSeeded for F32 {
+    #[inline]
+    fn hash_seeded(&self, seed: u64) -> u64 {
+        let v = if self.0.is_nan() {
+            f32::NAN
+        } else if self.0 == 0.0 {
+            0.0
+        } else {
+            self.0
+        };
+        v.to_bits().hash_seeded(seed)
+    }
+}
+
+impl HashSeeded for F64 {
+    #[inline]
+    fn hash_seeded(&self, seed: u64) -> u64 {
+        let v = if self.0.is_nan() {
+            f64::NAN
+        } else if self.0 == 0.0 {
+            0.0
+        } else {
+            self.0
+        };
+        v.to_bits().hash_seeded(seed)
+    }
+}
@@ -16,8 +16,10 @@ mod concat;
 mod filter;
 mod group_by;
 mod group_by_hash;
+mod hash;
 mod scatter;
 mod sort;
+mod string_view;
 mod take;
 mod take_chunks;
 mod take_compact;
@@ -27,6 +29,7 @@ mod utils;
 pub use group_by::*;
 pub use group_by_hash::*;
 pub use sort::*;
+pub use string_view::*;
 pub use take_chunks::*;
 pub use topk::*;
 pub use utils::*;
@@ -12,12 +12,25 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_arrow::arrow::buffer::Buffer;
 use common_exception::Result;
 use itertools::Itertools;
 
+use crate::kernels::utils::copy_advance_aligned;
 use crate::kernels::utils::set_vec_len_by_ptr;
 use crate::kernels::utils::store_advance_aligned;
+use crate::types::decimal::DecimalColumn;
+use crate::types::nullable::NullableColumn;
+use crate::types::number::NumberColumn;
+use crate::types::string::StringColumn;
+use crate::with_decimal_type;
+use crate::with_number_mapped_type;
+use crate::BlockEntry;
+use crate::Column;
 use crate::DataBlock;
+use crate::Value;
 
 impl DataBlock {
     pub fn scatter<I>(&self, indices: &[I], scatter_size: usize) -> Result<Vec<Self>>
@@ -31,27 +44,35 @@ impl DataBlock {
             return Ok(result);
         }
 
-        let scatter_indices = Self::divide_indices_by_scatter_size(indices, scatter_size);
-
-        let has_string_column = self
-            .columns()
-            .iter()
-            .any(|col| col.data_type.is_string_column());
-        let mut string_items_buf = if has_string_column {
-            let max_num_rows = scatter_indices
-                .iter()
-                .map(|indices| indices.len())
-                .max()
-                .unwrap();
-            Some(vec![(0, 0); max_num_rows])
-        } else {
-            None
-        };
+        let mut scatter_columns = Vec::with_capacity(self.num_columns());
+        for entry in self.columns() {
+            if let Value::Column(column) = &entry.value {
+                scatter_columns.push(column.scatter(indices, scatter_size).into_iter());
+            }
+        }
 
+        let scatter_num_rows = Column::scatter_num_rows(indices, scatter_size);
         let mut results = Vec::with_capacity(scatter_size);
-        for indices in scatter_indices.iter().take(scatter_size) {
-            let block = self.take(indices, &mut string_items_buf)?;
-            results.push(block);
+        for num_rows in scatter_num_rows {
+            let mut scatter_columns = scatter_columns.iter_mut();
+            let columns = self
+                .columns()
+                .iter()
+                .map(|entry| match &entry.value {
+                    Value::Scalar(s) => {
+                        BlockEntry::new(entry.data_type.clone(), Value::Scalar(s.clone()))
+                    }
+                    Value::Column(_) => BlockEntry::new(
+                        entry.data_type.clone(),
+                        Value::Column(scatter_columns.next().unwrap().next().unwrap()),
+                    ),
+                })
+                .collect();
+            results.push(DataBlock::new_with_meta(
+                columns,
+                num_rows,
+                self.get_meta().cloned(),
+            ));
         }
 
         Ok(results)
@@ -85,3 +106,254 @@ impl DataBlock {
         scatter_indices
     }
 }
+
+impl Column {
+    /// Split the column into `scatter_size` columns, the row `i` goes to the column `indices[i]`.
+    ///
+    /// Rows keep their relative order inside each output column. Every index must be less
+    /// than `scatter_size` and `indices.len()` must be equal to `self.len()`.
+    pub fn scatter<I>(&self, indices: &[I], scatter_size: usize) -> Vec<Self>
+    where I: common_arrow::arrow::types::Index {
+        debug_assert_eq!(self.len(), indices.len());
+        match self {
+            Column::Null { .. } => Self::scatter_num_rows(indices, scatter_size)
+                .into_iter()
+                .map(|len| Column::Null { len })
+                .collect(),
+            Column::EmptyArray { .. } => Self::scatter_num_rows(indices, scatter_size)
+                .into_iter()
+                .map(|len| Column::EmptyArray { len })
+                .collect(),
+            Column::EmptyMap { .. } => Self::scatter_num_rows(indices, scatter_size)
+                .into_iter()
+                .map(|len| Column::EmptyMap { len })
+                .collect(),
+            Column::Number(column) => with_number_mapped_type!(|NUM_TYPE| match column {
+                NumberColumn::NUM_TYPE(values) => {
+                    Self::scatter_primitive_types(values, indices, scatter_size)
+                        .into_iter()
+                        .map(|values| Column::Number(NumberColumn::NUM_TYPE(values)))
+                        .collect()
+                }
+            }),
+            Column::Decimal(column) => with_decimal_type!(|DECIMAL_TYPE| match column {
+                DecimalColumn::DECIMAL_TYPE(values, size) => {
+                    Self::scatter_primitive_types(values, indices, scatter_size)
+                        .into_iter()
+                        .map(|values| Column::Decimal(DecimalColumn::DECIMAL_TYPE(values, *size)))
+                        .collect()
+                }
+            }),
+            Column::Boolean(bm) => Self::scatter_boolean_types(bm, indices, scatter_size)
+                .into_iter()
+                .map(Column::Boolean)
+                .collect(),
+            Column::String(column) => Self::scatter_string_types(column, indices, scatter_size)
+                .into_iter()
+                .map(Column::String)
+                .collect(),
+            Column::Timestamp(column) => {
+                Self::scatter_primitive_types(column, indices, scatter_size)
+                    .into_iter()
+                    .map(Column::Timestamp)
+                    .collect()
+            }
+            Column::Date(column) => Self::scatter_primitive_types(column, indices, scatter_size)
+                .into_iter()
+                .map(Column::Date)
+                .collect(),
+            Column::Interval(column) => {
+                Self::scatter_primitive_types(column, indices, scatter_size)
+                    .into_iter()
+                    .map(Column::Interval)
+                    .collect()
+            }
+            Column::Array(_) | Column::Map(_) => {
+                // The values of nested columns are gathered by `take` in a single pass for
+                // each partition, so only the row indices need to be divided here.
+                let scatter_indices =
+                    DataBlock::divide_indices_by_scatter_size(indices, scatter_size);
+                let mut string_items_buf = None;
+                scatter_indices
+                    .iter()
+                    .map(|indices| self.take(indices, &mut string_items_buf))
+                    .collect()
+            }
+            Column::Bitmap(column) => Self::scatter_string_types(column, indices, scatter_size)
+                .into_iter()
+                .map(Column::Bitmap)
+                .collect(),
+            Column::Geometry(column) => Self::scatter_string_types(column, indices, scatter_size)
+                .into_iter()
+                .map(Column::Geometry)
+                .collect(),
+            Column::Nullable(c) => {
+                let columns = c.column.scatter(indices, scatter_size);
+                let validities = Self::scatter_boolean_types(&c.validity, indices, scatter_size);
+                columns
+                    .into_iter()
+                    .zip(validities)
+                    .map(|(column, validity)| {
+                        Column::Nullable(Box::new(NullableColumn { column, validity }))
+                    })
+                    .collect()
+            }
+            Column::Tuple(fields) => {
+                let mut fields = fields
+                    .iter()
+                    .map(|c| c.scatter(indices, scatter_size).into_iter())
+                    .collect::<Vec<_>>();
+                (0..scatter_size)
+                    .map(|_| {
+                        Column::Tuple(
+                            fields
+                                .iter_mut()
+                                .map(|field| field.next().unwrap())
+                                .collect(),
+                        )
+                    })
+                    .collect()
+            }
+            Column::Variant(column) => Self::scatter_string_types(column, indices, scatter_size)
+                .into_iter()
+                .map(Column::Variant)
+                .collect(),
+            Column::Constant(col) => Self::scatter_num_rows(indices, scatter_size)
+                .into_iter()
+                .map(|len| col.with_len(len))
+                .collect(),
+        }
+    }
+
+    /// Count the number of rows that go to each output column.
+    pub fn scatter_num_rows<I>(indices: &[I], scatter_size: usize) -> Vec<usize>
+    where I: common_arrow::arrow::types::Index {
+        let mut scatter_num_rows = vec![0usize; scatter_size];
+        for index in indices.iter() {
+            scatter_num_rows[index.to_usize()] += 1;
+        }
+        scatter_num_rows
+    }
+
+    pub fn scatter_primitive_types<T, I>(
+        col: &Buffer<T>,
+        indices: &[I],
+        scatter_size: usize,
+    ) -> Vec<Buffer<T>>
+    where
+        T: Copy,
+        I: common_arrow::arrow::types::Index,
+    {
+        let mut builders = Self::scatter_num_rows(indices, scatter_size)
+            .into_iter()
+            .map(Vec::<T>::with_capacity)
+            .collect::<Vec<_>>();
+        unsafe {
+            let mut builder_ptrs = builders
+                .iter_mut()
+                .map(|builder| builder.as_mut_ptr())
+                .collect_vec();
+            for (value, index) in col.iter().zip(indices.iter()) {
+                store_advance_aligned(*value, builder_ptrs.get_unchecked_mut(index.to_usize()));
+            }
+            for (builder, ptr) in builders.iter_mut().zip(builder_ptrs) {
+                set_vec_len_by_ptr(builder, ptr);
+            }
+        }
+        builders.into_iter().map(|builder| builder.into()).collect()
+    }
+
+    pub fn scatter_boolean_types<I>(
+        col: &Bitmap,
+        indices: &[I],
+        scatter_size: usize,
+    ) -> Vec<Bitmap>
+    where
+        I: common_arrow::arrow::types::Index,
+    {
+        let scatter_num_rows = Self::scatter_num_rows(indices, scatter_size);
+        // Fast path: all rows share the same value, so every output is a constant bitmap.
+        let unset_bits = col.unset_bits();
+        if unset_bits == 0 || unset_bits == col.len() {
+            let value = unset_bits == 0;
+            return scatter_num_rows
+                .into_iter()
+                .map(|len| {
+                    let mut builder = MutableBitmap::with_capacity(len);
+                    builder.extend_constant(len, value);
+                    builder.into()
+                })
+                .collect();
+        }
+
+        let mut builders = scatter_num_rows
+            .into_iter()
+            .map(MutableBitmap::with_capacity)
+            .collect::<Vec<_>>();
+        for (value, index) in col.iter().zip(indices.iter()) {
+            unsafe {
+                builders
+                    .get_unchecked_mut(index.to_usize())
+                    .push_unchecked(value)
+            };
+        }
+        builders.into_iter().map(|builder| builder.into()).collect()
+    }
+
+    pub fn scatter_string_types<I>(
+        col: &StringColumn,
+        indices: &[I],
+        scatter_size: usize,
+    ) -> Vec<StringColumn>
+    where
+        I: common_arrow::arrow::types::Index,
+    {
+        let col_offsets = col.offsets().as_slice();
+        let col_data = col.data().as_slice();
+
+        // Count the number of rows and bytes of each output column first,
+        // so that [`data`] and [`offsets`] are allocated only once.
+        let mut scatter_num_rows = vec![0usize; scatter_size];
+        let mut scatter_data_size = vec![0usize; scatter_size];
+        for (i, index) in indices.iter().enumerate() {
+            let index = index.to_usize();
+            scatter_num_rows[index] += 1;
+            scatter_data_size[index] += (col_offsets[i + 1] - col_offsets[i]) as usize;
+        }
+
+        let mut offsets = scatter_num_rows
+            .iter()
+            .map(|num_rows| {
+                let mut offsets = Vec::<u64>::with_capacity(num_rows + 1);
+                offsets.push(0);
+                offsets
+            })
+            .collect::<Vec<_>>();
+        let mut data = scatter_data_size
+            .iter()
+            .map(|data_size| Vec::<u8>::with_capacity(*data_size))
+            .collect::<Vec<_>>();
+
+        unsafe {
+            let mut data_ptrs = data.iter_mut().map(|data| data.as_mut_ptr()).collect_vec();
+            for (i, index) in indices.iter().enumerate() {
+                let index = index.to_usize();
+                let start = *col_offsets.get_unchecked(i) as usize;
+                let len = *col_offsets.get_unchecked(i + 1) as usize - start;
+                let data_ptr = data_ptrs.get_unchecked_mut(index);
+                copy_advance_aligned(col_data.as_ptr().add(start), data_ptr, len);
+                let offsets = offsets.get_unchecked_mut(index);
+                let last = *offsets.last().unwrap_unchecked();
+                offsets.push(last + len as u64);
+            }
+            for (data, ptr) in data.iter_mut().zip(data_ptrs) {
+                set_vec_len_by_ptr(data, ptr);
+            }
+        }
+
+        data.into_iter()
+            .zip(offsets)
+            .map(|(data, offsets)| StringColumn::new(data.into(), offsets.into()))
+            .collect()
+    }
+}
@@ -29,10 +29,12 @@ use common_arrow::arrow::error::Result as ArrowResult;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
+use crate::types::string::StringColumn;
 use crate::types::DataType;
 use crate::utils::arrow::column_to_arrow_array;
 use crate::Column;
 use crate::DataBlock;
+use crate::StringViewColumn;
 
 pub type Aborting = Arc<Box<dyn Fn() -> bool + Send + Sync + 'static>>;
 
@@ -75,6 +77,15 @@ impl DataBlock {
         if num_rows <= 1 {
             return Ok(block.clone());
         }
+        if let [description] = descriptions {
+            if let Some(Column::String(column)) =
+                block.get_by_offset(description.offset).value.as_column()
+            {
+                let indices = sort_string_column(column, description.asc, limit);
+                return DataBlock::take(block, &indices, &mut None);
+            }
+        }
+
         let order_columns = descriptions
             .iter()
             .map(|d| column_to_arrow_array(block.get_by_offset(d.offset), num_rows))
@@ -207,6 +218,28 @@ impl DataBlock {
     }
 }
 
+/// Sorts a string column by its string views, most of the comparisons are decided
+/// by the prefixes in the views without touching the data buffer.
+fn sort_string_column(column: &StringColumn, asc: bool, limit: Option<usize>) -> Vec<u32> {
+    let views = StringViewColumn::from(column);
+    let mut indices = (0..views.len() as u32).collect::<Vec<_>>();
+    let compare = |a: &u32, b: &u32| {
+        let ordering = views.compare(*a as usize, &views, *b as usize);
+        if asc { ordering } else { ordering.reverse() }
+    };
+    match limit {
+        Some(limit) if limit < indices.len() => {
+            if limit > 0 {
+                indices.select_nth_unstable_by(limit - 1, compare);
+            }
+            indices.truncate(limit);
+            indices.sort_by(compare);
+        }
+        _ => indices.sort_by(compare),
+    }
+    indices
+}
+
 fn compare_variant(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynComparator> {
     let left = Column::from_arrow(left, &DataType::Variant)
         .as_variant()
@@ -0,0 +1,113 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The string view layout of a [`StringColumn`], a.k.a. German strings.
+//!
+//! Every string is described by a [`StringView`] of 16 bytes: the length, the first 4 bytes,
+//! and either the remaining bytes if the string is not longer than 12 bytes, or
+//! the offset of the string in the data buffer. Comparing two views only touches
+//! the data buffer if both strings are long and share the same prefix.
+//!
+//! The views share the data buffer of the column and are built at the block
+//! boundary of the sort on a single non-nullable string key. The hash join keeps
+//! the keys of its string hash table in the same layout, see `StringRawEntry`.
+//! The group-by hash table stores keys up to 24 bytes inline already.
+
+use std::cmp::Ordering;
+
+use common_arrow::arrow::buffer::Buffer;
+pub use common_hashtable::StringView;
+pub use common_hashtable::STRING_VIEW_INLINE_SIZE as MAX_INLINE_LEN;
+
+use crate::types::string::StringColumn;
+use crate::types::string::StringColumnBuilder;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct StringViewColumn {
+    views: Vec<StringView>,
+    data: Buffer<u8>,
+}
+
+impl StringViewColumn {
+    pub fn len(&self) -> usize {
+        self.views.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.views.is_empty()
+    }
+
+    pub fn views(&self) -> &[StringView] {
+        &self.views
+    }
+
+    #[inline]
+    pub fn index(&self, index: usize) -> &[u8] {
+        let view = &self.views[index];
+        if view.is_inlined() {
+            view.inlined()
+        } else {
+            let offset = view.payload() as usize;
+            &self.data[offset..offset + view.len()]
+        }
+    }
+
+    /// Returns whether the string at `index` equals the string at `other_index` of `other`.
+    #[inline]
+    pub fn equals(&self, index: usize, other: &StringViewColumn, other_index: usize) -> bool {
+        let (lhs, rhs) = (&self.views[index], &other.views[other_index]);
+        if lhs.len() != rhs.len() || lhs.prefix() != rhs.prefix() {
+            return false;
+        }
+        if lhs.is_inlined() {
+            return lhs == rhs;
+        }
+        self.index(index) == other.index(other_index)
+    }
+
+    /// Compares the string at `index` with the string at `other_index` of `other`.
+    #[inline]
+    pub fn compare(&self, index: usize, other: &StringViewColumn, other_index: usize) -> Ordering {
+        let (lhs, rhs) = (&self.views[index], &other.views[other_index]);
+        match lhs.prefix().cmp(&rhs.prefix()) {
+            Ordering::Equal => self.index(index).cmp(other.index(other_index)),
+            ordering => ordering,
+        }
+    }
+
+    pub fn to_string_column(&self) -> StringColumn {
+        let data_capacity = self.views.iter().map(StringView::len).sum();
+        let mut builder = StringColumnBuilder::with_capacity(self.len(), data_capacity);
+        for index in 0..self.len() {
+            builder.put_slice(self.index(index));
+            builder.commit_row();
+        }
+        builder.build()
+    }
+}
+
+impl From<&StringColumn> for StringViewColumn {
+    fn from(column: &StringColumn) -> Self {
+        let data = column.data();
+        let views = column
+            .offsets()
+            .windows(2)
+            .map(|w| StringView::new(&data[w[0] as usize..w[1] as usize], w[0]))
+            .collect();
+        StringViewColumn {
+            views,
+            data: data.clone(),
+        }
+    }
+}
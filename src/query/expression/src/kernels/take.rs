@@ -21,18 +21,15 @@ use common_exception::Result;
 use crate::kernels::utils::copy_advance_aligned;
 use crate::kernels::utils::set_vec_len_by_ptr;
 use crate::types::array::ArrayColumn;
-use crate::types::array::ArrayColumnBuilder;
 use crate::types::bitmap::BitmapType;
 use crate::types::decimal::DecimalColumn;
-use crate::types::map::KvColumnBuilder;
 use crate::types::nullable::NullableColumn;
 use crate::types::number::NumberColumn;
 use crate::types::string::StringColumn;
 use crate::types::AnyType;
 use crate::types::ArgType;
-use crate::types::ArrayType;
 use crate::types::BooleanType;
-use crate::types::MapType;
+use crate::types::GeometryType;
 use crate::types::NumberType;
 use crate::types::StringType;
 use crate::types::ValueType;
@@ -41,7 +38,6 @@ use crate::with_decimal_type;
 use crate::with_number_mapped_type;
 use crate::BlockEntry;
 use crate::Column;
-use crate::ColumnBuilder;
 use crate::DataBlock;
 use crate::Value;
 
@@ -83,6 +79,9 @@ impl DataBlock {
 }
 
 impl Column {
+    /// Gather the rows at `indices` into a new column.
+    ///
+    /// The indices are not bounds checked, every index must be less than `self.len()`.
     pub fn take<I>(&self, indices: &[I], string_items_buf: &mut Option<Vec<(u64, usize)>>) -> Self
     where I: common_arrow::arrow::types::Index {
         match self {
@@ -134,36 +133,30 @@ impl Column {
                 .unwrap();
                 Column::Date(d)
             }
-            Column::Array(column) => {
-                let mut offsets = Vec::with_capacity(indices.len() + 1);
-                offsets.push(0);
-                let builder = ColumnBuilder::with_capacity(&column.values.data_type(), self.len());
-                let builder = ArrayColumnBuilder { builder, offsets };
-                Self::take_value_types::<ArrayType<AnyType>, _>(column, builder, indices)
-            }
-            Column::Map(column) => {
-                let mut offsets = Vec::with_capacity(indices.len() + 1);
-                offsets.push(0);
-                let builder = ColumnBuilder::from_column(
-                    ColumnBuilder::with_capacity(&column.values.data_type(), self.len()).build(),
-                );
-                let (key_builder, val_builder) = match builder {
-                    ColumnBuilder::Tuple(fields) => (fields[0].clone(), fields[1].clone()),
-                    _ => unreachable!(),
-                };
-                let builder = KvColumnBuilder {
-                    keys: key_builder,
-                    values: val_builder,
-                };
-                let builder = ArrayColumnBuilder { builder, offsets };
-                let column = ArrayColumn::try_downcast(column).unwrap();
-                Self::take_value_types::<MapType<AnyType, AnyType>, _>(&column, builder, indices)
+            Column::Interval(column) => {
+                let builder = Self::take_primitive_types(column, indices);
+                Column::Interval(builder.into())
             }
+            Column::Array(column) => Column::Array(Box::new(Self::take_array_types(
+                column,
+                indices,
+                string_items_buf,
+            ))),
+            Column::Map(column) => Column::Map(Box::new(Self::take_array_types(
+                column,
+                indices,
+                string_items_buf,
+            ))),
             Column::Bitmap(column) => BitmapType::upcast_column(Self::take_string_types(
                 column,
                 indices,
                 string_items_buf.as_mut(),
             )),
+            Column::Geometry(column) => GeometryType::upcast_column(Self::take_string_types(
+                column,
+                indices,
+                string_items_buf.as_mut(),
+            )),
             Column::Nullable(c) => {
                 let column = c.column.take(indices, string_items_buf);
                 let validity = Column::Boolean(Self::take_boolean_types(&c.validity, indices));
@@ -184,6 +177,7 @@ impl Column {
                 indices,
                 string_items_buf.as_mut(),
             )),
+            Column::Constant(col) => col.with_len(indices.len()),
         }
     }
 
@@ -303,19 +297,34 @@ impl Column {
         }
     }
 
-    fn take_value_types<T: ValueType, I>(
-        col: &T::Column,
-        mut builder: T::ColumnBuilder,
+    /// Take the offsets row by row, and the values all at once with the indices of the values
+    /// in the taken rows.
+    fn take_array_types<I>(
+        column: &ArrayColumn<AnyType>,
         indices: &[I],
-    ) -> Column
+        string_items_buf: &mut Option<Vec<(u64, usize)>>,
+    ) -> ArrayColumn<AnyType>
     where
         I: common_arrow::arrow::types::Index,
     {
+        let offsets = column.offsets.as_slice();
+        let mut new_offsets: Vec<u64> = Vec::with_capacity(indices.len() + 1);
+        new_offsets.push(0);
+        let mut values_indices: Vec<u64> = Vec::new();
         for index in indices {
-            T::push_item(&mut builder, unsafe {
-                T::index_column_unchecked(col, index.to_usize())
-            });
+            let (start, end) = unsafe {
+                (
+                    *offsets.get_unchecked(index.to_usize()),
+                    *offsets.get_unchecked(index.to_usize() + 1),
+                )
+            };
+            values_indices.extend(start..end);
+            new_offsets.push(values_indices.len() as u64);
+        }
+
+        ArrayColumn {
+            values: column.values.take(&values_indices, string_items_buf),
+            offsets: new_offsets.into(),
         }
-        T::upcast_column(T::build_column(builder))
     }
 }
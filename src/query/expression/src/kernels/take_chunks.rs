@@ -38,6 +38,8 @@ use crate::types::ArrayType;
 use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
+use crate::types::GeometryType;
+use crate::types::IntervalType;
 use crate::types::MapType;
 use crate::types::NumberColumnVec;
 use crate::types::NumberType;
@@ -231,6 +233,10 @@ impl Column {
         indices: &[BlockRowIndex],
         result_size: usize,
     ) -> Column {
+        if columns.iter().any(|col| col.is_constant()) {
+            let columns = columns.iter().map(|col| col.materialize()).collect_vec();
+            return Self::take_column_indices(&columns, datatype, indices, result_size);
+        }
         match &columns[0] {
             Column::Null { .. } => Column::Null { len: result_size },
             Column::EmptyArray { .. } => Column::EmptyArray { len: result_size },
@@ -277,6 +283,10 @@ impl Column {
                 let builder = DateType::create_builder(result_size, &[]);
                 Self::take_block_value_types::<DateType>(columns, builder, indices)
             }
+            Column::Interval(_) => {
+                let builder = IntervalType::create_builder(result_size, &[]);
+                Self::take_block_value_types::<IntervalType>(columns, builder, indices)
+            }
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(result_size + 1);
                 offsets.push(0);
@@ -305,6 +315,10 @@ impl Column {
                 let builder = BitmapType::create_builder(result_size, &[]);
                 Self::take_block_value_types::<BitmapType>(columns, builder, indices)
             }
+            Column::Geometry(_) => {
+                let builder = GeometryType::create_builder(result_size, &[]);
+                Self::take_block_value_types::<GeometryType>(columns, builder, indices)
+            }
             Column::Nullable(_) => {
                 let inner_ty = datatype.as_nullable().unwrap();
                 let inner_columns = columns
@@ -370,10 +384,15 @@ impl Column {
                 let builder = VariantType::create_builder(result_size, &[]);
                 Self::take_block_value_types::<VariantType>(columns, builder, indices)
             }
+            Column::Constant(_) => unreachable!(),
         }
     }
 
     pub fn take_downcast_column_vec(columns: &[Column], datatype: DataType) -> ColumnVec {
+        if columns.iter().any(|col| col.is_constant()) {
+            let columns = columns.iter().map(|col| col.materialize()).collect_vec();
+            return Self::take_downcast_column_vec(&columns, datatype);
+        }
         match &columns[0] {
             Column::Null { .. } => ColumnVec::Null,
             Column::EmptyArray { .. } => ColumnVec::EmptyArray,
@@ -449,6 +468,20 @@ impl Column {
                         .collect_vec();
                     ColumnVec::Number(NumberColumnVec::Float64(columns))
                 }
+                NumberColumn::UInt128(_) => {
+                    let columns = columns
+                        .iter()
+                        .map(|col| <NumberType<u128>>::try_downcast_column(col).unwrap())
+                        .collect_vec();
+                    ColumnVec::Number(NumberColumnVec::UInt128(columns))
+                }
+                NumberColumn::Int128(_) => {
+                    let columns = columns
+                        .iter()
+                        .map(|col| <NumberType<i128>>::try_downcast_column(col).unwrap())
+                        .collect_vec();
+                    ColumnVec::Number(NumberColumnVec::Int128(columns))
+                }
             },
             Column::Decimal(column) => with_decimal_type!(|DECIMAL_TYPE| match column {
                 DecimalColumn::DECIMAL_TYPE(_, size) => {
@@ -490,6 +523,13 @@ impl Column {
                     .collect_vec();
                 ColumnVec::Date(columns)
             }
+            Column::Interval(_) => {
+                let columns = columns
+                    .iter()
+                    .map(|col| IntervalType::try_downcast_column(col).unwrap())
+                    .collect_vec();
+                ColumnVec::Interval(columns)
+            }
             Column::Array(_) => {
                 let columns = columns
                     .iter()
@@ -511,6 +551,13 @@ impl Column {
                     .collect_vec();
                 ColumnVec::Bitmap(columns)
             }
+            Column::Geometry(_) => {
+                let columns = columns
+                    .iter()
+                    .map(|col| GeometryType::try_downcast_column(col).unwrap())
+                    .collect_vec();
+                ColumnVec::Geometry(columns)
+            }
             Column::Nullable(_) => {
                 let inner_ty = datatype.as_nullable().unwrap();
                 let inner_columns = columns
@@ -571,6 +618,7 @@ impl Column {
                     .collect_vec();
                 ColumnVec::Variant(columns)
             }
+            Column::Constant(_) => unreachable!(),
         }
     }
 
@@ -630,6 +678,10 @@ impl Column {
                 .unwrap();
                 Column::Date(d)
             }
+            ColumnVec::Interval(columns) => {
+                let builder = Self::take_block_vec_primitive_types(columns, indices);
+                Column::Interval(builder.into())
+            }
             ColumnVec::Array(columns) => {
                 let data_type = data_type.as_array().unwrap();
                 let mut offsets = Vec::with_capacity(result_size + 1);
@@ -661,6 +713,9 @@ impl Column {
             ColumnVec::Bitmap(columns) => BitmapType::upcast_column(
                 Self::take_block_vec_string_types(columns, indices, string_items_buf.as_mut()),
             ),
+            ColumnVec::Geometry(columns) => GeometryType::upcast_column(
+                Self::take_block_vec_string_types(columns, indices, string_items_buf.as_mut()),
+            ),
             ColumnVec::Nullable(columns) => {
                 let inner_data_type = data_type.as_nullable().unwrap();
                 let inner_column = Self::take_column_vec_indices(
@@ -3457,12 +3457,11 @@ pub fn passthrough_nullable_1_arg<I1: ArgType, O: ArgType>(
         )),
         (ValueRef::Column(arg1)) => {
             let and_validity = arg1.validity;
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(ValueRef::Column(arg1.column), ctx)
                 .into_column()
                 .unwrap();
@@ -3493,12 +3492,11 @@ pub fn passthrough_nullable_2_arg<I1: ArgType, I2: ArgType, O: ArgType>(
         )),
         (ValueRef::Column(arg1), ValueRef::Scalar(Some(arg2))) => {
             let and_validity = arg1.validity;
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(ValueRef::Column(arg1.column), ValueRef::Scalar(arg2), ctx)
                 .into_column()
                 .unwrap();
@@ -3506,12 +3504,11 @@ pub fn passthrough_nullable_2_arg<I1: ArgType, I2: ArgType, O: ArgType>(
         }
         (ValueRef::Scalar(Some(arg1)), ValueRef::Column(arg2)) => {
             let and_validity = arg2.validity;
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(ValueRef::Scalar(arg1), ValueRef::Column(arg2.column), ctx)
                 .into_column()
                 .unwrap();
@@ -3519,12 +3516,11 @@ pub fn passthrough_nullable_2_arg<I1: ArgType, I2: ArgType, O: ArgType>(
         }
         (ValueRef::Column(arg1), ValueRef::Column(arg2)) => {
             let and_validity = common_arrow::arrow::bitmap::and(&arg1.validity, &arg2.validity);
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Column(arg2.column),
@@ -3576,12 +3572,11 @@ pub fn passthrough_nullable_3_arg<I1: ArgType, I2: ArgType, I3: ArgType, O: ArgT
         )),
         (ValueRef::Column(arg1), ValueRef::Scalar(Some(arg2)), ValueRef::Scalar(Some(arg3))) => {
             let and_validity = arg1.validity;
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Scalar(arg2),
@@ -3594,12 +3589,11 @@ pub fn passthrough_nullable_3_arg<I1: ArgType, I2: ArgType, I3: ArgType, O: ArgT
         }
         (ValueRef::Scalar(Some(arg1)), ValueRef::Column(arg2), ValueRef::Scalar(Some(arg3))) => {
             let and_validity = arg2.validity;
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Column(arg2.column),
@@ -3612,12 +3606,11 @@ pub fn passthrough_nullable_3_arg<I1: ArgType, I2: ArgType, I3: ArgType, O: ArgT
         }
         (ValueRef::Column(arg1), ValueRef::Column(arg2), ValueRef::Scalar(Some(arg3))) => {
             let and_validity = common_arrow::arrow::bitmap::and(&arg1.validity, &arg2.validity);
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Column(arg2.column),
@@ -3630,12 +3623,11 @@ pub fn passthrough_nullable_3_arg<I1: ArgType, I2: ArgType, I3: ArgType, O: ArgT
         }
         (ValueRef::Scalar(Some(arg1)), ValueRef::Scalar(Some(arg2)), ValueRef::Column(arg3)) => {
             let and_validity = arg3.validity;
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Scalar(arg2),
@@ -3648,12 +3640,11 @@ pub fn passthrough_nullable_3_arg<I1: ArgType, I2: ArgType, I3: ArgType, O: ArgT
         }
         (ValueRef::Column(arg1), ValueRef::Scalar(Some(arg2)), ValueRef::Column(arg3)) => {
             let and_validity = common_arrow::arrow::bitmap::and(&arg1.validity, &arg3.validity);
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Scalar(arg2),
@@ -3666,12 +3657,11 @@ pub fn passthrough_nullable_3_arg<I1: ArgType, I2: ArgType, I3: ArgType, O: ArgT
         }
         (ValueRef::Scalar(Some(arg1)), ValueRef::Column(arg2), ValueRef::Column(arg3)) => {
             let and_validity = common_arrow::arrow::bitmap::and(&arg2.validity, &arg3.validity);
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Column(arg2.column),
@@ -3687,12 +3677,11 @@ pub fn passthrough_nullable_3_arg<I1: ArgType, I2: ArgType, I3: ArgType, O: ArgT
                 &common_arrow::arrow::bitmap::and(&arg1.validity, &arg2.validity),
                 &arg3.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Column(arg2.column),
@@ -3761,12 +3750,11 @@ pub fn passthrough_nullable_4_arg<
             ValueRef::Scalar(Some(arg4)),
         ) => {
             let and_validity = arg1.validity;
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Scalar(arg2),
@@ -3785,12 +3773,11 @@ pub fn passthrough_nullable_4_arg<
             ValueRef::Scalar(Some(arg4)),
         ) => {
             let and_validity = arg2.validity;
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Column(arg2.column),
@@ -3809,12 +3796,11 @@ pub fn passthrough_nullable_4_arg<
             ValueRef::Scalar(Some(arg4)),
         ) => {
             let and_validity = common_arrow::arrow::bitmap::and(&arg1.validity, &arg2.validity);
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Column(arg2.column),
@@ -3833,12 +3819,11 @@ pub fn passthrough_nullable_4_arg<
             ValueRef::Scalar(Some(arg4)),
         ) => {
             let and_validity = arg3.validity;
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Scalar(arg2),
@@ -3857,12 +3842,11 @@ pub fn passthrough_nullable_4_arg<
             ValueRef::Scalar(Some(arg4)),
         ) => {
             let and_validity = common_arrow::arrow::bitmap::and(&arg1.validity, &arg3.validity);
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Scalar(arg2),
@@ -3881,12 +3865,11 @@ pub fn passthrough_nullable_4_arg<
             ValueRef::Scalar(Some(arg4)),
         ) => {
             let and_validity = common_arrow::arrow::bitmap::and(&arg2.validity, &arg3.validity);
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Column(arg2.column),
@@ -3908,12 +3891,11 @@ pub fn passthrough_nullable_4_arg<
                 &common_arrow::arrow::bitmap::and(&arg1.validity, &arg2.validity),
                 &arg3.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Column(arg2.column),
@@ -3932,12 +3914,11 @@ pub fn passthrough_nullable_4_arg<
             ValueRef::Column(arg4),
         ) => {
             let and_validity = arg4.validity;
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Scalar(arg2),
@@ -3956,12 +3937,11 @@ pub fn passthrough_nullable_4_arg<
             ValueRef::Column(arg4),
         ) => {
             let and_validity = common_arrow::arrow::bitmap::and(&arg1.validity, &arg4.validity);
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Scalar(arg2),
@@ -3980,12 +3960,11 @@ pub fn passthrough_nullable_4_arg<
             ValueRef::Column(arg4),
         ) => {
             let and_validity = common_arrow::arrow::bitmap::and(&arg2.validity, &arg4.validity);
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Column(arg2.column),
@@ -4007,12 +3986,11 @@ pub fn passthrough_nullable_4_arg<
                 &common_arrow::arrow::bitmap::and(&arg1.validity, &arg2.validity),
                 &arg4.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Column(arg2.column),
@@ -4031,12 +4009,11 @@ pub fn passthrough_nullable_4_arg<
             ValueRef::Column(arg4),
         ) => {
             let and_validity = common_arrow::arrow::bitmap::and(&arg3.validity, &arg4.validity);
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Scalar(arg2),
@@ -4058,12 +4035,11 @@ pub fn passthrough_nullable_4_arg<
                 &common_arrow::arrow::bitmap::and(&arg1.validity, &arg3.validity),
                 &arg4.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Scalar(arg2),
@@ -4085,12 +4061,11 @@ pub fn passthrough_nullable_4_arg<
                 &common_arrow::arrow::bitmap::and(&arg2.validity, &arg3.validity),
                 &arg4.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Column(arg2.column),
@@ -4115,12 +4090,11 @@ pub fn passthrough_nullable_4_arg<
                 ),
                 &arg4.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Column(arg2.column),
@@ -4197,12 +4171,11 @@ pub fn passthrough_nullable_5_arg<
             ValueRef::Scalar(Some(arg5)),
         ) => {
             let and_validity = arg1.validity;
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Scalar(arg2),
@@ -4223,12 +4196,11 @@ pub fn passthrough_nullable_5_arg<
             ValueRef::Scalar(Some(arg5)),
         ) => {
             let and_validity = arg2.validity;
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Column(arg2.column),
@@ -4249,12 +4221,11 @@ pub fn passthrough_nullable_5_arg<
             ValueRef::Scalar(Some(arg5)),
         ) => {
             let and_validity = common_arrow::arrow::bitmap::and(&arg1.validity, &arg2.validity);
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Column(arg2.column),
@@ -4275,12 +4246,11 @@ pub fn passthrough_nullable_5_arg<
             ValueRef::Scalar(Some(arg5)),
         ) => {
             let and_validity = arg3.validity;
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Scalar(arg2),
@@ -4301,12 +4271,11 @@ pub fn passthrough_nullable_5_arg<
             ValueRef::Scalar(Some(arg5)),
         ) => {
             let and_validity = common_arrow::arrow::bitmap::and(&arg1.validity, &arg3.validity);
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Scalar(arg2),
@@ -4327,12 +4296,11 @@ pub fn passthrough_nullable_5_arg<
             ValueRef::Scalar(Some(arg5)),
         ) => {
             let and_validity = common_arrow::arrow::bitmap::and(&arg2.validity, &arg3.validity);
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Column(arg2.column),
@@ -4356,12 +4324,11 @@ pub fn passthrough_nullable_5_arg<
                 &common_arrow::arrow::bitmap::and(&arg1.validity, &arg2.validity),
                 &arg3.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Column(arg2.column),
@@ -4382,12 +4349,11 @@ pub fn passthrough_nullable_5_arg<
             ValueRef::Scalar(Some(arg5)),
         ) => {
             let and_validity = arg4.validity;
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Scalar(arg2),
@@ -4408,12 +4374,11 @@ pub fn passthrough_nullable_5_arg<
             ValueRef::Scalar(Some(arg5)),
         ) => {
             let and_validity = common_arrow::arrow::bitmap::and(&arg1.validity, &arg4.validity);
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Scalar(arg2),
@@ -4434,12 +4399,11 @@ pub fn passthrough_nullable_5_arg<
             ValueRef::Scalar(Some(arg5)),
         ) => {
             let and_validity = common_arrow::arrow::bitmap::and(&arg2.validity, &arg4.validity);
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Column(arg2.column),
@@ -4463,12 +4427,11 @@ pub fn passthrough_nullable_5_arg<
                 &common_arrow::arrow::bitmap::and(&arg1.validity, &arg2.validity),
                 &arg4.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Column(arg2.column),
@@ -4489,12 +4452,11 @@ pub fn passthrough_nullable_5_arg<
             ValueRef::Scalar(Some(arg5)),
         ) => {
             let and_validity = common_arrow::arrow::bitmap::and(&arg3.validity, &arg4.validity);
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Scalar(arg2),
@@ -4518,12 +4480,11 @@ pub fn passthrough_nullable_5_arg<
                 &common_arrow::arrow::bitmap::and(&arg1.validity, &arg3.validity),
                 &arg4.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Scalar(arg2),
@@ -4547,12 +4508,11 @@ pub fn passthrough_nullable_5_arg<
                 &common_arrow::arrow::bitmap::and(&arg2.validity, &arg3.validity),
                 &arg4.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Column(arg2.column),
@@ -4579,12 +4539,11 @@ pub fn passthrough_nullable_5_arg<
                 ),
                 &arg4.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Column(arg2.column),
@@ -4605,12 +4564,11 @@ pub fn passthrough_nullable_5_arg<
             ValueRef::Column(arg5),
         ) => {
             let and_validity = arg5.validity;
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Scalar(arg2),
@@ -4631,12 +4589,11 @@ pub fn passthrough_nullable_5_arg<
             ValueRef::Column(arg5),
         ) => {
             let and_validity = common_arrow::arrow::bitmap::and(&arg1.validity, &arg5.validity);
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Scalar(arg2),
@@ -4657,12 +4614,11 @@ pub fn passthrough_nullable_5_arg<
             ValueRef::Column(arg5),
         ) => {
             let and_validity = common_arrow::arrow::bitmap::and(&arg2.validity, &arg5.validity);
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Column(arg2.column),
@@ -4686,12 +4642,11 @@ pub fn passthrough_nullable_5_arg<
                 &common_arrow::arrow::bitmap::and(&arg1.validity, &arg2.validity),
                 &arg5.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Column(arg2.column),
@@ -4712,12 +4667,11 @@ pub fn passthrough_nullable_5_arg<
             ValueRef::Column(arg5),
         ) => {
             let and_validity = common_arrow::arrow::bitmap::and(&arg3.validity, &arg5.validity);
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Scalar(arg2),
@@ -4741,12 +4695,11 @@ pub fn passthrough_nullable_5_arg<
                 &common_arrow::arrow::bitmap::and(&arg1.validity, &arg3.validity),
                 &arg5.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Scalar(arg2),
@@ -4770,12 +4723,11 @@ pub fn passthrough_nullable_5_arg<
                 &common_arrow::arrow::bitmap::and(&arg2.validity, &arg3.validity),
                 &arg5.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Column(arg2.column),
@@ -4802,12 +4754,11 @@ pub fn passthrough_nullable_5_arg<
                 ),
                 &arg5.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Column(arg2.column),
@@ -4828,12 +4779,11 @@ pub fn passthrough_nullable_5_arg<
             ValueRef::Column(arg5),
         ) => {
             let and_validity = common_arrow::arrow::bitmap::and(&arg4.validity, &arg5.validity);
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Scalar(arg2),
@@ -4857,12 +4807,11 @@ pub fn passthrough_nullable_5_arg<
                 &common_arrow::arrow::bitmap::and(&arg1.validity, &arg4.validity),
                 &arg5.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Scalar(arg2),
@@ -4886,12 +4835,11 @@ pub fn passthrough_nullable_5_arg<
                 &common_arrow::arrow::bitmap::and(&arg2.validity, &arg4.validity),
                 &arg5.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Column(arg2.column),
@@ -4918,12 +4866,11 @@ pub fn passthrough_nullable_5_arg<
                 ),
                 &arg5.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Column(arg2.column),
@@ -4947,12 +4894,11 @@ pub fn passthrough_nullable_5_arg<
                 &common_arrow::arrow::bitmap::and(&arg3.validity, &arg4.validity),
                 &arg5.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Scalar(arg2),
@@ -4979,12 +4925,11 @@ pub fn passthrough_nullable_5_arg<
                 ),
                 &arg5.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Scalar(arg2),
@@ -5011,12 +4956,11 @@ pub fn passthrough_nullable_5_arg<
                 ),
                 &arg5.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Column(arg2.column),
@@ -5046,12 +4990,11 @@ pub fn passthrough_nullable_5_arg<
                 ),
                 &arg5.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Column(arg2.column),
@@ -5083,12 +5026,11 @@ pub fn combine_nullable_1_arg<I1: ArgType, O: ArgType>(
         }
         (ValueRef::Column(arg1)) => {
             let and_validity = arg1.validity;
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(ValueRef::Column(arg1.column), ctx)
                 .into_column()
                 .unwrap();
@@ -5128,12 +5070,11 @@ pub fn combine_nullable_2_arg<I1: ArgType, I2: ArgType, O: ArgType>(
         ),
         (ValueRef::Column(arg1), ValueRef::Scalar(Some(arg2))) => {
             let and_validity = arg1.validity;
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(ValueRef::Column(arg1.column), ValueRef::Scalar(arg2), ctx)
                 .into_column()
                 .unwrap();
@@ -5146,12 +5087,11 @@ pub fn combine_nullable_2_arg<I1: ArgType, I2: ArgType, O: ArgType>(
         }
         (ValueRef::Scalar(Some(arg1)), ValueRef::Column(arg2)) => {
             let and_validity = arg2.validity;
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(ValueRef::Scalar(arg1), ValueRef::Column(arg2.column), ctx)
                 .into_column()
                 .unwrap();
@@ -5164,12 +5104,11 @@ pub fn combine_nullable_2_arg<I1: ArgType, I2: ArgType, O: ArgType>(
         }
         (ValueRef::Column(arg1), ValueRef::Column(arg2)) => {
             let and_validity = common_arrow::arrow::bitmap::and(&arg1.validity, &arg2.validity);
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Column(arg2.column),
@@ -5226,12 +5165,11 @@ pub fn combine_nullable_3_arg<I1: ArgType, I2: ArgType, I3: ArgType, O: ArgType>
         ),
         (ValueRef::Column(arg1), ValueRef::Scalar(Some(arg2)), ValueRef::Scalar(Some(arg3))) => {
             let and_validity = arg1.validity;
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Scalar(arg2),
@@ -5249,12 +5187,11 @@ pub fn combine_nullable_3_arg<I1: ArgType, I2: ArgType, I3: ArgType, O: ArgType>
         }
         (ValueRef::Scalar(Some(arg1)), ValueRef::Column(arg2), ValueRef::Scalar(Some(arg3))) => {
             let and_validity = arg2.validity;
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Column(arg2.column),
@@ -5272,12 +5209,11 @@ pub fn combine_nullable_3_arg<I1: ArgType, I2: ArgType, I3: ArgType, O: ArgType>
         }
         (ValueRef::Column(arg1), ValueRef::Column(arg2), ValueRef::Scalar(Some(arg3))) => {
             let and_validity = common_arrow::arrow::bitmap::and(&arg1.validity, &arg2.validity);
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Column(arg2.column),
@@ -5295,12 +5231,11 @@ pub fn combine_nullable_3_arg<I1: ArgType, I2: ArgType, I3: ArgType, O: ArgType>
         }
         (ValueRef::Scalar(Some(arg1)), ValueRef::Scalar(Some(arg2)), ValueRef::Column(arg3)) => {
             let and_validity = arg3.validity;
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Scalar(arg2),
@@ -5318,12 +5253,11 @@ pub fn combine_nullable_3_arg<I1: ArgType, I2: ArgType, I3: ArgType, O: ArgType>
         }
         (ValueRef::Column(arg1), ValueRef::Scalar(Some(arg2)), ValueRef::Column(arg3)) => {
             let and_validity = common_arrow::arrow::bitmap::and(&arg1.validity, &arg3.validity);
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Scalar(arg2),
@@ -5341,12 +5275,11 @@ pub fn combine_nullable_3_arg<I1: ArgType, I2: ArgType, I3: ArgType, O: ArgType>
         }
         (ValueRef::Scalar(Some(arg1)), ValueRef::Column(arg2), ValueRef::Column(arg3)) => {
             let and_validity = common_arrow::arrow::bitmap::and(&arg2.validity, &arg3.validity);
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Column(arg2.column),
@@ -5367,12 +5300,11 @@ pub fn combine_nullable_3_arg<I1: ArgType, I2: ArgType, I3: ArgType, O: ArgType>
                 &common_arrow::arrow::bitmap::and(&arg1.validity, &arg2.validity),
                 &arg3.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Column(arg2.column),
@@ -5440,12 +5372,11 @@ pub fn combine_nullable_4_arg<I1: ArgType, I2: ArgType, I3: ArgType, I4: ArgType
             ValueRef::Scalar(Some(arg4)),
         ) => {
             let and_validity = arg1.validity;
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Scalar(arg2),
@@ -5469,12 +5400,11 @@ pub fn combine_nullable_4_arg<I1: ArgType, I2: ArgType, I3: ArgType, I4: ArgType
             ValueRef::Scalar(Some(arg4)),
         ) => {
             let and_validity = arg2.validity;
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Column(arg2.column),
@@ -5498,12 +5428,11 @@ pub fn combine_nullable_4_arg<I1: ArgType, I2: ArgType, I3: ArgType, I4: ArgType
             ValueRef::Scalar(Some(arg4)),
         ) => {
             let and_validity = common_arrow::arrow::bitmap::and(&arg1.validity, &arg2.validity);
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Column(arg2.column),
@@ -5527,12 +5456,11 @@ pub fn combine_nullable_4_arg<I1: ArgType, I2: ArgType, I3: ArgType, I4: ArgType
             ValueRef::Scalar(Some(arg4)),
         ) => {
             let and_validity = arg3.validity;
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Scalar(arg2),
@@ -5556,12 +5484,11 @@ pub fn combine_nullable_4_arg<I1: ArgType, I2: ArgType, I3: ArgType, I4: ArgType
             ValueRef::Scalar(Some(arg4)),
         ) => {
             let and_validity = common_arrow::arrow::bitmap::and(&arg1.validity, &arg3.validity);
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Scalar(arg2),
@@ -5585,12 +5512,11 @@ pub fn combine_nullable_4_arg<I1: ArgType, I2: ArgType, I3: ArgType, I4: ArgType
             ValueRef::Scalar(Some(arg4)),
         ) => {
             let and_validity = common_arrow::arrow::bitmap::and(&arg2.validity, &arg3.validity);
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Column(arg2.column),
@@ -5617,12 +5543,11 @@ pub fn combine_nullable_4_arg<I1: ArgType, I2: ArgType, I3: ArgType, I4: ArgType
                 &common_arrow::arrow::bitmap::and(&arg1.validity, &arg2.validity),
                 &arg3.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Column(arg2.column),
@@ -5646,12 +5571,11 @@ pub fn combine_nullable_4_arg<I1: ArgType, I2: ArgType, I3: ArgType, I4: ArgType
             ValueRef::Column(arg4),
         ) => {
             let and_validity = arg4.validity;
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Scalar(arg2),
@@ -5675,12 +5599,11 @@ pub fn combine_nullable_4_arg<I1: ArgType, I2: ArgType, I3: ArgType, I4: ArgType
             ValueRef::Column(arg4),
         ) => {
             let and_validity = common_arrow::arrow::bitmap::and(&arg1.validity, &arg4.validity);
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Scalar(arg2),
@@ -5704,12 +5627,11 @@ pub fn combine_nullable_4_arg<I1: ArgType, I2: ArgType, I3: ArgType, I4: ArgType
             ValueRef::Column(arg4),
         ) => {
             let and_validity = common_arrow::arrow::bitmap::and(&arg2.validity, &arg4.validity);
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Column(arg2.column),
@@ -5736,12 +5658,11 @@ pub fn combine_nullable_4_arg<I1: ArgType, I2: ArgType, I3: ArgType, I4: ArgType
                 &common_arrow::arrow::bitmap::and(&arg1.validity, &arg2.validity),
                 &arg4.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Column(arg2.column),
@@ -5765,12 +5686,11 @@ pub fn combine_nullable_4_arg<I1: ArgType, I2: ArgType, I3: ArgType, I4: ArgType
             ValueRef::Column(arg4),
         ) => {
             let and_validity = common_arrow::arrow::bitmap::and(&arg3.validity, &arg4.validity);
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Scalar(arg2),
@@ -5797,12 +5717,11 @@ pub fn combine_nullable_4_arg<I1: ArgType, I2: ArgType, I3: ArgType, I4: ArgType
                 &common_arrow::arrow::bitmap::and(&arg1.validity, &arg3.validity),
                 &arg4.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Scalar(arg2),
@@ -5829,12 +5748,11 @@ pub fn combine_nullable_4_arg<I1: ArgType, I2: ArgType, I3: ArgType, I4: ArgType
                 &common_arrow::arrow::bitmap::and(&arg2.validity, &arg3.validity),
                 &arg4.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Column(arg2.column),
@@ -5864,12 +5782,11 @@ pub fn combine_nullable_4_arg<I1: ArgType, I2: ArgType, I3: ArgType, I4: ArgType
                 ),
                 &arg4.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Column(arg2.column),
@@ -5951,12 +5868,11 @@ pub fn combine_nullable_5_arg<
             ValueRef::Scalar(Some(arg5)),
         ) => {
             let and_validity = arg1.validity;
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Scalar(arg2),
@@ -5982,12 +5898,11 @@ pub fn combine_nullable_5_arg<
             ValueRef::Scalar(Some(arg5)),
         ) => {
             let and_validity = arg2.validity;
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Column(arg2.column),
@@ -6013,12 +5928,11 @@ pub fn combine_nullable_5_arg<
             ValueRef::Scalar(Some(arg5)),
         ) => {
             let and_validity = common_arrow::arrow::bitmap::and(&arg1.validity, &arg2.validity);
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Column(arg2.column),
@@ -6044,12 +5958,11 @@ pub fn combine_nullable_5_arg<
             ValueRef::Scalar(Some(arg5)),
         ) => {
             let and_validity = arg3.validity;
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Scalar(arg2),
@@ -6075,12 +5988,11 @@ pub fn combine_nullable_5_arg<
             ValueRef::Scalar(Some(arg5)),
         ) => {
             let and_validity = common_arrow::arrow::bitmap::and(&arg1.validity, &arg3.validity);
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Scalar(arg2),
@@ -6106,12 +6018,11 @@ pub fn combine_nullable_5_arg<
             ValueRef::Scalar(Some(arg5)),
         ) => {
             let and_validity = common_arrow::arrow::bitmap::and(&arg2.validity, &arg3.validity);
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Column(arg2.column),
@@ -6140,12 +6051,11 @@ pub fn combine_nullable_5_arg<
                 &common_arrow::arrow::bitmap::and(&arg1.validity, &arg2.validity),
                 &arg3.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Column(arg2.column),
@@ -6171,12 +6081,11 @@ pub fn combine_nullable_5_arg<
             ValueRef::Scalar(Some(arg5)),
         ) => {
             let and_validity = arg4.validity;
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Scalar(arg2),
@@ -6202,12 +6111,11 @@ pub fn combine_nullable_5_arg<
             ValueRef::Scalar(Some(arg5)),
         ) => {
             let and_validity = common_arrow::arrow::bitmap::and(&arg1.validity, &arg4.validity);
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Scalar(arg2),
@@ -6233,12 +6141,11 @@ pub fn combine_nullable_5_arg<
             ValueRef::Scalar(Some(arg5)),
         ) => {
             let and_validity = common_arrow::arrow::bitmap::and(&arg2.validity, &arg4.validity);
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Column(arg2.column),
@@ -6267,12 +6174,11 @@ pub fn combine_nullable_5_arg<
                 &common_arrow::arrow::bitmap::and(&arg1.validity, &arg2.validity),
                 &arg4.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Column(arg2.column),
@@ -6298,12 +6204,11 @@ pub fn combine_nullable_5_arg<
             ValueRef::Scalar(Some(arg5)),
         ) => {
             let and_validity = common_arrow::arrow::bitmap::and(&arg3.validity, &arg4.validity);
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Scalar(arg2),
@@ -6332,12 +6237,11 @@ pub fn combine_nullable_5_arg<
                 &common_arrow::arrow::bitmap::and(&arg1.validity, &arg3.validity),
                 &arg4.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Scalar(arg2),
@@ -6366,12 +6270,11 @@ pub fn combine_nullable_5_arg<
                 &common_arrow::arrow::bitmap::and(&arg2.validity, &arg3.validity),
                 &arg4.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Column(arg2.column),
@@ -6403,12 +6306,11 @@ pub fn combine_nullable_5_arg<
                 ),
                 &arg4.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Column(arg2.column),
@@ -6434,12 +6336,11 @@ pub fn combine_nullable_5_arg<
             ValueRef::Column(arg5),
         ) => {
             let and_validity = arg5.validity;
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Scalar(arg2),
@@ -6465,12 +6366,11 @@ pub fn combine_nullable_5_arg<
             ValueRef::Column(arg5),
         ) => {
             let and_validity = common_arrow::arrow::bitmap::and(&arg1.validity, &arg5.validity);
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Scalar(arg2),
@@ -6496,12 +6396,11 @@ pub fn combine_nullable_5_arg<
             ValueRef::Column(arg5),
         ) => {
             let and_validity = common_arrow::arrow::bitmap::and(&arg2.validity, &arg5.validity);
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Column(arg2.column),
@@ -6530,12 +6429,11 @@ pub fn combine_nullable_5_arg<
                 &common_arrow::arrow::bitmap::and(&arg1.validity, &arg2.validity),
                 &arg5.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Column(arg2.column),
@@ -6561,12 +6459,11 @@ pub fn combine_nullable_5_arg<
             ValueRef::Column(arg5),
         ) => {
             let and_validity = common_arrow::arrow::bitmap::and(&arg3.validity, &arg5.validity);
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Scalar(arg2),
@@ -6595,12 +6492,11 @@ pub fn combine_nullable_5_arg<
                 &common_arrow::arrow::bitmap::and(&arg1.validity, &arg3.validity),
                 &arg5.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Scalar(arg2),
@@ -6629,12 +6525,11 @@ pub fn combine_nullable_5_arg<
                 &common_arrow::arrow::bitmap::and(&arg2.validity, &arg3.validity),
                 &arg5.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Column(arg2.column),
@@ -6666,12 +6561,11 @@ pub fn combine_nullable_5_arg<
                 ),
                 &arg5.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Column(arg2.column),
@@ -6697,12 +6591,11 @@ pub fn combine_nullable_5_arg<
             ValueRef::Column(arg5),
         ) => {
             let and_validity = common_arrow::arrow::bitmap::and(&arg4.validity, &arg5.validity);
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Scalar(arg2),
@@ -6731,12 +6624,11 @@ pub fn combine_nullable_5_arg<
                 &common_arrow::arrow::bitmap::and(&arg1.validity, &arg4.validity),
                 &arg5.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Scalar(arg2),
@@ -6765,12 +6657,11 @@ pub fn combine_nullable_5_arg<
                 &common_arrow::arrow::bitmap::and(&arg2.validity, &arg4.validity),
                 &arg5.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Column(arg2.column),
@@ -6802,12 +6693,11 @@ pub fn combine_nullable_5_arg<
                 ),
                 &arg5.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Column(arg2.column),
@@ -6836,12 +6726,11 @@ pub fn combine_nullable_5_arg<
                 &common_arrow::arrow::bitmap::and(&arg3.validity, &arg4.validity),
                 &arg5.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Scalar(arg2),
@@ -6873,12 +6762,11 @@ pub fn combine_nullable_5_arg<
                 ),
                 &arg5.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Scalar(arg2),
@@ -6910,12 +6798,11 @@ pub fn combine_nullable_5_arg<
                 ),
                 &arg5.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Scalar(arg1),
                 ValueRef::Column(arg2.column),
@@ -6950,12 +6837,11 @@ pub fn combine_nullable_5_arg<
                 ),
                 &arg5.validity,
             );
-            let validity = ctx
-                .validity
-                .as_ref()
-                .map(|valid| valid & (&and_validity))
-                .unwrap_or(and_validity);
-            ctx.validity = Some(validity.clone());
+            let len = and_validity.len();
+            let validity = match ctx.narrow_validity(and_validity) {
+                Some(validity) => validity,
+                None => return Value::Column(NullableColumn::new_null(len, ctx.generics)),
+            };
             let nullable_column = func(
                 ValueRef::Column(arg1.column),
                 ValueRef::Column(arg2.column),
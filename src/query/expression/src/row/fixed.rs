@@ -13,9 +13,11 @@
 // limitations under the License.
 
 use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
 use ethnum::i256;
 
 use super::row_converter::null_sentinel;
+use crate::types::interval::months_days_micros;
 use crate::types::string::StringColumnBuilder;
 use crate::types::F32;
 use crate::types::F64;
@@ -24,11 +26,18 @@ pub trait FixedLengthEncoding: Copy {
     // 1 for null byte
     const ENCODED_LEN: usize = 1 + std::mem::size_of::<Self::Encoded>();
 
-    type Encoded: Sized + Copy + AsRef<[u8]> + AsMut<[u8]>;
+    type Encoded: Sized + Copy + Default + AsRef<[u8]> + AsMut<[u8]>;
 
     fn encode(self) -> Self::Encoded;
 }
 
+/// Types whose encoding can be converted back to the original value.
+///
+/// Intervals are encoded by their total length, so they can't be decoded.
+pub trait FixedLengthDecoding: FixedLengthEncoding {
+    fn decode(encoded: Self::Encoded) -> Self;
+}
+
 impl FixedLengthEncoding for bool {
     type Encoded = [u8; 1];
 
@@ -37,6 +46,12 @@ impl FixedLengthEncoding for bool {
     }
 }
 
+impl FixedLengthDecoding for bool {
+    fn decode(encoded: [u8; 1]) -> Self {
+        encoded[0] != 0
+    }
+}
+
 macro_rules! encode_signed {
     ($n:expr, $t:ty) => {
         impl FixedLengthEncoding for $t {
@@ -49,6 +64,14 @@ macro_rules! encode_signed {
                 b
             }
         }
+
+        impl FixedLengthDecoding for $t {
+            fn decode(mut encoded: [u8; $n]) -> Self {
+                // Toggle top "sign" bit back
+                encoded[0] ^= 0x80;
+                Self::from_be_bytes(encoded)
+            }
+        }
     };
 }
 
@@ -68,6 +91,12 @@ macro_rules! encode_unsigned {
                 self.to_be_bytes()
             }
         }
+
+        impl FixedLengthDecoding for $t {
+            fn decode(encoded: [u8; $n]) -> Self {
+                Self::from_be_bytes(encoded)
+            }
+        }
     };
 }
 
@@ -75,6 +104,7 @@ encode_unsigned!(1, u8);
 encode_unsigned!(2, u16);
 encode_unsigned!(4, u32);
 encode_unsigned!(8, u64);
+encode_unsigned!(16, u128);
 
 impl FixedLengthEncoding for F32 {
     type Encoded = [u8; 4];
@@ -87,6 +117,15 @@ impl FixedLengthEncoding for F32 {
     }
 }
 
+impl FixedLengthDecoding for F32 {
+    fn decode(encoded: [u8; 4]) -> Self {
+        // The bit twiddling of `encode` is its own inverse
+        let s = i32::decode(encoded);
+        let val = s ^ (((s >> 31) as u32) >> 1) as i32;
+        F32::from(f32::from_bits(val as u32))
+    }
+}
+
 impl FixedLengthEncoding for F64 {
     type Encoded = [u8; 8];
 
@@ -98,6 +137,24 @@ impl FixedLengthEncoding for F64 {
     }
 }
 
+impl FixedLengthDecoding for F64 {
+    fn decode(encoded: [u8; 8]) -> Self {
+        // The bit twiddling of `encode` is its own inverse
+        let s = i64::decode(encoded);
+        let val = s ^ (((s >> 63) as u64) >> 1) as i64;
+        F64::from(f64::from_bits(val as u64))
+    }
+}
+
+impl FixedLengthEncoding for months_days_micros {
+    type Encoded = [u8; 16];
+
+    fn encode(self) -> [u8; 16] {
+        // Intervals are ordered by their total length.
+        self.total_micros().encode()
+    }
+}
+
 pub fn encode<T, I>(
     out: &mut StringColumnBuilder,
     iter: I,
@@ -149,3 +206,24 @@ pub fn encode<T, I>(
         }
     }
 }
+
+/// Decode values of `T` from the head of each row, and advance the rows past them.
+pub fn decode<T: FixedLengthDecoding>(rows: &mut [&[u8]], asc: bool) -> (Vec<T>, Bitmap) {
+    let mut values = Vec::with_capacity(rows.len());
+    let mut validity = MutableBitmap::with_capacity(rows.len());
+    for row in rows.iter_mut() {
+        let (encoded, rest) = row.split_at(T::ENCODED_LEN);
+        *row = rest;
+        // Both null sentinels differ from the valid marker `1_u8`
+        validity.push(encoded[0] == 1);
+        // The value of a null row is meaningless, but is still decoded from the zeroed bytes
+        let mut value = T::Encoded::default();
+        value.as_mut().copy_from_slice(&encoded[1..]);
+        if !asc {
+            // Flip bits back
+            value.as_mut().iter_mut().for_each(|v| *v = !*v)
+        }
+        values.push(T::decode(value));
+    }
+    (values, validity.into())
+}
@@ -19,15 +19,19 @@ use common_exception::Result;
 use ethnum::i256;
 
 use super::fixed;
+use super::fixed::FixedLengthDecoding;
 use super::fixed::FixedLengthEncoding;
 use super::variable;
 use crate::types::decimal::DecimalColumn;
+use crate::types::interval::months_days_micros;
 use crate::types::string::StringColumn;
 use crate::types::string::StringColumnBuilder;
 use crate::types::DataType;
 use crate::types::DecimalDataType;
 use crate::types::NumberColumn;
 use crate::types::NumberDataType;
+use crate::types::NumberType;
+use crate::types::ValueType;
 use crate::with_decimal_type;
 use crate::with_number_mapped_type;
 use crate::with_number_type;
@@ -62,6 +66,7 @@ impl RowConverter {
             | DataType::EmptyMap
             | DataType::Map(_)
             | DataType::Bitmap
+            | DataType::Geometry
             | DataType::Tuple(_)
             | DataType::Generic(_) => false,
             DataType::Nullable(inner) => Self::support_data_type(inner.as_ref()),
@@ -90,6 +95,20 @@ impl RowConverter {
         rows
     }
 
+    /// Convert rows produced by [`Self::convert_columns`] back into columns.
+    ///
+    /// **NOTE**: Interval is encoded by its total length, so it can't be converted back.
+    pub fn convert_rows(&self, rows: &StringColumn) -> Result<Vec<Column>> {
+        let mut rows = rows.iter().collect::<Vec<_>>();
+        let columns = self
+            .fields
+            .iter()
+            .map(|field| decode_column(&mut rows, field))
+            .collect::<Result<Vec<_>>>()?;
+        debug_assert!(rows.iter().all(|row| row.is_empty()));
+        Ok(columns)
+    }
+
     fn new_empty_rows(&self, cols: &[Column], num_rows: usize) -> StringColumnBuilder {
         let mut lengths = vec![0_u64; num_rows];
 
@@ -123,6 +142,9 @@ impl RowConverter {
                 DataType::Date => lengths
                     .iter_mut()
                     .for_each(|x| *x += i32::ENCODED_LEN as u64),
+                DataType::Interval => lengths
+                    .iter_mut()
+                    .for_each(|x| *x += months_days_micros::ENCODED_LEN as u64),
                 DataType::String => {
                     let col = col.remove_nullable();
                     if all_null {
@@ -210,6 +232,9 @@ pub(super) fn null_sentinel(nulls_first: bool) -> u8 {
 }
 
 fn encode_column(out: &mut StringColumnBuilder, column: &Column, asc: bool, nulls_first: bool) {
+    if let Column::Constant(col) = column {
+        return encode_column(out, &col.materialize(), asc, nulls_first);
+    }
     let validity = column.validity();
     let column = column.remove_nullable();
     match column {
@@ -231,8 +256,69 @@ fn encode_column(out: &mut StringColumnBuilder, column: &Column, asc: bool, null
         }
         Column::Timestamp(col) => fixed::encode(out, col, validity, asc, nulls_first),
         Column::Date(col) => fixed::encode(out, col, validity, asc, nulls_first),
+        Column::Interval(col) => fixed::encode(out, col, validity, asc, nulls_first),
         Column::String(col) => variable::encode(out, col.iter(), validity, asc, nulls_first),
         Column::Variant(col) => variable::encode(out, col.iter(), validity, asc, nulls_first),
         _ => unimplemented!(),
     }
 }
+
+fn decode_column(rows: &mut [&[u8]], field: &SortField) -> Result<Column> {
+    let asc = field.asc;
+    let (column, validity) = match field.data_type.remove_nullable() {
+        DataType::Null => return Ok(Column::Null { len: rows.len() }),
+        DataType::Boolean => {
+            let (values, validity) = fixed::decode::<bool>(rows, asc);
+            (Column::Boolean(values.into_iter().collect()), validity)
+        }
+        DataType::Number(t) => with_number_mapped_type!(|NUM_TYPE| match t {
+            NumberDataType::NUM_TYPE => {
+                let (values, validity) = fixed::decode::<NUM_TYPE>(rows, asc);
+                (
+                    NumberType::<NUM_TYPE>::upcast_column(values.into()),
+                    validity,
+                )
+            }
+        }),
+        DataType::Decimal(t) => match t {
+            DecimalDataType::Decimal128(size) => {
+                let (values, validity) = fixed::decode::<i128>(rows, asc);
+                let column = DecimalColumn::Decimal128(values.into(), size);
+                (Column::Decimal(column), validity)
+            }
+            DecimalDataType::Decimal256(size) => {
+                let (values, validity) = fixed::decode::<i256>(rows, asc);
+                let column = DecimalColumn::Decimal256(values.into(), size);
+                (Column::Decimal(column), validity)
+            }
+        },
+        DataType::Timestamp => {
+            let (values, validity) = fixed::decode::<i64>(rows, asc);
+            (Column::Timestamp(values.into()), validity)
+        }
+        DataType::Date => {
+            let (values, validity) = fixed::decode::<i32>(rows, asc);
+            (Column::Date(values.into()), validity)
+        }
+        DataType::String => {
+            let (values, validity) = variable::decode(rows, asc, field.nulls_first);
+            (Column::String(values), validity)
+        }
+        DataType::Variant => {
+            let (values, validity) = variable::decode(rows, asc, field.nulls_first);
+            (Column::Variant(values), validity)
+        }
+        ty => {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Row format can not be converted back to {ty}"
+            )));
+        }
+    };
+
+    if field.data_type.is_nullable() {
+        Ok(column.wrap_nullable(Some(validity)))
+    } else {
+        debug_assert_eq!(validity.unset_bits(), 0);
+        Ok(column)
+    }
+}
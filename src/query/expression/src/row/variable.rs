@@ -13,8 +13,10 @@
 // limitations under the License.
 
 use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
 
 use super::row_converter::null_sentinel;
+use crate::types::string::StringColumn;
 use crate::types::string::StringColumnBuilder;
 
 /// The block size of the variable length encoding
@@ -132,3 +134,56 @@ fn encode_one(out: &mut [u8], val: &[u8], is_null: bool, asc: bool, nulls_first:
         end_offset as u64
     }
 }
+
+/// Decode byte arrays from the head of each row, and advance the rows past them.
+pub(super) fn decode(rows: &mut [&[u8]], asc: bool, nulls_first: bool) -> (StringColumn, Bitmap) {
+    let mut builder = StringColumnBuilder::with_capacity(rows.len(), 0);
+    let mut validity = MutableBitmap::with_capacity(rows.len());
+    for row in rows.iter_mut() {
+        let (is_null, len) = decode_one(&mut builder, row, asc, nulls_first);
+        builder.commit_row();
+        validity.push(!is_null);
+        *row = &row[len..];
+    }
+    (builder.build(), validity.into())
+}
+
+/// Decode one byte array into `out`, returns whether it's null and the length of its encoding.
+fn decode_one(
+    out: &mut StringColumnBuilder,
+    row: &[u8],
+    asc: bool,
+    nulls_first: bool,
+) -> (bool, usize) {
+    // Null sentinel is never inverted, other bytes are inverted if descending
+    if row[0] == null_sentinel(nulls_first) {
+        return (true, 1);
+    }
+    let flip = |v: u8| if asc { v } else { !v };
+    if flip(row[0]) == EMPTY_SENTINEL {
+        return (false, 1);
+    }
+    debug_assert_eq!(flip(row[0]), NON_EMPTY_SENTINEL);
+
+    let mut offset = 1;
+    loop {
+        let block = &row[offset..offset + BLOCK_SIZE + 1];
+        offset += BLOCK_SIZE + 1;
+
+        let marker = flip(block[BLOCK_SIZE]);
+        let len = if marker == BLOCK_CONTINUATION {
+            BLOCK_SIZE
+        } else {
+            marker as usize
+        };
+        if asc {
+            out.put_slice(&block[..len]);
+        } else {
+            block[..len].iter().for_each(|v| out.put_u8(!*v));
+        }
+
+        if marker != BLOCK_CONTINUATION {
+            return (false, offset);
+        }
+    }
+}
@@ -19,6 +19,7 @@ use std::sync::Arc;
 
 use common_arrow::arrow::datatypes::DataType as ArrowDataType;
 use common_arrow::arrow::datatypes::Field as ArrowField;
+use common_arrow::arrow::datatypes::IntervalUnit;
 use common_arrow::arrow::datatypes::Schema as ArrowSchema;
 use common_arrow::arrow::datatypes::TimeUnit;
 use common_exception::ErrorCode;
@@ -29,15 +30,22 @@ use serde::Serialize;
 
 use crate::types::decimal::DecimalDataType;
 use crate::types::decimal::DecimalSize;
+use crate::types::timestamp::PRECISION_MICRO;
+use crate::types::timestamp::PRECISION_MILLI;
+use crate::types::timestamp::PRECISION_NANO;
+use crate::types::timestamp::PRECISION_SEC;
 use crate::types::DataType;
 use crate::types::NumberDataType;
-use crate::with_number_type;
+use crate::with_number_type_without_128;
 use crate::BlockMetaInfo;
 use crate::BlockMetaInfoDowncast;
 use crate::Scalar;
 use crate::ARROW_EXT_TYPE_BITMAP;
 use crate::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use crate::ARROW_EXT_TYPE_EMPTY_MAP;
+use crate::ARROW_EXT_TYPE_GEOMETRY;
+use crate::ARROW_EXT_TYPE_INT128;
+use crate::ARROW_EXT_TYPE_UINT128;
 use crate::ARROW_EXT_TYPE_VARIANT;
 
 // Column id of TableField
@@ -50,12 +58,14 @@ pub const ROW_ID_COLUMN_ID: u32 = u32::MAX;
 pub const BLOCK_NAME_COLUMN_ID: u32 = u32::MAX - 1;
 pub const SEGMENT_NAME_COLUMN_ID: u32 = u32::MAX - 2;
 pub const SNAPSHOT_NAME_COLUMN_ID: u32 = u32::MAX - 3;
+pub const FILE_NAME_COLUMN_ID: u32 = u32::MAX - 4;
 // internal column name.
 pub const ROW_ID_COL_NAME: &str = "_row_id";
 pub const ROW_NUMBER_COL_NAME: &str = "_row_number";
 pub const SNAPSHOT_NAME_COL_NAME: &str = "_snapshot_name";
 pub const SEGMENT_NAME_COL_NAME: &str = "_segment_name";
 pub const BLOCK_NAME_COL_NAME: &str = "_block_name";
+pub const FILE_NAME_COL_NAME: &str = "_file_name";
 
 // stream column id.
 pub const ORIGIN_BLOCK_ROW_NUM_COLUMN_ID: u32 = u32::MAX - 10;
@@ -77,7 +87,7 @@ pub fn all_stream_columns() -> HashSet<String> {
 
 #[inline]
 pub fn is_internal_column_id(column_id: ColumnId) -> bool {
-    column_id >= SNAPSHOT_NAME_COLUMN_ID
+    column_id >= FILE_NAME_COLUMN_ID
 }
 
 #[inline]
@@ -160,6 +170,12 @@ pub enum TableDataType {
         fields_type: Vec<TableDataType>,
     },
     Variant,
+    /// `TIMESTAMP(<precision>)` with the precision 0, 3 or 9, the default precision 6 is
+    /// `Timestamp`. The values are microseconds in memory, but rounded down to the precision
+    /// and stored in its time unit.
+    TimestampWithPrecision(u8),
+    /// Geometry in WKB, or EWKB if the geometry has a SRID.
+    Geometry,
 }
 
 impl DataSchema {
@@ -1128,7 +1144,9 @@ impl From<&TableDataType> for DataType {
             TableDataType::String => DataType::String,
             TableDataType::Number(ty) => DataType::Number(*ty),
             TableDataType::Decimal(ty) => DataType::Decimal(*ty),
-            TableDataType::Timestamp => DataType::Timestamp,
+            TableDataType::Timestamp | TableDataType::TimestampWithPrecision(_) => {
+                DataType::Timestamp
+            }
             TableDataType::Date => DataType::Date,
             TableDataType::Nullable(ty) => DataType::Nullable(Box::new((&**ty).into())),
             TableDataType::Array(ty) => DataType::Array(Box::new((&**ty).into())),
@@ -1138,6 +1156,7 @@ impl From<&TableDataType> for DataType {
                 DataType::Tuple(fields_type.iter().map(Into::into).collect())
             }
             TableDataType::Variant => DataType::Variant,
+            TableDataType::Geometry => DataType::Geometry,
         }
     }
 }
@@ -1169,6 +1188,24 @@ impl TableDataType {
         }
     }
 
+    /// The type of `TIMESTAMP(<precision>)`.
+    pub fn timestamp_with_precision(precision: u8) -> Self {
+        if precision == PRECISION_MICRO {
+            TableDataType::Timestamp
+        } else {
+            TableDataType::TimestampWithPrecision(precision)
+        }
+    }
+
+    /// Returns the precision of the timestamp type, or None if it's not a timestamp.
+    pub fn timestamp_precision(&self) -> Option<u8> {
+        match self {
+            TableDataType::Timestamp => Some(PRECISION_MICRO),
+            TableDataType::TimestampWithPrecision(precision) => Some(*precision),
+            _ => None,
+        }
+    }
+
     pub fn remove_recursive_nullable(&self) -> Self {
         match self {
             TableDataType::Nullable(ty) => ty.as_ref().remove_recursive_nullable(),
@@ -1239,6 +1276,8 @@ impl TableDataType {
                 NumberDataType::Int64 => "BIGINT".to_string(),
                 NumberDataType::Float32 => "FLOAT".to_string(),
                 NumberDataType::Float64 => "DOUBLE".to_string(),
+                NumberDataType::UInt128 => "INT128 UNSIGNED".to_string(),
+                NumberDataType::Int128 => "INT128".to_string(),
             },
             TableDataType::String => "VARCHAR".to_string(),
             TableDataType::Nullable(inner_ty) => format!("{} NULL", inner_ty.sql_name()),
@@ -1368,7 +1407,7 @@ impl From<&ArrowField> for DataField {
 // ArrowType can't map to DataType, we don't know the nullable flag
 impl From<&ArrowField> for TableDataType {
     fn from(f: &ArrowField) -> Self {
-        let ty = with_number_type!(|TYPE| match f.data_type() {
+        let ty = with_number_type_without_128!(|TYPE| match f.data_type() {
             ArrowDataType::TYPE => TableDataType::Number(NumberDataType::TYPE),
 
             ArrowDataType::Decimal(precision, scale) =>
@@ -1396,7 +1435,13 @@ impl From<&ArrowField> for TableDataType {
             | ArrowDataType::Utf8
             | ArrowDataType::LargeUtf8 => TableDataType::String,
 
-            ArrowDataType::Timestamp(_, _) => TableDataType::Timestamp,
+            ArrowDataType::Timestamp(unit, _) =>
+                TableDataType::timestamp_with_precision(match unit {
+                    TimeUnit::Second => PRECISION_SEC,
+                    TimeUnit::Millisecond => PRECISION_MILLI,
+                    TimeUnit::Microsecond => PRECISION_MICRO,
+                    TimeUnit::Nanosecond => PRECISION_NANO,
+                }),
             ArrowDataType::Date32 | ArrowDataType::Date64 => TableDataType::Date,
             ArrowDataType::Map(f, _) => {
                 let inner_ty = f.as_ref().into();
@@ -1415,6 +1460,9 @@ impl From<&ArrowField> for TableDataType {
                 ARROW_EXT_TYPE_EMPTY_ARRAY => TableDataType::EmptyArray,
                 ARROW_EXT_TYPE_EMPTY_MAP => TableDataType::EmptyMap,
                 ARROW_EXT_TYPE_BITMAP => TableDataType::Bitmap,
+                ARROW_EXT_TYPE_GEOMETRY => TableDataType::Geometry,
+                ARROW_EXT_TYPE_UINT128 => TableDataType::Number(NumberDataType::UInt128),
+                ARROW_EXT_TYPE_INT128 => TableDataType::Number(NumberDataType::Int128),
                 _ => {
                     let a =
                         ArrowField::new(custom_name, data_type.as_ref().to_owned(), f.is_nullable);
@@ -1478,6 +1526,27 @@ fn set_nullable(ty: &ArrowDataType) -> ArrowDataType {
     }
 }
 
+impl From<&NumberDataType> for ArrowDataType {
+    fn from(ty: &NumberDataType) -> Self {
+        match ty {
+            NumberDataType::UInt128 => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_UINT128.to_string(),
+                Box::new(ArrowDataType::Decimal(38, 0)),
+                None,
+            ),
+            NumberDataType::Int128 => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_INT128.to_string(),
+                Box::new(ArrowDataType::Decimal(38, 0)),
+                None,
+            ),
+            _ => with_number_type_without_128!(|TYPE| match ty {
+                NumberDataType::TYPE => ArrowDataType::TYPE,
+                _ => unreachable!(),
+            }),
+        }
+    }
+}
+
 impl From<&DataType> for ArrowDataType {
     fn from(ty: &DataType) -> Self {
         match ty {
@@ -1494,9 +1563,7 @@ impl From<&DataType> for ArrowDataType {
             ),
             DataType::Boolean => ArrowDataType::Boolean,
             DataType::String => ArrowDataType::LargeBinary,
-            DataType::Number(ty) => with_number_type!(|TYPE| match ty {
-                NumberDataType::TYPE => ArrowDataType::TYPE,
-            }),
+            DataType::Number(ty) => ty.into(),
             DataType::Decimal(DecimalDataType::Decimal128(s)) => {
                 ArrowDataType::Decimal(s.precision.into(), s.scale.into())
             }
@@ -1505,6 +1572,7 @@ impl From<&DataType> for ArrowDataType {
             }
             DataType::Timestamp => ArrowDataType::Timestamp(TimeUnit::Microsecond, None),
             DataType::Date => ArrowDataType::Date32,
+            DataType::Interval => ArrowDataType::Interval(IntervalUnit::MonthDayNano),
             DataType::Nullable(ty) => ty.as_ref().into(),
             DataType::Array(ty) => {
                 let arrow_ty = ty.as_ref().into();
@@ -1552,6 +1620,11 @@ impl From<&DataType> for ArrowDataType {
                 Box::new(ArrowDataType::LargeBinary),
                 None,
             ),
+            DataType::Geometry => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_GEOMETRY.to_string(),
+                Box::new(ArrowDataType::LargeBinary),
+                None,
+            ),
             _ => unreachable!(),
         }
     }
@@ -1573,9 +1646,7 @@ impl From<&TableDataType> for ArrowDataType {
             ),
             TableDataType::Boolean => ArrowDataType::Boolean,
             TableDataType::String => ArrowDataType::LargeBinary,
-            TableDataType::Number(ty) => with_number_type!(|TYPE| match ty {
-                NumberDataType::TYPE => ArrowDataType::TYPE,
-            }),
+            TableDataType::Number(ty) => ty.into(),
             TableDataType::Decimal(DecimalDataType::Decimal128(size)) => {
                 ArrowDataType::Decimal(size.precision as usize, size.scale as usize)
             }
@@ -1583,6 +1654,15 @@ impl From<&TableDataType> for ArrowDataType {
                 ArrowDataType::Decimal256(size.precision as usize, size.scale as usize)
             }
             TableDataType::Timestamp => ArrowDataType::Timestamp(TimeUnit::Microsecond, None),
+            TableDataType::TimestampWithPrecision(precision) => {
+                let unit = match *precision {
+                    PRECISION_SEC => TimeUnit::Second,
+                    PRECISION_MILLI => TimeUnit::Millisecond,
+                    PRECISION_NANO => TimeUnit::Nanosecond,
+                    _ => TimeUnit::Microsecond,
+                };
+                ArrowDataType::Timestamp(unit, None)
+            }
             TableDataType::Date => ArrowDataType::Date32,
             TableDataType::Nullable(ty) => ty.as_ref().into(),
             TableDataType::Array(ty) => {
@@ -1635,6 +1715,11 @@ impl From<&TableDataType> for ArrowDataType {
                 Box::new(ArrowDataType::LargeBinary),
                 None,
             ),
+            TableDataType::Geometry => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_GEOMETRY.to_string(),
+                Box::new(ArrowDataType::LargeBinary),
+                None,
+            ),
         }
     }
 }
@@ -1668,6 +1753,7 @@ pub fn infer_schema_type(data_type: &DataType) -> Result<TableDataType> {
         }
         DataType::Bitmap => Ok(TableDataType::Bitmap),
         DataType::Variant => Ok(TableDataType::Variant),
+        DataType::Geometry => Ok(TableDataType::Geometry),
         DataType::Tuple(fields) => {
             let fields_type = fields
                 .iter()
@@ -1683,7 +1769,7 @@ pub fn infer_schema_type(data_type: &DataType) -> Result<TableDataType> {
                 fields_type,
             })
         }
-        DataType::Generic(_) => Err(ErrorCode::SemanticError(format!(
+        DataType::Interval | DataType::Generic(_) => Err(ErrorCode::SemanticError(format!(
             "Cannot create table with type: {}",
             data_type
         ))),
@@ -714,6 +714,8 @@ pub const ALL_SIMPLE_CAST_FUNCTIONS: &[&str] = &[
     "to_int64",
     "to_float32",
     "to_float64",
+    "to_uint128",
+    "to_int128",
     "to_timestamp",
     "to_date",
     "to_variant",
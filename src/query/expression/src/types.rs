@@ -21,6 +21,8 @@ pub mod decimal;
 pub mod empty_array;
 pub mod empty_map;
 pub mod generic;
+pub mod geometry;
+pub mod interval;
 pub mod map;
 pub mod null;
 pub mod nullable;
@@ -48,6 +50,8 @@ pub use self::decimal::DecimalSize;
 pub use self::empty_array::EmptyArrayType;
 pub use self::empty_map::EmptyMapType;
 pub use self::generic::GenericType;
+pub use self::geometry::GeometryType;
+pub use self::interval::IntervalType;
 pub use self::map::MapType;
 pub use self::null::NullType;
 pub use self::nullable::NullableType;
@@ -75,12 +79,14 @@ pub enum DataType {
     Decimal(DecimalDataType),
     Timestamp,
     Date,
+    Interval,
     Nullable(Box<DataType>),
     Array(Box<DataType>),
     Map(Box<DataType>),
     Bitmap,
     Tuple(Vec<DataType>),
     Variant,
+    Geometry,
 
     // Used internally for generic types
     Generic(usize),
@@ -190,7 +196,7 @@ impl DataType {
     #[inline]
     pub fn is_string_column(&self) -> bool {
         match self {
-            DataType::String | DataType::Bitmap | DataType::Variant => true,
+            DataType::String | DataType::Bitmap | DataType::Variant | DataType::Geometry => true,
             DataType::Nullable(ty) => ty.is_string_column(),
             _ => false,
         }
@@ -243,6 +249,8 @@ impl DataType {
                 NumberDataType::Int64 => "BIGINT".to_string(),
                 NumberDataType::Float32 => "FLOAT".to_string(),
                 NumberDataType::Float64 => "DOUBLE".to_string(),
+                NumberDataType::UInt128 => "INT128 UNSIGNED".to_string(),
+                NumberDataType::Int128 => "INT128".to_string(),
             },
             DataType::String => "VARCHAR".to_string(),
             DataType::Nullable(inner_ty) => format!("{} NULL", inner_ty.sql_name()),
@@ -419,13 +419,25 @@ impl ArrayColumnBuilder<AnyType> {
             let pop_count = self.offsets[self.offsets.len() - 1] as usize
                 - self.offsets[self.offsets.len() - 2] as usize;
             self.offsets.pop();
+            // The values are popped from the last one, push them back in their original order.
+            let values = (0..pop_count)
+                .map(|_| self.builder.pop().unwrap())
+                .collect::<Vec<_>>();
             let mut builder = ColumnBuilder::with_capacity(&self.builder.data_type(), pop_count);
-            for _ in 0..pop_count {
-                builder.push(self.builder.pop().unwrap().as_ref());
+            for value in values.iter().rev() {
+                builder.push(value.as_ref());
             }
             Some(builder.build())
         } else {
             None
         }
     }
+
+    /// Shortens the builder to its first `len` rows, does nothing if it has less rows.
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len() {
+            self.builder.truncate(self.offsets[len] as usize);
+            self.offsets.truncate(len + 1);
+        }
+    }
 }
@@ -34,6 +34,7 @@ use crate::types::DataType;
 use crate::types::GenericMap;
 use crate::types::ValueType;
 use crate::utils::arrow::buffer_into_mut;
+use crate::utils::column_arena::arena_vec;
 use crate::Column;
 use crate::ColumnBuilder;
 use crate::Domain;
@@ -953,7 +954,7 @@ impl DecimalColumnBuilder {
     pub fn with_capacity(ty: &DecimalDataType, capacity: usize) -> Self {
         crate::with_decimal_type!(|DECIMAL_TYPE| match ty {
             DecimalDataType::DECIMAL_TYPE(size) =>
-                DecimalColumnBuilder::DECIMAL_TYPE(Vec::with_capacity(capacity), *size),
+                DecimalColumnBuilder::DECIMAL_TYPE(arena_vec(capacity), *size),
         })
     }
 
@@ -1021,6 +1022,12 @@ impl DecimalColumnBuilder {
             }
         })
     }
+
+    pub fn truncate(&mut self, len: usize) {
+        crate::with_decimal_type!(|DECIMAL_TYPE| match self {
+            DecimalColumnBuilder::DECIMAL_TYPE(builder, _) => builder.truncate(len),
+        })
+    }
 }
 
 impl PartialOrd for DecimalScalar {
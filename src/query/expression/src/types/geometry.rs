@@ -0,0 +1,398 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Write;
+use std::ops::Range;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::property::Domain;
+use crate::types::string::StringColumn;
+use crate::types::string::StringColumnBuilder;
+use crate::types::string::StringIterator;
+use crate::types::ArgType;
+use crate::types::DataType;
+use crate::types::GenericMap;
+use crate::types::ValueType;
+use crate::values::Column;
+use crate::values::Scalar;
+use crate::ColumnBuilder;
+use crate::ScalarRef;
+
+/// Geometries are stored as WKB (or EWKB if the geometry has a SRID) in a binary column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeometryType;
+
+impl ValueType for GeometryType {
+    type Scalar = Vec<u8>;
+    type ScalarRef<'a> = &'a [u8];
+    type Column = StringColumn;
+    type Domain = ();
+    type ColumnIterator<'a> = StringIterator<'a>;
+    type ColumnBuilder = StringColumnBuilder;
+
+    #[inline]
+    fn upcast_gat<'short, 'long: 'short>(long: Self::ScalarRef<'long>) -> Self::ScalarRef<'short> {
+        long
+    }
+
+    fn to_owned_scalar(scalar: Self::ScalarRef<'_>) -> Self::Scalar {
+        scalar.to_vec()
+    }
+
+    fn to_scalar_ref(scalar: &Self::Scalar) -> Self::ScalarRef<'_> {
+        scalar
+    }
+
+    fn try_downcast_scalar<'a>(scalar: &'a ScalarRef) -> Option<Self::ScalarRef<'a>> {
+        scalar.as_geometry().cloned()
+    }
+
+    fn try_downcast_column(col: &Column) -> Option<Self::Column> {
+        col.as_geometry().cloned()
+    }
+
+    fn try_downcast_builder(builder: &mut ColumnBuilder) -> Option<&mut Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::Geometry(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_owned_builder(builder: ColumnBuilder) -> Option<Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::Geometry(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn try_upcast_column_builder(builder: Self::ColumnBuilder) -> Option<ColumnBuilder> {
+        Some(ColumnBuilder::Geometry(builder))
+    }
+
+    fn try_downcast_domain(domain: &Domain) -> Option<Self::Domain> {
+        if domain.is_undefined() {
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    fn upcast_scalar(scalar: Self::Scalar) -> Scalar {
+        Scalar::Geometry(scalar)
+    }
+
+    fn upcast_column(col: Self::Column) -> Column {
+        Column::Geometry(col)
+    }
+
+    fn upcast_domain(_domain: Self::Domain) -> Domain {
+        Domain::Undefined
+    }
+
+    fn column_len(col: &Self::Column) -> usize {
+        col.len()
+    }
+
+    fn index_column(col: &Self::Column, index: usize) -> Option<Self::ScalarRef<'_>> {
+        col.index(index)
+    }
+
+    unsafe fn index_column_unchecked(col: &Self::Column, index: usize) -> Self::ScalarRef<'_> {
+        col.index_unchecked(index)
+    }
+
+    fn slice_column(col: &Self::Column, range: Range<usize>) -> Self::Column {
+        col.slice(range)
+    }
+
+    fn iter_column(col: &Self::Column) -> Self::ColumnIterator<'_> {
+        col.iter()
+    }
+
+    fn column_to_builder(col: Self::Column) -> Self::ColumnBuilder {
+        StringColumnBuilder::from_column(col)
+    }
+
+    fn builder_len(builder: &Self::ColumnBuilder) -> usize {
+        builder.len()
+    }
+
+    fn push_item(builder: &mut Self::ColumnBuilder, item: Self::ScalarRef<'_>) {
+        builder.put_slice(item);
+        builder.commit_row();
+    }
+
+    fn push_default(builder: &mut Self::ColumnBuilder) {
+        builder.put_slice(&DEFAULT_GEOMETRY);
+        builder.commit_row();
+    }
+
+    fn append_column(builder: &mut Self::ColumnBuilder, other: &Self::Column) {
+        builder.append_column(other)
+    }
+
+    fn build_column(builder: Self::ColumnBuilder) -> Self::Column {
+        builder.build()
+    }
+
+    fn build_scalar(builder: Self::ColumnBuilder) -> Self::Scalar {
+        builder.build_scalar()
+    }
+
+    fn scalar_memory_size(scalar: &Self::ScalarRef<'_>) -> usize {
+        scalar.len()
+    }
+
+    fn column_memory_size(col: &Self::Column) -> usize {
+        col.data().len() + col.offsets().len() * 8
+    }
+}
+
+impl ArgType for GeometryType {
+    fn data_type() -> DataType {
+        DataType::Geometry
+    }
+
+    fn full_domain() -> Self::Domain {}
+
+    fn create_builder(capacity: usize, _: &GenericMap) -> Self::ColumnBuilder {
+        StringColumnBuilder::with_capacity(capacity, 0)
+    }
+}
+
+/// `GEOMETRYCOLLECTION EMPTY` in little endian WKB, the default value of a geometry.
+pub const DEFAULT_GEOMETRY: [u8; 9] = [1, 7, 0, 0, 0, 0, 0, 0, 0];
+
+const EWKB_Z_FLAG: u32 = 0x8000_0000;
+const EWKB_M_FLAG: u32 = 0x4000_0000;
+const EWKB_SRID_FLAG: u32 = 0x2000_0000;
+
+/// Convert a geometry in WKB or EWKB to WKT, with a `SRID=<srid>;` prefix if the geometry has
+/// a SRID.
+pub fn geometry_to_wkt(wkb: &[u8]) -> Result<String> {
+    let mut reader = WkbReader {
+        data: wkb,
+        little_endian: true,
+    };
+    let mut out = String::new();
+    reader.write_geometry(&mut out, true)?;
+    if !reader.data.is_empty() {
+        return Err(ErrorCode::BadBytes(format!(
+            "invalid WKB: {} trailing bytes",
+            reader.data.len()
+        )));
+    }
+    Ok(out)
+}
+
+struct WkbHeader {
+    kind: u32,
+    dims: usize,
+    tag: &'static str,
+}
+
+struct WkbReader<'a> {
+    data: &'a [u8],
+    little_endian: bool,
+}
+
+impl<'a> WkbReader<'a> {
+    fn read<const N: usize>(&mut self) -> Result<[u8; N]> {
+        if self.data.len() < N {
+            return Err(ErrorCode::BadBytes("invalid WKB: unexpected end of data"));
+        }
+        let (bytes, rest) = self.data.split_at(N);
+        self.data = rest;
+        Ok(bytes.try_into().unwrap())
+    }
+
+    fn read_u32(&mut self) -> Result<u32> {
+        let bytes = self.read::<4>()?;
+        Ok(if self.little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    fn read_f64(&mut self) -> Result<f64> {
+        let bytes = self.read::<8>()?;
+        Ok(if self.little_endian {
+            f64::from_le_bytes(bytes)
+        } else {
+            f64::from_be_bytes(bytes)
+        })
+    }
+
+    /// Read the byte order, the geometry type and the optional SRID of a geometry, both the
+    /// ISO (`1001` is `POINT Z`) and the EWKB (high bit flags) dimension encodings are accepted.
+    fn read_header(&mut self) -> Result<(WkbHeader, Option<u32>)> {
+        self.little_endian = match self.read::<1>()?[0] {
+            0 => false,
+            1 => true,
+            b => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "invalid WKB: unknown byte order {b}"
+                )));
+            }
+        };
+        let ty = self.read_u32()?;
+        let srid = if ty & EWKB_SRID_FLAG != 0 {
+            Some(self.read_u32()?)
+        } else {
+            None
+        };
+        let mut has_z = ty & EWKB_Z_FLAG != 0;
+        let mut has_m = ty & EWKB_M_FLAG != 0;
+        let ty = ty & 0x0FFF_FFFF;
+        match ty / 1000 {
+            0 => {}
+            1 => has_z = true,
+            2 => has_m = true,
+            3 => (has_z, has_m) = (true, true),
+            _ => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "invalid WKB: unknown geometry type {ty}"
+                )));
+            }
+        }
+        let kind = ty % 1000;
+        if !(1..=7).contains(&kind) {
+            return Err(ErrorCode::BadBytes(format!(
+                "invalid WKB: unknown geometry type {ty}"
+            )));
+        }
+        let tag = match (has_z, has_m) {
+            (false, false) => "",
+            (true, false) => " Z ",
+            (false, true) => " M ",
+            (true, true) => " ZM ",
+        };
+        let dims = 2 + has_z as usize + has_m as usize;
+        Ok((WkbHeader { kind, dims, tag }, srid))
+    }
+
+    fn write_geometry(&mut self, out: &mut String, top_level: bool) -> Result<()> {
+        let (header, srid) = self.read_header()?;
+        if let (Some(srid), true) = (srid, top_level) {
+            write!(out, "SRID={srid};").unwrap();
+        }
+        out.push_str(match header.kind {
+            1 => "POINT",
+            2 => "LINESTRING",
+            3 => "POLYGON",
+            4 => "MULTIPOINT",
+            5 => "MULTILINESTRING",
+            6 => "MULTIPOLYGON",
+            _ => "GEOMETRYCOLLECTION",
+        });
+        out.push_str(header.tag);
+        self.write_body(out, &header)
+    }
+
+    fn write_body(&mut self, out: &mut String, header: &WkbHeader) -> Result<()> {
+        match header.kind {
+            1 => {
+                let mut coords = Vec::with_capacity(header.dims);
+                for _ in 0..header.dims {
+                    coords.push(self.read_f64()?);
+                }
+                // An empty point is encoded with NaN coordinates.
+                if coords.iter().all(|c| c.is_nan()) {
+                    return Self::write_empty(out);
+                }
+                out.push('(');
+                Self::write_coords(out, &coords);
+                out.push(')');
+            }
+            2 => self.write_points(out, header)?,
+            3 => self.write_parts(out, |reader, out| reader.write_points(out, header))?,
+            4..=6 => {
+                let kind = header.kind - 3;
+                self.write_parts(out, |reader, out| {
+                    let (part, _) = reader.read_header()?;
+                    if part.kind != kind {
+                        return Err(ErrorCode::BadBytes(format!(
+                            "invalid WKB: unexpected geometry type {} in a multi geometry",
+                            part.kind
+                        )));
+                    }
+                    reader.write_body(out, &part)
+                })?
+            }
+            _ => self.write_parts(out, |reader, out| reader.write_geometry(out, false))?,
+        }
+        Ok(())
+    }
+
+    fn write_points(&mut self, out: &mut String, header: &WkbHeader) -> Result<()> {
+        let num_points = self.read_u32()? as usize;
+        if num_points == 0 {
+            return Self::write_empty(out);
+        }
+        let mut coords = vec![0.0; header.dims];
+        out.push('(');
+        for i in 0..num_points {
+            for coord in coords.iter_mut() {
+                *coord = self.read_f64()?;
+            }
+            if i > 0 {
+                out.push(',');
+            }
+            Self::write_coords(out, &coords);
+        }
+        out.push(')');
+        Ok(())
+    }
+
+    fn write_parts(
+        &mut self,
+        out: &mut String,
+        mut write_part: impl FnMut(&mut Self, &mut String) -> Result<()>,
+    ) -> Result<()> {
+        let num_parts = self.read_u32()?;
+        if num_parts == 0 {
+            return Self::write_empty(out);
+        }
+        out.push('(');
+        for i in 0..num_parts {
+            if i > 0 {
+                out.push(',');
+            }
+            write_part(self, out)?;
+        }
+        out.push(')');
+        Ok(())
+    }
+
+    fn write_empty(out: &mut String) -> Result<()> {
+        // `POINT EMPTY`, but `MULTIPOINT(EMPTY,(1 2))` for the parts of a multi geometry.
+        if out.ends_with(|c: char| c.is_ascii_alphabetic()) {
+            out.push(' ');
+        }
+        out.push_str("EMPTY");
+        Ok(())
+    }
+
+    fn write_coords(out: &mut String, coords: &[f64]) {
+        for (i, coord) in coords.iter().enumerate() {
+            if i > 0 {
+                out.push(' ');
+            }
+            write!(out, "{coord}").unwrap();
+        }
+    }
+}
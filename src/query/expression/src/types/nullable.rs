@@ -26,6 +26,7 @@ use crate::types::DataType;
 use crate::types::GenericMap;
 use crate::types::ValueType;
 use crate::utils::arrow::bitmap_into_mut;
+use crate::utils::arrow::truncate_bitmap;
 use crate::values::Column;
 use crate::values::Scalar;
 use crate::ColumnBuilder;
@@ -287,6 +288,20 @@ impl<T: ValueType> NullableColumn<T> {
     }
 }
 
+impl<T: ArgType> NullableColumn<T> {
+    /// Creates a column of `len` NULLs, the inner column is filled with default values.
+    pub fn new_null(len: usize, generics: &GenericMap) -> Self {
+        let mut builder = T::create_builder(len, generics);
+        for _ in 0..len {
+            T::push_default(&mut builder);
+        }
+        NullableColumn {
+            column: T::build_column(builder),
+            validity: Bitmap::new_zeroed(len),
+        }
+    }
+}
+
 impl NullableColumn<AnyType> {
     pub fn try_downcast<T: ValueType>(&self) -> Option<NullableColumn<T>> {
         Some(NullableColumn {
@@ -400,6 +415,12 @@ impl NullableColumnBuilder<AnyType> {
             Some(None)
         }
     }
+
+    /// Shortens the builder to its first `len` rows, does nothing if it has less rows.
+    pub fn truncate(&mut self, len: usize) {
+        self.builder.truncate(len);
+        truncate_bitmap(&mut self.validity, len);
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -33,6 +33,7 @@ use crate::types::DataType;
 use crate::types::GenericMap;
 use crate::types::ValueType;
 use crate::utils::arrow::buffer_into_mut;
+use crate::utils::column_arena::arena_vec;
 use crate::values::Column;
 use crate::values::Scalar;
 use crate::ColumnBuilder;
@@ -80,6 +81,11 @@ pub const ALL_NUMERICS_TYPES: &[NumberDataType] = &[
     NumberDataType::Float64,
 ];
 
+/// The 128-bit integers, kept apart from the lists above which are used to register
+/// the overloads of the narrower number types.
+pub const ALL_WIDE_INTEGER_TYPES: &[NumberDataType] =
+    &[NumberDataType::UInt128, NumberDataType::Int128];
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct NumberType<T: Number>(PhantomData<T>);
 
@@ -93,6 +99,8 @@ pub type UInt32Type = NumberType<u32>;
 pub type UInt64Type = NumberType<u64>;
 pub type Float32Type = NumberType<F32>;
 pub type Float64Type = NumberType<F64>;
+pub type UInt128Type = NumberType<u128>;
+pub type Int128Type = NumberType<i128>;
 
 impl<Num: Number> ValueType for NumberType<Num> {
     type Scalar = Num;
@@ -251,6 +259,8 @@ pub enum NumberDataType {
     Int64,
     Float32,
     Float64,
+    UInt128,
+    Int128,
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, EnumAsInner, Serialize, Deserialize)]
@@ -265,6 +275,8 @@ pub enum NumberScalar {
     Int64(i64),
     Float32(F32),
     Float64(F64),
+    UInt128(u128),
+    Int128(i128),
 }
 
 #[derive(Clone, PartialEq, EnumAsInner)]
@@ -279,6 +291,8 @@ pub enum NumberColumn {
     Int64(Buffer<i64>),
     Float32(Buffer<F32>),
     Float64(Buffer<F64>),
+    UInt128(Buffer<u128>),
+    Int128(Buffer<i128>),
 }
 
 #[derive(Clone, PartialEq, EnumAsInner, Debug)]
@@ -293,6 +307,8 @@ pub enum NumberColumnVec {
     Int64(Vec<Buffer<i64>>),
     Float32(Vec<Buffer<F32>>),
     Float64(Vec<Buffer<F64>>),
+    UInt128(Vec<Buffer<u128>>),
+    Int128(Vec<Buffer<i128>>),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, EnumAsInner)]
@@ -307,6 +323,8 @@ pub enum NumberColumnBuilder {
     Int64(Vec<i64>),
     Float32(Vec<F32>),
     Float64(Vec<F64>),
+    UInt128(Vec<u128>),
+    Int128(Vec<i128>),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, EnumAsInner)]
@@ -321,6 +339,8 @@ pub enum NumberDomain {
     Int64(SimpleDomain<i64>),
     Float32(SimpleDomain<F32>),
     Float64(SimpleDomain<F64>),
+    UInt128(SimpleDomain<u128>),
+    Int128(SimpleDomain<i128>),
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -342,6 +362,8 @@ impl NumberDataType {
             (64, true, false) => NumberDataType::Int64,
             (32, true, true) => NumberDataType::Float32,
             (64, true, true) => NumberDataType::Float64,
+            (128, false, false) => NumberDataType::UInt128,
+            (128, true, false) => NumberDataType::Int128,
             _ => panic!("unsupported numeric type"),
         }
     }
@@ -358,6 +380,8 @@ impl NumberDataType {
             NumberDataType::Int64 => 64,
             NumberDataType::Float32 => 32,
             NumberDataType::Float64 => 64,
+            NumberDataType::UInt128 => 128,
+            NumberDataType::Int128 => 128,
         }
     }
 
@@ -373,6 +397,8 @@ impl NumberDataType {
             NumberDataType::Int64 => true,
             NumberDataType::Float32 => true,
             NumberDataType::Float64 => true,
+            NumberDataType::UInt128 => false,
+            NumberDataType::Int128 => true,
         }
     }
 
@@ -388,6 +414,8 @@ impl NumberDataType {
             NumberDataType::Int64 => false,
             NumberDataType::Float32 => true,
             NumberDataType::Float64 => true,
+            NumberDataType::UInt128 => false,
+            NumberDataType::Int128 => false,
         }
     }
 
@@ -431,6 +459,8 @@ impl NumberDataType {
             NumberDataType::UInt16 => (5, 0),
             NumberDataType::UInt32 => (10, 0),
             NumberDataType::UInt64 => (20, 0),
+            NumberDataType::Int128 => (39, 0),
+            NumberDataType::UInt128 => (39, 0),
             _ => return None,
         };
         Some(DecimalSize { precision, scale })
@@ -442,7 +472,8 @@ const fn next_bit_width(width: u8) -> Option<u8> {
         8 => Some(16),
         16 => Some(32),
         32 => Some(64),
-        64 => None,
+        64 => Some(128),
+        128 => None,
         _ => panic!("invalid bit width"),
     }
 }
@@ -563,7 +594,7 @@ impl NumberColumnBuilder {
 
     pub fn with_capacity(ty: &NumberDataType, capacity: usize) -> Self {
         crate::with_number_type!(|NUM_TYPE| match ty {
-            NumberDataType::NUM_TYPE => NumberColumnBuilder::NUM_TYPE(Vec::with_capacity(capacity)),
+            NumberDataType::NUM_TYPE => NumberColumnBuilder::NUM_TYPE(arena_vec(capacity)),
         })
     }
 
@@ -614,6 +645,12 @@ impl NumberColumnBuilder {
             NumberColumnBuilder::NUM_TYPE(builder) => builder.pop().map(NumberScalar::NUM_TYPE),
         })
     }
+
+    pub fn truncate(&mut self, len: usize) {
+        crate::with_number_type!(|NUM_TYPE| match self {
+            NumberColumnBuilder::NUM_TYPE(builder) => builder.truncate(len),
+        })
+    }
 }
 
 impl<T: Number> SimpleDomain<T> {
@@ -652,6 +689,21 @@ fn type_name_of<T>(_: T) -> &'static str {
 
 #[macro_export]
 macro_rules! with_number_type {
+    ( | $t:tt | $($tail:tt)* ) => {
+        match_template::match_template! {
+            $t = [
+                UInt8, UInt16, UInt32, UInt64, Int8, Int16, Int32, Int64, Float32, Float64, UInt128,
+                Int128
+            ],
+            $($tail)*
+        }
+    }
+}
+
+/// The number types which have a native Arrow type, the 128-bit integers are stored
+/// as Arrow extension types instead.
+#[macro_export]
+macro_rules! with_number_type_without_128 {
     ( | $t:tt | $($tail:tt)* ) => {
         match_template::match_template! {
             $t = [UInt8, UInt16, UInt32, UInt64, Int8, Int16, Int32, Int64, Float32, Float64],
@@ -665,7 +717,7 @@ macro_rules! with_unsigned_integer_mapped_type {
     (| $t:tt | $($tail:tt)*) => {
         match_template::match_template! {
             $t = [
-                UInt8 => u8, UInt16 => u16, UInt32 => u32, UInt64 => u64
+                UInt8 => u8, UInt16 => u16, UInt32 => u32, UInt64 => u64, UInt128 => u128
             ],
             $($tail)*
         }
@@ -677,7 +729,7 @@ macro_rules! with_signed_integer_mapped_type {
     (| $t:tt | $($tail:tt)*) => {
         match_template::match_template! {
             $t = [
-                Int8 => i8, Int16 => i16, Int32 => i32, Int64 => i64,
+                Int8 => i8, Int16 => i16, Int32 => i32, Int64 => i64, Int128 => i128,
             ],
             $($tail)*
         }
@@ -691,6 +743,7 @@ macro_rules! with_integer_mapped_type {
             $t = [
                 UInt8 => u8, UInt16 => u16, UInt32 => u32, UInt64 => u64,
                 Int8 => i8, Int16 => i16, Int32 => i32, Int64 => i64,
+                UInt128 => u128, Int128 => i128,
             ],
             $($tail)*
         }
@@ -716,7 +769,8 @@ macro_rules! with_number_mapped_type {
             $t = [
                 UInt8 => u8, UInt16 => u16, UInt32 => u32, UInt64 => u64,
                 Int8 => i8, Int16 => i16, Int32 => i32, Int64 => i64,
-                Float32 => $crate::types::number::F32, Float64 => $crate::types::number::F64
+                Float32 => $crate::types::number::F32, Float64 => $crate::types::number::F64,
+                UInt128 => u128, Int128 => i128
             ],
             $($tail)*
         }
@@ -1300,3 +1354,105 @@ impl Number for F64 {
         }
     }
 }
+
+impl Number for u128 {
+    type Native = Self;
+
+    const MIN: Self = u128::MIN;
+    const MAX: Self = u128::MAX;
+    const FLOATING: bool = false;
+
+    fn data_type() -> NumberDataType {
+        NumberDataType::UInt128
+    }
+
+    fn try_downcast_scalar(scalar: &NumberScalar) -> Option<Self> {
+        scalar.as_u_int128().cloned()
+    }
+
+    fn try_downcast_column(col: &NumberColumn) -> Option<Buffer<Self>> {
+        col.as_u_int128().cloned()
+    }
+
+    fn try_downcast_builder(builder: &mut NumberColumnBuilder) -> Option<&mut Vec<Self>> {
+        builder.as_u_int128_mut()
+    }
+
+    fn try_downcast_owned_builder(builder: NumberColumnBuilder) -> Option<Vec<Self>> {
+        match builder {
+            NumberColumnBuilder::UInt128(b) => Some(b),
+            _ => None,
+        }
+    }
+
+    fn try_upcast_column_builder(v: Vec<Self>) -> Option<ColumnBuilder> {
+        Some(ColumnBuilder::Number(NumberColumnBuilder::UInt128(v)))
+    }
+
+    fn try_downcast_domain(domain: &NumberDomain) -> Option<SimpleDomain<Self>> {
+        domain.as_u_int128().cloned()
+    }
+
+    fn upcast_scalar(scalar: Self) -> NumberScalar {
+        NumberScalar::UInt128(scalar)
+    }
+
+    fn upcast_column(col: Buffer<Self>) -> NumberColumn {
+        NumberColumn::UInt128(col)
+    }
+
+    fn upcast_domain(domain: SimpleDomain<Self>) -> NumberDomain {
+        NumberDomain::UInt128(domain)
+    }
+}
+
+impl Number for i128 {
+    type Native = Self;
+
+    const MIN: Self = i128::MIN;
+    const MAX: Self = i128::MAX;
+    const FLOATING: bool = false;
+
+    fn data_type() -> NumberDataType {
+        NumberDataType::Int128
+    }
+
+    fn try_downcast_scalar(scalar: &NumberScalar) -> Option<Self> {
+        scalar.as_int128().cloned()
+    }
+
+    fn try_downcast_column(col: &NumberColumn) -> Option<Buffer<Self>> {
+        col.as_int128().cloned()
+    }
+
+    fn try_downcast_builder(builder: &mut NumberColumnBuilder) -> Option<&mut Vec<Self>> {
+        builder.as_int128_mut()
+    }
+
+    fn try_downcast_owned_builder(builder: NumberColumnBuilder) -> Option<Vec<Self>> {
+        match builder {
+            NumberColumnBuilder::Int128(b) => Some(b),
+            _ => None,
+        }
+    }
+
+    fn try_upcast_column_builder(v: Vec<Self>) -> Option<ColumnBuilder> {
+        Some(ColumnBuilder::Number(NumberColumnBuilder::Int128(v)))
+    }
+
+    fn try_downcast_domain(domain: &NumberDomain) -> Option<SimpleDomain<Self>> {
+        domain.as_int128().cloned()
+    }
+
+    fn upcast_scalar(scalar: Self) -> NumberScalar {
+        NumberScalar::Int128(scalar)
+    }
+
+    fn upcast_column(col: Buffer<Self>) -> NumberColumn {
+        NumberColumn::Int128(col)
+    }
+
+    fn upcast_domain(domain: SimpleDomain<Self>) -> NumberDomain {
+        NumberDomain::Int128(domain)
+    }
+}
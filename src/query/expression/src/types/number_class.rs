@@ -30,6 +30,8 @@ pub enum NumberClass {
     Decimal256,
     Float32,
     Float64,
+    UInt128,
+    Int128,
 }
 
 /// used for function register
@@ -47,6 +49,8 @@ pub const ALL_NUMBER_CLASSES: &[NumberClass] = &[
     NumberClass::Decimal256,
     NumberClass::Float32,
     NumberClass::Float64,
+    NumberClass::UInt128,
+    NumberClass::Int128,
 ];
 
 impl NumberClass {
@@ -74,6 +78,8 @@ impl NumberClass {
             }
             NumberClass::Float32 => DataType::Number(NumberDataType::Float32),
             NumberClass::Float64 => DataType::Number(NumberDataType::Float64),
+            NumberClass::UInt128 => DataType::Number(NumberDataType::UInt128),
+            NumberClass::Int128 => DataType::Number(NumberDataType::Int128),
         }
     }
 
@@ -29,6 +29,7 @@ use crate::types::DataType;
 use crate::types::GenericMap;
 use crate::types::ValueType;
 use crate::utils::arrow::buffer_into_mut;
+use crate::utils::column_arena::arena_vec;
 use crate::values::Column;
 use crate::values::Scalar;
 use crate::ColumnBuilder;
@@ -298,11 +299,11 @@ pub struct StringColumnBuilder {
 
 impl StringColumnBuilder {
     pub fn with_capacity(len: usize, data_capacity: usize) -> Self {
-        let mut offsets = Vec::with_capacity(len + 1);
+        let mut offsets = arena_vec(len + 1);
         offsets.push(0);
         StringColumnBuilder {
             need_estimated: data_capacity == 0 && len > 0,
-            data: Vec::with_capacity(data_capacity),
+            data: arena_vec(data_capacity),
             offsets,
         }
     }
@@ -450,6 +451,14 @@ impl StringColumnBuilder {
             None
         }
     }
+
+    /// Shortens the builder to its first `len` rows, does nothing if it has less rows.
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len() {
+            self.data.truncate(self.offsets[len] as usize);
+            self.offsets.truncate(len + 1);
+        }
+    }
 }
 
 impl<'a> FromIterator<&'a [u8]> for StringColumnBuilder {
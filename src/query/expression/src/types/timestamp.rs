@@ -44,11 +44,68 @@ pub const TIMESTAMP_MAX: i64 = 253402300799999999;
 
 pub const MICROS_IN_A_SEC: i64 = 1_000_000;
 pub const MICROS_IN_A_MILLI: i64 = 1_000;
+pub const MICROS_IN_A_DAY: i64 = 86_400_000_000;
 
+pub const PRECISION_NANO: u8 = 9;
 pub const PRECISION_MICRO: u8 = 6;
 pub const PRECISION_MILLI: u8 = 3;
 pub const PRECISION_SEC: u8 = 0;
 
+/// Check if the precision can be declared by `TIMESTAMP(<precision>)`.
+#[inline]
+pub fn check_timestamp_precision(precision: u8) -> Result<u8, String> {
+    match precision {
+        PRECISION_SEC | PRECISION_MILLI | PRECISION_MICRO | PRECISION_NANO => Ok(precision),
+        _ => Err(format!(
+            "invalid timestamp precision {precision}, it must be one of 0, 3, 6, 9"
+        )),
+    }
+}
+
+/// Convert the microseconds to the unit of the precision, rounding down to the precision.
+///
+/// The nanoseconds are saturated at the bounds of i64, i.e. `1677-09-21` and `2262-04-11`,
+/// see `check_timestamp_in_precision`.
+#[inline]
+pub fn micros_to_precision(micros: i64, precision: u8) -> i64 {
+    match precision {
+        PRECISION_SEC => micros.div_euclid(MICROS_IN_A_SEC),
+        PRECISION_MILLI => micros.div_euclid(MICROS_IN_A_MILLI),
+        PRECISION_NANO => micros.saturating_mul(1_000),
+        _ => micros,
+    }
+}
+
+/// Convert the value in the unit of the precision to microseconds.
+#[inline]
+pub fn precision_to_micros(value: i64, precision: u8) -> i64 {
+    match precision {
+        PRECISION_SEC => value.saturating_mul(MICROS_IN_A_SEC),
+        PRECISION_MILLI => value.saturating_mul(MICROS_IN_A_MILLI),
+        PRECISION_NANO => value.div_euclid(1_000),
+        _ => value,
+    }
+}
+
+/// Round the microseconds down to the precision.
+#[inline]
+pub fn truncate_timestamp(micros: i64, precision: u8) -> i64 {
+    precision_to_micros(micros_to_precision(micros, precision), precision)
+}
+
+/// Check if the timestamp can be stored in the unit of the precision, only the nanoseconds
+/// have a narrower range than [`TIMESTAMP_MIN`] and [`TIMESTAMP_MAX`].
+#[inline]
+pub fn check_timestamp_in_precision(micros: i64, precision: u8) -> Result<i64, String> {
+    if precision == PRECISION_NANO && micros.checked_mul(1_000).is_none() {
+        Err(format!(
+            "timestamp is out of range for the precision {precision}"
+        ))
+    } else {
+        Ok(micros)
+    }
+}
+
 /// Check if the timestamp value is valid.
 #[inline]
 pub fn check_timestamp(micros: i64) -> Result<i64, String> {
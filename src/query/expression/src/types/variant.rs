@@ -17,6 +17,7 @@ use std::ops::Range;
 use roaring::RoaringTreemap;
 
 use super::date::date_to_string;
+use super::geometry::geometry_to_wkt;
 use super::number::NumberScalar;
 use super::timestamp::timestamp_to_string;
 use crate::date_helper::TzLUT;
@@ -196,12 +197,21 @@ pub fn cast_scalar_to_variant(scalar: ScalarRef, tz: TzLUT, buf: &mut Vec<u8>) {
             NumberScalar::Int64(n) => n.into(),
             NumberScalar::Float32(n) => n.0.into(),
             NumberScalar::Float64(n) => n.0.into(),
+            NumberScalar::UInt128(n) => match u64::try_from(n) {
+                Ok(n) => n.into(),
+                Err(_) => (n as f64).into(),
+            },
+            NumberScalar::Int128(n) => match i64::try_from(n) {
+                Ok(n) => n.into(),
+                Err(_) => (n as f64).into(),
+            },
         },
         ScalarRef::Decimal(x) => x.to_float64().into(),
         ScalarRef::Boolean(b) => jsonb::Value::Bool(b),
         ScalarRef::String(s) => jsonb::Value::String(String::from_utf8_lossy(s)),
         ScalarRef::Timestamp(ts) => timestamp_to_string(ts, inner_tz).to_string().into(),
         ScalarRef::Date(d) => date_to_string(d, inner_tz).to_string().into(),
+        ScalarRef::Interval(i) => i.to_string().into(),
         ScalarRef::Array(col) => {
             let items = cast_scalars_to_variants(col.iter(), tz);
             jsonb::build_array(items.iter(), buf).expect("failed to build jsonb array");
@@ -219,6 +229,7 @@ pub fn cast_scalar_to_variant(scalar: ScalarRef, tz: TzLUT, buf: &mut Vec<u8>) {
                         ScalarRef::Boolean(v) => v.to_string(),
                         ScalarRef::Timestamp(v) => timestamp_to_string(v, inner_tz).to_string(),
                         ScalarRef::Date(v) => date_to_string(v, inner_tz).to_string(),
+                        ScalarRef::Interval(v) => v.to_string(),
                         _ => unreachable!(),
                     };
                     let mut val = vec![];
@@ -230,6 +241,10 @@ pub fn cast_scalar_to_variant(scalar: ScalarRef, tz: TzLUT, buf: &mut Vec<u8>) {
                 .expect("failed to build jsonb object from map");
             return;
         }
+        ScalarRef::Geometry(g) => match geometry_to_wkt(g) {
+            Ok(wkt) => wkt.into(),
+            Err(_) => jsonb::Value::Null,
+        },
         ScalarRef::Bitmap(b) => {
             jsonb::Value::Array(
                 RoaringTreemap::deserialize_from(b)
@@ -841,6 +841,294 @@ impl ResultTypeOfBinary for (F64, F64) {
     type LeastSuper = F64;
 }
 
+impl ResultTypeOfBinary for (u8, u128) {
+    type AddMul = u128;
+    type Minus = i128;
+    type IntDiv = u128;
+    type Modulo = u128;
+    type LeastSuper = u128;
+}
+
+impl ResultTypeOfBinary for (u8, i128) {
+    type AddMul = i128;
+    type Minus = i128;
+    type IntDiv = i128;
+    type Modulo = u128;
+    type LeastSuper = i128;
+}
+
+impl ResultTypeOfBinary for (u16, u128) {
+    type AddMul = u128;
+    type Minus = i128;
+    type IntDiv = u128;
+    type Modulo = u128;
+    type LeastSuper = u128;
+}
+
+impl ResultTypeOfBinary for (u16, i128) {
+    type AddMul = i128;
+    type Minus = i128;
+    type IntDiv = i128;
+    type Modulo = u128;
+    type LeastSuper = i128;
+}
+
+impl ResultTypeOfBinary for (u32, u128) {
+    type AddMul = u128;
+    type Minus = i128;
+    type IntDiv = u128;
+    type Modulo = u128;
+    type LeastSuper = u128;
+}
+
+impl ResultTypeOfBinary for (u32, i128) {
+    type AddMul = i128;
+    type Minus = i128;
+    type IntDiv = i128;
+    type Modulo = u128;
+    type LeastSuper = i128;
+}
+
+impl ResultTypeOfBinary for (u64, u128) {
+    type AddMul = u128;
+    type Minus = i128;
+    type IntDiv = u128;
+    type Modulo = u128;
+    type LeastSuper = u128;
+}
+
+impl ResultTypeOfBinary for (u64, i128) {
+    type AddMul = i128;
+    type Minus = i128;
+    type IntDiv = i128;
+    type Modulo = u128;
+    type LeastSuper = i128;
+}
+
+impl ResultTypeOfBinary for (i8, u128) {
+    type AddMul = i128;
+    type Minus = i128;
+    type IntDiv = i128;
+    type Modulo = i128;
+    type LeastSuper = i128;
+}
+
+impl ResultTypeOfBinary for (i8, i128) {
+    type AddMul = i128;
+    type Minus = i128;
+    type IntDiv = i128;
+    type Modulo = i128;
+    type LeastSuper = i128;
+}
+
+impl ResultTypeOfBinary for (i16, u128) {
+    type AddMul = i128;
+    type Minus = i128;
+    type IntDiv = i128;
+    type Modulo = i128;
+    type LeastSuper = i128;
+}
+
+impl ResultTypeOfBinary for (i16, i128) {
+    type AddMul = i128;
+    type Minus = i128;
+    type IntDiv = i128;
+    type Modulo = i128;
+    type LeastSuper = i128;
+}
+
+impl ResultTypeOfBinary for (i32, u128) {
+    type AddMul = i128;
+    type Minus = i128;
+    type IntDiv = i128;
+    type Modulo = i128;
+    type LeastSuper = i128;
+}
+
+impl ResultTypeOfBinary for (i32, i128) {
+    type AddMul = i128;
+    type Minus = i128;
+    type IntDiv = i128;
+    type Modulo = i128;
+    type LeastSuper = i128;
+}
+
+impl ResultTypeOfBinary for (i64, u128) {
+    type AddMul = i128;
+    type Minus = i128;
+    type IntDiv = i128;
+    type Modulo = i128;
+    type LeastSuper = i128;
+}
+
+impl ResultTypeOfBinary for (i64, i128) {
+    type AddMul = i128;
+    type Minus = i128;
+    type IntDiv = i128;
+    type Modulo = i128;
+    type LeastSuper = i128;
+}
+
+impl ResultTypeOfBinary for (u128, u8) {
+    type AddMul = u128;
+    type Minus = i128;
+    type IntDiv = u128;
+    type Modulo = u8;
+    type LeastSuper = u128;
+}
+
+impl ResultTypeOfBinary for (u128, u16) {
+    type AddMul = u128;
+    type Minus = i128;
+    type IntDiv = u128;
+    type Modulo = u16;
+    type LeastSuper = u128;
+}
+
+impl ResultTypeOfBinary for (u128, u32) {
+    type AddMul = u128;
+    type Minus = i128;
+    type IntDiv = u128;
+    type Modulo = u32;
+    type LeastSuper = u128;
+}
+
+impl ResultTypeOfBinary for (u128, u64) {
+    type AddMul = u128;
+    type Minus = i128;
+    type IntDiv = u128;
+    type Modulo = u64;
+    type LeastSuper = u128;
+}
+
+impl ResultTypeOfBinary for (u128, i8) {
+    type AddMul = i128;
+    type Minus = i128;
+    type IntDiv = i128;
+    type Modulo = u8;
+    type LeastSuper = i128;
+}
+
+impl ResultTypeOfBinary for (u128, i16) {
+    type AddMul = i128;
+    type Minus = i128;
+    type IntDiv = i128;
+    type Modulo = u16;
+    type LeastSuper = i128;
+}
+
+impl ResultTypeOfBinary for (u128, i32) {
+    type AddMul = i128;
+    type Minus = i128;
+    type IntDiv = i128;
+    type Modulo = u32;
+    type LeastSuper = i128;
+}
+
+impl ResultTypeOfBinary for (u128, i64) {
+    type AddMul = i128;
+    type Minus = i128;
+    type IntDiv = i128;
+    type Modulo = u64;
+    type LeastSuper = i128;
+}
+
+impl ResultTypeOfBinary for (u128, u128) {
+    type AddMul = u128;
+    type Minus = i128;
+    type IntDiv = u128;
+    type Modulo = u128;
+    type LeastSuper = u128;
+}
+
+impl ResultTypeOfBinary for (u128, i128) {
+    type AddMul = i128;
+    type Minus = i128;
+    type IntDiv = i128;
+    type Modulo = u128;
+    type LeastSuper = i128;
+}
+
+impl ResultTypeOfBinary for (i128, u8) {
+    type AddMul = i128;
+    type Minus = i128;
+    type IntDiv = i128;
+    type Modulo = i16;
+    type LeastSuper = i128;
+}
+
+impl ResultTypeOfBinary for (i128, u16) {
+    type AddMul = i128;
+    type Minus = i128;
+    type IntDiv = i128;
+    type Modulo = i32;
+    type LeastSuper = i128;
+}
+
+impl ResultTypeOfBinary for (i128, u32) {
+    type AddMul = i128;
+    type Minus = i128;
+    type IntDiv = i128;
+    type Modulo = i64;
+    type LeastSuper = i128;
+}
+
+impl ResultTypeOfBinary for (i128, u64) {
+    type AddMul = i128;
+    type Minus = i128;
+    type IntDiv = i128;
+    type Modulo = i64;
+    type LeastSuper = i128;
+}
+
+impl ResultTypeOfBinary for (i128, i8) {
+    type AddMul = i128;
+    type Minus = i128;
+    type IntDiv = i128;
+    type Modulo = i16;
+    type LeastSuper = i128;
+}
+
+impl ResultTypeOfBinary for (i128, i16) {
+    type AddMul = i128;
+    type Minus = i128;
+    type IntDiv = i128;
+    type Modulo = i32;
+    type LeastSuper = i128;
+}
+
+impl ResultTypeOfBinary for (i128, i32) {
+    type AddMul = i128;
+    type Minus = i128;
+    type IntDiv = i128;
+    type Modulo = i64;
+    type LeastSuper = i128;
+}
+
+impl ResultTypeOfBinary for (i128, i64) {
+    type AddMul = i128;
+    type Minus = i128;
+    type IntDiv = i128;
+    type Modulo = i64;
+    type LeastSuper = i128;
+}
+
+impl ResultTypeOfBinary for (i128, u128) {
+    type AddMul = i128;
+    type Minus = i128;
+    type IntDiv = i128;
+    type Modulo = i128;
+    type LeastSuper = i128;
+}
+
+impl ResultTypeOfBinary for (i128, i128) {
+    type AddMul = i128;
+    type Minus = i128;
+    type IntDiv = i128;
+    type Modulo = i128;
+    type LeastSuper = i128;
+}
+
 impl ResultTypeOfUnary for u8 {
     type Negate = i16;
     type Sum = u64;
@@ -1090,3 +1378,53 @@ impl ResultTypeOfUnary for F64 {
         Some(self % rhs)
     }
 }
+
+impl ResultTypeOfUnary for u128 {
+    type Negate = i128;
+    type Sum = u128;
+
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.checked_add(rhs)
+    }
+
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_sub(rhs)
+    }
+
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        self.checked_mul(rhs)
+    }
+
+    fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.checked_div(rhs)
+    }
+
+    fn checked_rem(self, rhs: Self) -> Option<Self> {
+        self.checked_rem(rhs)
+    }
+}
+
+impl ResultTypeOfUnary for i128 {
+    type Negate = i128;
+    type Sum = i128;
+
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.checked_add(rhs)
+    }
+
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_sub(rhs)
+    }
+
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        self.checked_mul(rhs)
+    }
+
+    fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.checked_div(rhs)
+    }
+
+    fn checked_rem(self, rhs: Self) -> Option<Self> {
+        self.checked_rem(rhs)
+    }
+}
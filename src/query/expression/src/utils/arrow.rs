@@ -15,15 +15,31 @@
 use std::io::Cursor;
 
 use common_arrow::arrow::array::Array;
+use common_arrow::arrow::array::ListArray;
+use common_arrow::arrow::array::MapArray;
+use common_arrow::arrow::array::PrimitiveArray;
+use common_arrow::arrow::array::StructArray;
 use common_arrow::arrow::bitmap::Bitmap;
 use common_arrow::arrow::bitmap::MutableBitmap;
 use common_arrow::arrow::buffer::Buffer;
+use common_arrow::arrow::datatypes::DataType as ArrowDataType;
+use common_arrow::arrow::datatypes::Field as ArrowField;
 use common_arrow::arrow::datatypes::Schema;
+use common_arrow::arrow::datatypes::TimeUnit;
 use common_arrow::arrow::io::ipc::read::read_file_metadata;
+use common_arrow::arrow::io::ipc::read::read_stream_metadata;
 use common_arrow::arrow::io::ipc::read::FileReader;
-use common_arrow::arrow::io::ipc::write::FileWriter;
+use common_arrow::arrow::io::ipc::read::StreamReader;
+use common_arrow::arrow::io::ipc::read::StreamState;
+use common_arrow::arrow::io::ipc::write::Compression;
+use common_arrow::arrow::io::ipc::write::StreamWriter;
 use common_arrow::arrow::io::ipc::write::WriteOptions as IpcWriteOptions;
 
+use crate::types::timestamp::micros_to_precision;
+use crate::types::timestamp::PRECISION_MILLI;
+use crate::types::timestamp::PRECISION_NANO;
+use crate::types::timestamp::PRECISION_SEC;
+use crate::types::DataType;
 use crate::BlockEntry;
 use crate::Column;
 use crate::ColumnBuilder;
@@ -41,6 +57,14 @@ pub fn bitmap_into_mut(bitmap: Bitmap) -> MutableBitmap {
         .into_inner()
 }
 
+/// Shortens the bitmap to its first `len` bits, does nothing if it has less bits.
+pub fn truncate_bitmap(bitmap: &mut MutableBitmap, len: usize) {
+    if bitmap.len() > len {
+        let bytes = bitmap.as_slice()[..(len + 7) / 8].to_vec();
+        *bitmap = MutableBitmap::from_vec(bytes, len);
+    }
+}
+
 pub fn repeat_bitmap(bitmap: &mut Bitmap, n: usize) -> MutableBitmap {
     let mut builder = MutableBitmap::new();
     for _ in 0..n {
@@ -62,12 +86,112 @@ pub fn buffer_into_mut<T: Clone>(mut buffer: Buffer<T>) -> Vec<T> {
     }
 }
 
+/// Convert the timestamps of the array, which are always microseconds in memory, to the time
+/// unit of `data_type`, i.e. the arrow type of a `TIMESTAMP(<precision>)` table field.
+pub fn rescale_timestamp_array(array: Box<dyn Array>, data_type: &ArrowDataType) -> Box<dyn Array> {
+    let rescale_field = |field: &ArrowField, values: &Box<dyn Array>| {
+        ArrowField::new(
+            field.name.clone(),
+            values.data_type().clone(),
+            field.is_nullable,
+        )
+        .with_metadata(field.metadata.clone())
+    };
+
+    match (array.data_type(), data_type) {
+        (
+            ArrowDataType::Timestamp(TimeUnit::Microsecond, tz),
+            ArrowDataType::Timestamp(unit, _),
+        ) if *unit != TimeUnit::Microsecond => {
+            let precision = match unit {
+                TimeUnit::Second => PRECISION_SEC,
+                TimeUnit::Millisecond => PRECISION_MILLI,
+                _ => PRECISION_NANO,
+            };
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i64>>()
+                .unwrap();
+            let values = array
+                .values()
+                .iter()
+                .map(|v| micros_to_precision(*v, precision))
+                .collect::<Vec<_>>();
+            Box::new(PrimitiveArray::<i64>::new(
+                ArrowDataType::Timestamp(*unit, tz.clone()),
+                values.into(),
+                array.validity().cloned(),
+            ))
+        }
+        (ArrowDataType::LargeList(field), ArrowDataType::LargeList(target)) => {
+            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            let values = rescale_timestamp_array(array.values().clone(), target.data_type());
+            Box::new(ListArray::<i64>::new(
+                ArrowDataType::LargeList(Box::new(rescale_field(field, &values))),
+                array.offsets().clone(),
+                values,
+                array.validity().cloned(),
+            ))
+        }
+        (ArrowDataType::Map(field, sorted), ArrowDataType::Map(target, _)) => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            let values = rescale_timestamp_array(array.field().clone(), target.data_type());
+            Box::new(MapArray::new(
+                ArrowDataType::Map(Box::new(rescale_field(field, &values)), *sorted),
+                array.offsets().clone(),
+                values,
+                array.validity().cloned(),
+            ))
+        }
+        (ArrowDataType::Struct(fields), ArrowDataType::Struct(targets)) => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            let values = array
+                .values()
+                .iter()
+                .zip(targets)
+                .map(|(values, target)| rescale_timestamp_array(values.clone(), target.data_type()))
+                .collect::<Vec<_>>();
+            let fields = fields
+                .iter()
+                .zip(&values)
+                .map(|(field, values)| rescale_field(field, values))
+                .collect();
+            Box::new(StructArray::new(
+                ArrowDataType::Struct(fields),
+                values,
+                array.validity().cloned(),
+            ))
+        }
+        _ => array,
+    }
+}
+
+/// Magic bytes of a serialized column frame, followed by a version byte.
+const COLUMN_FRAME_MAGIC: &[u8; 4] = b"DBCF";
+const COLUMN_FRAME_VERSION: u8 = 1;
+
+/// Serializes a column into a self-describing binary frame:
+///
+/// ```text
+/// | magic | version | data type length (u32 LE) | data type (json) | LZ4 compressed arrow IPC stream |
+/// ```
+///
+/// This is the format used by serde, the cluster exchange and spill files.
 pub fn serialize_column(col: &Column) -> Vec<u8> {
-    let mut buffer = Vec::new();
+    let data_type = serde_json::to_vec(&col.data_type()).unwrap();
+
+    let mut buffer = Vec::with_capacity(COLUMN_FRAME_MAGIC.len() + 5 + data_type.len());
+    buffer.extend_from_slice(COLUMN_FRAME_MAGIC);
+    buffer.push(COLUMN_FRAME_VERSION);
+    buffer.extend_from_slice(&(data_type.len() as u32).to_le_bytes());
+    buffer.extend_from_slice(&data_type);
 
     let schema = Schema::from(vec![col.arrow_field()]);
-    let mut writer = FileWriter::new(&mut buffer, schema, None, IpcWriteOptions::default());
-    writer.start().unwrap();
+    let options = IpcWriteOptions {
+        compression: Some(Compression::LZ4),
+    };
+    let mut writer = StreamWriter::new(&mut buffer, options);
+    writer.start(&schema, None).unwrap();
     writer
         .write(
             &common_arrow::arrow::chunk::Chunk::new(vec![col.as_arrow()]),
@@ -79,7 +203,41 @@ pub fn serialize_column(col: &Column) -> Vec<u8> {
     buffer
 }
 
+/// Deserializes a column written by [`serialize_column`]. Columns written by older
+/// versions as a bare arrow IPC file are still accepted.
 pub fn deserialize_column(bytes: &[u8]) -> Option<Column> {
+    if !is_column_frame(bytes) {
+        return deserialize_legacy_column(bytes);
+    }
+
+    let header_len = COLUMN_FRAME_MAGIC.len() + 1;
+    if bytes[COLUMN_FRAME_MAGIC.len()] != COLUMN_FRAME_VERSION {
+        return None;
+    }
+    let data_type_len =
+        u32::from_le_bytes(bytes.get(header_len..header_len + 4)?.try_into().ok()?) as usize;
+    let data_type_start = header_len + 4;
+    let data_type_end = data_type_start + data_type_len;
+    let data_type: DataType =
+        serde_json::from_slice(bytes.get(data_type_start..data_type_end)?).ok()?;
+
+    let mut cursor = Cursor::new(&bytes[data_type_end..]);
+    let metadata = read_stream_metadata(&mut cursor).ok()?;
+    let mut reader = StreamReader::new(cursor, metadata, None);
+    let col = match reader.next()?.ok()? {
+        StreamState::Some(chunk) => chunk.into_arrays().remove(0),
+        StreamState::Waiting => return None,
+    };
+
+    Some(Column::from_arrow(col.as_ref(), &data_type))
+}
+
+/// Returns whether the bytes start with the header written by [`serialize_column`].
+pub fn is_column_frame(bytes: &[u8]) -> bool {
+    bytes.len() > COLUMN_FRAME_MAGIC.len() && bytes.starts_with(COLUMN_FRAME_MAGIC)
+}
+
+fn deserialize_legacy_column(bytes: &[u8]) -> Option<Column> {
     let mut cursor = Cursor::new(bytes);
 
     let metadata = read_file_metadata(&mut cursor).ok()?;
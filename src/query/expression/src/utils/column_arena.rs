@@ -0,0 +1,209 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A slab of recycled column buffers.
+//!
+//! Every pipeline executor worker enters a column arena when it starts. The
+//! buffers of transient columns are handed back to the arena at block boundaries
+//! (see [`DataBlock::recycle`]) and reused by the next [`ColumnBuilder`] of the same
+//! type on that worker, instead of going through the global allocator for every block.
+//! All retained buffers are freed at once when the worker leaves the arena.
+//!
+//! [`DataBlock::recycle`]: crate::DataBlock::recycle
+//! [`ColumnBuilder`]: crate::ColumnBuilder
+
+use std::any::Any;
+use std::any::TypeId;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_arrow::arrow::buffer::Buffer;
+
+use crate::types::decimal::DecimalColumn;
+use crate::types::NumberColumn;
+use crate::Column;
+
+/// The max bytes of buffers retained by the arena of one worker.
+const MAX_RETAINED_BYTES: usize = 16 * 1024 * 1024;
+
+/// The max number of buffers of one type retained by the arena of one worker.
+const MAX_RETAINED_BUFFERS: usize = 64;
+
+thread_local! {
+    static COLUMN_ARENA: RefCell<Option<ColumnArena>> = const { RefCell::new(None) };
+}
+
+/// Allocation statistics, shared by the arenas of all the workers of a pipeline.
+#[derive(Default, Debug)]
+pub struct ColumnArenaStats {
+    /// Number of buffers allocated through the arena.
+    pub allocations: AtomicU64,
+    /// Number of allocations served by a recycled buffer.
+    pub reused: AtomicU64,
+    /// Bytes of the recycled buffers that have been reused.
+    pub reused_bytes: AtomicU64,
+    /// Number of buffers handed back to the arena.
+    pub recycled: AtomicU64,
+}
+
+impl ColumnArenaStats {
+    pub fn create() -> Arc<ColumnArenaStats> {
+        Arc::new(ColumnArenaStats::default())
+    }
+}
+
+struct ColumnArena {
+    // TypeId::of::<T>() -> Vec<Vec<T>>
+    pools: HashMap<TypeId, Box<dyn Any>>,
+    retained_bytes: usize,
+    stats: Arc<ColumnArenaStats>,
+}
+
+impl ColumnArena {
+    fn pool<T: 'static>(&mut self) -> &mut Vec<Vec<T>> {
+        self.pools
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| Box::<Vec<Vec<T>>>::default())
+            .downcast_mut::<Vec<Vec<T>>>()
+            .unwrap()
+    }
+
+    fn alloc<T: 'static>(&mut self, capacity: usize) -> Vec<T> {
+        self.stats.allocations.fetch_add(1, Ordering::Relaxed);
+
+        // Don't hand out a buffer much larger than requested, it would be wasted.
+        let pool = self.pool::<T>();
+        let position = pool
+            .iter()
+            .position(|buffer| buffer.capacity() >= capacity && buffer.capacity() / 2 <= capacity);
+        match position {
+            Some(position) => {
+                let buffer = pool.swap_remove(position);
+                let bytes = buffer.capacity() * std::mem::size_of::<T>();
+                self.retained_bytes -= bytes;
+                self.stats.reused.fetch_add(1, Ordering::Relaxed);
+                self.stats
+                    .reused_bytes
+                    .fetch_add(bytes as u64, Ordering::Relaxed);
+                buffer
+            }
+            None => Vec::with_capacity(capacity),
+        }
+    }
+
+    fn recycle<T: 'static>(&mut self, mut buffer: Vec<T>) {
+        let bytes = buffer.capacity() * std::mem::size_of::<T>();
+        if bytes == 0 || self.retained_bytes + bytes > MAX_RETAINED_BYTES {
+            return;
+        }
+        buffer.clear();
+
+        let pool = self.pool::<T>();
+        let evicted = if pool.len() >= MAX_RETAINED_BUFFERS {
+            let oldest = pool.remove(0);
+            oldest.capacity() * std::mem::size_of::<T>()
+        } else {
+            0
+        };
+        pool.push(buffer);
+        self.retained_bytes = self.retained_bytes + bytes - evicted;
+        self.stats.recycled.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Keeps the current thread inside a column arena, the retained buffers are
+/// freed when the guard is dropped.
+pub struct ColumnArenaGuard {
+    // The arena is bound to the thread.
+    _marker: PhantomData<*const ()>,
+}
+
+impl ColumnArenaGuard {
+    pub fn enter(stats: Arc<ColumnArenaStats>) -> ColumnArenaGuard {
+        COLUMN_ARENA.with(|arena| {
+            *arena.borrow_mut() = Some(ColumnArena {
+                pools: HashMap::new(),
+                retained_bytes: 0,
+                stats,
+            })
+        });
+        ColumnArenaGuard {
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl Drop for ColumnArenaGuard {
+    fn drop(&mut self) {
+        COLUMN_ARENA.with(|arena| arena.borrow_mut().take());
+    }
+}
+
+/// Allocates a buffer with at least `capacity`, reusing a recycled one of the current
+/// arena if possible.
+pub fn arena_vec<T: 'static>(capacity: usize) -> Vec<T> {
+    if capacity == 0 {
+        return Vec::new();
+    }
+    COLUMN_ARENA.with(|arena| match arena.borrow_mut().as_mut() {
+        Some(arena) => arena.alloc(capacity),
+        None => Vec::with_capacity(capacity),
+    })
+}
+
+/// Hands the buffers of the column back to the current arena. The buffers still
+/// shared with other columns are left untouched.
+pub fn recycle_column(column: Column) {
+    COLUMN_ARENA.with(|arena| {
+        if let Some(arena) = arena.borrow_mut().as_mut() {
+            recycle_column_buffers(arena, column);
+        }
+    })
+}
+
+fn recycle_column_buffers(arena: &mut ColumnArena, column: Column) {
+    match column {
+        Column::Number(column) => {
+            crate::with_number_type!(|NUM_TYPE| match column {
+                NumberColumn::NUM_TYPE(buffer) => recycle_buffer(arena, buffer),
+            })
+        }
+        Column::Decimal(column) => {
+            crate::with_decimal_type!(|DECIMAL_TYPE| match column {
+                DecimalColumn::DECIMAL_TYPE(buffer, _) => recycle_buffer(arena, buffer),
+            })
+        }
+        Column::Timestamp(buffer) => recycle_buffer(arena, buffer),
+        Column::Date(buffer) => recycle_buffer(arena, buffer),
+        Column::Interval(buffer) => recycle_buffer(arena, buffer),
+        Column::String(column) => {
+            let (data, offsets) = column.into_buffer();
+            recycle_buffer(arena, data);
+            recycle_buffer(arena, offsets);
+        }
+        Column::Nullable(box column) => recycle_column_buffers(arena, column.column),
+        _ => {}
+    }
+}
+
+fn recycle_buffer<T: 'static>(arena: &mut ColumnArena, mut buffer: Buffer<T>) {
+    // Only a buffer owning the whole allocation can be reused.
+    if let Some(buffer) = unsafe { buffer.get_mut() }.map(std::mem::take) {
+        arena.recycle(buffer);
+    }
+}
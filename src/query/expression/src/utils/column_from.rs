@@ -68,8 +68,10 @@ impl_from_data! { BooleanType }
 impl_from_data! { StringType }
 impl_from_data! { DateType }
 impl_from_data! { TimestampType }
+impl_from_data! { IntervalType }
 impl_from_data! { VariantType }
 impl_from_data! { BitmapType }
+impl_from_data! { GeometryType }
 
 impl<'a> FromData<&'a str> for StringType {
     fn from_data(d: Vec<&'a str>) -> Column {
@@ -40,6 +40,7 @@ use crate::types::decimal::DecimalColumn;
 use crate::types::decimal::DecimalDataType;
 use crate::types::decimal::DecimalDomain;
 use crate::types::decimal::DecimalScalar;
+use crate::types::geometry::geometry_to_wkt;
 use crate::types::map::KvPair;
 use crate::types::nullable::NullableDomain;
 use crate::types::number::NumberColumn;
@@ -126,6 +127,7 @@ impl<'a> Debug for ScalarRef<'a> {
             },
             ScalarRef::Timestamp(t) => write!(f, "{t:?}"),
             ScalarRef::Date(d) => write!(f, "{d:?}"),
+            ScalarRef::Interval(i) => write!(f, "{i:?}"),
             ScalarRef::Array(col) => write!(f, "[{}]", col.iter().join(", ")),
             ScalarRef::Map(col) => {
                 write!(f, "{{")?;
@@ -144,6 +146,10 @@ impl<'a> Debug for ScalarRef<'a> {
                 let rb = RoaringTreemap::deserialize_from(*bits).unwrap();
                 write!(f, "{rb:?}")
             }
+            ScalarRef::Geometry(g) => match geometry_to_wkt(g) {
+                Ok(wkt) => write!(f, "{wkt:?}"),
+                Err(_) => write!(f, "0x{}", hex::encode(g)),
+            },
             ScalarRef::Tuple(fields) => {
                 write!(f, "(")?;
                 for (i, field) in fields.iter().enumerate() {
@@ -174,12 +180,19 @@ impl Debug for Column {
             Column::String(col) => write!(f, "{col:?}"),
             Column::Timestamp(col) => write!(f, "{col:?}"),
             Column::Date(col) => write!(f, "{col:?}"),
+            Column::Interval(col) => write!(f, "{col:?}"),
             Column::Array(col) => write!(f, "{col:?}"),
             Column::Map(col) => write!(f, "{col:?}"),
             Column::Bitmap(col) => write!(f, "{col:?}"),
+            Column::Geometry(col) => write!(f, "{col:?}"),
             Column::Nullable(col) => write!(f, "{col:?}"),
             Column::Tuple(fields) => f.debug_tuple("Tuple").field(fields).finish(),
             Column::Variant(col) => write!(f, "{col:?}"),
+            Column::Constant(col) => f
+                .debug_struct("Constant")
+                .field("scalar", &col.scalar)
+                .field("len", &col.len)
+                .finish(),
         }
     }
 }
@@ -205,6 +218,7 @@ impl<'a> Display for ScalarRef<'a> {
             },
             ScalarRef::Timestamp(t) => write!(f, "'{}'", timestamp_to_string(*t, Tz::UTC)),
             ScalarRef::Date(d) => write!(f, "'{}'", date_to_string(*d as i64, Tz::UTC)),
+            ScalarRef::Interval(i) => write!(f, "'{i}'"),
             ScalarRef::Array(col) => write!(f, "[{}]", col.iter().join(", ")),
             ScalarRef::Map(col) => {
                 write!(f, "{{")?;
@@ -243,6 +257,10 @@ impl<'a> Display for ScalarRef<'a> {
                 let value = jsonb::to_string(s);
                 write!(f, "{value}")
             }
+            ScalarRef::Geometry(g) => match geometry_to_wkt(g) {
+                Ok(wkt) => write!(f, "'{wkt}'"),
+                Err(_) => write!(f, "0x{}", hex::encode(g)),
+            },
         }
     }
 }
@@ -266,6 +284,8 @@ impl Debug for NumberScalar {
             NumberScalar::Int64(val) => write!(f, "{val}_i64"),
             NumberScalar::Float32(val) => write!(f, "{}_f32", display_f32(**val)),
             NumberScalar::Float64(val) => write!(f, "{}_f64", display_f64(**val)),
+            NumberScalar::UInt128(val) => write!(f, "{val}_u128"),
+            NumberScalar::Int128(val) => write!(f, "{val}_i128"),
         }
     }
 }
@@ -283,6 +303,8 @@ impl Display for NumberScalar {
             NumberScalar::Int64(val) => write!(f, "{val}"),
             NumberScalar::Float32(val) => write!(f, "{}", display_f32(**val)),
             NumberScalar::Float64(val) => write!(f, "{}", display_f64(**val)),
+            NumberScalar::UInt128(val) => write!(f, "{val}"),
+            NumberScalar::Int128(val) => write!(f, "{val}"),
         }
     }
 }
@@ -350,6 +372,8 @@ impl Debug for NumberColumn {
                     &val.iter().map(|x| display_f64(x.0)).join(", ")
                 ))
                 .finish(),
+            NumberColumn::UInt128(val) => f.debug_tuple("UInt128").field(val).finish(),
+            NumberColumn::Int128(val) => f.debug_tuple("Int128").field(val).finish(),
         }
     }
 }
@@ -468,6 +492,7 @@ impl Display for DataType {
             DataType::Decimal(decimal) => write!(f, "{decimal}"),
             DataType::Timestamp => write!(f, "Timestamp"),
             DataType::Date => write!(f, "Date"),
+            DataType::Interval => write!(f, "Interval"),
             DataType::Null => write!(f, "NULL"),
             DataType::Nullable(inner) => write!(f, "{inner} NULL"),
             DataType::EmptyArray => write!(f, "Array(Nothing)"),
@@ -480,6 +505,7 @@ impl Display for DataType {
                 _ => unreachable!(),
             },
             DataType::Bitmap => write!(f, "Bitmap"),
+            DataType::Geometry => write!(f, "Geometry"),
             DataType::Tuple(tys) => {
                 write!(f, "Tuple(")?;
                 for (i, ty) in tys.iter().enumerate() {
@@ -507,6 +533,7 @@ impl Display for TableDataType {
             TableDataType::Number(num) => write!(f, "{num}"),
             TableDataType::Decimal(decimal) => write!(f, "{decimal}"),
             TableDataType::Timestamp => write!(f, "Timestamp"),
+            TableDataType::TimestampWithPrecision(precision) => write!(f, "Timestamp({precision})"),
             TableDataType::Date => write!(f, "Date"),
             TableDataType::Null => write!(f, "NULL"),
             TableDataType::Nullable(inner) => write!(f, "{inner} NULL"),
@@ -540,6 +567,7 @@ impl Display for TableDataType {
                 write!(f, ")")
             }
             TableDataType::Variant => write!(f, "Variant"),
+            TableDataType::Geometry => write!(f, "Geometry"),
         }
     }
 }
@@ -557,6 +585,8 @@ impl Display for NumberDataType {
             NumberDataType::Int64 => write!(f, "Int64"),
             NumberDataType::Float32 => write!(f, "Float32"),
             NumberDataType::Float64 => write!(f, "Float64"),
+            NumberDataType::UInt128 => write!(f, "UInt128"),
+            NumberDataType::Int128 => write!(f, "Int128"),
         }
     }
 }
@@ -589,6 +619,8 @@ impl Display for NumberClass {
             NumberClass::Decimal256 => write!(f, "Decimal256"),
             NumberClass::Float32 => write!(f, "Float32"),
             NumberClass::Float64 => write!(f, "Float64"),
+            NumberClass::UInt128 => write!(f, "UInt128"),
+            NumberClass::Int128 => write!(f, "Int128"),
         }
     }
 }
@@ -985,6 +1017,7 @@ impl Display for Domain {
             Domain::String(domain) => write!(f, "{domain}"),
             Domain::Timestamp(domain) => write!(f, "{domain}"),
             Domain::Date(domain) => write!(f, "{domain}"),
+            Domain::Interval(domain) => write!(f, "{domain}"),
             Domain::Nullable(domain) => write!(f, "{domain}"),
             Domain::Array(None) => write!(f, "[]"),
             Domain::Array(Some(domain)) => write!(f, "[{domain}]"),
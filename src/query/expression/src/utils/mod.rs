@@ -16,6 +16,7 @@ pub mod arithmetics_type;
 pub mod arrow;
 pub mod block_debug;
 pub mod block_thresholds;
+pub mod column_arena;
 mod column_from;
 pub mod date_helper;
 pub mod display;
@@ -26,9 +26,11 @@ use common_arrow::arrow::buffer::Buffer;
 use common_arrow::arrow::compute::cast as arrow_cast;
 use common_arrow::arrow::datatypes::DataType as ArrowDataType;
 use common_arrow::arrow::datatypes::Field as ArrowField;
+use common_arrow::arrow::datatypes::IntervalUnit;
 use common_arrow::arrow::datatypes::TimeUnit;
 use common_arrow::arrow::offset::OffsetsBuffer;
 use common_arrow::arrow::trusted_len::TrustedLen;
+use common_arrow::arrow::types::months_days_ns;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_io::prelude::BinaryRead;
@@ -56,6 +58,9 @@ use crate::types::decimal::DecimalColumnBuilder;
 use crate::types::decimal::DecimalDataType;
 use crate::types::decimal::DecimalScalar;
 use crate::types::decimal::DecimalSize;
+use crate::types::geometry::GeometryType;
+use crate::types::geometry::DEFAULT_GEOMETRY;
+use crate::types::interval::months_days_micros;
 use crate::types::nullable::NullableColumn;
 use crate::types::nullable::NullableColumnBuilder;
 use crate::types::nullable::NullableColumnVec;
@@ -78,7 +83,10 @@ use crate::utils::arrow::append_bitmap;
 use crate::utils::arrow::bitmap_into_mut;
 use crate::utils::arrow::buffer_into_mut;
 use crate::utils::arrow::deserialize_column;
+use crate::utils::arrow::is_column_frame;
 use crate::utils::arrow::serialize_column;
+use crate::utils::arrow::truncate_bitmap;
+use crate::utils::column_arena::arena_vec;
 use crate::utils::FromData;
 use crate::values::decimal::DecimalColumnVec;
 use crate::values::map::KvPair;
@@ -108,6 +116,7 @@ pub enum Scalar {
     Decimal(DecimalScalar),
     Timestamp(i64),
     Date(i32),
+    Interval(months_days_micros),
     Boolean(bool),
     String(Vec<u8>),
     Array(Column),
@@ -115,6 +124,7 @@ pub enum Scalar {
     Bitmap(Vec<u8>),
     Tuple(Vec<Scalar>),
     Variant(Vec<u8>),
+    Geometry(Vec<u8>),
 }
 
 #[derive(Clone, Default, Eq, EnumAsInner)]
@@ -129,11 +139,13 @@ pub enum ScalarRef<'a> {
     String(&'a [u8]),
     Timestamp(i64),
     Date(i32),
+    Interval(months_days_micros),
     Array(Column),
     Map(Column),
     Bitmap(&'a [u8]),
     Tuple(Vec<ScalarRef<'a>>),
     Variant(&'a [u8]),
+    Geometry(&'a [u8]),
 }
 
 #[derive(Clone, EnumAsInner)]
@@ -147,12 +159,49 @@ pub enum Column {
     String(StringColumn),
     Timestamp(Buffer<i64>),
     Date(Buffer<i32>),
+    Interval(Buffer<months_days_micros>),
     Array(Box<ArrayColumn<AnyType>>),
     Map(Box<ArrayColumn<AnyType>>),
     Bitmap(StringColumn),
     Nullable(Box<NullableColumn<AnyType>>),
     Tuple(Vec<Column>),
     Variant(StringColumn),
+    Geometry(StringColumn),
+    Constant(Box<ConstantColumn>),
+}
+
+/// A column of `len` copies of the same value, which is expanded lazily by
+/// [`Column::materialize`] only if a concrete layout is required.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConstantColumn {
+    pub scalar: Scalar,
+    pub data_type: DataType,
+    pub len: usize,
+    /// The value as a column of one row, the row-wise readers such as the field
+    /// encoders read its first row instead of expanding the column.
+    pub row: Column,
+}
+
+impl ConstantColumn {
+    pub fn new(scalar: Scalar, data_type: DataType, len: usize) -> Column {
+        let row = ColumnBuilder::repeat(&scalar.as_ref(), 1, &data_type).build();
+        Column::Constant(Box::new(ConstantColumn {
+            scalar,
+            data_type,
+            len,
+            row,
+        }))
+    }
+
+    /// Returns a constant column of the same value with the given length.
+    pub fn with_len(&self, len: usize) -> Column {
+        Column::Constant(Box::new(ConstantColumn {
+            scalar: self.scalar.clone(),
+            data_type: self.data_type.clone(),
+            len,
+            row: self.row.clone(),
+        }))
+    }
 }
 
 #[derive(Clone, EnumAsInner, Debug, PartialEq)]
@@ -166,12 +215,14 @@ pub enum ColumnVec {
     String(Vec<StringColumn>),
     Timestamp(Vec<Buffer<i64>>),
     Date(Vec<Buffer<i32>>),
+    Interval(Vec<Buffer<months_days_micros>>),
     Array(Vec<ArrayColumn<AnyType>>),
     Map(Vec<ArrayColumn<KvPair<AnyType, AnyType>>>),
     Bitmap(Vec<StringColumn>),
     Nullable(Box<NullableColumnVec>),
     Tuple(Vec<ColumnVec>),
     Variant(Vec<StringColumn>),
+    Geometry(Vec<StringColumn>),
 }
 
 #[derive(Debug, Clone, EnumAsInner)]
@@ -185,12 +236,14 @@ pub enum ColumnBuilder {
     String(StringColumnBuilder),
     Timestamp(Vec<i64>),
     Date(Vec<i32>),
+    Interval(Vec<months_days_micros>),
     Array(Box<ArrayColumnBuilder<AnyType>>),
     Map(Box<ArrayColumnBuilder<AnyType>>),
     Bitmap(StringColumnBuilder),
     Nullable(Box<NullableColumnBuilder<AnyType>>),
     Tuple(Vec<ColumnBuilder>),
     Variant(StringColumnBuilder),
+    Geometry(StringColumnBuilder),
 }
 
 impl<'a, T: ValueType> ValueRef<'a, T> {
@@ -286,6 +339,15 @@ impl Value<AnyType> {
                 let builder = ColumnBuilder::repeat(&s.as_ref(), num_rows, ty);
                 builder.build()
             }
+            Value::Column(c) => c.materialize(),
+        }
+    }
+
+    /// Like `convert_to_full_column`, but keeps a scalar as a constant column instead of
+    /// repeating it `num_rows` times.
+    pub fn convert_to_constant_column(&self, ty: &DataType, num_rows: usize) -> Column {
+        match self {
+            Value::Scalar(s) => ConstantColumn::new(s.clone(), ty.clone(), num_rows),
             Value::Column(c) => c.clone(),
         }
     }
@@ -306,6 +368,9 @@ impl<'a> ValueRef<'a, AnyType> {
     pub fn try_downcast<T: ValueType>(&self) -> Option<ValueRef<'_, T>> {
         Some(match self {
             ValueRef::Scalar(scalar) => ValueRef::Scalar(T::try_downcast_scalar(scalar)?),
+            ValueRef::Column(Column::Constant(col)) => {
+                ValueRef::Scalar(T::try_downcast_scalar(&col.scalar.as_ref())?)
+            }
             ValueRef::Column(col) => ValueRef::Column(T::try_downcast_column(col)?),
         })
     }
@@ -330,9 +395,11 @@ impl Scalar {
             Scalar::String(s) => ScalarRef::String(s.as_slice()),
             Scalar::Timestamp(t) => ScalarRef::Timestamp(*t),
             Scalar::Date(d) => ScalarRef::Date(*d),
+            Scalar::Interval(i) => ScalarRef::Interval(*i),
             Scalar::Array(col) => ScalarRef::Array(col.clone()),
             Scalar::Map(col) => ScalarRef::Map(col.clone()),
             Scalar::Bitmap(b) => ScalarRef::Bitmap(b.as_slice()),
+            Scalar::Geometry(b) => ScalarRef::Geometry(b.as_slice()),
             Scalar::Tuple(fields) => ScalarRef::Tuple(fields.iter().map(Scalar::as_ref).collect()),
             Scalar::Variant(s) => ScalarRef::Variant(s.as_slice()),
         }
@@ -356,10 +423,13 @@ impl Scalar {
                 NumberDataType::Int64 => NumberScalar::Int64(0),
                 NumberDataType::Float32 => NumberScalar::Float32(OrderedFloat(0.0)),
                 NumberDataType::Float64 => NumberScalar::Float64(OrderedFloat(0.0)),
+                NumberDataType::UInt128 => NumberScalar::UInt128(0),
+                NumberDataType::Int128 => NumberScalar::Int128(0),
             }),
             DataType::Decimal(ty) => Scalar::Decimal(ty.default_scalar()),
             DataType::Timestamp => Scalar::Timestamp(0),
             DataType::Date => Scalar::Date(0),
+            DataType::Interval => Scalar::Interval(months_days_micros::default()),
             DataType::Nullable(_) => Scalar::Null,
             DataType::Array(ty) => {
                 let builder = ColumnBuilder::with_capacity(ty, 0);
@@ -377,6 +447,7 @@ impl Scalar {
                 rb.serialize_into(&mut buf).unwrap();
                 Scalar::Bitmap(buf)
             }
+            DataType::Geometry => Scalar::Geometry(DEFAULT_GEOMETRY.to_vec()),
             DataType::Tuple(tys) => Scalar::Tuple(tys.iter().map(Scalar::default_value).collect()),
             DataType::Variant => Scalar::Variant(vec![]),
 
@@ -390,6 +461,7 @@ impl Scalar {
             Scalar::Decimal(d) => d.is_positive(),
             Scalar::Timestamp(t) => *t > 0,
             Scalar::Date(d) => *d > 0,
+            Scalar::Interval(i) => i.total_micros() > 0,
             _ => unreachable!("is_positive() called on non-numeric scalar"),
         }
     }
@@ -407,9 +479,11 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::String(s) => Scalar::String(s.to_vec()),
             ScalarRef::Timestamp(t) => Scalar::Timestamp(*t),
             ScalarRef::Date(d) => Scalar::Date(*d),
+            ScalarRef::Interval(i) => Scalar::Interval(*i),
             ScalarRef::Array(col) => Scalar::Array(col.clone()),
             ScalarRef::Map(col) => Scalar::Map(col.clone()),
             ScalarRef::Bitmap(b) => Scalar::Bitmap(b.to_vec()),
+            ScalarRef::Geometry(b) => Scalar::Geometry(b.to_vec()),
             ScalarRef::Tuple(fields) => {
                 Scalar::Tuple(fields.iter().map(ScalarRef::to_owned).collect())
             }
@@ -451,6 +525,7 @@ impl<'a> ScalarRef<'a> {
             }),
             ScalarRef::Timestamp(t) => Domain::Timestamp(SimpleDomain { min: *t, max: *t }),
             ScalarRef::Date(d) => Domain::Date(SimpleDomain { min: *d, max: *d }),
+            ScalarRef::Interval(i) => Domain::Interval(SimpleDomain { min: *i, max: *i }),
             ScalarRef::Array(array) => {
                 if array.len() == 0 {
                     Domain::Array(None)
@@ -482,7 +557,9 @@ impl<'a> ScalarRef<'a> {
                         .collect(),
                 )
             }
-            ScalarRef::Bitmap(_) | ScalarRef::Variant(_) => Domain::Undefined,
+            ScalarRef::Bitmap(_) | ScalarRef::Variant(_) | ScalarRef::Geometry(_) => {
+                Domain::Undefined
+            }
         }
     }
 
@@ -499,15 +576,19 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::Number(NumberScalar::Int16(_)) => 2,
             ScalarRef::Number(NumberScalar::Int32(_)) => 4,
             ScalarRef::Number(NumberScalar::Int64(_)) => 8,
+            ScalarRef::Number(NumberScalar::UInt128(_)) => 16,
+            ScalarRef::Number(NumberScalar::Int128(_)) => 16,
             ScalarRef::Decimal(DecimalScalar::Decimal128(_, _)) => 16,
             ScalarRef::Decimal(DecimalScalar::Decimal256(_, _)) => 32,
             ScalarRef::Boolean(_) => 1,
             ScalarRef::String(s) => s.len(),
             ScalarRef::Timestamp(_) => 8,
             ScalarRef::Date(_) => 4,
+            ScalarRef::Interval(_) => 16,
             ScalarRef::Array(col) => col.memory_size(),
             ScalarRef::Map(col) => col.memory_size(),
             ScalarRef::Bitmap(b) => b.len(),
+            ScalarRef::Geometry(b) => b.len(),
             ScalarRef::Tuple(scalars) => scalars.iter().map(|s| s.memory_size()).sum(),
             ScalarRef::Variant(buf) => buf.len(),
         }
@@ -530,9 +611,11 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::String(_) => DataType::String,
             ScalarRef::Timestamp(_) => DataType::Timestamp,
             ScalarRef::Date(_) => DataType::Date,
+            ScalarRef::Interval(_) => DataType::Interval,
             ScalarRef::Array(array) => DataType::Array(Box::new(array.data_type())),
             ScalarRef::Map(col) => DataType::Map(Box::new(col.data_type())),
             ScalarRef::Bitmap(_) => DataType::Bitmap,
+            ScalarRef::Geometry(_) => DataType::Geometry,
             ScalarRef::Tuple(fields) => {
                 let inner = fields
                     .iter()
@@ -557,9 +640,11 @@ impl PartialOrd for Scalar {
             (Scalar::String(s1), Scalar::String(s2)) => s1.partial_cmp(s2),
             (Scalar::Timestamp(t1), Scalar::Timestamp(t2)) => t1.partial_cmp(t2),
             (Scalar::Date(d1), Scalar::Date(d2)) => d1.partial_cmp(d2),
+            (Scalar::Interval(i1), Scalar::Interval(i2)) => i1.partial_cmp(i2),
             (Scalar::Array(a1), Scalar::Array(a2)) => a1.partial_cmp(a2),
             (Scalar::Map(m1), Scalar::Map(m2)) => m1.partial_cmp(m2),
             (Scalar::Bitmap(b1), Scalar::Bitmap(b2)) => b1.partial_cmp(b2),
+            (Scalar::Geometry(b1), Scalar::Geometry(b2)) => b1.partial_cmp(b2),
             (Scalar::Tuple(t1), Scalar::Tuple(t2)) => t1.partial_cmp(t2),
             (Scalar::Variant(v1), Scalar::Variant(v2)) => {
                 jsonb::compare(v1.as_slice(), v2.as_slice()).ok()
@@ -593,9 +678,11 @@ impl PartialOrd for ScalarRef<'_> {
             (ScalarRef::String(s1), ScalarRef::String(s2)) => s1.partial_cmp(s2),
             (ScalarRef::Timestamp(t1), ScalarRef::Timestamp(t2)) => t1.partial_cmp(t2),
             (ScalarRef::Date(d1), ScalarRef::Date(d2)) => d1.partial_cmp(d2),
+            (ScalarRef::Interval(i1), ScalarRef::Interval(i2)) => i1.partial_cmp(i2),
             (ScalarRef::Array(a1), ScalarRef::Array(a2)) => a1.partial_cmp(a2),
             (ScalarRef::Map(m1), ScalarRef::Map(m2)) => m1.partial_cmp(m2),
             (ScalarRef::Bitmap(b1), ScalarRef::Bitmap(b2)) => b1.partial_cmp(b2),
+            (ScalarRef::Geometry(b1), ScalarRef::Geometry(b2)) => b1.partial_cmp(b2),
             (ScalarRef::Tuple(t1), ScalarRef::Tuple(t2)) => t1.partial_cmp(t2),
             (ScalarRef::Variant(v1), ScalarRef::Variant(v2)) => jsonb::compare(v1, v2).ok(),
             _ => None,
@@ -633,15 +720,14 @@ impl Hash for ScalarRef<'_> {
             ScalarRef::String(v) => v.hash(state),
             ScalarRef::Timestamp(v) => v.hash(state),
             ScalarRef::Date(v) => v.hash(state),
-            ScalarRef::Array(v) => {
-                let str = serialize_column(v);
-                str.hash(state);
-            }
-            ScalarRef::Map(v) => {
-                let str = serialize_column(v);
-                str.hash(state);
+            ScalarRef::Interval(v) => v.hash(state),
+            ScalarRef::Array(v) | ScalarRef::Map(v) => {
+                let mut hashes = Vec::with_capacity(v.len());
+                v.hash_into(0, &mut hashes);
+                hashes.hash(state);
             }
             ScalarRef::Bitmap(v) => v.hash(state),
+            ScalarRef::Geometry(v) => v.hash(state),
             ScalarRef::Tuple(v) => {
                 v.hash(state);
             }
@@ -674,9 +760,15 @@ impl PartialOrd for Column {
                 col1.iter().partial_cmp(col2.iter())
             }
             (Column::Date(col1), Column::Date(col2)) => col1.iter().partial_cmp(col2.iter()),
+            (Column::Interval(col1), Column::Interval(col2)) => {
+                col1.iter().partial_cmp(col2.iter())
+            }
             (Column::Array(col1), Column::Array(col2)) => col1.iter().partial_cmp(col2.iter()),
             (Column::Map(col1), Column::Map(col2)) => col1.iter().partial_cmp(col2.iter()),
             (Column::Bitmap(col1), Column::Bitmap(col2)) => col1.iter().partial_cmp(col2.iter()),
+            (Column::Geometry(col1), Column::Geometry(col2)) => {
+                col1.iter().partial_cmp(col2.iter())
+            }
             (Column::Nullable(col1), Column::Nullable(col2)) => {
                 col1.iter().partial_cmp(col2.iter())
             }
@@ -684,6 +776,11 @@ impl PartialOrd for Column {
             (Column::Variant(col1), Column::Variant(col2)) => col1
                 .iter()
                 .partial_cmp_by(col2.iter(), |v1, v2| jsonb::compare(v1, v2).ok()),
+            (Column::Constant(_), _) | (_, Column::Constant(_))
+                if self.data_type() == other.data_type() =>
+            {
+                self.iter().partial_cmp(other.iter())
+            }
             _ => None,
         }
     }
@@ -700,6 +797,9 @@ pub const ARROW_EXT_TYPE_EMPTY_ARRAY: &str = "EmptyArray";
 pub const ARROW_EXT_TYPE_EMPTY_MAP: &str = "EmptyMap";
 pub const ARROW_EXT_TYPE_VARIANT: &str = "Variant";
 pub const ARROW_EXT_TYPE_BITMAP: &str = "Bitmap";
+pub const ARROW_EXT_TYPE_GEOMETRY: &str = "Geometry";
+pub const ARROW_EXT_TYPE_INT128: &str = "Int128";
+pub const ARROW_EXT_TYPE_UINT128: &str = "UInt128";
 
 impl Column {
     pub fn len(&self) -> usize {
@@ -713,12 +813,15 @@ impl Column {
             Column::String(col) => col.len(),
             Column::Timestamp(col) => col.len(),
             Column::Date(col) => col.len(),
+            Column::Interval(col) => col.len(),
             Column::Array(col) => col.len(),
             Column::Map(col) => col.len(),
             Column::Bitmap(col) => col.len(),
+            Column::Geometry(col) => col.len(),
             Column::Nullable(col) => col.len(),
             Column::Tuple(fields) => fields[0].len(),
             Column::Variant(col) => col.len(),
+            Column::Constant(col) => col.len,
         }
     }
 
@@ -733,9 +836,11 @@ impl Column {
             Column::String(col) => Some(ScalarRef::String(col.index(index)?)),
             Column::Timestamp(col) => Some(ScalarRef::Timestamp(col.get(index).cloned()?)),
             Column::Date(col) => Some(ScalarRef::Date(col.get(index).cloned()?)),
+            Column::Interval(col) => Some(ScalarRef::Interval(col.get(index).cloned()?)),
             Column::Array(col) => Some(ScalarRef::Array(col.index(index)?)),
             Column::Map(col) => Some(ScalarRef::Map(col.index(index)?)),
             Column::Bitmap(col) => Some(ScalarRef::Bitmap(col.index(index)?)),
+            Column::Geometry(col) => Some(ScalarRef::Geometry(col.index(index)?)),
             Column::Nullable(col) => Some(col.index(index)?.unwrap_or(ScalarRef::Null)),
             Column::Tuple(fields) => Some(ScalarRef::Tuple(
                 fields
@@ -744,6 +849,7 @@ impl Column {
                     .collect::<Option<Vec<_>>>()?,
             )),
             Column::Variant(col) => Some(ScalarRef::Variant(col.index(index)?)),
+            Column::Constant(col) => (index < col.len).then(|| col.scalar.as_ref()),
         }
     }
 
@@ -761,9 +867,11 @@ impl Column {
             Column::String(col) => ScalarRef::String(col.index_unchecked(index)),
             Column::Timestamp(col) => ScalarRef::Timestamp(*col.get_unchecked(index)),
             Column::Date(col) => ScalarRef::Date(*col.get_unchecked(index)),
+            Column::Interval(col) => ScalarRef::Interval(*col.get_unchecked(index)),
             Column::Array(col) => ScalarRef::Array(col.index_unchecked(index)),
             Column::Map(col) => ScalarRef::Map(col.index_unchecked(index)),
             Column::Bitmap(col) => ScalarRef::Bitmap(col.index_unchecked(index)),
+            Column::Geometry(col) => ScalarRef::Geometry(col.index_unchecked(index)),
             Column::Nullable(col) => col.index_unchecked(index).unwrap_or(ScalarRef::Null),
             Column::Tuple(fields) => ScalarRef::Tuple(
                 fields
@@ -772,6 +880,7 @@ impl Column {
                     .collect::<Vec<_>>(),
             ),
             Column::Variant(col) => ScalarRef::Variant(col.index_unchecked(index)),
+            Column::Constant(col) => col.scalar.as_ref(),
         }
     }
 
@@ -810,9 +919,13 @@ impl Column {
             Column::Date(col) => {
                 Column::Date(col.clone().sliced(range.start, range.end - range.start))
             }
+            Column::Interval(col) => {
+                Column::Interval(col.clone().sliced(range.start, range.end - range.start))
+            }
             Column::Array(col) => Column::Array(Box::new(col.slice(range))),
             Column::Map(col) => Column::Map(Box::new(col.slice(range))),
             Column::Bitmap(col) => Column::Bitmap(col.slice(range)),
+            Column::Geometry(col) => Column::Geometry(col.slice(range)),
             Column::Nullable(col) => Column::Nullable(Box::new(col.slice(range))),
             Column::Tuple(fields) => Column::Tuple(
                 fields
@@ -821,6 +934,7 @@ impl Column {
                     .collect(),
             ),
             Column::Variant(col) => Column::Variant(col.slice(range)),
+            Column::Constant(col) => col.with_len(range.end - range.start),
         }
     }
 
@@ -870,6 +984,13 @@ impl Column {
                     max: *max,
                 })
             }
+            Column::Interval(col) => {
+                let (min, max) = col.iter().minmax().into_option().unwrap();
+                Domain::Interval(SimpleDomain {
+                    min: *min,
+                    max: *max,
+                })
+            }
             Column::Array(col) => {
                 if col.len() == 0 || col.values.len() == 0 {
                     Domain::Array(None)
@@ -903,7 +1024,8 @@ impl Column {
                 let domains = fields.iter().map(|col| col.domain()).collect::<Vec<_>>();
                 Domain::Tuple(domains)
             }
-            Column::Bitmap(_) | Column::Variant(_) => Domain::Undefined,
+            Column::Bitmap(_) | Column::Variant(_) | Column::Geometry(_) => Domain::Undefined,
+            Column::Constant(col) => col.scalar.as_ref().domain(&col.data_type),
         }
     }
 
@@ -923,6 +1045,7 @@ impl Column {
             Column::String(_) => DataType::String,
             Column::Timestamp(_) => DataType::Timestamp,
             Column::Date(_) => DataType::Date,
+            Column::Interval(_) => DataType::Interval,
             Column::Array(array) => {
                 let inner = array.values.data_type();
                 DataType::Array(Box::new(inner))
@@ -932,6 +1055,7 @@ impl Column {
                 DataType::Map(Box::new(inner))
             }
             Column::Bitmap(_) => DataType::Bitmap,
+            Column::Geometry(_) => DataType::Geometry,
             Column::Nullable(inner) => {
                 let inner = inner.column.data_type();
                 inner.wrap_nullable()
@@ -941,12 +1065,28 @@ impl Column {
                 DataType::Tuple(inner)
             }
             Column::Variant(_) => DataType::Variant,
+            Column::Constant(col) => col.data_type.clone(),
+        }
+    }
+
+    /// Expands a constant column into the concrete layout of its data type, other columns
+    /// are returned as is.
+    pub fn materialize(&self) -> Column {
+        match self {
+            Column::Constant(col) => {
+                ColumnBuilder::repeat(&col.scalar.as_ref(), col.len, &col.data_type).build()
+            }
+            _ => self.clone(),
         }
     }
 
     pub fn arrow_field(&self) -> ArrowField {
         let dummy = "DUMMY".to_string();
-        let is_nullable = matches!(&self, Column::Nullable(_));
+        let is_nullable = match &self {
+            Column::Nullable(_) => true,
+            Column::Constant(col) => col.data_type.is_nullable(),
+            _ => false,
+        };
         let arrow_type: ArrowDataType = (&self.data_type()).into();
         ArrowField::new(dummy, arrow_type, is_nullable)
     }
@@ -1047,6 +1187,24 @@ impl Column {
                     .unwrap(),
                 )
             }
+            Column::Number(NumberColumn::UInt128(col)) => {
+                let values =
+                    unsafe { std::mem::transmute::<Buffer<u128>, Buffer<i128>>(col.clone()) };
+                Box::new(
+                    common_arrow::arrow::array::PrimitiveArray::<i128>::try_new(
+                        arrow_type, values, None,
+                    )
+                    .unwrap(),
+                )
+            }
+            Column::Number(NumberColumn::Int128(col)) => Box::new(
+                common_arrow::arrow::array::PrimitiveArray::<i128>::try_new(
+                    arrow_type,
+                    col.clone(),
+                    None,
+                )
+                .unwrap(),
+            ),
             Column::Decimal(DecimalColumn::Decimal128(col, _)) => Box::new(
                 common_arrow::arrow::array::PrimitiveArray::<i128>::try_new(
                     arrow_type,
@@ -1100,6 +1258,15 @@ impl Column {
                 )
                 .unwrap(),
             ),
+            Column::Interval(col) => {
+                let values: Buffer<months_days_ns> = col.iter().map(|v| (*v).into()).collect();
+                Box::new(
+                    common_arrow::arrow::array::PrimitiveArray::<months_days_ns>::try_new(
+                        arrow_type, values, None,
+                    )
+                    .unwrap(),
+                )
+            }
             Column::Array(col) => {
                 let offsets: Buffer<i64> =
                     col.offsets.iter().map(|offset| *offset as i64).collect();
@@ -1140,7 +1307,7 @@ impl Column {
                     .unwrap(),
                 )
             }
-            Column::Bitmap(col) => {
+            Column::Bitmap(col) | Column::Geometry(col) => {
                 let offsets: Buffer<i64> =
                     col.offsets().iter().map(|offset| *offset as i64).collect();
                 Box::new(
@@ -1178,6 +1345,7 @@ impl Column {
                     .unwrap(),
                 )
             }
+            Column::Constant(_) => self.materialize().as_arrow(),
         }
     }
 
@@ -1225,6 +1393,7 @@ impl Column {
             Column::String(x) => x.check_valid(),
             Column::Variant(x) => x.check_valid(),
             Column::Bitmap(x) => x.check_valid(),
+            Column::Geometry(x) => x.check_valid(),
             Column::Map(x) => {
                 for y in x.iter() {
                     y.check_valid()?;
@@ -1392,6 +1561,9 @@ impl Column {
                         DataType::Bitmap => {
                             Column::Bitmap(StringColumn::new(arrow_col.values().clone(), offsets))
                         }
+                        DataType::Geometry => {
+                            Column::Geometry(StringColumn::new(arrow_col.values().clone(), offsets))
+                        }
                         _ => Column::String(StringColumn::new(arrow_col.values().clone(), offsets)),
                     }
                 }
@@ -1417,6 +1589,10 @@ impl Column {
                             arrow_col.values().clone(),
                             offsets.into(),
                         )),
+                        DataType::Geometry => Column::Geometry(StringColumn::new(
+                            arrow_col.values().clone(),
+                            offsets.into(),
+                        )),
                         _ => Column::String(StringColumn::new(
                             arrow_col.values().clone(),
                             offsets.into(),
@@ -1508,6 +1684,18 @@ impl Column {
                         .values()
                         .clone(),
                 ),
+                ArrowDataType::Interval(IntervalUnit::MonthDayNano) => Column::Interval(
+                    arrow_col
+                        .as_any()
+                        .downcast_ref::<common_arrow::arrow::array::PrimitiveArray<months_days_ns>>()
+                        .expect(
+                            "fail to read from arrow: array should be `PrimitiveArray<months_days_ns>`",
+                        )
+                        .values()
+                        .iter()
+                        .map(|v| (*v).into())
+                        .collect(),
+                ),
                 ArrowDataType::Extension(name, box ty, None) if name == ARROW_EXT_TYPE_VARIANT => {
                     match ty {
                         ArrowDataType::LargeBinary => {
@@ -1633,13 +1821,27 @@ impl Column {
                         .as_any()
                         .downcast_ref::<common_arrow::arrow::array::PrimitiveArray<i128>>()
                         .expect("fail to read from arrow: array should be `DecimalArray`");
-                    Column::Decimal(DecimalColumn::Decimal128(
-                        arrow_col.values().clone(),
-                        DecimalSize {
-                            precision: *precision as u8,
-                            scale: *scale as u8,
-                        },
-                    ))
+                    // Decimal may be Extension data type int128 and uint128
+                    match data_type {
+                        DataType::Number(NumberDataType::Int128) => {
+                            Column::Number(NumberColumn::Int128(arrow_col.values().clone()))
+                        }
+                        DataType::Number(NumberDataType::UInt128) => {
+                            let values = unsafe {
+                                std::mem::transmute::<Buffer<i128>, Buffer<u128>>(
+                                    arrow_col.values().clone(),
+                                )
+                            };
+                            Column::Number(NumberColumn::UInt128(values))
+                        }
+                        _ => Column::Decimal(DecimalColumn::Decimal128(
+                            arrow_col.values().clone(),
+                            DecimalSize {
+                                precision: *precision as u8,
+                                scale: *scale as u8,
+                            },
+                        )),
+                    }
                 }
                 ArrowDataType::Decimal256(precision, scale) => {
                     let arrow_col =
@@ -1777,6 +1979,14 @@ impl Column {
                     .map(|_| SmallRng::from_entropy().gen_range(DATE_MIN..=DATE_MAX))
                     .collect::<Vec<i32>>(),
             ),
+            DataType::Interval => IntervalType::from_data(
+                (0..len)
+                    .map(|_| {
+                        let mut rng = SmallRng::from_entropy();
+                        months_days_micros::new(rng.gen(), rng.gen(), rng.gen())
+                    })
+                    .collect::<Vec<months_days_micros>>(),
+            ),
             DataType::Nullable(ty) => Column::Nullable(Box::new(NullableColumn {
                 column: Column::random(ty, len),
                 validity: Bitmap::from(
@@ -1823,6 +2033,18 @@ impl Column {
                     })
                     .collect_vec(),
             ),
+            DataType::Geometry => GeometryType::from_data(
+                (0..len)
+                    .map(|_| {
+                        // A random point in little endian WKB.
+                        let (x, y): (f64, f64) = SmallRng::from_entropy().gen();
+                        let mut buf = vec![1, 1, 0, 0, 0];
+                        buf.extend_from_slice(&x.to_le_bytes());
+                        buf.extend_from_slice(&y.to_le_bytes());
+                        buf
+                    })
+                    .collect_vec(),
+            ),
             DataType::Tuple(fields) => {
                 let fields = fields
                     .iter()
@@ -1845,6 +2067,9 @@ impl Column {
     pub fn remove_nullable(&self) -> Self {
         match self {
             Column::Nullable(inner) => inner.column.clone(),
+            Column::Constant(col) if col.data_type.is_nullable() => {
+                self.materialize().remove_nullable()
+            }
             _ => self.clone(),
         }
     }
@@ -1852,6 +2077,10 @@ impl Column {
     pub fn wrap_nullable(self, validity: Option<Bitmap>) -> Self {
         match self {
             c @ Column::Null { .. } => c,
+            Column::Constant(col) if validity.is_none() => {
+                ConstantColumn::new(col.scalar, col.data_type.wrap_nullable(), col.len)
+            }
+            Column::Constant(_) => self.materialize().wrap_nullable(validity),
             Column::Nullable(null_column) => {
                 let validity = match validity {
                     Some(v) => &v & (&null_column.validity),
@@ -1887,18 +2116,23 @@ impl Column {
             Column::Number(NumberColumn::Int16(col)) => col.len() * 2,
             Column::Number(NumberColumn::Int32(col)) => col.len() * 4,
             Column::Number(NumberColumn::Int64(col)) => col.len() * 8,
+            Column::Number(NumberColumn::UInt128(col)) => col.len() * 16,
+            Column::Number(NumberColumn::Int128(col)) => col.len() * 16,
             Column::Decimal(DecimalColumn::Decimal128(col, _)) => col.len() * 16,
             Column::Decimal(DecimalColumn::Decimal256(col, _)) => col.len() * 32,
             Column::Boolean(c) => c.as_slice().0.len(),
             Column::String(col) => col.memory_size(),
             Column::Timestamp(col) => col.len() * 8,
             Column::Date(col) => col.len() * 4,
+            Column::Interval(col) => col.len() * 16,
             Column::Array(col) => col.values.memory_size() + col.offsets.len() * 8,
             Column::Map(col) => col.values.memory_size() + col.offsets.len() * 8,
             Column::Bitmap(col) => col.memory_size(),
+            Column::Geometry(col) => col.memory_size(),
             Column::Nullable(c) => c.column.memory_size() + c.validity.as_slice().0.len(),
             Column::Tuple(fields) => fields.iter().map(|f| f.memory_size()).sum(),
             Column::Variant(col) => col.memory_size(),
+            Column::Constant(col) => col.row.memory_size(),
         }
     }
 
@@ -1915,13 +2149,20 @@ impl Column {
             Column::Number(NumberColumn::Int16(col)) => col.len() * 2,
             Column::Number(NumberColumn::Int32(col)) | Column::Date(col) => col.len() * 4,
             Column::Number(NumberColumn::Int64(col)) | Column::Timestamp(col) => col.len() * 8,
+            Column::Number(NumberColumn::UInt128(col)) => col.len() * 16,
+            Column::Number(NumberColumn::Int128(col)) => col.len() * 16,
+            Column::Interval(col) => col.len() * 16,
             Column::Decimal(DecimalColumn::Decimal128(col, _)) => col.len() * 16,
             Column::Decimal(DecimalColumn::Decimal256(col, _)) => col.len() * 32,
             Column::Boolean(c) => c.len(),
-            Column::String(col) | Column::Bitmap(col) | Column::Variant(col) => col.memory_size(),
+            Column::String(col)
+            | Column::Bitmap(col)
+            | Column::Variant(col)
+            | Column::Geometry(col) => col.memory_size(),
             Column::Array(col) | Column::Map(col) => col.values.serialize_size() + col.len() * 8,
             Column::Nullable(c) => c.column.serialize_size() + c.len(),
             Column::Tuple(fields) => fields.iter().map(|f| f.serialize_size()).sum(),
+            Column::Constant(col) => col.row.serialize_size() * col.len,
         }
     }
 
@@ -1929,6 +2170,7 @@ impl Column {
     pub fn validity(&self) -> (bool, Option<&Bitmap>) {
         match self {
             Column::Null { .. } => (true, None),
+            Column::Constant(col) if col.scalar.is_null() => (true, None),
             Column::Nullable(c) => {
                 if c.validity.unset_bits() == c.validity.len() {
                     (true, Some(&c.validity))
@@ -1947,8 +2189,12 @@ impl Serialize for Column {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where S: Serializer {
         let bytes = serialize_column(self);
-        let base64_str = general_purpose::STANDARD.encode(bytes);
-        serializer.serialize_str(&base64_str)
+        if serializer.is_human_readable() {
+            let base64_str = general_purpose::STANDARD.encode(bytes);
+            serializer.serialize_str(&base64_str)
+        } else {
+            serializer.serialize_bytes(&bytes)
+        }
     }
 }
 
@@ -1961,19 +2207,35 @@ impl<'de> Deserialize<'de> for Column {
             type Value = Column;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-                formatter.write_str("an arrow chunk with exactly one column")
+                formatter.write_str("a serialized column frame")
             }
 
             fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
             where E: serde::de::Error {
-                let bytes = general_purpose::STANDARD.decode(v).unwrap();
-                let column = deserialize_column(&bytes)
-                    .expect("expecting an arrow chunk with exactly one column");
-                Ok(column)
+                let bytes = general_purpose::STANDARD
+                    .decode(v)
+                    .map_err(serde::de::Error::custom)?;
+                deserialize_column(&bytes)
+                    .ok_or_else(|| serde::de::Error::custom("invalid serialized column"))
+            }
+
+            fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+            where E: serde::de::Error {
+                if is_column_frame(v) {
+                    return deserialize_column(v)
+                        .ok_or_else(|| serde::de::Error::custom("invalid serialized column"));
+                }
+                // Columns used to be serialized as a base64 string in all formats.
+                let v = std::str::from_utf8(v).map_err(serde::de::Error::custom)?;
+                self.visit_str(v)
             }
         }
 
-        deserializer.deserialize_string(ColumnVisitor)
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_string(ColumnVisitor)
+        } else {
+            deserializer.deserialize_byte_buf(ColumnVisitor)
+        }
     }
 }
 
@@ -1991,6 +2253,7 @@ impl ColumnBuilder {
             Column::String(col) => ColumnBuilder::String(StringColumnBuilder::from_column(col)),
             Column::Timestamp(col) => ColumnBuilder::Timestamp(buffer_into_mut(col)),
             Column::Date(col) => ColumnBuilder::Date(buffer_into_mut(col)),
+            Column::Interval(col) => ColumnBuilder::Interval(buffer_into_mut(col)),
             Column::Array(box col) => {
                 ColumnBuilder::Array(Box::new(ArrayColumnBuilder::from_column(col)))
             }
@@ -1998,6 +2261,7 @@ impl ColumnBuilder {
                 ColumnBuilder::Map(Box::new(ArrayColumnBuilder::from_column(col)))
             }
             Column::Bitmap(col) => ColumnBuilder::Bitmap(StringColumnBuilder::from_column(col)),
+            Column::Geometry(col) => ColumnBuilder::Geometry(StringColumnBuilder::from_column(col)),
             Column::Nullable(box col) => {
                 ColumnBuilder::Nullable(Box::new(NullableColumnBuilder::from_column(col)))
             }
@@ -2008,6 +2272,9 @@ impl ColumnBuilder {
                     .collect(),
             ),
             Column::Variant(col) => ColumnBuilder::Variant(StringColumnBuilder::from_column(col)),
+            Column::Constant(col) => {
+                ColumnBuilder::repeat(&col.scalar.as_ref(), col.len, &col.data_type)
+            }
         }
     }
 
@@ -2050,11 +2317,13 @@ impl ColumnBuilder {
             ScalarRef::String(s) => ColumnBuilder::String(StringColumnBuilder::repeat(s, n)),
             ScalarRef::Timestamp(d) => ColumnBuilder::Timestamp(vec![*d; n]),
             ScalarRef::Date(d) => ColumnBuilder::Date(vec![*d; n]),
+            ScalarRef::Interval(i) => ColumnBuilder::Interval(vec![*i; n]),
             ScalarRef::Array(col) => {
                 ColumnBuilder::Array(Box::new(ArrayColumnBuilder::repeat(col, n)))
             }
             ScalarRef::Map(col) => ColumnBuilder::Map(Box::new(ArrayColumnBuilder::repeat(col, n))),
             ScalarRef::Bitmap(b) => ColumnBuilder::Bitmap(StringColumnBuilder::repeat(b, n)),
+            ScalarRef::Geometry(b) => ColumnBuilder::Geometry(StringColumnBuilder::repeat(b, n)),
             ScalarRef::Tuple(fields) => {
                 let fields_ty = match data_type {
                     DataType::Tuple(fields_ty) => fields_ty,
@@ -2083,9 +2352,11 @@ impl ColumnBuilder {
             ColumnBuilder::String(builder) => builder.len(),
             ColumnBuilder::Timestamp(builder) => builder.len(),
             ColumnBuilder::Date(builder) => builder.len(),
+            ColumnBuilder::Interval(builder) => builder.len(),
             ColumnBuilder::Array(builder) => builder.len(),
             ColumnBuilder::Map(builder) => builder.len(),
             ColumnBuilder::Bitmap(builder) => builder.len(),
+            ColumnBuilder::Geometry(builder) => builder.len(),
             ColumnBuilder::Nullable(builder) => builder.len(),
             ColumnBuilder::Tuple(fields) => fields[0].len(),
             ColumnBuilder::Variant(builder) => builder.len(),
@@ -2107,6 +2378,8 @@ impl ColumnBuilder {
             ColumnBuilder::Number(NumberColumnBuilder::Int16(builder)) => builder.len() * 2,
             ColumnBuilder::Number(NumberColumnBuilder::Int32(builder)) => builder.len() * 4,
             ColumnBuilder::Number(NumberColumnBuilder::Int64(builder)) => builder.len() * 8,
+            ColumnBuilder::Number(NumberColumnBuilder::UInt128(builder)) => builder.len() * 16,
+            ColumnBuilder::Number(NumberColumnBuilder::Int128(builder)) => builder.len() * 16,
             ColumnBuilder::Decimal(DecimalColumnBuilder::Decimal128(builder, _)) => {
                 builder.len() * 16
             }
@@ -2117,9 +2390,11 @@ impl ColumnBuilder {
             ColumnBuilder::String(col) => col.data.len() + col.offsets.len() * 8,
             ColumnBuilder::Timestamp(col) => col.len() * 8,
             ColumnBuilder::Date(col) => col.len() * 4,
+            ColumnBuilder::Interval(col) => col.len() * 16,
             ColumnBuilder::Array(col) => col.builder.memory_size() + col.offsets.len() * 8,
             ColumnBuilder::Map(col) => col.builder.memory_size() + col.offsets.len() * 8,
             ColumnBuilder::Bitmap(col) => col.data.len() + col.offsets.len() * 8,
+            ColumnBuilder::Geometry(col) => col.data.len() + col.offsets.len() * 8,
             ColumnBuilder::Nullable(c) => c.builder.memory_size() + c.validity.as_slice().len(),
             ColumnBuilder::Tuple(fields) => fields.iter().map(|f| f.memory_size()).sum(),
             ColumnBuilder::Variant(col) => col.data.len() + col.offsets.len() * 8,
@@ -2142,6 +2417,7 @@ impl ColumnBuilder {
             ColumnBuilder::String(_) => DataType::String,
             ColumnBuilder::Timestamp(_) => DataType::Timestamp,
             ColumnBuilder::Date(_) => DataType::Date,
+            ColumnBuilder::Interval(_) => DataType::Interval,
             ColumnBuilder::Array(col) => {
                 let inner = col.builder.data_type();
                 DataType::Array(Box::new(inner))
@@ -2151,6 +2427,7 @@ impl ColumnBuilder {
                 DataType::Map(Box::new(inner))
             }
             ColumnBuilder::Bitmap(_) => DataType::Bitmap,
+            ColumnBuilder::Geometry(_) => DataType::Geometry,
             ColumnBuilder::Nullable(col) => DataType::Nullable(Box::new(col.builder.data_type())),
             ColumnBuilder::Tuple(fields) => {
                 DataType::Tuple(fields.iter().map(|f| f.data_type()).collect::<Vec<_>>())
@@ -2185,8 +2462,9 @@ impl ColumnBuilder {
                 let data_capacity = if enable_datasize_hint { 0 } else { capacity };
                 ColumnBuilder::String(StringColumnBuilder::with_capacity(capacity, data_capacity))
             }
-            DataType::Timestamp => ColumnBuilder::Timestamp(Vec::with_capacity(capacity)),
-            DataType::Date => ColumnBuilder::Date(Vec::with_capacity(capacity)),
+            DataType::Timestamp => ColumnBuilder::Timestamp(arena_vec(capacity)),
+            DataType::Date => ColumnBuilder::Date(arena_vec(capacity)),
+            DataType::Interval => ColumnBuilder::Interval(arena_vec(capacity)),
             DataType::Nullable(ty) => ColumnBuilder::Nullable(Box::new(NullableColumnBuilder {
                 builder: Self::with_capacity_hint(ty, capacity, enable_datasize_hint),
                 validity: MutableBitmap::with_capacity(capacity),
@@ -2222,6 +2500,10 @@ impl ColumnBuilder {
                 let data_capacity = if enable_datasize_hint { 0 } else { capacity };
                 ColumnBuilder::Bitmap(StringColumnBuilder::with_capacity(capacity, data_capacity))
             }
+            DataType::Geometry => {
+                let data_capacity = if enable_datasize_hint { 0 } else { capacity };
+                ColumnBuilder::Geometry(StringColumnBuilder::with_capacity(capacity, data_capacity))
+            }
             DataType::Variant => {
                 let data_capacity = if enable_datasize_hint { 0 } else { capacity };
                 ColumnBuilder::Variant(StringColumnBuilder::with_capacity(capacity, data_capacity))
@@ -2248,6 +2530,7 @@ impl ColumnBuilder {
                 builder.push(value);
             }
             (ColumnBuilder::Date(builder), ScalarRef::Date(value)) => builder.push(value),
+            (ColumnBuilder::Interval(builder), ScalarRef::Interval(value)) => builder.push(value),
             (ColumnBuilder::Array(builder), ScalarRef::Array(value)) => {
                 builder.push(value);
             }
@@ -2258,6 +2541,10 @@ impl ColumnBuilder {
                 builder.put_slice(value);
                 builder.commit_row();
             }
+            (ColumnBuilder::Geometry(builder), ScalarRef::Geometry(value)) => {
+                builder.put_slice(value);
+                builder.commit_row();
+            }
             (ColumnBuilder::Nullable(builder), ScalarRef::Null) => {
                 builder.push_null();
             }
@@ -2289,9 +2576,14 @@ impl ColumnBuilder {
             ColumnBuilder::String(builder) => builder.commit_row(),
             ColumnBuilder::Timestamp(builder) => builder.push(0),
             ColumnBuilder::Date(builder) => builder.push(0),
+            ColumnBuilder::Interval(builder) => builder.push(months_days_micros::default()),
             ColumnBuilder::Array(builder) => builder.push_default(),
             ColumnBuilder::Map(builder) => builder.push_default(),
             ColumnBuilder::Bitmap(builder) => builder.commit_row(),
+            ColumnBuilder::Geometry(builder) => {
+                builder.put_slice(&DEFAULT_GEOMETRY);
+                builder.commit_row();
+            }
             ColumnBuilder::Nullable(builder) => builder.push_null(),
             ColumnBuilder::Tuple(fields) => {
                 for field in fields {
@@ -2328,7 +2620,8 @@ impl ColumnBuilder {
             }
             ColumnBuilder::String(builder)
             | ColumnBuilder::Variant(builder)
-            | ColumnBuilder::Bitmap(builder) => {
+            | ColumnBuilder::Bitmap(builder)
+            | ColumnBuilder::Geometry(builder) => {
                 let offset = reader.read_scalar::<u64>()? as usize;
                 builder.data.resize(offset + builder.data.len(), 0);
                 let last = *builder.offsets.last().unwrap() as usize;
@@ -2344,6 +2637,12 @@ impl ColumnBuilder {
                 let value: i32 = reader.read_scalar()?;
                 builder.push(value);
             }
+            ColumnBuilder::Interval(builder) => {
+                let months: i32 = reader.read_scalar()?;
+                let days: i32 = reader.read_scalar()?;
+                let micros: i64 = reader.read_scalar()?;
+                builder.push(months_days_micros::new(months, days, micros));
+            }
             ColumnBuilder::Array(builder) => {
                 let len = reader.read_scalar::<u64>()?;
                 for _ in 0..len {
@@ -2414,7 +2713,8 @@ impl ColumnBuilder {
             }
             ColumnBuilder::String(builder)
             | ColumnBuilder::Variant(builder)
-            | ColumnBuilder::Bitmap(builder) => {
+            | ColumnBuilder::Bitmap(builder)
+            | ColumnBuilder::Geometry(builder) => {
                 for row in 0..rows {
                     let reader = &reader[step * row..];
                     builder.put_slice(reader);
@@ -2437,6 +2737,15 @@ impl ColumnBuilder {
                     builder.push(value);
                 }
             }
+            ColumnBuilder::Interval(builder) => {
+                for row in 0..rows {
+                    let mut reader = &reader[step * row..];
+                    let months: i32 = reader.read_scalar()?;
+                    let days: i32 = reader.read_scalar()?;
+                    let micros: i64 = reader.read_scalar()?;
+                    builder.push(months_days_micros::new(months, days, micros));
+                }
+            }
             ColumnBuilder::Array(builder) => {
                 for row in 0..rows {
                     let mut reader = &reader[step * row..];
@@ -2509,9 +2818,11 @@ impl ColumnBuilder {
             ColumnBuilder::String(builder) => builder.pop().map(Scalar::String),
             ColumnBuilder::Timestamp(builder) => builder.pop().map(Scalar::Timestamp),
             ColumnBuilder::Date(builder) => builder.pop().map(Scalar::Date),
+            ColumnBuilder::Interval(builder) => builder.pop().map(Scalar::Interval),
             ColumnBuilder::Array(builder) => builder.pop().map(Scalar::Array),
             ColumnBuilder::Map(builder) => builder.pop().map(Scalar::Map),
             ColumnBuilder::Bitmap(builder) => builder.pop().map(Scalar::Bitmap),
+            ColumnBuilder::Geometry(builder) => builder.pop().map(Scalar::Geometry),
             ColumnBuilder::Nullable(builder) => Some(builder.pop()?.unwrap_or(Scalar::Null)),
             ColumnBuilder::Tuple(fields) => {
                 if fields[0].len() > 0 {
@@ -2529,6 +2840,34 @@ impl ColumnBuilder {
         }
     }
 
+    /// Shortens the builder to its first `len` rows, does nothing if it has less rows.
+    pub fn truncate(&mut self, len: usize) {
+        match self {
+            ColumnBuilder::Null { len: builder_len }
+            | ColumnBuilder::EmptyArray { len: builder_len }
+            | ColumnBuilder::EmptyMap { len: builder_len } => {
+                *builder_len = (*builder_len).min(len);
+            }
+            ColumnBuilder::Number(builder) => builder.truncate(len),
+            ColumnBuilder::Decimal(builder) => builder.truncate(len),
+            ColumnBuilder::Boolean(builder) => truncate_bitmap(builder, len),
+            ColumnBuilder::String(builder)
+            | ColumnBuilder::Bitmap(builder)
+            | ColumnBuilder::Variant(builder)
+            | ColumnBuilder::Geometry(builder) => builder.truncate(len),
+            ColumnBuilder::Timestamp(builder) => builder.truncate(len),
+            ColumnBuilder::Date(builder) => builder.truncate(len),
+            ColumnBuilder::Interval(builder) => builder.truncate(len),
+            ColumnBuilder::Array(builder) | ColumnBuilder::Map(builder) => builder.truncate(len),
+            ColumnBuilder::Nullable(builder) => builder.truncate(len),
+            ColumnBuilder::Tuple(fields) => {
+                for field in fields {
+                    field.truncate(len);
+                }
+            }
+        }
+    }
+
     pub fn append_column(&mut self, other: &Column) {
         match (self, other) {
             (ColumnBuilder::Null { len }, Column::Null { len: other_len }) => {
@@ -2561,6 +2900,9 @@ impl ColumnBuilder {
             (ColumnBuilder::Date(builder), Column::Date(other)) => {
                 builder.extend_from_slice(other);
             }
+            (ColumnBuilder::Interval(builder), Column::Interval(other)) => {
+                builder.extend_from_slice(other);
+            }
             (ColumnBuilder::Array(builder), Column::Array(other)) => {
                 builder.append_column(other.as_ref());
             }
@@ -2570,6 +2912,9 @@ impl ColumnBuilder {
             (ColumnBuilder::Bitmap(builder), Column::Bitmap(other)) => {
                 builder.append_column(other);
             }
+            (ColumnBuilder::Geometry(builder), Column::Geometry(other)) => {
+                builder.append_column(other);
+            }
             (ColumnBuilder::Nullable(builder), Column::Nullable(other)) => {
                 builder.append_column(other);
             }
@@ -2579,6 +2924,11 @@ impl ColumnBuilder {
                     field.append_column(other_field);
                 }
             }
+            (this, Column::Constant(col)) => {
+                for _ in 0..col.len {
+                    this.push(col.scalar.as_ref());
+                }
+            }
             (this, other) => unreachable!(
                 "unable append column(data type: {:?}) into builder(data type: {:?})",
                 other.data_type(),
@@ -2598,9 +2948,11 @@ impl ColumnBuilder {
             ColumnBuilder::String(builder) => Column::String(builder.build()),
             ColumnBuilder::Timestamp(builder) => Column::Timestamp(builder.into()),
             ColumnBuilder::Date(builder) => Column::Date(builder.into()),
+            ColumnBuilder::Interval(builder) => Column::Interval(builder.into()),
             ColumnBuilder::Array(builder) => Column::Array(Box::new(builder.build())),
             ColumnBuilder::Map(builder) => Column::Map(Box::new(builder.build())),
             ColumnBuilder::Bitmap(builder) => Column::Bitmap(builder.build()),
+            ColumnBuilder::Geometry(builder) => Column::Geometry(builder.build()),
             ColumnBuilder::Nullable(builder) => Column::Nullable(Box::new(builder.build())),
             ColumnBuilder::Tuple(fields) => {
                 assert!(fields.iter().map(|field| field.len()).all_equal());
@@ -2622,9 +2974,11 @@ impl ColumnBuilder {
             ColumnBuilder::String(builder) => Scalar::String(builder.build_scalar()),
             ColumnBuilder::Timestamp(builder) => Scalar::Timestamp(builder[0]),
             ColumnBuilder::Date(builder) => Scalar::Date(builder[0]),
+            ColumnBuilder::Interval(builder) => Scalar::Interval(builder[0]),
             ColumnBuilder::Array(builder) => Scalar::Array(builder.build_scalar()),
             ColumnBuilder::Map(builder) => Scalar::Map(builder.build_scalar()),
             ColumnBuilder::Bitmap(builder) => Scalar::Bitmap(builder.build_scalar()),
+            ColumnBuilder::Geometry(builder) => Scalar::Geometry(builder.build_scalar()),
             ColumnBuilder::Nullable(builder) => builder.build_scalar().unwrap_or(Scalar::Null),
             ColumnBuilder::Tuple(fields) => Scalar::Tuple(
                 fields
@@ -2677,6 +3031,8 @@ macro_rules! for_all_number_varints {
             { u16, UInt16 },
             { u32, UInt32 },
             { u64, UInt64 },
+            { i128, Int128 },
+            { u128, UInt128 },
             { f32, Float32 },
             { f64, Float64 },
             { F32, Float32 },
@@ -1,12 +1,17 @@
+use common_arrow::arrow::array::PrimitiveArray;
 use common_expression::block_debug::box_render;
 use common_expression::types::string::StringColumnBuilder;
 use common_expression::types::DataType;
 use common_expression::types::Int32Type;
 use common_expression::types::NumberDataType;
+use common_expression::types::TimestampType;
 use common_expression::Column;
 use common_expression::DataField;
 use common_expression::DataSchemaRefExt;
 use common_expression::FromData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
 
 use crate::common::new_block;
 
@@ -56,3 +61,54 @@ fn test_box_render_block() {
 └────────────────────┘"#;
     assert_eq!(d, expected);
 }
+
+#[test]
+fn test_block_to_arrow_chunk_with_timestamp_precision() {
+    let values = vec![1_500_000, -1, 0];
+    let block = new_block(&[
+        TimestampType::from_data(values.clone()),
+        TimestampType::from_data(values.clone()),
+        TimestampType::from_data(values.clone()),
+        TimestampType::from_data(values.clone()),
+    ]);
+    let schema = TableSchema::new(vec![
+        TableField::new("s", TableDataType::TimestampWithPrecision(0)),
+        TableField::new("ms", TableDataType::TimestampWithPrecision(3)),
+        TableField::new("us", TableDataType::Timestamp),
+        TableField::new("ns", TableDataType::TimestampWithPrecision(9)),
+    ]);
+    let arrow_schema = schema.to_arrow();
+    let chunk = block.to_arrow_chunk(&arrow_schema).unwrap();
+
+    let expected: [Vec<i64>; 4] = [
+        vec![1, -1, 0],
+        vec![1_500, -1, 0],
+        vec![1_500_000, -1, 0],
+        vec![1_500_000_000, -1_000, 0],
+    ];
+    for ((array, field), expected) in chunk
+        .arrays()
+        .iter()
+        .zip(&arrow_schema.fields)
+        .zip(expected)
+    {
+        assert_eq!(array.data_type(), field.data_type());
+        let array = array
+            .as_any()
+            .downcast_ref::<PrimitiveArray<i64>>()
+            .unwrap();
+        assert_eq!(array.values().as_slice(), expected.as_slice());
+    }
+
+    // The values are read back as microseconds, rounded down to the precision.
+    let expected: [Vec<i64>; 4] = [
+        vec![1_000_000, -1_000_000, 0],
+        vec![1_500_000, -1_000, 0],
+        values.clone(),
+        values,
+    ];
+    for (array, expected) in chunk.arrays().iter().zip(expected) {
+        let column = Column::from_arrow(array.as_ref(), &DataType::Timestamp);
+        assert_eq!(column, TimestampType::from_data(expected));
+    }
+}
@@ -14,10 +14,24 @@
 
 use common_arrow::arrow::array::new_empty_array;
 use common_arrow::arrow::datatypes::DataType as ArrowDataType;
+use common_arrow::arrow::datatypes::IntervalUnit;
 use common_exception::Result;
+use common_expression::types::geometry::geometry_to_wkt;
+use common_expression::types::interval::months_days_micros;
+use common_expression::types::BitmapType;
 use common_expression::types::DataType;
+use common_expression::types::GeometryType;
+use common_expression::types::Int128Type;
+use common_expression::types::IntervalType;
 use common_expression::types::NumberDataType;
+use common_expression::types::UInt128Type;
 use common_expression::Column;
+use common_expression::FromData;
+use common_expression::ARROW_EXT_TYPE_BITMAP;
+use common_expression::ARROW_EXT_TYPE_GEOMETRY;
+use common_expression::ARROW_EXT_TYPE_INT128;
+use common_expression::ARROW_EXT_TYPE_UINT128;
+use roaring::RoaringTreemap;
 
 #[test]
 fn test_from_arrow_extension_to_column() -> Result<()> {
@@ -30,3 +44,195 @@ fn test_from_arrow_extension_to_column() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_interval_column() -> Result<()> {
+    let data_type = DataType::Interval;
+    let column = IntervalType::from_data(vec![
+        months_days_micros::new(1, 0, 0),
+        months_days_micros::new(0, 30, 0),
+        months_days_micros::new(0, 1, -1),
+        months_days_micros::new(-1, 2, 3_600_000_000),
+    ]);
+
+    // A month is compared as 30 days, a day as 24 hours.
+    assert_eq!(column.index(0), column.index(1));
+    assert!(column.index(2) < column.index(1));
+    assert!(column.index(3) < column.index(2));
+    assert_eq!(
+        column.index(3).unwrap().to_string(),
+        "'-1 month 2 days 01:00:00'"
+    );
+
+    let arrow_col = column.as_arrow();
+    assert_eq!(
+        arrow_col.data_type(),
+        &ArrowDataType::Interval(IntervalUnit::MonthDayNano)
+    );
+    let column2 = Column::from_arrow(arrow_col.as_ref(), &data_type);
+    for (a, b) in column
+        .as_interval()
+        .unwrap()
+        .iter()
+        .zip(column2.as_interval().unwrap().iter())
+    {
+        assert_eq!((a.months, a.days, a.micros), (b.months, b.days, b.micros));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_bitmap_column() -> Result<()> {
+    let bitmaps = [vec![1, 2, 3], vec![], vec![u64::MAX]]
+        .into_iter()
+        .map(|values| {
+            let rb = RoaringTreemap::from_iter(values);
+            let mut buf = vec![];
+            rb.serialize_into(&mut buf).unwrap();
+            buf
+        })
+        .collect::<Vec<_>>();
+
+    let data_type = DataType::Bitmap;
+    let column = BitmapType::from_data(bitmaps);
+
+    let arrow_col = column.as_arrow();
+    assert_eq!(
+        arrow_col.data_type(),
+        &ArrowDataType::Extension(
+            ARROW_EXT_TYPE_BITMAP.to_string(),
+            Box::new(ArrowDataType::LargeBinary),
+            None
+        )
+    );
+    let column2 = Column::from_arrow(arrow_col.as_ref(), &data_type);
+    assert_eq!(column, column2);
+
+    let bitmap = column2.as_bitmap().unwrap().index(0).unwrap();
+    let rb = RoaringTreemap::deserialize_from(bitmap).unwrap();
+    assert_eq!(rb.iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+
+    Ok(())
+}
+
+#[test]
+fn test_128_bit_integer_column() -> Result<()> {
+    let data_type = DataType::Number(NumberDataType::Int128);
+    let column = Int128Type::from_data(vec![i128::MIN, -1, 0, i128::MAX]);
+
+    let arrow_col = column.as_arrow();
+    assert_eq!(
+        arrow_col.data_type(),
+        &ArrowDataType::Extension(
+            ARROW_EXT_TYPE_INT128.to_string(),
+            Box::new(ArrowDataType::Decimal(38, 0)),
+            None
+        )
+    );
+    let column2 = Column::from_arrow(arrow_col.as_ref(), &data_type);
+    assert_eq!(column, column2);
+
+    let data_type = DataType::Number(NumberDataType::UInt128);
+    let column = UInt128Type::from_data(vec![0, 1, u128::MAX]);
+
+    let arrow_col = column.as_arrow();
+    assert_eq!(
+        arrow_col.data_type(),
+        &ArrowDataType::Extension(
+            ARROW_EXT_TYPE_UINT128.to_string(),
+            Box::new(ArrowDataType::Decimal(38, 0)),
+            None
+        )
+    );
+    let column2 = Column::from_arrow(arrow_col.as_ref(), &data_type);
+    assert_eq!(column, column2);
+
+    Ok(())
+}
+
+fn wkb(ty: u32, srid: Option<u32>, body: &[&[f64]]) -> Vec<u8> {
+    let mut buf = vec![1];
+    buf.extend_from_slice(&ty.to_le_bytes());
+    if let Some(srid) = srid {
+        buf.extend_from_slice(&srid.to_le_bytes());
+    }
+    if (ty & 0x0FFF_FFFF) % 1000 != 1 {
+        buf.extend_from_slice(&(body.len() as u32).to_le_bytes());
+    }
+    for coords in body {
+        for c in coords.iter() {
+            buf.extend_from_slice(&c.to_le_bytes());
+        }
+    }
+    buf
+}
+
+#[test]
+fn test_geometry_column() -> Result<()> {
+    let point = wkb(1, None, &[&[1.0, 2.0]]);
+    let point_z = wkb(1001, None, &[&[1.0, 2.0, 3.0]]);
+    let line = wkb(0x2000_0002, Some(4326), &[&[1.0, 2.0], &[3.5, -4.0]]);
+    let empty_line = wkb(2, None, &[]);
+
+    assert_eq!(geometry_to_wkt(&point)?, "POINT(1 2)");
+    assert_eq!(geometry_to_wkt(&point_z)?, "POINT Z (1 2 3)");
+    assert_eq!(geometry_to_wkt(&line)?, "SRID=4326;LINESTRING(1 2,3.5 -4)");
+    assert_eq!(geometry_to_wkt(&empty_line)?, "LINESTRING EMPTY");
+    assert!(geometry_to_wkt(&point[..10]).is_err());
+    assert!(geometry_to_wkt(&[2, 1, 0, 0, 0]).is_err());
+
+    let data_type = DataType::Geometry;
+    let column = GeometryType::from_data(vec![point, line]);
+    assert_eq!(column.index(0).unwrap().to_string(), "'POINT(1 2)'");
+
+    let arrow_col = column.as_arrow();
+    assert_eq!(
+        arrow_col.data_type(),
+        &ArrowDataType::Extension(
+            ARROW_EXT_TYPE_GEOMETRY.to_string(),
+            Box::new(ArrowDataType::LargeBinary),
+            None
+        )
+    );
+    let column2 = Column::from_arrow(arrow_col.as_ref(), &data_type);
+    assert_eq!(column, column2);
+
+    Ok(())
+}
+
+#[test]
+fn test_column_builder_pop_and_truncate() {
+    use common_expression::ColumnBuilder;
+
+    use crate::get_all_test_data_types;
+
+    for data_type in get_all_test_data_types() {
+        let column = Column::random(&data_type, 20);
+
+        let mut builder = ColumnBuilder::from_column(column.clone());
+        for row in (15..20).rev() {
+            let expected = column.index(row).unwrap().to_owned();
+            assert_eq!(builder.pop(), Some(expected), "{data_type}");
+        }
+        assert_eq!(builder.len(), 15, "{data_type}");
+        assert_eq!(builder.clone().build(), column.slice(0..15), "{data_type}");
+
+        // Truncating to a larger length keeps the builder as is.
+        builder.truncate(20);
+        assert_eq!(builder.len(), 15, "{data_type}");
+
+        builder.truncate(7);
+        assert_eq!(builder.len(), 7, "{data_type}");
+        assert_eq!(builder.clone().build(), column.slice(0..7), "{data_type}");
+
+        // The builder is still usable after being truncated.
+        builder.push(column.index(19).unwrap());
+        let expected = Column::concat(&[column.slice(0..7), column.slice(19..20)]);
+        assert_eq!(builder.build(), expected, "{data_type}");
+
+        builder = ColumnBuilder::from_column(column.clone());
+        builder.truncate(0);
+        assert_eq!(builder.pop(), None, "{data_type}");
+    }
+}
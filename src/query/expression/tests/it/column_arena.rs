@@ -0,0 +1,86 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::Ordering;
+
+use common_expression::types::DataType;
+use common_expression::types::NumberColumn;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::utils::column_arena::arena_vec;
+use common_expression::utils::column_arena::ColumnArenaGuard;
+use common_expression::utils::column_arena::ColumnArenaStats;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::Value;
+
+#[test]
+fn test_column_arena_reuse() {
+    let stats = ColumnArenaStats::create();
+    let _guard = ColumnArenaGuard::enter(stats.clone());
+
+    let ty = DataType::Number(NumberDataType::UInt64);
+    let mut builder = ColumnBuilder::with_capacity(&ty, 1024);
+    for _ in 0..1024 {
+        builder.push_default();
+    }
+    let column = builder.build();
+    let ptr = column.as_number().unwrap().as_u_int64().unwrap().as_ptr();
+
+    let block = DataBlock::new(vec![BlockEntry::new(ty, Value::Column(column))], 1024);
+    block.recycle();
+
+    // The recycled buffer is reused by the next builder of the same type.
+    let buffer = arena_vec::<u64>(1000);
+    assert_eq!(buffer.as_ptr(), ptr);
+    assert!(buffer.is_empty());
+
+    // A much smaller request doesn't take a large buffer.
+    let buffer = arena_vec::<u64>(16);
+    assert_ne!(buffer.as_ptr(), ptr);
+
+    assert_eq!(stats.allocations.load(Ordering::Relaxed), 3);
+    assert_eq!(stats.reused.load(Ordering::Relaxed), 1);
+    assert_eq!(stats.recycled.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn test_column_arena_shared_buffer() {
+    let stats = ColumnArenaStats::create();
+    let _guard = ColumnArenaGuard::enter(stats.clone());
+
+    let column = StringType::from_data(vec!["a", "b", "c"]);
+    let shared = column.clone();
+    let block = DataBlock::new(
+        vec![BlockEntry::new(DataType::String, Value::Column(column))],
+        3,
+    );
+    block.recycle();
+
+    // Buffers still referenced by another column are not recycled.
+    assert_eq!(stats.recycled.load(Ordering::Relaxed), 0);
+    assert_eq!(shared.len(), 3);
+    assert!(matches!(shared, Column::String(_)));
+}
+
+#[test]
+fn test_column_arena_without_guard() {
+    let buffer = arena_vec::<u64>(16);
+    assert!(buffer.capacity() >= 16);
+    let column = Column::Number(NumberColumn::UInt64(buffer.into()));
+    assert_eq!(column.len(), 0);
+}
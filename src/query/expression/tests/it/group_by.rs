@@ -12,8 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
+
 use common_exception::Result;
 use common_expression::types::number::*;
+use common_expression::types::DataType;
 use common_expression::types::NumberDataType;
 use common_expression::types::StringType;
 use common_expression::*;
@@ -64,3 +67,171 @@ fn test_group_by_hash() -> Result<()> {
     ]);
     Ok(())
 }
+
+#[test]
+fn test_group_keys_dictionary() -> Result<()> {
+    let int8 = DataType::Number(NumberDataType::Int8);
+    let uint32 = DataType::Number(NumberDataType::UInt32);
+    let uint64 = DataType::Number(NumberDataType::UInt64);
+
+    assert_eq!(
+        GroupKeysDictionary::encoded_key_types(&[int8.clone(), DataType::String]),
+        Some(vec![int8.clone(), uint32.clone()])
+    );
+    assert_eq!(
+        GroupKeysDictionary::encoded_key_types(&[int8.clone(), DataType::String.wrap_nullable()]),
+        Some(vec![int8.clone(), uint32.wrap_nullable()])
+    );
+    // A single string key is already hashed by its value.
+    assert_eq!(
+        GroupKeysDictionary::encoded_key_types(&[DataType::String]),
+        None
+    );
+    // Without string keys there is nothing to encode.
+    assert_eq!(
+        GroupKeysDictionary::encoded_key_types(&[int8.clone(), uint64.clone()]),
+        None
+    );
+    // The packed key would be wider than 32 bytes.
+    assert_eq!(
+        GroupKeysDictionary::encoded_key_types(&[
+            DataType::String,
+            uint64.clone(),
+            uint64.clone(),
+            uint64.clone(),
+            uint64
+        ]),
+        None
+    );
+
+    let dictionary = GroupKeysDictionary::default();
+    let strings = StringType::from_data(vec!["x1", "x2", "x1", "x3"]);
+    let encoded = dictionary.encode(&strings)?;
+    assert_eq!(encoded, UInt32Type::from_data(vec![0u32, 1, 0, 2]));
+    assert_eq!(dictionary.len(), 3);
+
+    // The ids are stable across blocks.
+    let nullable_strings = StringType::from_opt_data(vec![Some("x3"), None, Some("x4")]);
+    let encoded_nullable = dictionary.encode(&nullable_strings)?;
+    assert_eq!(
+        encoded_nullable,
+        UInt32Type::from_data_with_validity(vec![2u32, 3, 4], vec![true, false, true])
+    );
+    assert_eq!(dictionary.decode(&encoded), strings);
+    assert_eq!(dictionary.decode(&encoded_nullable), nullable_strings);
+
+    // Pack the keys, and read them back.
+    let block = new_block(&[
+        Int8Type::from_data(vec![1i8, 1, 2, 1]),
+        StringType::from_data(vec!["x1", "x2", "x1", "x2"]),
+    ]);
+    let group_types =
+        GroupKeysDictionary::encoded_key_types(&[int8.clone(), DataType::String]).unwrap();
+    let method = DataBlock::choose_hash_method_with_types(&group_types, false)?;
+    assert_eq!(method.name(), HashMethodKeysU64::default().name());
+
+    let group_columns = vec![
+        (
+            block.get_by_offset(0).value.as_column().unwrap().clone(),
+            int8,
+        ),
+        (
+            dictionary.encode(block.get_by_offset(1).value.as_column().unwrap())?,
+            uint32,
+        ),
+    ];
+    let hash = HashMethodKeysU64::default();
+    let state = hash.build_keys_state(&group_columns, block.num_rows())?;
+    let keys: Vec<u64> = hash.build_keys_iter(&state)?.copied().collect();
+    assert_eq!(keys.iter().collect::<HashSet<_>>().len(), 3);
+
+    let group_items = [(0, group_types[0].clone()), (1, group_types[1].clone())];
+    let columns = hash.deserialize_group_columns(keys, &group_items)?;
+    assert_eq!(columns[0], group_columns[0].0);
+    assert_eq!(
+        dictionary.decode(&columns[1]),
+        StringType::from_data(vec!["x1", "x2", "x1", "x2"])
+    );
+    Ok(())
+}
+
+#[test]
+fn test_group_by_hash_constant_keys() -> Result<()> {
+    fn check<M: HashMethod>(method: M, columns: &[(Column, DataType)]) -> Result<()> {
+        let num_rows = columns[0].0.len();
+        let materialized = columns
+            .iter()
+            .map(|(col, ty)| (col.materialize(), ty.clone()))
+            .collect::<Vec<_>>();
+
+        // The hash join reads the keys by the accessor, and the group by by the iterator.
+        let mut hashes = Vec::new();
+        let state = method.build_keys_state(columns, num_rows)?;
+        assert_eq!(method.build_keys_iter(&state)?.count(), num_rows);
+        method.build_keys_accessor_and_hashes(state, &mut hashes)?;
+
+        let mut expected = Vec::new();
+        let state = method.build_keys_state(&materialized, num_rows)?;
+        method.build_keys_accessor_and_hashes(state, &mut expected)?;
+        assert_eq!(hashes, expected, "{}", method.name());
+        Ok(())
+    }
+
+    let int32 = DataType::Number(NumberDataType::Int32);
+    let uint8 = DataType::Number(NumberDataType::UInt8);
+    let constant = |scalar: Scalar, ty: &DataType| {
+        (
+            Value::Scalar(scalar).convert_to_constant_column(ty, 4),
+            ty.clone(),
+        )
+    };
+    let string = constant(Scalar::String(b"x1".to_vec()), &DataType::String);
+    let nullable_string = constant(Scalar::Null, &DataType::String.wrap_nullable());
+    let uint8_key = constant(Scalar::Number(NumberScalar::UInt8(7)), &uint8);
+    let int32_key = constant(Scalar::Number(NumberScalar::Int32(-7)), &int32);
+    let int8_key = (
+        Int8Type::from_data(vec![1i8, 2, 1, 3]),
+        DataType::Number(NumberDataType::Int8),
+    );
+
+    check(HashMethodSingleString::default(), &[string.clone()])?;
+    check(HashMethodKeysU8::default(), &[uint8_key.clone()])?;
+    check(HashMethodKeysU32::default(), &[int32_key.clone()])?;
+    check(HashMethodKeysU64::default(), &[
+        int32_key.clone(),
+        int8_key.clone(),
+    ])?;
+    check(HashMethodSerializer::default(), &[
+        string.clone(),
+        nullable_string.clone(),
+        int8_key.clone(),
+    ])?;
+    check(HashMethodDictionarySerializer { dict_keys: 2 }, &[
+        string,
+        nullable_string,
+        int8_key,
+    ])?;
+
+    // The group by and the hash join expand the constant columns of their input blocks.
+    let block = DataBlock::new(
+        vec![BlockEntry::new(
+            uint8.clone(),
+            Value::Column(uint8_key.0.clone()),
+        )],
+        4,
+    );
+    let full = block.consume_convert_to_full();
+    assert!(
+        !full
+            .get_by_offset(0)
+            .value
+            .as_column()
+            .unwrap()
+            .is_constant()
+    );
+    assert_eq!(
+        full.get_by_offset(0).value.as_column().unwrap(),
+        &uint8_key.0.materialize()
+    );
+    Ok(())
+}
@@ -415,6 +415,31 @@ pub fn test_filters() -> common_exception::Result<()> {
     Ok(())
 }
 
+#[test]
+pub fn test_concat_columns() {
+    use common_expression::ColumnBuilder;
+    use rand::Rng;
+
+    let mut rng = rand::thread_rng();
+    for data_type in get_all_test_data_types() {
+        // Sliced columns, so that the offsets of strings and arrays do not start from 0.
+        let columns = (0..rng.gen_range(2..10))
+            .map(|_| {
+                let len = rng.gen_range(1..50);
+                let start = rng.gen_range(0..len);
+                let end = rng.gen_range(start..=len);
+                Column::random(&data_type, len).slice(start..end)
+            })
+            .collect::<Vec<_>>();
+
+        let mut builder = ColumnBuilder::with_capacity(&data_type, 0);
+        for column in columns.iter() {
+            builder.append_column(column);
+        }
+        assert_eq!(Column::concat(&columns), builder.build(), "{data_type}");
+    }
+}
+
 #[test]
 pub fn test_divide_indices_by_scatter_size() -> common_exception::Result<()> {
     use common_expression::DataBlock;
@@ -504,3 +529,148 @@ pub fn test_scatter() -> common_exception::Result<()> {
 
     Ok(())
 }
+
+#[test]
+pub fn test_scatter_columns() {
+    use itertools::Itertools;
+    use rand::Rng;
+
+    let mut rng = rand::thread_rng();
+    for data_type in get_all_test_data_types() {
+        let len = rng.gen_range(1..100);
+        let start = rng.gen_range(0..len);
+        let column = Column::random(&data_type, len).slice(start..len);
+        let scatter_size = rng.gen_range(1..10);
+
+        let indices = (0..column.len())
+            .map(|_| rng.gen_range(0..scatter_size))
+            .collect_vec();
+        let scattered = column.scatter(&indices, scatter_size);
+        assert_eq!(scattered.len(), scatter_size);
+
+        let scatter_indices = DataBlock::divide_indices_by_scatter_size(&indices, scatter_size);
+        for (scattered, indices) in scattered.iter().zip(scatter_indices.iter()) {
+            assert_eq!(scattered, &column.take(indices, &mut None), "{data_type}");
+        }
+    }
+}
+
+#[test]
+pub fn test_constant_column() {
+    use common_arrow::arrow::bitmap::Bitmap;
+    use common_expression::types::number::NumberScalar;
+    use common_expression::Scalar;
+
+    let data_type = DataType::Number(NumberDataType::Int32);
+    let value = Value::Scalar(Scalar::Number(NumberScalar::Int32(7)));
+    let column = value.convert_to_constant_column(&data_type, 10);
+    let full = value.convert_to_full_column(&data_type, 10);
+    assert!(column.is_constant());
+    assert_eq!(column.len(), 10);
+    assert_eq!(column.materialize(), full);
+    assert_eq!(column.index(3), full.index(3));
+    assert_eq!(column.index(10), None);
+
+    let sliced = column.slice(2..5);
+    assert!(sliced.is_constant());
+    assert_eq!(sliced.materialize(), full.slice(2..5));
+
+    let filter = Bitmap::from_iter((0..10).map(|i| i % 3 == 0));
+    let filtered = column.filter(&filter);
+    assert!(filtered.is_constant());
+    assert_eq!(filtered.materialize(), full.filter(&filter));
+
+    let indices: Vec<u32> = vec![9, 0, 4, 4];
+    let taken = column.take(&indices, &mut None);
+    assert!(taken.is_constant());
+    assert_eq!(taken.materialize(), full.take(&indices, &mut None));
+
+    // Concatenating the same constant keeps the column constant.
+    let concated = Column::concat(&[column.clone(), sliced.clone()]);
+    assert!(concated.is_constant());
+    assert_eq!(concated.len(), 13);
+
+    // Mixing with a full column materializes the result.
+    let mixed = Column::concat(&[column.clone(), Int32Type::from_data(vec![1, 2])]);
+    assert!(!mixed.is_constant());
+    assert_eq!(
+        mixed,
+        Column::concat(&[full.clone(), Int32Type::from_data(vec![1, 2])])
+    );
+}
+
+#[test]
+pub fn test_hash_into() {
+    use common_expression::Scalar;
+    use itertools::Itertools;
+    use rand::Rng;
+
+    let mut rng = rand::thread_rng();
+    for data_type in get_all_test_data_types() {
+        let len = rng.gen_range(1..100);
+        let column = Column::random(&data_type, len);
+        let mut hashes = vec![];
+        column.hash_into(0, &mut hashes);
+        assert_eq!(hashes.len(), len);
+
+        // The hashes only depend on the values, not on the layout of the column.
+        let start = rng.gen_range(0..len);
+        let mut sliced_hashes = vec![];
+        column.slice(start..len).hash_into(0, &mut sliced_hashes);
+        assert_eq!(sliced_hashes, hashes[start..], "{data_type}");
+
+        let indices = (0..len).map(|_| rng.gen_range(0..len) as u32).collect_vec();
+        let mut taken_hashes = vec![];
+        column
+            .take(&indices, &mut None)
+            .hash_into(0, &mut taken_hashes);
+        let expected = indices.iter().map(|i| hashes[*i as usize]).collect_vec();
+        assert_eq!(taken_hashes, expected, "{data_type}");
+    }
+
+    let hash = |columns: &[Column], seed: u64| {
+        let mut hashes = vec![];
+        for column in columns {
+            column.hash_into(seed, &mut hashes);
+        }
+        hashes
+    };
+
+    // Valid rows of a nullable column hash like the non-nullable column.
+    let column = Int64Type::from_data(vec![1i64, 2, 3]);
+    let nullable = Int64Type::from_data_with_validity(vec![1i64, 0, 3], vec![true, false, true]);
+    let hashes = hash(&[column.clone()], 0);
+    let nullable_hashes = hash(&[nullable.clone()], 0);
+    assert_eq!(nullable_hashes[0], hashes[0]);
+    assert_eq!(nullable_hashes[2], hashes[2]);
+    assert_ne!(
+        nullable_hashes[1],
+        hash(&[Int64Type::from_data(vec![0i64])], 0)[0]
+    );
+
+    // Constant columns hash like their materialized counterpart.
+    let constant = Value::Scalar(Scalar::Number(NumberScalar::Int64(2)))
+        .convert_to_constant_column(&DataType::Number(NumberDataType::Int64), 3);
+    assert_eq!(
+        hash(&[constant.clone()], 0),
+        hash(&[constant.materialize()], 0)
+    );
+
+    // Floats are normalized before hashing.
+    let floats = Float64Type::from_data(vec![0.0f64, -0.0, f64::NAN, -f64::NAN]);
+    let float_hashes = hash(&[floats], 0);
+    assert_eq!(float_hashes[0], float_hashes[1]);
+    assert_eq!(float_hashes[2], float_hashes[3]);
+
+    // The seed and the order of the key columns change the hashes.
+    let strings = StringType::from_data(vec!["a", "b", "c"]);
+    assert_ne!(hash(&[column.clone()], 0), hash(&[column.clone()], 1));
+    assert_ne!(
+        hash(&[column.clone(), strings.clone()], 0),
+        hash(&[strings.clone(), column.clone()], 0)
+    );
+
+    // Tuples hash like their fields hashed one after another.
+    let tuple = Column::Tuple(vec![column.clone(), strings.clone()]);
+    assert_eq!(hash(&[tuple], 7), hash(&[column, strings], 7));
+}
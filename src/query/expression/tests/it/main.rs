@@ -26,6 +26,7 @@ extern crate core;
 
 mod block;
 mod column;
+mod column_arena;
 mod common;
 mod decimal;
 mod group_by;
@@ -57,8 +58,10 @@ fn get_all_test_data_types() -> Vec<DataType> {
         DataType::String,
         DataType::Bitmap,
         DataType::Variant,
+        DataType::Geometry,
         DataType::Timestamp,
         DataType::Date,
+        DataType::Interval,
         DataType::Number(NumberDataType::UInt8),
         DataType::Number(NumberDataType::UInt16),
         DataType::Number(NumberDataType::UInt32),
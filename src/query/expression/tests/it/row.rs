@@ -20,6 +20,7 @@ use arrow_schema::SortOptions;
 use common_arrow::arrow::bitmap::MutableBitmap;
 use common_arrow::arrow::offset::OffsetsBuffer;
 use common_expression::types::decimal::*;
+use common_expression::types::interval::months_days_micros;
 use common_expression::types::nullable::NullableColumn;
 use common_expression::types::string::StringColumnBuilder;
 use common_expression::types::*;
@@ -382,6 +383,55 @@ fn test_variant() {
     }
 }
 
+#[test]
+fn test_convert_rows() {
+    let size = DecimalSize {
+        precision: 38,
+        scale: 7,
+    };
+    let cols = [
+        Int32Type::from_opt_data(vec![Some(1), None, Some(-5), Some(i32::MAX)]),
+        Float64Type::from_data(vec![1.5f64, -0.0, f64::MIN, 0.1]),
+        Decimal128Type::from_opt_data_with_size(
+            vec![Some(-13), Some(i128::MAX), None, Some(0)],
+            size,
+        ),
+        TimestampType::from_data(vec![0, -1, 1_700_000_000_000_000, i64::MIN]),
+        DateType::from_opt_data(vec![None, Some(19000), Some(-1), Some(0)]),
+        BooleanType::from_data(vec![true, false, false, true]),
+        StringType::from_opt_data(vec![
+            Some("databend"),
+            Some(""),
+            None,
+            Some("a string that is longer than a single 32 bytes block"),
+        ]),
+        Column::Null { len: 4 },
+    ];
+
+    for (asc, nulls_first) in [(true, true), (true, false), (false, true), (false, false)] {
+        let fields = cols
+            .iter()
+            .map(|col| SortField::new_with_options(col.data_type(), asc, nulls_first))
+            .collect();
+        let converter = RowConverter::new(fields).unwrap();
+        let rows = converter.convert_columns(&cols, 4);
+        let decoded = converter.convert_rows(&rows).unwrap();
+
+        assert_eq!(decoded.len(), cols.len());
+        for (col, decoded) in cols.iter().zip(decoded.iter()) {
+            assert_eq!(col.data_type(), decoded.data_type());
+            for i in 0..4 {
+                assert_eq!(col.index(i), decoded.index(i));
+            }
+        }
+    }
+
+    let converter = RowConverter::new(vec![SortField::new(DataType::Interval)]).unwrap();
+    let col = IntervalType::from_data(vec![months_days_micros::new(1, 2, 3)]);
+    let rows = converter.convert_columns(&[col], 1);
+    assert!(converter.convert_rows(&rows).is_err());
+}
+
 fn generate_number_column<K>(len: usize, valid_percent: f64) -> Column
 where
     K: Number,
@@ -536,5 +586,12 @@ fn fuzz_test() {
                 }
             }
         }
+
+        let decoded = converter.convert_rows(&rows).unwrap();
+        for (col, decoded) in columns.iter().zip(decoded.iter()) {
+            for i in 0..num_rows {
+                assert_eq!(col.index(i), decoded.index(i));
+            }
+        }
     }
 }
@@ -26,6 +26,8 @@ use common_expression::Scalar;
 use common_io::prelude::deserialize_from_slice;
 use common_io::prelude::serialize_into_buf;
 
+use crate::get_all_test_data_types;
+
 #[test]
 fn test_serde_column() -> Result<()> {
     #[derive(serde::Serialize, serde::Deserialize, Eq, PartialEq, Debug)]
@@ -80,5 +82,51 @@ fn test_serde_bin_column() -> Result<()> {
         let t = deserialize_column(&data).unwrap();
         assert_eq!(col, t);
     }
+
+    for data_type in get_all_test_data_types() {
+        let col = Column::random(&data_type, 16);
+        let data = serialize_column(&col);
+        let t = deserialize_column(&data).unwrap();
+        assert_eq!(col, t, "{data_type}");
+    }
+    Ok(())
+}
+
+#[test]
+fn test_deserialize_legacy_column() -> Result<()> {
+    use base64::engine::general_purpose;
+    use base64::prelude::*;
+    use common_arrow::arrow::chunk::Chunk;
+    use common_arrow::arrow::datatypes::Schema;
+    use common_arrow::arrow::io::ipc::write::FileWriter;
+    use common_arrow::arrow::io::ipc::write::WriteOptions;
+    use common_expression::arrow::is_column_frame;
+
+    let col = StringType::from_data(vec!["SM CASE", "a", "b", "e", "f", "g"]);
+
+    // Columns used to be serialized as a bare arrow IPC file.
+    let mut legacy = Vec::new();
+    let schema = Schema::from(vec![col.arrow_field()]);
+    let mut writer = FileWriter::new(&mut legacy, schema, None, WriteOptions::default());
+    writer.start().unwrap();
+    writer
+        .write(&Chunk::new(vec![col.as_arrow()]), None)
+        .unwrap();
+    writer.finish().unwrap();
+
+    assert!(!is_column_frame(&legacy));
+    assert!(is_column_frame(&serialize_column(&col)));
+    assert_eq!(deserialize_column(&legacy).unwrap(), col);
+
+    // And as a base64 string by serde, in both text and binary formats.
+    let base64_str = general_purpose::STANDARD.encode(&legacy);
+    let json = serde_json::to_vec(&base64_str).unwrap();
+    assert_eq!(serde_json::from_slice::<Column>(&json).unwrap(), col);
+
+    let mut vs = vec![];
+    serialize_into_buf(&mut vs, &base64_str).unwrap();
+    let mut vs = vs.as_slice();
+    let new_col: Column = deserialize_from_slice(&mut vs).unwrap();
+    assert_eq!(new_col, col);
     Ok(())
 }
@@ -23,6 +23,7 @@ use common_expression::Column;
 use common_expression::DataBlock;
 use common_expression::FromData;
 use common_expression::SortColumnDescription;
+use common_expression::StringViewColumn;
 
 use crate::common::new_block;
 
@@ -316,3 +317,63 @@ fn test_blocks_merge_sort() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_block_sort_string_views() -> Result<()> {
+    let values = vec![
+        "databend_cloud_2",
+        "",
+        "databend",
+        "data",
+        "databend_cloud_1",
+        "dat\0",
+        "dat",
+        "z",
+        "databend_cloud_10",
+    ];
+    let block = new_block(&[
+        StringType::from_data(values.clone()),
+        Int64Type::from_data((0..values.len() as i64).collect::<Vec<_>>()),
+    ]);
+
+    let mut sorted = values.clone();
+    sorted.sort();
+    for (asc, limit) in [
+        (true, None),
+        (false, None),
+        (true, Some(4)),
+        (false, Some(0)),
+    ] {
+        let descs = vec![SortColumnDescription {
+            offset: 0,
+            asc,
+            nulls_first: false,
+            is_nullable: false,
+        }];
+        let res = DataBlock::sort(&block, &descs, limit)?;
+
+        let mut expected = sorted.clone();
+        if !asc {
+            expected.reverse();
+        }
+        expected.truncate(limit.unwrap_or(values.len()));
+        assert_eq!(
+            res.get_by_offset(0).value.as_column().unwrap(),
+            &StringType::from_data(expected),
+        );
+    }
+
+    let column = StringType::from_data(values.clone());
+    let column = column.as_string().unwrap();
+    let views = StringViewColumn::from(column);
+    for (i, lhs) in values.iter().enumerate() {
+        assert_eq!(views.index(i), lhs.as_bytes());
+        for (j, rhs) in values.iter().enumerate() {
+            assert_eq!(views.equals(i, &views, j), lhs == rhs);
+            assert_eq!(views.compare(i, &views, j), lhs.cmp(rhs));
+        }
+    }
+    assert_eq!(&views.to_string_column(), column);
+
+    Ok(())
+}
@@ -462,6 +462,8 @@ pub struct FastValuesDecoder<'a> {
     reader: Cursor<&'a [u8]>,
     estimated_rows: usize,
     positions: VecDeque<usize>,
+    // the rows parsed, the first row is not preceded by `,`
+    parsed_rows: usize,
 }
 
 #[async_trait::async_trait]
@@ -491,9 +493,22 @@ impl<'a> FastValuesDecoder<'a> {
             estimated_rows,
             positions,
             field_decoder,
+            parsed_rows: 0,
         }
     }
 
+    /// The data continues the rows parsed by another decoder, so its first row is
+    /// preceded by `,`.
+    pub fn with_leading_separator(mut self) -> Self {
+        self.parsed_rows = 1;
+        self
+    }
+
+    /// The position in the data after the last parsed row.
+    pub fn position(&self) -> usize {
+        self.reader.position() as usize
+    }
+
     pub fn estimated_rows(&self) -> usize {
         self.estimated_rows
     }
@@ -503,23 +518,36 @@ impl<'a> FastValuesDecoder<'a> {
         columns: &mut [ColumnBuilder],
         fallback_fn: &impl FastValuesDecodeFallback,
     ) -> Result<()> {
-        for row in 0.. {
+        self.parse_rows(columns, fallback_fn, usize::MAX).await?;
+        Ok(())
+    }
+
+    /// Parse at most `max_rows` rows into the columns, returns `true` if the end of the
+    /// rows is reached.
+    pub async fn parse_rows(
+        &mut self,
+        columns: &mut [ColumnBuilder],
+        fallback_fn: &impl FastValuesDecodeFallback,
+        max_rows: usize,
+    ) -> Result<bool> {
+        for _ in 0..max_rows {
             let _ = self.reader.ignore_white_spaces();
             if self.reader.eof() {
-                break;
+                return Ok(true);
             }
 
             // Not the first row
-            if row != 0 {
+            if self.parsed_rows != 0 {
                 if self.reader.ignore_byte(b';') {
-                    break;
+                    return Ok(true);
                 }
                 self.reader.must_ignore_byte(b',')?;
             }
 
             self.parse_next_row(columns, fallback_fn).await?;
+            self.parsed_rows += 1;
         }
-        Ok(())
+        Ok(false)
     }
 
     async fn parse_next_row(
@@ -15,6 +15,7 @@
 mod fast_values;
 mod json_ast;
 mod nested;
+mod protobuf;
 mod separated_text;
 
 use std::any::Any;
@@ -24,6 +25,7 @@ pub use fast_values::FastValuesDecodeFallback;
 pub use fast_values::FastValuesDecoder;
 pub use json_ast::FieldJsonAstDecoder;
 pub use nested::NestedValues;
+pub use protobuf::ProtobufDecoder;
 pub use separated_text::SeparatedTextDecoder;
 
 pub trait FieldDecoder: Send + Sync {
@@ -0,0 +1,336 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::HashMap;
+
+use base64::engine::general_purpose;
+use base64::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::ProtobufFileFormatParams;
+use prost::encoding::decode_key;
+use prost::encoding::decode_varint;
+use prost::encoding::WireType;
+use prost::Message;
+use prost_types::field_descriptor_proto::Label;
+use prost_types::field_descriptor_proto::Type;
+use prost_types::DescriptorProto;
+use prost_types::EnumDescriptorProto;
+use prost_types::FieldDescriptorProto;
+use prost_types::FileDescriptorSet;
+use serde_json::Map;
+use serde_json::Number;
+use serde_json::Value;
+
+use crate::FieldDecoder;
+use crate::FieldJsonAstDecoder;
+use crate::FileFormatOptionsExt;
+
+/// Decodes protobuf messages into json values with the descriptors of a `FileDescriptorSet`,
+/// the values are then read into the columns by the `FieldJsonAstDecoder`.
+///
+/// A message is decoded into an object holding all the fields in the order of the descriptor,
+/// so nested messages can be read into Tuple columns as well as Variant columns.
+/// Absent fields take the proto3 default values, except messages and `optional` fields which are null.
+pub struct ProtobufDecoder {
+    pub json: FieldJsonAstDecoder,
+    message_name: String,
+    // keyed by the full names with a leading dot, as referred by `FieldDescriptorProto::type_name`.
+    messages: HashMap<String, DescriptorProto>,
+    enums: HashMap<String, EnumDescriptorProto>,
+}
+
+impl FieldDecoder for ProtobufDecoder {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// A field value as read from the wire, before being converted by the type of the field.
+enum WireValue<'a> {
+    Varint(u64),
+    Fixed64(u64),
+    Fixed32(u32),
+    LengthDelimited(&'a [u8]),
+}
+
+impl ProtobufDecoder {
+    pub fn try_create(
+        params: &ProtobufFileFormatParams,
+        options: &FileFormatOptionsExt,
+    ) -> Result<Self> {
+        let bytes = general_purpose::STANDARD
+            .decode(params.descriptor_set.trim())
+            .map_err(|e| {
+                ErrorCode::InvalidArgument(format!("DESCRIPTOR_SET is not valid base64: {e}"))
+            })?;
+        let descriptor_set = FileDescriptorSet::decode(bytes.as_slice()).map_err(|e| {
+            ErrorCode::InvalidArgument(format!(
+                "DESCRIPTOR_SET is not a valid FileDescriptorSet: {e}"
+            ))
+        })?;
+
+        let mut decoder = ProtobufDecoder {
+            json: FieldJsonAstDecoder::create(options),
+            message_name: format!(".{}", params.message_name.trim_start_matches('.')),
+            messages: HashMap::new(),
+            enums: HashMap::new(),
+        };
+        for file in descriptor_set.file {
+            let scope = match file.package() {
+                "" => "".to_string(),
+                package => format!(".{package}"),
+            };
+            decoder.add_descriptors(&scope, file.message_type, file.enum_type);
+        }
+
+        if !decoder.messages.contains_key(&decoder.message_name) {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "MESSAGE_NAME '{}' is not found in DESCRIPTOR_SET",
+                params.message_name
+            )));
+        }
+        Ok(decoder)
+    }
+
+    fn add_descriptors(
+        &mut self,
+        scope: &str,
+        messages: Vec<DescriptorProto>,
+        enums: Vec<EnumDescriptorProto>,
+    ) {
+        for e in enums {
+            self.enums.insert(format!("{scope}.{}", e.name()), e);
+        }
+        for mut m in messages {
+            let name = format!("{scope}.{}", m.name());
+            let nested_types = std::mem::take(&mut m.nested_type);
+            let enum_types = std::mem::take(&mut m.enum_type);
+            self.add_descriptors(&name, nested_types, enum_types);
+            self.messages.insert(name, m);
+        }
+    }
+
+    /// Decodes one message of the rows, without the length prefix.
+    pub fn read_message(&self, buf: &[u8]) -> Result<Value> {
+        self.decode_message(&self.message_name, buf)
+    }
+
+    fn message(&self, name: &str) -> Result<&DescriptorProto> {
+        self.messages
+            .get(name)
+            .ok_or_else(|| ErrorCode::BadBytes(format!("unknown protobuf message type {name}")))
+    }
+
+    fn decode_message(&self, name: &str, mut buf: &[u8]) -> Result<Value> {
+        let descriptor = self.message(name)?;
+        let mut values: HashMap<i32, Vec<Value>> = HashMap::new();
+
+        while !buf.is_empty() {
+            let (number, wire_type) = decode_key(&mut buf).map_err(decode_error)?;
+            let wire_value = match wire_type {
+                WireType::Varint => {
+                    WireValue::Varint(decode_varint(&mut buf).map_err(decode_error)?)
+                }
+                WireType::SixtyFourBit => {
+                    WireValue::Fixed64(u64::from_le_bytes(take(&mut buf, 8)?.try_into().unwrap()))
+                }
+                WireType::ThirtyTwoBit => {
+                    WireValue::Fixed32(u32::from_le_bytes(take(&mut buf, 4)?.try_into().unwrap()))
+                }
+                WireType::LengthDelimited => {
+                    let len = decode_varint(&mut buf).map_err(decode_error)?;
+                    WireValue::LengthDelimited(take(&mut buf, len as usize)?)
+                }
+                WireType::StartGroup | WireType::EndGroup => {
+                    return Err(ErrorCode::BadBytes(
+                        "protobuf groups are not supported".to_string(),
+                    ));
+                }
+            };
+
+            // Unknown fields are skipped, as newer writers may add fields.
+            let Some(field) = descriptor
+                .field
+                .iter()
+                .find(|f| f.number() == number as i32)
+            else {
+                continue;
+            };
+            let entry = values.entry(field.number()).or_default();
+            match wire_value {
+                WireValue::LengthDelimited(mut packed) if is_packable(field.r#type()) => {
+                    while !packed.is_empty() {
+                        let wire_value = match field.r#type() {
+                            Type::Double | Type::Fixed64 | Type::Sfixed64 => WireValue::Fixed64(
+                                u64::from_le_bytes(take(&mut packed, 8)?.try_into().unwrap()),
+                            ),
+                            Type::Float | Type::Fixed32 | Type::Sfixed32 => WireValue::Fixed32(
+                                u32::from_le_bytes(take(&mut packed, 4)?.try_into().unwrap()),
+                            ),
+                            _ => {
+                                WireValue::Varint(decode_varint(&mut packed).map_err(decode_error)?)
+                            }
+                        };
+                        entry.push(self.decode_value(field, wire_value)?);
+                    }
+                }
+                wire_value => entry.push(self.decode_value(field, wire_value)?),
+            }
+        }
+
+        let mut object = Map::with_capacity(descriptor.field.len());
+        for field in &descriptor.field {
+            let mut field_values = values.remove(&field.number()).unwrap_or_default();
+            let value = if field.label() != Label::Repeated {
+                // The last value wins for singular fields.
+                match field_values.pop() {
+                    Some(value) => value,
+                    None => self.default_value(field)?,
+                }
+            } else if self.is_map(field)? {
+                // Map fields are repeated entries of `key` and `value`.
+                Value::Object(
+                    field_values
+                        .into_iter()
+                        .filter_map(|entry| match entry {
+                            Value::Object(mut kv) => {
+                                let key = match kv.remove("key")? {
+                                    Value::String(s) => s,
+                                    key => key.to_string(),
+                                };
+                                Some((key, kv.remove("value").unwrap_or(Value::Null)))
+                            }
+                            _ => None,
+                        })
+                        .collect(),
+                )
+            } else {
+                Value::Array(field_values)
+            };
+            object.insert(field.name().to_string(), value);
+        }
+        Ok(Value::Object(object))
+    }
+
+    fn is_map(&self, field: &FieldDescriptorProto) -> Result<bool> {
+        if field.r#type() != Type::Message {
+            return Ok(false);
+        }
+        let descriptor = self.message(field.type_name())?;
+        Ok(descriptor
+            .options
+            .as_ref()
+            .map(|o| o.map_entry())
+            .unwrap_or(false))
+    }
+
+    fn decode_value(&self, field: &FieldDescriptorProto, wire_value: WireValue) -> Result<Value> {
+        let value = match (field.r#type(), wire_value) {
+            (Type::Double, WireValue::Fixed64(v)) => float_value(f64::from_bits(v)),
+            (Type::Float, WireValue::Fixed32(v)) => float_value(f32::from_bits(v) as f64),
+            (Type::Int64, WireValue::Varint(v)) => Value::from(v as i64),
+            (Type::Uint64, WireValue::Varint(v)) => Value::from(v),
+            (Type::Int32, WireValue::Varint(v)) => Value::from(v as i32),
+            (Type::Uint32, WireValue::Varint(v)) => Value::from(v as u32),
+            (Type::Sint32, WireValue::Varint(v)) => {
+                let v = v as u32;
+                Value::from((v >> 1) as i32 ^ -((v & 1) as i32))
+            }
+            (Type::Sint64, WireValue::Varint(v)) => {
+                Value::from((v >> 1) as i64 ^ -((v & 1) as i64))
+            }
+            (Type::Fixed64, WireValue::Fixed64(v)) => Value::from(v),
+            (Type::Sfixed64, WireValue::Fixed64(v)) => Value::from(v as i64),
+            (Type::Fixed32, WireValue::Fixed32(v)) => Value::from(v),
+            (Type::Sfixed32, WireValue::Fixed32(v)) => Value::from(v as i32),
+            (Type::Bool, WireValue::Varint(v)) => Value::Bool(v != 0),
+            (Type::Enum, WireValue::Varint(v)) => self.enum_value(field, v as i32),
+            (Type::String, WireValue::LengthDelimited(v)) => {
+                Value::String(String::from_utf8(v.to_vec()).map_err(|_| {
+                    ErrorCode::BadBytes(format!("field {} is not valid utf-8", field.name()))
+                })?)
+            }
+            (Type::Bytes, WireValue::LengthDelimited(v)) => {
+                Value::String(general_purpose::STANDARD.encode(v))
+            }
+            (Type::Message, WireValue::LengthDelimited(v)) => {
+                self.decode_message(field.type_name(), v)?
+            }
+            (typ, _) => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "unexpected wire type for field {} of type {:?}",
+                    field.name(),
+                    typ
+                )));
+            }
+        };
+        Ok(value)
+    }
+
+    fn enum_value(&self, field: &FieldDescriptorProto, number: i32) -> Value {
+        // The number is kept for values unknown by the descriptor.
+        self.enums
+            .get(field.type_name())
+            .and_then(|e| e.value.iter().find(|v| v.number() == number))
+            .map(|v| Value::String(v.name().to_string()))
+            .unwrap_or_else(|| Value::from(number))
+    }
+
+    fn default_value(&self, field: &FieldDescriptorProto) -> Result<Value> {
+        if field.proto3_optional() {
+            return Ok(Value::Null);
+        }
+        let value = match field.r#type() {
+            Type::Double | Type::Float => float_value(0.0),
+            Type::Bool => Value::Bool(false),
+            Type::String | Type::Bytes => Value::String("".to_string()),
+            Type::Enum => self.enum_value(field, 0),
+            Type::Message | Type::Group => Value::Null,
+            _ => Value::from(0),
+        };
+        Ok(value)
+    }
+}
+
+fn is_packable(typ: Type) -> bool {
+    !matches!(
+        typ,
+        Type::String | Type::Bytes | Type::Message | Type::Group
+    )
+}
+
+fn float_value(v: f64) -> Value {
+    Number::from_f64(v)
+        .map(Value::Number)
+        .unwrap_or(Value::Null)
+}
+
+fn take<'a>(buf: &mut &'a [u8], len: usize) -> Result<&'a [u8]> {
+    if buf.len() < len {
+        return Err(ErrorCode::BadBytes(format!(
+            "protobuf message is truncated, expect {} bytes, but only {} left",
+            len,
+            buf.len()
+        )));
+    }
+    let (head, tail) = buf.split_at(len);
+    *buf = tail;
+    Ok(head)
+}
+
+fn decode_error(e: prost::DecodeError) -> ErrorCode {
+    ErrorCode::BadBytes(format!("invalid protobuf message: {e}"))
+}
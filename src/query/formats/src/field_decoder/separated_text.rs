@@ -15,7 +15,14 @@
 use std::any::Any;
 use std::io::Cursor;
 
+use base64::engine::general_purpose;
+use base64::prelude::*;
 use bstr::ByteSlice;
+use chrono::DateTime;
+use chrono::LocalResult;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use chrono::TimeZone;
 use common_arrow::arrow::bitmap::MutableBitmap;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -49,8 +56,11 @@ use common_io::cursor_ext::BufferReadDateTimeExt;
 use common_io::cursor_ext::DateTimeResType;
 use common_io::cursor_ext::ReadBytesExt;
 use common_io::parse_bitmap;
+use common_meta_app::principal::BinaryFormat;
+use common_meta_app::principal::ColumnFormatParams;
 use common_meta_app::principal::CsvFileFormatParams;
 use common_meta_app::principal::TsvFileFormatParams;
+use common_meta_app::principal::XlsxFileFormatParams;
 use common_meta_app::principal::XmlFileFormatParams;
 use jsonb::parse_value;
 use lexical_core::FromLexical;
@@ -64,6 +74,7 @@ use crate::NestedValues;
 pub struct SeparatedTextDecoder {
     pub common_settings: InputCommonSettings,
     pub nested_decoder: NestedValues,
+    pub column_formats: Vec<Option<ColumnFormatParams>>,
 }
 
 impl FieldDecoder for SeparatedTextDecoder {
@@ -87,6 +98,7 @@ impl SeparatedTextDecoder {
                 disable_variant_check: options_ext.disable_variant_check,
             },
             nested_decoder: NestedValues::create(options_ext),
+            column_formats: options_ext.column_formats.clone(),
         }
     }
 
@@ -102,6 +114,7 @@ impl SeparatedTextDecoder {
                 disable_variant_check: options_ext.disable_variant_check,
             },
             nested_decoder: NestedValues::create(options_ext),
+            column_formats: options_ext.column_formats.clone(),
         }
     }
 
@@ -117,6 +130,24 @@ impl SeparatedTextDecoder {
                 disable_variant_check: options_ext.disable_variant_check,
             },
             nested_decoder: NestedValues::create(options_ext),
+            column_formats: options_ext.column_formats.clone(),
+        }
+    }
+
+    /// The cells of xlsx are typed, so only empty cells are null.
+    pub fn create_xlsx(_params: &XlsxFileFormatParams, options_ext: &FileFormatOptionsExt) -> Self {
+        SeparatedTextDecoder {
+            common_settings: InputCommonSettings {
+                null_if: vec![],
+                true_bytes: TRUE_BYTES_LOWER.as_bytes().to_vec(),
+                false_bytes: FALSE_BYTES_LOWER.as_bytes().to_vec(),
+                nan_bytes: NAN_BYTES_LOWER.as_bytes().to_vec(),
+                inf_bytes: INF_BYTES_LOWER.as_bytes().to_vec(),
+                timezone: options_ext.timezone,
+                disable_variant_check: options_ext.disable_variant_check,
+            },
+            nested_decoder: NestedValues::create(options_ext),
+            column_formats: options_ext.column_formats.clone(),
         }
     }
 
@@ -160,6 +191,61 @@ impl SeparatedTextDecoder {
         }
     }
 
+    /// Reads the field of the column at `column_index` of the schema,
+    /// with the format options overridden for the column if any.
+    pub fn read_column_field(
+        &self,
+        column_index: usize,
+        column: &mut ColumnBuilder,
+        data: &[u8],
+    ) -> Result<()> {
+        match self.column_formats.get(column_index) {
+            Some(Some(format)) => self.read_field_with_format(format, column, data),
+            _ => self.read_field(column, data),
+        }
+    }
+
+    fn read_field_with_format(
+        &self,
+        format: &ColumnFormatParams,
+        column: &mut ColumnBuilder,
+        data: &[u8],
+    ) -> Result<()> {
+        match column {
+            ColumnBuilder::Nullable(c) => {
+                let is_null = match &format.null_display {
+                    Some(null_display) => data == null_display.as_bytes(),
+                    None => self
+                        .common_settings()
+                        .null_if
+                        .iter()
+                        .any(|null| data == null),
+                };
+                if is_null {
+                    c.push_null();
+                } else {
+                    self.read_field_with_format(format, &mut c.builder, data)?;
+                    c.validity.push(true);
+                }
+                Ok(())
+            }
+            ColumnBuilder::Timestamp(c) => match &format.timestamp_format {
+                Some(timestamp_format) => {
+                    self.read_timestamp_with_format(c, timestamp_format, data)
+                }
+                None => self.read_timestamp(c, data),
+            },
+            ColumnBuilder::Date(c) => match &format.date_format {
+                Some(date_format) => self.read_date_with_format(c, date_format, data),
+                None => self.read_date(c, data),
+            },
+            ColumnBuilder::String(c) if format.binary_format.is_some() => {
+                self.read_binary(c, format.binary_format.unwrap(), data)
+            }
+            _ => self.read_field(column, data),
+        }
+    }
+
     fn read_bool(&self, column: &mut MutableBitmap, data: &[u8]) -> Result<()> {
         if data == self.common_settings().false_bytes {
             column.push(false);
@@ -269,6 +355,78 @@ impl SeparatedTextDecoder {
         Ok(())
     }
 
+    fn read_timestamp_with_format(
+        &self,
+        column: &mut Vec<i64>,
+        timestamp_format: &str,
+        data: &[u8],
+    ) -> Result<()> {
+        let text = data
+            .to_str()
+            .map_err(|_| ErrorCode::BadBytes("invalid utf8 of timestamp"))?;
+        // The format may contain the offset of the timezone, otherwise the time is local.
+        let ts = match DateTime::parse_from_str(text, timestamp_format) {
+            Ok(t) => t.timestamp_micros(),
+            Err(_) => {
+                let t = NaiveDateTime::parse_from_str(text, timestamp_format).map_err(|e| {
+                    ErrorCode::BadBytes(format!(
+                        "fail to parse timestamp '{text}' with format '{timestamp_format}': {e}"
+                    ))
+                })?;
+                match self.common_settings().timezone.from_local_datetime(&t) {
+                    LocalResult::Single(t) | LocalResult::Ambiguous(t, _) => t.timestamp_micros(),
+                    LocalResult::None => {
+                        return Err(ErrorCode::BadBytes(format!(
+                            "timestamp '{text}' does not exist in timezone {}",
+                            self.common_settings().timezone
+                        )));
+                    }
+                }
+            }
+        };
+        check_timestamp(ts)?;
+        column.push(ts);
+        Ok(())
+    }
+
+    fn read_date_with_format(
+        &self,
+        column: &mut Vec<i32>,
+        date_format: &str,
+        data: &[u8],
+    ) -> Result<()> {
+        let text = data
+            .to_str()
+            .map_err(|_| ErrorCode::BadBytes("invalid utf8 of date"))?;
+        let date = NaiveDate::parse_from_str(text, date_format).map_err(|e| {
+            ErrorCode::BadBytes(format!(
+                "fail to parse date '{text}' with format '{date_format}': {e}"
+            ))
+        })?;
+        let days = uniform_date(date);
+        check_date(days as i64)?;
+        column.push(days);
+        Ok(())
+    }
+
+    fn read_binary(
+        &self,
+        column: &mut StringColumnBuilder,
+        binary_format: BinaryFormat,
+        data: &[u8],
+    ) -> Result<()> {
+        let bytes = match binary_format {
+            BinaryFormat::Hex => hex::decode(data)
+                .map_err(|e| ErrorCode::BadBytes(format!("invalid hex value: {e}")))?,
+            BinaryFormat::Base64 => general_purpose::STANDARD
+                .decode(data)
+                .map_err(|e| ErrorCode::BadBytes(format!("invalid base64 value: {e}")))?,
+        };
+        column.put_slice(&bytes);
+        column.commit_row();
+        Ok(())
+    }
+
     fn read_bitmap(&self, column: &mut StringColumnBuilder, data: &[u8]) -> Result<()> {
         let rb = parse_bitmap(data)?;
         rb.serialize_into(&mut column.data).unwrap();
@@ -122,7 +122,12 @@ impl FieldEncoderCSV {
                 self.string_formatter.write_string(buf, out_buf);
             }
 
-            Column::Date(..) | Column::Timestamp(..) | Column::Bitmap(..) | Column::Variant(..) => {
+            Column::Date(..)
+            | Column::Timestamp(..)
+            | Column::Interval(..)
+            | Column::Bitmap(..)
+            | Column::Variant(..)
+            | Column::Geometry(..) => {
                 let mut buf = Vec::new();
                 self.simple.write_field(column, row_index, &mut buf, false);
                 self.string_formatter.write_string(&buf, out_buf);
@@ -133,6 +138,7 @@ impl FieldEncoderCSV {
                 self.nested.write_field(column, row_index, &mut buf, false);
                 self.string_formatter.write_string(&buf, out_buf);
             }
+            Column::Constant(c) => self.write_field(&c.row, 0, out_buf),
             // null, bool, number
             _ => self.simple.write_field(column, row_index, out_buf, false),
         }
@@ -69,9 +69,11 @@ impl_int!(i8);
 impl_int!(i16);
 impl_int!(i32);
 impl_int!(i64);
+impl_int!(i128);
 impl_int!(u8);
 impl_int!(u16);
 impl_int!(u32);
 impl_int!(u64);
+impl_int!(u128);
 impl_float!(f32);
 impl_float!(f64);
@@ -60,7 +60,11 @@ impl FieldEncoderJSON {
                 self.write_string(buf, out_buf);
             }
 
-            Column::Date(..) | Column::Timestamp(..) | Column::Bitmap(..) => {
+            Column::Date(..)
+            | Column::Timestamp(..)
+            | Column::Interval(..)
+            | Column::Bitmap(..)
+            | Column::Geometry(..) => {
                 let mut buf = Vec::new();
                 self.simple.write_field(column, row_index, &mut buf, false);
                 self.write_string(&buf, out_buf);
@@ -74,6 +78,7 @@ impl FieldEncoderJSON {
             Column::Array(box c) => self.write_array(c, row_index, out_buf),
             Column::Map(box c) => self.write_map(c, row_index, out_buf),
             Column::Tuple(fields) => self.write_tuple(fields, row_index, out_buf),
+            Column::Constant(c) => self.write_field(&c.row, 0, out_buf),
 
             // null, bool, number
             _ => self.simple.write_field(column, row_index, out_buf, false),
@@ -18,6 +18,8 @@ use common_arrow::arrow::buffer::Buffer;
 use common_expression::types::array::ArrayColumn;
 use common_expression::types::date::date_to_string;
 use common_expression::types::decimal::DecimalColumn;
+use common_expression::types::geometry::geometry_to_wkt;
+use common_expression::types::interval::months_days_micros;
 use common_expression::types::nullable::NullableColumn;
 use common_expression::types::string::StringColumn;
 use common_expression::types::timestamp::timestamp_to_string;
@@ -116,6 +118,8 @@ impl FieldEncoderValues {
                 NumberColumn::Int64(c) => self.write_int(c, row_index, out_buf),
                 NumberColumn::Float32(c) => self.write_float(c, row_index, out_buf),
                 NumberColumn::Float64(c) => self.write_float(c, row_index, out_buf),
+                NumberColumn::UInt128(c) => self.write_int(c, row_index, out_buf),
+                NumberColumn::Int128(c) => self.write_int(c, row_index, out_buf),
             },
             Column::Decimal(c) => self.write_decimal(c, row_index, out_buf),
 
@@ -124,12 +128,15 @@ impl FieldEncoderValues {
             Column::String(c) => self.write_string(c, row_index, out_buf, in_nested),
             Column::Date(c) => self.write_date(c, row_index, out_buf, in_nested),
             Column::Timestamp(c) => self.write_timestamp(c, row_index, out_buf, in_nested),
+            Column::Interval(c) => self.write_interval(c, row_index, out_buf, in_nested),
             Column::Bitmap(b) => self.write_bitmap(b, row_index, out_buf, in_nested),
             Column::Variant(c) => self.write_variant(c, row_index, out_buf, in_nested),
+            Column::Geometry(c) => self.write_geometry(c, row_index, out_buf, in_nested),
 
             Column::Array(box c) => self.write_array(c, row_index, out_buf),
             Column::Map(box c) => self.write_map(c, row_index, out_buf),
             Column::Tuple(fields) => self.write_tuple(fields, row_index, out_buf),
+            Column::Constant(c) => self.write_field(&c.row, 0, out_buf, in_nested),
         }
     }
     fn common_settings(&self) -> &OutputCommonSettings {
@@ -252,6 +259,17 @@ impl FieldEncoderValues {
         self.write_string_inner(s.as_bytes(), out_buf, in_nested);
     }
 
+    fn write_interval(
+        &self,
+        column: &Buffer<months_days_micros>,
+        row_index: usize,
+        out_buf: &mut Vec<u8>,
+        in_nested: bool,
+    ) {
+        let v = unsafe { column.get_unchecked(row_index) };
+        self.write_string_inner(v.to_string().as_bytes(), out_buf, in_nested);
+    }
+
     fn write_bitmap(
         &self,
         _column: &StringColumn,
@@ -275,6 +293,20 @@ impl FieldEncoderValues {
         self.write_string_inner(s.as_bytes(), out_buf, in_nested);
     }
 
+    fn write_geometry(
+        &self,
+        column: &StringColumn,
+        row_index: usize,
+        out_buf: &mut Vec<u8>,
+        in_nested: bool,
+    ) {
+        let v = unsafe { column.index_unchecked(row_index) };
+        match geometry_to_wkt(v) {
+            Ok(wkt) => self.write_string_inner(wkt.as_bytes(), out_buf, in_nested),
+            Err(_) => self.write_string_inner("<invalid geometry>".as_bytes(), out_buf, in_nested),
+        }
+    }
+
     fn write_array<T: ValueType>(
         &self,
         column: &ArrayColumn<T>,
@@ -16,6 +16,7 @@ use chrono_tz::Tz;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::TableSchemaRef;
+use common_meta_app::principal::ColumnFormatParams;
 use common_meta_app::principal::FileFormatParams;
 use common_meta_app::principal::StageFileFormatType;
 use common_settings::Settings;
@@ -45,6 +46,8 @@ pub struct FileFormatOptionsExt {
     pub disable_variant_check: bool,
     pub timezone: Tz,
     pub is_select: bool,
+    /// The format options overridden for each column of the schema, empty if none is.
+    pub column_formats: Vec<Option<ColumnFormatParams>>,
 }
 
 impl FileFormatOptionsExt {
@@ -61,6 +64,7 @@ impl FileFormatOptionsExt {
             disable_variant_check: false,
             timezone,
             is_select,
+            column_formats: vec![],
         };
         Ok(options)
     }
@@ -78,6 +82,7 @@ impl FileFormatOptionsExt {
             disable_variant_check: false,
             timezone,
             is_select: false,
+            column_formats: vec![],
         };
         let suf = &clickhouse_type.suffixes;
         options.headers = suf.headers;
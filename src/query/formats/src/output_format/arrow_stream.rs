@@ -0,0 +1,54 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use arrow_ipc::writer::StreamWriter;
+use arrow_schema::Schema as ArrowSchema;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+
+use crate::output_format::OutputFormat;
+
+/// Serializes the blocks into the Arrow IPC streaming format,
+/// each block is written as a record batch once it arrives.
+pub struct ArrowStreamOutputFormat {
+    schema: DataSchemaRef,
+    writer: StreamWriter<Vec<u8>>,
+}
+
+impl ArrowStreamOutputFormat {
+    pub fn create(schema: DataSchemaRef) -> Result<Self> {
+        let arrow_schema = ArrowSchema::from(schema.as_ref());
+        // The schema message is buffered and sent with the first block.
+        let writer = StreamWriter::try_new(vec![], &arrow_schema)?;
+        Ok(Self { schema, writer })
+    }
+
+    fn take_buffer(&mut self) -> Vec<u8> {
+        std::mem::take(self.writer.get_mut())
+    }
+}
+
+impl OutputFormat for ArrowStreamOutputFormat {
+    fn serialize_block(&mut self, block: &DataBlock) -> Result<Vec<u8>> {
+        let batch = block.clone().to_record_batch(&self.schema)?;
+        self.writer.write(&batch)?;
+        Ok(self.take_buffer())
+    }
+
+    fn finalize(&mut self) -> Result<Vec<u8>> {
+        self.writer.finish()?;
+        Ok(self.take_buffer())
+    }
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use common_expression::date_helper::DateConverter;
+use common_expression::types::geometry::geometry_to_wkt;
 use common_expression::types::number::NumberScalar;
 use common_expression::DataBlock;
 use common_expression::ScalarRef;
@@ -86,6 +87,8 @@ fn scalar_to_json(s: ScalarRef<'_>, format: &FormatSettings) -> JsonValue {
             NumberScalar::Float64(v) => {
                 JsonValue::Number(serde_json::Number::from_f64(v.into()).unwrap())
             }
+            NumberScalar::UInt128(v) => serde_json::to_value(v.to_string()).unwrap(),
+            NumberScalar::Int128(v) => serde_json::to_value(v.to_string()).unwrap(),
         },
         ScalarRef::Decimal(x) => serde_json::to_value(x.to_string()).unwrap(),
         ScalarRef::Date(v) => {
@@ -96,6 +99,11 @@ fn scalar_to_json(s: ScalarRef<'_>, format: &FormatSettings) -> JsonValue {
             let dt = DateConverter::to_timestamp(&v, format.timezone);
             serde_json::to_value(dt.format("%Y-%m-%d %H:%M:%S").to_string()).unwrap()
         }
+        ScalarRef::Interval(v) => serde_json::to_value(v.to_string()).unwrap(),
+        ScalarRef::Geometry(v) => match geometry_to_wkt(v) {
+            Ok(wkt) => JsonValue::String(wkt),
+            Err(_) => JsonValue::Null,
+        },
         ScalarRef::EmptyArray => JsonValue::Array(vec![]),
         ScalarRef::EmptyMap => JsonValue::Object(JsonMap::new()),
         ScalarRef::String(x) => JsonValue::String(String::from_utf8_lossy(x).to_string()),
@@ -71,10 +71,9 @@ impl<const STRINGS: bool, const COMPACT: bool, const WITH_NAMES: bool, const WIT
             .collect();
 
         let columns: Vec<Column> = block
-            .convert_to_full()
             .columns()
             .iter()
-            .map(|column| column.value.clone().into_column().unwrap())
+            .map(|entry| entry.to_column(rows_size))
             .collect();
 
         for row_index in 0..rows_size {
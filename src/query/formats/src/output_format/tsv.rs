@@ -76,10 +76,9 @@ impl<const WITH_NAMES: bool, const WITH_TYPES: bool> OutputFormat
         let rd = &self.record_delimiter;
 
         let columns: Vec<Column> = block
-            .convert_to_full()
             .columns()
             .iter()
-            .map(|column| column.value.clone().into_column().unwrap())
+            .map(|entry| entry.to_column(rows_size))
             .collect();
 
         for row_index in 0..rows_size {
@@ -133,3 +133,55 @@ async fn test_fast_values_decoder_multi() -> Result<()> {
     }
     Ok(())
 }
+
+#[tokio::test]
+async fn test_fast_values_decoder_parse_rows() -> Result<()> {
+    let data = "(1, 'a'), (2, 'b'),(3, 'c') , (4, 'd'), (5, 'e');";
+    let field_decoder = FastFieldDecoderValues::create_for_insert(FormatSettings::default());
+    let fallback = DummyFastValuesDecodeFallback {};
+
+    let mut offset = 0;
+    let mut chunks = vec![];
+    loop {
+        let mut values_decoder = FastValuesDecoder::new(&data[offset..], &field_decoder);
+        if offset != 0 {
+            values_decoder = values_decoder.with_leading_separator();
+        }
+        let mut columns = vec![
+            ColumnBuilder::with_capacity(&DataType::Number(NumberDataType::Int32), 2),
+            ColumnBuilder::with_capacity(&DataType::String, 2),
+        ];
+        let finished = values_decoder
+            .parse_rows(&mut columns, &fallback, 2)
+            .await?;
+        offset += values_decoder.position();
+        let columns = columns.into_iter().map(|cb| cb.build()).collect::<Vec<_>>();
+        chunks.push(DataBlock::new_from_columns(columns).num_rows());
+        if finished {
+            break;
+        }
+    }
+    assert_eq!(chunks, vec![2, 2, 1]);
+
+    // A trailing comma is still an error when it follows a chunk boundary.
+    let data = "(1, 'a'), (2, 'b'), ";
+    let mut values_decoder = FastValuesDecoder::new(data, &field_decoder);
+    let mut columns = vec![
+        ColumnBuilder::with_capacity(&DataType::Number(NumberDataType::Int32), 2),
+        ColumnBuilder::with_capacity(&DataType::String, 2),
+    ];
+    let finished = values_decoder
+        .parse_rows(&mut columns, &fallback, 2)
+        .await?;
+    assert!(!finished);
+    let offset = values_decoder.position();
+    let mut values_decoder =
+        FastValuesDecoder::new(&data[offset..], &field_decoder).with_leading_separator();
+    let result = values_decoder.parse_rows(&mut columns, &fallback, 2).await;
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        ErrorCode::BadDataValueType("Must start with parentheses".to_string()).to_string()
+    );
+
+    Ok(())
+}
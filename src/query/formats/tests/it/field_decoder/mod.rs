@@ -13,3 +13,5 @@
 // limitations under the License.
 
 mod fast_values;
+mod protobuf;
+mod separated_text;
@@ -0,0 +1,165 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use base64::engine::general_purpose;
+use base64::prelude::*;
+use common_exception::Result;
+use common_formats::FileFormatOptionsExt;
+use common_formats::ProtobufDecoder;
+use common_meta_app::principal::ProtobufFileFormatParams;
+use common_settings::Settings;
+use prost::Message;
+use prost_types::field_descriptor_proto::Label;
+use prost_types::field_descriptor_proto::Type;
+use prost_types::DescriptorProto;
+use prost_types::EnumDescriptorProto;
+use prost_types::EnumValueDescriptorProto;
+use prost_types::FieldDescriptorProto;
+use prost_types::FileDescriptorProto;
+use prost_types::FileDescriptorSet;
+use serde_json::json;
+
+fn field(
+    name: &str,
+    number: i32,
+    label: Label,
+    typ: Type,
+    type_name: &str,
+) -> FieldDescriptorProto {
+    FieldDescriptorProto {
+        name: Some(name.to_string()),
+        number: Some(number),
+        label: Some(label as i32),
+        r#type: Some(typ as i32),
+        type_name: (!type_name.is_empty()).then(|| type_name.to_string()),
+        ..Default::default()
+    }
+}
+
+// package test;
+// enum Kind { UNKNOWN = 0; CLICK = 1; }
+// message Point { int32 x = 1; int32 y = 2; }
+// message Event {
+//   string name = 1; sint64 delta = 2; repeated int32 ids = 3;
+//   Point point = 4; Kind kind = 5; bytes raw = 6;
+// }
+fn descriptor_set() -> String {
+    let file = FileDescriptorProto {
+        name: Some("test.proto".to_string()),
+        package: Some("test".to_string()),
+        message_type: vec![
+            DescriptorProto {
+                name: Some("Point".to_string()),
+                field: vec![
+                    field("x", 1, Label::Optional, Type::Int32, ""),
+                    field("y", 2, Label::Optional, Type::Int32, ""),
+                ],
+                ..Default::default()
+            },
+            DescriptorProto {
+                name: Some("Event".to_string()),
+                field: vec![
+                    field("name", 1, Label::Optional, Type::String, ""),
+                    field("delta", 2, Label::Optional, Type::Sint64, ""),
+                    field("ids", 3, Label::Repeated, Type::Int32, ""),
+                    field("point", 4, Label::Optional, Type::Message, ".test.Point"),
+                    field("kind", 5, Label::Optional, Type::Enum, ".test.Kind"),
+                    field("raw", 6, Label::Optional, Type::Bytes, ""),
+                ],
+                ..Default::default()
+            },
+        ],
+        enum_type: vec![EnumDescriptorProto {
+            name: Some("Kind".to_string()),
+            value: vec![
+                EnumValueDescriptorProto {
+                    name: Some("UNKNOWN".to_string()),
+                    number: Some(0),
+                    options: None,
+                },
+                EnumValueDescriptorProto {
+                    name: Some("CLICK".to_string()),
+                    number: Some(1),
+                    options: None,
+                },
+            ],
+            ..Default::default()
+        }],
+        syntax: Some("proto3".to_string()),
+        ..Default::default()
+    };
+    let set = FileDescriptorSet { file: vec![file] };
+    general_purpose::STANDARD.encode(set.encode_to_vec())
+}
+
+fn create_decoder(message_name: &str) -> Result<ProtobufDecoder> {
+    let settings = Settings::create("default".to_string());
+    let options = FileFormatOptionsExt::create_from_settings(&settings, false)?;
+    let params = ProtobufFileFormatParams {
+        descriptor_set: descriptor_set(),
+        message_name: message_name.to_string(),
+        ..Default::default()
+    };
+    ProtobufDecoder::try_create(&params, &options)
+}
+
+#[test]
+fn test_protobuf_decoder() -> Result<()> {
+    let decoder = create_decoder("test.Event")?;
+
+    let data = [
+        0x0a, 1, b'a', // name: "a"
+        0x10, 3, // delta: -2, zigzag encoded
+        0x1a, 2, 1, 2, // ids: [1, 2], packed
+        0x22, 2, 0x08, 1, // point: { x: 1 }
+        0x28, 1, // kind: CLICK
+        0x38, 7, // unknown field 7
+    ];
+    let value = decoder.read_message(&data)?;
+    assert_eq!(
+        value,
+        json!({
+            "name": "a",
+            "delta": -2,
+            "ids": [1, 2],
+            "point": { "x": 1, "y": 0 },
+            "kind": "CLICK",
+            "raw": "",
+        })
+    );
+
+    // all the fields are absent
+    let value = decoder.read_message(&[])?;
+    assert_eq!(
+        value,
+        json!({
+            "name": "",
+            "delta": 0,
+            "ids": [],
+            "point": null,
+            "kind": "UNKNOWN",
+            "raw": "",
+        })
+    );
+
+    // truncated
+    assert!(decoder.read_message(&[0x0a, 3, b'a']).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_protobuf_decoder_unknown_message() {
+    assert!(create_decoder("test.Missing").is_err());
+    assert!(create_decoder("Event").is_err());
+}
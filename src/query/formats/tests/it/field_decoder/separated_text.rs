@@ -0,0 +1,90 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::ColumnBuilder;
+use common_expression::ScalarRef;
+use common_formats::FileFormatOptionsExt;
+use common_formats::SeparatedTextDecoder;
+use common_meta_app::principal::BinaryFormat;
+use common_meta_app::principal::ColumnFormatParams;
+use common_meta_app::principal::CsvFileFormatParams;
+use common_settings::Settings;
+
+fn create_decoder(column_formats: Vec<Option<ColumnFormatParams>>) -> Result<SeparatedTextDecoder> {
+    let settings = Settings::create("default".to_string());
+    let mut options = FileFormatOptionsExt::create_from_settings(&settings, false)?;
+    options.column_formats = column_formats;
+    Ok(SeparatedTextDecoder::create_csv(
+        &CsvFileFormatParams::default(),
+        &options,
+    ))
+}
+
+#[test]
+fn test_read_column_field_with_format() -> Result<()> {
+    let decoder = create_decoder(vec![
+        Some(ColumnFormatParams {
+            timestamp_format: Some("%d/%m/%Y %H:%M".to_string()),
+            date_format: Some("%d/%m/%Y".to_string()),
+            ..Default::default()
+        }),
+        Some(ColumnFormatParams {
+            binary_format: Some(BinaryFormat::Hex),
+            null_display: Some("-".to_string()),
+            ..Default::default()
+        }),
+        None,
+    ])?;
+
+    let mut column = ColumnBuilder::with_capacity(&DataType::Timestamp, 1);
+    decoder.read_column_field(0, &mut column, b"02/01/2023 10:30")?;
+    assert!(
+        decoder
+            .read_column_field(0, &mut column, b"2023-01-02 10:30")
+            .is_err()
+    );
+    assert_eq!(
+        column.build().index(0),
+        Some(ScalarRef::Timestamp(1_672_655_400_000_000))
+    );
+
+    let mut column = ColumnBuilder::with_capacity(&DataType::Date, 1);
+    decoder.read_column_field(0, &mut column, b"02/01/2023")?;
+    assert_eq!(column.build().index(0), Some(ScalarRef::Date(19359)));
+
+    let data_type = DataType::Nullable(Box::new(DataType::String));
+    let mut column = ColumnBuilder::with_capacity(&data_type, 2);
+    decoder.read_column_field(1, &mut column, b"6869")?;
+    decoder.read_column_field(1, &mut column, b"-")?;
+    assert!(decoder.read_column_field(1, &mut column, b"xyz").is_err());
+    let column = column.build();
+    assert_eq!(column.index(0), Some(ScalarRef::String(b"hi")));
+    assert_eq!(column.index(1), Some(ScalarRef::Null));
+
+    // the columns without overrides are read as usual
+    let mut column = ColumnBuilder::with_capacity(&DataType::Timestamp, 1);
+    assert!(
+        decoder
+            .read_column_field(2, &mut column, b"02/01/2023 10:30")
+            .is_err()
+    );
+    decoder.read_column_field(2, &mut column, b"2023-01-02 10:30:00")?;
+    assert_eq!(
+        column.build().index(0),
+        Some(ScalarRef::Timestamp(1_672_655_400_000_000))
+    );
+    Ok(())
+}
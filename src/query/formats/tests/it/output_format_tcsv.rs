@@ -16,10 +16,16 @@ use std::collections::BTreeMap;
 
 use common_exception::Result;
 use common_expression::types::number::Int32Type;
+use common_expression::types::DataType;
 use common_expression::types::NumberDataType;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
 use common_expression::FromData;
+use common_expression::Scalar;
 use common_expression::TableDataType;
 use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_expression::Value;
 use common_formats::FileFormatOptionsExt;
 use common_meta_app::principal::FileFormatOptionsAst;
 use common_meta_app::principal::FileFormatParams;
@@ -111,6 +117,43 @@ fn test_null() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_scalar() -> Result<()> {
+    let schema = TableSchemaRefExt::create(vec![
+        TableField::new("c1", TableDataType::Number(NumberDataType::Int32)),
+        TableField::new("c2", TableDataType::String),
+        TableField::new("c3", TableDataType::String.wrap_nullable()),
+    ]);
+    // The scalars are written as constant columns without being repeated.
+    let block = DataBlock::new(
+        vec![
+            BlockEntry::new(
+                DataType::Number(NumberDataType::Int32),
+                Value::Column(Int32Type::from_data(vec![1i32, 2, 3])),
+            ),
+            BlockEntry::new(
+                DataType::String,
+                Value::Scalar(Scalar::String(b"x".to_vec())),
+            ),
+            BlockEntry::new(
+                DataType::String.wrap_nullable(),
+                Value::Scalar(Scalar::Null),
+            ),
+        ],
+        3,
+    );
+
+    {
+        let mut formatter = get_output_format_clickhouse("tsv", schema)?;
+        let buffer = formatter.serialize_block(&block)?;
+
+        let tsv_block = String::from_utf8(buffer)?;
+        let expect = "1\tx\t\\N\n2\tx\t\\N\n3\tx\t\\N\n";
+        assert_eq!(&tsv_block, expect);
+    }
+    Ok(())
+}
+
 #[test]
 fn test_data_block_nullable() -> Result<()> {
     test_data_block(true)
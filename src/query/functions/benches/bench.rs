@@ -19,9 +19,15 @@ extern crate criterion;
 mod parser;
 
 use common_expression::type_check;
+use common_expression::types::DataType;
+use common_expression::types::Int64Type;
+use common_expression::types::NumberDataType;
+use common_expression::BlockEntry;
 use common_expression::DataBlock;
 use common_expression::Evaluator;
+use common_expression::FromData;
 use common_expression::FunctionContext;
+use common_expression::Value;
 use common_functions::BUILTIN_FUNCTIONS;
 use criterion::Criterion;
 
@@ -50,5 +56,69 @@ fn bench(c: &mut Criterion) {
     }
 }
 
-criterion_group!(benches, bench);
+fn bench_nullable(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bench_nullable");
+
+    let n = 65536;
+    let ty = DataType::Nullable(Box::new(DataType::Number(NumberDataType::Int64)));
+    let columns = [("a", ty.clone()), ("b", ty.clone())];
+    let func_ctx = FunctionContext::default();
+
+    for (validity_name, valid) in [
+        ("all_valid", (|_| true) as fn(usize) -> bool),
+        ("all_null", |_| false),
+        ("half_null", |i| i % 2 == 0),
+    ] {
+        let column = Int64Type::from_data_with_validity(
+            (0..n as i64).collect(),
+            (0..n).map(valid).collect(),
+        );
+        let block = DataBlock::new(
+            vec![
+                BlockEntry::new(ty.clone(), Value::Column(column.clone())),
+                BlockEntry::new(ty.clone(), Value::Column(column)),
+            ],
+            n,
+        );
+        let evaluator = Evaluator::new(&block, &func_ctx, &BUILTIN_FUNCTIONS);
+
+        for (func_name, text) in [("plus", "a + b"), ("gt", "a > b")] {
+            let raw_expr = parser::parse_raw_expr(text, &columns);
+            let expr = type_check::check(&raw_expr, &BUILTIN_FUNCTIONS).unwrap();
+
+            group.bench_function(format!("{func_name}/{validity_name}"), |b| {
+                b.iter(|| evaluator.run(&expr))
+            });
+        }
+    }
+}
+
+fn bench_if(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bench_if");
+
+    let n = 65536;
+    let ty = DataType::Number(NumberDataType::Int64);
+    let columns = [("a", ty.clone())];
+    let func_ctx = FunctionContext::default();
+
+    let column = Int64Type::from_data((0..n as i64).collect());
+    let block = DataBlock::new(vec![BlockEntry::new(ty, Value::Column(column))], n);
+    let evaluator = Evaluator::new(&block, &func_ctx, &BUILTIN_FUNCTIONS);
+
+    for branches in [2, 8, 16] {
+        let text = "if(".to_string()
+            + &(0..branches)
+                .map(|i| format!("a % {branches} = {i}, a + {i}, "))
+                .collect::<String>()
+            + "a)";
+        let raw_expr = parser::parse_raw_expr(&text, &columns);
+        let expr = type_check::check(&raw_expr, &BUILTIN_FUNCTIONS).unwrap();
+
+        group.bench_function(format!("branches/{branches}"), |b| {
+            b.iter(|| evaluator.run(&expr))
+        });
+    }
+}
+
+criterion_group!(benches, bench, bench_nullable, bench_if);
 criterion_main!(benches);
@@ -116,16 +116,16 @@ impl<const NULLABLE_RESULT: bool> AggregateFunction for AggregateNullUnaryAdapto
         let col = &columns[0];
         let validity = column_merge_validity(col, validity.cloned());
         let not_null_column = col.remove_nullable();
-
-        self.nested
-            .accumulate(place, &[not_null_column], validity.as_ref(), input_rows)?;
-
-        if validity
-            .as_ref()
-            .map(|c| c.unset_bits() != input_rows)
-            .unwrap_or(true)
-        {
-            self.set_flag(place, 1);
+        match validity {
+            // all nulls
+            Some(v) if v.unset_bits() == input_rows => {}
+            validity => {
+                // Without nulls, the nested function can take the dense path.
+                let validity = validity.filter(|v| v.unset_bits() > 0);
+                self.nested
+                    .accumulate(place, &[not_null_column], validity.as_ref(), input_rows)?;
+                self.set_flag(place, 1);
+            }
         }
         Ok(())
     }
@@ -121,15 +121,16 @@ impl<const NULLABLE_RESULT: bool> AggregateFunction
             not_null_columns.push(col.remove_nullable());
         }
 
-        self.nested
-            .accumulate(place, &not_null_columns, validity.as_ref(), input_rows)?;
-
-        if validity
-            .as_ref()
-            .map(|c| c.unset_bits() != input_rows)
-            .unwrap_or(true)
-        {
-            self.set_flag(place, 1);
+        match validity {
+            // all nulls
+            Some(v) if v.unset_bits() == input_rows => {}
+            validity => {
+                // Without nulls, the nested function can take the dense path.
+                let validity = validity.filter(|v| v.unset_bits() > 0);
+                self.nested
+                    .accumulate(place, &not_null_columns, validity.as_ref(), input_rows)?;
+                self.set_flag(place, 1);
+            }
         }
         Ok(())
     }
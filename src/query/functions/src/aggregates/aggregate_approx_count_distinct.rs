@@ -168,6 +168,7 @@ pub fn try_create_aggregate_approx_count_distinct_function(
 pub fn aggregate_approx_count_distinct_function_desc() -> AggregateFunctionDescription {
     let features = super::aggregate_function_factory::AggregateFunctionFeatures {
         returns_default_when_only_null: true,
+        state_arguments: Some(vec![DataType::String]),
         ..Default::default()
     };
 
@@ -69,7 +69,12 @@ where
     T: ValueType + Sync + Send,
     TSum: ValueType,
     T::Scalar: Number + AsPrimitive<TSum::Scalar>,
-    TSum::Scalar: Number + AsPrimitive<f64> + Serialize + DeserializeOwned + std::ops::AddAssign,
+    TSum::Scalar: Number
+        + AsPrimitive<f64>
+        + Serialize
+        + DeserializeOwned
+        + std::ops::AddAssign
+        + std::ops::SubAssign,
 {
     fn add(&mut self, other: T::ScalarRef<'_>) -> Result<()> {
         self.count += 1;
@@ -78,6 +83,18 @@ where
         Ok(())
     }
 
+    fn support_retract() -> bool {
+        // Subtracting floats accumulates rounding errors.
+        !TSum::Scalar::FLOATING
+    }
+
+    fn retract(&mut self, other: T::ScalarRef<'_>) -> Result<()> {
+        self.count -= 1;
+        let other = T::to_owned_scalar(other).as_();
+        self.value -= other;
+        Ok(())
+    }
+
     fn merge(&mut self, rhs: &Self) -> Result<()> {
         self.count += rhs.count;
         self.value += rhs.value;
@@ -139,14 +156,14 @@ where
     }
 }
 
-impl<const OVERFLOW: bool, T> UnaryState<T, T> for DecimalAvgState<OVERFLOW, T>
+impl<const OVERFLOW: bool, T> DecimalAvgState<OVERFLOW, T>
 where
     T: ValueType,
-    T::Scalar: Decimal + std::ops::AddAssign + Serialize + DeserializeOwned,
+    T::Scalar: Decimal + std::ops::AddAssign + std::ops::SubAssign + Serialize + DeserializeOwned,
 {
-    fn add(&mut self, other: T::ScalarRef<'_>) -> Result<()> {
-        self.count += 1;
-        self.value += T::to_owned_scalar(other);
+    fn add_value(&mut self, value: T::Scalar, count: u64) -> Result<()> {
+        self.count += count;
+        self.value += value;
         if OVERFLOW && (self.value > T::Scalar::MAX || self.value < T::Scalar::MIN) {
             return Err(ErrorCode::Overflow(format!(
                 "Decimal overflow: {:?} not in [{}, {}]",
@@ -157,9 +174,29 @@ where
         }
         Ok(())
     }
+}
+
+impl<const OVERFLOW: bool, T> UnaryState<T, T> for DecimalAvgState<OVERFLOW, T>
+where
+    T: ValueType,
+    T::Scalar: Decimal + std::ops::AddAssign + std::ops::SubAssign + Serialize + DeserializeOwned,
+{
+    fn add(&mut self, other: T::ScalarRef<'_>) -> Result<()> {
+        self.add_value(T::to_owned_scalar(other), 1)
+    }
+
+    fn support_retract() -> bool {
+        true
+    }
+
+    fn retract(&mut self, other: T::ScalarRef<'_>) -> Result<()> {
+        self.count -= 1;
+        self.value -= T::to_owned_scalar(other);
+        Ok(())
+    }
 
     fn merge(&mut self, rhs: &Self) -> Result<()> {
-        self.add(T::to_scalar_ref(&rhs.value))
+        self.add_value(rhs.value, rhs.count)
     }
 
     fn merge_result(
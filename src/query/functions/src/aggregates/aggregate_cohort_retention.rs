@@ -0,0 +1,302 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::collections::HashMap;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::group_hash_column;
+use common_expression::type_check::check_number;
+use common_expression::types::number::Number;
+use common_expression::types::ArgType;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberType;
+use common_expression::types::ValueType;
+use common_expression::with_integer_mapped_type;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use num_traits::AsPrimitive;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::deserialize_state;
+use super::serialize_state;
+use super::AggregateFunctionRef;
+use super::AggregateNullVariadicAdaptor;
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::assert_binary_arguments;
+use crate::aggregates::assert_unary_params;
+use crate::aggregates::AggregateFunction;
+use crate::BUILTIN_FUNCTIONS;
+
+const MAX_PERIODS: u64 = 64;
+
+/// Maps the hash of every user seen to a bitmask of the periods the user was active in.
+#[derive(Serialize, Deserialize, Default)]
+struct AggregateCohortRetentionState {
+    pub users: HashMap<u64, u64>,
+}
+
+impl AggregateCohortRetentionState {
+    #[inline(always)]
+    fn add(&mut self, user: u64, period: u8) {
+        *self.users.entry(user).or_insert(0) |= 1 << period;
+    }
+
+    fn merge(&mut self, other: &Self) {
+        for (user, periods) in other.users.iter() {
+            *self.users.entry(*user).or_insert(0) |= *periods;
+        }
+    }
+}
+
+/// `cohort_retention(n)(user, period)` counts the distinct users active in period `0`
+/// that are also active in each of the periods `0..n`, so element `0` of the result
+/// is the size of the cohort.
+#[derive(Clone)]
+pub struct AggregateCohortRetentionFunction<T> {
+    display_name: String,
+    periods: u8,
+    t: PhantomData<T>,
+}
+
+impl<T> AggregateCohortRetentionFunction<T>
+where
+    T: ArgType + Send + Sync,
+    T::Scalar: Number + AsPrimitive<i64>,
+{
+    #[inline(always)]
+    fn period(&self, value: T::ScalarRef<'_>) -> Option<u8> {
+        let period: i64 = T::to_owned_scalar(value).as_();
+        if period >= 0 && period < self.periods as i64 {
+            Some(period as u8)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> AggregateFunction for AggregateCohortRetentionFunction<T>
+where
+    T: ArgType + Send + Sync,
+    T::Scalar: Number + AsPrimitive<i64>,
+{
+    fn name(&self) -> &str {
+        "AggregateCohortRetentionFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(DataType::Array(Box::new(DataType::Number(
+            NumberDataType::UInt64,
+        ))))
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(AggregateCohortRetentionState::default);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateCohortRetentionState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let users = group_hash_column(&columns[0]);
+        let pcolumn = T::try_downcast_column(&columns[1]).unwrap();
+        let state = place.get::<AggregateCohortRetentionState>();
+
+        for (row, (user, period)) in users.iter().zip(T::iter_column(&pcolumn)).enumerate() {
+            if validity.map(|v| v.get_bit(row)).unwrap_or(true) {
+                if let Some(period) = self.period(period) {
+                    state.add(*user, period);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[Column],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let users = group_hash_column(&columns[0]);
+        let pcolumn = T::try_downcast_column(&columns[1]).unwrap();
+
+        for ((user, period), place) in users
+            .iter()
+            .zip(T::iter_column(&pcolumn))
+            .zip(places.iter())
+        {
+            if let Some(period) = self.period(period) {
+                let state = place.next(offset).get::<AggregateCohortRetentionState>();
+                state.add(*user, period);
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        let pcolumn = T::try_downcast_column(&columns[1]).unwrap();
+        let period = unsafe { T::index_column_unchecked(&pcolumn, row) };
+        if let Some(period) = self.period(period) {
+            let user = group_hash_column(&columns[0].slice(row..row + 1))[0];
+            let state = place.get::<AggregateCohortRetentionState>();
+            state.add(user, period);
+        }
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        let state = place.get::<AggregateCohortRetentionState>();
+        serialize_state(writer, state)
+    }
+
+    fn merge(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateCohortRetentionState>();
+        let rhs: AggregateCohortRetentionState = deserialize_state(reader)?;
+        state.merge(&rhs);
+        Ok(())
+    }
+
+    fn merge_states(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateCohortRetentionState>();
+        let other = rhs.get::<AggregateCohortRetentionState>();
+        state.merge(other);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        let state = place.get::<AggregateCohortRetentionState>();
+        let mut counts = vec![0u64; self.periods as usize];
+        for periods in state.users.values() {
+            if periods & 1 == 0 {
+                continue;
+            }
+            for (i, count) in counts.iter_mut().enumerate() {
+                if periods & (1 << i) != 0 {
+                    *count += 1;
+                }
+            }
+        }
+
+        let builder = builder.as_array_mut().unwrap();
+        let inner = builder
+            .builder
+            .as_number_mut()
+            .unwrap()
+            .as_u_int64_mut()
+            .unwrap();
+        inner.extend_from_slice(&counts);
+        builder.offsets.push(builder.builder.len() as u64);
+        Ok(())
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<AggregateCohortRetentionState>();
+        std::ptr::drop_in_place(state);
+    }
+
+    fn get_own_null_adaptor(
+        &self,
+        _nested_function: AggregateFunctionRef,
+        _params: Vec<Scalar>,
+        _arguments: Vec<DataType>,
+    ) -> Result<Option<AggregateFunctionRef>> {
+        Ok(Some(AggregateNullVariadicAdaptor::<false>::create(
+            Arc::new(self.clone()),
+        )))
+    }
+}
+
+impl<T> fmt::Display for AggregateCohortRetentionFunction<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl<T> AggregateCohortRetentionFunction<T>
+where
+    T: ArgType + Send + Sync,
+    T::Scalar: Number + AsPrimitive<i64>,
+{
+    pub fn try_create(display_name: &str, params: Vec<Scalar>) -> Result<AggregateFunctionRef> {
+        let periods = check_number::<_, u64>(
+            None,
+            &FunctionContext::default(),
+            &Expr::<usize>::Constant {
+                span: None,
+                scalar: params[0].clone(),
+                data_type: params[0].as_ref().infer_data_type(),
+            },
+            &BUILTIN_FUNCTIONS,
+        )?;
+        if periods == 0 || periods > MAX_PERIODS {
+            return Err(ErrorCode::BadArguments(format!(
+                "The number of periods of {} must be between 1 and {}, but got {}",
+                display_name, MAX_PERIODS, periods
+            )));
+        }
+
+        Ok(Arc::new(Self {
+            display_name: display_name.to_owned(),
+            periods: periods as u8,
+            t: PhantomData,
+        }))
+    }
+}
+
+pub fn try_create_aggregate_cohort_retention_function(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_params(display_name, params.len())?;
+    assert_binary_arguments(display_name, arguments.len())?;
+
+    with_integer_mapped_type!(|NUM_TYPE| match &arguments[1] {
+        DataType::Number(NumberDataType::NUM_TYPE) => AggregateCohortRetentionFunction::<
+            NumberType<NUM_TYPE>,
+        >::try_create(display_name, params,),
+        _ => Err(ErrorCode::BadDataValueType(format!(
+            "The period of {} must be an integer, but got {}",
+            display_name, arguments[1]
+        ))),
+    })
+}
+
+pub fn aggregate_cohort_retention_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_cohort_retention_function))
+}
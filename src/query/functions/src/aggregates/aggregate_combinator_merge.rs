@@ -0,0 +1,180 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::StringType;
+use common_expression::types::ValueType;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::Scalar;
+
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionCreator;
+use crate::aggregates::aggregate_function_factory::CombinatorDescription;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+/// Merges the serialized states produced by the `_state` combinator, so states
+/// stored in a table column can be rolled up again.
+///
+/// `xxx_merge` returns the final result of `xxx`, while `xxx_merge_state` returns
+/// the merged state again.
+#[derive(Clone)]
+pub struct AggregateMergeCombinator {
+    name: String,
+    nested: AggregateFunctionRef,
+    keep_state: bool,
+}
+
+impl AggregateMergeCombinator {
+    /// `state_arguments` are the argument types the nested function is instantiated with,
+    /// the input of the combinator itself is always the serialized state.
+    pub fn try_create(
+        nested_name: &str,
+        params: Vec<Scalar>,
+        state_arguments: Vec<DataType>,
+        nested_creator: &AggregateFunctionCreator,
+        keep_state: bool,
+    ) -> Result<AggregateFunctionRef> {
+        let name = if keep_state {
+            format!("MergeStateCombinator({nested_name})")
+        } else {
+            format!("MergeCombinator({nested_name})")
+        };
+        let nested = nested_creator(nested_name, params, state_arguments)?;
+
+        Ok(Arc::new(AggregateMergeCombinator {
+            name,
+            nested,
+            keep_state,
+        }))
+    }
+
+    pub fn combinator_desc() -> CombinatorDescription {
+        CombinatorDescription::creator(Box::new(|name, params, arguments, creator| {
+            Self::try_create(name, params, arguments, creator, false)
+        }))
+    }
+
+    pub fn combinator_state_desc() -> CombinatorDescription {
+        CombinatorDescription::creator(Box::new(|name, params, arguments, creator| {
+            Self::try_create(name, params, arguments, creator, true)
+        }))
+    }
+}
+
+impl AggregateFunction for AggregateMergeCombinator {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        if self.keep_state {
+            Ok(DataType::String)
+        } else {
+            self.nested.return_type()
+        }
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        self.nested.init_state(place);
+    }
+
+    fn is_state(&self) -> bool {
+        self.keep_state
+    }
+
+    fn state_layout(&self) -> Layout {
+        self.nested.state_layout()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let column = StringType::try_downcast_column(&columns[0]).unwrap();
+        for (row, mut state) in column.iter().enumerate() {
+            if validity.map(|v| v.get_bit(row)).unwrap_or(true) {
+                self.nested.merge(place, &mut state)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[Column],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let column = StringType::try_downcast_column(&columns[0]).unwrap();
+        for (mut state, place) in column.iter().zip(places.iter()) {
+            self.nested.merge(place.next(offset), &mut state)?;
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        let column = StringType::try_downcast_column(&columns[0]).unwrap();
+        let mut state = unsafe { column.index_unchecked(row) };
+        self.nested.merge(place, &mut state)
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        self.nested.serialize(place, writer)
+    }
+
+    fn merge(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        self.nested.merge(place, reader)
+    }
+
+    fn merge_states(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        self.nested.merge_states(place, rhs)
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        if self.keep_state {
+            let str_builder = builder.as_string_mut().unwrap();
+            self.serialize(place, &mut str_builder.data)?;
+            str_builder.commit_row();
+            Ok(())
+        } else {
+            self.nested.merge_result(place, builder)
+        }
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        self.nested.need_manual_drop_state()
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        self.nested.drop_state(place);
+    }
+}
+
+impl fmt::Display for AggregateMergeCombinator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
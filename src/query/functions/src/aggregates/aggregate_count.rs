@@ -58,6 +58,7 @@ impl AggregateCountFunction {
         let features = super::aggregate_function_factory::AggregateFunctionFeatures {
             returns_default_when_only_null: true,
             is_decomposable: true,
+            state_arguments: Some(vec![]),
             ..Default::default()
         };
         AggregateFunctionDescription::creator_with_features(Box::new(Self::try_create), features)
@@ -148,6 +149,16 @@ impl AggregateFunction for AggregateCountFunction {
         Ok(())
     }
 
+    fn support_retract(&self) -> bool {
+        true
+    }
+
+    fn retract_row(&self, place: StateAddr, _columns: &[Column], _row: usize) -> Result<()> {
+        let state = place.get::<AggregateCountState>();
+        state.count -= 1;
+        Ok(())
+    }
+
     fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
         let state = place.get::<AggregateCountState>();
         serialize_state(writer, &state.count)
@@ -27,6 +27,8 @@ use crate::aggregates::AggregateFunctionRef;
 use crate::aggregates::Aggregators;
 
 const STATE_SUFFIX: &str = "_state";
+const MERGE_SUFFIX: &str = "_merge";
+const MERGE_STATE_SUFFIX: &str = "_merge_state";
 
 pub type AggregateFunctionCreator =
     Box<dyn Fn(&str, Vec<Scalar>, Vec<DataType>) -> Result<AggregateFunctionRef> + Sync + Send>;
@@ -73,6 +75,11 @@ pub struct AggregateFunctionFeatures {
     ///   AVG(C) = SUM(C) / COUNT(C)
     pub(crate) is_decomposable: bool,
 
+    /// The argument types to instantiate the function with when merging the states
+    /// stored by the `_state` combinator, see `AggregateMergeCombinator`.
+    /// Only set when the state doesn't depend on the argument types.
+    pub(crate) state_arguments: Option<Vec<DataType>>,
+
     // Function Category
     pub category: &'static str,
     // Introduce the function in brief.
@@ -184,9 +191,12 @@ impl AggregateFunctionFactory {
         }
 
         if !arguments.is_empty() && arguments.iter().any(|f| f.is_nullable_or_null()) {
-            let (new_params, new_arguments) = match name.to_lowercase().strip_suffix(STATE_SUFFIX) {
-                Some(_) => (params.clone(), arguments.clone()),
-                None => {
+            let lowercase_name = name.to_lowercase();
+            let (new_params, new_arguments) = match lowercase_name.strip_suffix(STATE_SUFFIX) {
+                Some(_) if !lowercase_name.ends_with(MERGE_STATE_SUFFIX) => {
+                    (params.clone(), arguments.clone())
+                }
+                _ => {
                     let new_params = AggregateFunctionCombinatorNull::transform_params(&params)?;
                     let new_arguments =
                         AggregateFunctionCombinatorNull::transform_arguments(&arguments)?;
@@ -242,9 +252,27 @@ impl AggregateFunctionFactory {
                     }
                     Some(nested_desc) => {
                         *features = nested_desc.features.clone();
-                        if suffix.eq_ignore_ascii_case(STATE_SUFFIX) {
+                        if suffix.eq_ignore_ascii_case(STATE_SUFFIX)
+                            || suffix.eq_ignore_ascii_case(MERGE_STATE_SUFFIX)
+                        {
                             features.returns_default_when_only_null = true;
                         }
+                        if suffix.eq_ignore_ascii_case(MERGE_SUFFIX)
+                            || suffix.eq_ignore_ascii_case(MERGE_STATE_SUFFIX)
+                        {
+                            let state_arguments = Self::merge_state_arguments(
+                                name,
+                                nested_name,
+                                nested_desc,
+                                &arguments,
+                            )?;
+                            return (desc.creator)(
+                                nested_name,
+                                params,
+                                state_arguments,
+                                &nested_desc.aggregate_function_creator,
+                            );
+                        }
                         return (desc.creator)(
                             nested_name,
                             params,
@@ -262,6 +290,32 @@ impl AggregateFunctionFactory {
         )))
     }
 
+    fn merge_state_arguments(
+        name: &str,
+        nested_name: &str,
+        nested_desc: &AggregateFunctionDescription,
+        arguments: &[DataType],
+    ) -> Result<Vec<DataType>> {
+        if arguments.len() != 1 || arguments[0] != DataType::String {
+            return Err(ErrorCode::BadArguments(format!(
+                "{} expects a single String argument holding the aggregate state, but got ({})",
+                name,
+                arguments
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )));
+        }
+
+        nested_desc.features.state_arguments.clone().ok_or_else(|| {
+            ErrorCode::BadArguments(format!(
+                "The state of {} depends on its argument types and can not be merged by {}",
+                nested_name, name
+            ))
+        })
+    }
+
     pub fn contains(&self, func_name: impl AsRef<str>) -> bool {
         let origin = func_name.as_ref();
         let lowercase_name = origin.to_lowercase();
@@ -40,6 +40,7 @@ use serde::Serialize;
 use super::deserialize_state;
 use super::serialize_state;
 use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionFeatures;
 use crate::aggregates::assert_params;
 use crate::aggregates::assert_unary_arguments;
 use crate::aggregates::AggregateFunction;
@@ -499,13 +500,23 @@ pub fn try_create_aggregate_quantile_tdigest_function<const TYPE: u8>(
 }
 
 pub fn aggregate_quantile_tdigest_function_desc() -> AggregateFunctionDescription {
-    AggregateFunctionDescription::creator(Box::new(
-        try_create_aggregate_quantile_tdigest_function::<QUANTILE>,
-    ))
+    AggregateFunctionDescription::creator_with_features(
+        Box::new(try_create_aggregate_quantile_tdigest_function::<QUANTILE>),
+        tdigest_features(),
+    )
 }
 
 pub fn aggregate_median_tdigest_function_desc() -> AggregateFunctionDescription {
-    AggregateFunctionDescription::creator(Box::new(
-        try_create_aggregate_quantile_tdigest_function::<MEDIAN>,
-    ))
+    AggregateFunctionDescription::creator_with_features(
+        Box::new(try_create_aggregate_quantile_tdigest_function::<MEDIAN>),
+        tdigest_features(),
+    )
+}
+
+// The t-digest only keeps `f64` centroids, whatever the input type is.
+fn tdigest_features() -> AggregateFunctionFeatures {
+    AggregateFunctionFeatures {
+        state_arguments: Some(vec![DataType::Number(NumberDataType::Float64)]),
+        ..Default::default()
+    }
 }
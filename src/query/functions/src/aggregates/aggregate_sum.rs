@@ -89,7 +89,12 @@ where
     T: ValueType + Sync + Send,
     R: ValueType,
     T::Scalar: Number + AsPrimitive<R::Scalar>,
-    R::Scalar: Number + AsPrimitive<f64> + Serialize + DeserializeOwned + std::ops::AddAssign,
+    R::Scalar: Number
+        + AsPrimitive<f64>
+        + Serialize
+        + DeserializeOwned
+        + std::ops::AddAssign
+        + std::ops::SubAssign,
 {
     fn add(&mut self, other: T::ScalarRef<'_>) -> Result<()> {
         let other = T::to_owned_scalar(other).as_();
@@ -97,6 +102,17 @@ where
         Ok(())
     }
 
+    fn support_retract() -> bool {
+        // Subtracting floats accumulates rounding errors.
+        !R::Scalar::FLOATING
+    }
+
+    fn retract(&mut self, other: T::ScalarRef<'_>) -> Result<()> {
+        let other = T::to_owned_scalar(other).as_();
+        self.value -= other;
+        Ok(())
+    }
+
     fn merge(&mut self, rhs: &Self) -> Result<()> {
         self.value += rhs.value;
         Ok(())
@@ -145,7 +161,7 @@ where
 impl<const OVERFLOW: bool, T> UnaryState<T, T> for DecimalSumState<OVERFLOW, T>
 where
     T: ValueType,
-    T::Scalar: Decimal + std::ops::AddAssign + Serialize + DeserializeOwned,
+    T::Scalar: Decimal + std::ops::AddAssign + std::ops::SubAssign + Serialize + DeserializeOwned,
 {
     fn add(&mut self, other: T::ScalarRef<'_>) -> Result<()> {
         self.value += T::to_owned_scalar(other);
@@ -160,6 +176,16 @@ where
         Ok(())
     }
 
+    fn support_retract() -> bool {
+        true
+    }
+
+    fn retract(&mut self, other: T::ScalarRef<'_>) -> Result<()> {
+        // The value has been added before, so the sum goes back to a value it has already had.
+        self.value -= T::to_owned_scalar(other);
+        Ok(())
+    }
+
     fn merge(&mut self, rhs: &Self) -> Result<()> {
         self.add(T::to_scalar_ref(&rhs.value))
     }
@@ -22,6 +22,7 @@ use std::sync::Arc;
 
 use common_arrow::arrow::bitmap::Bitmap;
 use common_base::base::take_mut;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::types::decimal::Decimal128Type;
 use common_expression::types::decimal::Decimal256Type;
@@ -41,6 +42,15 @@ where
 {
     fn add(&mut self, other: T::ScalarRef<'_>) -> Result<()>;
 
+    /// If a value added by `add` can be removed again by `retract`.
+    fn support_retract() -> bool {
+        false
+    }
+
+    fn retract(&mut self, _other: T::ScalarRef<'_>) -> Result<()> {
+        Err(ErrorCode::Unimplemented("retract is not supported"))
+    }
+
     fn merge(&mut self, rhs: &Self) -> Result<()>;
 
     fn merge_result(
@@ -221,6 +231,18 @@ where
         Ok(())
     }
 
+    fn support_retract(&self) -> bool {
+        S::support_retract()
+    }
+
+    fn retract_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        let column = T::try_downcast_column(&columns[0]).unwrap();
+        let value = T::index_column(&column, row);
+
+        let state: &mut S = place.get::<S>();
+        state.retract(value.unwrap())
+    }
+
     fn accumulate_keys(
         &self,
         places: &[StateAddr],
@@ -37,9 +37,11 @@ use super::aggregate_window_funnel::aggregate_window_funnel_function_desc;
 use super::AggregateCountFunction;
 use super::AggregateFunctionFactory;
 use super::AggregateIfCombinator;
+use super::AggregateMergeCombinator;
 use crate::aggregates::aggregate_array_agg_function_desc;
 use crate::aggregates::aggregate_array_moving_avg_function_desc;
 use crate::aggregates::aggregate_array_moving_sum_function_desc;
+use crate::aggregates::aggregate_cohort_retention_function_desc;
 use crate::aggregates::aggregate_kurtosis_function_desc;
 use crate::aggregates::aggregate_median_function_desc;
 use crate::aggregates::aggregate_median_tdigest_function_desc;
@@ -98,6 +100,10 @@ impl Aggregators {
             aggregate_approx_count_distinct_function_desc(),
         );
         factory.register("retention", aggregate_retention_function_desc());
+        factory.register(
+            "cohort_retention",
+            aggregate_cohort_retention_function_desc(),
+        );
         factory.register("array_agg", aggregate_array_agg_function_desc());
         factory.register("list", aggregate_array_agg_function_desc());
         factory.register(
@@ -139,6 +145,12 @@ impl Aggregators {
     pub fn register_combinator(factory: &mut AggregateFunctionFactory) {
         factory.register_combinator("_if", AggregateIfCombinator::combinator_desc());
         factory.register_combinator("_distinct", aggregate_combinator_distinct_desc());
+        // `_merge_state` must be matched before `_state`.
+        factory.register_combinator(
+            "_merge_state",
+            AggregateMergeCombinator::combinator_state_desc(),
+        );
+        factory.register_combinator("_merge", AggregateMergeCombinator::combinator_desc());
         factory.register_combinator("_state", AggregateStateCombinator::combinator_desc());
     }
 }
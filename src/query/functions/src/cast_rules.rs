@@ -12,6 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+//! The matrix of implicit type coercions.
+//!
+//! An argument is implicitly cast to the type of a function signature only if the pair
+//! of types is in the cast rules of the function, see `FunctionRegistry::get_auto_cast_rules`:
+//!
+//! - `GENERAL_CAST_RULES` apply everywhere: integers and floats are widened, dates
+//!   are widened to timestamps, and strings are parsed as timestamps, dates or booleans.
+//! - `CAST_FROM_STRING_RULES` parse strings as numbers, except for comparisons.
+//! - `CAST_FROM_VARIANT_RULES` extract variants with `TRY_CAST`.
+//!
+//! When the setting `type_coercion_mode` is `strict`, only the coercions accepted by
+//! `is_lossless_cast` are allowed, the others must be written as explicit `CAST`s.
+
+use common_expression::type_check::can_auto_cast_to;
 use common_expression::type_check::ALL_SIMPLE_CAST_FUNCTIONS;
 use common_expression::types::DataType;
 use common_expression::types::NumberDataType;
@@ -200,8 +214,109 @@ pub const GENERAL_CAST_RULES: AutoCastRules = &[
         DataType::Number(NumberDataType::Float32),
         DataType::Number(NumberDataType::Float64),
     ),
+    (
+        DataType::Number(NumberDataType::UInt8),
+        DataType::Number(NumberDataType::UInt128),
+    ),
+    (
+        DataType::Number(NumberDataType::UInt8),
+        DataType::Number(NumberDataType::Int128),
+    ),
+    (
+        DataType::Number(NumberDataType::UInt16),
+        DataType::Number(NumberDataType::UInt128),
+    ),
+    (
+        DataType::Number(NumberDataType::UInt16),
+        DataType::Number(NumberDataType::Int128),
+    ),
+    (
+        DataType::Number(NumberDataType::UInt32),
+        DataType::Number(NumberDataType::UInt128),
+    ),
+    (
+        DataType::Number(NumberDataType::UInt32),
+        DataType::Number(NumberDataType::Int128),
+    ),
+    (
+        DataType::Number(NumberDataType::UInt64),
+        DataType::Number(NumberDataType::UInt128),
+    ),
+    (
+        DataType::Number(NumberDataType::UInt64),
+        DataType::Number(NumberDataType::Int128),
+    ),
+    (
+        DataType::Number(NumberDataType::Int8),
+        DataType::Number(NumberDataType::Int128),
+    ),
+    (
+        DataType::Number(NumberDataType::Int16),
+        DataType::Number(NumberDataType::Int128),
+    ),
+    (
+        DataType::Number(NumberDataType::Int32),
+        DataType::Number(NumberDataType::Int128),
+    ),
+    (
+        DataType::Number(NumberDataType::Int64),
+        DataType::Number(NumberDataType::Int128),
+    ),
+];
+
+/// The general cast rules that may lose precision, e.g. `Int64` to `Float64`, or may fail
+/// at runtime, e.g. `String` to `Timestamp`.
+pub const LOSSY_CAST_RULES: AutoCastRules = &[
+    (DataType::String, DataType::Timestamp),
+    (DataType::String, DataType::Date),
+    (DataType::String, DataType::Boolean),
+    (
+        DataType::Number(NumberDataType::UInt64),
+        DataType::Number(NumberDataType::Int64),
+    ),
+    (
+        DataType::Number(NumberDataType::UInt64),
+        DataType::Number(NumberDataType::Float64),
+    ),
+    (
+        DataType::Number(NumberDataType::Int64),
+        DataType::Number(NumberDataType::Float64),
+    ),
 ];
 
+/// Whether the implicit cast from `src_ty` to `dest_ty` neither loses information nor
+/// fails at runtime. Only these casts are allowed in the strict type coercion mode.
+pub fn is_lossless_cast(src_ty: &DataType, dest_ty: &DataType) -> bool {
+    match (src_ty, dest_ty) {
+        (src_ty, dest_ty) if src_ty == dest_ty => true,
+        (DataType::Null, DataType::Nullable(_))
+        | (DataType::EmptyArray, DataType::Array(_))
+        | (DataType::EmptyMap, DataType::Map(_)) => true,
+        (DataType::Nullable(box src_ty), DataType::Nullable(box dest_ty))
+        | (src_ty, DataType::Nullable(box dest_ty))
+        | (DataType::Array(box src_ty), DataType::Array(box dest_ty))
+        | (DataType::Map(box src_ty), DataType::Map(box dest_ty)) => {
+            is_lossless_cast(src_ty, dest_ty)
+        }
+        (DataType::Tuple(src_tys), DataType::Tuple(dest_tys))
+            if src_tys.len() == dest_tys.len() =>
+        {
+            src_tys
+                .iter()
+                .zip(dest_tys)
+                .all(|(src_ty, dest_ty)| is_lossless_cast(src_ty, dest_ty))
+        }
+        (DataType::String | DataType::Variant, _) => false,
+        (DataType::Decimal(_), DataType::Number(_)) => false,
+        (src_ty, dest_ty) => {
+            can_auto_cast_to(src_ty, dest_ty, GENERAL_CAST_RULES)
+                && !LOSSY_CAST_RULES
+                    .iter()
+                    .any(|(src, dest)| src == src_ty && dest == dest_ty)
+        }
+    }
+}
+
 /// The rules for automatic casting from string to other types. For example, they are
 /// used to allow `add_hours('2023-01-01 00:00:00', '1')`. But they should be disabled
 /// for comparison functions, because `1 < '1'` should be an error.
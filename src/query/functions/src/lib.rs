@@ -29,6 +29,8 @@ mod cast_rules;
 pub mod scalars;
 pub mod srfs;
 
+pub use cast_rules::is_lossless_cast;
+
 pub fn is_builtin_function(name: &str) -> bool {
     BUILTIN_FUNCTIONS.contains(name)
         || AggregateFunctionFactory::instance().contains(name)
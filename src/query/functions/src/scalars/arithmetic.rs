@@ -43,6 +43,7 @@ use common_expression::types::ALL_INTEGER_TYPES;
 use common_expression::types::ALL_NUMBER_CLASSES;
 use common_expression::types::ALL_NUMERICS_TYPES;
 use common_expression::types::ALL_UNSIGNED_INTEGER_TYPES;
+use common_expression::types::ALL_WIDE_INTEGER_TYPES;
 use common_expression::utils::arithmetics_type::ResultTypeOfBinary;
 use common_expression::utils::arithmetics_type::ResultTypeOfUnary;
 use common_expression::values::Value;
@@ -236,6 +237,7 @@ macro_rules! register_modulo {
                 | NumberDataType::UInt16
                 | NumberDataType::UInt32
                 | NumberDataType::UInt64
+                | NumberDataType::UInt128
         ) {
             $registry.register_passthrough_nullable_2_arg::<NumberType<L>, NumberType<R>,  NumberType<T>,_, _>(
                 "modulo",
@@ -426,6 +428,25 @@ fn register_binary_arithmetic(registry: &mut FunctionRegistry) {
         }
     }
 
+    // the 128-bit integers only do arithmetic with integers
+    let all_integer_types = || ALL_INTEGER_TYPES.iter().chain(ALL_WIDE_INTEGER_TYPES);
+    for left in all_integer_types() {
+        for right in all_integer_types() {
+            if left.bit_width() < 128 && right.bit_width() < 128 {
+                continue;
+            }
+            with_integer_mapped_type!(|L| match left {
+                NumberDataType::L => with_integer_mapped_type!(|R| match right {
+                    NumberDataType::R => {
+                        register_basic_arithmetic!(L, R, registry);
+                    }
+                    _ => unreachable!(),
+                }),
+                _ => unreachable!(),
+            });
+        }
+    }
+
     // register bitwise operation : AND/OR/XOR
     for left in ALL_INTEGER_TYPES {
         for right in ALL_INTEGER_TYPES {
@@ -557,6 +578,57 @@ fn register_unary_minus(registry: &mut FunctionRegistry) {
                         ),
                     );
             }
+            NumberClass::UInt128 => {
+                registry
+                    .register_passthrough_nullable_1_arg::<NumberType<u128>, NumberType<i128>, _, _>(
+                        "minus",
+                        |_, val| {
+                            if val.max > i128::MAX as u128 + 1 {
+                                return FunctionDomain::MayThrow;
+                            }
+                            FunctionDomain::Domain(SimpleDomain::<i128> {
+                                min: (val.max as i128).wrapping_neg(),
+                                max: (val.min as i128).wrapping_neg(),
+                            })
+                        },
+                        vectorize_with_builder_1_arg::<NumberType<u128>, NumberType<i128>>(
+                            |a, output, ctx| {
+                                if a > i128::MAX as u128 + 1 {
+                                    ctx.set_error(output.len(), "number overflowed");
+                                    output.push(0);
+                                } else {
+                                    output.push((a as i128).wrapping_neg());
+                                }
+                            },
+                        ),
+                    );
+            }
+            NumberClass::Int128 => {
+                registry
+                    .register_passthrough_nullable_1_arg::<NumberType<i128>, NumberType<i128>, _, _>(
+                        "minus",
+                        |_, val| {
+                            let min = val.max.checked_neg();
+                            let max = val.min.checked_neg();
+                            if min.is_none() || max.is_none() {
+                                return FunctionDomain::MayThrow;
+                            }
+                            FunctionDomain::Domain(SimpleDomain::<i128> {
+                                min: min.unwrap(),
+                                max: max.unwrap(),
+                            })
+                        },
+                        vectorize_with_builder_1_arg::<NumberType<i128>, NumberType<i128>>(
+                            |a, output, ctx| match a.checked_neg() {
+                                Some(a) => output.push(a),
+                                None => {
+                                    ctx.set_error(output.len(), "number overflowed");
+                                    output.push(0);
+                                }
+                            },
+                        ),
+                    );
+            }
 
             NumberClass::Decimal128 => {
                 register_decimal_minus(registry)
@@ -569,7 +641,7 @@ fn register_unary_minus(registry: &mut FunctionRegistry) {
 }
 
 pub fn register_number_to_number(registry: &mut FunctionRegistry) {
-    for dest_type in ALL_NUMERICS_TYPES {
+    for dest_type in ALL_NUMERICS_TYPES.iter().chain(ALL_WIDE_INTEGER_TYPES) {
         // each out loop register all to_{dest_type}
         // dest_type not include decimal
         for src_type in ALL_NUMBER_CLASSES {
@@ -818,7 +890,7 @@ fn unary_minus_decimal(args: &[ValueRef<AnyType>], arg_type: DataType) -> Value<
 }
 
 fn register_string_to_number(registry: &mut FunctionRegistry) {
-    for dest_type in ALL_NUMERICS_TYPES {
+    for dest_type in ALL_NUMERICS_TYPES.iter().chain(ALL_WIDE_INTEGER_TYPES) {
         with_number_mapped_type!(|DEST_TYPE| match dest_type {
             NumberDataType::DEST_TYPE => {
                 let name = format!("to_{dest_type}").to_lowercase();
@@ -21,6 +21,7 @@ use common_expression::EvalContext;
 use common_expression::Value;
 use common_expression::ValueRef;
 use num_traits::AsPrimitive;
+use strength_reduce::StrengthReducedU128;
 use strength_reduce::StrengthReducedU16;
 use strength_reduce::StrengthReducedU32;
 use strength_reduce::StrengthReducedU64;
@@ -106,10 +107,12 @@ impl_rem_scalar!(u8, StrengthReducedU8);
 impl_rem_scalar!(u16, StrengthReducedU16);
 impl_rem_scalar!(u32, StrengthReducedU32);
 impl_rem_scalar!(u64, StrengthReducedU64);
+impl_rem_scalar!(u128, StrengthReducedU128);
 
 impl<O: Number> RemScalar<O> for i8 {}
 impl<O: Number> RemScalar<O> for i16 {}
 impl<O: Number> RemScalar<O> for i32 {}
 impl<O: Number> RemScalar<O> for i64 {}
+impl<O: Number> RemScalar<O> for i128 {}
 impl<O: Number> RemScalar<O> for F32 {}
 impl<O: Number> RemScalar<O> for F64 {}
@@ -14,6 +14,8 @@
 
 use std::io::Write;
 
+use chrono::format::Item;
+use chrono::format::StrftimeItems;
 use chrono::prelude::*;
 use chrono::Datelike;
 use chrono::Utc;
@@ -38,6 +40,7 @@ use common_expression::types::string::StringDomain;
 use common_expression::types::timestamp::check_timestamp;
 use common_expression::types::timestamp::string_to_timestamp;
 use common_expression::types::timestamp::timestamp_to_string;
+use common_expression::types::timestamp::MICROS_IN_A_DAY;
 use common_expression::types::timestamp::MICROS_IN_A_MILLI;
 use common_expression::types::timestamp::MICROS_IN_A_SEC;
 use common_expression::types::DateType;
@@ -51,6 +54,7 @@ use common_expression::vectorize_1_arg;
 use common_expression::vectorize_2_arg;
 use common_expression::vectorize_with_builder_1_arg;
 use common_expression::vectorize_with_builder_2_arg;
+use common_expression::vectorize_with_builder_3_arg;
 use common_expression::EvalContext;
 use common_expression::FunctionDomain;
 use common_expression::FunctionProperty;
@@ -59,6 +63,8 @@ use common_expression::Value;
 use common_expression::ValueRef;
 use num_traits::AsPrimitive;
 
+use crate::scalars::locale::find_locale;
+
 pub fn register(registry: &mut FunctionRegistry) {
     // cast(xx AS timestamp)
     // to_timestamp(xx)
@@ -95,6 +101,9 @@ pub fn register(registry: &mut FunctionRegistry) {
     // to_*([date | timestamp]) -> [date | timestamp]
     register_rounder_functions(registry);
 
+    // time_bucket(width, [date | timestamp]) -> [date | timestamp]
+    register_time_bucket(registry);
+
     // [date | timestamp] +/- number
     register_timestamp_add_sub(registry);
 }
@@ -179,6 +188,46 @@ fn register_string_to_timestamp(registry: &mut FunctionRegistry) {
         ),
     );
 
+    // to_timestamp(<str>, <format>, <locale>) parses the month and weekday names of the locale.
+    registry
+        .register_combine_nullable_3_arg::<StringType, StringType, StringType, TimestampType, _, _>(
+            "to_timestamp",
+            |_, _, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_3_arg::<
+                StringType,
+                StringType,
+                StringType,
+                NullableType<TimestampType>,
+            >(|timestamp, format, locale, output, ctx| {
+                if format.is_empty() {
+                    output.push_null();
+                    return;
+                }
+                let locale = match find_locale(locale) {
+                    Ok(locale) => locale,
+                    Err(e) => {
+                        ctx.set_error(output.len(), e);
+                        output.push_null();
+                        return;
+                    }
+                };
+                match (std::str::from_utf8(timestamp), std::str::from_utf8(format)) {
+                    (Ok(date), Ok(format)) => {
+                        let date = locale.delocalize_input(date, format);
+                        // date need has timezone info.
+                        if let Ok(res) = DateTime::parse_from_str(&date, format) {
+                            output.push(res.with_timezone(&ctx.func_ctx.tz.tz).timestamp_micros());
+                        } else {
+                            output.push_null();
+                        }
+                    }
+                    _ => {
+                        output.push_null();
+                    }
+                }
+            }),
+        );
+
     registry.register_combine_nullable_2_arg::<StringType, StringType, DateType, _, _>(
         "to_date",
         |_, _, _| FunctionDomain::MayThrow,
@@ -206,6 +255,35 @@ fn register_string_to_timestamp(registry: &mut FunctionRegistry) {
             },
         ),
     );
+
+    // to_date(<str>, <format>, <locale>) parses the month and weekday names of the locale.
+    registry.register_combine_nullable_3_arg::<StringType, StringType, StringType, DateType, _, _>(
+        "to_date",
+        |_, _, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_3_arg::<StringType, StringType, StringType, NullableType<DateType>>(
+            |date, format, locale, output, ctx| {
+                if format.is_empty() {
+                    output.push_null();
+                    return;
+                }
+                let res = find_locale(locale).and_then(|locale| {
+                    let date = std::str::from_utf8(date).map_err(|e| e.to_string())?;
+                    let format = std::str::from_utf8(format).map_err(|e| e.to_string())?;
+                    let date = locale.delocalize_input(date, format);
+                    NaiveDate::parse_from_str(&date, format).map_err(|e| e.to_string())
+                });
+                match res {
+                    Ok(res) => {
+                        output.push(res.num_days_from_ce() - EPOCH_DAYS_FROM_CE);
+                    }
+                    Err(e) => {
+                        ctx.set_error(output.len(), e);
+                        output.push_null();
+                    }
+                }
+            },
+        ),
+    );
 }
 
 fn register_date_to_timestamp(registry: &mut FunctionRegistry) {
@@ -407,7 +485,7 @@ fn register_number_to_date(registry: &mut FunctionRegistry) {
 }
 
 fn register_to_string(registry: &mut FunctionRegistry) {
-    registry.register_aliases("to_string", &["date_format"]);
+    registry.register_aliases("to_string", &["date_format", "to_char"]);
     registry.register_combine_nullable_2_arg::<TimestampType, StringType, StringType, _, _>(
         "to_string",
         |_, _, _| FunctionDomain::MayThrow,
@@ -432,6 +510,40 @@ fn register_to_string(registry: &mut FunctionRegistry) {
         ),
     );
 
+    // to_string(<timestamp>, <format>, <locale>) writes the month and weekday names of the locale.
+    registry
+        .register_combine_nullable_3_arg::<TimestampType, StringType, StringType, StringType, _, _>(
+            "to_string",
+            |_, _, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_3_arg::<
+                TimestampType,
+                StringType,
+                StringType,
+                NullableType<StringType>,
+            >(|date, format, locale, output, ctx| {
+                if format.is_empty() {
+                    output.push_null();
+                    return;
+                }
+                let ts = date.to_timestamp(ctx.func_ctx.tz.tz);
+                let res = find_locale(locale).and_then(|locale| {
+                    let format = std::str::from_utf8(format).map_err(|e| e.to_string())?;
+                    let format = locale.localize_format(format, &ts);
+                    if StrftimeItems::new(&format).any(|item| item == Item::Error) {
+                        return Err(format!("invalid datetime format `{format}`"));
+                    }
+                    Ok(ts.format(&format).to_string())
+                });
+                match res {
+                    Ok(res) => output.push(res.as_bytes()),
+                    Err(e) => {
+                        ctx.set_error(output.len(), e);
+                        output.push_null();
+                    }
+                }
+            }),
+        );
+
     registry.register_passthrough_nullable_1_arg::<DateType, StringType, _, _>(
         "to_string",
         |_, _| FunctionDomain::Full,
@@ -1239,6 +1351,43 @@ fn register_timestamp_add_sub(registry: &mut FunctionRegistry) {
     );
 }
 
+/// Round the timestamps down to the start of the buckets of `width` microseconds,
+/// the buckets are aligned to the unix epoch.
+fn register_time_bucket(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_2_arg::<Int64Type, TimestampType, TimestampType, _, _>(
+        "time_bucket",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<Int64Type, TimestampType, TimestampType>(
+            |width, val, builder, ctx| {
+                if width <= 0 {
+                    ctx.set_error(builder.len(), "bucket width must be positive");
+                    builder.push(0);
+                } else {
+                    builder.push(val - val.rem_euclid(width));
+                }
+            },
+        ),
+    );
+    registry.register_passthrough_nullable_2_arg::<Int64Type, DateType, DateType, _, _>(
+        "time_bucket",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<Int64Type, DateType, DateType>(
+            |width, val, builder, ctx| {
+                if width <= 0 || width % MICROS_IN_A_DAY != 0 {
+                    ctx.set_error(
+                        builder.len(),
+                        "bucket width of dates must be a positive number of days",
+                    );
+                    builder.push(0);
+                } else {
+                    let days = (width / MICROS_IN_A_DAY).min(i32::MAX as i64) as i32;
+                    builder.push(val - val.rem_euclid(days));
+                }
+            },
+        ),
+    );
+}
+
 fn register_rounder_functions(registry: &mut FunctionRegistry) {
     // timestamp -> timestamp
     registry.register_passthrough_nullable_1_arg::<TimestampType, TimestampType, _, _>(
@@ -1174,14 +1174,17 @@ macro_rules! m_integer_to_decimal {
             .iter()
             .enumerate()
             .map(|(row, x)| {
-                let x = x.as_() * <$type_name>::one();
-                let x = x.checked_mul(multiplier).and_then(|v| {
-                    if v > max_for_precision || v < min_for_precision {
-                        None
-                    } else {
-                        Some(v)
-                    }
-                });
+                // `UInt128` values beyond `i128::MAX` can't be represented.
+                let x = num_traits::cast::<_, i128>(*x)
+                    .map(|x| x * <$type_name>::one())
+                    .and_then(|x| x.checked_mul(multiplier))
+                    .and_then(|v| {
+                        if v > max_for_precision || v < min_for_precision {
+                            None
+                        } else {
+                            Some(v)
+                        }
+                    });
 
                 match x {
                     Some(x) => x,
@@ -1196,7 +1199,7 @@ macro_rules! m_integer_to_decimal {
     };
 }
 
-fn integer_to_decimal_internal<T: Number + AsPrimitive<i128>>(
+fn integer_to_decimal_internal<T: Number>(
     from: Buffer<T>,
     ctx: &mut EvalContext,
     dest_type: &DecimalDataType,
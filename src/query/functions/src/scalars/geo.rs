@@ -28,8 +28,10 @@ use common_expression::types::DataType;
 use common_expression::types::NumberDataType;
 use common_expression::types::NumberType;
 use common_expression::types::StringType;
+use common_expression::types::UInt64Type;
 use common_expression::types::UInt8Type;
 use common_expression::types::ValueType;
+use common_expression::types::VariantType;
 use common_expression::vectorize_with_builder_1_arg;
 use common_expression::vectorize_with_builder_2_arg;
 use common_expression::vectorize_with_builder_3_arg;
@@ -51,6 +53,13 @@ use geo::LineString;
 use geo::Polygon;
 use h3o::LatLng;
 use h3o::Resolution;
+use jsonb::as_f64;
+use jsonb::as_str;
+use jsonb::get_by_index;
+use jsonb::get_by_name;
+use jsonb::Number as JsonbNumber;
+use jsonb::Object as JsonbObject;
+use jsonb::Value as JsonbValue;
 use once_cell::sync::OnceCell;
 
 const PI: f64 = std::f64::consts::PI;
@@ -114,6 +123,38 @@ pub fn register(registry: &mut FunctionRegistry) {
         ),
     );
 
+    registry.register_passthrough_nullable_2_arg::<VariantType, UInt8Type, UInt64Type, _, _>(
+        "geo_to_h3",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<VariantType, UInt8Type, UInt64Type>(
+            |point, r, builder, ctx| match geojson_point_coord(point)
+                .and_then(|(lon, lat)| LatLng::new(lat, lon).map_err(|e| e.to_string()))
+                .and_then(|coord| {
+                    Resolution::try_from(r)
+                        .map_err(|e| e.to_string())
+                        .map(|rr| coord.to_cell(rr))
+                }) {
+                Ok(h3_cell) => builder.push(h3_cell.into()),
+                Err(e) => {
+                    ctx.set_error(builder.len(), e);
+                    builder.push(0);
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<Float64Type, Float64Type, VariantType, _, _>(
+        "geojson_point",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<Float64Type, Float64Type, VariantType>(
+            |lon, lat, builder, _| {
+                geojson_geometry("Point", geojson_position(lon.into(), lat.into()))
+                    .write_to_vec(&mut builder.data);
+                builder.commit_row();
+            },
+        ),
+    );
+
     // geo distance
     registry.register_4_arg::<NumberType<F64>, NumberType<F64>, NumberType<F64>, NumberType<F64>,NumberType<F32>,_, _>(
         "geo_distance",
@@ -359,6 +400,52 @@ pub fn register(registry: &mut FunctionRegistry) {
     });
 }
 
+/// Builds a GeoJSON geometry object, e.g. `{"coordinates":[1.0,2.0],"type":"Point"}`.
+pub(crate) fn geojson_geometry(
+    geometry_type: &str,
+    coordinates: JsonbValue<'static>,
+) -> JsonbValue<'static> {
+    let mut obj = JsonbObject::new();
+    obj.insert(
+        "type".to_string(),
+        JsonbValue::String(geometry_type.to_string().into()),
+    );
+    obj.insert("coordinates".to_string(), coordinates);
+    JsonbValue::Object(obj)
+}
+
+/// Builds a GeoJSON position, which is `[longitude, latitude]`.
+pub(crate) fn geojson_position(lon: f64, lat: f64) -> JsonbValue<'static> {
+    JsonbValue::Array(vec![
+        JsonbValue::Number(JsonbNumber::Float64(lon)),
+        JsonbValue::Number(JsonbNumber::Float64(lat)),
+    ])
+}
+
+/// Returns the longitude and latitude of a GeoJSON Point, or of the Point geometry
+/// of a GeoJSON Feature.
+fn geojson_point_coord(value: &[u8]) -> Result<(f64, f64), String> {
+    let geometry_type =
+        get_by_name(value, "type", false).and_then(|v| as_str(&v).map(|s| s.to_string()));
+    match geometry_type.as_deref() {
+        Some("Feature") => match get_by_name(value, "geometry", false) {
+            Some(geometry) => geojson_point_coord(&geometry),
+            None => Err("GeoJSON Feature has no geometry".to_string()),
+        },
+        Some("Point") => get_by_name(value, "coordinates", false)
+            .and_then(|coordinates| {
+                let lon = get_by_index(&coordinates, 0).and_then(|v| as_f64(&v))?;
+                let lat = get_by_index(&coordinates, 1).and_then(|v| as_f64(&v))?;
+                Some((lon, lat))
+            })
+            .ok_or_else(|| {
+                "GeoJSON Point must have the coordinates [longitude, latitude]".to_string()
+            }),
+        Some(other) => Err(format!("expect a GeoJSON Point, but got {other}")),
+        None => Err("invalid GeoJSON, missing the member `type`".to_string()),
+    }
+}
+
 fn get_coord(fields: &[ScalarRef]) -> Coord {
     let v = fields
         .iter()
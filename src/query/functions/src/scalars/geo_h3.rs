@@ -23,6 +23,7 @@ use common_expression::types::StringType;
 use common_expression::types::UInt32Type;
 use common_expression::types::UInt64Type;
 use common_expression::types::UInt8Type;
+use common_expression::types::VariantType;
 use common_expression::types::F64;
 use common_expression::vectorize_with_builder_1_arg;
 use common_expression::vectorize_with_builder_2_arg;
@@ -32,6 +33,10 @@ use h3o::CellIndex;
 use h3o::DirectedEdgeIndex;
 use h3o::LatLng;
 use h3o::Resolution;
+use jsonb::Value as JsonbValue;
+
+use crate::scalars::geo::geojson_geometry;
+use crate::scalars::geo::geojson_position;
 
 pub fn register(registry: &mut FunctionRegistry) {
     registry
@@ -76,6 +81,30 @@ pub fn register(registry: &mut FunctionRegistry) {
             ),
         );
 
+    registry.register_passthrough_nullable_1_arg::<UInt64Type, VariantType, _, _>(
+        "h3_to_geojson",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<UInt64Type, VariantType>(|h3, builder, ctx| {
+            match CellIndex::try_from(h3) {
+                Ok(h3_cell) => {
+                    let mut ring = h3_cell
+                        .boundary()
+                        .iter()
+                        .map(|coord| geojson_position(coord.lng(), coord.lat()))
+                        .collect::<Vec<_>>();
+                    // The linear ring of a GeoJSON Polygon is closed.
+                    ring.push(ring[0].clone());
+                    geojson_geometry("Polygon", JsonbValue::Array(vec![JsonbValue::Array(ring)]))
+                        .write_to_vec(&mut builder.data);
+                }
+                Err(e) => {
+                    ctx.set_error(builder.len(), e.to_string());
+                }
+            }
+            builder.commit_row();
+        }),
+    );
+
     registry
         .register_passthrough_nullable_2_arg::<UInt64Type, UInt32Type, ArrayType<UInt64Type>, _, _>(
             "h3_k_ring",
@@ -303,7 +303,8 @@ macro_rules! for_all_integer_types{
             { u16 },
             { u32 },
             { u64 },
-            { i128 }
+            { i128 },
+            { u128 }
         }
     };
 }
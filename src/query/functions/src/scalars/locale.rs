@@ -0,0 +1,502 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::Datelike;
+
+/// The conventions of a locale used by the locale-aware formatting functions,
+/// e.g. `format(1234.5, 2, 'de_DE')` or `to_char(ts, '%d %B %Y', 'fr_FR')`.
+pub struct Locale {
+    pub name: &'static str,
+    pub decimal_separator: char,
+    pub thousands_separator: char,
+    pub long_months: [&'static str; 12],
+    pub short_months: [&'static str; 12],
+    /// Starting from Monday.
+    pub long_weekdays: [&'static str; 7],
+    pub short_weekdays: [&'static str; 7],
+}
+
+const EN_LONG_MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+const EN_SHORT_MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+const EN_LONG_WEEKDAYS: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+const EN_SHORT_WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+const CJK_SHORT_MONTHS: [&str; 12] = [
+    "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+];
+
+/// The supported locales, the first locale of a language is the one used if only
+/// the language is given, e.g. `en` is `en_US`.
+static LOCALES: &[Locale] = &[
+    Locale {
+        name: "en_US",
+        decimal_separator: '.',
+        thousands_separator: ',',
+        long_months: EN_LONG_MONTHS,
+        short_months: EN_SHORT_MONTHS,
+        long_weekdays: EN_LONG_WEEKDAYS,
+        short_weekdays: EN_SHORT_WEEKDAYS,
+    },
+    Locale {
+        name: "en_GB",
+        decimal_separator: '.',
+        thousands_separator: ',',
+        long_months: EN_LONG_MONTHS,
+        short_months: EN_SHORT_MONTHS,
+        long_weekdays: EN_LONG_WEEKDAYS,
+        short_weekdays: EN_SHORT_WEEKDAYS,
+    },
+    Locale {
+        name: "de_DE",
+        decimal_separator: ',',
+        thousands_separator: '.',
+        long_months: [
+            "Januar",
+            "Februar",
+            "März",
+            "April",
+            "Mai",
+            "Juni",
+            "Juli",
+            "August",
+            "September",
+            "Oktober",
+            "November",
+            "Dezember",
+        ],
+        short_months: [
+            "Jan", "Feb", "Mär", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez",
+        ],
+        long_weekdays: [
+            "Montag",
+            "Dienstag",
+            "Mittwoch",
+            "Donnerstag",
+            "Freitag",
+            "Samstag",
+            "Sonntag",
+        ],
+        short_weekdays: ["Mo", "Di", "Mi", "Do", "Fr", "Sa", "So"],
+    },
+    Locale {
+        name: "fr_FR",
+        decimal_separator: ',',
+        thousands_separator: ' ',
+        long_months: [
+            "janvier",
+            "février",
+            "mars",
+            "avril",
+            "mai",
+            "juin",
+            "juillet",
+            "août",
+            "septembre",
+            "octobre",
+            "novembre",
+            "décembre",
+        ],
+        short_months: [
+            "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.",
+            "nov.", "déc.",
+        ],
+        long_weekdays: [
+            "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche",
+        ],
+        short_weekdays: ["lun.", "mar.", "mer.", "jeu.", "ven.", "sam.", "dim."],
+    },
+    Locale {
+        name: "es_ES",
+        decimal_separator: ',',
+        thousands_separator: '.',
+        long_months: [
+            "enero",
+            "febrero",
+            "marzo",
+            "abril",
+            "mayo",
+            "junio",
+            "julio",
+            "agosto",
+            "septiembre",
+            "octubre",
+            "noviembre",
+            "diciembre",
+        ],
+        short_months: [
+            "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sep", "oct", "nov", "dic",
+        ],
+        long_weekdays: [
+            "lunes",
+            "martes",
+            "miércoles",
+            "jueves",
+            "viernes",
+            "sábado",
+            "domingo",
+        ],
+        short_weekdays: ["lun", "mar", "mié", "jue", "vie", "sáb", "dom"],
+    },
+    Locale {
+        name: "it_IT",
+        decimal_separator: ',',
+        thousands_separator: '.',
+        long_months: [
+            "gennaio",
+            "febbraio",
+            "marzo",
+            "aprile",
+            "maggio",
+            "giugno",
+            "luglio",
+            "agosto",
+            "settembre",
+            "ottobre",
+            "novembre",
+            "dicembre",
+        ],
+        short_months: [
+            "gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic",
+        ],
+        long_weekdays: [
+            "lunedì",
+            "martedì",
+            "mercoledì",
+            "giovedì",
+            "venerdì",
+            "sabato",
+            "domenica",
+        ],
+        short_weekdays: ["lun", "mar", "mer", "gio", "ven", "sab", "dom"],
+    },
+    Locale {
+        name: "pt_BR",
+        decimal_separator: ',',
+        thousands_separator: '.',
+        long_months: [
+            "janeiro",
+            "fevereiro",
+            "março",
+            "abril",
+            "maio",
+            "junho",
+            "julho",
+            "agosto",
+            "setembro",
+            "outubro",
+            "novembro",
+            "dezembro",
+        ],
+        short_months: [
+            "jan", "fev", "mar", "abr", "mai", "jun", "jul", "ago", "set", "out", "nov", "dez",
+        ],
+        long_weekdays: [
+            "segunda-feira",
+            "terça-feira",
+            "quarta-feira",
+            "quinta-feira",
+            "sexta-feira",
+            "sábado",
+            "domingo",
+        ],
+        short_weekdays: ["seg", "ter", "qua", "qui", "sex", "sáb", "dom"],
+    },
+    Locale {
+        name: "nl_NL",
+        decimal_separator: ',',
+        thousands_separator: '.',
+        long_months: [
+            "januari",
+            "februari",
+            "maart",
+            "april",
+            "mei",
+            "juni",
+            "juli",
+            "augustus",
+            "september",
+            "oktober",
+            "november",
+            "december",
+        ],
+        short_months: [
+            "jan", "feb", "mrt", "apr", "mei", "jun", "jul", "aug", "sep", "okt", "nov", "dec",
+        ],
+        long_weekdays: [
+            "maandag",
+            "dinsdag",
+            "woensdag",
+            "donderdag",
+            "vrijdag",
+            "zaterdag",
+            "zondag",
+        ],
+        short_weekdays: ["ma", "di", "wo", "do", "vr", "za", "zo"],
+    },
+    Locale {
+        name: "ja_JP",
+        decimal_separator: '.',
+        thousands_separator: ',',
+        long_months: CJK_SHORT_MONTHS,
+        short_months: CJK_SHORT_MONTHS,
+        long_weekdays: [
+            "月曜日",
+            "火曜日",
+            "水曜日",
+            "木曜日",
+            "金曜日",
+            "土曜日",
+            "日曜日",
+        ],
+        short_weekdays: ["月", "火", "水", "木", "金", "土", "日"],
+    },
+    Locale {
+        name: "zh_CN",
+        decimal_separator: '.',
+        thousands_separator: ',',
+        long_months: [
+            "一月",
+            "二月",
+            "三月",
+            "四月",
+            "五月",
+            "六月",
+            "七月",
+            "八月",
+            "九月",
+            "十月",
+            "十一月",
+            "十二月",
+        ],
+        short_months: CJK_SHORT_MONTHS,
+        long_weekdays: [
+            "星期一",
+            "星期二",
+            "星期三",
+            "星期四",
+            "星期五",
+            "星期六",
+            "星期日",
+        ],
+        short_weekdays: ["周一", "周二", "周三", "周四", "周五", "周六", "周日"],
+    },
+];
+
+/// The locale used if no locale is given.
+pub fn default_locale() -> &'static Locale {
+    &LOCALES[0]
+}
+
+/// Finds the locale by its name like `de_DE` or `de-DE`, or by its language like `de`.
+pub fn find_locale(name: &[u8]) -> Result<&'static Locale, String> {
+    let name = std::str::from_utf8(name)
+        .map_err(|e| e.to_string())?
+        .trim()
+        .replace('-', "_");
+    LOCALES
+        .iter()
+        .find(|locale| locale.name.eq_ignore_ascii_case(&name))
+        .or_else(|| {
+            LOCALES
+                .iter()
+                .find(|locale| locale.name[..2].eq_ignore_ascii_case(&name))
+        })
+        .ok_or_else(|| {
+            let names = LOCALES.iter().map(|locale| locale.name).collect::<Vec<_>>();
+            format!(
+                "unknown locale `{name}`, the supported locales are: {}",
+                names.join(", ")
+            )
+        })
+}
+
+impl Locale {
+    /// Formats the number rounded to `decimals` fractional digits, with the digits of
+    /// the integral part grouped by thousands, like `FORMAT(X, D, locale)` of MySQL.
+    pub fn format_number(&self, value: f64, decimals: usize, output: &mut Vec<u8>) {
+        if !value.is_finite() {
+            output.extend_from_slice(value.to_string().as_bytes());
+            return;
+        }
+        let digits = format!("{:.*}", decimals, value.abs());
+        let (integral, fractional) = digits.split_once('.').unwrap_or((&digits, ""));
+        if value < 0.0 && digits.bytes().any(|b| matches!(b, b'1'..=b'9')) {
+            output.push(b'-');
+        }
+
+        let mut buf = [0; 4];
+        for (i, digit) in integral.bytes().enumerate() {
+            if i > 0 && (integral.len() - i) % 3 == 0 {
+                let sep = self.thousands_separator.encode_utf8(&mut buf);
+                output.extend_from_slice(sep.as_bytes());
+            }
+            output.push(digit);
+        }
+        if !fractional.is_empty() {
+            let sep = self.decimal_separator.encode_utf8(&mut buf);
+            output.extend_from_slice(sep.as_bytes());
+            output.extend_from_slice(fractional.as_bytes());
+        }
+    }
+
+    /// Parses a number formatted in the locale, the thousands separators are optional.
+    pub fn parse_number(&self, s: &str) -> Result<f64, String> {
+        let mut normalized = String::with_capacity(s.len());
+        for c in s.trim().chars() {
+            if c == self.thousands_separator
+                || (self.thousands_separator.is_whitespace() && c.is_whitespace())
+            {
+                continue;
+            }
+            if c == self.decimal_separator {
+                normalized.push('.');
+            } else {
+                normalized.push(c);
+            }
+        }
+        normalized
+            .parse::<f64>()
+            .map_err(|_| format!("cannot parse `{s}` as a number in locale `{}`", self.name))
+    }
+
+    /// Replaces the month and weekday name specifiers (`%B`, `%b`, `%h`, `%A` and `%a`)
+    /// of a strftime format with the names in the locale of the given date.
+    pub fn localize_format(&self, format: &str, date: &impl Datelike) -> String {
+        let month = date.month0() as usize;
+        let weekday = date.weekday().num_days_from_monday() as usize;
+
+        let mut localized = String::with_capacity(format.len());
+        let mut chars = format.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                localized.push(c);
+                continue;
+            }
+            match chars.next() {
+                Some('B') => localized.push_str(self.long_months[month]),
+                Some('b') | Some('h') => localized.push_str(self.short_months[month]),
+                Some('A') => localized.push_str(self.long_weekdays[weekday]),
+                Some('a') => localized.push_str(self.short_weekdays[weekday]),
+                Some(spec) => {
+                    localized.push('%');
+                    localized.push(spec);
+                }
+                None => localized.push('%'),
+            }
+        }
+        localized
+    }
+
+    /// Replaces the month and weekday names of the locale in `input` with the English
+    /// names, so that it can be parsed with the strftime `format`. Only the names of the
+    /// specifiers used in `format` are replaced, the month names win if an abbreviated
+    /// month name is also an abbreviated weekday name.
+    pub fn delocalize_input(&self, input: &str, format: &str) -> String {
+        let (mut long_month, mut short_month, mut long_weekday, mut short_weekday) =
+            (false, false, false, false);
+        let mut chars = format.chars();
+        while let Some(c) = chars.next() {
+            if c == '%' {
+                match chars.next() {
+                    Some('B') => long_month = true,
+                    Some('b') | Some('h') => short_month = true,
+                    Some('A') => long_weekday = true,
+                    Some('a') => short_weekday = true,
+                    _ => {}
+                }
+            }
+        }
+
+        let mut names: Vec<(&str, &str)> = vec![];
+        for (used, localized, english) in [
+            (long_month, &self.long_months[..], &EN_LONG_MONTHS[..]),
+            (short_month, &self.short_months[..], &EN_SHORT_MONTHS[..]),
+            (long_weekday, &self.long_weekdays[..], &EN_LONG_WEEKDAYS[..]),
+            (
+                short_weekday,
+                &self.short_weekdays[..],
+                &EN_SHORT_WEEKDAYS[..],
+            ),
+        ] {
+            if used {
+                names.extend(localized.iter().copied().zip(english.iter().copied()));
+            }
+        }
+        if names.is_empty() || self.long_months == EN_LONG_MONTHS {
+            return input.to_string();
+        }
+        // Try the longest names first, the sort is stable so the months are still
+        // before the weekdays with the same name.
+        names.sort_by_key(|(localized, _)| std::cmp::Reverse(localized.len()));
+
+        let mut delocalized = String::with_capacity(input.len());
+        let mut pos = 0;
+        'outer: while pos < input.len() {
+            for (localized, english) in names.iter() {
+                let end = pos + localized.len();
+                let matched = input
+                    .get(pos..end)
+                    .map_or(false, |s| s.to_lowercase() == localized.to_lowercase());
+                if matched && is_name_boundary(input, pos, end, localized) {
+                    delocalized.push_str(english);
+                    pos = end;
+                    continue 'outer;
+                }
+            }
+            let c = input[pos..].chars().next().unwrap();
+            delocalized.push(c);
+            pos += c.len_utf8();
+        }
+        delocalized
+    }
+}
+
+/// Checks that the name matched at `input[start..end]` is not a part of a longer word.
+fn is_name_boundary(input: &str, start: usize, end: usize, name: &str) -> bool {
+    let starts_with_letter = name.chars().next().map_or(false, is_word_letter);
+    let ends_with_letter = name.chars().next_back().map_or(false, is_word_letter);
+    let before = input[..start].chars().next_back();
+    let after = input[end..].chars().next();
+    !(starts_with_letter && before.map_or(false, is_word_letter))
+        && !(ends_with_letter && after.map_or(false, is_word_letter))
+}
+
+/// The letters of the scripts separating the words by spaces, the Chinese and Japanese
+/// names are written next to the other characters.
+fn is_word_letter(c: char) -> bool {
+    c.is_alphabetic() && !matches!(c, '\u{3040}'..='\u{30ff}' | '\u{3400}'..='\u{9fff}')
+}
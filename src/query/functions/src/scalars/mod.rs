@@ -26,6 +26,7 @@ mod decimal;
 mod geo;
 mod geo_h3;
 mod hash;
+mod locale;
 mod map;
 mod math;
 mod other;
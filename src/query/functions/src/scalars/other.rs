@@ -44,6 +44,8 @@ use common_expression::types::StringType;
 use common_expression::types::TimestampType;
 use common_expression::types::ValueType;
 use common_expression::vectorize_with_builder_1_arg;
+use common_expression::vectorize_with_builder_2_arg;
+use common_expression::vectorize_with_builder_3_arg;
 use common_expression::Column;
 use common_expression::Domain;
 use common_expression::EvalContext;
@@ -61,6 +63,12 @@ use ordered_float::OrderedFloat;
 use rand::Rng;
 use rand::SeedableRng;
 
+use crate::scalars::locale::default_locale;
+use crate::scalars::locale::find_locale;
+
+/// The maximum number of the fractional digits of `format`, the same as MySQL.
+const MAX_FORMAT_DECIMALS: i64 = 30;
+
 pub fn register(registry: &mut FunctionRegistry) {
     registry.register_aliases("inet_aton", &["ipv4_string_to_num"]);
     registry.register_aliases("try_inet_aton", &["try_ipv4_string_to_num"]);
@@ -99,6 +107,99 @@ pub fn register(registry: &mut FunctionRegistry) {
         }),
     );
 
+    // format(<number>, <decimals>[, <locale>]) groups the digits like `1,234,567.89`.
+    registry.register_passthrough_nullable_2_arg::<Float64Type, Int64Type, StringType, _, _>(
+        "format",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<Float64Type, Int64Type, StringType>(
+            move |val, decimals, output, _| {
+                let decimals = decimals.clamp(0, MAX_FORMAT_DECIMALS) as usize;
+                default_locale().format_number(val.into(), decimals, &mut output.data);
+                output.commit_row();
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_3_arg::<
+        Float64Type,
+        Int64Type,
+        StringType,
+        StringType,
+        _,
+        _,
+    >(
+        "format",
+        |_, _, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_3_arg::<Float64Type, Int64Type, StringType, StringType>(
+            move |val, decimals, locale, output, ctx| {
+                match find_locale(locale) {
+                    Ok(locale) => {
+                        let decimals = decimals.clamp(0, MAX_FORMAT_DECIMALS) as usize;
+                        locale.format_number(val.into(), decimals, &mut output.data);
+                    }
+                    Err(e) => ctx.set_error(output.len(), e),
+                }
+                output.commit_row();
+            },
+        ),
+    );
+
+    // parse_number(<str>[, <locale>]) is the reverse of `format`.
+    registry.register_passthrough_nullable_1_arg::<StringType, Float64Type, _, _>(
+        "parse_number",
+        |_, _| FunctionDomain::MayThrow,
+        eval_parse_number,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, Float64Type, _, _>(
+        "try_parse_number",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_parse_number),
+    );
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, Float64Type, _, _>(
+        "parse_number",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<StringType, StringType, Float64Type>(
+            |val, locale, output, ctx| match parse_number(val, Some(locale)) {
+                Ok(n) => output.push(n),
+                Err(e) => {
+                    ctx.set_error(output.len(), e);
+                    output.push(F64::default());
+                }
+            },
+        ),
+    );
+    registry.register_combine_nullable_2_arg::<StringType, StringType, Float64Type, _, _>(
+        "try_parse_number",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<StringType, StringType, NullableType<Float64Type>>(
+            |val, locale, output, _| match parse_number(val, Some(locale)) {
+                Ok(n) => output.push(n),
+                Err(_) => output.push_null(),
+            },
+        ),
+    );
+
+    fn eval_parse_number(val: ValueRef<StringType>, ctx: &mut EvalContext) -> Value<Float64Type> {
+        vectorize_with_builder_1_arg::<StringType, Float64Type>(|val, output, ctx| {
+            match parse_number(val, None) {
+                Ok(n) => output.push(n),
+                Err(e) => {
+                    ctx.set_error(output.len(), e);
+                    output.push(F64::default());
+                }
+            }
+        })(val, ctx)
+    }
+
+    fn parse_number(val: &[u8], locale: Option<&[u8]>) -> Result<F64, String> {
+        let locale = match locale {
+            Some(locale) => find_locale(locale)?,
+            None => default_locale(),
+        };
+        let val = std::str::from_utf8(val).map_err(|e| e.to_string())?;
+        locale.parse_number(val).map(F64::from)
+    }
+
     registry.register_1_arg_core::<Float64Type, UInt8Type, _, _>(
         "sleep",
         |_, _| FunctionDomain::MayThrow,
@@ -76,7 +76,13 @@ use jsonb::get_by_path;
 use jsonb::get_by_path_array;
 use jsonb::get_by_path_first;
 use jsonb::is_array;
+use jsonb::is_boolean;
+use jsonb::is_f64;
+use jsonb::is_i64;
+use jsonb::is_null;
 use jsonb::is_object;
+use jsonb::is_string;
+use jsonb::is_u64;
 use jsonb::jsonpath::parse_json_path;
 use jsonb::keypath::parse_key_paths;
 use jsonb::object_keys;
@@ -1103,6 +1109,49 @@ pub fn register(registry: &mut FunctionRegistry) {
         }),
     );
 
+    // The `is_*` predicates only need to read the JSONB header, the values are never decoded.
+    registry.register_passthrough_nullable_1_arg::<VariantType, BooleanType, _, _>(
+        "is_null_value",
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<VariantType, BooleanType>(|v, _| is_null(v)),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<VariantType, BooleanType, _, _>(
+        "is_boolean",
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<VariantType, BooleanType>(|v, _| is_boolean(v)),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<VariantType, BooleanType, _, _>(
+        "is_integer",
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<VariantType, BooleanType>(|v, _| is_i64(v) || is_u64(v)),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<VariantType, BooleanType, _, _>(
+        "is_float",
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<VariantType, BooleanType>(|v, _| is_f64(v)),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<VariantType, BooleanType, _, _>(
+        "is_string",
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<VariantType, BooleanType>(|v, _| is_string(v)),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<VariantType, BooleanType, _, _>(
+        "is_array",
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<VariantType, BooleanType>(|v, _| is_array(v)),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<VariantType, BooleanType, _, _>(
+        "is_object",
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<VariantType, BooleanType>(|v, _| is_object(v)),
+    );
+
     registry.register_function_factory("json_object", |_, args_type| {
         Some(Arc::new(Function {
             signature: FunctionSignature {
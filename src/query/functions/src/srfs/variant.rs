@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use common_expression::types::string::StringColumnBuilder;
+use common_expression::types::variant::cast_scalar_to_variant;
 use common_expression::types::AnyType;
 use common_expression::types::DataType;
 use common_expression::types::NumberDataType;
@@ -237,7 +238,12 @@ pub fn register(registry: &mut FunctionRegistry) {
         if args_type.is_empty() || args_type.len() > 5 {
             return None;
         }
-        if args_type[0].remove_nullable() != DataType::Variant && args_type[0] != DataType::Null {
+        // Arrays are flattened as the Variant array they would be cast to.
+        if !matches!(
+            args_type[0].remove_nullable(),
+            DataType::Variant | DataType::Array(_) | DataType::EmptyArray
+        ) && args_type[0] != DataType::Null
+        {
             return None;
         }
         if args_type.len() >= 2
@@ -395,37 +401,39 @@ pub fn register(registry: &mut FunctionRegistry) {
                         }
                     }
                     let mut generator = FlattenGenerator::create(outer, recursive, mode);
+                    let mut buf = Vec::new();
 
                     for (row, max_nums_per_row) in
                         max_nums_per_row.iter_mut().enumerate().take(ctx.num_rows)
                     {
-                        match arg.index(row).unwrap() {
+                        let val = match arg.index(row).unwrap() {
                             ScalarRef::Null => {
                                 results.push((Value::Scalar(Scalar::Tuple(vec![Scalar::Null])), 0));
+                                continue;
                             }
-                            ScalarRef::Variant(val) => {
-                                let columns = match json_path {
-                                    Some((path, ref selector)) => {
-                                        // get inner input values by path
-                                        let mut builder = StringColumnBuilder::with_capacity(0, 0);
-                                        selector.select(
-                                            val,
-                                            &mut builder.data,
-                                            &mut builder.offsets,
-                                        );
-                                        let inner_val = builder.pop().unwrap_or_default();
-                                        generator.generate((row + 1) as u64, &inner_val, path)
-                                    }
-                                    None => generator.generate((row + 1) as u64, val, ""),
-                                };
-                                let len = columns[0].len();
-                                *max_nums_per_row = std::cmp::max(*max_nums_per_row, len);
-
-                                let inner_col = Column::Tuple(columns).wrap_nullable(None);
-                                results.push((Value::Column(Column::Tuple(vec![inner_col])), len));
+                            ScalarRef::Variant(val) => val,
+                            scalar @ (ScalarRef::Array(_) | ScalarRef::EmptyArray) => {
+                                buf.clear();
+                                cast_scalar_to_variant(scalar, ctx.func_ctx.tz, &mut buf);
+                                buf.as_slice()
                             }
                             _ => unreachable!(),
-                        }
+                        };
+                        let columns = match json_path {
+                            Some((path, ref selector)) => {
+                                // get inner input values by path
+                                let mut builder = StringColumnBuilder::with_capacity(0, 0);
+                                selector.select(val, &mut builder.data, &mut builder.offsets);
+                                let inner_val = builder.pop().unwrap_or_default();
+                                generator.generate((row + 1) as u64, &inner_val, path)
+                            }
+                            None => generator.generate((row + 1) as u64, val, ""),
+                        };
+                        let len = columns[0].len();
+                        *max_nums_per_row = std::cmp::max(*max_nums_per_row, len);
+
+                        let inner_col = Column::Tuple(columns).wrap_nullable(None);
+                        results.push((Value::Column(Column::Tuple(vec![inner_col])), len));
                     }
                     results
                 }),
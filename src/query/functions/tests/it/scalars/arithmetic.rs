@@ -14,14 +14,22 @@
 
 use std::io::Write;
 
+use common_expression::type_check;
 use common_expression::types::decimal::DecimalColumn;
 use common_expression::types::decimal::DecimalSize;
 use common_expression::types::number::*;
+use common_expression::BlockEntry;
 use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
 use common_expression::FromData;
+use common_expression::FunctionContext;
+use common_expression::Value;
+use common_functions::BUILTIN_FUNCTIONS;
 use ethnum::i256;
 use goldenfile::Mint;
 
+use super::parser;
 use super::run_ast;
 
 #[test]
@@ -271,3 +279,60 @@ fn test_bitwise_shift_right(file: &mut impl Write, columns: &[(&str, Column)]) {
     run_ast(file, "a2 >> 2", columns);
     run_ast(file, "c >> 2", columns);
 }
+
+#[test]
+fn test_nullable_fast_path() {
+    let all_valid = Int64Type::from_data_with_validity(vec![1i64, 2, 3], vec![true; 3]);
+    let all_null = Int64Type::from_data_with_validity(vec![1i64, 0, 3], vec![false; 3]);
+    let half_null = Int64Type::from_data_with_validity(vec![1i64, 0, 3], vec![true, false, true]);
+
+    // No NULLs: the result is the dense result.
+    assert_eq!(
+        eval("a + b", &[
+            ("a", all_valid.clone()),
+            ("b", all_valid.clone())
+        ]),
+        Int64Type::from_data_with_validity(vec![2i64, 4, 6], vec![true; 3]),
+    );
+
+    // All NULLs: the kernel is skipped, so dividing by the zero hidden behind NULL is fine.
+    assert_eq!(
+        eval("a / b", &[
+            ("a", all_valid.clone()),
+            ("b", all_null.clone())
+        ]),
+        Float64Type::from_data_with_validity(vec![0f64; 3], vec![false; 3]),
+    );
+    assert_eq!(
+        eval("a + b + 1", &[("a", all_null), ("b", all_valid.clone())]),
+        Int64Type::from_data_with_validity(vec![0i64; 3], vec![false; 3]),
+    );
+
+    // Mixed: the errors on NULL rows are still ignored.
+    assert_eq!(
+        eval("a / b", &[("a", all_valid), ("b", half_null)]),
+        Float64Type::from_data_with_validity(vec![1f64, 0.0, 1.0], vec![true, false, true]),
+    );
+}
+
+fn eval(text: &str, columns: &[(&str, Column)]) -> Column {
+    let raw_expr = parser::parse_raw_expr(
+        text,
+        &columns
+            .iter()
+            .map(|(name, c)| (*name, c.data_type()))
+            .collect::<Vec<_>>(),
+    );
+    let expr = type_check::check(&raw_expr, &BUILTIN_FUNCTIONS).unwrap();
+    let num_rows = columns[0].1.len();
+    let block = DataBlock::new(
+        columns
+            .iter()
+            .map(|(_, col)| BlockEntry::new(col.data_type(), Value::Column(col.clone())))
+            .collect(),
+        num_rows,
+    );
+    let func_ctx = FunctionContext::default();
+    let evaluator = Evaluator::new(&block, &func_ctx, &BUILTIN_FUNCTIONS);
+    evaluator.run(&expr).unwrap().into_column().unwrap()
+}
@@ -14,12 +14,21 @@
 
 use std::io::Write;
 
+use common_expression::type_check;
 use common_expression::types::nullable::NullableColumn;
 use common_expression::types::*;
+use common_expression::BlockEntry;
 use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
 use common_expression::FromData;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::Value;
+use common_functions::BUILTIN_FUNCTIONS;
 use goldenfile::Mint;
 
+use super::parser;
 use super::run_ast;
 
 fn one_null_column() -> Vec<(&'static str, Column)> {
@@ -128,3 +137,72 @@ fn test_is_true(file: &mut impl Write) {
         ]),
     )]);
 }
+
+#[test]
+fn test_and_filters_selection() {
+    let columns = [
+        ("a", Int64Type::from_data((0..10).collect::<Vec<i64>>())),
+        (
+            "b",
+            StringType::from_data(vec!["x", "y", "x", "y", "x", "y", "x", "y", "x", "y"]),
+        ),
+        (
+            "c",
+            Int64Type::from_data_with_validity(vec![1, 1, 1, 1, 1, 1, 1, 0, 1, 1], vec![
+                true, true, false, true, true, true, true, true, true, true,
+            ]),
+        ),
+    ];
+
+    // The first conjunct selects only a few rows, the rest are evaluated on them only.
+    assert_eq!(
+        eval_filter("and_filters(a < 2, b = 'x', c > 0)", &columns),
+        vec![
+            true, false, false, false, false, false, false, false, false, false
+        ]
+    );
+    assert_eq!(
+        eval_filter("and_filters(a > 5, b = 'x', c > 0)", &columns),
+        vec![
+            false, false, false, false, false, false, true, false, true, false
+        ]
+    );
+    assert_eq!(eval_filter("and_filters(a > 20, b = 'x')", &columns), vec![
+        false;
+        10
+    ]);
+
+    // Rows out of the selection are not evaluated, so dividing by zero is not an error.
+    assert_eq!(
+        eval_filter("and_filters(a = 7, 100 / (a - 5) = 50)", &columns),
+        vec![
+            false, false, false, false, false, false, false, true, false, false
+        ]
+    );
+}
+
+fn eval_filter(text: &str, columns: &[(&str, Column)]) -> Vec<bool> {
+    let raw_expr = parser::parse_raw_expr(
+        text,
+        &columns
+            .iter()
+            .map(|(name, c)| (*name, c.data_type()))
+            .collect::<Vec<_>>(),
+    );
+    let expr = type_check::check(&raw_expr, &BUILTIN_FUNCTIONS).unwrap();
+    let num_rows = columns[0].1.len();
+    let block = DataBlock::new(
+        columns
+            .iter()
+            .map(|(_, col)| BlockEntry::new(col.data_type(), Value::Column(col.clone())))
+            .collect(),
+        num_rows,
+    );
+    let func_ctx = FunctionContext::default();
+    let evaluator = Evaluator::new(&block, &func_ctx, &BUILTIN_FUNCTIONS);
+    match evaluator.run(&expr).unwrap() {
+        Value::Scalar(Scalar::Boolean(flag)) => vec![flag; num_rows],
+        Value::Column(Column::Boolean(bitmap)) => bitmap.iter().collect(),
+        value => unreachable!("unexpected filter result {value}"),
+    }
+}
@@ -48,6 +48,11 @@ fn test_geo_to_h3(file: &mut impl Write) {
             Float64Type::from_data(vec![55.77922738, 55.76324100, 55.72076200]),
         ),
     ]);
+    run_ast(
+        file,
+        r#"geo_to_h3(parse_json('{"type":"Point","coordinates":[37.79506683,55.71290588]}'), 15)"#,
+        &[],
+    );
 }
 
 fn test_great_circle_distance(file: &mut impl Write) {
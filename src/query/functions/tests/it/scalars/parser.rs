@@ -516,11 +516,13 @@ fn transform_data_type(target_type: common_ast::ast::TypeName) -> DataType {
         common_ast::ast::TypeName::Int64 => DataType::Number(NumberDataType::Int64),
         common_ast::ast::TypeName::Float32 => DataType::Number(NumberDataType::Float32),
         common_ast::ast::TypeName::Float64 => DataType::Number(NumberDataType::Float64),
+        common_ast::ast::TypeName::UInt128 => DataType::Number(NumberDataType::UInt128),
+        common_ast::ast::TypeName::Int128 => DataType::Number(NumberDataType::Int128),
         common_ast::ast::TypeName::Decimal { precision, scale } => {
             DataType::Decimal(DecimalDataType::from_size(DecimalSize { precision, scale }).unwrap())
         }
         common_ast::ast::TypeName::String => DataType::String,
-        common_ast::ast::TypeName::Timestamp => DataType::Timestamp,
+        common_ast::ast::TypeName::Timestamp { .. } => DataType::Timestamp,
         common_ast::ast::TypeName::Date => DataType::Date,
         common_ast::ast::TypeName::Array(item_type) => {
             DataType::Array(Box::new(transform_data_type(*item_type)))
@@ -538,6 +540,7 @@ fn transform_data_type(target_type: common_ast::ast::TypeName) -> DataType {
             DataType::Nullable(Box::new(transform_data_type(*inner_type)))
         }
         common_ast::ast::TypeName::Variant => DataType::Variant,
+        common_ast::ast::TypeName::Geometry => DataType::Geometry,
     }
 }
 
@@ -56,6 +56,7 @@ fn test_variant() {
     test_exists_all_keys_op(file);
     test_contains_in_left_op(file);
     test_contains_in_right_op(file);
+    test_is_type(file);
 }
 
 fn test_parse_json(file: &mut impl Write) {
@@ -1294,3 +1295,16 @@ fn test_contains_in_right_op(file: &mut impl Write) {
         &[],
     );
 }
+
+fn test_is_type(file: &mut impl Write) {
+    run_ast(file, r#"is_null_value(NULL)"#, &[]);
+    run_ast(file, r#"is_null_value(parse_json('null'))"#, &[]);
+    run_ast(file, r#"is_boolean(parse_json('true'))"#, &[]);
+    run_ast(file, r#"is_integer(parse_json('-1'))"#, &[]);
+    run_ast(file, r#"is_integer(parse_json('1.5'))"#, &[]);
+    run_ast(file, r#"is_float(parse_json('1.5'))"#, &[]);
+    run_ast(file, r#"is_string(parse_json('"a"'))"#, &[]);
+    run_ast(file, r#"is_array(parse_json('[1,2]'))"#, &[]);
+    run_ast(file, r#"is_array(parse_json('{"a":1}'))"#, &[]);
+    run_ast(file, r#"is_object(parse_json('{"a":1}'))"#, &[]);
+}
@@ -20,9 +20,12 @@ mod file_format;
 mod network_policy;
 mod quota;
 mod role;
+mod secret;
+mod sequence;
 mod serde;
 mod setting;
 mod stage;
+mod subject_key;
 mod udf;
 mod user;
 
@@ -38,12 +41,18 @@ pub use quota::QuotaApi;
 pub use quota::QuotaMgr;
 pub use role::RoleApi;
 pub use role::RoleMgr;
+pub use secret::SecretApi;
+pub use secret::SecretMgr;
+pub use sequence::SequenceApi;
+pub use sequence::SequenceMgr;
 pub use serde::deserialize_struct;
 pub use serde::serialize_struct;
 pub use setting::SettingApi;
 pub use setting::SettingMgr;
 pub use stage::StageApi;
 pub use stage::StageMgr;
+pub use subject_key::SubjectKeyApi;
+pub use subject_key::SubjectKeyMgr;
 pub use udf::UdfApi;
 pub use udf::UdfMgr;
 pub use user::UserApi;
@@ -14,11 +14,12 @@
 
 use std::sync::Arc;
 
-use common_base::base::escape_for_key;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::tenant::TenantQuota;
+use common_meta_app::tenant::TenantQuotaIdent;
 use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::Key;
 use common_meta_kvapi::kvapi::UpsertKVReq;
 use common_meta_types::IntoSeqV;
 use common_meta_types::MatchSeq;
@@ -29,8 +30,6 @@ use common_meta_types::SeqV;
 
 use super::quota_api::QuotaApi;
 
-static QUOTA_API_KEY_PREFIX: &str = "__fd_quotas";
-
 pub struct QuotaMgr {
     kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
     key: String,
@@ -45,7 +44,7 @@ impl QuotaMgr {
         }
         Ok(QuotaMgr {
             kv_api,
-            key: format!("{}/{}", QUOTA_API_KEY_PREFIX, escape_for_key(tenant)?),
+            key: TenantQuotaIdent::new(tenant).to_string_key(),
         })
     }
 }
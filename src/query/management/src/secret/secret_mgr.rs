@@ -0,0 +1,123 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::escape_for_key;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::UserDefinedSecret;
+use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::MatchSeq;
+use common_meta_types::MatchSeqExt;
+use common_meta_types::MetaError;
+use common_meta_types::Operation;
+use common_meta_types::SeqV;
+
+use crate::serde::deserialize_struct;
+use crate::serde::serialize_struct;
+use crate::SecretApi;
+
+static USER_SECRET_API_KEY_PREFIX: &str = "__fd_secret";
+
+pub struct SecretMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    secret_prefix: String,
+}
+
+impl SecretMgr {
+    pub fn create(kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>, tenant: &str) -> Result<Self> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while secret mgr create)",
+            ));
+        }
+
+        Ok(Self {
+            kv_api,
+            secret_prefix: format!("{}/{}", USER_SECRET_API_KEY_PREFIX, escape_for_key(tenant)?),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl SecretApi for SecretMgr {
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn add_secret(&self, info: UserDefinedSecret) -> Result<u64> {
+        let seq = MatchSeq::Exact(0);
+        let val = Operation::Update(serialize_struct(&info, ErrorCode::IllegalSecret, || "")?);
+        let key = format!("{}/{}", self.secret_prefix, escape_for_key(&info.name)?);
+        let upsert_info = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, val, None));
+
+        let res_seq = upsert_info.await?.added_seq_or_else(|v| {
+            ErrorCode::SecretAlreadyExists(format!("secret already exists, seq [{}]", v.seq))
+        })?;
+
+        Ok(res_seq)
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_secret(&self, name: &str, seq: MatchSeq) -> Result<SeqV<UserDefinedSecret>> {
+        let key = format!("{}/{}", self.secret_prefix, escape_for_key(name)?);
+        let kv_api = self.kv_api.clone();
+        let get_kv = async move { kv_api.get_kv(&key).await };
+        let res = get_kv.await?;
+        let seq_value =
+            res.ok_or_else(|| ErrorCode::UnknownSecret(format!("Unknown secret {}", name)))?;
+
+        match seq.match_seq(&seq_value) {
+            Ok(_) => Ok(SeqV::new(
+                seq_value.seq,
+                deserialize_struct(&seq_value.data, ErrorCode::IllegalSecret, || "")?,
+            )),
+            Err(_) => Err(ErrorCode::UnknownSecret(format!("Unknown secret {}", name))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_secrets(&self) -> Result<Vec<UserDefinedSecret>> {
+        let values = self.kv_api.prefix_list_kv(&self.secret_prefix).await?;
+
+        let mut secret_infos = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            let secret_info = deserialize_struct(&value.data, ErrorCode::IllegalSecret, || "")?;
+            secret_infos.push(secret_info);
+        }
+        Ok(secret_infos)
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn drop_secret(&self, name: &str, seq: MatchSeq) -> Result<()> {
+        let key = format!("{}/{}", self.secret_prefix, escape_for_key(name)?);
+        let kv_api = self.kv_api.clone();
+        let upsert_kv = async move {
+            kv_api
+                .upsert_kv(UpsertKVReq::new(&key, seq, Operation::Delete, None))
+                .await
+        };
+        let res = upsert_kv.await?;
+        if res.prev.is_some() && res.result.is_none() {
+            Ok(())
+        } else {
+            Err(ErrorCode::UnknownSecret(format!("Unknown secret {}", name)))
+        }
+    }
+}
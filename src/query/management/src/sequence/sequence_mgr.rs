@@ -0,0 +1,176 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::escape_for_key;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::SequenceRange;
+use common_meta_app::principal::UserDefinedSequence;
+use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::MatchSeq;
+use common_meta_types::MatchSeqExt;
+use common_meta_types::MetaError;
+use common_meta_types::Operation;
+use common_meta_types::SeqV;
+
+use crate::serde::deserialize_struct;
+use crate::serde::serialize_struct;
+use crate::SequenceApi;
+
+static USER_SEQUENCE_API_KEY_PREFIX: &str = "__fd_sequences";
+
+/// Max number of attempts to reserve values when the sequence is concurrently updated.
+const RESERVE_SEQUENCE_MAX_RETRIES: usize = 32;
+
+pub struct SequenceMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    sequence_prefix: String,
+}
+
+impl SequenceMgr {
+    pub fn create(kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>, tenant: &str) -> Result<Self> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while sequence mgr create)",
+            ));
+        }
+
+        Ok(Self {
+            kv_api,
+            sequence_prefix: format!(
+                "{}/{}",
+                USER_SEQUENCE_API_KEY_PREFIX,
+                escape_for_key(tenant)?
+            ),
+        })
+    }
+
+    fn sequence_key(&self, name: &str) -> Result<String> {
+        Ok(format!(
+            "{}/{}",
+            self.sequence_prefix,
+            escape_for_key(name)?
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl SequenceApi for SequenceMgr {
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn add_sequence(&self, info: UserDefinedSequence) -> Result<u64> {
+        let seq = MatchSeq::Exact(0);
+        let val = Operation::Update(serialize_struct(&info, ErrorCode::IllegalSequence, || "")?);
+        let key = self.sequence_key(&info.name)?;
+        let upsert_info = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, val, None));
+
+        let res_seq = upsert_info.await?.added_seq_or_else(|v| {
+            ErrorCode::SequenceAlreadyExists(format!("sequence already exists, seq [{}]", v.seq))
+        })?;
+
+        Ok(res_seq)
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_sequence(&self, name: &str, seq: MatchSeq) -> Result<SeqV<UserDefinedSequence>> {
+        let key = self.sequence_key(name)?;
+        let res = self.kv_api.get_kv(&key).await?;
+        let seq_value =
+            res.ok_or_else(|| ErrorCode::UnknownSequence(format!("Unknown sequence {}", name)))?;
+
+        match seq.match_seq(&seq_value) {
+            Ok(_) => Ok(SeqV::new(
+                seq_value.seq,
+                deserialize_struct(&seq_value.data, ErrorCode::IllegalSequence, || "")?,
+            )),
+            Err(_) => Err(ErrorCode::UnknownSequence(format!(
+                "Unknown sequence {}",
+                name
+            ))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_sequences(&self) -> Result<Vec<UserDefinedSequence>> {
+        let values = self.kv_api.prefix_list_kv(&self.sequence_prefix).await?;
+
+        let mut sequences = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            let sequence = deserialize_struct(&value.data, ErrorCode::IllegalSequence, || "")?;
+            sequences.push(sequence);
+        }
+        Ok(sequences)
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn reserve_sequence_values(&self, name: &str, count: u64) -> Result<SequenceRange> {
+        let key = self.sequence_key(name)?;
+        for _ in 0..RESERVE_SEQUENCE_MAX_RETRIES {
+            let SeqV {
+                seq,
+                data: mut sequence,
+                ..
+            } = self.get_sequence(name, MatchSeq::GE(1)).await?;
+
+            let range = sequence.reserve(count.max(sequence.cache)).ok_or_else(|| {
+                ErrorCode::IllegalSequence(format!("Sequence {} reached its max value", name))
+            })?;
+
+            // Only succeed if nobody else reserved values in the meantime.
+            let val = Operation::Update(serialize_struct(
+                &sequence,
+                ErrorCode::IllegalSequence,
+                || "",
+            )?);
+            let res = self
+                .kv_api
+                .upsert_kv(UpsertKVReq::new(&key, MatchSeq::Exact(seq), val, None))
+                .await?;
+            if res.is_changed() {
+                return Ok(range);
+            }
+        }
+
+        Err(ErrorCode::IllegalSequence(format!(
+            "Fail to reserve values of sequence {}, it is being updated concurrently",
+            name
+        )))
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn drop_sequence(&self, name: &str, seq: MatchSeq) -> Result<()> {
+        let key = self.sequence_key(name)?;
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, Operation::Delete, None))
+            .await?;
+        if res.prev.is_some() && res.result.is_none() {
+            Ok(())
+        } else {
+            Err(ErrorCode::UnknownSequence(format!(
+                "Unknown sequence {}",
+                name
+            )))
+        }
+    }
+}
@@ -0,0 +1,33 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_meta_app::principal::SubjectKey;
+
+#[async_trait::async_trait]
+pub trait SubjectKeyApi: Sync + Send {
+    // Get the key encryption key of the tenant, `kek` is added if there is none yet.
+    async fn get_or_add_kek(&self, kek: SubjectKey) -> Result<SubjectKey>;
+
+    // Get the key of a subject, `key` is added if there is none yet.
+    async fn get_or_add_subject_key(&self, key: SubjectKey) -> Result<SubjectKey>;
+
+    async fn get_subject_key(&self, subject: &str) -> Result<Option<SubjectKey>>;
+
+    // Get all the subject keys of a tenant.
+    async fn get_subject_keys(&self) -> Result<Vec<SubjectKey>>;
+
+    // Drop the key of a subject, returns false if there is no such key.
+    async fn drop_subject_key(&self, subject: &str) -> Result<bool>;
+}
@@ -0,0 +1,153 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::escape_for_key;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::SubjectKey;
+use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::MatchSeq;
+use common_meta_types::MetaError;
+use common_meta_types::Operation;
+
+use crate::serde::deserialize_struct;
+use crate::serde::serialize_struct;
+use crate::SubjectKeyApi;
+
+static SUBJECT_KEY_API_KEY_PREFIX: &str = "__fd_subject_keys";
+static SUBJECT_KEK_API_KEY_PREFIX: &str = "__fd_subject_kek";
+
+pub struct SubjectKeyMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    subject_key_prefix: String,
+    kek_key: String,
+}
+
+impl SubjectKeyMgr {
+    pub fn create(kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>, tenant: &str) -> Result<Self> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while subject key mgr create)",
+            ));
+        }
+
+        Ok(Self {
+            kv_api,
+            subject_key_prefix: format!(
+                "{}/{}",
+                SUBJECT_KEY_API_KEY_PREFIX,
+                escape_for_key(tenant)?
+            ),
+            kek_key: format!("{}/{}", SUBJECT_KEK_API_KEY_PREFIX, escape_for_key(tenant)?),
+        })
+    }
+
+    fn subject_key(&self, subject: &str) -> Result<String> {
+        Ok(format!(
+            "{}/{}",
+            self.subject_key_prefix,
+            escape_for_key(subject)?
+        ))
+    }
+
+    // Adds the value if the key doesn't exist, otherwise returns the existing value.
+    async fn get_or_add(&self, key: &str, value: SubjectKey) -> Result<SubjectKey> {
+        let val = Operation::Update(serialize_struct(
+            &value,
+            ErrorCode::IllegalSubjectKey,
+            || "",
+        )?);
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(key, MatchSeq::Exact(0), val, None))
+            .await?;
+        if res.is_changed() {
+            return Ok(value);
+        }
+
+        // Another query added it first.
+        match res.result {
+            Some(v) => deserialize_struct(&v.data, ErrorCode::IllegalSubjectKey, || ""),
+            None => Err(ErrorCode::IllegalSubjectKey(format!(
+                "Fail to add subject key {}, it is being dropped concurrently",
+                value.subject
+            ))),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl SubjectKeyApi for SubjectKeyMgr {
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_or_add_kek(&self, kek: SubjectKey) -> Result<SubjectKey> {
+        self.get_or_add(&self.kek_key, kek).await
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_or_add_subject_key(&self, key: SubjectKey) -> Result<SubjectKey> {
+        let subject_key = self.subject_key(&key.subject)?;
+        self.get_or_add(&subject_key, key).await
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_subject_key(&self, subject: &str) -> Result<Option<SubjectKey>> {
+        let key = self.subject_key(subject)?;
+        match self.kv_api.get_kv(&key).await? {
+            Some(v) => Ok(Some(deserialize_struct(
+                &v.data,
+                ErrorCode::IllegalSubjectKey,
+                || "",
+            )?)),
+            None => Ok(None),
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_subject_keys(&self) -> Result<Vec<SubjectKey>> {
+        let values = self
+            .kv_api
+            .prefix_list_kv(&format!("{}/", self.subject_key_prefix))
+            .await?;
+
+        let mut keys = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            let key = deserialize_struct(&value.data, ErrorCode::IllegalSubjectKey, || "")?;
+            keys.push(key);
+        }
+        Ok(keys)
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn drop_subject_key(&self, subject: &str) -> Result<bool> {
+        let key = self.subject_key(subject)?;
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(
+                &key,
+                MatchSeq::GE(1),
+                Operation::Delete,
+                None,
+            ))
+            .await?;
+        Ok(res.prev.is_some() && res.result.is_none())
+    }
+}
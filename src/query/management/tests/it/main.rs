@@ -15,7 +15,9 @@
 #![allow(clippy::uninlined_format_args)]
 
 mod cluster;
+mod sequence;
 mod setting;
 mod stage;
+mod subject_key;
 mod udf;
 mod user;
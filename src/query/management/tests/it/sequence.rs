@@ -0,0 +1,102 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_management::*;
+use common_meta_app::principal::UserDefinedSequence;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_types::MatchSeq;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_add_and_drop_sequence() -> Result<()> {
+    let (_, sequence_api) = new_sequence_api().await?;
+
+    let sequence = UserDefinedSequence::new("seq1", 1, 1, 100, None);
+    sequence_api.add_sequence(sequence.clone()).await?;
+    match sequence_api.add_sequence(sequence.clone()).await {
+        Ok(_) => panic!("Already exists add sequence must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), ErrorCode::SEQUENCE_ALREADY_EXISTS),
+    }
+
+    let got = sequence_api.get_sequence("seq1", MatchSeq::GE(0)).await?;
+    assert_eq!(got.data, sequence);
+    assert_eq!(sequence_api.get_sequences().await?, vec![sequence]);
+
+    sequence_api.drop_sequence("seq1", MatchSeq::GE(1)).await?;
+    match sequence_api.get_sequence("seq1", MatchSeq::GE(0)).await {
+        Ok(_) => panic!("Get dropped sequence must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), ErrorCode::UNKNOWN_SEQUENCE),
+    }
+    match sequence_api.drop_sequence("seq1", MatchSeq::GE(1)).await {
+        Ok(_) => panic!("Drop unknown sequence must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), ErrorCode::UNKNOWN_SEQUENCE),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_reserve_sequence_values() -> Result<()> {
+    let (_, sequence_api) = new_sequence_api().await?;
+
+    let sequence = UserDefinedSequence::new("seq1", 10, 5, 1, None);
+    sequence_api.add_sequence(sequence).await?;
+
+    let range = sequence_api.reserve_sequence_values("seq1", 3).await?;
+    assert_eq!(range.collect::<Vec<_>>(), vec![10, 15, 20]);
+    let range = sequence_api.reserve_sequence_values("seq1", 2).await?;
+    assert_eq!(range.collect::<Vec<_>>(), vec![25, 30]);
+
+    let got = sequence_api.get_sequence("seq1", MatchSeq::GE(0)).await?;
+    assert_eq!(got.data.next_value, 35);
+
+    // At least `cache` values are reserved at a time.
+    let sequence = UserDefinedSequence::new("seq_cached", 1, 1, 100, None);
+    sequence_api.add_sequence(sequence).await?;
+    let range = sequence_api
+        .reserve_sequence_values("seq_cached", 3)
+        .await?;
+    assert_eq!(range.count(), 100);
+    let range = sequence_api
+        .reserve_sequence_values("seq_cached", 200)
+        .await?;
+    assert_eq!(range.next, 101);
+    assert_eq!(range.remaining, 200);
+
+    // Reserving values of an unknown sequence fails.
+    match sequence_api.reserve_sequence_values("seq2", 1).await {
+        Ok(_) => panic!("Reserve values of unknown sequence must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), ErrorCode::UNKNOWN_SEQUENCE),
+    }
+
+    // Reserving values beyond u64::MAX fails.
+    let sequence = UserDefinedSequence::new("seq3", u64::MAX - 1, 1, 1, None);
+    sequence_api.add_sequence(sequence).await?;
+    match sequence_api.reserve_sequence_values("seq3", 2).await {
+        Ok(_) => panic!("Reserve values of exhausted sequence must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), ErrorCode::ILLEGAL_SEQUENCE),
+    }
+
+    Ok(())
+}
+
+async fn new_sequence_api() -> Result<(Arc<MetaEmbedded>, SequenceMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = SequenceMgr::create(test_api.clone(), "admin")?;
+    Ok((test_api, mgr))
+}
@@ -0,0 +1,70 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_management::*;
+use common_meta_app::principal::SubjectKey;
+use common_meta_embedded::MetaEmbedded;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_add_and_drop_subject_key() -> Result<()> {
+    let (_, subject_key_api) = new_subject_key_api().await?;
+
+    let key = SubjectKey::new("alice", vec![1, 2, 3]);
+    let got = subject_key_api.get_or_add_subject_key(key.clone()).await?;
+    assert_eq!(got, key);
+
+    // The existing key is kept.
+    let got = subject_key_api
+        .get_or_add_subject_key(SubjectKey::new("alice", vec![4, 5, 6]))
+        .await?;
+    assert_eq!(got, key);
+    assert_eq!(
+        subject_key_api.get_subject_key("alice").await?,
+        Some(key.clone())
+    );
+    assert_eq!(subject_key_api.get_subject_keys().await?, vec![key]);
+
+    assert!(subject_key_api.drop_subject_key("alice").await?);
+    assert_eq!(subject_key_api.get_subject_key("alice").await?, None);
+    assert!(!subject_key_api.drop_subject_key("alice").await?);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_get_or_add_kek() -> Result<()> {
+    let (_, subject_key_api) = new_subject_key_api().await?;
+
+    let kek = SubjectKey::new("", vec![1, 2, 3]);
+    assert_eq!(subject_key_api.get_or_add_kek(kek.clone()).await?, kek);
+    let got = subject_key_api
+        .get_or_add_kek(SubjectKey::new("", vec![4, 5, 6]))
+        .await?;
+    assert_eq!(got, kek);
+
+    // The key encryption key is not a subject key.
+    assert!(subject_key_api.get_subject_keys().await?.is_empty());
+
+    Ok(())
+}
+
+async fn new_subject_key_api() -> Result<(Arc<MetaEmbedded>, SubjectKeyMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = SubjectKeyMgr::create(test_api.clone(), "admin")?;
+    Ok((test_api, mgr))
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_expression::ColumnBuilder;
 use common_expression::TableSchemaRef;
 use common_storage::FileParseError;
 
@@ -39,3 +40,26 @@ pub fn get_decode_error_by_pos(
         column_data: truncate_column_data(column_data),
     }
 }
+
+/// Replaces the value that fails to be decoded by the default value of the column, i.e. NULL
+/// for nullable columns, and collects the error, if `null_errors` is provided.
+///
+/// `len` is the length of the column before decoding the value.
+pub fn null_on_error(
+    builder: &mut ColumnBuilder,
+    len: usize,
+    result: std::result::Result<(), FileParseError>,
+    null_errors: Option<&mut Vec<FileParseError>>,
+) -> std::result::Result<(), FileParseError> {
+    match (result, null_errors) {
+        (Err(e), Some(null_errors)) => {
+            if builder.len() > len {
+                builder.pop();
+            }
+            builder.push_default();
+            null_errors.push(e);
+            Ok(())
+        }
+        (result, _) => result,
+    }
+}
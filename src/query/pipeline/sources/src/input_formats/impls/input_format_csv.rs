@@ -33,6 +33,7 @@ use csv_core::ReadRecordResult;
 use log::debug;
 
 use crate::input_formats::error_utils::get_decode_error_by_pos;
+use crate::input_formats::error_utils::null_on_error;
 use crate::input_formats::AligningStateCommon;
 use crate::input_formats::AligningStateTextBased;
 use crate::input_formats::BlockBuilder;
@@ -70,10 +71,11 @@ impl InputFormatCSV {
             return Ok(());
         }
         field_decoder
-            .read_field(builder, col_data)
+            .read_column_field(column_index, builder, col_data)
             .map_err(|e| get_decode_error_by_pos(column_index, schema, &e.message(), col_data))
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn read_row(
         field_decoder: &SeparatedTextDecoder,
         buf: &[u8],
@@ -82,6 +84,7 @@ impl InputFormatCSV {
         field_ends: &[usize],
         columns_to_read: &Option<Vec<usize>>,
         default_values: &Option<Vec<Scalar>>,
+        mut null_errors: Option<&mut Vec<FileParseError>>,
     ) -> std::result::Result<(), FileParseError> {
         if let Some(columns_to_read) = columns_to_read {
             for c in columns_to_read {
@@ -91,14 +94,16 @@ impl InputFormatCSV {
                     let field_start = if *c == 0 { 0 } else { field_ends[c - 1] };
                     let field_end = field_ends[*c];
                     let col_data = &buf[field_start..field_end];
-                    Self::read_column(
+                    let len = columns[*c].len();
+                    let res = Self::read_column(
                         &mut columns[*c],
                         field_decoder,
                         col_data,
                         *c,
                         schema,
                         default_values,
-                    )?;
+                    );
+                    null_on_error(&mut columns[*c], len, res, null_errors.as_deref_mut())?;
                 }
             }
         } else {
@@ -106,7 +111,10 @@ impl InputFormatCSV {
             for (c, column) in columns.iter_mut().enumerate() {
                 let field_end = field_ends[c];
                 let col_data = &buf[field_start..field_end];
-                Self::read_column(column, field_decoder, col_data, c, schema, default_values)?;
+                let len = column.len();
+                let res =
+                    Self::read_column(column, field_decoder, col_data, c, schema, default_values);
+                null_on_error(column, len, res, null_errors.as_deref_mut())?;
                 field_start = field_end;
             }
         }
@@ -124,16 +132,20 @@ impl InputFormatTextBase for InputFormatCSV {
     fn create_field_decoder(
         params: &FileFormatParams,
         options: &FileFormatOptionsExt,
-    ) -> Arc<dyn FieldDecoder> {
+    ) -> Result<Arc<dyn FieldDecoder>> {
         let csv_params = CsvFileFormatParams::downcast_unchecked(params);
-        Arc::new(SeparatedTextDecoder::create_csv(csv_params, options))
+        Ok(Arc::new(SeparatedTextDecoder::create_csv(
+            csv_params, options,
+        )))
     }
 
     fn try_create_align_state(
         ctx: &Arc<InputContext>,
         split_info: &Arc<SplitInfo>,
     ) -> Result<Self::AligningState> {
-        let csv_params = CsvFileFormatParams::downcast_unchecked(&ctx.file_format_params);
+        let csv_params = CsvFileFormatParams::downcast_unchecked(
+            ctx.file_format_params_of(&split_info.file.path),
+        );
 
         let escape = if csv_params.escape.is_empty() {
             None
@@ -177,6 +189,8 @@ impl InputFormatTextBase for InputFormatCSV {
             .as_any()
             .downcast_ref::<SeparatedTextDecoder>()
             .expect("must success");
+        let set_null = builder.ctx.null_on_error();
+        let mut null_errors = vec![];
         for (i, end) in batch.row_ends.iter().enumerate() {
             let num_fields = batch.num_fields[i];
             let buf = &batch.data[start..*end];
@@ -188,15 +202,41 @@ impl InputFormatTextBase for InputFormatCSV {
                 &batch.field_ends[field_end_idx..field_end_idx + num_fields],
                 &builder.projection,
                 &builder.ctx.default_values,
+                set_null.then_some(&mut null_errors),
             ) {
+                null_errors.clear();
+                // The fields are unescaped by the reader, join them back as the record.
+                let record = builder.ctx.quarantine_on_error().then(|| {
+                    let field_delimiter = CsvFileFormatParams::downcast_unchecked(
+                        builder
+                            .ctx
+                            .file_format_params_of(&batch.split_info.file.path),
+                    )
+                    .field_delimiter
+                    .as_bytes();
+                    let field_ends = &batch.field_ends[field_end_idx..field_end_idx + num_fields];
+                    let mut field_start = 0;
+                    let mut fields = Vec::with_capacity(num_fields);
+                    for field_end in field_ends {
+                        fields.push(&buf[field_start..*field_end]);
+                        field_start = *field_end;
+                    }
+                    fields.join(field_delimiter)
+                });
                 builder.ctx.on_error(
                     e,
                     Some((columns, builder.num_rows)),
                     &mut builder.file_status,
                     &batch.split_info.file.path,
                     i + batch.start_row_in_split,
+                    record.as_deref().map(|record| (record, None)),
                 )?
             } else {
+                for e in null_errors.drain(..) {
+                    builder
+                        .file_status
+                        .add_load_error(e, i + batch.start_row_in_split);
+                }
                 builder.num_rows += 1;
                 builder.file_status.num_rows_loaded += 1;
             }
@@ -296,6 +336,7 @@ impl CsvReaderState {
                                     file_status,
                                     &self.split_info.file.path,
                                     self.common.rows,
+                                    None,
                                 )?;
                                 ReadRecordOutput::RecordSkipped
                             } else {
@@ -175,8 +175,8 @@ impl InputFormatTextBase for InputFormatNDJson {
     fn create_field_decoder(
         _params: &FileFormatParams,
         options: &FileFormatOptionsExt,
-    ) -> Arc<dyn FieldDecoder> {
-        Arc::new(FieldJsonAstDecoder::create(options))
+    ) -> Result<Arc<dyn FieldDecoder>> {
+        Ok(Arc::new(FieldJsonAstDecoder::create(options)))
     }
 
     fn deserialize(builder: &mut BlockBuilder<Self>, batch: RowBatch) -> Result<()> {
@@ -188,8 +188,11 @@ impl InputFormatTextBase for InputFormatNDJson {
 
         let columns = &mut builder.mutable_columns;
         let mut start = 0usize;
-        let format_params = match builder.ctx.file_format_params {
-            FileFormatParams::NdJson(ref p) => p,
+        let format_params = match builder
+            .ctx
+            .file_format_params_of(&batch.split_info.file.path)
+        {
+            FileFormatParams::NdJson(p) => p,
             _ => unreachable!(),
         };
 
@@ -212,6 +215,7 @@ impl InputFormatTextBase for InputFormatNDJson {
                         &mut builder.file_status,
                         &batch.split_info.file.path,
                         batch.start_row_in_split + i,
+                        Some((buf, Some(batch.offset_in_file(start)))),
                     )?
                 } else {
                     builder.num_rows += 1;
@@ -0,0 +1,265 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::mem;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::ColumnBuilder;
+use common_expression::Scalar;
+use common_expression::TableSchemaRef;
+use common_formats::FieldDecoder;
+use common_formats::FileFormatOptionsExt;
+use common_formats::ProtobufDecoder;
+use common_meta_app::principal::FileFormatParams;
+use common_meta_app::principal::ProtobufFileFormatParams;
+use common_meta_app::principal::StageFileFormatType;
+use common_storage::FileParseError;
+use log::debug;
+
+use crate::input_formats::error_utils::truncate_column_data;
+use crate::input_formats::AligningStateCommon;
+use crate::input_formats::AligningStateTextBased;
+use crate::input_formats::BlockBuilder;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormatTextBase;
+use crate::input_formats::RowBatch;
+use crate::input_formats::SplitInfo;
+
+// A varint of u64 takes at most 10 bytes.
+const MAX_VARINT_LEN: usize = 10;
+
+pub struct InputFormatProtobuf {}
+
+impl InputFormatProtobuf {
+    pub fn create() -> Self {
+        Self {}
+    }
+
+    fn read_row(
+        field_decoder: &ProtobufDecoder,
+        buf: &[u8],
+        columns: &mut [ColumnBuilder],
+        schema: &TableSchemaRef,
+        default_values: &Option<Vec<Scalar>>,
+    ) -> std::result::Result<(), FileParseError> {
+        let message =
+            field_decoder
+                .read_message(buf)
+                .map_err(|e| FileParseError::InvalidProtobufRow {
+                    message: e.message(),
+                })?;
+        let serde_json::Value::Object(mut message) = message else {
+            unreachable!("protobuf message must be decoded to object");
+        };
+        if !field_decoder.json.ident_case_sensitive {
+            message = message
+                .into_iter()
+                .map(|(k, v)| (k.to_lowercase(), v))
+                .collect();
+        }
+
+        for ((column_index, field), column) in
+            schema.fields().iter().enumerate().zip(columns.iter_mut())
+        {
+            let field_name = if field_decoder.json.ident_case_sensitive {
+                field.name().to_owned()
+            } else {
+                field.name().to_lowercase()
+            };
+            match message.get(&field_name) {
+                // Absent fields are decoded to the default values of protobuf,
+                // so only the columns not in the message are missing.
+                None => match default_values {
+                    Some(values) => column.push(values[column_index].as_ref()),
+                    None => column.push_default(),
+                },
+                Some(serde_json::Value::Null) if !field.is_nullable_or_null() => {
+                    column.push_default();
+                }
+                Some(value) => {
+                    field_decoder.json.read_field(column, value).map_err(|e| {
+                        FileParseError::ColumnDecodeError {
+                            column_index,
+                            column_name: field.name().to_owned(),
+                            column_type: field.data_type.to_string(),
+                            decode_error: e.message(),
+                            column_data: truncate_column_data(value.to_string()),
+                        }
+                    })?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Aligns the rows of messages, each prefixed by its length encoded as a varint,
+/// as written by `writeDelimitedTo` of the protobuf libraries.
+///
+/// The length prefixes are removed from the data of the batches.
+pub struct AligningStateLengthDelimited {
+    split_info: Arc<SplitInfo>,
+    common: AligningStateCommon,
+    tail_of_last_batch: Vec<u8>,
+}
+
+impl AligningStateLengthDelimited {
+    fn try_create(_ctx: &Arc<InputContext>, split_info: &Arc<SplitInfo>) -> Result<Self> {
+        Ok(Self {
+            split_info: split_info.clone(),
+            common: AligningStateCommon::create(split_info, false, 0),
+            tail_of_last_batch: vec![],
+        })
+    }
+}
+
+impl AligningStateTextBased for AligningStateLengthDelimited {
+    fn align(&mut self, buf: &[u8]) -> Result<Vec<RowBatch>> {
+        self.tail_of_last_batch.extend_from_slice(buf);
+        let tail = mem::take(&mut self.tail_of_last_batch);
+
+        let mut data = Vec::with_capacity(tail.len());
+        let mut row_ends = vec![];
+        let mut pos = 0;
+        while let Some((len, prefix_len)) = decode_length_prefix(&tail[pos..])? {
+            let start = pos + prefix_len;
+            if tail.len() - start < len {
+                break;
+            }
+            data.extend_from_slice(&tail[start..start + len]);
+            row_ends.push(data.len());
+            pos = start + len;
+        }
+        self.tail_of_last_batch = tail[pos..].to_vec();
+
+        if row_ends.is_empty() {
+            return Ok(vec![]);
+        }
+        let num_rows = row_ends.len();
+        let batch = RowBatch {
+            data,
+            row_ends,
+            field_ends: vec![],
+            num_fields: vec![],
+            split_info: self.split_info.clone(),
+            batch_id: self.common.batch_id,
+            start_offset_in_split: self.common.offset,
+            start_row_in_split: self.common.rows,
+            start_row_of_split: Some(0),
+        };
+        self.common.offset += pos;
+        self.common.rows += num_rows;
+        self.common.batch_id += 1;
+        debug!(
+            "align batch {}, {} bytes to {} messages, {} bytes left",
+            batch.batch_id,
+            pos,
+            num_rows,
+            self.tail_of_last_batch.len(),
+        );
+        Ok(vec![batch])
+    }
+
+    fn align_flush(&mut self) -> Result<Vec<RowBatch>> {
+        if self.tail_of_last_batch.is_empty() {
+            Ok(vec![])
+        } else {
+            Err(ErrorCode::BadBytes(format!(
+                "fail to parse protobuf {}: the last message is truncated, {} bytes left after {} messages",
+                self.split_info.file.path,
+                self.tail_of_last_batch.len(),
+                self.common.rows
+            )))
+        }
+    }
+}
+
+/// Returns the length and the size of the prefix, or None if the prefix is not complete yet.
+fn decode_length_prefix(buf: &[u8]) -> Result<Option<(usize, usize)>> {
+    let mut len = 0u64;
+    for (i, b) in buf.iter().take(MAX_VARINT_LEN).enumerate() {
+        len |= ((b & 0x7f) as u64) << (7 * i);
+        if b & 0x80 == 0 {
+            return Ok(Some((len as usize, i + 1)));
+        }
+    }
+    if buf.len() >= MAX_VARINT_LEN {
+        return Err(ErrorCode::BadBytes(
+            "invalid length prefix of protobuf message",
+        ));
+    }
+    Ok(None)
+}
+
+impl InputFormatTextBase for InputFormatProtobuf {
+    type AligningState = AligningStateLengthDelimited;
+
+    fn format_type() -> StageFileFormatType {
+        StageFileFormatType::Protobuf
+    }
+
+    fn create_field_decoder(
+        params: &FileFormatParams,
+        options: &FileFormatOptionsExt,
+    ) -> Result<Arc<dyn FieldDecoder>> {
+        Ok(Arc::new(ProtobufDecoder::try_create(
+            ProtobufFileFormatParams::downcast_unchecked(params),
+            options,
+        )?))
+    }
+
+    fn try_create_align_state(
+        ctx: &Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<Self::AligningState> {
+        AligningStateLengthDelimited::try_create(ctx, split_info)
+    }
+
+    fn deserialize(builder: &mut BlockBuilder<Self>, batch: RowBatch) -> Result<()> {
+        let field_decoder = builder
+            .field_decoder
+            .as_any()
+            .downcast_ref::<ProtobufDecoder>()
+            .expect("must success");
+
+        let columns = &mut builder.mutable_columns;
+        let mut start = 0usize;
+        for (i, end) in batch.row_ends.iter().enumerate() {
+            let buf = &batch.data[start..*end];
+            if let Err(e) = Self::read_row(
+                field_decoder,
+                buf,
+                columns,
+                &builder.ctx.schema,
+                &builder.ctx.default_values,
+            ) {
+                builder.ctx.on_error(
+                    e,
+                    Some((columns, builder.num_rows)),
+                    &mut builder.file_status,
+                    &batch.split_info.file.path,
+                    batch.start_row_in_split + i,
+                    None,
+                )?
+            } else {
+                builder.num_rows += 1;
+                builder.file_status.num_rows_loaded += 1;
+            }
+            start = *end;
+        }
+        Ok(())
+    }
+}
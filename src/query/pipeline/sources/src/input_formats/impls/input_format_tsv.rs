@@ -15,6 +15,7 @@
 use std::io::Cursor;
 use std::sync::Arc;
 
+use bstr::ByteSlice;
 use common_exception::Result;
 use common_expression::ColumnBuilder;
 use common_expression::Scalar;
@@ -30,6 +31,7 @@ use common_storage::FileParseError;
 use log::debug;
 
 use crate::input_formats::error_utils::get_decode_error_by_pos;
+use crate::input_formats::error_utils::null_on_error;
 use crate::input_formats::AligningStateRowDelimiter;
 use crate::input_formats::BlockBuilder;
 use crate::input_formats::InputContext;
@@ -69,7 +71,7 @@ impl InputFormatTSV {
             cursor.read_escaped_string_text(&mut data).map_err(|e| {
                 get_decode_error_by_pos(column_index, schema, &e.to_string(), col_data)
             })?;
-            if let Err(e) = field_decoder.read_field(builder, &data) {
+            if let Err(e) = field_decoder.read_column_field(column_index, builder, &data) {
                 return Err(get_decode_error_by_pos(
                     column_index,
                     schema,
@@ -81,6 +83,7 @@ impl InputFormatTSV {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn read_row(
         field_delimiter: u8,
         field_decoder: &SeparatedTextDecoder,
@@ -89,6 +92,7 @@ impl InputFormatTSV {
         schema: &TableSchemaRef,
         columns_to_read: &Option<Vec<usize>>,
         default_values: &Option<Vec<Scalar>>,
+        mut null_errors: Option<&mut Vec<FileParseError>>,
     ) -> std::result::Result<(), FileParseError> {
         let num_columns = columns.len();
         let mut column_index = 0;
@@ -102,13 +106,20 @@ impl InputFormatTSV {
                 if field_end == buf_len || (buf[field_end] == field_delimiter && !last_is_delimiter)
                 {
                     if columns_to_read.contains(&column_index) {
-                        if let Err(e) = Self::read_column(
+                        let len = columns[column_index].len();
+                        let res = Self::read_column(
                             &mut columns[column_index],
                             field_decoder,
                             &buf[field_start..field_end],
                             column_index,
                             schema,
                             default_values,
+                        );
+                        if let Err(e) = null_on_error(
+                            &mut columns[column_index],
+                            len,
+                            res,
+                            null_errors.as_deref_mut(),
                         ) {
                             error = Some(e);
                             break;
@@ -132,13 +143,20 @@ impl InputFormatTSV {
             while field_end <= buf_len && column_index < num_columns {
                 if field_end == buf_len || (buf[field_end] == field_delimiter && !last_is_delimiter)
                 {
-                    if let Err(err) = Self::read_column(
+                    let len = columns[column_index].len();
+                    let res = Self::read_column(
                         &mut columns[column_index],
                         field_decoder,
                         &buf[field_start..field_end],
                         column_index,
                         schema,
                         default_values,
+                    );
+                    if let Err(err) = null_on_error(
+                        &mut columns[column_index],
+                        len,
+                        res,
+                        null_errors.as_deref_mut(),
                     ) {
                         error = Some(err);
                         break;
@@ -184,16 +202,20 @@ impl InputFormatTextBase for InputFormatTSV {
     fn create_field_decoder(
         params: &FileFormatParams,
         options: &FileFormatOptionsExt,
-    ) -> Arc<dyn FieldDecoder> {
+    ) -> Result<Arc<dyn FieldDecoder>> {
         let tsv_params = TsvFileFormatParams::downcast_unchecked(params);
-        Arc::new(SeparatedTextDecoder::create_tsv(tsv_params, options))
+        Ok(Arc::new(SeparatedTextDecoder::create_tsv(
+            tsv_params, options,
+        )))
     }
 
     fn try_create_align_state(
         ctx: &Arc<InputContext>,
         split_info: &Arc<SplitInfo>,
     ) -> Result<Self::AligningState> {
-        let tsv_params = TsvFileFormatParams::downcast_unchecked(&ctx.file_format_params);
+        let tsv_params = TsvFileFormatParams::downcast_unchecked(
+            ctx.file_format_params_of(&split_info.file.path),
+        );
         AligningStateRowDelimiter::try_create(
             ctx,
             split_info,
@@ -216,12 +238,17 @@ impl InputFormatTextBase for InputFormatTSV {
             .as_any()
             .downcast_ref::<SeparatedTextDecoder>()
             .expect("must success");
-        let field_delimiter =
-            TsvFileFormatParams::downcast_unchecked(&builder.ctx.file_format_params)
-                .field_delimiter
-                .as_bytes()[0];
+        let field_delimiter = TsvFileFormatParams::downcast_unchecked(
+            builder
+                .ctx
+                .file_format_params_of(&batch.split_info.file.path),
+        )
+        .field_delimiter
+        .as_bytes()[0];
         let schema = &builder.ctx.schema;
         let columns = &mut builder.mutable_columns;
+        let set_null = builder.ctx.null_on_error();
+        let mut null_errors = vec![];
         let mut start = 0usize;
         for (i, end) in batch.row_ends.iter().enumerate() {
             let buf = &batch.data[start..*end]; // include \n
@@ -233,15 +260,23 @@ impl InputFormatTextBase for InputFormatTSV {
                 schema,
                 &builder.projection,
                 &builder.ctx.default_values,
+                set_null.then_some(&mut null_errors),
             ) {
+                null_errors.clear();
                 builder.ctx.on_error(
                     e,
                     Some((columns, builder.num_rows)),
                     &mut builder.file_status,
                     &batch.split_info.file.path,
                     i + batch.start_row_in_split,
+                    Some((buf.trim_end(), Some(batch.offset_in_file(start)))),
                 )?
             } else {
+                for e in null_errors.drain(..) {
+                    builder
+                        .file_status
+                        .add_load_error(e, i + batch.start_row_in_split);
+                }
                 builder.num_rows += 1;
                 builder.file_status.num_rows_loaded += 1;
             }
@@ -0,0 +1,338 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Cursor;
+use std::sync::Arc;
+
+use calamine::DataType;
+use calamine::Range;
+use calamine::Reader;
+use calamine::Xlsx;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::NumberDataType;
+use common_expression::ColumnBuilder;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use common_formats::FieldDecoder;
+use common_formats::FileFormatOptionsExt;
+use common_formats::SeparatedTextDecoder;
+use common_meta_app::principal::FileFormatParams;
+use common_meta_app::principal::StageFileFormatType;
+use common_meta_app::principal::XlsxFileFormatParams;
+use common_storage::FileParseError;
+
+use super::input_format_xml::AligningStateWholeFile;
+use crate::input_formats::error_utils::truncate_column_data;
+use crate::input_formats::BlockBuilder;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormatTextBase;
+use crate::input_formats::RowBatch;
+use crate::input_formats::SplitInfo;
+
+pub struct InputFormatXlsx {}
+
+impl InputFormatXlsx {
+    pub fn create() -> Self {
+        Self {}
+    }
+
+    fn read_row(
+        field_decoder: &SeparatedTextDecoder,
+        row: &[DataType],
+        positions: &[Option<usize>],
+        columns: &mut [ColumnBuilder],
+        schema: &TableSchemaRef,
+    ) -> std::result::Result<(), FileParseError> {
+        for ((column_index, field), column) in
+            schema.fields().iter().enumerate().zip(columns.iter_mut())
+        {
+            let cell = positions[column_index].and_then(|i| row.get(i));
+            let value = match cell.map(cell_to_text) {
+                Some(Ok(value)) => value,
+                Some(Err(e)) => {
+                    return Err(FileParseError::ColumnDecodeError {
+                        column_index,
+                        column_name: field.name().to_string(),
+                        column_type: field.data_type.to_string(),
+                        decode_error: e,
+                        column_data: "".to_string(),
+                    });
+                }
+                None => None,
+            };
+            match value {
+                Some(value) => {
+                    if let Err(e) = field_decoder.read_field(column, value.as_bytes()) {
+                        return Err(FileParseError::ColumnDecodeError {
+                            column_index,
+                            column_name: field.name().to_string(),
+                            column_type: field.data_type.to_string(),
+                            decode_error: e.message(),
+                            column_data: truncate_column_data(value),
+                        });
+                    }
+                }
+                None => column.push_default(),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl InputFormatTextBase for InputFormatXlsx {
+    type AligningState = AligningStateWholeFile;
+
+    fn format_type() -> StageFileFormatType {
+        StageFileFormatType::Xlsx
+    }
+
+    fn create_field_decoder(
+        params: &FileFormatParams,
+        options: &FileFormatOptionsExt,
+    ) -> Result<Arc<dyn FieldDecoder>> {
+        Ok(Arc::new(SeparatedTextDecoder::create_xlsx(
+            XlsxFileFormatParams::downcast_unchecked(params),
+            options,
+        )))
+    }
+
+    fn try_create_align_state(
+        ctx: &Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<Self::AligningState> {
+        AligningStateWholeFile::try_create(ctx, split_info)
+    }
+
+    fn deserialize(builder: &mut BlockBuilder<Self>, batch: RowBatch) -> Result<()> {
+        let field_decoder = builder
+            .field_decoder
+            .as_any()
+            .downcast_ref::<SeparatedTextDecoder>()
+            .expect("must success");
+        let columns = &mut builder.mutable_columns;
+
+        let path = &batch.split_info.file.path;
+        let params =
+            XlsxFileFormatParams::downcast_unchecked(builder.ctx.file_format_params_of(path));
+        let sheet = XlsxSheet::read(&batch.data, params)
+            .map_err(|e| e.add_message_back(format!(" (while reading {path})")))?;
+
+        // Map the columns to the cells by the names in the header, or else by position.
+        let schema = &builder.ctx.schema;
+        let mut positions = schema
+            .fields()
+            .iter()
+            .map(|field| {
+                sheet.header.iter().position(|name| {
+                    if builder.ident_case_sensitive {
+                        name == field.name()
+                    } else {
+                        name.eq_ignore_ascii_case(field.name())
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        if positions.iter().all(|p| p.is_none()) {
+            positions = (0..schema.fields().len()).map(Some).collect();
+        }
+
+        for (i, row) in sheet.rows().enumerate() {
+            if let Err(e) = Self::read_row(field_decoder, row, &positions, columns, schema) {
+                builder.ctx.on_error(
+                    e,
+                    Some((columns, builder.num_rows)),
+                    &mut builder.file_status,
+                    path,
+                    i + sheet.headers,
+                    None,
+                )?
+            } else {
+                builder.num_rows += 1;
+                builder.file_status.num_rows_loaded += 1;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The cells of a sheet of a xlsx file.
+struct XlsxSheet {
+    range: Range<DataType>,
+    headers: usize,
+    /// The names in the last header row, empty if there is no header.
+    header: Vec<String>,
+}
+
+impl XlsxSheet {
+    fn read(data: &[u8], params: &XlsxFileFormatParams) -> Result<XlsxSheet> {
+        let mut workbook = Xlsx::new(Cursor::new(data))
+            .map_err(|e| ErrorCode::BadBytes(format!("invalid xlsx file: {e}")))?;
+
+        let sheet_names = workbook.sheet_names().to_vec();
+        let sheet_name = if params.sheet_name.is_empty() {
+            sheet_names
+                .first()
+                .cloned()
+                .ok_or_else(|| ErrorCode::BadBytes("xlsx file has no sheet"))?
+        } else {
+            params.sheet_name.clone()
+        };
+        let range = match workbook.worksheet_range(&sheet_name) {
+            Some(range) => range.map_err(|e| {
+                ErrorCode::BadBytes(format!("fail to read sheet '{sheet_name}' of xlsx: {e}"))
+            })?,
+            None => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "sheet '{}' not found in xlsx file, the sheets are: {}",
+                    sheet_name,
+                    sheet_names.join(", ")
+                )));
+            }
+        };
+
+        let headers = params.headers as usize;
+        let header = match headers {
+            0 => vec![],
+            _ => range
+                .rows()
+                .nth(headers - 1)
+                .map(|row| {
+                    row.iter()
+                        .map(|cell| cell_to_text(cell).ok().flatten().unwrap_or_default())
+                        .collect()
+                })
+                .unwrap_or_default(),
+        };
+        Ok(XlsxSheet {
+            range,
+            headers,
+            header,
+        })
+    }
+
+    fn rows(&self) -> impl Iterator<Item = &[DataType]> {
+        self.range.rows().skip(self.headers)
+    }
+}
+
+/// Formats a cell as text for the field decoder, or None if the cell is empty.
+fn cell_to_text(cell: &DataType) -> std::result::Result<Option<String>, String> {
+    let text = match cell {
+        DataType::Empty => return Ok(None),
+        DataType::String(s) | DataType::DateTimeIso(s) | DataType::DurationIso(s) => s.clone(),
+        DataType::Int(v) => v.to_string(),
+        // Numbers are stored as floats in xlsx, whole numbers are formatted as integers.
+        DataType::Float(v) if is_whole(*v) => (*v as i64).to_string(),
+        DataType::Float(v) | DataType::Duration(v) => v.to_string(),
+        DataType::Bool(v) => v.to_string(),
+        DataType::DateTime(v) => match cell.as_datetime() {
+            Some(datetime) if is_whole(*v) => datetime.date().to_string(),
+            Some(datetime) => datetime.to_string(),
+            None => v.to_string(),
+        },
+        DataType::Error(e) => return Err(format!("error cell {e:?}")),
+    };
+    Ok(Some(text))
+}
+
+fn is_whole(v: f64) -> bool {
+    v.fract() == 0.0 && v.abs() < 1e15
+}
+
+/// The type of the cells of a column, merged over the rows.
+#[derive(Clone, Copy, PartialEq)]
+enum CellType {
+    Unknown,
+    Boolean,
+    Int,
+    Float,
+    Date,
+    Timestamp,
+    String,
+}
+
+impl CellType {
+    fn of(cell: &DataType) -> CellType {
+        match cell {
+            DataType::Empty => CellType::Unknown,
+            DataType::Bool(_) => CellType::Boolean,
+            DataType::Int(_) => CellType::Int,
+            DataType::Float(v) if is_whole(*v) => CellType::Int,
+            DataType::Float(_) => CellType::Float,
+            DataType::DateTime(v) if is_whole(*v) => CellType::Date,
+            DataType::DateTime(_) => CellType::Timestamp,
+            _ => CellType::String,
+        }
+    }
+
+    fn merge(self, other: CellType) -> CellType {
+        match (self, other) {
+            (a, b) if a == b => a,
+            (CellType::Unknown, t) | (t, CellType::Unknown) => t,
+            (CellType::Int, CellType::Float) | (CellType::Float, CellType::Int) => CellType::Float,
+            (CellType::Date, CellType::Timestamp) | (CellType::Timestamp, CellType::Date) => {
+                CellType::Timestamp
+            }
+            _ => CellType::String,
+        }
+    }
+
+    fn data_type(self) -> TableDataType {
+        match self {
+            CellType::Boolean => TableDataType::Boolean,
+            CellType::Int => TableDataType::Number(NumberDataType::Int64),
+            CellType::Float => TableDataType::Number(NumberDataType::Float64),
+            CellType::Date => TableDataType::Date,
+            CellType::Timestamp => TableDataType::Timestamp,
+            CellType::Unknown | CellType::String => TableDataType::String,
+        }
+    }
+}
+
+/// Infers the schema of a sheet of a xlsx file from the types of the cells.
+///
+/// The columns are named by the last header row, or `c1`, `c2`, ... if there is no header,
+/// and are nullable if any of the cells is empty.
+pub fn infer_xlsx_schema(data: &[u8], params: &XlsxFileFormatParams) -> Result<TableSchema> {
+    let sheet = XlsxSheet::read(data, params)?;
+    let width = sheet.range.width();
+
+    let mut types = vec![CellType::Unknown; width];
+    let mut nullable = vec![false; width];
+    for row in sheet.rows() {
+        for (i, cell) in row.iter().enumerate() {
+            types[i] = types[i].merge(CellType::of(cell));
+            nullable[i] |= cell.is_empty();
+        }
+    }
+
+    let fields = (0..width)
+        .map(|i| {
+            let name = match sheet.header.get(i) {
+                Some(name) if !name.is_empty() => name.clone(),
+                _ => format!("c{}", i + 1),
+            };
+            let data_type = types[i].data_type();
+            if nullable[i] {
+                TableField::new(&name, data_type.wrap_nullable())
+            } else {
+                TableField::new(&name, data_type)
+            }
+        })
+        .collect();
+    Ok(TableSchema::new(fields))
+}
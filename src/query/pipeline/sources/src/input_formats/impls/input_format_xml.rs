@@ -44,41 +44,37 @@ impl InputFormatXML {
     pub fn create() -> Self {
         Self {}
     }
+
     fn read_row(
         field_decoder: &SeparatedTextDecoder,
-        row_data: &mut HashMap<String, Vec<u8>>,
+        row: &XmlNode,
+        column_paths: &[Option<Vec<&str>>],
         columns: &mut [ColumnBuilder],
         schema: &TableSchemaRef,
         ident_case_sensitive: bool,
     ) -> std::result::Result<(), FileParseError> {
-        let raw_data = if !ident_case_sensitive {
-            row_data
-                .drain()
-                .map(|(k, v)| (k.to_lowercase(), v))
-                .collect()
-        } else {
-            row_data.clone()
-        };
+        let raw_data = row.fields(ident_case_sensitive)?;
 
         for ((column_index, field), column) in
             schema.fields().iter().enumerate().zip(columns.iter_mut())
         {
-            let value = if ident_case_sensitive {
-                raw_data.get(field.name())
-            } else {
-                raw_data.get(&field.name().to_lowercase())
+            let value = match &column_paths[column_index] {
+                Some(path) => row.select(path),
+                None if ident_case_sensitive => raw_data.get(field.name()).cloned(),
+                None => raw_data.get(&field.name().to_lowercase()).cloned(),
             };
             if let Some(value) = value {
                 if value.is_empty() {
                     column.push_default();
-                } else if let Err(e) = field_decoder.read_field(column, value) {
+                } else if let Err(e) = field_decoder.read_column_field(column_index, column, &value)
+                {
                     return Err(FileParseError::ColumnDecodeError {
                         column_index,
                         column_name: field.name().to_string(),
                         column_type: field.data_type.to_string(),
                         decode_error: e.message(),
                         column_data: truncate_column_data(
-                            String::from_utf8_lossy(value).to_string(),
+                            String::from_utf8_lossy(&value).to_string(),
                         ),
                     });
                 }
@@ -90,6 +86,168 @@ impl InputFormatXML {
     }
 }
 
+/// An element of a row, with its attributes, text and child elements.
+#[derive(Default)]
+struct XmlNode {
+    name: String,
+    attributes: Vec<(String, String)>,
+    text: String,
+    children: Vec<XmlNode>,
+}
+
+impl XmlNode {
+    /// The text of the element, or the element as a json object if it has attributes or children,
+    /// so nested elements can be loaded into Variant columns.
+    fn value(&self) -> Vec<u8> {
+        if self.attributes.is_empty() && self.children.is_empty() {
+            self.text.as_bytes().to_vec()
+        } else {
+            self.to_json().to_string().into_bytes()
+        }
+    }
+
+    /// Attributes are named `@name`, and the text is `#text` if there are attributes or children.
+    /// Children of the same name are collected into an array.
+    fn to_json(&self) -> serde_json::Value {
+        if self.attributes.is_empty() && self.children.is_empty() {
+            return serde_json::Value::String(self.text.clone());
+        }
+
+        let mut object = serde_json::Map::new();
+        for (name, value) in &self.attributes {
+            object.insert(format!("@{name}"), serde_json::Value::String(value.clone()));
+        }
+        for child in &self.children {
+            let value = child.to_json();
+            match object.get_mut(&child.name) {
+                Some(serde_json::Value::Array(values)) => values.push(value),
+                Some(prev) => *prev = serde_json::Value::Array(vec![prev.take(), value]),
+                None => {
+                    object.insert(child.name.clone(), value);
+                }
+            }
+        }
+        if !self.text.is_empty() {
+            object.insert(
+                "#text".to_string(),
+                serde_json::Value::String(self.text.clone()),
+            );
+        }
+        serde_json::Value::Object(object)
+    }
+
+    /// Selects the value at a path relative to this element, made of the steps of
+    /// child element names, `*` for any child, `.` for the element itself,
+    /// and `@name` for an attribute as the last step.
+    fn select(&self, path: &[&str]) -> Option<Vec<u8>> {
+        match path {
+            [] => Some(self.value()),
+            [".", rest @ ..] => self.select(rest),
+            [step, rest @ ..] => match step.strip_prefix('@') {
+                Some(attribute) => self
+                    .attributes
+                    .iter()
+                    .find(|(name, _)| name == attribute)
+                    .map(|(_, value)| value.as_bytes().to_vec()),
+                None => self
+                    .children
+                    .iter()
+                    .filter(|child| *step == "*" || child.name == *step)
+                    .find_map(|child| child.select(rest)),
+            },
+        }
+    }
+
+    /// The values of the columns of a row without column paths: the attributes and the children,
+    /// or the `<field name="column">value</field>` children.
+    fn fields(
+        &self,
+        ident_case_sensitive: bool,
+    ) -> std::result::Result<HashMap<String, Vec<u8>>, FileParseError> {
+        let mut fields = HashMap::with_capacity(self.attributes.len() + self.children.len());
+        for (name, value) in &self.attributes {
+            fields.insert(name.clone(), value.as_bytes().to_vec());
+        }
+        for child in &self.children {
+            if child.name == "field" && !child.attributes.is_empty() {
+                if child.attributes.len() > 1 {
+                    return Err(FileParseError::InvalidXmlRow {
+                        message: format!(
+                            "invalid field tag, expect 1 attr, but got {}",
+                            child.attributes.len()
+                        ),
+                    });
+                }
+                fields.insert(
+                    child.attributes[0].1.clone(),
+                    child.text.as_bytes().to_vec(),
+                );
+            } else {
+                fields.insert(child.name.clone(), child.value());
+            }
+        }
+
+        if !ident_case_sensitive {
+            fields = fields
+                .into_iter()
+                .map(|(k, v)| (k.to_lowercase(), v))
+                .collect();
+        }
+        Ok(fields)
+    }
+}
+
+/// Matches the elements of the rows by `row_path` if set, or else by `row_tag`.
+struct RowMatcher {
+    row_tag: String,
+    // the steps of row_path, or None if row_path is not set.
+    steps: Option<Vec<String>>,
+    any_depth: bool,
+}
+
+impl RowMatcher {
+    fn create(params: &XmlFileFormatParams) -> Self {
+        let (steps, any_depth) = if params.row_path.is_empty() {
+            (None, false)
+        } else if let Some(steps) = params.row_path.strip_prefix("//") {
+            (
+                Some(steps.split('/').map(|s| s.to_string()).collect()),
+                true,
+            )
+        } else {
+            let steps = params.row_path.trim_start_matches('/');
+            (
+                Some(steps.split('/').map(|s| s.to_string()).collect()),
+                false,
+            )
+        };
+        Self {
+            row_tag: params.row_tag.clone(),
+            steps,
+            any_depth,
+        }
+    }
+
+    /// `path` holds the names of the open elements, from the root to the current element.
+    fn is_row(&self, path: &[String]) -> bool {
+        match &self.steps {
+            None => path.last() == Some(&self.row_tag),
+            Some(steps) => {
+                let matches = |path: &[String]| {
+                    path.iter()
+                        .zip(steps.iter())
+                        .all(|(name, step)| step == "*" || name == step)
+                };
+                if self.any_depth {
+                    path.len() >= steps.len() && matches(&path[path.len() - steps.len()..])
+                } else {
+                    path.len() == steps.len() && matches(path)
+                }
+            }
+        }
+    }
+}
+
 pub struct AligningStateWholeFile {
     #[allow(unused)]
     split_info: Arc<SplitInfo>,
@@ -97,7 +255,7 @@ pub struct AligningStateWholeFile {
 }
 
 impl AligningStateWholeFile {
-    fn try_create(_ctx: &Arc<InputContext>, split_info: &Arc<SplitInfo>) -> Result<Self> {
+    pub fn try_create(_ctx: &Arc<InputContext>, split_info: &Arc<SplitInfo>) -> Result<Self> {
         Ok(Self {
             split_info: split_info.clone(),
             bufs: vec![],
@@ -138,11 +296,11 @@ impl InputFormatTextBase for InputFormatXML {
     fn create_field_decoder(
         params: &FileFormatParams,
         options: &FileFormatOptionsExt,
-    ) -> Arc<dyn FieldDecoder> {
-        Arc::new(SeparatedTextDecoder::create_xml(
+    ) -> Result<Arc<dyn FieldDecoder>> {
+        Ok(Arc::new(SeparatedTextDecoder::create_xml(
             XmlFileFormatParams::downcast_unchecked(params),
             options,
-        ))
+        )))
     }
 
     fn try_create_align_state(
@@ -161,115 +319,105 @@ impl InputFormatTextBase for InputFormatXML {
         let columns = &mut builder.mutable_columns;
 
         let path = &batch.split_info.file.path;
-        let xml_params = XmlFileFormatParams::downcast_unchecked(&builder.ctx.file_format_params);
-        let row_tag = xml_params.row_tag.as_bytes().to_vec();
-        let field_tag = vec![b'f', b'i', b'e', b'l', b'd'];
+        let xml_params =
+            XmlFileFormatParams::downcast_unchecked(builder.ctx.file_format_params_of(path));
+        let row_matcher = RowMatcher::create(xml_params);
+        let column_paths = builder
+            .ctx
+            .schema
+            .fields()
+            .iter()
+            .map(|field| {
+                xml_params
+                    .column_paths
+                    .iter()
+                    .find(|(column, _)| {
+                        if builder.ident_case_sensitive {
+                            *column == field.name()
+                        } else {
+                            column.eq_ignore_ascii_case(field.name())
+                        }
+                    })
+                    .map(|(_, path)| path.split('/').collect::<Vec<_>>())
+            })
+            .collect::<Vec<_>>();
 
         let mut buf = Cursor::new(&batch.data);
-        let num_fields = builder.ctx.schema.fields().len();
         let reader = ParserConfig::new().create_reader(&mut buf);
 
-        let mut rows_to_skip = 0;
-
-        let mut cols = HashMap::with_capacity(num_fields);
-
-        let mut key = None;
-        let mut has_start_row = false;
+        // The names of the open elements outside of the rows.
+        let mut element_path: Vec<String> = vec![];
+        // The open elements of the current row, the first one is the row element.
+        let mut row_elements: Vec<XmlNode> = vec![];
         // for deal with on_error mode
         let mut num_rows = 0usize;
 
         for e in reader {
-            if rows_to_skip != 0 {
-                match e {
-                    Ok(XmlEvent::EndElement { name }) => {
-                        // Arrived one row end and skip.
-                        if name.local_name.into_bytes().eq(&row_tag) {
-                            rows_to_skip -= 1;
+            match e {
+                Ok(XmlEvent::StartElement {
+                    name, attributes, ..
+                }) => {
+                    if row_elements.is_empty() {
+                        element_path.push(name.local_name.clone());
+                        if !row_matcher.is_row(&element_path) {
+                            continue;
                         }
                     }
-                    Err(e) => {
-                        return Err(xml_error(e.msg(), path, num_rows));
-                    }
-                    _ => {}
+                    row_elements.push(XmlNode {
+                        name: name.local_name,
+                        attributes: attributes
+                            .into_iter()
+                            .map(|attr| (attr.name.local_name, attr.value))
+                            .collect(),
+                        ..Default::default()
+                    });
                 }
-            } else {
-                match e {
-                    Ok(XmlEvent::StartElement {
-                        name, attributes, ..
-                    }) => {
-                        let name_byte = name.local_name.clone().into_bytes();
-                        match attributes.is_empty() {
-                            true => {
-                                // Column names as tags and column values as the content of these tags.
-                                if !name_byte.eq(&row_tag) && has_start_row {
-                                    key = Some(name.local_name);
-                                } else if name_byte.eq(&row_tag) {
-                                    has_start_row = true;
-                                }
-                            }
-                            false => {
-                                // Column name as attributes and column values as attribute values.
-                                if name_byte.eq(&row_tag) {
-                                    for attr in attributes {
-                                        let key = attr.name.local_name;
-                                        let value = attr.value;
-                                        cols.insert(key, value.into_bytes());
-                                    }
-                                } else if name_byte.eq(&field_tag) {
-                                    if attributes.len() > 1 {
-                                        return Err(xml_error(
-                                            &format!(
-                                                "invalid field tag, expect 1 attr, but got {}",
-                                                attributes.len()
-                                            ),
-                                            path,
-                                            num_rows,
-                                        ));
-                                    }
-                                    let attr = attributes.first().unwrap();
-                                    key = Some(attr.value.clone());
-                                }
-                            }
-                        }
-                    }
-                    Ok(XmlEvent::EndElement { name }) => {
-                        if name.local_name.into_bytes().eq(&row_tag) {
-                            if let Err(e) = Self::read_row(
-                                field_decoder,
-                                &mut cols,
-                                columns,
-                                &builder.ctx.schema,
-                                builder.ident_case_sensitive,
-                            ) {
-                                builder
-                                    .ctx
-                                    .on_error(
-                                        e,
-                                        Some((columns, builder.num_rows)),
-                                        &mut builder.file_status,
-                                        path,
-                                        num_rows + batch.start_row_in_split,
-                                    )
-                                    .map_err(|e| xml_error(&e.message(), path, num_rows))?;
-                            } else {
-                                builder.num_rows += 1;
-                                builder.file_status.num_rows_loaded += 1;
-                            }
-                            num_rows += 1;
-                            cols.clear();
-                            has_start_row = false;
-                        }
+                Ok(XmlEvent::EndElement { .. }) => {
+                    let Some(element) = row_elements.pop() else {
+                        element_path.pop();
+                        continue;
+                    };
+                    if let Some(parent) = row_elements.last_mut() {
+                        parent.children.push(element);
+                        continue;
                     }
-                    Ok(XmlEvent::Characters(v)) => {
-                        if let Some(key) = key.take() {
-                            cols.insert(key, v.into_bytes());
-                        }
+
+                    // Arrived one row end.
+                    element_path.pop();
+                    if let Err(e) = Self::read_row(
+                        field_decoder,
+                        &element,
+                        &column_paths,
+                        columns,
+                        &builder.ctx.schema,
+                        builder.ident_case_sensitive,
+                    ) {
+                        builder
+                            .ctx
+                            .on_error(
+                                e,
+                                Some((columns, builder.num_rows)),
+                                &mut builder.file_status,
+                                path,
+                                num_rows + batch.start_row_in_split,
+                                None,
+                            )
+                            .map_err(|e| xml_error(&e.message(), path, num_rows))?;
+                    } else {
+                        builder.num_rows += 1;
+                        builder.file_status.num_rows_loaded += 1;
                     }
-                    Err(e) => {
-                        return Err(xml_error(e.msg(), path, num_rows));
+                    num_rows += 1;
+                }
+                Ok(XmlEvent::Characters(v)) | Ok(XmlEvent::CData(v)) => {
+                    if let Some(element) = row_elements.last_mut() {
+                        element.text.push_str(&v);
                     }
-                    _ => {}
                 }
+                Err(e) => {
+                    return Err(xml_error(e.msg(), path, num_rows));
+                }
+                _ => {}
             }
         }
         Ok(())
@@ -15,11 +15,16 @@
 mod input_format_csv;
 mod input_format_ndjson;
 mod input_format_parquet;
+mod input_format_protobuf;
 mod input_format_tsv;
+mod input_format_xlsx;
 mod input_format_xml;
 
 pub use input_format_csv::InputFormatCSV;
 pub use input_format_ndjson::InputFormatNDJson;
 pub use input_format_parquet::InputFormatParquet;
+pub use input_format_protobuf::InputFormatProtobuf;
 pub use input_format_tsv::InputFormatTSV;
+pub use input_format_xlsx::infer_xlsx_schema;
+pub use input_format_xlsx::InputFormatXlsx;
 pub use input_format_xml::InputFormatXML;
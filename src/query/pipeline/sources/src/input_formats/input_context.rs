@@ -34,6 +34,7 @@ use common_expression::Scalar;
 use common_expression::TableSchemaRef;
 use common_formats::ClickhouseFormatType;
 use common_formats::FileFormatOptionsExt;
+use common_meta_app::principal::FileFormatOverrides;
 use common_meta_app::principal::FileFormatParams;
 use common_meta_app::principal::OnErrorMode;
 use common_meta_app::principal::StageFileCompression;
@@ -42,14 +43,18 @@ use common_pipeline_core::InputError;
 use common_settings::Settings;
 use common_storage::FileParseError;
 use common_storage::FileStatus;
+use common_storage::StageFileFormats;
+use common_storage::StageFilesInfo;
 use dashmap::DashMap;
 use opendal::Operator;
 
 use crate::input_formats::impls::InputFormatCSV;
 use crate::input_formats::impls::InputFormatNDJson;
 use crate::input_formats::impls::InputFormatParquet;
+use crate::input_formats::impls::InputFormatProtobuf;
 use crate::input_formats::impls::InputFormatTSV;
 use crate::input_formats::impls::InputFormatXML;
+use crate::input_formats::impls::InputFormatXlsx;
 use crate::input_formats::InputFormat;
 use crate::input_formats::SplitInfo;
 use crate::input_formats::StreamingReadBatch;
@@ -122,6 +127,8 @@ pub struct InputContext {
     pub splits: Vec<Arc<SplitInfo>>,
 
     pub file_format_params: FileFormatParams,
+    /// The file format of the files matching the patterns of COPY, if any.
+    pub file_formats: Option<StageFileFormats>,
     pub file_format_options_ext: FileFormatOptionsExt,
     // runtime config
     pub settings: Arc<Settings>,
@@ -134,6 +141,8 @@ pub struct InputContext {
     pub on_error_count: AtomicU64,
     pub on_error_map: Option<Arc<DashMap<String, HashMap<u16, InputError>>>>,
     pub projection: Option<Vec<usize>>,
+    /// Whether to append the internal column `_file_name` to the output blocks.
+    pub output_file_name: bool,
 }
 
 impl InputContext {}
@@ -157,6 +166,8 @@ impl InputContext {
             FileFormatParams::NdJson(_) => Ok(Arc::new(InputFormatNDJson::create())),
             FileFormatParams::Parquet(_) => Ok(Arc::new(InputFormatParquet {})),
             FileFormatParams::Xml(_) => Ok(Arc::new(InputFormatXML::create())),
+            FileFormatParams::Protobuf(_) => Ok(Arc::new(InputFormatProtobuf::create())),
+            FileFormatParams::Xlsx(_) => Ok(Arc::new(InputFormatXlsx::create())),
             format => Err(ErrorCode::Internal(format!(
                 "Unsupported file format: {:?}",
                 format
@@ -178,14 +189,32 @@ impl InputContext {
         is_select: bool,
         projection: Option<Vec<usize>>,
         default_values: Option<Vec<Scalar>>,
+        files_info: &StageFilesInfo,
+        format_overrides: &FileFormatOverrides,
     ) -> Result<Self> {
         let mut file_format_options_ext =
             FileFormatOptionsExt::create_from_settings(&settings, is_select)?;
         file_format_options_ext.disable_variant_check =
             stage_info.copy_options.disable_variant_check;
+        if !format_overrides.columns.is_empty() {
+            file_format_options_ext.column_formats = schema
+                .fields()
+                .iter()
+                .map(|f| format_overrides.columns.get(f.name()).cloned())
+                .collect();
+        }
         let on_error_mode = stage_info.copy_options.on_error.clone();
         let plan = Box::new(CopyIntoPlan { stage_info });
         let file_format_params = plan.stage_info.file_format_params.clone();
+        let file_formats = if format_overrides.files.is_empty() {
+            None
+        } else {
+            Some(StageFileFormats::try_create(
+                files_info,
+                &file_format_params,
+                format_overrides,
+            )?)
+        };
         let read_batch_size = settings.get_input_read_buffer_size()? as usize;
 
         let format = Self::get_input_format(&file_format_params)?;
@@ -202,12 +231,14 @@ impl InputContext {
             plan: InputPlan::CopyInto(plan),
             block_compact_thresholds,
             file_format_params,
+            file_formats,
             file_format_options_ext,
             on_error_mode,
             on_error_count: AtomicU64::new(0),
             on_error_map: Some(on_error_map),
             projection,
             default_values,
+            output_file_name: false,
         })
     }
 
@@ -259,12 +290,14 @@ impl InputContext {
             splits: vec![],
             block_compact_thresholds,
             file_format_params,
+            file_formats: None,
             file_format_options_ext,
             on_error_mode: OnErrorMode::AbortNum(1),
             on_error_count: AtomicU64::new(0),
             on_error_map: None,
             projection: None,
             default_values: None,
+            output_file_name: false,
         })
     }
 
@@ -303,11 +336,13 @@ impl InputContext {
             block_compact_thresholds,
             file_format_options_ext,
             file_format_params,
+            file_formats: None,
             on_error_mode,
             on_error_count: AtomicU64::new(0),
             on_error_map: None,
             projection: None,
             default_values: None,
+            output_file_name: false,
         })
     }
 
@@ -323,9 +358,17 @@ impl InputContext {
         (&self.schema.clone()).into()
     }
 
+    /// The file format params of the file, which may be overridden by its path.
+    pub fn file_format_params_of(&self, path: &str) -> &FileFormatParams {
+        match &self.file_formats {
+            Some(formats) => formats.get(path),
+            None => &self.file_format_params,
+        }
+    }
+
     pub fn get_compression_alg(&self, path: &str) -> Result<Option<CompressAlgorithm>> {
         let opt = match &self.plan {
-            InputPlan::CopyInto(p) => p.stage_info.file_format_params.compression(),
+            InputPlan::CopyInto(_) => self.file_format_params_of(path).compression(),
             InputPlan::StreamingLoad(p) => p.compression,
         };
         Self::get_compression_alg_copy(opt, path)
@@ -403,7 +446,20 @@ impl InputContext {
         None
     }
 
+    /// Returns true if the values that fail to be decoded should be loaded as NULL.
+    pub fn null_on_error(&self) -> bool {
+        matches!(self.on_error_mode, OnErrorMode::SetNull)
+    }
+
+    /// Returns true if the records that fail to be loaded should be quarantined.
+    pub fn quarantine_on_error(&self) -> bool {
+        matches!(self.on_error_mode, OnErrorMode::Quarantine)
+    }
+
     /// the line start from 0, it will be increased by 1 right before output
+    ///
+    /// `record` is the raw bytes of the record and its offset in the file if known,
+    /// kept by the `QUARANTINE` mode.
     pub fn on_error(
         &self,
         e: FileParseError,
@@ -411,6 +467,7 @@ impl InputContext {
         file_status: &mut FileStatus,
         file_path: &str,
         line: usize,
+        record: Option<(&[u8], Option<usize>)>,
     ) -> Result<()> {
         if let Some((columns, num_rows)) = columns {
             columns.iter_mut().for_each(|c| {
@@ -428,6 +485,15 @@ impl InputContext {
                 file_status.add_error(e, line);
                 Ok(())
             }
+            OnErrorMode::SetNull => {
+                // the record can not be split into values, skip it as `CONTINUE` does.
+                file_status.add_load_error(e, line);
+                Ok(())
+            }
+            OnErrorMode::Quarantine => {
+                file_status.add_quarantined(e, line, record);
+                Ok(())
+            }
             OnErrorMode::AbortNum(abort_num) => {
                 if *abort_num <= 1
                     || self.on_error_count.fetch_add(1, Ordering::Relaxed) >= *abort_num - 1
@@ -332,7 +332,7 @@ pub trait InputFormatTextBase: Sized + Send + Sync + 'static {
     ) -> Result<Self::AligningState>;
 
     fn try_create_block_builder(ctx: &Arc<InputContext>) -> Result<BlockBuilder<Self>> {
-        Ok(BlockBuilder::<Self>::create(ctx.clone()))
+        BlockBuilder::<Self>::create(ctx.clone())
     }
 
     fn format_type() -> StageFileFormatType;
@@ -344,7 +344,7 @@ pub trait InputFormatTextBase: Sized + Send + Sync + 'static {
     fn create_field_decoder(
         params: &FileFormatParams,
         options: &FileFormatOptionsExt,
-    ) -> Arc<dyn FieldDecoder>;
+    ) -> Result<Arc<dyn FieldDecoder>>;
 
     fn deserialize(builder: &mut BlockBuilder<Self>, batch: RowBatch) -> Result<()>;
 }
@@ -369,7 +369,7 @@ impl<T: InputFormatTextBase> InputFormatPipe for InputFormatTextPipe<T> {
     }
 
     fn try_create_block_builder(ctx: &Arc<InputContext>) -> Result<Self::BlockBuilder> {
-        Ok(BlockBuilder::<T>::create(ctx.clone()))
+        BlockBuilder::<T>::create(ctx.clone())
     }
 }
 
@@ -468,6 +468,13 @@ pub struct RowBatch {
     pub start_row_of_split: Option<usize>,
 }
 
+impl RowBatch {
+    /// Returns the offset in the file of the byte at `pos` of the batch data.
+    pub fn offset_in_file(&self, pos: usize) -> usize {
+        self.split_info.offset + self.start_offset_in_split + pos
+    }
+}
+
 impl RowBatchTrait for RowBatch {
     fn size(&self) -> usize {
         self.data.len()
@@ -553,17 +560,21 @@ impl<T: InputFormatTextBase> AligningStateTrait for AligningStateMaybeCompressed
 
 pub struct BlockBuilder<T> {
     pub field_decoder: Arc<dyn FieldDecoder>,
+    /// The field decoders of the file formats seen, when they are overridden per file.
+    field_decoders: Vec<(FileFormatParams, Arc<dyn FieldDecoder>)>,
     pub ctx: Arc<InputContext>,
     pub mutable_columns: Vec<ColumnBuilder>,
     pub num_rows: usize,
     pub projection: Option<Vec<usize>>,
     pub file_status: FileStatus,
     pub ident_case_sensitive: bool,
+    /// The paths of the files the rows were read from, if `_file_name` is queried.
+    file_names: Option<StringColumnBuilder>,
     phantom: PhantomData<T>,
 }
 
 impl<T: InputFormatTextBase> BlockBuilder<T> {
-    fn create(ctx: Arc<InputContext>) -> Self {
+    fn create(ctx: Arc<InputContext>) -> Result<Self> {
         let columns = ctx
             .schema
             .fields()
@@ -578,19 +589,24 @@ impl<T: InputFormatTextBase> BlockBuilder<T> {
             })
             .collect();
         let field_decoder =
-            T::create_field_decoder(&ctx.file_format_params, &ctx.file_format_options_ext);
+            T::create_field_decoder(&ctx.file_format_params, &ctx.file_format_options_ext)?;
         let projection = ctx.projection.clone();
+        let file_names = ctx
+            .output_file_name
+            .then(|| StringColumnBuilder::with_capacity(1024, 0));
 
-        BlockBuilder {
+        Ok(BlockBuilder {
             ident_case_sensitive: ctx.file_format_options_ext.ident_case_sensitive,
             mutable_columns: columns,
             num_rows: 0,
+            field_decoders: vec![(ctx.file_format_params.clone(), field_decoder.clone())],
             field_decoder,
             phantom: PhantomData,
             projection,
             file_status: Default::default(),
+            file_names,
             ctx,
-        }
+        })
     }
 
     fn flush(&mut self) -> Result<Vec<DataBlock>> {
@@ -604,7 +620,7 @@ impl<T: InputFormatTextBase> BlockBuilder<T> {
             })
             .collect();
 
-        let columns = if let Some(projection) = &self.projection {
+        let mut columns = if let Some(projection) = &self.projection {
             columns
                 .into_iter()
                 .enumerate()
@@ -626,6 +642,10 @@ impl<T: InputFormatTextBase> BlockBuilder<T> {
         } else {
             columns
         };
+        if let Some(file_names) = &mut self.file_names {
+            let file_names = mem::replace(file_names, StringColumnBuilder::with_capacity(1024, 0));
+            columns.push(Column::String(file_names.build()));
+        }
         self.num_rows = 0;
 
         if columns.is_empty() || columns[0].len() == 0 {
@@ -638,6 +658,21 @@ impl<T: InputFormatTextBase> BlockBuilder<T> {
     fn memory_size(&self) -> usize {
         self.mutable_columns.iter().map(|x| x.memory_size()).sum()
     }
+
+    /// Switch to the field decoder of the file format of the file.
+    fn select_field_decoder(&mut self, path: &str) -> Result<()> {
+        let ctx = self.ctx.clone();
+        let params = ctx.file_format_params_of(path);
+        match self.field_decoders.iter().find(|(p, _)| p == params) {
+            Some((_, decoder)) => self.field_decoder = decoder.clone(),
+            None => {
+                let decoder = T::create_field_decoder(params, &ctx.file_format_options_ext)?;
+                self.field_decoders.push((params.clone(), decoder.clone()));
+                self.field_decoder = decoder;
+            }
+        }
+        Ok(())
+    }
 }
 
 impl<T: InputFormatTextBase> BlockBuilderTrait for BlockBuilder<T> {
@@ -646,7 +681,17 @@ impl<T: InputFormatTextBase> BlockBuilderTrait for BlockBuilder<T> {
     fn deserialize(&mut self, batch: Option<RowBatch>) -> Result<Vec<DataBlock>> {
         if let Some(b) = batch {
             let file_name = b.split_info.file.path.clone();
+            if self.ctx.file_formats.is_some() {
+                self.select_field_decoder(&file_name)?;
+            }
+            let num_rows = self.num_rows;
             T::deserialize(self, b)?;
+            if let Some(file_names) = &mut self.file_names {
+                for _ in num_rows..self.num_rows {
+                    file_names.put_str(&file_name);
+                    file_names.commit_row();
+                }
+            }
             let file_status = mem::take(&mut self.file_status);
             self.ctx
                 .table_context
@@ -19,6 +19,7 @@ mod transform_accumulating_async;
 mod transform_async;
 mod transform_block_compact;
 mod transform_block_compact_for_copy;
+mod transform_block_resize;
 mod transform_blocking;
 mod transform_compact;
 mod transform_dummy;
@@ -34,6 +35,7 @@ pub use transform_accumulating_async::*;
 pub use transform_async::*;
 pub use transform_block_compact::*;
 pub use transform_block_compact_for_copy::*;
+pub use transform_block_resize::*;
 pub use transform_blocking::*;
 pub use transform_compact::*;
 pub use transform_dummy::*;
@@ -0,0 +1,152 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::DataBlock;
+
+use super::AccumulatingTransform;
+
+/// The sizes of the blocks that [`TransformBlockResize`] lets through.
+#[derive(Clone, Copy, Debug)]
+pub struct BlockResizePolicy {
+    pub max_rows: usize,
+    pub max_bytes: usize,
+    pub min_rows: usize,
+    pub min_bytes: usize,
+}
+
+impl BlockResizePolicy {
+    /// Blocks smaller than a quarter of the limits are merged.
+    pub fn new(max_rows: usize, max_bytes: usize) -> Self {
+        let max_rows = max_rows.max(1);
+        let max_bytes = max_bytes.max(1);
+        BlockResizePolicy {
+            max_rows,
+            max_bytes,
+            min_rows: max_rows / 4,
+            min_bytes: max_bytes / 4,
+        }
+    }
+
+    #[inline]
+    fn is_oversized(&self, num_rows: usize, memory_size: usize) -> bool {
+        num_rows > self.max_rows || memory_size > self.max_bytes
+    }
+
+    #[inline]
+    fn is_undersized(&self, num_rows: usize, memory_size: usize) -> bool {
+        num_rows < self.min_rows && memory_size < self.min_bytes
+    }
+}
+
+/// Keeps the blocks flowing between two operators within the sizes of a [`BlockResizePolicy`],
+/// so that the working set of the downstream operator stays cache-friendly: blocks exceeding the
+/// row or byte limits are split, and undersized blocks (e.g. the output of a selective filter)
+/// are buffered and merged. The order of the rows is preserved, and blocks with meta are passed
+/// through as is.
+pub struct TransformBlockResize {
+    policy: BlockResizePolicy,
+    buffer: Vec<DataBlock>,
+    buffered_rows: usize,
+    buffered_bytes: usize,
+}
+
+impl TransformBlockResize {
+    pub fn create(policy: BlockResizePolicy) -> Self {
+        TransformBlockResize {
+            policy,
+            buffer: vec![],
+            buffered_rows: 0,
+            buffered_bytes: 0,
+        }
+    }
+
+    fn flush(&mut self, output: &mut Vec<DataBlock>) -> Result<()> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+
+        let block = match self.buffer.len() {
+            1 => self.buffer.pop().unwrap(),
+            _ => DataBlock::concat(&std::mem::take(&mut self.buffer))?,
+        };
+        self.buffered_rows = 0;
+        self.buffered_bytes = 0;
+        self.split(block, output);
+        Ok(())
+    }
+
+    fn split(&self, block: DataBlock, output: &mut Vec<DataBlock>) {
+        let num_rows = block.num_rows();
+        let memory_size = block.memory_size();
+        if !self.policy.is_oversized(num_rows, memory_size) {
+            output.push(block);
+            return;
+        }
+
+        // Rows per block under both limits, assuming rows of even size.
+        let rows_by_bytes = num_rows * self.policy.max_bytes / memory_size.max(1);
+        let rows_per_block = self.policy.max_rows.min(rows_by_bytes).max(1);
+        let mut start = 0;
+        while start < num_rows {
+            let end = (start + rows_per_block).min(num_rows);
+            output.push(block.slice(start..end));
+            start = end;
+        }
+    }
+}
+
+impl AccumulatingTransform for TransformBlockResize {
+    const NAME: &'static str = "TransformBlockResize";
+
+    fn transform(&mut self, data: DataBlock) -> Result<Vec<DataBlock>> {
+        let mut output = vec![];
+        if data.get_meta().is_some() {
+            self.flush(&mut output)?;
+            output.push(data);
+            return Ok(output);
+        }
+
+        let num_rows = data.num_rows();
+        if num_rows == 0 {
+            return Ok(output);
+        }
+
+        let memory_size = data.memory_size();
+        if !self.policy.is_undersized(num_rows, memory_size) {
+            self.flush(&mut output)?;
+            self.split(data, &mut output);
+            return Ok(output);
+        }
+
+        self.buffered_rows += num_rows;
+        self.buffered_bytes += memory_size;
+        self.buffer.push(data);
+        if !self
+            .policy
+            .is_undersized(self.buffered_rows, self.buffered_bytes)
+        {
+            self.flush(&mut output)?;
+        }
+        Ok(output)
+    }
+
+    fn on_finish(&mut self, output: bool) -> Result<Vec<DataBlock>> {
+        let mut blocks = vec![];
+        if output {
+            self.flush(&mut blocks)?;
+        }
+        Ok(blocks)
+    }
+}
@@ -0,0 +1,171 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::types::UInt64Type;
+use common_expression::BlockMetaInfo;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_pipeline_transforms::processors::AccumulatingTransform;
+use common_pipeline_transforms::processors::BlockResizePolicy;
+use common_pipeline_transforms::processors::TransformBlockResize;
+use serde::Deserializer;
+use serde::Serializer;
+
+#[derive(Clone, Debug)]
+struct TestDataMeta;
+
+impl serde::Serialize for TestDataMeta {
+    fn serialize<S>(&self, _: S) -> Result<S::Ok, S::Error>
+    where S: Serializer {
+        unimplemented!("Serialize is unimplemented for TestDataMeta")
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for TestDataMeta {
+    fn deserialize<D>(_: D) -> Result<Self, D::Error>
+    where D: Deserializer<'de> {
+        unimplemented!("Deserialize is unimplemented for TestDataMeta")
+    }
+}
+
+#[typetag::serde(name = "test_data_meta")]
+impl BlockMetaInfo for TestDataMeta {
+    fn equals(&self, _: &Box<dyn BlockMetaInfo>) -> bool {
+        unimplemented!("equals is unimplemented for TestDataMeta")
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        Box::new(self.clone())
+    }
+}
+
+fn block(values: std::ops::Range<u64>) -> DataBlock {
+    DataBlock::new_from_columns(vec![UInt64Type::from_data(values.collect::<Vec<_>>())])
+}
+
+fn values(blocks: &[DataBlock]) -> Vec<u64> {
+    blocks
+        .iter()
+        .flat_map(|block| {
+            let column = block.get_by_offset(0).value.as_column().unwrap();
+            column.as_number().unwrap().as_u_int64().unwrap().to_vec()
+        })
+        .collect()
+}
+
+fn num_rows(blocks: &[DataBlock]) -> Vec<usize> {
+    blocks.iter().map(|block| block.num_rows()).collect()
+}
+
+#[test]
+fn test_block_resize_split_by_rows() -> Result<()> {
+    let mut transform = TransformBlockResize::create(BlockResizePolicy::new(10, usize::MAX));
+
+    let output = transform.transform(block(0..25))?;
+    assert_eq!(num_rows(&output), vec![10, 10, 5]);
+    assert_eq!(values(&output), (0..25).collect::<Vec<_>>());
+    assert!(transform.on_finish(true)?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_block_resize_split_by_bytes() -> Result<()> {
+    let input = block(0..100);
+    let max_bytes = input.memory_size() / 4;
+    let mut transform = TransformBlockResize::create(BlockResizePolicy::new(1000, max_bytes));
+
+    let output = transform.transform(input)?;
+    assert_eq!(num_rows(&output), vec![25, 25, 25, 25]);
+    assert!(output.iter().all(|block| block.memory_size() <= max_bytes));
+    assert_eq!(values(&output), (0..100).collect::<Vec<_>>());
+    Ok(())
+}
+
+#[test]
+fn test_block_resize_merge() -> Result<()> {
+    // Blocks below 25 rows are merged.
+    let mut transform = TransformBlockResize::create(BlockResizePolicy::new(100, usize::MAX));
+
+    assert!(transform.transform(block(0..10))?.is_empty());
+    assert!(transform.transform(block(10..20))?.is_empty());
+    let output = transform.transform(block(20..30))?;
+    assert_eq!(num_rows(&output), vec![30]);
+    assert_eq!(values(&output), (0..30).collect::<Vec<_>>());
+
+    // Blocks within the limits are not buffered.
+    let output = transform.transform(block(30..60))?;
+    assert_eq!(num_rows(&output), vec![30]);
+
+    // Empty blocks are dropped.
+    assert!(transform.transform(block(60..60))?.is_empty());
+
+    assert!(transform.transform(block(60..65))?.is_empty());
+    assert!(transform.on_finish(false)?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_block_resize_merge_by_bytes() -> Result<()> {
+    // A block of 20 rows is undersized by rows, but not by bytes.
+    let input = block(0..20);
+    let mut transform =
+        TransformBlockResize::create(BlockResizePolicy::new(100, input.memory_size() * 4));
+    let output = transform.transform(input)?;
+    assert_eq!(num_rows(&output), vec![20]);
+
+    // Blocks of 10 rows are below a quarter of both limits.
+    assert!(transform.transform(block(20..30))?.is_empty());
+    let output = transform.on_finish(true)?;
+    assert_eq!(num_rows(&output), vec![10]);
+    assert_eq!(values(&output), (20..30).collect::<Vec<_>>());
+    Ok(())
+}
+
+#[test]
+fn test_block_resize_keep_order() -> Result<()> {
+    let mut transform = TransformBlockResize::create(BlockResizePolicy::new(16, usize::MAX));
+
+    let mut output = vec![];
+    let mut start = 0;
+    for len in [3, 1, 40, 2, 2, 2, 17, 0, 5, 16, 1] {
+        output.extend(transform.transform(block(start..start + len))?);
+        start += len;
+    }
+    output.extend(transform.on_finish(true)?);
+
+    assert!(output.iter().all(|block| block.num_rows() <= 16));
+    assert_eq!(values(&output), (0..start).collect::<Vec<_>>());
+    Ok(())
+}
+
+#[test]
+fn test_block_resize_pass_through_meta() -> Result<()> {
+    let mut transform = TransformBlockResize::create(BlockResizePolicy::new(100, usize::MAX));
+
+    assert!(transform.transform(block(0..10))?.is_empty());
+
+    // The buffered blocks are flushed ahead of the block with meta.
+    let with_meta = block(10..200).add_meta(Some(Box::new(TestDataMeta)))?;
+    let output = transform.transform(with_meta)?;
+    assert_eq!(num_rows(&output), vec![10, 190]);
+    assert!(output[0].get_meta().is_none());
+    assert!(output[1].get_meta().is_some());
+    assert_eq!(values(&output), (0..200).collect::<Vec<_>>());
+
+    let output = transform.transform(DataBlock::empty_with_meta(Box::new(TestDataMeta)))?;
+    assert_eq!(num_rows(&output), vec![0]);
+    assert!(output[0].get_meta().is_some());
+    Ok(())
+}
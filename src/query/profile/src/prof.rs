@@ -63,9 +63,12 @@ pub enum OperatorType {
     ProjectSet,
     EvalScalar,
     Limit,
+    LimitBy,
     TableScan,
     CteScan,
     Sort,
+    GapFill,
+    ConnectBy,
     UnionAll,
     Project,
     Window,
@@ -87,8 +90,11 @@ impl Display for OperatorType {
             OperatorType::ProjectSet => write!(f, "ProjectSet"),
             OperatorType::EvalScalar => write!(f, "EvalScalar"),
             OperatorType::Limit => write!(f, "Limit"),
+            OperatorType::LimitBy => write!(f, "LimitBy"),
             OperatorType::TableScan => write!(f, "TableScan"),
             OperatorType::Sort => write!(f, "Sort"),
+            OperatorType::GapFill => write!(f, "GapFill"),
+            OperatorType::ConnectBy => write!(f, "ConnectBy"),
             OperatorType::UnionAll => write!(f, "UnionAll"),
             OperatorType::Project => write!(f, "Project"),
             OperatorType::Window => write!(f, "Window"),
@@ -139,8 +145,11 @@ pub enum OperatorAttribute {
     EvalScalar(EvalScalarAttribute),
     ProjectSet(ProjectSetAttribute),
     Limit(LimitAttribute),
+    LimitBy(LimitByAttribute),
     TableScan(TableScanAttribute),
     Sort(SortAttribute),
+    GapFill(GapFillAttribute),
+    ConnectBy(ConnectByAttribute),
     Window(WindowAttribute),
     Exchange(ExchangeAttribute),
     CteScan(CteScanAttribute),
@@ -188,11 +197,31 @@ pub struct LimitAttribute {
     pub offset: usize,
 }
 
+#[derive(Debug, Clone)]
+pub struct LimitByAttribute {
+    pub limit: usize,
+    pub by: String,
+}
+
 #[derive(Debug, Clone)]
 pub struct SortAttribute {
     pub sort_keys: String,
 }
 
+#[derive(Debug, Clone)]
+pub struct GapFillAttribute {
+    pub time_column: String,
+    pub partition_by: String,
+    pub method: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ConnectByAttribute {
+    pub id_column: String,
+    pub parent_column: String,
+    pub max_depth: u64,
+}
+
 #[derive(Debug, Clone)]
 pub struct TableScanAttribute {
     pub qualified_name: String,
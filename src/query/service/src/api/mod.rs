@@ -33,6 +33,7 @@ pub use rpc::ExecutePartialQueryPacket;
 pub use rpc::FlightAction;
 pub use rpc::FlightClient;
 pub use rpc::FlightScatter;
+pub use rpc::FlushCachePacket;
 pub use rpc::FragmentData;
 pub use rpc::FragmentPlanPacket;
 pub use rpc::HashFlightScatter;
@@ -0,0 +1,118 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_exception::Result;
+use common_settings::Settings;
+
+use crate::api::rpc::packets::PacketCompression;
+
+// The compression of an auto link is adapted after sending this number of packets.
+const ADAPT_INTERVAL_PACKETS: usize = 16;
+const MAX_ZSTD_LEVEL: i32 = 22;
+
+/// The compression of the data packets sent over an exchange link.
+pub enum ExchangeCompression {
+    None,
+    Fixed(PacketCompression),
+    Auto(AdaptiveCompression),
+}
+
+impl ExchangeCompression {
+    pub fn create(settings: &Settings) -> Result<ExchangeCompression> {
+        let zstd_level = settings
+            .get_exchange_compression_zstd_level()?
+            .clamp(1, MAX_ZSTD_LEVEL);
+
+        Ok(match settings.get_exchange_compression()?.as_str() {
+            "lz4" => ExchangeCompression::Fixed(PacketCompression::Lz4),
+            "zstd" => ExchangeCompression::Fixed(PacketCompression::Zstd(zstd_level)),
+            "auto" => ExchangeCompression::Auto(AdaptiveCompression::create(zstd_level)),
+            _ => ExchangeCompression::None,
+        })
+    }
+
+    pub fn compression(&self) -> Option<PacketCompression> {
+        match self {
+            ExchangeCompression::None => None,
+            ExchangeCompression::Fixed(compression) => Some(*compression),
+            ExchangeCompression::Auto(adaptive) => adaptive.compression(),
+        }
+    }
+
+    pub fn on_compressed(&mut self, elapsed: Duration) {
+        if let ExchangeCompression::Auto(adaptive) = self {
+            adaptive.compress_time += elapsed;
+        }
+    }
+
+    pub fn on_sent(&mut self, elapsed: Duration) {
+        if let ExchangeCompression::Auto(adaptive) = self {
+            adaptive.on_sent(elapsed);
+        }
+    }
+}
+
+/// Moves along the compressions from the cheapest to the densest one, by comparing the time
+/// spent to compress the packets of the link with the time spent to send them.
+///
+/// Sending waits when the network of the link can't keep up, so the compression is raised
+/// while sending takes longer than compressing, and lowered when the CPU is the bottleneck.
+pub struct AdaptiveCompression {
+    compressions: Vec<Option<PacketCompression>>,
+    position: usize,
+    packets: usize,
+    compress_time: Duration,
+    send_time: Duration,
+}
+
+impl AdaptiveCompression {
+    pub fn create(max_zstd_level: i32) -> AdaptiveCompression {
+        let mut compressions = vec![None, Some(PacketCompression::Lz4)];
+        compressions.extend((1..=max_zstd_level).map(|level| Some(PacketCompression::Zstd(level))));
+
+        AdaptiveCompression {
+            compressions,
+            // Starts with lz4, which is cheap enough for most links.
+            position: 1,
+            packets: 0,
+            compress_time: Duration::ZERO,
+            send_time: Duration::ZERO,
+        }
+    }
+
+    pub fn compression(&self) -> Option<PacketCompression> {
+        self.compressions[self.position]
+    }
+
+    fn on_sent(&mut self, elapsed: Duration) {
+        self.send_time += elapsed;
+        self.packets += 1;
+
+        if self.packets < ADAPT_INTERVAL_PACKETS {
+            return;
+        }
+
+        if self.send_time > self.compress_time * 2 {
+            self.position = (self.position + 1).min(self.compressions.len() - 1);
+        } else if self.compress_time > self.send_time {
+            self.position = self.position.saturating_sub(1);
+        }
+
+        self.packets = 0;
+        self.compress_time = Duration::ZERO;
+        self.send_time = Duration::ZERO;
+    }
+}
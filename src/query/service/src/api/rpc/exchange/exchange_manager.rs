@@ -734,7 +734,8 @@ impl QueryCoordinator {
 
         let query_id = info.query_ctx.get_id();
         let executor_settings =
-            ExecutorSettings::try_create(&info.query_ctx.get_settings(), query_id)?;
+            ExecutorSettings::try_create(&info.query_ctx.get_settings(), query_id)?
+                .with_cpu_time(info.query_ctx.get_cpu_time());
 
         let executor = PipelineCompleteExecutor::from_pipelines(pipelines, executor_settings)?;
 
@@ -800,6 +801,12 @@ impl FragmentCoordinator {
         exchange_injector: Arc<dyn ExchangeInjector>,
     ) -> Result<ExchangeParams> {
         if let Some(data_exchange) = &self.data_exchange {
+            let settings = info.query_ctx.get_settings();
+            let dictionary_max_cardinality = match settings.get_enable_exchange_dictionary()? {
+                true => Some(settings.get_exchange_dictionary_max_cardinality()?),
+                false => None,
+            };
+
             return match data_exchange {
                 DataExchange::Merge(exchange) => {
                     Ok(ExchangeParams::MergeExchange(MergeExchangeParams {
@@ -809,6 +816,7 @@ impl FragmentCoordinator {
                         query_id: info.query_id.to_string(),
                         destination_id: exchange.destination_id.clone(),
                         ignore_exchange: exchange.ignore_exchange,
+                        dictionary_max_cardinality,
                     }))
                 }
                 DataExchange::Broadcast(exchange) => {
@@ -821,6 +829,7 @@ impl FragmentCoordinator {
                         destination_ids: exchange.destination_ids.to_owned(),
                         shuffle_scatter: exchange_injector
                             .flight_scatter(&info.query_ctx, data_exchange)?,
+                        dictionary_max_cardinality,
                     }))
                 }
                 DataExchange::ShuffleDataExchange(exchange) => {
@@ -833,6 +842,7 @@ impl FragmentCoordinator {
                         destination_ids: exchange.destination_ids.to_owned(),
                         shuffle_scatter: exchange_injector
                             .flight_scatter(&info.query_ctx, data_exchange)?,
+                        dictionary_max_cardinality,
                     }))
                 }
             };
@@ -40,6 +40,9 @@ pub struct ShuffleExchangeParams {
     pub destination_ids: Vec<String>,
     pub shuffle_scatter: Arc<Box<dyn FlightScatter>>,
     pub exchange_injector: Arc<dyn ExchangeInjector>,
+    /// Dictionary encodes the string columns with at most this number of distinct values in a
+    /// block, `None` if dictionary encoding is disabled.
+    pub dictionary_max_cardinality: Option<usize>,
 }
 
 #[derive(Clone)]
@@ -50,6 +53,9 @@ pub struct MergeExchangeParams {
     pub schema: DataSchemaRef,
     pub ignore_exchange: bool,
     pub exchange_injector: Arc<dyn ExchangeInjector>,
+    /// Dictionary encodes the string columns with at most this number of distinct values in a
+    /// block, `None` if dictionary encoding is disabled.
+    pub dictionary_max_cardinality: Option<usize>,
 }
 
 pub enum ExchangeParams {
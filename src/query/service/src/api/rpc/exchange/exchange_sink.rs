@@ -84,7 +84,7 @@ impl ExchangeSink {
                     &params.destination_id,
                     params.fragment_id,
                     &ctx.get_cluster().local_id(),
-                )]));
+                )?]));
                 Ok(())
             }
             ExchangeParams::ShuffleExchange(params) => {
@@ -102,7 +102,7 @@ impl ExchangeSink {
                         destination_id,
                         params.fragment_id,
                         &ctx.get_cluster().local_id(),
-                    ));
+                    )?);
                 }
 
                 pipeline.add_pipe(Pipe::create(len, 0, items));
@@ -13,7 +13,11 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Instant;
 
+use common_arrow::arrow_format::flight::data::FlightData;
+use common_base::base::Progress;
+use common_base::base::ProgressValues;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -29,12 +33,18 @@ use common_pipeline_sinks::AsyncSinker;
 use common_pipeline_sinks::Sink;
 use common_pipeline_sinks::Sinker;
 
+use crate::api::rpc::exchange::exchange_compression::ExchangeCompression;
 use crate::api::rpc::flight_client::FlightSender;
+use crate::api::rpc::packets::compress_flight_data;
+use crate::api::rpc::packets::PacketCompression;
+use crate::api::DataPacket;
 use crate::api::ExchangeSerializeMeta;
 use crate::sessions::QueryContext;
 
 pub struct ExchangeWriterSink {
     flight_sender: FlightSender,
+    network_progress: Arc<Progress>,
+    compression: ExchangeCompression,
     source: String,
     destination: String,
     fragment: usize,
@@ -45,12 +55,15 @@ impl ExchangeWriterSink {
         ctx: Arc<dyn TableContext>,
         input: Arc<InputPort>,
         flight_sender: FlightSender,
+        compression: ExchangeCompression,
         source_id: &str,
         destination_id: &str,
         fragment_id: usize,
     ) -> Box<dyn Processor> {
-        AsyncSinker::create(input, ctx, ExchangeWriterSink {
+        AsyncSinker::create(input, ctx.clone(), ExchangeWriterSink {
             flight_sender,
+            network_progress: ctx.get_network_progress(),
+            compression,
             source: source_id.to_string(),
             destination: destination_id.to_string(),
             fragment: fragment_id,
@@ -84,13 +97,39 @@ impl AsyncSink for ExchangeWriterSink {
         let count = serialize_meta.packet.len();
         for packet in serialize_meta.packet {
             bytes += packet.bytes_size();
-            if let Err(error) = self.flight_sender.send(packet).await {
+            let compression = match &packet {
+                DataPacket::FragmentData(_) | DataPacket::Dictionary(_) => {
+                    self.compression.compression()
+                }
+                _ => None,
+            };
+
+            let mut flight_data = FlightData::try_from(packet)?;
+            if let Some(compression) = compression {
+                let instant = Instant::now();
+                let uncompressed_size = flight_data.data_body.len();
+                compress_flight_data(&mut flight_data, compression)?;
+
+                let elapsed = instant.elapsed();
+                self.compression.on_compressed(elapsed);
+                metrics_inc_exchange_compress_bytes(uncompressed_size, flight_data.data_body.len());
+                metrics_inc_exchange_compress_milliseconds(elapsed.as_millis() as u64);
+            }
+
+            let sent_bytes = flight_data.data_header.len() + flight_data.data_body.len();
+            let instant = Instant::now();
+            if let Err(error) = self.flight_sender.send_flight_data(flight_data).await {
                 if error.code() == ErrorCode::ABORTED_QUERY {
                     return Ok(true);
                 }
 
                 return Err(error);
             }
+            self.compression.on_sent(instant.elapsed());
+            self.network_progress.incr(&ProgressValues {
+                rows: 0,
+                bytes: sent_bytes,
+            });
         }
 
         {
@@ -108,12 +147,14 @@ impl AsyncSink for ExchangeWriterSink {
             source: String,
             destination: String,
             fragment: usize,
+            compression: Option<PacketCompression>,
         }
 
         Some(format!("{:?}", Display {
             source: self.source.clone(),
             destination: self.destination.clone(),
-            fragment: self.fragment
+            fragment: self.fragment,
+            compression: self.compression.compression(),
         }))
     }
 }
@@ -148,15 +189,18 @@ pub fn create_writer_item(
     destination_id: &str,
     fragment_id: usize,
     source_id: &str,
-) -> PipeItem {
+) -> Result<PipeItem> {
     let input = InputPort::create();
-    PipeItem::create(
+    let checksum = ctx.get_settings().get_enable_block_checksum()?;
+    let compression = ExchangeCompression::create(&ctx.get_settings())?;
+    Ok(PipeItem::create(
         match ignore {
             true => ProcessorPtr::create(IgnoreExchangeSink::create(input.clone(), exchange)),
             false => ProcessorPtr::create(ExchangeWriterSink::create(
                 ctx,
                 input.clone(),
-                exchange,
+                exchange.with_checksum(checksum),
+                compression,
                 source_id,
                 destination_id,
                 fragment_id,
@@ -164,5 +208,5 @@ pub fn create_writer_item(
         },
         vec![input],
         vec![],
-    )
+    ))
 }
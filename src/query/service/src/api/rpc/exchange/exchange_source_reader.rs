@@ -17,6 +17,7 @@ use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataBlock;
 use common_metrics::transform::*;
@@ -37,6 +38,7 @@ pub struct ExchangeSourceReader {
     output: Arc<OutputPort>,
     output_data: Vec<DataPacket>,
     flight_receiver: FlightReceiver,
+    received_packets: usize,
     source: String,
     destination: String,
     fragment: usize,
@@ -53,6 +55,7 @@ impl ExchangeSourceReader {
         ProcessorPtr::create(Box::new(ExchangeSourceReader {
             output,
             flight_receiver,
+            received_packets: 0,
             source: source.to_string(),
             destination: destination.to_string(),
             finished: AtomicBool::new(false),
@@ -60,6 +63,22 @@ impl ExchangeSourceReader {
             fragment,
         }))
     }
+
+    #[async_backtrace::framed]
+    async fn recv_packet(&mut self) -> Result<Option<DataPacket>> {
+        match self.flight_receiver.recv().await {
+            Ok(packet) => {
+                self.received_packets += 1;
+                Ok(packet)
+            }
+            Err(cause) if cause.code() == ErrorCode::CHECKSUM_MISMATCH => Err(cause
+                .add_message_back(format!(
+                    "(while receiving packet {} of fragment {} from {} on {})",
+                    self.received_packets, self.fragment, self.source, self.destination
+                ))),
+            Err(cause) => Err(cause),
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -118,7 +137,7 @@ impl Processor for ExchangeSourceReader {
         if self.output_data.is_empty() {
             let mut bytes = 0;
             let mut dictionaries = Vec::new();
-            while let Some(output_data) = self.flight_receiver.recv().await? {
+            while let Some(output_data) = self.recv_packet().await? {
                 bytes += output_data.bytes_size();
                 if !matches!(&output_data, DataPacket::Dictionary(_)) {
                     {
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod data_exchange;
+mod exchange_compression;
 mod exchange_injector;
 mod exchange_manager;
 mod exchange_params;
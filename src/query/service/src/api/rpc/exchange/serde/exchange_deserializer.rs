@@ -16,6 +16,9 @@ use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::sync::Arc;
 
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::chunk::Chunk;
+use common_arrow::arrow::datatypes::Field as ArrowField;
 use common_arrow::arrow::datatypes::Schema as ArrowSchema;
 use common_arrow::arrow::io::flight::default_ipc_fields;
 use common_arrow::arrow::io::flight::deserialize_batch;
@@ -38,6 +41,9 @@ use common_pipeline_transforms::processors::UnknownMode;
 use serde::Deserializer;
 use serde::Serializer;
 
+use crate::api::rpc::exchange::serde::exchange_dictionary::dictionary_arrow_fields;
+use crate::api::rpc::exchange::serde::exchange_dictionary::dictionary_columns;
+use crate::api::rpc::exchange::serde::exchange_dictionary::dictionary_decode_chunk;
 use crate::api::DataPacket;
 use crate::api::FragmentData;
 
@@ -88,27 +94,27 @@ impl TransformExchangeDeserializer {
             return Ok(DataBlock::new_with_meta(vec![], 0, meta));
         }
 
-        let mut dictionaries = Dictionaries::new();
-
-        for dict_packet in dict {
-            if let DataPacket::Dictionary(ff) = dict_packet {
-                deserialize_dictionary(
-                    &ff,
+        let dictionary_columns = dictionary_columns(&dict)?;
+        let data_block = match dictionary_columns.is_empty() {
+            true => {
+                let batch = deserialize_chunk(
+                    dict,
+                    &fragment_data,
                     &self.arrow_schema.fields,
                     &self.ipc_schema,
-                    &mut dictionaries,
                 )?;
+                DataBlock::from_arrow_chunk(&batch, &self.schema)?
             }
-        }
-
-        let batch = deserialize_batch(
-            &fragment_data.data,
-            &self.arrow_schema.fields,
-            &self.ipc_schema,
-            &dictionaries,
-        )?;
-
-        let data_block = DataBlock::from_arrow_chunk(&batch, &self.schema)?;
+            false => {
+                let (arrow_fields, ipc_schema) = dictionary_arrow_fields(
+                    &self.arrow_schema.fields,
+                    &self.ipc_schema,
+                    &dictionary_columns,
+                );
+                let batch = deserialize_chunk(dict, &fragment_data, &arrow_fields, &ipc_schema)?;
+                dictionary_decode_chunk(&batch, &self.schema)?
+            }
+        };
 
         if data_block.num_columns() == 0 {
             return Ok(DataBlock::new_with_meta(vec![], row_count as usize, meta));
@@ -118,6 +124,28 @@ impl TransformExchangeDeserializer {
     }
 }
 
+fn deserialize_chunk(
+    dict: Vec<DataPacket>,
+    fragment_data: &FragmentData,
+    arrow_fields: &[ArrowField],
+    ipc_schema: &IpcSchema,
+) -> Result<Chunk<Box<dyn Array>>> {
+    let mut dictionaries = Dictionaries::new();
+
+    for dict_packet in dict {
+        if let DataPacket::Dictionary(ff) = dict_packet {
+            deserialize_dictionary(&ff, arrow_fields, ipc_schema, &mut dictionaries)?;
+        }
+    }
+
+    Ok(deserialize_batch(
+        &fragment_data.data,
+        arrow_fields,
+        ipc_schema,
+        &dictionaries,
+    )?)
+}
+
 impl BlockMetaTransform<ExchangeDeserializeMeta> for TransformExchangeDeserializer {
     const UNKNOWN_MODE: UnknownMode = UnknownMode::Pass;
     const NAME: &'static str = "TransformExchangeDeserializer";
@@ -0,0 +1,185 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Dictionary encoding of the low-cardinality string columns sent over an exchange.
+//!
+//! The dictionary id of an encoded column is its index in the block, so the receiver can tell
+//! which columns of a block are encoded from the dictionary packets sent along with it.
+
+use std::collections::HashMap;
+
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::array::DictionaryArray;
+use common_arrow::arrow::array::PrimitiveArray;
+use common_arrow::arrow::chunk::Chunk;
+use common_arrow::arrow::datatypes::DataType as ArrowDataType;
+use common_arrow::arrow::datatypes::Field as ArrowField;
+use common_arrow::arrow::datatypes::IntegerType;
+use common_arrow::arrow::io::ipc::IpcField;
+use common_arrow::arrow::io::ipc::IpcSchema;
+use common_arrow::arrow_format::ipc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::arrow::column_to_arrow_array;
+use common_expression::types::string::StringColumnBuilder;
+use common_expression::types::DataType;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_expression::Value;
+
+use crate::api::DataPacket;
+
+/// Converts the block into an arrow chunk, dictionary encoding the string columns with at most
+/// `max_cardinality` distinct values. Returns the chunk with the ipc fields describing it.
+pub fn dictionary_encode_block(
+    data_block: &DataBlock,
+    ipc_fields: &[IpcField],
+    max_cardinality: usize,
+) -> Result<(Chunk<Box<dyn Array>>, Vec<IpcField>)> {
+    let num_rows = data_block.num_rows();
+    let mut arrays = Vec::with_capacity(data_block.num_columns());
+    let mut ipc_fields = ipc_fields.to_vec();
+
+    for (index, entry) in data_block.columns().iter().enumerate() {
+        if entry.data_type.remove_nullable() == DataType::String {
+            let column = entry
+                .value
+                .convert_to_full_column(&entry.data_type, num_rows);
+            if let Some(array) = dictionary_encode_column(&column, max_cardinality) {
+                arrays.push(array);
+                ipc_fields[index] = IpcField {
+                    fields: vec![],
+                    dictionary_id: Some(index as i64),
+                };
+                continue;
+            }
+        }
+        arrays.push(column_to_arrow_array(entry, num_rows));
+    }
+
+    Ok((Chunk::try_new(arrays)?, ipc_fields))
+}
+
+fn dictionary_encode_column(column: &Column, max_cardinality: usize) -> Option<Box<dyn Array>> {
+    let (strings, validity) = match column {
+        Column::String(column) => (column, None),
+        Column::Nullable(column) => (column.column.as_string()?, Some(&column.validity)),
+        _ => return None,
+    };
+
+    // The dictionary only pays off if values are repeated.
+    let max_cardinality = max_cardinality.min(strings.len() / 2);
+    let mut dictionary = HashMap::new();
+    let mut values = StringColumnBuilder::with_capacity(0, 0);
+    let mut keys = Vec::with_capacity(strings.len());
+    for value in strings.iter() {
+        let next_key = dictionary.len() as u32;
+        let key = *dictionary.entry(value).or_insert_with(|| {
+            values.put_slice(value);
+            values.commit_row();
+            next_key
+        });
+        if dictionary.len() > max_cardinality {
+            return None;
+        }
+        keys.push(key);
+    }
+
+    let values = Column::String(values.build()).as_arrow();
+    let keys = PrimitiveArray::<u32>::new(ArrowDataType::UInt32, keys.into(), validity.cloned());
+    let data_type = ArrowDataType::Dictionary(
+        IntegerType::UInt32,
+        Box::new(values.data_type().clone()),
+        false,
+    );
+    let array = DictionaryArray::try_new(data_type, keys, values).ok()?;
+    Some(Box::new(array))
+}
+
+/// Returns the indices of the columns encoded by the dictionaries of a block.
+pub fn dictionary_columns(dict: &[DataPacket]) -> Result<Vec<usize>> {
+    let mut columns = Vec::new();
+    for packet in dict {
+        if let DataPacket::Dictionary(flight_data) = packet {
+            let message = ipc::MessageRef::read_as_root(&flight_data.data_header)
+                .map_err(|cause| ErrorCode::BadBytes(format!("{:?}", cause)))?;
+            let header = message
+                .header()
+                .map_err(|cause| ErrorCode::BadBytes(format!("{:?}", cause)))?;
+            if let Some(ipc::MessageHeaderRef::DictionaryBatch(batch)) = header {
+                let id = batch
+                    .id()
+                    .map_err(|cause| ErrorCode::BadBytes(format!("{:?}", cause)))?;
+                columns.push(id as usize);
+            }
+        }
+    }
+    Ok(columns)
+}
+
+/// Returns the arrow fields and the ipc schema of a block whose `columns` are dictionary encoded.
+pub fn dictionary_arrow_fields(
+    fields: &[ArrowField],
+    ipc_schema: &IpcSchema,
+    columns: &[usize],
+) -> (Vec<ArrowField>, IpcSchema) {
+    let mut fields = fields.to_vec();
+    let mut ipc_fields = ipc_schema.fields.clone();
+    for index in columns {
+        let field = &mut fields[*index];
+        field.data_type = ArrowDataType::Dictionary(
+            IntegerType::UInt32,
+            Box::new(field.data_type.clone()),
+            false,
+        );
+        ipc_fields[*index] = IpcField {
+            fields: vec![],
+            dictionary_id: Some(*index as i64),
+        };
+    }
+
+    let ipc_schema = IpcSchema {
+        fields: ipc_fields,
+        is_little_endian: ipc_schema.is_little_endian,
+    };
+    (fields, ipc_schema)
+}
+
+/// Converts a chunk with dictionary encoded string columns into a block.
+pub fn dictionary_decode_chunk(
+    chunk: &Chunk<Box<dyn Array>>,
+    schema: &DataSchema,
+) -> Result<DataBlock> {
+    let mut columns = Vec::with_capacity(chunk.arrays().len());
+    for (field, array) in schema.fields().iter().zip(chunk.arrays()) {
+        let data_type = field.data_type();
+        let column = match array.as_any().downcast_ref::<DictionaryArray<u32>>() {
+            Some(array) => {
+                let values = Column::from_arrow(array.values().as_ref(), &DataType::String);
+                let keys = array.keys();
+                let column = values.take(keys.values().as_slice(), &mut None);
+                match data_type.is_nullable() {
+                    true => column.wrap_nullable(keys.validity().cloned()),
+                    false => column,
+                }
+            }
+            None => Column::from_arrow(array.as_ref(), data_type),
+        };
+        columns.push(BlockEntry::new(data_type.clone(), Value::Column(column)));
+    }
+
+    Ok(DataBlock::new(columns, chunk.len()))
+}
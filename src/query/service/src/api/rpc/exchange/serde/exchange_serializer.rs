@@ -41,6 +41,7 @@ use serde::Serializer;
 use crate::api::rpc::exchange::exchange_params::MergeExchangeParams;
 use crate::api::rpc::exchange::exchange_params::ShuffleExchangeParams;
 use crate::api::rpc::exchange::exchange_transform_shuffle::ExchangeShuffleMeta;
+use crate::api::rpc::exchange::serde::exchange_dictionary::dictionary_encode_block;
 use crate::api::DataPacket;
 use crate::api::FragmentData;
 
@@ -92,6 +93,7 @@ impl BlockMetaInfo for ExchangeSerializeMeta {
 pub struct TransformExchangeSerializer {
     options: WriteOptions,
     ipc_fields: Vec<IpcField>,
+    dictionary_max_cardinality: Option<usize>,
 }
 
 impl TransformExchangeSerializer {
@@ -108,6 +110,7 @@ impl TransformExchangeSerializer {
             TransformExchangeSerializer {
                 ipc_fields,
                 options: WriteOptions { compression: None },
+                dictionary_max_cardinality: params.dictionary_max_cardinality,
             },
         )))
     }
@@ -117,7 +120,13 @@ impl Transform for TransformExchangeSerializer {
     const NAME: &'static str = "ExchangeSerializerTransform";
 
     fn transform(&mut self, data_block: DataBlock) -> Result<DataBlock> {
-        serialize_block(0, data_block, &self.ipc_fields, &self.options)
+        serialize_block_with_dictionary(
+            0,
+            data_block,
+            &self.ipc_fields,
+            &self.options,
+            self.dictionary_max_cardinality,
+        )
     }
 }
 
@@ -125,6 +134,7 @@ pub struct TransformScatterExchangeSerializer {
     local_pos: usize,
     options: WriteOptions,
     ipc_fields: Vec<IpcField>,
+    dictionary_max_cardinality: Option<usize>,
 }
 
 impl TransformScatterExchangeSerializer {
@@ -142,6 +152,7 @@ impl TransformScatterExchangeSerializer {
             TransformScatterExchangeSerializer {
                 ipc_fields,
                 options: WriteOptions { compression: None },
+                dictionary_max_cardinality: params.dictionary_max_cardinality,
                 local_pos: params
                     .destination_ids
                     .iter()
@@ -166,7 +177,13 @@ impl BlockMetaTransform<ExchangeShuffleMeta> for TransformScatterExchangeSeriali
 
             new_blocks.push(match self.local_pos == index {
                 true => block,
-                false => serialize_block(0, block, &self.ipc_fields, &self.options)?,
+                false => serialize_block_with_dictionary(
+                    0,
+                    block,
+                    &self.ipc_fields,
+                    &self.options,
+                    self.dictionary_max_cardinality,
+                )?,
             });
         }
 
@@ -181,6 +198,18 @@ pub fn serialize_block(
     data_block: DataBlock,
     ipc_field: &[IpcField],
     options: &WriteOptions,
+) -> Result<DataBlock> {
+    serialize_block_with_dictionary(block_num, data_block, ipc_field, options, None)
+}
+
+/// Serializes the block like [`serialize_block`], dictionary encoding the string columns with at
+/// most `dictionary_max_cardinality` distinct values if it is set.
+pub fn serialize_block_with_dictionary(
+    block_num: isize,
+    data_block: DataBlock,
+    ipc_field: &[IpcField],
+    options: &WriteOptions,
+    dictionary_max_cardinality: Option<usize>,
 ) -> Result<DataBlock> {
     if data_block.is_empty() && data_block.get_meta().is_none() {
         return Ok(DataBlock::empty_with_meta(ExchangeSerializeMeta::create(
@@ -196,10 +225,17 @@ pub fn serialize_block(
 
     let (dict, values) = match data_block.is_empty() {
         true => serialize_batch(&Chunk::new(vec![]), &[], options)?,
-        false => {
-            let chunks = data_block.try_into()?;
-            serialize_batch(&chunks, ipc_field, options)?
-        }
+        false => match dictionary_max_cardinality {
+            Some(max_cardinality) => {
+                let (chunks, ipc_fields) =
+                    dictionary_encode_block(&data_block, ipc_field, max_cardinality)?;
+                serialize_batch(&chunks, &ipc_fields, options)?
+            }
+            None => {
+                let chunks = data_block.try_into()?;
+                serialize_batch(&chunks, ipc_field, options)?
+            }
+        },
     };
 
     let mut packet = Vec::with_capacity(dict.len() + 1);
@@ -13,4 +13,5 @@
 // limitations under the License.
 
 pub mod exchange_deserializer;
+pub mod exchange_dictionary;
 pub mod exchange_serializer;
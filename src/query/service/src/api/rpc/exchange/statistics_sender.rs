@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -161,6 +162,19 @@ impl StatisticsSender {
             progress_info.push(ProgressInfo::ResultProgress(result_progress_values));
         }
 
+        let network_progress = ctx.get_network_progress();
+        let network_progress_values = network_progress.fetch();
+
+        if network_progress_values.rows != 0 || network_progress_values.bytes != 0 {
+            progress_info.push(ProgressInfo::NetworkProgress(network_progress_values));
+        }
+
+        let cpu_time = ctx.get_cpu_time().swap(0, Ordering::SeqCst);
+
+        if cpu_time != 0 {
+            progress_info.push(ProgressInfo::CpuTime(cpu_time));
+        }
+
         Ok(progress_info)
     }
 }
@@ -37,6 +37,9 @@ use tonic::Status;
 use tonic::Streaming;
 
 use crate::api::rpc::flight_actions::FlightAction;
+use crate::api::rpc::packets::attach_checksum;
+use crate::api::rpc::packets::decompress_flight_data;
+use crate::api::rpc::packets::verify_checksum;
 use crate::api::rpc::packets::DataPacket;
 use crate::api::rpc::request_builder::RequestBuilder;
 
@@ -196,7 +199,11 @@ impl FlightReceiver {
         match self.rx.recv().await {
             Err(_) => Ok(None),
             Ok(Err(error)) => Err(error),
-            Ok(Ok(message)) => Ok(Some(DataPacket::try_from(message)?)),
+            Ok(Ok(mut message)) => {
+                verify_checksum(&mut message)?;
+                decompress_flight_data(&mut message)?;
+                Ok(Some(DataPacket::try_from(message)?))
+            }
         }
     }
 
@@ -208,11 +215,21 @@ impl FlightReceiver {
 
 pub struct FlightSender {
     tx: Sender<Result<FlightData, Status>>,
+    checksum: bool,
 }
 
 impl FlightSender {
     pub fn create(tx: Sender<Result<FlightData, Status>>) -> FlightSender {
-        FlightSender { tx }
+        FlightSender {
+            tx,
+            checksum: false,
+        }
+    }
+
+    /// Attaches a checksum to the sent packets, which is verified by the receiver.
+    pub fn with_checksum(mut self, checksum: bool) -> FlightSender {
+        self.checksum = checksum;
+        self
     }
 
     pub fn is_closed(&self) -> bool {
@@ -221,7 +238,16 @@ impl FlightSender {
 
     #[async_backtrace::framed]
     pub async fn send(&self, data: DataPacket) -> Result<()> {
-        if let Err(_cause) = self.tx.send(Ok(FlightData::try_from(data)?)).await {
+        self.send_flight_data(FlightData::try_from(data)?).await
+    }
+
+    #[async_backtrace::framed]
+    pub async fn send_flight_data(&self, mut flight_data: FlightData) -> Result<()> {
+        if self.checksum {
+            attach_checksum(&mut flight_data);
+        }
+
+        if let Err(_cause) = self.tx.send(Ok(flight_data)).await {
             return Err(ErrorCode::AbortedQuery(
                 "Aborted query, because the remote flight channel is closed.",
             ));
@@ -258,7 +284,7 @@ impl FlightExchange {
 
     pub fn convert_to_sender(self) -> FlightSender {
         match self {
-            FlightExchange::Sender(tx) => FlightSender { tx },
+            FlightExchange::Sender(tx) => FlightSender::create(tx),
             _ => unreachable!(),
         }
     }
@@ -45,6 +45,7 @@ use tonic::Streaming;
 use crate::api::rpc::flight_actions::FlightAction;
 use crate::api::rpc::request_builder::RequestGetter;
 use crate::api::DataExchangeManager;
+use crate::interpreters::FlushCacheInterpreter;
 use crate::interpreters::Interpreter;
 use crate::interpreters::KillInterpreter;
 use crate::interpreters::TruncateTableInterpreter;
@@ -250,6 +251,18 @@ impl FlightService for DatabendQueryFlightService {
                     interpreter.execute2().await?;
                     FlightResult { body: vec![] }
                 }
+                FlightAction::FlushCache(flush_cache) => {
+                    let config = GlobalConfig::instance();
+                    let session_manager = SessionManager::instance();
+                    let settings = Settings::create(config.query.tenant_id.clone());
+                    let session =
+                        session_manager.create_with_settings(SessionType::FlightRPC, settings)?;
+                    let ctx = session.create_query_context().await?;
+
+                    let interpreter = FlushCacheInterpreter::from_flight(ctx, flush_cache.packet)?;
+                    interpreter.execute2().await?;
+                    FlightResult { body: vec![] }
+                }
             };
 
             Ok(RawResponse::new(
@@ -47,6 +47,7 @@ pub use flight_scatter_hash::HashFlightScatter;
 pub use packets::ConnectionInfo;
 pub use packets::DataPacket;
 pub use packets::ExecutePartialQueryPacket;
+pub use packets::FlushCachePacket;
 pub use packets::FragmentData;
 pub use packets::FragmentPlanPacket;
 pub use packets::InitNodesChannelPacket;
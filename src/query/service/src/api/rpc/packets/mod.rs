@@ -17,17 +17,24 @@ mod packet_data;
 mod packet_data_progressinfo;
 mod packet_execute;
 mod packet_executor;
+mod packet_flush_cache;
 mod packet_fragment;
 mod packet_kill_query;
 mod packet_publisher;
 mod packet_truncate_table;
 
 pub use packet::Packet;
+pub use packet_data::attach_checksum;
+pub use packet_data::compress_flight_data;
+pub use packet_data::decompress_flight_data;
+pub use packet_data::verify_checksum;
 pub use packet_data::DataPacket;
 pub use packet_data::FragmentData;
+pub use packet_data::PacketCompression;
 pub use packet_data_progressinfo::ProgressInfo;
 pub use packet_execute::ExecutePartialQueryPacket;
 pub use packet_executor::QueryFragmentsPlanPacket;
+pub use packet_flush_cache::FlushCachePacket;
 pub use packet_fragment::FragmentPlanPacket;
 pub use packet_kill_query::KillQueryPacket;
 pub use packet_publisher::ConnectionInfo;
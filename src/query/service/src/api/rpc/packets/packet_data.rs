@@ -14,6 +14,7 @@
 
 use std::fmt::Debug;
 use std::fmt::Formatter;
+use std::hash::Hasher;
 use std::vec;
 
 use byteorder::BigEndian;
@@ -24,6 +25,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_storage::CopyStatus;
 use log::error;
+use twox_hash::XxHash64;
 
 use crate::api::rpc::packets::ProgressInfo;
 
@@ -61,6 +63,145 @@ fn calc_size(flight_data: &FlightData) -> usize {
     flight_data.data_body.len() + flight_data.data_header.len() + flight_data.app_metadata.len()
 }
 
+// The packet type of the flight data is flagged if the flight data carries a checksum.
+const CHECKSUM_FLAG: u8 = 0x80;
+const CHECKSUM_SIZE: usize = std::mem::size_of::<u64>();
+// The packet type of the flight data is flagged if the data body is compressed.
+const COMPRESSION_FLAG: u8 = 0x40;
+// The codec and the uncompressed size of the data body.
+const COMPRESSION_HEADER_SIZE: usize = 1 + std::mem::size_of::<u64>();
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PacketCompression {
+    Lz4,
+    Zstd(i32),
+}
+
+impl PacketCompression {
+    fn codec(&self) -> u8 {
+        match self {
+            PacketCompression::Lz4 => 0x01,
+            PacketCompression::Zstd(_) => 0x02,
+        }
+    }
+}
+
+/// Compresses the data body of the flight data, the codec and the uncompressed size are
+/// appended to its app metadata, before the packet type.
+pub fn compress_flight_data(
+    flight_data: &mut FlightData,
+    compression: PacketCompression,
+) -> Result<()> {
+    let packet_type = match flight_data.app_metadata.pop() {
+        None => return Ok(()),
+        Some(packet_type) => packet_type,
+    };
+
+    let uncompressed_size = flight_data.data_body.len();
+    flight_data.data_body = match compression {
+        PacketCompression::Lz4 => lz4::block::compress(&flight_data.data_body, None, false)?,
+        PacketCompression::Zstd(level) => zstd::bulk::compress(&flight_data.data_body, level)?,
+    };
+
+    flight_data.app_metadata.push(compression.codec());
+    flight_data
+        .app_metadata
+        .write_u64::<BigEndian>(uncompressed_size as u64)?;
+    flight_data
+        .app_metadata
+        .push(packet_type | COMPRESSION_FLAG);
+    Ok(())
+}
+
+/// Decompresses the data body of the flight data, if it's compressed.
+pub fn decompress_flight_data(flight_data: &mut FlightData) -> Result<()> {
+    let packet_type = match flight_data.app_metadata.last() {
+        Some(packet_type) if packet_type & COMPRESSION_FLAG != 0 => packet_type & !COMPRESSION_FLAG,
+        _ => return Ok(()),
+    };
+
+    let metadata_len = flight_data.app_metadata.len() - 1;
+    if metadata_len < COMPRESSION_HEADER_SIZE {
+        return Err(ErrorCode::BadBytes(
+            "Flight data compression header is missing.",
+        ));
+    }
+
+    let mut header = &flight_data.app_metadata[metadata_len - COMPRESSION_HEADER_SIZE..];
+    let codec = header.read_u8()?;
+    let uncompressed_size = header.read_u64::<BigEndian>()? as usize;
+    flight_data
+        .app_metadata
+        .truncate(metadata_len - COMPRESSION_HEADER_SIZE);
+
+    flight_data.data_body = match codec {
+        0x01 => lz4::block::decompress(&flight_data.data_body, Some(uncompressed_size as i32))?,
+        0x02 => zstd::bulk::decompress(&flight_data.data_body, uncompressed_size)?,
+        _ => {
+            return Err(ErrorCode::BadBytes(format!(
+                "Unknown flight data compression codec {:#04x}.",
+                codec
+            )));
+        }
+    };
+
+    flight_data.app_metadata.push(packet_type);
+    Ok(())
+}
+
+fn calc_checksum(flight_data: &FlightData) -> u64 {
+    let mut hasher = XxHash64::with_seed(0);
+    hasher.write(&flight_data.data_header);
+    hasher.write(&flight_data.data_body);
+    hasher.write(&flight_data.app_metadata);
+    hasher.finish()
+}
+
+/// Appends the xxhash64 checksum of the flight data to its app metadata, before the packet type.
+pub fn attach_checksum(flight_data: &mut FlightData) {
+    if let Some(packet_type) = flight_data.app_metadata.pop() {
+        let checksum = calc_checksum(flight_data);
+        flight_data
+            .app_metadata
+            .extend_from_slice(&checksum.to_be_bytes());
+        flight_data.app_metadata.push(packet_type | CHECKSUM_FLAG);
+    }
+}
+
+/// Verifies and strips the checksum of the flight data, if it carries one.
+pub fn verify_checksum(flight_data: &mut FlightData) -> Result<()> {
+    let packet_type = match flight_data.app_metadata.last() {
+        Some(packet_type) if packet_type & CHECKSUM_FLAG != 0 => packet_type & !CHECKSUM_FLAG,
+        _ => return Ok(()),
+    };
+
+    let metadata_len = flight_data.app_metadata.len() - 1;
+    if metadata_len < CHECKSUM_SIZE {
+        return Err(ErrorCode::BadBytes("Flight data checksum is missing."));
+    }
+
+    let mut checksum_bytes = &flight_data.app_metadata[metadata_len - CHECKSUM_SIZE..];
+    let expected = checksum_bytes.read_u64::<BigEndian>()?;
+    flight_data
+        .app_metadata
+        .truncate(metadata_len - CHECKSUM_SIZE);
+
+    let actual = calc_checksum(flight_data);
+    if actual != expected {
+        return Err(ErrorCode::ChecksumMismatch(format!(
+            "Checksum mismatch of flight data packet(type: {:#04x}, header: {} bytes, body: {} bytes), expected {:#018x}, actual {:#018x}",
+            packet_type,
+            flight_data.data_header.len(),
+            flight_data.data_body.len(),
+            expected,
+            actual
+        )));
+    }
+
+    flight_data.app_metadata.push(packet_type);
+    Ok(())
+}
+
 impl DataPacket {
     pub fn bytes_size(&self) -> usize {
         match self {
@@ -15,6 +15,7 @@
 use std::fmt::Debug;
 use std::io::Read;
 use std::io::Write;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 use byteorder::BigEndian;
@@ -33,6 +34,9 @@ pub enum ProgressInfo {
     ScanProgress(ProgressValues),
     WriteProgress(ProgressValues),
     ResultProgress(ProgressValues),
+    NetworkProgress(ProgressValues),
+    /// The CPU time in nanoseconds.
+    CpuTime(u64),
 }
 
 impl ProgressInfo {
@@ -41,6 +45,10 @@ impl ProgressInfo {
             ProgressInfo::ScanProgress(values) => ctx.get_scan_progress().incr(values),
             ProgressInfo::WriteProgress(values) => ctx.get_write_progress().incr(values),
             ProgressInfo::ResultProgress(values) => ctx.get_result_progress().incr(values),
+            ProgressInfo::NetworkProgress(values) => ctx.get_network_progress().incr(values),
+            ProgressInfo::CpuTime(nanos) => {
+                ctx.get_cpu_time().fetch_add(*nanos, Ordering::Relaxed);
+            }
         };
     }
 
@@ -49,6 +57,12 @@ impl ProgressInfo {
             ProgressInfo::ScanProgress(values) => (1_u8, values),
             ProgressInfo::WriteProgress(values) => (2_u8, values),
             ProgressInfo::ResultProgress(values) => (3_u8, values),
+            ProgressInfo::NetworkProgress(values) => (4_u8, values),
+            // The CPU time is carried in the bytes of the progress values.
+            ProgressInfo::CpuTime(nanos) => (5_u8, ProgressValues {
+                rows: 0,
+                bytes: nanos as usize,
+            }),
         };
 
         bytes.write_u8(info_type)?;
@@ -66,6 +80,11 @@ impl ProgressInfo {
             1 => Ok(ProgressInfo::ScanProgress(ProgressValues { rows, bytes })),
             2 => Ok(ProgressInfo::WriteProgress(ProgressValues { rows, bytes })),
             3 => Ok(ProgressInfo::ResultProgress(ProgressValues { rows, bytes })),
+            4 => Ok(ProgressInfo::NetworkProgress(ProgressValues {
+                rows,
+                bytes,
+            })),
+            5 => Ok(ProgressInfo::CpuTime(bytes as u64)),
             _ => Err(ErrorCode::Unimplemented(format!(
                 "Unimplemented progress info type, {}",
                 info_type
@@ -0,0 +1,50 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_config::InnerConfig;
+use common_exception::Result;
+use common_meta_types::NodeInfo;
+use common_sql::plans::FlushCacheKind;
+
+use crate::api::rpc::flight_actions::FlushCache;
+use crate::api::rpc::packets::packet::create_client;
+use crate::api::rpc::Packet;
+use crate::api::FlightAction;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct FlushCachePacket {
+    pub kind: FlushCacheKind,
+    pub executor: Arc<NodeInfo>,
+}
+
+impl FlushCachePacket {
+    pub fn create(kind: FlushCacheKind, executor: Arc<NodeInfo>) -> FlushCachePacket {
+        FlushCachePacket { kind, executor }
+    }
+}
+
+#[async_trait::async_trait]
+impl Packet for FlushCachePacket {
+    #[async_backtrace::framed]
+    async fn commit(&self, config: &InnerConfig, timeout: u64) -> Result<()> {
+        let executor_info = &self.executor;
+        let mut conn = create_client(config, &executor_info.flight_address).await?;
+        let action = FlightAction::FlushCache(FlushCache {
+            packet: self.clone(),
+        });
+        conn.execute_action(action, timeout).await
+    }
+}
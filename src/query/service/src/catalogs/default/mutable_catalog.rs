@@ -452,7 +452,7 @@ impl Catalog for MutableCatalog {
     async fn update_table_meta(
         &self,
         table_info: &TableInfo,
-        req: UpdateTableMetaReq,
+        mut req: UpdateTableMetaReq,
     ) -> Result<UpdateTableMetaReply> {
         match table_info.db_type.clone() {
             DatabaseType::NormalDB => {
@@ -461,6 +461,12 @@ impl Catalog for MutableCatalog {
                     table_info.desc,
                     req.copied_files.is_some()
                 );
+                // External and attached tables are loaded with the values of the secrets
+                // their credentials refer to, keep persisting only the references.
+                if req.new_table_meta.storage_params.is_some() {
+                    let (_, persisted) = self.ctx.meta.get_table_by_id(req.table_id).await?;
+                    req.new_table_meta.storage_params = persisted.storage_params.clone();
+                }
                 Ok(self.ctx.meta.update_table_meta(req).await?)
             }
             DatabaseType::ShareDB(share_ident) => {
@@ -39,6 +39,7 @@ use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpsertTableOptionReply;
 use common_meta_app::schema::UpsertTableOptionReq;
+use common_users::UserApiProvider;
 
 use crate::databases::Database;
 use crate::databases::DatabaseContext;
@@ -57,12 +58,30 @@ impl DefaultDatabase {
         Ok(Box::new(Self { ctx, db_info }))
     }
 
-    fn load_tables(&self, table_infos: Vec<Arc<TableInfo>>) -> Result<Vec<Arc<dyn Table>>> {
-        table_infos.iter().try_fold(vec![], |mut acc, item| {
-            let tbl = self.get_table_by_info(item.as_ref())?;
-            acc.push(tbl);
-            Ok(acc)
-        })
+    #[async_backtrace::framed]
+    async fn load_tables(&self, table_infos: Vec<Arc<TableInfo>>) -> Result<Vec<Arc<dyn Table>>> {
+        let mut tables = Vec::with_capacity(table_infos.len());
+        for table_info in table_infos {
+            tables.push(self.load_table(table_info.as_ref()).await?);
+        }
+        Ok(tables)
+    }
+
+    /// External and attached tables keep only the references to the secrets of
+    /// their credentials, the table is built with the values of the secrets.
+    #[async_backtrace::framed]
+    async fn load_table(&self, table_info: &TableInfo) -> Result<Arc<dyn Table>> {
+        if table_info.meta.storage_params.is_none() {
+            return self.get_table_by_info(table_info);
+        }
+
+        let mut table_info = table_info.clone();
+        if let Some(storage_params) = table_info.meta.storage_params.as_mut() {
+            UserApiProvider::instance()
+                .resolve_storage_secrets(self.get_tenant(), storage_params)
+                .await?;
+        }
+        self.get_table_by_info(&table_info)
     }
 }
 
@@ -93,7 +112,7 @@ impl Database for DefaultDatabase {
                 table_name,
             ))
             .await?;
-        self.get_table_by_info(table_info.as_ref())
+        self.load_table(table_info.as_ref()).await
     }
 
     #[async_backtrace::framed]
@@ -104,7 +123,7 @@ impl Database for DefaultDatabase {
             .list_tables(ListTableReq::new(self.get_tenant(), self.get_db_name()))
             .await?;
 
-        self.load_tables(table_infos)
+        self.load_tables(table_infos).await
     }
 
     #[async_backtrace::framed]
@@ -129,7 +148,7 @@ impl Database for DefaultDatabase {
 
         table_infos.append(&mut dropped);
 
-        self.load_tables(table_infos)
+        self.load_tables(table_infos).await
     }
 
     #[async_backtrace::framed]
@@ -28,6 +28,7 @@ use common_storages_system::CachesTable;
 use common_storages_system::CatalogsTable;
 use common_storages_system::ClusteringHistoryTable;
 use common_storages_system::ClustersTable;
+use common_storages_system::ColumnLineageTable;
 use common_storages_system::ColumnsTable;
 use common_storages_system::ConfigsTable;
 use common_storages_system::ContributorsTable;
@@ -36,9 +37,11 @@ use common_storages_system::DatabasesTable;
 use common_storages_system::EnginesTable;
 use common_storages_system::FunctionsTable;
 use common_storages_system::IndexesTable;
+use common_storages_system::LoadErrorsTable;
 use common_storages_system::MallocStatsTable;
 use common_storages_system::MallocStatsTotalsTable;
 use common_storages_system::MetricsTable;
+use common_storages_system::NotificationsTable;
 use common_storages_system::OneTable;
 use common_storages_system::ProcessesTable;
 use common_storages_system::ProcessorProfileTable;
@@ -46,16 +49,20 @@ use common_storages_system::QueryCacheTable;
 use common_storages_system::QueryLogTable;
 use common_storages_system::QueryProfileTable;
 use common_storages_system::QuerySummaryTable;
+use common_storages_system::ReplicationLagTable;
+use common_storages_system::ResourceUsageTable;
 use common_storages_system::RolesTable;
 use common_storages_system::SettingsTable;
 use common_storages_system::StagesTable;
 use common_storages_system::StreamsTable;
 use common_storages_system::TableFunctionsTable;
+use common_storages_system::TableStatisticsTable;
 use common_storages_system::TablesTableWithHistory;
 use common_storages_system::TablesTableWithoutHistory;
 use common_storages_system::TaskHistoryTable;
 use common_storages_system::TasksTable;
 use common_storages_system::TempFilesTable;
+use common_storages_system::TenantsTable;
 use common_storages_system::TracingTable;
 use common_storages_system::UsersTable;
 
@@ -122,7 +129,20 @@ impl SystemDatabase {
             QuerySummaryTable::create(sys_db_meta.next_table_id()),
             TasksTable::create(sys_db_meta.next_table_id()),
             TaskHistoryTable::create(sys_db_meta.next_table_id()),
+            NotificationsTable::create(sys_db_meta.next_table_id()),
             ProcessorProfileTable::create(sys_db_meta.next_table_id()),
+            ReplicationLagTable::create(sys_db_meta.next_table_id()),
+            Arc::new(LoadErrorsTable::create(
+                sys_db_meta.next_table_id(),
+                config.query.max_query_log_size,
+            )),
+            Arc::new(ColumnLineageTable::create(
+                sys_db_meta.next_table_id(),
+                config.query.max_query_log_size,
+            )),
+            TableStatisticsTable::create(sys_db_meta.next_table_id()),
+            TenantsTable::create(sys_db_meta.next_table_id()),
+            ResourceUsageTable::create(sys_db_meta.next_table_id()),
         ];
 
         let disable_tables = Self::disable_system_tables();
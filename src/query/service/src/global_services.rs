@@ -27,10 +27,12 @@ use common_exception::Result;
 use common_meta_app::schema::CatalogType;
 use common_profile::QueryProfileManager;
 use common_sharing::ShareEndpointManager;
+use common_sql::PlanCache;
 use common_storage::DataOperator;
 use common_storage::ShareTableConfig;
 use common_storages_hive::HiveCreator;
 use common_storages_iceberg::IcebergCreator;
+use common_storages_system::ResourceUsageRecorder;
 use common_tracing::GlobalLogger;
 use common_users::RoleCacheManager;
 use common_users::UserApiProvider;
@@ -42,6 +44,7 @@ use crate::auth::AuthMgr;
 use crate::catalogs::DatabaseCatalog;
 use crate::clusters::ClusterDiscovery;
 use crate::servers::http::v1::HttpQueryManager;
+use crate::sessions::ReadOnlyTableCache;
 use crate::sessions::SessionManager;
 
 pub struct GlobalServices;
@@ -107,6 +110,7 @@ impl GlobalServices {
         HttpQueryManager::init(&config).await?;
         DataExchangeManager::init()?;
         SessionManager::init(&config)?;
+        ReadOnlyTableCache::init(&config)?;
         LockManager::init()?;
         AuthMgr::init(&config)?;
         UserApiProvider::init(
@@ -119,6 +123,7 @@ impl GlobalServices {
         RoleCacheManager::init()?;
         ShareEndpointManager::init()?;
         QueryProfileManager::init();
+        ResourceUsageRecorder::init(config.query.resource_usage_retention_hours);
 
         DataOperator::init(&config.storage).await?;
         ShareTableConfig::init(
@@ -127,6 +132,7 @@ impl GlobalServices {
             config.query.tenant_id.clone(),
         )?;
         CacheManager::init(&config.cache, &config.query.tenant_id)?;
+        PlanCache::init(&config.cache)?;
 
         if let Some(addr) = config.query.cloud_control_grpc_server_address.clone() {
             CloudControlApiProvider::init(addr).await?;
@@ -27,9 +27,11 @@ use common_sql::optimizer::get_udf_names;
 use common_sql::plans::PresignAction;
 use common_sql::plans::RewriteKind;
 use common_users::RoleCacheManager;
+use common_users::UserApiProvider;
 
 use crate::interpreters::access::AccessChecker;
 use crate::sessions::QueryContext;
+use crate::sessions::SessionType;
 use crate::sql::plans::Plan;
 
 pub struct PrivilegeAccess {
@@ -101,7 +103,15 @@ impl PrivilegeAccess {
         privileges: Vec<UserPrivilegeType>,
         verify_ownership: bool,
     ) -> Result<()> {
-        let session = self.ctx.get_current_session();
+        if matches!(
+            self.ctx.get_current_session().get_type(),
+            SessionType::Local
+        ) {
+            return Ok(());
+        }
+
+        // the roles are resolved once per query, only the owner is looked up per object.
+        let effective_privileges = self.ctx.get_effective_privileges().await?;
         if verify_ownership {
             let object_by_id =
                 self.convert_grant_object_by_id(object)
@@ -113,14 +123,23 @@ impl PrivilegeAccess {
                         _ => Err(e.add_message("error on validating access")),
                     })?;
             if let Some(object_by_id) = &object_by_id {
-                let result = session.validate_ownership(object_by_id).await;
+                let tenant = self.ctx.get_tenant();
+                // if the object is not owned by any role, then considered as PUBLIC, which is always true
+                let owner = UserApiProvider::instance()
+                    .get_ownership(&tenant, object_by_id)
+                    .await;
+                let result = match owner {
+                    Ok(Some(owner)) => effective_privileges.validate_ownership(&owner.role),
+                    Ok(None) => Ok(()),
+                    Err(e) => Err(e),
+                };
                 if result.is_ok() {
                     return Ok(());
                 }
             }
         }
 
-        session.validate_privilege(object, privileges).await
+        effective_privileges.validate_privilege(object, privileges)
     }
 
     async fn check_udf_priv(&self, udf_names: HashSet<&String>) -> Result<()> {
@@ -274,7 +293,9 @@ impl AccessChecker for PrivilegeAccess {
                         .await?
                 }
             }
-            Plan::ExplainAnalyze { plan } | Plan::Explain { plan, .. } => {
+            Plan::ExplainAnalyze { plan }
+            | Plan::Explain { plan, .. }
+            | Plan::DescribeQuery { plan } => {
                 self.check(ctx, plan).await?
             }
 
@@ -398,6 +419,24 @@ impl AccessChecker for PrivilegeAccess {
                 )
                     .await?;
             }
+            Plan::CloneTable(plan) => {
+                self.validate_access(
+                    &GrantObject::Table(
+                        plan.source_catalog.clone(),
+                        plan.source_database.clone(),
+                        plan.source_table.clone(),
+                    ),
+                    vec![UserPrivilegeType::Select],
+                    true,
+                )
+                    .await?;
+                self.validate_access(
+                    &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                    vec![UserPrivilegeType::Create],
+                    true,
+                )
+                    .await?;
+            }
             Plan::DropTable(plan) => {
                 self.validate_access(
                     &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
@@ -452,6 +491,18 @@ impl AccessChecker for PrivilegeAccess {
                 )
                     .await?;
             }
+            Plan::PromoteReplica(plan) => {
+                self.validate_access(
+                    &GrantObject::Table(
+                        plan.catalog.clone(),
+                        plan.database.clone(),
+                        plan.table.clone(),
+                    ),
+                    vec![UserPrivilegeType::Alter],
+                    true,
+                )
+                    .await?;
+            }
             Plan::AddTableColumn(plan) => {
                 self.validate_access(
                     &GrantObject::Table(
@@ -500,6 +551,30 @@ impl AccessChecker for PrivilegeAccess {
                 )
                     .await?;
             }
+            Plan::AddTableConstraint(plan) => {
+                self.validate_access(
+                    &GrantObject::Table(
+                        plan.catalog.clone(),
+                        plan.database.clone(),
+                        plan.table.clone(),
+                    ),
+                    vec![UserPrivilegeType::Alter],
+                    true,
+                )
+                    .await?;
+            }
+            Plan::DropTableConstraint(plan) => {
+                self.validate_access(
+                    &GrantObject::Table(
+                        plan.catalog.clone(),
+                        plan.database.clone(),
+                        plan.table.clone(),
+                    ),
+                    vec![UserPrivilegeType::Alter],
+                    true,
+                )
+                    .await?;
+            }
             Plan::AlterTableClusterKey(plan) => {
                 self.validate_access(
                     &GrantObject::Table(
@@ -838,7 +913,10 @@ impl AccessChecker for PrivilegeAccess {
                 self.validate_access(&GrantObject::Global, vec![UserPrivilegeType::Grant], false)
                     .await?;
             }
-            Plan::SetVariable(_) | Plan::UnSetVariable(_) | Plan::Kill(_) => {
+            Plan::SetVariable(_)
+            | Plan::UnSetVariable(_)
+            | Plan::Kill(_)
+            | Plan::FlushCache(_) => {
                 self.validate_access(&GrantObject::Global, vec![UserPrivilegeType::Super], false)
                     .await?;
             }
@@ -930,12 +1008,23 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::ShowConnections(_)
             | Plan::DescConnection(_)
             | Plan::DropConnection(_)
+            | Plan::CreateSequence(_)
+            | Plan::DropSequence(_)
+            | Plan::ShowSequences(_)
             | Plan::CreateTask(_)   // TODO: need to build ownership info for task
             | Plan::ShowTasks(_)    // TODO: need to build ownership info for task
             | Plan::DescribeTask(_) // TODO: need to build ownership info for task
             | Plan::ExecuteTask(_)  // TODO: need to build ownership info for task
             | Plan::DropTask(_)     // TODO: need to build ownership info for task
-            | Plan::AlterTask(_) => {
+            | Plan::AlterTask(_)
+            | Plan::CreateNotification(_)
+            | Plan::AlterNotification(_)
+            | Plan::DropNotification(_)
+            | Plan::DescNotification(_)
+            | Plan::CreateSecret(_)
+            | Plan::DropSecret(_)
+            | Plan::DescSecret(_)
+            | Plan::ShowSecrets(_) => {
                 self.validate_access(&GrantObject::Global, vec![UserPrivilegeType::Super], false)
                     .await?;
             }
@@ -952,6 +1041,8 @@ impl AccessChecker for PrivilegeAccess {
             Plan::SetRole(_) => {}
             Plan::SetSecondaryRoles(_) => {}
             Plan::ShowRoles(_) => {}
+            // The statements of the script are checked one by one when they're run.
+            Plan::ExecuteImmediate(_) => {}
             Plan::Presign(plan) => {
                     if enable_stage_udf_priv_check && !plan.stage.is_from_uri {
                         let stage_name = &plan.stage.stage_name;
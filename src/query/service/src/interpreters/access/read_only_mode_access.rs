@@ -0,0 +1,98 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_config::GlobalConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_sql::plans::RewriteKind;
+
+use crate::interpreters::access::AccessChecker;
+use crate::sessions::QueryContext;
+use crate::sql::plans::Plan;
+
+pub struct ReadOnlyModeAccess {}
+impl ReadOnlyModeAccess {
+    pub fn create() -> Box<dyn AccessChecker> {
+        Box::new(ReadOnlyModeAccess {})
+    }
+
+    fn is_read_only(plan: &Plan) -> bool {
+        match plan {
+            // `CALL` may change the tenant settings, such as `admin$tenant_quota`.
+            Plan::Query { rewrite_kind, .. } => !matches!(rewrite_kind, Some(RewriteKind::Call)),
+            Plan::ExplainAnalyze { plan } | Plan::DescribeQuery { plan } => Self::is_read_only(plan),
+
+            // Explain.
+            Plan::Explain { .. }
+            | Plan::ExplainAst { .. }
+            | Plan::ExplainSyntax { .. }
+
+            // Show and describe.
+            | Plan::ShowCreateCatalog(_)
+            | Plan::ShowCreateDatabase(_)
+            | Plan::ShowCreateTable(_)
+            | Plan::DescribeTable(_)
+            | Plan::ExistsTable(_)
+            | Plan::ShowRoles(_)
+            | Plan::ShowGrants(_)
+            | Plan::ShowFileFormats(_)
+            | Plan::DescConnection(_)
+            | Plan::ShowConnections(_)
+            | Plan::ShowSequences(_)
+            | Plan::DescSecret(_)
+            | Plan::ShowSecrets(_)
+            | Plan::ShowShareEndpoint(_)
+            | Plan::DescShare(_)
+            | Plan::ShowShares(_)
+            | Plan::ShowObjectGrantPrivileges(_)
+            | Plan::ShowGrantTenantsOfShare(_)
+            | Plan::DescDatamaskPolicy(_)
+            | Plan::DescNetworkPolicy(_)
+            | Plan::ShowNetworkPolicies(_)
+            | Plan::DescribeTask(_)
+            | Plan::ShowTasks(_)
+            | Plan::DescNotification(_)
+
+            // Session.
+            | Plan::UseDatabase(_)
+            | Plan::SetVariable(_)
+            | Plan::UnSetVariable(_)
+            | Plan::SetRole(_)
+            | Plan::SetSecondaryRoles(_)
+            | Plan::Kill(_)
+
+            // System.
+            | Plan::FlushCache(_) => true,
+            _ => false,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl AccessChecker for ReadOnlyModeAccess {
+    // Refuse DDL/DML if in read-only mode.
+    #[async_backtrace::framed]
+    async fn check(&self, _ctx: &Arc<QueryContext>, plan: &Plan) -> Result<()> {
+        if GlobalConfig::instance().query.read_only && !Self::is_read_only(plan) {
+            return Err(ErrorCode::ReadOnlyModePermissionDenied(format!(
+                "Access denied for operation:{:?} in read-only mode",
+                plan.format_indent()
+            )));
+        }
+
+        Ok(())
+    }
+}
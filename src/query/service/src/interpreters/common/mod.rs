@@ -15,8 +15,12 @@
 mod compact_hook;
 mod grant;
 mod metrics;
+mod notification;
+mod quarantine_hook;
 mod query_log;
+mod quota;
 mod refresh_aggregating_index;
+mod replication_hook;
 mod stream;
 mod table;
 mod task;
@@ -24,10 +28,19 @@ mod util;
 
 pub use compact_hook::*;
 pub use grant::validate_grant_object_exists;
+pub use notification::make_notification_target;
+pub use notification::make_retry_policy;
+pub use quarantine_hook::hook_quarantine;
+pub use quarantine_hook::QuarantineTableDesc;
 pub use query_log::InterpreterQueryLog;
+pub use quota::check_concurrent_queries_quota;
+pub use quota::check_storage_quota;
 pub use refresh_aggregating_index::hook_refresh_agg_index;
 pub use refresh_aggregating_index::RefreshAggIndexDesc;
+pub use replication_hook::hook_replicate_table;
+pub use replication_hook::ReplicateTableDesc;
 pub use stream::build_update_stream_meta_seq;
+pub use table::check_crypto_shred_columns;
 pub use table::check_referenced_computed_columns;
 pub use task::get_client_config;
 pub use task::make_schedule_options;
@@ -0,0 +1,55 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_cloud_control::pb::EmailConfig;
+use common_cloud_control::pb::NotificationType;
+use common_cloud_control::pb::RetryPolicy;
+use common_cloud_control::pb::WebhookConfig;
+use common_sql::plans::NotificationRetryPolicy;
+use common_sql::plans::NotificationTarget;
+
+/// The notification type, the webhook config and the email config of the target.
+pub fn make_notification_target(
+    target: NotificationTarget,
+) -> (NotificationType, Option<WebhookConfig>, Option<EmailConfig>) {
+    match target {
+        NotificationTarget::Webhook {
+            url,
+            method,
+            authorization_header,
+            signing_secret,
+        } => (
+            NotificationType::Webhook,
+            Some(WebhookConfig {
+                url,
+                method,
+                authorization_header,
+                signing_secret,
+            }),
+            None,
+        ),
+        NotificationTarget::Email { recipients } => (
+            NotificationType::Email,
+            None,
+            Some(EmailConfig { recipients }),
+        ),
+    }
+}
+
+pub fn make_retry_policy(policy: NotificationRetryPolicy) -> RetryPolicy {
+    RetryPolicy {
+        max_retries: policy.max_retries,
+        retry_interval_secs: policy.retry_interval_secs,
+    }
+}
@@ -0,0 +1,146 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::runtime::GlobalIORuntime;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_io::escape_string_with_quote;
+use common_meta_app::principal::OnErrorMode;
+use common_pipeline_core::Pipeline;
+use common_sql::Planner;
+use futures_util::TryStreamExt;
+use log::info;
+
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::QueryContext;
+
+/// Max number of records written by each `INSERT` statement.
+const QUARANTINE_BATCH_ROWS: usize = 1000;
+
+pub struct QuarantineTableDesc {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+}
+
+/// Returns the name of the table holding the records of `table` quarantined by
+/// `ON_ERROR = QUARANTINE`.
+fn quarantine_table_name(table: &str) -> String {
+    format!("{table}_quarantine")
+}
+
+// with `ON_ERROR = QUARANTINE`, the records which fail to be decoded or violate the CHECK
+// constraints are skipped by the load, write them into the quarantine table of the target
+// table once the pipeline finished successfully.
+//
+// the quarantine table is created in the database of the target table if not exists.
+pub fn hook_quarantine(ctx: Arc<QueryContext>, pipeline: &mut Pipeline, desc: QuarantineTableDesc) {
+    if pipeline.is_empty() || ctx.get_on_error_mode() != Some(OnErrorMode::Quarantine) {
+        return;
+    }
+
+    pipeline.set_on_finished(move |err| {
+        if err.is_none() {
+            let written =
+                GlobalIORuntime::instance().block_on(write_quarantined_records(ctx, desc))?;
+            if written > 0 {
+                info!("{} records written into the quarantine table", written);
+            }
+        }
+        Ok(())
+    });
+}
+
+async fn write_quarantined_records(
+    ctx: Arc<QueryContext>,
+    desc: QuarantineTableDesc,
+) -> Result<usize> {
+    let query_id = escape_string(&ctx.get_id());
+    let copy_status = ctx.get_copy_status();
+    let mut rows = Vec::new();
+    for entry in copy_status.files.iter() {
+        let file = escape_string(entry.key());
+        for r in entry.value().quarantined.iter() {
+            rows.push(format!(
+                "('{}', '{}', {}, {}, {}, '{}', NOW())",
+                query_id,
+                file,
+                display_opt(r.line.map(|line| line + 1)),
+                display_opt(r.offset),
+                display_opt(r.record.as_ref().map(|s| format!("'{}'", escape_string(s)))),
+                escape_string(&r.error),
+            ));
+        }
+    }
+    for entry in copy_status.rejected_rows.iter() {
+        for r in entry.value().iter() {
+            rows.push(format!(
+                "('{}', NULL, NULL, NULL, {}, '{}', NOW())",
+                query_id,
+                display_opt(r.record.as_ref().map(|s| format!("'{}'", escape_string(s)))),
+                escape_string(&r.error),
+            ));
+        }
+    }
+    if rows.is_empty() {
+        return Ok(0);
+    }
+
+    let table = format!(
+        "`{}`.`{}`.`{}`",
+        desc.catalog,
+        desc.database,
+        quarantine_table_name(&desc.table)
+    );
+    run_sql(
+        &ctx,
+        &format!(
+            "CREATE TABLE IF NOT EXISTS {table} (query_id STRING, source_file STRING NULL, \
+            source_line UINT64 NULL, source_offset UINT64 NULL, raw_record STRING NULL, \
+            error_message STRING, quarantined_on TIMESTAMP)"
+        ),
+    )
+    .await?;
+    for chunk in rows.chunks(QUARANTINE_BATCH_ROWS) {
+        run_sql(
+            &ctx,
+            &format!("INSERT INTO {table} VALUES {}", chunk.join(", ")),
+        )
+        .await?;
+    }
+    Ok(rows.len())
+}
+
+async fn run_sql(ctx: &Arc<QueryContext>, sql: &str) -> Result<()> {
+    let ctx = ctx.get_current_session().create_query_context().await?;
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(sql).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    let _ = interpreter
+        .execute(ctx)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    Ok(())
+}
+
+fn escape_string(s: &str) -> String {
+    escape_string_with_quote(s, Some('\''))
+}
+
+fn display_opt<T: ToString>(v: Option<T>) -> String {
+    v.map_or_else(|| "NULL".to_string(), |v| v.to_string())
+}
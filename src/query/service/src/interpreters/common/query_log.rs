@@ -22,6 +22,7 @@ use common_exception::Result;
 use common_storages_system::LogType;
 use common_storages_system::QueryLogElement;
 use common_storages_system::QueryLogQueue;
+use common_storages_system::ResourceUsageRecorder;
 use log::error;
 use log::info;
 use serde_json;
@@ -55,6 +56,15 @@ fn error_fields(log_type: LogType, err: Option<ErrorCode>) -> (LogType, i32, Str
     }
 }
 
+/// The snapshots pinned in the query, formatted as `catalog.database.table: snapshot_id`.
+fn pinned_snapshots(ctx: &QueryContext) -> String {
+    ctx.get_pinned_snapshots()
+        .into_iter()
+        .map(|(table, snapshot_id)| format!("{table}: {snapshot_id}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 impl InterpreterQueryLog {
     fn write_log(event: QueryLogElement) -> Result<()> {
         let event_str = serde_json::to_string(&event)?;
@@ -155,6 +165,7 @@ impl InterpreterQueryLog {
             tables: "".to_string(),
             columns: "".to_string(),
             projections: "".to_string(),
+            snapshots: pinned_snapshots(ctx),
             written_rows,
             written_bytes,
             written_io_bytes,
@@ -169,6 +180,8 @@ impl InterpreterQueryLog {
             result_bytes,
             cpu_usage,
             memory_usage,
+            cpu_time_ms: 0,
+            network_bytes: 0,
             join_spilled_bytes,
             join_spilled_rows,
             agg_spilled_bytes,
@@ -226,6 +239,8 @@ impl InterpreterQueryLog {
         let total_partitions = data_metrics.get_partitions_total();
         let cpu_usage = ctx.get_settings().get_max_threads()? as u32;
         let memory_usage = ctx.get_current_session().get_memory_usage() as u64;
+        let cpu_time_ms = ctx.get_cpu_time_value().as_millis() as u64;
+        let network_bytes = ctx.get_network_progress_value().bytes as u64;
 
         let join_spilled_rows = ctx.get_join_spill_progress_value().rows as u64;
         let join_spilled_bytes = ctx.get_join_spill_progress_value().bytes as u64;
@@ -265,7 +280,7 @@ impl InterpreterQueryLog {
         let (log_type, exception_code, exception_text, stack_trace) =
             error_fields(LogType::Finish, err);
 
-        Self::write_log(QueryLogElement {
+        let event = QueryLogElement {
             log_type,
             handler_type,
             tenant_id,
@@ -285,6 +300,7 @@ impl InterpreterQueryLog {
             tables: "".to_string(),
             columns: "".to_string(),
             projections: "".to_string(),
+            snapshots: pinned_snapshots(ctx),
             written_rows,
             written_bytes,
             written_io_bytes,
@@ -299,6 +315,8 @@ impl InterpreterQueryLog {
             result_bytes,
             cpu_usage,
             memory_usage,
+            cpu_time_ms,
+            network_bytes,
             join_spilled_bytes,
             join_spilled_rows,
             agg_spilled_bytes,
@@ -316,6 +334,8 @@ impl InterpreterQueryLog {
             server_version: "".to_string(),
             session_settings,
             extra: "".to_string(),
-        })
+        };
+        ResourceUsageRecorder::instance().record(&event);
+        Self::write_log(event)
     }
 }
@@ -0,0 +1,69 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::MatchSeq;
+use common_storages_system::TenantUsage;
+use common_users::UserApiProvider;
+
+use crate::sessions::QueryContext;
+
+/// Rejects the query if the queries running on this node exceed the
+/// `max_concurrent_queries` quota of the tenant.
+pub async fn check_concurrent_queries_quota(ctx: &QueryContext) -> Result<()> {
+    if !ctx.get_current_session().get_type().is_user_session() {
+        return Ok(());
+    }
+
+    // The running queries include this one, the quota is only fetched when
+    // other queries are running.
+    let running_queries = TenantUsage::running_queries(ctx);
+    if running_queries <= 1 {
+        return Ok(());
+    }
+
+    let quota_api = UserApiProvider::instance().get_tenant_quota_api_client(&ctx.get_tenant())?;
+    let quota = quota_api.get_quota(MatchSeq::GE(0)).await?.data;
+    if quota.max_concurrent_queries != 0 && running_queries > quota.max_concurrent_queries as u64 {
+        return Err(ErrorCode::TenantQuotaExceeded(format!(
+            "Max concurrent queries quota exceeded: {}",
+            quota.max_concurrent_queries
+        )));
+    }
+    Ok(())
+}
+
+/// Rejects writing data if the tables of the tenant already store more than the
+/// `max_storage_bytes` quota.
+pub async fn check_storage_quota(ctx: &QueryContext) -> Result<()> {
+    let quota_api = UserApiProvider::instance().get_tenant_quota_api_client(&ctx.get_tenant())?;
+    let quota = quota_api.get_quota(MatchSeq::GE(0)).await?.data;
+    if quota.max_storage_bytes == 0 {
+        return Ok(());
+    }
+
+    // Note:
+    // Collecting the usage lists all the tables of the tenant,
+    // so it's only done when max_storage_bytes != 0
+    let usage = TenantUsage::collect(ctx).await?;
+    if usage.storage_bytes >= quota.max_storage_bytes {
+        return Err(ErrorCode::TenantQuotaExceeded(format!(
+            "Max storage bytes quota exceeded: {}",
+            quota.max_storage_bytes
+        )));
+    }
+    Ok(())
+}
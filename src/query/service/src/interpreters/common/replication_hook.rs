@@ -0,0 +1,95 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Instant;
+
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_base::GLOBAL_TASK;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_pipeline_core::Pipeline;
+use common_sql::binder::parse_replication_location;
+use common_storage::DataOperator;
+use common_storages_fuse::FuseTable;
+use log::info;
+use log::warn;
+
+use crate::sessions::QueryContext;
+
+pub struct ReplicateTableDesc {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+}
+
+// if the target table has a replication target, ship the newly committed snapshot to it
+// once the pipeline finished successfully.
+//
+// replication runs in background, errors (if any) are ignored, the replica simply lags
+// behind until the next successful replication.
+pub fn hook_replicate_table(
+    ctx: Arc<QueryContext>,
+    pipeline: &mut Pipeline,
+    desc: ReplicateTableDesc,
+) {
+    if pipeline.is_empty() {
+        return;
+    }
+
+    pipeline.set_on_finished(move |err| {
+        if err.is_none() {
+            GlobalIORuntime::instance().spawn(GLOBAL_TASK, async move {
+                let start = Instant::now();
+                match replicate_table(ctx, &desc).await {
+                    Ok(Some(copied)) => info!(
+                        "replicate table {}.{} finished, {} files copied in {:?}",
+                        desc.database,
+                        desc.table,
+                        copied,
+                        start.elapsed()
+                    ),
+                    Ok(None) => {}
+                    Err(e) => warn!(
+                        "replicate table {}.{} failed (ignored): {:?}",
+                        desc.database, desc.table, e
+                    ),
+                }
+            });
+        }
+        Ok(())
+    });
+}
+
+async fn replicate_table(
+    ctx: Arc<QueryContext>,
+    desc: &ReplicateTableDesc,
+) -> Result<Option<usize>> {
+    // load the table from meta again, the one held by the query is stale after committing.
+    let catalog = ctx.get_catalog(&desc.catalog).await?;
+    let table = catalog
+        .get_table(ctx.get_tenant().as_str(), &desc.database, &desc.table)
+        .await?;
+
+    let ctx: Arc<dyn TableContext> = ctx;
+    let Some(sp) = parse_replication_location(&ctx, table.options()).await? else {
+        return Ok(None);
+    };
+
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let target = DataOperator::try_create(&sp).await?.operator();
+    let copied = fuse_table.do_replicate(ctx, target).await?;
+    Ok(Some(copied))
+}
@@ -14,12 +14,15 @@
 
 use std::sync::Arc;
 
+use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::ComputedExpr;
 use common_expression::DataSchemaRef;
+use common_expression::FieldIndex;
 use common_sql::parse_computed_expr;
+use storages_common_table_meta::table::CryptoShredColumns;
 
 pub fn check_referenced_computed_columns(
     ctx: Arc<dyn TableContext>,
@@ -55,3 +58,27 @@ pub fn check_referenced_computed_columns(
     }
     Ok(())
 }
+
+/// The values of the crypto-shredded columns are kept encrypted in the blocks, so the
+/// mutations working on the blocks directly can't filter by or assign them.
+pub fn check_crypto_shred_columns(
+    table: &dyn Table,
+    field_indices: impl IntoIterator<Item = FieldIndex>,
+    action: &str,
+) -> Result<()> {
+    if let Some(shred_columns) = CryptoShredColumns::from_options(table.options())? {
+        let schema = table.schema();
+        for index in field_indices {
+            if let Some(field) = schema.fields().get(index) {
+                if shred_columns.contains(field.name()) {
+                    return Err(ErrorCode::Unimplemented(format!(
+                        "{} can not reference crypto-shredded column {}",
+                        action,
+                        field.name()
+                    )));
+                }
+            }
+        }
+    }
+    Ok(())
+}
@@ -22,6 +22,7 @@ use common_expression::SendableDataBlockStream;
 use common_pipeline_core::SourcePipeBuilder;
 use log::error;
 
+use crate::interpreters::common::check_concurrent_queries_quota;
 use crate::interpreters::InterpreterMetrics;
 use crate::interpreters::InterpreterQueryLog;
 use crate::pipelines::executor::ExecutorSettings;
@@ -53,6 +54,13 @@ pub trait Interpreter: Sync + Send {
             log_query_finished(&ctx, Some(err.clone()));
             return Err(err);
         }
+
+        if let Err(err) = check_concurrent_queries_quota(&ctx).await {
+            InterpreterMetrics::record_query_error(&ctx);
+            log_query_finished(&ctx, Some(err.clone()));
+            return Err(err);
+        }
+
         let mut build_res = match self.execute2().await {
             Ok(build_res) => build_res,
             Err(build_error) => {
@@ -85,7 +93,8 @@ pub trait Interpreter: Sync + Send {
         let settings = ctx.get_settings();
         let query_id = ctx.get_id();
         build_res.set_max_threads(settings.get_max_threads()? as usize);
-        let settings = ExecutorSettings::try_create(&settings, query_id)?;
+        let settings =
+            ExecutorSettings::try_create(&settings, query_id)?.with_cpu_time(ctx.get_cpu_time());
 
         if build_res.main_pipeline.is_complete_pipeline()? {
             let mut pipelines = build_res.sources_pipelines;
@@ -97,6 +106,11 @@ pub trait Interpreter: Sync + Send {
             complete_executor.execute()?;
             self.inject_result()
         } else {
+            // Only the queries returning a result set may be stopped with a partial result.
+            let settings = match ctx.get_settings().get_timeout_overflow_break()? {
+                true => settings.with_partial_result(ctx.get_partial_result()),
+                false => settings,
+            };
             let pulling_executor = PipelinePullingExecutor::from_pipelines(build_res, settings)?;
 
             ctx.set_executor(pulling_executor.get_inner())?;
@@ -25,6 +25,7 @@ use common_meta_app::schema::CatalogNameIdent;
 use common_meta_app::schema::CatalogOption;
 use common_sql::plans::CreateCatalogPlan;
 use common_storages_fuse::TableContext;
+use common_users::UserApiProvider;
 use log::debug;
 
 use super::Interpreter;
@@ -64,6 +65,12 @@ impl Interpreter for CreateCatalogInterpreter {
 
         let catalog_manager = CatalogManager::instance();
 
+        // Only the references to the secrets are persisted, the values are used to check the catalog.
+        let mut catalog_option = self.plan.meta.catalog_option.clone();
+        UserApiProvider::instance()
+            .resolve_catalog_secrets(&self.plan.tenant, &mut catalog_option)
+            .await?;
+
         // Build and check if catalog is valid.
         let ctl = catalog_manager
             .build_catalog(&CatalogInfo {
@@ -73,7 +80,7 @@ impl Interpreter for CreateCatalogInterpreter {
                     catalog_name: self.plan.catalog.clone(),
                 },
                 meta: CatalogMeta {
-                    catalog_option: self.plan.meta.catalog_option.clone(),
+                    catalog_option,
                     created_on: chrono::Utc::now(),
                 },
             })
@@ -0,0 +1,63 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_exception::Result;
+use common_sql::ColumnLineage;
+use common_storages_system::ColumnLineageLogElement;
+use common_storages_system::ColumnLineageQueue;
+
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct InterpreterColumnLineage;
+
+impl InterpreterColumnLineage {
+    /// Append one record into `system.column_lineage` for every source of every output column.
+    pub fn write_log(ctx: &QueryContext, lineage: &[ColumnLineage]) -> Result<()> {
+        if lineage.is_empty() {
+            return Ok(());
+        }
+
+        let query_id = ctx.get_id();
+        let event_time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_micros() as i64;
+
+        let queue = ColumnLineageQueue::instance()?;
+        for column in lineage {
+            let (target_database, target_table) = match &column.target_table {
+                Some((database, table)) => (Some(database.clone()), Some(table.clone())),
+                None => (None, None),
+            };
+            for source in column.sources.iter() {
+                queue.append_data(ColumnLineageLogElement {
+                    query_id: query_id.clone(),
+                    event_time,
+                    target_database: target_database.clone(),
+                    target_table: target_table.clone(),
+                    output_column: column.output_column.clone(),
+                    source_catalog: source.catalog.clone(),
+                    source_database: source.database.clone(),
+                    source_table: source.table.clone(),
+                    source_column: source.column.clone(),
+                })?;
+            }
+        }
+        Ok(())
+    }
+}
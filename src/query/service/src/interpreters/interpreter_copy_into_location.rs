@@ -109,6 +109,7 @@ impl CopyIntoLocationInterpreter {
             files_to_copy: None,
             is_select: false,
             default_values: None,
+            format_overrides: Default::default(),
         };
         let to_table = StageTable::try_create(stage_table_info)?;
         PipelineBuilder::build_append2table_with_commit_pipeline(
@@ -37,16 +37,23 @@ use common_sql::executor::table_read_plan::ToReadDataSourcePlan;
 use common_sql::executor::PhysicalPlan;
 use common_storage::StageFileInfo;
 use common_storages_stage::StageTable;
+use common_storages_system::LoadErrorLogElement;
+use common_storages_system::LoadErrorsQueue;
 use log::debug;
 use log::info;
 
 use crate::interpreters::common::build_update_stream_meta_seq;
 use crate::interpreters::common::check_deduplicate_label;
+use crate::interpreters::common::check_storage_quota;
 use crate::interpreters::common::hook_compact;
+use crate::interpreters::common::hook_quarantine;
 use crate::interpreters::common::hook_refresh_agg_index;
+use crate::interpreters::common::hook_replicate_table;
 use crate::interpreters::common::CompactHookTraceCtx;
 use crate::interpreters::common::CompactTargetTableDescription;
+use crate::interpreters::common::QuarantineTableDesc;
 use crate::interpreters::common::RefreshAggIndexDesc;
+use crate::interpreters::common::ReplicateTableDesc;
 use crate::interpreters::Interpreter;
 use crate::interpreters::SelectInterpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -223,6 +230,26 @@ impl CopyIntoTableInterpreter {
         Ok(())
     }
 
+    /// Logs the errors of `ON_ERROR = SET_NULL` into `system.load_errors`.
+    fn log_load_errors(&self) -> Result<()> {
+        let cs = self.ctx.get_copy_status();
+        let query_id = self.ctx.get_id();
+        for entry in cs.files.iter() {
+            for info in entry.value().load_errors.iter() {
+                LoadErrorsQueue::instance()?.append_data(LoadErrorLogElement {
+                    query_id: query_id.clone(),
+                    database: self.plan.database_name.clone(),
+                    table: self.plan.table_name.clone(),
+                    file: entry.key().clone(),
+                    line: info.line as u64 + 1,
+                    column_name: info.error.column_name().map(|name| name.to_string()),
+                    error: info.error.to_string(),
+                })?;
+            }
+        }
+        Ok(())
+    }
+
     fn get_copy_into_table_result(&self) -> Result<Vec<DataBlock>> {
         let return_all = !self
             .plan
@@ -340,6 +367,16 @@ impl Interpreter for CopyIntoTableInterpreter {
         if self.plan.no_file_to_copy {
             return Ok(PipelineBuildResult::create());
         }
+        check_storage_quota(&self.ctx).await?;
+        self.ctx.set_on_error_mode(
+            self.plan
+                .stage_table_info
+                .stage_info
+                .copy_options
+                .on_error
+                .clone(),
+        );
+
         let (physical_plan, files, update_stream_meta) =
             self.build_physical_plan(&self.plan).await?;
         let mut build_res =
@@ -357,6 +394,17 @@ impl Interpreter for CopyIntoTableInterpreter {
             .await?;
         }
 
+        // Write the skipped records into the quarantine table if `ON_ERROR = QUARANTINE`.
+        hook_quarantine(
+            self.ctx.clone(),
+            &mut build_res.main_pipeline,
+            QuarantineTableDesc {
+                catalog: self.plan.catalog_info.name_ident.catalog_name.clone(),
+                database: self.plan.database_name.clone(),
+                table: self.plan.table_name.clone(),
+            },
+        );
+
         // Compact if 'enable_recluster_after_write' on.
         {
             let compact_target = CompactTargetTableDescription {
@@ -396,6 +444,17 @@ impl Interpreter for CopyIntoTableInterpreter {
             .await?;
         }
 
+        // Replicate the new snapshot if replication is enabled.
+        hook_replicate_table(
+            self.ctx.clone(),
+            &mut build_res.main_pipeline,
+            ReplicateTableDesc {
+                catalog: self.plan.catalog_info.name_ident.catalog_name.clone(),
+                database: self.plan.database_name.clone(),
+                table: self.plan.table_name.clone(),
+            },
+        );
+
         Ok(build_res)
     }
 
@@ -403,6 +462,7 @@ impl Interpreter for CopyIntoTableInterpreter {
         let blocks = if self.plan.no_file_to_copy {
             vec![DataBlock::empty_with_schema(self.plan.schema())]
         } else {
+            self.log_load_errors()?;
             self.get_copy_into_table_result()?
         };
 
@@ -12,15 +12,48 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
+use common_ast::ast::CopyIntoTableStmt;
+use common_ast::ast::DeleteStmt;
+use common_ast::ast::Identifier;
+use common_ast::ast::InsertSource;
+use common_ast::ast::InsertStmt;
+use common_ast::ast::TableReference;
+use common_ast::ast::UpdateStmt;
+use common_ast::parser::parse_sql;
+use common_ast::parser::tokenize_sql;
+use common_ast::walk_query;
+use common_ast::walk_statement;
+use common_ast::walk_table_reference;
+use common_ast::Visitor;
+use common_catalog::catalog::Catalog;
+use common_cloud_control::cloud_api::CloudControlApiProvider;
+use common_cloud_control::pb::DropTaskRequest;
+use common_cloud_control::pb::ShowTasksRequest;
+use common_cloud_control::task_client::make_request;
+use common_config::GlobalConfig;
 use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::DataBlock;
+use common_expression::FromData;
 use common_management::RoleApi;
 use common_meta_app::principal::GrantObjectByID;
+use common_meta_app::schema::DropDatabaseReq;
+use common_sql::normalize_identifier;
 use common_sql::plans::DropDatabasePlan;
+use common_sql::NameResolutionContext;
 use common_storages_share::save_share_spec;
+use common_storages_stream::stream_table::OPT_KEY_DATABASE_NAME;
+use common_storages_stream::stream_table::OPT_KEY_TABLE_ID;
+use common_storages_stream::stream_table::STREAM_ENGINE;
+use common_storages_view::view_table::QUERY;
+use common_storages_view::view_table::VIEW_ENGINE;
 use common_users::UserApiProvider;
+use log::info;
 
+use crate::interpreters::common::get_client_config;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -49,9 +82,28 @@ impl Interpreter for DropDatabaseInterpreter {
         let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
         let role_api = UserApiProvider::instance().get_role_api_client(&tenant)?;
 
-        // unset the ownership of the database, the database may not exists.
-        let db = catalog.get_database(&tenant, &self.plan.database).await;
-        if let Ok(db) = db {
+        let db = match catalog.get_database(&tenant, &self.plan.database).await {
+            Ok(db) => Some(db),
+            Err(e) if self.plan.dry_run && !self.plan.if_exists => return Err(e),
+            // the database may not exists.
+            Err(_) => None,
+        };
+
+        // the pre-flight report of the objects dropped together with the database
+        let mut dependents = vec![];
+        let mut dependent_tasks = vec![];
+        if db.is_some() && self.plan.cascade {
+            dependents = self.dependent_objects(catalog.as_ref()).await?;
+            dependent_tasks = self.dependent_tasks().await?;
+        }
+        let report = report_block(&dependents, &dependent_tasks);
+
+        if self.plan.dry_run {
+            return PipelineBuildResult::from_blocks(vec![report]);
+        }
+
+        // unset the ownership of the database
+        if let Some(db) = &db {
             role_api
                 .drop_ownership(&GrantObjectByID::Database {
                     catalog_name: self.plan.catalog.clone(),
@@ -60,8 +112,47 @@ impl Interpreter for DropDatabaseInterpreter {
                 .await?;
         }
 
-        // actual drop database
-        let resp = catalog.drop_database(self.plan.clone().into()).await?;
+        if !dependents.is_empty() || !dependent_tasks.is_empty() {
+            info!(
+                "drop database {} cascade, dependent objects: {:?}, dependent tasks: {:?}",
+                self.plan.database,
+                dependents
+                    .iter()
+                    .map(|d| format!("{} {}.{}", d.object_type, d.database, d.name))
+                    .collect::<Vec<_>>(),
+                dependent_tasks
+            );
+        }
+
+        // actual drop database, together with the dependent objects of other databases
+        let mut req: DropDatabaseReq = self.plan.clone().into();
+        req.dependent_table_ids = dependents.iter().map(|d| d.table_id).collect();
+        let resp = catalog.drop_database(req).await?;
+
+        for dependent in dependents.iter() {
+            role_api
+                .drop_ownership(&GrantObjectByID::Table {
+                    catalog_name: self.plan.catalog.clone(),
+                    db_id: dependent.db_id,
+                    table_id: dependent.table_id,
+                })
+                .await?;
+        }
+
+        // tasks are kept by the cloud control service instead of the meta-service,
+        // thus they can't join the txn and are dropped once the database is dropped.
+        if !dependent_tasks.is_empty() {
+            let task_client = CloudControlApiProvider::instance().get_task_client();
+            for task_name in dependent_tasks.iter() {
+                let req = DropTaskRequest {
+                    task_name: task_name.clone(),
+                    tenant_id: tenant.clone(),
+                    if_exist: true,
+                };
+                let req = make_request(req, get_client_config(self.ctx.clone())?);
+                task_client.drop_task(req).await?;
+            }
+        }
 
         // handle share cleanups with the DropDatabaseReply
         if let Some(spec_vec) = resp.spec_vec {
@@ -79,6 +170,202 @@ impl Interpreter for DropDatabaseInterpreter {
             .await?;
         }
 
-        Ok(PipelineBuildResult::create())
+        if !self.plan.cascade {
+            return Ok(PipelineBuildResult::create());
+        }
+        PipelineBuildResult::from_blocks(vec![report])
+    }
+}
+
+/// The report of `DROP DATABASE ... CASCADE`: the type, database and name of every object
+/// dropped with the database. Tasks do not belong to a database.
+fn report_block(dependents: &[DependentObject], dependent_tasks: &[String]) -> DataBlock {
+    let rows = dependents
+        .iter()
+        .map(|d| (d.object_type, d.database.as_str(), d.name.as_str()))
+        .chain(dependent_tasks.iter().map(|t| ("TASK", "", t.as_str())))
+        .collect::<Vec<_>>();
+    DataBlock::new_from_columns(vec![
+        StringType::from_data(
+            rows.iter()
+                .map(|(object_type, _, _)| object_type.as_bytes().to_vec())
+                .collect::<Vec<_>>(),
+        ),
+        StringType::from_data(
+            rows.iter()
+                .map(|(_, database, _)| database.as_bytes().to_vec())
+                .collect::<Vec<_>>(),
+        ),
+        StringType::from_data(
+            rows.iter()
+                .map(|(_, _, name)| name.as_bytes().to_vec())
+                .collect::<Vec<_>>(),
+        ),
+    ])
+}
+
+/// An object of another database that depends on the tables of the dropped database.
+struct DependentObject {
+    object_type: &'static str,
+    database: String,
+    name: String,
+    db_id: u64,
+    table_id: u64,
+}
+
+impl DropDatabaseInterpreter {
+    /// Collects the streams and views of other databases that reference the tables of the
+    /// dropped database. The views only count if they name the database explicitly, since
+    /// unqualified names are resolved against the current database when queried.
+    async fn dependent_objects(&self, catalog: &dyn Catalog) -> Result<Vec<DependentObject>> {
+        let tenant = self.ctx.get_tenant();
+        let table_ids = catalog
+            .list_tables(&tenant, &self.plan.database)
+            .await?
+            .iter()
+            .map(|t| t.get_id())
+            .collect::<HashSet<_>>();
+
+        let mut dependents = vec![];
+        for db in catalog.list_databases(&tenant).await? {
+            if db.name() == self.plan.database {
+                continue;
+            }
+            let db_id = db.get_db_info().ident.db_id;
+            for table in db.list_tables().await? {
+                let options = table.options();
+                let object_type = match table.engine() {
+                    STREAM_ENGINE => {
+                        let source_id = options
+                            .get(OPT_KEY_TABLE_ID)
+                            .and_then(|id| id.parse::<u64>().ok());
+                        let source_db = options.get(OPT_KEY_DATABASE_NAME);
+                        if !source_id.is_some_and(|id| table_ids.contains(&id))
+                            && source_db != Some(&self.plan.database)
+                        {
+                            continue;
+                        }
+                        "STREAM"
+                    }
+                    VIEW_ENGINE => {
+                        let Some(query) = options.get(QUERY) else {
+                            continue;
+                        };
+                        if !self.references_database(query)? {
+                            continue;
+                        }
+                        "VIEW"
+                    }
+                    _ => continue,
+                };
+                dependents.push(DependentObject {
+                    object_type,
+                    database: db.name().to_string(),
+                    name: table.name().to_string(),
+                    db_id,
+                    table_id: table.get_id(),
+                });
+            }
+        }
+        dependents.sort_by(|a, b| (&a.database, &a.name).cmp(&(&b.database, &b.name)));
+        Ok(dependents)
+    }
+
+    /// Collects the names of the tasks whose query names the dropped database explicitly.
+    ///
+    /// Tasks only exist if cloud control is enabled.
+    async fn dependent_tasks(&self) -> Result<Vec<String>> {
+        let config = GlobalConfig::instance();
+        if config.query.cloud_control_grpc_server_address.is_none() {
+            return Ok(vec![]);
+        }
+
+        let available_roles = self
+            .ctx
+            .get_current_session()
+            .get_all_available_roles()
+            .await?;
+        let req = ShowTasksRequest {
+            tenant_id: self.ctx.get_tenant(),
+            name_like: "".to_string(),
+            result_limit: 10000,
+            owners: available_roles
+                .into_iter()
+                .map(|x| x.identity().to_string())
+                .collect(),
+            task_ids: vec![],
+        };
+        let req = make_request(req, get_client_config(self.ctx.clone())?);
+        let task_client = CloudControlApiProvider::instance().get_task_client();
+        let tasks = task_client.show_tasks(req).await?.tasks;
+
+        let mut dependent_tasks = vec![];
+        for task in tasks {
+            if self.references_database(&task.query_text)? {
+                dependent_tasks.push(task.task_name);
+            }
+        }
+        dependent_tasks.sort();
+        Ok(dependent_tasks)
+    }
+
+    /// Whether the sql names the dropped database explicitly.
+    ///
+    /// A sql that fails to parse can't be resolved anyway, thus it references nothing.
+    fn references_database(&self, sql: &str) -> Result<bool> {
+        let settings = self.ctx.get_settings();
+        let sql_dialect = settings.get_sql_dialect()?;
+        let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+
+        let Ok(tokens) = tokenize_sql(sql) else {
+            return Ok(false);
+        };
+        let Ok((stmt, _)) = parse_sql(&tokens, sql_dialect) else {
+            return Ok(false);
+        };
+        let mut visitor = ReferencedDatabases::default();
+        walk_statement(&mut visitor, &stmt);
+        Ok(visitor.databases.iter().any(|database| {
+            normalize_identifier(database, &name_resolution_ctx).name == self.plan.database
+        }))
+    }
+}
+
+/// Collects the databases named explicitly by the table references of a statement,
+/// including the target tables of `INSERT`, `COPY`, `DELETE` and `UPDATE`.
+#[derive(Default)]
+struct ReferencedDatabases {
+    databases: Vec<Identifier>,
+}
+
+impl<'ast> Visitor<'ast> for ReferencedDatabases {
+    fn visit_table_reference(&mut self, table: &'ast TableReference) {
+        if let TableReference::Table {
+            database: Some(database),
+            ..
+        } = table
+        {
+            self.databases.push(database.clone());
+        }
+        walk_table_reference(self, table);
+    }
+
+    fn visit_insert(&mut self, insert: &'ast InsertStmt) {
+        self.databases.extend(insert.database.clone());
+        if let InsertSource::Select { query } = &insert.source {
+            walk_query(self, query);
+        }
+    }
+
+    fn visit_copy_into_table(&mut self, copy: &'ast CopyIntoTableStmt) {
+        self.databases.extend(copy.dst.database.clone());
+    }
+
+    fn visit_delete(&mut self, delete: &'ast DeleteStmt) {
+        self.visit_table_reference(&delete.table);
+    }
+
+    fn visit_update(&mut self, update: &'ast UpdateStmt) {
+        self.visit_table_reference(&update.table);
     }
 }
@@ -61,7 +61,10 @@ use log::debug;
 use storages_common_locks::LockManager;
 use storages_common_table_meta::meta::TableSnapshot;
 
+use crate::interpreters::common::check_crypto_shred_columns;
 use crate::interpreters::common::create_push_down_filters;
+use crate::interpreters::common::hook_replicate_table;
+use crate::interpreters::common::ReplicateTableDesc;
 use crate::interpreters::Interpreter;
 use crate::interpreters::SelectInterpreter;
 use crate::pipelines::executor::ExecutorSettings;
@@ -185,6 +188,7 @@ impl Interpreter for DeleteInterpreter {
         } else {
             (None, vec![])
         };
+        check_crypto_shred_columns(tbl.as_ref(), col_indices.iter().copied(), "DELETE")?;
 
         let fuse_table =
             tbl.as_any()
@@ -207,7 +211,10 @@ impl Interpreter for DeleteInterpreter {
             .await?
         {
             let cluster = self.ctx.get_cluster();
-            let is_lazy = !cluster.is_empty() && snapshot.segments.len() >= cluster.nodes.len();
+            // The block limit of deletion is applied to the pruned blocks, not the lazy segments.
+            let is_lazy = !cluster.is_empty()
+                && snapshot.segments.len() >= cluster.nodes.len()
+                && self.ctx.get_settings().get_deletion_block_limit()? == 0;
             let partitions = fuse_table
                 .mutation_read_partitions(
                     self.ctx.clone(),
@@ -235,6 +242,17 @@ impl Interpreter for DeleteInterpreter {
             build_res =
                 build_query_pipeline_without_render_result_set(&self.ctx, &physical_plan, false)
                     .await?;
+
+            // Replicate the new snapshot if replication is enabled.
+            hook_replicate_table(
+                self.ctx.clone(),
+                &mut build_res.main_pipeline,
+                ReplicateTableDesc {
+                    catalog: catalog_name.to_string(),
+                    database: db_name.to_string(),
+                    table: tbl_name.to_string(),
+                },
+            );
         }
 
         build_res.main_pipeline.add_lock_guard(lock_guard);
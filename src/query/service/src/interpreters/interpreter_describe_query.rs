@@ -0,0 +1,68 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::infer_table_schema;
+use common_expression::types::StringType;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_sql::plans::Plan;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+
+/// Returns the result schema of a bound query without optimizing or executing it.
+pub struct DescribeQueryInterpreter {
+    plan: Plan,
+}
+
+impl DescribeQueryInterpreter {
+    pub fn try_create(plan: Plan) -> Result<Self> {
+        Ok(DescribeQueryInterpreter { plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DescribeQueryInterpreter {
+    fn name(&self) -> &str {
+        "DescribeQueryInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let schema = infer_table_schema(&self.plan.schema())?;
+
+        let mut names: Vec<Vec<u8>> = vec![];
+        let mut types: Vec<Vec<u8>> = vec![];
+        let mut nulls: Vec<Vec<u8>> = vec![];
+
+        for field in schema.fields().iter() {
+            names.push(field.name().as_bytes().to_vec());
+
+            let non_null_type = field.data_type().remove_recursive_nullable();
+            types.push(non_null_type.sql_name().as_bytes().to_vec());
+            nulls.push(if field.is_nullable() {
+                "YES".as_bytes().to_vec()
+            } else {
+                "NO".as_bytes().to_vec()
+            });
+        }
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_data(types),
+            StringType::from_data(nulls),
+        ])])
+    }
+}
@@ -0,0 +1,542 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_ast::ast::ColumnID;
+use common_ast::ast::DeclareVar;
+use common_ast::ast::Expr;
+use common_ast::ast::Identifier;
+use common_ast::ast::Literal;
+use common_ast::ast::ScriptBlock;
+use common_ast::ast::ScriptStatement;
+use common_ast::ast::TypeName;
+use common_ast::ast::UnaryOperator;
+use common_ast::parser::parse_expr;
+use common_ast::parser::token::TokenKind;
+use common_ast::parser::tokenize_sql;
+use common_ast::walk_expr_mut;
+use common_ast::VisitorMut;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::StringType;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::FromData;
+use common_expression::ScalarRef;
+use common_sql::plans::ExecuteImmediatePlan;
+use common_sql::Planner;
+use futures_util::future::BoxFuture;
+use futures_util::FutureExt;
+use futures_util::TryStreamExt;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Runs the script of `EXECUTE IMMEDIATE` statement by statement in the current session.
+///
+/// Every SQL statement of the script is planned and executed in its own query context,
+/// so it sees the changes made by the previous ones. There is no multi-statement
+/// transaction, a statement that has been run is not rolled back if a later one fails.
+pub struct ExecuteImmediateInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: ExecuteImmediatePlan,
+}
+
+/// How the execution of the statements goes on.
+enum Flow {
+    Next,
+    Break,
+    Continue,
+    Return(Option<String>),
+}
+
+struct Variable {
+    data_type: Option<TypeName>,
+    /// The value as a literal, for example `CAST('1' AS INT)`.
+    value: Expr,
+}
+
+/// The variables of the blocks being executed, the innermost block is the last one.
+#[derive(Default)]
+struct Scopes {
+    scopes: Vec<HashMap<String, Variable>>,
+}
+
+impl Scopes {
+    fn get(&self, name: &str) -> Option<&Variable> {
+        self.scopes.iter().rev().find_map(|scope| scope.get(name))
+    }
+
+    fn get_mut(&mut self, name: &str) -> Option<&mut Variable> {
+        self.scopes
+            .iter_mut()
+            .rev()
+            .find_map(|scope| scope.get_mut(name))
+    }
+
+    fn declare(&mut self, name: String, variable: Variable) {
+        self.scopes.last_mut().unwrap().insert(name, variable);
+    }
+}
+
+/// Replaces the variables in an expression with their values.
+struct VariableReplacer<'a> {
+    scopes: &'a Scopes,
+}
+
+impl<'a> VisitorMut for VariableReplacer<'a> {
+    fn visit_expr(&mut self, expr: &mut Expr) {
+        if let Expr::ColumnRef {
+            database: None,
+            table: None,
+            column: ColumnID::Name(column),
+            ..
+        } = expr
+        {
+            if let Some(variable) = self.scopes.get(&variable_name(column)) {
+                *expr = variable.value.clone();
+                return;
+            }
+        }
+        walk_expr_mut(self, expr);
+    }
+}
+
+fn variable_name(ident: &Identifier) -> String {
+    if ident.quote.is_some() {
+        ident.name.clone()
+    } else {
+        ident.name.to_lowercase()
+    }
+}
+
+impl ExecuteImmediateInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: ExecuteImmediatePlan) -> Result<Self> {
+        Ok(ExecuteImmediateInterpreter { ctx, plan })
+    }
+
+    #[async_backtrace::framed]
+    async fn run_sql(&self, sql: &str) -> Result<(DataSchemaRef, Vec<DataBlock>)> {
+        let ctx = self
+            .ctx
+            .get_current_session()
+            .create_query_context()
+            .await?;
+        let mut planner = Planner::new(ctx.clone());
+        let (plan, _) = planner.plan_sql(sql).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+        let blocks = interpreter.execute(ctx).await?.try_collect().await?;
+        Ok((plan.schema(), blocks))
+    }
+
+    /// Evaluates the expression, returns its data type and its value in text.
+    #[async_backtrace::framed]
+    async fn eval(&self, expr: &Expr, scopes: &Scopes) -> Result<(DataType, Option<String>)> {
+        let mut expr = expr.clone();
+        VariableReplacer { scopes }.visit_expr(&mut expr);
+        let sql = format!("SELECT v, v::STRING FROM (SELECT {expr} AS v)");
+        let (schema, blocks) = self.run_sql(&sql).await?;
+        let data_type = schema.field(0).data_type().clone();
+        let block = DataBlock::concat(&blocks)?;
+        if block.num_rows() != 1 {
+            return Err(ErrorCode::SemanticError(format!(
+                "expression `{expr}` must return exactly one row, but got {}",
+                block.num_rows()
+            )));
+        }
+        let text = match block.get_by_offset(1).value.index(0) {
+            Some(ScalarRef::String(s)) => Some(String::from_utf8_lossy(s).into_owned()),
+            _ => None,
+        };
+        Ok((data_type, text))
+    }
+
+    #[async_backtrace::framed]
+    async fn eval_bool(&self, expr: &Expr, scopes: &Scopes) -> Result<bool> {
+        let (data_type, text) = self.eval(expr, scopes).await?;
+        match data_type.remove_nullable() {
+            DataType::Boolean | DataType::Null => Ok(text.as_deref() == Some("true")),
+            other => Err(ErrorCode::SemanticError(format!(
+                "condition `{expr}` must be a boolean, but got {other}"
+            ))),
+        }
+    }
+
+    /// Evaluates the value of a variable, it's cast to the type of the variable if there is one.
+    #[async_backtrace::framed]
+    async fn eval_variable(
+        &self,
+        expr: &Expr,
+        data_type: Option<&TypeName>,
+        scopes: &Scopes,
+    ) -> Result<Expr> {
+        let expr = match data_type {
+            Some(data_type) => Expr::Cast {
+                span: expr.span(),
+                expr: Box::new(expr.clone()),
+                target_type: data_type.clone(),
+                pg_style: false,
+            },
+            None => expr.clone(),
+        };
+        let (data_type, text) = self.eval(&expr, scopes).await?;
+        let inner_type = data_type.remove_nullable();
+        let literal = match (&inner_type, text) {
+            (DataType::Null, _) => return Ok(null_literal()),
+            (_, None) => Literal::Null,
+            (DataType::Boolean, Some(text)) => Literal::Boolean(text == "true"),
+            (DataType::String, Some(text)) => Literal::String(text),
+            (
+                DataType::Number(_)
+                | DataType::Decimal(_)
+                | DataType::Date
+                | DataType::Timestamp
+                | DataType::Variant,
+                Some(text),
+            ) => Literal::String(text),
+            (other, _) => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "variable of type {other} is not supported in script"
+                )));
+            }
+        };
+        let sql = format!(
+            "CAST({} AS {})",
+            Expr::Literal {
+                span: None,
+                lit: literal
+            },
+            inner_type.sql_name()
+        );
+        let tokens = tokenize_sql(&sql)?;
+        parse_expr(&tokens, self.ctx.get_settings().get_sql_dialect()?)
+    }
+
+    /// Replaces the `:<var>` in the SQL statement with the values of the variables.
+    ///
+    /// The colon right after an identifier, a string, a number or a bracket is a JSON path
+    /// access like `v:a`, it's left as it is.
+    fn substitute_variables(sql: &str, scopes: &Scopes) -> Result<String> {
+        let tokens = tokenize_sql(sql)?;
+        let mut result = String::with_capacity(sql.len());
+        let mut last = 0;
+        for i in 0..tokens.len().saturating_sub(1) {
+            let (colon, name) = (&tokens[i], &tokens[i + 1]);
+            if colon.kind != TokenKind::Colon
+                || !(name.kind == TokenKind::Ident || name.kind.is_keyword())
+                || name.span.start != colon.span.end
+            {
+                continue;
+            }
+            if i > 0
+                && tokens[i - 1].span.end == colon.span.start
+                && matches!(
+                    tokens[i - 1].kind,
+                    TokenKind::Ident
+                        | TokenKind::QuotedString
+                        | TokenKind::LiteralInteger
+                        | TokenKind::RParen
+                        | TokenKind::RBracket
+                )
+            {
+                continue;
+            }
+            if let Some(variable) = scopes.get(&name.text().to_lowercase()) {
+                result.push_str(&sql[last..colon.span.start]);
+                result.push_str(&format!("({})", variable.value));
+                last = name.span.end;
+            }
+        }
+        result.push_str(&sql[last..]);
+        Ok(result)
+    }
+
+    #[async_backtrace::framed]
+    async fn declare(&self, declare: &DeclareVar, scopes: &mut Scopes) -> Result<()> {
+        let value = match &declare.default {
+            Some(default) => {
+                self.eval_variable(default, declare.data_type.as_ref(), scopes)
+                    .await?
+            }
+            None => match &declare.data_type {
+                Some(data_type) => Expr::Cast {
+                    span: None,
+                    expr: Box::new(null_literal()),
+                    target_type: data_type.clone(),
+                    pg_style: false,
+                },
+                None => null_literal(),
+            },
+        };
+        scopes.declare(variable_name(&declare.name), Variable {
+            data_type: declare.data_type.clone(),
+            value,
+        });
+        Ok(())
+    }
+
+    fn execute_block<'a>(
+        &'a self,
+        block: &'a ScriptBlock,
+        scopes: &'a mut Scopes,
+    ) -> BoxFuture<'a, Result<Flow>> {
+        async move {
+            scopes.scopes.push(HashMap::new());
+            let result = self.execute_declares_and_body(block, scopes).await;
+            scopes.scopes.pop();
+
+            match (result, &block.exception_handler) {
+                // A killed query can't be caught.
+                (Err(err), Some(handler))
+                    if err.code() != ErrorCode::ABORTED_QUERY
+                        && err.code() != ErrorCode::ABORTED_SESSION =>
+                {
+                    let mut scope = HashMap::new();
+                    scope.insert("sqlcode".to_string(), Variable {
+                        data_type: None,
+                        value: Expr::Literal {
+                            span: None,
+                            lit: Literal::UInt64(err.code() as u64),
+                        },
+                    });
+                    scope.insert("sqlerrm".to_string(), Variable {
+                        data_type: None,
+                        value: Expr::Literal {
+                            span: None,
+                            lit: Literal::String(err.message()),
+                        },
+                    });
+                    scopes.scopes.push(scope);
+                    let result = self.execute_statements(handler, scopes).await;
+                    scopes.scopes.pop();
+                    result
+                }
+                (result, _) => result,
+            }
+        }
+        .boxed()
+    }
+
+    #[async_backtrace::framed]
+    async fn execute_declares_and_body(
+        &self,
+        block: &ScriptBlock,
+        scopes: &mut Scopes,
+    ) -> Result<Flow> {
+        for declare in &block.declares {
+            self.declare(declare, scopes).await?;
+        }
+        self.execute_statements(&block.body, scopes).await
+    }
+
+    fn execute_statements<'a>(
+        &'a self,
+        stmts: &'a [ScriptStatement],
+        scopes: &'a mut Scopes,
+    ) -> BoxFuture<'a, Result<Flow>> {
+        async move {
+            for stmt in stmts {
+                self.ctx.check_aborting()?;
+                match self.execute_statement(stmt, scopes).await? {
+                    Flow::Next => {}
+                    flow => return Ok(flow),
+                }
+            }
+            Ok(Flow::Next)
+        }
+        .boxed()
+    }
+
+    /// Runs the body of a loop, returns `None` if the loop should stop.
+    #[async_backtrace::framed]
+    async fn execute_loop_body(
+        &self,
+        body: &[ScriptStatement],
+        scopes: &mut Scopes,
+    ) -> Result<Option<Flow>> {
+        match self.execute_statements(body, scopes).await? {
+            Flow::Next | Flow::Continue => Ok(None),
+            Flow::Break => Ok(Some(Flow::Next)),
+            flow @ Flow::Return(_) => Ok(Some(flow)),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn execute_statement(&self, stmt: &ScriptStatement, scopes: &mut Scopes) -> Result<Flow> {
+        match stmt {
+            ScriptStatement::Block(block) => return self.execute_block(block, scopes).await,
+            ScriptStatement::Let { declare, .. } => self.declare(declare, scopes).await?,
+            ScriptStatement::Assign { name, value, .. } => {
+                let data_type = match scopes.get(&variable_name(name)) {
+                    Some(variable) => variable.data_type.clone(),
+                    None => {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "variable {name} is not declared"
+                        ))
+                        .set_span(name.span));
+                    }
+                };
+                let value = self
+                    .eval_variable(value, data_type.as_ref(), scopes)
+                    .await?;
+                scopes.get_mut(&variable_name(name)).unwrap().value = value;
+            }
+            ScriptStatement::If {
+                conditions,
+                results,
+                else_result,
+                ..
+            } => {
+                for (condition, result) in conditions.iter().zip(results) {
+                    if self.eval_bool(condition, scopes).await? {
+                        return self.execute_statements(result, scopes).await;
+                    }
+                }
+                if let Some(else_result) = else_result {
+                    return self.execute_statements(else_result, scopes).await;
+                }
+            }
+            ScriptStatement::While {
+                condition, body, ..
+            } => {
+                while self.eval_bool(condition, scopes).await? {
+                    if let Some(flow) = self.execute_loop_body(body, scopes).await? {
+                        return Ok(flow);
+                    }
+                }
+            }
+            ScriptStatement::Loop { body, .. } => loop {
+                if let Some(flow) = self.execute_loop_body(body, scopes).await? {
+                    return Ok(flow);
+                }
+            },
+            ScriptStatement::For {
+                variable,
+                is_reverse,
+                lower_bound,
+                upper_bound,
+                body,
+                ..
+            } => {
+                let lower = self.eval_integer(lower_bound, scopes).await?;
+                let upper = self.eval_integer(upper_bound, scopes).await?;
+                let values: Box<dyn Iterator<Item = i64> + Send> = if *is_reverse {
+                    Box::new((lower..=upper).rev())
+                } else {
+                    Box::new(lower..=upper)
+                };
+                for value in values {
+                    let mut scope = HashMap::new();
+                    scope.insert(variable_name(variable), Variable {
+                        data_type: None,
+                        value: integer_literal(value),
+                    });
+                    scopes.scopes.push(scope);
+                    let flow = self.execute_loop_body(body, scopes).await;
+                    scopes.scopes.pop();
+                    if let Some(flow) = flow? {
+                        return Ok(flow);
+                    }
+                }
+            }
+            ScriptStatement::Break { .. } => return Ok(Flow::Break),
+            ScriptStatement::Continue { .. } => return Ok(Flow::Continue),
+            ScriptStatement::Return { value, .. } => {
+                let value = match value {
+                    Some(value) => self.eval(value, scopes).await?.1,
+                    None => None,
+                };
+                return Ok(Flow::Return(value));
+            }
+            ScriptStatement::RunStatement { sql, .. } => {
+                let sql = Self::substitute_variables(sql, scopes)?;
+                self.run_sql(&sql).await?;
+            }
+        }
+        Ok(Flow::Next)
+    }
+
+    #[async_backtrace::framed]
+    async fn eval_integer(&self, expr: &Expr, scopes: &Scopes) -> Result<i64> {
+        let (data_type, text) = self.eval(expr, scopes).await?;
+        match (data_type.remove_nullable(), text) {
+            (DataType::Number(ty), Some(text)) if ty.is_integer() => {
+                text.parse::<i64>().map_err(|_| {
+                    ErrorCode::SemanticError(format!("bound `{expr}` is out of range: {text}"))
+                })
+            }
+            (data_type, _) => Err(ErrorCode::SemanticError(format!(
+                "bound `{expr}` of FOR must be a non-null integer, but got {data_type}"
+            ))),
+        }
+    }
+}
+
+fn null_literal() -> Expr {
+    Expr::Literal {
+        span: None,
+        lit: Literal::Null,
+    }
+}
+
+fn integer_literal(value: i64) -> Expr {
+    let lit = Expr::Literal {
+        span: None,
+        lit: Literal::UInt64(value.unsigned_abs()),
+    };
+    if value < 0 {
+        Expr::UnaryOp {
+            span: None,
+            op: UnaryOperator::Minus,
+            expr: Box::new(lit),
+        }
+    } else {
+        lit
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ExecuteImmediateInterpreter {
+    fn name(&self) -> &str {
+        "ExecuteImmediateInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let mut scopes = Scopes::default();
+        scopes.scopes.push(HashMap::new());
+        let result = match self
+            .execute_statements(&self.plan.script, &mut scopes)
+            .await?
+        {
+            Flow::Next => None,
+            Flow::Return(value) => value,
+            Flow::Break | Flow::Continue => {
+                return Err(ErrorCode::SemanticError(
+                    "BREAK and CONTINUE must be in a loop".to_string(),
+                ));
+            }
+        };
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_opt_data(vec![result.map(String::into_bytes)]),
+        ])])
+    }
+}
@@ -26,7 +26,9 @@ use common_profile::QueryProfileManager;
 use common_profile::SharedProcessorProfiles;
 use common_sql::executor::ProfileHelper;
 use common_sql::optimizer::ColumnSet;
+use common_sql::plan_column_lineage;
 use common_sql::MetadataRef;
+use common_sql::DUMMY_TABLE_INDEX;
 use common_storages_result_cache::gen_result_cache_key;
 use common_storages_result_cache::ResultCacheReader;
 use common_users::UserApiProvider;
@@ -41,6 +43,7 @@ use crate::schedulers::build_query_pipeline;
 use crate::schedulers::Fragmenter;
 use crate::schedulers::QueryFragmentsActions;
 use crate::sessions::QueryContext;
+use crate::sql::executor::physical_plans::TableScan;
 use crate::sql::executor::PhysicalPlan;
 use crate::sql::executor::PhysicalPlanBuilder;
 use crate::sql::optimizer::SExpr;
@@ -157,6 +160,27 @@ impl Interpreter for ExplainInterpreter {
                 }
             },
 
+            ExplainKind::Snapshot => self.explain_snapshots()?,
+
+            ExplainKind::Lineage => self.explain_lineage()?,
+
+            ExplainKind::Prune => match &self.plan {
+                Plan::Query {
+                    s_expr,
+                    metadata,
+                    bind_context,
+                    ..
+                } => {
+                    let ctx = self.ctx.clone();
+                    let mut builder = PhysicalPlanBuilder::new(metadata.clone(), ctx, true);
+                    let plan = builder.build(s_expr, bind_context.column_set()).await?;
+                    self.explain_pruning(&plan, metadata).await?
+                }
+                _ => Err(ErrorCode::Unimplemented(
+                    "Unsupported EXPLAIN PRUNE statement",
+                ))?,
+            },
+
             ExplainKind::Graph => {
                 return Err(ErrorCode::Unimplemented(
                     "ExplainKind graph is unimplemented",
@@ -181,6 +205,133 @@ impl ExplainInterpreter {
         Ok(ExplainInterpreter { ctx, plan, kind })
     }
 
+    /// Explain the snapshots which the tables of the statement are pinned to, one table
+    /// per line.
+    pub fn explain_snapshots(&self) -> Result<Vec<DataBlock>> {
+        let lines = self
+            .ctx
+            .get_pinned_snapshots()
+            .into_iter()
+            .map(|(table, snapshot_id)| format!("{table}: {snapshot_id}").into_bytes())
+            .collect::<Vec<_>>();
+        let formatted_snapshots = StringType::from_data(lines);
+        Ok(vec![DataBlock::new_from_columns(vec![formatted_snapshots])])
+    }
+
+    pub fn explain_lineage(&self) -> Result<Vec<DataBlock>> {
+        if !matches!(self.plan, Plan::Query { .. } | Plan::Insert(_)) {
+            return Err(ErrorCode::Unimplemented(
+                "Unsupported EXPLAIN LINEAGE statement",
+            ));
+        }
+        let lines = plan_column_lineage(&self.plan)?
+            .iter()
+            .map(|lineage| lineage.to_string().into_bytes())
+            .collect::<Vec<_>>();
+        let formatted_lineage = StringType::from_data(lines);
+        Ok(vec![DataBlock::new_from_columns(vec![formatted_lineage])])
+    }
+
+    /// Explain how the tables scanned by the plan are pruned, by the range index, the cluster
+    /// key and the bloom index, and how each predicate of the filters prunes by itself.
+    pub async fn explain_pruning(
+        &self,
+        plan: &PhysicalPlan,
+        metadata: &MetadataRef,
+    ) -> Result<Vec<DataBlock>> {
+        let mut scans = vec![];
+        collect_table_scans(plan, &mut scans);
+
+        let mut lines = vec![];
+        for scan in scans {
+            if scan.table_index == DUMMY_TABLE_INDEX {
+                continue;
+            }
+            let table = metadata.read().table(scan.table_index).clone();
+            let stats = &scan.source.statistics.pruning_stats;
+            let segments_cluster_pruned =
+                stats.segments_cluster_pruning_before - stats.segments_cluster_pruning_after;
+            let segments_range_pruned = stats.segments_range_pruning_before
+                - stats.segments_range_pruning_after
+                - segments_cluster_pruned;
+            let blocks_cluster_pruned =
+                stats.blocks_cluster_pruning_before - stats.blocks_cluster_pruning_after;
+            let blocks_range_pruned = stats.blocks_range_pruning_before
+                - stats.blocks_range_pruning_after
+                - blocks_cluster_pruned;
+            let blocks_bloom_pruned =
+                stats.blocks_bloom_pruning_before - stats.blocks_bloom_pruning_after;
+
+            let mut children = vec![
+                FormatTreeNode::with_children("segments".to_string(), vec![
+                    FormatTreeNode::new(format!("total: {}", stats.segments_range_pruning_before)),
+                    FormatTreeNode::new(format!("pruned by range stats: {segments_range_pruned}")),
+                    FormatTreeNode::new(format!(
+                        "pruned by cluster key: {segments_cluster_pruned}"
+                    )),
+                    FormatTreeNode::new(format!(
+                        "remaining: {}",
+                        stats.segments_range_pruning_after
+                    )),
+                ]),
+                FormatTreeNode::with_children("blocks".to_string(), vec![
+                    FormatTreeNode::new(format!("total: {}", stats.blocks_range_pruning_before)),
+                    FormatTreeNode::new(format!("pruned by range stats: {blocks_range_pruned}")),
+                    FormatTreeNode::new(format!("pruned by cluster key: {blocks_cluster_pruned}")),
+                    FormatTreeNode::new(format!("pruned by bloom index: {blocks_bloom_pruned}")),
+                    FormatTreeNode::new(format!(
+                        "remaining: {}",
+                        scan.source.statistics.partitions_scanned
+                    )),
+                ]),
+            ];
+
+            let predicates = table
+                .table()
+                .explain_pruning(self.ctx.clone(), scan.source.push_downs.clone())
+                .await?;
+            let mut unprunable = vec![];
+            for predicate in predicates.iter() {
+                let mut indexes = vec![];
+                if predicate.segments_range_pruned > 0 || predicate.blocks_range_pruned > 0 {
+                    indexes.push(format!(
+                        "range stats (segments: {}, blocks: {})",
+                        predicate.segments_range_pruned, predicate.blocks_range_pruned
+                    ));
+                }
+                if predicate.cluster_key_prunable {
+                    indexes.push("cluster key".to_string());
+                }
+                if predicate.bloom_prunable {
+                    indexes.push("bloom index".to_string());
+                }
+                if !predicate.is_prunable() {
+                    unprunable.push(FormatTreeNode::new(predicate.predicate.clone()));
+                    continue;
+                }
+                children.push(FormatTreeNode::new(format!(
+                    "predicate: {}, pruned by: {}",
+                    predicate.predicate,
+                    indexes.join(", ")
+                )));
+            }
+            if !unprunable.is_empty() {
+                children.push(FormatTreeNode::with_children(
+                    "unprunable predicates".to_string(),
+                    unprunable,
+                ));
+            }
+
+            let table_name = format!("{}.{}.{}", table.catalog(), table.database(), table.name());
+            let result =
+                FormatTreeNode::with_children(format!("TableScan: {table_name}"), children)
+                    .format_pretty()?;
+            lines.extend(result.lines().map(|line| line.to_string()));
+        }
+        let formatted_pruning = StringType::from_data(lines);
+        Ok(vec![DataBlock::new_from_columns(vec![formatted_pruning])])
+    }
+
     pub fn explain_plan(&self, plan: &Plan) -> Result<Vec<DataBlock>> {
         let result = plan.format_indent()?;
         let line_split_result: Vec<&str> = result.lines().collect();
@@ -341,3 +492,12 @@ impl ExplainInterpreter {
         Ok(vec![DataBlock::new_from_columns(vec![formatted_plan])])
     }
 }
+
+fn collect_table_scans<'a>(plan: &'a PhysicalPlan, scans: &mut Vec<&'a TableScan>) {
+    if let PhysicalPlan::TableScan(scan) = plan {
+        scans.push(scan);
+    }
+    for child in plan.children() {
+        collect_table_scans(child, scans);
+    }
+}
@@ -38,9 +38,22 @@ use crate::interpreters::interpreter_copy_into_table::CopyIntoTableInterpreter;
 use crate::interpreters::interpreter_file_format_create::CreateFileFormatInterpreter;
 use crate::interpreters::interpreter_file_format_drop::DropFileFormatInterpreter;
 use crate::interpreters::interpreter_file_format_show::ShowFileFormatsInterpreter;
+use crate::interpreters::interpreter_notification_alter::AlterNotificationInterpreter;
+use crate::interpreters::interpreter_notification_create::CreateNotificationInterpreter;
+use crate::interpreters::interpreter_notification_desc::DescNotificationInterpreter;
+use crate::interpreters::interpreter_notification_drop::DropNotificationInterpreter;
 use crate::interpreters::interpreter_presign::PresignInterpreter;
 use crate::interpreters::interpreter_role_show::ShowRolesInterpreter;
+use crate::interpreters::interpreter_secret_create::CreateSecretInterpreter;
+use crate::interpreters::interpreter_secret_desc::DescSecretInterpreter;
+use crate::interpreters::interpreter_secret_drop::DropSecretInterpreter;
+use crate::interpreters::interpreter_secret_show::ShowSecretsInterpreter;
+use crate::interpreters::interpreter_sequence_create::CreateSequenceInterpreter;
+use crate::interpreters::interpreter_sequence_drop::DropSequenceInterpreter;
+use crate::interpreters::interpreter_sequence_show::ShowSequencesInterpreter;
+use crate::interpreters::interpreter_table_clone::CloneTableInterpreter;
 use crate::interpreters::interpreter_table_create::CreateTableInterpreter;
+use crate::interpreters::interpreter_table_promote_replica::PromoteReplicaInterpreter;
 use crate::interpreters::interpreter_table_revert::RevertTableInterpreter;
 use crate::interpreters::interpreter_task_alter::AlterTaskInterpreter;
 use crate::interpreters::interpreter_task_create::CreateTaskInterpreter;
@@ -114,6 +127,9 @@ impl InterpreterFactory {
                 *plan.clone(),
                 ExplainKind::AnalyzePlan,
             )?)),
+            Plan::DescribeQuery { plan } => Ok(Arc::new(DescribeQueryInterpreter::try_create(
+                *plan.clone(),
+            )?)),
 
             Plan::CopyIntoTable(copy_plan) => Ok(Arc::new(CopyIntoTableInterpreter::try_create(
                 ctx,
@@ -165,6 +181,10 @@ impl InterpreterFactory {
                 ctx,
                 *create_table.clone(),
             )?)),
+            Plan::CloneTable(clone_table) => Ok(Arc::new(CloneTableInterpreter::try_create(
+                ctx,
+                *clone_table.clone(),
+            )?)),
             Plan::DropTable(drop_table) => Ok(Arc::new(DropTableInterpreter::try_create(
                 ctx,
                 *drop_table.clone(),
@@ -181,6 +201,9 @@ impl InterpreterFactory {
                 ctx,
                 *set_options.clone(),
             )?)),
+            Plan::PromoteReplica(promote_replica) => Ok(Arc::new(
+                PromoteReplicaInterpreter::try_create(ctx, *promote_replica.clone())?,
+            )),
             Plan::RenameTableColumn(rename_table_column) => Ok(Arc::new(
                 RenameTableColumnInterpreter::try_create(ctx, *rename_table_column.clone())?,
             )),
@@ -193,6 +216,12 @@ impl InterpreterFactory {
             Plan::DropTableColumn(drop_table_column) => Ok(Arc::new(
                 DropTableColumnInterpreter::try_create(ctx, *drop_table_column.clone())?,
             )),
+            Plan::AddTableConstraint(add_table_constraint) => Ok(Arc::new(
+                AddTableConstraintInterpreter::try_create(ctx, *add_table_constraint.clone())?,
+            )),
+            Plan::DropTableConstraint(drop_table_constraint) => Ok(Arc::new(
+                DropTableConstraintInterpreter::try_create(ctx, *drop_table_constraint.clone())?,
+            )),
             Plan::AlterTableClusterKey(alter_table_cluster_key) => Ok(Arc::new(
                 AlterTableClusterKeyInterpreter::try_create(ctx, *alter_table_cluster_key.clone())?,
             )),
@@ -398,6 +427,14 @@ impl InterpreterFactory {
                 *p.clone(),
             )?)),
             Plan::Kill(p) => Ok(Arc::new(KillInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::FlushCache(p) => Ok(Arc::new(FlushCacheInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::ExecuteImmediate(p) => Ok(Arc::new(ExecuteImmediateInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
 
             // share plans
             Plan::CreateShareEndpoint(p) => Ok(Arc::new(
@@ -488,6 +525,23 @@ impl InterpreterFactory {
             )?)),
             Plan::ShowTasks(p) => Ok(Arc::new(ShowTasksInterpreter::try_create(ctx, *p.clone())?)),
 
+            Plan::CreateNotification(p) => Ok(Arc::new(CreateNotificationInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::AlterNotification(p) => Ok(Arc::new(AlterNotificationInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DropNotification(p) => Ok(Arc::new(DropNotificationInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DescNotification(p) => Ok(Arc::new(DescNotificationInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+
             Plan::CreateConnection(p) => Ok(Arc::new(CreateConnectionInterpreter::try_create(
                 ctx,
                 *p.clone(),
@@ -501,6 +555,30 @@ impl InterpreterFactory {
                 *p.clone(),
             )?)),
             Plan::ShowConnections(_) => Ok(Arc::new(ShowConnectionsInterpreter::try_create(ctx)?)),
+
+            Plan::CreateSequence(p) => Ok(Arc::new(CreateSequenceInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DropSequence(p) => Ok(Arc::new(DropSequenceInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::ShowSequences(_) => Ok(Arc::new(ShowSequencesInterpreter::try_create(ctx)?)),
+
+            Plan::CreateSecret(p) => Ok(Arc::new(CreateSecretInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DropSecret(p) => Ok(Arc::new(DropSecretInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DescSecret(p) => Ok(Arc::new(DescSecretInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::ShowSecrets(_) => Ok(Arc::new(ShowSecretsInterpreter::try_create(ctx)?)),
         }
     }
 }
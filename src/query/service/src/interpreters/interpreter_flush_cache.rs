@@ -0,0 +1,131 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_config::GlobalConfig;
+use common_exception::Result;
+use common_sql::plans::FlushCacheKind;
+use common_sql::plans::FlushCachePlan;
+use common_sql::PlanCache;
+use common_storage::DataOperator;
+use common_storages_result_cache::gen_result_cache_prefix;
+use common_storages_result_cache::ResultCacheMetaManager;
+use common_users::UserApiProvider;
+use log::info;
+use storages_common_cache_manager::CacheManager;
+
+use crate::api::FlushCachePacket;
+use crate::api::Packet;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+pub struct FlushCacheInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: FlushCachePlan,
+    proxy_to_cluster: bool,
+}
+
+impl FlushCacheInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: FlushCachePlan) -> Result<Self> {
+        Ok(FlushCacheInterpreter {
+            ctx,
+            plan,
+            proxy_to_cluster: true,
+        })
+    }
+
+    pub fn from_flight(ctx: Arc<QueryContext>, packet: FlushCachePacket) -> Result<Self> {
+        Ok(FlushCacheInterpreter {
+            ctx,
+            plan: FlushCachePlan { kind: packet.kind },
+            proxy_to_cluster: false,
+        })
+    }
+
+    /// The result cache is kept in the meta service and the storage, so it is shared by
+    /// the nodes of the tenant and only flushed once.
+    #[async_backtrace::framed]
+    async fn flush_result_cache(&self) -> Result<()> {
+        let meta_client = UserApiProvider::instance().get_meta_store_client();
+        let result_cache_mgr = ResultCacheMetaManager::create(meta_client, 0);
+        let prefix = gen_result_cache_prefix(&self.ctx.get_tenant());
+        let removed = result_cache_mgr.remove_all(prefix.as_str()).await?;
+
+        let operator = DataOperator::instance().operator();
+        for value in removed.iter() {
+            operator.delete(&value.location).await?;
+        }
+        info!("Flushed {} entries of the result cache", removed.len());
+        Ok(())
+    }
+
+    fn flush_local_caches(&self) {
+        let kind = self.plan.kind;
+        if matches!(kind, FlushCacheKind::All | FlushCacheKind::Plan) {
+            PlanCache::instance().clear();
+        }
+        if matches!(kind, FlushCacheKind::All | FlushCacheKind::Meta) {
+            CacheManager::instance().clear_table_meta_caches();
+        }
+        if matches!(kind, FlushCacheKind::All | FlushCacheKind::Data) {
+            CacheManager::instance().clear_table_data_caches();
+        }
+        info!("Flushed the {:?} caches of the local node", kind);
+    }
+
+    #[async_backtrace::framed]
+    async fn flush_cluster_caches(&self) -> Result<()> {
+        let settings = self.ctx.get_settings();
+        let timeout = settings.get_flight_client_timeout()?;
+        let conf = GlobalConfig::instance();
+        let cluster = self.ctx.get_cluster();
+        for node_info in &cluster.nodes {
+            if node_info.id != cluster.local_id {
+                let flush_cache_packet =
+                    FlushCachePacket::create(self.plan.kind, node_info.clone());
+                flush_cache_packet.commit(conf.as_ref(), timeout).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for FlushCacheInterpreter {
+    fn name(&self) -> &str {
+        "FlushCacheInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        if self.proxy_to_cluster
+            && matches!(self.plan.kind, FlushCacheKind::All | FlushCacheKind::Result)
+        {
+            self.flush_result_cache().await?;
+        }
+
+        if self.plan.kind.flush_local_caches() {
+            self.flush_local_caches();
+            if self.proxy_to_cluster {
+                self.flush_cluster_caches().await?;
+            }
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -32,8 +32,13 @@ use common_sql::NameResolutionContext;
 
 use crate::interpreters::common::build_update_stream_meta_seq;
 use crate::interpreters::common::check_deduplicate_label;
+use crate::interpreters::common::check_storage_quota;
+use crate::interpreters::common::hook_quarantine;
 use crate::interpreters::common::hook_refresh_agg_index;
+use crate::interpreters::common::hook_replicate_table;
+use crate::interpreters::common::QuarantineTableDesc;
 use crate::interpreters::common::RefreshAggIndexDesc;
+use crate::interpreters::common::ReplicateTableDesc;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::pipelines::processors::transforms::TransformRuntimeCastSchema;
@@ -84,6 +89,8 @@ impl Interpreter for InsertInterpreter {
         if check_deduplicate_label(self.ctx.clone()).await? {
             return Ok(PipelineBuildResult::create());
         }
+        check_storage_quota(&self.ctx).await?;
+
         let table = self
             .ctx
             .get_table(&self.plan.catalog, &self.plan.database, &self.plan.table)
@@ -144,9 +151,11 @@ impl Interpreter for InsertInterpreter {
             }
             InsertInputSource::StreamingWithFileFormat {
                 format,
+                on_error_mode,
                 input_context_option: input_context,
                 ..
             } => {
+                self.ctx.set_on_error_mode(on_error_mode.clone());
                 let input_context = input_context.as_ref().expect("must success").clone();
                 input_context
                     .format
@@ -260,6 +269,16 @@ impl Interpreter for InsertInterpreter {
                 )
                 .await?;
 
+                hook_replicate_table(
+                    self.ctx.clone(),
+                    &mut build_res.main_pipeline,
+                    ReplicateTableDesc {
+                        catalog: self.plan.catalog.clone(),
+                        database: self.plan.database.clone(),
+                        table: self.plan.table.clone(),
+                    },
+                );
+
                 return Ok(build_res);
             }
         };
@@ -281,6 +300,17 @@ impl Interpreter for InsertInterpreter {
             append_mode,
         )?;
 
+        // Write the skipped records into the quarantine table if `ON_ERROR = QUARANTINE`.
+        hook_quarantine(
+            self.ctx.clone(),
+            &mut build_res.main_pipeline,
+            QuarantineTableDesc {
+                catalog: self.plan.catalog.clone(),
+                database: self.plan.database.clone(),
+                table: self.plan.table.clone(),
+            },
+        );
+
         let refresh_agg_index_desc = RefreshAggIndexDesc {
             catalog: self.plan.catalog.clone(),
             database: self.plan.database.clone(),
@@ -294,6 +324,16 @@ impl Interpreter for InsertInterpreter {
         )
         .await?;
 
+        hook_replicate_table(
+            self.ctx.clone(),
+            &mut build_res.main_pipeline,
+            ReplicateTableDesc {
+                catalog: self.plan.catalog.clone(),
+                database: self.plan.database.clone(),
+                table: self.plan.table.clone(),
+            },
+        );
+
         Ok(build_res)
     }
 }
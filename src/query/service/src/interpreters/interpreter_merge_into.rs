@@ -51,11 +51,15 @@ use common_storages_fuse::TableContext;
 use itertools::Itertools;
 use storages_common_locks::LockManager;
 use storages_common_table_meta::meta::TableSnapshot;
+use storages_common_table_meta::table::CryptoShredColumns;
 
 use crate::interpreters::common::build_update_stream_meta_seq;
+use crate::interpreters::common::check_storage_quota;
 use crate::interpreters::common::hook_compact;
+use crate::interpreters::common::hook_replicate_table;
 use crate::interpreters::common::CompactHookTraceCtx;
 use crate::interpreters::common::CompactTargetTableDescription;
+use crate::interpreters::common::ReplicateTableDesc;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::pipelines::PipelineBuildResult;
@@ -84,6 +88,8 @@ impl Interpreter for MergeIntoInterpreter {
 
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
+        check_storage_quota(&self.ctx).await?;
+
         let start = Instant::now();
         let (physical_plan, table_info) = self.build_physical_plan().await?;
         let mut build_res =
@@ -118,6 +124,17 @@ impl Interpreter for MergeIntoInterpreter {
             .await;
         }
 
+        // Replicate the new snapshot if replication is enabled.
+        hook_replicate_table(
+            self.ctx.clone(),
+            &mut build_res.main_pipeline,
+            ReplicateTableDesc {
+                catalog: self.plan.catalog.clone(),
+                database: self.plan.database.clone(),
+                table: self.plan.table.clone(),
+            },
+        );
+
         Ok(build_res)
     }
 }
@@ -152,6 +169,13 @@ impl MergeIntoInterpreter {
                 check_table.name(),
             )));
         }
+        // The matched rows would be written back with the decrypted values.
+        if CryptoShredColumns::from_options(check_table.options())?.is_some() {
+            return Err(ErrorCode::Unimplemented(format!(
+                "table '{}' has crypto-shredded columns, does not support MERGE INTO",
+                check_table.name(),
+            )));
+        }
 
         let update_stream_meta = build_update_stream_meta_seq(self.ctx.clone(), meta_data).await?;
 
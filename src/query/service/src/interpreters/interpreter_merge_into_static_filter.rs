@@ -301,6 +301,9 @@ impl MergeIntoInterpreter {
             RelOperator::Pattern(_) => {}
             RelOperator::AddRowNumber(_) => {}
             RelOperator::Udf(_) => {}
+            RelOperator::GapFill(_) => {}
+            RelOperator::LimitBy(_) => {}
+            RelOperator::ConnectBy(_) => {}
         }
         Ok(())
     }
@@ -0,0 +1,86 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_cloud_control::cloud_api::CloudControlApiProvider;
+use common_cloud_control::pb::CreateNotificationRequest;
+use common_cloud_control::task_client::make_request;
+use common_config::GlobalConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_sql::plans::CreateNotificationPlan;
+
+use crate::interpreters::common::get_client_config;
+use crate::interpreters::common::make_notification_target;
+use crate::interpreters::common::make_retry_policy;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+#[derive(Debug)]
+pub struct CreateNotificationInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateNotificationPlan,
+}
+
+impl CreateNotificationInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateNotificationPlan) -> Result<Self> {
+        Ok(CreateNotificationInterpreter { ctx, plan })
+    }
+}
+
+impl CreateNotificationInterpreter {
+    fn build_request(&self) -> CreateNotificationRequest {
+        let plan = self.plan.clone();
+        let (notification_type, webhook, email) = make_notification_target(plan.target);
+        CreateNotificationRequest {
+            tenant_id: plan.tenant,
+            name: plan.name,
+            notification_type: notification_type as i32,
+            enabled: plan.enabled,
+            if_not_exists: plan.if_not_exists,
+            webhook,
+            email,
+            retry_policy: Some(make_retry_policy(plan.retry_policy)),
+            comments: Some(plan.comments),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateNotificationInterpreter {
+    fn name(&self) -> &str {
+        "CreateNotificationInterpreter"
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let config = GlobalConfig::instance();
+        if config.query.cloud_control_grpc_server_address.is_none() {
+            return Err(ErrorCode::CloudControlNotEnabled(
+                "cannot create notification integration without cloud control enabled, please set cloud_control_grpc_server_address in config",
+            ));
+        }
+        let cloud_api = CloudControlApiProvider::instance();
+        let notification_client = cloud_api.get_notification_client();
+        let req = self.build_request();
+        let config = get_client_config(self.ctx.clone())?;
+        let req = make_request(req, config);
+        notification_client.create_notification(req).await?;
+        Ok(PipelineBuildResult::create())
+    }
+}
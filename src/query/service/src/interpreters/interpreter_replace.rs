@@ -49,9 +49,12 @@ use storages_common_table_meta::meta::TableSnapshot;
 
 use crate::interpreters::common::build_update_stream_meta_seq;
 use crate::interpreters::common::check_deduplicate_label;
+use crate::interpreters::common::check_storage_quota;
 use crate::interpreters::common::hook_compact;
+use crate::interpreters::common::hook_replicate_table;
 use crate::interpreters::common::CompactHookTraceCtx;
 use crate::interpreters::common::CompactTargetTableDescription;
+use crate::interpreters::common::ReplicateTableDesc;
 use crate::interpreters::interpreter_copy_into_table::CopyIntoTableInterpreter;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
@@ -83,6 +86,7 @@ impl Interpreter for ReplaceInterpreter {
         if check_deduplicate_label(self.ctx.clone()).await? {
             return Ok(PipelineBuildResult::create());
         }
+        check_storage_quota(&self.ctx).await?;
 
         self.check_on_conflicts()?;
         let start = Instant::now();
@@ -127,6 +131,17 @@ impl Interpreter for ReplaceInterpreter {
             .await;
         }
 
+        // Replicate the new snapshot if replication is enabled.
+        hook_replicate_table(
+            self.ctx.clone(),
+            &mut pipeline.main_pipeline,
+            ReplicateTableDesc {
+                catalog: self.plan.catalog.clone(),
+                database: self.plan.database.clone(),
+                table: self.plan.table.clone(),
+            },
+        );
+
         Ok(pipeline)
     }
 }
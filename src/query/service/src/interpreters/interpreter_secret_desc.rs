@@ -0,0 +1,65 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::types::UInt64Type;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_sql::plans::DescSecretPlan;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct DescSecretInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DescSecretPlan,
+}
+
+impl DescSecretInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DescSecretPlan) -> Result<Self> {
+        Ok(DescSecretInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DescSecretInterpreter {
+    fn name(&self) -> &str {
+        "DescSecretInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let user_mgr = UserApiProvider::instance();
+
+        // Only where to find the value is shown, the value itself is never read here.
+        let secret = user_mgr
+            .get_secret(&tenant, self.plan.name.as_str())
+            .await?;
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(vec![secret.name.as_bytes().to_vec()]),
+            StringType::from_data(vec![secret.provider.as_bytes().to_vec()]),
+            StringType::from_data(vec![secret.provider_params_display().as_bytes().to_vec()]),
+            UInt64Type::from_data(vec![secret.cache_ttl_secs]),
+        ])])
+    }
+}
@@ -0,0 +1,79 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::types::UInt64Type;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_users::UserApiProvider;
+use log::debug;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct ShowSecretsInterpreter {
+    ctx: Arc<QueryContext>,
+}
+
+impl ShowSecretsInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>) -> Result<Self> {
+        Ok(ShowSecretsInterpreter { ctx })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ShowSecretsInterpreter {
+    fn name(&self) -> &str {
+        "ShowSecretsInterpreter"
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "show_secrets_execute");
+
+        let user_mgr = UserApiProvider::instance();
+        let tenant = self.ctx.get_tenant();
+        let mut secrets = user_mgr.get_secrets(&tenant).await?;
+
+        secrets.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let names = secrets
+            .iter()
+            .map(|x| x.name.as_bytes().to_vec())
+            .collect::<Vec<_>>();
+        let providers = secrets
+            .iter()
+            .map(|x| x.provider.as_bytes().to_vec())
+            .collect::<Vec<_>>();
+        let params = secrets
+            .iter()
+            .map(|x| x.provider_params_display().as_bytes().to_vec())
+            .collect::<Vec<_>>();
+        let cache_ttls = secrets.iter().map(|x| x.cache_ttl_secs).collect::<Vec<_>>();
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_data(providers),
+            StringType::from_data(params),
+            UInt64Type::from_data(cache_ttls),
+        ])])
+    }
+}
@@ -0,0 +1,90 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::number::UInt64Type;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_users::UserApiProvider;
+use log::debug;
+use storages_common_table_meta::table::AUTO_INCREMENT_SEQUENCE_PREFIX;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct ShowSequencesInterpreter {
+    ctx: Arc<QueryContext>,
+}
+
+impl ShowSequencesInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>) -> Result<Self> {
+        Ok(ShowSequencesInterpreter { ctx })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ShowSequencesInterpreter {
+    fn name(&self) -> &str {
+        "ShowSequencesInterpreter"
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "show_sequences_execute");
+
+        let tenant = self.ctx.get_tenant();
+        // the implicit sequences of AUTO_INCREMENT columns are managed with their tables.
+        let mut sequences = UserApiProvider::instance()
+            .get_sequences(&tenant)
+            .await?
+            .into_iter()
+            .filter(|x| !x.name.starts_with(AUTO_INCREMENT_SEQUENCE_PREFIX))
+            .collect::<Vec<_>>();
+        sequences.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let names = sequences
+            .iter()
+            .map(|x| x.name.as_bytes().to_vec())
+            .collect::<Vec<_>>();
+        let starts = sequences.iter().map(|x| x.start).collect::<Vec<_>>();
+        let increments = sequences.iter().map(|x| x.increment).collect::<Vec<_>>();
+        let caches = sequences.iter().map(|x| x.cache).collect::<Vec<_>>();
+        let next_values = sequences.iter().map(|x| x.next_value).collect::<Vec<_>>();
+        let comments = sequences
+            .iter()
+            .map(|x| x.comment.clone().unwrap_or_default().into_bytes())
+            .collect::<Vec<_>>();
+        let created_ons = sequences
+            .iter()
+            .map(|x| x.create_on.timestamp_micros())
+            .collect::<Vec<_>>();
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            UInt64Type::from_data(starts),
+            UInt64Type::from_data(increments),
+            UInt64Type::from_data(caches),
+            UInt64Type::from_data(next_values),
+            StringType::from_data(comments),
+            TimestampType::from_data(created_ons),
+        ])])
+    }
+}
@@ -70,9 +70,10 @@ impl Interpreter for ShowGrantsInterpreter {
                 }
             },
         };
-        // TODO: display roles list instead of the inherited roles
-        let grant_list = RoleCacheManager::instance()
-            .find_related_roles(&tenant, &grant_set.roles())
+        let role_cache = RoleCacheManager::instance();
+        let roles = grant_set.roles();
+        let mut grant_list = role_cache
+            .find_related_roles(&tenant, &roles)
             .await?
             .into_iter()
             .map(|role| role.grants)
@@ -82,6 +83,20 @@ impl Interpreter for ShowGrantsInterpreter {
             .map(|e| format!("{} TO {}", e, identity).as_bytes().to_vec())
             .collect::<Vec<_>>();
 
+        // the privileges above include the inherited ones, followed by the role graph they
+        // are inherited through.
+        for (role, grantee) in role_cache.find_role_grants(&tenant, &roles).await? {
+            let grantee = match grantee {
+                Some(grantee) => format!("ROLE `{}`", grantee),
+                None => identity.clone(),
+            };
+            grant_list.push(
+                format!("GRANT ROLE `{}` TO {}", role, grantee)
+                    .as_bytes()
+                    .to_vec(),
+            );
+        }
+
         PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
             StringType::from_data(grant_list),
         ])])
@@ -0,0 +1,155 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table::TableExt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::DatabaseType;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::AddTableConstraintPlan;
+use common_sql::Planner;
+use common_storages_share::save_share_table_info;
+use common_storages_stream::stream_table::STREAM_ENGINE;
+use common_storages_view::view_table::VIEW_ENGINE;
+use futures_util::TryStreamExt;
+use storages_common_table_meta::table::TableConstraint;
+use storages_common_table_meta::table::TableConstraints;
+use storages_common_table_meta::table::OPT_KEY_CONSTRAINTS;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct AddTableConstraintInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AddTableConstraintPlan,
+}
+
+impl AddTableConstraintInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AddTableConstraintPlan) -> Result<Self> {
+        Ok(AddTableConstraintInterpreter { ctx, plan })
+    }
+
+    /// The existing rows must satisfy the CHECK constraint to be added.
+    #[async_backtrace::framed]
+    async fn validate_existing_rows(&self, expr: &str) -> Result<()> {
+        let sql = format!(
+            "SELECT 1 FROM `{}`.`{}`.`{}` WHERE NOT ({}) LIMIT 1",
+            self.plan.catalog, self.plan.database, self.plan.table, expr
+        );
+        let mut planner = Planner::new(self.ctx.clone());
+        let (plan, _) = planner.plan_sql(&sql).await?;
+        let interpreter = InterpreterFactory::get(self.ctx.clone(), &plan).await?;
+        let blocks = interpreter
+            .execute(self.ctx.clone())
+            .await?
+            .try_collect::<Vec<_>>()
+            .await?;
+        if blocks.iter().any(|block| !block.is_empty()) {
+            return Err(ErrorCode::CheckConstraintViolated(format!(
+                "existing rows of table {}.{} violate check constraint {}: {}",
+                self.plan.database, self.plan.table, self.plan.name, expr
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AddTableConstraintInterpreter {
+    fn name(&self) -> &str {
+        "AddTableConstraintInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog_name = self.plan.catalog.as_str();
+        let db_name = self.plan.database.as_str();
+        let tbl_name = self.plan.table.as_str();
+        let table = self
+            .ctx
+            .get_catalog(catalog_name)
+            .await?
+            .get_table(self.ctx.get_tenant().as_str(), db_name, tbl_name)
+            .await?;
+
+        // check mutability
+        table.check_mutable()?;
+
+        let table_info = table.get_table_info();
+        let engine = table_info.engine();
+        if matches!(engine, VIEW_ENGINE | STREAM_ENGINE) {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} engine is {} that doesn't support alter",
+                &self.plan.database, &self.plan.table, engine
+            )));
+        }
+        if table_info.db_type != DatabaseType::NormalDB {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} doesn't support alter",
+                &self.plan.database, &self.plan.table
+            )));
+        }
+
+        let mut constraints = TableConstraints::from_options(table_info.options())?;
+        if constraints.get(&self.plan.name).is_some() {
+            return Err(ErrorCode::ConstraintAlreadyExists(format!(
+                "constraint {} already exists",
+                self.plan.name
+            )));
+        }
+        if let TableConstraint::Check { expr, .. } = &self.plan.constraint {
+            self.validate_existing_rows(expr).await?;
+        }
+        constraints
+            .0
+            .insert(self.plan.name.clone(), self.plan.constraint.clone());
+
+        let catalog = self.ctx.get_catalog(catalog_name).await?;
+        let mut new_table_meta = table_info.meta.clone();
+        new_table_meta.options.insert(
+            OPT_KEY_CONSTRAINTS.to_owned(),
+            constraints.to_option_value()?,
+        );
+
+        let table_id = table_info.ident.table_id;
+        let table_version = table_info.ident.seq;
+
+        let req = UpdateTableMetaReq {
+            table_id,
+            seq: MatchSeq::Exact(table_version),
+            new_table_meta,
+            copied_files: None,
+            deduplicated_label: None,
+            update_stream_meta: vec![],
+        };
+
+        let res = catalog.update_table_meta(table_info, req).await?;
+        if let Some(share_table_info) = res.share_table_info {
+            save_share_table_info(
+                &self.ctx.get_tenant(),
+                self.ctx.get_data_operator()?.operator(),
+                share_table_info,
+            )
+            .await?;
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,195 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::GrantObjectByID;
+use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::DropTableByIdReq;
+use common_meta_app::schema::Ownership;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TableNameIdent;
+use common_sql::plans::CloneTablePlan;
+use common_storages_fuse::FuseTable;
+use common_users::UserApiProvider;
+use storages_common_table_meta::table::OPT_KEY_CLONED_FROM;
+use storages_common_table_meta::table::OPT_KEY_CLONE_SHARED_SNAPSHOTS;
+use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
+use storages_common_table_meta::table::OPT_KEY_REPLICATION_CONNECTION;
+use storages_common_table_meta::table::OPT_KEY_REPLICATION_URI;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CloneTableInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CloneTablePlan,
+}
+
+impl CloneTableInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CloneTablePlan) -> Result<Self> {
+        Ok(CloneTableInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CloneTableInterpreter {
+    fn name(&self) -> &str {
+        "CloneTableInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        if self.plan.catalog != self.plan.source_catalog {
+            return Err(ErrorCode::Unimplemented(
+                "clone table across catalogs is not supported",
+            ));
+        }
+        let catalog = self.ctx.get_catalog(self.plan.catalog.as_str()).await?;
+
+        if self.plan.if_not_exists
+            && catalog
+                .exists_table(tenant.as_str(), &self.plan.database, &self.plan.table)
+                .await?
+        {
+            return Ok(PipelineBuildResult::create());
+        }
+
+        let source = catalog
+            .get_table(
+                tenant.as_str(),
+                &self.plan.source_database,
+                &self.plan.source_table,
+            )
+            .await?;
+        let fuse_table = FuseTable::try_from_table(source.as_ref())?;
+        fuse_table.check_clone_source()?;
+        let snapshot_location = fuse_table.snapshot_loc().await?;
+
+        let db = catalog
+            .get_database(tenant.as_str(), &self.plan.database)
+            .await?;
+        let db_id = db.get_db_info().ident.db_id;
+
+        let source_meta = &source.get_table_info().meta;
+        let mut options = source_meta.options.clone();
+        for key in [
+            OPT_KEY_SNAPSHOT_LOCATION,
+            OPT_KEY_LEGACY_SNAPSHOT_LOC,
+            OPT_KEY_CLONE_SHARED_SNAPSHOTS,
+            OPT_KEY_REPLICATION_URI,
+            OPT_KEY_REPLICATION_CONNECTION,
+        ] {
+            options.remove(key);
+        }
+        options.insert(OPT_KEY_DATABASE_ID.to_owned(), db_id.to_string());
+        options.insert(OPT_KEY_CLONED_FROM.to_owned(), source.get_id().to_string());
+        let statistics = match &snapshot_location {
+            Some(location) => {
+                options.insert(OPT_KEY_SNAPSHOT_LOCATION.to_owned(), location.clone());
+                source_meta.statistics.clone()
+            }
+            None => Default::default(),
+        };
+
+        let table_meta = TableMeta {
+            schema: source_meta.schema.clone(),
+            engine: source_meta.engine.clone(),
+            options,
+            default_cluster_key: source_meta.default_cluster_key.clone(),
+            cluster_keys: source_meta.cluster_keys.clone(),
+            default_cluster_key_id: source_meta.default_cluster_key_id,
+            comment: source_meta.comment.clone(),
+            field_comments: source_meta.field_comments.clone(),
+            statistics,
+            owner: self
+                .ctx
+                .get_current_role()
+                .map(|role| Ownership::new(role.name)),
+            ..Default::default()
+        };
+
+        // Pin the snapshot of the source table before creating the clone, the source table will
+        // no longer purge the files of this snapshot until the clone releases it.
+        let pending_id = match &snapshot_location {
+            Some(location) => Some(
+                fuse_table
+                    .pin_snapshot_for_clone(self.ctx.as_ref(), location)
+                    .await?,
+            ),
+            None => None,
+        };
+
+        let req = CreateTableReq {
+            if_not_exists: self.plan.if_not_exists,
+            name_ident: TableNameIdent {
+                tenant: tenant.clone(),
+                db_name: self.plan.database.clone(),
+                table_name: self.plan.table.clone(),
+            },
+            table_meta,
+        };
+        let reply = match catalog.create_table(req).await {
+            Ok(reply) if reply.new_table => reply,
+            res => {
+                if let Some(pending_id) = pending_id {
+                    let _ = fuse_table
+                        .release_clone_pin(self.ctx.as_ref(), pending_id)
+                        .await;
+                }
+                return res.map(|_| PipelineBuildResult::create());
+            }
+        };
+
+        if let Some(pending_id) = pending_id {
+            if let Err(e) = fuse_table
+                .confirm_clone_pin(self.ctx.as_ref(), pending_id, reply.table_id)
+                .await
+            {
+                let _ = catalog
+                    .drop_table_by_id(DropTableByIdReq {
+                        if_exists: true,
+                        tenant: tenant.clone(),
+                        tb_id: reply.table_id,
+                    })
+                    .await;
+                return Err(e);
+            }
+        }
+
+        // grant the ownership of the table to the current role.
+        if let Some(current_role) = self.ctx.get_current_role() {
+            let role_api = UserApiProvider::instance().get_role_api_client(&tenant)?;
+            role_api
+                .grant_ownership(
+                    &GrantObjectByID::Table {
+                        catalog_name: self.plan.catalog.clone(),
+                        db_id,
+                        table_id: reply.table_id,
+                    },
+                    &current_role.name,
+                )
+                .await?;
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -16,53 +16,87 @@ use std::collections::BTreeMap;
 use std::collections::HashSet;
 use std::sync::Arc;
 
+use chrono::Utc;
+use common_catalog::catalog::Catalog;
 use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::ComputedExpr;
+use common_expression::TableSchema;
 use common_expression::TableSchemaRef;
 use common_expression::TableSchemaRefExt;
 use common_expression::BLOCK_NAME_COL_NAME;
+use common_expression::FILE_NAME_COL_NAME;
 use common_expression::ROW_ID_COL_NAME;
 use common_expression::SEGMENT_NAME_COL_NAME;
 use common_expression::SNAPSHOT_NAME_COL_NAME;
 use common_io::constants::DEFAULT_BLOCK_MAX_ROWS;
 use common_license::license::Feature::ComputedColumn;
+use common_license::license::Feature::DataMask;
 use common_license::license_manager::get_license_manager;
 use common_management::RoleApi;
 use common_meta_app::principal::GrantObjectByID;
+use common_meta_app::principal::UserDefinedSequence;
 use common_meta_app::schema::CreateTableReq;
 use common_meta_app::schema::Ownership;
+use common_meta_app::schema::SetTableColumnMaskPolicyAction;
+use common_meta_app::schema::SetTableColumnMaskPolicyReq;
 use common_meta_app::schema::TableMeta;
 use common_meta_app::schema::TableNameIdent;
 use common_meta_app::schema::TableStatistics;
+use common_meta_app::schema::OPT_KEY_DATA_RETENTION_TIME_IN_DAYS;
 use common_meta_types::MatchSeq;
+use common_sql::binder::parse_replication_location;
 use common_sql::field_default_value;
+use common_sql::parse_row_ttl_to_string;
 use common_sql::plans::CreateTablePlan;
 use common_sql::plans::PREDICATE_COLUMN_NAME;
 use common_sql::BloomIndexColumns;
 use common_storage::DataOperator;
 use common_storages_fuse::io::MetaReaders;
+use common_storages_fuse::operations::CompactAggregator;
 use common_storages_fuse::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use common_storages_fuse::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
+use common_storages_fuse::FUSE_OPT_KEY_BUCKET_NUM;
 use common_storages_fuse::FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD;
 use common_storages_fuse::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use common_storages_fuse::FUSE_OPT_KEY_ROW_PER_PAGE;
 use common_storages_fuse::FUSE_TBL_LAST_SNAPSHOT_HINT;
+use common_storages_share::save_share_table_info;
 use common_users::UserApiProvider;
+use data_mask_feature::get_datamask_handler;
 use log::error;
 use once_cell::sync::Lazy;
 use storages_common_cache::LoadParams;
 use storages_common_index::BloomIndex;
+use storages_common_index::Index;
+use storages_common_index::PointLookupIndex;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::Versioned;
+use storages_common_table_meta::table::AggregatingColumns;
+use storages_common_table_meta::table::CryptoShredColumns;
+use storages_common_table_meta::table::DedupColumns;
+use storages_common_table_meta::table::SequenceColumns;
+use storages_common_table_meta::table::OPT_KEY_AGGREGATING_FUNCTIONS;
+use storages_common_table_meta::table::OPT_KEY_AGGREGATING_KEYS;
 use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 use storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
 use storages_common_table_meta::table::OPT_KEY_COMMENT;
+use storages_common_table_meta::table::OPT_KEY_CONSTRAINTS;
+use storages_common_table_meta::table::OPT_KEY_CRYPTO_SHRED_COLUMNS;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_DEDUP_KEYS;
+use storages_common_table_meta::table::OPT_KEY_DEDUP_VERSION_COLUMN;
 use storages_common_table_meta::table::OPT_KEY_ENGINE;
+use storages_common_table_meta::table::OPT_KEY_POINT_LOOKUP_COLUMN;
+use storages_common_table_meta::table::OPT_KEY_REPLICATION_CONNECTION;
+use storages_common_table_meta::table::OPT_KEY_REPLICATION_URI;
+use storages_common_table_meta::table::OPT_KEY_ROW_TTL;
+use storages_common_table_meta::table::OPT_KEY_SEQUENCE_COLUMNS;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
+use storages_common_table_meta::table::OPT_KEY_SUBJECT_KEY_COLUMN;
 use storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_READ_ONLY;
 use storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
 
@@ -76,6 +110,9 @@ use crate::sql::plans::insert::InsertInputSource;
 use crate::sql::plans::Plan;
 use crate::storages::StorageDescription;
 
+/// Number of values a query node reserves at a time for an `AUTO_INCREMENT` column.
+const AUTO_INCREMENT_SEQUENCE_CACHE: u64 = 1000;
+
 pub struct CreateTableInterpreter {
     ctx: Arc<QueryContext>,
     plan: CreateTablePlan,
@@ -146,6 +183,9 @@ impl Interpreter for CreateTableInterpreter {
             }
         }
 
+        self.check_sequences_exist().await?;
+        self.check_column_mask_policies().await?;
+
         match &self.plan.as_select {
             Some(select_plan_node) => self.create_table_as_select(select_plan_node.clone()).await,
             None => self.create_table().await,
@@ -169,6 +209,8 @@ impl CreateTableInterpreter {
         if !reply.new_table {
             return Ok(PipelineBuildResult::create());
         }
+        self.create_auto_increment_sequences().await?;
+        self.set_column_mask_policies(catalog.clone()).await?;
 
         let table = catalog
             .get_table(tenant.as_str(), &self.plan.database, &self.plan.table)
@@ -246,6 +288,7 @@ impl CreateTableInterpreter {
                 });
             }
         }
+        is_valid_replication_options(self.ctx.clone(), &self.plan.options).await?;
         let mut req = if let Some(storage_prefix) = self.plan.options.get(OPT_KEY_STORAGE_PREFIX) {
             self.build_attach_request(storage_prefix).await
         } else {
@@ -257,6 +300,10 @@ impl CreateTableInterpreter {
         }
 
         let reply = catalog.create_table(req.clone()).await?;
+        if reply.new_table {
+            self.create_auto_increment_sequences().await?;
+            self.set_column_mask_policies(catalog.clone()).await?;
+        }
 
         // grant the ownership of the table to the current role, the above req.table_meta.owner could be removed in future.
         if let Some(current_role) = self.ctx.get_current_role() {
@@ -282,6 +329,113 @@ impl CreateTableInterpreter {
         Ok(PipelineBuildResult::create())
     }
 
+    /// Checks the sequences referenced by `DEFAULT NEXTVAL(<sequence>)` columns exist.
+    #[async_backtrace::framed]
+    async fn check_sequences_exist(&self) -> Result<()> {
+        let sequence_columns = SequenceColumns::from_options(&self.plan.options)?;
+        for column in sequence_columns.0.values() {
+            if !column.auto_increment {
+                UserApiProvider::instance()
+                    .get_sequence(&self.plan.tenant, &column.sequence)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks the masking policies bound by `MASKING POLICY <policy>` columns exist
+    /// and match the column types.
+    #[async_backtrace::framed]
+    async fn check_column_mask_policies(&self) -> Result<()> {
+        let Some(column_mask_policy) = &self.plan.column_mask_policy else {
+            return Ok(());
+        };
+        let license_manager = get_license_manager();
+        license_manager
+            .manager
+            .check_enterprise_enabled(self.ctx.get_license_key(), DataMask)?;
+
+        let handler = get_datamask_handler();
+        for (column, mask_name) in column_mask_policy {
+            let meta_api = UserApiProvider::instance().get_meta_store_client();
+            let policy = handler
+                .get_data_mask(meta_api, self.plan.tenant.clone(), mask_name.clone())
+                .await?;
+
+            let policy_data_type = policy.args[0].1.to_string().to_lowercase();
+            let field =
+                self.plan.schema.field_with_name(column).map_err(|_| {
+                    ErrorCode::UnknownColumn(format!("Cannot find column {}", column))
+                })?;
+            let data_type = field.data_type().to_string().to_lowercase();
+            if data_type != policy_data_type {
+                return Err(ErrorCode::UnmatchColumnDataType(format!(
+                    "Column '{}' data type {} does not match to the mask policy type {}",
+                    column, data_type, policy_data_type,
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Binds the masking policies of the columns to the newly created table.
+    #[async_backtrace::framed]
+    async fn set_column_mask_policies(&self, catalog: Arc<dyn Catalog>) -> Result<()> {
+        let Some(column_mask_policy) = &self.plan.column_mask_policy else {
+            return Ok(());
+        };
+        let tenant = self.plan.tenant.clone();
+        for (column, mask_name) in column_mask_policy {
+            // Each update bumps the table version, so reload the table before the next one.
+            let table = catalog
+                .get_table(tenant.as_str(), &self.plan.database, &self.plan.table)
+                .await?;
+            let table_info = table.get_table_info();
+            let req = SetTableColumnMaskPolicyReq {
+                tenant: tenant.clone(),
+                seq: MatchSeq::Exact(table_info.ident.seq),
+                table_id: table_info.ident.table_id,
+                column: column.clone(),
+                action: SetTableColumnMaskPolicyAction::Set(mask_name.clone(), None),
+            };
+            let res = catalog.set_table_column_mask_policy(req).await?;
+            if let Some(share_table_info) = res.share_table_info {
+                save_share_table_info(
+                    &tenant,
+                    self.ctx.get_data_operator()?.operator(),
+                    share_table_info,
+                )
+                .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Creates the implicit sequences of `AUTO_INCREMENT` columns.
+    ///
+    /// The sequences are kept when the table is dropped, so that an undropped table
+    /// continues to generate unique values. They are removed when the metadata of the
+    /// dropped table is vacuumed.
+    #[async_backtrace::framed]
+    async fn create_auto_increment_sequences(&self) -> Result<()> {
+        let sequence_columns = SequenceColumns::from_options(&self.plan.options)?;
+        for column in sequence_columns.0.values() {
+            if column.auto_increment {
+                let sequence = UserDefinedSequence::new(
+                    &column.sequence,
+                    1,
+                    1,
+                    AUTO_INCREMENT_SEQUENCE_CACHE,
+                    None,
+                );
+                UserApiProvider::instance()
+                    .add_sequence(&self.plan.tenant, sequence, false)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
     /// Build CreateTableReq from CreateTablePlanV2.
     ///
     /// - Rebuild `DataSchema` with default exprs.
@@ -320,9 +474,16 @@ impl CreateTableInterpreter {
 
         is_valid_block_per_segment(&table_meta.options)?;
         is_valid_row_per_block(&table_meta.options)?;
+        is_valid_data_retention_time_in_days(&table_meta.options)?;
         // check bloom_index_columns.
-        is_valid_bloom_index_columns(&table_meta.options, schema)?;
+        is_valid_bloom_index_columns(&table_meta.options, schema.clone())?;
         is_valid_change_tracking(&table_meta.options)?;
+        is_valid_row_ttl(self.ctx.clone(), &table_meta.options, schema.clone())?;
+        is_valid_crypto_shred_columns(&table_meta.options, &schema)?;
+        is_valid_aggregating_columns(&table_meta.options, &schema)?;
+        is_valid_dedup_columns(&table_meta.options, &schema)?;
+        is_valid_point_lookup_column(&table_meta.options, &schema)?;
+        is_valid_bucket_num(&table_meta.options, self.plan.cluster_key.is_some())?;
 
         for table_option in table_meta.options.iter() {
             let key = table_option.0.to_lowercase();
@@ -353,8 +514,11 @@ impl CreateTableInterpreter {
 
     async fn build_attach_request(&self, storage_prefix: &str) -> Result<CreateTableReq> {
         // Safe to unwrap in this function, as attach table must have storage params.
-        let sp = self.plan.storage_params.as_ref().unwrap();
-        let operator = DataOperator::try_create(sp).await?;
+        let mut sp = self.plan.storage_params.clone().unwrap();
+        UserApiProvider::instance()
+            .resolve_storage_secrets(&self.plan.tenant, &mut sp)
+            .await?;
+        let operator = DataOperator::try_create(&sp).await?;
         let operator = operator.operator();
         let reader = MetaReaders::table_snapshot_reader(operator.clone());
         let hint = format!("{}/{}", storage_prefix, FUSE_TBL_LAST_SNAPSHOT_HINT);
@@ -426,6 +590,7 @@ pub static CREATE_TABLE_OPTIONS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     r.insert(FUSE_OPT_KEY_ROW_PER_BLOCK);
     r.insert(FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD);
     r.insert(FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD);
+    r.insert(FUSE_OPT_KEY_BUCKET_NUM);
 
     r.insert(OPT_KEY_BLOOM_INDEX_COLUMNS);
     r.insert(OPT_KEY_TABLE_COMPRESSION);
@@ -433,6 +598,19 @@ pub static CREATE_TABLE_OPTIONS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_COMMENT);
     r.insert(OPT_KEY_CHANGE_TRACKING);
+    r.insert(OPT_KEY_REPLICATION_URI);
+    r.insert(OPT_KEY_REPLICATION_CONNECTION);
+    r.insert(OPT_KEY_SEQUENCE_COLUMNS);
+    r.insert(OPT_KEY_CONSTRAINTS);
+    r.insert(OPT_KEY_ROW_TTL);
+    r.insert(OPT_KEY_CRYPTO_SHRED_COLUMNS);
+    r.insert(OPT_KEY_SUBJECT_KEY_COLUMN);
+    r.insert(OPT_KEY_POINT_LOOKUP_COLUMN);
+    r.insert(OPT_KEY_AGGREGATING_KEYS);
+    r.insert(OPT_KEY_AGGREGATING_FUNCTIONS);
+    r.insert(OPT_KEY_DEDUP_KEYS);
+    r.insert(OPT_KEY_DEDUP_VERSION_COLUMN);
+    r.insert(OPT_KEY_DATA_RETENTION_TIME_IN_DAYS);
 
     r.insert(OPT_KEY_ENGINE);
 
@@ -453,6 +631,7 @@ pub static INTERNAL_COLUMN_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     r.insert(SNAPSHOT_NAME_COL_NAME);
     r.insert(SEGMENT_NAME_COL_NAME);
     r.insert(BLOCK_NAME_COL_NAME);
+    r.insert(FILE_NAME_COL_NAME);
 
     r.insert(PREDICATE_COLUMN_NAME);
 
@@ -497,6 +676,38 @@ pub fn is_valid_row_per_block(options: &BTreeMap<String, String>) -> Result<()>
     Ok(())
 }
 
+pub fn is_valid_data_retention_time_in_days(options: &BTreeMap<String, String>) -> Result<()> {
+    // check data_retention_time_in_days is a number of days.
+    if let Some(value) = options.get(OPT_KEY_DATA_RETENTION_TIME_IN_DAYS) {
+        let error_str = "invalid data_retention_time_in_days option, must be a number of days";
+        if value.parse::<u32>().is_err() {
+            error!("{}", error_str);
+            return Err(ErrorCode::TableOptionInvalid(error_str));
+        }
+    }
+    Ok(())
+}
+
+pub fn is_valid_bucket_num(
+    options: &BTreeMap<String, String>,
+    has_cluster_key: bool,
+) -> Result<()> {
+    // check bucket_num is a positive number and the table is clustered.
+    if let Some(value) = options.get(FUSE_OPT_KEY_BUCKET_NUM) {
+        let error_str = "invalid bucket_num option, must be a positive number";
+        if !value.parse::<u32>().is_ok_and(|bucket_num| bucket_num > 0) {
+            error!("{}", error_str);
+            return Err(ErrorCode::TableOptionInvalid(error_str));
+        }
+        if !has_cluster_key {
+            let error_str = "invalid bucket_num option, the table must have cluster keys";
+            error!("{}", error_str);
+            return Err(ErrorCode::TableOptionInvalid(error_str));
+        }
+    }
+    Ok(())
+}
+
 pub fn is_valid_bloom_index_columns(
     options: &BTreeMap<String, String>,
     schema: TableSchemaRef,
@@ -513,3 +724,92 @@ pub fn is_valid_change_tracking(options: &BTreeMap<String, String>) -> Result<()
     }
     Ok(())
 }
+
+pub fn is_valid_row_ttl(
+    ctx: Arc<QueryContext>,
+    options: &BTreeMap<String, String>,
+    schema: TableSchemaRef,
+) -> Result<()> {
+    if let Some(value) = options.get(OPT_KEY_ROW_TTL) {
+        parse_row_ttl_to_string(ctx, schema, value, Utc::now().timestamp_micros())?;
+    }
+    Ok(())
+}
+
+pub fn is_valid_crypto_shred_columns(
+    options: &BTreeMap<String, String>,
+    schema: &TableSchema,
+) -> Result<()> {
+    if let Some(columns) = CryptoShredColumns::from_options(options)? {
+        columns.check_schema(schema)?;
+    }
+    Ok(())
+}
+
+pub fn is_valid_aggregating_columns(
+    options: &BTreeMap<String, String>,
+    schema: &TableSchema,
+) -> Result<()> {
+    if let Some(columns) = AggregatingColumns::from_options(options)? {
+        columns.check_schema(schema)?;
+        CompactAggregator::try_create(&columns, schema)?;
+    }
+    Ok(())
+}
+
+pub fn is_valid_dedup_columns(
+    options: &BTreeMap<String, String>,
+    schema: &TableSchema,
+) -> Result<()> {
+    if let Some(columns) = DedupColumns::from_options(options)? {
+        if AggregatingColumns::from_options(options)?.is_some() {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "table options {} and {} can not be set together",
+                OPT_KEY_DEDUP_KEYS, OPT_KEY_AGGREGATING_KEYS
+            )));
+        }
+        columns.check_schema(schema)?;
+    }
+    Ok(())
+}
+
+pub fn is_valid_point_lookup_column(
+    options: &BTreeMap<String, String>,
+    schema: &TableSchema,
+) -> Result<()> {
+    if let Some(name) = options.get(OPT_KEY_POINT_LOOKUP_COLUMN) {
+        let field = schema.field_with_name(name).map_err(|_| {
+            ErrorCode::TableOptionInvalid(format!(
+                "invalid {OPT_KEY_POINT_LOOKUP_COLUMN} option, column {name} does not exist",
+            ))
+        })?;
+        if matches!(field.computed_expr(), Some(ComputedExpr::Virtual(_)))
+            || !PointLookupIndex::supported_type(&field.data_type().into())
+        {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "invalid {OPT_KEY_POINT_LOOKUP_COLUMN} option, column {name} of type {} can't be looked up",
+                field.data_type(),
+            )));
+        }
+    }
+    Ok(())
+}
+
+pub async fn is_valid_replication_options(
+    ctx: Arc<QueryContext>,
+    options: &BTreeMap<String, String>,
+) -> Result<()> {
+    if options.contains_key(OPT_KEY_REPLICATION_CONNECTION)
+        && !options.contains_key(OPT_KEY_REPLICATION_URI)
+    {
+        return Err(ErrorCode::TableOptionInvalid(format!(
+            "{OPT_KEY_REPLICATION_CONNECTION} requires {OPT_KEY_REPLICATION_URI} to be set",
+        )));
+    }
+    let ctx: Arc<dyn TableContext> = ctx;
+    if let Some(sp) = parse_replication_location(&ctx, options).await? {
+        // create a temporary op to check if params is correct
+        DataOperator::try_create(&sp).await?;
+    }
+    Ok(())
+}
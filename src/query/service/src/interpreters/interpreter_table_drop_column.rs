@@ -26,7 +26,9 @@ use common_sql::BloomIndexColumns;
 use common_storages_share::save_share_table_info;
 use common_storages_stream::stream_table::STREAM_ENGINE;
 use common_storages_view::view_table::VIEW_ENGINE;
+use storages_common_table_meta::table::TableConstraints;
 use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
+use storages_common_table_meta::table::OPT_KEY_POINT_LOOKUP_COLUMN;
 
 use crate::interpreters::common::check_referenced_computed_columns;
 use crate::interpreters::Interpreter;
@@ -81,6 +83,18 @@ impl Interpreter for DropTableColumnInterpreter {
             )));
         }
 
+        let column_id = table_info
+            .schema()
+            .field_with_name(self.plan.column.as_str())?
+            .column_id();
+        let constraints = TableConstraints::from_options(table_info.options())?;
+        if let Some(constraint) = constraints.referencing_column(column_id) {
+            return Err(ErrorCode::ColumnReferencedByConstraint(format!(
+                "column {} is referenced by constraint {}, drop the constraint first",
+                self.plan.column, constraint
+            )));
+        }
+
         let mut schema: DataSchema = table_info.schema().into();
         let field = schema.field_with_name(self.plan.column.as_str())?;
         if field.computed_expr().is_none() {
@@ -109,6 +123,10 @@ impl Interpreter for DropTableColumnInterpreter {
                 }
             }
         }
+        if opts.get(OPT_KEY_POINT_LOOKUP_COLUMN) == Some(&self.plan.column) {
+            // the indexes written so far are ignored once the column is gone.
+            opts.remove(OPT_KEY_POINT_LOOKUP_COLUMN);
+        }
 
         let table_id = table_info.ident.table_id;
         let table_version = table_info.ident.seq;
@@ -26,7 +26,10 @@ use common_sql::BloomIndexColumns;
 use common_storages_share::save_share_table_info;
 use common_storages_stream::stream_table::STREAM_ENGINE;
 use common_storages_view::view_table::VIEW_ENGINE;
+use storages_common_table_meta::table::TableConstraint;
+use storages_common_table_meta::table::TableConstraints;
 use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
+use storages_common_table_meta::table::OPT_KEY_POINT_LOOKUP_COLUMN;
 
 use crate::interpreters::common::check_referenced_computed_columns;
 use crate::interpreters::interpreter_table_create::is_valid_column;
@@ -89,6 +92,21 @@ impl Interpreter for RenameTableColumnInterpreter {
 
             is_valid_column(&self.plan.new_column)?;
 
+            // The check constraints refer to the columns by names in their expressions.
+            let column_id = table_info
+                .schema()
+                .field_with_name(self.plan.old_column.as_str())?
+                .column_id();
+            let constraints = TableConstraints::from_options(table_info.options())?;
+            if let Some((constraint, _)) = constraints.0.iter().find(|(_, constraint)| {
+                matches!(constraint, TableConstraint::Check { columns, .. } if columns.contains(&column_id))
+            }) {
+                return Err(ErrorCode::ColumnReferencedByConstraint(format!(
+                    "column {} is referenced by check constraint {}, drop the constraint first",
+                    self.plan.old_column, constraint
+                )));
+            }
+
             let mut schema: DataSchema = table_info.schema().into();
             let field = schema.field_with_name(self.plan.old_column.as_str())?;
             if field.computed_expr().is_none() {
@@ -116,6 +134,11 @@ impl Interpreter for RenameTableColumnInterpreter {
                     }
                 }
             }
+            if let Some(value) = opts.get_mut(OPT_KEY_POINT_LOOKUP_COLUMN) {
+                if *value == self.plan.old_column {
+                    *value = self.plan.new_column.clone();
+                }
+            }
 
             let table_id = table_info.ident.table_id;
             let table_version = table_info.ident.seq;
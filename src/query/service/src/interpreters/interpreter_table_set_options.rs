@@ -22,15 +22,26 @@ use common_meta_app::schema::UpsertTableOptionReq;
 use common_meta_types::MatchSeq;
 use common_sql::plans::SetOptionsPlan;
 use common_storages_fuse::TableContext;
+use common_storages_fuse::FUSE_OPT_KEY_BUCKET_NUM;
 use log::error;
+use storages_common_table_meta::table::OPT_KEY_CONSTRAINTS;
+use storages_common_table_meta::table::OPT_KEY_CRYPTO_SHRED_COLUMNS;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_SEQUENCE_COLUMNS;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
+use storages_common_table_meta::table::OPT_KEY_SUBJECT_KEY_COLUMN;
 
+use super::interpreter_table_create::is_valid_aggregating_columns;
 use super::interpreter_table_create::is_valid_block_per_segment;
 use super::interpreter_table_create::is_valid_bloom_index_columns;
 use super::interpreter_table_create::is_valid_change_tracking;
 use super::interpreter_table_create::is_valid_create_opt;
+use super::interpreter_table_create::is_valid_data_retention_time_in_days;
+use super::interpreter_table_create::is_valid_dedup_columns;
+use super::interpreter_table_create::is_valid_point_lookup_column;
+use super::interpreter_table_create::is_valid_replication_options;
 use super::interpreter_table_create::is_valid_row_per_block;
+use super::interpreter_table_create::is_valid_row_ttl;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -60,6 +71,8 @@ impl Interpreter for SetOptionsInterpreter {
         is_valid_block_per_segment(&self.plan.set_options)?;
         // check row_per_block
         is_valid_row_per_block(&self.plan.set_options)?;
+        // check data_retention_time_in_days
+        is_valid_data_retention_time_in_days(&self.plan.set_options)?;
         is_valid_change_tracking(&self.plan.set_options)?;
         // check storage_format
         let error_str = "invalid opt for fuse table in alter table statement";
@@ -70,6 +83,14 @@ impl Interpreter for SetOptionsInterpreter {
                 OPT_KEY_STORAGE_FORMAT
             )));
         }
+        // The blocks already written aren't bucketed by the new bucket_num.
+        if self.plan.set_options.get(FUSE_OPT_KEY_BUCKET_NUM).is_some() {
+            error!("{}", &error_str);
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "can't change {} for alter table statement",
+                FUSE_OPT_KEY_BUCKET_NUM
+            )));
+        }
         if self.plan.set_options.get(OPT_KEY_DATABASE_ID).is_some() {
             error!("{}", &error_str);
             return Err(ErrorCode::TableOptionInvalid(format!(
@@ -77,6 +98,35 @@ impl Interpreter for SetOptionsInterpreter {
                 OPT_KEY_DATABASE_ID
             )));
         }
+        if self
+            .plan
+            .set_options
+            .get(OPT_KEY_SEQUENCE_COLUMNS)
+            .is_some()
+        {
+            error!("{}", &error_str);
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "can't change {} for alter table statement",
+                OPT_KEY_SEQUENCE_COLUMNS
+            )));
+        }
+        if self.plan.set_options.get(OPT_KEY_CONSTRAINTS).is_some() {
+            error!("{}", &error_str);
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "can't change {} for alter table statement",
+                OPT_KEY_CONSTRAINTS
+            )));
+        }
+        // The existing values can't be re-encrypted or decrypted in place.
+        for key in [OPT_KEY_CRYPTO_SHRED_COLUMNS, OPT_KEY_SUBJECT_KEY_COLUMN] {
+            if self.plan.set_options.get(key).is_some() {
+                error!("{}", &error_str);
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "can't change {} for alter table statement",
+                    key
+                )));
+            }
+        }
         for table_option in self.plan.set_options.iter() {
             let key = table_option.0.to_lowercase();
             if !is_valid_create_opt(&key) {
@@ -110,6 +160,15 @@ impl Interpreter for SetOptionsInterpreter {
 
         // check bloom_index_columns.
         is_valid_bloom_index_columns(&self.plan.set_options, table.schema())?;
+        is_valid_row_ttl(self.ctx.clone(), &self.plan.set_options, table.schema())?;
+        is_valid_point_lookup_column(&self.plan.set_options, &table.schema())?;
+
+        // check replication target, against the options after altering.
+        let mut new_options = table.options().clone();
+        new_options.extend(self.plan.set_options.clone());
+        is_valid_replication_options(self.ctx.clone(), &new_options).await?;
+        is_valid_aggregating_columns(&new_options, &table.schema())?;
+        is_valid_dedup_columns(&new_options, &table.schema())?;
 
         let req = UpsertTableOptionReq {
             table_id: table.get_id(),
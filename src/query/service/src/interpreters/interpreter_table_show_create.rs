@@ -22,6 +22,7 @@ use common_expression::BlockEntry;
 use common_expression::ComputedExpr;
 use common_expression::DataBlock;
 use common_expression::Scalar;
+use common_expression::TableDataType;
 use common_expression::Value;
 use common_sql::plans::ShowCreateTablePlan;
 use common_storages_stream::stream_table::StreamTable;
@@ -30,6 +31,10 @@ use common_storages_view::view_table::QUERY;
 use common_storages_view::view_table::VIEW_ENGINE;
 use log::debug;
 use storages_common_table_meta::table::is_internal_opt_key;
+use storages_common_table_meta::table::SequenceColumns;
+use storages_common_table_meta::table::TableConstraint;
+use storages_common_table_meta::table::TableConstraints;
+use storages_common_table_meta::table::OPT_KEY_COMMENT;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
 use storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_READ_ONLY;
@@ -70,25 +75,30 @@ impl Interpreter for ShowCreateTableInterpreter {
             VIEW_ENGINE => self.show_create_view(table.as_ref()),
             _ => match table.options().get(OPT_KEY_STORAGE_PREFIX) {
                 Some(_) => self.show_attach_table(table.as_ref()),
-                None => self.show_create_table(table.as_ref()),
+                None => self.show_create_table(table.as_ref()).await,
             },
         }
     }
 }
 
 impl ShowCreateTableInterpreter {
-    fn show_create_table(&self, table: &dyn Table) -> Result<PipelineBuildResult> {
+    #[async_backtrace::framed]
+    async fn show_create_table(&self, table: &dyn Table) -> Result<PipelineBuildResult> {
         let name = table.name();
         let engine = table.engine();
         let schema = table.schema();
         let field_comments = table.field_comments();
         let n_fields = schema.fields().len();
+        let sequence_columns = SequenceColumns::from_options(table.options())?;
 
         let mut table_create_sql = format!("CREATE TABLE `{}` (\n", name);
         if table.options().contains_key("TRANSIENT") {
             table_create_sql = format!("CREATE TRANSIENT TABLE `{}` (\n", name)
         }
 
+        let table_info = table.get_table_info();
+        let column_mask_policy = table_info.meta.column_mask_policy.as_ref();
+
         // Append columns.
         {
             let mut columns = vec![];
@@ -102,7 +112,11 @@ impl ShowCreateTableInterpreter {
                     Some(expr) => {
                         format!(" DEFAULT {expr}")
                     }
-                    None => "".to_string(),
+                    None => match sequence_columns.get(field.column_id()) {
+                        Some(column) if column.auto_increment => " AUTO_INCREMENT".to_string(),
+                        Some(column) => format!(" DEFAULT NEXTVAL({})", column.sequence),
+                        None => "".to_string(),
+                    },
                 };
                 let computed_expr = match field.computed_expr() {
                     Some(ComputedExpr::Virtual(expr)) => {
@@ -117,25 +131,81 @@ impl ShowCreateTableInterpreter {
                 let comment = if field_comments.len() == n_fields && !field_comments[idx].is_empty()
                 {
                     // make the display more readable.
-                    format!(
-                        " COMMENT '{}'",
-                        &field_comments[idx].as_str().replace('\'', "\\'")
-                    )
+                    format!(" COMMENT '{}'", escape_string(&field_comments[idx]))
                 } else {
                     "".to_string()
                 };
+                let mask_policy = match column_mask_policy.and_then(|m| m.get(field.name())) {
+                    Some(policy) => format!(" MASKING POLICY {policy}"),
+                    None => "".to_string(),
+                };
                 let column = format!(
-                    "  `{}` {}{}{}{}{}",
+                    "  `{}` {}{}{}{}{}{}",
                     field.name(),
-                    field.data_type().remove_recursive_nullable().sql_name(),
+                    column_type_sql_name(&field.data_type().remove_nullable()),
                     nullable,
                     default_expr,
                     computed_expr,
+                    mask_policy,
                     comment
                 );
 
                 columns.push(column);
             }
+            // Append constraints.
+            let constraints = TableConstraints::from_options(table.options())?;
+            for (constraint_name, constraint) in constraints.0.iter() {
+                let column_names = || {
+                    constraint
+                        .columns()
+                        .iter()
+                        .filter_map(|id| schema.fields().iter().find(|f| f.column_id() == *id))
+                        .map(|f| format!("`{}`", f.name()))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                };
+                let definition = match constraint {
+                    TableConstraint::Check { expr, .. } => format!("CHECK ({expr})"),
+                    TableConstraint::Unique { .. } => format!("UNIQUE ({})", column_names()),
+                    TableConstraint::PrimaryKey { .. } => {
+                        format!("PRIMARY KEY ({})", column_names())
+                    }
+                    TableConstraint::ForeignKey {
+                        ref_table_id,
+                        ref_database,
+                        ref_table,
+                        ref_columns,
+                        ..
+                    } => {
+                        // the foreign keys to dropped tables are omitted
+                        let catalog = self.ctx.get_catalog(self.plan.catalog.as_str()).await?;
+                        let Ok((_, ref_meta)) = catalog.get_table_meta_by_id(*ref_table_id).await
+                        else {
+                            continue;
+                        };
+                        let ref_column_names = ref_columns
+                            .iter()
+                            .filter_map(|id| {
+                                ref_meta
+                                    .schema
+                                    .fields()
+                                    .iter()
+                                    .find(|f| f.column_id() == *id)
+                            })
+                            .map(|f| format!("`{}`", f.name()))
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        format!(
+                            "FOREIGN KEY ({}) REFERENCES `{}`.`{}` ({})",
+                            column_names(),
+                            ref_database,
+                            ref_table,
+                            ref_column_names
+                        )
+                    }
+                };
+                columns.push(format!("  CONSTRAINT `{constraint_name}` {definition}"));
+            }
             // Format is:
             //  (
             //      x,
@@ -148,7 +218,6 @@ impl ShowCreateTableInterpreter {
         let table_engine = format!(") ENGINE={}", engine);
         table_create_sql.push_str(table_engine.as_str());
 
-        let table_info = table.get_table_info();
         if let Some((_, cluster_keys_str)) = table_info.meta.cluster_key() {
             table_create_sql.push_str(format!(" CLUSTER BY {}", cluster_keys_str).as_str());
         }
@@ -163,14 +232,25 @@ impl ShowCreateTableInterpreter {
                 let mut opts = table_info.options().iter().collect::<Vec<_>>();
                 opts.sort_by_key(|(k, _)| *k);
                 opts.iter()
-                    .filter(|(k, _)| !is_internal_opt_key(k))
-                    .map(|(k, v)| format!(" {}='{}'", k.to_uppercase(), v))
+                    .filter(|(k, _)| {
+                        !is_internal_opt_key(k)
+                            && k.as_str() != OPT_KEY_COMMENT
+                            && k.as_str() != "TRANSIENT"
+                    })
+                    .map(|(k, v)| format!(" {}='{}'", k.to_uppercase(), escape_string(v)))
                     .collect::<Vec<_>>()
                     .join("")
                     .as_str()
             });
         }
 
+        // The table comment is always shown, it is part of the table definition.
+        if let Some(comment) = table_info.options().get(OPT_KEY_COMMENT) {
+            if !comment.is_empty() {
+                table_create_sql.push_str(&format!(" COMMENT='{}'", escape_string(comment)));
+            }
+        }
+
         let block = DataBlock::new(
             vec![
                 BlockEntry::new(
@@ -285,3 +365,36 @@ impl ShowCreateTableInterpreter {
         PipelineBuildResult::from_blocks(vec![block])
     }
 }
+
+/// Renders the column type so that it is parsed back into the same type,
+/// keeping the nullability of inner types and the names of tuple fields.
+fn column_type_sql_name(data_type: &TableDataType) -> String {
+    match data_type {
+        TableDataType::Nullable(inner) => format!("{} NULL", column_type_sql_name(inner)),
+        TableDataType::Array(inner) => format!("ARRAY({})", column_type_sql_name(inner)),
+        TableDataType::Map(inner) => match inner.as_ref() {
+            TableDataType::Tuple { fields_type, .. } => format!(
+                "MAP({}, {})",
+                column_type_sql_name(&fields_type[0]),
+                column_type_sql_name(&fields_type[1])
+            ),
+            _ => unreachable!(),
+        },
+        TableDataType::Tuple {
+            fields_name,
+            fields_type,
+        } => {
+            let fields = fields_name
+                .iter()
+                .zip(fields_type)
+                .map(|(name, ty)| format!("`{}` {}", name, column_type_sql_name(ty)))
+                .collect::<Vec<_>>();
+            format!("TUPLE({})", fields.join(", "))
+        }
+        _ => data_type.sql_name(),
+    }
+}
+
+fn escape_string(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('\'', "\\'")
+}
@@ -65,6 +65,9 @@ impl AlterTaskInterpreter {
             schedule_options: None,
             warehouse_options: None,
             suspend_task_after_num_failures: None,
+            add_after: vec![],
+            remove_after: vec![],
+            error_integration: None,
         };
         match plan.alter_options {
             AlterTaskOptions::Resume => {
@@ -78,6 +81,7 @@ impl AlterTaskInterpreter {
                 comments,
                 warehouse,
                 suspend_task_after_num_failures,
+                error_integration,
             } => {
                 req.alter_task_type = AlterTaskType::Set as i32;
                 req.schedule_options = schedule.map(make_schedule_options);
@@ -88,6 +92,7 @@ impl AlterTaskInterpreter {
                 });
                 req.suspend_task_after_num_failures =
                     suspend_task_after_num_failures.map(|i| i as i32);
+                req.error_integration = error_integration;
             }
             AlterTaskOptions::Unset { .. } => {
                 todo!()
@@ -96,6 +101,14 @@ impl AlterTaskInterpreter {
                 req.alter_task_type = AlterTaskType::ModifyAs as i32;
                 req.query_text = Some(sql);
             }
+            AlterTaskOptions::AddAfter(after) => {
+                req.alter_task_type = AlterTaskType::AddAfter as i32;
+                req.add_after = after;
+            }
+            AlterTaskOptions::RemoveAfter(after) => {
+                req.alter_task_type = AlterTaskType::RemoveAfter as i32;
+                req.remove_after = after;
+            }
         }
         req
     }
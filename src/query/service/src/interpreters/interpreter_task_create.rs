@@ -57,10 +57,12 @@ impl CreateTaskInterpreter {
             query_text: plan.sql,
             owner,
             comment: Some(plan.comment),
-            schedule_options: Some(make_schedule_options(plan.schedule_opts)),
+            schedule_options: plan.schedule_opts.map(make_schedule_options),
             warehouse_options: Some(make_warehouse_options(plan.warehouse_opts)),
             suspend_task_after_num_failures: plan.suspend_task_after_num_failures.map(|x| x as i32),
             if_not_exist: plan.if_not_exists,
+            after: plan.after,
+            error_integration: plan.error_integration,
         }
     }
 }
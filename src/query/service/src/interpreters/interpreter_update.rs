@@ -45,10 +45,13 @@ use log::debug;
 use storages_common_locks::LockManager;
 use storages_common_table_meta::meta::TableSnapshot;
 
+use crate::interpreters::common::check_crypto_shred_columns;
 use crate::interpreters::common::check_deduplicate_label;
 use crate::interpreters::common::create_push_down_filters;
 use crate::interpreters::common::hook_refresh_agg_index;
+use crate::interpreters::common::hook_replicate_table;
 use crate::interpreters::common::RefreshAggIndexDesc;
+use crate::interpreters::common::ReplicateTableDesc;
 use crate::interpreters::interpreter_delete::replace_subquery;
 use crate::interpreters::interpreter_delete::subquery_filter;
 use crate::interpreters::Interpreter;
@@ -170,6 +173,14 @@ impl Interpreter for UpdateInterpreter {
         } else {
             (None, vec![])
         };
+        check_crypto_shred_columns(
+            tbl.as_ref(),
+            col_indices
+                .iter()
+                .chain(self.plan.update_list.keys())
+                .copied(),
+            "UPDATE",
+        )?;
 
         let update_list = self.plan.generate_update_list(
             self.ctx.clone(),
@@ -252,6 +263,17 @@ impl Interpreter for UpdateInterpreter {
                 )
                 .await?;
             }
+
+            // Replicate the new snapshot if replication is enabled.
+            hook_replicate_table(
+                self.ctx.clone(),
+                &mut build_res.main_pipeline,
+                ReplicateTableDesc {
+                    catalog: catalog_name.to_string(),
+                    database: db_name.to_string(),
+                    table: tbl_name.to_string(),
+                },
+            );
         }
 
         build_res.main_pipeline.add_lock_guard(lock_guard);
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::cmp::min;
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use common_exception::Result;
@@ -22,12 +23,17 @@ use common_expression::FromData;
 use common_license::license::Feature::Vacuum;
 use common_license::license_manager::get_license_manager;
 use common_meta_app::schema::DatabaseNameIdent;
+use common_meta_app::schema::DroppedId;
 use common_meta_app::schema::GcDroppedTableReq;
 use common_meta_app::schema::ListDroppedTableReq;
 use common_meta_app::schema::TableInfoFilter;
 use common_sql::plans::VacuumDropTablePlan;
+use common_storages_fuse::FuseTable;
+use common_users::UserApiProvider;
 use log::as_debug;
 use log::info;
+use storages_common_table_meta::table::SequenceColumns;
+use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use vacuum_handler::get_vacuum_handler;
 
 use crate::interpreters::Interpreter;
@@ -80,7 +86,7 @@ impl Interpreter for VacuumDropTablesInterpreter {
         };
 
         let tenant = self.ctx.get_tenant();
-        let (tables, drop_ids) = catalog
+        let (tables, mut drop_ids) = catalog
             .get_drop_table_infos(ListDroppedTableReq {
                 inner: DatabaseNameIdent {
                     tenant,
@@ -106,10 +112,50 @@ impl Interpreter for VacuumDropTablesInterpreter {
             .filter(|tbl| !tbl.as_ref().is_read_only())
             .collect::<Vec<_>>();
 
+        // tables sharing snapshots with their clones are kept, both the files and the metadata,
+        // until the clones release the snapshots.
+        let mut shared_table_ids = HashSet::new();
+        let mut shared_db_ids = HashSet::new();
+        let mut tables_to_vacuum = Vec::with_capacity(tables.len());
+        for table in tables {
+            if let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) {
+                if !fuse_table
+                    .clone_shared_snapshots(self.ctx.as_ref())
+                    .await?
+                    .is_empty()
+                {
+                    info!(
+                        "ignore table {} which shares snapshots with clones",
+                        table.get_table_info().name
+                    );
+                    shared_table_ids.insert(table.get_id());
+                    if let Some(db_id) = table.get_table_info().options().get(OPT_KEY_DATABASE_ID) {
+                        shared_db_ids.insert(db_id.clone());
+                    }
+                    continue;
+                }
+            }
+            tables_to_vacuum.push(table);
+        }
+        // the implicit sequences of AUTO_INCREMENT columns are removed with the table metadata.
+        let mut auto_increment_sequences = Vec::new();
+        for table in &tables_to_vacuum {
+            let sequence_columns = SequenceColumns::from_options(table.options())?;
+            auto_increment_sequences.extend(
+                sequence_columns
+                    .auto_increment_sequences()
+                    .map(|sequence| sequence.to_string()),
+            );
+        }
+        drop_ids.retain(|drop_id| match drop_id {
+            DroppedId::Db(db_id, _) => !shared_db_ids.contains(&db_id.to_string()),
+            DroppedId::Table(_, table_id, _) => !shared_table_ids.contains(table_id),
+        });
+
         let handler = get_vacuum_handler();
         let files_opt = handler
             .do_vacuum_drop_tables(
-                tables,
+                tables_to_vacuum,
                 if self.plan.option.dry_run.is_some() {
                     Some(DRY_RUN_LIMIT)
                 } else {
@@ -129,6 +175,12 @@ impl Interpreter for VacuumDropTablesInterpreter {
                 drop_ids,
             };
             let _ = catalog.gc_drop_tables(req).await?;
+
+            let tenant = self.ctx.get_tenant();
+            let user_mgr = UserApiProvider::instance();
+            for sequence in &auto_increment_sequences {
+                user_mgr.drop_sequence(&tenant, sequence, true).await?;
+            }
         }
 
         match files_opt {
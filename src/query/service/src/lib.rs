@@ -46,6 +46,7 @@ pub mod clusters;
 pub mod databases;
 pub mod interpreters;
 pub mod local;
+pub mod log_history;
 pub mod metrics;
 pub mod pipelines;
 pub mod schedulers;
@@ -0,0 +1,207 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_base::base::tokio::time::sleep;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_config::InnerConfig;
+use common_exception::Result;
+use common_io::escape_string_with_quote;
+use common_meta_app::principal::GrantObject;
+use common_meta_app::principal::UserInfo;
+use common_meta_app::principal::UserPrivilegeSet;
+use common_sql::Planner;
+use common_users::BUILTIN_ROLE_ACCOUNT_ADMIN;
+use futures_util::TryStreamExt;
+use log::info;
+use log::warn;
+
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::Session;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+
+pub const LOG_HISTORY_DATABASE: &str = "system_history";
+
+/// Expired records are deleted at most once per this interval.
+const ROTATE_INTERVAL: Duration = Duration::from_secs(3600);
+
+/// Persists `system.query_log` and snapshots of `system.metrics` of this node into fuse tables
+/// of the `system_history` database, and deletes the records older than the retention.
+///
+/// The in-memory log queue is empty after a restart, thus every node only flushes the records
+/// produced since it started and there are no duplicates.
+pub struct LogHistory {
+    node_id: String,
+    flush_interval: Duration,
+    retention_days: u64,
+    /// Records of `system.query_log` with `event_time` no later than this are flushed.
+    flushed_until: Option<chrono::NaiveDateTime>,
+    last_rotation: Option<Instant>,
+}
+
+impl LogHistory {
+    pub fn init(conf: &InnerConfig) -> Result<()> {
+        if conf.query.log_history_retention_days == 0 {
+            return Ok(());
+        }
+
+        let mut history = LogHistory {
+            node_id: conf.query.node_id.clone(),
+            flush_interval: Duration::from_secs(conf.query.log_history_flush_interval_secs.max(1)),
+            retention_days: conf.query.log_history_retention_days,
+            flushed_until: None,
+            last_rotation: None,
+        };
+        let user = log_history_user(conf);
+
+        GlobalIORuntime::instance().spawn("log-history", async move {
+            loop {
+                sleep(history.flush_interval).await;
+                if let Err(cause) = history.flush(user.clone()).await {
+                    warn!("flush log history failed: {:?}", cause);
+                }
+            }
+        });
+        Ok(())
+    }
+
+    async fn flush(&mut self, user: UserInfo) -> Result<()> {
+        let session = SessionManager::instance()
+            .create_session(SessionType::Dummy)
+            .await?;
+        session
+            .set_authed_user(user, Some(BUILTIN_ROLE_ACCOUNT_ADMIN.to_string()))
+            .await?;
+
+        run_sql(
+            &session,
+            &format!("CREATE DATABASE IF NOT EXISTS {LOG_HISTORY_DATABASE}"),
+        )
+        .await?;
+        run_sql(
+            &session,
+            &format!(
+                "CREATE TABLE IF NOT EXISTS {LOG_HISTORY_DATABASE}.query_log AS \
+                SELECT * FROM system.query_log LIMIT 0"
+            ),
+        )
+        .await?;
+        run_sql(
+            &session,
+            &format!(
+                "CREATE TABLE IF NOT EXISTS {LOG_HISTORY_DATABASE}.metrics AS \
+                SELECT now() AS event_time, * FROM system.metrics LIMIT 0"
+            ),
+        )
+        .await?;
+
+        let node_id = escape_string(&self.node_id);
+
+        // Leave the latest second, its records may still be appending.
+        let until = chrono::Utc::now().naive_utc() - chrono::Duration::seconds(1);
+        let since = match self.flushed_until {
+            Some(since) => format!("AND event_time > '{}'", format_time(since)),
+            None => "".to_string(),
+        };
+        run_sql(
+            &session,
+            &format!(
+                "INSERT INTO {LOG_HISTORY_DATABASE}.query_log SELECT * FROM system.query_log \
+                WHERE node_id = '{node_id}' AND event_time <= '{}' {since}",
+                format_time(until)
+            ),
+        )
+        .await?;
+        self.flushed_until = Some(until);
+
+        run_sql(
+            &session,
+            &format!(
+                "INSERT INTO {LOG_HISTORY_DATABASE}.metrics SELECT now(), * FROM system.metrics \
+                WHERE node = '{node_id}'"
+            ),
+        )
+        .await?;
+
+        if self
+            .last_rotation
+            .map_or(true, |t| t.elapsed() >= ROTATE_INTERVAL)
+        {
+            self.rotate(&session).await?;
+            self.last_rotation = Some(Instant::now());
+        }
+        Ok(())
+    }
+
+    async fn rotate(&self, session: &Arc<Session>) -> Result<()> {
+        let expire =
+            chrono::Utc::now().naive_utc() - chrono::Duration::days(self.retention_days as i64);
+        for table in ["query_log", "metrics"] {
+            run_sql(
+                session,
+                &format!(
+                    "DELETE FROM {LOG_HISTORY_DATABASE}.{table} WHERE event_time < '{}'",
+                    format_time(expire)
+                ),
+            )
+            .await?;
+        }
+        info!(
+            "log history older than {} days is deleted",
+            self.retention_days
+        );
+        Ok(())
+    }
+}
+
+fn log_history_user(conf: &InnerConfig) -> UserInfo {
+    let mut user = UserInfo::new_no_auth(
+        &format!(
+            "{}-{}-log-history",
+            conf.query.tenant_id, conf.query.cluster_id
+        ),
+        "0.0.0.0",
+    );
+    user.grants.grant_privileges(
+        &GrantObject::Global,
+        UserPrivilegeSet::available_privileges_on_global(),
+    );
+    user
+}
+
+async fn run_sql(session: &Arc<Session>, sql: &str) -> Result<()> {
+    let ctx = session.create_query_context().await?;
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(sql).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    let _ = interpreter
+        .execute(ctx)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    Ok(())
+}
+
+fn format_time(t: chrono::NaiveDateTime) -> String {
+    t.format("%Y-%m-%d %H:%M:%S%.6f").to_string()
+}
+
+fn escape_string(s: &str) -> String {
+    escape_string_with_quote(s, Some('\''))
+}
@@ -21,6 +21,7 @@ use common_expression::with_mappedhash_method;
 use common_expression::AggregateFunctionRef;
 use common_expression::DataBlock;
 use common_expression::DataSchemaRef;
+use common_expression::GroupKeysDictionary;
 use common_expression::HashMethodKind;
 use common_functions::aggregates::AggregateFunctionFactory;
 use common_pipeline_core::processors::ProcessorPtr;
@@ -99,6 +100,7 @@ impl PipelineBuilder {
     }
 
     pub(crate) fn build_aggregate_partial(&mut self, aggregate: &AggregatePartial) -> Result<()> {
+        let group_keys_dictionary = self.group_keys_dictionary.take();
         self.build_pipeline(&aggregate.input)?;
 
         let params = Self::build_aggregator_params(
@@ -106,6 +108,7 @@ impl PipelineBuilder {
             &aggregate.group_by,
             &aggregate.agg_funcs,
             None,
+            group_keys_dictionary,
         )?;
 
         if params.group_columns.is_empty() {
@@ -126,10 +129,8 @@ impl PipelineBuilder {
 
         let efficiently_memory = self.settings.get_efficiently_memory_group_by()?;
 
-        let group_cols = &params.group_columns;
-        let schema_before_group_by = params.input_schema.clone();
-        let sample_block = DataBlock::empty_with_schema(schema_before_group_by);
-        let method = DataBlock::choose_hash_method(&sample_block, group_cols, efficiently_memory)?;
+        let method =
+            DataBlock::choose_hash_method_with_types(&params.group_data_types, efficiently_memory)?;
 
         self.main_pipeline.add_transform(|input, output| {
             let transform = match params.aggregate_functions.is_empty() {
@@ -220,12 +221,25 @@ impl PipelineBuilder {
     }
 
     pub(crate) fn build_aggregate_final(&mut self, aggregate: &AggregateFinal) -> Result<()> {
+        // The keys of the hash tables never leave this node if the partial aggregation is
+        // right below, so the string keys can be packed through a dictionary shared by both.
+        let group_keys_dictionary = match aggregate.input.as_ref() {
+            PhysicalPlan::AggregatePartial(_)
+                if self.settings.get_enable_group_by_keys_dictionary()? =>
+            {
+                Some(Arc::new(GroupKeysDictionary::default()))
+            }
+            _ => None,
+        };
+
         let params = Self::build_aggregator_params(
             aggregate.before_group_by_schema.clone(),
             &aggregate.group_by,
             &aggregate.agg_funcs,
             aggregate.limit,
+            group_keys_dictionary,
         )?;
+        self.group_keys_dictionary = params.group_dictionary.clone();
 
         if params.group_columns.is_empty() {
             self.build_pipeline(&aggregate.input)?;
@@ -262,10 +276,15 @@ impl PipelineBuilder {
 
         let efficiently_memory = self.settings.get_efficiently_memory_group_by()?;
 
-        let group_cols = &params.group_columns;
-        let schema_before_group_by = params.input_schema.clone();
-        let sample_block = DataBlock::empty_with_schema(schema_before_group_by);
-        let method = DataBlock::choose_hash_method(&sample_block, group_cols, efficiently_memory)?;
+        let method =
+            DataBlock::choose_hash_method_with_types(&params.group_data_types, efficiently_memory)?;
+
+        // The partial aggregations switch to two level by the number of their own groups,
+        // the final aggregation revises it by the total number of groups received.
+        let split_threshold = match self.settings.get_enable_adaptive_final_aggregate()? {
+            true => Some(self.settings.get_group_by_two_level_threshold()? as usize),
+            false => None,
+        };
 
         let old_inject = self.exchange_injector.clone();
 
@@ -287,6 +306,7 @@ impl PipelineBuilder {
                         v,
                         &mut self.main_pipeline,
                         params.clone(),
+                        split_threshold,
                         self.enable_profiling,
                         aggregate.plan_id,
                         self.proc_profs.clone(),
@@ -309,6 +329,7 @@ impl PipelineBuilder {
                         v,
                         &mut self.main_pipeline,
                         params.clone(),
+                        split_threshold,
                         self.enable_profiling,
                         aggregate.plan_id,
                         self.proc_profs.clone(),
@@ -323,6 +344,7 @@ impl PipelineBuilder {
         group_by: &[IndexType],
         agg_funcs: &[AggregateFunctionDesc],
         limit: Option<usize>,
+        group_keys_dictionary: Option<Arc<GroupKeysDictionary>>,
     ) -> Result<Arc<AggregatorParams>> {
         let mut agg_args = Vec::with_capacity(agg_funcs.len());
         let (group_by, group_data_types) = group_by
@@ -335,6 +357,15 @@ impl PipelineBuilder {
             .into_iter()
             .unzip::<_, _, Vec<_>, Vec<_>>();
 
+        // Only use the dictionary if all the keys can be packed into a fixed-width key.
+        let encoded_types = group_keys_dictionary
+            .as_ref()
+            .and_then(|_| GroupKeysDictionary::encoded_key_types(&group_data_types));
+        let (group_data_types, group_keys_dictionary) = match encoded_types {
+            Some(encoded_types) => (encoded_types, group_keys_dictionary),
+            None => (group_data_types, None),
+        };
+
         let aggs: Vec<AggregateFunctionRef> = agg_funcs
             .iter()
             .map(|agg_func| {
@@ -358,6 +389,7 @@ impl PipelineBuilder {
         let params = AggregatorParams::try_create(
             input_schema,
             group_data_types,
+            group_keys_dictionary,
             &group_by,
             &aggs,
             &agg_args,
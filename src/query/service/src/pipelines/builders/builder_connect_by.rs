@@ -0,0 +1,55 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_pipeline_core::processors::ProcessorPtr;
+use common_pipeline_transforms::processors::ProcessorProfileWrapper;
+use common_sql::executor::physical_plans::ConnectBy;
+
+use crate::pipelines::processors::transforms::TransformConnectBy;
+use crate::pipelines::PipelineBuilder;
+
+impl PipelineBuilder {
+    pub(crate) fn build_connect_by(&mut self, connect_by: &ConnectBy) -> Result<()> {
+        self.build_pipeline(&connect_by.input)?;
+
+        let input_schema = connect_by.input.output_schema()?;
+        let id_offset = input_schema.index_of(&connect_by.id_column.to_string())?;
+        let parent_offset = input_schema.index_of(&connect_by.parent_column.to_string())?;
+        let root_offset = input_schema.index_of(&connect_by.root_column.to_string())?;
+
+        // `TransformConnectBy` is a pipeline breaker, the whole hierarchy is traversed by a single processor.
+        self.main_pipeline.try_resize(1)?;
+        self.main_pipeline.add_transform(|input, output| {
+            let transform = TransformConnectBy::try_create(
+                input,
+                output,
+                id_offset,
+                parent_offset,
+                root_offset,
+                connect_by.max_depth,
+                connect_by.separator.clone(),
+            )?;
+            if self.enable_profiling {
+                Ok(ProcessorPtr::create(ProcessorProfileWrapper::create(
+                    transform,
+                    connect_by.plan_id,
+                    self.proc_profs.clone(),
+                )))
+            } else {
+                Ok(ProcessorPtr::create(transform))
+            }
+        })
+    }
+}
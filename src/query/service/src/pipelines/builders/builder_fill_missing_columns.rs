@@ -19,14 +19,21 @@ use common_catalog::plan::StreamColumnMeta;
 use common_catalog::table::Table;
 use common_exception::Result;
 use common_expression::DataSchemaRef;
+use common_pipeline_core::processors::ProcessorPtr;
 use common_pipeline_core::Pipeline;
 use common_sql::TransformStreamKind;
+use storages_common_table_meta::table::CryptoShredColumns;
+use storages_common_table_meta::table::SequenceColumns;
 
+use crate::pipelines::processors::transforms::SequenceColumnDesc;
 use crate::pipelines::processors::transforms::TransformAddComputedColumns;
+use crate::pipelines::processors::transforms::TransformAddSequenceColumns;
 use crate::pipelines::processors::transforms::TransformAddStreamColumns;
+use crate::pipelines::processors::transforms::TransformEncryptShredColumns;
 use crate::pipelines::processors::TransformResortAddOn;
 use crate::pipelines::PipelineBuilder;
 use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
 
 /// This file implements append to table pipeline builder.
 impl PipelineBuilder {
@@ -55,6 +62,42 @@ impl PipelineBuilder {
             })?;
         }
 
+        // Fill sequence columns.
+        let sequence_columns = SequenceColumns::from_options(table.options())?;
+        if !sequence_columns.is_empty() {
+            let columns = table_default_schema
+                .fields()
+                .iter()
+                .enumerate()
+                .filter(|(_, f)| !source_schema.has_field(f.name()))
+                .filter_map(|(offset, f)| {
+                    sequence_columns
+                        .get(f.column_id())
+                        .map(|column| SequenceColumnDesc {
+                            offset,
+                            name: f.name().clone(),
+                            sequence: column.sequence.clone(),
+                            data_type: f.data_type().into(),
+                        })
+                })
+                .collect::<Vec<_>>();
+            if !columns.is_empty() {
+                let tenant = ctx.get_tenant();
+                let func_ctx = ctx.get_function_context()?;
+                pipeline.add_transform(|transform_input_port, transform_output_port| {
+                    Ok(ProcessorPtr::create(
+                        TransformAddSequenceColumns::try_create(
+                            tenant.clone(),
+                            func_ctx.clone(),
+                            columns.clone(),
+                            transform_input_port,
+                            transform_output_port,
+                        )?,
+                    ))
+                })?;
+            }
+        }
+
         // Fill computed columns.
         if default_schema != computed_schema {
             pipeline.add_transform(|transform_input_port, transform_output_port| {
@@ -68,6 +111,28 @@ impl PipelineBuilder {
             })?;
         }
 
+        // Encrypt crypto-shredded columns.
+        if let Some(shred_columns) = CryptoShredColumns::from_options(table.options())? {
+            let subject_offset = computed_schema.index_of(&shred_columns.subject_column)?;
+            let offsets = shred_columns
+                .columns
+                .iter()
+                .map(|c| computed_schema.index_of(c))
+                .collect::<Result<Vec<_>>>()?;
+            let tenant = ctx.get_tenant();
+            pipeline.add_transform(|transform_input_port, transform_output_port| {
+                Ok(ProcessorPtr::create(
+                    TransformEncryptShredColumns::try_create(
+                        tenant.clone(),
+                        subject_offset,
+                        offsets.clone(),
+                        transform_input_port,
+                        transform_output_port,
+                    )?,
+                ))
+            })?;
+        }
+
         // Fill stream columns.
         if table.change_tracking_enabled() {
             let version = table.get_table_info().ident.seq;
@@ -17,6 +17,9 @@ use common_exception::Result;
 use common_expression::type_check::check_function;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_pipeline_core::processors::ProcessorPtr;
+use common_pipeline_transforms::processors::AccumulatingTransformer;
+use common_pipeline_transforms::processors::BlockResizePolicy;
+use common_pipeline_transforms::processors::TransformBlockResize;
 use common_pipeline_transforms::processors::TransformProfileWrapper;
 use common_pipeline_transforms::processors::Transformer;
 use common_sql::evaluator::BlockOperator;
@@ -69,6 +72,21 @@ impl PipelineBuilder {
             }
         })?;
 
+        // A selective filter leaves small blocks behind, merge them for the downstream operators.
+        if self.settings.get_enable_block_resize()? {
+            let policy = BlockResizePolicy::new(
+                self.settings.get_max_block_size()? as usize,
+                self.settings.get_block_resize_max_bytes()?,
+            );
+            self.main_pipeline.add_transform(|input, output| {
+                Ok(ProcessorPtr::create(AccumulatingTransformer::create(
+                    input,
+                    output,
+                    TransformBlockResize::create(policy),
+                )))
+            })?;
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,78 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_pipeline_core::processors::ProcessorPtr;
+use common_pipeline_transforms::processors::ProcessorProfileWrapper;
+use common_sql::executor::physical_plans::GapFill;
+
+use crate::pipelines::processors::transforms::TransformGapFill;
+use crate::pipelines::PipelineBuilder;
+
+impl PipelineBuilder {
+    pub(crate) fn build_gap_fill(&mut self, gap_fill: &GapFill) -> Result<()> {
+        self.build_pipeline(&gap_fill.input)?;
+
+        let input_schema = gap_fill.input.output_schema()?;
+        let output_schema = gap_fill.output_schema()?;
+
+        let time_offset = input_schema.index_of(&gap_fill.time_column.to_string())?;
+        let partition_offsets = gap_fill
+            .partition_by
+            .iter()
+            .map(|index| input_schema.index_of(&index.to_string()))
+            .collect::<Result<Vec<_>>>()?;
+        let filled = input_schema
+            .fields()
+            .iter()
+            .map(|field| {
+                gap_fill
+                    .items
+                    .iter()
+                    .any(|item| item.input.to_string() == *field.name())
+            })
+            .collect::<Vec<_>>();
+        let output_types = output_schema
+            .fields()
+            .iter()
+            .map(|field| field.data_type().clone())
+            .collect::<Vec<_>>();
+
+        // `TransformGapFill` is a pipeline breaker, all the series are filled by a single processor.
+        self.main_pipeline.try_resize(1)?;
+        self.main_pipeline.add_transform(|input, output| {
+            let transform = TransformGapFill::try_create(
+                input,
+                output,
+                time_offset,
+                partition_offsets.clone(),
+                filled.clone(),
+                output_types.clone(),
+                gap_fill.interval,
+                gap_fill.start,
+                gap_fill.end,
+                gap_fill.method,
+            )?;
+            if self.enable_profiling {
+                Ok(ProcessorPtr::create(ProcessorProfileWrapper::create(
+                    transform,
+                    gap_fill.plan_id,
+                    self.proc_profs.clone(),
+                )))
+            } else {
+                Ok(ProcessorPtr::create(transform))
+            }
+        })
+    }
+}
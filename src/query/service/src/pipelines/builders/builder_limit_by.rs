@@ -0,0 +1,50 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_pipeline_core::processors::ProcessorPtr;
+use common_pipeline_transforms::processors::ProcessorProfileWrapper;
+use common_sql::executor::physical_plans::LimitBy;
+
+use crate::pipelines::processors::transforms::TransformLimitBy;
+use crate::pipelines::PipelineBuilder;
+
+impl PipelineBuilder {
+    pub(crate) fn build_limit_by(&mut self, limit_by: &LimitBy) -> Result<()> {
+        self.build_pipeline(&limit_by.input)?;
+
+        let input_schema = limit_by.input.output_schema()?;
+        let key_offsets = limit_by
+            .by
+            .iter()
+            .map(|index| input_schema.index_of(&index.to_string()))
+            .collect::<Result<Vec<_>>>()?;
+
+        // The rows must be counted in the order of the input, e.g. sorted by `ORDER BY`.
+        self.main_pipeline.try_resize(1)?;
+        self.main_pipeline.add_transform(|input, output| {
+            let transform =
+                TransformLimitBy::try_create(input, output, limit_by.limit, key_offsets.clone())?;
+            if self.enable_profiling {
+                Ok(ProcessorPtr::create(ProcessorProfileWrapper::create(
+                    transform,
+                    limit_by.plan_id,
+                    self.proc_profs.clone(),
+                )))
+            } else {
+                Ok(ProcessorPtr::create(transform))
+            }
+        })
+    }
+}
@@ -22,6 +22,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataSchema;
 use common_expression::DataSchemaRef;
+use common_expression::TableSchema;
 use common_expression::ROW_NUMBER_COL_NAME;
 use common_pipeline_core::processors::InputPort;
 use common_pipeline_core::processors::OutputPort;
@@ -30,6 +31,7 @@ use common_pipeline_core::Pipe;
 use common_pipeline_core::PipeItem;
 use common_pipeline_core::TransformPipeBuilder;
 use common_pipeline_transforms::processors::create_dummy_item;
+use common_pipeline_transforms::processors::Transformer;
 use common_sql::binder::MergeIntoType;
 use common_sql::evaluator::BlockOperator;
 use common_sql::evaluator::CompoundBlockOperator;
@@ -45,15 +47,41 @@ use common_storages_fuse::operations::RowNumberAndLogSplitProcessor;
 use common_storages_fuse::operations::TransformAddRowNumberColumnProcessor;
 use common_storages_fuse::operations::TransformSerializeBlock;
 use common_storages_fuse::FuseTable;
+use storages_common_table_meta::table::SequenceColumns;
 
 use crate::pipelines::processors::transforms::AccumulateRowNumber;
 use crate::pipelines::processors::transforms::ExtractHashTableByRowNumber;
+use crate::pipelines::processors::transforms::SequenceColumnDesc;
 use crate::pipelines::processors::transforms::TransformAddComputedColumns;
+use crate::pipelines::processors::transforms::TransformAddSequenceColumns;
 use crate::pipelines::processors::DeduplicateRowNumber;
 use crate::pipelines::processors::TransformResortAddOnWithoutSourceSchema;
 use crate::pipelines::PipelineBuilder;
 
 impl PipelineBuilder {
+    // The sequence columns of the table, which are filled when not provided by the insert clause.
+    fn merge_into_sequence_columns(
+        table: &dyn Table,
+        table_default_schema: &TableSchema,
+    ) -> Result<Vec<SequenceColumnDesc>> {
+        let sequence_columns = SequenceColumns::from_options(table.options())?;
+        Ok(table_default_schema
+            .fields()
+            .iter()
+            .enumerate()
+            .filter_map(|(offset, f)| {
+                sequence_columns
+                    .get(f.column_id())
+                    .map(|column| SequenceColumnDesc {
+                        offset,
+                        name: f.name().clone(),
+                        sequence: column.sequence.clone(),
+                        data_type: f.data_type().into(),
+                    })
+            })
+            .collect())
+    }
+
     // Build and add row_number column
     pub(crate) fn build_add_row_number(
         &mut self,
@@ -156,6 +184,8 @@ impl PipelineBuilder {
             .build_table_by_table_info(catalog_info, table_info, None)?;
         // 1.fill default columns
         let table_default_schema = &tbl.schema().remove_computed_fields();
+        let sequence_columns =
+            Self::merge_into_sequence_columns(tbl.as_ref(), table_default_schema)?;
         let mut builder = self.main_pipeline.add_transform_with_specified_len(
             |transform_input_port, transform_output_port| {
                 TransformResortAddOnWithoutSourceSchema::try_create(
@@ -164,6 +194,7 @@ impl PipelineBuilder {
                     transform_output_port,
                     Arc::new(DataSchema::from(table_default_schema)),
                     tbl.clone(),
+                    !sequence_columns.is_empty(),
                 )
             },
             1,
@@ -171,6 +202,27 @@ impl PipelineBuilder {
         builder.add_items(vec![create_dummy_item()]);
         self.main_pipeline.add_pipe(builder.finalize());
 
+        // fill sequence columns which are not provided by the insert clause
+        if !sequence_columns.is_empty() {
+            let tenant = self.ctx.get_tenant();
+            builder = self.main_pipeline.add_transform_with_specified_len(
+                |transform_input_port, transform_output_port| {
+                    Ok(ProcessorPtr::create(
+                        TransformAddSequenceColumns::try_create(
+                            tenant.clone(),
+                            self.func_ctx.clone(),
+                            sequence_columns.clone(),
+                            transform_input_port,
+                            transform_output_port,
+                        )?,
+                    ))
+                },
+                1,
+            )?;
+            builder.add_items(vec![create_dummy_item()]);
+            self.main_pipeline.add_pipe(builder.finalize());
+        }
+
         // 2.fill computed columns
         let table_computed_schema = &tbl.schema().remove_virtual_computed_fields();
         let default_schema: DataSchemaRef = Arc::new(table_default_schema.into());
@@ -192,8 +244,24 @@ impl PipelineBuilder {
             self.main_pipeline.add_pipe(builder.finalize());
         }
 
-        // 3. cluster sort
+        // 3. check constraints
         let table = FuseTable::try_from_table(tbl.as_ref())?;
+        if let Some(transform) = table.check_constraints_transform(self.ctx.clone(), None)? {
+            builder = self.main_pipeline.add_transform_with_specified_len(
+                |transform_input_port, transform_output_port| {
+                    Ok(ProcessorPtr::create(Transformer::create(
+                        transform_input_port,
+                        transform_output_port,
+                        transform.clone(),
+                    )))
+                },
+                1,
+            )?;
+            builder.add_items(vec![create_dummy_item()]);
+            self.main_pipeline.add_pipe(builder.finalize());
+        }
+
+        // 4. cluster sort
         let block_thresholds = table.get_block_thresholds();
         table.cluster_gen_for_append_with_specified_len(
             self.ctx.clone(),
@@ -203,7 +271,7 @@ impl PipelineBuilder {
             1,
         )?;
 
-        // 4. serialize block
+        // 5. serialize block
         let cluster_stats_gen =
             table.get_cluster_stats_gen(self.ctx.clone(), 0, block_thresholds, None)?;
         let serialize_block_transform = TransformSerializeBlock::try_create(
@@ -220,7 +288,7 @@ impl PipelineBuilder {
         ];
         self.main_pipeline.add_pipe(Pipe::create(2, 2, pipe_items));
 
-        // 5. serialize segment
+        // 6. serialize segment
         let serialize_segment_transform = TransformSerializeSegment::new(
             self.ctx.clone(),
             InputPort::create(),
@@ -538,6 +606,8 @@ impl PipelineBuilder {
 
         // fill default columns
         let table_default_schema = &table.schema().remove_computed_fields();
+        let sequence_columns =
+            Self::merge_into_sequence_columns(tbl.as_ref(), table_default_schema)?;
         let mut builder = self.main_pipeline.add_transform_with_specified_len(
             |transform_input_port, transform_output_port| {
                 TransformResortAddOnWithoutSourceSchema::try_create(
@@ -546,6 +616,7 @@ impl PipelineBuilder {
                     transform_output_port,
                     Arc::new(DataSchema::from(table_default_schema)),
                     tbl.clone(),
+                    !sequence_columns.is_empty(),
                 )
             },
             fill_default_len,
@@ -571,6 +642,26 @@ impl PipelineBuilder {
 
         self.main_pipeline
             .add_pipe(add_builder_pipe(builder, distributed));
+        // fill sequence columns which are not provided by the insert clauses
+        if !sequence_columns.is_empty() {
+            let tenant = self.ctx.get_tenant();
+            builder = self.main_pipeline.add_transform_with_specified_len(
+                |transform_input_port, transform_output_port| {
+                    Ok(ProcessorPtr::create(
+                        TransformAddSequenceColumns::try_create(
+                            tenant.clone(),
+                            self.func_ctx.clone(),
+                            sequence_columns.clone(),
+                            transform_input_port,
+                            transform_output_port,
+                        )?,
+                    ))
+                },
+                fill_default_len,
+            )?;
+            self.main_pipeline
+                .add_pipe(add_builder_pipe(builder, distributed));
+        }
         // fill computed columns
         let table_computed_schema = &table.schema().remove_virtual_computed_fields();
         let default_schema: DataSchemaRef = Arc::new(table_default_schema.into());
@@ -592,6 +683,22 @@ impl PipelineBuilder {
                 .add_pipe(add_builder_pipe(builder, distributed));
         }
 
+        // check constraints
+        if let Some(transform) = table.check_constraints_transform(self.ctx.clone(), None)? {
+            builder = self.main_pipeline.add_transform_with_specified_len(
+                |transform_input_port, transform_output_port| {
+                    Ok(ProcessorPtr::create(Transformer::create(
+                        transform_input_port,
+                        transform_output_port,
+                        transform.clone(),
+                    )))
+                },
+                fill_default_len,
+            )?;
+            self.main_pipeline
+                .add_pipe(add_builder_pipe(builder, distributed));
+        }
+
         let max_threads = self.settings.get_max_threads()?;
         let io_request_semaphore = Arc::new(Semaphore::new(max_threads as usize));
 
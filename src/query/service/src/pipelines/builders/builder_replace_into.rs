@@ -318,6 +318,19 @@ impl PipelineBuilder {
             Arc::new(table_schema.clone().into()),
         )?;
 
+        // The rows to be deleted are not written, they are not checked.
+        let deletion = delete_when.as_ref().map(|(remote_expr, _)| {
+            let expr = remote_expr
+                .as_expr(&BUILTIN_FUNCTIONS)
+                .project_column_ref(|_| delete_column_idx);
+            (delete_column_idx, expr)
+        });
+        table.add_check_constraints_transform_with_deletion(
+            self.ctx.clone(),
+            &mut self.main_pipeline,
+            deletion,
+        )?;
+
         let _ = table.cluster_gen_for_append(
             self.ctx.clone(),
             &mut self.main_pipeline,
@@ -389,6 +402,8 @@ pub struct ValueSource {
     schema: DataSchemaRef,
     metadata: MetadataRef,
     start: usize,
+    // the position in `data` of the next rows to decode
+    offset: usize,
     is_finished: bool,
 }
 
@@ -407,8 +422,12 @@ impl AsyncSource for ValueSource {
         let format = self.ctx.get_format_settings()?;
         let field_decoder = FastFieldDecoderValues::create_for_insert(format);
 
-        let mut values_decoder = FastValuesDecoder::new(&self.data, &field_decoder);
-        let estimated_rows = values_decoder.estimated_rows();
+        let mut values_decoder = FastValuesDecoder::new(&self.data[self.offset..], &field_decoder);
+        if self.offset != 0 {
+            values_decoder = values_decoder.with_leading_separator();
+        }
+        let max_block_size = self.ctx.get_settings().get_max_block_size()?.max(1) as usize;
+        let estimated_rows = values_decoder.estimated_rows().min(max_block_size);
 
         let mut columns = self
             .schema
@@ -417,14 +436,20 @@ impl AsyncSource for ValueSource {
             .map(|f| ColumnBuilder::with_capacity(f.data_type(), estimated_rows))
             .collect::<Vec<_>>();
 
-        values_decoder.parse(&mut columns, self).await?;
+        // Emit the rows in blocks of at most `max_block_size` rows, so that a huge
+        // multi-row INSERT does not end up as a single block.
+        let finished = values_decoder
+            .parse_rows(&mut columns, self, max_block_size)
+            .await?;
+        let position = values_decoder.position();
+        self.offset += position;
 
         let columns = columns
             .into_iter()
             .map(|col| col.build())
             .collect::<Vec<_>>();
         let block = DataBlock::new_from_columns(columns);
-        self.is_finished = true;
+        self.is_finished = finished;
         Ok(Some(block))
     }
 }
@@ -455,7 +480,9 @@ impl FastValuesDecodeFallback for ValueSource {
             // the error span to have an offset, so we adjust the span accordingly.
             if let Some(span) = err.span() {
                 err = err.set_span(Some(
-                    (span.start() + self.start..span.end() + self.start).into(),
+                    (span.start() + self.start + self.offset
+                        ..span.end() + self.start + self.offset)
+                        .into(),
                 ));
             }
             err
@@ -482,6 +509,7 @@ impl ValueSource {
             bind_context,
             metadata,
             start,
+            offset: 0,
             is_finished: false,
         }
     }
@@ -28,10 +28,12 @@ impl PipelineBuilder {
         self.build_pipeline(&eval_scalar.input)?;
 
         let input_schema = eval_scalar.input.output_schema()?;
+        // The common subexpressions are evaluated first, the exprs refer to their results.
         let exprs = eval_scalar
-            .exprs
+            .common_exprs
             .iter()
-            .map(|(scalar, _)| scalar.as_expr(&BUILTIN_FUNCTIONS))
+            .chain(eval_scalar.exprs.iter().map(|(scalar, _)| scalar))
+            .map(|scalar| scalar.as_expr(&BUILTIN_FUNCTIONS))
             .collect::<Vec<_>>();
 
         if exprs.is_empty() {
@@ -30,8 +30,10 @@ use common_sql::executor::physical_plans::ConstantTableScan;
 use common_sql::executor::physical_plans::CteScan;
 use common_sql::executor::physical_plans::TableScan;
 use common_storages_fuse::operations::FillInternalColumnProcessor;
+use storages_common_table_meta::table::CryptoShredColumns;
 
 use crate::pipelines::processors::transforms::MaterializedCteSource;
+use crate::pipelines::processors::transforms::TransformDecryptShredColumns;
 use crate::pipelines::PipelineBuilder;
 
 impl PipelineBuilder {
@@ -70,7 +72,7 @@ impl PipelineBuilder {
             })?;
         }
 
-        // Fill internal columns if needed.
+        // Fill internal columns if needed, other tables output them from their sources.
         if let Some(internal_columns) = &scan.internal_column {
             if table.support_row_id_column() {
                 self.main_pipeline.add_transform(|input, output| {
@@ -82,15 +84,46 @@ impl PipelineBuilder {
                         ),
                     )))
                 })?;
-            } else {
+            } else if let Some(internal_column) = internal_columns
+                .values()
+                .find(|c| !table.support_internal_column(c.column_id()))
+            {
                 return Err(ErrorCode::TableEngineNotSupported(format!(
-                    "Table engine `{}` does not support virtual column _row_id",
-                    table.engine()
+                    "Table engine `{}` does not support internal column {}",
+                    table.engine(),
+                    internal_column.column_name()
                 )));
             }
         }
 
         let schema = scan.source.schema();
+
+        // Decrypt crypto-shredded columns.
+        if let Some(shred_columns) = CryptoShredColumns::from_options(table.options())? {
+            let offsets = schema
+                .fields()
+                .iter()
+                .enumerate()
+                .filter(|(_, f)| shred_columns.contains(f.name()))
+                .map(|(offset, _)| offset)
+                .collect::<Vec<_>>();
+            if !offsets.is_empty() {
+                let tenant = self.ctx.get_tenant();
+                let anonymize = self.settings.get_anonymize_crypto_shred_columns()?;
+                self.main_pipeline.add_transform(|input, output| {
+                    Ok(ProcessorPtr::create(
+                        TransformDecryptShredColumns::try_create(
+                            tenant.clone(),
+                            offsets.clone(),
+                            anonymize,
+                            input,
+                            output,
+                        )?,
+                    ))
+                })?;
+            }
+        }
+
         let mut projection = scan
             .name_mapping
             .keys()
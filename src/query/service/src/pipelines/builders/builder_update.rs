@@ -47,6 +47,7 @@ impl PipelineBuilder {
             update.query_row_id_col,
             &mut self.main_pipeline,
         )?;
+        table.add_check_constraints_transform(self.ctx.clone(), &mut self.main_pipeline)?;
 
         if table.change_tracking_enabled() {
             let func_ctx = self.ctx.get_function_context()?;
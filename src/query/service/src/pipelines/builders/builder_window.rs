@@ -75,6 +75,7 @@ impl PipelineBuilder {
         // `TransformWindow` is a pipeline breaker.
         self.main_pipeline.try_resize(1)?;
         let func = WindowFunctionInfo::try_create(&window.func, &input_schema)?;
+        let exclusion = window.window_frame.exclusion;
         // Window
         self.main_pipeline.add_transform(|input, output| {
             // The transform can only be created here, because it cannot be cloned.
@@ -82,14 +83,17 @@ impl PipelineBuilder {
             let transform = if window.window_frame.units.is_rows() {
                 let start_bound = FrameBound::try_from(&window.window_frame.start_bound)?;
                 let end_bound = FrameBound::try_from(&window.window_frame.end_bound)?;
-                Box::new(TransformWindow::<u64>::try_create_rows(
-                    input,
-                    output,
-                    func.clone(),
-                    partition_by.clone(),
-                    order_by.clone(),
-                    (start_bound, end_bound),
-                )?) as Box<dyn Processor>
+                Box::new(
+                    TransformWindow::<u64>::try_create_rows(
+                        input,
+                        output,
+                        func.clone(),
+                        partition_by.clone(),
+                        order_by.clone(),
+                        (start_bound, end_bound),
+                    )?
+                    .with_exclusion(exclusion),
+                ) as Box<dyn Processor>
             } else {
                 if order_by.len() == 1 {
                     // If the length of order_by is 1, there may be a RANGE frame.
@@ -110,7 +114,8 @@ impl PipelineBuilder {
                                     partition_by.clone(),
                                     order_by.clone(),
                                     (start_bound, end_bound),
-                                )?,
+                                )?
+                                .with_exclusion(exclusion),
                             )
                                 as Box<dyn Processor>));
                         }
@@ -122,14 +127,17 @@ impl PipelineBuilder {
                 // So we can use any number type to create the transform.
                 let start_bound = FrameBound::try_from(&window.window_frame.start_bound)?;
                 let end_bound = FrameBound::try_from(&window.window_frame.end_bound)?;
-                Box::new(TransformWindow::<u8>::try_create_range(
-                    input,
-                    output,
-                    func.clone(),
-                    partition_by.clone(),
-                    order_by.clone(),
-                    (start_bound, end_bound),
-                )?) as Box<dyn Processor>
+                Box::new(
+                    TransformWindow::<u8>::try_create_range(
+                        input,
+                        output,
+                        func.clone(),
+                        partition_by.clone(),
+                        order_by.clone(),
+                        (start_bound, end_bound),
+                    )?
+                    .with_exclusion(exclusion),
+                ) as Box<dyn Processor>
             };
             Ok(ProcessorPtr::create(transform))
         })?;
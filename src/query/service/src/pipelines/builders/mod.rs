@@ -16,14 +16,17 @@ mod builder_aggregate;
 mod builder_append_table;
 mod builder_commit;
 mod builder_compact;
+mod builder_connect_by;
 mod builder_copy_into;
 mod builder_delete;
 mod builder_distributed_insert_select;
 mod builder_exchange;
 mod builder_fill_missing_columns;
 mod builder_filter;
+mod builder_gap_fill;
 mod builder_join;
 mod builder_limit;
+mod builder_limit_by;
 mod builder_merge_into;
 mod builder_on_finished;
 mod builder_project;
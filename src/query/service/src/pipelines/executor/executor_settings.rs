@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicU64;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -22,17 +24,38 @@ use common_settings::Settings;
 pub struct ExecutorSettings {
     pub query_id: Arc<String>,
     pub enable_profiling: bool,
+    pub enable_column_arena: bool,
     pub max_execute_time_in_seconds: Duration,
+    /// The CPU time in nanoseconds consumed by the executor threads, shared with the query
+    /// context when the pipeline belongs to a query.
+    pub cpu_time: Arc<AtomicU64>,
+    /// If set, the executor stops without error when the execution time limit is exceeded and
+    /// raises this flag to mark the result as partial.
+    pub partial_result: Option<Arc<AtomicBool>>,
 }
 
 impl ExecutorSettings {
     pub fn try_create(settings: &Settings, query_id: String) -> Result<ExecutorSettings> {
         let enable_profiling = settings.get_enable_query_profiling()?;
+        let enable_column_arena = settings.get_enable_column_arena()?;
         let max_execute_time_in_seconds = settings.get_max_execute_time_in_seconds()?;
         Ok(ExecutorSettings {
             enable_profiling,
+            enable_column_arena,
             query_id: Arc::new(query_id),
             max_execute_time_in_seconds: Duration::from_secs(max_execute_time_in_seconds),
+            cpu_time: Arc::new(AtomicU64::new(0)),
+            partial_result: None,
         })
     }
+
+    pub fn with_cpu_time(mut self, cpu_time: Arc<AtomicU64>) -> ExecutorSettings {
+        self.cpu_time = cpu_time;
+        self
+    }
+
+    pub fn with_partial_result(mut self, partial_result: Arc<AtomicBool>) -> ExecutorSettings {
+        self.partial_result = Some(partial_result);
+        self
+    }
 }
@@ -28,6 +28,8 @@ use common_base::runtime::TrySpawn;
 use common_base::GLOBAL_TASK;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::utils::column_arena::ColumnArenaGuard;
+use common_expression::utils::column_arena::ColumnArenaStats;
 use common_pipeline_core::processors::profile::Profile;
 use common_pipeline_core::LockGuard;
 use common_pipeline_core::Pipeline;
@@ -64,6 +66,7 @@ pub struct PipelineExecutor {
     settings: ExecutorSettings,
     finished_notify: Arc<Notify>,
     finished_error: Mutex<Option<ErrorCode>>,
+    column_arena_stats: Arc<ColumnArenaStats>,
     #[allow(unused)]
     lock_guards: Vec<LockGuard>,
 }
@@ -195,6 +198,7 @@ impl PipelineExecutor {
             async_runtime: GlobalIORuntime::instance(),
             settings,
             finished_error: Mutex::new(None),
+            column_arena_stats: ColumnArenaStats::create(),
             finished_notify: Arc::new(Notify::new()),
             lock_guards,
         }))
@@ -228,6 +232,10 @@ impl PipelineExecutor {
         self.global_tasks_queue.is_finished()
     }
 
+    fn is_partial_result(&self) -> bool {
+        matches!(&self.settings.partial_result, Some(partial_result) if partial_result.load(Ordering::Acquire))
+    }
+
     #[minitrace::trace]
     pub fn execute(self: &Arc<Self>) -> Result<()> {
         self.init()?;
@@ -258,11 +266,29 @@ impl PipelineExecutor {
             }
         }
 
+        // The graph is interrupted on purpose when the result is allowed to be partial.
         if let Err(error) = self.graph.assert_finished_graph() {
+            if self.is_partial_result() {
+                self.on_finished(&None)?;
+                return Ok(());
+            }
+
             self.on_finished(&Some(error.clone()))?;
             return Err(error);
         }
 
+        if self.settings.enable_column_arena {
+            let stats = &self.column_arena_stats;
+            info!(
+                "Column arena of pipeline, query_id: {:?}, allocations: {}, reused: {}, reused bytes: {}, recycled: {}",
+                self.settings.query_id,
+                stats.allocations.load(Ordering::Relaxed),
+                stats.reused.load(Ordering::Relaxed),
+                stats.reused_bytes.load(Ordering::Relaxed),
+                stats.recycled.load(Ordering::Relaxed),
+            );
+        }
+
         self.on_finished(&None)?;
         Ok(())
     }
@@ -318,14 +344,25 @@ impl PipelineExecutor {
             let this = Arc::downgrade(self);
             let max_execute_time_in_seconds = self.settings.max_execute_time_in_seconds;
             let finished_notify = self.finished_notify.clone();
+            let partial_result = self.settings.partial_result.clone();
             self.async_runtime.spawn(GLOBAL_TASK, async move {
                 let finished_future = Box::pin(finished_notify.notified());
                 let max_execute_future = Box::pin(tokio::time::sleep(max_execute_time_in_seconds));
                 if let Either::Left(_) = select(max_execute_future, finished_future).await {
                     if let Some(executor) = this.upgrade() {
-                        executor.finish(Some(ErrorCode::AbortedQuery(
-                            "Aborted query, because the execution time exceeds the maximum execution time limit",
-                        )));
+                        match partial_result {
+                            Some(partial_result) => {
+                                warn!(
+                                    "Query {:?} exceeds the maximum execution time limit, stop it and return the partial result",
+                                    executor.settings.query_id
+                                );
+                                partial_result.store(true, Ordering::Release);
+                                executor.finish(None);
+                            }
+                            None => executor.finish(Some(ErrorCode::AbortedQuery(
+                                "Aborted query, because the execution time exceeds the maximum execution time limit",
+                            ))),
+                        }
                     }
                 }
             });
@@ -397,6 +434,13 @@ impl PipelineExecutor {
         self: &Arc<Self>,
         thread_num: usize,
     ) -> Result<()> {
+        // Each worker recycles the transient columns through its own arena, the arena
+        // is freed when the worker exits.
+        let _column_arena_guard = self
+            .settings
+            .enable_column_arena
+            .then(|| ColumnArenaGuard::enter(self.column_arena_stats.clone()));
+
         let workers_condvar = self.workers_condvar.clone();
         let mut context = ExecutorWorkerContext::create(
             thread_num,
@@ -404,6 +448,7 @@ impl PipelineExecutor {
             self.settings.query_id.clone(),
         );
 
+        let mut cpu_time = Thread::cpu_time();
         while !self.global_tasks_queue.is_finished() {
             // When there are not enough tasks, the thread will be blocked, so we need loop check.
             while !self.global_tasks_queue.is_finished() && !context.has_task() {
@@ -414,6 +459,14 @@ impl PipelineExecutor {
                 let (executed_pid, is_async, elapsed) =
                     context.execute_task::<ENABLE_PROFILING>()?;
 
+                // Charge the CPU time to the query before the task may finish the pipeline.
+                let now = Thread::cpu_time();
+                let consumed = now.saturating_sub(cpu_time).as_nanos() as u64;
+                self.settings
+                    .cpu_time
+                    .fetch_add(consumed, Ordering::Relaxed);
+                cpu_time = now;
+
                 if ENABLE_PROFILING {
                     let node = self.graph.get_node(executed_pid);
                     if let Some(elapsed) = elapsed {
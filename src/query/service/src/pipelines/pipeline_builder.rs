@@ -19,6 +19,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataField;
 use common_expression::FunctionContext;
+use common_expression::GroupKeysDictionary;
 use common_pipeline_core::Pipeline;
 use common_pipeline_core::PlanScope;
 use common_profile::SharedProcessorProfiles;
@@ -51,6 +52,8 @@ pub struct PipelineBuilder {
 
     // Cte -> state, each cte has it's own state
     pub cte_state: HashMap<IndexType, Arc<MaterializedCteState>>,
+    // The dictionary shared by the partial aggregation with the final aggregation above it
+    pub group_keys_dictionary: Option<Arc<GroupKeysDictionary>>,
 
     pub(crate) enable_profiling: bool,
     pub(crate) proc_profs: SharedProcessorProfiles,
@@ -78,6 +81,7 @@ impl PipelineBuilder {
             exchange_injector: DefaultExchangeInjector::create(),
             index: None,
             cte_state: HashMap::new(),
+            group_keys_dictionary: None,
             probe_data_fields: None,
         }
     }
@@ -120,7 +124,10 @@ impl PipelineBuilder {
             PhysicalPlan::AggregateFinal(aggregate) => self.build_aggregate_final(aggregate),
             PhysicalPlan::Window(window) => self.build_window(window),
             PhysicalPlan::Sort(sort) => self.build_sort(sort),
+            PhysicalPlan::GapFill(gap_fill) => self.build_gap_fill(gap_fill),
+            PhysicalPlan::ConnectBy(connect_by) => self.build_connect_by(connect_by),
             PhysicalPlan::Limit(limit) => self.build_limit(limit),
+            PhysicalPlan::LimitBy(limit_by) => self.build_limit_by(limit_by),
             PhysicalPlan::RowFetch(row_fetch) => self.build_row_fetch(row_fetch),
             PhysicalPlan::HashJoin(join) => self.build_join(join),
             PhysicalPlan::ExchangeSink(sink) => self.build_exchange_sink(sink),
@@ -17,7 +17,10 @@ use std::sync::Arc;
 
 use common_exception::Result;
 use common_expression::types::DataType;
+use common_expression::Column;
+use common_expression::DataBlock;
 use common_expression::DataSchemaRef;
+use common_expression::GroupKeysDictionary;
 use common_functions::aggregates::get_layout_offsets;
 use common_functions::aggregates::AggregateFunctionRef;
 use common_functions::aggregates::StateAddr;
@@ -29,6 +32,9 @@ pub struct AggregatorParams {
     pub input_schema: DataSchemaRef,
     pub group_columns: Vec<IndexType>,
     pub group_data_types: Vec<DataType>,
+    // If set, the string group by keys are replaced by their ids in the dictionary
+    // before building the hash keys, and `group_data_types` are the types of the ids.
+    pub group_dictionary: Option<Arc<GroupKeysDictionary>>,
 
     pub aggregate_functions: Vec<AggregateFunctionRef>,
     pub aggregate_functions_arguments: Vec<Vec<usize>>,
@@ -46,6 +52,7 @@ impl AggregatorParams {
     pub fn try_create(
         input_schema: DataSchemaRef,
         group_data_types: Vec<DataType>,
+        group_dictionary: Option<Arc<GroupKeysDictionary>>,
         group_columns: &[usize],
         agg_funcs: &[AggregateFunctionRef],
         agg_args: &[Vec<usize>],
@@ -62,6 +69,7 @@ impl AggregatorParams {
             input_schema,
             group_columns: group_columns.to_vec(),
             group_data_types,
+            group_dictionary,
             aggregate_functions: agg_funcs.to_vec(),
             aggregate_functions_arguments: agg_args.to_vec(),
             layout: states_layout,
@@ -82,6 +90,23 @@ impl AggregatorParams {
         place
     }
 
+    /// Returns the group by columns of the block, with the strings replaced by their ids
+    /// if the keys are packed through the dictionary.
+    pub fn group_columns_of(&self, block: &DataBlock) -> Result<Vec<(Column, DataType)>> {
+        self.group_columns
+            .iter()
+            .zip(self.group_data_types.iter())
+            .map(|(&index, data_type)| {
+                let column = block.get_by_offset(index).value.as_column().unwrap();
+                let column = match &self.group_dictionary {
+                    Some(dictionary) => dictionary.encode(column)?,
+                    None => column.clone(),
+                };
+                Ok((column, data_type.clone()))
+            })
+            .collect()
+    }
+
     pub fn has_distinct_combinator(&self) -> bool {
         self.aggregate_functions
             .iter()
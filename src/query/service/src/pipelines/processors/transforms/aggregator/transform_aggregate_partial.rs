@@ -222,17 +222,11 @@ impl<Method: HashMethodBounds> TransformPartialAggregate<Method> {
             .map(|index| index.is_agg)
             .unwrap_or_default();
 
-        let block = block.convert_to_full();
+        let block = block.consume_convert_to_full();
 
-        let group_columns = self
-            .params
-            .group_columns
-            .iter()
-            .map(|&index| block.get_by_offset(index))
-            .map(|c| (c.value.as_column().unwrap().clone(), c.data_type.clone()))
-            .collect::<Vec<_>>();
+        let group_columns = self.params.group_columns_of(&block)?;
 
-        unsafe {
+        let res = unsafe {
             let rows_num = block.num_rows();
             let state = self.method.build_keys_state(&group_columns, rows_num)?;
 
@@ -279,7 +273,12 @@ impl<Method: HashMethodBounds> TransformPartialAggregate<Method> {
                     }
                 }
             }
-        }
+        };
+
+        // The states have taken what they need from the block, recycle its columns.
+        drop(group_columns);
+        block.recycle();
+        res
     }
 }
 
@@ -29,7 +29,6 @@ use common_pipeline_core::processors::OutputPort;
 use common_pipeline_core::processors::Processor;
 use common_pipeline_transforms::processors::AccumulatingTransform;
 use common_pipeline_transforms::processors::AccumulatingTransformer;
-use common_sql::IndexType;
 use log::info;
 
 use crate::pipelines::processors::transforms::aggregator::aggregate_cell::GroupByHashTableDropper;
@@ -99,7 +98,7 @@ impl TryFrom<Arc<QueryContext>> for GroupBySettings {
 pub struct TransformPartialGroupBy<Method: HashMethodBounds> {
     method: Method,
     hash_table: HashTable<Method>,
-    group_columns: Vec<IndexType>,
+    params: Arc<AggregatorParams>,
     settings: GroupBySettings,
 }
 
@@ -122,7 +121,7 @@ impl<Method: HashMethodBounds> TransformPartialGroupBy<Method> {
             TransformPartialGroupBy::<Method> {
                 method,
                 hash_table,
-                group_columns: params.group_columns.clone(),
+                params,
                 settings: GroupBySettings::try_from(ctx)?,
             },
         ))
@@ -133,17 +132,8 @@ impl<Method: HashMethodBounds> AccumulatingTransform for TransformPartialGroupBy
     const NAME: &'static str = "TransformPartialGroupBy";
 
     fn transform(&mut self, block: DataBlock) -> Result<Vec<DataBlock>> {
-        let block = block.convert_to_full();
-        let group_columns = self
-            .group_columns
-            .iter()
-            .map(|&index| block.get_by_offset(index))
-            .collect::<Vec<_>>();
-
-        let group_columns = group_columns
-            .iter()
-            .map(|c| (c.value.as_column().unwrap().clone(), c.data_type.clone()))
-            .collect::<Vec<_>>();
+        let block = block.consume_convert_to_full();
+        let group_columns = self.params.group_columns_of(&block)?;
 
         unsafe {
             let rows_num = block.num_rows();
@@ -163,6 +153,11 @@ impl<Method: HashMethodBounds> AccumulatingTransform for TransformPartialGroupBy
                 }
             };
 
+            // The keys have been copied into the hash table, recycle the columns of the block.
+            drop(state);
+            drop(group_columns);
+            block.recycle();
+
             #[allow(clippy::collapsible_if)]
             if Method::SUPPORT_PARTITIONED {
                 if matches!(&self.hash_table, HashTable::HashTable(cell)
@@ -69,13 +69,19 @@ pub struct TransformPartitionBucket<Method: HashMethodBounds, V: Copy + Send + S
     initialized_all_inputs: bool,
     buckets_blocks: BTreeMap<isize, Vec<DataBlock>>,
     unsplitted_blocks: Vec<DataBlock>,
+    // Split the single level results into buckets if they hold at least this many groups.
+    split_threshold: Option<usize>,
     _phantom: PhantomData<V>,
 }
 
 impl<Method: HashMethodBounds, V: Copy + Send + Sync + 'static>
     TransformPartitionBucket<Method, V>
 {
-    pub fn create(method: Method, input_nums: usize) -> Result<Self> {
+    pub fn create(
+        method: Method,
+        input_nums: usize,
+        split_threshold: Option<usize>,
+    ) -> Result<Self> {
         let mut inputs = Vec::with_capacity(input_nums);
 
         for _index in 0..input_nums {
@@ -95,6 +101,7 @@ impl<Method: HashMethodBounds, V: Copy + Send + Sync + 'static>
             buckets_blocks: BTreeMap::new(),
             unsplitted_blocks: vec![],
             initialized_all_inputs: false,
+            split_threshold,
             _phantom: Default::default(),
         })
     }
@@ -206,6 +213,34 @@ impl<Method: HashMethodBounds, V: Copy + Send + Sync + 'static>
         SINGLE_LEVEL_BUCKET_NUM
     }
 
+    /// Whether the partial results are all single level but hold too many groups in total
+    /// to be merged by a single processor.
+    ///
+    /// Each partial aggregation only knows the number of its own groups, the total is known
+    /// only after all of them are received.
+    fn need_split_single_level(&self) -> bool {
+        let Some(split_threshold) = self.split_threshold else {
+            return false;
+        };
+
+        if !self.buckets_blocks.is_empty() || self.unsplitted_blocks.len() < 2 {
+            return false;
+        }
+
+        let mut groups = 0;
+        for data_block in &self.unsplitted_blocks {
+            if let Some(block_meta) = data_block.get_meta() {
+                groups += match AggregateMeta::<Method, V>::downcast_ref_from(block_meta) {
+                    Some(AggregateMeta::HashTable(payload)) => payload.cell.hashtable.len(),
+                    Some(AggregateMeta::Serialized(payload)) => payload.data_block.num_rows(),
+                    _ => 0,
+                };
+            }
+        }
+
+        groups >= split_threshold
+    }
+
     fn try_push_data_block(&mut self) -> bool {
         match self.buckets_blocks.is_empty() {
             true => self.try_push_single_level(),
@@ -324,8 +359,15 @@ impl<Method: HashMethodBounds, V: Copy + Send + Sync + 'static> Processor
         }
 
         // We pull the first unsplitted data block
-        if !self.initialized_all_inputs && !self.initialize_all_inputs()? {
-            return Ok(Event::NeedData);
+        if !self.initialized_all_inputs {
+            if !self.initialize_all_inputs()? {
+                return Ok(Event::NeedData);
+            }
+
+            if self.need_split_single_level() {
+                // Split the single level results, so that the buckets are merged in parallel.
+                return Ok(Event::Sync);
+            }
         }
 
         if !self.buckets_blocks.is_empty() && !self.unsplitted_blocks.is_empty() {
@@ -443,12 +485,14 @@ pub fn build_partition_bucket<Method: HashMethodBounds, V: Copy + Send + Sync +
     method: Method,
     pipeline: &mut Pipeline,
     params: Arc<AggregatorParams>,
+    split_threshold: Option<usize>,
     enable_profiling: bool,
     prof_id: u32,
     proc_profs: SharedProcessorProfiles,
 ) -> Result<()> {
     let input_nums = pipeline.output_len();
-    let transform = TransformPartitionBucket::<Method, V>::create(method.clone(), input_nums)?;
+    let transform =
+        TransformPartitionBucket::<Method, V>::create(method.clone(), input_nums, split_threshold)?;
 
     let output = transform.get_output();
     let inputs_port = transform.get_inputs();
@@ -13,12 +13,14 @@
 // limitations under the License.
 
 use std::marker::PhantomData;
+use std::sync::Arc;
 
 use common_exception::Result;
 use common_expression::types::string::StringColumnBuilder;
 use common_expression::types::DataType;
 use common_expression::Column;
 use common_expression::ColumnBuilder;
+use common_expression::GroupKeysDictionary;
 use common_expression::HashMethodFixedKeys;
 use common_hashtable::DictionaryKeys;
 
@@ -35,15 +37,30 @@ pub struct FixedKeysGroupColumnsBuilder<'a, T> {
     data: Vec<T>,
     group_column_indices: Vec<usize>,
     group_data_types: Vec<DataType>,
+    // The string columns to decode, if the keys are packed through the dictionary.
+    dictionary: Option<(Arc<GroupKeysDictionary>, Vec<bool>)>,
 }
 
 impl<'a, T> FixedKeysGroupColumnsBuilder<'a, T> {
     pub fn create(capacity: usize, params: &AggregatorParams) -> Self {
+        let dictionary = params.group_dictionary.clone().map(|dictionary| {
+            let encoded = params
+                .group_columns
+                .iter()
+                .map(|&index| {
+                    let data_type = params.input_schema.field(index).data_type();
+                    data_type.remove_nullable().is_string()
+                })
+                .collect();
+            (dictionary, encoded)
+        });
+
         Self {
             _t: Default::default(),
             data: Vec::with_capacity(capacity),
             group_column_indices: params.group_columns.clone(),
             group_data_types: params.group_data_types.clone(),
+            dictionary,
         }
     }
 }
@@ -61,7 +78,7 @@ impl<'a, T: Copy + Send + Sync + 'static> GroupColumnsBuilder
     #[inline]
     fn finish(self) -> Result<Vec<Column>> {
         let method = HashMethodFixedKeys::<T>::default();
-        method.deserialize_group_columns(
+        let columns = method.deserialize_group_columns(
             self.data,
             &self
                 .group_column_indices
@@ -69,7 +86,19 @@ impl<'a, T: Copy + Send + Sync + 'static> GroupColumnsBuilder
                 .cloned()
                 .zip(self.group_data_types.iter().cloned())
                 .collect::<Vec<_>>(),
-        )
+        )?;
+
+        Ok(match &self.dictionary {
+            None => columns,
+            Some((dictionary, encoded)) => columns
+                .iter()
+                .zip(encoded.iter())
+                .map(|(column, encoded)| match encoded {
+                    true => dictionary.decode(column),
+                    false => column.clone(),
+                })
+                .collect(),
+        })
     }
 }
 
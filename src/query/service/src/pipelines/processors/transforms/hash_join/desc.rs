@@ -39,6 +39,9 @@ pub struct HashJoinDesc {
     pub(crate) marker_join_desc: MarkJoinDesc,
     /// Whether the Join are derived from correlated subquery.
     pub(crate) from_correlated_subquery: bool,
+    /// Whether the anti join is null-aware, `marker_join_desc.has_null` records
+    /// if the build side has a NULL key.
+    pub(crate) null_aware: bool,
 }
 
 impl HashJoinDesc {
@@ -66,6 +69,7 @@ impl HashJoinDesc {
                 // marker_index: join.marker_index,
             },
             from_correlated_subquery: join.from_correlated_subquery,
+            null_aware: join.null_aware,
         })
     }
 
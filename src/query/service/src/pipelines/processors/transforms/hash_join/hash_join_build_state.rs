@@ -45,7 +45,8 @@ use common_hashtable::RawEntry;
 use common_hashtable::RowPtr;
 use common_hashtable::StringHashJoinHashMap;
 use common_hashtable::StringRawEntry;
-use common_hashtable::STRING_EARLY_SIZE;
+use common_hashtable::StringView;
+use common_hashtable::STRING_VIEW_INLINE_SIZE;
 use common_sql::plans::JoinType;
 use common_sql::ColumnSet;
 use ethnum::U256;
@@ -421,9 +422,9 @@ impl HashJoinBuildState {
 
                 let space_size = match &keys_state {
                     // safe to unwrap(): offset.len() >= 1.
-                    KeysState::Column(Column::String(col) | Column::Variant(col) | Column::Bitmap(col)) => col.offsets().last().unwrap(),
+                    KeysState::Column(Column::String(col) | Column::Variant(col) | Column::Bitmap(col) | Column::Geometry(col)) => col.offsets().last().unwrap(),
                     // The function `build_keys_state` of both HashMethodSerializer and HashMethodSingleString
-                    // must return `Column::String` | `Column::Variant` | `Column::Bitmap`.
+                    // must return `Column::String` | `Column::Variant` | `Column::Bitmap` | `Column::Geometry`.
                     _ => unreachable!(),
                 };
                 let valid_num = match &$valids {
@@ -453,17 +454,15 @@ impl HashJoinBuildState {
                             // string_offset + key.len() <= space_size.
                             unsafe {
                                 (*raw_entry_ptr).row_ptr = row_ptr;
-                                (*raw_entry_ptr).length = key.len() as u32;
                                 (*raw_entry_ptr).next = 0;
-                                (*raw_entry_ptr).key = string_local_space_ptr;
-                                // The size of `early` is 4.
-                                std::ptr::copy_nonoverlapping(
-                                    key.as_ptr(),
-                                    (*raw_entry_ptr).early.as_mut_ptr(),
-                                    std::cmp::min(STRING_EARLY_SIZE, key.len()),
-                                );
-                                std::ptr::copy_nonoverlapping(key.as_ptr(), string_local_space_ptr, key.len());
-                                string_local_space_ptr = string_local_space_ptr.add(key.len());
+                                // A short key is stored inside its view, only a long key is copied.
+                                let mut key_ptr = 0;
+                                if key.len() > STRING_VIEW_INLINE_SIZE {
+                                    key_ptr = string_local_space_ptr as u64;
+                                    std::ptr::copy_nonoverlapping(key.as_ptr(), string_local_space_ptr, key.len());
+                                    string_local_space_ptr = string_local_space_ptr.add(key.len());
+                                }
+                                (*raw_entry_ptr).key = StringView::new(key, key_ptr);
                             }
 
                             $table.insert(key, raw_entry_ptr);
@@ -482,17 +481,15 @@ impl HashJoinBuildState {
                             // string_offset + key.len() <= space_size.
                             unsafe {
                                 (*raw_entry_ptr).row_ptr = row_ptr;
-                                (*raw_entry_ptr).length = key.len() as u32;
                                 (*raw_entry_ptr).next = 0;
-                                (*raw_entry_ptr).key = string_local_space_ptr;
-                                // The size of `early` is 4.
-                                std::ptr::copy_nonoverlapping(
-                                    key.as_ptr(),
-                                    (*raw_entry_ptr).early.as_mut_ptr(),
-                                    std::cmp::min(STRING_EARLY_SIZE, key.len()),
-                                );
-                                std::ptr::copy_nonoverlapping(key.as_ptr(), string_local_space_ptr, key.len());
-                                string_local_space_ptr = string_local_space_ptr.add(key.len());
+                                // A short key is stored inside its view, only a long key is copied.
+                                let mut key_ptr = 0;
+                                if key.len() > STRING_VIEW_INLINE_SIZE {
+                                    key_ptr = string_local_space_ptr as u64;
+                                    std::ptr::copy_nonoverlapping(key.as_ptr(), string_local_space_ptr, key.len());
+                                    string_local_space_ptr = string_local_space_ptr.add(key.len());
+                                }
+                                (*raw_entry_ptr).key = StringView::new(key, key_ptr);
                             }
 
                             $table.insert(key, raw_entry_ptr);
@@ -581,6 +578,18 @@ impl HashJoinBuildState {
             }
         }
 
+        if self.hash_join_state.hash_join_desc.null_aware
+            && matches!(&valids, Some(valids) if valids.unset_bits() > 0)
+        {
+            let mut has_null_ref = self
+                .hash_join_state
+                .hash_join_desc
+                .marker_join_desc
+                .has_null
+                .write();
+            *has_null_ref = true;
+        }
+
         valids = match valids {
             Some(valids) => {
                 if valids.unset_bits() == valids.len() {
@@ -219,6 +219,31 @@ impl HashJoinProbeState {
             );
         }
 
+        // The null-aware anti join returns nothing if the build side has a NULL key, and drops
+        // the probe rows with a NULL key, unless the build side is empty.
+        if self.hash_join_state.hash_join_desc.null_aware
+            && !self.hash_join_state.fast_return.load(Ordering::Relaxed)
+        {
+            if *self
+                .hash_join_state
+                .hash_join_desc
+                .marker_join_desc
+                .has_null
+                .read()
+            {
+                return Ok(vec![]);
+            }
+            match probe_keys[0].0.validity() {
+                (true, _) => return Ok(vec![]),
+                (false, Some(validity)) if validity.unset_bits() > 0 => {
+                    let validity = validity.clone();
+                    let input = input.filter_with_bitmap(&validity)?;
+                    return self.probe_join(input, probe_state);
+                }
+                _ => (),
+            }
+        }
+
         let mut valids = None;
         if !Self::check_for_eliminate_valids(
             self.hash_join_state.hash_join_desc.from_correlated_subquery,
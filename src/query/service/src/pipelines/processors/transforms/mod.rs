@@ -22,10 +22,15 @@ pub(crate) mod range_join;
 mod runtime_filter;
 mod transform_add_computed_columns;
 mod transform_add_const_columns;
+mod transform_add_sequence_columns;
 mod transform_add_stream_columns;
 mod transform_cast_schema;
+mod transform_connect_by;
 mod transform_create_sets;
+mod transform_crypto_shred;
+mod transform_gap_fill;
 mod transform_limit;
+mod transform_limit_by;
 mod transform_materialized_cte;
 mod transform_merge_block;
 mod transform_resort_addon;
@@ -44,11 +49,18 @@ pub use range_join::RangeJoinState;
 pub use runtime_filter::RuntimeFilterState;
 pub use transform_add_computed_columns::TransformAddComputedColumns;
 pub use transform_add_const_columns::TransformAddConstColumns;
+pub use transform_add_sequence_columns::SequenceColumnDesc;
+pub use transform_add_sequence_columns::TransformAddSequenceColumns;
 pub use transform_add_stream_columns::TransformAddStreamColumns;
 pub use transform_cast_schema::TransformCastSchema;
+pub use transform_connect_by::TransformConnectBy;
 pub use transform_create_sets::SubqueryReceiver;
 pub use transform_create_sets::TransformCreateSets;
+pub use transform_crypto_shred::TransformDecryptShredColumns;
+pub use transform_crypto_shred::TransformEncryptShredColumns;
+pub use transform_gap_fill::TransformGapFill;
 pub use transform_limit::TransformLimit;
+pub use transform_limit_by::TransformLimitBy;
 pub use transform_materialized_cte::MaterializedCteSink;
 pub use transform_materialized_cte::MaterializedCteSource;
 pub use transform_materialized_cte::MaterializedCteState;
@@ -15,6 +15,7 @@
 mod ie_join_state;
 mod ie_join_util;
 mod merge_join_state;
+mod nested_loop_join_state;
 mod range_join_state;
 mod transform_range_join;
 
@@ -0,0 +1,87 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::max;
+use std::cmp::min;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::DataBlock;
+
+use crate::pipelines::processors::transforms::range_join::filter_block;
+use crate::pipelines::processors::transforms::range_join::RangeJoinState;
+
+impl RangeJoinState {
+    pub fn right_table_len(&self) -> usize {
+        self.right_table.read().len()
+    }
+
+    // Join a block of the left table, which is streamed, and a block of the cached right table
+    // by evaluating other conditions on their cross product. The cross product is generated in
+    // batches of at most `max_block_size` rows (unless a single left row is paired with more
+    // right rows), so the memory used by a task is bounded no matter how large the blocks are.
+    pub fn nested_loop_join(
+        &self,
+        left_block: &DataBlock,
+        right_idx: usize,
+    ) -> Result<Vec<DataBlock>> {
+        let right_table = self.right_table.read();
+        let right_block = &right_table[right_idx];
+
+        let left_len = left_block.num_rows();
+        let right_len = right_block.num_rows();
+        if left_len == 0 || right_len == 0 {
+            return Ok(vec![]);
+        }
+
+        let max_block_size = self.ctx.get_settings().get_max_block_size()? as usize;
+        let right_batch_size = min(right_len, max(max_block_size, 1));
+
+        let mut result_blocks = vec![];
+        for right_start in (0..right_len).step_by(right_batch_size) {
+            let right_end = min(right_start + right_batch_size, right_len);
+            let right_batch = right_block.slice(right_start..right_end);
+            let right_batch_len = right_batch.num_rows();
+            // Number of left rows paired with the whole right batch at a time
+            let left_batch_size = max(max_block_size / right_batch_len, 1);
+
+            for left_start in (0..left_len).step_by(left_batch_size) {
+                let left_end = min(left_start + left_batch_size, left_len);
+                let num_rows = (left_end - left_start) * right_batch_len;
+
+                // Each left row is repeated `right_batch_len` times, and the right batch
+                // is repeated once for each left row.
+                let left_indices = (left_start..left_end)
+                    .map(|idx| (idx as u32, right_batch_len as u32))
+                    .collect::<Vec<_>>();
+                let right_indices = (0..num_rows)
+                    .map(|idx| (idx % right_batch_len) as u32)
+                    .collect::<Vec<_>>();
+
+                let mut block = left_block.take_compacted_indices(&left_indices, num_rows)?;
+                let right_result_block = right_batch.take(&right_indices, &mut None)?;
+                for col in right_result_block.columns() {
+                    block.add_column(col.clone());
+                }
+                for filter in self.other_conditions.iter() {
+                    block = filter_block(block, filter)?;
+                }
+                if !block.is_empty() {
+                    result_blocks.push(block);
+                }
+            }
+        }
+        Ok(result_blocks)
+    }
+}
@@ -61,6 +61,9 @@ pub struct RangeJoinState {
     pub(crate) finished_tasks: AtomicU64,
     // IEJoin state
     pub(crate) ie_join_state: Option<IEJoinState>,
+    // Whether to join by nested loop, there is no range condition.
+    // Only the right table is sunk, and the left table is streamed against it.
+    pub(crate) nested_loop: bool,
 }
 
 impl RangeJoinState {
@@ -88,6 +91,7 @@ impl RangeJoinState {
             row_offset: RwLock::new(vec![]),
             finished_tasks: AtomicU64::new(0),
             ie_join_state,
+            nested_loop: matches!(range_join.range_join_type, RangeJoinType::NestedLoop),
         }
     }
 
@@ -143,10 +147,13 @@ impl RangeJoinState {
         let mut right_sinker_count = self.right_sinker_count.write();
         *right_sinker_count -= 1;
         let left_sinker_count = self.left_sinker_count.read();
-        if *right_sinker_count == 0 && *left_sinker_count == 0 {
+        // Nested loop join only sinks the right table, the left table is streamed against it
+        if *right_sinker_count == 0 && (*left_sinker_count == 0 || self.nested_loop) {
             // Left and right both finish sink
             // Partition left/right table
-            self.partition()?;
+            if !self.nested_loop {
+                self.partition()?;
+            }
             // Set partition finished
             let mut partition_finished = self.partition_finished.lock();
             *partition_finished = true;
@@ -31,6 +31,10 @@ enum RangeJoinStep {
     Merging,
     // Execute ie_join algo,
     Execute,
+    // Nested loop join waits for the right table to be sunk,
+    WaitRight,
+    // then probes it with the blocks of the left table as they come.
+    Probe,
 }
 
 pub struct TransformRangeJoinLeft {
@@ -41,6 +45,8 @@ pub struct TransformRangeJoinLeft {
     state: Arc<RangeJoinState>,
     step: RangeJoinStep,
     execute_finished: bool,
+    // The next block of the right table to be probed by `input_data` in nested loop join
+    next_right_idx: usize,
 }
 
 impl TransformRangeJoinLeft {
@@ -50,14 +56,20 @@ impl TransformRangeJoinLeft {
         ie_join_state: Arc<RangeJoinState>,
     ) -> Box<dyn Processor> {
         ie_join_state.left_attach();
+        let step = if ie_join_state.nested_loop {
+            RangeJoinStep::WaitRight
+        } else {
+            RangeJoinStep::Sink
+        };
         Box::new(TransformRangeJoinLeft {
             input_port,
             output_port,
             input_data: None,
             output_data_blocks: Default::default(),
             state: ie_join_state,
-            step: RangeJoinStep::Sink,
+            step,
             execute_finished: false,
+            next_right_idx: 0,
         })
     }
 }
@@ -67,6 +79,8 @@ impl Processor for TransformRangeJoinLeft {
     fn name(&self) -> String {
         if self.state.ie_join_state.is_some() {
             "TransformIEJoinLeft".to_string()
+        } else if self.state.nested_loop {
+            "TransformNestedLoopJoinLeft".to_string()
         } else {
             "TransformMergeJoinLeft".to_string()
         }
@@ -120,6 +134,43 @@ impl Processor for TransformRangeJoinLeft {
                     Ok(Event::Finished)
                 }
             }
+            RangeJoinStep::WaitRight => Ok(Event::Async),
+            RangeJoinStep::Probe => {
+                if self.output_port.is_finished() {
+                    self.input_port.finish();
+                    return Ok(Event::Finished);
+                }
+
+                if !self.output_port.can_push() {
+                    return Ok(Event::NeedConsume);
+                }
+
+                if !self.output_data_blocks.is_empty() {
+                    let data = self.output_data_blocks.pop_front().unwrap();
+                    self.output_port.push_data(Ok(data));
+                    return Ok(Event::NeedConsume);
+                }
+
+                if self.input_data.is_some() {
+                    return Ok(Event::Sync);
+                }
+
+                if self.input_port.is_finished() {
+                    self.output_port.finish();
+                    return Ok(Event::Finished);
+                }
+
+                match self.input_port.has_data() {
+                    true => {
+                        self.input_data = Some(self.input_port.pull_data().unwrap()?);
+                        Ok(Event::Sync)
+                    }
+                    false => {
+                        self.input_port.set_need_data();
+                        Ok(Event::NeedData)
+                    }
+                }
+            }
             _ => unreachable!(),
         }
     }
@@ -136,6 +187,7 @@ impl Processor for TransformRangeJoinLeft {
                 if let Some(task_id) = task_id {
                     let res = match self.state.ie_join_state {
                         Some(ref _ie_join_state) => self.state.ie_join(task_id)?,
+                        None if self.state.nested_loop => self.state.nested_loop_join(task_id)?,
                         None => self.state.merge_join(task_id)?,
                     };
                     for block in res {
@@ -147,6 +199,29 @@ impl Processor for TransformRangeJoinLeft {
                     self.execute_finished = true;
                 }
             }
+            RangeJoinStep::Probe => {
+                if let Some(left_block) = self.input_data.take() {
+                    // Probe one block of the right table at a time, so that the output of a
+                    // left block is pushed downstream in pieces.
+                    let right_table_len = self.state.right_table_len();
+                    if self.next_right_idx < right_table_len {
+                        let res = self
+                            .state
+                            .nested_loop_join(&left_block, self.next_right_idx)?;
+                        for block in res {
+                            if !block.is_empty() {
+                                self.output_data_blocks.push_back(block);
+                            }
+                        }
+                        self.next_right_idx += 1;
+                    }
+                    if self.next_right_idx < right_table_len {
+                        self.input_data = Some(left_block);
+                    } else {
+                        self.next_right_idx = 0;
+                    }
+                }
+            }
             _ => unreachable!(),
         }
         Ok(())
@@ -154,9 +229,16 @@ impl Processor for TransformRangeJoinLeft {
 
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
-        if let RangeJoinStep::Merging = self.step {
-            self.state.wait_merge_finish().await?;
-            self.step = RangeJoinStep::Execute;
+        match self.step {
+            RangeJoinStep::Merging => {
+                self.state.wait_merge_finish().await?;
+                self.step = RangeJoinStep::Execute;
+            }
+            RangeJoinStep::WaitRight => {
+                self.state.wait_merge_finish().await?;
+                self.step = RangeJoinStep::Probe;
+            }
+            _ => {}
         }
         Ok(())
     }
@@ -0,0 +1,132 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::number::UInt64Type;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::BlockEntry;
+use common_expression::BlockMetaInfoDowncast;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FromData;
+use common_expression::FunctionContext;
+use common_expression::Value;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_pipeline_transforms::processors::AsyncTransform;
+use common_pipeline_transforms::processors::AsyncTransformer;
+use common_users::UserApiProvider;
+
+use crate::pipelines::processors::InputPort;
+use crate::pipelines::processors::OutputPort;
+use crate::pipelines::processors::Processor;
+
+/// A column of the block to be filled with the values of a sequence.
+#[derive(Clone)]
+pub struct SequenceColumnDesc {
+    pub offset: usize,
+    pub name: String,
+    pub sequence: String,
+    pub data_type: DataType,
+}
+
+/// Fills the columns generated by sequences, which are not provided by the insert source.
+///
+/// The columns must already exist in the block (e.g. filled with default values).
+/// If the block carries its source schema as meta (e.g. the not matched data of MERGE INTO),
+/// only the columns missing from that schema are filled, and the meta is removed.
+pub struct TransformAddSequenceColumns {
+    tenant: String,
+    func_ctx: FunctionContext,
+    columns: Vec<SequenceColumnDesc>,
+}
+
+impl TransformAddSequenceColumns {
+    pub fn try_create(
+        tenant: String,
+        func_ctx: FunctionContext,
+        columns: Vec<SequenceColumnDesc>,
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+    ) -> Result<Box<dyn Processor>> {
+        Ok(AsyncTransformer::create(input, output, Self {
+            tenant,
+            func_ctx,
+            columns,
+        }))
+    }
+
+    fn cast_values(&self, values: Vec<u64>, data_type: &DataType) -> Result<BlockEntry> {
+        let num_rows = values.len();
+        let source_type = DataType::Number(NumberDataType::UInt64);
+        let values = UInt64Type::from_data(values);
+        if data_type == &source_type {
+            return Ok(BlockEntry::new(source_type, Value::Column(values)));
+        }
+
+        let block = DataBlock::new_from_columns(vec![values]);
+        let expr = Expr::Cast {
+            span: None,
+            is_try: false,
+            expr: Box::new(Expr::ColumnRef {
+                span: None,
+                id: 0,
+                data_type: source_type,
+                display_name: "".to_string(),
+            }),
+            dest_type: data_type.clone(),
+        };
+        let evaluator = Evaluator::new(&block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let value = evaluator.run(&expr)?;
+        Ok(BlockEntry::new(
+            data_type.clone(),
+            Value::Column(value.convert_to_full_column(data_type, num_rows)),
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncTransform for TransformAddSequenceColumns {
+    const NAME: &'static str = "AddSequenceColumnsTransform";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, mut data_block: DataBlock) -> Result<DataBlock> {
+        let source_schema = data_block
+            .take_meta()
+            .and_then(DataSchemaRef::downcast_from);
+        let num_rows = data_block.num_rows();
+        if num_rows == 0 {
+            return Ok(data_block);
+        }
+
+        let user_mgr = UserApiProvider::instance();
+        for column in &self.columns {
+            if let Some(source_schema) = &source_schema {
+                if source_schema.has_field(&column.name) {
+                    continue;
+                }
+            }
+            let values = user_mgr
+                .next_sequence_values(&self.tenant, &column.sequence, num_rows as u64)
+                .await?;
+            let entry = self.cast_values(values, &column.data_type)?;
+            data_block.columns_mut()[column.offset] = entry;
+        }
+        Ok(data_block)
+    }
+}
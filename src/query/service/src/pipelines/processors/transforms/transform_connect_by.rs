@@ -0,0 +1,161 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::UInt64Type;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::ScalarRef;
+use common_expression::Value;
+use common_pipeline_core::processors::InputPort;
+use common_pipeline_core::processors::OutputPort;
+use common_pipeline_core::processors::Processor;
+use common_pipeline_transforms::processors::AccumulatingTransform;
+use common_pipeline_transforms::processors::AccumulatingTransformer;
+
+/// Traverses the hierarchy of its input, see `common_sql::plans::ConnectBy`.
+///
+/// The whole input is collected before any row is emitted, the transform
+/// must be the only processor of the pipeline.
+pub struct TransformConnectBy {
+    id_offset: usize,
+    parent_offset: usize,
+    root_offset: usize,
+    max_depth: u64,
+    separator: String,
+
+    blocks: Vec<DataBlock>,
+}
+
+impl TransformConnectBy {
+    pub fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        id_offset: usize,
+        parent_offset: usize,
+        root_offset: usize,
+        max_depth: u64,
+        separator: String,
+    ) -> Result<Box<dyn Processor>> {
+        Ok(AccumulatingTransformer::create(
+            input,
+            output,
+            TransformConnectBy {
+                id_offset,
+                parent_offset,
+                root_offset,
+                max_depth,
+                separator,
+                blocks: vec![],
+            },
+        ))
+    }
+}
+
+impl AccumulatingTransform for TransformConnectBy {
+    const NAME: &'static str = "TransformConnectBy";
+
+    fn transform(&mut self, data: DataBlock) -> Result<Vec<DataBlock>> {
+        if !data.is_empty() {
+            self.blocks.push(data.convert_to_full());
+        }
+        Ok(vec![])
+    }
+
+    fn on_finish(&mut self, output: bool) -> Result<Vec<DataBlock>> {
+        if !output || self.blocks.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let block = DataBlock::concat(&std::mem::take(&mut self.blocks))?;
+        let ids = &block.get_by_offset(self.id_offset).value;
+        let parents = &block.get_by_offset(self.parent_offset).value;
+        let roots = &block.get_by_offset(self.root_offset).value;
+
+        // The rows of the children of each id, in the input order.
+        let mut children: HashMap<ScalarRef, Vec<usize>> = HashMap::new();
+        for row in 0..block.num_rows() {
+            let parent = parents.index(row).unwrap();
+            if parent != ScalarRef::Null {
+                children.entry(parent).or_default().push(row);
+            }
+        }
+
+        let mut indices: Vec<u32> = vec![];
+        let mut levels: Vec<u64> = vec![];
+        let mut paths: Vec<Vec<u8>> = vec![];
+
+        // Depth-first traversal from each root, the rows already on the path are
+        // skipped to break the cycles.
+        let mut stack: Vec<(usize, u64)> = vec![];
+        let mut ancestors: Vec<usize> = vec![];
+        let mut segments: Vec<String> = vec![];
+        for root in 0..block.num_rows() {
+            if roots.index(root) != Some(ScalarRef::Boolean(true)) {
+                continue;
+            }
+            stack.push((root, 1));
+            while let Some((row, level)) = stack.pop() {
+                ancestors.truncate(level as usize - 1);
+                segments.truncate(level as usize - 1);
+
+                let id = ids.index(row).unwrap();
+                segments.push(path_segment(&id));
+                ancestors.push(row);
+
+                indices.push(row as u32);
+                levels.push(level);
+                paths.push(segments.join(&self.separator).into_bytes());
+
+                if level >= self.max_depth || id == ScalarRef::Null {
+                    continue;
+                }
+                if let Some(rows) = children.get(&id) {
+                    // Pushed in reverse order, so that the children are visited in the input order.
+                    for child in rows.iter().rev() {
+                        if !ancestors.contains(child) {
+                            stack.push((*child, level + 1));
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut result = block.take(&indices, &mut None)?;
+        result.add_column(BlockEntry::new(
+            DataType::Number(NumberDataType::UInt64),
+            Value::Column(UInt64Type::from_data(levels)),
+        ));
+        result.add_column(BlockEntry::new(
+            DataType::String,
+            Value::Column(StringType::from_data(paths)),
+        ));
+        Ok(vec![result])
+    }
+}
+
+/// Formats the id of a row as a segment of the path, strings are not quoted.
+fn path_segment(id: &ScalarRef) -> String {
+    match id {
+        ScalarRef::String(s) => String::from_utf8_lossy(s).into_owned(),
+        other => other.to_string(),
+    }
+}
@@ -0,0 +1,221 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::StringType;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::ScalarRef;
+use common_expression::Value;
+use common_pipeline_transforms::processors::AsyncTransform;
+use common_pipeline_transforms::processors::AsyncTransformer;
+use common_users::subject_key::open_data;
+use common_users::subject_key::pseudonymize_data;
+use common_users::subject_key::seal_data;
+use common_users::UserApiProvider;
+
+use crate::pipelines::processors::InputPort;
+use crate::pipelines::processors::OutputPort;
+use crate::pipelines::processors::Processor;
+
+/// A crypto-shredded value is kept as `<base64 of subject>:<base64 of encrypted value>`,
+/// so it can be decrypted without reading the subject key column.
+fn encode_value(subject: &[u8], sealed: &[u8]) -> Vec<u8> {
+    format!(
+        "{}:{}",
+        BASE64_STANDARD.encode(subject),
+        BASE64_STANDARD.encode(sealed)
+    )
+    .into_bytes()
+}
+
+fn decode_value(value: &[u8]) -> Result<(Vec<u8>, Vec<u8>)> {
+    let invalid = || ErrorCode::IllegalSubjectKey("invalid crypto-shredded value");
+    let pos = value.iter().position(|c| *c == b':').ok_or_else(invalid)?;
+    let subject = BASE64_STANDARD
+        .decode(&value[..pos])
+        .map_err(|_| invalid())?;
+    let sealed = BASE64_STANDARD
+        .decode(&value[pos + 1..])
+        .map_err(|_| invalid())?;
+    Ok((subject, sealed))
+}
+
+fn full_column(entry: &BlockEntry, num_rows: usize) -> Column {
+    entry
+        .value
+        .convert_to_full_column(&entry.data_type, num_rows)
+}
+
+/// Encrypts the values of the crypto-shredded columns by the data keys of their subjects,
+/// the keys of the new subjects are created.
+pub struct TransformEncryptShredColumns {
+    tenant: String,
+    subject_offset: usize,
+    offsets: Vec<usize>,
+}
+
+impl TransformEncryptShredColumns {
+    pub fn try_create(
+        tenant: String,
+        subject_offset: usize,
+        offsets: Vec<usize>,
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+    ) -> Result<Box<dyn Processor>> {
+        Ok(AsyncTransformer::create(input, output, Self {
+            tenant,
+            subject_offset,
+            offsets,
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncTransform for TransformEncryptShredColumns {
+    const NAME: &'static str = "EncryptShredColumnsTransform";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, mut data_block: DataBlock) -> Result<DataBlock> {
+        let num_rows = data_block.num_rows();
+        if num_rows == 0 {
+            return Ok(data_block);
+        }
+
+        let subjects = full_column(data_block.get_by_offset(self.subject_offset), num_rows);
+        let user_mgr = UserApiProvider::instance();
+        let mut keys = HashMap::new();
+        for offset in &self.offsets {
+            let column = full_column(data_block.get_by_offset(*offset), num_rows);
+            let mut values = Vec::with_capacity(num_rows);
+            for row in 0..num_rows {
+                let value = match column.index(row).unwrap() {
+                    ScalarRef::String(value) => value,
+                    _ => {
+                        values.push(None);
+                        continue;
+                    }
+                };
+                let subject = match subjects.index(row).unwrap() {
+                    ScalarRef::String(subject) => subject,
+                    _ => {
+                        return Err(ErrorCode::BadArguments(
+                            "the subject key of a crypto-shredded value can not be NULL",
+                        ));
+                    }
+                };
+                if !keys.contains_key(subject) {
+                    let key = user_mgr
+                        .get_or_create_subject_key(&self.tenant, &String::from_utf8_lossy(subject))
+                        .await?;
+                    keys.insert(subject.to_vec(), key);
+                }
+                let sealed = seal_data(&keys[subject], value)?;
+                values.push(Some(encode_value(subject, &sealed)));
+            }
+            data_block.columns_mut()[*offset] = BlockEntry::new(
+                DataType::String.wrap_nullable(),
+                Value::Column(StringType::from_opt_data(values)),
+            );
+        }
+        Ok(data_block)
+    }
+}
+
+/// Decrypts the values of the crypto-shredded columns, the values of the erased subjects
+/// are NULL. If `anonymize` is set, the pseudonyms of the values are returned instead.
+pub struct TransformDecryptShredColumns {
+    tenant: String,
+    offsets: Vec<usize>,
+    anonymize: bool,
+}
+
+impl TransformDecryptShredColumns {
+    pub fn try_create(
+        tenant: String,
+        offsets: Vec<usize>,
+        anonymize: bool,
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+    ) -> Result<Box<dyn Processor>> {
+        Ok(AsyncTransformer::create(input, output, Self {
+            tenant,
+            offsets,
+            anonymize,
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncTransform for TransformDecryptShredColumns {
+    const NAME: &'static str = "DecryptShredColumnsTransform";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, mut data_block: DataBlock) -> Result<DataBlock> {
+        let num_rows = data_block.num_rows();
+        if num_rows == 0 {
+            return Ok(data_block);
+        }
+
+        let user_mgr = UserApiProvider::instance();
+        let mut keys: HashMap<Vec<u8>, Option<Vec<u8>>> = HashMap::new();
+        for offset in &self.offsets {
+            let column = full_column(data_block.get_by_offset(*offset), num_rows);
+            let mut values = Vec::with_capacity(num_rows);
+            for row in 0..num_rows {
+                let value = match column.index(row).unwrap() {
+                    ScalarRef::String(value) => value,
+                    _ => {
+                        values.push(None);
+                        continue;
+                    }
+                };
+                let (subject, sealed) = decode_value(value)?;
+                if !keys.contains_key(&subject) {
+                    let key = user_mgr
+                        .get_subject_key(&self.tenant, &String::from_utf8_lossy(&subject))
+                        .await?;
+                    keys.insert(subject.clone(), key);
+                }
+                let value = match &keys[&subject] {
+                    Some(key) => {
+                        let value = open_data(key, &sealed)?;
+                        if self.anonymize {
+                            Some(pseudonymize_data(key, &value).into_bytes())
+                        } else {
+                            Some(value)
+                        }
+                    }
+                    // The subject is erased.
+                    None => None,
+                };
+                values.push(value);
+            }
+            data_block.columns_mut()[*offset] = BlockEntry::new(
+                DataType::String.wrap_nullable(),
+                Value::Column(StringType::from_opt_data(values)),
+            );
+        }
+        Ok(data_block)
+    }
+}
@@ -0,0 +1,316 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::number::F32;
+use common_expression::types::number::F64;
+use common_expression::types::DataType;
+use common_expression::types::NumberScalar;
+use common_expression::ColumnBuilder;
+use common_expression::DataBlock;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_expression::SortColumnDescription;
+use common_pipeline_core::processors::InputPort;
+use common_pipeline_core::processors::OutputPort;
+use common_pipeline_core::processors::Processor;
+use common_pipeline_transforms::processors::AccumulatingTransform;
+use common_pipeline_transforms::processors::AccumulatingTransformer;
+use common_sql::plans::GapFillMethod;
+
+/// Densifies the time series of its input, see `common_sql::plans::GapFill`.
+///
+/// The whole input is collected before any row is generated, the transform
+/// must be the only processor of the pipeline.
+pub struct TransformGapFill {
+    time_offset: usize,
+    partition_offsets: Vec<usize>,
+    /// Whether the column at each offset is filled by `method`.
+    filled: Vec<bool>,
+    output_types: Vec<DataType>,
+    is_date: bool,
+    interval: i64,
+    start: Option<i64>,
+    end: Option<i64>,
+    method: GapFillMethod,
+
+    blocks: Vec<DataBlock>,
+}
+
+impl TransformGapFill {
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        time_offset: usize,
+        partition_offsets: Vec<usize>,
+        filled: Vec<bool>,
+        output_types: Vec<DataType>,
+        interval: i64,
+        start: Option<i64>,
+        end: Option<i64>,
+        method: GapFillMethod,
+    ) -> Result<Box<dyn Processor>> {
+        let is_date = output_types[time_offset].remove_nullable() == DataType::Date;
+        Ok(AccumulatingTransformer::create(
+            input,
+            output,
+            TransformGapFill {
+                time_offset,
+                partition_offsets,
+                filled,
+                output_types,
+                is_date,
+                interval,
+                start,
+                end,
+                method,
+                blocks: vec![],
+            },
+        ))
+    }
+
+    fn time_of(&self, block: &DataBlock, row: usize) -> Option<i64> {
+        match block.get_by_offset(self.time_offset).value.index(row) {
+            Some(ScalarRef::Timestamp(ts)) => Some(ts),
+            Some(ScalarRef::Date(date)) => Some(date as i64),
+            _ => None,
+        }
+    }
+
+    fn time_scalar(&self, time: i64) -> Scalar {
+        if self.is_date {
+            Scalar::Date(time as i32)
+        } else {
+            Scalar::Timestamp(time)
+        }
+    }
+
+    fn same_series(&self, block: &DataBlock, lhs: usize, rhs: usize) -> bool {
+        self.partition_offsets.iter().all(|offset| {
+            let column = &block.get_by_offset(*offset).value;
+            column.index(lhs) == column.index(rhs)
+        })
+    }
+
+    fn push_row(&self, builders: &mut [ColumnBuilder], block: &DataBlock, row: usize) {
+        for (offset, builder) in builders.iter_mut().enumerate() {
+            let value = block.get_by_offset(offset).value.index(row).unwrap();
+            builder.push(value);
+        }
+    }
+
+    /// Pushes a generated row at `time`, between the existing rows `prev` and `next` of
+    /// the series whose first row is `first`.
+    fn push_gap(
+        &self,
+        builders: &mut [ColumnBuilder],
+        block: &DataBlock,
+        first: usize,
+        time: i64,
+        prev: Option<(usize, i64)>,
+        next: Option<(usize, i64)>,
+    ) {
+        for (offset, builder) in builders.iter_mut().enumerate() {
+            let column = &block.get_by_offset(offset).value;
+            if offset == self.time_offset {
+                builder.push(self.time_scalar(time).as_ref());
+            } else if self.partition_offsets.contains(&offset) {
+                builder.push(column.index(first).unwrap());
+            } else if !self.filled[offset] {
+                builder.push_default();
+            } else {
+                match (self.method, prev, next) {
+                    (GapFillMethod::Locf, Some((prev, _)), _) => {
+                        builder.push(column.index(prev).unwrap());
+                    }
+                    (GapFillMethod::Linear, Some((prev, prev_time)), Some((next, next_time))) => {
+                        let ratio = (time - prev_time) as f64 / (next_time - prev_time) as f64;
+                        let value = interpolate(
+                            column.index(prev).unwrap(),
+                            column.index(next).unwrap(),
+                            ratio,
+                        );
+                        builder.push(value.as_ref());
+                    }
+                    _ => builder.push(ScalarRef::Null),
+                }
+            }
+        }
+    }
+
+    /// Fills the series in the rows `[begin, end)` of the sorted block.
+    fn fill_series(
+        &self,
+        builders: &mut [ColumnBuilder],
+        block: &DataBlock,
+        begin: usize,
+        end: usize,
+    ) {
+        // Rows with NULL time are sorted at the end of the series, they are kept as is.
+        let timed_end = (begin..end)
+            .find(|row| self.time_of(block, *row).is_none())
+            .unwrap_or(end);
+
+        let (Some(first_time), Some(last_time)) = (
+            self.start.or_else(|| self.time_of(block, begin)),
+            self.end.or_else(|| {
+                (begin..timed_end)
+                    .last()
+                    .and_then(|row| self.time_of(block, row))
+            }),
+        ) else {
+            for row in begin..end {
+                self.push_row(builders, block, row);
+            }
+            return;
+        };
+
+        let mut row = begin;
+        let mut prev = None;
+        let mut time = first_time;
+        while time <= last_time {
+            // Emit the existing rows before the grid point.
+            let mut found = false;
+            while row < timed_end {
+                let row_time = self.time_of(block, row).unwrap();
+                if row_time > time {
+                    break;
+                }
+                found |= row_time == time;
+                self.push_row(builders, block, row);
+                prev = Some((row, row_time));
+                row += 1;
+            }
+
+            if !found {
+                let next = (row < timed_end).then(|| (row, self.time_of(block, row).unwrap()));
+                self.push_gap(builders, block, begin, time, prev, next);
+            }
+
+            time = match time.checked_add(self.interval) {
+                Some(time) => time,
+                None => break,
+            };
+        }
+
+        for row in row..end {
+            self.push_row(builders, block, row);
+        }
+    }
+}
+
+impl AccumulatingTransform for TransformGapFill {
+    const NAME: &'static str = "TransformGapFill";
+
+    fn transform(&mut self, data: DataBlock) -> Result<Vec<DataBlock>> {
+        if !data.is_empty() {
+            self.blocks.push(data.convert_to_full());
+        }
+        Ok(vec![])
+    }
+
+    fn on_finish(&mut self, output: bool) -> Result<Vec<DataBlock>> {
+        if !output || self.blocks.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let block = DataBlock::concat(&std::mem::take(&mut self.blocks))?;
+        let sort_desc = self
+            .partition_offsets
+            .iter()
+            .chain(std::iter::once(&self.time_offset))
+            .map(|offset| SortColumnDescription {
+                offset: *offset,
+                asc: true,
+                nulls_first: false,
+                is_nullable: block.get_by_offset(*offset).data_type.is_nullable(),
+            })
+            .collect::<Vec<_>>();
+        let block = DataBlock::sort(&block, &sort_desc, None)?;
+
+        let num_rows = block.num_rows();
+        let mut builders = self
+            .output_types
+            .iter()
+            .map(|ty| ColumnBuilder::with_capacity(ty, num_rows))
+            .collect::<Vec<_>>();
+
+        let mut begin = 0;
+        while begin < num_rows {
+            let mut end = begin + 1;
+            while end < num_rows && self.same_series(&block, begin, end) {
+                end += 1;
+            }
+            self.fill_series(&mut builders, &block, begin, end);
+            begin = end;
+        }
+
+        let columns = builders
+            .into_iter()
+            .map(|builder| builder.build())
+            .collect::<Vec<_>>();
+        Ok(vec![DataBlock::new_from_columns(columns)])
+    }
+}
+
+/// Interpolates the number at `ratio` between `prev` and `next`, the result is NULL if
+/// any of them is not a number.
+fn interpolate(prev: ScalarRef, next: ScalarRef, ratio: f64) -> Scalar {
+    match (prev, next) {
+        (ScalarRef::Number(prev), ScalarRef::Number(next)) => {
+            let prev_value = number_to_f64(prev);
+            let value = prev_value + (number_to_f64(next) - prev_value) * ratio;
+            Scalar::Number(number_from_f64(prev, value))
+        }
+        _ => Scalar::Null,
+    }
+}
+
+fn number_to_f64(number: NumberScalar) -> f64 {
+    match number {
+        NumberScalar::UInt8(v) => v as f64,
+        NumberScalar::UInt16(v) => v as f64,
+        NumberScalar::UInt32(v) => v as f64,
+        NumberScalar::UInt64(v) => v as f64,
+        NumberScalar::Int8(v) => v as f64,
+        NumberScalar::Int16(v) => v as f64,
+        NumberScalar::Int32(v) => v as f64,
+        NumberScalar::Int64(v) => v as f64,
+        NumberScalar::Float32(v) => v.0 as f64,
+        NumberScalar::Float64(v) => v.0,
+        NumberScalar::UInt128(v) => v as f64,
+        NumberScalar::Int128(v) => v as f64,
+    }
+}
+
+/// Converts `value` to the number type of `like`, integers are rounded.
+fn number_from_f64(like: NumberScalar, value: f64) -> NumberScalar {
+    match like {
+        NumberScalar::UInt8(_) => NumberScalar::UInt8(value.round() as u8),
+        NumberScalar::UInt16(_) => NumberScalar::UInt16(value.round() as u16),
+        NumberScalar::UInt32(_) => NumberScalar::UInt32(value.round() as u32),
+        NumberScalar::UInt64(_) => NumberScalar::UInt64(value.round() as u64),
+        NumberScalar::Int8(_) => NumberScalar::Int8(value.round() as i8),
+        NumberScalar::Int16(_) => NumberScalar::Int16(value.round() as i16),
+        NumberScalar::Int32(_) => NumberScalar::Int32(value.round() as i32),
+        NumberScalar::Int64(_) => NumberScalar::Int64(value.round() as i64),
+        NumberScalar::Float32(_) => NumberScalar::Float32(F32::from(value as f32)),
+        NumberScalar::Float64(_) => NumberScalar::Float64(F64::from(value)),
+        NumberScalar::UInt128(_) => NumberScalar::UInt128(value.round() as u128),
+        NumberScalar::Int128(_) => NumberScalar::Int128(value.round() as i128),
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::HashMethod;
+use common_expression::HashMethodSerializer;
+use common_pipeline_core::processors::InputPort;
+use common_pipeline_core::processors::OutputPort;
+use common_pipeline_core::processors::Processor;
+use common_pipeline_transforms::processors::Transform;
+use common_pipeline_transforms::processors::Transformer;
+
+/// Keeps the first `limit` rows of each distinct key, see `common_sql::plans::LimitBy`.
+///
+/// The rows are taken in the order they arrive, the transform must be the only processor
+/// of the pipeline. Only the number of rows of every key is kept in memory.
+pub struct TransformLimitBy {
+    limit: usize,
+    key_offsets: Vec<usize>,
+    method: HashMethodSerializer,
+    counts: HashMap<Vec<u8>, usize>,
+}
+
+impl TransformLimitBy {
+    pub fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        limit: usize,
+        key_offsets: Vec<usize>,
+    ) -> Result<Box<dyn Processor>> {
+        Ok(Transformer::create(input, output, TransformLimitBy {
+            limit,
+            key_offsets,
+            method: HashMethodSerializer::default(),
+            counts: HashMap::new(),
+        }))
+    }
+}
+
+impl Transform for TransformLimitBy {
+    const NAME: &'static str = "LimitByTransform";
+
+    fn transform(&mut self, data: DataBlock) -> Result<DataBlock> {
+        let num_rows = data.num_rows();
+        if num_rows == 0 || self.limit == 0 {
+            return Ok(data.slice(0..0));
+        }
+
+        let keys = self
+            .key_offsets
+            .iter()
+            .map(|offset| {
+                let entry = data.get_by_offset(*offset);
+                let column = entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, num_rows);
+                (column, entry.data_type.clone())
+            })
+            .collect::<Vec<_>>();
+        let keys_state = self.method.build_keys_state(&keys, num_rows)?;
+
+        let mut selection = MutableBitmap::with_capacity(num_rows);
+        for key in self.method.build_keys_iter(&keys_state)? {
+            let selected = match self.counts.get_mut(key) {
+                Some(count) if *count >= self.limit => false,
+                Some(count) => {
+                    *count += 1;
+                    true
+                }
+                None => {
+                    self.counts.insert(key.to_vec(), 1);
+                    true
+                }
+            };
+            selection.push(selected);
+        }
+
+        data.filter_with_bitmap(&selection.into())
+    }
+}
@@ -37,6 +37,9 @@ pub struct TransformResortAddOnWithoutSourceSchema {
     output_schema: DataSchemaRef,
     ctx: Arc<QueryContext>,
     table: Arc<dyn Table>,
+    // Keep the source schema as the meta of the output block, so that the columns
+    // generated by sequences can be filled by the downstream transform.
+    keep_source_schema: bool,
 }
 
 pub fn build_expression_transform(
@@ -100,6 +103,7 @@ where Self: Transform
         output: Arc<OutputPort>,
         output_schema: DataSchemaRef,
         table: Arc<dyn Table>,
+        keep_source_schema: bool,
     ) -> Result<ProcessorPtr> {
         Ok(ProcessorPtr::create(Transformer::create(
             input,
@@ -108,6 +112,7 @@ where Self: Transform
                 output_schema,
                 ctx,
                 table,
+                keep_source_schema,
             },
         )))
     }
@@ -127,6 +132,11 @@ impl Transform for TransformResortAddOnWithoutSourceSchema {
         )?
         .transform(block)?;
         let columns = block.columns()[input_schema.num_fields()..].to_owned();
-        Ok(DataBlock::new(columns, block.num_rows()))
+        let block = DataBlock::new(columns, block.num_rows());
+        if self.keep_source_schema {
+            block.add_meta(Some(Box::new(input_schema)))
+        } else {
+            Ok(block)
+        }
     }
 }
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod frame_bound;
+mod segment_tree;
 mod transform_window;
 mod window_function;
 
@@ -0,0 +1,176 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::Column;
+use common_functions::aggregates::get_layout_offsets;
+use common_functions::aggregates::AggregateFunction;
+use common_functions::aggregates::StateAddr;
+
+use crate::pipelines::processors::transforms::group_by::Area;
+
+/// The number of rows of a leaf and the number of children of an inner node.
+pub const SEGMENT_TREE_FANOUT: usize = 16;
+
+/// A segment tree over the aggregate states of the rows in one partition.
+///
+/// It is used for the sliding window frames whose aggregate function can't retract rows:
+/// a leaf holds the state of `SEGMENT_TREE_FANOUT` consecutive rows and every inner node
+/// holds the merged state of `SEGMENT_TREE_FANOUT` nodes of the level below,
+/// so the state of any frame can be merged from O(log n) nodes.
+///
+/// The states are not merged in the order of the rows, so it can only be used by
+/// the aggregate functions whose result does not depend on the order of rows.
+pub struct WindowSegmentTree {
+    arena: Area,
+    agg: Arc<dyn AggregateFunction>,
+    layout: Layout,
+    offset: usize,
+
+    /// `levels[0]` are the complete leaves, `levels[i]` are the nodes built from `levels[i - 1]`.
+    levels: Vec<Vec<StateAddr>>,
+    /// The leaf that is being filled, it is not in `levels` until it's complete.
+    leaf: StateAddr,
+    leaf_rows: usize,
+    num_rows: usize,
+}
+
+impl WindowSegmentTree {
+    pub fn try_create(agg: Arc<dyn AggregateFunction>) -> Result<Self> {
+        let mut state_offset = Vec::with_capacity(1);
+        let layout = get_layout_offsets(&[agg.clone()], &mut state_offset)?;
+        let mut arena = Area::create();
+        let leaf = Self::alloc_state(&mut arena, &agg, layout, state_offset[0]);
+        Ok(Self {
+            arena,
+            agg,
+            layout,
+            offset: state_offset[0],
+            levels: vec![],
+            leaf,
+            leaf_rows: 0,
+            num_rows: 0,
+        })
+    }
+
+    fn alloc_state(
+        arena: &mut Area,
+        agg: &Arc<dyn AggregateFunction>,
+        layout: Layout,
+        offset: usize,
+    ) -> StateAddr {
+        let place: StateAddr = arena.alloc_layout(layout).into();
+        let place = place.next(offset);
+        agg.init_state(place);
+        place
+    }
+
+    /// The number of rows added into the tree.
+    #[inline]
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    /// Add the next row of the partition.
+    pub fn add_row(&mut self, columns: &[Column], row: usize) -> Result<()> {
+        self.agg.accumulate_row(self.leaf, columns, row)?;
+        self.leaf_rows += 1;
+        self.num_rows += 1;
+
+        if self.leaf_rows == SEGMENT_TREE_FANOUT {
+            let leaf = Self::alloc_state(&mut self.arena, &self.agg, self.layout, self.offset);
+            let leaf = std::mem::replace(&mut self.leaf, leaf);
+            self.leaf_rows = 0;
+            self.push_node(leaf)?;
+        }
+        Ok(())
+    }
+
+    /// Push a complete leaf and build the parents that become complete.
+    fn push_node(&mut self, mut node: StateAddr) -> Result<()> {
+        let mut level = 0;
+        loop {
+            if self.levels.len() == level {
+                self.levels.push(vec![]);
+            }
+            let nodes = &mut self.levels[level];
+            nodes.push(node);
+            if nodes.len() % SEGMENT_TREE_FANOUT != 0 {
+                return Ok(());
+            }
+
+            let parent = Self::alloc_state(&mut self.arena, &self.agg, self.layout, self.offset);
+            let nodes = &self.levels[level];
+            for child in &nodes[nodes.len() - SEGMENT_TREE_FANOUT..] {
+                self.agg.merge_states(parent, *child)?;
+            }
+            node = parent;
+            level += 1;
+        }
+    }
+
+    /// Merge the states of the complete leaves in [`start`, `end`) into `place`.
+    pub fn merge_leaves(&self, place: StateAddr, mut start: usize, mut end: usize) -> Result<()> {
+        let mut level = 0;
+        while start < end {
+            let parent_start = (start + SEGMENT_TREE_FANOUT - 1) / SEGMENT_TREE_FANOUT;
+            let parent_end = end / SEGMENT_TREE_FANOUT;
+            if parent_start >= parent_end {
+                return self.merge_nodes(place, level, start, end);
+            }
+
+            // The nodes that don't fill a parent are merged at this level.
+            self.merge_nodes(place, level, start, parent_start * SEGMENT_TREE_FANOUT)?;
+            self.merge_nodes(place, level, parent_end * SEGMENT_TREE_FANOUT, end)?;
+            start = parent_start;
+            end = parent_end;
+            level += 1;
+        }
+        Ok(())
+    }
+
+    fn merge_nodes(&self, place: StateAddr, level: usize, start: usize, end: usize) -> Result<()> {
+        for node in &self.levels[level][start..end] {
+            self.agg.merge_states(place, *node)?;
+        }
+        Ok(())
+    }
+
+    /// Clear the tree for the next partition.
+    pub fn reset(&mut self) {
+        self.drop_states();
+        self.arena = Area::create();
+        self.levels.clear();
+        self.leaf = Self::alloc_state(&mut self.arena, &self.agg, self.layout, self.offset);
+        self.leaf_rows = 0;
+        self.num_rows = 0;
+    }
+
+    fn drop_states(&mut self) {
+        if self.agg.need_manual_drop_state() {
+            for place in self.levels.iter().flatten().chain(Some(&self.leaf)) {
+                unsafe { self.agg.drop_state(*place) }
+            }
+        }
+    }
+}
+
+impl Drop for WindowSegmentTree {
+    fn drop(&mut self) {
+        self.drop_states();
+    }
+}
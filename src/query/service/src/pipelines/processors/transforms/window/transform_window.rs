@@ -38,9 +38,12 @@ use common_pipeline_core::processors::InputPort;
 use common_pipeline_core::processors::OutputPort;
 use common_pipeline_core::processors::Processor;
 use common_sql::executor::physical_plans::LagLeadDefault;
+use common_sql::plans::WindowFuncFrameExclusion;
 use common_sql::plans::WindowFuncFrameUnits;
 
 use super::frame_bound::FrameBound;
+use super::segment_tree::WindowSegmentTree;
+use super::segment_tree::SEGMENT_TREE_FANOUT;
 use super::window_function::WindowFuncAggImpl;
 use super::window_function::WindowFunctionImpl;
 use super::WindowFunctionInfo;
@@ -99,6 +102,8 @@ pub struct TransformWindow<T: Number> {
     frame_unit: WindowFuncFrameUnits,
     start_bound: FrameBound<T>,
     end_bound: FrameBound<T>,
+    // Rows excluded from the frame when aggregating, see `is_excluded`.
+    exclusion: WindowFuncFrameExclusion,
 
     // Only used for ROWS frame, default value: 0. (when not used)
     rows_start_bound: usize,
@@ -137,9 +142,44 @@ pub struct TransformWindow<T: Number> {
 
     // If `is_empty_frame`, the window function result of non-NULL rows will be NULL.
     is_empty_frame: bool,
+
+    // Used to aggregate the sliding frames if the aggregate function can't retract rows.
+    segment_tree: Option<WindowSegmentTree>,
+    // The next row of the partition to add into `segment_tree`.
+    segment_tree_end: RowPtr,
 }
 
 impl<T: Number> TransformWindow<T> {
+    /// A segment tree is only needed if the frame start slides and the rows can't be retracted.
+    fn try_create_segment_tree(
+        func: &WindowFunctionImpl,
+        start_bound: &FrameBound<T>,
+    ) -> Result<Option<WindowSegmentTree>> {
+        match func {
+            WindowFunctionImpl::Aggregate(agg)
+                if agg.use_segment_tree() && *start_bound != FrameBound::Preceding(None) =>
+            {
+                Ok(Some(WindowSegmentTree::try_create(agg.agg().clone())?))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Set the `EXCLUDE` option of the window frame.
+    pub fn with_exclusion(mut self, exclusion: WindowFuncFrameExclusion) -> Self {
+        // Excluding the peers of the current row requires the end of its peer group.
+        self.need_peer |= matches!(
+            exclusion,
+            WindowFuncFrameExclusion::Group | WindowFuncFrameExclusion::Ties
+        );
+        if exclusion != WindowFuncFrameExclusion::NoOthers {
+            // The excluded rows split the frame, so it's aggregated row by row.
+            self.segment_tree = None;
+        }
+        self.exclusion = exclusion;
+        self
+    }
+
     #[inline(always)]
     fn blocks_end(&self) -> RowPtr {
         RowPtr::new(self.first_block + self.blocks.len(), 0)
@@ -164,6 +204,19 @@ impl<T: Number> TransformWindow<T> {
             .unwrap()
     }
 
+    /// The number of rows in [`start`, `end`).
+    fn rows_between(&self, mut start: RowPtr, end: RowPtr) -> usize {
+        debug_assert!(start <= end);
+
+        let mut rows = 0;
+        while start.block < end.block {
+            rows += self.block_rows(&start) - start.row;
+            start.block += 1;
+            start.row = 0;
+        }
+        rows + end.row - start.row
+    }
+
     fn add_rows_within_partition(&self, mut cur: RowPtr, mut n: usize) -> RowPtr {
         debug_assert!(cur.ge(&self.partition_start) && cur.le(&self.partition_end));
 
@@ -456,43 +509,180 @@ impl<T: Number> TransformWindow<T> {
         debug_assert!(self.partition_start <= self.frame_start);
         debug_assert!(self.frame_end <= self.partition_end);
 
-        let (rows_start, rows_end, reset) = if self.frame_start == self.prev_frame_start {
-            (self.prev_frame_end, self.frame_end, false)
-        } else {
-            (self.frame_start, self.frame_end, true)
-        };
+        // The excluded rows move with the current row, so the frame can't be slid incrementally.
+        let check_exclusion = self.exclusion != WindowFuncFrameExclusion::NoOthers;
+        if !check_exclusion {
+            if self.frame_start == self.prev_frame_start {
+                return self.accumulate_rows(agg, self.prev_frame_end, self.frame_end, false);
+            }
+
+            // Slide the frame: remove the rows leaving the frame and add the rows entering it.
+            if agg.support_retract() && self.frame_start < self.prev_frame_end {
+                self.visit_rows(agg, self.prev_frame_start, self.frame_start, |cols, row| {
+                    agg.retract_row(cols, row.row)
+                })?;
+                return self.accumulate_rows(agg, self.prev_frame_end, self.frame_end, false);
+            }
+        }
+
+        agg.reset();
+        match &self.segment_tree {
+            Some(tree) => self.apply_aggregate_by_segment_tree(agg, tree),
+            None => self.accumulate_rows(agg, self.frame_start, self.frame_end, check_exclusion),
+        }
+    }
 
-        if reset {
-            agg.reset();
+    /// Merge the states of the complete leaves in the frame from the segment tree,
+    /// and accumulate the rows at both ends of the frame that don't fill a leaf.
+    fn apply_aggregate_by_segment_tree(
+        &self,
+        agg: &WindowFuncAggImpl,
+        tree: &WindowSegmentTree,
+    ) -> Result<()> {
+        // The offsets of the frame in the partition, the tree has been built to `segment_tree_end`.
+        let end = tree.num_rows() - self.rows_between(self.frame_end, self.segment_tree_end);
+        let start = end - self.rows_between(self.frame_start, self.frame_end);
+
+        let leaf_start = (start + SEGMENT_TREE_FANOUT - 1) / SEGMENT_TREE_FANOUT;
+        let leaf_end = end / SEGMENT_TREE_FANOUT;
+        if leaf_start >= leaf_end {
+            return self.accumulate_rows(agg, self.frame_start, self.frame_end, false);
         }
 
-        let end_block = if rows_end.row == 0 {
-            rows_end.block
+        let head_end = self
+            .add_rows_within_partition(self.frame_start, leaf_start * SEGMENT_TREE_FANOUT - start);
+        let tail_start =
+            self.add_rows_within_partition(head_end, (leaf_end - leaf_start) * SEGMENT_TREE_FANOUT);
+
+        self.accumulate_rows(agg, self.frame_start, head_end, false)?;
+        tree.merge_leaves(agg.place(), leaf_start, leaf_end)?;
+        self.accumulate_rows(agg, tail_start, self.frame_end, false)
+    }
+
+    /// Add the rows in [`segment_tree_end`, `frame_end`) into the segment tree.
+    fn update_segment_tree(&mut self) -> Result<()> {
+        let Some(mut tree) = self.segment_tree.take() else {
+            return Ok(());
+        };
+        let res = match &self.func {
+            WindowFunctionImpl::Aggregate(agg) if self.segment_tree_end < self.frame_end => self
+                .visit_rows(agg, self.segment_tree_end, self.frame_end, |cols, row| {
+                    tree.add_row(cols, row.row)
+                }),
+            _ => Ok(()),
+        };
+        self.segment_tree = Some(tree);
+        self.segment_tree_end = self.segment_tree_end.max(self.frame_end);
+        res
+    }
+
+    /// Accumulate the rows in [`start`, `end`) into the state of `agg`.
+    fn accumulate_rows(
+        &self,
+        agg: &WindowFuncAggImpl,
+        start: RowPtr,
+        end: RowPtr,
+        check_exclusion: bool,
+    ) -> Result<()> {
+        self.visit_rows(agg, start, end, |cols, row| {
+            if check_exclusion && self.is_excluded(&row) {
+                return Ok(());
+            }
+            agg.accumulate_row(cols, row.row)
+        })
+    }
+
+    /// Call `f` with the argument columns of `agg` for each row in [`start`, `end`).
+    fn visit_rows<F>(
+        &self,
+        agg: &WindowFuncAggImpl,
+        start: RowPtr,
+        end: RowPtr,
+        mut f: F,
+    ) -> Result<()>
+    where
+        F: FnMut(&[Column], RowPtr) -> Result<()>,
+    {
+        let end_block = if end.row == 0 {
+            end.block
         } else {
-            rows_end.block + 1
+            end.block + 1
         };
 
-        for block in rows_start.block..end_block {
+        for block in start.block..end_block {
             let data = &self.blocks[block - self.first_block].block;
-            let start_row = if block == rows_start.block {
-                rows_start.row
-            } else {
-                0
-            };
-            let end_row = if block == rows_end.block {
-                rows_end.row
+            let start_row = if block == start.block { start.row } else { 0 };
+            let end_row = if block == end.block {
+                end.row
             } else {
                 data.num_rows()
             };
             let cols = agg.arg_columns(data);
             for row in start_row..end_row {
-                agg.accumulate_row(&cols, row)?;
+                f(&cols, RowPtr::new(block, row))?;
             }
         }
 
         Ok(())
     }
 
+    /// If the row is removed from the frame of the current row by the `EXCLUDE` option.
+    #[inline]
+    fn is_excluded(&self, row: &RowPtr) -> bool {
+        match self.exclusion {
+            WindowFuncFrameExclusion::NoOthers => false,
+            WindowFuncFrameExclusion::CurrentRow => *row == self.current_row,
+            WindowFuncFrameExclusion::Group => {
+                self.peer_group_start <= *row && *row < self.peer_group_end
+            }
+            WindowFuncFrameExclusion::Ties => {
+                *row != self.current_row
+                    && self.peer_group_start <= *row
+                    && *row < self.peer_group_end
+            }
+        }
+    }
+
+    /// Find the `n`-th (counting from 1) non-NULL value of the column in the current frame,
+    /// scanning forward from `frame_start` or backward from `frame_end`.
+    fn nth_non_null_in_frame(
+        &self,
+        column_index: usize,
+        mut n: u64,
+        forward: bool,
+    ) -> Option<Scalar> {
+        debug_assert!(n > 0);
+        let mut cur = if forward {
+            self.frame_start
+        } else {
+            self.frame_end
+        };
+        loop {
+            if forward {
+                if cur == self.frame_end {
+                    return None;
+                }
+            } else {
+                if cur == self.frame_start {
+                    return None;
+                }
+                cur = self.goback_row(cur);
+            }
+
+            let value = unsafe { self.column_at(&cur, column_index).index_unchecked(cur.row) };
+            if !value.is_null() {
+                n -= 1;
+                if n == 0 {
+                    return Some(value.to_owned());
+                }
+            }
+
+            if forward {
+                cur = self.advance_row(cur);
+            }
+        }
+    }
+
     #[inline]
     fn merge_result_of_current_row(&mut self) -> Result<()> {
         match &self.func {
@@ -529,16 +719,10 @@ impl<T: Number> TransformWindow<T> {
             }
             WindowFunctionImpl::LagLead(ll) => {
                 let value = if self.frame_start == self.frame_end {
-                    let default_value = match ll.default.clone() {
-                        LagLeadDefault::Null => Scalar::Null,
-                        LagLeadDefault::Index(col) => {
-                            let block =
-                                &self.blocks[self.current_row.block - self.first_block].block;
-                            let value = &block.get_by_offset(col).value;
-                            value.index(self.current_row.row).unwrap().to_owned()
-                        }
-                    };
-                    default_value
+                    None
+                } else if ll.ignore_null && ll.offset > 0 {
+                    // The frame covers all the rows before (lag) or after (lead) the current row.
+                    self.nth_non_null_in_frame(ll.arg, ll.offset, !ll.is_lag)
                 } else {
                     let block = &self
                         .blocks
@@ -546,8 +730,16 @@ impl<T: Number> TransformWindow<T> {
                         .unwrap()
                         .block;
                     let value = &block.get_by_offset(ll.arg).value;
-                    value.index(self.frame_start.row).unwrap().to_owned()
+                    Some(value.index(self.frame_start.row).unwrap().to_owned())
                 };
+                let value = value.unwrap_or_else(|| match ll.default.clone() {
+                    LagLeadDefault::Null => Scalar::Null,
+                    LagLeadDefault::Index(col) => {
+                        let block = &self.blocks[self.current_row.block - self.first_block].block;
+                        let value = &block.get_by_offset(col).value;
+                        value.index(self.current_row.row).unwrap().to_owned()
+                    }
+                });
 
                 let builder = &mut self.blocks[self.current_row.block - self.first_block].builder;
                 builder.push(value.as_ref());
@@ -555,6 +747,11 @@ impl<T: Number> TransformWindow<T> {
             WindowFunctionImpl::NthValue(func) => {
                 let value = if self.frame_start == self.frame_end {
                     Scalar::Null
+                } else if func.ignore_null {
+                    // `last_value` is the first non-NULL value counting back from the frame end.
+                    let (n, forward) = func.n.map_or((1, false), |n| (n, true));
+                    self.nth_non_null_in_frame(func.arg, n, forward)
+                        .unwrap_or(Scalar::Null)
                 } else if let Some(mut n) = func.n {
                     let mut cur = self.frame_start;
                     // n is counting from 1
@@ -656,6 +853,8 @@ impl TransformWindow<u64> {
 
         let is_empty_frame = start_bound > end_bound;
 
+        let segment_tree = Self::try_create_segment_tree(&func, &start_bound)?;
+
         let rows_start_bound = start_bound.get_inner().unwrap_or_default() as usize;
         let rows_end_bound = end_bound.get_inner().unwrap_or_default() as usize;
 
@@ -677,6 +876,7 @@ impl TransformWindow<u64> {
             frame_unit: WindowFuncFrameUnits::Rows,
             start_bound,
             end_bound,
+            exclusion: WindowFuncFrameExclusion::NoOthers,
             rows_start_bound,
             rows_end_bound,
             need_check_null_frame: false,
@@ -698,6 +898,8 @@ impl TransformWindow<u64> {
             current_dense_rank: 1,
             input_is_finished: false,
             is_empty_frame,
+            segment_tree,
+            segment_tree_end: RowPtr::default(),
         })
     }
 }
@@ -728,6 +930,7 @@ where T: Number + ResultTypeOfUnary
         };
 
         let need_peer = matches!(func, WindowFunctionImpl::CumeDist);
+        let segment_tree = Self::try_create_segment_tree(&func, &start_bound)?;
 
         Ok(Self {
             input,
@@ -747,6 +950,7 @@ where T: Number + ResultTypeOfUnary
             frame_unit: WindowFuncFrameUnits::Range,
             start_bound,
             end_bound,
+            exclusion: WindowFuncFrameExclusion::NoOthers,
             rows_start_bound: 0,
             rows_end_bound: 0,
             need_check_null_frame,
@@ -768,6 +972,8 @@ where T: Number + ResultTypeOfUnary
             current_dense_rank: 1,
             input_is_finished: false,
             is_empty_frame,
+            segment_tree,
+            segment_tree_end: RowPtr::default(),
         })
     }
 
@@ -890,6 +1096,7 @@ where T: Number + ResultTypeOfUnary
     }
 
     fn compute_on_frame(&mut self) -> Result<()> {
+        self.update_segment_tree()?;
         match &self.func {
             WindowFunctionImpl::Aggregate(agg) => self.apply_aggregate(agg),
             _ => Ok(()),
@@ -1023,6 +1230,9 @@ where T: Number + ResultTypeOfUnary
             {
                 // reset function
                 self.func.reset();
+                if let Some(tree) = self.segment_tree.as_mut() {
+                    tree.reset();
+                }
 
                 // reset partition
                 self.partition_start = self.partition_end;
@@ -1037,6 +1247,7 @@ where T: Number + ResultTypeOfUnary
                 self.frame_end = self.partition_start;
                 self.prev_frame_start = self.frame_start;
                 self.prev_frame_end = self.frame_end;
+                self.segment_tree_end = self.partition_start;
 
                 // reset peer group
                 self.peer_group_start = self.partition_start;
@@ -1270,7 +1481,7 @@ mod tests {
         arg_type: DataType,
     ) -> Result<TransformWindow<u64>> {
         let agg = AggregateFunctionFactory::instance().get("sum", vec![], vec![arg_type])?;
-        let func = WindowFunctionInfo::Aggregate(agg, vec![0]);
+        let func = WindowFunctionInfo::Aggregate(agg, vec![0], false);
         TransformWindow::try_create_rows(
             InputPort::create(),
             OutputPort::create(),
@@ -1886,7 +2097,7 @@ mod tests {
     ) -> Result<(Box<dyn Processor>, Arc<InputPort>, Arc<OutputPort>)> {
         let agg = AggregateFunctionFactory::instance()
             .get("sum", vec![], vec![DataType::Number(NumberDataType::Int32)])?;
-        let func = WindowFunctionInfo::Aggregate(agg, vec![0]);
+        let func = WindowFunctionInfo::Aggregate(agg, vec![0], false);
         let input = InputPort::create();
         let output = OutputPort::create();
         let transform = TransformWindow::try_create_rows(
@@ -30,10 +30,14 @@ use common_sql::executor::physical_plans::WindowFunction;
 
 use crate::pipelines::processors::transforms::group_by::Area;
 
+/// The aggregate functions whose result doesn't depend on the order of rows and whose states are small,
+/// so a sliding window frame can be merged from the states of a [`super::segment_tree::WindowSegmentTree`].
+const SEGMENT_TREE_FUNCTIONS: [&str; 6] = ["sum", "avg", "min", "max", "count", "count_distinct"];
+
 #[derive(Clone)]
 pub enum WindowFunctionInfo {
-    // (func instance, argument offsets)
-    Aggregate(Arc<dyn AggregateFunction>, Vec<usize>),
+    // (func instance, argument offsets, if a segment tree can be used for sliding frames)
+    Aggregate(Arc<dyn AggregateFunction>, Vec<usize>, bool),
     RowNumber,
     Rank,
     DenseRank,
@@ -50,14 +54,36 @@ pub struct WindowFuncAggImpl {
     agg: Arc<dyn AggregateFunction>,
     place: StateAddr,
     args: Vec<usize>,
+    use_segment_tree: bool,
 }
 
 impl WindowFuncAggImpl {
     #[inline]
     pub fn reset(&self) {
+        if self.agg.need_manual_drop_state() {
+            unsafe {
+                self.agg.drop_state(self.place);
+            }
+        }
         self.agg.init_state(self.place);
     }
 
+    #[inline]
+    pub fn agg(&self) -> &Arc<dyn AggregateFunction> {
+        &self.agg
+    }
+
+    #[inline]
+    pub fn place(&self) -> StateAddr {
+        self.place
+    }
+
+    /// If the frames can be slid by a segment tree when the rows can't be retracted.
+    #[inline]
+    pub fn use_segment_tree(&self) -> bool {
+        self.use_segment_tree && !self.support_retract()
+    }
+
     #[inline]
     pub fn arg_columns(&self, data: &DataBlock) -> Vec<Column> {
         self.args
@@ -77,6 +103,16 @@ impl WindowFuncAggImpl {
         self.agg.accumulate_row(self.place, args, row)
     }
 
+    #[inline]
+    pub fn support_retract(&self) -> bool {
+        self.agg.support_retract()
+    }
+
+    #[inline]
+    pub fn retract_row(&self, args: &[Column], row: usize) -> Result<()> {
+        self.agg.retract_row(self.place, args, row)
+    }
+
     #[inline]
     pub fn merge_result(&self, builder: &mut ColumnBuilder) -> Result<()> {
         self.agg.merge_result(self.place, builder)
@@ -95,6 +131,10 @@ impl Drop for WindowFuncAggImpl {
 
 #[derive(Clone)]
 pub struct WindowFuncLagLeadImpl {
+    pub is_lag: bool,
+    pub offset: u64,
+    /// If true, `offset` counts non-NULL rows only.
+    pub ignore_null: bool,
     pub arg: usize,
     pub default: LagLeadDefault,
     pub return_type: DataType,
@@ -103,6 +143,8 @@ pub struct WindowFuncLagLeadImpl {
 #[derive(Clone)]
 pub struct WindowFuncNthValueImpl {
     pub n: Option<u64>,
+    /// If true, NULL rows are skipped when looking for the nth row.
+    pub ignore_null: bool,
     pub arg: usize,
     pub return_type: DataType,
 }
@@ -195,7 +237,8 @@ impl WindowFunctionInfo {
                         Ok(offset)
                     })
                     .collect::<Result<Vec<_>>>()?;
-                Self::Aggregate(agg_func, args)
+                let use_segment_tree = SEGMENT_TREE_FUNCTIONS.contains(&agg.sig.name.as_str());
+                Self::Aggregate(agg_func, args, use_segment_tree)
             }
             WindowFunction::RowNumber => Self::RowNumber,
             WindowFunction::Rank => Self::Rank,
@@ -211,6 +254,9 @@ impl WindowFunctionInfo {
                     }
                 };
                 Self::LagLead(WindowFuncLagLeadImpl {
+                    is_lag: ll.is_lag,
+                    offset: ll.offset,
+                    ignore_null: ll.ignore_null,
                     arg: new_arg,
                     default: new_default,
                     return_type: ll.return_type.clone(),
@@ -220,6 +266,7 @@ impl WindowFunctionInfo {
                 let new_arg = schema.index_of(&func.arg.to_string())?;
                 Self::NthValue(WindowFuncNthValueImpl {
                     n: func.n,
+                    ignore_null: func.ignore_null,
                     arg: new_arg,
                     return_type: func.return_type.clone(),
                 })
@@ -236,7 +283,7 @@ impl WindowFunctionInfo {
 impl WindowFunctionImpl {
     pub(crate) fn try_create(window: WindowFunctionInfo) -> Result<Self> {
         Ok(match window {
-            WindowFunctionInfo::Aggregate(agg, args) => {
+            WindowFunctionInfo::Aggregate(agg, args, use_segment_tree) => {
                 let mut arena = Area::create();
                 let mut state_offset = Vec::with_capacity(1);
                 let layout = get_layout_offsets(&[agg.clone()], &mut state_offset)?;
@@ -247,8 +294,9 @@ impl WindowFunctionImpl {
                     agg,
                     place,
                     args,
+                    use_segment_tree,
                 };
-                agg.reset();
+                agg.agg.init_state(place);
                 Self::Aggregate(agg)
             }
             WindowFunctionInfo::RowNumber => Self::RowNumber,
@@ -237,6 +237,7 @@ impl PhysicalPlanReplacer for Fragmenter {
             output_schema: plan.output_schema.clone(),
             contain_runtime_filter: plan.contain_runtime_filter,
             need_hold_hash_table: plan.need_hold_hash_table,
+            null_aware: plan.null_aware,
             stat_info: plan.stat_info.clone(),
         }))
     }
@@ -17,6 +17,7 @@ use std::sync::Arc;
 
 use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::Partitions;
+use common_catalog::plan::PartitionsShuffleKind;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_settings::ReplaceIntoShuffleStrategy;
@@ -155,6 +156,11 @@ impl PlanFragment {
             ));
         }
 
+        let table_scans = self.get_table_scans();
+        if table_scans.len() > 1 {
+            return self.redistribute_bucket_source_fragment(ctx, table_scans, fragment_actions);
+        }
+
         let read_source = self.get_read_source()?;
 
         let executors = Fragmenter::get_executors(ctx);
@@ -180,6 +186,48 @@ impl PlanFragment {
         Ok(())
     }
 
+    /// Redistribute partitions of the co-located table scans of bucketed tables, the
+    /// partitions of the same bucket of all the tables are sent to the same executor.
+    fn redistribute_bucket_source_fragment(
+        &self,
+        ctx: Arc<QueryContext>,
+        table_scans: Vec<TableScan>,
+        fragment_actions: &mut QueryFragmentActions,
+    ) -> Result<()> {
+        if table_scans
+            .iter()
+            .any(|scan| scan.source.parts.kind != PartitionsShuffleKind::Bucket)
+        {
+            return Err(ErrorCode::Internal(
+                "Invalid source fragment with multiple table scan".to_string(),
+            ));
+        }
+
+        let executors = Fragmenter::get_executors(ctx);
+        let mut partition_reshuffles = Vec::with_capacity(table_scans.len());
+        for scan in table_scans.iter() {
+            partition_reshuffles.push(scan.source.parts.reshuffle(executors.clone())?);
+        }
+
+        for executor in executors {
+            let mut plan = self.plan.clone();
+            for (scan, partition_reshuffle) in table_scans.iter().zip(partition_reshuffles.iter()) {
+                let mut new_read_source = *scan.source.clone();
+                new_read_source.parts = partition_reshuffle[&executor].clone();
+
+                let mut replace_read_source = ReplaceTableScanSource {
+                    plan_id: scan.plan_id,
+                    source: new_read_source,
+                };
+                plan = replace_read_source.replace(&plan)?;
+            }
+
+            fragment_actions.add_action(QueryFragmentAction::create(executor, plan));
+        }
+
+        Ok(())
+    }
+
     fn redistribute_delete_leaf(
         &self,
         ctx: Arc<QueryContext>,
@@ -374,6 +422,21 @@ impl PlanFragment {
         Ok(executor_part)
     }
 
+    fn get_table_scans(&self) -> Vec<TableScan> {
+        let mut table_scans = vec![];
+        PhysicalPlan::traverse(
+            &self.plan,
+            &mut |_| true,
+            &mut |plan| {
+                if let PhysicalPlan::TableScan(scan) = plan {
+                    table_scans.push(scan.clone());
+                }
+            },
+            &mut |_| {},
+        );
+        table_scans
+    }
+
     fn get_read_source(&self) -> Result<DataSourcePlan> {
         if self.fragment_type != FragmentType::Source {
             return Err(ErrorCode::Internal(
@@ -448,6 +511,24 @@ impl PhysicalPlanReplacer for ReplaceReadSource {
     }
 }
 
+/// Replace the source of the table scan with the given plan id.
+struct ReplaceTableScanSource {
+    pub plan_id: u32,
+    pub source: DataSourcePlan,
+}
+
+impl PhysicalPlanReplacer for ReplaceTableScanSource {
+    fn replace_table_scan(&mut self, plan: &TableScan) -> Result<PhysicalPlan> {
+        if plan.plan_id != self.plan_id {
+            return Ok(PhysicalPlan::TableScan(plan.clone()));
+        }
+        Ok(PhysicalPlan::TableScan(TableScan {
+            source: Box::new(self.source.clone()),
+            ..plan.clone()
+        }))
+    }
+}
+
 struct ReplaceReclusterSource {
     pub tasks: Vec<ReclusterTask>,
 }
@@ -39,6 +39,7 @@ use tonic::Status;
 use super::status;
 use super::DoGetStream;
 use super::FlightSqlServiceImpl;
+use crate::interpreters::InterpreterColumnLineage;
 use crate::interpreters::InterpreterFactory;
 use crate::sessions::QueryContext;
 use crate::sessions::Session;
@@ -96,6 +97,7 @@ impl FlightSqlServiceImpl {
             .map_err(|e| status!("Could not create_query_context", e))?;
 
         context.attach_query_str(plan.kind(), plan_extras.statement.to_mask_sql());
+        InterpreterColumnLineage::write_log(&context, &plan_extras.lineage)?;
         let interpreter = InterpreterFactory::get(context.clone(), plan).await?;
 
         let mut blocks = interpreter.execute(context.clone()).await?;
@@ -121,6 +123,7 @@ impl FlightSqlServiceImpl {
             .map_err(|e| status!("Could not create_query_context", e))?;
 
         context.attach_query_str(plan.kind(), plan_extras.statement.to_mask_sql());
+        InterpreterColumnLineage::write_log(&context, &plan_extras.lineage)?;
         let interpreter = InterpreterFactory::get(context.clone(), plan).await?;
 
         let data_schema = plan.schema();
@@ -57,6 +57,7 @@ use poem::Route;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::interpreters::InterpreterColumnLineage;
 use crate::interpreters::InterpreterFactory;
 use crate::interpreters::InterpreterPtr;
 use crate::servers::http::middleware::sanitize_request_headers;
@@ -258,6 +259,8 @@ pub async fn clickhouse_handler_get(
         let format = get_format_with_default(extras.format, default_format)?;
 
         context.attach_query_str(plan.kind(), extras.statement.to_mask_sql());
+        InterpreterColumnLineage::write_log(&context, &extras.lineage)
+            .map_err(InternalServerError)?;
         let interpreter = InterpreterFactory::get(context.clone(), &plan)
             .await
             .map_err(|err| err.display_with_sql(&sql))
@@ -325,6 +328,7 @@ pub async fn clickhouse_handler_post(
             .map_err(BadRequest)?;
         let schema = plan.schema();
         ctx.attach_query_str(plan.kind(), extras.statement.to_mask_sql());
+        InterpreterColumnLineage::write_log(&ctx, &extras.lineage).map_err(InternalServerError)?;
         let mut handle = None;
         if let Plan::Insert(insert) = &mut plan {
             if let InsertInputSource::StreamingWithFormat(format, start, input_context_ref) =
@@ -24,6 +24,7 @@ use minitrace::prelude::*;
 use poem::error::Error as PoemError;
 use poem::error::Result as PoemResult;
 use poem::get;
+use poem::http::HeaderMap;
 use poem::http::StatusCode;
 use poem::post;
 use poem::web::Json;
@@ -41,13 +42,16 @@ use super::query::HttpQueryResponseInternal;
 use super::query::RemoveReason;
 use crate::servers::http::middleware::MetricsMiddleware;
 use crate::servers::http::v1::query::Progresses;
+use crate::servers::http::v1::result_format::query_result_response;
+use crate::servers::http::v1::result_format::ResultFormat;
 use crate::servers::http::v1::HttpQueryContext;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::servers::http::v1::HttpSessionConf;
 use crate::servers::http::v1::JsonBlock;
 use crate::sessions::QueryAffect;
+use crate::sessions::SessionType;
 
-const HEADER_QUERY_ID: &str = "X-DATABEND-QUERY-ID";
+pub(crate) const HEADER_QUERY_ID: &str = "X-DATABEND-QUERY-ID";
 const HEADER_QUERY_STATE: &str = "X-DATABEND-QUERY-STATE";
 const HEADER_QUERY_PAGE_ROWS: &str = "X-DATABEND-QUERY-PAGE-ROWS";
 
@@ -123,6 +127,8 @@ pub struct QueryResponse {
     pub error: Option<QueryError>,
     pub stats: QueryStats,
     pub affect: Option<QueryAffect>,
+    // non-fatal messages, e.g. the result is partial because of the execution time limit
+    pub warnings: Vec<String>,
     pub stats_uri: Option<String>,
     // just call it after client not use it anymore, not care about the server-side behavior
     pub final_uri: Option<String>,
@@ -186,6 +192,7 @@ impl QueryResponse {
             session: r.session,
             stats,
             affect: state.affect,
+            warnings: state.warnings,
             id: id.clone(),
             next_uri,
             stats_uri: Some(make_state_uri(&id)),
@@ -205,6 +212,7 @@ impl QueryResponse {
             stats: QueryStats::default(),
             state: ExecuteStateKind::Failed,
             affect: None,
+            warnings: vec![],
             data: vec![],
             schema: vec![],
             session_id: None,
@@ -359,6 +367,7 @@ async fn query_page_handler(
 #[async_backtrace::framed]
 pub(crate) async fn query_handler(
     ctx: &HttpQueryContext,
+    headers: &HeaderMap,
     Json(req): Json<HttpQueryRequest>,
 ) -> PoemResult<impl IntoResponse> {
     let trace_id = query_id_to_trace_id(&ctx.query_id);
@@ -375,6 +384,22 @@ pub(crate) async fn query_handler(
             .map_err(|err| err.display_with_sql(&sql));
         match query {
             Ok(query) => {
+                if let Some(format) = ResultFormat::from_headers(headers) {
+                    info!(
+                        "http query {} streams the result as {:?}, sql='{}'",
+                        &query.id, format, mask_connection_info(&sql)
+                    );
+                    let settings = ctx.get_session(SessionType::HTTPQuery).get_settings();
+                    return match query_result_response(query, format, &settings).await {
+                        Ok(response) => Ok(response),
+                        Err(e) => {
+                            let e = e.display_with_sql(&sql);
+                            error!("{}: http query fail to stream result, error: {:?}", &ctx.query_id, e);
+                            Ok(QueryResponse::fail_to_start_sql(&e).into_response())
+                        }
+                    };
+                }
+
                 query.update_expire_time(true).await;
                 let resp = query
                     .get_response_page(0)
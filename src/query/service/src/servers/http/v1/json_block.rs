@@ -40,10 +40,9 @@ pub fn block_to_json_value(
     }
     let rows_size = block.num_rows();
     let columns: Vec<Column> = block
-        .convert_to_full()
         .columns()
         .iter()
-        .map(|column| column.value.clone().into_column().unwrap())
+        .map(|entry| entry.to_column(rows_size))
         .collect();
 
     let mut res = Vec::new();
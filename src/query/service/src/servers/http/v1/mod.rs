@@ -16,6 +16,7 @@ mod http_query_handlers;
 pub mod json_block;
 mod load;
 mod query;
+mod result_format;
 mod stage;
 mod suggestions;
 
@@ -35,6 +36,9 @@ pub use query::ExpiringState;
 pub use query::HttpQueryContext;
 pub use query::HttpQueryManager;
 pub use query::HttpSessionConf;
+pub use result_format::ResultFormat;
+pub use result_format::ARROW_STREAM_CONTENT_TYPE;
+pub use result_format::PARQUET_CONTENT_TYPE;
 pub use stage::upload_to_stage;
 pub use stage::UploadToStageResponse;
 pub use suggestions::list_suggestions;
@@ -37,6 +37,7 @@ use serde::Serialize;
 use ExecuteState::*;
 
 use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterColumnLineage;
 use crate::interpreters::InterpreterFactory;
 use crate::interpreters::InterpreterQueryLog;
 use crate::servers::http::v1::query::sized_spsc::SizedChannelSender;
@@ -109,6 +110,7 @@ pub struct ExecuteRunning {
 pub struct ExecuteStopped {
     pub stats: Progresses,
     pub affect: Option<QueryAffect>,
+    pub warnings: Vec<String>,
     pub reason: Result<()>,
     pub session_state: ExecutorSessionState,
     pub query_duration_ms: i64,
@@ -158,6 +160,14 @@ impl Executor {
         }
     }
 
+    pub fn get_warnings(&self) -> Vec<String> {
+        match &self.state {
+            Starting(_) => vec![],
+            Running(r) => r.ctx.get_warnings(),
+            Stopped(r) => r.warnings.clone(),
+        }
+    }
+
     pub fn get_session_state(&self) -> ExecutorSessionState {
         match &self.state {
             Starting(r) => ExecutorSessionState::new(r.ctx.get_current_session()),
@@ -213,6 +223,7 @@ impl Executor {
                     session_state: ExecutorSessionState::new(s.ctx.get_current_session()),
                     query_duration_ms: s.ctx.get_query_duration_ms(),
                     affect: Default::default(),
+                    warnings: vec![],
                 }))
             }
             Running(r) => {
@@ -233,6 +244,7 @@ impl Executor {
                     session_state: ExecutorSessionState::new(r.ctx.get_current_session()),
                     query_duration_ms: r.ctx.get_query_duration_ms(),
                     affect: r.ctx.get_affect(),
+                    warnings: r.ctx.get_warnings(),
                 }))
             }
             Stopped(s) => {
@@ -262,6 +274,7 @@ impl ExecuteState {
         block_sender: SizedChannelSender<DataBlock>,
     ) -> Result<()> {
         ctx.attach_query_str(plan.kind(), extras.statement.to_mask_sql());
+        InterpreterColumnLineage::write_log(&ctx, &extras.lineage)?;
         let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
         let running_state = ExecuteRunning {
             session,
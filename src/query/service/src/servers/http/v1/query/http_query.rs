@@ -26,6 +26,8 @@ use common_base::runtime::TrySpawn;
 use common_catalog::table_context::StageAttachment;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
 use log::info;
 use log::warn;
 use minitrace::prelude::*;
@@ -161,6 +163,7 @@ pub struct ResponseState {
     pub progresses: Progresses,
     pub state: ExecuteStateKind,
     pub affect: Option<QueryAffect>,
+    pub warnings: Vec<String>,
     pub error: Option<ErrorCode>,
 }
 
@@ -363,6 +366,7 @@ impl HttpQuery {
                         session_state: ExecutorSessionState::new(ctx_clone.get_current_session()),
                         query_duration_ms: ctx_clone.get_query_duration_ms(),
                         affect: ctx_clone.get_affect(),
+                        warnings: ctx_clone.get_warnings(),
                     };
                     info!(
                         "{}: http query change state to Stopped, fail to start {:?}",
@@ -429,6 +433,40 @@ impl HttpQuery {
         }
     }
 
+    pub async fn get_schema(&self) -> DataSchemaRef {
+        self.page_manager.lock().await.schema()
+    }
+
+    /// Receives the result blocks as they are, instead of the pages of JSON rows.
+    #[async_backtrace::framed]
+    pub async fn recv_block(&self) -> Option<DataBlock> {
+        self.page_manager.lock().await.next_block().await
+    }
+
+    /// Waits for the query to stop after all the result blocks are received,
+    /// and returns the error of the query if it fails.
+    #[async_backtrace::framed]
+    pub async fn wait_stopped(&self) -> Result<()> {
+        let mut n = 0;
+        loop {
+            let state = self.get_state().await;
+            if let Some(err) = state.error {
+                return Err(err);
+            }
+            if state.state != ExecuteStateKind::Running {
+                return Ok(());
+            }
+            // The block channel is closed right before the query stops.
+            tokio::time::sleep(Duration::from_millis(1)).await;
+            n += 1;
+            if n > 1000 {
+                return Err(ErrorCode::Internal(
+                    "query not stopped after sending all blocks",
+                ));
+            }
+        }
+    }
+
     #[async_backtrace::framed]
     async fn get_state(&self) -> ResponseState {
         let state = self.state.read().await;
@@ -439,6 +477,7 @@ impl HttpQuery {
             state: exe_state,
             error: err,
             affect: state.get_affect(),
+            warnings: state.get_warnings(),
         }
     }
 
@@ -205,6 +205,21 @@ impl PageManager {
         Ok((block, end))
     }
 
+    pub fn schema(&self) -> DataSchemaRef {
+        self.schema.clone()
+    }
+
+    /// Receives the next block as it is, for the results not returned in pages.
+    #[async_backtrace::framed]
+    pub async fn next_block(&mut self) -> Option<DataBlock> {
+        let block = self.block_receiver.recv().await;
+        if block.is_none() {
+            self.block_end = true;
+            self.end = true;
+        }
+        block
+    }
+
     #[async_backtrace::framed]
     pub async fn detach(&self) {
         self.block_receiver.close();
@@ -0,0 +1,179 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use async_stream::stream;
+use common_exception::Result;
+use common_expression::infer_table_schema;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_formats::output_format::ArrowStreamOutputFormat;
+use common_formats::output_format::OutputFormat;
+use common_formats::output_format::ParquetOutputFormat;
+use common_formats::FileFormatOptionsExt;
+use common_settings::Settings;
+use poem::http::header::ACCEPT;
+use poem::http::HeaderMap;
+use poem::Body;
+use poem::IntoResponse;
+use poem::Response;
+
+use super::query::HttpQuery;
+use super::query::RemoveReason;
+use crate::servers::http::v1::http_query_handlers::HEADER_QUERY_ID;
+use crate::servers::http::v1::HttpQueryManager;
+
+pub const ARROW_STREAM_CONTENT_TYPE: &str = "application/vnd.apache.arrow.stream";
+pub const PARQUET_CONTENT_TYPE: &str = "application/vnd.apache.parquet";
+
+/// The binary formats of the query result, which are returned in the response body
+/// at once instead of the pages of JSON rows.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ResultFormat {
+    ArrowStream,
+    Parquet,
+}
+
+impl ResultFormat {
+    /// Gets the binary format from the `Accept` header, `None` for the JSON pages.
+    pub fn from_headers(headers: &HeaderMap) -> Option<ResultFormat> {
+        let accept = headers.get(ACCEPT)?.to_str().ok()?;
+        accept.split(',').find_map(|media_range| {
+            let media_type = media_range.split(';').next().unwrap_or_default();
+            match media_type.trim() {
+                ARROW_STREAM_CONTENT_TYPE => Some(ResultFormat::ArrowStream),
+                PARQUET_CONTENT_TYPE => Some(ResultFormat::Parquet),
+                _ => None,
+            }
+        })
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ResultFormat::ArrowStream => ARROW_STREAM_CONTENT_TYPE,
+            ResultFormat::Parquet => PARQUET_CONTENT_TYPE,
+        }
+    }
+
+    fn create_output_format(
+        &self,
+        schema: DataSchemaRef,
+        settings: &Settings,
+    ) -> Result<Box<dyn OutputFormat>> {
+        match self {
+            ResultFormat::ArrowStream => Ok(Box::new(ArrowStreamOutputFormat::create(schema)?)),
+            ResultFormat::Parquet => {
+                let options = FileFormatOptionsExt::create_from_settings(settings, true)?;
+                let table_schema = infer_table_schema(&schema)?;
+                Ok(Box::new(ParquetOutputFormat::create(
+                    table_schema,
+                    &options,
+                )))
+            }
+        }
+    }
+}
+
+/// Reads the result blocks of the query.
+///
+/// A failed query sends its error message as the last block, so every block is held
+/// back until the next one arrives, and the error of the query is returned in place of it.
+struct ResultBlockReader {
+    query: Arc<HttpQuery>,
+    pending: Option<DataBlock>,
+}
+
+impl ResultBlockReader {
+    #[async_backtrace::framed]
+    async fn next(&mut self) -> Result<Option<DataBlock>> {
+        loop {
+            match self.query.recv_block().await {
+                Some(block) => {
+                    if let Some(block) = self.pending.replace(block) {
+                        return Ok(Some(block));
+                    }
+                }
+                None => {
+                    self.query.wait_stopped().await?;
+                    return Ok(self.pending.take());
+                }
+            }
+        }
+    }
+}
+
+/// Streams the whole result of the query in the binary format with chunked transfer.
+///
+/// The query is removed once the result is sent, the client does not need to call the
+/// final uri. An error before the first block fails the request, while an error after
+/// it aborts the response body.
+#[async_backtrace::framed]
+pub async fn query_result_response(
+    query: Arc<HttpQuery>,
+    format: ResultFormat,
+    settings: &Settings,
+) -> Result<Response> {
+    let schema = query.get_schema().await;
+    let mut output_format = format.create_output_format(schema, settings)?;
+
+    query.update_expire_time(true).await;
+    let mut reader = ResultBlockReader {
+        query: query.clone(),
+        pending: None,
+    };
+    let first_block = match reader.next().await {
+        Ok(block) => block,
+        Err(err) => {
+            remove_query(&query).await;
+            return Err(err);
+        }
+    };
+
+    let query_id = query.id.clone();
+    let stream = stream! {
+        let mut next_block = first_block;
+        while let Some(block) = next_block {
+            match output_format.serialize_block(&block) {
+                Ok(bytes) => yield Ok(bytes),
+                Err(err) => {
+                    remove_query(&query).await;
+                    yield Err(err);
+                    return;
+                }
+            }
+            next_block = match reader.next().await {
+                Ok(block) => block,
+                Err(err) => {
+                    remove_query(&query).await;
+                    yield Err(err);
+                    return;
+                }
+            };
+        }
+        yield output_format.finalize();
+        remove_query(&query).await;
+    };
+
+    Ok(Body::from_bytes_stream(stream)
+        .with_content_type(format.content_type())
+        .with_header(HEADER_QUERY_ID, query_id)
+        .into_response())
+}
+
+async fn remove_query(query: &HttpQuery) {
+    let _ = HttpQueryManager::instance()
+        .remove_query(&query.id, RemoveReason::Finished)
+        .await;
+}
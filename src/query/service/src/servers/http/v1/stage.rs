@@ -92,7 +92,7 @@ pub async fn upload_to_stage(
         .map_err(InternalServerError)?;
     let args = UploadToStageArgs::parse(req)?;
 
-    let stage = if args.stage_name == "~" {
+    let mut stage = if args.stage_name == "~" {
         StageInfo::new_user_stage(
             context
                 .get_current_user()
@@ -106,6 +106,13 @@ pub async fn upload_to_stage(
             .await
             .map_err(InternalServerError)?
     };
+    UserApiProvider::instance()
+        .resolve_storage_secrets(
+            context.get_tenant().as_str(),
+            &mut stage.stage_params.storage,
+        )
+        .await
+        .map_err(InternalServerError)?;
 
     let op = StageTable::get_op(&stage).map_err(InternalServerError)?;
 
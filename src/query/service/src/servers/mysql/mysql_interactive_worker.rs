@@ -46,6 +46,7 @@ use opensrv_mysql::StatementMetaWriter;
 use rand::RngCore;
 
 use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterColumnLineage;
 use crate::interpreters::InterpreterFactory;
 use crate::interpreters::InterpreterQueryLog;
 use crate::servers::mysql::writers::DFInitResultWriter;
@@ -349,6 +350,7 @@ impl InteractiveWorkerBase {
                 let (plan, extras) = planner.plan_sql(query).await?;
 
                 context.attach_query_str(plan.kind(), extras.statement.to_mask_sql());
+                InterpreterColumnLineage::write_log(&context, &extras.lineage)?;
                 let interpreter = InterpreterFactory::get(context.clone(), &plan).await;
 
                 let has_result_set = plan.has_result_set();
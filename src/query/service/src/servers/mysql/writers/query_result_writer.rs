@@ -167,12 +167,16 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
                     NumberDataType::UInt64 => Ok(ColumnType::MYSQL_TYPE_LONGLONG),
                     NumberDataType::Float32 => Ok(ColumnType::MYSQL_TYPE_FLOAT),
                     NumberDataType::Float64 => Ok(ColumnType::MYSQL_TYPE_DOUBLE),
+                    NumberDataType::UInt128 => Ok(ColumnType::MYSQL_TYPE_DECIMAL),
+                    NumberDataType::Int128 => Ok(ColumnType::MYSQL_TYPE_DECIMAL),
                 },
                 DataType::Date => Ok(ColumnType::MYSQL_TYPE_DATE),
                 DataType::Timestamp => Ok(ColumnType::MYSQL_TYPE_DATETIME),
+                DataType::Interval => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 DataType::Array(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 DataType::Map(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 DataType::Bitmap => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+                DataType::Geometry => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 DataType::Tuple(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 DataType::Variant => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 DataType::Decimal(_) => Ok(ColumnType::MYSQL_TYPE_DECIMAL),
@@ -223,10 +227,9 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
                     let mut buf = Vec::<u8>::new();
 
                     let columns = block
-                        .convert_to_full()
                         .columns()
                         .iter()
-                        .map(|column| column.value.clone().into_column().unwrap())
+                        .map(|entry| entry.to_column(num_rows))
                         .collect::<Vec<_>>();
 
                     for row_index in 0..num_rows {
@@ -21,6 +21,8 @@ use std::collections::VecDeque;
 use std::future::Future;
 use std::net::SocketAddr;
 use std::str::FromStr;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicU64;
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
@@ -77,6 +79,7 @@ use common_storages_parquet::Parquet2Table;
 use common_storages_parquet::ParquetRSTable;
 use common_storages_result_cache::ResultScan;
 use common_storages_stage::StageTable;
+use common_users::EffectivePrivileges;
 use common_users::GrantObjectVisibilityChecker;
 use common_users::UserApiProvider;
 use dashmap::mapref::multiple::RefMulti;
@@ -217,6 +220,22 @@ impl QueryContext {
         self.shared.session.clone()
     }
 
+    /// Resolves the role graph of the current session once per query, so the access checks
+    /// on many objects don't look up the roles again for each of them.
+    #[async_backtrace::framed]
+    pub async fn get_effective_privileges(&self) -> Result<Arc<EffectivePrivileges>> {
+        if let Some(privileges) = self.shared.effective_privileges.read().clone() {
+            return Ok(privileges);
+        }
+        let privileges = Arc::new(
+            self.get_current_session()
+                .get_effective_privileges()
+                .await?,
+        );
+        *self.shared.effective_privileges.write() = Some(privileges.clone());
+        Ok(privileges)
+    }
+
     // Get one session by session id.
     pub fn get_session_by_id(self: &Arc<Self>, id: &str) -> Option<Arc<Session>> {
         SessionManager::instance().get_session_by_id(id)
@@ -286,9 +305,39 @@ impl QueryContext {
         *self.shared.finish_time.write() = Some(time)
     }
 
+    /// The counter of the CPU time in nanoseconds, which is passed to the executors of the query.
+    pub fn get_cpu_time(&self) -> Arc<AtomicU64> {
+        self.shared.cpu_time.clone()
+    }
+
+    pub fn get_cpu_time_value(&self) -> Duration {
+        Duration::from_nanos(self.shared.cpu_time.load(Ordering::Relaxed))
+    }
+
+    /// The flag raised by the executor when the query is stopped by `max_execute_time_in_seconds`
+    /// with `timeout_overflow_mode` set to "break".
+    pub fn get_partial_result(&self) -> Arc<AtomicBool> {
+        self.shared.partial_result.clone()
+    }
+
+    pub fn get_warnings(&self) -> Vec<String> {
+        let mut warnings = vec![];
+        if self.shared.partial_result.load(Ordering::Acquire) {
+            warnings.push(
+                "The query exceeds max_execute_time_in_seconds, the result is partial".to_string(),
+            );
+        }
+        warnings
+    }
+
     pub fn evict_table_from_cache(&self, catalog: &str, database: &str, table: &str) -> Result<()> {
         self.shared.evict_table_from_cache(catalog, database, table)
     }
+
+    /// Get the snapshots pinned in this query, as (`catalog.database.table`, snapshot id).
+    pub fn get_pinned_snapshots(&self) -> Vec<(String, String)> {
+        self.shared.get_pinned_snapshots()
+    }
 }
 
 #[async_trait::async_trait]
@@ -374,6 +423,14 @@ impl TableContext for QueryContext {
         self.shared.result_progress.as_ref().get_values()
     }
 
+    fn get_network_progress(&self) -> Arc<Progress> {
+        self.shared.network_progress.clone()
+    }
+
+    fn get_network_progress_value(&self) -> ProgressValues {
+        self.shared.network_progress.as_ref().get_values()
+    }
+
     fn get_status_info(&self) -> String {
         let status = self.shared.status.read();
         status.clone()
@@ -705,6 +762,16 @@ impl TableContext for QueryContext {
         self.shared.get_table(catalog, database, table).await
     }
 
+    #[async_backtrace::framed]
+    async fn get_pinned_table(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+    ) -> Result<Arc<dyn Table>> {
+        self.shared.get_pinned_table(catalog, database, table).await
+    }
+
     #[async_backtrace::framed]
     async fn filter_out_copied_files(
         &self,
@@ -794,7 +861,10 @@ impl TableContext for QueryContext {
     }
 
     fn add_file_status(&self, file_path: &str, file_status: FileStatus) -> Result<()> {
-        if matches!(self.get_query_kind(), QueryKind::CopyIntoTable) {
+        // The streaming load keeps the status only for the records to be quarantined.
+        if matches!(self.get_query_kind(), QueryKind::CopyIntoTable)
+            || self.get_on_error_mode() == Some(OnErrorMode::Quarantine)
+        {
             self.shared.copy_status.add_chunk(file_path, file_status);
         }
         Ok(())
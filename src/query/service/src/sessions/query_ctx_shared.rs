@@ -13,8 +13,10 @@
 // limitations under the License.
 
 use std::collections::hash_map::Entry;
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicU64;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Weak;
@@ -23,9 +25,11 @@ use std::time::SystemTime;
 use common_base::base::Progress;
 use common_base::runtime::Runtime;
 use common_catalog::catalog::CatalogManager;
+use common_catalog::catalog::CATALOG_DEFAULT;
 use common_catalog::query_kind::QueryKind;
 use common_catalog::table_context::MaterializedCtesBlocks;
 use common_catalog::table_context::StageAttachment;
+use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::principal::OnErrorMode;
@@ -36,6 +40,7 @@ use common_settings::Settings;
 use common_storage::CopyStatus;
 use common_storage::DataOperator;
 use common_storage::StorageMetrics;
+use common_users::EffectivePrivileges;
 use dashmap::DashMap;
 use parking_lot::Mutex;
 use parking_lot::RwLock;
@@ -44,6 +49,7 @@ use uuid::Uuid;
 use crate::clusters::Cluster;
 use crate::pipelines::executor::PipelineExecutor;
 use crate::sessions::query_affect::QueryAffect;
+use crate::sessions::ReadOnlyTableCache;
 use crate::sessions::Session;
 use crate::storages::Table;
 
@@ -65,6 +71,12 @@ pub struct QueryContextShared {
     pub(in crate::sessions) group_by_spill_progress: Arc<Progress>,
     /// result_progress for metrics of result datablocks (uncompressed)
     pub(in crate::sessions) result_progress: Arc<Progress>,
+    /// Record how many bytes have been sent to the other nodes by the exchanges.
+    pub(in crate::sessions) network_progress: Arc<Progress>,
+    /// The CPU time in nanoseconds consumed by the pipelines of the query.
+    pub(in crate::sessions) cpu_time: Arc<AtomicU64>,
+    /// Whether the query has been stopped by the execution time limit and returns a partial result.
+    pub(in crate::sessions) partial_result: Arc<AtomicBool>,
     pub(in crate::sessions) error: Arc<Mutex<Option<ErrorCode>>>,
     pub(in crate::sessions) session: Arc<Session>,
     pub(in crate::sessions) runtime: Arc<RwLock<Option<Arc<Runtime>>>>,
@@ -74,6 +86,8 @@ pub struct QueryContextShared {
     pub(in crate::sessions) running_query_kind: Arc<RwLock<Option<QueryKind>>>,
     pub(in crate::sessions) aborting: Arc<AtomicBool>,
     pub(in crate::sessions) tables_refs: Arc<Mutex<HashMap<DatabaseAndTable, Arc<dyn Table>>>>,
+    /// Snapshot id of each table pinned in the query.
+    pub(in crate::sessions) pinned_snapshots: Arc<Mutex<BTreeMap<DatabaseAndTable, String>>>,
     pub(in crate::sessions) affect: Arc<Mutex<Option<QueryAffect>>>,
     pub(in crate::sessions) catalog_manager: Arc<CatalogManager>,
     pub(in crate::sessions) data_operator: DataOperator,
@@ -100,6 +114,8 @@ pub struct QueryContextShared {
     pub(in crate::sessions) user_agent: Arc<RwLock<String>>,
     /// Key is (cte index, used_count), value contains cte's materialized blocks
     pub(in crate::sessions) materialized_cte_tables: MaterializedCtesBlocks,
+    /// The privileges of the session, resolved on the first access check of the query.
+    pub(in crate::sessions) effective_privileges: Arc<RwLock<Option<Arc<EffectivePrivileges>>>>,
 }
 
 impl QueryContextShared {
@@ -123,6 +139,7 @@ impl QueryContextShared {
             running_query_kind: Arc::new(RwLock::new(None)),
             aborting: Arc::new(AtomicBool::new(false)),
             tables_refs: Arc::new(Mutex::new(HashMap::new())),
+            pinned_snapshots: Arc::new(Mutex::new(BTreeMap::new())),
             affect: Arc::new(Mutex::new(None)),
             executor: Arc::new(RwLock::new(Weak::new())),
             stage_attachment: Arc::new(RwLock::new(None)),
@@ -140,6 +157,10 @@ impl QueryContextShared {
             join_spill_progress: Arc::new(Progress::create()),
             agg_spill_progress: Arc::new(Progress::create()),
             group_by_spill_progress: Arc::new(Progress::create()),
+            network_progress: Arc::new(Progress::create()),
+            cpu_time: Arc::new(AtomicU64::new(0)),
+            partial_result: Arc::new(AtomicBool::new(false)),
+            effective_privileges: Arc::new(RwLock::new(None)),
         }))
     }
 
@@ -287,8 +308,16 @@ impl QueryContextShared {
     ) -> Result<Arc<dyn Table>> {
         let tenant = self.get_tenant();
         let table_meta_key = (catalog.to_string(), database.to_string(), table.to_string());
+        let read_only = GlobalConfig::instance().query.read_only && catalog == CATALOG_DEFAULT;
         let catalog = self.catalog_manager.get_catalog(&tenant, catalog).await?;
-        let cache_table = catalog.get_table(tenant.as_str(), database, table).await?;
+        let cache_table = match read_only {
+            true => {
+                ReadOnlyTableCache::instance()
+                    .get_table(&catalog, &tenant, database, table)
+                    .await?
+            }
+            false => catalog.get_table(tenant.as_str(), database, table).await?,
+        };
 
         let mut tables_refs = self.tables_refs.lock();
 
@@ -298,10 +327,57 @@ impl QueryContextShared {
         }
     }
 
+    #[async_backtrace::framed]
+    pub async fn get_pinned_table(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+    ) -> Result<Arc<dyn Table>> {
+        let table_meta_key = (catalog.to_string(), database.to_string(), table.to_string());
+        let cache_table = self.get_table(catalog, database, table).await?;
+        if self.pinned_snapshots.lock().contains_key(&table_meta_key) {
+            return Ok(cache_table);
+        }
+
+        let Some(pinned) = cache_table.pin_snapshot().await? else {
+            return Ok(cache_table);
+        };
+
+        let mut tables_refs = self.tables_refs.lock();
+        let mut pinned_snapshots = self.pinned_snapshots.lock();
+        if pinned_snapshots.contains_key(&table_meta_key) {
+            // Pinned concurrently, keep the first one.
+            return Ok(tables_refs
+                .get(&table_meta_key)
+                .cloned()
+                .unwrap_or(cache_table));
+        }
+        pinned_snapshots.insert(table_meta_key.clone(), pinned.snapshot_id);
+        match pinned.table {
+            Some(pinned_table) => {
+                tables_refs.insert(table_meta_key, pinned_table.clone());
+                Ok(pinned_table)
+            }
+            None => Ok(cache_table),
+        }
+    }
+
+    pub fn get_pinned_snapshots(&self) -> Vec<(String, String)> {
+        self.pinned_snapshots
+            .lock()
+            .iter()
+            .map(|((catalog, database, table), snapshot_id)| {
+                (format!("{catalog}.{database}.{table}"), snapshot_id.clone())
+            })
+            .collect()
+    }
+
     pub fn evict_table_from_cache(&self, catalog: &str, database: &str, table: &str) -> Result<()> {
         let table_meta_key = (catalog.to_string(), database.to_string(), table.to_string());
         let mut tables_refs = self.tables_refs.lock();
         tables_refs.remove(&table_meta_key);
+        self.pinned_snapshots.lock().remove(&table_meta_key);
         Ok(())
     }
 
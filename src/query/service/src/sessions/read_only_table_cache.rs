@@ -0,0 +1,80 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_base::base::GlobalInstance;
+use common_catalog::catalog::Catalog;
+use common_config::InnerConfig;
+use common_exception::Result;
+use common_meta_app::schema::TableInfo;
+use parking_lot::RwLock;
+
+use crate::storages::Table;
+
+// (tenant, database, table)
+type TableKey = (String, String, String);
+
+/// Caches the table info of the default catalog across queries on a read-only node.
+///
+/// A read-only node never changes the tables itself, so the cached table info
+/// is only refreshed after `read_only_table_cache_ttl_secs`, and the tables written
+/// by the other nodes are visible to the queries after at most this delay.
+pub struct ReadOnlyTableCache {
+    ttl: Duration,
+    tables: RwLock<HashMap<TableKey, (Instant, Arc<TableInfo>)>>,
+}
+
+impl ReadOnlyTableCache {
+    pub fn init(conf: &InnerConfig) -> Result<()> {
+        GlobalInstance::set(Arc::new(ReadOnlyTableCache {
+            ttl: Duration::from_secs(conf.query.read_only_table_cache_ttl_secs),
+            tables: RwLock::new(HashMap::new()),
+        }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<ReadOnlyTableCache> {
+        GlobalInstance::get()
+    }
+
+    #[async_backtrace::framed]
+    pub async fn get_table(
+        &self,
+        catalog: &Arc<dyn Catalog>,
+        tenant: &str,
+        database: &str,
+        table: &str,
+    ) -> Result<Arc<dyn Table>> {
+        let key = (tenant.to_string(), database.to_string(), table.to_string());
+        if let Some((cached_on, table_info)) = self.tables.read().get(&key) {
+            if cached_on.elapsed() < self.ttl {
+                // Build a new table for every query, the table keeps the per-query states.
+                return catalog.get_table_by_info(table_info);
+            }
+        }
+
+        let table = catalog.get_table(tenant, database, table).await?;
+        let mut tables = self.tables.write();
+        tables.retain(|_, (cached_on, _)| cached_on.elapsed() < self.ttl);
+        tables.insert(
+            key,
+            (Instant::now(), Arc::new(table.get_table_info().clone())),
+        );
+        Ok(table)
+    }
+}
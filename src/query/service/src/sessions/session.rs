@@ -25,6 +25,7 @@ use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserInfo;
 use common_meta_app::principal::UserPrivilegeType;
 use common_settings::Settings;
+use common_users::EffectivePrivileges;
 use common_users::GrantObjectVisibilityChecker;
 use log::debug;
 use parking_lot::RwLock;
@@ -254,6 +255,11 @@ impl Session {
             .await
     }
 
+    #[async_backtrace::framed]
+    pub async fn get_effective_privileges(self: &Arc<Self>) -> Result<EffectivePrivileges> {
+        self.privilege_mgr.get_effective_privileges().await
+    }
+
     #[async_backtrace::framed]
     pub async fn validate_ownership(self: &Arc<Self>, object: &GrantObjectByID) -> Result<()> {
         if matches!(self.get_type(), SessionType::Local) {
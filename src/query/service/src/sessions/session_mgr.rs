@@ -105,6 +105,13 @@ impl SessionManager {
             settings.set_max_storage_io_requests(max_storage_io_requests)?;
         }
 
+        // Read-only nodes serve the dashboards from the result cache, the tables
+        // written by the other nodes are visible after the cache expires.
+        if query_config.read_only {
+            settings.set_enable_query_result_cache(true)?;
+            settings.set_query_result_cache_allow_inconsistent(true)?;
+        }
+
         if let Some(enterprise_license_key) = query_config.databend_enterprise_license.clone() {
             unsafe {
                 settings.set_enterprise_license(enterprise_license_key)?;
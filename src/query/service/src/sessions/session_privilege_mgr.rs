@@ -21,6 +21,7 @@ use common_meta_app::principal::GrantObjectByID;
 use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserInfo;
 use common_meta_app::principal::UserPrivilegeType;
+use common_users::EffectivePrivileges;
 use common_users::GrantObjectVisibilityChecker;
 use common_users::RoleCacheManager;
 use common_users::BUILTIN_ROLE_PUBLIC;
@@ -57,6 +58,8 @@ pub trait SessionPrivilegeManager {
 
     async fn get_all_available_roles(&self) -> Result<Vec<RoleInfo>>;
 
+    async fn get_effective_privileges(&self) -> Result<EffectivePrivileges>;
+
     async fn validate_privilege(
         &self,
         object: &GrantObject,
@@ -236,43 +239,34 @@ impl SessionPrivilegeManager for SessionPrivilegeManagerImpl {
         Ok(related_roles)
     }
 
+    // Resolves the role graph of the current session, the result can be kept to validate
+    // the privileges on many objects.
+    #[async_backtrace::framed]
+    async fn get_effective_privileges(&self) -> Result<EffectivePrivileges> {
+        let current_user = self.get_current_user()?;
+        self.ensure_current_role().await?;
+        let effective_roles = self.get_all_effective_roles().await?;
+        let available_roles = if self.session_ctx.get_secondary_roles().is_none() {
+            effective_roles.clone()
+        } else {
+            self.get_all_available_roles().await?
+        };
+        Ok(EffectivePrivileges::new(
+            current_user,
+            effective_roles,
+            available_roles,
+        ))
+    }
+
     #[async_backtrace::framed]
     async fn validate_privilege(
         &self,
         object: &GrantObject,
         privilege: Vec<UserPrivilegeType>,
     ) -> Result<()> {
-        // 1. check user's privilege set
-        let current_user = self.get_current_user()?;
-        let user_verified = current_user
-            .grants
-            .verify_privilege(object, privilege.clone());
-        if user_verified {
-            return Ok(());
-        }
-
-        // 2. check the user's roles' privilege set
-        self.ensure_current_role().await?;
-        let effective_roles = self.get_all_effective_roles().await?;
-        let role_verified = &effective_roles
-            .iter()
-            .any(|r| r.grants.verify_privilege(object, privilege.clone()));
-        if *role_verified {
-            return Ok(());
-        }
-
-        let roles_name = effective_roles
-            .iter()
-            .map(|r| r.name.clone())
-            .collect::<Vec<_>>()
-            .join(",");
-        Err(ErrorCode::PermissionDenied(format!(
-            "Permission denied, privilege {:?} is required on {} for user {} with roles [{}]",
-            privilege.clone(),
-            object,
-            &current_user.identity(),
-            roles_name,
-        )))
+        self.get_effective_privileges()
+            .await?
+            .validate_privilege(object, privilege)
     }
 
     #[async_backtrace::framed]
@@ -16,17 +16,29 @@ use std::collections::HashMap;
 use std::collections::HashSet;
 use std::fmt::Display;
 use std::fmt::Formatter;
+use std::hash::Hasher;
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use common_base::base::GlobalUniqName;
 use common_base::base::ProgressValues;
 use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::arrow::deserialize_column;
 use common_expression::arrow::serialize_column;
 use common_expression::DataBlock;
+use common_meta_app::storage::StorageFsConfig;
+use common_meta_app::storage::StorageParams;
+use common_storage::init_operator;
 use log::info;
+use log::warn;
 use opendal::Operator;
+use storages_common_cache::ScratchConsumer;
+use storages_common_cache::ScratchManager;
+use storages_common_cache::ScratchReservation;
+use storages_common_cache_manager::CacheManager;
+use twox_hash::XxHash64;
 
 use crate::sessions::QueryContext;
 
@@ -80,6 +92,40 @@ pub struct Spiller {
     pub partition_location: HashMap<u8, Vec<String>>,
     /// Record columns layout for spilled data, will be used when read data from disk
     pub columns_layout: HashMap<String, Vec<usize>>,
+    /// Record the xxhash64 checksums of the spilled files if `enable_block_checksum` is set,
+    /// will be verified when read data from disk
+    pub checksums: HashMap<String, u64>,
+    /// The local scratch disk, spilled files are written to it while its budget allows
+    local: Option<LocalSpill>,
+    /// The spilled files on the local scratch disk and their budgets
+    local_files: HashMap<String, ScratchReservation>,
+}
+
+struct LocalSpill {
+    scratch: Arc<ScratchManager>,
+    root: PathBuf,
+    operator: Operator,
+}
+
+impl LocalSpill {
+    fn try_create() -> Option<LocalSpill> {
+        let scratch = CacheManager::instance().get_scratch_manager()?;
+        let root = scratch.path(ScratchConsumer::Spill);
+        let params = StorageParams::Fs(StorageFsConfig {
+            root: root.to_string_lossy().to_string(),
+        });
+        match init_operator(&params) {
+            Ok(operator) => Some(LocalSpill {
+                scratch,
+                root,
+                operator,
+            }),
+            Err(e) => {
+                warn!("init local spill operator failed, spill to storage instead: {e}");
+                None
+            }
+        }
+    }
 }
 
 impl Spiller {
@@ -99,6 +145,17 @@ impl Spiller {
             spilled_partition_set: Default::default(),
             partition_location: Default::default(),
             columns_layout: Default::default(),
+            checksums: Default::default(),
+            local: LocalSpill::try_create(),
+            local_files: Default::default(),
+        }
+    }
+
+    /// The operator the spilled file at `location` is kept by.
+    fn operator_of(&self, location: &str) -> &Operator {
+        match &self.local {
+            Some(local) if self.local_files.contains_key(location) => &local.operator,
+            _ => &self.operator,
         }
     }
 
@@ -133,7 +190,6 @@ impl Spiller {
                 locs.push(location.clone());
             })
             .or_insert(vec![location.clone()]);
-        let mut writer = self.operator.writer(location.as_str()).await?;
         let columns = data.columns().to_vec();
         let mut columns_data = Vec::with_capacity(columns.len());
         for column in columns.into_iter() {
@@ -147,6 +203,25 @@ impl Spiller {
                 .or_insert(vec![column_data.len()]);
             columns_data.push(column_data);
         }
+        if self.ctx.get_settings().get_enable_block_checksum()? {
+            let mut hasher = XxHash64::with_seed(0);
+            for data in columns_data.iter() {
+                hasher.write(data);
+            }
+            self.checksums.insert(location.clone(), hasher.finish());
+        }
+
+        // Spill to the local scratch disk if it has room, otherwise to the storage.
+        let size = columns_data.iter().map(|d| d.len() as u64).sum::<u64>();
+        if let Some(local) = &self.local {
+            if let Some(reservation) = local.scratch.acquire(ScratchConsumer::Spill, size) {
+                self.local_files.insert(location.clone(), reservation);
+            }
+        }
+        let mut writer = self
+            .operator_of(&location)
+            .writer(location.as_str())
+            .await?;
         for data in columns_data.into_iter() {
             writer.write(data).await?;
         }
@@ -190,7 +265,22 @@ impl Spiller {
         let mut spilled_data = Vec::with_capacity(files.len());
         // Todo: make it parallel
         for file in files.iter() {
-            let data = self.operator.read(file).await?;
+            let data = self.operator_of(file).read(file).await?;
+            if let Some(expected) = self.checksums.get(file) {
+                let mut hasher = XxHash64::with_seed(0);
+                hasher.write(&data);
+                let actual = hasher.finish();
+                if actual != *expected {
+                    return Err(ErrorCode::ChecksumMismatch(format!(
+                        "Checksum mismatch of spilled file {}(partition: {}, {} bytes), expected {:#018x}, actual {:#018x}",
+                        file,
+                        p_id,
+                        data.len(),
+                        expected,
+                        actual
+                    )));
+                }
+            }
             let mut begin = 0;
             let mut columns = Vec::with_capacity(self.columns_layout.len());
             let columns_layout = self.columns_layout.get(file).unwrap();
@@ -278,3 +368,16 @@ impl Spiller {
         !self.spilled_partition_set.is_empty()
     }
 }
+
+impl Drop for Spiller {
+    fn drop(&mut self) {
+        // The budgets of the local files are given back after the files are removed.
+        if let Some(local) = &self.local {
+            for location in self.local_files.keys() {
+                if let Err(e) = std::fs::remove_file(local.root.join(location)) {
+                    warn!("remove local spilled file {} failed: {}", location, e);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_catalog::plan::PartInfo;
+use common_catalog::plan::PartInfoPtr;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PartitionsShuffleKind;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::BenchmarkTable;
+
+#[derive(serde::Serialize, serde::Deserialize, PartialEq)]
+pub struct BenchmarkPartInfo {
+    pub table: BenchmarkTable,
+    pub scale_factor: f64,
+    pub part_start: u64,
+    pub part_end: u64,
+}
+
+#[typetag::serde(name = "benchmark_gen")]
+impl PartInfo for BenchmarkPartInfo {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, info: &Box<dyn PartInfo>) -> bool {
+        info.as_any()
+            .downcast_ref::<BenchmarkPartInfo>()
+            .is_some_and(|other| self == other)
+    }
+
+    fn hash(&self) -> u64 {
+        0
+    }
+}
+
+impl BenchmarkPartInfo {
+    pub fn create(
+        table: BenchmarkTable,
+        scale_factor: f64,
+        start: u64,
+        end: u64,
+    ) -> Arc<Box<dyn PartInfo>> {
+        Arc::new(Box::new(BenchmarkPartInfo {
+            table,
+            scale_factor,
+            part_start: start,
+            part_end: end,
+        }))
+    }
+
+    pub fn from_part(info: &PartInfoPtr) -> Result<&BenchmarkPartInfo> {
+        info.as_any()
+            .downcast_ref::<BenchmarkPartInfo>()
+            .ok_or(ErrorCode::Internal(
+                "Cannot downcast from PartInfo to BenchmarkPartInfo.",
+            ))
+    }
+}
+
+/// Splits the units of the table into `workers` parts of about the same size.
+pub fn generate_benchmark_parts(
+    table: BenchmarkTable,
+    scale_factor: f64,
+    workers: u64,
+    total: u64,
+) -> Partitions {
+    let workers = workers.clamp(1, total.max(1));
+    let part_size = total / workers;
+    let part_remain = total % workers;
+
+    let mut partitions = Vec::with_capacity(workers as usize);
+    let mut part_begin = 0;
+    for part in 0..workers {
+        let part_end = part_begin + part_size + u64::from(part < part_remain);
+        partitions.push(BenchmarkPartInfo::create(
+            table,
+            scale_factor,
+            part_begin,
+            part_end,
+        ));
+        part_begin = part_end;
+    }
+
+    Partitions::create_nolazy(PartitionsShuffleKind::Seq, partitions)
+}
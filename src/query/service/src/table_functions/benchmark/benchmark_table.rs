@@ -0,0 +1,343 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::NaiveDateTime;
+use chrono::TimeZone;
+use chrono::Utc;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::PartInfoPtr;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table_args::TableArgs;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::type_check::check_number;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::number::F64;
+use common_expression::DataBlock;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::TableSchemaRef;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_pipeline_core::Pipeline;
+use common_pipeline_core::SourcePipeBuilder;
+use common_pipeline_sources::EmptySource;
+use common_pipeline_sources::SyncSource;
+use common_pipeline_sources::SyncSourcer;
+
+use super::generate_benchmark_parts;
+use super::BenchmarkPartInfo;
+use super::TpcdsTable;
+use super::TpchTable;
+use crate::pipelines::processors::OutputPort;
+use crate::pipelines::processors::ProcessorPtr;
+use crate::sessions::TableContext;
+use crate::storages::Table;
+use crate::table_functions::TableFunction;
+
+/// A table of a benchmark.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum BenchmarkTable {
+    Tpch(TpchTable),
+    Tpcds(TpcdsTable),
+}
+
+impl BenchmarkTable {
+    pub fn from_name(table_func_name: &str, name: &str) -> Result<Self> {
+        match table_func_name {
+            "tpcds_gen" => Ok(BenchmarkTable::Tpcds(TpcdsTable::from_name(name)?)),
+            _ => Ok(BenchmarkTable::Tpch(TpchTable::from_name(name)?)),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            BenchmarkTable::Tpch(table) => table.name(),
+            BenchmarkTable::Tpcds(table) => table.name(),
+        }
+    }
+
+    pub fn schema(&self) -> TableSchemaRef {
+        match self {
+            BenchmarkTable::Tpch(table) => table.schema(),
+            BenchmarkTable::Tpcds(table) => table.schema(),
+        }
+    }
+
+    /// The number of units the generation of the table is split by.
+    pub fn units(&self, scale_factor: f64) -> u64 {
+        match self {
+            BenchmarkTable::Tpch(table) => table.units(scale_factor),
+            BenchmarkTable::Tpcds(table) => table.units(scale_factor),
+        }
+    }
+
+    /// The number of rows generated by a unit, on average.
+    pub fn rows_per_unit(&self) -> u64 {
+        match self {
+            BenchmarkTable::Tpch(table) => table.rows_per_unit(),
+            BenchmarkTable::Tpcds(table) => table.rows_per_unit(),
+        }
+    }
+
+    /// Whether every unit generates at least one row.
+    pub fn generates_rows_per_unit(&self) -> bool {
+        match self {
+            BenchmarkTable::Tpch(_) => true,
+            BenchmarkTable::Tpcds(table) => table.generates_rows_per_unit(),
+        }
+    }
+
+    /// Generates the rows of the units in `[start, end)`.
+    pub fn generate(&self, scale_factor: f64, start: u64, end: u64) -> DataBlock {
+        match self {
+            BenchmarkTable::Tpch(table) => table.generate(scale_factor, start, end),
+            BenchmarkTable::Tpcds(table) => table.generate(scale_factor, start, end),
+        }
+    }
+}
+
+/// `tpch_gen('<table>' [, sf => <scale factor>])` and `tpcds_gen('<table>' [, sf => <scale factor>])`
+/// generate a table of the TPC-H and the TPC-DS benchmarks at the scale factor, which is 1
+/// by default.
+pub struct BenchmarkGenTable {
+    table_info: TableInfo,
+    table: BenchmarkTable,
+    scale_factor: f64,
+}
+
+impl BenchmarkGenTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        if table_args.positioned.len() != 1 {
+            return Err(ErrorCode::BadArguments(format!(
+                "{} must accept exactly 1 positioned arg, the name of the table",
+                table_func_name
+            )));
+        }
+        let name = TableArgs::expect_all_strings(table_args.positioned.clone())?.remove(0);
+        let table = BenchmarkTable::from_name(table_func_name, &name)?;
+
+        let mut scale_factor = 1.0;
+        for (key, value) in &table_args.named {
+            match key.to_lowercase().as_str() {
+                "sf" => {
+                    scale_factor = check_number::<_, F64>(
+                        None,
+                        &FunctionContext::default(),
+                        &Expr::<usize>::Constant {
+                            span: None,
+                            scalar: value.clone(),
+                            data_type: value.as_ref().infer_data_type(),
+                        },
+                        &BUILTIN_FUNCTIONS,
+                    )?
+                    .into_inner();
+                }
+                _ => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Unknown named arg '{}' of {}, expect sf",
+                        key, table_func_name
+                    )));
+                }
+            }
+        }
+
+        if !scale_factor.is_finite() || scale_factor <= 0.0 {
+            return Err(ErrorCode::BadArguments(format!(
+                "The scale factor of {} must be positive, but got {}",
+                table_func_name, scale_factor
+            )));
+        }
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema: table.schema(),
+                engine: "BenchmarkGen".to_string(),
+                created_on: Utc
+                    .from_utc_datetime(&NaiveDateTime::from_timestamp_opt(0, 0).unwrap()),
+                updated_on: Utc
+                    .from_utc_datetime(&NaiveDateTime::from_timestamp_opt(0, 0).unwrap()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        Ok(Arc::new(BenchmarkGenTable {
+            table_info,
+            table,
+            scale_factor,
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for BenchmarkGenTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+        _dry_run: bool,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let mut total = self.table.units(self.scale_factor);
+
+        if let Some(extras) = &push_downs {
+            if let Some(limit) = extras.limit {
+                if extras.filters.is_none()
+                    && extras.order_by.is_empty()
+                    && self.table.generates_rows_per_unit()
+                {
+                    // Every unit generates at least one row.
+                    total = total.min(limit as u64);
+                }
+            }
+        }
+
+        let max_block_size = ctx.get_settings().get_max_block_size()?;
+        let num_rows = total * self.table.rows_per_unit();
+        let fake_partitions = num_rows / max_block_size + 1;
+        let statistics = PartStatistics::new_estimated(
+            None,
+            num_rows as usize,
+            num_rows as usize * self.table.schema().fields().len() * 8,
+            fake_partitions as usize,
+            fake_partitions as usize,
+        );
+
+        let cluster = ctx.get_cluster();
+        let max_threads = ctx.get_settings().get_max_threads()?;
+        let workers = std::cmp::min(
+            fake_partitions,
+            max_threads * cluster.nodes.len().max(1) as u64,
+        );
+
+        let parts = generate_benchmark_parts(self.table, self.scale_factor, workers, total);
+        Ok((statistics, parts))
+    }
+
+    fn table_args(&self) -> Option<TableArgs> {
+        Some(TableArgs {
+            positioned: vec![Scalar::String(self.table.name().as_bytes().to_vec())],
+            named: HashMap::from([(
+                "sf".to_string(),
+                Scalar::Number(NumberScalar::Float64(F64::from(self.scale_factor))),
+            )]),
+        })
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+        _put_cache: bool,
+    ) -> Result<()> {
+        if plan.parts.partitions.is_empty() {
+            pipeline.add_source(EmptySource::create, 1)?;
+            return Ok(());
+        }
+
+        let mut source_builder = SourcePipeBuilder::create();
+
+        for part in &plan.parts.partitions {
+            let output = OutputPort::create();
+            source_builder.add_source(
+                output.clone(),
+                BenchmarkGenSource::create(output, ctx.clone(), part)?,
+            );
+        }
+
+        pipeline.add_pipe(source_builder.finalize());
+        Ok(())
+    }
+}
+
+struct BenchmarkGenSource {
+    table: BenchmarkTable,
+    scale_factor: f64,
+    begin: u64,
+    end: u64,
+    step: u64,
+}
+
+impl BenchmarkGenSource {
+    pub fn create(
+        output: Arc<OutputPort>,
+        ctx: Arc<dyn TableContext>,
+        part: &PartInfoPtr,
+    ) -> Result<ProcessorPtr> {
+        let part = BenchmarkPartInfo::from_part(part)?;
+        // Keep the blocks of the tables generating several rows per unit around the block size.
+        let step = (ctx.get_settings().get_max_block_size()? / part.table.rows_per_unit()).max(1);
+
+        SyncSourcer::create(ctx, output, BenchmarkGenSource {
+            table: part.table,
+            scale_factor: part.scale_factor,
+            begin: part.part_start,
+            end: part.part_end,
+            step,
+        })
+    }
+}
+
+impl SyncSource for BenchmarkGenSource {
+    const NAME: &'static str = "BenchmarkGenSource";
+
+    fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.begin >= self.end {
+            return Ok(None);
+        }
+
+        let end = std::cmp::min(self.end, self.begin + self.step);
+        let block = self.table.generate(self.scale_factor, self.begin, end);
+        self.begin = end;
+        Ok(Some(block))
+    }
+}
+
+impl TableFunction for BenchmarkGenTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
@@ -0,0 +1,26 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod benchmark_part;
+mod benchmark_table;
+mod row_generator;
+mod tpcds_generator;
+mod tpch_generator;
+
+pub use benchmark_part::generate_benchmark_parts;
+pub use benchmark_part::BenchmarkPartInfo;
+pub use benchmark_table::BenchmarkGenTable;
+pub use benchmark_table::BenchmarkTable;
+pub use tpcds_generator::TpcdsTable;
+pub use tpch_generator::TpchTable;
@@ -0,0 +1,115 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The building blocks shared by the generators of the benchmark tables.
+
+use common_expression::types::decimal::DecimalScalar;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::DataType;
+use common_expression::types::DecimalSize;
+use common_expression::ColumnBuilder;
+use common_expression::DataBlock;
+use common_expression::ScalarRef;
+use common_expression::TableSchemaRef;
+
+pub fn scaled(rows: u64, scale_factor: f64) -> u64 {
+    ((rows as f64 * scale_factor) as u64).max(1)
+}
+
+pub struct RowWriter {
+    builders: Vec<ColumnBuilder>,
+    num_rows: usize,
+}
+
+impl RowWriter {
+    pub fn new(schema: &TableSchemaRef, capacity: usize) -> Self {
+        let builders = schema
+            .fields()
+            .iter()
+            .map(|field| ColumnBuilder::with_capacity(&DataType::from(field.data_type()), capacity))
+            .collect();
+
+        RowWriter {
+            builders,
+            num_rows: 0,
+        }
+    }
+
+    pub fn push(&mut self, row: &[ScalarRef]) {
+        debug_assert_eq!(row.len(), self.builders.len());
+        for (builder, value) in self.builders.iter_mut().zip(row.iter()) {
+            builder.push(value.clone());
+        }
+        self.num_rows += 1;
+    }
+
+    pub fn finish(self) -> DataBlock {
+        let columns = self
+            .builders
+            .into_iter()
+            .map(|builder| builder.build())
+            .collect();
+        DataBlock::new_from_columns(columns)
+    }
+}
+
+pub fn int32<'a>(value: i32) -> ScalarRef<'a> {
+    ScalarRef::Number(NumberScalar::Int32(value))
+}
+
+pub fn int64<'a>(value: i64) -> ScalarRef<'a> {
+    ScalarRef::Number(NumberScalar::Int64(value))
+}
+
+pub fn string(value: &str) -> ScalarRef {
+    ScalarRef::String(value.as_bytes())
+}
+
+/// `value` is in units of the scale of the decimal.
+pub fn decimal<'a>(value: i64, size: DecimalSize) -> ScalarRef<'a> {
+    ScalarRef::Decimal(DecimalScalar::Decimal128(value as i128, size))
+}
+
+/// A splitmix64 generator, cheap enough to be seeded for every row.
+pub struct RowRng {
+    state: u64,
+}
+
+impl RowRng {
+    /// `seed` tells the tables apart, so that they don't share random sequences.
+    pub fn new(seed: u64, row: u64) -> Self {
+        let mut rng = RowRng {
+            state: (seed + 1).wrapping_mul(0x9E37_79B9_7F4A_7C15) ^ row,
+        };
+        rng.next_u64();
+        rng
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A uniformly distributed number in `[low, high]`.
+    pub fn range(&mut self, low: i64, high: i64) -> i64 {
+        low + (self.next_u64() % (high - low + 1) as u64) as i64
+    }
+
+    pub fn pick<'a>(&mut self, values: &[&'a str]) -> &'a str {
+        values[self.range(0, values.len() as i64 - 1) as usize]
+    }
+}
@@ -0,0 +1,2561 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Generates the tables of the TPC-DS benchmark following the schema and the value
+//! domains of the specification (clause 2).
+//!
+//! As for TPC-H, every row is generated from a random generator seeded by the table and the
+//! row number, and the returns are generated from the random sequences of the sales they
+//! return, so that any range of rows can be generated independently. The cardinalities are
+//! the ones of `dsdgen` at scale factor 1: the sales, the returns and the inventory grow
+//! linearly with the scale factor, the dimensions growing with it grow with its square root,
+//! and the other dimensions are fixed. The data is not byte-identical to the output of
+//! `dsdgen`, and the values are only NULL where they don't apply, such as the end dates of the
+//! current revisions.
+
+use chrono::Datelike;
+use chrono::NaiveDate;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DecimalDataType;
+use common_expression::types::DecimalSize;
+use common_expression::types::NumberDataType;
+use common_expression::DataBlock;
+use common_expression::ScalarRef;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRef;
+use common_expression::TableSchemaRefExt;
+
+use super::row_generator::decimal;
+use super::row_generator::int32;
+use super::row_generator::int64;
+use super::row_generator::scaled;
+use super::row_generator::string;
+use super::row_generator::RowRng;
+use super::row_generator::RowWriter;
+use super::tpch_generator::COLORS;
+
+/// The date surrogate keys are julian day numbers.
+const JULIAN_DAY_OF_CE: i32 = 1721425;
+const UNIX_EPOCH_DAY_OF_CE: i32 = 719163;
+const UNIX_EPOCH_SK: i32 = JULIAN_DAY_OF_CE + UNIX_EPOCH_DAY_OF_CE;
+
+// 1900-01-02
+const DATE_DIM_START_SK: i32 = 2415022;
+// 1997-03-13
+const REC_START_DATE: i32 = 9933;
+// 1997-10-27
+const ITEM_REC_START_DATE: i32 = 10161;
+// 2000-10-27, the start of the second revision of the items.
+const ITEM_REVISION_DATE: i32 = 11257;
+// 1998-01-01
+const INVENTORY_START_SK: i32 = 2450815;
+const INVENTORY_WEEKS: u64 = 261;
+// 1998-01-02
+const SALES_START_SK: i32 = 2450816;
+// 2003-01-02
+const SALES_END_SK: i32 = 2452642;
+
+const MONEY_SIZE: DecimalSize = DecimalSize {
+    precision: 7,
+    scale: 2,
+};
+
+const RATE_SIZE: DecimalSize = DecimalSize {
+    precision: 5,
+    scale: 2,
+};
+
+const COST_SIZE: DecimalSize = DecimalSize {
+    precision: 15,
+    scale: 2,
+};
+
+/// The states and their offsets to GMT, in hundredths of hours.
+const STATES: [(&str, i64); 51] = [
+    ("AK", -900),
+    ("AL", -600),
+    ("AR", -600),
+    ("AZ", -700),
+    ("CA", -800),
+    ("CO", -700),
+    ("CT", -500),
+    ("DC", -500),
+    ("DE", -500),
+    ("FL", -500),
+    ("GA", -500),
+    ("HI", -1000),
+    ("IA", -600),
+    ("ID", -700),
+    ("IL", -600),
+    ("IN", -500),
+    ("KS", -600),
+    ("KY", -500),
+    ("LA", -600),
+    ("MA", -500),
+    ("MD", -500),
+    ("ME", -500),
+    ("MI", -500),
+    ("MN", -600),
+    ("MO", -600),
+    ("MS", -600),
+    ("MT", -700),
+    ("NC", -500),
+    ("ND", -600),
+    ("NE", -600),
+    ("NH", -500),
+    ("NJ", -500),
+    ("NM", -700),
+    ("NV", -800),
+    ("NY", -500),
+    ("OH", -500),
+    ("OK", -600),
+    ("OR", -800),
+    ("PA", -500),
+    ("RI", -500),
+    ("SC", -500),
+    ("SD", -600),
+    ("TN", -600),
+    ("TX", -600),
+    ("UT", -700),
+    ("VA", -500),
+    ("VT", -500),
+    ("WA", -800),
+    ("WI", -600),
+    ("WV", -500),
+    ("WY", -700),
+];
+
+const CITIES: [&str; 30] = [
+    "Midway",
+    "Fairview",
+    "Oak Grove",
+    "Five Points",
+    "Pleasant Hill",
+    "Centerville",
+    "Riverside",
+    "Oakland",
+    "Union",
+    "Greenwood",
+    "Salem",
+    "Georgetown",
+    "Mount Zion",
+    "Liberty",
+    "Shiloh",
+    "Marion",
+    "Franklin",
+    "Clinton",
+    "Springfield",
+    "Bethel",
+    "Greenville",
+    "Pleasant Valley",
+    "Glendale",
+    "Lakeview",
+    "Spring Hill",
+    "Jamestown",
+    "Hopewell",
+    "Newport",
+    "Ashland",
+    "Antioch",
+];
+
+const COUNTIES: [&str; 20] = [
+    "Williamson County",
+    "Walker County",
+    "Ziebach County",
+    "Franklin Parish",
+    "Barrow County",
+    "Daviess County",
+    "Luce County",
+    "Richland County",
+    "Fairfield County",
+    "Bronx County",
+    "Orange County",
+    "Jackson County",
+    "Lunenburg County",
+    "Mobile County",
+    "Dauphin County",
+    "Raleigh County",
+    "Huron County",
+    "Furnas County",
+    "Oglethorpe County",
+    "Maverick County",
+];
+
+const STREET_NAMES: [&str; 40] = [
+    "Main",
+    "Oak",
+    "Park",
+    "Elm",
+    "Maple",
+    "Pine",
+    "Cedar",
+    "Hill",
+    "Lake",
+    "Washington",
+    "Lincoln",
+    "Jackson",
+    "Church",
+    "Sunset",
+    "Ridge",
+    "Forest",
+    "River",
+    "Spring",
+    "Meadow",
+    "Willow",
+    "Highland",
+    "College",
+    "Center",
+    "Walnut",
+    "Chestnut",
+    "Mill",
+    "Jefferson",
+    "Johnson",
+    "First",
+    "Second",
+    "Third",
+    "Fourth",
+    "Fifth",
+    "Sixth",
+    "Green",
+    "Lee",
+    "Dogwood",
+    "Poplar",
+    "Birch",
+    "Laurel",
+];
+
+const STREET_TYPES: [&str; 20] = [
+    "Street",
+    "ST",
+    "Avenue",
+    "Ave",
+    "Boulevard",
+    "Blvd",
+    "Road",
+    "RD",
+    "Parkway",
+    "Pkwy",
+    "Way",
+    "Wy",
+    "Drive",
+    "Dr.",
+    "Circle",
+    "Cir.",
+    "Lane",
+    "Ln",
+    "Court",
+    "Ct.",
+];
+
+const LOCATION_TYPES: [&str; 3] = ["single family", "condo", "apartment"];
+
+const FIRST_NAMES: [&str; 40] = [
+    "James",
+    "Mary",
+    "John",
+    "Patricia",
+    "Robert",
+    "Jennifer",
+    "Michael",
+    "Linda",
+    "William",
+    "Elizabeth",
+    "David",
+    "Barbara",
+    "Richard",
+    "Susan",
+    "Joseph",
+    "Jessica",
+    "Thomas",
+    "Sarah",
+    "Charles",
+    "Karen",
+    "Christopher",
+    "Nancy",
+    "Daniel",
+    "Lisa",
+    "Matthew",
+    "Betty",
+    "Anthony",
+    "Margaret",
+    "Mark",
+    "Sandra",
+    "Donald",
+    "Ashley",
+    "Steven",
+    "Kimberly",
+    "Paul",
+    "Emily",
+    "Andrew",
+    "Donna",
+    "Joshua",
+    "Michelle",
+];
+
+const LAST_NAMES: [&str; 40] = [
+    "Smith",
+    "Johnson",
+    "Williams",
+    "Brown",
+    "Jones",
+    "Garcia",
+    "Miller",
+    "Davis",
+    "Rodriguez",
+    "Martinez",
+    "Hernandez",
+    "Lopez",
+    "Gonzalez",
+    "Wilson",
+    "Anderson",
+    "Thomas",
+    "Taylor",
+    "Moore",
+    "Jackson",
+    "Martin",
+    "Lee",
+    "Perez",
+    "Thompson",
+    "White",
+    "Harris",
+    "Sanchez",
+    "Clark",
+    "Ramirez",
+    "Lewis",
+    "Robinson",
+    "Walker",
+    "Young",
+    "Allen",
+    "King",
+    "Wright",
+    "Scott",
+    "Torres",
+    "Nguyen",
+    "Hill",
+    "Flores",
+];
+
+const SALUTATIONS: [&str; 6] = ["Mr.", "Mrs.", "Ms.", "Miss", "Sir", "Dr."];
+
+const COUNTRIES: [&str; 35] = [
+    "UNITED STATES",
+    "CANADA",
+    "MEXICO",
+    "BRAZIL",
+    "ARGENTINA",
+    "CHILE",
+    "PERU",
+    "UNITED KINGDOM",
+    "IRELAND",
+    "FRANCE",
+    "GERMANY",
+    "ITALY",
+    "SPAIN",
+    "PORTUGAL",
+    "NETHERLANDS",
+    "BELGIUM",
+    "SWEDEN",
+    "NORWAY",
+    "FINLAND",
+    "POLAND",
+    "RUSSIAN FEDERATION",
+    "TURKEY",
+    "EGYPT",
+    "NIGERIA",
+    "KENYA",
+    "SOUTH AFRICA",
+    "INDIA",
+    "CHINA",
+    "JAPAN",
+    "KOREA, REPUBLIC OF",
+    "VIET NAM",
+    "INDONESIA",
+    "PHILIPPINES",
+    "AUSTRALIA",
+    "NEW ZEALAND",
+];
+
+const TOP_DOMAINS: [&str; 3] = ["com", "org", "edu"];
+
+/// The syllables the digits of a number are spelled with, in the names of the brands,
+/// the manufacturers, the products, the stores and the promotions.
+const SYLLABLES: [&str; 10] = [
+    "ought", "able", "pri", "ese", "anti", "cally", "ation", "eing", "bar", "n st",
+];
+
+const CATEGORIES: [(&str, &[&str]); 10] = [
+    ("Women", &["dresses", "maternity", "swimwear", "fragrances"]),
+    ("Men", &["accessories", "pants", "shirts", "sports-apparel"]),
+    ("Children", &[
+        "infants",
+        "newborn",
+        "school-uniforms",
+        "toddlers",
+    ]),
+    ("Shoes", &["athletic", "kids", "mens", "womens"]),
+    ("Music", &["classical", "country", "pop", "rock"]),
+    ("Jewelry", &[
+        "bracelets",
+        "costume",
+        "diamonds",
+        "earings",
+        "estate",
+        "gold",
+        "jewelry boxes",
+        "loose stones",
+        "mens watch",
+        "pendants",
+        "rings",
+        "semi-precious",
+        "womens watch",
+        "birdal",
+        "consignment",
+        "custom",
+    ]),
+    ("Home", &[
+        "accent",
+        "bathroom",
+        "bedding",
+        "blinds/shades",
+        "curtains/drapes",
+        "decor",
+        "flatware",
+        "furniture",
+        "glassware",
+        "kids",
+        "lighting",
+        "mattresses",
+        "paint",
+        "rugs",
+        "tables",
+        "wallpaper",
+    ]),
+    ("Sports", &[
+        "archery",
+        "athletic shoes",
+        "baseball",
+        "basketball",
+        "camping",
+        "fishing",
+        "fitness",
+        "football",
+        "golf",
+        "guns",
+        "hockey",
+        "optics",
+        "outdoor",
+        "pools",
+        "sailing",
+        "tennis",
+    ]),
+    ("Books", &[
+        "arts",
+        "business",
+        "computers",
+        "cooking",
+        "entertainments",
+        "fiction",
+        "history",
+        "home repair",
+        "mystery",
+        "parenting",
+        "reference",
+        "romance",
+        "science",
+        "self-help",
+        "sports",
+        "travel",
+    ]),
+    ("Electronics", &[
+        "audio",
+        "automotive",
+        "cameras",
+        "camcorders",
+        "disk drives",
+        "dvd/vcr players",
+        "karoke",
+        "memory",
+        "monitors",
+        "musical",
+        "personal",
+        "portable",
+        "scanners",
+        "stereo",
+        "televisions",
+        "wireless",
+    ]),
+];
+
+const BRANDS: [&str; 10] = [
+    "amalg", "importo", "exporti", "edu pack", "scholar", "corp", "nameless", "univ", "brand",
+    "maxi",
+];
+
+const SIZES: [&str; 7] = [
+    "petite",
+    "small",
+    "medium",
+    "large",
+    "extra large",
+    "economy",
+    "N/A",
+];
+
+const UNITS: [&str; 20] = [
+    "Unknown", "Each", "Dozen", "Case", "Pallet", "Gross", "Carton", "Box", "Bunch", "Bundle",
+    "Oz", "Lb", "Ton", "Ounce", "Pound", "Tsp", "Tbl", "Cup", "Dram", "Gram",
+];
+
+const GENDERS: [&str; 2] = ["M", "F"];
+const MARITAL_STATUSES: [&str; 5] = ["M", "S", "D", "W", "U"];
+const EDUCATION_STATUSES: [&str; 7] = [
+    "Primary",
+    "Secondary",
+    "College",
+    "2 yr Degree",
+    "4 yr Degree",
+    "Advanced Degree",
+    "Unknown",
+];
+const CREDIT_RATINGS: [&str; 4] = ["Good", "Low Risk", "High Risk", "Unknown"];
+
+const BUY_POTENTIALS: [&str; 6] = [
+    ">10000",
+    "5001-10000",
+    "1001-5000",
+    "501-1000",
+    "0-500",
+    "Unknown",
+];
+
+const REASONS: [&str; 35] = [
+    "Package was damaged",
+    "Stopped working",
+    "Did not get it on time",
+    "Not the product that was ordred",
+    "Parts missing",
+    "Does not work with a product that I have",
+    "Gift exchange",
+    "Did not like the color",
+    "Did not like the model",
+    "Did not like the make",
+    "Did not like the warranty",
+    "No service location in my area",
+    "Found a better price in a store",
+    "Found a better extended warranty in a store",
+    "Not working any more",
+    "Did not fit",
+    "Wrong size",
+    "Lost my job",
+    "unauthoized purchase",
+    "duplicate purchase",
+    "its is a boy",
+    "it is a girl",
+    "Arrived too late",
+    "Arrived broken",
+    "Changed my mind",
+    "Bought the wrong one",
+    "Ordered twice",
+    "Too expensive",
+    "Found it cheaper online",
+    "Poor quality",
+    "Missing instructions",
+    "Missing accessories",
+    "Received a gift card instead",
+    "Replaced by a newer model",
+    "Other",
+];
+
+const SHIP_MODE_TYPES: [&str; 6] = [
+    "REGULAR",
+    "EXPRESS",
+    "NEXT DAY",
+    "OVERNIGHT",
+    "TWO DAY",
+    "LIBRARY",
+];
+
+const SHIP_MODE_CODES: [&str; 4] = ["AIR", "SURFACE", "SEA", "BIKE"];
+
+const CARRIERS: [&str; 20] = [
+    "UPS",
+    "FEDEX",
+    "AIRBORNE",
+    "USPS",
+    "DHL",
+    "TBS",
+    "ZHOU",
+    "ZOUROS",
+    "MSC",
+    "LATVIAN",
+    "ALLIANCE",
+    "ORIENTAL",
+    "BARIAN",
+    "BOXBUNDLES",
+    "GREAT EASTERN",
+    "DIAMOND",
+    "RUPEKSA",
+    "GERMA",
+    "HARMSTORF",
+    "PRIVATECARRIER",
+];
+
+const CALL_CENTER_NAMES: [&str; 10] = [
+    "NY Metro",
+    "Mid Atlantic",
+    "Pacific Northwest",
+    "North Midwest",
+    "California",
+    "Hawaii/Alaska",
+    "Central Midwest",
+    "South Midwest",
+    "Southwest",
+    "Southeast",
+];
+
+const CALL_CENTER_CLASSES: [&str; 3] = ["small", "medium", "large"];
+
+const HOURS: [&str; 3] = ["8AM-4PM", "8AM-12AM", "8AM-8AM"];
+
+const CATALOG_TYPES: [(&str, i32); 3] = [("bi-annual", 182), ("quarterly", 91), ("monthly", 30)];
+
+const CATALOG_PAGES_PER_CATALOG: u64 = 108;
+
+const WEB_PAGE_TYPES: [&str; 7] = [
+    "welcome",
+    "protected",
+    "dynamic",
+    "feedback",
+    "general",
+    "order",
+    "ad",
+];
+
+const DAY_NAMES: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+const ALPHANUMERIC_CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum TpcdsTable {
+    CallCenter,
+    CatalogPage,
+    CatalogReturns,
+    CatalogSales,
+    Customer,
+    CustomerAddress,
+    CustomerDemographics,
+    DateDim,
+    HouseholdDemographics,
+    IncomeBand,
+    Inventory,
+    Item,
+    Promotion,
+    Reason,
+    ShipMode,
+    Store,
+    StoreReturns,
+    StoreSales,
+    TimeDim,
+    Warehouse,
+    WebPage,
+    WebReturns,
+    WebSales,
+    WebSite,
+}
+
+const TPCDS_TABLES: [TpcdsTable; 24] = [
+    TpcdsTable::CallCenter,
+    TpcdsTable::CatalogPage,
+    TpcdsTable::CatalogReturns,
+    TpcdsTable::CatalogSales,
+    TpcdsTable::Customer,
+    TpcdsTable::CustomerAddress,
+    TpcdsTable::CustomerDemographics,
+    TpcdsTable::DateDim,
+    TpcdsTable::HouseholdDemographics,
+    TpcdsTable::IncomeBand,
+    TpcdsTable::Inventory,
+    TpcdsTable::Item,
+    TpcdsTable::Promotion,
+    TpcdsTable::Reason,
+    TpcdsTable::ShipMode,
+    TpcdsTable::Store,
+    TpcdsTable::StoreReturns,
+    TpcdsTable::StoreSales,
+    TpcdsTable::TimeDim,
+    TpcdsTable::Warehouse,
+    TpcdsTable::WebPage,
+    TpcdsTable::WebReturns,
+    TpcdsTable::WebSales,
+    TpcdsTable::WebSite,
+];
+
+impl TpcdsTable {
+    pub fn from_name(name: &str) -> Result<Self> {
+        let name = name.to_lowercase();
+        TPCDS_TABLES
+            .into_iter()
+            .find(|table| table.name() == name)
+            .ok_or_else(|| {
+                ErrorCode::BadArguments(format!(
+                    "Unknown TPC-DS table '{}', expect one of {}",
+                    name,
+                    TPCDS_TABLES.map(|table| table.name()).join(", ")
+                ))
+            })
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            TpcdsTable::CallCenter => "call_center",
+            TpcdsTable::CatalogPage => "catalog_page",
+            TpcdsTable::CatalogReturns => "catalog_returns",
+            TpcdsTable::CatalogSales => "catalog_sales",
+            TpcdsTable::Customer => "customer",
+            TpcdsTable::CustomerAddress => "customer_address",
+            TpcdsTable::CustomerDemographics => "customer_demographics",
+            TpcdsTable::DateDim => "date_dim",
+            TpcdsTable::HouseholdDemographics => "household_demographics",
+            TpcdsTable::IncomeBand => "income_band",
+            TpcdsTable::Inventory => "inventory",
+            TpcdsTable::Item => "item",
+            TpcdsTable::Promotion => "promotion",
+            TpcdsTable::Reason => "reason",
+            TpcdsTable::ShipMode => "ship_mode",
+            TpcdsTable::Store => "store",
+            TpcdsTable::StoreReturns => "store_returns",
+            TpcdsTable::StoreSales => "store_sales",
+            TpcdsTable::TimeDim => "time_dim",
+            TpcdsTable::Warehouse => "warehouse",
+            TpcdsTable::WebPage => "web_page",
+            TpcdsTable::WebReturns => "web_returns",
+            TpcdsTable::WebSales => "web_sales",
+            TpcdsTable::WebSite => "web_site",
+        }
+    }
+
+    /// The columns are nullable as in the specification, except the keys.
+    pub fn schema(&self) -> TableSchemaRef {
+        let nullable = |data_type| TableDataType::Nullable(Box::new(data_type));
+        let key = || TableDataType::Number(NumberDataType::Int32);
+        let number = || TableDataType::Number(NumberDataType::Int64);
+        let id = || TableDataType::String;
+        let int = || nullable(TableDataType::Number(NumberDataType::Int32));
+        let string = || nullable(TableDataType::String);
+        let date = || nullable(TableDataType::Date);
+        let decimal = |size| nullable(TableDataType::Decimal(DecimalDataType::Decimal128(size)));
+        let money = || decimal(MONEY_SIZE);
+        let rate = || decimal(RATE_SIZE);
+        let address = |prefix: &str| {
+            [
+                ("street_number", string()),
+                ("street_name", string()),
+                ("street_type", string()),
+                ("suite_number", string()),
+                ("city", string()),
+                ("county", string()),
+                ("state", string()),
+                ("zip", string()),
+                ("country", string()),
+                ("gmt_offset", rate()),
+            ]
+            .map(|(name, data_type)| (format!("{}_{}", prefix, name), data_type))
+        };
+
+        let fields: Vec<(String, TableDataType)> = match self {
+            TpcdsTable::CallCenter => named(vec![
+                ("cc_call_center_sk", key()),
+                ("cc_call_center_id", id()),
+                ("cc_rec_start_date", date()),
+                ("cc_rec_end_date", date()),
+                ("cc_closed_date_sk", int()),
+                ("cc_open_date_sk", int()),
+                ("cc_name", string()),
+                ("cc_class", string()),
+                ("cc_employees", int()),
+                ("cc_sq_ft", int()),
+                ("cc_hours", string()),
+                ("cc_manager", string()),
+                ("cc_mkt_id", int()),
+                ("cc_mkt_class", string()),
+                ("cc_mkt_desc", string()),
+                ("cc_market_manager", string()),
+                ("cc_division", int()),
+                ("cc_division_name", string()),
+                ("cc_company", int()),
+                ("cc_company_name", string()),
+            ])
+            .into_iter()
+            .chain(address("cc"))
+            .chain(named(vec![("cc_tax_percentage", rate())]))
+            .collect(),
+            TpcdsTable::CatalogPage => named(vec![
+                ("cp_catalog_page_sk", key()),
+                ("cp_catalog_page_id", id()),
+                ("cp_start_date_sk", int()),
+                ("cp_end_date_sk", int()),
+                ("cp_department", string()),
+                ("cp_catalog_number", int()),
+                ("cp_catalog_page_number", int()),
+                ("cp_description", string()),
+                ("cp_type", string()),
+            ]),
+            TpcdsTable::CatalogReturns => named(vec![
+                ("cr_returned_date_sk", int()),
+                ("cr_returned_time_sk", int()),
+                ("cr_item_sk", key()),
+                ("cr_refunded_customer_sk", int()),
+                ("cr_refunded_cdemo_sk", int()),
+                ("cr_refunded_hdemo_sk", int()),
+                ("cr_refunded_addr_sk", int()),
+                ("cr_returning_customer_sk", int()),
+                ("cr_returning_cdemo_sk", int()),
+                ("cr_returning_hdemo_sk", int()),
+                ("cr_returning_addr_sk", int()),
+                ("cr_call_center_sk", int()),
+                ("cr_catalog_page_sk", int()),
+                ("cr_ship_mode_sk", int()),
+                ("cr_warehouse_sk", int()),
+                ("cr_reason_sk", int()),
+                ("cr_order_number", number()),
+                ("cr_return_quantity", int()),
+                ("cr_return_amount", money()),
+                ("cr_return_tax", money()),
+                ("cr_return_amt_inc_tax", money()),
+                ("cr_fee", money()),
+                ("cr_return_ship_cost", money()),
+                ("cr_refunded_cash", money()),
+                ("cr_reversed_charge", money()),
+                ("cr_store_credit", money()),
+                ("cr_net_loss", money()),
+            ]),
+            TpcdsTable::CatalogSales => named(vec![
+                ("cs_sold_date_sk", int()),
+                ("cs_sold_time_sk", int()),
+                ("cs_ship_date_sk", int()),
+                ("cs_bill_customer_sk", int()),
+                ("cs_bill_cdemo_sk", int()),
+                ("cs_bill_hdemo_sk", int()),
+                ("cs_bill_addr_sk", int()),
+                ("cs_ship_customer_sk", int()),
+                ("cs_ship_cdemo_sk", int()),
+                ("cs_ship_hdemo_sk", int()),
+                ("cs_ship_addr_sk", int()),
+                ("cs_call_center_sk", int()),
+                ("cs_catalog_page_sk", int()),
+                ("cs_ship_mode_sk", int()),
+                ("cs_warehouse_sk", int()),
+                ("cs_item_sk", key()),
+                ("cs_promo_sk", int()),
+                ("cs_order_number", number()),
+                ("cs_quantity", int()),
+                ("cs_wholesale_cost", money()),
+                ("cs_list_price", money()),
+                ("cs_sales_price", money()),
+                ("cs_ext_discount_amt", money()),
+                ("cs_ext_sales_price", money()),
+                ("cs_ext_wholesale_cost", money()),
+                ("cs_ext_list_price", money()),
+                ("cs_ext_tax", money()),
+                ("cs_coupon_amt", money()),
+                ("cs_ext_ship_cost", money()),
+                ("cs_net_paid", money()),
+                ("cs_net_paid_inc_tax", money()),
+                ("cs_net_paid_inc_ship", money()),
+                ("cs_net_paid_inc_ship_tax", money()),
+                ("cs_net_profit", money()),
+            ]),
+            TpcdsTable::Customer => named(vec![
+                ("c_customer_sk", key()),
+                ("c_customer_id", id()),
+                ("c_current_cdemo_sk", int()),
+                ("c_current_hdemo_sk", int()),
+                ("c_current_addr_sk", int()),
+                ("c_first_shipto_date_sk", int()),
+                ("c_first_sales_date_sk", int()),
+                ("c_salutation", string()),
+                ("c_first_name", string()),
+                ("c_last_name", string()),
+                ("c_preferred_cust_flag", string()),
+                ("c_birth_day", int()),
+                ("c_birth_month", int()),
+                ("c_birth_year", int()),
+                ("c_birth_country", string()),
+                ("c_login", string()),
+                ("c_email_address", string()),
+                ("c_last_review_date_sk", int()),
+            ]),
+            TpcdsTable::CustomerAddress => {
+                named(vec![("ca_address_sk", key()), ("ca_address_id", id())])
+                    .into_iter()
+                    .chain(address("ca"))
+                    .chain(named(vec![("ca_location_type", string())]))
+                    .collect()
+            }
+            TpcdsTable::CustomerDemographics => named(vec![
+                ("cd_demo_sk", key()),
+                ("cd_gender", string()),
+                ("cd_marital_status", string()),
+                ("cd_education_status", string()),
+                ("cd_purchase_estimate", int()),
+                ("cd_credit_rating", string()),
+                ("cd_dep_count", int()),
+                ("cd_dep_employed_count", int()),
+                ("cd_dep_college_count", int()),
+            ]),
+            TpcdsTable::DateDim => named(vec![
+                ("d_date_sk", key()),
+                ("d_date_id", id()),
+                ("d_date", date()),
+                ("d_month_seq", int()),
+                ("d_week_seq", int()),
+                ("d_quarter_seq", int()),
+                ("d_year", int()),
+                ("d_dow", int()),
+                ("d_moy", int()),
+                ("d_dom", int()),
+                ("d_qoy", int()),
+                ("d_fy_year", int()),
+                ("d_fy_quarter_seq", int()),
+                ("d_fy_week_seq", int()),
+                ("d_day_name", string()),
+                ("d_quarter_name", string()),
+                ("d_holiday", string()),
+                ("d_weekend", string()),
+                ("d_following_holiday", string()),
+                ("d_first_dom", int()),
+                ("d_last_dom", int()),
+                ("d_same_day_ly", int()),
+                ("d_same_day_lq", int()),
+                ("d_current_day", string()),
+                ("d_current_week", string()),
+                ("d_current_month", string()),
+                ("d_current_quarter", string()),
+                ("d_current_year", string()),
+            ]),
+            TpcdsTable::HouseholdDemographics => named(vec![
+                ("hd_demo_sk", key()),
+                ("hd_income_band_sk", int()),
+                ("hd_buy_potential", string()),
+                ("hd_dep_count", int()),
+                ("hd_vehicle_count", int()),
+            ]),
+            TpcdsTable::IncomeBand => named(vec![
+                ("ib_income_band_sk", key()),
+                ("ib_lower_bound", int()),
+                ("ib_upper_bound", int()),
+            ]),
+            TpcdsTable::Inventory => named(vec![
+                ("inv_date_sk", key()),
+                ("inv_item_sk", key()),
+                ("inv_warehouse_sk", key()),
+                ("inv_quantity_on_hand", int()),
+            ]),
+            TpcdsTable::Item => named(vec![
+                ("i_item_sk", key()),
+                ("i_item_id", id()),
+                ("i_rec_start_date", date()),
+                ("i_rec_end_date", date()),
+                ("i_item_desc", string()),
+                ("i_current_price", money()),
+                ("i_wholesale_cost", money()),
+                ("i_brand_id", int()),
+                ("i_brand", string()),
+                ("i_class_id", int()),
+                ("i_class", string()),
+                ("i_category_id", int()),
+                ("i_category", string()),
+                ("i_manufact_id", int()),
+                ("i_manufact", string()),
+                ("i_size", string()),
+                ("i_formulation", string()),
+                ("i_color", string()),
+                ("i_units", string()),
+                ("i_container", string()),
+                ("i_manager_id", int()),
+                ("i_product_name", string()),
+            ]),
+            TpcdsTable::Promotion => named(vec![
+                ("p_promo_sk", key()),
+                ("p_promo_id", id()),
+                ("p_start_date_sk", int()),
+                ("p_end_date_sk", int()),
+                ("p_item_sk", int()),
+                ("p_cost", decimal(COST_SIZE)),
+                ("p_response_target", int()),
+                ("p_promo_name", string()),
+                ("p_channel_dmail", string()),
+                ("p_channel_email", string()),
+                ("p_channel_catalog", string()),
+                ("p_channel_tv", string()),
+                ("p_channel_radio", string()),
+                ("p_channel_press", string()),
+                ("p_channel_event", string()),
+                ("p_channel_demo", string()),
+                ("p_channel_details", string()),
+                ("p_purpose", string()),
+                ("p_discount_active", string()),
+            ]),
+            TpcdsTable::Reason => named(vec![
+                ("r_reason_sk", key()),
+                ("r_reason_id", id()),
+                ("r_reason_desc", string()),
+            ]),
+            TpcdsTable::ShipMode => named(vec![
+                ("sm_ship_mode_sk", key()),
+                ("sm_ship_mode_id", id()),
+                ("sm_type", string()),
+                ("sm_code", string()),
+                ("sm_carrier", string()),
+                ("sm_contract", string()),
+            ]),
+            TpcdsTable::Store => named(vec![
+                ("s_store_sk", key()),
+                ("s_store_id", id()),
+                ("s_rec_start_date", date()),
+                ("s_rec_end_date", date()),
+                ("s_closed_date_sk", int()),
+                ("s_store_name", string()),
+                ("s_number_employees", int()),
+                ("s_floor_space", int()),
+                ("s_hours", string()),
+                ("s_manager", string()),
+                ("s_market_id", int()),
+                ("s_geography_class", string()),
+                ("s_market_desc", string()),
+                ("s_market_manager", string()),
+                ("s_division_id", int()),
+                ("s_division_name", string()),
+                ("s_company_id", int()),
+                ("s_company_name", string()),
+            ])
+            .into_iter()
+            .chain(address("s"))
+            .chain(named(vec![("s_tax_precentage", rate())]))
+            .collect(),
+            TpcdsTable::StoreReturns => named(vec![
+                ("sr_returned_date_sk", int()),
+                ("sr_return_time_sk", int()),
+                ("sr_item_sk", key()),
+                ("sr_customer_sk", int()),
+                ("sr_cdemo_sk", int()),
+                ("sr_hdemo_sk", int()),
+                ("sr_addr_sk", int()),
+                ("sr_store_sk", int()),
+                ("sr_reason_sk", int()),
+                ("sr_ticket_number", number()),
+                ("sr_return_quantity", int()),
+                ("sr_return_amt", money()),
+                ("sr_return_tax", money()),
+                ("sr_return_amt_inc_tax", money()),
+                ("sr_fee", money()),
+                ("sr_return_ship_cost", money()),
+                ("sr_refunded_cash", money()),
+                ("sr_reversed_charge", money()),
+                ("sr_store_credit", money()),
+                ("sr_net_loss", money()),
+            ]),
+            TpcdsTable::StoreSales => named(vec![
+                ("ss_sold_date_sk", int()),
+                ("ss_sold_time_sk", int()),
+                ("ss_item_sk", key()),
+                ("ss_customer_sk", int()),
+                ("ss_cdemo_sk", int()),
+                ("ss_hdemo_sk", int()),
+                ("ss_addr_sk", int()),
+                ("ss_store_sk", int()),
+                ("ss_promo_sk", int()),
+                ("ss_ticket_number", number()),
+                ("ss_quantity", int()),
+                ("ss_wholesale_cost", money()),
+                ("ss_list_price", money()),
+                ("ss_sales_price", money()),
+                ("ss_ext_discount_amt", money()),
+                ("ss_ext_sales_price", money()),
+                ("ss_ext_wholesale_cost", money()),
+                ("ss_ext_list_price", money()),
+                ("ss_ext_tax", money()),
+                ("ss_coupon_amt", money()),
+                ("ss_net_paid", money()),
+                ("ss_net_paid_inc_tax", money()),
+                ("ss_net_profit", money()),
+            ]),
+            TpcdsTable::TimeDim => named(vec![
+                ("t_time_sk", key()),
+                ("t_time_id", id()),
+                ("t_time", int()),
+                ("t_hour", int()),
+                ("t_minute", int()),
+                ("t_second", int()),
+                ("t_am_pm", string()),
+                ("t_shift", string()),
+                ("t_sub_shift", string()),
+                ("t_meal_time", string()),
+            ]),
+            TpcdsTable::Warehouse => named(vec![
+                ("w_warehouse_sk", key()),
+                ("w_warehouse_id", id()),
+                ("w_warehouse_name", string()),
+                ("w_warehouse_sq_ft", int()),
+            ])
+            .into_iter()
+            .chain(address("w"))
+            .collect(),
+            TpcdsTable::WebPage => named(vec![
+                ("wp_web_page_sk", key()),
+                ("wp_web_page_id", id()),
+                ("wp_rec_start_date", date()),
+                ("wp_rec_end_date", date()),
+                ("wp_creation_date_sk", int()),
+                ("wp_access_date_sk", int()),
+                ("wp_autogen_flag", string()),
+                ("wp_customer_sk", int()),
+                ("wp_url", string()),
+                ("wp_type", string()),
+                ("wp_char_count", int()),
+                ("wp_link_count", int()),
+                ("wp_image_count", int()),
+                ("wp_max_ad_count", int()),
+            ]),
+            TpcdsTable::WebReturns => named(vec![
+                ("wr_returned_date_sk", int()),
+                ("wr_returned_time_sk", int()),
+                ("wr_item_sk", key()),
+                ("wr_refunded_customer_sk", int()),
+                ("wr_refunded_cdemo_sk", int()),
+                ("wr_refunded_hdemo_sk", int()),
+                ("wr_refunded_addr_sk", int()),
+                ("wr_returning_customer_sk", int()),
+                ("wr_returning_cdemo_sk", int()),
+                ("wr_returning_hdemo_sk", int()),
+                ("wr_returning_addr_sk", int()),
+                ("wr_web_page_sk", int()),
+                ("wr_reason_sk", int()),
+                ("wr_order_number", number()),
+                ("wr_return_quantity", int()),
+                ("wr_return_amt", money()),
+                ("wr_return_tax", money()),
+                ("wr_return_amt_inc_tax", money()),
+                ("wr_fee", money()),
+                ("wr_return_ship_cost", money()),
+                ("wr_refunded_cash", money()),
+                ("wr_reversed_charge", money()),
+                ("wr_account_credit", money()),
+                ("wr_net_loss", money()),
+            ]),
+            TpcdsTable::WebSales => named(vec![
+                ("ws_sold_date_sk", int()),
+                ("ws_sold_time_sk", int()),
+                ("ws_ship_date_sk", int()),
+                ("ws_item_sk", key()),
+                ("ws_bill_customer_sk", int()),
+                ("ws_bill_cdemo_sk", int()),
+                ("ws_bill_hdemo_sk", int()),
+                ("ws_bill_addr_sk", int()),
+                ("ws_ship_customer_sk", int()),
+                ("ws_ship_cdemo_sk", int()),
+                ("ws_ship_hdemo_sk", int()),
+                ("ws_ship_addr_sk", int()),
+                ("ws_web_page_sk", int()),
+                ("ws_web_site_sk", int()),
+                ("ws_ship_mode_sk", int()),
+                ("ws_warehouse_sk", int()),
+                ("ws_promo_sk", int()),
+                ("ws_order_number", number()),
+                ("ws_quantity", int()),
+                ("ws_wholesale_cost", money()),
+                ("ws_list_price", money()),
+                ("ws_sales_price", money()),
+                ("ws_ext_discount_amt", money()),
+                ("ws_ext_sales_price", money()),
+                ("ws_ext_wholesale_cost", money()),
+                ("ws_ext_list_price", money()),
+                ("ws_ext_tax", money()),
+                ("ws_coupon_amt", money()),
+                ("ws_ext_ship_cost", money()),
+                ("ws_net_paid", money()),
+                ("ws_net_paid_inc_tax", money()),
+                ("ws_net_paid_inc_ship", money()),
+                ("ws_net_paid_inc_ship_tax", money()),
+                ("ws_net_profit", money()),
+            ]),
+            TpcdsTable::WebSite => named(vec![
+                ("web_site_sk", key()),
+                ("web_site_id", id()),
+                ("web_rec_start_date", date()),
+                ("web_rec_end_date", date()),
+                ("web_name", string()),
+                ("web_open_date_sk", int()),
+                ("web_close_date_sk", int()),
+                ("web_class", string()),
+                ("web_manager", string()),
+                ("web_mkt_id", int()),
+                ("web_mkt_class", string()),
+                ("web_mkt_desc", string()),
+                ("web_market_manager", string()),
+                ("web_company_id", int()),
+                ("web_company_name", string()),
+            ])
+            .into_iter()
+            .chain(address("web"))
+            .chain(named(vec![("web_tax_percentage", rate())]))
+            .collect(),
+        };
+
+        TableSchemaRefExt::create(
+            fields
+                .into_iter()
+                .map(|(name, data_type)| TableField::new(&name, data_type))
+                .collect(),
+        )
+    }
+
+    /// The number of units the generation of the table is split by.
+    ///
+    /// A unit is a row, except for the sales which generate the lines of a ticket or an
+    /// order per unit, and for the returns which generate the returned lines of them.
+    pub fn units(&self, scale_factor: f64) -> u64 {
+        let dimension_scale = scale_factor.sqrt();
+        match self {
+            TpcdsTable::CallCenter => scaled(6, dimension_scale),
+            TpcdsTable::CatalogPage => 11_718,
+            TpcdsTable::CatalogReturns | TpcdsTable::CatalogSales => scaled(160_000, scale_factor),
+            TpcdsTable::Customer => scaled(100_000, dimension_scale),
+            TpcdsTable::CustomerAddress => scaled(50_000, dimension_scale),
+            TpcdsTable::CustomerDemographics => 1_920_800,
+            TpcdsTable::DateDim => 73_049,
+            TpcdsTable::HouseholdDemographics => 7_200,
+            TpcdsTable::IncomeBand => 20,
+            TpcdsTable::Inventory => {
+                INVENTORY_WEEKS
+                    * (TpcdsTable::Item.units(scale_factor) / 2)
+                    * TpcdsTable::Warehouse.units(scale_factor)
+            }
+            // Every item has two revisions.
+            TpcdsTable::Item => 2 * scaled(9_000, dimension_scale),
+            TpcdsTable::Promotion => scaled(300, dimension_scale),
+            TpcdsTable::Reason => REASONS.len() as u64,
+            TpcdsTable::ShipMode => 20,
+            TpcdsTable::Store => scaled(12, dimension_scale),
+            TpcdsTable::StoreReturns | TpcdsTable::StoreSales => scaled(240_000, scale_factor),
+            TpcdsTable::TimeDim => 86_400,
+            TpcdsTable::Warehouse => scaled(5, dimension_scale),
+            TpcdsTable::WebPage => scaled(60, dimension_scale),
+            TpcdsTable::WebReturns | TpcdsTable::WebSales => scaled(60_000, scale_factor),
+            TpcdsTable::WebSite => scaled(30, dimension_scale),
+        }
+    }
+
+    /// The number of rows generated by a unit, on average.
+    pub fn rows_per_unit(&self) -> u64 {
+        match self {
+            TpcdsTable::StoreSales | TpcdsTable::WebSales => 12,
+            TpcdsTable::CatalogSales => 9,
+            _ => 1,
+        }
+    }
+
+    /// Whether every unit generates at least one row, which is not the case of the returns.
+    pub fn generates_rows_per_unit(&self) -> bool {
+        !matches!(
+            self,
+            TpcdsTable::StoreReturns | TpcdsTable::CatalogReturns | TpcdsTable::WebReturns
+        )
+    }
+
+    /// Generates the rows of the units in `[start, end)`.
+    pub fn generate(&self, scale_factor: f64, start: u64, end: u64) -> DataBlock {
+        let generator = TpcdsGenerator::new(*self, scale_factor);
+        let mut writer = RowWriter::new(
+            &self.schema(),
+            ((end - start) * self.rows_per_unit()) as usize,
+        );
+
+        for unit in start..end {
+            match self {
+                TpcdsTable::CallCenter => generator.call_center(unit, &mut writer),
+                TpcdsTable::CatalogPage => generator.catalog_page(unit, &mut writer),
+                TpcdsTable::CatalogReturns => generator.catalog_returns(unit, &mut writer),
+                TpcdsTable::CatalogSales => generator.catalog_sales(unit, &mut writer),
+                TpcdsTable::Customer => generator.customer(unit, &mut writer),
+                TpcdsTable::CustomerAddress => generator.customer_address(unit, &mut writer),
+                TpcdsTable::CustomerDemographics => {
+                    generator.customer_demographics(unit, &mut writer)
+                }
+                TpcdsTable::DateDim => generator.date_dim(unit, &mut writer),
+                TpcdsTable::HouseholdDemographics => {
+                    generator.household_demographics(unit, &mut writer)
+                }
+                TpcdsTable::IncomeBand => generator.income_band(unit, &mut writer),
+                TpcdsTable::Inventory => generator.inventory(unit, &mut writer),
+                TpcdsTable::Item => generator.item(unit, &mut writer),
+                TpcdsTable::Promotion => generator.promotion(unit, &mut writer),
+                TpcdsTable::Reason => generator.reason(unit, &mut writer),
+                TpcdsTable::ShipMode => generator.ship_mode(unit, &mut writer),
+                TpcdsTable::Store => generator.store(unit, &mut writer),
+                TpcdsTable::StoreReturns => generator.store_returns(unit, &mut writer),
+                TpcdsTable::StoreSales => generator.store_sales(unit, &mut writer),
+                TpcdsTable::TimeDim => generator.time_dim(unit, &mut writer),
+                TpcdsTable::Warehouse => generator.warehouse(unit, &mut writer),
+                TpcdsTable::WebPage => generator.web_page(unit, &mut writer),
+                TpcdsTable::WebReturns => generator.web_returns(unit, &mut writer),
+                TpcdsTable::WebSales => generator.web_sales(unit, &mut writer),
+                TpcdsTable::WebSite => generator.web_site(unit, &mut writer),
+            }
+        }
+
+        writer.finish()
+    }
+}
+
+fn named(fields: Vec<(&str, TableDataType)>) -> Vec<(String, TableDataType)> {
+    fields
+        .into_iter()
+        .map(|(name, data_type)| (name.to_string(), data_type))
+        .collect()
+}
+
+/// `value` is in hundredths.
+fn money<'a>(value: i64) -> ScalarRef<'a> {
+    decimal(value, MONEY_SIZE)
+}
+
+/// `value` is in hundredths.
+fn rate<'a>(value: i64) -> ScalarRef<'a> {
+    decimal(value, RATE_SIZE)
+}
+
+fn flag<'a>(value: bool) -> ScalarRef<'a> {
+    match value {
+        true => ScalarRef::String(b"Y"),
+        false => ScalarRef::String(b"N"),
+    }
+}
+
+fn date_value(date: NaiveDate) -> i32 {
+    date.num_days_from_ce() - UNIX_EPOCH_DAY_OF_CE
+}
+
+fn date_sk(date: NaiveDate) -> i32 {
+    date.num_days_from_ce() + JULIAN_DAY_OF_CE
+}
+
+/// The date of the day, or of the last day of the month if the month is shorter.
+fn clamped_date(year: i32, month: u32, day: u32) -> NaiveDate {
+    (1..=day)
+        .rev()
+        .find_map(|day| NaiveDate::from_ymd_opt(year, month, day))
+        .unwrap()
+}
+
+/// The business key of the `n`-th row, 16 characters such as `AAAAAAAABAAAAAAA`.
+fn business_key(n: u64) -> String {
+    let mut key = String::with_capacity(16);
+    key.push_str("AAAAAAAA");
+    for shift in 0..8 {
+        key.push((b'A' + ((n >> (4 * shift)) & 0xF) as u8) as char);
+    }
+    key
+}
+
+/// Spells the digits of `n` with the syllables.
+fn syllables(n: i64) -> String {
+    n.to_string()
+        .bytes()
+        .map(|digit| SYLLABLES[(digit - b'0') as usize])
+        .collect()
+}
+
+fn alphanumeric(rng: &mut RowRng, len: i64) -> String {
+    (0..len)
+        .map(|_| {
+            ALPHANUMERIC_CHARS[rng.range(0, ALPHANUMERIC_CHARS.len() as i64 - 1) as usize] as char
+        })
+        .collect()
+}
+
+fn full_name(rng: &mut RowRng) -> String {
+    format!("{} {}", rng.pick(&FIRST_NAMES), rng.pick(&LAST_NAMES))
+}
+
+struct Address {
+    street_number: String,
+    street_name: String,
+    street_type: &'static str,
+    suite_number: String,
+    city: &'static str,
+    county: &'static str,
+    state: &'static str,
+    zip: String,
+    gmt_offset: i64,
+}
+
+impl Address {
+    fn generate(rng: &mut RowRng) -> Self {
+        let street_number = rng.range(1, 1000).to_string();
+        let street_name = match rng.range(0, 1) {
+            0 => rng.pick(&STREET_NAMES).to_string(),
+            _ => format!("{} {}", rng.pick(&STREET_NAMES), rng.pick(&STREET_NAMES)),
+        };
+        let street_type = rng.pick(&STREET_TYPES);
+        let suite_number = match rng.range(0, 1) {
+            0 => format!("Suite {}", rng.range(0, 49) * 10),
+            _ => format!("Suite {}", (b'A' + rng.range(0, 25) as u8) as char),
+        };
+        let city = rng.pick(&CITIES);
+        let county = rng.pick(&COUNTIES);
+        let (state, gmt_offset) = STATES[rng.range(0, STATES.len() as i64 - 1) as usize];
+        let zip = format!("{:05}", rng.range(600, 99999));
+
+        Address {
+            street_number,
+            street_name,
+            street_type,
+            suite_number,
+            city,
+            county,
+            state,
+            zip,
+            gmt_offset,
+        }
+    }
+
+    fn row(&self) -> [ScalarRef; 10] {
+        [
+            string(&self.street_number),
+            string(&self.street_name),
+            string(self.street_type),
+            string(&self.suite_number),
+            string(self.city),
+            string(self.county),
+            string(self.state),
+            string(&self.zip),
+            string("United States"),
+            rate(self.gmt_offset),
+        ]
+    }
+}
+
+/// The customer a sale is billed or shipped to, with the demographics and the address
+/// of the purchase.
+#[derive(Clone, Copy)]
+struct Buyer {
+    customer_sk: i32,
+    cdemo_sk: i32,
+    hdemo_sk: i32,
+    addr_sk: i32,
+}
+
+/// The prices of a sale line, in hundredths, as described in clause 2.3.2 of the specification.
+struct Pricing {
+    quantity: i64,
+    wholesale_cost: i64,
+    list_price: i64,
+    sales_price: i64,
+    ext_discount_amt: i64,
+    ext_sales_price: i64,
+    ext_wholesale_cost: i64,
+    ext_list_price: i64,
+    tax_percentage: i64,
+    ext_tax: i64,
+    coupon_amt: i64,
+    ext_ship_cost: i64,
+    net_paid: i64,
+    net_paid_inc_tax: i64,
+    net_paid_inc_ship: i64,
+    net_paid_inc_ship_tax: i64,
+    net_profit: i64,
+}
+
+impl Pricing {
+    fn generate(rng: &mut RowRng) -> Self {
+        let quantity = rng.range(1, 100);
+        let wholesale_cost = rng.range(100, 10000);
+        let list_price = wholesale_cost * (100 + rng.range(0, 200)) / 100;
+        let sales_price = list_price * (100 - rng.range(0, 100)) / 100;
+        let ext_sales_price = sales_price * quantity;
+        let ext_wholesale_cost = wholesale_cost * quantity;
+        let ext_list_price = list_price * quantity;
+        let tax_percentage = rng.range(0, 9);
+        let ext_tax = ext_sales_price * tax_percentage / 100;
+        // One sale out of five uses a coupon.
+        let coupon_amt = match rng.range(0, 4) {
+            0 => ext_sales_price * rng.range(0, 100) / 100,
+            _ => 0,
+        };
+        let ext_ship_cost = ext_list_price * rng.range(0, 50) / 100;
+        let net_paid = ext_sales_price - coupon_amt;
+
+        Pricing {
+            quantity,
+            wholesale_cost,
+            list_price,
+            sales_price,
+            ext_discount_amt: ext_list_price - ext_sales_price,
+            ext_sales_price,
+            ext_wholesale_cost,
+            ext_list_price,
+            tax_percentage,
+            ext_tax,
+            coupon_amt,
+            ext_ship_cost,
+            net_paid,
+            net_paid_inc_tax: net_paid + ext_tax,
+            net_paid_inc_ship: net_paid + ext_ship_cost,
+            net_paid_inc_ship_tax: net_paid + ext_ship_cost + ext_tax,
+            net_profit: net_paid - ext_wholesale_cost,
+        }
+    }
+}
+
+/// The return of a part of a sale line, the amounts are in hundredths.
+struct Return {
+    date_sk: i32,
+    time_sk: i32,
+    reason_sk: i32,
+    quantity: i64,
+    amount: i64,
+    tax: i64,
+    amount_inc_tax: i64,
+    fee: i64,
+    ship_cost: i64,
+    refunded_cash: i64,
+    reversed_charge: i64,
+    credit: i64,
+    net_loss: i64,
+}
+
+impl Return {
+    /// One line out of ten is returned, within 90 days after `date_sk`.
+    fn generate(rng: &mut RowRng, pricing: &Pricing, date_sk: i32) -> Option<Self> {
+        if rng.range(0, 9) != 0 {
+            return None;
+        }
+
+        let quantity = rng.range(1, pricing.quantity);
+        let amount = pricing.sales_price * quantity;
+        let tax = amount * pricing.tax_percentage / 100;
+        let fee = rng.range(50, 10000);
+        let ship_cost = pricing.list_price * quantity * rng.range(0, 50) / 100;
+        let refunded_cash = amount * rng.range(0, 100) / 100;
+        let reversed_charge = (amount - refunded_cash) * rng.range(0, 100) / 100;
+
+        Some(Return {
+            date_sk: date_sk + rng.range(1, 90) as i32,
+            time_sk: rng.range(0, 86399) as i32,
+            reason_sk: rng.range(1, REASONS.len() as i64) as i32,
+            quantity,
+            amount,
+            tax,
+            amount_inc_tax: amount + tax,
+            fee,
+            ship_cost,
+            refunded_cash,
+            reversed_charge,
+            credit: amount - refunded_cash - reversed_charge,
+            net_loss: fee + ship_cost + tax,
+        })
+    }
+}
+
+struct SaleLine {
+    item_sk: i32,
+    promo_sk: i32,
+    pricing: Pricing,
+    ret: Option<Return>,
+}
+
+struct StoreTicket {
+    ticket_number: i64,
+    sold_date_sk: i32,
+    sold_time_sk: i32,
+    buyer: Buyer,
+    store_sk: i32,
+    lines: Vec<SaleLine>,
+}
+
+/// An order of the catalog or the web channel.
+struct ShippedOrder {
+    order_number: i64,
+    sold_date_sk: i32,
+    sold_time_sk: i32,
+    ship_date_sk: i32,
+    bill: Buyer,
+    ship: Buyer,
+    /// The catalog page or the web page the order is placed from.
+    page_sk: i32,
+    /// The call center or the web site the order is placed to.
+    outlet_sk: i32,
+    ship_mode_sk: i32,
+    warehouse_sk: i32,
+    lines: Vec<SaleLine>,
+}
+
+struct TpcdsGenerator {
+    table: TpcdsTable,
+    call_centers: i64,
+    catalog_pages: i64,
+    customers: i64,
+    addresses: i64,
+    items: i64,
+    promotions: i64,
+    stores: i64,
+    warehouses: i64,
+    web_pages: i64,
+    web_sites: i64,
+}
+
+impl TpcdsGenerator {
+    fn new(table: TpcdsTable, scale_factor: f64) -> Self {
+        let units = |table: TpcdsTable| table.units(scale_factor) as i64;
+        TpcdsGenerator {
+            table,
+            call_centers: units(TpcdsTable::CallCenter),
+            catalog_pages: units(TpcdsTable::CatalogPage),
+            customers: units(TpcdsTable::Customer),
+            addresses: units(TpcdsTable::CustomerAddress),
+            items: units(TpcdsTable::Item) / 2,
+            promotions: units(TpcdsTable::Promotion),
+            stores: units(TpcdsTable::Store),
+            warehouses: units(TpcdsTable::Warehouse),
+            web_pages: units(TpcdsTable::WebPage),
+            web_sites: units(TpcdsTable::WebSite),
+        }
+    }
+
+    fn rng(&self, unit: u64) -> RowRng {
+        // The sales and their returns are generated from the same random sequence.
+        let table = match self.table {
+            TpcdsTable::StoreReturns => TpcdsTable::StoreSales,
+            TpcdsTable::CatalogReturns => TpcdsTable::CatalogSales,
+            TpcdsTable::WebReturns => TpcdsTable::WebSales,
+            table => table,
+        };
+        RowRng::new(table as u64, unit)
+    }
+
+    /// The surrogate key of the revision of the `id`-th item at the date.
+    fn item_sk(id: i64, date_sk: i32) -> i32 {
+        match date_sk < ITEM_REVISION_DATE + UNIX_EPOCH_SK {
+            true => (2 * id - 1) as i32,
+            false => (2 * id) as i32,
+        }
+    }
+
+    fn buyer(&self, rng: &mut RowRng) -> Buyer {
+        Buyer {
+            customer_sk: rng.range(1, self.customers) as i32,
+            cdemo_sk: rng.range(1, 1_920_800) as i32,
+            hdemo_sk: rng.range(1, 7_200) as i32,
+            addr_sk: rng.range(1, self.addresses) as i32,
+        }
+    }
+
+    /// The lines of a sale sold at `sold_date_sk` and received at `received_date_sk`, each
+    /// line is of a different item.
+    fn sale_lines(
+        &self,
+        rng: &mut RowRng,
+        (min, max): (i64, i64),
+        sold_date_sk: i32,
+        received_date_sk: i32,
+    ) -> Vec<SaleLine> {
+        let num_lines = rng.range(min, max).min(self.items);
+        let mut item_ids = Vec::with_capacity(num_lines as usize);
+        while item_ids.len() < num_lines as usize {
+            let item_id = rng.range(1, self.items);
+            if !item_ids.contains(&item_id) {
+                item_ids.push(item_id);
+            }
+        }
+
+        item_ids
+            .into_iter()
+            .map(|item_id| {
+                let promo_sk = rng.range(1, self.promotions) as i32;
+                let pricing = Pricing::generate(rng);
+                let ret = Return::generate(rng, &pricing, received_date_sk);
+                SaleLine {
+                    item_sk: Self::item_sk(item_id, sold_date_sk),
+                    promo_sk,
+                    pricing,
+                    ret,
+                }
+            })
+            .collect()
+    }
+
+    fn sold_date_sk(rng: &mut RowRng) -> i32 {
+        rng.range(SALES_START_SK as i64, SALES_END_SK as i64) as i32
+    }
+
+    fn store_ticket(&self, unit: u64) -> StoreTicket {
+        let mut rng = self.rng(unit);
+        let sold_date_sk = Self::sold_date_sk(&mut rng);
+        // The stores are open from 8AM to 10PM.
+        let sold_time_sk = rng.range(8 * 3600, 22 * 3600 - 1) as i32;
+        let buyer = self.buyer(&mut rng);
+        let store_sk = rng.range(1, self.stores) as i32;
+        let lines = self.sale_lines(&mut rng, (8, 16), sold_date_sk, sold_date_sk);
+
+        StoreTicket {
+            ticket_number: unit as i64 + 1,
+            sold_date_sk,
+            sold_time_sk,
+            buyer,
+            store_sk,
+            lines,
+        }
+    }
+
+    fn shipped_order(
+        &self,
+        unit: u64,
+        pages: i64,
+        outlets: i64,
+        lines: (i64, i64),
+    ) -> ShippedOrder {
+        let mut rng = self.rng(unit);
+        let sold_date_sk = Self::sold_date_sk(&mut rng);
+        let sold_time_sk = rng.range(0, 86399) as i32;
+        let ship_date_sk = sold_date_sk + rng.range(2, 90) as i32;
+        let bill = self.buyer(&mut rng);
+        // Half of the orders are shipped to another customer.
+        let ship = match rng.range(0, 1) {
+            0 => bill,
+            _ => self.buyer(&mut rng),
+        };
+        let page_sk = rng.range(1, pages) as i32;
+        let outlet_sk = rng.range(1, outlets) as i32;
+        let ship_mode_sk = rng.range(1, 20) as i32;
+        let warehouse_sk = rng.range(1, self.warehouses) as i32;
+        let lines = self.sale_lines(&mut rng, lines, sold_date_sk, ship_date_sk);
+
+        ShippedOrder {
+            order_number: unit as i64 + 1,
+            sold_date_sk,
+            sold_time_sk,
+            ship_date_sk,
+            bill,
+            ship,
+            page_sk,
+            outlet_sk,
+            ship_mode_sk,
+            warehouse_sk,
+            lines,
+        }
+    }
+
+    fn catalog_order(&self, unit: u64) -> ShippedOrder {
+        self.shipped_order(unit, self.catalog_pages, self.call_centers, (4, 14))
+    }
+
+    fn web_order(&self, unit: u64) -> ShippedOrder {
+        self.shipped_order(unit, self.web_pages, self.web_sites, (8, 16))
+    }
+
+    fn store_sales(&self, unit: u64, writer: &mut RowWriter) {
+        let ticket = self.store_ticket(unit);
+
+        for line in &ticket.lines {
+            let p = &line.pricing;
+            writer.push(&[
+                int32(ticket.sold_date_sk),
+                int32(ticket.sold_time_sk),
+                int32(line.item_sk),
+                int32(ticket.buyer.customer_sk),
+                int32(ticket.buyer.cdemo_sk),
+                int32(ticket.buyer.hdemo_sk),
+                int32(ticket.buyer.addr_sk),
+                int32(ticket.store_sk),
+                int32(line.promo_sk),
+                int64(ticket.ticket_number),
+                int32(p.quantity as i32),
+                money(p.wholesale_cost),
+                money(p.list_price),
+                money(p.sales_price),
+                money(p.ext_discount_amt),
+                money(p.ext_sales_price),
+                money(p.ext_wholesale_cost),
+                money(p.ext_list_price),
+                money(p.ext_tax),
+                money(p.coupon_amt),
+                money(p.net_paid),
+                money(p.net_paid_inc_tax),
+                money(p.net_profit),
+            ]);
+        }
+    }
+
+    fn store_returns(&self, unit: u64, writer: &mut RowWriter) {
+        let ticket = self.store_ticket(unit);
+
+        for line in &ticket.lines {
+            let Some(r) = &line.ret else {
+                continue;
+            };
+            writer.push(&[
+                int32(r.date_sk),
+                int32(r.time_sk),
+                int32(line.item_sk),
+                int32(ticket.buyer.customer_sk),
+                int32(ticket.buyer.cdemo_sk),
+                int32(ticket.buyer.hdemo_sk),
+                int32(ticket.buyer.addr_sk),
+                int32(ticket.store_sk),
+                int32(r.reason_sk),
+                int64(ticket.ticket_number),
+                int32(r.quantity as i32),
+                money(r.amount),
+                money(r.tax),
+                money(r.amount_inc_tax),
+                money(r.fee),
+                money(r.ship_cost),
+                money(r.refunded_cash),
+                money(r.reversed_charge),
+                money(r.credit),
+                money(r.net_loss),
+            ]);
+        }
+    }
+
+    fn catalog_sales(&self, unit: u64, writer: &mut RowWriter) {
+        let order = self.catalog_order(unit);
+
+        for line in &order.lines {
+            let p = &line.pricing;
+            writer.push(&[
+                int32(order.sold_date_sk),
+                int32(order.sold_time_sk),
+                int32(order.ship_date_sk),
+                int32(order.bill.customer_sk),
+                int32(order.bill.cdemo_sk),
+                int32(order.bill.hdemo_sk),
+                int32(order.bill.addr_sk),
+                int32(order.ship.customer_sk),
+                int32(order.ship.cdemo_sk),
+                int32(order.ship.hdemo_sk),
+                int32(order.ship.addr_sk),
+                int32(order.outlet_sk),
+                int32(order.page_sk),
+                int32(order.ship_mode_sk),
+                int32(order.warehouse_sk),
+                int32(line.item_sk),
+                int32(line.promo_sk),
+                int64(order.order_number),
+                int32(p.quantity as i32),
+                money(p.wholesale_cost),
+                money(p.list_price),
+                money(p.sales_price),
+                money(p.ext_discount_amt),
+                money(p.ext_sales_price),
+                money(p.ext_wholesale_cost),
+                money(p.ext_list_price),
+                money(p.ext_tax),
+                money(p.coupon_amt),
+                money(p.ext_ship_cost),
+                money(p.net_paid),
+                money(p.net_paid_inc_tax),
+                money(p.net_paid_inc_ship),
+                money(p.net_paid_inc_ship_tax),
+                money(p.net_profit),
+            ]);
+        }
+    }
+
+    fn catalog_returns(&self, unit: u64, writer: &mut RowWriter) {
+        let order = self.catalog_order(unit);
+
+        for line in &order.lines {
+            let Some(r) = &line.ret else {
+                continue;
+            };
+            writer.push(&[
+                int32(r.date_sk),
+                int32(r.time_sk),
+                int32(line.item_sk),
+                int32(order.bill.customer_sk),
+                int32(order.bill.cdemo_sk),
+                int32(order.bill.hdemo_sk),
+                int32(order.bill.addr_sk),
+                int32(order.ship.customer_sk),
+                int32(order.ship.cdemo_sk),
+                int32(order.ship.hdemo_sk),
+                int32(order.ship.addr_sk),
+                int32(order.outlet_sk),
+                int32(order.page_sk),
+                int32(order.ship_mode_sk),
+                int32(order.warehouse_sk),
+                int32(r.reason_sk),
+                int64(order.order_number),
+                int32(r.quantity as i32),
+                money(r.amount),
+                money(r.tax),
+                money(r.amount_inc_tax),
+                money(r.fee),
+                money(r.ship_cost),
+                money(r.refunded_cash),
+                money(r.reversed_charge),
+                money(r.credit),
+                money(r.net_loss),
+            ]);
+        }
+    }
+
+    fn web_sales(&self, unit: u64, writer: &mut RowWriter) {
+        let order = self.web_order(unit);
+
+        for line in &order.lines {
+            let p = &line.pricing;
+            writer.push(&[
+                int32(order.sold_date_sk),
+                int32(order.sold_time_sk),
+                int32(order.ship_date_sk),
+                int32(line.item_sk),
+                int32(order.bill.customer_sk),
+                int32(order.bill.cdemo_sk),
+                int32(order.bill.hdemo_sk),
+                int32(order.bill.addr_sk),
+                int32(order.ship.customer_sk),
+                int32(order.ship.cdemo_sk),
+                int32(order.ship.hdemo_sk),
+                int32(order.ship.addr_sk),
+                int32(order.page_sk),
+                int32(order.outlet_sk),
+                int32(order.ship_mode_sk),
+                int32(order.warehouse_sk),
+                int32(line.promo_sk),
+                int64(order.order_number),
+                int32(p.quantity as i32),
+                money(p.wholesale_cost),
+                money(p.list_price),
+                money(p.sales_price),
+                money(p.ext_discount_amt),
+                money(p.ext_sales_price),
+                money(p.ext_wholesale_cost),
+                money(p.ext_list_price),
+                money(p.ext_tax),
+                money(p.coupon_amt),
+                money(p.ext_ship_cost),
+                money(p.net_paid),
+                money(p.net_paid_inc_tax),
+                money(p.net_paid_inc_ship),
+                money(p.net_paid_inc_ship_tax),
+                money(p.net_profit),
+            ]);
+        }
+    }
+
+    fn web_returns(&self, unit: u64, writer: &mut RowWriter) {
+        let order = self.web_order(unit);
+
+        for line in &order.lines {
+            let Some(r) = &line.ret else {
+                continue;
+            };
+            writer.push(&[
+                int32(r.date_sk),
+                int32(r.time_sk),
+                int32(line.item_sk),
+                int32(order.bill.customer_sk),
+                int32(order.bill.cdemo_sk),
+                int32(order.bill.hdemo_sk),
+                int32(order.bill.addr_sk),
+                int32(order.ship.customer_sk),
+                int32(order.ship.cdemo_sk),
+                int32(order.ship.hdemo_sk),
+                int32(order.ship.addr_sk),
+                int32(order.page_sk),
+                int32(r.reason_sk),
+                int64(order.order_number),
+                int32(r.quantity as i32),
+                money(r.amount),
+                money(r.tax),
+                money(r.amount_inc_tax),
+                money(r.fee),
+                money(r.ship_cost),
+                money(r.refunded_cash),
+                money(r.reversed_charge),
+                money(r.credit),
+                money(r.net_loss),
+            ]);
+        }
+    }
+
+    fn inventory(&self, unit: u64, writer: &mut RowWriter) {
+        let mut rng = self.rng(unit);
+        let warehouses = self.warehouses as u64;
+        let items = self.items as u64;
+
+        let warehouse_sk = (unit % warehouses) as i32 + 1;
+        let item_id = ((unit / warehouses) % items) as i64 + 1;
+        let date_sk = INVENTORY_START_SK + 7 * (unit / warehouses / items) as i32;
+
+        writer.push(&[
+            int32(date_sk),
+            int32(Self::item_sk(item_id, date_sk)),
+            int32(warehouse_sk),
+            int32(rng.range(0, 1000) as i32),
+        ]);
+    }
+
+    fn call_center(&self, unit: u64, writer: &mut RowWriter) {
+        let mut rng = self.rng(unit);
+        let id = business_key(unit + 1);
+        let name = match unit < CALL_CENTER_NAMES.len() as u64 {
+            true => CALL_CENTER_NAMES[unit as usize].to_string(),
+            false => format!(
+                "{} {}",
+                CALL_CENTER_NAMES[unit as usize % CALL_CENTER_NAMES.len()],
+                unit as usize / CALL_CENTER_NAMES.len()
+            ),
+        };
+        let open_date_sk = Self::sold_date_sk(&mut rng) - 1000;
+        let class = rng.pick(&CALL_CENTER_CLASSES);
+        let employees = rng.range(1, 70000) as i32;
+        let sq_ft = rng.range(100, 700000) as i32 * 10;
+        let hours = rng.pick(&HOURS);
+        let manager = full_name(&mut rng);
+        let market_id = rng.range(1, 6);
+        let market_class = rng.text(20, 50);
+        let market_desc = rng.text(20, 100);
+        let market_manager = full_name(&mut rng);
+        let division = rng.range(1, 6);
+        let company = rng.range(1, 6);
+        let address = Address::generate(&mut rng);
+        let tax = rng.range(0, 12);
+        let division_name = syllables(division);
+        let company_name = syllables(company);
+
+        let mut row = vec![
+            int32(unit as i32 + 1),
+            string(&id),
+            ScalarRef::Date(REC_START_DATE),
+            ScalarRef::Null,
+            ScalarRef::Null,
+            int32(open_date_sk),
+            string(&name),
+            string(class),
+            int32(employees),
+            int32(sq_ft),
+            string(hours),
+            string(&manager),
+            int32(market_id as i32),
+            string(&market_class),
+            string(&market_desc),
+            string(&market_manager),
+            int32(division as i32),
+            string(&division_name),
+            int32(company as i32),
+            string(&company_name),
+        ];
+        row.extend(address.row());
+        row.push(rate(tax));
+        writer.push(&row);
+    }
+
+    fn catalog_page(&self, unit: u64, writer: &mut RowWriter) {
+        let mut rng = self.rng(unit);
+        let catalog_number = unit / CATALOG_PAGES_PER_CATALOG + 1;
+        let page_number = unit % CATALOG_PAGES_PER_CATALOG + 1;
+        let (catalog_type, duration) =
+            CATALOG_TYPES[(catalog_number % CATALOG_TYPES.len() as u64) as usize];
+        // The catalogs are issued every month of the sales.
+        let start_date_sk = SALES_START_SK - 1 + (catalog_number as i32 - 1) % 60 * 30;
+        let id = business_key(unit + 1);
+        let description = rng.text(20, 100);
+
+        writer.push(&[
+            int32(unit as i32 + 1),
+            string(&id),
+            int32(start_date_sk),
+            int32(start_date_sk + duration - 1),
+            string("DEPARTMENT"),
+            int32(catalog_number as i32),
+            int32(page_number as i32),
+            string(&description),
+            string(catalog_type),
+        ]);
+    }
+
+    fn customer(&self, unit: u64, writer: &mut RowWriter) {
+        let mut rng = self.rng(unit);
+        let id = business_key(unit + 1);
+        let cdemo_sk = rng.range(1, 1_920_800) as i32;
+        let hdemo_sk = rng.range(1, 7_200) as i32;
+        let addr_sk = rng.range(1, self.addresses) as i32;
+        let first_sales_date_sk = Self::sold_date_sk(&mut rng);
+        let first_shipto_date_sk = first_sales_date_sk + 30;
+        let salutation = rng.pick(&SALUTATIONS);
+        let first_name = rng.pick(&FIRST_NAMES);
+        let last_name = rng.pick(&LAST_NAMES);
+        let preferred = rng.range(0, 1) == 0;
+        let birth_day = rng.range(1, 28) as i32;
+        let birth_month = rng.range(1, 12) as i32;
+        let birth_year = rng.range(1924, 1992) as i32;
+        let birth_country = rng.pick(&COUNTRIES);
+        let email_domain_len = rng.range(1, 20);
+        let email_domain = alphanumeric(&mut rng, email_domain_len);
+        let email = format!(
+            "{}.{}@{}.{}",
+            first_name,
+            last_name,
+            email_domain,
+            rng.pick(&TOP_DOMAINS)
+        );
+        let last_review_date_sk = rng.range(SALES_END_SK as i64 - 365, SALES_END_SK as i64) as i32;
+
+        writer.push(&[
+            int32(unit as i32 + 1),
+            string(&id),
+            int32(cdemo_sk),
+            int32(hdemo_sk),
+            int32(addr_sk),
+            int32(first_shipto_date_sk),
+            int32(first_sales_date_sk),
+            string(salutation),
+            string(first_name),
+            string(last_name),
+            flag(preferred),
+            int32(birth_day),
+            int32(birth_month),
+            int32(birth_year),
+            string(birth_country),
+            ScalarRef::Null,
+            string(&email),
+            int32(last_review_date_sk),
+        ]);
+    }
+
+    fn customer_address(&self, unit: u64, writer: &mut RowWriter) {
+        let mut rng = self.rng(unit);
+        let id = business_key(unit + 1);
+        let address = Address::generate(&mut rng);
+        let location_type = rng.pick(&LOCATION_TYPES);
+
+        let mut row = vec![int32(unit as i32 + 1), string(&id)];
+        row.extend(address.row());
+        row.push(string(location_type));
+        writer.push(&row);
+    }
+
+    /// The demographics are all the combinations of their attributes.
+    fn customer_demographics(&self, unit: u64, writer: &mut RowWriter) {
+        let mut rest = unit as usize;
+        let mut next = |radix: usize| {
+            let value = rest % radix;
+            rest /= radix;
+            value
+        };
+        let gender = GENDERS[next(GENDERS.len())];
+        let marital_status = MARITAL_STATUSES[next(MARITAL_STATUSES.len())];
+        let education_status = EDUCATION_STATUSES[next(EDUCATION_STATUSES.len())];
+        let purchase_estimate = (next(20) as i32 + 1) * 500;
+        let credit_rating = CREDIT_RATINGS[next(CREDIT_RATINGS.len())];
+        let dep_count = next(7) as i32;
+        let dep_employed_count = next(7) as i32;
+        let dep_college_count = next(7) as i32;
+
+        writer.push(&[
+            int32(unit as i32 + 1),
+            string(gender),
+            string(marital_status),
+            string(education_status),
+            int32(purchase_estimate),
+            string(credit_rating),
+            int32(dep_count),
+            int32(dep_employed_count),
+            int32(dep_college_count),
+        ]);
+    }
+
+    fn date_dim(&self, unit: u64, writer: &mut RowWriter) {
+        let sk = DATE_DIM_START_SK + unit as i32;
+        let date = NaiveDate::from_num_days_from_ce_opt(sk - JULIAN_DAY_OF_CE).unwrap();
+        let is_holiday =
+            |date: NaiveDate| matches!((date.month(), date.day()), (1, 1) | (7, 4) | (12, 25));
+
+        let year = date.year();
+        let moy = date.month() as i32;
+        let dom = date.day() as i32;
+        let dow = date.weekday().num_days_from_sunday() as i32;
+        let qoy = (moy - 1) / 3 + 1;
+        let month_seq = (year - 1900) * 12 + moy - 1;
+        // The weeks start on Sunday, 1900-01-02 is a Tuesday.
+        let week_seq = (unit as i32 + 2) / 7 + 1;
+        let quarter_seq = (year - 1900) * 4 + qoy;
+        let id = business_key(sk as u64);
+        let quarter_name = format!("{}Q{}", year, qoy);
+        let first_dom = date_sk(clamped_date(year, moy as u32, 1));
+        let last_dom = date_sk(clamped_date(year, moy as u32, 31));
+        let same_day_ly = date_sk(clamped_date(year - 1, moy as u32, dom as u32));
+        let (lq_year, lq_month) = match moy > 3 {
+            true => (year, moy - 3),
+            false => (year - 1, moy + 9),
+        };
+        let same_day_lq = date_sk(clamped_date(lq_year, lq_month as u32, dom as u32));
+        let following_holiday = date.pred_opt().is_some_and(is_holiday);
+
+        writer.push(&[
+            int32(sk),
+            string(&id),
+            ScalarRef::Date(date_value(date)),
+            int32(month_seq),
+            int32(week_seq),
+            int32(quarter_seq),
+            int32(year),
+            int32(dow),
+            int32(moy),
+            int32(dom),
+            int32(qoy),
+            int32(year),
+            int32(quarter_seq),
+            int32(week_seq),
+            string(DAY_NAMES[dow as usize]),
+            string(&quarter_name),
+            flag(is_holiday(date)),
+            flag(dow == 0 || dow == 6),
+            flag(following_holiday),
+            int32(first_dom),
+            int32(last_dom),
+            int32(same_day_ly),
+            int32(same_day_lq),
+            flag(false),
+            flag(false),
+            flag(false),
+            flag(false),
+            flag(false),
+        ]);
+    }
+
+    /// The demographics are all the combinations of their attributes.
+    fn household_demographics(&self, unit: u64, writer: &mut RowWriter) {
+        let income_band_sk = (unit % 20) as i32 + 1;
+        let buy_potential = BUY_POTENTIALS[(unit / 20 % 6) as usize];
+        let dep_count = (unit / 120 % 10) as i32;
+        let vehicle_count = (unit / 1200 % 6) as i32 - 1;
+
+        writer.push(&[
+            int32(unit as i32 + 1),
+            int32(income_band_sk),
+            string(buy_potential),
+            int32(dep_count),
+            int32(vehicle_count),
+        ]);
+    }
+
+    fn income_band(&self, unit: u64, writer: &mut RowWriter) {
+        let lower_bound = match unit {
+            0 => 0,
+            _ => unit as i32 * 10000 + 1,
+        };
+
+        writer.push(&[
+            int32(unit as i32 + 1),
+            int32(lower_bound),
+            int32((unit as i32 + 1) * 10000),
+        ]);
+    }
+
+    fn item(&self, unit: u64, writer: &mut RowWriter) {
+        // The attributes of the item are shared by its revisions, except the prices.
+        let item_id = unit / 2 + 1;
+        let revision = unit % 2;
+        let mut rng = RowRng::new(TpcdsTable::Item as u64, item_id);
+
+        let id = business_key(item_id);
+        let description = rng.text(1, 200);
+        let category = rng.range(0, CATEGORIES.len() as i64 - 1) as usize;
+        let (category_name, classes) = CATEGORIES[category];
+        let class = rng.range(0, classes.len() as i64 - 1) as usize;
+        let brand_number = rng.range(1, 10);
+        let brand_id = (category as i64 + 1) * 1_000_000 + (class as i64 + 1) * 1000 + brand_number;
+        let brand = format!(
+            "{}{} #{}",
+            BRANDS[category],
+            BRANDS[class % BRANDS.len()],
+            brand_number
+        );
+        let manufact_id = rng.range(1, 1000);
+        let manufact = syllables(manufact_id);
+        let size = rng.pick(&SIZES);
+        let formulation = alphanumeric(&mut rng, 20);
+        let color = rng.pick(&COLORS);
+        let units = rng.pick(&UNITS);
+        let manager_id = rng.range(1, 100) as i32;
+        let product_name = syllables(item_id as i64);
+        let mut current_price = rng.range(9, 9999);
+        if revision == 1 {
+            current_price = rng.range(9, 9999);
+        }
+        let wholesale_cost = current_price * rng.range(20, 90) / 100;
+        let (rec_start_date, rec_end_date) = match revision {
+            0 => (
+                ScalarRef::Date(ITEM_REC_START_DATE),
+                ScalarRef::Date(ITEM_REVISION_DATE - 1),
+            ),
+            _ => (ScalarRef::Date(ITEM_REVISION_DATE), ScalarRef::Null),
+        };
+
+        writer.push(&[
+            int32(unit as i32 + 1),
+            string(&id),
+            rec_start_date,
+            rec_end_date,
+            string(&description),
+            money(current_price),
+            money(wholesale_cost),
+            int32(brand_id as i32),
+            string(&brand),
+            int32(class as i32 + 1),
+            string(classes[class]),
+            int32(category as i32 + 1),
+            string(category_name),
+            int32(manufact_id as i32),
+            string(&manufact),
+            string(size),
+            string(&formulation),
+            string(color),
+            string(units),
+            string("Unknown"),
+            int32(manager_id),
+            string(&product_name),
+        ]);
+    }
+
+    fn promotion(&self, unit: u64, writer: &mut RowWriter) {
+        let mut rng = self.rng(unit);
+        let id = business_key(unit + 1);
+        let start_date_sk = Self::sold_date_sk(&mut rng);
+        let end_date_sk = start_date_sk + rng.range(1, 60) as i32;
+        let item_id = rng.range(1, self.items);
+        let name = syllables(unit as i64 + 1);
+        let channels: Vec<bool> = (0..8).map(|_| rng.range(0, 1) == 0).collect();
+        let details = rng.text(20, 60);
+
+        let mut row = vec![
+            int32(unit as i32 + 1),
+            string(&id),
+            int32(start_date_sk),
+            int32(end_date_sk),
+            int32(Self::item_sk(item_id, start_date_sk)),
+            decimal(100000, COST_SIZE),
+            int32(1),
+            string(&name),
+        ];
+        row.extend(channels.into_iter().map(flag));
+        row.extend([string(&details), string("Unknown"), flag(false)]);
+        writer.push(&row);
+    }
+
+    fn reason(&self, unit: u64, writer: &mut RowWriter) {
+        let id = business_key(unit + 1);
+
+        writer.push(&[
+            int32(unit as i32 + 1),
+            string(&id),
+            string(REASONS[unit as usize]),
+        ]);
+    }
+
+    fn ship_mode(&self, unit: u64, writer: &mut RowWriter) {
+        let mut rng = self.rng(unit);
+        let id = business_key(unit + 1);
+        let contract_len = rng.range(1, 20);
+        let contract = alphanumeric(&mut rng, contract_len);
+
+        writer.push(&[
+            int32(unit as i32 + 1),
+            string(&id),
+            string(SHIP_MODE_TYPES[unit as usize % SHIP_MODE_TYPES.len()]),
+            string(SHIP_MODE_CODES[unit as usize % SHIP_MODE_CODES.len()]),
+            string(CARRIERS[unit as usize % CARRIERS.len()]),
+            string(&contract),
+        ]);
+    }
+
+    fn store(&self, unit: u64, writer: &mut RowWriter) {
+        let mut rng = self.rng(unit);
+        let id = business_key(unit + 1);
+        let name = syllables(unit as i64 + 1);
+        let employees = rng.range(200, 300) as i32;
+        let floor_space = rng.range(5_000_000, 10_000_000) as i32;
+        let hours = rng.pick(&HOURS);
+        let manager = full_name(&mut rng);
+        let market_id = rng.range(1, 10) as i32;
+        let market_desc = rng.text(20, 100);
+        let market_manager = full_name(&mut rng);
+        let address = Address::generate(&mut rng);
+        let tax = rng.range(0, 11);
+
+        let mut row = vec![
+            int32(unit as i32 + 1),
+            string(&id),
+            ScalarRef::Date(REC_START_DATE),
+            ScalarRef::Null,
+            ScalarRef::Null,
+            string(&name),
+            int32(employees),
+            int32(floor_space),
+            string(hours),
+            string(&manager),
+            int32(market_id),
+            string("Unknown"),
+            string(&market_desc),
+            string(&market_manager),
+            int32(1),
+            string("Unknown"),
+            int32(1),
+            string("Unknown"),
+        ];
+        row.extend(address.row());
+        row.push(rate(tax));
+        writer.push(&row);
+    }
+
+    fn time_dim(&self, unit: u64, writer: &mut RowWriter) {
+        let time = unit as i32;
+        let hour = time / 3600;
+        let id = business_key(unit + 1);
+        let shift = match hour {
+            0..=7 => "third",
+            8..=15 => "first",
+            _ => "second",
+        };
+        let sub_shift = match hour {
+            6..=11 => "morning",
+            12..=16 => "afternoon",
+            17..=20 => "evening",
+            _ => "night",
+        };
+        let meal_time = match hour {
+            6..=8 => string("breakfast"),
+            11..=13 => string("lunch"),
+            17..=19 => string("dinner"),
+            _ => ScalarRef::Null,
+        };
+
+        writer.push(&[
+            int32(time),
+            string(&id),
+            int32(time),
+            int32(hour),
+            int32(time / 60 % 60),
+            int32(time % 60),
+            string(if hour < 12 { "AM" } else { "PM" }),
+            string(shift),
+            string(sub_shift),
+            meal_time,
+        ]);
+    }
+
+    fn warehouse(&self, unit: u64, writer: &mut RowWriter) {
+        let mut rng = self.rng(unit);
+        let id = business_key(unit + 1);
+        let name = rng.text(10, 20);
+        let sq_ft = rng.range(50_000, 1_000_000) as i32;
+        let address = Address::generate(&mut rng);
+
+        let mut row = vec![
+            int32(unit as i32 + 1),
+            string(&id),
+            string(&name),
+            int32(sq_ft),
+        ];
+        row.extend(address.row());
+        writer.push(&row);
+    }
+
+    fn web_page(&self, unit: u64, writer: &mut RowWriter) {
+        let mut rng = self.rng(unit);
+        let id = business_key(unit + 1);
+        let creation_date_sk = Self::sold_date_sk(&mut rng) - 1000;
+        let access_date_sk = SALES_END_SK - rng.range(0, 100) as i32;
+        // The pages generated automatically are the pages of the customers.
+        let autogen = rng.range(0, 1) == 0;
+        let customer_sk = match autogen {
+            true => int32(rng.range(1, self.customers) as i32),
+            false => ScalarRef::Null,
+        };
+        let page_type = rng.pick(&WEB_PAGE_TYPES);
+        let char_count = rng.range(100, 8000) as i32;
+        let link_count = rng.range(2, 25) as i32;
+        let image_count = rng.range(1, 7) as i32;
+        let max_ad_count = rng.range(0, 4) as i32;
+
+        writer.push(&[
+            int32(unit as i32 + 1),
+            string(&id),
+            ScalarRef::Date(REC_START_DATE),
+            ScalarRef::Null,
+            int32(creation_date_sk),
+            int32(access_date_sk),
+            flag(autogen),
+            customer_sk,
+            string("http://www.foo.com"),
+            string(page_type),
+            int32(char_count),
+            int32(link_count),
+            int32(image_count),
+            int32(max_ad_count),
+        ]);
+    }
+
+    fn web_site(&self, unit: u64, writer: &mut RowWriter) {
+        let mut rng = self.rng(unit);
+        let id = business_key(unit + 1);
+        let name = format!("site_{}", unit);
+        let open_date_sk = Self::sold_date_sk(&mut rng) - 1000;
+        let manager = full_name(&mut rng);
+        let market_id = rng.range(1, 6);
+        let market_class = rng.text(20, 50);
+        let market_desc = rng.text(20, 100);
+        let market_manager = full_name(&mut rng);
+        let company_id = rng.range(1, 6);
+        let company_name = syllables(company_id);
+        let address = Address::generate(&mut rng);
+        let tax = rng.range(0, 12);
+
+        let mut row = vec![
+            int32(unit as i32 + 1),
+            string(&id),
+            ScalarRef::Date(REC_START_DATE),
+            ScalarRef::Null,
+            string(&name),
+            int32(open_date_sk),
+            ScalarRef::Null,
+            string("Unknown"),
+            string(&manager),
+            int32(market_id as i32),
+            string(&market_class),
+            string(&market_desc),
+            string(&market_manager),
+            int32(company_id as i32),
+            string(&company_name),
+        ];
+        row.extend(address.row());
+        row.push(rate(tax));
+        writer.push(&row);
+    }
+}
@@ -0,0 +1,951 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Generates the tables of the TPC-H benchmark following the value domains of the
+//! specification (clause 4.2.3).
+//!
+//! Every row is generated from a random generator seeded by the table and the row number,
+//! so that any range of rows can be generated independently and the same data is produced
+//! on every run. The data follows the distributions of `dbgen`, but is not byte-identical
+//! to its output.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DecimalDataType;
+use common_expression::types::DecimalSize;
+use common_expression::types::NumberDataType;
+use common_expression::DataBlock;
+use common_expression::ScalarRef;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRef;
+use common_expression::TableSchemaRefExt;
+
+use super::row_generator::int32;
+use super::row_generator::int64;
+use super::row_generator::scaled;
+use super::row_generator::string;
+use super::row_generator::RowRng;
+use super::row_generator::RowWriter;
+
+// 1992-01-01
+const START_DATE: i32 = 8035;
+// 1995-06-17
+const CURRENT_DATE: i32 = 9298;
+// 1998-12-31
+const END_DATE: i32 = 10591;
+
+const DECIMAL_SIZE: DecimalSize = DecimalSize {
+    precision: 15,
+    scale: 2,
+};
+
+const NATIONS: [(&str, i32); 25] = [
+    ("ALGERIA", 0),
+    ("ARGENTINA", 1),
+    ("BRAZIL", 1),
+    ("CANADA", 1),
+    ("EGYPT", 4),
+    ("ETHIOPIA", 0),
+    ("FRANCE", 3),
+    ("GERMANY", 3),
+    ("INDIA", 2),
+    ("INDONESIA", 2),
+    ("IRAN", 4),
+    ("IRAQ", 4),
+    ("JAPAN", 2),
+    ("JORDAN", 4),
+    ("KENYA", 0),
+    ("MOROCCO", 0),
+    ("MOZAMBIQUE", 0),
+    ("PERU", 1),
+    ("CHINA", 2),
+    ("ROMANIA", 3),
+    ("SAUDI ARABIA", 4),
+    ("VIETNAM", 2),
+    ("RUSSIA", 3),
+    ("UNITED KINGDOM", 3),
+    ("UNITED STATES", 1),
+];
+
+const REGIONS: [&str; 5] = ["AFRICA", "AMERICA", "ASIA", "EUROPE", "MIDDLE EAST"];
+
+pub(super) const COLORS: [&str; 92] = [
+    "almond",
+    "antique",
+    "aquamarine",
+    "azure",
+    "beige",
+    "bisque",
+    "black",
+    "blanched",
+    "blue",
+    "blush",
+    "brown",
+    "burlywood",
+    "burnished",
+    "chartreuse",
+    "chiffon",
+    "chocolate",
+    "coral",
+    "cornflower",
+    "cornsilk",
+    "cream",
+    "cyan",
+    "dark",
+    "deep",
+    "dim",
+    "dodger",
+    "drab",
+    "firebrick",
+    "floral",
+    "forest",
+    "frosted",
+    "gainsboro",
+    "ghost",
+    "goldenrod",
+    "green",
+    "grey",
+    "honeydew",
+    "hot",
+    "indian",
+    "ivory",
+    "khaki",
+    "lace",
+    "lavender",
+    "lawn",
+    "lemon",
+    "light",
+    "lime",
+    "linen",
+    "magenta",
+    "maroon",
+    "medium",
+    "metallic",
+    "midnight",
+    "mint",
+    "misty",
+    "moccasin",
+    "navajo",
+    "navy",
+    "olive",
+    "orange",
+    "orchid",
+    "pale",
+    "papaya",
+    "peach",
+    "peru",
+    "pink",
+    "plum",
+    "powder",
+    "puff",
+    "purple",
+    "red",
+    "rose",
+    "rosy",
+    "royal",
+    "saddle",
+    "salmon",
+    "sandy",
+    "seashell",
+    "sienna",
+    "sky",
+    "slate",
+    "smoke",
+    "snow",
+    "spring",
+    "steel",
+    "tan",
+    "thistle",
+    "tomato",
+    "turquoise",
+    "violet",
+    "wheat",
+    "white",
+    "yellow",
+];
+
+const TYPE_SYLLABLES_1: [&str; 6] = ["STANDARD", "SMALL", "MEDIUM", "LARGE", "ECONOMY", "PROMO"];
+const TYPE_SYLLABLES_2: [&str; 5] = ["ANODIZED", "BURNISHED", "PLATED", "POLISHED", "BRUSHED"];
+const TYPE_SYLLABLES_3: [&str; 5] = ["TIN", "NICKEL", "BRASS", "STEEL", "COPPER"];
+
+const CONTAINER_SYLLABLES_1: [&str; 5] = ["SM", "LG", "MED", "JUMBO", "WRAP"];
+const CONTAINER_SYLLABLES_2: [&str; 8] =
+    ["CASE", "BOX", "BAG", "JAR", "PKG", "PACK", "CAN", "DRUM"];
+
+const SEGMENTS: [&str; 5] = [
+    "AUTOMOBILE",
+    "BUILDING",
+    "FURNITURE",
+    "MACHINERY",
+    "HOUSEHOLD",
+];
+
+const PRIORITIES: [&str; 5] = ["1-URGENT", "2-HIGH", "3-MEDIUM", "4-NOT SPECIFIED", "5-LOW"];
+
+const INSTRUCTIONS: [&str; 4] = [
+    "DELIVER IN PERSON",
+    "COLLECT COD",
+    "NONE",
+    "TAKE BACK RETURN",
+];
+
+const MODES: [&str; 7] = ["REG AIR", "AIR", "RAIL", "SHIP", "TRUCK", "MAIL", "FOB"];
+
+const NOUNS: [&str; 40] = [
+    "foxes",
+    "ideas",
+    "theodolites",
+    "pinto beans",
+    "instructions",
+    "dependencies",
+    "excuses",
+    "platelets",
+    "asymptotes",
+    "courts",
+    "dolphins",
+    "multipliers",
+    "sauternes",
+    "warthogs",
+    "frets",
+    "dinos",
+    "attainments",
+    "somas",
+    "Tiresias",
+    "patterns",
+    "forges",
+    "braids",
+    "hockey players",
+    "frays",
+    "warhorses",
+    "dugouts",
+    "notornis",
+    "epitaphs",
+    "pearls",
+    "tithes",
+    "waters",
+    "orbits",
+    "gifts",
+    "sheaves",
+    "depths",
+    "sentiments",
+    "decoys",
+    "realms",
+    "pains",
+    "grouches",
+];
+
+const VERBS: [&str; 40] = [
+    "sleep",
+    "wake",
+    "are",
+    "cajole",
+    "haggle",
+    "nag",
+    "use",
+    "boost",
+    "affix",
+    "detect",
+    "integrate",
+    "maintain",
+    "nod",
+    "was",
+    "lose",
+    "sublate",
+    "solve",
+    "thrash",
+    "promise",
+    "engage",
+    "hinder",
+    "print",
+    "x-ray",
+    "breach",
+    "eat",
+    "grow",
+    "impress",
+    "mold",
+    "poach",
+    "serve",
+    "run",
+    "dazzle",
+    "snooze",
+    "doze",
+    "unwind",
+    "kindle",
+    "play",
+    "hang",
+    "believe",
+    "doubt",
+];
+
+const ADJECTIVES: [&str; 25] = [
+    "furious",
+    "sly",
+    "careful",
+    "blithe",
+    "quick",
+    "fluffy",
+    "slow",
+    "quiet",
+    "ruthless",
+    "thin",
+    "close",
+    "dogged",
+    "daring",
+    "brave",
+    "stealthy",
+    "permanent",
+    "enticing",
+    "idle",
+    "busy",
+    "regular",
+    "final",
+    "ironic",
+    "even",
+    "bold",
+    "silent",
+];
+
+const ADVERBS: [&str; 25] = [
+    "sometimes",
+    "always",
+    "never",
+    "furiously",
+    "slyly",
+    "carefully",
+    "blithely",
+    "quickly",
+    "fluffily",
+    "slowly",
+    "quietly",
+    "ruthlessly",
+    "thinly",
+    "closely",
+    "doggedly",
+    "daringly",
+    "bravely",
+    "stealthily",
+    "permanently",
+    "enticingly",
+    "idly",
+    "busily",
+    "regularly",
+    "finally",
+    "ironically",
+];
+
+const PREPOSITIONS: [&str; 20] = [
+    "about",
+    "above",
+    "according to",
+    "across",
+    "after",
+    "against",
+    "along",
+    "among",
+    "around",
+    "atop",
+    "before",
+    "behind",
+    "beneath",
+    "beside",
+    "between",
+    "beyond",
+    "during",
+    "inside",
+    "outside",
+    "until",
+];
+
+const TERMINATORS: [&str; 6] = [".", ";", ":", "?", "!", "--"];
+
+const ADDRESS_CHARS: &[u8] =
+    b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789, ";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum TpchTable {
+    Part,
+    Supplier,
+    PartSupp,
+    Customer,
+    Orders,
+    Lineitem,
+    Nation,
+    Region,
+}
+
+impl TpchTable {
+    pub fn from_name(name: &str) -> Result<Self> {
+        match name.to_lowercase().as_str() {
+            "part" => Ok(TpchTable::Part),
+            "supplier" => Ok(TpchTable::Supplier),
+            "partsupp" => Ok(TpchTable::PartSupp),
+            "customer" => Ok(TpchTable::Customer),
+            "orders" => Ok(TpchTable::Orders),
+            "lineitem" => Ok(TpchTable::Lineitem),
+            "nation" => Ok(TpchTable::Nation),
+            "region" => Ok(TpchTable::Region),
+            _ => Err(ErrorCode::BadArguments(format!(
+                "Unknown TPC-H table '{}', expect one of part, supplier, partsupp, customer, orders, lineitem, nation and region",
+                name
+            ))),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            TpchTable::Part => "part",
+            TpchTable::Supplier => "supplier",
+            TpchTable::PartSupp => "partsupp",
+            TpchTable::Customer => "customer",
+            TpchTable::Orders => "orders",
+            TpchTable::Lineitem => "lineitem",
+            TpchTable::Nation => "nation",
+            TpchTable::Region => "region",
+        }
+    }
+
+    pub fn schema(&self) -> TableSchemaRef {
+        let int32 = || TableDataType::Number(NumberDataType::Int32);
+        let int64 = || TableDataType::Number(NumberDataType::Int64);
+        let string = || TableDataType::String;
+        let decimal = || TableDataType::Decimal(DecimalDataType::Decimal128(DECIMAL_SIZE));
+        let date = || TableDataType::Date;
+
+        let fields: Vec<(&str, TableDataType)> = match self {
+            TpchTable::Part => vec![
+                ("p_partkey", int64()),
+                ("p_name", string()),
+                ("p_mfgr", string()),
+                ("p_brand", string()),
+                ("p_type", string()),
+                ("p_size", int32()),
+                ("p_container", string()),
+                ("p_retailprice", decimal()),
+                ("p_comment", string()),
+            ],
+            TpchTable::Supplier => vec![
+                ("s_suppkey", int64()),
+                ("s_name", string()),
+                ("s_address", string()),
+                ("s_nationkey", int32()),
+                ("s_phone", string()),
+                ("s_acctbal", decimal()),
+                ("s_comment", string()),
+            ],
+            TpchTable::PartSupp => vec![
+                ("ps_partkey", int64()),
+                ("ps_suppkey", int64()),
+                ("ps_availqty", int64()),
+                ("ps_supplycost", decimal()),
+                ("ps_comment", string()),
+            ],
+            TpchTable::Customer => vec![
+                ("c_custkey", int64()),
+                ("c_name", string()),
+                ("c_address", string()),
+                ("c_nationkey", int32()),
+                ("c_phone", string()),
+                ("c_acctbal", decimal()),
+                ("c_mktsegment", string()),
+                ("c_comment", string()),
+            ],
+            TpchTable::Orders => vec![
+                ("o_orderkey", int64()),
+                ("o_custkey", int64()),
+                ("o_orderstatus", string()),
+                ("o_totalprice", decimal()),
+                ("o_orderdate", date()),
+                ("o_orderpriority", string()),
+                ("o_clerk", string()),
+                ("o_shippriority", int32()),
+                ("o_comment", string()),
+            ],
+            TpchTable::Lineitem => vec![
+                ("l_orderkey", int64()),
+                ("l_partkey", int64()),
+                ("l_suppkey", int64()),
+                ("l_linenumber", int64()),
+                ("l_quantity", decimal()),
+                ("l_extendedprice", decimal()),
+                ("l_discount", decimal()),
+                ("l_tax", decimal()),
+                ("l_returnflag", string()),
+                ("l_linestatus", string()),
+                ("l_shipdate", date()),
+                ("l_commitdate", date()),
+                ("l_receiptdate", date()),
+                ("l_shipinstruct", string()),
+                ("l_shipmode", string()),
+                ("l_comment", string()),
+            ],
+            TpchTable::Nation => vec![
+                ("n_nationkey", int32()),
+                ("n_name", string()),
+                ("n_regionkey", int32()),
+                ("n_comment", string()),
+            ],
+            TpchTable::Region => vec![
+                ("r_regionkey", int32()),
+                ("r_name", string()),
+                ("r_comment", string()),
+            ],
+        };
+
+        TableSchemaRefExt::create(
+            fields
+                .into_iter()
+                .map(|(name, data_type)| TableField::new(name, data_type))
+                .collect(),
+        )
+    }
+
+    /// The number of units the generation of the table is split by.
+    ///
+    /// A unit is a row, except for `partsupp` which generates 4 rows per part and
+    /// `lineitem` which generates 1 to 7 rows per order.
+    pub fn units(&self, scale_factor: f64) -> u64 {
+        match self {
+            TpchTable::Part | TpchTable::PartSupp => scaled(200_000, scale_factor),
+            TpchTable::Supplier => scaled(10_000, scale_factor),
+            TpchTable::Customer => scaled(150_000, scale_factor),
+            TpchTable::Orders | TpchTable::Lineitem => scaled(1_500_000, scale_factor),
+            TpchTable::Nation => NATIONS.len() as u64,
+            TpchTable::Region => REGIONS.len() as u64,
+        }
+    }
+
+    /// The number of rows generated by a unit, on average.
+    pub fn rows_per_unit(&self) -> u64 {
+        match self {
+            TpchTable::PartSupp => 4,
+            TpchTable::Lineitem => 4,
+            _ => 1,
+        }
+    }
+
+    /// Generates the rows of the units in `[start, end)`.
+    pub fn generate(&self, scale_factor: f64, start: u64, end: u64) -> DataBlock {
+        let generator = TpchGenerator::new(*self, scale_factor);
+        let mut writer = RowWriter::new(
+            &self.schema(),
+            ((end - start) * self.rows_per_unit()) as usize,
+        );
+
+        for unit in start..end {
+            match self {
+                TpchTable::Part => generator.part(unit, &mut writer),
+                TpchTable::Supplier => generator.supplier(unit, &mut writer),
+                TpchTable::PartSupp => generator.partsupp(unit, &mut writer),
+                TpchTable::Customer => generator.customer(unit, &mut writer),
+                TpchTable::Orders => generator.orders(unit, &mut writer),
+                TpchTable::Lineitem => generator.lineitem(unit, &mut writer),
+                TpchTable::Nation => generator.nation(unit, &mut writer),
+                TpchTable::Region => generator.region(unit, &mut writer),
+            }
+        }
+
+        writer.finish()
+    }
+}
+
+/// `value` is in hundredths.
+fn decimal<'a>(value: i64) -> ScalarRef<'a> {
+    super::row_generator::decimal(value, DECIMAL_SIZE)
+}
+
+/// The random values following the value domains of TPC-H.
+impl RowRng {
+    /// A random string of the characters of addresses, with a length in `[min, max]`.
+    fn address(&mut self, min: i64, max: i64) -> String {
+        let len = self.range(min, max);
+        (0..len)
+            .map(|_| ADDRESS_CHARS[self.range(0, ADDRESS_CHARS.len() as i64 - 1) as usize] as char)
+            .collect()
+    }
+
+    /// A random text made of sentences of the grammar words, with a length in `[min, max]`.
+    pub(super) fn text(&mut self, min: i64, max: i64) -> String {
+        let len = self.range(min, max) as usize;
+        let mut text = String::with_capacity(len + 64);
+        while text.len() < len {
+            if !text.is_empty() {
+                text.push(' ');
+            }
+            let sentence = [
+                self.pick(&ADJECTIVES),
+                self.pick(&NOUNS),
+                self.pick(&VERBS),
+                self.pick(&ADVERBS),
+                self.pick(&PREPOSITIONS),
+                "the",
+                self.pick(&ADJECTIVES),
+                self.pick(&NOUNS),
+            ];
+            text.push_str(&sentence.join(" "));
+            text.push_str(self.pick(&TERMINATORS));
+        }
+        text.truncate(len);
+        text
+    }
+
+    fn phone(&mut self, nation_key: i64) -> String {
+        format!(
+            "{}-{}-{}-{}",
+            nation_key + 10,
+            self.range(100, 999),
+            self.range(100, 999),
+            self.range(1000, 9999)
+        )
+    }
+}
+
+struct TpchGenerator {
+    table: TpchTable,
+    parts: i64,
+    suppliers: i64,
+    customers: i64,
+    clerks: i64,
+}
+
+impl TpchGenerator {
+    fn new(table: TpchTable, scale_factor: f64) -> Self {
+        TpchGenerator {
+            table,
+            parts: TpchTable::Part.units(scale_factor) as i64,
+            suppliers: TpchTable::Supplier.units(scale_factor) as i64,
+            customers: TpchTable::Customer.units(scale_factor) as i64,
+            clerks: scaled(1_000, scale_factor) as i64,
+        }
+    }
+
+    fn rng(&self, unit: u64) -> RowRng {
+        // The orders and their line items are generated from the same random sequence.
+        match self.table {
+            TpchTable::Lineitem => RowRng::new(TpchTable::Orders as u64, unit),
+            table => RowRng::new(table as u64, unit),
+        }
+    }
+
+    fn retail_price(part_key: i64) -> i64 {
+        90000 + ((part_key / 10) % 20001) + 100 * (part_key % 1000)
+    }
+
+    /// The `index`-th supplier of the part, in `[0, 4)`.
+    fn supp_key(&self, part_key: i64, index: i64) -> i64 {
+        let suppliers = self.suppliers;
+        (part_key + index * (suppliers / 4 + (part_key - 1) / suppliers)) % suppliers + 1
+    }
+
+    fn part(&self, unit: u64, writer: &mut RowWriter) {
+        let mut rng = self.rng(unit);
+        let part_key = unit as i64 + 1;
+
+        let mut colors = Vec::with_capacity(5);
+        while colors.len() < 5 {
+            let color = rng.pick(&COLORS);
+            if !colors.contains(&color) {
+                colors.push(color);
+            }
+        }
+        let name = colors.join(" ");
+        let mfgr = rng.range(1, 5);
+        let mfgr_name = format!("Manufacturer#{}", mfgr);
+        let brand = format!("Brand#{}{}", mfgr, rng.range(1, 5));
+        let part_type = format!(
+            "{} {} {}",
+            rng.pick(&TYPE_SYLLABLES_1),
+            rng.pick(&TYPE_SYLLABLES_2),
+            rng.pick(&TYPE_SYLLABLES_3)
+        );
+        let size = rng.range(1, 50) as i32;
+        let container = format!(
+            "{} {}",
+            rng.pick(&CONTAINER_SYLLABLES_1),
+            rng.pick(&CONTAINER_SYLLABLES_2)
+        );
+        let comment = rng.text(5, 22);
+
+        writer.push(&[
+            int64(part_key),
+            string(&name),
+            string(&mfgr_name),
+            string(&brand),
+            string(&part_type),
+            int32(size),
+            string(&container),
+            decimal(Self::retail_price(part_key)),
+            string(&comment),
+        ]);
+    }
+
+    fn supplier(&self, unit: u64, writer: &mut RowWriter) {
+        let mut rng = self.rng(unit);
+        let supp_key = unit as i64 + 1;
+
+        let name = format!("Supplier#{:09}", supp_key);
+        let address = rng.address(10, 40);
+        let nation_key = rng.range(0, 24);
+        let phone = rng.phone(nation_key);
+        let acctbal = rng.range(-99999, 999999);
+        let mut comment = rng.text(25, 100);
+        // About 5 suppliers out of 10,000 have complaints or recommendations from the customers.
+        match rng.range(0, 1999) {
+            0 => insert_customer_comment(&mut comment, "Complaints"),
+            1 => insert_customer_comment(&mut comment, "Recommends"),
+            _ => {}
+        }
+
+        writer.push(&[
+            int64(supp_key),
+            string(&name),
+            string(&address),
+            int32(nation_key as i32),
+            string(&phone),
+            decimal(acctbal),
+            string(&comment),
+        ]);
+    }
+
+    fn partsupp(&self, unit: u64, writer: &mut RowWriter) {
+        let mut rng = self.rng(unit);
+        let part_key = unit as i64 + 1;
+
+        for index in 0..4 {
+            let supp_key = self.supp_key(part_key, index);
+            let availqty = rng.range(1, 9999);
+            let supplycost = rng.range(100, 100000);
+            let comment = rng.text(49, 198);
+
+            writer.push(&[
+                int64(part_key),
+                int64(supp_key),
+                int64(availqty),
+                decimal(supplycost),
+                string(&comment),
+            ]);
+        }
+    }
+
+    fn customer(&self, unit: u64, writer: &mut RowWriter) {
+        let mut rng = self.rng(unit);
+        let cust_key = unit as i64 + 1;
+
+        let name = format!("Customer#{:09}", cust_key);
+        let address = rng.address(10, 40);
+        let nation_key = rng.range(0, 24);
+        let phone = rng.phone(nation_key);
+        let acctbal = rng.range(-99999, 999999);
+        let segment = rng.pick(&SEGMENTS);
+        let comment = rng.text(29, 116);
+
+        writer.push(&[
+            int64(cust_key),
+            string(&name),
+            string(&address),
+            int32(nation_key as i32),
+            string(&phone),
+            decimal(acctbal),
+            string(segment),
+            string(&comment),
+        ]);
+    }
+
+    fn order(&self, unit: u64) -> (Order, Vec<Lineitem>) {
+        let mut rng = self.rng(unit);
+        // Only the first 8 keys of every 32 keys are used.
+        let order_key = (unit / 8 * 32 + unit % 8 + 1) as i64;
+
+        // A third of the customers never place orders.
+        let mut cust_key = rng.range(1, self.customers);
+        let mut delta = 1;
+        while cust_key % 3 == 0 && self.customers > 1 {
+            cust_key = (cust_key + delta).min(self.customers);
+            delta = -delta;
+        }
+
+        let order_date = rng.range(START_DATE as i64, (END_DATE - 151) as i64) as i32;
+        let priority = rng.pick(&PRIORITIES);
+        let clerk = format!("Clerk#{:09}", rng.range(1, self.clerks));
+        let comment = rng.text(19, 78);
+
+        let num_lines = rng.range(1, 7);
+        let mut lines = Vec::with_capacity(num_lines as usize);
+        let mut total_price = 0;
+        for line_number in 1..=num_lines {
+            let part_key = rng.range(1, self.parts);
+            let supp_key = self.supp_key(part_key, rng.range(0, 3));
+            let quantity = rng.range(1, 50);
+            let extended_price = quantity * Self::retail_price(part_key);
+            let discount = rng.range(0, 10);
+            let tax = rng.range(0, 8);
+            let ship_date = order_date + rng.range(1, 121) as i32;
+            let commit_date = order_date + rng.range(30, 90) as i32;
+            let receipt_date = ship_date + rng.range(1, 30) as i32;
+            let return_flag = match receipt_date <= CURRENT_DATE {
+                true => rng.pick(&["R", "A"]),
+                false => "N",
+            };
+            let line_status = match ship_date > CURRENT_DATE {
+                true => "O",
+                false => "F",
+            };
+
+            total_price += extended_price * (100 + tax) / 100 * (100 - discount) / 100;
+            lines.push(Lineitem {
+                part_key,
+                supp_key,
+                line_number,
+                quantity,
+                extended_price,
+                discount,
+                tax,
+                return_flag,
+                line_status,
+                ship_date,
+                commit_date,
+                receipt_date,
+                ship_instruct: rng.pick(&INSTRUCTIONS),
+                ship_mode: rng.pick(&MODES),
+                comment: rng.text(10, 43),
+            });
+        }
+
+        let status = if lines.iter().all(|line| line.line_status == "F") {
+            "F"
+        } else if lines.iter().all(|line| line.line_status == "O") {
+            "O"
+        } else {
+            "P"
+        };
+
+        let order = Order {
+            order_key,
+            cust_key,
+            status,
+            total_price,
+            order_date,
+            priority,
+            clerk,
+            comment,
+        };
+        (order, lines)
+    }
+
+    fn orders(&self, unit: u64, writer: &mut RowWriter) {
+        let (order, _) = self.order(unit);
+
+        writer.push(&[
+            int64(order.order_key),
+            int64(order.cust_key),
+            string(order.status),
+            decimal(order.total_price),
+            ScalarRef::Date(order.order_date),
+            string(order.priority),
+            string(&order.clerk),
+            int32(0),
+            string(&order.comment),
+        ]);
+    }
+
+    fn lineitem(&self, unit: u64, writer: &mut RowWriter) {
+        let (order, lines) = self.order(unit);
+
+        for line in lines {
+            writer.push(&[
+                int64(order.order_key),
+                int64(line.part_key),
+                int64(line.supp_key),
+                int64(line.line_number),
+                decimal(line.quantity * 100),
+                decimal(line.extended_price),
+                decimal(line.discount),
+                decimal(line.tax),
+                string(line.return_flag),
+                string(line.line_status),
+                ScalarRef::Date(line.ship_date),
+                ScalarRef::Date(line.commit_date),
+                ScalarRef::Date(line.receipt_date),
+                string(line.ship_instruct),
+                string(line.ship_mode),
+                string(&line.comment),
+            ]);
+        }
+    }
+
+    fn nation(&self, unit: u64, writer: &mut RowWriter) {
+        let mut rng = self.rng(unit);
+        let (name, region_key) = NATIONS[unit as usize];
+        let comment = rng.text(31, 114);
+
+        writer.push(&[
+            int32(unit as i32),
+            string(name),
+            int32(region_key),
+            string(&comment),
+        ]);
+    }
+
+    fn region(&self, unit: u64, writer: &mut RowWriter) {
+        let mut rng = self.rng(unit);
+        let comment = rng.text(31, 115);
+
+        writer.push(&[
+            int32(unit as i32),
+            string(REGIONS[unit as usize]),
+            string(&comment),
+        ]);
+    }
+}
+
+/// Replaces the middle of the comment by `Customer ... <word>`, matched by the queries
+/// with `LIKE '%Customer%Complaints%'`.
+fn insert_customer_comment(comment: &mut String, word: &str) {
+    let insert = format!("Customer {}", word);
+    if comment.len() <= insert.len() {
+        *comment = insert;
+        return;
+    }
+    let start = (comment.len() - insert.len()) / 2;
+    comment.replace_range(start..start + insert.len(), &insert);
+}
+
+struct Order {
+    order_key: i64,
+    cust_key: i64,
+    status: &'static str,
+    total_price: i64,
+    order_date: i32,
+    priority: &'static str,
+    clerk: String,
+    comment: String,
+}
+
+struct Lineitem {
+    part_key: i64,
+    supp_key: i64,
+    line_number: i64,
+    quantity: i64,
+    extended_price: i64,
+    discount: i64,
+    tax: i64,
+    return_flag: &'static str,
+    line_status: &'static str,
+    ship_date: i32,
+    commit_date: i32,
+    receipt_date: i32,
+    ship_instruct: &'static str,
+    ship_mode: &'static str,
+    comment: String,
+}
@@ -34,11 +34,13 @@ use common_expression::TableField;
 use common_expression::TableSchema;
 use common_expression::TableSchemaRefExt;
 use common_meta_app::principal::StageFileFormatType;
+use common_meta_app::principal::XlsxFileFormatParams;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
 use common_pipeline_core::processors::ProcessorPtr;
 use common_pipeline_core::Pipeline;
+use common_pipeline_sources::input_formats::infer_xlsx_schema;
 use common_pipeline_sources::AsyncSource;
 use common_pipeline_sources::AsyncSourcer;
 use common_sql::binder::resolve_stage_location;
@@ -223,9 +225,16 @@ impl AsyncSource for InferSchemaSource {
                     TableSchema::try_from(&arrow_schema)?
                 }
             }
+            StageFileFormatType::Xlsx => {
+                let data = operator.read(&first_file.path).await?;
+                infer_xlsx_schema(
+                    &data,
+                    XlsxFileFormatParams::downcast_unchecked(&file_format_params),
+                )?
+            }
             _ => {
                 return Err(ErrorCode::BadArguments(
-                    "infer_schema is currently limited to format Parquet",
+                    "infer_schema is currently limited to format Parquet and Xlsx",
                 ));
             }
         };
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod async_crash_me;
+mod benchmark;
 mod infer_schema;
 mod inspect_parquet;
 mod list_stage;
@@ -24,10 +25,12 @@ mod sync_crash_me;
 mod table_function;
 mod table_function_factory;
 
+pub use benchmark::BenchmarkGenTable;
 pub use numbers::generate_numbers_parts;
 pub use numbers::NumbersPartInfo;
 pub use numbers::NumbersTable;
 pub use openai::GPT2SQLTable;
+pub use others::EraseSubjectTable;
 pub use others::ExecuteBackgroundJobTable;
 pub use others::LicenseInfoTable;
 pub use others::SuggestedBackgroundTasksSource;
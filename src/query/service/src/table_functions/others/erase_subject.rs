@@ -0,0 +1,228 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use chrono::NaiveDateTime;
+use chrono::TimeZone;
+use chrono::Utc;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table_args::TableArgs;
+use common_catalog::table_context::TableContext;
+use common_catalog::table_function::TableFunction;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::BooleanType;
+use common_expression::types::StringType;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRef;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::principal::GrantObject;
+use common_meta_app::principal::UserPrivilegeType;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_pipeline_core::processors::OutputPort;
+use common_pipeline_core::processors::ProcessorPtr;
+use common_pipeline_core::Pipeline;
+use common_pipeline_sources::AsyncSource;
+use common_pipeline_sources::AsyncSourcer;
+use common_storages_factory::Table;
+use common_users::UserApiProvider;
+
+/// `erase_subject(<subject>)` drops the key of the subject, so that the values of the
+/// crypto-shredded columns written for it can't be decrypted anymore.
+pub struct EraseSubjectTable {
+    table_info: TableInfo,
+    subject: String,
+}
+
+impl EraseSubjectTable {
+    pub fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("subject", TableDataType::String),
+            TableField::new("erased", TableDataType::Boolean),
+        ])
+    }
+
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let args = table_args.expect_all_positioned(table_func_name, Some(1))?;
+        let subject = TableArgs::expect_all_strings(args)?.remove(0);
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: String::from("erase_subject"),
+            meta: TableMeta {
+                schema: Self::schema(),
+                engine: String::from(table_func_name),
+                // Assuming that created_on is unnecessary for function table,
+                // we could make created_on fixed to pass test_shuffle_action_try_into.
+                created_on: Utc
+                    .from_utc_datetime(&NaiveDateTime::from_timestamp_opt(0, 0).unwrap()),
+                updated_on: Utc
+                    .from_utc_datetime(&NaiveDateTime::from_timestamp_opt(0, 0).unwrap()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        Ok(Arc::new(EraseSubjectTable {
+            table_info,
+            subject,
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for EraseSubjectTable {
+    fn is_local(&self) -> bool {
+        true
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+        _dry_run: bool,
+    ) -> Result<(PartStatistics, Partitions)> {
+        // dummy statistics
+        Ok((PartStatistics::new_exact(1, 1, 1, 1), Partitions::default()))
+    }
+
+    fn table_args(&self) -> Option<TableArgs> {
+        Some(TableArgs::new_positioned(vec![Scalar::String(
+            self.subject.as_bytes().to_vec(),
+        )]))
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+        _put_cache: bool,
+    ) -> Result<()> {
+        pipeline.add_source(
+            |output| EraseSubjectSource::create(ctx.clone(), output, self.subject.clone()),
+            1,
+        )?;
+
+        Ok(())
+    }
+}
+
+struct EraseSubjectSource {
+    ctx: Arc<dyn TableContext>,
+    subject: String,
+    done: bool,
+}
+
+impl EraseSubjectSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        subject: String,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, EraseSubjectSource {
+            ctx,
+            subject,
+            done: false,
+        })
+    }
+
+    // Erasing a subject affects all the tables of the tenant, so it requires the global
+    // SUPER privilege, either granted to the user or to the current role.
+    fn check_privilege(&self) -> Result<()> {
+        let privileges = vec![UserPrivilegeType::Super];
+        let user_info = self.ctx.get_current_user()?;
+        if user_info
+            .grants
+            .verify_privilege(&GrantObject::Global, privileges.clone())
+        {
+            return Ok(());
+        }
+        if let Some(role) = self.ctx.get_current_role() {
+            if role
+                .grants
+                .verify_privilege(&GrantObject::Global, privileges)
+            {
+                return Ok(());
+            }
+        }
+        Err(ErrorCode::PermissionDenied(format!(
+            "Access denied: '{}' requires the global {} privilege",
+            Self::NAME,
+            UserPrivilegeType::Super
+        )))
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for EraseSubjectSource {
+    const NAME: &'static str = "erase_subject";
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.done {
+            return Ok(None);
+        }
+        self.done = true;
+
+        self.check_privilege()?;
+        let tenant = self.ctx.get_tenant();
+        let erased = UserApiProvider::instance()
+            .drop_subject_key(&tenant, &self.subject)
+            .await?;
+
+        Ok(Some(DataBlock::new_from_columns(vec![
+            StringType::from_data(vec![self.subject.as_bytes().to_vec()]),
+            BooleanType::from_data(vec![erased]),
+        ])))
+    }
+}
+
+impl TableFunction for EraseSubjectTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
@@ -12,12 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod erase_subject;
 mod execute_background_job;
 mod license_info;
 mod suggested_background_compaction_tasks;
 mod suggested_background_tasks;
 mod tenant_quota;
 
+pub use erase_subject::EraseSubjectTable;
 pub use execute_background_job::ExecuteBackgroundJobTable;
 pub use license_info::LicenseInfoTable;
 pub use suggested_background_tasks::SuggestedBackgroundTasksSource;
@@ -30,6 +30,7 @@ use common_exception::Result;
 use common_expression::types::DataType;
 use common_expression::types::NumberDataType;
 use common_expression::types::UInt32Type;
+use common_expression::types::UInt64Type;
 use common_expression::types::ValueType;
 use common_expression::BlockEntry;
 use common_expression::DataBlock;
@@ -74,6 +75,15 @@ impl TenantQuotaTable {
                 "max_files_per_stage",
                 TableDataType::Number(NumberDataType::UInt32),
             ),
+            TableField::new("max_tables", TableDataType::Number(NumberDataType::UInt32)),
+            TableField::new(
+                "max_storage_bytes",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "max_concurrent_queries",
+                TableDataType::Number(NumberDataType::UInt32),
+            ),
         ])
     }
 
@@ -198,6 +208,18 @@ impl TenantQuotaSource {
                     DataType::Number(NumberDataType::UInt32),
                     Value::Scalar(UInt32Type::upcast_scalar(quota.max_files_per_stage)),
                 ),
+                BlockEntry::new(
+                    DataType::Number(NumberDataType::UInt32),
+                    Value::Scalar(UInt32Type::upcast_scalar(quota.max_tables)),
+                ),
+                BlockEntry::new(
+                    DataType::Number(NumberDataType::UInt64),
+                    Value::Scalar(UInt64Type::upcast_scalar(quota.max_storage_bytes)),
+                ),
+                BlockEntry::new(
+                    DataType::Number(NumberDataType::UInt32),
+                    Value::Scalar(UInt32Type::upcast_scalar(quota.max_concurrent_queries)),
+                ),
             ],
             1,
         ))
@@ -210,6 +232,9 @@ impl TenantQuotaSource {
 /// max_tables_per_database: u32
 /// max_stages: u32
 /// max_files_per_stage: u32
+/// max_tables: u32
+/// max_storage_bytes: u64
+/// max_concurrent_queries: u32
 #[async_trait::async_trait]
 impl AsyncSource for TenantQuotaSource {
     const NAME: &'static str = "tenant_quota";
@@ -253,6 +278,15 @@ impl AsyncSource for TenantQuotaSource {
         if let Some(max_files_per_stage) = args.get(4) {
             quota.max_files_per_stage = max_files_per_stage.parse::<u32>()?
         };
+        if let Some(max_tables) = args.get(5) {
+            quota.max_tables = max_tables.parse::<u32>()?;
+        };
+        if let Some(max_storage_bytes) = args.get(6) {
+            quota.max_storage_bytes = max_storage_bytes.parse::<u64>()?;
+        };
+        if let Some(max_concurrent_queries) = args.get(7) {
+            quota.max_concurrent_queries = max_concurrent_queries.parse::<u32>()?;
+        };
 
         quota_api
             .set_quota(&quota, MatchSeq::Exact(res.seq))
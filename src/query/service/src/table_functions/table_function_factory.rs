@@ -25,6 +25,7 @@ use common_storages_stream::stream_status_table_func::StreamStatusTable;
 use itertools::Itertools;
 use parking_lot::RwLock;
 
+use super::EraseSubjectTable;
 use super::ExecuteBackgroundJobTable;
 use super::LicenseInfoTable;
 use super::SuggestedBackgroundTasksTable;
@@ -43,6 +44,7 @@ use crate::table_functions::list_stage::ListStageTable;
 use crate::table_functions::numbers::NumbersTable;
 use crate::table_functions::srf::RangeTable;
 use crate::table_functions::sync_crash_me::SyncCrashMeTable;
+use crate::table_functions::BenchmarkGenTable;
 use crate::table_functions::GPT2SQLTable;
 use crate::table_functions::TableFunction;
 
@@ -201,11 +203,26 @@ impl TableFunctionFactory {
             (next_id(), Arc::new(TenantQuotaTable::create)),
         );
 
+        creators.insert(
+            "erase_subject".to_string(),
+            (next_id(), Arc::new(EraseSubjectTable::create)),
+        );
+
         creators.insert(
             "fuse_encoding".to_string(),
             (next_id(), Arc::new(FuseEncodingTable::create)),
         );
 
+        creators.insert(
+            "tpch_gen".to_string(),
+            (next_id(), Arc::new(BenchmarkGenTable::create)),
+        );
+
+        creators.insert(
+            "tpcds_gen".to_string(),
+            (next_id(), Arc::new(BenchmarkGenTable::create)),
+        );
+
         TableFunctionFactory {
             creators: RwLock::new(creators),
         }
@@ -45,6 +45,12 @@ impl ConfigBuilder {
         ConfigBuilder { conf }
     }
 
+    pub fn with_read_only_mode(&self) -> ConfigBuilder {
+        let mut conf = self.conf.clone();
+        conf.query.read_only = true;
+        ConfigBuilder { conf }
+    }
+
     pub fn api_tls_server_key(mut self, value: impl Into<String>) -> ConfigBuilder {
         self.conf.query.api_tls_server_key = value.into();
         self
@@ -285,6 +285,7 @@ impl TestFixture {
             field_comments: vec!["number".to_string(), "tuple".to_string()],
             as_select: None,
             cluster_key: Some("(id)".to_string()),
+            column_mask_policy: None,
         }
     }
 
@@ -309,6 +310,7 @@ impl TestFixture {
             field_comments: vec!["number".to_string(), "tuple".to_string()],
             as_select: None,
             cluster_key: None,
+            column_mask_policy: None,
         }
     }
 
@@ -344,6 +346,7 @@ impl TestFixture {
             field_comments: vec![],
             as_select: None,
             cluster_key: None,
+            column_mask_policy: None,
         }
     }
 
@@ -388,6 +391,7 @@ impl TestFixture {
             field_comments: vec![],
             as_select: None,
             cluster_key: None,
+            column_mask_policy: None,
         }
     }
 
@@ -124,6 +124,7 @@ async fn test_catalogs_database() -> Result<()> {
                 tenant: tenant.to_string(),
                 db_name: "db1".to_string(),
             },
+            dependent_table_ids: vec![],
         };
         let res = catalog.drop_database(req.clone()).await;
         assert!(res.is_err());
@@ -137,6 +138,7 @@ async fn test_catalogs_database() -> Result<()> {
                 tenant: tenant.to_string(),
                 db_name: "db2".to_string(),
             },
+            dependent_table_ids: vec![],
         };
         let res = catalog.drop_database(req.clone()).await;
         assert!(res.is_ok());
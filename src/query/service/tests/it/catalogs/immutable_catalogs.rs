@@ -59,6 +59,7 @@ async fn test_immutable_catalogs_database() -> Result<()> {
             tenant: tenant.to_string(),
             db_name: "system".to_string(),
         },
+        dependent_table_ids: vec![],
     };
     let drop_db_req = catalog.drop_database(drop_db_req).await;
     assert!(drop_db_req.is_err());
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicU64;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
@@ -40,8 +41,11 @@ use databend_query::test_kits::create_query_context;
 async fn test_always_call_on_finished() -> Result<()> {
     let settings = ExecutorSettings {
         enable_profiling: false,
+        enable_column_arena: false,
         query_id: Arc::new("".to_string()),
         max_execute_time_in_seconds: Default::default(),
+        cpu_time: Arc::new(AtomicU64::new(0)),
+        partial_result: None,
     };
 
     {
@@ -14,9 +14,11 @@
 
 use std::collections::BTreeMap;
 use std::fs::File;
+use std::io::Cursor;
 use std::io::Read;
 use std::time::Duration;
 
+use arrow_ipc::reader::StreamReader;
 use base64::engine::general_purpose;
 use base64::prelude::*;
 use common_base::base::get_free_tcp_port;
@@ -38,6 +40,8 @@ use databend_query::servers::http::v1::ExecuteStateKind;
 use databend_query::servers::http::v1::HttpSessionConf;
 use databend_query::servers::http::v1::QueryError;
 use databend_query::servers::http::v1::QueryResponse;
+use databend_query::servers::http::v1::ARROW_STREAM_CONTENT_TYPE;
+use databend_query::servers::http::v1::PARQUET_CONTENT_TYPE;
 use databend_query::servers::HttpHandler;
 use databend_query::servers::HttpHandlerKind;
 use databend_query::sessions::QueryAffect;
@@ -276,7 +280,7 @@ async fn test_simple_sql() -> Result<()> {
     assert_eq!(result.state, ExecuteStateKind::Succeeded, "{:?}", result);
     assert_eq!(result.next_uri, Some(final_uri.clone()), "{:?}", result);
     assert_eq!(result.data.len(), 10, "{:?}", result);
-    assert_eq!(result.schema.len(), 18, "{:?}", result);
+    assert_eq!(result.schema.len(), 20, "{:?}", result);
 
     // get state
     let uri = make_state_uri(query_id);
@@ -622,6 +626,35 @@ async fn test_result_timeout() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_timeout_overflow_mode_break() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    // one row per block and one second per block, the query is stopped after about 2 rows.
+    let sql = "select number from numbers(10) where sleep(1) = 0";
+    let json = serde_json::json!({ "sql": sql, "pagination": {"wait_time_secs": 5}, "session": { "settings": {
+        "max_threads": "1",
+        "max_block_size": "1",
+        "max_execute_time_in_seconds": "2",
+        "timeout_overflow_mode": "break",
+    }}});
+    let reply = TestHttpQueryRequest::new(json).fetch_total().await?;
+
+    assert!(reply.error().is_none(), "{:?}", reply);
+    assert_eq!(reply.state(), ExecuteStateKind::Succeeded, "{:?}", reply);
+    let num_rows = reply.data().len();
+    assert!(num_rows > 0 && num_rows < 10, "{:?}", reply);
+    assert!(
+        reply
+            .resps
+            .iter()
+            .any(|(_, resp)| !resp.warnings.is_empty()),
+        "{:?}",
+        reply
+    );
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn test_system_tables() -> Result<()> {
     let config = ConfigBuilder::create().build();
@@ -646,11 +679,12 @@ async fn test_system_tables() -> Result<()> {
         .collect::<Vec<_>>();
 
     let skipped = [
-        "credits",      // slow for ci (> 1s) and maybe flaky
-        "metrics",      // QueryError: "Prometheus recorder is not initialized yet"
-        "tasks",        // need to connect grpc server, tested on sqllogic test
-        "task_history", // same with tasks
-        "tracing",      // Could be very large.
+        "credits",       // slow for ci (> 1s) and maybe flaky
+        "metrics",       // QueryError: "Prometheus recorder is not initialized yet"
+        "notifications", // need to connect grpc server, same with tasks
+        "tasks",         // need to connect grpc server, tested on sqllogic test
+        "task_history",  // same with tasks
+        "tracing",       // Could be very large.
     ];
     for table_name in table_names {
         if skipped.contains(&table_name.as_str()) {
@@ -703,6 +737,74 @@ async fn test_insert() -> Result<()> {
     Ok(())
 }
 
+async fn post_sql_accept(ep: &EndpointType, sql: &str, accept: &str) -> Result<Response> {
+    let json = serde_json::json!({ "sql": sql.to_string() });
+    let basic = headers::Authorization::basic("root", "");
+    let req = Request::builder()
+        .uri("/v1/query".parse().unwrap())
+        .method(Method::POST)
+        .header(header::CONTENT_TYPE, "application/json")
+        .header(header::ACCEPT, accept)
+        .typed_header(basic)
+        .body(serde_json::to_vec(&json)?);
+    ep.call(req)
+        .await
+        .map_err(|e| ErrorCode::Internal(e.to_string()))
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_binary_result_format() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let ep = create_endpoint().await?;
+    let sql = "select number, number::string from numbers(10000)";
+
+    // arrow
+    {
+        let response = post_sql_accept(&ep, sql, ARROW_STREAM_CONTENT_TYPE).await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.content_type(), Some(ARROW_STREAM_CONTENT_TYPE));
+        let body = response.into_body().into_vec().await.unwrap();
+        let reader = StreamReader::try_new(Cursor::new(body), None)?;
+        assert_eq!(reader.schema().fields().len(), 2);
+        let mut rows = 0;
+        for batch in reader {
+            rows += batch?.num_rows();
+        }
+        assert_eq!(rows, 10000);
+    }
+
+    // parquet
+    {
+        let response = post_sql_accept(&ep, sql, PARQUET_CONTENT_TYPE).await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.content_type(), Some(PARQUET_CONTENT_TYPE));
+        let body = response.into_body().into_vec().await.unwrap();
+        assert!(body.starts_with(b"PAR1"));
+        assert!(body.ends_with(b"PAR1"));
+    }
+
+    // the query fails before the result is sent
+    {
+        let response =
+            post_sql_accept(&ep, "select * from not_exists", ARROW_STREAM_CONTENT_TYPE).await?;
+        let (status, result) = check_response(response).await?;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(result.state, ExecuteStateKind::Failed);
+        assert!(result.error.is_some());
+    }
+
+    // json is still the default
+    {
+        let response = post_sql_accept(&ep, sql, "application/json").await?;
+        let (status, result) = check_response(response).await?;
+        assert_eq!(status, StatusCode::OK);
+        assert!(result.error.is_none(), "{:?}", result.error);
+    }
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "current_thread")]
 async fn test_query_log() -> Result<()> {
     let config = ConfigBuilder::create().build();
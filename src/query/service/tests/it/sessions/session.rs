@@ -13,9 +13,12 @@
 // limitations under the License.
 
 use common_base::base::tokio;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use databend_query::sessions::SessionManager;
 use databend_query::sessions::SessionType;
+use databend_query::test_kits::create_query_context_with_config;
+use databend_query::test_kits::table_test_fixture::execute_command;
 use databend_query::test_kits::ConfigBuilder;
 use databend_query::test_kits::TestGlobalServices;
 
@@ -68,3 +71,38 @@ async fn test_session_in_management_mode() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_session_in_read_only_mode() -> Result<()> {
+    let conf = ConfigBuilder::create().with_read_only_mode().config();
+    let (_guard, ctx) = create_query_context_with_config(conf, None).await?;
+
+    // Settings.
+    {
+        let settings = ctx.get_settings();
+        assert!(settings.get_enable_query_result_cache()?);
+        assert!(settings.get_query_result_cache_allow_inconsistent()?);
+    }
+
+    // Queries.
+    {
+        execute_command(ctx.clone(), "select * from system.one").await?;
+        execute_command(ctx.clone(), "show tables").await?;
+    }
+
+    // DDL/DML.
+    for sql in [
+        "create table t(a int)",
+        "drop database default",
+        "insert into system.one values(1)",
+    ] {
+        let err = execute_command(ctx.clone(), sql).await.unwrap_err();
+        assert_eq!(
+            err.code(),
+            ErrorCode::READ_ONLY_MODE_PERMISSION_DENIED,
+            "{sql}"
+        );
+    }
+
+    Ok(())
+}
@@ -14,6 +14,7 @@
 
 use common_base::base::tokio;
 use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::types::DataType;
 use common_expression::types::Int32Type;
@@ -73,3 +74,38 @@ async fn test_spill_with_partition() -> Result<()> {
     }
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_spill_with_checksum() -> Result<()> {
+    let fixture = TestFixture::new().await?;
+    let ctx = fixture.new_query_ctx().await?;
+    ctx.get_settings()
+        .set_setting("enable_block_checksum".to_string(), "1".to_string())?;
+    let tenant = ctx.get_tenant();
+    let spiller_config = SpillerConfig::create(query_spill_prefix(&tenant));
+    let operator = DataOperator::instance().operator();
+
+    let mut spiller = Spiller::create(
+        ctx,
+        operator.clone(),
+        spiller_config,
+        SpillerType::HashJoinBuild,
+    );
+
+    let data =
+        DataBlock::new_from_columns(vec![Int32Type::from_data((0..100).collect::<Vec<_>>())]);
+    spiller.spill_with_partition(0_u8, data, 0).await?;
+    let data_blocks = spiller.read_spilled_data(&(0_u8), 0).await?;
+    assert_eq!(data_blocks[0].num_rows(), 100);
+
+    // Corrupt the spilled file.
+    let location = spiller.partition_location.get(&0).unwrap()[0].clone();
+    let mut bytes = operator.read(&location).await?;
+    let last = bytes.len() - 1;
+    bytes[last] ^= 0xff;
+    operator.write(&location, bytes).await?;
+
+    let res = spiller.read_spilled_data(&(0_u8), 0).await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::CHECKSUM_MISMATCH);
+    Ok(())
+}
@@ -430,6 +430,14 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn get_network_progress(&self) -> Arc<Progress> {
+        todo!()
+    }
+
+    fn get_network_progress_value(&self) -> ProgressValues {
+        todo!()
+    }
+
     fn get_status_info(&self) -> String {
         "".to_string()
     }
@@ -645,6 +653,15 @@ impl TableContext for CtxDelegation {
         }
     }
 
+    async fn get_pinned_table(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+    ) -> Result<Arc<dyn Table>> {
+        self.get_table(catalog, database, table).await
+    }
+
     async fn filter_out_copied_files(
         &self,
         _catalog_name: &str,
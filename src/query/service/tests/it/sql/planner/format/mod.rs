@@ -154,6 +154,7 @@ fn test_format() {
                 from_correlated_subquery: false,
                 contain_runtime_filter: false,
                 need_hold_hash_table: false,
+                null_aware: false,
             }
             .into(),
         ),
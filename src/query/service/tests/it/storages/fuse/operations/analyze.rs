@@ -38,6 +38,10 @@ async fn test_fuse_snapshot_analyze() -> Result<()> {
     // Purge will keep at least two snapshots.
     let table = fixture.latest_default_table().await?;
     let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    assert_eq!(
+        fuse_table.analyzed_row_count().await?,
+        Some(table.get_table_info().meta.statistics.number_of_rows)
+    );
     let snapshot_files = fuse_table.list_snapshot_files().await?;
     let table_ctx: Arc<dyn TableContext> = ctx.clone();
     fuse_table
@@ -54,6 +54,7 @@ async fn test_fuse_alter_table_cluster_key() -> common_exception::Result<()> {
         field_comments: vec![],
         as_select: None,
         cluster_key: None,
+        column_mask_policy: None,
     };
 
     // create test table
@@ -411,6 +411,14 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn get_network_progress(&self) -> Arc<Progress> {
+        todo!()
+    }
+
+    fn get_network_progress_value(&self) -> ProgressValues {
+        todo!()
+    }
+
     fn get_status_info(&self) -> String {
         "".to_string()
     }
@@ -600,6 +608,15 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    async fn get_pinned_table(
+        &self,
+        _catalog: &str,
+        _database: &str,
+        _table: &str,
+    ) -> Result<Arc<dyn Table>> {
+        todo!()
+    }
+
     async fn filter_out_copied_files(
         &self,
         _catalog_name: &str,
@@ -22,6 +22,7 @@ mod internal_column;
 mod mutation;
 mod navigate;
 mod optimize;
+mod pinned_snapshot;
 mod purge_drop;
 mod read_plan;
 mod replace_into;
@@ -0,0 +1,78 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::Result;
+use databend_query::sessions::TableContext;
+use databend_query::storages::fuse::FuseTable;
+use databend_query::test_kits::table_test_fixture::execute_query;
+use databend_query::test_kits::table_test_fixture::TestFixture;
+use databend_query::test_kits::utils::query_count;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_pinned_snapshot() -> Result<()> {
+    // - pin the table in a query
+    // - commit to the table by another query
+    // - the first query should still read the pinned snapshot
+
+    // 1. Setup
+    let fixture = TestFixture::new().await?;
+    let catalog = fixture.default_catalog_name();
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    fixture.create_default_table().await?;
+
+    let qry = format!(
+        "insert into {}.{} values (1, (2, 3)), (2, (4, 6)) ",
+        db, tbl
+    );
+    fixture.execute_command(qry.as_str()).await?;
+
+    // 2. pin the table
+    let ctx = fixture.new_query_ctx().await?;
+    let table = ctx.get_pinned_table(&catalog, &db, &tbl).await?;
+    let pinned = FuseTable::try_from_table(table.as_ref())?
+        .read_table_snapshot()
+        .await?
+        .unwrap();
+    assert_eq!(ctx.get_pinned_snapshots(), vec![(
+        format!("{}.{}.{}", catalog, db, tbl),
+        pinned.snapshot_id.simple().to_string()
+    )]);
+
+    // 3. commit by another query
+    let qry = format!("insert into {}.{} values (3, (6, 9)) ", db, tbl);
+    fixture.execute_command(qry.as_str()).await?;
+    let latest = fixture.latest_default_table().await?;
+    let latest = FuseTable::try_from_table(latest.as_ref())?
+        .read_table_snapshot()
+        .await?
+        .unwrap();
+    assert_ne!(latest.snapshot_id, pinned.snapshot_id);
+
+    // 4. the table is still read at the pinned snapshot
+    let table = ctx.get_pinned_table(&catalog, &db, &tbl).await?;
+    let snapshot = FuseTable::try_from_table(table.as_ref())?
+        .read_table_snapshot()
+        .await?
+        .unwrap();
+    assert_eq!(snapshot.snapshot_id, pinned.snapshot_id);
+    assert_eq!(ctx.get_pinned_snapshots().len(), 1);
+
+    let qry = format!("select count(*) from {}.{}", db, tbl);
+    let stream = execute_query(ctx.clone(), qry.as_str()).await?;
+    assert_eq!(2, query_count(stream).await?);
+
+    Ok(())
+}
@@ -39,6 +39,7 @@ use common_functions::aggregates::eval_aggr;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_sql::evaluator::BlockOperator;
 use common_storages_fuse::statistics::reducers::reduce_block_metas;
+use common_storages_fuse::statistics::HistogramBuilder;
 use common_storages_fuse::statistics::Trim;
 use common_storages_fuse::statistics::STATS_REPLACEMENT_CHAR;
 use common_storages_fuse::statistics::STATS_STRING_PREFIX_LEN;
@@ -54,6 +55,7 @@ use opendal::Operator;
 use rand::Rng;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ClusterStatistics;
+use storages_common_table_meta::meta::ColumnHistogramBucket;
 use storages_common_table_meta::meta::ColumnStatistics;
 use storages_common_table_meta::meta::Compression;
 use storages_common_table_meta::meta::Statistics;
@@ -643,3 +645,49 @@ fn test_reduce_block_meta() -> common_exception::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_histogram_builder() {
+    // uniform blocks over [0, 1000)
+    let mut builder = HistogramBuilder::new(0.0, 999.0);
+    for i in 0..10 {
+        builder.add_block(i as f64 * 100.0, i as f64 * 100.0 + 99.0, 100);
+    }
+    let histogram = builder.build(1000, 10).unwrap();
+    assert_eq!(histogram.lower_bound, 0.0);
+    assert_eq!(histogram.buckets.len(), 10);
+    assert_eq!(histogram.buckets.last().unwrap().upper_bound, 999.0);
+    for bucket in histogram.buckets.iter() {
+        assert_eq!(bucket.num_values, 100);
+    }
+    assert!((histogram.buckets[4].upper_bound - 500.0).abs() < 2.0);
+
+    // skewed: most of the values in [0, 10]
+    let mut builder = HistogramBuilder::new(0.0, 1000.0);
+    builder.add_block(0.0, 10.0, 900);
+    builder.add_block(10.0, 1000.0, 100);
+    let histogram = builder.build(1000, 10).unwrap();
+    let total: u64 = histogram.buckets.iter().map(|b| b.num_values).sum();
+    assert_eq!(total, 1000);
+    assert!(histogram.buckets[8].upper_bound < 11.0);
+    assert_eq!(histogram.buckets.last().unwrap().upper_bound, 1000.0);
+
+    // the buckets are bounded by the distinct values
+    let mut builder = HistogramBuilder::new(0.0, 1.0);
+    builder.add_block(0.0, 1.0, 1000);
+    assert_eq!(builder.build(2, 10).unwrap().buckets.len(), 2);
+
+    // a single value
+    let mut builder = HistogramBuilder::new(5.0, 5.0);
+    builder.add_block(5.0, 5.0, 10);
+    let histogram = builder.build(1, 10).unwrap();
+    assert_eq!(histogram.buckets, vec![ColumnHistogramBucket {
+        upper_bound: 5.0,
+        num_values: 10,
+        num_distinct: 1,
+    }]);
+
+    // no values
+    let builder = HistogramBuilder::new(0.0, 1.0);
+    assert!(builder.build(0, 10).is_none());
+}
@@ -12,9 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::fmt::Formatter;
+use std::hash::Hash;
+use std::hash::Hasher;
 use std::sync::Arc;
 
 use common_exception::ErrorCode;
@@ -98,6 +101,21 @@ impl Settings {
         !self.changes.is_empty()
     }
 
+    /// The hash of the changed settings, which differs if any setting is changed.
+    pub fn changes_fingerprint(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        for (key, value) in self
+            .changes
+            .iter()
+            .map(|c| (c.key().clone(), format!("{:?}", c.value().value)))
+            .sorted()
+        {
+            key.hash(&mut hasher);
+            value.hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+
     /// # Safety
     ///
     /// We will not validate the setting value type
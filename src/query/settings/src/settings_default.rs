@@ -65,6 +65,18 @@ impl DefaultSettings {
                     possible_values: None,
                     mode: SettingMode::Both,
                 }),
+                ("enable_block_resize", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables splitting the blocks larger than max_block_size rows or block_resize_max_bytes bytes and merging the blocks smaller than a quarter of them after filters.",
+                    possible_values: None,
+                    mode: SettingMode::Both,
+                }),
+                ("block_resize_max_bytes", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(64 * 1024 * 1024),
+                    desc: "Sets the maximum byte size of the blocks resized after filters.",
+                    possible_values: None,
+                    mode: SettingMode::Both,
+                }),
                 ("parquet_max_block_size", DefaultSettingValue {
                     value: UserSettingValue::UInt64(8192),
                     desc: "Max block size for parquet reader",
@@ -209,6 +221,12 @@ impl DefaultSettings {
                     possible_values: None,
                     mode: SettingMode::Both,
                 }),
+                ("timeout_overflow_mode", DefaultSettingValue {
+                    value: UserSettingValue::String("throw".to_owned()),
+                    desc: "Sets the behavior when max_execute_time_in_seconds is exceeded. \"throw\" aborts the query, \"break\" stops it and returns the rows produced so far with a warning.",
+                    possible_values: Some(vec!["throw", "break"]),
+                    mode: SettingMode::Both,
+                }),
                 ("collation", DefaultSettingValue {
                     value: UserSettingValue::String("binary".to_owned()),
                     desc: "Sets the character collation. Available values include \"binary\" and \"utf8\".",
@@ -269,6 +287,12 @@ impl DefaultSettings {
                     possible_values: None,
                     mode: SettingMode::Both,
                 }),
+                ("enable_plan_cache", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables caching the plans of queries to skip binding and optimizing identical queries.",
+                    possible_values: None,
+                    mode: SettingMode::Both,
+                }),
                 ("query_result_cache_max_bytes", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1048576), // 1MB
                     desc: "Sets the maximum byte size of cache for a single query result.",
@@ -312,6 +336,36 @@ impl DefaultSettings {
                     possible_values: None,
                     mode: SettingMode::Both,
                 }),
+                ("enable_block_checksum", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables xxhash64 checksums of the data blocks sent over the exchange and written to spill files, to detect corrupted data.",
+                    possible_values: None,
+                    mode: SettingMode::Both,
+                }),
+                ("exchange_compression", DefaultSettingValue {
+                    value: UserSettingValue::String(String::from("none")),
+                    desc: "Sets the compression of the data blocks exchanged between nodes, 'auto' adapts it to the network and CPU of each link.",
+                    possible_values: Some(vec!["none", "lz4", "zstd", "auto"]),
+                    mode: SettingMode::Both,
+                }),
+                ("exchange_compression_zstd_level", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(3),
+                    desc: "Sets the zstd level of the exchange compression, the highest level used by 'auto' exchange compression.",
+                    possible_values: None,
+                    mode: SettingMode::Both,
+                }),
+                ("enable_exchange_dictionary", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables dictionary encoding of the low-cardinality string columns exchanged between nodes.",
+                    possible_values: None,
+                    mode: SettingMode::Both,
+                }),
+                ("exchange_dictionary_max_cardinality", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(4096),
+                    desc: "Sets the maximum number of distinct values of a string column in an exchanged block for it to be dictionary encoded.",
+                    possible_values: None,
+                    mode: SettingMode::Both,
+                }),
                 ("group_by_shuffle_mode", DefaultSettingValue {
                     value: UserSettingValue::String(String::from("before_merge")),
                     desc: "Group by shuffle mode, 'before_partial' is more balanced, but more data needs to exchange.",
@@ -324,6 +378,24 @@ impl DefaultSettings {
                     possible_values: None,
                     mode: SettingMode::Both,
                 }),
+                ("enable_group_by_keys_dictionary", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables packing the string group by keys with the other keys into a fixed-width key through a dictionary.",
+                    possible_values: None,
+                    mode: SettingMode::Both,
+                }),
+                ("shared_scan_threshold", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the maximum rows of a table whose scans are shared by the multiple references to it in a query. Setting it to 0 disables scan sharing.",
+                    possible_values: None,
+                    mode: SettingMode::Both,
+                }),
+                ("enable_adaptive_final_aggregate", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables splitting the single level partial aggregation results into buckets to be merged in parallel, if they hold too many groups in total.",
+                    possible_values: None,
+                    mode: SettingMode::Both,
+                }),
                 ("lazy_read_threshold", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1000),
                     desc: "Sets the maximum LIMIT in a query to enable lazy read optimization. Setting it to 0 disables the optimization.",
@@ -471,6 +543,12 @@ impl DefaultSettings {
                     possible_values: None,
                     mode: SettingMode::Both,
                 }),
+                ("enable_column_arena", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables recycling the memory of transient columns through a per-pipeline arena.",
+                    possible_values: None,
+                    mode: SettingMode::Both,
+                }),
                 ("recluster_block_size", DefaultSettingValue {
                     value: UserSettingValue::UInt64(recluster_block_size),
                     desc: "Sets the maximum byte size of blocks for recluster",
@@ -513,12 +591,30 @@ impl DefaultSettings {
                     possible_values: None,
                     mode: SettingMode::Both,
                 }),
+                ("type_coercion_mode", DefaultSettingValue {
+                    value: UserSettingValue::String("default".to_string()),
+                    desc: "Set implicit type coercion mode as \"default\" or \"strict\", strict mode only allows lossless implicit casts.",
+                    possible_values: Some(vec!["default", "strict"]),
+                    mode: SettingMode::Both,
+                }),
                 ("numeric_cast_option", DefaultSettingValue {
                     value: UserSettingValue::String("rounding".to_string()),
                     desc: "Set numeric cast mode as \"rounding\" or \"truncating\".",
                     possible_values: Some(vec!["rounding", "truncating"]),
                     mode: SettingMode::Both,
                 }),
+                ("deletion_block_limit", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the maximum number of blocks a DELETE rewrites, the other matched rows are left to later deletions. 0 means no limit.",
+                    possible_values: None,
+                    mode: SettingMode::Both,
+                }),
+                ("anonymize_crypto_shred_columns", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Reads the crypto-shredded columns as pseudonyms of their values instead of the values, for anonymized exports (disabled by default).",
+                    possible_values: None,
+                    mode: SettingMode::Both,
+                }),
                 ("experiment_enable_stage_udf_priv_check", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "experiment setting disables stage and udf privilege check(disable by default).",
@@ -109,6 +109,14 @@ impl Settings {
         self.try_get_u64("max_block_size")
     }
 
+    pub fn get_enable_block_resize(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_block_resize")? != 0)
+    }
+
+    pub fn get_block_resize_max_bytes(&self) -> Result<usize> {
+        Ok(self.try_get_u64("block_resize_max_bytes")? as usize)
+    }
+
     // Max block size for parquet reader
     pub fn get_parquet_max_block_size(&self) -> Result<u64> {
         self.try_get_u64("parquet_max_block_size")
@@ -183,6 +191,13 @@ impl Settings {
         self.try_get_u64("max_execute_time_in_seconds")
     }
 
+    // Whether to return partial results instead of an error when max_execute_time_in_seconds is exceeded.
+    pub fn get_timeout_overflow_break(&self) -> Result<bool> {
+        Ok(self
+            .try_get_string("timeout_overflow_mode")?
+            .eq_ignore_ascii_case("break"))
+    }
+
     // Get flight client timeout.
     pub fn get_flight_client_timeout(&self) -> Result<u64> {
         self.try_get_u64("flight_client_timeout")
@@ -291,6 +306,14 @@ impl Settings {
         Ok(self.try_get_u64("enable_query_result_cache")? != 0)
     }
 
+    pub fn get_enable_plan_cache(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_plan_cache")? != 0)
+    }
+
+    pub fn set_enable_query_result_cache(&self, val: bool) -> Result<()> {
+        self.try_set_u64("enable_query_result_cache", u64::from(val))
+    }
+
     pub fn get_query_result_cache_max_bytes(&self) -> Result<usize> {
         Ok(self.try_get_u64("query_result_cache_max_bytes")? as usize)
     }
@@ -307,6 +330,10 @@ impl Settings {
         Ok(self.try_get_u64("query_result_cache_allow_inconsistent")? != 0)
     }
 
+    pub fn set_query_result_cache_allow_inconsistent(&self, val: bool) -> Result<()> {
+        self.try_set_u64("query_result_cache_allow_inconsistent", u64::from(val))
+    }
+
     pub fn get_spilling_bytes_threshold_per_proc(&self) -> Result<usize> {
         Ok(self.try_get_u64("spilling_bytes_threshold_per_proc")? as usize)
     }
@@ -315,6 +342,26 @@ impl Settings {
         Ok(self.try_get_u64("spilling_memory_ratio")? as usize)
     }
 
+    pub fn get_enable_block_checksum(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_block_checksum")? != 0)
+    }
+
+    pub fn get_exchange_compression(&self) -> Result<String> {
+        self.try_get_string("exchange_compression")
+    }
+
+    pub fn get_exchange_compression_zstd_level(&self) -> Result<i32> {
+        Ok(self.try_get_u64("exchange_compression_zstd_level")? as i32)
+    }
+
+    pub fn get_enable_exchange_dictionary(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_exchange_dictionary")? != 0)
+    }
+
+    pub fn get_exchange_dictionary_max_cardinality(&self) -> Result<usize> {
+        Ok(self.try_get_u64("exchange_dictionary_max_cardinality")? as usize)
+    }
+
     pub fn get_group_by_shuffle_mode(&self) -> Result<String> {
         self.try_get_string("group_by_shuffle_mode")
     }
@@ -323,6 +370,18 @@ impl Settings {
         Ok(self.try_get_u64("efficiently_memory_group_by")? == 1)
     }
 
+    pub fn get_enable_group_by_keys_dictionary(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_group_by_keys_dictionary")? == 1)
+    }
+
+    pub fn get_shared_scan_threshold(&self) -> Result<u64> {
+        self.try_get_u64("shared_scan_threshold")
+    }
+
+    pub fn get_enable_adaptive_final_aggregate(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_adaptive_final_aggregate")? == 1)
+    }
+
     pub fn get_lazy_read_threshold(&self) -> Result<u64> {
         self.try_get_u64("lazy_read_threshold")
     }
@@ -468,6 +527,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_query_profiling")? != 0)
     }
 
+    pub fn get_enable_column_arena(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_column_arena")? != 0)
+    }
+
     pub fn get_enable_parquet_page_index(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_parquet_page_index")? != 0)
     }
@@ -484,6 +547,22 @@ impl Settings {
         self.try_get_string("numeric_cast_option")
     }
 
+    pub fn get_strict_type_coercion(&self) -> Result<bool> {
+        Ok(self.try_get_string("type_coercion_mode")? == "strict")
+    }
+
+    pub fn get_deletion_block_limit(&self) -> Result<u64> {
+        self.try_get_u64("deletion_block_limit")
+    }
+
+    pub fn set_deletion_block_limit(&self, val: u64) -> Result<()> {
+        self.try_set_u64("deletion_block_limit", val)
+    }
+
+    pub fn get_anonymize_crypto_shred_columns(&self) -> Result<bool> {
+        Ok(self.try_get_u64("anonymize_crypto_shred_columns")? != 0)
+    }
+
     pub fn get_external_server_connect_timeout_secs(&self) -> Result<u64> {
         self.try_get_u64("external_server_connect_timeout_secs")
     }
@@ -26,6 +26,7 @@ use crate::executor::physical_plans::AggregateFinal;
 use crate::executor::physical_plans::AggregateFunctionDesc;
 use crate::executor::physical_plans::AggregatePartial;
 use crate::executor::physical_plans::CommitSink;
+use crate::executor::physical_plans::ConnectBy;
 use crate::executor::physical_plans::ConstantTableScan;
 use crate::executor::physical_plans::CopyIntoTable;
 use crate::executor::physical_plans::CteScan;
@@ -36,8 +37,10 @@ use crate::executor::physical_plans::ExchangeSink;
 use crate::executor::physical_plans::ExchangeSource;
 use crate::executor::physical_plans::Filter;
 use crate::executor::physical_plans::FragmentKind;
+use crate::executor::physical_plans::GapFill;
 use crate::executor::physical_plans::HashJoin;
 use crate::executor::physical_plans::Limit;
+use crate::executor::physical_plans::LimitBy;
 use crate::executor::physical_plans::MaterializedCte;
 use crate::executor::physical_plans::Project;
 use crate::executor::physical_plans::ProjectSet;
@@ -185,7 +188,10 @@ fn to_format_tree(
         PhysicalPlan::AggregateFinal(plan) => aggregate_final_to_format_tree(plan, metadata, profs),
         PhysicalPlan::Window(plan) => window_to_format_tree(plan, metadata, profs),
         PhysicalPlan::Sort(plan) => sort_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::GapFill(plan) => gap_fill_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::ConnectBy(plan) => connect_by_to_format_tree(plan, metadata, profs),
         PhysicalPlan::Limit(plan) => limit_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::LimitBy(plan) => limit_by_to_format_tree(plan, metadata, profs),
         PhysicalPlan::RowFetch(plan) => row_fetch_to_format_tree(plan, metadata, profs),
         PhysicalPlan::HashJoin(plan) => hash_join_to_format_tree(plan, metadata, profs),
         PhysicalPlan::Exchange(plan) => exchange_to_format_tree(plan, metadata, profs),
@@ -484,6 +490,24 @@ fn eval_scalar_to_format_tree(
         FormatTreeNode::new(format!("expressions: [{scalars}]")),
     ];
 
+    if !plan.common_exprs.is_empty() {
+        let common_exprs = plan
+            .common_exprs
+            .iter()
+            .enumerate()
+            .map(|(i, expr)| {
+                format!(
+                    "{} AS __cse_{i}",
+                    expr.as_expr(&BUILTIN_FUNCTIONS).sql_display()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        children.push(FormatTreeNode::new(format!(
+            "common subexpressions: [{common_exprs}]"
+        )));
+    }
+
     if let Some(info) = &plan.stat_info {
         let items = plan_stats_info_to_format_tree(info);
         children.extend(items);
@@ -742,6 +766,83 @@ fn sort_to_format_tree(
     Ok(FormatTreeNode::with_children("Sort".to_string(), children))
 }
 
+fn gap_fill_to_format_tree(
+    plan: &GapFill,
+    metadata: &Metadata,
+    prof_span_set: &SharedProcessorProfiles,
+) -> Result<FormatTreeNode<String>> {
+    let partition_by = plan
+        .partition_by
+        .iter()
+        .map(|index| metadata.column(*index).name())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!(
+            "time column: {}",
+            metadata.column(plan.time_column).name()
+        )),
+        FormatTreeNode::new(format!("interval: {}", plan.interval)),
+        FormatTreeNode::new(format!("partition by: [{partition_by}]")),
+        FormatTreeNode::new(format!("method: {}", plan.method)),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, prof_span_set, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
+
+    Ok(FormatTreeNode::with_children(
+        "GapFill".to_string(),
+        children,
+    ))
+}
+
+fn connect_by_to_format_tree(
+    plan: &ConnectBy,
+    metadata: &Metadata,
+    prof_span_set: &SharedProcessorProfiles,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!(
+            "id column: {}",
+            metadata.column(plan.id_column).name()
+        )),
+        FormatTreeNode::new(format!(
+            "parent column: {}",
+            metadata.column(plan.parent_column).name()
+        )),
+        FormatTreeNode::new(format!("max depth: {}", plan.max_depth)),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, prof_span_set, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
+
+    Ok(FormatTreeNode::with_children(
+        "ConnectBy".to_string(),
+        children,
+    ))
+}
+
 fn limit_to_format_tree(
     plan: &Limit,
     metadata: &Metadata,
@@ -772,6 +873,42 @@ fn limit_to_format_tree(
     Ok(FormatTreeNode::with_children("Limit".to_string(), children))
 }
 
+fn limit_by_to_format_tree(
+    plan: &LimitBy,
+    metadata: &Metadata,
+    prof_span_set: &SharedProcessorProfiles,
+) -> Result<FormatTreeNode<String>> {
+    let by = plan
+        .by
+        .iter()
+        .map(|index| metadata.column(*index).name())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!("limit: {}", plan.limit)),
+        FormatTreeNode::new(format!("by: [{by}]")),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, prof_span_set, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
+
+    Ok(FormatTreeNode::with_children(
+        "LimitBy".to_string(),
+        children,
+    ))
+}
+
 fn row_fetch_to_format_tree(
     plan: &RowFetch,
     metadata: &Metadata,
@@ -865,6 +1002,7 @@ fn range_join_to_format_tree(
         match plan.range_join_type {
             RangeJoinType::IEJoin => "IEJoin".to_string(),
             RangeJoinType::Merge => "MergeJoin".to_string(),
+            RangeJoinType::NestedLoop => "NestedLoopJoin".to_string(),
         },
         children,
     ))
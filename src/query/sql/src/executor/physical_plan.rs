@@ -22,6 +22,7 @@ use crate::executor::physical_plans::AggregateFinal;
 use crate::executor::physical_plans::AggregatePartial;
 use crate::executor::physical_plans::CommitSink;
 use crate::executor::physical_plans::CompactSource;
+use crate::executor::physical_plans::ConnectBy;
 use crate::executor::physical_plans::ConstantTableScan;
 use crate::executor::physical_plans::CopyIntoTable;
 use crate::executor::physical_plans::CteScan;
@@ -32,8 +33,10 @@ use crate::executor::physical_plans::Exchange;
 use crate::executor::physical_plans::ExchangeSink;
 use crate::executor::physical_plans::ExchangeSource;
 use crate::executor::physical_plans::Filter;
+use crate::executor::physical_plans::GapFill;
 use crate::executor::physical_plans::HashJoin;
 use crate::executor::physical_plans::Limit;
+use crate::executor::physical_plans::LimitBy;
 use crate::executor::physical_plans::MaterializedCte;
 use crate::executor::physical_plans::MergeInto;
 use crate::executor::physical_plans::MergeIntoAddRowNumber;
@@ -69,7 +72,10 @@ pub enum PhysicalPlan {
     AggregateFinal(AggregateFinal),
     Window(Window),
     Sort(Sort),
+    GapFill(GapFill),
+    ConnectBy(ConnectBy),
     Limit(Limit),
+    LimitBy(LimitBy),
     RowFetch(RowFetch),
     HashJoin(HashJoin),
     RangeJoin(RangeJoin),
@@ -133,7 +139,10 @@ impl PhysicalPlan {
             PhysicalPlan::AggregateFinal(v) => v.plan_id,
             PhysicalPlan::Window(v) => v.plan_id,
             PhysicalPlan::Sort(v) => v.plan_id,
+            PhysicalPlan::GapFill(v) => v.plan_id,
+            PhysicalPlan::ConnectBy(v) => v.plan_id,
             PhysicalPlan::Limit(v) => v.plan_id,
+            PhysicalPlan::LimitBy(v) => v.plan_id,
             PhysicalPlan::RowFetch(v) => v.plan_id,
             PhysicalPlan::HashJoin(v) => v.plan_id,
             PhysicalPlan::RangeJoin(v) => v.plan_id,
@@ -175,7 +184,10 @@ impl PhysicalPlan {
             PhysicalPlan::AggregateFinal(plan) => plan.output_schema(),
             PhysicalPlan::Window(plan) => plan.output_schema(),
             PhysicalPlan::Sort(plan) => plan.output_schema(),
+            PhysicalPlan::GapFill(plan) => plan.output_schema(),
+            PhysicalPlan::ConnectBy(plan) => plan.output_schema(),
             PhysicalPlan::Limit(plan) => plan.output_schema(),
+            PhysicalPlan::LimitBy(plan) => plan.output_schema(),
             PhysicalPlan::RowFetch(plan) => plan.output_schema(),
             PhysicalPlan::HashJoin(plan) => plan.output_schema(),
             PhysicalPlan::Exchange(plan) => plan.output_schema(),
@@ -218,7 +230,10 @@ impl PhysicalPlan {
             PhysicalPlan::AggregateFinal(_) => "AggregateFinal".to_string(),
             PhysicalPlan::Window(_) => "Window".to_string(),
             PhysicalPlan::Sort(_) => "Sort".to_string(),
+            PhysicalPlan::GapFill(_) => "GapFill".to_string(),
+            PhysicalPlan::ConnectBy(_) => "ConnectBy".to_string(),
             PhysicalPlan::Limit(_) => "Limit".to_string(),
+            PhysicalPlan::LimitBy(_) => "LimitBy".to_string(),
             PhysicalPlan::RowFetch(_) => "RowFetch".to_string(),
             PhysicalPlan::HashJoin(_) => "HashJoin".to_string(),
             PhysicalPlan::Exchange(_) => "Exchange".to_string(),
@@ -270,7 +285,10 @@ impl PhysicalPlan {
             PhysicalPlan::AggregateFinal(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::Window(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::Sort(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::GapFill(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::ConnectBy(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::Limit(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::LimitBy(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::RowFetch(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::HashJoin(plan) => Box::new(
                 std::iter::once(plan.probe.as_ref()).chain(std::iter::once(plan.build.as_ref())),
@@ -321,7 +339,10 @@ impl PhysicalPlan {
             PhysicalPlan::EvalScalar(plan) => plan.input.try_find_single_data_source(),
             PhysicalPlan::Window(plan) => plan.input.try_find_single_data_source(),
             PhysicalPlan::Sort(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::GapFill(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::ConnectBy(plan) => plan.input.try_find_single_data_source(),
             PhysicalPlan::Limit(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::LimitBy(plan) => plan.input.try_find_single_data_source(),
             PhysicalPlan::Exchange(plan) => plan.input.try_find_single_data_source(),
             PhysicalPlan::ExchangeSink(plan) => plan.input.try_find_single_data_source(),
             PhysicalPlan::DistributedInsertSelect(plan) => plan.input.try_find_single_data_source(),
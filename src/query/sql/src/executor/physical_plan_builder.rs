@@ -38,6 +38,8 @@ pub struct PhysicalPlanBuilder {
     pub(crate) dry_run: bool,
     // Record cte_idx and the cte's output columns
     pub(crate) cte_output_columns: HashMap<IndexType, Vec<ColumnBinding>>,
+    // Record cte_idx and all the columns of the cte before pruning, the offsets of cte scans point into them
+    pub(crate) cte_columns: HashMap<IndexType, Vec<ColumnBinding>>,
 }
 
 impl PhysicalPlanBuilder {
@@ -50,6 +52,7 @@ impl PhysicalPlanBuilder {
             func_ctx,
             dry_run,
             cte_output_columns: Default::default(),
+            cte_columns: Default::default(),
         }
     }
 
@@ -91,7 +94,19 @@ impl PhysicalPlanBuilder {
                 self.build_window(s_expr, window, required, stat_info).await
             }
             RelOperator::Sort(sort) => self.build_sort(s_expr, sort, required, stat_info).await,
+            RelOperator::GapFill(gap_fill) => {
+                self.build_gap_fill(s_expr, gap_fill, required, stat_info)
+                    .await
+            }
+            RelOperator::ConnectBy(connect_by) => {
+                self.build_connect_by(s_expr, connect_by, required, stat_info)
+                    .await
+            }
             RelOperator::Limit(limit) => self.build_limit(s_expr, limit, required, stat_info).await,
+            RelOperator::LimitBy(limit_by) => {
+                self.build_limit_by(s_expr, limit_by, required, stat_info)
+                    .await
+            }
             RelOperator::Exchange(exchange) => {
                 self.build_exchange(s_expr, exchange, required).await
             }
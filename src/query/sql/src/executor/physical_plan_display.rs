@@ -24,6 +24,7 @@ use crate::executor::physical_plans::AggregateFinal;
 use crate::executor::physical_plans::AggregatePartial;
 use crate::executor::physical_plans::CommitSink;
 use crate::executor::physical_plans::CompactSource;
+use crate::executor::physical_plans::ConnectBy;
 use crate::executor::physical_plans::ConstantTableScan;
 use crate::executor::physical_plans::CopyIntoTable;
 use crate::executor::physical_plans::CteScan;
@@ -34,8 +35,10 @@ use crate::executor::physical_plans::Exchange;
 use crate::executor::physical_plans::ExchangeSink;
 use crate::executor::physical_plans::ExchangeSource;
 use crate::executor::physical_plans::Filter;
+use crate::executor::physical_plans::GapFill;
 use crate::executor::physical_plans::HashJoin;
 use crate::executor::physical_plans::Limit;
+use crate::executor::physical_plans::LimitBy;
 use crate::executor::physical_plans::MaterializedCte;
 use crate::executor::physical_plans::MergeInto;
 use crate::executor::physical_plans::MergeIntoAddRowNumber;
@@ -84,7 +87,10 @@ impl<'a> Display for PhysicalPlanIndentFormatDisplay<'a> {
             PhysicalPlan::AggregateFinal(aggregate) => write!(f, "{}", aggregate)?,
             PhysicalPlan::Window(window) => write!(f, "{}", window)?,
             PhysicalPlan::Sort(sort) => write!(f, "{}", sort)?,
+            PhysicalPlan::GapFill(gap_fill) => write!(f, "{}", gap_fill)?,
+            PhysicalPlan::ConnectBy(connect_by) => write!(f, "{}", connect_by)?,
             PhysicalPlan::Limit(limit) => write!(f, "{}", limit)?,
+            PhysicalPlan::LimitBy(limit_by) => write!(f, "{}", limit_by)?,
             PhysicalPlan::RowFetch(row_fetch) => write!(f, "{}", row_fetch)?,
             PhysicalPlan::HashJoin(join) => write!(f, "{}", join)?,
             PhysicalPlan::Exchange(exchange) => write!(f, "{}", exchange)?,
@@ -208,6 +214,33 @@ impl Display for Sort {
     }
 }
 
+impl Display for GapFill {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let partition_by = self
+            .partition_by
+            .iter()
+            .map(|index| index.to_string())
+            .collect::<Vec<String>>();
+        write!(
+            f,
+            "GapFill: [{}], Partition by: [{}], Method: [{}]",
+            self.time_column,
+            partition_by.join(", "),
+            self.method
+        )
+    }
+}
+
+impl Display for ConnectBy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ConnectBy: [{}], Parent: [{}], Max depth: [{}]",
+            self.id_column, self.parent_column, self.max_depth
+        )
+    }
+}
+
 impl Display for EvalScalar {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let scalars = self
@@ -319,6 +352,18 @@ impl Display for Limit {
     }
 }
 
+impl Display for LimitBy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let by = self
+            .by
+            .iter()
+            .map(|index| index.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(f, "LimitBy: [{}], By: [{}]", self.limit, by)
+    }
+}
+
 impl Display for RowFetch {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "RowFetch: [{:?}]", self.cols_to_fetch)
@@ -20,6 +20,7 @@ use crate::executor::physical_plans::AggregateFinal;
 use crate::executor::physical_plans::AggregatePartial;
 use crate::executor::physical_plans::CommitSink;
 use crate::executor::physical_plans::CompactSource;
+use crate::executor::physical_plans::ConnectBy;
 use crate::executor::physical_plans::ConstantTableScan;
 use crate::executor::physical_plans::CopyIntoTable;
 use crate::executor::physical_plans::CopyIntoTableSource;
@@ -31,8 +32,10 @@ use crate::executor::physical_plans::Exchange;
 use crate::executor::physical_plans::ExchangeSink;
 use crate::executor::physical_plans::ExchangeSource;
 use crate::executor::physical_plans::Filter;
+use crate::executor::physical_plans::GapFill;
 use crate::executor::physical_plans::HashJoin;
 use crate::executor::physical_plans::Limit;
+use crate::executor::physical_plans::LimitBy;
 use crate::executor::physical_plans::MaterializedCte;
 use crate::executor::physical_plans::MergeInto;
 use crate::executor::physical_plans::MergeIntoAddRowNumber;
@@ -69,7 +72,10 @@ pub trait PhysicalPlanReplacer {
             PhysicalPlan::AggregateFinal(plan) => self.replace_aggregate_final(plan),
             PhysicalPlan::Window(plan) => self.replace_window(plan),
             PhysicalPlan::Sort(plan) => self.replace_sort(plan),
+            PhysicalPlan::GapFill(plan) => self.replace_gap_fill(plan),
+            PhysicalPlan::ConnectBy(plan) => self.replace_connect_by(plan),
             PhysicalPlan::Limit(plan) => self.replace_limit(plan),
+            PhysicalPlan::LimitBy(plan) => self.replace_limit_by(plan),
             PhysicalPlan::RowFetch(plan) => self.replace_row_fetch(plan),
             PhysicalPlan::HashJoin(plan) => self.replace_hash_join(plan),
             PhysicalPlan::Exchange(plan) => self.replace_exchange(plan),
@@ -158,6 +164,7 @@ pub trait PhysicalPlanReplacer {
             projections: plan.projections.clone(),
             input: Box::new(input),
             exprs: plan.exprs.clone(),
+            common_exprs: plan.common_exprs.clone(),
             stat_info: plan.stat_info.clone(),
         }))
     }
@@ -235,6 +242,7 @@ pub trait PhysicalPlanReplacer {
             output_schema: plan.output_schema.clone(),
             contain_runtime_filter: plan.contain_runtime_filter,
             need_hold_hash_table: plan.need_hold_hash_table,
+            null_aware: plan.null_aware,
             stat_info: plan.stat_info.clone(),
         }))
     }
@@ -282,6 +290,40 @@ pub trait PhysicalPlanReplacer {
         }))
     }
 
+    fn replace_gap_fill(&mut self, plan: &GapFill) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+
+        Ok(PhysicalPlan::GapFill(GapFill {
+            plan_id: plan.plan_id,
+            input: Box::new(input),
+            time_column: plan.time_column,
+            partition_by: plan.partition_by.clone(),
+            interval: plan.interval,
+            start: plan.start,
+            end: plan.end,
+            method: plan.method,
+            items: plan.items.clone(),
+            stat_info: plan.stat_info.clone(),
+        }))
+    }
+
+    fn replace_connect_by(&mut self, plan: &ConnectBy) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+
+        Ok(PhysicalPlan::ConnectBy(ConnectBy {
+            plan_id: plan.plan_id,
+            input: Box::new(input),
+            id_column: plan.id_column,
+            parent_column: plan.parent_column,
+            root_column: plan.root_column,
+            max_depth: plan.max_depth,
+            separator: plan.separator.clone(),
+            level_column: plan.level_column,
+            path_column: plan.path_column,
+            stat_info: plan.stat_info.clone(),
+        }))
+    }
+
     fn replace_limit(&mut self, plan: &Limit) -> Result<PhysicalPlan> {
         let input = self.replace(&plan.input)?;
 
@@ -294,6 +336,18 @@ pub trait PhysicalPlanReplacer {
         }))
     }
 
+    fn replace_limit_by(&mut self, plan: &LimitBy) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+
+        Ok(PhysicalPlan::LimitBy(LimitBy {
+            plan_id: plan.plan_id,
+            input: Box::new(input),
+            limit: plan.limit,
+            by: plan.by.clone(),
+            stat_info: plan.stat_info.clone(),
+        }))
+    }
+
     fn replace_row_fetch(&mut self, plan: &RowFetch) -> Result<PhysicalPlan> {
         let input = self.replace(&plan.input)?;
 
@@ -551,9 +605,18 @@ impl PhysicalPlan {
                 PhysicalPlan::Sort(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
+                PhysicalPlan::GapFill(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::ConnectBy(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
                 PhysicalPlan::Limit(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
+                PhysicalPlan::LimitBy(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
                 PhysicalPlan::RowFetch(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
@@ -24,6 +24,8 @@ mod physical_commit_sink;
 pub use physical_commit_sink::CommitSink;
 mod physical_compact_source;
 pub use physical_compact_source::CompactSource;
+mod physical_connect_by;
+pub use physical_connect_by::ConnectBy;
 mod physical_constant_table_scan;
 pub use physical_constant_table_scan::ConstantTableScan;
 mod physical_copy_into;
@@ -46,12 +48,16 @@ mod physical_exchange_source;
 pub use physical_exchange_source::ExchangeSource;
 mod physical_filter;
 pub use physical_filter::Filter;
+mod physical_gap_fill;
+pub use physical_gap_fill::GapFill;
 mod physical_hash_join;
 pub use physical_hash_join::HashJoin;
 mod physical_join;
 pub use physical_join::PhysicalJoinType;
 mod physical_limit;
 pub use physical_limit::Limit;
+mod physical_limit_by;
+pub use physical_limit_by::LimitBy;
 mod physical_materialized_cte;
 pub use physical_materialized_cte::MaterializedCte;
 mod physical_merge_into;
@@ -0,0 +1,91 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::DataField;
+use common_expression::DataSchemaRef;
+use common_expression::DataSchemaRefExt;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::optimizer::SExpr;
+use crate::ColumnSet;
+use crate::IndexType;
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ConnectBy {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+    pub id_column: IndexType,
+    pub parent_column: IndexType,
+    pub root_column: IndexType,
+    pub max_depth: u64,
+    pub separator: String,
+    pub level_column: IndexType,
+    pub path_column: IndexType,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl ConnectBy {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let input_schema = self.input.output_schema()?;
+        let mut fields = input_schema.fields().clone();
+        fields.push(DataField::new(
+            &self.level_column.to_string(),
+            DataType::Number(NumberDataType::UInt64),
+        ));
+        fields.push(DataField::new(
+            &self.path_column.to_string(),
+            DataType::String,
+        ));
+        Ok(DataSchemaRefExt::create(fields))
+    }
+}
+
+impl PhysicalPlanBuilder {
+    pub(crate) async fn build_connect_by(
+        &mut self,
+        s_expr: &SExpr,
+        connect_by: &crate::plans::ConnectBy,
+        mut required: ColumnSet,
+        stat_info: PlanStatsInfo,
+    ) -> Result<PhysicalPlan> {
+        // 1. Prune unused Columns.
+        required.remove(&connect_by.level_column);
+        required.remove(&connect_by.path_column);
+        required.insert(connect_by.id_column);
+        required.insert(connect_by.parent_column);
+        required.insert(connect_by.root_column);
+
+        // 2. Build physical plan.
+        Ok(PhysicalPlan::ConnectBy(ConnectBy {
+            plan_id: self.next_plan_id(),
+            input: Box::new(self.build(s_expr.child(0)?, required).await?),
+            id_column: connect_by.id_column,
+            parent_column: connect_by.parent_column,
+            root_column: connect_by.root_column,
+            max_depth: connect_by.max_depth,
+            separator: connect_by.separator.clone(),
+            level_column: connect_by.level_column,
+            path_column: connect_by.path_column,
+            stat_info: Some(stat_info),
+        }))
+    }
+}
@@ -47,17 +47,17 @@ impl PhysicalPlanBuilder {
         used_columns = required.intersection(&used_columns).cloned().collect();
         let mut pruned_fields = vec![];
         let mut pruned_offsets = vec![];
+        let cte_columns = self.cte_columns.get(&cte_scan.cte_idx.0).unwrap();
         let cte_output_columns = self.cte_output_columns.get(&cte_scan.cte_idx.0).unwrap();
-        for field in cte_scan.fields.iter() {
+        for (field, offset) in cte_scan.fields.iter().zip(cte_scan.offsets.iter()) {
             if used_columns.contains(&field.name().parse()?) {
                 pruned_fields.push(field.clone());
-            }
-        }
-        for field in pruned_fields.iter() {
-            for (offset, col) in cte_output_columns.iter().enumerate() {
-                if col.index.eq(&field.name().parse::<IndexType>()?) {
-                    pruned_offsets.push(offset);
-                    break;
+                let cte_column = cte_columns[*offset].index;
+                for (offset, col) in cte_output_columns.iter().enumerate() {
+                    if col.index.eq(&cte_column) {
+                        pruned_offsets.push(offset);
+                        break;
+                    }
                 }
             }
         }
@@ -12,11 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+
 use common_exception::Result;
 use common_expression::ConstantFolder;
 use common_expression::DataField;
 use common_expression::DataSchemaRef;
 use common_expression::DataSchemaRefExt;
+use common_expression::Expr;
+use common_expression::Function;
 use common_expression::RemoteExpr;
 use common_functions::BUILTIN_FUNCTIONS;
 
@@ -35,6 +39,9 @@ pub struct EvalScalar {
     pub projections: ColumnSet,
     pub input: Box<PhysicalPlan>,
     pub exprs: Vec<(RemoteExpr, IndexType)>,
+    /// Subexpressions shared by `exprs`, evaluated before them and referenced
+    /// as the columns right after the input columns.
+    pub common_exprs: Vec<RemoteExpr>,
 
     /// Only used for explain
     pub stat_info: Option<PlanStatsInfo>,
@@ -52,7 +59,7 @@ impl EvalScalar {
                 fields.push(field.clone());
             }
         }
-        let input_column_nums = input_schema.num_fields();
+        let input_column_nums = input_schema.num_fields() + self.common_exprs.len();
         for (i, (expr, index)) in self.exprs.iter().enumerate() {
             let i = i + input_column_nums;
             if !self.projections.contains(&i) {
@@ -115,27 +122,37 @@ impl PhysicalPlanBuilder {
                     .type_check(input_schema.as_ref())?
                     .project_column_ref(|index| input_schema.index_of(&index.to_string()).unwrap());
                 let (expr, _) = ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
-                Ok((expr.as_remote_expr(), item.index))
+                Ok((expr, item.index))
             })
             .collect::<Result<Vec<_>>>()?;
 
-        let exprs = exprs
+        let mut exprs = exprs
             .into_iter()
             .filter(|(scalar, idx)| {
-                if let RemoteExpr::ColumnRef { id, .. } = scalar {
+                if let Expr::ColumnRef { id, .. } = scalar {
                     return idx.to_string() != input_schema.field(*id).name().as_str();
                 }
                 true
             })
             .collect::<Vec<_>>();
 
+        let common_exprs = eliminate_common_subexprs(&mut exprs, input_schema.num_fields());
+        let exprs = exprs
+            .into_iter()
+            .map(|(expr, idx)| (expr.as_remote_expr(), idx))
+            .collect::<Vec<_>>();
+        let common_exprs = common_exprs
+            .iter()
+            .map(|expr| expr.as_remote_expr())
+            .collect::<Vec<_>>();
+
         let mut projections = ColumnSet::new();
         for column in column_projections.iter() {
             if let Ok(index) = input_schema.index_of(&column.to_string()) {
                 projections.insert(index);
             }
         }
-        let input_column_nums = input_schema.num_fields();
+        let input_column_nums = input_schema.num_fields() + common_exprs.len();
         for (index, (_, idx)) in exprs.iter().enumerate() {
             if column_projections.contains(idx) {
                 projections.insert(index + input_column_nums);
@@ -146,7 +163,132 @@ impl PhysicalPlanBuilder {
             projections,
             input: Box::new(input),
             exprs,
+            common_exprs,
             stat_info: Some(stat_info),
         }))
     }
 }
+
+/// Hoists the subexpressions appearing more than once in `exprs` out, so that they
+/// are evaluated only once per block. The occurrences are replaced by references to
+/// the columns of the hoisted subexpressions, which follow the input columns.
+fn eliminate_common_subexprs(
+    exprs: &mut [(Expr, IndexType)],
+    input_column_nums: usize,
+) -> Vec<Expr> {
+    let mut counts = HashMap::new();
+    for (expr, _) in exprs.iter() {
+        count_subexprs(expr, &mut counts);
+    }
+    if counts.values().all(|count| *count < 2) {
+        return vec![];
+    }
+
+    let mut common = CommonSubexprs {
+        counts,
+        input_column_nums,
+        exprs: vec![],
+        columns: HashMap::new(),
+    };
+    for (expr, _) in exprs.iter_mut() {
+        *expr = common.rewrite(expr, 1);
+    }
+    common.exprs
+}
+
+fn is_common_subexpr_candidate(expr: &Expr) -> bool {
+    matches!(expr, Expr::FunctionCall { .. } | Expr::Cast { .. })
+        && !expr.column_refs().is_empty()
+        && expr.is_deterministic(&BUILTIN_FUNCTIONS)
+}
+
+// The arguments of the conditional functions are evaluated lazily, hoisting them
+// would evaluate them on rows they were not supposed to.
+fn is_conditional(function: &Function) -> bool {
+    matches!(function.signature.name.as_str(), "if" | "and_filters")
+}
+
+fn count_subexprs(expr: &Expr, counts: &mut HashMap<Expr, usize>) {
+    if is_common_subexpr_candidate(expr) {
+        *counts.entry(expr.clone()).or_default() += 1;
+    }
+    match expr {
+        Expr::FunctionCall { function, args, .. } if !is_conditional(function) => {
+            for arg in args {
+                count_subexprs(arg, counts);
+            }
+        }
+        Expr::Cast { expr, .. } => count_subexprs(expr, counts),
+        _ => {}
+    }
+}
+
+struct CommonSubexprs {
+    counts: HashMap<Expr, usize>,
+    input_column_nums: usize,
+    exprs: Vec<Expr>,
+    columns: HashMap<Expr, usize>,
+}
+
+impl CommonSubexprs {
+    // `parent_count` is the occurrences of the nearest hoisted ancestor, the subexpressions
+    // only appearing within it are evaluated once anyway.
+    fn rewrite(&mut self, expr: &Expr, parent_count: usize) -> Expr {
+        let count = self.counts.get(expr).copied().unwrap_or_default();
+        if count < 2 || count <= parent_count {
+            return self.rewrite_args(expr, parent_count);
+        }
+
+        let id = match self.columns.get(expr) {
+            Some(id) => *id,
+            None => {
+                let hoisted = self.rewrite_args(expr, count);
+                let id = self.input_column_nums + self.exprs.len();
+                self.exprs.push(hoisted);
+                self.columns.insert(expr.clone(), id);
+                id
+            }
+        };
+        Expr::ColumnRef {
+            span: None,
+            id,
+            data_type: expr.data_type().clone(),
+            display_name: format!("__cse_{}", id - self.input_column_nums),
+        }
+    }
+
+    fn rewrite_args(&mut self, expr: &Expr, parent_count: usize) -> Expr {
+        match expr {
+            Expr::FunctionCall {
+                span,
+                id,
+                function,
+                generics,
+                args,
+                return_type,
+            } if !is_conditional(function) => Expr::FunctionCall {
+                span: *span,
+                id: id.clone(),
+                function: function.clone(),
+                generics: generics.clone(),
+                args: args
+                    .iter()
+                    .map(|arg| self.rewrite(arg, parent_count))
+                    .collect(),
+                return_type: return_type.clone(),
+            },
+            Expr::Cast {
+                span,
+                is_try,
+                expr,
+                dest_type,
+            } => Expr::Cast {
+                span: *span,
+                is_try: *is_try,
+                expr: Box::new(self.rewrite(expr, parent_count)),
+                dest_type: dest_type.clone(),
+            },
+            _ => expr.clone(),
+        }
+    }
+}
@@ -0,0 +1,100 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::DataField;
+use common_expression::DataSchemaRef;
+use common_expression::DataSchemaRefExt;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::optimizer::SExpr;
+use crate::plans::GapFillItem;
+use crate::plans::GapFillMethod;
+use crate::ColumnSet;
+use crate::IndexType;
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct GapFill {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+    pub time_column: IndexType,
+    pub partition_by: Vec<IndexType>,
+    pub interval: i64,
+    pub start: Option<i64>,
+    pub end: Option<i64>,
+    pub method: GapFillMethod,
+    pub items: Vec<GapFillItem>,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl GapFill {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let input_schema = self.input.output_schema()?;
+        let fields = input_schema
+            .fields()
+            .iter()
+            .map(|field| {
+                match self
+                    .items
+                    .iter()
+                    .find(|item| item.input.to_string() == *field.name())
+                {
+                    // The filled columns keep their positions, but are renamed and made nullable.
+                    Some(item) => {
+                        DataField::new(&item.output.to_string(), field.data_type().wrap_nullable())
+                    }
+                    None => field.clone(),
+                }
+            })
+            .collect::<Vec<_>>();
+        Ok(DataSchemaRefExt::create(fields))
+    }
+}
+
+impl PhysicalPlanBuilder {
+    pub(crate) async fn build_gap_fill(
+        &mut self,
+        s_expr: &SExpr,
+        gap_fill: &crate::plans::GapFill,
+        mut required: ColumnSet,
+        stat_info: PlanStatsInfo,
+    ) -> Result<PhysicalPlan> {
+        // 1. Prune unused Columns.
+        for item in gap_fill.items.iter() {
+            required.remove(&item.output);
+            required.insert(item.input);
+        }
+        required.insert(gap_fill.time_column);
+        required.extend(gap_fill.partition_by.iter().cloned());
+
+        // 2. Build physical plan.
+        Ok(PhysicalPlan::GapFill(GapFill {
+            plan_id: self.next_plan_id(),
+            input: Box::new(self.build(s_expr.child(0)?, required).await?),
+            time_column: gap_fill.time_column,
+            partition_by: gap_fill.partition_by.clone(),
+            interval: gap_fill.interval,
+            start: gap_fill.start,
+            end: gap_fill.end,
+            method: gap_fill.method,
+            items: gap_fill.items.clone(),
+            stat_info: Some(stat_info),
+        }))
+    }
+}
@@ -66,6 +66,8 @@ pub struct HashJoin {
     // if we execute distributed merge into, we need to hold the
     // hash table to get not match data from source.
     pub need_hold_hash_table: bool,
+    // The anti join of `NOT IN` with a nullable key, see `Join::null_aware`.
+    pub null_aware: bool,
 
     // Only used for explain
     pub stat_info: Option<PlanStatsInfo>,
@@ -400,6 +402,7 @@ impl PhysicalPlanBuilder {
             output_schema,
             contain_runtime_filter: join.contain_runtime_filter,
             need_hold_hash_table: join.need_hold_hash_table,
+            null_aware: join.null_aware,
             stat_info: Some(stat_info),
         }))
     }
@@ -12,23 +12,35 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use common_exception::Result;
 
+use crate::binder::split_conjunctions;
 use crate::binder::JoinPredicate;
 use crate::executor::explain::PlanStatsInfo;
 use crate::executor::PhysicalPlan;
 use crate::executor::PhysicalPlanBuilder;
+use crate::optimizer::base_column_indices;
+use crate::optimizer::column_indices;
+use crate::optimizer::derive_foreign_keys;
+use crate::optimizer::derive_unique_keys;
 use crate::optimizer::RelExpr;
 use crate::optimizer::RelationalProperty;
 use crate::optimizer::SExpr;
+use crate::plans::Filter;
+use crate::plans::FunctionCall;
 use crate::plans::Join;
 use crate::plans::JoinType;
+use crate::plans::RelOperator;
 use crate::ColumnSet;
+use crate::IndexType;
 use crate::ScalarExpr;
 
 pub enum PhysicalJoinType {
     Hash,
-    // The first arg is range conditions, the second arg is other conditions
+    // The first arg is range conditions, the second arg is other conditions.
+    // Range conditions are empty for nested loop join.
     RangeJoin(Vec<ScalarExpr>, Vec<ScalarExpr>),
 }
 
@@ -43,9 +55,11 @@ pub fn physical_join(join: &Join, s_expr: &SExpr) -> Result<PhysicalJoinType> {
     let right_prop = RelExpr::with_s_expr(s_expr.child(0)?).derive_relational_prop()?;
     let mut range_conditions = vec![];
     let mut other_conditions = vec![];
-    for condition in join.non_equi_conditions.iter() {
+    // Band predicates such as `a.ts BETWEEN b.start AND b.end` are bound as a single
+    // conjunction, split them so that both bounds can be used as range conditions.
+    for condition in join.non_equi_conditions.iter().flat_map(split_conjunctions) {
         check_condition(
-            condition,
+            &condition,
             &left_prop,
             &right_prop,
             &mut range_conditions,
@@ -53,7 +67,11 @@ pub fn physical_join(join: &Join, s_expr: &SExpr) -> Result<PhysicalJoinType> {
         )
     }
 
-    if !range_conditions.is_empty() && matches!(join.join_type, JoinType::Inner | JoinType::Cross) {
+    // Without any range condition, arbitrary non-equi conditions are evaluated by a
+    // blocked nested loop join, which is a range join without range conditions.
+    if (!range_conditions.is_empty() || !other_conditions.is_empty())
+        && matches!(join.join_type, JoinType::Inner | JoinType::Cross)
+    {
         return Ok(PhysicalJoinType::RangeJoin(
             range_conditions,
             other_conditions,
@@ -86,6 +104,7 @@ fn check_condition(
                 JoinPredicate::Left(_) => left = true,
                 JoinPredicate::Right(_) => right = true,
                 JoinPredicate::Both { .. } | JoinPredicate::Other(_) | JoinPredicate::ALL(_) => {
+                    other_conditions.push(expr.clone());
                     return;
                 }
             }
@@ -106,6 +125,39 @@ impl PhysicalPlanBuilder {
         required: ColumnSet,
         stat_info: PlanStatsInfo,
     ) -> Result<PhysicalPlan> {
+        // The left outer join keeps every row of the left side exactly once if the right side
+        // has at most one match, it can be eliminated if no column of the right side is required.
+        if self.is_redundant_left_join(s_expr, join, &required)? {
+            return self.build(s_expr.child(0)?, required).await;
+        }
+        // The inner join of a foreign key to the referenced table keeps every row of the
+        // referencing side having no NULL in the foreign key exactly once, the referenced
+        // side can be eliminated if no column of it is required.
+        if let Some((child, nullable_keys)) =
+            self.redundant_inner_join_side(s_expr, join, &required)?
+        {
+            let input = s_expr.child(child)?;
+            if nullable_keys.is_empty() {
+                return self.build(input, required).await;
+            }
+            let predicates = nullable_keys
+                .into_iter()
+                .map(|key| {
+                    ScalarExpr::FunctionCall(FunctionCall {
+                        span: None,
+                        func_name: "is_not_null".to_string(),
+                        params: vec![],
+                        arguments: vec![key],
+                    })
+                })
+                .collect();
+            let filter = SExpr::create_unary(
+                Arc::new(Filter { predicates }.into()),
+                Arc::new(input.clone()),
+            );
+            return self.build(&filter, required).await;
+        }
+
         // 1. Prune unused Columns.
         let column_projections = required.clone().into_iter().collect::<Vec<_>>();
         let others_required = join
@@ -156,4 +208,151 @@ impl PhysicalPlanBuilder {
             }
         }
     }
+
+    fn is_redundant_left_join(
+        &self,
+        s_expr: &SExpr,
+        join: &Join,
+        required: &ColumnSet,
+    ) -> Result<bool> {
+        if join.join_type != JoinType::Left
+            || join.right_conditions.is_empty()
+            || join.from_correlated_subquery
+            || join.need_hold_hash_table
+        {
+            return Ok(false);
+        }
+        let right = s_expr.child(1)?;
+        let right_prop = RelExpr::with_s_expr(right).derive_relational_prop()?;
+        if required
+            .iter()
+            .any(|column| right_prop.output_columns.contains(column))
+        {
+            return Ok(false);
+        }
+
+        let mut right_keys = ColumnSet::new();
+        for condition in join.right_conditions.iter() {
+            match condition {
+                ScalarExpr::BoundColumnRef(column) => {
+                    right_keys.insert(column.column.index);
+                }
+                _ => return Ok(false),
+            }
+        }
+        Ok(derive_unique_keys(right, &self.metadata)
+            .iter()
+            .any(|key| key.is_subset(&right_keys)))
+    }
+
+    /// Returns the referencing side of an inner join on a foreign key and the nullable
+    /// join keys of it, if the other side reads a whole table unique on the join keys and
+    /// none of its columns is required.
+    fn redundant_inner_join_side(
+        &self,
+        s_expr: &SExpr,
+        join: &Join,
+        required: &ColumnSet,
+    ) -> Result<Option<(usize, Vec<ScalarExpr>)>> {
+        if join.join_type != JoinType::Inner
+            || join.left_conditions.is_empty()
+            || !join.non_equi_conditions.is_empty()
+            || join.from_correlated_subquery
+            || join.need_hold_hash_table
+        {
+            return Ok(None);
+        }
+        let (Some(left_keys), Some(right_keys)) = (
+            column_indices(&join.left_conditions),
+            column_indices(&join.right_conditions),
+        ) else {
+            return Ok(None);
+        };
+
+        let sides = [
+            (0, &left_keys, &right_keys, &join.left_conditions),
+            (1, &right_keys, &left_keys, &join.right_conditions),
+        ];
+        for (child, keys, ref_keys, conditions) in sides {
+            let referenced = s_expr.child(1 - child)?;
+            let referenced_prop = RelExpr::with_s_expr(referenced).derive_relational_prop()?;
+            if required
+                .iter()
+                .any(|column| referenced_prop.output_columns.contains(column))
+            {
+                continue;
+            }
+            let Some(ref_table_index) = whole_table_scan(referenced) else {
+                continue;
+            };
+            let ref_key_set = ref_keys.iter().cloned().collect::<ColumnSet>();
+            if !derive_unique_keys(referenced, &self.metadata)
+                .iter()
+                .any(|key| key.is_subset(&ref_key_set))
+            {
+                continue;
+            }
+
+            let ref_table_id = self.metadata.read().table(ref_table_index).table().get_id();
+            let mut join_pairs = keys
+                .iter()
+                .cloned()
+                .zip(ref_keys.iter().cloned())
+                .collect::<Vec<_>>();
+            join_pairs.sort();
+            let is_foreign_key = derive_foreign_keys(s_expr.child(child)?, &self.metadata)
+                .into_iter()
+                .any(|foreign_key| {
+                    if foreign_key.ref_table_id != ref_table_id {
+                        return false;
+                    }
+                    let Some(ref_columns) = base_column_indices(
+                        &self.metadata,
+                        ref_table_index,
+                        &foreign_key.ref_columns,
+                    ) else {
+                        return false;
+                    };
+                    let mut pairs = foreign_key
+                        .columns
+                        .into_iter()
+                        .zip(ref_columns)
+                        .collect::<Vec<_>>();
+                    pairs.sort();
+                    pairs == join_pairs
+                });
+            if !is_foreign_key {
+                continue;
+            }
+
+            let mut nullable_keys = vec![];
+            for condition in conditions.iter() {
+                if condition.data_type()?.is_nullable_or_null() {
+                    nullable_keys.push(condition.clone());
+                }
+            }
+            return Ok(Some((child, nullable_keys)));
+        }
+        Ok(None)
+    }
+}
+
+/// The index of the table if the expression reads all the rows of it.
+fn whole_table_scan(s_expr: &SExpr) -> Option<IndexType> {
+    match s_expr.plan() {
+        RelOperator::Scan(scan)
+            if scan
+                .push_down_predicates
+                .as_ref()
+                .map_or(true, |predicates| predicates.is_empty())
+                && scan.prewhere.is_none()
+                && scan.limit.is_none()
+                && scan.agg_index.is_none()
+                && scan.bucket_info.is_none() =>
+        {
+            Some(scan.table_index)
+        }
+        RelOperator::EvalScalar(_) => whole_table_scan(s_expr.child(0).ok()?),
+        _ => None,
+    }
 }
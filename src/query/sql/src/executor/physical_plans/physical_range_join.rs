@@ -41,6 +41,7 @@ pub struct RangeJoin {
     pub right: Box<PhysicalPlan>,
     // The first two conditions: (>, >=, <, <=)
     // Condition's left/right side only contains one table's column
+    // Empty if `range_join_type` is `NestedLoop`
     pub conditions: Vec<RangeJoinCondition>,
     // The other conditions
     pub other_conditions: Vec<RemoteExpr>,
@@ -64,6 +65,8 @@ impl RangeJoin {
 pub enum RangeJoinType {
     IEJoin,
     Merge,
+    // Evaluate other conditions on the cross product of each pair of blocks
+    NestedLoop,
 }
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
@@ -86,9 +89,11 @@ impl PhysicalPlanBuilder {
         let left_prop = RelExpr::with_s_expr(s_expr.child(1)?).derive_relational_prop()?;
         let right_prop = RelExpr::with_s_expr(s_expr.child(0)?).derive_relational_prop()?;
 
-        debug_assert!(!range_conditions.is_empty());
+        debug_assert!(!range_conditions.is_empty() || !other_conditions.is_empty());
 
-        let range_join_type = if range_conditions.len() >= 2 {
+        let range_join_type = if range_conditions.is_empty() {
+            RangeJoinType::NestedLoop
+        } else if range_conditions.len() >= 2 {
             // Contain more than 2 ie conditions, use ie join
             while range_conditions.len() > 2 {
                 other_conditions.push(range_conditions.pop().unwrap());
@@ -99,8 +104,18 @@ impl PhysicalPlanBuilder {
         };
 
         // Construct IEJoin
-        let left_side = self.build(s_expr.child(1)?, left_required).await?;
-        let right_side = self.build(s_expr.child(0)?, right_required).await?;
+        // Nested loop join caches the right side and streams the left side against it, so the
+        // right side is the right child, which is the smaller one like the build side of hash join.
+        let (left_side, right_side) = match range_join_type {
+            RangeJoinType::NestedLoop => (
+                self.build(s_expr.child(0)?, left_required).await?,
+                self.build(s_expr.child(1)?, right_required).await?,
+            ),
+            _ => (
+                self.build(s_expr.child(1)?, left_required).await?,
+                self.build(s_expr.child(0)?, right_required).await?,
+            ),
+        };
 
         let left_schema = left_side.output_schema()?;
         let right_schema = right_side.output_schema()?;
@@ -22,6 +22,7 @@ use common_catalog::catalog::CATALOG_DEFAULT;
 use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::Filters;
 use common_catalog::plan::InternalColumn;
+use common_catalog::plan::PartitionsShuffleKind;
 use common_catalog::plan::PrewhereInfo;
 use common_catalog::plan::Projection;
 use common_catalog::plan::PushDownInfo;
@@ -41,6 +42,7 @@ use common_expression::TableSchemaRef;
 use common_expression::ROW_ID_COL_NAME;
 use common_functions::BUILTIN_FUNCTIONS;
 use itertools::Itertools;
+use storages_common_table_meta::table::CryptoShredColumns;
 
 use crate::binder::INTERNAL_COLUMN_FACTORY;
 use crate::executor::cast_expr_to_non_null_boolean;
@@ -249,6 +251,21 @@ impl PhysicalPlanBuilder {
             let agg_index = Self::build_agg_index(agg_index, &output_fields)?;
             push_down.agg_index = Some(agg_index);
         }
+        if scan.bucket_info.is_some() && scan.agg_index.is_none() && !source.parts.is_lazy {
+            // The rows are distributed by the buckets instead of being shuffled.
+            if source
+                .parts
+                .partitions
+                .iter()
+                .any(|part| part.bucket().is_none())
+            {
+                return Err(ErrorCode::Internal(format!(
+                    "Partitions of bucketed table {} have no bucket",
+                    table.name()
+                )));
+            }
+            source.parts.kind = PartitionsShuffleKind::Bucket;
+        }
         let internal_column = if project_internal_columns.is_empty() {
             None
         } else {
@@ -438,7 +455,7 @@ impl PhysicalPlanBuilder {
             })
             .transpose()?;
 
-        let order_by = scan
+        let mut order_by = scan
             .order_by
             .clone()
             .map(|items| {
@@ -484,6 +501,18 @@ impl PhysicalPlanBuilder {
             })
             .transpose()?;
 
+        // The crypto-shredded columns are encrypted in storage, the top-k can't be pushed down.
+        let mut limit = scan.limit;
+        let table = metadata.table(scan.table_index).table();
+        if let Some(shred_columns) = CryptoShredColumns::from_options(table.options())? {
+            if order_by.iter().flatten().any(|(scalar, _, _)| {
+                matches!(scalar, RemoteExpr::ColumnRef { id, .. } if shred_columns.contains(id))
+            }) {
+                order_by = None;
+                limit = None;
+            }
+        }
+
         let virtual_columns = self.build_virtual_columns(&scan.columns);
 
         Ok(PushDownInfo {
@@ -492,7 +521,7 @@ impl PhysicalPlanBuilder {
             filters: push_down_filter,
             is_deterministic,
             prewhere: prewhere_info,
-            limit: scan.limit,
+            limit,
             order_by: order_by.unwrap_or_default(),
             virtual_columns,
             lazy_materialization: !metadata.lazy_columns().is_empty(),
@@ -63,11 +63,23 @@ impl PhysicalPlanBuilder {
         stat_info: PlanStatsInfo,
     ) -> Result<PhysicalPlan> {
         // 1. Prune unused Columns.
-        let left_required = union_all.pairs.iter().fold(required.clone(), |mut acc, v| {
+        // Output columns of union all are the left columns of pairs, the pairs whose
+        // output column isn't required are pruned, so the unused columns of both
+        // children are pruned as well. At least one pair is kept to carry the rows.
+        let mut union_pairs = union_all
+            .pairs
+            .iter()
+            .filter(|(left, _)| required.contains(left))
+            .cloned()
+            .collect::<Vec<_>>();
+        if union_pairs.is_empty() {
+            union_pairs.extend(union_all.pairs.first().cloned());
+        }
+        let left_required = union_pairs.iter().fold(required.clone(), |mut acc, v| {
             acc.insert(v.0);
             acc
         });
-        let right_required = union_all.pairs.iter().fold(required, |mut acc, v| {
+        let right_required = union_pairs.iter().fold(required, |mut acc, v| {
             acc.insert(v.1);
             acc
         });
@@ -78,7 +90,7 @@ impl PhysicalPlanBuilder {
         let left_schema = left_plan.output_schema()?;
         let right_schema = right_plan.output_schema()?;
 
-        let common_types = union_all.pairs.iter().map(|(l, r)| {
+        let common_types = union_pairs.iter().map(|(l, r)| {
             let left_field = left_schema.field_with_name(&l.to_string()).unwrap();
             let right_field = right_schema.field_with_name(&r.to_string()).unwrap();
 
@@ -147,8 +159,8 @@ impl PhysicalPlanBuilder {
             Ok(new_plan)
         }
 
-        let left_indexes = union_all.pairs.iter().map(|(l, _)| *l).collect::<Vec<_>>();
-        let right_indexes = union_all.pairs.iter().map(|(_, r)| *r).collect::<Vec<_>>();
+        let left_indexes = union_pairs.iter().map(|(l, _)| *l).collect::<Vec<_>>();
+        let right_indexes = union_pairs.iter().map(|(_, r)| *r).collect::<Vec<_>>();
         let left_plan = cast_plan(
             self,
             left_plan,
@@ -127,6 +127,7 @@ pub enum LagLeadDefault {
 pub struct LagLeadFunctionDesc {
     pub is_lag: bool,
     pub offset: u64,
+    pub ignore_null: bool,
     pub arg: usize,
     pub return_type: DataType,
     pub default: LagLeadDefault,
@@ -135,6 +136,7 @@ pub struct LagLeadFunctionDesc {
 #[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct NthValueFunctionDesc {
     pub n: Option<u64>,
+    pub ignore_null: bool,
     pub arg: usize,
     pub return_type: DataType,
 }
@@ -315,6 +317,7 @@ impl PhysicalPlanBuilder {
                 WindowFunction::LagLead(LagLeadFunctionDesc {
                     is_lag: lag_lead.is_lag,
                     offset: lag_lead.offset,
+                    ignore_null: lag_lead.ignore_null,
                     return_type: *lag_lead.return_type.clone(),
                     arg: if let ScalarExpr::BoundColumnRef(col) = *lag_lead.arg.clone() {
                         Ok(col.column.index)
@@ -329,6 +332,7 @@ impl PhysicalPlanBuilder {
 
             WindowFuncType::NthValue(func) => WindowFunction::NthValue(NthValueFunctionDesc {
                 n: func.n,
+                ignore_null: func.ignore_null,
                 return_type: *func.return_type.clone(),
                 arg: if let ScalarExpr::BoundColumnRef(col) = &*func.arg {
                     Ok(col.column.index)
@@ -16,12 +16,15 @@ use common_exception::Result;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_profile::AggregateAttribute;
 use common_profile::AggregateExpandAttribute;
+use common_profile::ConnectByAttribute;
 use common_profile::CteScanAttribute;
 use common_profile::EvalScalarAttribute;
 use common_profile::ExchangeAttribute;
 use common_profile::FilterAttribute;
+use common_profile::GapFillAttribute;
 use common_profile::JoinAttribute;
 use common_profile::LimitAttribute;
+use common_profile::LimitByAttribute;
 use common_profile::OperatorAttribute;
 use common_profile::OperatorProfile;
 use common_profile::OperatorType;
@@ -308,6 +311,42 @@ fn flatten_plan_node_profile(
             };
             plan_node_profs.push(prof);
         }
+        PhysicalPlan::GapFill(gap_fill) => {
+            flatten_plan_node_profile(metadata, &gap_fill.input, profs, plan_node_profs)?;
+            let proc_prof = profs.get(&gap_fill.plan_id).copied().unwrap_or_default();
+            let prof = OperatorProfile {
+                id: gap_fill.plan_id,
+                operator_type: OperatorType::GapFill,
+                execution_info: proc_prof.into(),
+                children: vec![gap_fill.input.get_id()],
+                attribute: OperatorAttribute::GapFill(GapFillAttribute {
+                    time_column: metadata.column(gap_fill.time_column).name(),
+                    partition_by: gap_fill
+                        .partition_by
+                        .iter()
+                        .map(|index| metadata.column(*index).name())
+                        .join(", "),
+                    method: gap_fill.method.to_string(),
+                }),
+            };
+            plan_node_profs.push(prof);
+        }
+        PhysicalPlan::ConnectBy(connect_by) => {
+            flatten_plan_node_profile(metadata, &connect_by.input, profs, plan_node_profs)?;
+            let proc_prof = profs.get(&connect_by.plan_id).copied().unwrap_or_default();
+            let prof = OperatorProfile {
+                id: connect_by.plan_id,
+                operator_type: OperatorType::ConnectBy,
+                execution_info: proc_prof.into(),
+                children: vec![connect_by.input.get_id()],
+                attribute: OperatorAttribute::ConnectBy(ConnectByAttribute {
+                    id_column: metadata.column(connect_by.id_column).name(),
+                    parent_column: metadata.column(connect_by.parent_column).name(),
+                    max_depth: connect_by.max_depth,
+                }),
+            };
+            plan_node_profs.push(prof);
+        }
         PhysicalPlan::Limit(limit) => {
             flatten_plan_node_profile(metadata, &limit.input, profs, plan_node_profs)?;
             let proc_prof = profs.get(&limit.plan_id).copied().unwrap_or_default();
@@ -323,6 +362,25 @@ fn flatten_plan_node_profile(
             };
             plan_node_profs.push(prof);
         }
+        PhysicalPlan::LimitBy(limit_by) => {
+            flatten_plan_node_profile(metadata, &limit_by.input, profs, plan_node_profs)?;
+            let proc_prof = profs.get(&limit_by.plan_id).copied().unwrap_or_default();
+            let prof = OperatorProfile {
+                id: limit_by.plan_id,
+                operator_type: OperatorType::LimitBy,
+                execution_info: proc_prof.into(),
+                children: vec![limit_by.input.get_id()],
+                attribute: OperatorAttribute::LimitBy(LimitByAttribute {
+                    limit: limit_by.limit,
+                    by: limit_by
+                        .by
+                        .iter()
+                        .map(|index| metadata.column(*index).name())
+                        .join(", "),
+                }),
+            };
+            plan_node_profs.push(prof);
+        }
         PhysicalPlan::RowFetch(fetch) => {
             flatten_plan_node_profile(metadata, &fetch.input, profs, plan_node_profs)?;
             let proc_prof = profs.get(&fetch.plan_id).copied().unwrap_or_default();
@@ -541,6 +541,14 @@ impl BindContext {
                 let table_index =
                     BindContext::get_internal_column_table_index(column_binding, metadata.clone())?;
                 let mut metadata = metadata.write();
+                let table = metadata.table(table_index);
+                if !table.table().support_internal_column(column_id) {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "Internal column `{}` is not supported by table `{}`",
+                        column_binding.internal_column.column_name(),
+                        table.name()
+                    )));
+                }
                 let column_index = metadata
                     .add_internal_column(table_index, column_binding.internal_column.clone());
                 e.insert((table_index, column_index));
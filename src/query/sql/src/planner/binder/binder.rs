@@ -46,12 +46,16 @@ use crate::optimizer::SExpr;
 use crate::plans::CreateFileFormatPlan;
 use crate::plans::CreateRolePlan;
 use crate::plans::DescConnectionPlan;
+use crate::plans::DescSecretPlan;
 use crate::plans::DropConnectionPlan;
 use crate::plans::DropFileFormatPlan;
 use crate::plans::DropRolePlan;
+use crate::plans::DropSecretPlan;
+use crate::plans::DropSequencePlan;
 use crate::plans::DropStagePlan;
 use crate::plans::DropUDFPlan;
 use crate::plans::DropUserPlan;
+use crate::plans::FlushCachePlan;
 use crate::plans::MaterializedCte;
 use crate::plans::Plan;
 use crate::plans::RelOperator;
@@ -60,6 +64,8 @@ use crate::plans::ShowConnectionsPlan;
 use crate::plans::ShowFileFormatsPlan;
 use crate::plans::ShowGrantsPlan;
 use crate::plans::ShowRolesPlan;
+use crate::plans::ShowSecretsPlan;
+use crate::plans::ShowSequencesPlan;
 use crate::plans::UseDatabasePlan;
 use crate::plans::Visitor;
 use crate::BindContext;
@@ -94,6 +100,9 @@ pub struct Binder {
     /// Use `IndexMap` because need to keep the insertion order
     /// Then wrap materialized ctes to main plan.
     pub ctes_map: Box<IndexMap<String, CteInfo>>,
+    /// Bind the tables with dedup keys without collapsing their duplicated rows,
+    /// e.g. the target tables of mutations.
+    pub raw_dedup_tables: bool,
 }
 
 impl<'a> Binder {
@@ -114,6 +123,7 @@ impl<'a> Binder {
             eq_scalars: vec![],
             m_cte_bound_s_expr: Default::default(),
             ctes_map: Box::default(),
+            raw_dedup_tables: false,
         }
     }
 
@@ -231,6 +241,11 @@ impl<'a> Binder {
                 Plan::ExplainAnalyze { plan: Box::new(plan) }
             }
 
+            Statement::DescribeQuery { query } => {
+                let plan = self.bind_statement(bind_context, &Statement::Query(query.clone())).await?;
+                Plan::DescribeQuery { plan: Box::new(plan) }
+            }
+
             Statement::ShowFunctions { show_options } => {
                 self.bind_show_functions(bind_context, show_options).await?
             }
@@ -452,6 +467,30 @@ impl<'a> Binder {
             })),
             Statement::ShowConnections(_) => Plan::ShowConnections(Box::new(ShowConnectionsPlan{})),
 
+            // Sequences
+            Statement::CreateSequence(stmt) => self.bind_create_sequence(stmt)?,
+            Statement::DropSequence(stmt) => Plan::DropSequence(Box::new(DropSequencePlan {
+                if_exists: stmt.if_exists,
+                name: stmt.name.to_string(),
+            })),
+            Statement::ShowSequences(_) => Plan::ShowSequences(Box::new(ShowSequencesPlan {})),
+
+            // Secrets
+            Statement::CreateSecret(stmt) => self.bind_create_secret(stmt)?,
+            Statement::DropSecret(stmt) => Plan::DropSecret(Box::new(DropSecretPlan {
+                if_exists: stmt.if_exists,
+                name: stmt.name.to_string(),
+            })),
+            Statement::DescribeSecret(stmt) => Plan::DescSecret(Box::new(DescSecretPlan {
+                name: stmt.name.to_string(),
+            })),
+            Statement::ShowSecrets(_) => Plan::ShowSecrets(Box::new(ShowSecretsPlan {})),
+
+            // System
+            Statement::FlushCache(stmt) => Plan::FlushCache(Box::new(FlushCachePlan {
+                kind: stmt.kind.into(),
+            })),
+
             // UDFs
             Statement::CreateUDF(stmt) => self.bind_create_udf(stmt).await?,
             Statement::AlterUDF(stmt) => self.bind_alter_udf(stmt).await?,
@@ -575,6 +614,13 @@ impl<'a> Binder {
                 self.bind_show_tasks(stmt).await?
             }
 
+            Statement::CreateNotification(stmt) => self.bind_create_notification(stmt).await?,
+            Statement::AlterNotification(stmt) => self.bind_alter_notification(stmt).await?,
+            Statement::DropNotification(stmt) => self.bind_drop_notification(stmt).await?,
+            Statement::DescribeNotification(stmt) => self.bind_desc_notification(stmt).await?,
+
+            Statement::ExecuteImmediate(stmt) => self.bind_execute_immediate(stmt).await?,
+
             // Streams
             Statement::CreateStream(stmt) => self.bind_create_stream(stmt).await?,
             Statement::DropStream(stmt) => self.bind_drop_stream(stmt).await?,
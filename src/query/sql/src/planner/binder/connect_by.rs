@@ -0,0 +1,285 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_ast::ast::Expr;
+use common_ast::ast::Literal;
+use common_ast::ast::Statement;
+use common_ast::ast::TableAlias;
+use common_ast::parser::parse_sql;
+use common_ast::parser::tokenize_sql;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::Span;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberScalar;
+use common_expression::ConstantFolder;
+use common_expression::Scalar;
+use common_functions::BUILTIN_FUNCTIONS;
+
+use super::wrap_cast;
+use crate::optimizer::SExpr;
+use crate::planner::semantic::TypeChecker;
+use crate::plans::BoundColumnRef;
+use crate::plans::ConnectBy;
+use crate::plans::EvalScalar;
+use crate::plans::FunctionCall;
+use crate::plans::ScalarExpr;
+use crate::plans::ScalarItem;
+use crate::BindContext;
+use crate::Binder;
+
+/// The default number of levels traversed by `generate_path`.
+const DEFAULT_MAX_DEPTH: u64 = 100;
+
+impl Binder {
+    /// Bind the `generate_path` table function, which emulates `CONNECT BY`:
+    ///
+    /// ```sql
+    /// SELECT * FROM generate_path('<table>', '<id column>', '<parent column>'
+    ///     [, start_with => <id>] [, max_depth => <n>] [, separator => '<separator>'])
+    /// ```
+    ///
+    /// The rows of the table are traversed from the rows whose id is `start_with`, or
+    /// whose parent is NULL by default, and returned with the `level` and `path`
+    /// pseudo-columns.
+    #[async_backtrace::framed]
+    pub(crate) async fn bind_generate_path(
+        &mut self,
+        bind_context: &mut BindContext,
+        span: &Span,
+        params: &[Expr],
+        named_params: &[(String, Expr)],
+        alias: &Option<TableAlias>,
+    ) -> Result<(SExpr, BindContext)> {
+        let [table, id, parent] = params else {
+            return Err(ErrorCode::NumberArgumentsNotMatch(
+                "generate_path expects the table, the id column and the parent column",
+            )
+            .set_span(*span));
+        };
+        let table = Self::generate_path_string_arg(table)?;
+        let id = Self::generate_path_string_arg(id)?;
+        let parent = Self::generate_path_string_arg(parent)?;
+
+        // Bind the table as a subquery.
+        let tokens = tokenize_sql(&format!("SELECT * FROM {table}"))?;
+        let (stmt, _) = parse_sql(&tokens, self.dialect)?;
+        let Statement::Query(query) = &stmt else {
+            return Err(ErrorCode::SemanticError(format!(
+                "generate_path got an invalid table name {table}"
+            ))
+            .set_span(*span));
+        };
+        let mut new_bind_context = BindContext::with_parent(
+            bind_context
+                .parent
+                .clone()
+                .unwrap_or_else(|| Box::new(BindContext::new())),
+        );
+        let (child, mut result_bind_context) =
+            self.bind_query(&mut new_bind_context, query).await?;
+
+        let find_column = |name: &str| {
+            result_bind_context
+                .columns
+                .iter()
+                .find(|column| column.column_name == name)
+                .cloned()
+                .ok_or_else(|| {
+                    ErrorCode::SemanticError(format!(
+                        "generate_path column {name} does not exist in table {table}"
+                    ))
+                    .set_span(*span)
+                })
+        };
+        let id_column = find_column(&id)?;
+        let parent_column = find_column(&parent)?;
+        let id_type = id_column.data_type.remove_nullable();
+        if id_type != parent_column.data_type.remove_nullable() {
+            return Err(ErrorCode::SemanticError(format!(
+                "generate_path id column {id} and parent column {parent} must have the same type, but got {} and {}",
+                id_column.data_type, parent_column.data_type
+            ))
+            .set_span(*span));
+        }
+
+        let mut start_with = None;
+        let mut max_depth = DEFAULT_MAX_DEPTH;
+        let mut separator = "/".to_string();
+        let mut constant_context = BindContext::new();
+        let mut type_checker = TypeChecker::try_create(
+            &mut constant_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            false,
+            false,
+        )?;
+        for (name, expr) in named_params.iter() {
+            match name.to_lowercase().as_str() {
+                "start_with" => {
+                    let (scalar, _) = *type_checker.resolve(expr).await?;
+                    start_with = Some(wrap_cast(&scalar, &id_type));
+                }
+                "max_depth" => {
+                    let depth = self
+                        .resolve_generate_path_constant(
+                            &mut type_checker,
+                            expr,
+                            &DataType::Number(NumberDataType::UInt64),
+                        )
+                        .await?;
+                    max_depth = match depth {
+                        Scalar::Number(NumberScalar::UInt64(depth)) if depth > 0 => depth,
+                        _ => {
+                            return Err(ErrorCode::BadArguments(
+                                "generate_path max_depth must be a positive integer",
+                            )
+                            .set_span(expr.span()));
+                        }
+                    };
+                }
+                "separator" => {
+                    let value = self
+                        .resolve_generate_path_constant(&mut type_checker, expr, &DataType::String)
+                        .await?;
+                    separator = match value {
+                        Scalar::String(value) => String::from_utf8(value)?,
+                        _ => {
+                            return Err(ErrorCode::BadArguments(
+                                "generate_path separator must be a string",
+                            )
+                            .set_span(expr.span()));
+                        }
+                    };
+                }
+                _ => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Unknown generate_path parameter {name}, must be one of start_with, max_depth and separator"
+                    ))
+                    .set_span(expr.span()));
+                }
+            }
+        }
+
+        // The roots are the row of `start_with`, or the rows without parent.
+        let root = match start_with {
+            Some(start_with) => ScalarExpr::FunctionCall(FunctionCall {
+                span: *span,
+                func_name: "eq".to_string(),
+                params: vec![],
+                arguments: vec![
+                    BoundColumnRef {
+                        span: *span,
+                        column: id_column.clone(),
+                    }
+                    .into(),
+                    start_with,
+                ],
+            }),
+            None => ScalarExpr::FunctionCall(FunctionCall {
+                span: *span,
+                func_name: "is_null".to_string(),
+                params: vec![],
+                arguments: vec![
+                    BoundColumnRef {
+                        span: *span,
+                        column: parent_column.clone(),
+                    }
+                    .into(),
+                ],
+            }),
+        };
+        let root_column = self
+            .metadata
+            .write()
+            .add_derived_column("_generate_path_root".to_string(), root.data_type()?);
+        let child = SExpr::create_unary(
+            Arc::new(
+                EvalScalar {
+                    items: vec![ScalarItem {
+                        scalar: root,
+                        index: root_column,
+                    }],
+                }
+                .into(),
+            ),
+            Arc::new(child),
+        );
+
+        let level_column = self.create_derived_column_binding(
+            "level".to_string(),
+            DataType::Number(NumberDataType::UInt64),
+        );
+        let path_column = self.create_derived_column_binding("path".to_string(), DataType::String);
+        let connect_by = ConnectBy {
+            id_column: id_column.index,
+            parent_column: parent_column.index,
+            root_column,
+            max_depth,
+            separator,
+            level_column: level_column.index,
+            path_column: path_column.index,
+        };
+        result_bind_context.add_column_binding(level_column);
+        result_bind_context.add_column_binding(path_column);
+
+        if let Some(alias) = alias {
+            result_bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
+        }
+        Ok((
+            SExpr::create_unary(Arc::new(connect_by.into()), Arc::new(child)),
+            result_bind_context,
+        ))
+    }
+
+    fn generate_path_string_arg(expr: &Expr) -> Result<String> {
+        match expr {
+            Expr::Literal {
+                lit: Literal::String(value),
+                ..
+            } => Ok(value.clone()),
+            _ => Err(ErrorCode::BadArguments(
+                "generate_path expects the table and the column names as string literals",
+            )
+            .set_span(expr.span())),
+        }
+    }
+
+    async fn resolve_generate_path_constant(
+        &self,
+        type_checker: &mut TypeChecker<'_>,
+        expr: &Expr,
+        data_type: &DataType,
+    ) -> Result<Scalar> {
+        let (scalar, _) = *type_checker.resolve(expr).await?;
+        let scalar = wrap_cast(&scalar, data_type);
+        let (new_expr, _) = ConstantFolder::fold(
+            &scalar.as_expr()?,
+            &self.ctx.get_function_context()?,
+            &BUILTIN_FUNCTIONS,
+        );
+        match new_expr {
+            common_expression::Expr::Constant { scalar, .. } => Ok(scalar),
+            _ => Err(
+                ErrorCode::BadArguments("generate_path parameters must be constant values")
+                    .set_span(expr.span()),
+            ),
+        }
+    }
+}
@@ -14,12 +14,17 @@
 
 use common_ast::ast::CopyIntoLocationSource;
 use common_ast::ast::CopyIntoLocationStmt;
+use common_ast::ast::FileLocation;
 use common_ast::ast::Statement;
 use common_ast::parser::parse_sql;
 use common_ast::parser::tokenize_sql;
+use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::principal::StageInfo;
+use common_meta_app::storage::StorageHttpConfig;
+use common_meta_app::storage::StorageParams;
+use common_storage::unload_presigned_url;
 
 use crate::binder::copy_into_table::resolve_file_location;
 use crate::binder::Binder;
@@ -64,10 +69,31 @@ impl<'a> Binder {
             }
         }?;
 
-        let (mut stage_info, path) = resolve_file_location(&self.ctx, &stmt.dst).await?;
+        let (mut stage_info, path) = match &stmt.dst {
+            FileLocation::Uri(uri) if uri.protocol == "http" || uri.protocol == "https" => {
+                // The path is kept as it is, the query of a presigned url must not be decoded.
+                let storage_params = StorageParams::Http(StorageHttpConfig {
+                    endpoint_url: format!("{}://{}", uri.protocol, uri.name),
+                    paths: vec![uri.path.clone()],
+                });
+                if !storage_params.is_secure() && !GlobalConfig::instance().storage.allow_insecure {
+                    return Err(ErrorCode::StorageInsecure(
+                        "copy into insecure storage is not allowed",
+                    ));
+                }
+                let stage_info = StageInfo::new_external_stage(storage_params, "/", true);
+                (stage_info, "/".to_string())
+            }
+            _ => resolve_file_location(&self.ctx, &stmt.dst).await?,
+        };
         self.apply_copy_into_location_options(stmt, &mut stage_info)
             .await?;
 
+        // A presigned url can only be written as one file.
+        if unload_presigned_url(&stage_info.stage_params.storage).is_some() {
+            stage_info.copy_options.single = true;
+        }
+
         Ok(Plan::CopyIntoLocation(CopyIntoLocationPlan {
             stage: Box::new(stage_info),
             path,
@@ -16,6 +16,8 @@ use std::collections::HashMap;
 use std::str::FromStr;
 use std::sync::Arc;
 
+use chrono::format::Item;
+use chrono::format::StrftimeItems;
 use common_ast::ast::ColumnID as AstColumnID;
 use common_ast::ast::CopyIntoTableSource;
 use common_ast::ast::CopyIntoTableStmt;
@@ -44,8 +46,11 @@ use common_expression::DataSchema;
 use common_expression::DataSchemaRef;
 use common_expression::Evaluator;
 use common_expression::Scalar;
+use common_expression::TableSchema;
 use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::principal::ColumnFormatParams;
 use common_meta_app::principal::FileFormatOptionsAst;
+use common_meta_app::principal::FileFormatOverrides;
 use common_meta_app::principal::FileFormatParams;
 use common_meta_app::principal::StageInfo;
 use common_storage::StageFilesInfo;
@@ -53,10 +58,12 @@ use common_users::UserApiProvider;
 use indexmap::IndexMap;
 use log::debug;
 use parking_lot::RwLock;
+use regex::Regex;
 
 use crate::binder::location::parse_uri_location;
 use crate::binder::select::MaxColumnPosition;
 use crate::binder::Binder;
+use crate::normalize_identifier;
 use crate::plans::CopyIntoTableMode;
 use crate::plans::CopyIntoTablePlan;
 use crate::plans::Plan;
@@ -134,6 +141,9 @@ impl<'a> Binder {
         );
 
         let stage_schema = infer_table_schema(&required_values_schema)?;
+        let format_overrides = self
+            .bind_format_overrides(stmt, &stage_info.file_format_params, &stage_schema)
+            .await?;
         let default_values = self
             .prepare_default_values(bind_context, &required_values_schema)
             .await?;
@@ -153,6 +163,7 @@ impl<'a> Binder {
                 files_to_copy: None,
                 is_select: false,
                 default_values: Some(default_values),
+                format_overrides,
             },
             values_consts: vec![],
             required_source_schema: required_values_schema.clone(),
@@ -286,6 +297,7 @@ impl<'a> Binder {
                 files_to_copy: None,
                 is_select: false,
                 default_values: Some(default_values),
+                format_overrides: Default::default(),
             },
             write_mode,
             query: None,
@@ -374,6 +386,72 @@ impl<'a> Binder {
         stmt.apply_to_copy_option(&mut stage.copy_options)
     }
 
+    /// Resolve `FILE_FORMAT_OVERRIDES` and `COLUMN_FORMATS`, which are applied when decoding
+    /// the rows of a stage, so the files must have the same type as the FILE_FORMAT of COPY.
+    #[async_backtrace::framed]
+    async fn bind_format_overrides(
+        &self,
+        stmt: &CopyIntoTableStmt,
+        file_format_params: &FileFormatParams,
+        schema: &TableSchema,
+    ) -> Result<FileFormatOverrides> {
+        let mut overrides = FileFormatOverrides::default();
+        if stmt.file_format_overrides.is_empty() && stmt.column_formats.is_empty() {
+            return Ok(overrides);
+        }
+        if matches!(stmt.src, CopyIntoTableSource::Query(_))
+            || matches!(file_format_params, FileFormatParams::Parquet(_))
+        {
+            return Err(ErrorCode::BadArguments(
+                "FILE_FORMAT_OVERRIDES and COLUMN_FORMATS are not supported when copying with a query or from parquet files",
+            ));
+        }
+
+        for (pattern, options) in &stmt.file_format_overrides {
+            if let Err(e) = Regex::new(&format!("^{pattern}$")) {
+                return Err(ErrorCode::SyntaxException(format!(
+                    "Pattern format invalid, got:{}, error:{:?}",
+                    pattern, e
+                )));
+            }
+            let params = self.try_resolve_file_format(options).await?;
+            if params.get_type() != file_format_params.get_type() {
+                return Err(ErrorCode::BadArguments(format!(
+                    "the file format of pattern '{pattern}' must be of type {:?} as the one of COPY, got {:?}",
+                    file_format_params.get_type(),
+                    params.get_type()
+                )));
+            }
+            overrides.files.push((pattern.clone(), params));
+        }
+
+        if !stmt.column_formats.is_empty()
+            && !matches!(
+                file_format_params,
+                FileFormatParams::Csv(_) | FileFormatParams::Tsv(_) | FileFormatParams::Xml(_)
+            )
+        {
+            return Err(ErrorCode::BadArguments(format!(
+                "COLUMN_FORMATS is only supported for CSV, TSV and XML, got {:?}",
+                file_format_params.get_type()
+            )));
+        }
+        for (column, options) in &stmt.column_formats {
+            let name = normalize_identifier(column, &self.name_resolution_ctx).name;
+            schema.field_with_name(&name)?;
+            let params = ColumnFormatParams::try_create(options.clone())?;
+            for format in params.timestamp_format.iter().chain(&params.date_format) {
+                if StrftimeItems::new(format).any(|item| matches!(item, Item::Error)) {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "invalid format '{format}' of column {name}"
+                    )));
+                }
+            }
+            overrides.columns.insert(name, params);
+        }
+        Ok(overrides)
+    }
+
     #[async_backtrace::framed]
     pub(crate) async fn prepared_values(
         &self,
@@ -456,6 +534,7 @@ fn check_transform_query(
 ) -> Result<(&Vec<SelectTarget>, &FileLocation, &Option<TableAlias>)> {
     if query.offset.is_none()
         && query.limit.is_empty()
+        && query.limit_by.is_none()
         && query.order_by.is_empty()
         && query.with.is_none()
     {
@@ -520,13 +599,17 @@ pub async fn resolve_stage_location(
     // my_named_stage/abc/
     let names: Vec<&str> = location.splitn(2, '/').filter(|v| !v.is_empty()).collect();
 
-    let stage = if names[0] == "~" {
+    let mut stage = if names[0] == "~" {
         StageInfo::new_user_stage(&ctx.get_current_user()?.name)
     } else {
         UserApiProvider::instance()
             .get_stage(&ctx.get_tenant(), names[0])
             .await?
     };
+    // The stage keeps only the references to the secrets of its credentials.
+    UserApiProvider::instance()
+        .resolve_storage_secrets(&ctx.get_tenant(), &mut stage.stage_params.storage)
+        .await?;
 
     let path = names.get(1).unwrap_or(&"").trim_start_matches('/');
     let path = if path.is_empty() { "/" } else { path };
@@ -543,7 +626,10 @@ pub async fn resolve_file_location(
     match location.clone() {
         FileLocation::Stage(location) => resolve_stage_location(ctx, &location).await,
         FileLocation::Uri(mut uri) => {
-            let (storage_params, path) = parse_uri_location(&mut uri, Some(ctx)).await?;
+            let (mut storage_params, path) = parse_uri_location(&mut uri, Some(ctx)).await?;
+            UserApiProvider::instance()
+                .resolve_storage_secrets(&ctx.get_tenant(), &mut storage_params)
+                .await?;
             if !storage_params.is_secure() && !GlobalConfig::instance().storage.allow_insecure {
                 Err(ErrorCode::StorageInsecure(
                     "copy from insecure storage is not allowed",
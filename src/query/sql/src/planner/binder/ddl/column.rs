@@ -71,7 +71,7 @@ impl Binder {
 
         select_builder
             .with_column("column_name AS `Field`")
-            .with_column("column_type AS `Type`")
+            .with_column("upper(column_type) AS `Type`")
             .with_column("is_nullable AS `Null`")
             .with_column("default AS `Default`")
             .with_column("extra AS `Extra`")
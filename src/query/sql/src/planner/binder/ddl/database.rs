@@ -161,6 +161,8 @@ impl Binder {
             if_exists,
             catalog,
             database,
+            cascade,
+            dry_run,
         } = stmt;
 
         let tenant = self.ctx.get_tenant();
@@ -175,6 +177,8 @@ impl Binder {
             tenant,
             catalog,
             database,
+            cascade: *cascade,
+            dry_run: *dry_run,
         })))
     }
 
@@ -20,7 +20,10 @@ mod data_mask;
 mod database;
 mod index;
 mod network_policy;
+mod notification;
 mod role;
+mod secret;
+mod sequence;
 mod share;
 mod stage;
 mod stream;
@@ -0,0 +1,235 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::AlterNotificationOptions;
+use common_ast::ast::AlterNotificationStmt;
+use common_ast::ast::CreateNotificationStmt;
+use common_ast::ast::DescribeNotificationStmt;
+use common_ast::ast::DropNotificationStmt;
+use common_ast::ast::NotificationOptions;
+use common_ast::ast::NotificationType;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::plans::AlterNotificationPlan;
+use crate::plans::CreateNotificationPlan;
+use crate::plans::DescNotificationPlan;
+use crate::plans::DropNotificationPlan;
+use crate::plans::NotificationRetryPolicy;
+use crate::plans::NotificationTarget;
+use crate::plans::Plan;
+use crate::Binder;
+
+/// The retry policy is returned only if any of its options is set.
+fn parse_notification_options(
+    options: &NotificationOptions,
+) -> Result<(NotificationTarget, Option<NotificationRetryPolicy>)> {
+    let NotificationOptions {
+        notification_type,
+        options,
+    } = options;
+
+    let allowed: &[&str] = match notification_type {
+        NotificationType::Webhook => &["url", "method", "authorization_header", "signing_secret"],
+        NotificationType::Email => &["recipients"],
+    };
+    let mut retry_policy = None;
+    for (k, v) in options {
+        match k.as_str() {
+            "max_retries" => {
+                let max_retries = v.parse::<u32>().map_err(|_| {
+                    ErrorCode::SemanticError(format!("invalid max_retries '{v}', expect a number"))
+                })?;
+                retry_policy
+                    .get_or_insert_with(NotificationRetryPolicy::default)
+                    .max_retries = max_retries;
+            }
+            "retry_interval" => {
+                let secs = v
+                    .parse::<u64>()
+                    .ok()
+                    .filter(|secs| *secs > 0)
+                    .ok_or_else(|| {
+                        ErrorCode::SemanticError(format!(
+                            "invalid retry_interval '{v}', expect a positive number of seconds"
+                        ))
+                    })?;
+                retry_policy
+                    .get_or_insert_with(NotificationRetryPolicy::default)
+                    .retry_interval_secs = secs;
+            }
+            k if allowed.contains(&k) => {}
+            _ => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "unknown option {k} for {notification_type} notification integration"
+                )));
+            }
+        }
+    }
+
+    let target = match notification_type {
+        NotificationType::Webhook => {
+            let url = options.get("url").ok_or_else(|| {
+                ErrorCode::SemanticError("webhook notification integration requires url")
+            })?;
+            if !url.starts_with("http://") && !url.starts_with("https://") {
+                return Err(ErrorCode::SemanticError(format!(
+                    "invalid webhook url '{url}', expect http:// or https://"
+                )));
+            }
+            let method = options
+                .get("method")
+                .map(|method| method.to_uppercase())
+                .unwrap_or_else(|| "POST".to_string());
+            if method != "GET" && method != "POST" {
+                return Err(ErrorCode::SemanticError(format!(
+                    "invalid webhook method '{method}', expect GET or POST"
+                )));
+            }
+            NotificationTarget::Webhook {
+                url: url.clone(),
+                method,
+                authorization_header: options.get("authorization_header").cloned(),
+                signing_secret: options.get("signing_secret").cloned(),
+            }
+        }
+        NotificationType::Email => {
+            let recipients = options
+                .get("recipients")
+                .map(|recipients| {
+                    recipients
+                        .split(',')
+                        .map(|recipient| recipient.trim().to_string())
+                        .filter(|recipient| !recipient.is_empty())
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default();
+            if recipients.is_empty() {
+                return Err(ErrorCode::SemanticError(
+                    "email notification integration requires recipients",
+                ));
+            }
+            if let Some(recipient) = recipients.iter().find(|r| !r.contains('@')) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "invalid email recipient '{recipient}'"
+                )));
+            }
+            NotificationTarget::Email { recipients }
+        }
+    };
+    Ok((target, retry_policy))
+}
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_notification(
+        &mut self,
+        stmt: &CreateNotificationStmt,
+    ) -> Result<Plan> {
+        let CreateNotificationStmt {
+            if_not_exists,
+            name,
+            enabled,
+            options,
+            comments,
+        } = stmt;
+
+        let (target, retry_policy) = parse_notification_options(options)?;
+
+        let tenant = self.ctx.get_tenant();
+        let plan = CreateNotificationPlan {
+            if_not_exists: *if_not_exists,
+            tenant,
+            name: name.to_string(),
+            enabled: *enabled,
+            target,
+            retry_policy: retry_policy.unwrap_or_default(),
+            comments: comments.clone(),
+        };
+        Ok(Plan::CreateNotification(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_notification(
+        &mut self,
+        stmt: &AlterNotificationStmt,
+    ) -> Result<Plan> {
+        let AlterNotificationStmt {
+            if_exists,
+            name,
+            options,
+        } = stmt;
+
+        let AlterNotificationOptions::Set {
+            enabled,
+            options,
+            comments,
+        } = options;
+        if enabled.is_none() && options.is_none() && comments.is_none() {
+            return Err(ErrorCode::SyntaxException(
+                "alter notification integration must set at least one option".to_string(),
+            ));
+        }
+        let (target, retry_policy) = match options {
+            Some(options) => {
+                let (target, retry_policy) = parse_notification_options(options)?;
+                (Some(target), retry_policy)
+            }
+            None => (None, None),
+        };
+
+        let tenant = self.ctx.get_tenant();
+        let plan = AlterNotificationPlan {
+            if_exists: *if_exists,
+            tenant,
+            name: name.to_string(),
+            enabled: *enabled,
+            target,
+            retry_policy,
+            comments: comments.clone(),
+        };
+        Ok(Plan::AlterNotification(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_notification(
+        &mut self,
+        stmt: &DropNotificationStmt,
+    ) -> Result<Plan> {
+        let DropNotificationStmt { if_exists, name } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let plan = DropNotificationPlan {
+            if_exists: *if_exists,
+            tenant,
+            name: name.to_string(),
+        };
+        Ok(Plan::DropNotification(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_desc_notification(
+        &mut self,
+        stmt: &DescribeNotificationStmt,
+    ) -> Result<Plan> {
+        let DescribeNotificationStmt { name } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let plan = DescNotificationPlan {
+            tenant,
+            name: name.to_string(),
+        };
+        Ok(Plan::DescNotification(Box::new(plan)))
+    }
+}
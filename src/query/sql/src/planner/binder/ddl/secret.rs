@@ -0,0 +1,43 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::CreateSecretStmt;
+use common_exception::Result;
+use common_meta_app::principal::UserDefinedSecret;
+use common_meta_app::principal::DEFAULT_SECRET_CACHE_TTL_SECS;
+use common_users::secret::check_secret_params;
+
+use crate::plans::CreateSecretPlan;
+use crate::plans::Plan;
+use crate::Binder;
+
+impl Binder {
+    pub(in crate::planner::binder) fn bind_create_secret(
+        &mut self,
+        stmt: &CreateSecretStmt,
+    ) -> Result<Plan> {
+        let secret = UserDefinedSecret::new(
+            &stmt.name.to_string(),
+            stmt.provider.clone(),
+            stmt.provider_params.clone(),
+            stmt.cache_ttl.unwrap_or(DEFAULT_SECRET_CACHE_TTL_SECS),
+        );
+        check_secret_params(&secret)?;
+
+        Ok(Plan::CreateSecret(Box::new(CreateSecretPlan {
+            if_not_exists: stmt.if_not_exists,
+            secret,
+        })))
+    }
+}
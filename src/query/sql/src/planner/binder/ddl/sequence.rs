@@ -0,0 +1,50 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::CreateSequenceStmt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::plans::CreateSequencePlan;
+use crate::plans::Plan;
+use crate::Binder;
+
+impl Binder {
+    pub(in crate::planner::binder) fn bind_create_sequence(
+        &mut self,
+        stmt: &CreateSequenceStmt,
+    ) -> Result<Plan> {
+        let increment = stmt.increment.unwrap_or(1);
+        if increment == 0 {
+            return Err(ErrorCode::IllegalSequence(
+                "INCREMENT of a sequence must be greater than 0",
+            ));
+        }
+        let cache = stmt.cache.unwrap_or(1);
+        if cache == 0 {
+            return Err(ErrorCode::IllegalSequence(
+                "CACHE of a sequence must be greater than 0",
+            ));
+        }
+
+        Ok(Plan::CreateSequence(Box::new(CreateSequencePlan {
+            if_not_exists: stmt.if_not_exists,
+            name: stmt.name.to_string(),
+            start: stmt.start.unwrap_or(1),
+            increment,
+            cache,
+            comment: stmt.comment.clone(),
+        })))
+    }
+}
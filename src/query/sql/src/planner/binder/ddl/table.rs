@@ -44,6 +44,8 @@ use common_ast::ast::ShowLimit;
 use common_ast::ast::ShowTablesStatusStmt;
 use common_ast::ast::ShowTablesStmt;
 use common_ast::ast::Statement;
+use common_ast::ast::TableConstraintDefinition;
+use common_ast::ast::TableConstraintKind;
 use common_ast::ast::TableReference;
 use common_ast::ast::TruncateTableStmt;
 use common_ast::ast::UndropTableStmt;
@@ -59,9 +61,11 @@ use common_exception::Result;
 use common_expression::infer_schema_type;
 use common_expression::infer_table_schema;
 use common_expression::types::DataType;
+use common_expression::ColumnId;
 use common_expression::ComputedExpr;
 use common_expression::DataField;
 use common_expression::DataSchemaRefExt;
+use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchema;
 use common_expression::TableSchemaRef;
@@ -71,14 +75,23 @@ use common_meta_app::storage::StorageParams;
 use common_storage::DataOperator;
 use common_storages_view::view_table::QUERY;
 use common_storages_view::view_table::VIEW_ENGINE;
+use common_users::UserApiProvider;
 use log::debug;
 use log::error;
 use storages_common_table_meta::table::is_reserved_opt_key;
+use storages_common_table_meta::table::SequenceColumn;
+use storages_common_table_meta::table::SequenceColumns;
+use storages_common_table_meta::table::TableConstraint;
+use storages_common_table_meta::table::TableConstraints;
+use storages_common_table_meta::table::AUTO_INCREMENT_SEQUENCE_PREFIX;
+use storages_common_table_meta::table::OPT_KEY_CONSTRAINTS;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_SEQUENCE_COLUMNS;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
 use storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
+use uuid::Uuid;
 
 use crate::binder::location::parse_uri_location;
 use crate::binder::scalar::ScalarBinder;
@@ -88,6 +101,7 @@ use crate::binder::Visibility;
 use crate::optimizer::optimize;
 use crate::optimizer::OptimizerConfig;
 use crate::optimizer::OptimizerContext;
+use crate::parse_check_expr_to_string;
 use crate::parse_computed_expr_to_string;
 use crate::parse_default_expr_to_string;
 use crate::planner::semantic::normalize_identifier;
@@ -95,12 +109,15 @@ use crate::planner::semantic::resolve_type_name;
 use crate::planner::semantic::IdentifierNormalizer;
 use crate::plans::AddColumnOption;
 use crate::plans::AddTableColumnPlan;
+use crate::plans::AddTableConstraintPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AnalyzeTablePlan;
+use crate::plans::CloneTablePlan;
 use crate::plans::CreateTablePlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
+use crate::plans::DropTableConstraintPlan;
 use crate::plans::DropTablePlan;
 use crate::plans::ExistsTablePlan;
 use crate::plans::ModifyColumnAction as ModifyColumnActionInPlan;
@@ -108,6 +125,7 @@ use crate::plans::ModifyTableColumnPlan;
 use crate::plans::OptimizeTableAction;
 use crate::plans::OptimizeTablePlan;
 use crate::plans::Plan;
+use crate::plans::PromoteReplicaPlan;
 use crate::plans::ReclusterTablePlan;
 use crate::plans::RenameTableColumnPlan;
 use crate::plans::RenameTablePlan;
@@ -407,6 +425,39 @@ impl Binder {
         let (catalog, database, table) =
             self.normalize_object_identifier_triple(catalog, database, table);
 
+        if let Some(CreateTableSource::Clone {
+            catalog: source_catalog,
+            database: source_database,
+            table: source_table,
+        }) = source
+        {
+            // A clone shares the definition and data of its source table,
+            // anything else in the statement would be silently ignored.
+            if as_query.is_some()
+                || uri_location.is_some()
+                || !table_options.is_empty()
+                || !cluster_by.is_empty()
+                || engine.is_some()
+                || *transient
+            {
+                return Err(ErrorCode::BadArguments(
+                    "CREATE TABLE ... CLONE does not accept table options, engine, cluster by, location or AS SELECT",
+                ));
+            }
+            let (source_catalog, source_database, source_table) = self
+                .normalize_object_identifier_triple(source_catalog, source_database, source_table);
+            return Ok(Plan::CloneTable(Box::new(CloneTablePlan {
+                if_not_exists: *if_not_exists,
+                tenant: self.ctx.get_tenant(),
+                catalog,
+                database,
+                table,
+                source_catalog,
+                source_database,
+                source_table,
+            })));
+        }
+
         // Take FUSE engine AS default engine
         let engine = engine.unwrap_or(Engine::Fuse);
         let mut options: BTreeMap<String, String> = BTreeMap::new();
@@ -430,7 +481,7 @@ impl Binder {
                 let (sp, _) = parse_uri_location(&mut uri, Some(&self.ctx)).await?;
 
                 // create a temporary op to check if params is correct
-                DataOperator::try_create(&sp).await?;
+                self.check_storage_params(&sp).await?;
 
                 // Path ends with "/" means it's a directory.
                 let fp = if uri.path.ends_with('/') {
@@ -500,6 +551,31 @@ impl Binder {
             ))?,
         };
 
+        let mut column_mask_policy = None;
+        if let Some(CreateTableSource::Columns(columns, constraints)) = source {
+            let sequence_columns = self.analyze_sequence_columns(columns, &schema)?;
+            if !sequence_columns.is_empty() {
+                options.insert(
+                    OPT_KEY_SEQUENCE_COLUMNS.to_owned(),
+                    sequence_columns.to_option_value()?,
+                );
+            }
+            let mut table_constraints = TableConstraints::default();
+            for constraint in constraints {
+                let (name, constraint) = self
+                    .analyze_table_constraint(&catalog, constraint, &schema, &table_constraints)
+                    .await?;
+                table_constraints.0.insert(name, constraint);
+            }
+            if !table_constraints.is_empty() {
+                options.insert(
+                    OPT_KEY_CONSTRAINTS.to_owned(),
+                    table_constraints.to_option_value()?,
+                );
+            }
+            column_mask_policy = self.analyze_column_mask_policy(columns);
+        }
+
         // for fuse engine, we will insert database_id, so if we check it in execute phase,
         // we can't distinct user key and our internal key.
         if options.contains_key(&OPT_KEY_DATABASE_ID.to_lowercase()) {
@@ -593,6 +669,7 @@ impl Binder {
             options,
             field_comments,
             cluster_key,
+            column_mask_policy,
             as_select: if let Some(query) = as_query {
                 let mut bind_context = BindContext::new();
                 let stmt = Statement::Query(Box::new(*query.clone()));
@@ -649,7 +726,7 @@ impl Binder {
         let (sp, _) = parse_uri_location(&mut uri, Some(&self.ctx)).await?;
 
         // create a temporary op to check if params is correct
-        DataOperator::try_create(&sp).await?;
+        self.check_storage_params(&sp).await?;
 
         // Path ends with "/" means it's a directory.
         let part_prefix = if uri.path.ends_with('/') {
@@ -672,10 +749,23 @@ impl Binder {
             options,
             field_comments: vec![],
             cluster_key: None,
+            column_mask_policy: None,
             as_select: None,
         })))
     }
 
+    /// Only the references to the secrets of the credentials are persisted, the
+    /// storage is checked with the values of the secrets.
+    #[async_backtrace::framed]
+    async fn check_storage_params(&self, storage_params: &StorageParams) -> Result<()> {
+        let mut storage_params = storage_params.clone();
+        UserApiProvider::instance()
+            .resolve_storage_secrets(&self.ctx.get_tenant(), &mut storage_params)
+            .await?;
+        DataOperator::try_create(&storage_params).await?;
+        Ok(())
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_drop_table(
         &mut self,
@@ -940,6 +1030,41 @@ impl Binder {
                     table,
                 })))
             }
+            AlterTableAction::PromoteReplica => {
+                Ok(Plan::PromoteReplica(Box::new(PromoteReplicaPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                })))
+            }
+            AlterTableAction::AddConstraint { constraint } => {
+                let table_info = self.ctx.get_table(&catalog, &database, &table).await?;
+                let constraints = TableConstraints::from_options(table_info.options())?;
+                let (name, constraint) = self
+                    .analyze_table_constraint(
+                        &catalog,
+                        constraint,
+                        &table_info.schema(),
+                        &constraints,
+                    )
+                    .await?;
+                Ok(Plan::AddTableConstraint(Box::new(AddTableConstraintPlan {
+                    catalog,
+                    database,
+                    table,
+                    name,
+                    constraint,
+                })))
+            }
+            AlterTableAction::DropConstraint { name } => Ok(Plan::DropTableConstraint(Box::new(
+                DropTableConstraintPlan {
+                    catalog,
+                    database,
+                    table,
+                    name: normalize_identifier(name, &self.name_resolution_ctx).name,
+                },
+            ))),
         }
     }
 
@@ -1218,6 +1343,11 @@ impl Binder {
         table_schema: TableSchemaRef,
     ) -> Result<(TableField, String)> {
         let name = normalize_identifier(&column.name, &self.name_resolution_ctx).name;
+        if column.mask_policy.is_some() {
+            return Err(ErrorCode::SemanticError(format!(
+                "can't add column {name} with a masking policy, use `ALTER TABLE MODIFY COLUMN {name} SET MASKING POLICY` instead"
+            )));
+        }
         let not_null = self.is_column_not_null(column)?;
         let data_type = resolve_type_name(&column.data_type, not_null)?;
         let mut field = TableField::new(&name, data_type);
@@ -1243,6 +1373,11 @@ impl Binder {
                         "can't add a stored computed column".to_string(),
                     ));
                 }
+                ColumnExpr::NextVal(_) | ColumnExpr::AutoIncrement => {
+                    return Err(ErrorCode::SemanticError(
+                        "can't add a column generated by a sequence".to_string(),
+                    ));
+                }
             }
         }
         let comment = column.comment.clone().unwrap_or_default();
@@ -1275,7 +1410,10 @@ impl Binder {
                         )?;
                         field = field.with_default_expr(Some(expr));
                     }
-                    _ => has_computed = true,
+                    ColumnExpr::NextVal(_) | ColumnExpr::AutoIncrement => {
+                        Self::validate_sequence_column(&field)?;
+                    }
+                    ColumnExpr::Virtual(_) | ColumnExpr::Stored(_) => has_computed = true,
                 }
             }
             fields.push(field);
@@ -1336,7 +1474,7 @@ impl Binder {
         source: &CreateTableSource,
     ) -> Result<(TableSchemaRef, Vec<String>)> {
         match source {
-            CreateTableSource::Columns(columns) => {
+            CreateTableSource::Columns(columns, _) => {
                 self.analyze_create_table_schema_by_columns(columns).await
             }
             CreateTableSource::Like {
@@ -1362,6 +1500,204 @@ impl Binder {
                     Ok((table.schema(), table.field_comments().clone()))
                 }
             }
+            CreateTableSource::Clone { .. } => Err(ErrorCode::Internal(
+                "Logical error, CLONE table source should be bound as a clone table plan",
+            )),
+        }
+    }
+
+    /// Binds the columns whose values are generated by sequences,
+    /// each `AUTO_INCREMENT` column gets its own implicit sequence.
+    fn analyze_sequence_columns(
+        &self,
+        columns: &[ColumnDefinition],
+        schema: &TableSchemaRef,
+    ) -> Result<SequenceColumns> {
+        let mut sequence_columns = SequenceColumns::default();
+        for column in columns.iter() {
+            let sequence_column = match &column.expr {
+                Some(ColumnExpr::NextVal(sequence)) => SequenceColumn {
+                    sequence: sequence.to_string(),
+                    auto_increment: false,
+                },
+                Some(ColumnExpr::AutoIncrement) => SequenceColumn {
+                    sequence: format!(
+                        "{}{}",
+                        AUTO_INCREMENT_SEQUENCE_PREFIX,
+                        Uuid::new_v4().simple()
+                    ),
+                    auto_increment: true,
+                },
+                _ => continue,
+            };
+            let name = normalize_identifier(&column.name, &self.name_resolution_ctx).name;
+            let field = schema.field_with_name(&name)?;
+            sequence_columns
+                .0
+                .insert(field.column_id(), sequence_column);
+        }
+        Ok(sequence_columns)
+    }
+
+    /// Resolves a constraint against the schema of the table, returns its name and
+    /// definition. The constraints already declared on the table are used to check
+    /// the name conflicts and to name the anonymous constraints.
+    ///
+    /// The table referenced by a foreign key is looked up in `catalog`.
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn analyze_table_constraint(
+        &self,
+        catalog: &str,
+        constraint: &TableConstraintDefinition,
+        schema: &TableSchemaRef,
+        constraints: &TableConstraints,
+    ) -> Result<(String, TableConstraint)> {
+        let resolve_columns =
+            |columns: &[Identifier], schema: &TableSchemaRef| -> Result<Vec<ColumnId>> {
+                let mut column_ids = Vec::with_capacity(columns.len());
+                for column in columns {
+                    let name = normalize_identifier(column, &self.name_resolution_ctx).name;
+                    let column_id = schema.field_with_name(&name)?.column_id();
+                    if column_ids.contains(&column_id) {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "column {} is duplicated in constraint",
+                            name
+                        )));
+                    }
+                    column_ids.push(column_id);
+                }
+                Ok(column_ids)
+            };
+
+        let (prefix, table_constraint) = match &constraint.kind {
+            TableConstraintKind::Check(expr) => {
+                let (expr, columns) =
+                    parse_check_expr_to_string(self.ctx.clone(), schema.clone(), expr)?;
+                ("check", TableConstraint::Check { expr, columns })
+            }
+            TableConstraintKind::Unique(columns) => ("unique", TableConstraint::Unique {
+                columns: resolve_columns(columns, schema)?,
+            }),
+            TableConstraintKind::PrimaryKey(columns) => {
+                if constraints.has_primary_key() {
+                    return Err(ErrorCode::SemanticError(
+                        "table can have only one primary key",
+                    ));
+                }
+                ("primary_key", TableConstraint::PrimaryKey {
+                    columns: resolve_columns(columns, schema)?,
+                })
+            }
+            TableConstraintKind::ForeignKey {
+                columns,
+                ref_database,
+                ref_table,
+                ref_columns,
+            } => {
+                let ref_database = ref_database
+                    .as_ref()
+                    .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+                    .unwrap_or_else(|| self.ctx.get_current_database());
+                let ref_table = normalize_identifier(ref_table, &self.name_resolution_ctx).name;
+                let table = self
+                    .ctx
+                    .get_table(catalog, &ref_database, &ref_table)
+                    .await?;
+                let ref_schema = table.schema();
+
+                if columns.len() != ref_columns.len() {
+                    return Err(ErrorCode::SemanticError(
+                        "number of referencing and referenced columns of foreign key disagree",
+                    ));
+                }
+                for (column, ref_column) in columns.iter().zip(ref_columns.iter()) {
+                    let name = normalize_identifier(column, &self.name_resolution_ctx).name;
+                    let ref_name = normalize_identifier(ref_column, &self.name_resolution_ctx).name;
+                    let field = schema.field_with_name(&name)?;
+                    let ref_field = ref_schema.field_with_name(&ref_name)?;
+                    if field.data_type().remove_nullable()
+                        != ref_field.data_type().remove_nullable()
+                    {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "foreign key column {} of type {} can't reference column {} of type {}",
+                            field.name(),
+                            field.data_type(),
+                            ref_field.name(),
+                            ref_field.data_type()
+                        )));
+                    }
+                }
+                let columns = resolve_columns(columns, schema)?;
+                let ref_columns = resolve_columns(ref_columns, &ref_schema)?;
+                let ref_constraints = TableConstraints::from_options(table.options())?;
+                if !ref_constraints
+                    .unique_keys()
+                    .any(|key| key.iter().all(|id| ref_columns.contains(id)))
+                {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "no unique constraint of table {}.{} matches the referenced columns",
+                        ref_database, ref_table
+                    )));
+                }
+                ("foreign_key", TableConstraint::ForeignKey {
+                    columns,
+                    ref_table_id: table.get_id(),
+                    ref_database,
+                    ref_table,
+                    ref_columns,
+                })
+            }
+        };
+
+        let name = match &constraint.name {
+            Some(name) => {
+                let name = normalize_identifier(name, &self.name_resolution_ctx).name;
+                if constraints.get(&name).is_some() {
+                    return Err(ErrorCode::ConstraintAlreadyExists(format!(
+                        "constraint {} already exists",
+                        name
+                    )));
+                }
+                name
+            }
+            None => (1..)
+                .map(|i| format!("{prefix}_{i}"))
+                .find(|name| constraints.get(name).is_none())
+                .unwrap(),
+        };
+        Ok((name, table_constraint))
+    }
+
+    /// Collects the masking policies bound to columns, keyed by column name.
+    fn analyze_column_mask_policy(
+        &self,
+        columns: &[ColumnDefinition],
+    ) -> Option<BTreeMap<String, String>> {
+        let column_mask_policy = columns
+            .iter()
+            .filter_map(|column| {
+                column.mask_policy.as_ref().map(|mask_policy| {
+                    let name = normalize_identifier(&column.name, &self.name_resolution_ctx).name;
+                    (name, mask_policy.clone())
+                })
+            })
+            .collect::<BTreeMap<_, _>>();
+        if column_mask_policy.is_empty() {
+            None
+        } else {
+            Some(column_mask_policy)
+        }
+    }
+
+    /// Only integer columns can be generated by sequences.
+    fn validate_sequence_column(field: &TableField) -> Result<()> {
+        match field.data_type().remove_nullable() {
+            TableDataType::Number(ty) if ty.is_integer() => Ok(()),
+            ty => Err(ErrorCode::SemanticError(format!(
+                "column {} generated by a sequence must be an integer, but got {}",
+                field.name(),
+                ty
+            ))),
         }
     }
 
@@ -26,6 +26,7 @@ use common_ast::ast::ShowTasksStmt;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use cron;
+use itertools::Itertools;
 
 use crate::plans::AlterTaskPlan;
 use crate::plans::CreateTaskPlan;
@@ -54,6 +55,16 @@ fn verify_scheduler_option(schedule_opts: &ScheduleOptions) -> Result<()> {
     Ok(())
 }
 
+/// A task can not be its own predecessor, the longer cycles are rejected by the task service.
+fn verify_task_after(task_name: &str, after: &[String]) -> Result<()> {
+    if after.iter().any(|predecessor| predecessor == task_name) {
+        return Err(ErrorCode::SemanticError(format!(
+            "task {task_name} can not run after itself"
+        )));
+    }
+    Ok(())
+}
+
 impl Binder {
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_create_task(
@@ -65,12 +76,28 @@ impl Binder {
             name,
             warehouse_opts,
             schedule_opts,
+            after,
             suspend_task_after_num_failures,
+            error_integration,
             comments,
             sql,
         } = stmt;
 
-        verify_scheduler_option(schedule_opts)?;
+        match (schedule_opts, after.is_empty()) {
+            (Some(schedule_opts), true) => verify_scheduler_option(schedule_opts)?,
+            (None, false) => verify_task_after(name, after)?,
+            (Some(_), false) => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "task {name} can not have both SCHEDULE and AFTER, it runs after its predecessors finish"
+                )));
+            }
+            (None, true) => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "task {name} must have either SCHEDULE or AFTER"
+                )));
+            }
+        }
+        let after = after.iter().unique().cloned().collect();
 
         let tenant = self.ctx.get_tenant();
         let plan = CreateTaskPlan {
@@ -79,7 +106,9 @@ impl Binder {
             task_name: name.to_string(),
             warehouse_opts: warehouse_opts.clone(),
             schedule_opts: schedule_opts.clone(),
+            after,
             suspend_task_after_num_failures: *suspend_task_after_num_failures,
+            error_integration: error_integration.clone(),
             comment: comments.clone(),
             sql: sql.clone(),
         };
@@ -101,12 +130,14 @@ impl Binder {
             warehouse,
             schedule,
             suspend_task_after_num_failures,
+            error_integration,
             comments,
         } = options
         {
             if warehouse.is_none()
                 && schedule.is_none()
                 && suspend_task_after_num_failures.is_none()
+                && error_integration.is_none()
                 && comments.is_none()
             {
                 return Err(ErrorCode::SyntaxException(
@@ -117,6 +148,9 @@ impl Binder {
                 verify_scheduler_option(schedule)?;
             }
         }
+        if let AlterTaskOptions::AddAfter(after) = options {
+            verify_task_after(name, after)?;
+        }
 
         let tenant = self.ctx.get_tenant();
         let plan = AlterTaskPlan {
@@ -82,7 +82,8 @@ impl<'a> Binder {
             ));
         };
 
-        let (table_expr, mut context) = self.bind_single_table(bind_context, table).await?;
+        let (table_expr, mut context) =
+            self.bind_mutation_target_table(bind_context, table).await?;
 
         context.allow_internal_columns(false);
         let mut scalar_binder = ScalarBinder::new(
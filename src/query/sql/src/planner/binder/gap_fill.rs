@@ -0,0 +1,205 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_ast::ast::Expr;
+use common_ast::ast::GapFill as AstGapFill;
+use common_ast::ast::GapFillMethod as AstGapFillMethod;
+use common_ast::ast::Identifier;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::timestamp::MICROS_IN_A_DAY;
+use common_expression::types::DataType;
+use common_expression::ConstantFolder;
+use common_expression::Scalar;
+use common_functions::BUILTIN_FUNCTIONS;
+
+use super::wrap_cast;
+use crate::normalize_identifier;
+use crate::optimizer::SExpr;
+use crate::planner::semantic::TypeChecker;
+use crate::plans::GapFill;
+use crate::plans::GapFillItem;
+use crate::plans::GapFillMethod;
+use crate::BindContext;
+use crate::Binder;
+use crate::IndexType;
+
+impl Binder {
+    /// Bind `GAP_FILL` clause on top of the projection. The columns of the select list,
+    /// other than the time column and the partition by columns, are replaced by their
+    /// nullable counterparts, so that the generated rows can hold NULL values.
+    #[async_backtrace::framed]
+    pub async fn bind_gap_fill(
+        &mut self,
+        bind_context: &mut BindContext,
+        gap_fill: &AstGapFill,
+        child: SExpr,
+    ) -> Result<SExpr> {
+        let time_column = self.resolve_gap_fill_column(bind_context, &gap_fill.time_column)?;
+        let time_type = bind_context.columns[time_column]
+            .data_type
+            .remove_nullable();
+        let is_date = match time_type {
+            DataType::Timestamp => false,
+            DataType::Date => true,
+            _ => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "GAP_FILL time column must be a TIMESTAMP or a DATE, but got {time_type}"
+                ))
+                .set_span(gap_fill.time_column.span));
+            }
+        };
+
+        let mut constant_context = BindContext::new();
+        let mut type_checker = TypeChecker::try_create(
+            &mut constant_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            false,
+            false,
+        )?;
+        let mut interval = type_checker
+            .resolve_interval_micros(&gap_fill.interval)
+            .await?;
+        if is_date {
+            if interval % MICROS_IN_A_DAY != 0 {
+                return Err(ErrorCode::SemanticError(
+                    "GAP_FILL interval of dates must be a whole number of days".to_string(),
+                )
+                .set_span(gap_fill.interval.span()));
+            }
+            interval /= MICROS_IN_A_DAY;
+        }
+
+        let (start, end) = match &gap_fill.range {
+            Some((start, end)) => {
+                let start = self
+                    .resolve_gap_fill_bound(&mut type_checker, start, &time_type)
+                    .await?;
+                let end = self
+                    .resolve_gap_fill_bound(&mut type_checker, end, &time_type)
+                    .await?;
+                (Some(start), Some(end))
+            }
+            None => (None, None),
+        };
+
+        let mut partition_by = Vec::with_capacity(gap_fill.partition_by.len());
+        let mut keys = vec![time_column];
+        for ident in gap_fill.partition_by.iter() {
+            let offset = self.resolve_gap_fill_column(bind_context, ident)?;
+            partition_by.push(bind_context.columns[offset].index);
+            keys.push(offset);
+        }
+        let key_indices = keys
+            .iter()
+            .map(|offset| bind_context.columns[*offset].index)
+            .collect::<Vec<_>>();
+
+        // Replace the other columns by new nullable columns.
+        let mut items: Vec<GapFillItem> = vec![];
+        let mut outputs: HashMap<IndexType, IndexType> = HashMap::new();
+        for column in bind_context.columns.iter_mut() {
+            if key_indices.contains(&column.index) {
+                continue;
+            }
+            let data_type = column.data_type.wrap_nullable();
+            let output = *outputs.entry(column.index).or_insert_with(|| {
+                let output = self
+                    .metadata
+                    .write()
+                    .add_derived_column(column.column_name.clone(), data_type.clone());
+                items.push(GapFillItem {
+                    input: column.index,
+                    output,
+                });
+                output
+            });
+            column.index = output;
+            column.data_type = Box::new(data_type);
+            column.table_index = None;
+            column.column_position = None;
+        }
+
+        let gap_fill = GapFill {
+            time_column: bind_context.columns[time_column].index,
+            partition_by,
+            interval,
+            start,
+            end,
+            method: match gap_fill.method {
+                AstGapFillMethod::Null => GapFillMethod::Null,
+                AstGapFillMethod::Locf => GapFillMethod::Locf,
+                AstGapFillMethod::Linear => GapFillMethod::Linear,
+            },
+            items,
+        };
+        Ok(SExpr::create_unary(
+            Arc::new(gap_fill.into()),
+            Arc::new(child),
+        ))
+    }
+
+    /// Resolve a column of `GAP_FILL` by its name in the select list, returns its offset.
+    fn resolve_gap_fill_column(
+        &self,
+        bind_context: &BindContext,
+        ident: &Identifier,
+    ) -> Result<usize> {
+        let name = normalize_identifier(ident, &self.name_resolution_ctx).name;
+        bind_context
+            .columns
+            .iter()
+            .position(|column| column.column_name == name)
+            .ok_or_else(|| {
+                ErrorCode::SemanticError(format!(
+                    "GAP_FILL column {name} must appear in the select list"
+                ))
+                .set_span(ident.span)
+            })
+    }
+
+    async fn resolve_gap_fill_bound(
+        &self,
+        type_checker: &mut TypeChecker<'_>,
+        bound: &Expr,
+        time_type: &DataType,
+    ) -> Result<i64> {
+        let (scalar, _) = *type_checker.resolve(bound).await?;
+        let scalar = wrap_cast(&scalar, time_type);
+        let expr = scalar.as_expr()?;
+
+        let (new_expr, _) =
+            ConstantFolder::fold(&expr, &self.ctx.get_function_context()?, &BUILTIN_FUNCTIONS);
+        match new_expr {
+            common_expression::Expr::Constant {
+                scalar: Scalar::Timestamp(ts),
+                ..
+            } => Ok(ts),
+            common_expression::Expr::Constant {
+                scalar: Scalar::Date(date),
+                ..
+            } => Ok(date as i64),
+            _ => Err(ErrorCode::SemanticError(
+                "GAP_FILL range must be constant values".to_string(),
+            )
+            .set_span(bound.span())),
+        }
+    }
+}
@@ -21,8 +21,11 @@ use common_ast::ast::InsertStmt;
 use common_ast::ast::Statement;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::DataSchemaRef;
 use common_expression::TableSchema;
 use common_expression::TableSchemaRefExt;
+use common_functions::is_lossless_cast;
 use common_meta_app::principal::FileFormatOptionsAst;
 use common_meta_app::principal::OnErrorMode;
 
@@ -150,6 +153,9 @@ impl Binder {
             InsertSource::Select { query } => {
                 let statement = Statement::Query(query);
                 let select_plan = self.bind_statement(bind_context, &statement).await?;
+                if self.ctx.get_settings().get_strict_type_coercion()? {
+                    Self::check_strict_insert_types(&select_plan.schema(), &schema)?;
+                }
                 let opt_ctx = Arc::new(OptimizerContext::new(OptimizerConfig {
                     enable_distributed_optimization: !self.ctx.get_cluster().is_empty(),
                 }));
@@ -170,4 +176,28 @@ impl Binder {
 
         Ok(Plan::Insert(Box::new(plan)))
     }
+
+    /// In the strict type coercion mode, the columns of the select statement can only be
+    /// implicitly cast to the columns of the table without losing information. The
+    /// nullability is checked when the rows are inserted.
+    fn check_strict_insert_types(
+        select_schema: &DataSchemaRef,
+        insert_schema: &TableSchema,
+    ) -> Result<()> {
+        for (select_field, insert_field) in select_schema
+            .fields()
+            .iter()
+            .zip(insert_schema.fields().iter())
+        {
+            let src_ty = select_field.data_type();
+            let dest_ty = DataType::from(insert_field.data_type());
+            if !is_lossless_cast(src_ty, &dest_ty.wrap_nullable()) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "cannot implicitly cast {src_ty} to {dest_ty} for column {} when type_coercion_mode is strict, use an explicit CAST instead",
+                    insert_field.name()
+                )));
+            }
+        }
+        Ok(())
+    }
 }
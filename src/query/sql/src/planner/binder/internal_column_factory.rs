@@ -17,6 +17,7 @@ use std::collections::BTreeMap;
 use common_catalog::plan::InternalColumn;
 use common_catalog::plan::InternalColumnType;
 use common_expression::BLOCK_NAME_COL_NAME;
+use common_expression::FILE_NAME_COL_NAME;
 use common_expression::ROW_ID_COL_NAME;
 use common_expression::SEGMENT_NAME_COL_NAME;
 use common_expression::SNAPSHOT_NAME_COL_NAME;
@@ -53,6 +54,11 @@ impl InternalColumnFactory {
             InternalColumn::new(SNAPSHOT_NAME_COL_NAME, InternalColumnType::SnapshotName),
         );
 
+        internal_columns.insert(
+            FILE_NAME_COL_NAME.to_string(),
+            InternalColumn::new(FILE_NAME_COL_NAME, InternalColumnType::FileName),
+        );
+
         InternalColumnFactory { internal_columns }
     }
 
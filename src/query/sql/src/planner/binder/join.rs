@@ -238,6 +238,7 @@ impl Binder {
             from_correlated_subquery: false,
             contain_runtime_filter: false,
             need_hold_hash_table: false,
+            null_aware: false,
         };
         Ok(SExpr::create_binary(
             Arc::new(logical_join.into()),
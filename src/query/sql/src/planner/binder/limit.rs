@@ -15,13 +15,20 @@
 use std::sync::Arc;
 
 use common_ast::ast::Expr;
+use common_ast::ast::LimitBy as AstLimitBy;
 use common_ast::ast::Literal;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
+use crate::binder::scalar::ScalarBinder;
 use crate::binder::Binder;
 use crate::optimizer::SExpr;
+use crate::plans::EvalScalar;
 use crate::plans::Limit;
+use crate::plans::LimitBy;
+use crate::plans::ScalarExpr;
+use crate::plans::ScalarItem;
+use crate::BindContext;
 
 impl Binder {
     pub(super) fn analyze_limit(
@@ -57,6 +64,56 @@ impl Binder {
         SExpr::create_unary(Arc::new(limit_plan.into()), Arc::new(child))
     }
 
+    /// Bind `LIMIT n BY` clause on top of the ordered query. The keys are resolved against
+    /// the output columns of the query, expressions other than columns are evaluated first.
+    #[async_backtrace::framed]
+    pub(super) async fn bind_limit_by(
+        &mut self,
+        bind_context: &mut BindContext,
+        child: SExpr,
+        limit_by: &AstLimitBy,
+    ) -> Result<SExpr> {
+        let limit = Self::bind_limit_argument(&limit_by.limit).ok_or_else(|| {
+            ErrorCode::SemanticError("Invalid LIMIT BY expression").set_span(limit_by.limit.span())
+        })? as usize;
+
+        let mut scalar_binder = ScalarBinder::new(
+            bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            self.m_cte_bound_ctx.clone(),
+            self.ctes_map.clone(),
+        );
+        let mut by = Vec::with_capacity(limit_by.by.len());
+        let mut items = vec![];
+        for expr in limit_by.by.iter() {
+            let (scalar, _) = scalar_binder.bind(expr).await?;
+            match scalar {
+                ScalarExpr::BoundColumnRef(column_ref) => by.push(column_ref.column.index),
+                _ => {
+                    let index = self
+                        .metadata
+                        .write()
+                        .add_derived_column(expr.to_string(), scalar.data_type()?);
+                    items.push(ScalarItem { scalar, index });
+                    by.push(index);
+                }
+            }
+        }
+
+        let mut s_expr = child;
+        if !items.is_empty() {
+            s_expr = SExpr::create_unary(Arc::new(EvalScalar { items }.into()), Arc::new(s_expr));
+        }
+        let limit_by = LimitBy { limit, by };
+        Ok(SExpr::create_unary(
+            Arc::new(limit_by.into()),
+            Arc::new(s_expr),
+        ))
+    }
+
     /// So far, we only support integer literal as limit argument.
     /// So we will try to extract the integer value from the AST directly.
     /// In the future it's possible to treat the argument as an expression.
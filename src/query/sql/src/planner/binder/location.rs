@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::io::Error;
 use std::io::ErrorKind;
 use std::io::Result;
@@ -38,6 +39,8 @@ use common_meta_app::storage::STORAGE_S3_DEFAULT_ENDPOINT;
 use common_storage::STDIN_FD;
 use opendal::Scheme;
 use percent_encoding::percent_decode_str;
+use storages_common_table_meta::table::OPT_KEY_REPLICATION_CONNECTION;
+use storages_common_table_meta::table::OPT_KEY_REPLICATION_URI;
 
 /// secure_omission will fix omitted endpoint url schemes into 'https://'
 #[inline]
@@ -159,6 +162,13 @@ fn parse_s3_params(l: &mut UriLocation, root: String) -> Result<StorageParams> {
     }
     .to_string();
 
+    if role_arn.is_empty() && !external_id.is_empty() {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            anyhow!("external_id is only used with role_arn for storage s3"),
+        ));
+    }
+
     let allow_anonymous = {
         if let Some(s) = l.connection.get("allow_anonymous") {
             s
@@ -185,8 +195,12 @@ fn parse_s3_params(l: &mut UriLocation, root: String) -> Result<StorageParams> {
         master_key,
         root,
         // Disable credential load by default.
-        // TODO(xuanwo): we should support AssumeRole.
-        disable_credential_loader: !GlobalConfig::instance().storage.allow_insecure,
+        //
+        // The role is assumed by the credential of the node (env, profile, instance
+        // metadata or web identity), so it must be loaded if role_arn is given. The
+        // temporary credentials of the role are refreshed before they expire.
+        disable_credential_loader: role_arn.is_empty()
+            && !GlobalConfig::instance().storage.allow_insecure,
         enable_virtual_host_style,
         role_arn,
         external_id,
@@ -209,6 +223,11 @@ fn parse_gcs_params(l: &mut UriLocation) -> Result<StorageParams> {
         bucket: l.name.clone(),
         root: l.path.clone(),
         credential: l.connection.get("credential").cloned().unwrap_or_default(),
+        service_account: l
+            .connection
+            .get("service_account")
+            .cloned()
+            .unwrap_or_default(),
     });
 
     l.connection.check()?;
@@ -496,3 +515,32 @@ pub async fn parse_uri_location(
 
     Ok((sp, path))
 }
+
+/// Parse the replication target of a table from its table options.
+///
+/// Returns `None` if replication is not enabled for the table.
+pub async fn parse_replication_location(
+    ctx: &Arc<dyn TableContext>,
+    options: &BTreeMap<String, String>,
+) -> Result<Option<StorageParams>> {
+    let Some(uri) = options.get(OPT_KEY_REPLICATION_URI) else {
+        return Ok(None);
+    };
+
+    let mut conns = BTreeMap::new();
+    if let Some(name) = options.get(OPT_KEY_REPLICATION_CONNECTION) {
+        conns.insert("connection_name".to_string(), name.clone());
+    }
+    let mut l = UriLocation::from_uri(uri.clone(), "".to_string(), conns).map_err(|err| {
+        Error::new(
+            ErrorKind::InvalidInput,
+            anyhow!("invalid replication uri {uri}: {err}"),
+        )
+    })?;
+    // The replication target is always a directory.
+    if !l.path.ends_with('/') {
+        l.path.push('/');
+    }
+    let (sp, _) = parse_uri_location(&mut l, Some(ctx)).await?;
+    Ok(Some(sp))
+}
@@ -261,7 +261,7 @@ impl Binder {
         // when the target table has been binded in bind_merge_into_source
         // bind table for target table
         let (mut target_expr, mut target_context) = self
-            .bind_single_table(&mut source_context, &target_table)
+            .bind_mutation_target_table(&mut source_context, &target_table)
             .await?;
 
         // add internal_column (_row_id)
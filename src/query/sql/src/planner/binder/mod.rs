@@ -20,11 +20,13 @@ mod binder;
 mod builders;
 mod call;
 mod column_binding;
+mod connect_by;
 mod copy_into_location;
 mod copy_into_table;
 mod ddl;
 mod delete;
 mod distinct;
+mod gap_fill;
 mod having;
 mod insert;
 mod internal_column_factory;
@@ -40,6 +42,7 @@ mod qualify;
 mod replace;
 mod scalar;
 mod scalar_common;
+mod script;
 mod select;
 mod setting;
 mod show;
@@ -61,6 +64,7 @@ pub use column_binding::ColumnBinding;
 pub use column_binding::ColumnBindingBuilder;
 pub use copy_into_table::resolve_stage_location;
 pub use internal_column_factory::INTERNAL_COLUMN_FACTORY;
+pub use location::parse_replication_location;
 pub use location::parse_uri_location;
 pub use merge_into::MergeIntoType;
 pub use scalar::ScalarBinder;
@@ -18,7 +18,7 @@ use common_ast::ast::Expr;
 use common_ast::ast::Identifier;
 use common_ast::ast::Lambda;
 use common_ast::ast::Literal;
-use common_ast::ast::Window;
+use common_ast::ast::WindowDesc;
 use common_ast::Visitor;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -52,7 +52,7 @@ impl<'a> Visitor<'a> for SrfCollector {
         name: &'a Identifier,
         args: &'a [Expr],
         params: &'a [Literal],
-        over: &'a Option<Window>,
+        over: &'a Option<WindowDesc>,
         lambda: &'a Option<Lambda>,
     ) {
         if BUILTIN_FUNCTIONS
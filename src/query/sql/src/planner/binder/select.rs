@@ -44,6 +44,7 @@ use common_expression::types::DataType;
 use common_expression::ROW_ID_COL_NAME;
 use common_functions::BUILTIN_FUNCTIONS;
 use log::warn;
+use storages_common_table_meta::table::CryptoShredColumns;
 
 use super::sort::OrderItem;
 use super::Finder;
@@ -236,7 +237,7 @@ impl Binder {
             .await?;
 
         // After all analysis is done.
-        if set_returning_functions.is_empty() {
+        if set_returning_functions.is_empty() && stmt.gap_fill.is_none() {
             // Ignore SRFs.
             self.analyze_lazy_materialization(
                 &from_context,
@@ -295,6 +296,13 @@ impl Binder {
 
         s_expr = self.bind_projection(&mut from_context, &projections, &scalar_items, s_expr)?;
 
+        // `GAP_FILL` generates rows for the output of the projection.
+        if let Some(gap_fill) = &stmt.gap_fill {
+            s_expr = self
+                .bind_gap_fill(&mut from_context, gap_fill, s_expr)
+                .await?;
+        }
+
         // rewrite udf
         let mut udf_rewriter = UdfRewriter::new(self.metadata.clone());
         s_expr = udf_rewriter.rewrite(&s_expr)?;
@@ -385,19 +393,26 @@ impl Binder {
             (None, 0)
         };
 
-        let (mut s_expr, bind_context) = match query.body {
-            SetExpr::Select(_) | SetExpr::Query(_) => {
-                self.bind_set_expr(
-                    bind_context,
-                    &query.body,
-                    &query.order_by,
-                    limit.unwrap_or_default(),
-                )
-                .await?
+        // The sort can only be limited if all its rows are taken in order, which is not
+        // the case with `LIMIT BY`.
+        let sort_limit = match query.limit_by {
+            Some(_) => 0,
+            None => limit.unwrap_or_default(),
+        };
+
+        // The ORDER BY of a query with `GAP_FILL` must be bound over the filled rows.
+        let has_gap_fill = matches!(&query.body, SetExpr::Select(stmt) if stmt.gap_fill.is_some());
+        let (mut s_expr, mut bind_context) = match query.body {
+            SetExpr::Select(_) | SetExpr::Query(_) if !has_gap_fill => {
+                self.bind_set_expr(bind_context, &query.body, &query.order_by, sort_limit)
+                    .await?
             }
-            SetExpr::SetOperation(_) | SetExpr::Values { .. } => {
+            SetExpr::Select(_)
+            | SetExpr::Query(_)
+            | SetExpr::SetOperation(_)
+            | SetExpr::Values { .. } => {
                 let (mut s_expr, mut bind_context) = self
-                    .bind_set_expr(bind_context, &query.body, &[], limit.unwrap_or_default())
+                    .bind_set_expr(bind_context, &query.body, &[], sort_limit)
                     .await?;
                 if !query.order_by.is_empty() {
                     s_expr = self
@@ -408,6 +423,12 @@ impl Binder {
             }
         };
 
+        if let Some(limit_by) = &query.limit_by {
+            s_expr = self
+                .bind_limit_by(&mut bind_context, s_expr, limit_by)
+                .await?;
+        }
+
         if !query.limit.is_empty() || query.offset.is_some() {
             s_expr = Self::bind_limit(s_expr, limit, offset);
         }
@@ -841,6 +862,11 @@ impl Binder {
             return Ok(());
         }
 
+        // The rows fetcher reads the blocks directly, it can't decrypt crypto-shredded columns.
+        if CryptoShredColumns::from_options(metadata.table(0).table().options())?.is_some() {
+            return Ok(());
+        }
+
         let cols = metadata.columns();
 
         let virtual_cols = cols
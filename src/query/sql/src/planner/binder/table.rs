@@ -32,12 +32,14 @@ use common_ast::ast::Query;
 use common_ast::ast::SelectStageOptions;
 use common_ast::ast::SelectStmt;
 use common_ast::ast::SelectTarget;
+use common_ast::ast::SetExpr;
 use common_ast::ast::Statement;
 use common_ast::ast::TableAlias;
 use common_ast::ast::TableReference;
 use common_ast::ast::TimeTravelPoint;
 use common_ast::ast::UriLocation;
 use common_ast::parser::parse_sql;
+use common_ast::parser::quote::quote_ident;
 use common_ast::parser::tokenize_sql;
 use common_catalog::catalog_kind::CATALOG_DEFAULT;
 use common_catalog::plan::ParquetReadOptions;
@@ -57,6 +59,7 @@ use common_expression::ColumnId;
 use common_expression::ConstantFolder;
 use common_expression::DataField;
 use common_expression::FunctionKind;
+use common_expression::RemoteExpr;
 use common_expression::Scalar;
 use common_expression::TableDataType;
 use common_expression::TableField;
@@ -84,6 +87,7 @@ use common_users::UserApiProvider;
 use dashmap::DashMap;
 use log::info;
 use parking_lot::RwLock;
+use storages_common_table_meta::table::DedupColumns;
 
 use crate::binder::copy_into_table::resolve_file_location;
 use crate::binder::scalar::ScalarBinder;
@@ -98,6 +102,7 @@ use crate::optimizer::SExpr;
 use crate::planner::semantic::normalize_identifier;
 use crate::planner::semantic::TypeChecker;
 use crate::plans::BoundColumnRef;
+use crate::plans::BucketInfo;
 use crate::plans::ConstantExpr;
 use crate::plans::CteScan;
 use crate::plans::EvalScalar;
@@ -309,6 +314,20 @@ impl Binder {
                 }
             }
             _ => {
+                if !self.raw_dedup_tables {
+                    if let Some(dedup) = DedupColumns::from_options(table_meta.options())? {
+                        return self
+                            .bind_dedup_table(
+                                bind_context,
+                                *span,
+                                (catalog, database, table_name),
+                                alias,
+                                travel_point,
+                                &dedup,
+                            )
+                            .await;
+                    }
+                }
                 if table_meta.engine() == "STREAM" {
                     bind_context.allow_internal_columns(false);
                 }
@@ -333,6 +352,84 @@ impl Binder {
         }
     }
 
+    /// Binds a table with dedup keys as
+    /// `SELECT * FROM t QUALIFY row_number() OVER (PARTITION BY <keys> ORDER BY <version> DESC) = 1`,
+    /// which only reads the latest row among the rows sharing the same keys.
+    #[async_backtrace::framed]
+    async fn bind_dedup_table(
+        &mut self,
+        bind_context: &mut BindContext,
+        span: Span,
+        (catalog, database, table_name): (String, String, String),
+        alias: &Option<TableAlias>,
+        travel_point: &Option<TimeTravelPoint>,
+        dedup: &DedupColumns,
+    ) -> Result<(SExpr, BindContext)> {
+        let quote = |name: &str| quote_ident(name, '`', true);
+        let sql = format!(
+            "SELECT * FROM t QUALIFY row_number() OVER \
+             (PARTITION BY {} ORDER BY {} DESC NULLS LAST) = 1",
+            dedup
+                .keys
+                .iter()
+                .map(|k| quote(k))
+                .collect::<Vec<_>>()
+                .join(", "),
+            quote(&dedup.version_column),
+        );
+        let tokens = tokenize_sql(&sql)?;
+        let (stmt, _) = parse_sql(&tokens, self.dialect)?;
+        let Statement::Query(mut query) = stmt else {
+            return Err(ErrorCode::Internal("Invalid dedup query"));
+        };
+        if let SetExpr::Select(select) = &mut query.body {
+            select.from = vec![TableReference::Table {
+                span,
+                catalog: Some(Identifier::from_name_with_quoted(catalog, Some('`'))),
+                database: Some(Identifier::from_name_with_quoted(
+                    database.clone(),
+                    Some('`'),
+                )),
+                table: Identifier::from_name_with_quoted(table_name.clone(), Some('`')),
+                alias: None,
+                travel_point: travel_point.clone(),
+                pivot: None,
+                unpivot: None,
+            }];
+        }
+
+        let mut new_bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
+        let raw_dedup_tables = std::mem::replace(&mut self.raw_dedup_tables, true);
+        let res = self.bind_query(&mut new_bind_context, &query).await;
+        self.raw_dedup_tables = raw_dedup_tables;
+        let (s_expr, mut new_bind_context) = res?;
+
+        if let Some(alias) = alias {
+            new_bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
+        } else {
+            for column in new_bind_context.columns.iter_mut() {
+                column.database_name = Some(database.clone());
+                column.table_name = Some(table_name.clone());
+            }
+        }
+        new_bind_context.parent = Some(Box::new(bind_context.clone()));
+        Ok((s_expr, new_bind_context))
+    }
+
+    /// Binds the target table of a mutation, the duplicated rows of the tables with dedup keys
+    /// are not collapsed.
+    #[async_backtrace::framed]
+    pub(crate) async fn bind_mutation_target_table(
+        &mut self,
+        bind_context: &mut BindContext,
+        table_ref: &TableReference,
+    ) -> Result<(SExpr, BindContext)> {
+        let raw_dedup_tables = std::mem::replace(&mut self.raw_dedup_tables, true);
+        let res = self.bind_single_table(bind_context, table_ref).await;
+        self.raw_dedup_tables = raw_dedup_tables;
+        res
+    }
+
     /// Extract the srf inner tuple fields as columns.
     #[async_backtrace::framed]
     async fn extract_srf_table_function_columns(
@@ -567,6 +664,7 @@ impl Binder {
                 having: None,
                 window_list: None,
                 qualify: None,
+                gap_fill: None,
             };
             let (srf_expr, mut bind_context) = self
                 .bind_select_stmt(bind_context, &select_stmt, &[], 0)
@@ -583,6 +681,12 @@ impl Binder {
                 .await;
         }
 
+        if func_name.name.eq_ignore_ascii_case("generate_path") {
+            return self
+                .bind_generate_path(bind_context, span, params, named_params, alias)
+                .await;
+        }
+
         let mut scalar_binder = ScalarBinder::new(
             bind_context,
             self.ctx.clone(),
@@ -893,6 +997,7 @@ impl Binder {
                     files_to_copy: None,
                     is_select: true,
                     default_values: None,
+                    format_overrides: Default::default(),
                 };
                 StageTable::try_create(info)?
             }
@@ -920,6 +1025,7 @@ impl Binder {
                     files_to_copy: None,
                     is_select: true,
                     default_values: None,
+                    format_overrides: Default::default(),
                 };
                 StageTable::try_create(info)?
             }
@@ -1184,6 +1290,39 @@ impl Binder {
         Ok((s_expr, new_bind_context))
     }
 
+    /// Bind the cluster keys of a bucketed table as its bucket keys. The buckets only
+    /// matter in cluster mode, where the rows are distributed to the executors by them.
+    #[async_backtrace::framed]
+    async fn bind_bucket_info(
+        &self,
+        table: &Arc<dyn Table>,
+        bind_context: &BindContext,
+    ) -> Result<Option<BucketInfo>> {
+        if self.ctx.get_cluster().is_empty() {
+            return Ok(None);
+        }
+        let Some(num_buckets) = table.bucket_num(self.ctx.clone()).await? else {
+            return Ok(None);
+        };
+
+        let mut keys = vec![];
+        for cluster_key in table.cluster_keys(self.ctx.clone()) {
+            let RemoteExpr::ColumnRef { id, .. } = cluster_key else {
+                return Ok(None);
+            };
+            let Some(column) = bind_context.columns.iter().find(|column| {
+                column.column_name == id && column.visibility == Visibility::Visible
+            }) else {
+                return Ok(None);
+            };
+            keys.push(ScalarExpr::BoundColumnRef(BoundColumnRef {
+                span: None,
+                column: column.clone(),
+            }));
+        }
+        Ok(Some(BucketInfo { keys, num_buckets }))
+    }
+
     #[async_backtrace::framed]
     pub(crate) async fn bind_base_table(
         &mut self,
@@ -1295,6 +1434,7 @@ impl Binder {
         }
 
         let stat = table.table_statistics().await?;
+        let bucket_info = self.bind_bucket_info(&table, &bind_context).await?;
         let scan = SExpr::create_leaf(Arc::new(
             Scan {
                 table_index,
@@ -1303,6 +1443,7 @@ impl Binder {
                     statistics: stat,
                     col_stats,
                 },
+                bucket_info,
                 ..Default::default()
             }
             .into(),
@@ -1345,16 +1486,23 @@ impl Binder {
         // Resolve table with ctx
         // for example: select * from t1 join (select * from t1 as t2 where a > 1 and a < 13);
         // we will invoke here twice for t1, so in the past, we use catalog every time to get the
-        // newest snapshot, we can't get consistent snapshot
-        let mut table_meta = self
-            .ctx
-            .get_table(catalog_name, database_name, table_name)
-            .await?;
-
-        if let Some(tp) = travel_point {
-            table_meta = table_meta.navigate_to(tp).await?;
+        // newest snapshot, we can't get consistent snapshot.
+        // The table is also pinned to its current snapshot here, so that it is read at the
+        // same snapshot during the whole query, even if it is committed concurrently.
+        match travel_point {
+            Some(tp) => {
+                let table_meta = self
+                    .ctx
+                    .get_table(catalog_name, database_name, table_name)
+                    .await?;
+                table_meta.navigate_to(tp).await
+            }
+            None => {
+                self.ctx
+                    .get_pinned_table(catalog_name, database_name, table_name)
+                    .await
+            }
         }
-        Ok(table_meta)
     }
 
     #[async_backtrace::framed]
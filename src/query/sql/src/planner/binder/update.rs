@@ -63,7 +63,8 @@ impl Binder {
             ));
         };
 
-        let (table_expr, mut context) = self.bind_single_table(bind_context, table).await?;
+        let (table_expr, mut context) =
+            self.bind_mutation_target_table(bind_context, table).await?;
 
         let table = self
             .ctx
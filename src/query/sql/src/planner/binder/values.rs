@@ -20,6 +20,7 @@ use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::Span;
+use common_expression::type_check::check_cast;
 use common_expression::type_check::common_super_type;
 use common_expression::types::DataType;
 use common_expression::ColumnBuilder;
@@ -28,6 +29,9 @@ use common_expression::DataField;
 use common_expression::DataSchema;
 use common_expression::DataSchemaRefExt;
 use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
 use common_functions::BUILTIN_FUNCTIONS;
 use indexmap::IndexMap;
 
@@ -35,6 +39,7 @@ use crate::binder::wrap_cast_scalar;
 use crate::optimizer::ColumnSet;
 use crate::optimizer::SExpr;
 use crate::plans::ConstantTableScan;
+use crate::plans::ScalarExpr;
 use crate::BindContext;
 use crate::Binder;
 use crate::ColumnBindingBuilder;
@@ -144,38 +149,71 @@ pub async fn bind_values(
     }
     let value_schema = DataSchema::new(value_fields);
 
-    let input = DataBlock::empty();
     let func_ctx = ctx.get_function_context()?;
-    let evaluator = Evaluator::new(&input, &func_ctx, &BUILTIN_FUNCTIONS);
 
     // use values to build columns
     let mut value_columns = Vec::with_capacity(col_scalars.len());
     for (scalars, value_field) in col_scalars.iter().zip(value_schema.fields().iter()) {
-        let mut builder = ColumnBuilder::with_capacity(value_field.data_type(), col_scalars.len());
-        for (scalar, value_type) in scalars {
-            let scalar = if value_type != value_field.data_type() {
-                wrap_cast_scalar(scalar, value_type, value_field.data_type())?
-            } else {
-                scalar.clone()
-            };
-            let expr = scalar
-                .as_expr()?
-                .project_column_ref(|col| value_schema.index_of(&col.index.to_string()).unwrap());
-            let result = evaluator.run(&expr)?;
-
-            match result.as_scalar() {
-                Some(val) => {
-                    builder.push(val.as_ref());
+        let data_type = value_field.data_type();
+        let mut cells: Vec<Option<Scalar>> = vec![None; num_rows];
+        // Constants of the same type that need to be cast are grouped, so that each
+        // group is cast as a whole column instead of evaluating the cast row by row.
+        let mut cast_groups: HashMap<DataType, Vec<(usize, Scalar)>> = HashMap::new();
+        for (row, (scalar, value_type)) in scalars.iter().enumerate() {
+            match scalar {
+                ScalarExpr::ConstantExpr(constant) if value_type == data_type => {
+                    cells[row] = Some(constant.value.clone());
                 }
-                None => {
-                    return Err(ErrorCode::SemanticError(format!(
-                        "Value must be a scalar, but get {}",
-                        result
-                    ))
-                    .set_span(span));
+                ScalarExpr::ConstantExpr(constant) => {
+                    cast_groups
+                        .entry(value_type.clone())
+                        .or_default()
+                        .push((row, constant.value.clone()));
                 }
+                _ => {
+                    cells[row] = Some(eval_value(
+                        &func_ctx,
+                        &value_schema,
+                        scalar,
+                        value_type,
+                        data_type,
+                        span,
+                    )?);
+                }
+            }
+        }
+
+        for (value_type, group) in cast_groups {
+            let mut builder = ColumnBuilder::with_capacity(&value_type, group.len());
+            for (_, value) in group.iter() {
+                builder.push(value.as_ref());
+            }
+            let input = DataBlock::new_from_columns(vec![builder.build()]);
+            let expr = check_cast(
+                span,
+                false,
+                Expr::ColumnRef {
+                    span,
+                    id: 0,
+                    data_type: value_type,
+                    display_name: value_field.name().clone(),
+                },
+                data_type,
+                &BUILTIN_FUNCTIONS,
+            )?;
+            let evaluator = Evaluator::new(&input, &func_ctx, &BUILTIN_FUNCTIONS);
+            let column = evaluator
+                .run(&expr)?
+                .convert_to_full_column(data_type, group.len());
+            for (i, (row, _)) in group.iter().enumerate() {
+                cells[*row] = Some(column.index(i).unwrap().to_owned());
             }
         }
+
+        let mut builder = ColumnBuilder::with_capacity(data_type, num_rows);
+        for value in cells.iter() {
+            builder.push(value.as_ref().unwrap().as_ref());
+        }
         value_columns.push(builder.build());
     }
 
@@ -214,3 +252,33 @@ pub async fn bind_values(
 
     Ok((s_expr, bind_context.clone()))
 }
+
+fn eval_value(
+    func_ctx: &FunctionContext,
+    value_schema: &DataSchema,
+    scalar: &ScalarExpr,
+    value_type: &DataType,
+    data_type: &DataType,
+    span: Span,
+) -> Result<Scalar> {
+    let scalar = if value_type != data_type {
+        wrap_cast_scalar(scalar, value_type, data_type)?
+    } else {
+        scalar.clone()
+    };
+    let expr = scalar
+        .as_expr()?
+        .project_column_ref(|col| value_schema.index_of(&col.index.to_string()).unwrap());
+    let input = DataBlock::empty();
+    let evaluator = Evaluator::new(&input, func_ctx, &BUILTIN_FUNCTIONS);
+    let result = evaluator.run(&expr)?;
+
+    match result.as_scalar() {
+        Some(val) => Ok(val.clone()),
+        None => Err(ErrorCode::SemanticError(format!(
+            "Value must be a scalar, but get {}",
+            result
+        ))
+        .set_span(span)),
+    }
+}
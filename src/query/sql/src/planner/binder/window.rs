@@ -268,6 +268,7 @@ impl<'a> WindowRewriter<'a> {
                     is_lag: ll.is_lag,
                     arg: Box::new(new_arg),
                     offset: ll.offset,
+                    ignore_null: ll.ignore_null,
                     default: new_default,
                     return_type: ll.return_type.clone(),
                 })
@@ -284,6 +285,7 @@ impl<'a> WindowRewriter<'a> {
                 });
                 WindowFuncType::NthValue(NthValueFunction {
                     n: func.n,
+                    ignore_null: func.ignore_null,
                     arg: Box::new(replaced_arg.into()),
                     return_type: func.return_type.clone(),
                 })
@@ -15,9 +15,14 @@
 use std::sync::Arc;
 
 use common_ast::ast::Expr as AExpr;
+use common_ast::ast::Identifier;
+use common_ast::ast::Literal;
+use common_ast::ast::TypeName;
 use common_ast::parser::parse_comma_separated_exprs;
+use common_ast::parser::parse_expr;
 use common_ast::parser::tokenize_sql;
 use common_ast::walk_expr_mut;
+use common_ast::VisitorMut;
 use common_base::base::tokio::runtime::Handle;
 use common_base::base::tokio::task::block_in_place;
 use common_catalog::catalog::CATALOG_DEFAULT;
@@ -30,6 +35,7 @@ use common_expression::infer_schema_type;
 use common_expression::infer_table_schema;
 use common_expression::type_check::check_function;
 use common_expression::types::DataType;
+use common_expression::ColumnId;
 use common_expression::ConstantFolder;
 use common_expression::DataBlock;
 use common_expression::DataSchemaRef;
@@ -359,6 +365,192 @@ pub fn parse_computed_expr_to_string(
     Ok(format!("{:#}", ast))
 }
 
+/// Binds the expression of a CHECK constraint against the stored columns of the table,
+/// returns its normalized SQL text and the ids of the referenced columns.
+pub fn parse_check_expr_to_string(
+    ctx: Arc<dyn TableContext>,
+    table_schema: TableSchemaRef,
+    ast: &AExpr,
+) -> Result<(String, Vec<ColumnId>)> {
+    let table_schema = table_schema.remove_virtual_computed_fields();
+    let settings = Settings::create("".to_string());
+    let mut bind_context = BindContext::new();
+    let mut metadata = Metadata::default();
+    for (index, field) in table_schema.fields().iter().enumerate() {
+        bind_context.add_column_binding(
+            ColumnBindingBuilder::new(
+                field.name().clone(),
+                index,
+                Box::new(field.data_type().into()),
+                Visibility::Visible,
+            )
+            .build(),
+        );
+        metadata.add_base_table_column(
+            field.name().clone(),
+            field.data_type().clone(),
+            0,
+            None,
+            None,
+            None,
+            None,
+        );
+    }
+
+    let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+    let mut type_checker = TypeChecker::try_create(
+        &mut bind_context,
+        ctx,
+        &name_resolution_ctx,
+        Arc::new(RwLock::new(metadata)),
+        &[],
+        false,
+        false,
+    )?;
+
+    let (scalar, data_type) =
+        *block_in_place(|| Handle::current().block_on(type_checker.resolve(ast)))?;
+    if data_type.remove_nullable() != DataType::Boolean {
+        return Err(ErrorCode::SemanticError(format!(
+            "expected check constraint expression have type Boolean, but `{}` has type {}.",
+            ast, data_type,
+        )));
+    }
+    let check_expr = scalar.as_expr()?;
+    if !check_expr.is_deterministic(&BUILTIN_FUNCTIONS) {
+        return Err(ErrorCode::SemanticError(format!(
+            "check constraint expression `{}` is not deterministic.",
+            check_expr.sql_display(),
+        )));
+    }
+    let columns = scalar
+        .used_columns()
+        .into_iter()
+        .map(|index| table_schema.field(index).column_id())
+        .collect();
+
+    let mut ast = ast.clone();
+    walk_expr_mut(
+        &mut IdentifierNormalizer {
+            ctx: &name_resolution_ctx,
+        },
+        &mut ast,
+    );
+    Ok((format!("{:#}", ast), columns))
+}
+
+/// Binds the predicate of the `row_ttl` option against the stored columns of the table,
+/// returns its normalized SQL text with `now()` replaced by the given timestamp, so that
+/// the rows expired at that moment can be deleted with a deterministic filter.
+pub fn parse_row_ttl_to_string(
+    ctx: Arc<dyn TableContext>,
+    table_schema: TableSchemaRef,
+    predicate: &str,
+    now: i64,
+) -> Result<String> {
+    let table_schema = table_schema.remove_virtual_computed_fields();
+    let settings = Settings::create("".to_string());
+    let mut bind_context = BindContext::new();
+    let mut metadata = Metadata::default();
+    for (index, field) in table_schema.fields().iter().enumerate() {
+        bind_context.add_column_binding(
+            ColumnBindingBuilder::new(
+                field.name().clone(),
+                index,
+                Box::new(field.data_type().into()),
+                Visibility::Visible,
+            )
+            .build(),
+        );
+        metadata.add_base_table_column(
+            field.name().clone(),
+            field.data_type().clone(),
+            0,
+            None,
+            None,
+            None,
+            None,
+        );
+    }
+
+    let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+    let sql_dialect = ctx.get_settings().get_sql_dialect()?;
+    let mut type_checker = TypeChecker::try_create(
+        &mut bind_context,
+        ctx,
+        &name_resolution_ctx,
+        Arc::new(RwLock::new(metadata)),
+        &[],
+        false,
+        false,
+    )?;
+
+    let tokens = tokenize_sql(predicate)?;
+    let mut ast = parse_expr(&tokens, sql_dialect)?;
+    walk_expr_mut(&mut NowReplacer { now }, &mut ast);
+    let (scalar, data_type) =
+        *block_in_place(|| Handle::current().block_on(type_checker.resolve(&ast)))?;
+    if data_type.remove_nullable() != DataType::Boolean {
+        return Err(ErrorCode::SemanticError(format!(
+            "expected row ttl expression have type Boolean, but `{}` has type {}.",
+            predicate, data_type,
+        )));
+    }
+    let ttl_expr = scalar.as_expr()?;
+    if !ttl_expr.is_deterministic(&BUILTIN_FUNCTIONS) {
+        return Err(ErrorCode::SemanticError(format!(
+            "row ttl expression `{}` is not deterministic.",
+            predicate,
+        )));
+    }
+
+    walk_expr_mut(
+        &mut IdentifierNormalizer {
+            ctx: &name_resolution_ctx,
+        },
+        &mut ast,
+    );
+    Ok(format!("{:#}", ast))
+}
+
+/// Replaces `now()` and `current_timestamp` by a timestamp literal.
+struct NowReplacer {
+    now: i64,
+}
+
+impl VisitorMut for NowReplacer {
+    fn visit_expr(&mut self, expr: &mut AExpr) {
+        if let AExpr::FunctionCall {
+            span, name, args, ..
+        } = expr
+        {
+            let func_name = name.name.to_lowercase();
+            if args.is_empty() && (func_name == "now" || func_name == "current_timestamp") {
+                let span = *span;
+                *expr = AExpr::FunctionCall {
+                    span,
+                    distinct: false,
+                    name: Identifier::from_name("to_timestamp"),
+                    args: vec![AExpr::Cast {
+                        span,
+                        expr: Box::new(AExpr::Literal {
+                            span,
+                            lit: Literal::UInt64(self.now as u64),
+                        }),
+                        target_type: TypeName::Int64,
+                        pg_style: false,
+                    }],
+                    params: vec![],
+                    window: None,
+                    lambda: None,
+                };
+                return;
+            }
+        }
+        walk_expr_mut(self, expr);
+    }
+}
+
 pub fn parse_lambda_expr(
     ctx: Arc<dyn TableContext>,
     column_name: &str,
@@ -45,6 +45,7 @@ impl Plan {
             Plan::ExplainAst { .. } => Ok("ExplainAst".to_string()),
             Plan::ExplainSyntax { .. } => Ok("ExplainSyntax".to_string()),
             Plan::ExplainAnalyze { .. } => Ok("ExplainAnalyze".to_string()),
+            Plan::DescribeQuery { .. } => Ok("DescribeQuery".to_string()),
 
             Plan::CopyIntoTable(plan) => Ok(format!("{:?}", plan)),
             Plan::CopyIntoLocation(plan) => Ok(format!("{:?}", plan)),
@@ -68,11 +69,13 @@ impl Plan {
             // Tables
             Plan::ShowCreateTable(show_create_table) => Ok(format!("{:?}", show_create_table)),
             Plan::CreateTable(create_table) => Ok(format!("{:?}", create_table)),
+            Plan::CloneTable(clone_table) => Ok(format!("{:?}", clone_table)),
             Plan::DropTable(drop_table) => Ok(format!("{:?}", drop_table)),
             Plan::UndropTable(undrop_table) => Ok(format!("{:?}", undrop_table)),
             Plan::DescribeTable(describe_table) => Ok(format!("{:?}", describe_table)),
             Plan::RenameTable(rename_table) => Ok(format!("{:?}", rename_table)),
             Plan::SetOptions(set_options) => Ok(format!("{:?}", set_options)),
+            Plan::PromoteReplica(promote_replica) => Ok(format!("{:?}", promote_replica)),
             Plan::RenameTableColumn(rename_table_column) => {
                 Ok(format!("{:?}", rename_table_column))
             }
@@ -81,6 +84,12 @@ impl Plan {
                 Ok(format!("{:?}", modify_table_column))
             }
             Plan::DropTableColumn(drop_table_column) => Ok(format!("{:?}", drop_table_column)),
+            Plan::AddTableConstraint(add_table_constraint) => {
+                Ok(format!("{:?}", add_table_constraint))
+            }
+            Plan::DropTableConstraint(drop_table_constraint) => {
+                Ok(format!("{:?}", drop_table_constraint))
+            }
             Plan::AlterTableClusterKey(alter_table_cluster_key) => {
                 Ok(format!("{:?}", alter_table_cluster_key))
             }
@@ -162,6 +171,8 @@ impl Plan {
             Plan::SetSecondaryRoles(p) => Ok(format!("{:?}", p)),
             Plan::UseDatabase(p) => Ok(format!("{:?}", p)),
             Plan::Kill(p) => Ok(format!("{:?}", p)),
+            Plan::FlushCache(p) => Ok(format!("{:?}", p)),
+            Plan::ExecuteImmediate(p) => Ok(format!("{:?}", p)),
 
             Plan::CreateShareEndpoint(p) => Ok(format!("{:?}", p)),
             Plan::ShowShareEndpoint(p) => Ok(format!("{:?}", p)),
@@ -198,11 +209,28 @@ impl Plan {
             Plan::ExecuteTask(p) => Ok(format!("{:?}", p)),
             Plan::ShowTasks(p) => Ok(format!("{:?}", p)),
 
+            // notification
+            Plan::CreateNotification(p) => Ok(format!("{:?}", p)),
+            Plan::AlterNotification(p) => Ok(format!("{:?}", p)),
+            Plan::DropNotification(p) => Ok(format!("{:?}", p)),
+            Plan::DescNotification(p) => Ok(format!("{:?}", p)),
+
             // task
             Plan::CreateConnection(p) => Ok(format!("{:?}", p)),
             Plan::DescConnection(p) => Ok(format!("{:?}", p)),
             Plan::DropConnection(p) => Ok(format!("{:?}", p)),
             Plan::ShowConnections(p) => Ok(format!("{:?}", p)),
+
+            // sequence
+            Plan::CreateSequence(p) => Ok(format!("{:?}", p)),
+            Plan::DropSequence(p) => Ok(format!("{:?}", p)),
+            Plan::ShowSequences(p) => Ok(format!("{:?}", p)),
+
+            // secret
+            Plan::CreateSecret(p) => Ok(format!("{:?}", p)),
+            Plan::DropSecret(p) => Ok(format!("{:?}", p)),
+            Plan::DescSecret(p) => Ok(format!("{:?}", p)),
+            Plan::ShowSecrets(p) => Ok(format!("{:?}", p)),
         }
     }
 }
@@ -248,6 +276,7 @@ fn format_delete(delete: &DeletePlan) -> Result<String> {
             order_by: None,
             prewhere: None,
             agg_index: None,
+            bucket_info: None,
             statistics: Default::default(),
         });
         let scan_expr = SExpr::create_leaf(Arc::new(scan));
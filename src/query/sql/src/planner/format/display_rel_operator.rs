@@ -81,6 +81,9 @@ impl Display for FormatContext {
                 RelOperator::ConstantTableScan(_) => write!(f, "ConstantTableScan"),
                 RelOperator::AddRowNumber(_) => write!(f, "AddRowNumber"),
                 RelOperator::Udf(_) => write!(f, "Udf"),
+                RelOperator::GapFill(_) => write!(f, "GapFill"),
+                RelOperator::LimitBy(_) => write!(f, "LimitBy"),
+                RelOperator::ConnectBy(_) => write!(f, "ConnectBy"),
             },
             Self::Text(text) => write!(f, "{}", text),
         }
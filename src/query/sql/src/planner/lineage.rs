@@ -0,0 +1,268 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use common_exception::Result;
+
+use crate::optimizer::ColumnSet;
+use crate::optimizer::SExpr;
+use crate::plans::BoundColumnRef;
+use crate::plans::InsertInputSource;
+use crate::plans::Plan;
+use crate::plans::RelOperator;
+use crate::plans::ScalarItem;
+use crate::plans::SubqueryExpr;
+use crate::plans::Visitor;
+use crate::BindContext;
+use crate::ColumnEntry;
+use crate::IndexType;
+use crate::Metadata;
+use crate::ScalarExpr;
+
+/// A column of a base table.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SourceColumn {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub column: String,
+}
+
+impl Display for SourceColumn {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.database, self.table, self.column)
+    }
+}
+
+/// The base table columns an output column of a statement is derived from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnLineage {
+    /// `(database, table)` the output column is written to, `None` for a query.
+    pub target_table: Option<(String, String)>,
+    pub output_column: String,
+    /// Sorted and deduplicated. Empty if the column is computed from constants only.
+    pub sources: Vec<SourceColumn>,
+}
+
+impl Display for ColumnLineage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some((database, table)) = &self.target_table {
+            write!(f, "{}.{}.", database, table)?;
+        }
+        write!(f, "{} <- ", self.output_column)?;
+        if self.sources.is_empty() {
+            return write!(f, "(none)");
+        }
+        for (i, source) in self.sources.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", source)?;
+        }
+        Ok(())
+    }
+}
+
+/// Extract the column lineage of a bound query or `INSERT INTO ... SELECT`.
+///
+/// Other statements have no lineage and return an empty list.
+pub fn plan_column_lineage(plan: &Plan) -> Result<Vec<ColumnLineage>> {
+    match plan {
+        Plan::Query {
+            s_expr,
+            metadata,
+            bind_context,
+            ..
+        } => query_column_lineage(s_expr, &metadata.read(), bind_context),
+        Plan::Insert(insert) => match &insert.source {
+            InsertInputSource::SelectPlan(select_plan) => {
+                let lineage = plan_column_lineage(select_plan)?;
+                // The columns of the select are inserted by position.
+                Ok(lineage
+                    .into_iter()
+                    .zip(insert.schema.fields().iter())
+                    .map(|(lineage, field)| ColumnLineage {
+                        target_table: Some((insert.database.clone(), insert.table.clone())),
+                        output_column: field.name().clone(),
+                        sources: lineage.sources,
+                    })
+                    .collect())
+            }
+            _ => Ok(vec![]),
+        },
+        _ => Ok(vec![]),
+    }
+}
+
+fn query_column_lineage(
+    s_expr: &SExpr,
+    metadata: &Metadata,
+    bind_context: &BindContext,
+) -> Result<Vec<ColumnLineage>> {
+    let mut collector = LineageCollector {
+        metadata,
+        lineage: HashMap::new(),
+    };
+    collector.collect(s_expr)?;
+
+    Ok(bind_context
+        .columns
+        .iter()
+        .map(|column| ColumnLineage {
+            target_table: None,
+            output_column: column.column_name.clone(),
+            sources: collector
+                .lineage
+                .get(&column.index)
+                .map(|sources| sources.iter().cloned().collect())
+                .unwrap_or_default(),
+        })
+        .collect())
+}
+
+/// Maps every column produced by a plan to the base table columns it reads from.
+struct LineageCollector<'a> {
+    metadata: &'a Metadata,
+    lineage: HashMap<IndexType, BTreeSet<SourceColumn>>,
+}
+
+impl<'a> LineageCollector<'a> {
+    fn collect(&mut self, s_expr: &SExpr) -> Result<()> {
+        for child in s_expr.children() {
+            self.collect(child)?;
+        }
+
+        match s_expr.plan() {
+            RelOperator::Scan(scan) => {
+                for index in scan.columns.iter() {
+                    if let ColumnEntry::BaseTableColumn(column) = self.metadata.column(*index) {
+                        let table = self.metadata.table(column.table_index);
+                        self.lineage
+                            .entry(*index)
+                            .or_default()
+                            .insert(SourceColumn {
+                                catalog: table.catalog().to_string(),
+                                database: table.database().to_string(),
+                                table: table.name().to_string(),
+                                column: column.column_name.clone(),
+                            });
+                    }
+                }
+            }
+            RelOperator::EvalScalar(eval_scalar) => self.derive_items(&eval_scalar.items)?,
+            RelOperator::Aggregate(aggregate) => {
+                self.derive_items(&aggregate.group_items)?;
+                self.derive_items(&aggregate.aggregate_functions)?;
+            }
+            RelOperator::Udf(udf) => self.derive_items(&udf.items)?,
+            RelOperator::ProjectSet(project_set) => {
+                for srf in project_set.srfs.iter() {
+                    self.derive(srf.index, &srf.scalar)?;
+                }
+            }
+            RelOperator::Window(window) => {
+                self.derive_items(&window.arguments)?;
+                self.derive_items(&window.partition_by)?;
+                for order_by in window.order_by.iter() {
+                    let item = &order_by.order_by_item;
+                    self.derive(item.index, &item.scalar)?;
+                }
+
+                let mut sources = BTreeSet::new();
+                for item in window
+                    .arguments
+                    .iter()
+                    .chain(window.partition_by.iter())
+                    .chain(
+                        window
+                            .order_by
+                            .iter()
+                            .map(|order_by| &order_by.order_by_item),
+                    )
+                {
+                    sources.extend(self.sources_of(item.index));
+                }
+                self.lineage
+                    .entry(window.index)
+                    .or_default()
+                    .extend(sources);
+            }
+            RelOperator::UnionAll(union_all) => {
+                // The output columns of union reuse the indexes of the left side.
+                for (left, right) in union_all.pairs.iter() {
+                    let sources = self.sources_of(*right);
+                    self.lineage.entry(*left).or_default().extend(sources);
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn derive_items(&mut self, items: &[ScalarItem]) -> Result<()> {
+        for item in items.iter() {
+            self.derive(item.index, &item.scalar)?;
+        }
+        Ok(())
+    }
+
+    fn derive(&mut self, index: IndexType, scalar: &ScalarExpr) -> Result<()> {
+        let mut visitor = UsedColumnsVisitor {
+            columns: ColumnSet::new(),
+            subqueries: vec![],
+        };
+        visitor.visit(scalar)?;
+        for subquery in visitor.subqueries {
+            self.collect(subquery)?;
+        }
+
+        let mut sources = BTreeSet::new();
+        for column in visitor.columns.iter() {
+            sources.extend(self.sources_of(*column));
+        }
+        self.lineage.entry(index).or_default().extend(sources);
+        Ok(())
+    }
+
+    fn sources_of(&self, index: IndexType) -> BTreeSet<SourceColumn> {
+        self.lineage.get(&index).cloned().unwrap_or_default()
+    }
+}
+
+/// Collects the columns used by a scalar, including the output columns of its subqueries.
+struct UsedColumnsVisitor<'a> {
+    columns: ColumnSet,
+    subqueries: Vec<&'a SExpr>,
+}
+
+impl<'a> Visitor<'a> for UsedColumnsVisitor<'a> {
+    fn visit_bound_column_ref(&mut self, col: &'a BoundColumnRef) -> Result<()> {
+        self.columns.insert(col.column.index);
+        Ok(())
+    }
+
+    fn visit_subquery(&mut self, subquery: &'a SubqueryExpr) -> Result<()> {
+        self.columns.extend(subquery.outer_columns.iter().cloned());
+        self.columns.insert(subquery.output_column.index);
+        self.subqueries.push(&subquery.subquery);
+        if let Some(child_expr) = subquery.child_expr.as_ref() {
+            self.visit(child_expr)?;
+        }
+        Ok(())
+    }
+}
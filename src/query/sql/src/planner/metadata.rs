@@ -62,6 +62,8 @@ pub struct Metadata {
     table_row_id_index: HashMap<IndexType, IndexType>,
     agg_indexes: HashMap<String, Vec<(u64, String, SExpr)>>,
     max_column_position: usize, // for CSV
+    /// The values of the session, such as connection_id(), are bound as literals.
+    session_dependent: bool,
 }
 
 impl Metadata {
@@ -258,6 +260,23 @@ impl Metadata {
             .or_insert(agg_indexes);
     }
 
+    pub fn has_agg_indexes(&self) -> bool {
+        !self.agg_indexes.is_empty()
+    }
+
+    pub fn set_session_dependent(&mut self) {
+        self.session_dependent = true;
+    }
+
+    pub fn is_session_dependent(&self) -> bool {
+        self.session_dependent
+    }
+
+    /// Replaces the table of the entry, the new table must be the same version.
+    pub(crate) fn replace_table(&mut self, index: IndexType, table: Arc<dyn Table>) {
+        self.tables[index].table = table;
+    }
+
     pub fn get_agg_indexes(&self, table: &str) -> Option<&[(u64, String, SExpr)]> {
         self.agg_indexes.get(table).map(|v| v.as_slice())
     }
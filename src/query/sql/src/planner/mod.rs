@@ -14,7 +14,9 @@
 
 mod bloom_index;
 mod format;
+mod lineage;
 mod metadata;
+mod plan_cache;
 #[allow(clippy::module_inception)]
 mod planner;
 mod semantic;
@@ -38,7 +40,9 @@ pub use binder::Visibility;
 pub use bloom_index::BloomIndexColumns;
 pub use expression_parser::*;
 pub use format::format_scalar;
+pub use lineage::*;
 pub use metadata::*;
+pub use plan_cache::PlanCache;
 pub use planner::PlanExtras;
 pub use planner::Planner;
 pub use plans::ScalarExpr;
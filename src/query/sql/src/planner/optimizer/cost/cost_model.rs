@@ -54,6 +54,9 @@ fn compute_cost_impl(memo: &Memo, m_expr: &MExpr) -> Result<Cost> {
         | RelOperator::Sort(_)
         | RelOperator::ProjectSet(_)
         | RelOperator::Udf(_)
+        | RelOperator::GapFill(_)
+        | RelOperator::LimitBy(_)
+        | RelOperator::ConnectBy(_)
         | RelOperator::Limit(_) => compute_cost_unary_common_operator(memo, m_expr),
 
         _ => Err(ErrorCode::Internal("Cannot compute cost from logical plan")),
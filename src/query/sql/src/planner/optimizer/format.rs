@@ -54,6 +54,9 @@ pub fn display_rel_op(rel_op: &RelOperator) -> String {
         RelOperator::ConstantTableScan(_) => "ConstantTableScan".to_string(),
         RelOperator::AddRowNumber(_) => "AddRowNumber".to_string(),
         RelOperator::Udf(_) => "Udf".to_string(),
+        RelOperator::GapFill(_) => "GapFill".to_string(),
+        RelOperator::LimitBy(_) => "LimitBy".to_string(),
+        RelOperator::ConnectBy(_) => "ConnectBy".to_string(),
     }
 }
 
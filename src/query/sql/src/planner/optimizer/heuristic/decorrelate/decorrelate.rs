@@ -222,6 +222,7 @@ impl SubqueryRewriter {
             from_correlated_subquery: true,
             contain_runtime_filter: false,
             need_hold_hash_table: false,
+            null_aware: false,
         };
 
         // Rewrite plan to semi-join.
@@ -297,6 +298,7 @@ impl SubqueryRewriter {
                     from_correlated_subquery: true,
                     contain_runtime_filter: false,
                     need_hold_hash_table: false,
+                    null_aware: false,
                 };
                 let s_expr = SExpr::create_binary(
                     Arc::new(join_plan.into()),
@@ -345,6 +347,7 @@ impl SubqueryRewriter {
                     from_correlated_subquery: true,
                     contain_runtime_filter: false,
                     need_hold_hash_table: false,
+                    null_aware: false,
                 };
                 let s_expr = SExpr::create_binary(
                     Arc::new(join_plan.into()),
@@ -408,6 +411,7 @@ impl SubqueryRewriter {
                     from_correlated_subquery: true,
                     contain_runtime_filter: false,
                     need_hold_hash_table: false,
+                    null_aware: false,
                 }
                 .into();
                 Ok((
@@ -92,6 +92,8 @@ impl SubqueryRewriter {
                 .into(),
             ));
             // Wrap logical get with distinct to eliminate duplicates rows.
+            // So the subquery is evaluated only once for each distinct correlation value,
+            // and the result is shared by all outer rows with the same value through the join.
             let mut group_items = Vec::with_capacity(self.derived_columns.len());
             for (index, column_index) in self.derived_columns.values().cloned().enumerate() {
                 group_items.push(ScalarItem {
@@ -133,6 +135,7 @@ impl SubqueryRewriter {
                 from_correlated_subquery: false,
                 contain_runtime_filter: false,
                 need_hold_hash_table: false,
+                null_aware: false,
             }
             .into();
 
@@ -461,6 +464,7 @@ impl SubqueryRewriter {
                     from_correlated_subquery: false,
                     contain_runtime_filter: false,
                     need_hold_hash_table: false,
+                    null_aware: false,
                 }
                 .into(),
             ),
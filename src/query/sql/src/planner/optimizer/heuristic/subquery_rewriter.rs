@@ -165,7 +165,11 @@ impl SubqueryRewriter {
                 ))
             }
 
-            RelOperator::Limit(_) | RelOperator::Sort(_) => Ok(SExpr::create_unary(
+            RelOperator::Limit(_)
+            | RelOperator::Sort(_)
+            | RelOperator::GapFill(_)
+            | RelOperator::LimitBy(_)
+            | RelOperator::ConnectBy(_) => Ok(SExpr::create_unary(
                 Arc::new(s_expr.plan().clone()),
                 Arc::new(self.rewrite(s_expr.child(0)?)?),
             )),
@@ -477,6 +481,7 @@ impl SubqueryRewriter {
                     from_correlated_subquery: false,
                     contain_runtime_filter: false,
                     need_hold_hash_table: false,
+                    null_aware: false,
                 }
                 .into();
                 Ok((
@@ -545,6 +550,7 @@ impl SubqueryRewriter {
                     from_correlated_subquery: false,
                     contain_runtime_filter: false,
                     need_hold_hash_table: false,
+                    null_aware: false,
                 }
                 .into();
                 let s_expr = SExpr::create_binary(
@@ -575,6 +581,7 @@ impl SubqueryRewriter {
             from_correlated_subquery: false,
             contain_runtime_filter: false,
             need_hold_hash_table: false,
+            null_aware: false,
         }
         .into();
 
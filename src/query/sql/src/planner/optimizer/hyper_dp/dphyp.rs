@@ -164,6 +164,9 @@ impl DPhpy {
                         | RelOperator::ProjectSet(_)
                         | RelOperator::Window(_)
                         | RelOperator::Udf(_)
+                        | RelOperator::GapFill(_)
+                        | RelOperator::LimitBy(_)
+                        | RelOperator::ConnectBy(_)
                 ) {
                     left_is_subquery = true;
                 }
@@ -176,6 +179,9 @@ impl DPhpy {
                         | RelOperator::ProjectSet(_)
                         | RelOperator::Window(_)
                         | RelOperator::Udf(_)
+                        | RelOperator::GapFill(_)
+                        | RelOperator::LimitBy(_)
+                        | RelOperator::ConnectBy(_)
                 ) {
                     right_is_subquery = true;
                 }
@@ -220,6 +226,9 @@ impl DPhpy {
             | RelOperator::EvalScalar(_)
             | RelOperator::Window(_)
             | RelOperator::Udf(_)
+            | RelOperator::GapFill(_)
+            | RelOperator::LimitBy(_)
+            | RelOperator::ConnectBy(_)
             | RelOperator::Filter(_) => {
                 if join_child {
                     // If plan is filter, save it
@@ -88,6 +88,7 @@ impl JoinNode {
             from_correlated_subquery: false,
             contain_runtime_filter: false,
             need_hold_hash_table: false,
+            null_aware: false,
         });
         let children = self
             .children
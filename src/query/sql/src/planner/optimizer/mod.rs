@@ -28,6 +28,7 @@ mod property;
 mod rule;
 mod runtime_filter;
 pub mod s_expr;
+mod share_scan;
 mod util;
 
 pub use cascades::CascadesOptimizer;
@@ -51,3 +52,8 @@ pub use rule::RuleID;
 pub use rule::RuleSet;
 pub use s_expr::get_udf_names;
 pub use s_expr::SExpr;
+pub use util::base_column_indices;
+pub use util::column_indices;
+pub use util::derive_foreign_keys;
+pub use util::derive_unique_keys;
+pub use util::ForeignKey;
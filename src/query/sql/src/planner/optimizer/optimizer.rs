@@ -29,6 +29,7 @@ use crate::optimizer::cascades::CascadesOptimizer;
 use crate::optimizer::distributed::optimize_distributed_query;
 use crate::optimizer::hyper_dp::DPhpy;
 use crate::optimizer::runtime_filter::try_add_runtime_filter_nodes;
+use crate::optimizer::share_scan::try_share_scans;
 use crate::optimizer::util::contains_local_table_scan;
 use crate::optimizer::HeuristicOptimizer;
 use crate::optimizer::RuleID;
@@ -81,9 +82,12 @@ pub fn optimize(
             ignore_result,
         }),
         Plan::Explain { kind, plan } => match kind {
-            ExplainKind::Raw | ExplainKind::Ast(_) | ExplainKind::Syntax(_) => {
-                Ok(Plan::Explain { kind, plan })
-            }
+            ExplainKind::Raw
+            | ExplainKind::Ast(_)
+            | ExplainKind::Syntax(_)
+            | ExplainKind::Snapshot
+            | ExplainKind::Prune
+            | ExplainKind::Lineage => Ok(Plan::Explain { kind, plan }),
             ExplainKind::Memo(_) => {
                 if let box Plan::Query {
                     ref s_expr,
@@ -196,9 +200,18 @@ pub fn optimize_query(
     s_expr: SExpr,
 ) -> Result<SExpr> {
     let contains_local_table_scan = contains_local_table_scan(&s_expr, &metadata);
+    // So far, we don't have ability to execute distributed query
+    // with reading data from local tales(e.g. system tables).
+    let enable_distributed_query =
+        opt_ctx.config.enable_distributed_optimization && !contains_local_table_scan;
 
     let heuristic = HeuristicOptimizer::new(ctx.get_function_context()?, metadata.clone());
     let mut result = heuristic.pre_optimize(s_expr)?;
+    // Share the scans before the filters are pushed down into them.
+    // Currently, we only support standalone.
+    if !enable_distributed_query {
+        result = try_share_scans(ctx.clone(), &metadata, result)?;
+    }
     result = heuristic.optimize_expression(&result, &DEFAULT_REWRITE_RULES)?;
     let mut dphyp_optimized = false;
     if ctx.get_settings().get_enable_dphyp()?
@@ -213,10 +226,6 @@ pub fn optimize_query(
     }
     let mut cascades = CascadesOptimizer::create(ctx.clone(), metadata, dphyp_optimized)?;
     result = cascades.optimize(result)?;
-    // So far, we don't have ability to execute distributed query
-    // with reading data from local tales(e.g. system tables).
-    let enable_distributed_query =
-        opt_ctx.config.enable_distributed_optimization && !contains_local_table_scan;
     // Add runtime filter related nodes after cbo
     // Because cbo may change join order and we don't want to
     // break optimizer due to new added nodes by runtime filter.
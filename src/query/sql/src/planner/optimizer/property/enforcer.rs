@@ -15,13 +15,16 @@
 use std::sync::Arc;
 
 use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
 use common_exception::Result;
 
+use super::property::is_same_column;
 use crate::optimizer::property::Distribution;
 use crate::optimizer::RelExpr;
 use crate::optimizer::RequiredProperty;
 use crate::optimizer::SExpr;
 use crate::plans::Exchange;
+use crate::plans::Join;
 use crate::plans::RelOperator;
 
 /// Require and enforce physical property from a physical `SExpr`
@@ -76,6 +79,22 @@ pub fn require_property(
                 continue;
             }
         }
+        if let RelOperator::Join(join) = s_expr.plan.as_ref() {
+            if let Distribution::Hash(_) = required.distribution {
+                // The hash join skips the shuffle only if both sides are bucketed by the
+                // join keys in the same way, otherwise the rows of the bucketed side must
+                // be shuffled by the same hash function with the other side.
+                let left = rel_expr.derive_physical_prop_child(0)?;
+                let right = rel_expr.derive_physical_prop_child(1)?;
+                let child = optimized_expr.child(index)?;
+                if is_co_located(join, &left.distribution, &right.distribution)? {
+                    children.push(Arc::new(child.clone()));
+                } else {
+                    children.push(Arc::new(enforce_property(child, &required)?));
+                }
+                continue;
+            }
+        }
         if let RelOperator::UnionAll(_) = s_expr.plan.as_ref() {
             // Wrap the child with Random exchange to make it partition to all nodes
             // Check if exists `Merge` in child, if not exits, wrap it with `Exchange`
@@ -139,7 +158,40 @@ pub fn enforce_distribution(distribution: &Distribution, s_expr: &SExpr) -> Resu
             Arc::new(Exchange::Hash(hash_keys.clone()).into()),
             Arc::new(s_expr.clone()),
         )),
+
+        Distribution::Bucket(_) => {
+            Err(ErrorCode::Internal("Bucket distribution can't be enforced"))
+        }
+    }
+}
+
+/// Check if the rows of both sides with the same join keys are in the same bucket,
+/// i.e. both sides are bucketed by the join keys into the same number of buckets.
+fn is_co_located(join: &Join, left: &Distribution, right: &Distribution) -> Result<bool> {
+    let (Distribution::Bucket(left), Distribution::Bucket(right)) = (left, right) else {
+        return Ok(false);
+    };
+    if left.num_buckets != right.num_buckets || left.keys.len() != right.keys.len() {
+        return Ok(false);
+    }
+    for (left_key, right_key) in left.keys.iter().zip(right.keys.iter()) {
+        // The keys of the same type are hashed into the same bucket.
+        if left_key.data_type()?.remove_nullable() != right_key.data_type()?.remove_nullable() {
+            return Ok(false);
+        }
+        if !join
+            .left_conditions
+            .iter()
+            .zip(join.right_conditions.iter())
+            .any(|(left_condition, right_condition)| {
+                is_same_column(left_condition, left_key)
+                    && is_same_column(right_condition, right_key)
+            })
+        {
+            return Ok(false);
+        }
     }
+    Ok(true)
 }
 
 fn check_merge(s_expr: &SExpr) -> bool {
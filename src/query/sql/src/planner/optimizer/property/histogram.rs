@@ -18,7 +18,9 @@ use std::fmt::Debug;
 
 use common_exception::Result;
 use common_expression::arithmetics_type::ResultTypeOfUnary;
+use common_expression::types::number::F64;
 use common_storage::Datum;
+use storages_common_table_meta::meta::ColumnHistogram;
 
 pub const DEFAULT_HISTOGRAM_BUCKETS: usize = 100;
 
@@ -37,14 +39,21 @@ pub const DEFAULT_HISTOGRAM_BUCKETS: usize = 100;
 /// constructed from NDV(number of distinct values) and the total number
 /// of rows instead of maintaining a real histogram for each column,
 /// which brings the assumption that the data is uniformly distributed.
+///
+/// The histograms collected by `ANALYZE TABLE` are `accuracy`, they reflect
+/// the distribution of the column, see `histogram_from_column_histogram`.
 #[derive(Debug, Clone)]
 pub struct Histogram {
     pub buckets: Vec<HistogramBucket>,
+    pub accuracy: bool,
 }
 
 impl Histogram {
     pub fn new(buckets: Vec<HistogramBucket>) -> Self {
-        Self { buckets }
+        Self {
+            buckets,
+            accuracy: false,
+        }
     }
 
     /// Get number of buckets
@@ -73,6 +82,90 @@ impl Histogram {
     ) -> impl Iterator<Item = &HistogramBucket> + DoubleEndedIterator<Item = &HistogramBucket> {
         self.buckets.iter()
     }
+
+    /// Estimate the number of values less than `value`, the values of the bucket
+    /// containing `value` are assumed to spread uniformly between its bounds.
+    ///
+    /// Returns `None` if the bounds are not numeric.
+    pub fn num_values_below(&self, value: &Datum) -> Option<f64> {
+        let value = value.to_double().ok()?;
+        let mut lower_bound = self.buckets.first()?.upper_bound.to_double().ok()?;
+        let mut num_values = 0.0;
+        for bucket in self.buckets.iter() {
+            let upper_bound = bucket.upper_bound.to_double().ok()?;
+            if value >= upper_bound {
+                num_values += bucket.num_values;
+            } else {
+                if value > lower_bound {
+                    num_values +=
+                        bucket.num_values * (value - lower_bound) / (upper_bound - lower_bound);
+                }
+                break;
+            }
+            lower_bound = upper_bound;
+        }
+        Some(num_values)
+    }
+
+    /// Keep the part of the histogram between `min` and `max`, the values of the
+    /// buckets partially kept are scaled by the kept fraction of their bounds.
+    ///
+    /// Returns `None` if the bounds are not numeric or nothing is kept.
+    pub fn trim(&self, min: &Datum, max: &Datum) -> Option<Histogram> {
+        let (min, max) = (min.to_double().ok()?, max.to_double().ok()?);
+        let mut lower_bound = self.buckets.first()?.upper_bound.to_double().ok()?;
+        let mut buckets = vec![HistogramBucket::new(
+            Datum::Float(F64::from(lower_bound.max(min))),
+            0.0,
+            0.0,
+        )];
+        for bucket in self.buckets.iter().skip(1) {
+            let upper_bound = bucket.upper_bound.to_double().ok()?;
+            let (low, high) = (lower_bound.max(min), upper_bound.min(max));
+            let fraction = if upper_bound > lower_bound {
+                (high - low) / (upper_bound - lower_bound)
+            } else if low <= high {
+                1.0
+            } else {
+                0.0
+            };
+            lower_bound = upper_bound;
+            if fraction <= 0.0 {
+                continue;
+            }
+            buckets.push(HistogramBucket::new(
+                Datum::Float(F64::from(high)),
+                bucket.num_values * fraction,
+                (bucket.num_distinct * fraction).max(1.0),
+            ));
+        }
+        (buckets.len() > 1).then_some(Histogram {
+            buckets,
+            accuracy: self.accuracy,
+        })
+    }
+}
+
+/// Construct a histogram from the equi-depth histogram collected by `ANALYZE TABLE`.
+pub fn histogram_from_column_histogram(histogram: &ColumnHistogram) -> Histogram {
+    // The first bucket is a dummy bucket recording the min value of the column
+    let mut buckets = Vec::with_capacity(histogram.buckets.len() + 1);
+    buckets.push(HistogramBucket::new(
+        Datum::Float(F64::from(histogram.lower_bound)),
+        0.0,
+        0.0,
+    ));
+    for bucket in histogram.buckets.iter() {
+        buckets.push(HistogramBucket::new(
+            Datum::Float(F64::from(bucket.upper_bound)),
+            bucket.num_values as f64,
+            bucket.num_distinct as f64,
+        ));
+    }
+    Histogram {
+        buckets,
+        accuracy: true,
+    }
 }
 
 /// Construct a histogram from NDV and total number of rows.
@@ -95,7 +188,7 @@ pub fn histogram_from_ndv(
                 ndv, num_rows
             ))
         } else {
-            Ok(Histogram { buckets: vec![] })
+            Ok(Histogram::new(vec![]))
         };
     }
 
@@ -150,7 +243,7 @@ pub fn histogram_from_ndv(
         buckets.push(bucket);
     }
 
-    Ok(Histogram { buckets })
+    Ok(Histogram::new(buckets))
 }
 
 #[derive(Debug, Clone)]
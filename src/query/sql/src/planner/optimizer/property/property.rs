@@ -15,7 +15,9 @@
 use std::collections::HashSet;
 
 use super::column_stat::ColumnStatSet;
+use crate::plans::BucketInfo;
 use crate::plans::ScalarExpr;
+use crate::plans::SortItem;
 use crate::IndexType;
 
 pub type ColumnSet = HashSet<IndexType>;
@@ -64,6 +66,15 @@ pub struct RelationalProperty {
 #[derive(Default, Clone)]
 pub struct PhysicalProperty {
     pub distribution: Distribution,
+    /// The order of the output rows, empty if the rows are not ordered.
+    pub ordering: Vec<SortItem>,
+}
+
+impl PhysicalProperty {
+    /// Check if the output rows are already sorted by the given sort items.
+    pub fn satisfies_ordering(&self, items: &[SortItem]) -> bool {
+        self.ordering.starts_with(items)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -73,6 +84,8 @@ pub enum Distribution {
     Serial,
     Broadcast,
     Hash(Vec<ScalarExpr>),
+    /// The rows are distributed by the buckets of a bucketed table.
+    Bucket(BucketInfo),
 }
 
 impl Default for Distribution {
@@ -92,6 +105,13 @@ impl Distribution {
             | (Distribution::Serial, Distribution::Serial)
             | (Distribution::Broadcast, Distribution::Broadcast) => true,
 
+            // The rows with the same values of the keys are in the same bucket, so are the
+            // rows with the same values of any superset of the keys.
+            (Distribution::Hash(ref keys), Distribution::Bucket(ref info)) => info
+                .keys
+                .iter()
+                .all(|bucket_key| keys.iter().any(|key| is_same_column(key, bucket_key))),
+
             // TODO(leiysky): this is actually broken by https://github.com/datafuselabs/databend/pull/7451
             // , would be fixed later.
             // (Distribution::Hash(ref keys), Distribution::Hash(ref other_keys)) => keys
@@ -101,3 +121,13 @@ impl Distribution {
         }
     }
 }
+
+/// Check if the two expressions reference the same column.
+pub fn is_same_column(lhs: &ScalarExpr, rhs: &ScalarExpr) -> bool {
+    match (lhs, rhs) {
+        (ScalarExpr::BoundColumnRef(lhs), ScalarExpr::BoundColumnRef(rhs)) => {
+            lhs.column.index == rhs.column.index
+        }
+        _ => false,
+    }
+}
@@ -161,6 +161,31 @@ impl<'a> SelectivityEstimator<'a> {
                     }
                     Ok(selectivity)
                 }
+                ComparisonOp::GT | ComparisonOp::GTE | ComparisonOp::LT | ComparisonOp::LTE
+                    if column_stat.histogram.as_ref().map_or(false, |h| h.accuracy) =>
+                {
+                    // The histogram collected by `ANALYZE TABLE` reflects the distribution
+                    // of the column, so the bucket containing the constant value is
+                    // interpolated instead of being counted as a whole.
+                    let col_hist = column_stat.histogram.as_ref().unwrap();
+                    let num_values = col_hist.num_values();
+                    let num_less = match col_hist.num_values_below(&const_datum) {
+                        Some(num_less) if num_values > 0.0 => num_less,
+                        _ => return Ok(DEFAULT_SELECTIVITY),
+                    };
+                    let fraction_less = (num_less / num_values).clamp(0.0, 1.0);
+                    let (selectivity, new_min, new_max) = match op {
+                        ComparisonOp::GT | ComparisonOp::GTE => {
+                            (1.0 - fraction_less, const_datum, column_stat.max.clone())
+                        }
+                        _ => (fraction_less, column_stat.min.clone(), const_datum),
+                    };
+                    if update {
+                        update_statistic(column_stat, new_min, new_max, selectivity)?;
+                        self.updated_column_indexes.insert(column_ref.column.index);
+                    }
+                    Ok(selectivity)
+                }
                 ComparisonOp::GT => {
                     let col_hist = if let Some(hist) = column_stat.histogram.as_ref() {
                         hist
@@ -329,7 +354,9 @@ fn evaluate_equal(column_stat: &ColumnStat, constant: &ConstantExpr) -> f64 {
             | NumberDataType::Int32
             | NumberDataType::Int64
             | NumberDataType::Float32
-            | NumberDataType::Float64 => compare_equal(&constant_datum, column_stat),
+            | NumberDataType::Float64
+            | NumberDataType::UInt128
+            | NumberDataType::Int128 => compare_equal(&constant_datum, column_stat),
         },
         DataType::Boolean | DataType::String => compare_equal(&constant_datum, column_stat),
         _ => {
@@ -388,6 +415,10 @@ fn update_statistic(
             column_stat.histogram = None;
             return Ok(());
         }
+        if histogram.accuracy {
+            column_stat.histogram = histogram.trim(&new_min, &new_max);
+            return Ok(());
+        }
         column_stat.histogram = Some(histogram_from_ndv(
             new_ndv,
             max(new_num_values, new_ndv),
@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use common_exception::Result;
+use common_expression::types::DataType;
 
 use crate::optimizer::SExpr;
 use crate::plans::Filter;
@@ -34,6 +35,8 @@ pub fn convert_mark_to_semi_join(s_expr: &SExpr) -> Result<SExpr> {
 
     let mark_index = join.marker_index.unwrap();
     let mut find_mark_index = false;
+    let mut find_not_mark_index = false;
+    let mut null_aware = false;
 
     // remove mark index filter
     for (idx, predicate) in filter.predicates.iter().enumerate() {
@@ -44,10 +47,16 @@ pub fn convert_mark_to_semi_join(s_expr: &SExpr) -> Result<SExpr> {
                 break;
             }
             ScalarExpr::FunctionCall(func) if func.func_name == "not" => {
-                // Check if the argument is mark index, if so, we won't convert it to semi join
+                // Check if the argument is mark index, if so, try to convert it to anti join
                 if let ScalarExpr::BoundColumnRef(col) = &func.arguments[0] {
                     if col.column.index == mark_index {
-                        return Ok(s_expr.clone());
+                        match anti_join_null_awareness(&join)? {
+                            Some(is_null_aware) => null_aware = is_null_aware,
+                            None => return Ok(s_expr.clone()),
+                        }
+                        find_not_mark_index = true;
+                        filter.predicates.remove(idx);
+                        break;
                     }
                 }
             }
@@ -55,16 +64,19 @@ pub fn convert_mark_to_semi_join(s_expr: &SExpr) -> Result<SExpr> {
         }
     }
 
-    if !find_mark_index {
+    if !find_mark_index && !find_not_mark_index {
         // To be conservative, we do not convert
         return Ok(s_expr.clone());
     }
 
-    join.join_type = match join.join_type {
-        JoinType::LeftMark => JoinType::RightSemi,
-        JoinType::RightMark => JoinType::LeftSemi,
+    join.join_type = match (&join.join_type, find_not_mark_index) {
+        (JoinType::LeftMark, false) => JoinType::RightSemi,
+        (JoinType::RightMark, false) => JoinType::LeftSemi,
+        (JoinType::LeftMark, true) => JoinType::RightAnti,
+        (JoinType::RightMark, true) => JoinType::LeftAnti,
         _ => unreachable!(),
     };
+    join.null_aware = null_aware;
 
     let s_join_expr = s_expr.child(0)?;
     let mut result = SExpr::create_binary(
@@ -76,3 +88,39 @@ pub fn convert_mark_to_semi_join(s_expr: &SExpr) -> Result<SExpr> {
     result = SExpr::create_unary(Arc::new(filter.into()), Arc::new(result));
     Ok(result)
 }
+
+// The marker of `NOT IN` is NULL instead of FALSE if there is no match and either the
+// probe key or any build key is NULL, in which case `NOT marker` filters out the row.
+// A plain anti join isn't aware of it, so it's only used if none of the keys can be NULL.
+// Otherwise a null-aware anti join is used, which supports a single key of an uncorrelated
+// subquery with the outer query as the probe side, i.e. the `RightMark` join. Keys of tuple
+// type are skipped because of their nullable fields.
+//
+// Returns whether the anti join must be null-aware, or None if it can't be converted.
+fn anti_join_null_awareness(join: &Join) -> Result<Option<bool>> {
+    if !join.non_equi_conditions.is_empty() {
+        return Ok(None);
+    }
+    let mut nullable = false;
+    for condition in join
+        .left_conditions
+        .iter()
+        .chain(join.right_conditions.iter())
+    {
+        let data_type = condition.data_type()?;
+        if matches!(data_type.remove_nullable(), DataType::Tuple(_)) {
+            return Ok(None);
+        }
+        nullable |= data_type.is_nullable_or_null();
+    }
+    if !nullable {
+        return Ok(Some(false));
+    }
+    if join.join_type == JoinType::RightMark
+        && !join.from_correlated_subquery
+        && join.left_conditions.len() == 1
+    {
+        return Ok(Some(true));
+    }
+    Ok(None)
+}
@@ -15,8 +15,10 @@
 pub mod agg_index;
 mod filter_join;
 mod rule_commute_join;
+mod rule_eliminate_distinct;
 mod rule_eliminate_eval_scalar;
 mod rule_eliminate_filter;
+mod rule_eliminate_sort;
 mod rule_fold_count_aggregate;
 mod rule_infer_filter;
 mod rule_merge_eval_scalar;
@@ -43,8 +45,10 @@ mod rule_split_aggregate;
 mod rule_try_apply_agg_index;
 
 pub use rule_commute_join::RuleCommuteJoin;
+pub use rule_eliminate_distinct::RuleEliminateDistinct;
 pub use rule_eliminate_eval_scalar::RuleEliminateEvalScalar;
 pub use rule_eliminate_filter::RuleEliminateFilter;
+pub use rule_eliminate_sort::RuleEliminateSort;
 pub use rule_fold_count_aggregate::RuleFoldCountAggregate;
 pub use rule_infer_filter::RuleInferFilter;
 pub use rule_merge_eval_scalar::RuleMergeEvalScalar;
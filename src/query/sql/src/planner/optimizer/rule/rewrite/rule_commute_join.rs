@@ -64,6 +64,10 @@ impl Rule for RuleCommuteJoin {
 
     fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
         let mut join: Join = s_expr.plan().clone().try_into()?;
+        // The null-aware anti join only supports the outer query as the probe side.
+        if join.null_aware {
+            return Ok(());
+        }
         let left_child = s_expr.child(0)?;
         if left_child.plan.rel_op() == RelOp::RuntimeFilterSource {
             return Ok(());
@@ -0,0 +1,120 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+
+use crate::optimizer::rule::Rule;
+use crate::optimizer::rule::RuleID;
+use crate::optimizer::rule::TransformResult;
+use crate::optimizer::util::derive_unique_keys;
+use crate::optimizer::SExpr;
+use crate::plans::Aggregate;
+use crate::plans::AggregateMode;
+use crate::plans::EvalScalar;
+use crate::plans::PatternPlan;
+use crate::plans::RelOp;
+use crate::plans::ScalarExpr;
+use crate::ColumnSet;
+use crate::MetadataRef;
+
+/// Eliminate the aggregates without aggregate functions (e.g. `SELECT DISTINCT`)
+/// whose group items cover a unique key of the input, the groups are the input rows.
+pub struct RuleEliminateDistinct {
+    id: RuleID,
+    patterns: Vec<SExpr>,
+    metadata: MetadataRef,
+}
+
+impl RuleEliminateDistinct {
+    pub fn new(metadata: MetadataRef) -> Self {
+        Self {
+            id: RuleID::EliminateDistinct,
+            //  Aggregate
+            //  \
+            //   *
+            patterns: vec![SExpr::create_unary(
+                Arc::new(
+                    PatternPlan {
+                        plan_type: RelOp::Aggregate,
+                    }
+                    .into(),
+                ),
+                Arc::new(SExpr::create_leaf(Arc::new(
+                    PatternPlan {
+                        plan_type: RelOp::Pattern,
+                    }
+                    .into(),
+                ))),
+            )],
+            metadata,
+        }
+    }
+}
+
+impl Rule for RuleEliminateDistinct {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
+        let agg: Aggregate = s_expr.plan().clone().try_into()?;
+        if agg.mode != AggregateMode::Initial
+            || !agg.aggregate_functions.is_empty()
+            || agg.grouping_sets.is_some()
+            || agg.limit.is_some()
+            || agg.group_items.is_empty()
+        {
+            return Ok(());
+        }
+
+        let mut group_columns = ColumnSet::new();
+        for item in agg.group_items.iter() {
+            match &item.scalar {
+                ScalarExpr::BoundColumnRef(column) => {
+                    group_columns.insert(column.column.index);
+                }
+                _ => return Ok(()),
+            }
+        }
+
+        let input = s_expr.child(0)?;
+        let unique_keys = derive_unique_keys(input, &self.metadata);
+        if !unique_keys.iter().any(|key| key.is_subset(&group_columns)) {
+            return Ok(());
+        }
+
+        // The group items referring to the input columns with new indices are kept
+        // as the scalar items.
+        let items = agg
+            .group_items
+            .into_iter()
+            .filter(|item| !matches!(&item.scalar, ScalarExpr::BoundColumnRef(column) if column.column.index == item.index))
+            .collect::<Vec<_>>();
+        if items.is_empty() {
+            state.add_result(input.clone());
+        } else {
+            state.add_result(SExpr::create_unary(
+                Arc::new(EvalScalar { items }.into()),
+                Arc::new(input.clone()),
+            ));
+        }
+        Ok(())
+    }
+
+    fn patterns(&self) -> &Vec<SExpr> {
+        &self.patterns
+    }
+}
@@ -0,0 +1,100 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+
+use crate::optimizer::rule::Rule;
+use crate::optimizer::rule::RuleID;
+use crate::optimizer::rule::TransformResult;
+use crate::optimizer::RelExpr;
+use crate::optimizer::SExpr;
+use crate::plans::PatternPlan;
+use crate::plans::RelOp;
+use crate::plans::RelOperator;
+use crate::plans::Sort;
+
+/// Eliminate the sorts that don't change the order of the rows:
+/// - A sort whose input is already sorted by its sort items (or a superset of them)
+///   is removed. If there is a limit above, it becomes a streaming limit.
+/// - A sort without limit below another sort is removed, the order of its output
+///   is discarded by the sort above.
+pub struct RuleEliminateSort {
+    id: RuleID,
+    patterns: Vec<SExpr>,
+}
+
+impl RuleEliminateSort {
+    pub fn new() -> Self {
+        Self {
+            id: RuleID::EliminateSort,
+            // Sort
+            //  \
+            //   *
+            patterns: vec![SExpr::create_unary(
+                Arc::new(
+                    PatternPlan {
+                        plan_type: RelOp::Sort,
+                    }
+                    .into(),
+                ),
+                Arc::new(SExpr::create_leaf(Arc::new(
+                    PatternPlan {
+                        plan_type: RelOp::Pattern,
+                    }
+                    .into(),
+                ))),
+            )],
+        }
+    }
+}
+
+impl Rule for RuleEliminateSort {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
+        let sort: Sort = s_expr.plan().clone().try_into()?;
+        let input = s_expr.child(0)?;
+
+        let input_prop = RelExpr::with_s_expr(input).derive_physical_prop()?;
+        if input_prop.satisfies_ordering(&sort.items) {
+            state.add_result(input.clone());
+            return Ok(());
+        }
+
+        if let Some(input) = eliminate_inner_sort(input) {
+            state.add_result(s_expr.replace_children(vec![Arc::new(input)]));
+        }
+        Ok(())
+    }
+
+    fn patterns(&self) -> &Vec<SExpr> {
+        &self.patterns
+    }
+}
+
+// Remove the first sort without limit below the operators that keep the order of the rows.
+fn eliminate_inner_sort(s_expr: &SExpr) -> Option<SExpr> {
+    match s_expr.plan() {
+        RelOperator::Sort(sort) if sort.limit.is_none() => s_expr.child(0).ok().cloned(),
+        RelOperator::EvalScalar(_) | RelOperator::Filter(_) => {
+            let child = eliminate_inner_sort(s_expr.child(0).ok()?)?;
+            Some(s_expr.replace_children(vec![Arc::new(child)]))
+        }
+        _ => None,
+    }
+}
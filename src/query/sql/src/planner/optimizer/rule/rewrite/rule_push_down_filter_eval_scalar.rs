@@ -123,6 +123,7 @@ impl RulePushDownFilterEvalScalar {
                             is_lag: ll.is_lag,
                             arg: Box::new(new_arg),
                             offset: ll.offset,
+                            ignore_null: ll.ignore_null,
                             default: new_default,
                             return_type: ll.return_type.clone(),
                         })
@@ -131,6 +132,7 @@ impl RulePushDownFilterEvalScalar {
                         let new_arg = Self::replace_predicate(&func.arg, items)?;
                         WindowFuncType::NthValue(NthValueFunction {
                             n: func.n,
+                            ignore_null: func.ignore_null,
                             arg: Box::new(new_arg),
                             return_type: func.return_type.clone(),
                         })
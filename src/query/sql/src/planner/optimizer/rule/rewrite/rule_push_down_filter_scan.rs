@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use common_exception::Result;
+use storages_common_table_meta::table::CryptoShredColumns;
 
 use crate::binder::ColumnBindingBuilder;
 use crate::optimizer::rule::Rule;
@@ -36,6 +37,7 @@ use crate::plans::UDFServerCall;
 use crate::plans::WindowFunc;
 use crate::plans::WindowFuncType;
 use crate::plans::WindowOrderBy;
+use crate::BaseTableColumn;
 use crate::ColumnEntry;
 use crate::MetadataRef;
 use crate::ScalarExpr;
@@ -151,6 +153,7 @@ impl RulePushDownFilterScan {
                             is_lag: ll.is_lag,
                             arg: Box::new(new_arg),
                             offset: ll.offset,
+                            ignore_null: ll.ignore_null,
                             default: new_default,
                             return_type: ll.return_type.clone(),
                         })
@@ -160,6 +163,7 @@ impl RulePushDownFilterScan {
                             Self::replace_view_column(&func.arg, table_entries, column_entries)?;
                         WindowFuncType::NthValue(NthValueFunction {
                             n: func.n,
+                            ignore_null: func.ignore_null,
                             arg: Box::new(new_arg),
                             return_type: func.return_type.clone(),
                         })
@@ -272,6 +276,18 @@ impl RulePushDownFilterScan {
         }
     }
 
+    fn is_crypto_shredded(table_entries: &[TableEntry], column: &BaseTableColumn) -> bool {
+        table_entries
+            .iter()
+            .find(|table_entry| table_entry.index() == column.table_index)
+            .is_some_and(|table_entry| {
+                CryptoShredColumns::from_options(table_entry.table().options())
+                    .ok()
+                    .flatten()
+                    .is_some_and(|columns| columns.contains(&column.column_name))
+            })
+    }
+
     fn find_push_down_predicates(&self, predicates: &[ScalarExpr]) -> Result<Vec<ScalarExpr>> {
         let metadata = self.metadata.read();
         let column_entries = metadata.columns();
@@ -282,9 +298,19 @@ impl RulePushDownFilterScan {
         for predicate in predicates {
             let used_columns = predicate.used_columns();
             let mut contain_derived_column = false;
+            let mut contain_shredded_column = false;
             for column_entry in column_entries {
                 match column_entry {
-                    ColumnEntry::BaseTableColumn(_) => {}
+                    ColumnEntry::BaseTableColumn(column) => {
+                        // Don't push down predicate that contains crypto-shredded column
+                        // Because the values are encrypted in storage.
+                        if used_columns.contains(&column.column_index)
+                            && Self::is_crypto_shredded(table_entries, column)
+                        {
+                            contain_shredded_column = true;
+                            break;
+                        }
+                    }
                     ColumnEntry::InternalColumn(_) => {}
                     ColumnEntry::DerivedColumn(column) => {
                         // Don't push down predicate that contains derived column
@@ -297,7 +323,7 @@ impl RulePushDownFilterScan {
                     ColumnEntry::VirtualColumn(_) => {}
                 }
             }
-            if !contain_derived_column {
+            if !contain_derived_column && !contain_shredded_column {
                 if is_source_of_view {
                     let new_predicate =
                         Self::replace_view_column(predicate, table_entries, column_entries)?;
@@ -61,6 +61,8 @@ pub enum RuleID {
     PushDownSortScan,
     EliminateEvalScalar,
     EliminateFilter,
+    EliminateDistinct,
+    EliminateSort,
     MergeEvalScalar,
     MergeFilter,
     SplitAggregate,
@@ -94,6 +96,8 @@ impl Display for RuleID {
             RuleID::PushDownSortScan => write!(f, "PushDownSortScan"),
             RuleID::EliminateEvalScalar => write!(f, "EliminateEvalScalar"),
             RuleID::EliminateFilter => write!(f, "EliminateFilter"),
+            RuleID::EliminateDistinct => write!(f, "EliminateDistinct"),
+            RuleID::EliminateSort => write!(f, "EliminateSort"),
             RuleID::MergeEvalScalar => write!(f, "MergeEvalScalar"),
             RuleID::MergeFilter => write!(f, "MergeFilter"),
             RuleID::NormalizeScalarFilter => write!(f, "NormalizeScalarFilter"),
@@ -70,6 +70,11 @@ impl Rule for RuleCommuteJoinBaseTable {
             return Ok(());
         }
 
+        // The null-aware anti join only supports the outer query as the probe side.
+        if join.null_aware {
+            return Ok(());
+        }
+
         match join.join_type {
             JoinType::Inner
             | JoinType::Cross
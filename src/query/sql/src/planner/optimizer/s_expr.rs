@@ -365,6 +365,9 @@ impl SExpr {
             | RelOperator::RuntimeFilterSource(_)
             | RelOperator::Pattern(_)
             | RelOperator::MaterializedCte(_)
+            | RelOperator::GapFill(_)
+            | RelOperator::LimitBy(_)
+            | RelOperator::ConnectBy(_)
             | RelOperator::ConstantTableScan(_) => {}
         };
         for child in &self.children {
@@ -432,6 +435,9 @@ fn find_subquery(rel_op: &RelOperator) -> bool {
         | RelOperator::RuntimeFilterSource(_)
         | RelOperator::Pattern(_)
         | RelOperator::MaterializedCte(_)
+        | RelOperator::GapFill(_)
+        | RelOperator::LimitBy(_)
+        | RelOperator::ConnectBy(_)
         | RelOperator::ConstantTableScan(_) => false,
         RelOperator::Join(op) => {
             op.left_conditions.iter().any(find_subquery_in_expr)
@@ -0,0 +1,272 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::DataField;
+use parking_lot::RwLock;
+
+use crate::optimizer::RelExpr;
+use crate::optimizer::SExpr;
+use crate::plans::CteScan;
+use crate::plans::MaterializedCte;
+use crate::plans::RelOperator;
+use crate::plans::Scan;
+use crate::BaseTableColumn;
+use crate::ColumnBinding;
+use crate::ColumnBindingBuilder;
+use crate::ColumnEntry;
+use crate::IndexType;
+use crate::MetadataRef;
+use crate::Visibility;
+
+/// Share the scan of a small table referenced multiple times in a query, e.g. a self-join.
+///
+/// The table is read once into a materialized cte, and every reference of it is replaced
+/// by a cte scan of it. Only the plain scans (without any push down) of the tables
+/// whose number of rows is known and not larger than `shared_scan_threshold` are shared.
+pub fn try_share_scans(
+    ctx: Arc<dyn TableContext>,
+    metadata: &MetadataRef,
+    s_expr: SExpr,
+) -> Result<SExpr> {
+    let threshold = ctx.get_settings().get_shared_scan_threshold()?;
+    if threshold == 0 {
+        return Ok(s_expr);
+    }
+
+    let mut scans = vec![];
+    collect_scans(&s_expr, &mut scans);
+
+    // Group the scans by the table they read.
+    let mut groups: Vec<Vec<Scan>> = vec![];
+    for scan in scans {
+        if !is_shareable(metadata, &scan, threshold) {
+            continue;
+        }
+        match groups
+            .iter_mut()
+            .find(|group| is_same_table(metadata, &group[0], &scan))
+        {
+            Some(group) => group.push(scan),
+            None => groups.push(vec![scan]),
+        }
+    }
+
+    let mut s_expr = s_expr;
+    for group in groups.into_iter().filter(|group| group.len() >= 2) {
+        s_expr = share_scan(ctx.clone(), metadata, s_expr, &group)?;
+    }
+    Ok(s_expr)
+}
+
+fn collect_scans(s_expr: &SExpr, scans: &mut Vec<Scan>) {
+    if let RelOperator::Scan(scan) = s_expr.plan() {
+        scans.push(scan.clone());
+    }
+    for child in s_expr.children() {
+        collect_scans(child, scans);
+    }
+}
+
+fn is_shareable(metadata: &MetadataRef, scan: &Scan, threshold: u64) -> bool {
+    if scan.push_down_predicates.is_some()
+        || scan.prewhere.is_some()
+        || scan.limit.is_some()
+        || scan.order_by.is_some()
+        || scan.agg_index.is_some()
+    {
+        return false;
+    }
+
+    let num_rows = scan.statistics.statistics.and_then(|stat| stat.num_rows);
+    if !matches!(num_rows, Some(num_rows) if num_rows <= threshold) {
+        return false;
+    }
+
+    let metadata = metadata.read();
+    let table = metadata.table(scan.table_index);
+    if table.table().is_local() || table.is_source_of_stage() {
+        return false;
+    }
+    scan.columns.iter().all(|column| {
+        matches!(
+            metadata.column(*column),
+            ColumnEntry::BaseTableColumn(BaseTableColumn {
+                virtual_computed_expr: None,
+                ..
+            })
+        )
+    })
+}
+
+fn is_same_table(metadata: &MetadataRef, lhs: &Scan, rhs: &Scan) -> bool {
+    let metadata = metadata.read();
+    let (lhs, rhs) = (
+        metadata.table(lhs.table_index),
+        metadata.table(rhs.table_index),
+    );
+    // The whole table info is compared, so that the scans of different snapshots aren't shared.
+    lhs.catalog() == rhs.catalog()
+        && lhs.database() == rhs.database()
+        && lhs.name() == rhs.name()
+        && lhs.table().get_table_info() == rhs.table().get_table_info()
+}
+
+fn share_scan(
+    ctx: Arc<dyn TableContext>,
+    metadata: &MetadataRef,
+    s_expr: SExpr,
+    scans: &[Scan],
+) -> Result<SExpr> {
+    // The first scan reads all the columns used by the references, map the columns
+    // of every reference to the columns of it.
+    let source = &scans[0];
+    let source_columns = metadata.read().columns_by_table_index(source.table_index);
+    let mut columns: Vec<BaseTableColumn> = vec![];
+    let mut column_maps = Vec::with_capacity(scans.len());
+    for scan in scans {
+        let mut column_map = Vec::with_capacity(scan.columns.len());
+        for index in scan.columns.iter() {
+            let ColumnEntry::BaseTableColumn(column) = metadata.read().column(*index).clone()
+            else {
+                unreachable!()
+            };
+            let Some(source_column) = source_columns.iter().find_map(|entry| match entry {
+                ColumnEntry::BaseTableColumn(source_column)
+                    if source_column.column_name == column.column_name
+                        && source_column.path_indices == column.path_indices =>
+                {
+                    Some(source_column.clone())
+                }
+                _ => None,
+            }) else {
+                return Ok(s_expr);
+            };
+            column_map.push((*index, source_column.column_index));
+            if !columns
+                .iter()
+                .any(|column| column.column_index == source_column.column_index)
+            {
+                columns.push(source_column);
+            }
+        }
+        column_map.sort();
+        column_maps.push(column_map);
+    }
+    columns.sort_by_key(|column| column.column_index);
+
+    let cte_idx = ctx
+        .get_materialized_ctes()
+        .read()
+        .keys()
+        .map(|idx| idx.0 + 1)
+        .max()
+        .unwrap_or(0);
+
+    let left_output_columns = columns
+        .iter()
+        .map(|column| {
+            ColumnBindingBuilder::new(
+                column.column_name.clone(),
+                column.column_index,
+                Box::new((&column.data_type).into()),
+                Visibility::Visible,
+            )
+            .column_position(column.column_position)
+            .table_index(Some(column.table_index))
+            .build()
+        })
+        .collect::<Vec<_>>();
+
+    let mut s_expr = s_expr;
+    for (used_idx, (scan, column_map)) in scans.iter().zip(column_maps.iter()).enumerate() {
+        let cte_scan = create_cte_scan(
+            metadata,
+            scan,
+            (cte_idx, used_idx + 1),
+            column_map,
+            &left_output_columns,
+        )?;
+        ctx.set_materialized_cte((cte_idx, used_idx + 1), Arc::new(RwLock::new(vec![])))?;
+        s_expr = replace_scan(&s_expr, scan.table_index, &cte_scan);
+    }
+
+    let source = Scan {
+        columns: columns.iter().map(|column| column.column_index).collect(),
+        ..source.clone()
+    };
+    Ok(SExpr::create_binary(
+        Arc::new(RelOperator::MaterializedCte(MaterializedCte {
+            left_output_columns,
+            cte_idx,
+        })),
+        Arc::new(SExpr::create_leaf(Arc::new(source.into()))),
+        Arc::new(s_expr),
+    ))
+}
+
+fn create_cte_scan(
+    metadata: &MetadataRef,
+    scan: &Scan,
+    cte_idx: (IndexType, usize),
+    column_map: &[(IndexType, IndexType)],
+    cte_columns: &[ColumnBinding],
+) -> Result<SExpr> {
+    let stat = RelExpr::with_s_expr(&SExpr::create_leaf(Arc::new(scan.clone().into())))
+        .derive_cardinality()?;
+
+    let metadata = metadata.read();
+    let mut fields = Vec::with_capacity(column_map.len());
+    let mut offsets = Vec::with_capacity(column_map.len());
+    for (index, source_index) in column_map {
+        fields.push(DataField::new(
+            &index.to_string(),
+            metadata.column(*index).data_type(),
+        ));
+        // It is safe to unwrap here because the cte reads all the columns of the references.
+        offsets.push(
+            cte_columns
+                .iter()
+                .position(|cte_column| cte_column.index == *source_index)
+                .unwrap(),
+        );
+    }
+
+    Ok(SExpr::create_leaf(Arc::new(
+        CteScan {
+            cte_idx,
+            fields,
+            offsets,
+            stat,
+        }
+        .into(),
+    )))
+}
+
+fn replace_scan(s_expr: &SExpr, table_index: IndexType, cte_scan: &SExpr) -> SExpr {
+    if let RelOperator::Scan(scan) = s_expr.plan() {
+        if scan.table_index == table_index {
+            return cte_scan.clone();
+        }
+    }
+    let children = s_expr
+        .children()
+        .iter()
+        .map(|child| Arc::new(replace_scan(child, table_index, cte_scan)))
+        .collect::<Vec<_>>();
+    s_expr.replace_children(children)
+}
@@ -12,8 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_expression::ColumnId;
+use storages_common_table_meta::table::TableConstraint;
+use storages_common_table_meta::table::TableConstraints;
+
 use super::SExpr;
+use crate::plans::AggregateMode;
+use crate::plans::JoinType;
 use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::ColumnEntry;
+use crate::ColumnSet;
+use crate::IndexType;
 use crate::MetadataRef;
 
 /// Check if a query will read data from local tables(e.g. system tables).
@@ -28,3 +38,209 @@ pub fn contains_local_table_scan(s_expr: &SExpr, metadata: &MetadataRef) -> bool
             false
         }
 }
+
+/// Derive the sets of columns whose values are unique in the output of the expression,
+/// from the UNIQUE and PRIMARY KEY constraints of the scanned tables.
+///
+/// The constraints are informational, they are trusted without being enforced.
+pub fn derive_unique_keys(s_expr: &SExpr, metadata: &MetadataRef) -> Vec<ColumnSet> {
+    match s_expr.plan() {
+        RelOperator::Scan(scan) => {
+            let metadata_guard = metadata.read();
+            let table = metadata_guard.table(scan.table_index).table();
+            let Ok(constraints) = TableConstraints::from_options(table.options()) else {
+                return vec![];
+            };
+            drop(metadata_guard);
+            constraints
+                .unique_keys()
+                .filter_map(|columns| base_column_indices(metadata, scan.table_index, columns))
+                .map(|columns| columns.into_iter().collect())
+                .collect()
+        }
+        RelOperator::Filter(_)
+        | RelOperator::Sort(_)
+        | RelOperator::Limit(_)
+        | RelOperator::LimitBy(_)
+        | RelOperator::EvalScalar(_) => match s_expr.child(0) {
+            Ok(child) => derive_unique_keys(child, metadata),
+            Err(_) => vec![],
+        },
+        // The group items are unique in the output, so are the unique keys of the input
+        // covered by them. An aggregate without group items outputs a single row, which is
+        // identified by the empty key.
+        RelOperator::Aggregate(agg)
+            if agg.grouping_sets.is_none() && agg.mode != AggregateMode::Partial =>
+        {
+            let group_columns: ColumnSet = agg.group_items.iter().map(|item| item.index).collect();
+            let mut keys = match s_expr.child(0) {
+                Ok(child) => derive_unique_keys(child, metadata)
+                    .into_iter()
+                    .filter(|key| key.is_subset(&group_columns))
+                    .collect(),
+                Err(_) => vec![],
+            };
+            keys.push(group_columns);
+            keys
+        }
+        RelOperator::Join(join) => {
+            let (Ok(left), Ok(right)) = (s_expr.child(0), s_expr.child(1)) else {
+                return vec![];
+            };
+            match join.join_type {
+                JoinType::LeftSemi | JoinType::LeftAnti => derive_unique_keys(left, metadata),
+                JoinType::RightSemi | JoinType::RightAnti => derive_unique_keys(right, metadata),
+                JoinType::Inner | JoinType::Cross | JoinType::Left | JoinType::Right => {
+                    let left_keys = derive_unique_keys(left, metadata);
+                    let right_keys = derive_unique_keys(right, metadata);
+                    // The rows of a side are kept unique if each of them matches at most one
+                    // row of the other side, i.e. the other side is unique on its join keys.
+                    let at_most_one_match = |keys: &[ColumnSet], conditions: &[ScalarExpr]| {
+                        let join_keys = column_indices(conditions)
+                            .map(|columns| columns.into_iter().collect::<ColumnSet>());
+                        match join_keys {
+                            Some(join_keys) if !join_keys.is_empty() => {
+                                keys.iter().any(|key| key.is_subset(&join_keys))
+                            }
+                            _ => false,
+                        }
+                    };
+                    let mut keys = vec![];
+                    if matches!(join.join_type, JoinType::Inner | JoinType::Left)
+                        && at_most_one_match(&right_keys, &join.right_conditions)
+                    {
+                        keys.extend(left_keys.iter().cloned());
+                    }
+                    if matches!(join.join_type, JoinType::Inner | JoinType::Right)
+                        && at_most_one_match(&left_keys, &join.left_conditions)
+                    {
+                        keys.extend(right_keys.iter().cloned());
+                    }
+                    // A pair of rows is identified by the keys of both sides, the NULLs padded
+                    // by the outer joins may repeat.
+                    if matches!(join.join_type, JoinType::Inner | JoinType::Cross) {
+                        for left_key in left_keys.iter() {
+                            for right_key in right_keys.iter() {
+                                keys.push(left_key.union(right_key).cloned().collect());
+                            }
+                        }
+                    }
+                    keys
+                }
+                _ => vec![],
+            }
+        }
+        _ => vec![],
+    }
+}
+
+/// A foreign key in the output of an expression, its rows having no NULL in `columns`
+/// match exactly one row of the table `ref_table_id` in the columns `ref_columns`.
+#[derive(Clone, Debug)]
+pub struct ForeignKey {
+    pub columns: Vec<IndexType>,
+    pub ref_table_id: u64,
+    pub ref_columns: Vec<ColumnId>,
+}
+
+/// Derive the foreign keys in the output of the expression, from the FOREIGN KEY
+/// constraints of the scanned tables.
+///
+/// The operators filtering or repeating the rows keep the foreign keys, unlike the ones
+/// padding NULLs or computing new values.
+pub fn derive_foreign_keys(s_expr: &SExpr, metadata: &MetadataRef) -> Vec<ForeignKey> {
+    match s_expr.plan() {
+        RelOperator::Scan(scan) => {
+            let metadata_guard = metadata.read();
+            let table = metadata_guard.table(scan.table_index).table();
+            let Ok(constraints) = TableConstraints::from_options(table.options()) else {
+                return vec![];
+            };
+            drop(metadata_guard);
+            constraints
+                .foreign_keys()
+                .filter_map(|constraint| match constraint {
+                    TableConstraint::ForeignKey {
+                        columns,
+                        ref_table_id,
+                        ref_columns,
+                        ..
+                    } => Some(ForeignKey {
+                        columns: base_column_indices(metadata, scan.table_index, columns)?,
+                        ref_table_id: *ref_table_id,
+                        ref_columns: ref_columns.clone(),
+                    }),
+                    _ => None,
+                })
+                .collect()
+        }
+        RelOperator::Filter(_)
+        | RelOperator::Sort(_)
+        | RelOperator::Limit(_)
+        | RelOperator::LimitBy(_)
+        | RelOperator::EvalScalar(_) => match s_expr.child(0) {
+            Ok(child) => derive_foreign_keys(child, metadata),
+            Err(_) => vec![],
+        },
+        RelOperator::Join(join) => {
+            let (Ok(left), Ok(right)) = (s_expr.child(0), s_expr.child(1)) else {
+                return vec![];
+            };
+            match join.join_type {
+                JoinType::Inner | JoinType::Cross => {
+                    let mut keys = derive_foreign_keys(left, metadata);
+                    keys.extend(derive_foreign_keys(right, metadata));
+                    keys
+                }
+                JoinType::Left | JoinType::LeftSemi | JoinType::LeftAnti => {
+                    derive_foreign_keys(left, metadata)
+                }
+                JoinType::Right | JoinType::RightSemi | JoinType::RightAnti => {
+                    derive_foreign_keys(right, metadata)
+                }
+                _ => vec![],
+            }
+        }
+        _ => vec![],
+    }
+}
+
+/// The indices of the columns read from a table by their column ids, `None` if any of
+/// the columns is not found, e.g. dropped.
+pub fn base_column_indices(
+    metadata: &MetadataRef,
+    table_index: IndexType,
+    column_ids: &[ColumnId],
+) -> Option<Vec<IndexType>> {
+    let metadata = metadata.read();
+    let schema = metadata.table(table_index).table().schema();
+    let columns = metadata.columns_by_table_index(table_index);
+    column_ids
+        .iter()
+        .map(|column_id| {
+            let field = schema
+                .fields()
+                .iter()
+                .find(|f| f.column_id() == *column_id)?;
+            columns.iter().find_map(|column| match column {
+                ColumnEntry::BaseTableColumn(column)
+                    if column.path_indices.is_none() && &column.column_name == field.name() =>
+                {
+                    Some(column.column_index)
+                }
+                _ => None,
+            })
+        })
+        .collect()
+}
+
+/// The indices of the columns if all the expressions are column references.
+pub fn column_indices(scalars: &[ScalarExpr]) -> Option<Vec<IndexType>> {
+    scalars
+        .iter()
+        .map(|scalar| match scalar {
+            ScalarExpr::BoundColumnRef(column) => Some(column.column.index),
+            _ => None,
+        })
+        .collect()
+}
@@ -0,0 +1,282 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_ast::parser::token::Token;
+use common_ast::parser::token::TokenKind;
+use common_base::base::GlobalInstance;
+use common_cache::Cache;
+use common_cache::LruCache;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_config::CacheConfig;
+use common_exception::Result;
+use common_meta_app::schema::TableIdent;
+use common_metrics::cache::metrics_inc_cache_access_count;
+use common_metrics::cache::metrics_inc_cache_hit_count;
+use common_metrics::cache::metrics_inc_cache_miss_count;
+use parking_lot::Mutex;
+use parking_lot::RwLock;
+use storages_common_cache::CacheStats;
+
+use crate::plans::Plan;
+use crate::PlanExtras;
+
+const PLAN_CACHE_NAME: &str = "plan";
+
+/// PlanCache keeps the bound and optimized plans of queries, so the identical queries
+/// skip binding and optimizing.
+///
+/// A plan is keyed by the normalized sql together with everything else the binder
+/// depends on: the user, the role, the current database and the changed settings.
+/// The literals are kept in the key, as the optimizer folds constants and prunes
+/// by them. The queries binding the values of the session as literals, such as
+/// `connection_id()` and `last_query_id()`, are never cached.
+///
+/// Every table the plan depends on, including views, is looked up again on hitting
+/// the cache. The plan is dropped if any of them has been changed since, which also
+/// covers the data written to them.
+pub struct PlanCache {
+    plans: Option<Mutex<LruCache<String, Arc<CachedPlan>>>>,
+    stats: CacheStats,
+}
+
+struct CachedPlan {
+    plan: Plan,
+    extras: PlanExtras,
+    cacheable: bool,
+    tables: Vec<TableVersion>,
+}
+
+/// The version of a table the plan is bound with.
+struct TableVersion {
+    catalog: String,
+    database: String,
+    name: String,
+    ident: TableIdent,
+    options: BTreeMap<String, String>,
+}
+
+impl TableVersion {
+    fn create(catalog: &str, database: &str, name: &str, table: &dyn Table) -> Self {
+        TableVersion {
+            catalog: catalog.to_string(),
+            database: database.to_string(),
+            name: name.to_string(),
+            ident: table.get_table_info().ident,
+            options: table.options().clone(),
+        }
+    }
+
+    fn matches(&self, table: &dyn Table) -> bool {
+        // The options differ for the tables of time travel.
+        table.get_table_info().ident == self.ident && table.options() == &self.options
+    }
+}
+
+impl PlanCache {
+    pub fn init(config: &CacheConfig) -> Result<()> {
+        let plans = match config.plan_cache_count {
+            0 => None,
+            capacity => Some(Mutex::new(LruCache::new(capacity))),
+        };
+        GlobalInstance::set(Arc::new(PlanCache {
+            plans,
+            stats: CacheStats::default(),
+        }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<PlanCache> {
+        GlobalInstance::get()
+    }
+
+    /// Returns None if the plan cache is disabled.
+    pub fn num_items(&self) -> Option<usize> {
+        self.plans.as_ref().map(|plans| plans.lock().len())
+    }
+
+    pub fn name(&self) -> &str {
+        PLAN_CACHE_NAME
+    }
+
+    pub fn stats(&self) -> &CacheStats {
+        &self.stats
+    }
+
+    pub fn clear(&self) {
+        if let Some(plans) = &self.plans {
+            plans.lock().clear();
+        }
+    }
+
+    /// Generates the cache key of the sql, None if the query can't be cached.
+    pub fn cache_key(ctx: &dyn TableContext, tokens: &[Token]) -> Result<Option<String>> {
+        if tokens.first().map(|t| t.kind) != Some(TokenKind::SELECT)
+            && tokens.first().map(|t| t.kind) != Some(TokenKind::WITH)
+        {
+            return Ok(None);
+        }
+
+        // Keywords are case-insensitive, while the quoted identifiers and strings are not.
+        let normalized = tokens
+            .iter()
+            .filter(|t| t.kind != TokenKind::EOI)
+            .map(|t| match t.kind.is_keyword() {
+                true => t.text().to_uppercase(),
+                false => t.text().to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let user = ctx.get_current_user()?.identity();
+        let role = ctx.get_current_role().map(|r| r.name).unwrap_or_default();
+        Ok(Some(format!(
+            "{}/{}/{}/{}/{}/{}/{}",
+            ctx.get_tenant(),
+            user,
+            role,
+            ctx.get_current_database(),
+            ctx.get_settings().changes_fingerprint(),
+            ctx.get_cluster().is_empty(),
+            normalized
+        )))
+    }
+
+    /// Gets the cached plan of the key if none of its tables has been changed.
+    #[async_backtrace::framed]
+    pub async fn get(
+        &self,
+        ctx: &dyn TableContext,
+        key: &str,
+    ) -> Result<Option<(Plan, PlanExtras)>> {
+        let Some(plans) = &self.plans else {
+            return Ok(None);
+        };
+        metrics_inc_cache_access_count(1, PLAN_CACHE_NAME);
+        let cached = plans.lock().get(key).cloned();
+        let Some(cached) = cached else {
+            metrics_inc_cache_miss_count(1, PLAN_CACHE_NAME);
+            self.stats.record(false);
+            return Ok(None);
+        };
+
+        let mut tables = Vec::with_capacity(cached.tables.len());
+        for version in cached.tables.iter() {
+            match ctx
+                .get_table(&version.catalog, &version.database, &version.name)
+                .await
+            {
+                Ok(table) if version.matches(table.as_ref()) => tables.push(table),
+                _ => {
+                    plans.lock().pop(key);
+                    metrics_inc_cache_miss_count(1, PLAN_CACHE_NAME);
+                    self.stats.record(false);
+                    return Ok(None);
+                }
+            }
+        }
+        metrics_inc_cache_hit_count(1, PLAN_CACHE_NAME);
+        self.stats.record(true);
+
+        // The tables keep the states of a query, every query reads the tables of its own.
+        let mut metadata = cached.extras.metadata.read().clone();
+        for (index, table) in tables.into_iter().enumerate() {
+            metadata.replace_table(index, table);
+        }
+        let metadata = Arc::new(RwLock::new(metadata));
+        let Plan::Query {
+            s_expr,
+            bind_context,
+            rewrite_kind,
+            formatted_ast,
+            ignore_result,
+            ..
+        } = &cached.plan
+        else {
+            return Ok(None);
+        };
+        let plan = Plan::Query {
+            s_expr: s_expr.clone(),
+            metadata: metadata.clone(),
+            bind_context: bind_context.clone(),
+            rewrite_kind: rewrite_kind.clone(),
+            formatted_ast: formatted_ast.clone(),
+            ignore_result: *ignore_result,
+        };
+        if !cached.cacheable {
+            ctx.set_cacheable(false);
+        }
+
+        Ok(Some((plan, PlanExtras {
+            metadata,
+            format: cached.extras.format.clone(),
+            statement: cached.extras.statement.clone(),
+            lineage: cached.extras.lineage.clone(),
+        })))
+    }
+
+    /// Caches the plan of the key if it only depends on the tables in catalogs.
+    #[async_backtrace::framed]
+    pub async fn put(&self, ctx: &dyn TableContext, key: String, plan: &Plan, extras: &PlanExtras) {
+        let Some(plans) = &self.plans else {
+            return;
+        };
+        if !matches!(plan, Plan::Query { .. }) || !ctx.get_materialized_ctes().read().is_empty() {
+            return;
+        }
+
+        let entries = {
+            let metadata = extras.metadata.read();
+            // The literals of the session values are only valid in the session and the query.
+            if metadata.has_agg_indexes() || metadata.is_session_dependent() {
+                return;
+            }
+            metadata.tables().to_vec()
+        };
+        let mut tables = Vec::with_capacity(entries.len());
+        for (index, entry) in entries.iter().enumerate() {
+            // The stages are listed, and the aggregating indexes are chosen, on binding.
+            if entry.index() != index || entry.is_source_of_stage() || entry.is_source_of_index() {
+                return;
+            }
+            let version = TableVersion::create(
+                entry.catalog(),
+                entry.database(),
+                entry.name(),
+                entry.table().as_ref(),
+            );
+            // The tables of table functions and time travel can't be looked up again.
+            match ctx
+                .get_table(entry.catalog(), entry.database(), entry.name())
+                .await
+            {
+                Ok(table) if version.matches(table.as_ref()) => tables.push(version),
+                _ => return,
+            }
+        }
+
+        plans.lock().put(
+            key,
+            Arc::new(CachedPlan {
+                plan: plan.clone(),
+                extras: extras.clone(),
+                cacheable: ctx.get_cacheable(),
+                tables,
+            }),
+        );
+    }
+}
@@ -27,6 +27,7 @@ use common_catalog::catalog::CatalogManager;
 use common_catalog::query_kind::QueryKind;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
+use common_meta_client::with_follower_read;
 use parking_lot::RwLock;
 
 use super::semantic::AggregateRewriter;
@@ -34,13 +35,16 @@ use super::semantic::DistinctToGroupBy;
 use crate::optimizer::optimize;
 use crate::optimizer::OptimizerConfig;
 use crate::optimizer::OptimizerContext;
+use crate::plan_column_lineage;
 use crate::plans::Insert;
 use crate::plans::InsertInputSource;
 use crate::plans::Plan;
 use crate::Binder;
+use crate::ColumnLineage;
 use crate::Metadata;
 use crate::MetadataRef;
 use crate::NameResolutionContext;
+use crate::PlanCache;
 
 const PROBE_INSERT_INITIAL_TOKENS: usize = 128;
 const PROBE_INSERT_MAX_TOKENS: usize = 128 * 8;
@@ -54,6 +58,8 @@ pub struct PlanExtras {
     pub metadata: MetadataRef,
     pub format: Option<String>,
     pub statement: Statement,
+    /// Column lineage of the bound plan, see [`plan_column_lineage`].
+    pub lineage: Vec<ColumnLineage>,
 }
 
 impl Planner {
@@ -89,6 +95,17 @@ impl Planner {
             (&mut tokenizer).collect::<Result<_>>()?
         };
 
+        // Reuse the plan of an identical query if none of its tables has been changed.
+        let plan_cache_key = match settings.get_enable_plan_cache()? && !is_insert_stmt {
+            true => PlanCache::cache_key(self.ctx.as_ref(), &tokens)?,
+            false => None,
+        };
+        if let Some(key) = &plan_cache_key {
+            if let Some(cached) = PlanCache::instance().get(self.ctx.as_ref(), key).await? {
+                return Ok(cached);
+            }
+        }
+
         loop {
             let res = async {
                 // Step 2: Parse the SQL.
@@ -111,7 +128,13 @@ impl Planner {
                     name_resolution_ctx,
                     metadata.clone(),
                 );
-                let plan = binder.bind(&stmt).await?;
+                let plan = if matches!(stmt, Statement::Query(_)) {
+                    // A query only reads the metadata, let it be served by a meta-service follower.
+                    with_follower_read(binder.bind(&stmt)).await?
+                } else {
+                    binder.bind(&stmt).await?
+                };
+                let lineage = plan_column_lineage(&plan)?;
 
                 // Step 4: Optimize the SExpr with optimizers, and generate optimized physical SExpr
                 let opt_ctx = Arc::new(OptimizerContext::new(OptimizerConfig {
@@ -123,6 +146,7 @@ impl Planner {
                     metadata,
                     format,
                     statement: stmt,
+                    lineage,
                 }))
             }
             .await;
@@ -162,6 +186,11 @@ impl Planner {
                     tokens.extend(iter);
                 };
             } else {
+                if let (Some(key), Ok((plan, extras))) = (plan_cache_key, &res) {
+                    PlanCache::instance()
+                        .put(self.ctx.as_ref(), key, plan, extras)
+                        .await;
+                }
                 return res;
             }
         }
@@ -97,7 +97,10 @@ impl Operator for Aggregate {
     }
 
     fn derive_physical_prop(&self, rel_expr: &RelExpr) -> Result<PhysicalProperty> {
-        rel_expr.derive_physical_prop_child(0)
+        let mut prop = rel_expr.derive_physical_prop_child(0)?;
+        // The groups are output in the order of the hash table.
+        prop.ordering.clear();
+        Ok(prop)
     }
 
     fn compute_required_prop_child(
@@ -0,0 +1,110 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+
+use crate::optimizer::ColumnSet;
+use crate::optimizer::Distribution;
+use crate::optimizer::PhysicalProperty;
+use crate::optimizer::RelExpr;
+use crate::optimizer::RelationalProperty;
+use crate::optimizer::RequiredProperty;
+use crate::optimizer::StatInfo;
+use crate::plans::Operator;
+use crate::plans::RelOp;
+use crate::IndexType;
+
+/// `ConnectBy` traverses the hierarchy stored in its input as an edge list, where each
+/// row links `id_column` to its `parent_column`. Starting from the rows whose
+/// `root_column` is true, the descendants are emitted in depth-first order, up to
+/// `max_depth` levels, together with their level and path from the root.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ConnectBy {
+    pub id_column: IndexType,
+    pub parent_column: IndexType,
+    /// A boolean column, true for the rows the traversals start with.
+    pub root_column: IndexType,
+    pub max_depth: u64,
+    pub separator: String,
+    /// The level of the row, starting from 1 for the roots.
+    pub level_column: IndexType,
+    /// The ids from the root to the row, joined by `separator`.
+    pub path_column: IndexType,
+}
+
+impl ConnectBy {
+    pub fn used_columns(&self) -> ColumnSet {
+        ColumnSet::from([
+            self.id_column,
+            self.parent_column,
+            self.root_column,
+            self.level_column,
+            self.path_column,
+        ])
+    }
+}
+
+impl Operator for ConnectBy {
+    fn rel_op(&self) -> RelOp {
+        RelOp::ConnectBy
+    }
+
+    fn derive_relational_prop(&self, rel_expr: &RelExpr) -> Result<Arc<RelationalProperty>> {
+        let input_prop = rel_expr.derive_relational_prop_child(0)?;
+
+        // Derive output columns
+        let mut output_columns = input_prop.output_columns.clone();
+        output_columns.insert(self.level_column);
+        output_columns.insert(self.path_column);
+
+        // Derive used columns
+        let mut used_columns = self.used_columns();
+        used_columns.extend(input_prop.used_columns.clone());
+
+        Ok(Arc::new(RelationalProperty {
+            output_columns,
+            outer_columns: input_prop.outer_columns.clone(),
+            used_columns,
+        }))
+    }
+
+    fn derive_physical_prop(&self, rel_expr: &RelExpr) -> Result<PhysicalProperty> {
+        let mut prop = rel_expr.derive_physical_prop_child(0)?;
+        // The rows are output in the order of the traversal.
+        prop.ordering.clear();
+        Ok(prop)
+    }
+
+    fn derive_cardinality(&self, rel_expr: &RelExpr) -> Result<Arc<StatInfo>> {
+        // Each row is visited once per path from a root, which is unknown before the
+        // traversal, the input cardinality is a fair estimation of acyclic hierarchies.
+        rel_expr.derive_cardinality_child(0)
+    }
+
+    fn compute_required_prop_child(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _rel_expr: &RelExpr,
+        _child_index: usize,
+        required: &RequiredProperty,
+    ) -> Result<RequiredProperty> {
+        // The whole hierarchy must be seen by the same processor.
+        let mut required = required.clone();
+        required.distribution = Distribution::Serial;
+        Ok(required)
+    }
+}
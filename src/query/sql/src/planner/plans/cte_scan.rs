@@ -78,6 +78,7 @@ impl Operator for CteScan {
     fn derive_physical_prop(&self, _rel_expr: &RelExpr) -> Result<PhysicalProperty> {
         Ok(PhysicalProperty {
             distribution: Distribution::Serial,
+            ordering: vec![],
         })
     }
 
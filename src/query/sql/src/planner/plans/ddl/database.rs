@@ -12,6 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
+use common_expression::types::DataType;
+use common_expression::DataField;
+use common_expression::DataSchema;
 use common_expression::DataSchemaRef;
 use common_meta_app::schema::CreateDatabaseReq;
 use common_meta_app::schema::DatabaseMeta;
@@ -62,6 +67,24 @@ pub struct DropDatabasePlan {
     pub tenant: String,
     pub catalog: String,
     pub database: String,
+    pub cascade: bool,
+    /// Only report the objects `CASCADE` would drop.
+    pub dry_run: bool,
+}
+
+impl DropDatabasePlan {
+    /// `DROP DATABASE ... CASCADE` reports the objects of other databases dropped with it.
+    pub fn schema(&self) -> DataSchemaRef {
+        if self.cascade {
+            Arc::new(DataSchema::new(vec![
+                DataField::new("object_type", DataType::String),
+                DataField::new("database", DataType::String),
+                DataField::new("name", DataType::String),
+            ]))
+        } else {
+            Arc::new(DataSchema::empty())
+        }
+    }
 }
 
 impl From<DropDatabasePlan> for DropDatabaseReq {
@@ -72,6 +95,7 @@ impl From<DropDatabasePlan> for DropDatabaseReq {
                 tenant: p.tenant,
                 db_name: p.database,
             },
+            dependent_table_ids: vec![],
         }
     }
 }
@@ -84,6 +108,7 @@ impl From<&DropDatabasePlan> for DropDatabaseReq {
                 tenant: p.tenant.clone(),
                 db_name: p.database.clone(),
             },
+            dependent_table_ids: vec![],
         }
     }
 }
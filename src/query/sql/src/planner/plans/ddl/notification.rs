@@ -0,0 +1,127 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType::UInt64;
+use common_expression::DataField;
+use common_expression::DataSchema;
+use common_expression::DataSchemaRef;
+use common_expression::DataSchemaRefExt;
+
+pub fn notification_schema() -> DataSchemaRef {
+    Arc::new(DataSchema::new(vec![
+        DataField::new("created_on", DataType::Timestamp),
+        DataField::new("name", DataType::String),
+        DataField::new("id", DataType::Number(UInt64)),
+        DataField::new("type", DataType::String),
+        DataField::new("enabled", DataType::Boolean),
+        DataField::new("options", DataType::String),
+        DataField::new("max_retries", DataType::Number(UInt64)),
+        DataField::new("retry_interval", DataType::Number(UInt64)),
+        DataField::new("comment", DataType::String.wrap_nullable()),
+        DataField::new("updated_on", DataType::Timestamp),
+    ]))
+}
+
+/// Where the events are sent to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NotificationTarget {
+    Webhook {
+        url: String,
+        method: String,
+        authorization_header: Option<String>,
+        /// The payload is signed with HMAC-SHA256 by this secret.
+        signing_secret: Option<String>,
+    },
+    Email {
+        recipients: Vec<String>,
+    },
+}
+
+/// A failed delivery is retried `max_retries` times, `retry_interval_secs` apart.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NotificationRetryPolicy {
+    pub max_retries: u32,
+    pub retry_interval_secs: u64,
+}
+
+impl Default for NotificationRetryPolicy {
+    fn default() -> Self {
+        NotificationRetryPolicy {
+            max_retries: 3,
+            retry_interval_secs: 60,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateNotificationPlan {
+    pub if_not_exists: bool,
+    pub tenant: String,
+    pub name: String,
+    pub enabled: bool,
+    pub target: NotificationTarget,
+    pub retry_policy: NotificationRetryPolicy,
+    pub comments: String,
+}
+
+impl CreateNotificationPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlterNotificationPlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub name: String,
+    pub enabled: Option<bool>,
+    pub target: Option<NotificationTarget>,
+    pub retry_policy: Option<NotificationRetryPolicy>,
+    pub comments: Option<String>,
+}
+
+impl AlterNotificationPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropNotificationPlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub name: String,
+}
+
+impl DropNotificationPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DescNotificationPlan {
+    pub tenant: String,
+    pub name: String,
+}
+
+impl DescNotificationPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        notification_schema()
+    }
+}
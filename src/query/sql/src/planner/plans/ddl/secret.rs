@@ -0,0 +1,61 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::DataField;
+use common_expression::DataSchemaRef;
+use common_expression::DataSchemaRefExt;
+use common_meta_app::principal::UserDefinedSecret;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateSecretPlan {
+    pub if_not_exists: bool,
+    pub secret: UserDefinedSecret,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropSecretPlan {
+    pub if_exists: bool,
+    pub name: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DescSecretPlan {
+    pub name: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShowSecretsPlan {}
+
+fn secret_schema() -> DataSchemaRef {
+    DataSchemaRefExt::create(vec![
+        DataField::new("name", DataType::String),
+        DataField::new("provider", DataType::String),
+        DataField::new("provider_params", DataType::String),
+        DataField::new("cache_ttl", DataType::Number(NumberDataType::UInt64)),
+    ])
+}
+
+impl DescSecretPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        secret_schema()
+    }
+}
+
+impl ShowSecretsPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        secret_schema()
+    }
+}
@@ -29,6 +29,7 @@ use common_expression::TableSchemaRef;
 use common_meta_app::schema::TableNameIdent;
 use common_meta_app::schema::UndropTableReq;
 use common_meta_app::storage::StorageParams;
+use storages_common_table_meta::table::TableConstraint;
 
 use crate::plans::Plan;
 
@@ -50,6 +51,8 @@ pub struct CreateTablePlan {
     pub options: TableOptions,
     pub field_comments: Vec<String>,
     pub cluster_key: Option<String>,
+    /// Masking policies bound to columns at creation, keyed by column name.
+    pub column_mask_policy: Option<BTreeMap<String, String>>,
     pub as_select: Option<Box<Plan>>,
 }
 
@@ -59,6 +62,25 @@ impl CreateTablePlan {
     }
 }
 
+/// Zero-copy clone of a fuse table.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CloneTablePlan {
+    pub if_not_exists: bool,
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub source_catalog: String,
+    pub source_database: String,
+    pub source_table: String,
+}
+
+impl CloneTablePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
 /// Desc.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DescribeTablePlan {
@@ -221,6 +243,21 @@ impl SetOptionsPlan {
     }
 }
 
+/// Promote a read-only attached replica.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PromoteReplicaPlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+}
+
+impl PromoteReplicaPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
 // Table add column
 #[derive(Clone, Debug, PartialEq)]
 pub struct AddTableColumnPlan {
@@ -279,6 +316,37 @@ impl DropTableColumnPlan {
     }
 }
 
+// Table add constraint
+#[derive(Clone, Debug, PartialEq)]
+pub struct AddTableConstraintPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub name: String,
+    pub constraint: TableConstraint,
+}
+
+impl AddTableConstraintPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+// Table drop constraint
+#[derive(Clone, Debug, PartialEq)]
+pub struct DropTableConstraintPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub name: String,
+}
+
+impl DropTableConstraintPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
 // ModifyColumnAction after name resolved, used in ModifyTableColumnPlan
 #[derive(Debug, Clone, PartialEq)]
 pub enum ModifyColumnAction {
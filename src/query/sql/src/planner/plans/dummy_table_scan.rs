@@ -54,6 +54,7 @@ impl Operator for DummyTableScan {
     fn derive_physical_prop(&self, _rel_expr: &RelExpr) -> Result<PhysicalProperty> {
         Ok(PhysicalProperty {
             distribution: Distribution::Serial,
+            ordering: vec![],
         })
     }
 
@@ -52,6 +52,8 @@ impl Operator for Exchange {
                 Exchange::Broadcast => Distribution::Broadcast,
                 Exchange::Merge => Distribution::Serial,
             },
+            // The rows from different nodes or threads are interleaved by the exchange.
+            ordering: vec![],
         })
     }
 
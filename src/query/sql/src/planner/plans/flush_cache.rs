@@ -0,0 +1,49 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::CacheKind;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum FlushCacheKind {
+    All,
+    Result,
+    Plan,
+    Meta,
+    Data,
+}
+
+impl FlushCacheKind {
+    /// Whether the caches local to each node are flushed, which requires the statement to be
+    /// sent to the other nodes of the cluster.
+    pub fn flush_local_caches(&self) -> bool {
+        !matches!(self, FlushCacheKind::Result)
+    }
+}
+
+impl From<CacheKind> for FlushCacheKind {
+    fn from(kind: CacheKind) -> Self {
+        match kind {
+            CacheKind::All => FlushCacheKind::All,
+            CacheKind::Result => FlushCacheKind::Result,
+            CacheKind::Plan => FlushCacheKind::Plan,
+            CacheKind::Meta => FlushCacheKind::Meta,
+            CacheKind::Data => FlushCacheKind::Data,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FlushCachePlan {
+    pub kind: FlushCacheKind,
+}
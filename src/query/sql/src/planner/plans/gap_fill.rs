@@ -0,0 +1,146 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+
+use crate::optimizer::ColumnSet;
+use crate::optimizer::Distribution;
+use crate::optimizer::PhysicalProperty;
+use crate::optimizer::RelExpr;
+use crate::optimizer::RelationalProperty;
+use crate::optimizer::RequiredProperty;
+use crate::optimizer::StatInfo;
+use crate::plans::Operator;
+use crate::plans::RelOp;
+use crate::plans::SortItem;
+use crate::IndexType;
+
+/// `GapFill` densifies the time series of its input. For each series identified by
+/// `partition_by`, the rows missing on the grid of `interval` are generated, and the
+/// other columns of the generated rows are filled by `method`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GapFill {
+    pub time_column: IndexType,
+    pub partition_by: Vec<IndexType>,
+    /// Width of the buckets, in microseconds for timestamps or in days for dates.
+    pub interval: i64,
+    /// Bounds of the grid, default to the first and the last time of each series.
+    pub start: Option<i64>,
+    pub end: Option<i64>,
+    pub method: GapFillMethod,
+    /// The filled columns, the outputs are the nullable counterparts of the inputs.
+    pub items: Vec<GapFillItem>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct GapFillItem {
+    pub input: IndexType,
+    pub output: IndexType,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum GapFillMethod {
+    Null,
+    Locf,
+    Linear,
+}
+
+impl std::fmt::Display for GapFillMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GapFillMethod::Null => write!(f, "NULL"),
+            GapFillMethod::Locf => write!(f, "LOCF"),
+            GapFillMethod::Linear => write!(f, "LINEAR"),
+        }
+    }
+}
+
+impl GapFill {
+    pub fn used_columns(&self) -> ColumnSet {
+        let mut used_columns = ColumnSet::new();
+        used_columns.insert(self.time_column);
+        used_columns.extend(self.partition_by.iter().cloned());
+        for item in self.items.iter() {
+            used_columns.insert(item.input);
+            used_columns.insert(item.output);
+        }
+        used_columns
+    }
+
+    /// The output rows are sorted by the partition by columns and then the time column.
+    pub fn ordering(&self) -> Vec<SortItem> {
+        self.partition_by
+            .iter()
+            .chain(std::iter::once(&self.time_column))
+            .map(|index| SortItem {
+                index: *index,
+                asc: true,
+                nulls_first: false,
+            })
+            .collect()
+    }
+}
+
+impl Operator for GapFill {
+    fn rel_op(&self) -> RelOp {
+        RelOp::GapFill
+    }
+
+    fn derive_relational_prop(&self, rel_expr: &RelExpr) -> Result<Arc<RelationalProperty>> {
+        let input_prop = rel_expr.derive_relational_prop_child(0)?;
+
+        // Derive output columns
+        let mut output_columns = input_prop.output_columns.clone();
+        for item in self.items.iter() {
+            output_columns.remove(&item.input);
+            output_columns.insert(item.output);
+        }
+
+        // Derive used columns
+        let mut used_columns = self.used_columns();
+        used_columns.extend(input_prop.used_columns.clone());
+
+        Ok(Arc::new(RelationalProperty {
+            output_columns,
+            outer_columns: input_prop.outer_columns.clone(),
+            used_columns,
+        }))
+    }
+
+    fn derive_physical_prop(&self, rel_expr: &RelExpr) -> Result<PhysicalProperty> {
+        let mut prop = rel_expr.derive_physical_prop_child(0)?;
+        prop.ordering = self.ordering();
+        Ok(prop)
+    }
+
+    fn derive_cardinality(&self, rel_expr: &RelExpr) -> Result<Arc<StatInfo>> {
+        rel_expr.derive_cardinality_child(0)
+    }
+
+    fn compute_required_prop_child(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _rel_expr: &RelExpr,
+        _child_index: usize,
+        required: &RequiredProperty,
+    ) -> Result<RequiredProperty> {
+        // All rows of a series must be seen by the same processor.
+        let mut required = required.clone();
+        required.distribution = Distribution::Serial;
+        Ok(required)
+    }
+}
@@ -153,6 +153,10 @@ pub struct Join {
     // if we execute distributed merge into, we need to hold the
     // hash table to get not match data from source.
     pub need_hold_hash_table: bool,
+    // The left anti join converted from `NOT IN` with a nullable key. It returns nothing if
+    // any build key is NULL, and drops the probe rows whose key is NULL unless the build side
+    // is empty.
+    pub null_aware: bool,
 }
 
 impl Default for Join {
@@ -166,6 +170,7 @@ impl Default for Join {
             from_correlated_subquery: Default::default(),
             contain_runtime_filter: false,
             need_hold_hash_table: false,
+            null_aware: false,
         }
     }
 }
@@ -402,19 +407,44 @@ impl Operator for Join {
         let build_prop = rel_expr.derive_physical_prop_child(1)?;
 
         match (&probe_prop.distribution, &build_prop.distribution) {
+            // The bucketed build side is shuffled if the probe side is Random.
+            (Distribution::Random, Distribution::Bucket(_)) => Ok(PhysicalProperty {
+                distribution: Distribution::Random,
+                ordering: vec![],
+            }),
+            // The unmatched rows of the co-located build side have NULL probe keys,
+            // which are not in the buckets of the probe side any more.
+            (Distribution::Bucket(_), Distribution::Bucket(_))
+                if matches!(
+                    self.join_type,
+                    JoinType::Right
+                        | JoinType::Full
+                        | JoinType::RightAnti
+                        | JoinType::RightSemi
+                        | JoinType::RightMark
+                ) =>
+            {
+                Ok(PhysicalProperty {
+                    distribution: Distribution::Random,
+                    ordering: vec![],
+                })
+            }
             // If the distribution of probe side is Random, we will pass through
             // the distribution of build side.
             (Distribution::Random, _) => Ok(PhysicalProperty {
                 distribution: build_prop.distribution.clone(),
+                ordering: vec![],
             }),
             // If both sides are broadcast, which means broadcast join is enabled, to make sure the current join is broadcast, should return Random.
             // Then required proper is broadcast, and the join will be broadcast.
             (Distribution::Broadcast, Distribution::Broadcast) => Ok(PhysicalProperty {
                 distribution: Distribution::Random,
+                ordering: vec![],
             }),
             // Otherwise pass through probe side.
             _ => Ok(PhysicalProperty {
                 distribution: probe_prop.distribution.clone(),
+                ordering: vec![],
             }),
         }
     }
@@ -489,6 +519,10 @@ impl Operator for Join {
             // TODO(leiysky): we can enforce redistribution here
             required.distribution = Distribution::Serial;
             return Ok(required);
+        } else if self.null_aware {
+            // Every node must see the whole build side to know whether it is empty or has a NULL key.
+            required.distribution = Distribution::Broadcast;
+            return Ok(required);
         } else if ctx.get_settings().get_prefer_broadcast_join()?
             && !matches!(
                 self.join_type,
@@ -15,6 +15,7 @@
 mod add_row_number;
 mod aggregate;
 mod call;
+mod connect_by;
 mod constant_table_scan;
 mod copy_into_table;
 mod cte_scan;
@@ -25,10 +26,13 @@ mod dummy_table_scan;
 mod eval_scalar;
 mod exchange;
 mod filter;
+mod flush_cache;
+mod gap_fill;
 pub mod insert;
 mod join;
 mod kill;
 mod limit;
+mod limit_by;
 mod materialized_cte;
 mod merge_into;
 mod udf;
@@ -45,6 +49,7 @@ mod revert_table;
 mod runtime_filter_source;
 mod scalar_expr;
 mod scan;
+mod script;
 mod setting;
 pub mod share;
 mod sort;
@@ -55,6 +60,7 @@ mod window;
 pub use add_row_number::AddRowNumber;
 pub use aggregate::*;
 pub use call::CallPlan;
+pub use connect_by::*;
 pub use constant_table_scan::ConstantTableScan;
 pub use copy_into_location::*;
 pub use copy_into_table::*;
@@ -67,11 +73,14 @@ pub use dummy_table_scan::DummyTableScan;
 pub use eval_scalar::*;
 pub use exchange::*;
 pub use filter::*;
+pub use flush_cache::*;
+pub use gap_fill::*;
 pub use insert::Insert;
 pub use insert::InsertInputSource;
 pub use join::*;
 pub use kill::KillPlan;
 pub use limit::*;
+pub use limit_by::*;
 pub use materialized_cte::MaterializedCte;
 pub use merge_into::MatchedEvaluator;
 pub use merge_into::MergeInto;
@@ -88,6 +97,7 @@ pub use runtime_filter_source::RuntimeFilterId;
 pub use runtime_filter_source::RuntimeFilterSource;
 pub use scalar_expr::*;
 pub use scan::*;
+pub use script::ExecuteImmediatePlan;
 pub use setting::*;
 pub use share::*;
 pub use sort::*;
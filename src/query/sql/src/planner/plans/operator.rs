@@ -36,9 +36,12 @@ use crate::optimizer::RequiredProperty;
 use crate::optimizer::StatInfo;
 use crate::plans::materialized_cte::MaterializedCte;
 use crate::plans::runtime_filter_source::RuntimeFilterSource;
+use crate::plans::ConnectBy;
 use crate::plans::ConstantTableScan;
 use crate::plans::CteScan;
 use crate::plans::Exchange;
+use crate::plans::GapFill;
+use crate::plans::LimitBy;
 use crate::plans::ProjectSet;
 use crate::plans::Udf;
 use crate::plans::Window;
@@ -76,6 +79,7 @@ pub enum RelOp {
     Aggregate,
     Sort,
     Limit,
+    LimitBy,
     Exchange,
     UnionAll,
     DummyTableScan,
@@ -86,6 +90,8 @@ pub enum RelOp {
     ConstantTableScan,
     AddRowNumber,
     Udf,
+    GapFill,
+    ConnectBy,
 
     // Pattern
     Pattern,
@@ -102,6 +108,7 @@ pub enum RelOperator {
     Aggregate(Aggregate),
     Sort(Sort),
     Limit(Limit),
+    LimitBy(LimitBy),
     Exchange(Exchange),
     AddRowNumber(AddRowNumber),
     UnionAll(UnionAll),
@@ -112,6 +119,8 @@ pub enum RelOperator {
     MaterializedCte(MaterializedCte),
     ConstantTableScan(ConstantTableScan),
     Udf(Udf),
+    GapFill(GapFill),
+    ConnectBy(ConnectBy),
     Pattern(PatternPlan),
 }
 
@@ -125,6 +134,7 @@ impl Operator for RelOperator {
             RelOperator::Aggregate(rel_op) => rel_op.rel_op(),
             RelOperator::Sort(rel_op) => rel_op.rel_op(),
             RelOperator::Limit(rel_op) => rel_op.rel_op(),
+            RelOperator::LimitBy(rel_op) => rel_op.rel_op(),
             RelOperator::Pattern(rel_op) => rel_op.rel_op(),
             RelOperator::Exchange(rel_op) => rel_op.rel_op(),
             RelOperator::UnionAll(rel_op) => rel_op.rel_op(),
@@ -137,6 +147,8 @@ impl Operator for RelOperator {
             RelOperator::ConstantTableScan(rel_op) => rel_op.rel_op(),
             RelOperator::AddRowNumber(rel_op) => rel_op.rel_op(),
             RelOperator::Udf(rel_op) => rel_op.rel_op(),
+            RelOperator::GapFill(rel_op) => rel_op.rel_op(),
+            RelOperator::ConnectBy(rel_op) => rel_op.rel_op(),
         }
     }
 
@@ -149,6 +161,7 @@ impl Operator for RelOperator {
             RelOperator::Aggregate(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::Sort(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::Limit(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::LimitBy(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::Pattern(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::Exchange(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::UnionAll(rel_op) => rel_op.derive_relational_prop(rel_expr),
@@ -161,6 +174,8 @@ impl Operator for RelOperator {
             RelOperator::ConstantTableScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::AddRowNumber(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::Udf(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::GapFill(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::ConnectBy(rel_op) => rel_op.derive_relational_prop(rel_expr),
         }
     }
 
@@ -173,6 +188,7 @@ impl Operator for RelOperator {
             RelOperator::Aggregate(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::Sort(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::Limit(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::LimitBy(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::Pattern(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::Exchange(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::UnionAll(rel_op) => rel_op.derive_physical_prop(rel_expr),
@@ -185,6 +201,8 @@ impl Operator for RelOperator {
             RelOperator::ConstantTableScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::AddRowNumber(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::Udf(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::GapFill(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::ConnectBy(rel_op) => rel_op.derive_physical_prop(rel_expr),
         }
     }
 
@@ -197,6 +215,7 @@ impl Operator for RelOperator {
             RelOperator::Aggregate(rel_op) => rel_op.derive_cardinality(rel_expr),
             RelOperator::Sort(rel_op) => rel_op.derive_cardinality(rel_expr),
             RelOperator::Limit(rel_op) => rel_op.derive_cardinality(rel_expr),
+            RelOperator::LimitBy(rel_op) => rel_op.derive_cardinality(rel_expr),
             RelOperator::Pattern(rel_op) => rel_op.derive_cardinality(rel_expr),
             RelOperator::Exchange(rel_op) => rel_op.derive_cardinality(rel_expr),
             RelOperator::UnionAll(rel_op) => rel_op.derive_cardinality(rel_expr),
@@ -209,6 +228,8 @@ impl Operator for RelOperator {
             RelOperator::ConstantTableScan(rel_op) => rel_op.derive_cardinality(rel_expr),
             RelOperator::AddRowNumber(rel_op) => rel_op.derive_cardinality(rel_expr),
             RelOperator::Udf(rel_op) => rel_op.derive_cardinality(rel_expr),
+            RelOperator::GapFill(rel_op) => rel_op.derive_cardinality(rel_expr),
+            RelOperator::ConnectBy(rel_op) => rel_op.derive_cardinality(rel_expr),
         }
     }
 
@@ -241,6 +262,9 @@ impl Operator for RelOperator {
             RelOperator::Limit(rel_op) => {
                 rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
             }
+            RelOperator::LimitBy(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
             RelOperator::Pattern(rel_op) => {
                 rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
             }
@@ -277,6 +301,12 @@ impl Operator for RelOperator {
             RelOperator::Udf(rel_op) => {
                 rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
             }
+            RelOperator::GapFill(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
+            RelOperator::ConnectBy(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
         }
     }
 }
@@ -619,3 +649,63 @@ impl TryFrom<RelOperator> for Udf {
         }
     }
 }
+
+impl From<GapFill> for RelOperator {
+    fn from(value: GapFill) -> Self {
+        Self::GapFill(value)
+    }
+}
+
+impl TryFrom<RelOperator> for GapFill {
+    type Error = ErrorCode;
+
+    fn try_from(value: RelOperator) -> std::result::Result<Self, Self::Error> {
+        if let RelOperator::GapFill(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast RelOperator to GapFill",
+            ))
+        }
+    }
+}
+
+impl From<LimitBy> for RelOperator {
+    fn from(value: LimitBy) -> Self {
+        Self::LimitBy(value)
+    }
+}
+
+impl TryFrom<RelOperator> for LimitBy {
+    type Error = ErrorCode;
+
+    fn try_from(value: RelOperator) -> std::result::Result<Self, Self::Error> {
+        if let RelOperator::LimitBy(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast RelOperator to LimitBy",
+            ))
+        }
+    }
+}
+
+impl From<ConnectBy> for RelOperator {
+    fn from(value: ConnectBy) -> Self {
+        Self::ConnectBy(value)
+    }
+}
+
+impl TryFrom<RelOperator> for ConnectBy {
+    type Error = ErrorCode;
+
+    fn try_from(value: RelOperator) -> std::result::Result<Self, Self::Error> {
+        if let RelOperator::ConnectBy(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast RelOperator to ConnectBy",
+            ))
+        }
+    }
+}
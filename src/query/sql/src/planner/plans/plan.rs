@@ -28,7 +28,9 @@ use super::SetSecondaryRolesPlan;
 use crate::optimizer::SExpr;
 use crate::plans::copy_into_location::CopyIntoLocationPlan;
 use crate::plans::AddTableColumnPlan;
+use crate::plans::AddTableConstraintPlan;
 use crate::plans::AlterNetworkPolicyPlan;
+use crate::plans::AlterNotificationPlan;
 use crate::plans::AlterShareTenantsPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AlterTaskPlan;
@@ -37,6 +39,7 @@ use crate::plans::AlterUserPlan;
 use crate::plans::AlterViewPlan;
 use crate::plans::AlterVirtualColumnPlan;
 use crate::plans::AnalyzeTablePlan;
+use crate::plans::CloneTablePlan;
 use crate::plans::CopyIntoTableMode;
 use crate::plans::CopyIntoTablePlan;
 use crate::plans::CreateCatalogPlan;
@@ -46,7 +49,10 @@ use crate::plans::CreateDatamaskPolicyPlan;
 use crate::plans::CreateFileFormatPlan;
 use crate::plans::CreateIndexPlan;
 use crate::plans::CreateNetworkPolicyPlan;
+use crate::plans::CreateNotificationPlan;
 use crate::plans::CreateRolePlan;
+use crate::plans::CreateSecretPlan;
+use crate::plans::CreateSequencePlan;
 use crate::plans::CreateShareEndpointPlan;
 use crate::plans::CreateSharePlan;
 use crate::plans::CreateStagePlan;
@@ -61,6 +67,8 @@ use crate::plans::DeletePlan;
 use crate::plans::DescConnectionPlan;
 use crate::plans::DescDatamaskPolicyPlan;
 use crate::plans::DescNetworkPolicyPlan;
+use crate::plans::DescNotificationPlan;
+use crate::plans::DescSecretPlan;
 use crate::plans::DescSharePlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DescribeTaskPlan;
@@ -71,21 +79,27 @@ use crate::plans::DropDatamaskPolicyPlan;
 use crate::plans::DropFileFormatPlan;
 use crate::plans::DropIndexPlan;
 use crate::plans::DropNetworkPolicyPlan;
+use crate::plans::DropNotificationPlan;
 use crate::plans::DropRolePlan;
+use crate::plans::DropSecretPlan;
+use crate::plans::DropSequencePlan;
 use crate::plans::DropShareEndpointPlan;
 use crate::plans::DropSharePlan;
 use crate::plans::DropStagePlan;
 use crate::plans::DropStreamPlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
+use crate::plans::DropTableConstraintPlan;
 use crate::plans::DropTablePlan;
 use crate::plans::DropTaskPlan;
 use crate::plans::DropUDFPlan;
 use crate::plans::DropUserPlan;
 use crate::plans::DropViewPlan;
 use crate::plans::DropVirtualColumnPlan;
+use crate::plans::ExecuteImmediatePlan;
 use crate::plans::ExecuteTaskPlan;
 use crate::plans::ExistsTablePlan;
+use crate::plans::FlushCachePlan;
 use crate::plans::GrantPrivilegePlan;
 use crate::plans::GrantRolePlan;
 use crate::plans::GrantShareObjectPlan;
@@ -95,6 +109,7 @@ use crate::plans::MergeInto;
 use crate::plans::ModifyTableColumnPlan;
 use crate::plans::OptimizeTablePlan;
 use crate::plans::PresignPlan;
+use crate::plans::PromoteReplicaPlan;
 use crate::plans::ReclusterTablePlan;
 use crate::plans::RefreshIndexPlan;
 use crate::plans::RefreshVirtualColumnPlan;
@@ -120,6 +135,8 @@ use crate::plans::ShowGrantsPlan;
 use crate::plans::ShowNetworkPoliciesPlan;
 use crate::plans::ShowObjectGrantPrivilegesPlan;
 use crate::plans::ShowRolesPlan;
+use crate::plans::ShowSecretsPlan;
+use crate::plans::ShowSequencesPlan;
 use crate::plans::ShowShareEndpointPlan;
 use crate::plans::ShowSharesPlan;
 use crate::plans::ShowTasksPlan;
@@ -160,6 +177,9 @@ pub enum Plan {
     ExplainAnalyze {
         plan: Box<Plan>,
     },
+    DescribeQuery {
+        plan: Box<Plan>,
+    },
 
     CopyIntoTable(Box<CopyIntoTablePlan>),
     CopyIntoLocation(CopyIntoLocationPlan),
@@ -184,6 +204,7 @@ pub enum Plan {
     ShowCreateTable(Box<ShowCreateTablePlan>),
     DescribeTable(Box<DescribeTablePlan>),
     CreateTable(Box<CreateTablePlan>),
+    CloneTable(Box<CloneTablePlan>),
     DropTable(Box<DropTablePlan>),
     UndropTable(Box<UndropTablePlan>),
     RenameTable(Box<RenameTablePlan>),
@@ -191,6 +212,8 @@ pub enum Plan {
     AddTableColumn(Box<AddTableColumnPlan>),
     DropTableColumn(Box<DropTableColumnPlan>),
     ModifyTableColumn(Box<ModifyTableColumnPlan>),
+    AddTableConstraint(Box<AddTableConstraintPlan>),
+    DropTableConstraint(Box<DropTableConstraintPlan>),
     AlterTableClusterKey(Box<AlterTableClusterKeyPlan>),
     DropTableClusterKey(Box<DropTableClusterKeyPlan>),
     ReclusterTable(Box<ReclusterTablePlan>),
@@ -202,6 +225,7 @@ pub enum Plan {
     AnalyzeTable(Box<AnalyzeTablePlan>),
     ExistsTable(Box<ExistsTablePlan>),
     SetOptions(Box<SetOptionsPlan>),
+    PromoteReplica(Box<PromoteReplicaPlan>),
 
     // Insert
     Insert(Box<Insert>),
@@ -267,6 +291,17 @@ pub enum Plan {
     DropConnection(Box<DropConnectionPlan>),
     ShowConnections(Box<ShowConnectionsPlan>),
 
+    // Sequence
+    CreateSequence(Box<CreateSequencePlan>),
+    DropSequence(Box<DropSequencePlan>),
+    ShowSequences(Box<ShowSequencesPlan>),
+
+    // Secret
+    CreateSecret(Box<CreateSecretPlan>),
+    DropSecret(Box<DropSecretPlan>),
+    DescSecret(Box<DescSecretPlan>),
+    ShowSecrets(Box<ShowSecretsPlan>),
+
     // Presign
     Presign(Box<PresignPlan>),
 
@@ -275,6 +310,12 @@ pub enum Plan {
     UnSetVariable(Box<UnSettingPlan>),
     Kill(Box<KillPlan>),
 
+    // System
+    FlushCache(Box<FlushCachePlan>),
+
+    // Script
+    ExecuteImmediate(Box<ExecuteImmediatePlan>),
+
     // Share
     CreateShareEndpoint(Box<CreateShareEndpointPlan>),
     ShowShareEndpoint(Box<ShowShareEndpointPlan>),
@@ -308,6 +349,12 @@ pub enum Plan {
     DescribeTask(Box<DescribeTaskPlan>),
     ShowTasks(Box<ShowTasksPlan>),
     ExecuteTask(Box<ExecuteTaskPlan>),
+
+    // Notification
+    CreateNotification(Box<CreateNotificationPlan>),
+    AlterNotification(Box<AlterNotificationPlan>),
+    DropNotification(Box<DropNotificationPlan>),
+    DescNotification(Box<DescNotificationPlan>),
 }
 
 #[derive(Clone, Debug)]
@@ -382,8 +429,14 @@ impl Plan {
             | Plan::ExplainAnalyze { .. } => {
                 DataSchemaRefExt::create(vec![DataField::new("explain", DataType::String)])
             }
+            Plan::DescribeQuery { .. } => DataSchemaRefExt::create(vec![
+                DataField::new("Field", DataType::String),
+                DataField::new("Type", DataType::String),
+                DataField::new("Null", DataType::String),
+            ]),
             Plan::ShowCreateCatalog(plan) => plan.schema(),
             Plan::ShowCreateDatabase(plan) => plan.schema(),
+            Plan::DropDatabase(plan) => plan.schema(),
             Plan::ShowCreateTable(plan) => plan.schema(),
             Plan::DescribeTable(plan) => plan.schema(),
             Plan::VacuumTable(plan) => plan.schema(),
@@ -415,9 +468,14 @@ impl Plan {
             Plan::DescribeTask(plan) => plan.schema(),
             Plan::ShowTasks(plan) => plan.schema(),
             Plan::ExecuteTask(plan) => plan.schema(),
+            Plan::ExecuteImmediate(plan) => plan.schema(),
+            Plan::DescNotification(plan) => plan.schema(),
 
             Plan::DescConnection(plan) => plan.schema(),
             Plan::ShowConnections(plan) => plan.schema(),
+            Plan::ShowSequences(plan) => plan.schema(),
+            Plan::DescSecret(plan) => plan.schema(),
+            Plan::ShowSecrets(plan) => plan.schema(),
 
             other => {
                 debug_assert!(!other.has_result_set());
@@ -434,6 +492,7 @@ impl Plan {
                 | Plan::ExplainAst { .. }
                 | Plan::ExplainSyntax { .. }
                 | Plan::ExplainAnalyze { .. }
+                | Plan::DescribeQuery { .. }
                 | Plan::ShowCreateDatabase(_)
                 | Plan::ShowCreateTable(_)
                 | Plan::ShowCreateCatalog(_)
@@ -455,8 +514,13 @@ impl Plan {
                 | Plan::CopyIntoTable(_)
                 | Plan::ShowTasks(_)
                 | Plan::DescribeTask(_)
+                | Plan::DescNotification(_)
                 | Plan::DescConnection(_)
                 | Plan::ShowConnections(_)
-        )
+                | Plan::ShowSequences(_)
+                | Plan::DescSecret(_)
+                | Plan::ShowSecrets(_)
+                | Plan::ExecuteImmediate(_)
+        ) || matches!(self, Plan::DropDatabase(plan) if plan.cascade)
     }
 }
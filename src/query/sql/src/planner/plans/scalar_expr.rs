@@ -465,6 +465,8 @@ pub struct LagLeadFunction {
     pub is_lag: bool,
     pub arg: Box<ScalarExpr>,
     pub offset: u64,
+    /// Skip NULL values when counting `offset` rows, set by `IGNORE NULLS`.
+    pub ignore_null: bool,
     pub default: Option<Box<ScalarExpr>>,
     pub return_type: Box<DataType>,
 }
@@ -477,6 +479,8 @@ pub struct NthValueFunction {
     /// - Some(n): `nth_value`
     /// - None: `last_value`
     pub n: Option<u64>,
+    /// Skip NULL values when looking for the nth row, set by `IGNORE NULLS`.
+    pub ignore_null: bool,
     pub arg: Box<ScalarExpr>,
     pub return_type: Box<DataType>,
 }
@@ -24,6 +24,7 @@ use common_expression::TableSchemaRef;
 use itertools::Itertools;
 
 use super::ScalarItem;
+use crate::optimizer::histogram_from_column_histogram;
 use crate::optimizer::histogram_from_ndv;
 use crate::optimizer::ColumnSet;
 use crate::optimizer::ColumnStat;
@@ -77,6 +78,14 @@ impl AggIndexInfo {
     }
 }
 
+/// The table is bucketed by the hash of the keys, the rows of the same bucket are read
+/// by the same executor.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BucketInfo {
+    pub keys: Vec<ScalarExpr>,
+    pub num_buckets: u32,
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct Statistics {
     // statistics will be ignored in comparison and hashing
@@ -94,6 +103,7 @@ pub struct Scan {
     pub order_by: Option<Vec<SortItem>>,
     pub prewhere: Option<Prewhere>,
     pub agg_index: Option<AggIndexInfo>,
+    pub bucket_info: Option<BucketInfo>,
 
     pub statistics: Statistics,
 }
@@ -120,6 +130,7 @@ impl Scan {
             },
             prewhere,
             agg_index: self.agg_index.clone(),
+            bucket_info: self.bucket_info.clone(),
         }
     }
 
@@ -173,8 +184,16 @@ impl Operator for Scan {
     }
 
     fn derive_physical_prop(&self, _rel_expr: &RelExpr) -> Result<PhysicalProperty> {
+        // The aggregating index isn't bucketed.
+        let distribution = match &self.bucket_info {
+            Some(bucket_info) if self.agg_index.is_none() => {
+                Distribution::Bucket(bucket_info.clone())
+            }
+            _ => Distribution::Random,
+        };
         Ok(PhysicalProperty {
-            distribution: Distribution::Random,
+            distribution,
+            ordering: vec![],
         })
     }
 
@@ -199,13 +218,16 @@ impl Operator for Scan {
                 let min = col_stat.min.unwrap();
                 let max = col_stat.max.unwrap();
                 let ndv = col_stat.ndv.unwrap();
-                let histogram = histogram_from_ndv(
-                    ndv,
-                    num_rows,
-                    Some((min.clone(), max.clone())),
-                    DEFAULT_HISTOGRAM_BUCKETS,
-                )
-                .ok();
+                let histogram = match &col_stat.histogram {
+                    Some(histogram) => Some(histogram_from_column_histogram(histogram)),
+                    None => histogram_from_ndv(
+                        ndv,
+                        num_rows,
+                        Some((min.clone(), max.clone())),
+                        DEFAULT_HISTOGRAM_BUCKETS,
+                    )
+                    .ok(),
+                };
                 let column_stat = ColumnStat {
                     min,
                     max,
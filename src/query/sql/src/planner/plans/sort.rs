@@ -53,7 +53,9 @@ impl Operator for Sort {
     }
 
     fn derive_physical_prop(&self, rel_expr: &RelExpr) -> Result<PhysicalProperty> {
-        rel_expr.derive_physical_prop_child(0)
+        let mut prop = rel_expr.derive_physical_prop_child(0)?;
+        prop.ordering = self.items.clone();
+        Ok(prop)
     }
 
     fn compute_required_prop_child(
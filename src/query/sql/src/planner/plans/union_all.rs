@@ -83,8 +83,14 @@ impl Operator for UnionAll {
 
     fn derive_physical_prop(&self, rel_expr: &RelExpr) -> Result<PhysicalProperty> {
         let left_child = rel_expr.derive_physical_prop_child(0)?;
+        // The rows of the right child aren't in the buckets of the left child.
+        let distribution = match left_child.distribution {
+            Distribution::Bucket(_) => Distribution::Random,
+            distribution => distribution,
+        };
         Ok(PhysicalProperty {
-            distribution: left_child.distribution,
+            distribution,
+            ordering: vec![],
         })
     }
 
@@ -98,7 +98,11 @@ impl Operator for Window {
     }
 
     fn derive_physical_prop(&self, rel_expr: &RelExpr) -> Result<PhysicalProperty> {
-        rel_expr.derive_physical_prop_child(0)
+        let mut prop = rel_expr.derive_physical_prop_child(0)?;
+        // The window is computed by a single thread, whose output is spread to the threads
+        // of the input again, so the rows are not ordered.
+        prop.ordering.clear();
+        Ok(prop)
     }
 
     fn compute_required_prop_child(
@@ -185,6 +189,7 @@ pub struct WindowFuncFrame {
     pub units: WindowFuncFrameUnits,
     pub start_bound: WindowFuncFrameBound,
     pub end_bound: WindowFuncFrameBound,
+    pub exclusion: WindowFuncFrameExclusion,
 }
 
 impl Display for WindowFuncFrame {
@@ -193,7 +198,11 @@ impl Display for WindowFuncFrame {
             f,
             "{:?}: {:?} ~ {:?}",
             self.units, self.start_bound, self.end_bound
-        )
+        )?;
+        if self.exclusion != WindowFuncFrameExclusion::NoOthers {
+            write!(f, " exclude {:?}", self.exclusion)?;
+        }
+        Ok(())
     }
 }
 
@@ -215,6 +224,18 @@ pub enum WindowFuncFrameBound {
     Following(Option<Scalar>),
 }
 
+/// Rows removed from the frame by the `EXCLUDE` clause.
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
+pub enum WindowFuncFrameExclusion {
+    #[default]
+    NoOthers,
+    CurrentRow,
+    /// The current row and its peers.
+    Group,
+    /// The peers of the current row, but not the row itself.
+    Ties,
+}
+
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub enum WindowFuncType {
     Aggregate(AggregateFunction),
@@ -23,7 +23,7 @@ use common_ast::ast::SelectStmt;
 use common_ast::ast::SelectTarget;
 use common_ast::ast::SetExpr;
 use common_ast::ast::TableReference;
-use common_ast::ast::Window;
+use common_ast::ast::WindowDesc;
 use common_ast::walk_expr;
 use common_ast::walk_select_target;
 use common_ast::walk_select_target_mut;
@@ -168,7 +168,7 @@ impl<'ast> Visitor<'ast> for AggregatingIndexChecker {
         name: &'ast Identifier,
         args: &'ast [Expr],
         _params: &'ast [Literal],
-        _over: &'ast Option<Window>,
+        _over: &'ast Option<WindowDesc>,
         _lambda: &'ast Option<Lambda>,
     ) {
         if self.not_support {
@@ -231,7 +231,11 @@ impl<'ast> Visitor<'ast> for AggregatingIndexChecker {
         if self.not_support {
             return;
         }
-        if query.with.is_some() || !query.order_by.is_empty() || !query.limit.is_empty() {
+        if query.with.is_some()
+            || !query.order_by.is_empty()
+            || query.limit_by.is_some()
+            || !query.limit.is_empty()
+        {
             self.not_support = true;
             return;
         }
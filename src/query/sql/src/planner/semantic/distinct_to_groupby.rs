@@ -37,6 +37,7 @@ impl VisitorMut for DistinctToGroupBy {
             having,
             window_list,
             qualify,
+            gap_fill,
             ..
         } = stmt;
 
@@ -78,8 +79,10 @@ impl VisitorMut for DistinctToGroupBy {
                             having: None,
                             window_list: None,
                             qualify: None,
+                            gap_fill: None,
                         })),
                         order_by: vec![],
+                        limit_by: None,
                         limit: vec![],
                         offset: None,
                         ignore_result: false,
@@ -124,6 +127,7 @@ impl VisitorMut for DistinctToGroupBy {
                         having: having.clone(),
                         window_list: window_list.clone(),
                         qualify: qualify.clone(),
+                        gap_fill: gap_fill.clone(),
                     };
 
                     *stmt = new_stmt;
@@ -32,6 +32,7 @@ use common_ast::ast::UnaryOperator;
 use common_ast::ast::Window;
 use common_ast::ast::WindowFrame;
 use common_ast::ast::WindowFrameBound;
+use common_ast::ast::WindowFrameExclusion;
 use common_ast::ast::WindowFrameUnits;
 use common_ast::parser::parse_expr;
 use common_ast::parser::tokenize_sql;
@@ -49,6 +50,9 @@ use common_expression::type_check::check_number;
 use common_expression::types::decimal::DecimalDataType;
 use common_expression::types::decimal::DecimalScalar;
 use common_expression::types::decimal::DecimalSize;
+use common_expression::types::timestamp::check_timestamp_precision;
+use common_expression::types::timestamp::MICROS_IN_A_DAY;
+use common_expression::types::timestamp::MICROS_IN_A_SEC;
 use common_expression::types::DataType;
 use common_expression::types::NumberDataType;
 use common_expression::types::NumberScalar;
@@ -64,6 +68,7 @@ use common_expression::Scalar;
 use common_expression::TableDataType;
 use common_functions::aggregates::AggregateFunctionFactory;
 use common_functions::is_builtin_function;
+use common_functions::is_lossless_cast;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_functions::GENERAL_LAMBDA_FUNCTIONS;
 use common_functions::GENERAL_WINDOW_FUNCTIONS;
@@ -112,6 +117,7 @@ use crate::plans::UDFServerCall;
 use crate::plans::WindowFunc;
 use crate::plans::WindowFuncFrame;
 use crate::plans::WindowFuncFrameBound;
+use crate::plans::WindowFuncFrameExclusion;
 use crate::plans::WindowFuncFrameUnits;
 use crate::plans::WindowFuncType;
 use crate::plans::WindowOrderBy;
@@ -764,6 +770,26 @@ impl<'a> TypeChecker<'a> {
                     )
                     .set_span(*span));
                 }
+                // check null treatment legal
+                if window
+                    .as_ref()
+                    .is_some_and(|window| window.ignore_nulls.is_some())
+                    && !matches!(
+                        func_name,
+                        "lag"
+                            | "lead"
+                            | "first_value"
+                            | "first"
+                            | "last_value"
+                            | "last"
+                            | "nth_value"
+                    )
+                {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "IGNORE NULLS or RESPECT NULLS is not allowed for window function {func_name}"
+                    ))
+                    .set_span(*span));
+                }
                 // check lambda function legal
                 if lambda.is_some() && !GENERAL_LAMBDA_FUNCTIONS.contains(&func_name) {
                     return Err(ErrorCode::SemanticError(
@@ -824,12 +850,13 @@ impl<'a> TypeChecker<'a> {
                             "window function {name} can only be used in window clause"
                         )));
                     }
+                    let window = window.as_ref().unwrap();
+                    let ignore_null = window.ignore_nulls.unwrap_or(false);
                     let func = self
-                        .resolve_general_window_function(*span, func_name, &args)
+                        .resolve_general_window_function(*span, func_name, &args, ignore_null)
                         .await?;
-                    let window = window.as_ref().unwrap();
                     let display_name = format!("{:#}", expr);
-                    self.resolve_window(*span, display_name, window, func)
+                    self.resolve_window(*span, display_name, &window.window, func)
                         .await?
                 } else if AggregateFunctionFactory::instance().contains(func_name) {
                     if matches!(
@@ -856,7 +883,7 @@ impl<'a> TypeChecker<'a> {
                         // aggregate window function
                         let display_name = format!("{:#}", expr);
                         let func = WindowFuncType::Aggregate(new_agg_func);
-                        self.resolve_window(*span, display_name, window, func)
+                        self.resolve_window(*span, display_name, &window.window, func)
                             .await?
                     } else {
                         // aggregate function
@@ -1216,6 +1243,17 @@ impl<'a> TypeChecker<'a> {
                 .clone(),
         };
 
+        if let Some(frame) = &spec.window_frame {
+            if frame.exclusion != WindowFrameExclusion::NoOthers
+                && !matches!(func, WindowFuncType::Aggregate(_))
+            {
+                return Err(ErrorCode::SemanticError(
+                    "frame exclusion is only supported for aggregate window functions".to_string(),
+                )
+                .set_span(span));
+            }
+        }
+
         self.in_window_function = true;
         let mut partitions = Vec::with_capacity(spec.partition_by.len());
         for p in spec.partition_by.iter() {
@@ -1337,9 +1375,21 @@ impl<'a> TypeChecker<'a> {
             units,
             start_bound: start,
             end_bound: end,
+            exclusion: Self::resolve_window_frame_exclusion(&frame.exclusion),
         })
     }
 
+    fn resolve_window_frame_exclusion(
+        exclusion: &WindowFrameExclusion,
+    ) -> WindowFuncFrameExclusion {
+        match exclusion {
+            WindowFrameExclusion::NoOthers => WindowFuncFrameExclusion::NoOthers,
+            WindowFrameExclusion::CurrentRow => WindowFuncFrameExclusion::CurrentRow,
+            WindowFrameExclusion::Group => WindowFuncFrameExclusion::Group,
+            WindowFrameExclusion::Ties => WindowFuncFrameExclusion::Ties,
+        }
+    }
+
     #[async_backtrace::framed]
     async fn resolve_range_offset(&mut self, bound: &WindowFrameBound) -> Result<Option<Scalar>> {
         match bound {
@@ -1385,6 +1435,7 @@ impl<'a> TypeChecker<'a> {
             units,
             start_bound: start,
             end_bound: end,
+            exclusion: Self::resolve_window_frame_exclusion(&frame.exclusion),
         })
     }
 
@@ -1403,6 +1454,32 @@ impl<'a> TypeChecker<'a> {
                     units: WindowFuncFrameUnits::Rows,
                     start_bound: WindowFuncFrameBound::Preceding(None),
                     end_bound: WindowFuncFrameBound::Following(None),
+                    exclusion: WindowFuncFrameExclusion::NoOthers,
+                });
+            }
+            WindowFuncType::LagLead(lag_lead) if lag_lead.ignore_null && lag_lead.offset > 0 => {
+                // The `offset`-th non-NULL value can be any row before (lag) or after (lead)
+                // the current row, so the frame covers the rest of the partition.
+                let (start_bound, end_bound) = if lag_lead.is_lag {
+                    (
+                        WindowFuncFrameBound::Preceding(None),
+                        WindowFuncFrameBound::Preceding(Some(Scalar::Number(
+                            NumberScalar::UInt64(1),
+                        ))),
+                    )
+                } else {
+                    (
+                        WindowFuncFrameBound::Following(Some(Scalar::Number(
+                            NumberScalar::UInt64(1),
+                        ))),
+                        WindowFuncFrameBound::Following(None),
+                    )
+                };
+                return Ok(WindowFuncFrame {
+                    units: WindowFuncFrameUnits::Rows,
+                    start_bound,
+                    end_bound,
+                    exclusion: WindowFuncFrameExclusion::NoOthers,
                 });
             }
             WindowFuncType::LagLead(lag_lead) if lag_lead.is_lag => {
@@ -1414,6 +1491,7 @@ impl<'a> TypeChecker<'a> {
                     end_bound: WindowFuncFrameBound::Preceding(Some(Scalar::Number(
                         NumberScalar::UInt64(lag_lead.offset),
                     ))),
+                    exclusion: WindowFuncFrameExclusion::NoOthers,
                 });
             }
             WindowFuncType::LagLead(lag_lead) => {
@@ -1425,6 +1503,7 @@ impl<'a> TypeChecker<'a> {
                     end_bound: WindowFuncFrameBound::Following(Some(Scalar::Number(
                         NumberScalar::UInt64(lag_lead.offset),
                     ))),
+                    exclusion: WindowFuncFrameExclusion::NoOthers,
                 });
             }
             WindowFuncType::Ntile(_) => {
@@ -1433,12 +1512,14 @@ impl<'a> TypeChecker<'a> {
                         units: WindowFuncFrameUnits::Rows,
                         start_bound: WindowFuncFrameBound::Preceding(None),
                         end_bound: WindowFuncFrameBound::Following(None),
+                        exclusion: WindowFuncFrameExclusion::NoOthers,
                     }
                 } else {
                     WindowFuncFrame {
                         units: WindowFuncFrameUnits::Rows,
                         start_bound: WindowFuncFrameBound::CurrentRow,
                         end_bound: WindowFuncFrameBound::CurrentRow,
+                        exclusion: WindowFuncFrameExclusion::NoOthers,
                     }
                 });
             }
@@ -1461,12 +1542,14 @@ impl<'a> TypeChecker<'a> {
                 units: WindowFuncFrameUnits::Range,
                 start_bound: WindowFuncFrameBound::Preceding(None),
                 end_bound: WindowFuncFrameBound::Following(None),
+                exclusion: WindowFuncFrameExclusion::NoOthers,
             })
         } else {
             Ok(WindowFuncFrame {
                 units: WindowFuncFrameUnits::Range,
                 start_bound: WindowFuncFrameBound::Preceding(None),
                 end_bound: WindowFuncFrameBound::CurrentRow,
+                exclusion: WindowFuncFrameExclusion::NoOthers,
             })
         }
     }
@@ -1478,6 +1561,7 @@ impl<'a> TypeChecker<'a> {
         span: Span,
         func_name: &str,
         args: &[&Expr],
+        ignore_null: bool,
     ) -> Result<WindowFuncType> {
         // try to resolve window function without arguments first
         if let Ok(window_func) = WindowFuncType::from_name(func_name) {
@@ -1504,12 +1588,22 @@ impl<'a> TypeChecker<'a> {
 
         match func_name {
             "lag" | "lead" => {
-                self.resolve_lag_lead_window_function(func_name, &arguments, &arg_types)
-                    .await
+                self.resolve_lag_lead_window_function(
+                    func_name,
+                    &arguments,
+                    &arg_types,
+                    ignore_null,
+                )
+                .await
             }
             "first_value" | "first" | "last_value" | "last" | "nth_value" => {
-                self.resolve_nth_value_window_function(func_name, &arguments, &arg_types)
-                    .await
+                self.resolve_nth_value_window_function(
+                    func_name,
+                    &arguments,
+                    &arg_types,
+                    ignore_null,
+                )
+                .await
             }
             "ntile" => self.resolve_ntile_window_function(&arguments).await,
             _ => Err(ErrorCode::UnknownFunction(format!(
@@ -1524,6 +1618,7 @@ impl<'a> TypeChecker<'a> {
         func_name: &str,
         args: &[ScalarExpr],
         arg_types: &[DataType],
+        ignore_null: bool,
     ) -> Result<WindowFuncType> {
         if args.is_empty() || args.len() > 3 {
             return Err(ErrorCode::InvalidArgument(format!(
@@ -1583,6 +1678,7 @@ impl<'a> TypeChecker<'a> {
             is_lag,
             arg: Box::new(args[0].clone()),
             offset: offset.unsigned_abs(),
+            ignore_null,
             default: cast_default,
             return_type: Box::new(return_type),
         }))
@@ -1594,6 +1690,7 @@ impl<'a> TypeChecker<'a> {
         func_name: &str,
         args: &[ScalarExpr],
         arg_types: &[DataType],
+        ignore_null: bool,
     ) -> Result<WindowFuncType> {
         Ok(match func_name {
             "first_value" | "first" => {
@@ -1606,6 +1703,7 @@ impl<'a> TypeChecker<'a> {
                 let return_type = arg_types[0].wrap_nullable();
                 WindowFuncType::NthValue(NthValueFunction {
                     n: Some(1),
+                    ignore_null,
                     arg: Box::new(args[0].clone()),
                     return_type: Box::new(return_type),
                 })
@@ -1620,6 +1718,7 @@ impl<'a> TypeChecker<'a> {
                 let return_type = arg_types[0].wrap_nullable();
                 WindowFuncType::NthValue(NthValueFunction {
                     n: None,
+                    ignore_null,
                     arg: Box::new(args[0].clone()),
                     return_type: Box::new(return_type),
                 })
@@ -1654,6 +1753,7 @@ impl<'a> TypeChecker<'a> {
 
                 WindowFuncType::NthValue(NthValueFunction {
                     n: Some(n),
+                    ignore_null,
                     arg: Box::new(args[0].clone()),
                     return_type: Box::new(return_type),
                 })
@@ -1881,6 +1981,7 @@ impl<'a> TypeChecker<'a> {
             args: arguments,
         };
         let expr = type_check::check(&raw_expr, &BUILTIN_FUNCTIONS)?;
+        self.check_strict_coercion(func_name, &args, &expr)?;
 
         // Run constant folding for arguments of the scalar function.
         // This will be helpful to simplify some constant expressions, especially
@@ -1926,6 +2027,38 @@ impl<'a> TypeChecker<'a> {
         )))
     }
 
+    /// In the strict type coercion mode, the arguments of a function can only be
+    /// implicitly cast to the signature without losing information.
+    fn check_strict_coercion(
+        &self,
+        func_name: &str,
+        args: &[ScalarExpr],
+        expr: &common_expression::Expr,
+    ) -> Result<()> {
+        if type_check::is_simple_cast_function(func_name)
+            || !self.ctx.get_settings().get_strict_type_coercion()?
+        {
+            return Ok(());
+        }
+        let common_expression::Expr::FunctionCall {
+            args: checked_args, ..
+        } = expr
+        else {
+            return Ok(());
+        };
+        for (checked_arg, arg) in checked_args.iter().zip(args.iter()) {
+            let src_ty = arg.data_type()?;
+            let dest_ty = checked_arg.data_type();
+            if !is_lossless_cast(&src_ty, dest_ty) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "implicit cast from {src_ty} to {dest_ty} in function {func_name} is not allowed when type_coercion_mode is strict, use an explicit CAST instead"
+                ))
+                .set_span(arg.span()));
+            }
+        }
+        Ok(())
+    }
+
     /// Resolve binary expressions. Most of the binary expressions
     /// would be transformed into `FunctionCall`, except comparison
     /// expressions, conjunction(`AND`) and disjunction(`OR`).
@@ -2057,6 +2190,45 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
+    /// Resolve a fixed width interval like `INTERVAL 1 HOUR` into microseconds.
+    #[async_recursion::async_recursion]
+    #[async_backtrace::framed]
+    pub async fn resolve_interval_micros(&mut self, interval: &Expr) -> Result<i64> {
+        let Expr::Interval { span, expr, unit } = interval else {
+            return Err(ErrorCode::SemanticError(
+                "expect a fixed width interval like `INTERVAL 1 HOUR`".to_string(),
+            )
+            .set_span(interval.span()));
+        };
+        let unit_micros = match unit {
+            ASTIntervalKind::Second => MICROS_IN_A_SEC,
+            ASTIntervalKind::Minute => 60 * MICROS_IN_A_SEC,
+            ASTIntervalKind::Hour => 3600 * MICROS_IN_A_SEC,
+            ASTIntervalKind::Day => MICROS_IN_A_DAY,
+            ASTIntervalKind::Week => 7 * MICROS_IN_A_DAY,
+            _ => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "interval unit {unit} has no fixed width, expect SECOND, MINUTE, HOUR, DAY or WEEK"
+                ))
+                .set_span(*span));
+            }
+        };
+        let box (scalar, _) = self.resolve(expr).await?;
+        let n = check_number::<_, i64>(
+            *span,
+            &self.func_ctx,
+            &scalar.as_expr()?,
+            &BUILTIN_FUNCTIONS,
+        )?;
+        match n.checked_mul(unit_micros) {
+            Some(micros) if micros > 0 => Ok(micros),
+            _ => Err(ErrorCode::SemanticError(format!(
+                "interval must be positive and fit in 64 bits microseconds, but got {n} {unit}"
+            ))
+            .set_span(*span)),
+        }
+    }
+
     #[async_recursion::async_recursion]
     #[async_backtrace::framed]
     pub async fn resolve_date_add(
@@ -2288,13 +2460,16 @@ impl<'a> TypeChecker<'a> {
                 })
                 .await,
             ),
-            ("connection_id", &[]) => Some(
-                self.resolve(&Expr::Literal {
-                    span,
-                    lit: Literal::String(self.ctx.get_connection_id()),
-                })
-                .await,
-            ),
+            ("connection_id", &[]) => {
+                self.metadata.write().set_session_dependent();
+                Some(
+                    self.resolve(&Expr::Literal {
+                        span,
+                        lit: Literal::String(self.ctx.get_connection_id()),
+                    })
+                    .await,
+                )
+            }
             ("timezone", &[]) => {
                 let tz = self.ctx.get_settings().get_timezone().unwrap();
                 Some(
@@ -2437,6 +2612,7 @@ impl<'a> TypeChecker<'a> {
 
                 Some(match res {
                     Ok(index) => {
+                        self.metadata.write().set_session_dependent();
                         let query_id = self.ctx.get_last_query_id(index as i32);
                         self.resolve(&Expr::Literal {
                             span,
@@ -2605,6 +2781,23 @@ impl<'a> TypeChecker<'a> {
                 self.resolve_cast_to_variant(span, &data_type, &scalar, true)
                     .await
             }
+            ("time_bucket", &[interval @ Expr::Interval { .. }, arg]) => {
+                // Rewrite time_bucket(INTERVAL n unit, x) to time_bucket(<micros of the interval>, x)
+                let micros = match self.resolve_interval_micros(interval).await {
+                    Ok(micros) => micros,
+                    Err(e) => return Some(Err(e)),
+                };
+                Some(
+                    self.resolve_function(span, "time_bucket", vec![], &[
+                        &Expr::Literal {
+                            span: interval.span(),
+                            lit: Literal::UInt64(micros as u64),
+                        },
+                        arg,
+                    ])
+                    .await,
+                )
+            }
             ("greatest", args) => {
                 let (array, _) = *self
                     .resolve_function(span, "array", vec![], args)
@@ -3757,6 +3950,8 @@ pub fn resolve_type_name_inner(type_name: &TypeName) -> Result<TableDataType> {
         TypeName::Int64 => TableDataType::Number(NumberDataType::Int64),
         TypeName::Float32 => TableDataType::Number(NumberDataType::Float32),
         TypeName::Float64 => TableDataType::Number(NumberDataType::Float64),
+        TypeName::UInt128 => TableDataType::Number(NumberDataType::UInt128),
+        TypeName::Int128 => TableDataType::Number(NumberDataType::Int128),
         TypeName::Decimal { precision, scale } => {
             TableDataType::Decimal(DecimalDataType::from_size(DecimalSize {
                 precision: *precision,
@@ -3764,7 +3959,12 @@ pub fn resolve_type_name_inner(type_name: &TypeName) -> Result<TableDataType> {
             })?)
         }
         TypeName::String => TableDataType::String,
-        TypeName::Timestamp => TableDataType::Timestamp,
+        TypeName::Timestamp { precision } => match precision {
+            Some(precision) => TableDataType::timestamp_with_precision(
+                check_timestamp_precision(*precision).map_err(ErrorCode::BadArguments)?,
+            ),
+            None => TableDataType::Timestamp,
+        },
         TypeName::Date => TableDataType::Date,
         TypeName::Array(item_type) => {
             TableDataType::Array(Box::new(resolve_type_name_inner(item_type)?))
@@ -3777,6 +3977,7 @@ pub fn resolve_type_name_inner(type_name: &TypeName) -> Result<TableDataType> {
                 | TableDataType::Number(_)
                 | TableDataType::Decimal(_)
                 | TableDataType::Timestamp
+                | TableDataType::TimestampWithPrecision(_)
                 | TableDataType::Date => {
                     let val_type = resolve_type_name_inner(val_type)?;
                     let inner_type = TableDataType::Tuple {
@@ -3816,6 +4017,7 @@ pub fn resolve_type_name_inner(type_name: &TypeName) -> Result<TableDataType> {
             TableDataType::Nullable(Box::new(resolve_type_name_inner(inner_type)?))
         }
         TypeName::Variant => TableDataType::Variant,
+        TypeName::Geometry => TableDataType::Geometry,
     };
 
     Ok(data_type)
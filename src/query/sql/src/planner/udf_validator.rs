@@ -20,6 +20,7 @@ use common_ast::ast::Identifier;
 use common_ast::ast::Lambda;
 use common_ast::ast::Literal;
 use common_ast::ast::Window;
+use common_ast::ast::WindowDesc;
 use common_ast::walk_expr;
 use common_ast::Visitor;
 use common_exception::ErrorCode;
@@ -89,7 +90,7 @@ impl<'ast> Visitor<'ast> for UDFValidator {
         name: &'ast Identifier,
         args: &'ast [Expr],
         _params: &'ast [Literal],
-        over: &'ast Option<Window>,
+        over: &'ast Option<WindowDesc>,
         lambda: &'ast Option<Lambda>,
     ) {
         let name = name.to_string();
@@ -103,7 +104,7 @@ impl<'ast> Visitor<'ast> for UDFValidator {
         }
 
         if let Some(over) = over {
-            match over {
+            match &over.window {
                 Window::WindowSpec(spec) => {
                     spec.partition_by
                         .iter()
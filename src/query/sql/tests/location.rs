@@ -272,10 +272,14 @@ async fn test_parse_uri_location() -> Result<()> {
                 "test".to_string(),
                 "/tmp/".to_string(),
                 "".to_string(),
-                [("role_arn", "aws::iam::xxxx"), ("region", "us-east-2")]
-                    .iter()
-                    .map(|(k, v)| (k.to_string(), v.to_string()))
-                    .collect::<BTreeMap<String, String>>(),
+                [
+                    ("role_arn", "aws::iam::xxxx"),
+                    ("external_id", "my-external-id"),
+                    ("region", "us-east-2"),
+                ]
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect::<BTreeMap<String, String>>(),
             ),
             (
                 StorageParams::S3(StorageS3Config {
@@ -287,10 +291,10 @@ async fn test_parse_uri_location() -> Result<()> {
                     security_token: "".to_string(),
                     master_key: "".to_string(),
                     root: "/tmp/".to_string(),
-                    disable_credential_loader: true,
+                    disable_credential_loader: false,
                     enable_virtual_host_style: false,
                     role_arn: "aws::iam::xxxx".to_string(),
-                    external_id: "".to_string(),
+                    external_id: "my-external-id".to_string(),
                     allow_anonymous: false,
                 }),
                 "/".to_string(),
@@ -330,6 +334,30 @@ async fn test_parse_uri_location() -> Result<()> {
                     bucket: "example".to_string(),
                     root: "/tmp/".to_string(),
                     credential: "gcs.credential".to_string(),
+                    service_account: "".to_string(),
+                }),
+                "/".to_string(),
+            ),
+        ),
+        (
+            "gcs_with_service_account",
+            UriLocation::new(
+                "gcs".to_string(),
+                "example".to_string(),
+                "/tmp/".to_string(),
+                "".to_string(),
+                vec![("service_account", "sa@example.iam.gserviceaccount.com")]
+                    .into_iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect::<BTreeMap<String, String>>(),
+            ),
+            (
+                StorageParams::Gcs(StorageGcsConfig {
+                    endpoint_url: STORAGE_GCS_DEFAULT_ENDPOINT.to_string(),
+                    bucket: "example".to_string(),
+                    root: "/tmp/".to_string(),
+                    credential: "".to_string(),
+                    service_account: "sa@example.iam.gserviceaccount.com".to_string(),
                 }),
                 "/".to_string(),
             ),
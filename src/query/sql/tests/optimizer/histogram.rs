@@ -12,9 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_expression::types::number::F64;
+use common_sql::optimizer::histogram_from_column_histogram;
 use common_sql::optimizer::Histogram;
 use common_sql::optimizer::HistogramBucket;
 use common_storage::Datum;
+use storages_common_table_meta::meta::ColumnHistogram;
+use storages_common_table_meta::meta::ColumnHistogramBucket;
 
 #[test]
 fn test_histogram() {
@@ -28,3 +32,52 @@ fn test_histogram() {
     assert_eq!(histogram.num_values(), 4.0);
     assert_eq!(histogram.num_distinct_values(), 2.0);
 }
+
+#[test]
+fn test_histogram_from_column_histogram() {
+    let histogram = histogram_from_column_histogram(&ColumnHistogram {
+        lower_bound: 0.0,
+        buckets: vec![
+            ColumnHistogramBucket {
+                upper_bound: 10.0,
+                num_values: 90,
+                num_distinct: 10,
+            },
+            ColumnHistogramBucket {
+                upper_bound: 100.0,
+                num_values: 10,
+                num_distinct: 10,
+            },
+        ],
+    });
+    assert!(histogram.accuracy);
+    // the first bucket records the min value
+    assert_eq!(histogram.num_buckets(), 3);
+    assert_eq!(histogram.num_values(), 100.0);
+
+    let below = |v: f64| histogram.num_values_below(&Datum::Float(F64::from(v)));
+    assert_eq!(below(-1.0), Some(0.0));
+    assert_eq!(below(5.0), Some(45.0));
+    assert_eq!(below(55.0), Some(95.0));
+    assert_eq!(below(200.0), Some(100.0));
+    assert_eq!(histogram.num_values_below(&Datum::Int(10)), Some(90.0));
+    assert_eq!(
+        histogram.num_values_below(&Datum::Bytes(b"a".to_vec())),
+        None
+    );
+
+    let trimmed = histogram.trim(&Datum::Int(5), &Datum::Int(55)).unwrap();
+    assert!(trimmed.accuracy);
+    assert_eq!(trimmed.num_buckets(), 3);
+    assert_eq!(trimmed.num_values(), 50.0);
+    assert_eq!(
+        trimmed.buckets[0].upper_bound(),
+        &Datum::Float(F64::from(5.0))
+    );
+    assert_eq!(
+        trimmed.buckets[2].upper_bound(),
+        &Datum::Float(F64::from(55.0))
+    );
+
+    assert!(histogram.trim(&Datum::Int(200), &Datum::Int(300)).is_none());
+}
@@ -12,7 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use common_arrow::arrow::chunk::Chunk;
 use common_arrow::arrow::datatypes::DataType as ArrowDataType;
 use common_arrow::arrow::io::parquet::write::transverse;
 use common_arrow::arrow::io::parquet::write::RowGroupIterator;
@@ -45,7 +44,7 @@ pub fn blocks_to_parquet(
     };
     let batches = blocks
         .into_iter()
-        .map(Chunk::try_from)
+        .map(|block| block.to_arrow_chunk(&arrow_schema))
         .collect::<Result<Vec<_>>>()?;
 
     let encoding_map = |data_type: &ArrowDataType| match data_type {
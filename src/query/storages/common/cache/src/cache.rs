@@ -14,6 +14,8 @@
 
 use std::hash::BuildHasher;
 use std::hash::Hash;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 use common_cache::Count;
@@ -31,6 +33,8 @@ where
     fn get<Q: AsRef<str>>(&self, k: Q) -> Option<Arc<V>>;
     fn put(&self, key: K, value: Arc<V>);
     fn evict(&self, k: &str) -> bool;
+    /// Evict all the items.
+    fn clear(&self);
     fn contains_key(&self, k: &str) -> bool;
     fn size(&self) -> u64;
     fn len(&self) -> usize;
@@ -47,6 +51,7 @@ where Self: Sized
         NamedCache {
             name: name.into(),
             cache: self,
+            stats: Arc::new(CacheStats::default()),
         }
     }
 }
@@ -58,6 +63,7 @@ impl<T> Named for T where T: Sized + Clone {}
 pub struct NamedCache<C> {
     name: String,
     cache: C,
+    stats: Arc<CacheStats>,
 }
 
 impl<C> NamedCache<C> {
@@ -65,6 +71,42 @@ impl<C> NamedCache<C> {
     pub fn name(&self) -> &str {
         &self.name
     }
+
+    #[inline]
+    pub fn stats(&self) -> &CacheStats {
+        &self.stats
+    }
+}
+
+/// The accesses of a cache since it's created, shown in `system.caches`.
+///
+/// Unlike the metrics, which are shared by the whole process, the stats belong to the
+/// cache instance.
+#[derive(Default)]
+pub struct CacheStats {
+    hit: AtomicU64,
+    miss: AtomicU64,
+}
+
+impl CacheStats {
+    pub fn record(&self, hit: bool) {
+        match hit {
+            true => self.hit.fetch_add(1, Ordering::Relaxed),
+            false => self.miss.fetch_add(1, Ordering::Relaxed),
+        };
+    }
+
+    pub fn hit(&self) -> u64 {
+        self.hit.load(Ordering::Relaxed)
+    }
+
+    pub fn miss(&self) -> u64 {
+        self.miss.load(Ordering::Relaxed)
+    }
+
+    pub fn access(&self) -> u64 {
+        self.hit() + self.miss()
+    }
 }
 
 impl<K, V, S, M, C> CacheAccessor<K, V, S, M> for NamedCache<C>
@@ -79,10 +121,12 @@ where
         match self.cache.get(k) {
             None => {
                 metrics_inc_cache_miss_count(1, &self.name);
+                self.stats.record(false);
                 None
             }
             v @ Some(_) => {
                 metrics_inc_cache_hit_count(1, &self.name);
+                self.stats.record(true);
                 v
             }
         }
@@ -96,6 +140,10 @@ where
         self.cache.evict(k)
     }
 
+    fn clear(&self) {
+        self.cache.clear()
+    }
+
     fn size(&self) -> u64 {
         self.cache.size()
     }
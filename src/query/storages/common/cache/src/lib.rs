@@ -17,8 +17,10 @@
 mod cache;
 mod providers;
 mod read;
+mod scratch;
 
 pub use cache::CacheAccessor;
+pub use cache::CacheStats;
 pub use cache::Named;
 pub use cache::NamedCache;
 pub use providers::DiskCacheError;
@@ -40,3 +42,7 @@ pub use read::InMemoryCacheReader;
 pub use read::InMemoryItemCacheReader;
 pub use read::LoadParams;
 pub use read::Loader;
+pub use scratch::ScratchConsumer;
+pub use scratch::ScratchEvictor;
+pub use scratch::ScratchManager;
+pub use scratch::ScratchReservation;
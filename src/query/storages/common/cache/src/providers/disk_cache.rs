@@ -21,6 +21,7 @@ use std::io::Write;
 use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::sync::Weak;
 
 use bytes::Bytes;
 use common_cache::Cache;
@@ -37,10 +38,15 @@ use siphasher::sip128;
 use siphasher::sip128::Hasher128;
 
 use crate::CacheAccessor;
+use crate::ScratchConsumer;
+use crate::ScratchEvictor;
+use crate::ScratchManager;
 
 pub struct DiskCache<C> {
     cache: C,
     root: PathBuf,
+    /// The budget of the cached files is taken from the scratch disk if it's enabled.
+    scratch: Option<Arc<ScratchManager>>,
 }
 
 pub struct DiskCacheKey(String);
@@ -85,9 +91,17 @@ where C: Cache<String, u64, DefaultHashBuilder, FileSize>
         DiskCache {
             cache: C::with_meter_and_hasher(size, FileSize, DefaultHashBuilder::default()),
             root: PathBuf::from(path),
+            scratch: None,
         }
         .init()
     }
+
+    /// Share the scratch disk with other consumers, the cached files take the budget of
+    /// [`ScratchConsumer::TableDataCache`].
+    pub fn with_scratch(mut self, scratch: Arc<ScratchManager>) -> Self {
+        self.scratch = Some(scratch);
+        self
+    }
 }
 
 impl<C> DiskCache<C>
@@ -153,35 +167,80 @@ where C: Cache<String, u64, DefaultHashBuilder, FileSize>
             return Err(Error::FileTooLarge);
         }
 
+        // the budget of a replaced item is given back before taking the new one
+        let cache_key = self.cache_key(key.as_ref());
+        if let Some(size) = self.cache.pop(&cache_key.0) {
+            self.release_scratch(size);
+        }
+
         // check eviction
         while self.cache.size() + bytes_len > self.cache.capacity() {
-            if let Some((rel_path, _)) = self.cache.pop_by_policy() {
-                let cached_item_path = self.abs_path_of_cache_key(&DiskCacheKey(rel_path));
-                fs::remove_file(&cached_item_path).unwrap_or_else(|e| {
-                    error!(
-                        "Error removing file from cache: `{:?}`: {}",
-                        cached_item_path, e
-                    )
-                });
-            }
+            self.pop_by_policy();
         }
         debug_assert!(self.cache.size() <= self.cache.capacity());
 
-        let cache_key = self.cache_key(key.as_ref());
-        let path = self.abs_path_of_cache_key(&cache_key);
-        if let Some(parent_path) = path.parent() {
-            fs::create_dir_all(parent_path)?;
+        // the scratch disk is shared with others, evict our own items until it has room
+        if let Some(scratch) = self.scratch.clone() {
+            while !scratch.try_acquire(ScratchConsumer::TableDataCache, bytes_len) {
+                if self.pop_by_policy().is_none() {
+                    return Err(Error::NoScratchSpace);
+                }
+            }
         }
-        let mut f = File::create(&path)?;
-        let mut bufs = Vec::with_capacity(bytes.len());
-        for slick in bytes {
-            bufs.push(IoSlice::new(slick));
+
+        let path = self.abs_path_of_cache_key(&cache_key);
+        let write_file = || {
+            if let Some(parent_path) = path.parent() {
+                fs::create_dir_all(parent_path)?;
+            }
+            let mut f = File::create(&path)?;
+            let mut bufs = Vec::with_capacity(bytes.len());
+            for slick in bytes {
+                bufs.push(IoSlice::new(slick));
+            }
+            f.write_all_vectored(&mut bufs)
+        };
+        if let Err(e) = write_file() {
+            self.release_scratch(bytes_len);
+            return Err(e.into());
         }
-        f.write_all_vectored(&mut bufs)?;
         self.cache.put(cache_key.0, bytes_len);
         Ok(())
     }
 
+    /// Evict the item chosen by the policy, returns its size.
+    fn pop_by_policy(&mut self) -> Option<u64> {
+        let (rel_path, size) = self.cache.pop_by_policy()?;
+        let cached_item_path = self.abs_path_of_cache_key(&DiskCacheKey(rel_path));
+        fs::remove_file(&cached_item_path).unwrap_or_else(|e| {
+            error!(
+                "Error removing file from cache: `{:?}`: {}",
+                cached_item_path, e
+            )
+        });
+        self.release_scratch(size);
+        Some(size)
+    }
+
+    /// Evict items until at least `bytes` are freed or the cache is empty, returns the
+    /// bytes freed.
+    pub fn evict_bytes(&mut self, bytes: u64) -> u64 {
+        let mut freed = 0;
+        while freed < bytes {
+            match self.pop_by_policy() {
+                Some(size) => freed += size,
+                None => break,
+            }
+        }
+        freed
+    }
+
+    fn release_scratch(&self, bytes: u64) {
+        if let Some(scratch) = &self.scratch {
+            scratch.release(ScratchConsumer::TableDataCache, bytes);
+        }
+    }
+
     /// Return `true` if a file with path `key` is in the cache.
     pub fn contains_key(&self, key: &str) -> bool {
         let cache_key = self.cache_key(key);
@@ -200,7 +259,8 @@ where C: Cache<String, u64, DefaultHashBuilder, FileSize>
     pub fn remove(&mut self, key: &str) -> Result<()> {
         let cache_key = self.cache_key(key);
         match self.cache.pop(&cache_key.0) {
-            Some(_) => {
+            Some(size) => {
+                self.release_scratch(size);
                 let path = self.abs_path_of_cache_key(&cache_key);
                 fs::remove_file(&path).map_err(|e| {
                     error!("Error removing file from cache: `{:?}`: {}", path, e);
@@ -222,6 +282,8 @@ pub mod result {
     pub enum Error {
         /// The file was too large to fit in the cache.
         FileTooLarge,
+        /// The scratch disk shared with others has no room for the file.
+        NoScratchSpace,
         /// The file was not in the cache.
         MalformedPath,
         /// An IO Error occurred.
@@ -232,6 +294,7 @@ pub mod result {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             match self {
                 Error::FileTooLarge => write!(f, "File too large"),
+                Error::NoScratchSpace => write!(f, "No scratch space"),
                 Error::MalformedPath => write!(f, "Malformed catch file path"),
                 Error::Io(ref e) => write!(f, "{e}"),
             }
@@ -328,6 +391,11 @@ impl CacheAccessor<String, Bytes, common_cache::DefaultHashBuilder, Count> for L
         }
     }
 
+    fn clear(&self) {
+        let mut cache = self.write();
+        cache.evict_bytes(u64::MAX);
+    }
+
     fn contains_key(&self, k: &str) -> bool {
         let cache = self.read();
         cache.contains_key(k)
@@ -370,14 +438,38 @@ fn validate_checksum(bytes: &[u8]) -> Result<()> {
 pub type LruDiskCache = DiskCache<LruCache<String, u64, DefaultHashBuilder, FileSize>>;
 pub type LruDiskCacheHolder = Arc<RwLock<LruDiskCache>>;
 
+/// Evicts the least recently used items of the disk cache for the consumers of the
+/// scratch disk with a higher priority.
+struct DiskCacheEvictor(Weak<RwLock<LruDiskCache>>);
+
+impl ScratchEvictor for DiskCacheEvictor {
+    fn evict(&self, bytes: u64) -> u64 {
+        match self.0.upgrade() {
+            Some(cache) => cache.write().evict_bytes(bytes),
+            None => 0,
+        }
+    }
+}
+
 pub struct LruDiskCacheBuilder;
 impl LruDiskCacheBuilder {
     pub fn new_disk_cache(
         path: &PathBuf,
         disk_cache_bytes_size: u64,
+        scratch: Option<Arc<ScratchManager>>,
     ) -> Result<LruDiskCacheHolder> {
-        let external_cache = DiskCache::new(path, disk_cache_bytes_size)
+        let mut external_cache = DiskCache::new(path, disk_cache_bytes_size)
             .map_err(|e| ErrorCode::StorageOther(format!("create disk cache failed, {e}")))?;
-        Ok(Arc::new(RwLock::new(external_cache)))
+        if let Some(scratch) = &scratch {
+            external_cache = external_cache.with_scratch(scratch.clone());
+        }
+        let holder = Arc::new(RwLock::new(external_cache));
+        if let Some(scratch) = scratch {
+            scratch.register_evictor(
+                ScratchConsumer::TableDataCache,
+                Arc::new(DiskCacheEvictor(Arc::downgrade(&holder))),
+            );
+        }
+        Ok(holder)
     }
 }
@@ -90,6 +90,11 @@ mod impls {
             guard.pop(k).is_some()
         }
 
+        fn clear(&self) {
+            let mut guard = self.write();
+            guard.clear();
+        }
+
         fn contains_key(&self, k: &str) -> bool {
             let guard = self.read();
             guard.contains(k)
@@ -131,6 +136,12 @@ mod impls {
             }
         }
 
+        fn clear(&self) {
+            if let Some(cache) = self {
+                cache.clear();
+            }
+        }
+
         fn contains_key(&self, k: &str) -> bool {
             if let Some(cache) = self {
                 cache.contains_key(k)
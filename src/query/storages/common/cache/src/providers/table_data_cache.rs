@@ -28,7 +28,9 @@ use log::info;
 
 use crate::providers::LruDiskCacheHolder;
 use crate::CacheAccessor;
+use crate::CacheStats;
 use crate::LruDiskCacheBuilder;
+use crate::ScratchManager;
 
 struct CacheItem {
     key: String,
@@ -63,24 +65,39 @@ impl AsRef<str> for TableDataCacheKey {
 #[derive(Clone)]
 pub struct TableDataCache<T = LruDiskCacheHolder> {
     external_cache: T,
+    stats: Arc<CacheStats>,
     population_queue: crossbeam_channel::Sender<CacheItem>,
     _cache_populator: DiskCachePopulator,
 }
 
 const TABLE_DATA_CACHE_NAME: &str = "table_data";
 
+impl<T> TableDataCache<T> {
+    #[inline]
+    pub fn name(&self) -> &str {
+        TABLE_DATA_CACHE_NAME
+    }
+
+    #[inline]
+    pub fn stats(&self) -> &CacheStats {
+        &self.stats
+    }
+}
+
 pub struct TableDataCacheBuilder;
 impl TableDataCacheBuilder {
     pub fn new_table_data_disk_cache(
         path: &PathBuf,
         population_queue_size: u32,
         disk_cache_bytes_size: u64,
+        scratch: Option<Arc<ScratchManager>>,
     ) -> Result<TableDataCache<LruDiskCacheHolder>> {
-        let disk_cache = LruDiskCacheBuilder::new_disk_cache(path, disk_cache_bytes_size)?;
+        let disk_cache = LruDiskCacheBuilder::new_disk_cache(path, disk_cache_bytes_size, scratch)?;
         let (rx, tx) = crossbeam_channel::bounded(population_queue_size as usize);
         let num_population_thread = 1;
         Ok(TableDataCache {
             external_cache: disk_cache.clone(),
+            stats: Arc::new(CacheStats::default()),
             population_queue: rx,
             _cache_populator: DiskCachePopulator::new(tx, disk_cache, num_population_thread)?,
         })
@@ -93,9 +110,11 @@ impl CacheAccessor<String, Bytes, DefaultHashBuilder, Count> for TableDataCache
         let k = k.as_ref();
         if let Some(item) = self.external_cache.get(k) {
             metrics_inc_cache_hit_count(1, TABLE_DATA_CACHE_NAME);
+            self.stats.record(true);
             Some(item)
         } else {
             metrics_inc_cache_miss_count(1, TABLE_DATA_CACHE_NAME);
+            self.stats.record(false);
             None
         }
     }
@@ -124,6 +143,10 @@ impl CacheAccessor<String, Bytes, DefaultHashBuilder, Count> for TableDataCache
         self.external_cache.evict(k)
     }
 
+    fn clear(&self) {
+        self.external_cache.clear()
+    }
+
     fn contains_key(&self, k: &str) -> bool {
         self.external_cache.contains_key(k)
     }
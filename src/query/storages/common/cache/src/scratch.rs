@@ -0,0 +1,250 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_metrics::cache::metrics_inc_scratch_evicted_bytes;
+use common_metrics::cache::metrics_inc_scratch_rejected_count;
+use common_metrics::cache::metrics_set_scratch_used_bytes;
+use log::info;
+use log::warn;
+use parking_lot::Mutex;
+use parking_lot::RwLock;
+
+/// The subsystems sharing the local scratch disk.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ScratchConsumer {
+    /// The disk cache of table data, which can always be populated again.
+    TableDataCache,
+    /// The files spilled by queries, which are lost if evicted.
+    Spill,
+}
+
+impl ScratchConsumer {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ScratchConsumer::TableDataCache => "table_data_cache",
+            ScratchConsumer::Spill => "spill",
+        }
+    }
+
+    /// A consumer may evict the space of the consumers with a lower priority if the
+    /// scratch disk is full.
+    pub fn priority(&self) -> u8 {
+        match self {
+            ScratchConsumer::TableDataCache => 0,
+            ScratchConsumer::Spill => 1,
+        }
+    }
+}
+
+/// ScratchEvictor frees the space of a consumer on the request of a consumer with a
+/// higher priority.
+pub trait ScratchEvictor: Send + Sync {
+    /// Frees at least `bytes` if possible, returns the bytes freed.
+    fn evict(&self, bytes: u64) -> u64;
+}
+
+#[derive(Default)]
+struct ConsumerUsage {
+    quota: u64,
+    used: u64,
+}
+
+/// ScratchManager allocates the budget of the local scratch disk to its consumers, so
+/// no consumer can fill the disk and make the others fail.
+///
+/// Each consumer holds at most its quota, and all of them hold at most the capacity in
+/// total. If the disk is full, a consumer evicts the consumers with a lower priority,
+/// e.g. spilling evicts the table data cache.
+pub struct ScratchManager {
+    root: PathBuf,
+    capacity: u64,
+    usages: Mutex<HashMap<ScratchConsumer, ConsumerUsage>>,
+    evictors: RwLock<Vec<(ScratchConsumer, Arc<dyn ScratchEvictor>)>>,
+}
+
+impl ScratchManager {
+    /// Creates a scratch manager of `capacity` bytes, the files of the consumers are
+    /// kept under `root`, which is cleared on creating.
+    pub fn try_create(
+        root: impl Into<PathBuf>,
+        capacity: u64,
+        quotas: &[(ScratchConsumer, u64)],
+    ) -> Result<Arc<ScratchManager>> {
+        let root = root.into();
+        if let Err(e) = fs::remove_dir_all(&root) {
+            warn!("remove scratch dir {:?} error {}", root, e);
+        }
+        fs::create_dir_all(&root).map_err(|e| {
+            ErrorCode::StorageOther(format!("create scratch dir {:?} failed, {e}", root))
+        })?;
+
+        let usages = quotas
+            .iter()
+            .map(|(consumer, quota)| {
+                (*consumer, ConsumerUsage {
+                    quota: *quota,
+                    used: 0,
+                })
+            })
+            .collect();
+        info!(
+            "scratch disk enabled, root {:?}, capacity {}, quotas {:?}",
+            root, capacity, quotas
+        );
+        Ok(Arc::new(ScratchManager {
+            root,
+            capacity,
+            usages: Mutex::new(usages),
+            evictors: RwLock::new(vec![]),
+        }))
+    }
+
+    /// The directory of the files of the consumer.
+    pub fn path(&self, consumer: ScratchConsumer) -> PathBuf {
+        self.root.join(consumer.name())
+    }
+
+    pub fn capacity(&self) -> u64 {
+        self.capacity
+    }
+
+    pub fn used(&self, consumer: ScratchConsumer) -> u64 {
+        self.usages.lock().get(&consumer).map_or(0, |u| u.used)
+    }
+
+    pub fn total_used(&self) -> u64 {
+        self.usages.lock().values().map(|u| u.used).sum()
+    }
+
+    pub fn register_evictor(&self, consumer: ScratchConsumer, evictor: Arc<dyn ScratchEvictor>) {
+        self.evictors.write().push((consumer, evictor));
+    }
+
+    /// Takes `bytes` of the budget for the consumer if both its quota and the capacity
+    /// allow, without evicting others.
+    ///
+    /// Used by the consumers evicting their own items to make room, like the disk cache.
+    pub fn try_acquire(&self, consumer: ScratchConsumer, bytes: u64) -> bool {
+        let mut usages = self.usages.lock();
+        let total_used = usages.values().map(|u| u.used).sum::<u64>();
+        let Some(usage) = usages.get_mut(&consumer) else {
+            return false;
+        };
+        if usage.used + bytes > usage.quota || total_used + bytes > self.capacity {
+            return false;
+        }
+        usage.used += bytes;
+        metrics_set_scratch_used_bytes(usage.used, consumer.name());
+        true
+    }
+
+    /// Takes `bytes` of the budget for the consumer, evicting the consumers with a lower
+    /// priority if the capacity is used up. Returns `None` if the quota of the consumer is
+    /// used up, or the space can't be freed.
+    ///
+    /// The budget is given back when the returned reservation is dropped.
+    pub fn acquire(
+        self: &Arc<Self>,
+        consumer: ScratchConsumer,
+        bytes: u64,
+    ) -> Option<ScratchReservation> {
+        loop {
+            let needed = {
+                let mut usages = self.usages.lock();
+                let total_used = usages.values().map(|u| u.used).sum::<u64>();
+                let usage = usages.get_mut(&consumer)?;
+                if usage.used + bytes > usage.quota {
+                    drop(usages);
+                    metrics_inc_scratch_rejected_count(1, consumer.name());
+                    return None;
+                }
+                if total_used + bytes <= self.capacity {
+                    usage.used += bytes;
+                    metrics_set_scratch_used_bytes(usage.used, consumer.name());
+                    return Some(ScratchReservation {
+                        manager: self.clone(),
+                        consumer,
+                        bytes,
+                    });
+                }
+                total_used + bytes - self.capacity
+            };
+
+            // The lock of usages must not be held here, evictors give the space back by
+            // `release`.
+            if self.evict(consumer, needed) == 0 {
+                metrics_inc_scratch_rejected_count(1, consumer.name());
+                return None;
+            }
+        }
+    }
+
+    /// Gives `bytes` of the budget of the consumer back.
+    pub fn release(&self, consumer: ScratchConsumer, bytes: u64) {
+        let mut usages = self.usages.lock();
+        if let Some(usage) = usages.get_mut(&consumer) {
+            usage.used = usage.used.saturating_sub(bytes);
+            metrics_set_scratch_used_bytes(usage.used, consumer.name());
+        }
+    }
+
+    /// Evicts the consumers with a lower priority than `consumer`, the lowest first.
+    fn evict(&self, consumer: ScratchConsumer, bytes: u64) -> u64 {
+        let mut evictors = self
+            .evictors
+            .read()
+            .iter()
+            .filter(|(c, _)| c.priority() < consumer.priority())
+            .cloned()
+            .collect::<Vec<_>>();
+        evictors.sort_by_key(|(c, _)| c.priority());
+
+        let mut freed = 0;
+        for (evicted, evictor) in evictors {
+            if freed >= bytes {
+                break;
+            }
+            let n = evictor.evict(bytes - freed);
+            metrics_inc_scratch_evicted_bytes(n, evicted.name());
+            freed += n;
+        }
+        freed
+    }
+}
+
+/// The budget taken by a consumer, given back on drop.
+pub struct ScratchReservation {
+    manager: Arc<ScratchManager>,
+    consumer: ScratchConsumer,
+    bytes: u64,
+}
+
+impl ScratchReservation {
+    pub fn bytes(&self) -> u64 {
+        self.bytes
+    }
+}
+
+impl Drop for ScratchReservation {
+    fn drop(&mut self) {
+        self.manager.release(self.consumer, self.bytes);
+    }
+}
@@ -13,3 +13,4 @@
 // limitations under the License.
 
 mod providers;
+mod scratch;
@@ -0,0 +1,86 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use storages_common_cache::LruDiskCacheBuilder;
+use storages_common_cache::ScratchConsumer;
+use storages_common_cache::ScratchManager;
+
+#[test]
+fn test_scratch_quota() {
+    let dir = tempfile::tempdir().unwrap();
+    let scratch = ScratchManager::try_create(dir.path().join("scratch"), 100, &[(
+        ScratchConsumer::Spill,
+        60,
+    )])
+    .unwrap();
+    assert!(scratch.path(ScratchConsumer::Spill).starts_with(dir.path()));
+
+    let r1 = scratch.acquire(ScratchConsumer::Spill, 40).unwrap();
+    // exceeds the quota of spill
+    assert!(scratch.acquire(ScratchConsumer::Spill, 30).is_none());
+    // consumers without quota get nothing
+    assert!(
+        scratch
+            .acquire(ScratchConsumer::TableDataCache, 1)
+            .is_none()
+    );
+    assert_eq!(scratch.used(ScratchConsumer::Spill), 40);
+
+    // the budget is given back on drop
+    drop(r1);
+    assert_eq!(scratch.total_used(), 0);
+    let _r2 = scratch.acquire(ScratchConsumer::Spill, 60).unwrap();
+    assert_eq!(scratch.used(ScratchConsumer::Spill), 60);
+}
+
+#[test]
+fn test_scratch_spill_evicts_disk_cache() {
+    let dir = tempfile::tempdir().unwrap();
+    let scratch = ScratchManager::try_create(dir.path().join("scratch"), 100, &[
+        (ScratchConsumer::Spill, 60),
+        (ScratchConsumer::TableDataCache, 100),
+    ])
+    .unwrap();
+    let cache =
+        LruDiskCacheBuilder::new_disk_cache(&dir.path().join("cache"), 100, Some(scratch.clone()))
+            .unwrap();
+
+    for i in 0..5 {
+        cache
+            .write()
+            .insert_bytes(&format!("key_{i}"), &[&[0; 20]])
+            .unwrap();
+    }
+    assert_eq!(scratch.used(ScratchConsumer::TableDataCache), 100);
+
+    // the disk cache evicts its own items, as the capacity is used up
+    cache.write().insert_bytes("key_5", &[&[0; 20]]).unwrap();
+    assert_eq!(scratch.total_used(), 100);
+    assert!(!cache.read().contains_key("key_0"));
+
+    // spilling evicts the least recently used items of the disk cache
+    let r = scratch.acquire(ScratchConsumer::Spill, 30).unwrap();
+    assert_eq!(scratch.used(ScratchConsumer::TableDataCache), 60);
+    assert_eq!(cache.read().len(), 3);
+    assert!(!cache.read().contains_key("key_1"));
+    assert!(!cache.read().contains_key("key_2"));
+
+    // the disk cache can't evict spilled files
+    cache.write().insert_bytes("key_6", &[&[0; 20]]).unwrap();
+    assert_eq!(scratch.used(ScratchConsumer::TableDataCache), 60);
+    assert_eq!(scratch.total_used(), 90);
+
+    drop(r);
+    assert_eq!(scratch.total_used(), 60);
+}
@@ -22,10 +22,14 @@ use common_config::CacheConfig;
 use common_config::CacheStorageTypeInnerConfig;
 use common_exception::Result;
 use log::info;
+use storages_common_cache::CacheAccessor;
+use storages_common_cache::CacheStats;
 use storages_common_cache::InMemoryCacheBuilder;
 use storages_common_cache::InMemoryItemCacheHolder;
 use storages_common_cache::Named;
 use storages_common_cache::NamedCache;
+use storages_common_cache::ScratchConsumer;
+use storages_common_cache::ScratchManager;
 use storages_common_cache::TableDataCache;
 use storages_common_cache::TableDataCacheBuilder;
 
@@ -54,11 +58,17 @@ pub struct CacheManager {
     file_meta_data_cache: Option<FileMetaDataCache>,
     table_data_cache: Option<TableDataCache>,
     table_column_array_cache: Option<ColumnArrayCache>,
+    scratch_manager: Option<Arc<ScratchManager>>,
+    /// The result cache is kept in the meta service, only the accesses of this node are here.
+    result_cache_stats: Arc<CacheStats>,
 }
 
 impl CacheManager {
     /// Initialize the caches according to the relevant configurations.
     pub fn init(config: &CacheConfig, tenant_id: impl Into<String>) -> Result<()> {
+        // setup local scratch disk
+        let scratch_manager = Self::new_scratch_manager(config)?;
+
         // setup table data cache
         let table_data_cache = {
             match config.data_cache_storage {
@@ -88,6 +98,7 @@ impl CacheManager {
                         &real_disk_cache_root,
                         queue_size,
                         config.disk_cache_config.max_bytes,
+                        scratch_manager.clone(),
                     )?
                 }
             }
@@ -112,6 +123,8 @@ impl CacheManager {
                 table_statistic_cache: None,
                 table_data_cache,
                 table_column_array_cache,
+                scratch_manager,
+                result_cache_stats: Arc::new(CacheStats::default()),
             }));
         } else {
             let table_snapshot_cache =
@@ -147,6 +160,8 @@ impl CacheManager {
                 table_statistic_cache,
                 table_data_cache,
                 table_column_array_cache,
+                scratch_manager,
+                result_cache_stats: Arc::new(CacheStats::default()),
             }));
         }
 
@@ -193,6 +208,49 @@ impl CacheManager {
         self.table_column_array_cache.clone()
     }
 
+    pub fn get_scratch_manager(&self) -> Option<Arc<ScratchManager>> {
+        self.scratch_manager.clone()
+    }
+
+    pub fn get_result_cache_stats(&self) -> Arc<CacheStats> {
+        self.result_cache_stats.clone()
+    }
+
+    /// Evict all the items of the caches of table meta, including the bloom index.
+    pub fn clear_table_meta_caches(&self) {
+        if let Some(cache) = &self.table_snapshot_cache {
+            cache.clear();
+        }
+        if let Some(cache) = &self.table_statistic_cache {
+            cache.clear();
+        }
+        if let Some(cache) = &self.segment_info_cache {
+            cache.clear();
+        }
+        if let Some(cache) = &self.bloom_index_filter_cache {
+            cache.clear();
+        }
+        if let Some(cache) = &self.bloom_index_meta_cache {
+            cache.clear();
+        }
+        if let Some(cache) = &self.prune_partitions_cache {
+            cache.clear();
+        }
+        if let Some(cache) = &self.file_meta_data_cache {
+            cache.clear();
+        }
+    }
+
+    /// Evict all the items of the caches of table data.
+    pub fn clear_table_data_caches(&self) {
+        if let Some(cache) = &self.table_data_cache {
+            cache.clear();
+        }
+        if let Some(cache) = &self.table_column_array_cache {
+            cache.clear();
+        }
+    }
+
     // create cache that meters size by `Count`
     fn new_item_cache<V>(
         capacity: u64,
@@ -224,16 +282,36 @@ impl CacheManager {
         }
     }
 
+    fn new_scratch_manager(config: &CacheConfig) -> Result<Option<Arc<ScratchManager>>> {
+        let scratch_config = &config.scratch_config;
+        if scratch_config.max_bytes == 0 {
+            return Ok(None);
+        }
+
+        let mut quotas = vec![(ScratchConsumer::Spill, scratch_config.spill_max_bytes)];
+        if config.data_cache_storage == CacheStorageTypeInnerConfig::Disk {
+            quotas.push((
+                ScratchConsumer::TableDataCache,
+                config.disk_cache_config.max_bytes,
+            ));
+        }
+        let manager =
+            ScratchManager::try_create(&scratch_config.path, scratch_config.max_bytes, &quotas)?;
+        Ok(Some(manager))
+    }
+
     fn new_block_data_cache(
         path: &PathBuf,
         population_queue_size: u32,
         disk_cache_bytes_size: u64,
+        scratch: Option<Arc<ScratchManager>>,
     ) -> Result<Option<TableDataCache>> {
         if disk_cache_bytes_size > 0 {
             let cache_holder = TableDataCacheBuilder::new_table_data_disk_cache(
                 path,
                 population_queue_size,
                 disk_cache_bytes_size,
+                scratch,
             )?;
             Ok(Some(cache_holder))
         } else {
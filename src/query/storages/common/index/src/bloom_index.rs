@@ -501,7 +501,7 @@ impl BloomIndex {
     }
 }
 
-fn visit_expr_column_eq_constant(
+pub(crate) fn visit_expr_column_eq_constant(
     expr: &mut Expr<String>,
     visitor: &mut impl FnMut(Span, &str, &Scalar, &DataType, &DataType) -> Result<Option<Expr<String>>>,
 ) -> Result<()> {
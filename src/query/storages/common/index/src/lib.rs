@@ -19,6 +19,7 @@ mod bloom_index;
 pub mod filters;
 mod index;
 mod page_index;
+mod point_lookup_index;
 mod range_index;
 
 pub use bloom_index::BloomIndex;
@@ -26,4 +27,7 @@ pub use bloom_index::BloomIndexMeta;
 pub use bloom_index::FilterEvalResult;
 pub use index::Index;
 pub use page_index::PageIndex;
+pub use point_lookup_index::PointLookupIndex;
+pub use point_lookup_index::PointLookupResult;
+pub use range_index::statistics_to_domain;
 pub use range_index::RangeIndex;
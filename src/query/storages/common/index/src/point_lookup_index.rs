@@ -0,0 +1,387 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::Column;
+use common_expression::ColumnId;
+use common_expression::ConstantFolder;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::TableField;
+use common_functions::BUILTIN_FUNCTIONS;
+
+use crate::bloom_index::visit_expr_column_eq_constant;
+use crate::BloomIndex;
+use crate::Index;
+
+/// Average number of keys per bucket of the perfect hash.
+const AVG_BUCKET_SIZE: usize = 4;
+/// Displacement seeds tried for a bucket before the slots are grown.
+const MAX_SEED: u32 = 1 << 16;
+
+/// A perfect hash of the distinct values of the key column of a block, to the rows holding them.
+///
+/// The values are hashed by the same digest as the bloom index, and placed with the
+/// hash-and-displace scheme: every value falls into a bucket, and every bucket has a seed
+/// that sends all of its values to free slots. A lookup is then a bucket, a slot and a
+/// digest comparison, however many distinct values the block has.
+///
+/// NULLs are not indexed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PointLookupIndex {
+    pub column_id: ColumnId,
+    seeds: Vec<u32>,
+    // digest of the value of each slot, meaningless for the empty slots.
+    digests: Vec<u64>,
+    // rows of slot `i` are `rows[offsets[i]..offsets[i + 1]]`, in ascending order.
+    offsets: Vec<u32>,
+    rows: Vec<u32>,
+}
+
+/// The result of applying a predicate to a [`PointLookupIndex`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PointLookupResult {
+    /// No row of the block matches the predicate.
+    MustFalse,
+    /// Only these rows of the block may match the predicate, in ascending order.
+    Rows(Vec<u32>),
+    /// The index can't tell.
+    Uncertain,
+}
+
+impl PointLookupIndex {
+    pub const VERSION: u8 = 1;
+
+    /// Build the index of the key column `column` of a block.
+    pub fn try_create(
+        func_ctx: &FunctionContext,
+        column_id: ColumnId,
+        column: &Column,
+        data_type: &DataType,
+    ) -> Result<Self> {
+        let (digests, validity) =
+            BloomIndex::calculate_nullable_column_digest(func_ctx, column, data_type)?;
+        let digests = digests
+            .iter()
+            .enumerate()
+            .map(|(row, digest)| match &validity {
+                Some(validity) if !validity.get_bit(row) => None,
+                _ => Some(*digest),
+            });
+        Ok(Self::from_digests(column_id, digests))
+    }
+
+    /// Build the index from the digests of the rows of a block, `None` for the NULLs.
+    pub fn from_digests(column_id: ColumnId, digests: impl Iterator<Item = Option<u64>>) -> Self {
+        let mut groups: Vec<(u64, Vec<u32>)> = vec![];
+        let mut positions = HashMap::new();
+        for (row, digest) in digests.enumerate() {
+            let Some(digest) = digest else {
+                continue;
+            };
+            let pos = *positions.entry(digest).or_insert_with(|| {
+                groups.push((digest, vec![]));
+                groups.len() - 1
+            });
+            groups[pos].1.push(row as u32);
+        }
+
+        let num_keys = groups.len();
+        let num_buckets = (num_keys / AVG_BUCKET_SIZE).max(1);
+        let mut num_slots = (num_keys + num_keys / 4).max(1);
+        let (seeds, key_slots) = loop {
+            if let Some(placement) = Self::displace(&groups, num_buckets, num_slots) {
+                break placement;
+            }
+            num_slots *= 2;
+        };
+
+        let mut slot_keys = vec![None; num_slots];
+        for (key, slot) in key_slots.into_iter().enumerate() {
+            slot_keys[slot] = Some(key);
+        }
+        let mut digests = Vec::with_capacity(num_slots);
+        let mut offsets = Vec::with_capacity(num_slots + 1);
+        let mut rows = Vec::new();
+        offsets.push(0);
+        for key in slot_keys {
+            match key {
+                Some(key) => {
+                    let (digest, key_rows) = &groups[key];
+                    digests.push(*digest);
+                    rows.extend_from_slice(key_rows);
+                }
+                None => digests.push(0),
+            }
+            offsets.push(rows.len() as u32);
+        }
+
+        Self {
+            column_id,
+            seeds,
+            digests,
+            offsets,
+            rows,
+        }
+    }
+
+    // Find a seed for every bucket, such that all the keys land in distinct slots.
+    // Returns the seeds and the slot of every key.
+    fn displace(
+        groups: &[(u64, Vec<u32>)],
+        num_buckets: usize,
+        num_slots: usize,
+    ) -> Option<(Vec<u32>, Vec<usize>)> {
+        let mut buckets = vec![vec![]; num_buckets];
+        for (key, (digest, _)) in groups.iter().enumerate() {
+            buckets[bucket_of(*digest, num_buckets)].push(key);
+        }
+        // place the largest buckets first, while most of the slots are free.
+        let mut order = (0..num_buckets).collect::<Vec<_>>();
+        order.sort_by_key(|b| std::cmp::Reverse(buckets[*b].len()));
+
+        let mut seeds = vec![0; num_buckets];
+        let mut key_slots = vec![0; groups.len()];
+        let mut taken = vec![false; num_slots];
+        let mut slots = Vec::with_capacity(AVG_BUCKET_SIZE);
+        for bucket in order {
+            let keys = &buckets[bucket];
+            if keys.is_empty() {
+                break;
+            }
+            let seed = (0..MAX_SEED).find(|seed| {
+                slots.clear();
+                for key in keys {
+                    let slot = slot_of(groups[*key].0, *seed, num_slots);
+                    if taken[slot] || slots.contains(&slot) {
+                        return false;
+                    }
+                    slots.push(slot);
+                }
+                true
+            })?;
+            seeds[bucket] = seed;
+            for (key, slot) in keys.iter().zip(slots.iter()) {
+                taken[*slot] = true;
+                key_slots[*key] = *slot;
+            }
+        }
+        Some((seeds, key_slots))
+    }
+
+    /// The rows holding the value of `digest`, in ascending order.
+    pub fn lookup(&self, digest: u64) -> &[u32] {
+        let seed = self.seeds[bucket_of(digest, self.seeds.len())];
+        let slot = slot_of(digest, seed, self.digests.len());
+        if self.digests[slot] != digest {
+            return &[];
+        }
+        &self.rows[self.offsets[slot] as usize..self.offsets[slot + 1] as usize]
+    }
+
+    /// Number of the distinct values indexed.
+    pub fn num_keys(&self) -> usize {
+        self.offsets.windows(2).filter(|w| w[0] != w[1]).count()
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(
+            17 + 4 * (self.seeds.len() + self.offsets.len() + self.rows.len())
+                + 8 * self.digests.len(),
+        );
+        buf.push(Self::VERSION);
+        buf.extend_from_slice(&self.column_id.to_le_bytes());
+        buf.extend_from_slice(&(self.seeds.len() as u32).to_le_bytes());
+        buf.extend_from_slice(&(self.digests.len() as u32).to_le_bytes());
+        buf.extend_from_slice(&(self.rows.len() as u32).to_le_bytes());
+        self.seeds
+            .iter()
+            .for_each(|v| buf.extend_from_slice(&v.to_le_bytes()));
+        self.digests
+            .iter()
+            .for_each(|v| buf.extend_from_slice(&v.to_le_bytes()));
+        self.offsets
+            .iter()
+            .for_each(|v| buf.extend_from_slice(&v.to_le_bytes()));
+        self.rows
+            .iter()
+            .for_each(|v| buf.extend_from_slice(&v.to_le_bytes()));
+        buf
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut reader = Reader(bytes);
+        let version = reader.take::<1>()?[0];
+        if version != Self::VERSION {
+            return Err(ErrorCode::StorageOther(format!(
+                "unsupported point lookup index version {}",
+                version
+            )));
+        }
+        let column_id = reader.u32()?;
+        let num_buckets = reader.u32()? as usize;
+        let num_slots = reader.u32()? as usize;
+        let num_rows = reader.u32()? as usize;
+        if num_buckets == 0 || num_slots == 0 {
+            return Err(ErrorCode::StorageOther("invalid point lookup index"));
+        }
+        let seeds = reader.u32s(num_buckets)?;
+        let digests = (0..num_slots)
+            .map(|_| reader.take::<8>().map(u64::from_le_bytes))
+            .collect::<Result<Vec<_>>>()?;
+        let offsets = reader.u32s(num_slots + 1)?;
+        let rows = reader.u32s(num_rows)?;
+        if !reader.0.is_empty()
+            || offsets.windows(2).any(|w| w[0] > w[1])
+            || offsets.last().copied() != Some(num_rows as u32)
+        {
+            return Err(ErrorCode::StorageOther("invalid point lookup index"));
+        }
+        Ok(Self {
+            column_id,
+            seeds,
+            digests,
+            offsets,
+            rows,
+        })
+    }
+
+    /// Find the constants of the `key = <constant>` patterns in the expression.
+    pub fn find_eq_scalars(
+        expr: &Expr<String>,
+        key: &TableField,
+    ) -> Result<Vec<(Scalar, DataType)>> {
+        let mut scalars = Vec::new();
+        visit_expr_column_eq_constant(&mut expr.clone(), &mut |_, col_name, scalar, ty, _| {
+            if col_name == key.name() && Self::supported_type(ty) && !scalar.is_null() {
+                scalars.push((scalar.clone(), ty.clone()));
+            }
+            Ok(None)
+        })?;
+        Ok(scalars)
+    }
+
+    /// Apply the predicate expression to the index of the key column `key_name`.
+    ///
+    /// `scalar_map` holds the digests of the constants found by [`Self::find_eq_scalars`].
+    pub fn apply(
+        &self,
+        func_ctx: &FunctionContext,
+        expr: &Expr<String>,
+        key_name: &str,
+        scalar_map: &HashMap<Scalar, u64>,
+    ) -> Result<PointLookupResult> {
+        // Rewrite `key = <constant>` to `false` if the block doesn't contain the constant.
+        let mut rows = vec![];
+        let mut absent = expr.clone();
+        visit_expr_column_eq_constant(&mut absent, &mut |span, col_name, scalar, _, ty| {
+            let Some(digest) = scalar_map.get(scalar).filter(|_| col_name == key_name) else {
+                return Ok(None);
+            };
+            let matched = self.lookup(*digest);
+            if matched.is_empty() {
+                return Ok(Some(false_expr(span, ty)));
+            }
+            rows.extend_from_slice(matched);
+            Ok(None)
+        })?;
+        if is_false(&absent, func_ctx) {
+            return Ok(PointLookupResult::MustFalse);
+        }
+
+        // If the predicate is false once all the lookups fail, the rows matched by
+        // the lookups are the only ones that may match it.
+        let mut complete = true;
+        let mut missed = expr.clone();
+        visit_expr_column_eq_constant(&mut missed, &mut |span, col_name, scalar, _, ty| {
+            if col_name != key_name {
+                return Ok(None);
+            }
+            if !scalar_map.contains_key(scalar) {
+                complete = false;
+                return Ok(None);
+            }
+            Ok(Some(false_expr(span, ty)))
+        })?;
+        if complete && is_false(&missed, func_ctx) {
+            rows.sort_unstable();
+            rows.dedup();
+            return Ok(PointLookupResult::Rows(rows));
+        }
+        Ok(PointLookupResult::Uncertain)
+    }
+}
+
+impl Index for PointLookupIndex {}
+
+fn false_expr(span: common_exception::Span, data_type: &DataType) -> Expr<String> {
+    Expr::Constant {
+        span,
+        scalar: Scalar::Boolean(false),
+        data_type: data_type.clone(),
+    }
+}
+
+fn is_false(expr: &Expr<String>, func_ctx: &FunctionContext) -> bool {
+    let (expr, _) = ConstantFolder::fold(expr, func_ctx, &BUILTIN_FUNCTIONS);
+    matches!(expr, Expr::Constant {
+        scalar: Scalar::Boolean(false),
+        ..
+    })
+}
+
+// splitmix64 finalizer, the digests of the bloom index are hashed again so that
+// the buckets and the slots are independent.
+fn mix(mut x: u64) -> u64 {
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
+
+fn bucket_of(digest: u64, num_buckets: usize) -> usize {
+    (mix(digest) % num_buckets as u64) as usize
+}
+
+fn slot_of(digest: u64, seed: u32, num_slots: usize) -> usize {
+    let salt = (seed as u64 + 1).wrapping_mul(0x9e37_79b9_7f4a_7c15);
+    (mix(digest ^ salt) % num_slots as u64) as usize
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl Reader<'_> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N]> {
+        if self.0.len() < N {
+            return Err(ErrorCode::StorageOther(
+                "invalid point lookup index, unexpected end of data",
+            ));
+        }
+        let (head, tail) = self.0.split_at(N);
+        self.0 = tail;
+        Ok(head.try_into().unwrap())
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        self.take::<4>().map(u32::from_le_bytes)
+    }
+
+    fn u32s(&mut self, n: usize) -> Result<Vec<u32>> {
+        (0..n).map(|_| self.u32()).collect()
+    }
+}
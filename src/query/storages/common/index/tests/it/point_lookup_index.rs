@@ -0,0 +1,163 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use common_exception::Result;
+use common_expression::type_check::check_function;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::UInt64Type;
+use common_expression::Expr;
+use common_expression::FromData;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_functions::BUILTIN_FUNCTIONS;
+use rand::rngs::StdRng;
+use rand::Rng;
+use rand::SeedableRng;
+use storages_common_index::BloomIndex;
+use storages_common_index::PointLookupIndex;
+use storages_common_index::PointLookupResult;
+
+#[test]
+fn test_point_lookup_index_lookup() -> Result<()> {
+    let mut rng = StdRng::seed_from_u64(0);
+    for num_keys in [0, 1, 7, 1000, 100_000] {
+        let keys = (0..num_keys).map(|_| rng.gen::<u64>()).collect::<Vec<_>>();
+        let digests = (0..num_keys * 3)
+            .map(|_| match rng.gen_range(0..10) {
+                0 => None,
+                _ => Some(keys[rng.gen_range(0..num_keys)]),
+            })
+            .collect::<Vec<_>>();
+        let mut expected: BTreeMap<u64, Vec<u32>> = BTreeMap::new();
+        for (row, digest) in digests.iter().enumerate() {
+            if let Some(digest) = digest {
+                expected.entry(*digest).or_default().push(row as u32);
+            }
+        }
+
+        let index = PointLookupIndex::from_digests(1, digests.into_iter());
+        assert_eq!(index.num_keys(), expected.len());
+        for (digest, rows) in expected.iter() {
+            assert_eq!(index.lookup(*digest), rows.as_slice());
+        }
+        for _ in 0..1000 {
+            let digest = rng.gen::<u64>();
+            if !expected.contains_key(&digest) {
+                assert!(index.lookup(digest).is_empty());
+            }
+        }
+
+        let bytes = index.to_bytes();
+        assert_eq!(PointLookupIndex::from_bytes(&bytes)?, index);
+        assert!(PointLookupIndex::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    }
+    Ok(())
+}
+
+#[test]
+fn test_point_lookup_index_apply() -> Result<()> {
+    let func_ctx = FunctionContext::default();
+    let ty = DataType::Number(NumberDataType::UInt64);
+    let column = UInt64Type::from_data((0..1000u64).map(|v| v % 100).collect::<Vec<_>>());
+    let index = PointLookupIndex::try_create(&func_ctx, 0, &column, &ty)?;
+    let key = TableField::new("id", TableDataType::Number(NumberDataType::UInt64));
+
+    let rows_of = |v: u32| (0..10).map(|i| v + i * 100).collect::<Vec<_>>();
+    let mut rows_5_or_7 = [rows_of(5), rows_of(7)].concat();
+    rows_5_or_7.sort();
+
+    assert_eq!(
+        apply(&index, &key, eq("id", 5))?,
+        PointLookupResult::Rows(rows_of(5))
+    );
+    assert_eq!(
+        apply(&index, &key, eq("id", 500))?,
+        PointLookupResult::MustFalse
+    );
+    assert_eq!(
+        apply(&index, &key, or(eq("id", 5), eq("id", 7)))?,
+        PointLookupResult::Rows(rows_5_or_7)
+    );
+    assert_eq!(
+        apply(&index, &key, or(eq("id", 5), eq("id", 500)))?,
+        PointLookupResult::Rows(rows_of(5))
+    );
+    assert_eq!(
+        apply(&index, &key, and(eq("id", 5), eq("v", 1)))?,
+        PointLookupResult::Rows(rows_of(5))
+    );
+    assert_eq!(
+        apply(&index, &key, and(eq("id", 500), eq("v", 1)))?,
+        PointLookupResult::MustFalse
+    );
+    assert_eq!(
+        apply(&index, &key, or(eq("id", 5), eq("v", 1)))?,
+        PointLookupResult::Uncertain
+    );
+    assert_eq!(
+        apply(&index, &key, eq("v", 1))?,
+        PointLookupResult::Uncertain
+    );
+    Ok(())
+}
+
+fn apply(
+    index: &PointLookupIndex,
+    key: &TableField,
+    expr: Expr<String>,
+) -> Result<PointLookupResult> {
+    let func_ctx = FunctionContext::default();
+    let mut scalar_map = HashMap::new();
+    for (scalar, ty) in PointLookupIndex::find_eq_scalars(&expr, key)? {
+        let digest = BloomIndex::calculate_scalar_digest(&func_ctx, &scalar, &ty)?;
+        scalar_map.insert(scalar, digest);
+    }
+    index.apply(&func_ctx, &expr, key.name(), &scalar_map)
+}
+
+fn eq(col_name: &str, val: u64) -> Expr<String> {
+    let ty = DataType::Number(NumberDataType::UInt64);
+    call("eq", vec![
+        Expr::ColumnRef {
+            span: None,
+            id: col_name.to_string(),
+            data_type: ty.clone(),
+            display_name: col_name.to_string(),
+        },
+        Expr::Constant {
+            span: None,
+            scalar: Scalar::Number(NumberScalar::UInt64(val)),
+            data_type: ty,
+        },
+    ])
+}
+
+fn and(lhs: Expr<String>, rhs: Expr<String>) -> Expr<String> {
+    call("and", vec![lhs, rhs])
+}
+
+fn or(lhs: Expr<String>, rhs: Expr<String>) -> Expr<String> {
+    call("or", vec![lhs, rhs])
+}
+
+fn call(name: &str, args: Vec<Expr<String>>) -> Expr<String> {
+    check_function(None, name, &[], &args, &BUILTIN_FUNCTIONS).unwrap()
+}
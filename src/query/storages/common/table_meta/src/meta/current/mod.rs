@@ -13,6 +13,8 @@
 // limitations under the License.
 
 pub use v0::ColumnMeta as SingleColumnMeta;
+pub use v1::ColumnHistogram;
+pub use v1::ColumnHistogramBucket;
 pub use v1::TableSnapshotStatistics;
 pub use v2::BlockMeta;
 pub use v2::ClusterStatistics;
@@ -31,14 +31,30 @@ pub struct TableSnapshotStatistics {
     pub snapshot_id: SnapshotId,
 
     pub column_distinct_values: HashMap<ColumnId, u64>,
+
+    /// number of rows of the snapshot that the statistics are built from,
+    /// `None` if the statistics are written by an older version.
+    #[serde(default)]
+    pub row_count: Option<u64>,
+
+    /// equi-depth histograms of the columns, only the numeric, date and timestamp
+    /// columns have histograms.
+    #[serde(default)]
+    pub column_histograms: HashMap<ColumnId, ColumnHistogram>,
 }
 
 impl TableSnapshotStatistics {
-    pub fn new(column_distinct_values: HashMap<ColumnId, u64>) -> Self {
+    pub fn new(
+        column_distinct_values: HashMap<ColumnId, u64>,
+        column_histograms: HashMap<ColumnId, ColumnHistogram>,
+        row_count: u64,
+    ) -> Self {
         Self {
             format_version: TableSnapshotStatistics::VERSION,
             snapshot_id: SnapshotId::new_v4(),
             column_distinct_values,
+            row_count: Some(row_count),
+            column_histograms,
         }
     }
 
@@ -50,3 +66,23 @@ impl TableSnapshotStatistics {
         &self.column_distinct_values
     }
 }
+
+/// An equi-depth histogram of the non-null values of a column, every bucket holds
+/// roughly the same number of values.
+///
+/// The bounds are kept as `f64`, dates and timestamps are days and microseconds
+/// since the epoch.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ColumnHistogram {
+    /// lower bound of the first bucket, i.e. the min value of the column.
+    pub lower_bound: f64,
+    pub buckets: Vec<ColumnHistogramBucket>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ColumnHistogramBucket {
+    /// upper bound of the bucket (inclusive), which is the lower bound of the next one.
+    pub upper_bound: f64,
+    pub num_values: u64,
+    pub num_distinct: u64,
+}
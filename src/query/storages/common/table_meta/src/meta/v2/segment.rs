@@ -80,6 +80,10 @@ pub struct BlockMeta {
 
     // block create_on
     pub create_on: Option<DateTime<Utc>>,
+
+    /// location of the point lookup index of the key column, see `point_lookup_column`
+    #[serde(default)]
+    pub point_lookup_index_location: Option<Location>,
 }
 
 impl BlockMeta {
@@ -109,6 +113,7 @@ impl BlockMeta {
             bloom_filter_index_size,
             compression,
             create_on,
+            point_lookup_index_location: None,
         }
     }
 
@@ -254,6 +259,7 @@ impl BlockMeta {
             bloom_filter_index_size: 0,
             compression: Compression::Lz4,
             create_on: None,
+            point_lookup_index_location: None,
         }
     }
 
@@ -285,6 +291,7 @@ impl BlockMeta {
             bloom_filter_index_size: s.bloom_filter_index_size,
             compression: s.compression,
             create_on: None,
+            point_lookup_index_location: None,
         }
     }
 }
@@ -39,6 +39,10 @@ pub struct ClusterStatistics {
 
     // currently it's only used in native engine
     pub pages: Option<Vec<Scalar>>,
+
+    // The bucket of the rows if the table is bucketed and all the rows belong to the same bucket.
+    #[serde(default)]
+    pub bucket: Option<u32>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq, Default)]
@@ -112,9 +116,15 @@ impl ClusterStatistics {
             max,
             level,
             pages,
+            bucket: None,
         }
     }
 
+    pub fn with_bucket(mut self, bucket: Option<u32>) -> Self {
+        self.bucket = bucket;
+        self
+    }
+
     pub fn min(&self) -> Vec<Scalar> {
         self.min.clone()
     }
@@ -146,6 +156,7 @@ impl ClusterStatistics {
                 .collect(),
             level: v0.level,
             pages: None,
+            bucket: None,
         }
     }
 }
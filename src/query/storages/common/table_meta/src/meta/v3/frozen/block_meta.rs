@@ -62,6 +62,7 @@ impl From<BlockMeta> for crate::meta::BlockMeta {
             bloom_filter_index_size: value.bloom_filter_index_size,
             compression: value.compression.into(),
             create_on: None,
+            point_lookup_index_location: None,
         }
     }
 }
@@ -177,6 +178,7 @@ impl From<ClusterStatistics> for crate::meta::ClusterStatistics {
             max: value.max,
             level: value.level,
             pages: value.pages,
+            bucket: None,
         }
     }
 }
@@ -154,6 +154,10 @@ mod converters {
                 common_expression::types::number::NumberDataType::Int64 => Self::Int64,
                 common_expression::types::number::NumberDataType::Float32 => Self::Float32,
                 common_expression::types::number::NumberDataType::Float64 => Self::Float64,
+                common_expression::types::number::NumberDataType::UInt128
+                | common_expression::types::number::NumberDataType::Int128 => {
+                    unreachable!("128-bit integers are not supported by the v3 table schema")
+                }
             }
         }
     }
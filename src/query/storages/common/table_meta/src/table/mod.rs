@@ -12,10 +12,23 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod table_aggregating;
 mod table_compression;
+mod table_constraint;
+mod table_crypto_shred;
+mod table_dedup;
 mod table_keys;
 mod table_prefix;
+mod table_sequence;
 
+pub use table_aggregating::AggregatingColumns;
 pub use table_compression::TableCompression;
+pub use table_constraint::TableConstraint;
+pub use table_constraint::TableConstraints;
+pub use table_crypto_shred::CryptoShredColumns;
+pub use table_dedup::DedupColumns;
 pub use table_keys::*;
 pub use table_prefix::*;
+pub use table_sequence::SequenceColumn;
+pub use table_sequence::SequenceColumns;
+pub use table_sequence::AUTO_INCREMENT_SEQUENCE_PREFIX;
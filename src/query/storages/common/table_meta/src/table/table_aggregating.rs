@@ -0,0 +1,110 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::TableSchema;
+
+use crate::table::OPT_KEY_AGGREGATING_FUNCTIONS;
+use crate::table::OPT_KEY_AGGREGATING_KEYS;
+
+/// The group-by keys and the aggregate functions the rows of an aggregating table are
+/// merged by when its blocks are compacted, e.g.
+/// `aggregating_keys = 'site, day'` and `aggregating_functions = 'sum(hits), max(last_seen)'`.
+///
+/// The columns without an aggregate function keep the value of any row of the group.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AggregatingColumns {
+    pub keys: Vec<String>,
+    /// Pairs of the aggregated column and the aggregate function name.
+    pub functions: Vec<(String, String)>,
+}
+
+impl AggregatingColumns {
+    pub fn from_options(options: &BTreeMap<String, String>) -> Result<Option<Self>> {
+        let keys = match options.get(OPT_KEY_AGGREGATING_KEYS) {
+            Some(value) => split_list(value),
+            None => vec![],
+        };
+        let functions = match options.get(OPT_KEY_AGGREGATING_FUNCTIONS) {
+            Some(value) => split_list(value)
+                .into_iter()
+                .map(|item| parse_function(&item))
+                .collect::<Result<Vec<_>>>()?,
+            None => vec![],
+        };
+        match (keys.is_empty(), functions.is_empty()) {
+            (true, true) => Ok(None),
+            (false, false) => Ok(Some(Self { keys, functions })),
+            _ => Err(ErrorCode::TableOptionInvalid(format!(
+                "table options {} and {} must be set together",
+                OPT_KEY_AGGREGATING_KEYS, OPT_KEY_AGGREGATING_FUNCTIONS
+            ))),
+        }
+    }
+
+    /// The keys and the aggregated columns must exist, and a column can only be
+    /// either a key or aggregated by one function.
+    pub fn check_schema(&self, schema: &TableSchema) -> Result<()> {
+        let columns = self
+            .keys
+            .iter()
+            .chain(self.functions.iter().map(|(column, _)| column));
+        for (i, column) in columns.enumerate() {
+            schema.field_with_name(column).map_err(|_| {
+                ErrorCode::TableOptionInvalid(format!(
+                    "aggregating column {} does not exist",
+                    column
+                ))
+            })?;
+            let duplicated = self
+                .keys
+                .iter()
+                .chain(self.functions.iter().map(|(column, _)| column))
+                .take(i)
+                .any(|c| c == column);
+            if duplicated {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "aggregating column {} is specified more than once",
+                    column
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn split_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|c| c.trim().to_string())
+        .filter(|c| !c.is_empty())
+        .collect()
+}
+
+// Parses `func(column)`.
+fn parse_function(item: &str) -> Result<(String, String)> {
+    item.strip_suffix(')')
+        .and_then(|item| item.split_once('('))
+        .map(|(func, column)| (column.trim().to_string(), func.trim().to_lowercase()))
+        .filter(|(column, func)| !column.is_empty() && !func.is_empty())
+        .ok_or_else(|| {
+            ErrorCode::TableOptionInvalid(format!(
+                "invalid {} option {}, expect `function(column)`",
+                OPT_KEY_AGGREGATING_FUNCTIONS, item
+            ))
+        })
+}
@@ -0,0 +1,137 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::ColumnId;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::table::OPT_KEY_CONSTRAINTS;
+
+/// A constraint declared on a table.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub enum TableConstraint {
+    /// Enforced at write time: the rows making the expression false are rejected,
+    /// the ones making it NULL are accepted.
+    Check {
+        /// The SQL text of the boolean expression.
+        expr: String,
+        /// The columns referenced by the expression.
+        columns: Vec<ColumnId>,
+    },
+    /// Informational only, the uniqueness is trusted by the optimizer but not enforced.
+    Unique { columns: Vec<ColumnId> },
+    /// Informational only, the same as `Unique`.
+    PrimaryKey { columns: Vec<ColumnId> },
+    /// Informational only, every row having no NULL in `columns` is trusted to match
+    /// exactly one row of the referenced table in `ref_columns`.
+    ForeignKey {
+        columns: Vec<ColumnId>,
+        ref_table_id: u64,
+        /// The names of the referenced database and table when the constraint is declared.
+        ref_database: String,
+        ref_table: String,
+        ref_columns: Vec<ColumnId>,
+    },
+}
+
+impl TableConstraint {
+    pub fn columns(&self) -> &[ColumnId] {
+        match self {
+            TableConstraint::Check { columns, .. }
+            | TableConstraint::Unique { columns }
+            | TableConstraint::PrimaryKey { columns }
+            | TableConstraint::ForeignKey { columns, .. } => columns,
+        }
+    }
+
+    /// The columns whose values identify a row, if the constraint is a unique key.
+    pub fn unique_columns(&self) -> Option<&[ColumnId]> {
+        match self {
+            TableConstraint::Check { .. } | TableConstraint::ForeignKey { .. } => None,
+            TableConstraint::Unique { columns } | TableConstraint::PrimaryKey { columns } => {
+                Some(columns)
+            }
+        }
+    }
+}
+
+/// The constraints of a table by their names, kept as json in the table option `constraints`.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct TableConstraints(pub BTreeMap<String, TableConstraint>);
+
+impl TableConstraints {
+    pub fn from_options(options: &BTreeMap<String, String>) -> Result<Self> {
+        match options.get(OPT_KEY_CONSTRAINTS) {
+            Some(value) => serde_json::from_str(value).map_err(|e| {
+                ErrorCode::TableOptionInvalid(format!(
+                    "invalid table option {}: {}",
+                    OPT_KEY_CONSTRAINTS, e
+                ))
+            }),
+            None => Ok(Self::default()),
+        }
+    }
+
+    pub fn to_option_value(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn get(&self, name: &str) -> Option<&TableConstraint> {
+        self.0.get(name)
+    }
+
+    /// The names and the expressions of the CHECK constraints.
+    pub fn checks(&self) -> impl Iterator<Item = (&String, &String)> {
+        self.0
+            .iter()
+            .filter_map(|(name, constraint)| match constraint {
+                TableConstraint::Check { expr, .. } => Some((name, expr)),
+                _ => None,
+            })
+    }
+
+    pub fn unique_keys(&self) -> impl Iterator<Item = &[ColumnId]> {
+        self.0
+            .values()
+            .filter_map(|constraint| constraint.unique_columns())
+    }
+
+    pub fn foreign_keys(&self) -> impl Iterator<Item = &TableConstraint> {
+        self.0
+            .values()
+            .filter(|constraint| matches!(constraint, TableConstraint::ForeignKey { .. }))
+    }
+
+    pub fn has_primary_key(&self) -> bool {
+        self.0
+            .values()
+            .any(|constraint| matches!(constraint, TableConstraint::PrimaryKey { .. }))
+    }
+
+    /// The name of a constraint referencing the column, if any.
+    pub fn referencing_column(&self, column_id: ColumnId) -> Option<&String> {
+        self.0
+            .iter()
+            .find(|(_, constraint)| constraint.columns().contains(&column_id))
+            .map(|(name, _)| name)
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::TableDataType;
+use common_expression::TableSchema;
+
+use crate::table::OPT_KEY_CRYPTO_SHRED_COLUMNS;
+use crate::table::OPT_KEY_SUBJECT_KEY_COLUMN;
+
+/// The crypto-shredded columns of a table, set by the table options `crypto_shred_columns`
+/// and `subject_key_column`.
+///
+/// The values of a crypto-shredded column are encrypted by the data key of the subject in the
+/// subject key column of the same row. Once the key of a subject is dropped, its values are
+/// read as NULL in all the tables.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CryptoShredColumns {
+    pub subject_column: String,
+    pub columns: Vec<String>,
+}
+
+impl CryptoShredColumns {
+    pub fn from_options(options: &BTreeMap<String, String>) -> Result<Option<Self>> {
+        let columns = match options.get(OPT_KEY_CRYPTO_SHRED_COLUMNS) {
+            Some(value) => value
+                .split(',')
+                .map(|c| c.trim().to_string())
+                .filter(|c| !c.is_empty())
+                .collect::<Vec<_>>(),
+            None => vec![],
+        };
+        let subject_column = options.get(OPT_KEY_SUBJECT_KEY_COLUMN);
+        match (columns.is_empty(), subject_column) {
+            (true, None) => Ok(None),
+            (false, Some(subject_column)) => Ok(Some(Self {
+                subject_column: subject_column.trim().to_string(),
+                columns,
+            })),
+            _ => Err(ErrorCode::TableOptionInvalid(format!(
+                "table options {} and {} must be set together",
+                OPT_KEY_CRYPTO_SHRED_COLUMNS, OPT_KEY_SUBJECT_KEY_COLUMN
+            ))),
+        }
+    }
+
+    pub fn contains(&self, column: &str) -> bool {
+        self.columns.iter().any(|c| c == column)
+    }
+
+    /// The subject key column must be a string column, and the crypto-shredded columns
+    /// must be nullable string columns, NULL is read for the erased values.
+    pub fn check_schema(&self, schema: &TableSchema) -> Result<()> {
+        let field = schema.field_with_name(&self.subject_column).map_err(|_| {
+            ErrorCode::TableOptionInvalid(format!(
+                "subject key column {} does not exist",
+                self.subject_column
+            ))
+        })?;
+        if field.data_type().remove_nullable() != TableDataType::String {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "subject key column {} must be of type String, but got {}",
+                self.subject_column,
+                field.data_type()
+            )));
+        }
+
+        for (i, column) in self.columns.iter().enumerate() {
+            if column == &self.subject_column {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "subject key column {} can not be crypto-shredded",
+                    column
+                )));
+            }
+            if self.columns[..i].contains(column) {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "duplicated crypto-shredded column {}",
+                    column
+                )));
+            }
+            let field = schema.field_with_name(column).map_err(|_| {
+                ErrorCode::TableOptionInvalid(format!(
+                    "crypto-shredded column {} does not exist",
+                    column
+                ))
+            })?;
+            if field.data_type() != &TableDataType::Nullable(Box::new(TableDataType::String)) {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "crypto-shredded column {} must be of type Nullable(String), but got {}",
+                    column,
+                    field.data_type()
+                )));
+            }
+        }
+        Ok(())
+    }
+}
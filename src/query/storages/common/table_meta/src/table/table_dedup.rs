@@ -0,0 +1,86 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::TableSchema;
+
+use crate::table::OPT_KEY_DEDUP_KEYS;
+use crate::table::OPT_KEY_DEDUP_VERSION_COLUMN;
+
+/// The unique keys of a table and the version column deciding the latest row among the
+/// rows sharing the same keys, e.g. `dedup_keys = 'id'` and `dedup_version_column = 'ts'`.
+///
+/// Reads collapse the duplicated rows to the latest one, and compaction drops the older ones.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DedupColumns {
+    pub keys: Vec<String>,
+    pub version_column: String,
+}
+
+impl DedupColumns {
+    pub fn from_options(options: &BTreeMap<String, String>) -> Result<Option<Self>> {
+        let keys = match options.get(OPT_KEY_DEDUP_KEYS) {
+            Some(value) => value
+                .split(',')
+                .map(|c| c.trim().to_string())
+                .filter(|c| !c.is_empty())
+                .collect::<Vec<_>>(),
+            None => vec![],
+        };
+        let version_column = options.get(OPT_KEY_DEDUP_VERSION_COLUMN);
+        match (keys.is_empty(), version_column) {
+            (true, None) => Ok(None),
+            (false, Some(version_column)) => Ok(Some(Self {
+                keys,
+                version_column: version_column.trim().to_string(),
+            })),
+            _ => Err(ErrorCode::TableOptionInvalid(format!(
+                "table options {} and {} must be set together",
+                OPT_KEY_DEDUP_KEYS, OPT_KEY_DEDUP_VERSION_COLUMN
+            ))),
+        }
+    }
+
+    /// The keys and the version column must exist, and the version column can't be a key.
+    pub fn check_schema(&self, schema: &TableSchema) -> Result<()> {
+        for (i, key) in self.keys.iter().enumerate() {
+            schema.field_with_name(key).map_err(|_| {
+                ErrorCode::TableOptionInvalid(format!("dedup key {} does not exist", key))
+            })?;
+            if self.keys[..i].contains(key) {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "duplicated dedup key {}",
+                    key
+                )));
+            }
+        }
+
+        schema.field_with_name(&self.version_column).map_err(|_| {
+            ErrorCode::TableOptionInvalid(format!(
+                "dedup version column {} does not exist",
+                self.version_column
+            ))
+        })?;
+        if self.keys.contains(&self.version_column) {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "dedup version column {} can not be a dedup key",
+                self.version_column
+            )));
+        }
+        Ok(())
+    }
+}
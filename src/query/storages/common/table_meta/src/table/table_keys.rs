@@ -24,12 +24,53 @@ pub const OPT_KEY_COMMENT: &str = "comment";
 pub const OPT_KEY_ENGINE: &str = "engine";
 pub const OPT_KEY_BLOOM_INDEX_COLUMNS: &str = "bloom_index_columns";
 pub const OPT_KEY_CHANGE_TRACKING: &str = "change_tracking";
+/// Json encoded [`crate::table::SequenceColumns`] of a table.
+pub const OPT_KEY_SEQUENCE_COLUMNS: &str = "sequence_columns";
+/// Json encoded [`crate::table::TableConstraints`] of a table.
+pub const OPT_KEY_CONSTRAINTS: &str = "constraints";
+/// Boolean predicate on the columns of a table, the rows matching it are expired and deleted
+/// by the background row TTL job. `now()` in it is the time the job runs.
+pub const OPT_KEY_ROW_TTL: &str = "row_ttl";
+/// Comma separated columns of a table encrypted by the keys of the subjects, see
+/// [`crate::table::CryptoShredColumns`].
+pub const OPT_KEY_CRYPTO_SHRED_COLUMNS: &str = "crypto_shred_columns";
+/// The column of a table identifying the subject of a row.
+pub const OPT_KEY_SUBJECT_KEY_COLUMN: &str = "subject_key_column";
+/// Comma separated group-by keys of an aggregating table, see [`crate::table::AggregatingColumns`].
+pub const OPT_KEY_AGGREGATING_KEYS: &str = "aggregating_keys";
+/// Comma separated `function(column)` the rows of an aggregating table are merged by when
+/// its blocks are compacted, see [`crate::table::AggregatingColumns`].
+pub const OPT_KEY_AGGREGATING_FUNCTIONS: &str = "aggregating_functions";
+/// Comma separated unique keys of a table whose duplicated rows are collapsed to the latest one,
+/// see [`crate::table::DedupColumns`].
+pub const OPT_KEY_DEDUP_KEYS: &str = "dedup_keys";
+/// The column deciding the latest row among the rows sharing the same dedup keys.
+pub const OPT_KEY_DEDUP_VERSION_COLUMN: &str = "dedup_version_column";
+/// The column of a table looked up by equality, every block written gets a perfect hash index
+/// of it, locating the rows of a value without scanning the block.
+pub const OPT_KEY_POINT_LOOKUP_COLUMN: &str = "point_lookup_column";
+/// Snapshot location that a table is pinned to for the duration of a query.
+///
+/// Only set on the in-memory table info of tables whose snapshot location is not kept in
+/// the table meta, e.g. read-only attached tables, it is never committed to the meta.
+pub const OPT_KEY_PINNED_SNAPSHOT_LOCATION: &str = "pinned_snapshot_location";
 
 // Attached table options.
 pub const OPT_KEY_TABLE_ATTACHED_DATA_URI: &str = "table_data_uri";
 // Read only attached table options.
 pub const OPT_KEY_TABLE_ATTACHED_READ_ONLY: &str = "read_only_attached";
 
+// Replication table options.
+pub const OPT_KEY_REPLICATION_URI: &str = "replication_uri";
+pub const OPT_KEY_REPLICATION_CONNECTION: &str = "replication_connection";
+
+// Zero-copy clone table options.
+/// Id of the table which a clone table was created from.
+pub const OPT_KEY_CLONED_FROM: &str = "cloned_from";
+/// Snapshot locations of a table which are shared with its clones, and the ids of the clones
+/// referencing each of them, encoded as a JSON object.
+pub const OPT_KEY_CLONE_SHARED_SNAPSHOTS: &str = "clone_shared_snapshots";
+
 /// Legacy table snapshot location key
 ///
 /// # Deprecated
@@ -47,6 +88,11 @@ pub static RESERVED_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+    r.insert(OPT_KEY_CLONED_FROM);
+    r.insert(OPT_KEY_CLONE_SHARED_SNAPSHOTS);
+    r.insert(OPT_KEY_SEQUENCE_COLUMNS);
+    r.insert(OPT_KEY_CONSTRAINTS);
+    r.insert(OPT_KEY_PINNED_SNAPSHOT_LOCATION);
     r
 });
 
@@ -55,6 +101,10 @@ pub static INTERNAL_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
     r.insert(OPT_KEY_DATABASE_ID);
+    r.insert(OPT_KEY_CLONED_FROM);
+    r.insert(OPT_KEY_CLONE_SHARED_SNAPSHOTS);
+    r.insert(OPT_KEY_SEQUENCE_COLUMNS);
+    r.insert(OPT_KEY_CONSTRAINTS);
     r
 });
 
@@ -0,0 +1,73 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::ColumnId;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::table::OPT_KEY_SEQUENCE_COLUMNS;
+
+/// The name prefix of the implicit sequences created for `AUTO_INCREMENT` columns.
+pub const AUTO_INCREMENT_SEQUENCE_PREFIX: &str = "_auto_increment_";
+
+/// A column whose values are generated by a sequence if they are not provided by `INSERT`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SequenceColumn {
+    pub sequence: String,
+    /// True if the sequence was created implicitly for an `AUTO_INCREMENT` column,
+    /// false for a column declared with `DEFAULT NEXTVAL(<sequence>)`.
+    pub auto_increment: bool,
+}
+
+/// The sequence columns of a table, kept as json in the table option `sequence_columns`.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct SequenceColumns(pub BTreeMap<ColumnId, SequenceColumn>);
+
+impl SequenceColumns {
+    pub fn from_options(options: &BTreeMap<String, String>) -> Result<Self> {
+        match options.get(OPT_KEY_SEQUENCE_COLUMNS) {
+            Some(value) => serde_json::from_str(value).map_err(|e| {
+                ErrorCode::TableOptionInvalid(format!(
+                    "invalid table option {}: {}",
+                    OPT_KEY_SEQUENCE_COLUMNS, e
+                ))
+            }),
+            None => Ok(Self::default()),
+        }
+    }
+
+    pub fn to_option_value(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn get(&self, column_id: ColumnId) -> Option<&SequenceColumn> {
+        self.0.get(&column_id)
+    }
+
+    /// The implicit sequences owned by the table, which are removed together with its metadata.
+    pub fn auto_increment_sequences(&self) -> impl Iterator<Item = &str> {
+        self.0
+            .values()
+            .filter(|column| column.auto_increment)
+            .map(|column| column.sequence.as_str())
+    }
+}
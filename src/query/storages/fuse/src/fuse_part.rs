@@ -45,6 +45,7 @@ pub struct FusePartInfo {
 
     pub sort_min_max: Option<(Scalar, Scalar)>,
     pub block_meta_index: Option<BlockMetaIndex>,
+    pub bucket: Option<u32>,
 }
 
 #[typetag::serde(name = "fuse")]
@@ -64,9 +65,14 @@ impl PartInfo for FusePartInfo {
         self.location.hash(&mut s);
         s.finish()
     }
+
+    fn bucket(&self) -> Option<u32> {
+        self.bucket
+    }
 }
 
 impl FusePartInfo {
+    #[allow(clippy::too_many_arguments)]
     pub fn create(
         location: String,
         rows_count: u64,
@@ -75,6 +81,7 @@ impl FusePartInfo {
         sort_min_max: Option<(Scalar, Scalar)>,
         block_meta_index: Option<BlockMetaIndex>,
         create_on: Option<DateTime<Utc>>,
+        bucket: Option<u32>,
     ) -> Arc<Box<dyn PartInfo>> {
         Arc::new(Box::new(FusePartInfo {
             location,
@@ -84,6 +91,7 @@ impl FusePartInfo {
             compression,
             sort_min_max,
             block_meta_index,
+            bucket,
         }))
     }
 
@@ -14,6 +14,7 @@
 
 use std::any::Any;
 use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::str;
 use std::str::FromStr;
 use std::sync::Arc;
@@ -22,16 +23,22 @@ use common_catalog::catalog::StorageDescription;
 use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::PartStatistics;
 use common_catalog::plan::Partitions;
+use common_catalog::plan::PredicatePruning;
 use common_catalog::plan::PushDownInfo;
 use common_catalog::plan::StreamColumn;
 use common_catalog::table::AppendMode;
 use common_catalog::table::ColumnStatisticsProvider;
 use common_catalog::table::NavigationDescriptor;
+use common_catalog::table::PinnedSnapshot;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::BlockThresholds;
+use common_expression::ColumnId;
+use common_expression::FieldIndex;
 use common_expression::RemoteExpr;
+use common_expression::TableField;
+use common_expression::TableSchema;
 use common_expression::ORIGIN_BLOCK_ID_COL_NAME;
 use common_expression::ORIGIN_BLOCK_ROW_NUM_COL_NAME;
 use common_expression::ORIGIN_VERSION_COL_NAME;
@@ -67,6 +74,8 @@ use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 use storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
+use storages_common_table_meta::table::OPT_KEY_PINNED_SNAPSHOT_LOCATION;
+use storages_common_table_meta::table::OPT_KEY_POINT_LOOKUP_COLUMN;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
@@ -326,6 +335,14 @@ impl FuseTable {
 
     #[async_backtrace::framed]
     pub async fn snapshot_loc(&self) -> Result<Option<String>> {
+        if let Some(loc) = self
+            .table_info
+            .options()
+            .get(OPT_KEY_PINNED_SNAPSHOT_LOCATION)
+        {
+            return Ok(Some(loc.clone()));
+        }
+
         match self.table_info.db_type {
             DatabaseType::ShareDB(_) => {
                 let url = FUSE_TBL_LAST_SNAPSHOT_HINT;
@@ -398,10 +415,59 @@ impl FuseTable {
         self.cluster_key_meta.clone()
     }
 
+    /// The expressions of every version of the cluster key by the cluster key id, including
+    /// the dropped ones, as the blocks written with them keep their cluster statistics.
+    ///
+    /// The versions referring to the columns that don't exist anymore are skipped.
+    pub fn cluster_key_history(
+        &self,
+        ctx: Arc<dyn TableContext>,
+    ) -> Vec<(u32, Vec<RemoteExpr<String>>)> {
+        self.table_info
+            .meta
+            .cluster_keys
+            .iter()
+            .enumerate()
+            .filter_map(|(id, order)| {
+                let cluster_keys = self.parse_cluster_key(ctx.clone(), order).ok()?;
+                Some((id as u32, cluster_keys))
+            })
+            .collect()
+    }
+
+    fn parse_cluster_key(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        order: &str,
+    ) -> Result<Vec<RemoteExpr<String>>> {
+        let table_meta = Arc::new(self.clone());
+        let cluster_keys = parse_exprs(ctx, table_meta.clone(), order)?;
+        let cluster_keys = if cluster_keys.len() == 1 {
+            unwrap_tuple(&cluster_keys[0]).unwrap_or(cluster_keys)
+        } else {
+            cluster_keys
+        };
+        Ok(cluster_keys
+            .iter()
+            .map(|k| {
+                k.project_column_ref(|index| table_meta.schema().field(*index).name().to_string())
+                    .as_remote_expr()
+            })
+            .collect())
+    }
+
     pub fn bloom_index_cols(&self) -> BloomIndexColumns {
         self.bloom_index_cols.clone()
     }
 
+    /// The field of the `point_lookup_column` option and its index in `schema`,
+    /// if the option is set and the column is in `schema`.
+    pub fn point_lookup_field(&self, schema: &TableSchema) -> Option<(FieldIndex, TableField)> {
+        let name = self.table_info.options().get(OPT_KEY_POINT_LOOKUP_COLUMN)?;
+        let index = schema.index_of(name).ok()?;
+        Some((index, schema.field(index).clone()))
+    }
+
     // Check if table is attached.
     fn is_table_attached(table_meta_options: &BTreeMap<String, String>) -> bool {
         table_meta_options
@@ -410,7 +476,9 @@ impl FuseTable {
     }
 
     // Check if table is read-only attached.
-    fn is_table_attached_read_only(table_meta_options: &BTreeMap<String, String>) -> bool {
+    pub(crate) fn is_table_attached_read_only(
+        table_meta_options: &BTreeMap<String, String>,
+    ) -> bool {
         table_meta_options
             .get(OPT_KEY_TABLE_ATTACHED_READ_ONLY)
             .is_some()
@@ -444,24 +512,8 @@ impl Table for FuseTable {
     }
 
     fn cluster_keys(&self, ctx: Arc<dyn TableContext>) -> Vec<RemoteExpr<String>> {
-        let table_meta = Arc::new(self.clone());
         if let Some((_, order)) = &self.cluster_key_meta {
-            let cluster_keys = parse_exprs(ctx, table_meta.clone(), order).unwrap();
-            let cluster_keys = if cluster_keys.len() == 1 {
-                unwrap_tuple(&cluster_keys[0]).unwrap_or(cluster_keys)
-            } else {
-                cluster_keys
-            };
-            let cluster_keys = cluster_keys
-                .iter()
-                .map(|k| {
-                    k.project_column_ref(|index| {
-                        table_meta.schema().field(*index).name().to_string()
-                    })
-                    .as_remote_expr()
-                })
-                .collect();
-            return cluster_keys;
+            return self.parse_cluster_key(ctx, order).unwrap();
         }
         vec![]
     }
@@ -605,6 +657,15 @@ impl Table for FuseTable {
         self.do_read_partitions(ctx, push_downs, dry_run).await
     }
 
+    #[async_backtrace::framed]
+    async fn explain_pruning(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+    ) -> Result<Vec<PredicatePruning>> {
+        self.do_explain_pruning(ctx, push_downs).await
+    }
+
     #[minitrace::trace]
     fn read_data(
         &self,
@@ -681,6 +742,11 @@ impl Table for FuseTable {
         self.do_analyze(&ctx).await
     }
 
+    #[async_backtrace::framed]
+    async fn bucket_num(&self, ctx: Arc<dyn TableContext>) -> Result<Option<u32>> {
+        self.do_bucket_num(ctx).await
+    }
+
     async fn table_statistics(&self) -> Result<Option<TableStatistics>> {
         let stats = match self.table_type {
             FuseTableType::AttachedReadOnly => {
@@ -724,12 +790,14 @@ impl Table for FuseTable {
                 FuseTableColumnStatisticsProvider::new(
                     stats.clone(),
                     Some(table_statistics.column_distinct_values.clone()),
+                    table_statistics.column_histograms.clone(),
                     snapshot.summary.row_count,
                 )
             } else {
                 FuseTableColumnStatisticsProvider::new(
                     stats.clone(),
                     None,
+                    HashMap::new(),
                     snapshot.summary.row_count,
                 )
             }
@@ -761,6 +829,42 @@ impl Table for FuseTable {
         }
     }
 
+    #[async_backtrace::framed]
+    async fn pin_snapshot(&self) -> Result<Option<PinnedSnapshot>> {
+        let Some(location) = self.snapshot_loc().await? else {
+            return Ok(None);
+        };
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        let ver = self.snapshot_format_version(Some(location.clone())).await?;
+        let params = LoadParams {
+            location: location.clone(),
+            len_hint: None,
+            ver,
+            put_cache: true,
+        };
+        let snapshot = reader.read(&params).await?;
+
+        // The snapshot location of read only tables is read from the last snapshot hint
+        // each time, keep the location that was read here instead.
+        let table = match self.table_type {
+            FuseTableType::SharedReadOnly | FuseTableType::AttachedReadOnly => {
+                let mut table_info = self.table_info.clone();
+                table_info
+                    .meta
+                    .options
+                    .insert(OPT_KEY_PINNED_SNAPSHOT_LOCATION.to_owned(), location);
+                let table: Arc<dyn Table> = FuseTable::do_create(table_info)?.into();
+                Some(table)
+            }
+            _ => None,
+        };
+
+        Ok(Some(PinnedSnapshot {
+            snapshot_id: snapshot.snapshot_id.simple().to_string(),
+            table,
+        }))
+    }
+
     fn get_block_thresholds(&self) -> BlockThresholds {
         let max_rows_per_block =
             self.get_option(FUSE_OPT_KEY_ROW_PER_BLOCK, DEFAULT_BLOCK_MAX_ROWS);
@@ -815,6 +919,10 @@ impl Table for FuseTable {
         true
     }
 
+    fn support_internal_column(&self, _column_id: ColumnId) -> bool {
+        true
+    }
+
     fn result_can_be_cached(&self) -> bool {
         true
     }
@@ -17,20 +17,30 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use chrono::Utc;
-use common_arrow::arrow::chunk::Chunk as ArrowChunk;
 use common_arrow::native::write::NativeWriter;
 use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::nullable::NullableColumn;
+use common_expression::types::timestamp::check_timestamp_in_precision;
+use common_expression::types::timestamp::truncate_timestamp;
+use common_expression::BlockEntry;
+use common_expression::Column;
 use common_expression::ColumnId;
 use common_expression::DataBlock;
 use common_expression::FieldIndex;
+use common_expression::Scalar;
+use common_expression::TableDataType;
 use common_expression::TableField;
+use common_expression::TableSchema;
 use common_expression::TableSchemaRef;
+use common_expression::Value;
 use common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use common_io::constants::DEFAULT_BLOCK_INDEX_BUFFER_SIZE;
 use opendal::Operator;
 use storages_common_blocks::blocks_to_parquet;
 use storages_common_index::BloomIndex;
+use storages_common_index::PointLookupIndex;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ClusterStatistics;
 use storages_common_table_meta::meta::ColumnMeta;
@@ -62,6 +72,7 @@ pub fn serialize_block(
         FuseStorageFormat::Native => {
             let arrow_schema = schema.to_arrow();
             let leaf_column_ids = schema.to_leaf_column_ids();
+            let batch = block.to_arrow_chunk(&arrow_schema)?;
 
             let mut default_compress_ratio = Some(2.10f64);
             if matches!(write_settings.table_compression, TableCompression::Zstd) {
@@ -79,8 +90,6 @@ pub fn serialize_block(
                 },
             );
 
-            let batch = ArrowChunk::try_from(block)?;
-
             writer.start()?;
             writer.write(&batch)?;
             writer.finish()?;
@@ -97,6 +106,68 @@ pub fn serialize_block(
     }
 }
 
+/// Round the timestamps down to the declared precision of the fields, so that the statistics
+/// and the indexes are built from the values as they are stored.
+fn truncate_timestamp_columns(data_block: DataBlock, schema: &TableSchema) -> Result<DataBlock> {
+    let precisions = schema
+        .remove_virtual_computed_fields()
+        .fields()
+        .iter()
+        .map(|f| match f.data_type().remove_nullable() {
+            TableDataType::TimestampWithPrecision(precision) => Some(precision),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    if precisions.iter().all(Option::is_none) {
+        return Ok(data_block);
+    }
+
+    fn truncate_column(column: &Column, precision: u8) -> Result<Column> {
+        match column {
+            Column::Timestamp(values) => {
+                let values = values
+                    .iter()
+                    .map(|v| {
+                        check_timestamp_in_precision(*v, precision)
+                            .map(|v| truncate_timestamp(v, precision))
+                    })
+                    .collect::<std::result::Result<Vec<_>, _>>()
+                    .map_err(ErrorCode::BadArguments)?;
+                Ok(Column::Timestamp(values.into()))
+            }
+            Column::Nullable(col) => Ok(Column::Nullable(Box::new(NullableColumn {
+                column: truncate_column(&col.column, precision)?,
+                validity: col.validity.clone(),
+            }))),
+            _ => Ok(column.clone()),
+        }
+    }
+
+    let num_rows = data_block.num_rows();
+    let meta = data_block.get_meta().cloned();
+    let columns = data_block
+        .columns()
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let Some(precision) = precisions.get(i).copied().flatten() else {
+                return Ok(entry.clone());
+            };
+            let value = match &entry.value {
+                Value::Scalar(Scalar::Timestamp(v)) => {
+                    let v = check_timestamp_in_precision(*v, precision)
+                        .map_err(ErrorCode::BadArguments)?;
+                    Value::Scalar(Scalar::Timestamp(truncate_timestamp(v, precision)))
+                }
+                Value::Scalar(scalar) => Value::Scalar(scalar.clone()),
+                Value::Column(column) => Value::Column(truncate_column(column, precision)?),
+            };
+            Ok(BlockEntry::new(entry.data_type.clone(), value))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(DataBlock::new_with_meta(columns, num_rows, meta))
+}
+
 /// Take ownership here to avoid extra copy.
 #[async_backtrace::framed]
 pub async fn write_data(data: Vec<u8>, data_accessor: &Operator, location: &str) -> Result<()> {
@@ -150,11 +221,41 @@ impl BloomIndexState {
     }
 }
 
+pub struct PointLookupIndexState {
+    pub(crate) data: Vec<u8>,
+    pub(crate) location: Location,
+}
+
+impl PointLookupIndexState {
+    pub fn try_create(
+        ctx: Arc<dyn TableContext>,
+        block: &DataBlock,
+        location: Location,
+        (field_index, field): &(FieldIndex, TableField),
+    ) -> Result<Self> {
+        let entry = block.get_by_offset(*field_index);
+        let column = entry
+            .value
+            .convert_to_full_column(&entry.data_type, block.num_rows());
+        let index = PointLookupIndex::try_create(
+            &ctx.get_function_context()?,
+            field.column_id(),
+            &column,
+            &entry.data_type,
+        )?;
+        Ok(Self {
+            data: index.to_bytes(),
+            location,
+        })
+    }
+}
+
 pub struct BlockSerialization {
     pub block_raw_data: Vec<u8>,
     pub size: u64, // TODO redundancy
     pub block_meta: BlockMeta,
     pub bloom_index_state: Option<BloomIndexState>,
+    pub point_lookup_index_state: Option<PointLookupIndexState>,
 }
 
 #[derive(Clone)]
@@ -165,12 +266,14 @@ pub struct BlockBuilder {
     pub write_settings: WriteSettings,
     pub cluster_stats_gen: ClusterStatsGenerator,
     pub bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+    pub point_lookup_field: Option<(FieldIndex, TableField)>,
 }
 
 impl BlockBuilder {
     pub fn build<F>(&self, data_block: DataBlock, f: F) -> Result<BlockSerialization>
     where F: Fn(DataBlock, &ClusterStatsGenerator) -> Result<(Option<ClusterStatistics>, DataBlock)>
     {
+        let data_block = truncate_timestamp_columns(data_block, &self.source_schema)?;
         let (cluster_stats, data_block) = f(data_block, &self.cluster_stats_gen)?;
         let (block_location, block_id) = self.meta_locations.gen_block_location();
 
@@ -185,6 +288,20 @@ impl BlockBuilder {
             .as_ref()
             .map(|i| i.column_distinct_count.clone());
 
+        let point_lookup_index_state = self
+            .point_lookup_field
+            .as_ref()
+            .map(|field| {
+                PointLookupIndexState::try_create(
+                    self.ctx.clone(),
+                    &data_block,
+                    self.meta_locations
+                        .block_point_lookup_index_location(&block_id),
+                    field,
+                )
+            })
+            .transpose()?;
+
         let row_count = data_block.num_rows() as u64;
         let block_size = data_block.memory_size() as u64;
         let col_stats =
@@ -213,6 +330,9 @@ impl BlockBuilder {
                 .unwrap_or_default(),
             compression: self.write_settings.table_compression.try_into()?,
             create_on: Some(Utc::now()),
+            point_lookup_index_location: point_lookup_index_state
+                .as_ref()
+                .map(|v| v.location.clone()),
         };
 
         let serialized = BlockSerialization {
@@ -220,6 +340,7 @@ impl BlockBuilder {
             size: file_size,
             block_meta,
             bloom_index_state,
+            point_lookup_index_state,
         };
         Ok(serialized)
     }
@@ -26,8 +26,11 @@ use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::TableSnapshotStatistics;
 
 use crate::io::SegmentsIO;
+use crate::statistics::histogram_value_of;
 use crate::statistics::reduce_block_statistics;
 use crate::statistics::reduce_cluster_statistics;
+use crate::statistics::HistogramBuilder;
+use crate::statistics::DEFAULT_HISTOGRAM_BUCKETS;
 use crate::FuseTable;
 
 impl FuseTable {
@@ -60,6 +63,18 @@ impl FuseTable {
             let mut col_stats = HashMap::new();
             let mut cluster_stats = None;
 
+            // histograms are built for the columns whose values can be ordered as numbers
+            let mut histogram_builders: HashMap<_, _> = snapshot
+                .summary
+                .col_stats
+                .iter()
+                .filter_map(|(column_id, stat)| {
+                    let min = histogram_value_of(stat.min())?;
+                    let max = histogram_value_of(stat.max())?;
+                    (min <= max).then(|| (*column_id, HistogramBuilder::new(min, max)))
+                })
+                .collect();
+
             let start = Instant::now();
             let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
             let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
@@ -98,6 +113,17 @@ impl FuseTable {
                                         let _ = sum_map.insert(*i, density);
                                     }
                                 }
+
+                                if let Some(builder) = histogram_builders.get_mut(i) {
+                                    if let (Some(min), Some(max)) = (
+                                        histogram_value_of(col_stat.min()),
+                                        histogram_value_of(col_stat.max()),
+                                    ) {
+                                        let num_values =
+                                            row_count.saturating_sub(col_stat.null_count);
+                                        builder.add_block(min, max, num_values);
+                                    }
+                                }
                             }
                         }
                     });
@@ -127,8 +153,18 @@ impl FuseTable {
                 ndv_map.insert(*i, (density_avg * row_count_sum as f64) as u64);
             }
 
+            let histograms = histogram_builders
+                .iter()
+                .filter_map(|(column_id, builder)| {
+                    let ndv = ndv_map.get(column_id).copied().unwrap_or(row_count_sum);
+                    let histogram = builder.build(ndv, DEFAULT_HISTOGRAM_BUCKETS)?;
+                    Some((*column_id, histogram))
+                })
+                .collect();
+
             // 3. Generate new table statistics
-            let table_statistics = TableSnapshotStatistics::new(ndv_map);
+            let table_statistics =
+                TableSnapshotStatistics::new(ndv_map, histograms, snapshot.summary.row_count);
             let table_statistics_location = self
                 .meta_location_generator
                 .snapshot_statistics_location_from_uuid(
@@ -155,4 +191,15 @@ impl FuseTable {
 
         Ok(())
     }
+
+    /// The number of rows that the latest table statistics are built from, `None` if the
+    /// table is never analyzed, or analyzed by an older version.
+    #[async_backtrace::framed]
+    pub async fn analyzed_row_count(&self) -> Result<Option<u64>> {
+        let snapshot = self.read_table_snapshot().await?;
+        let statistics = self
+            .read_table_snapshot_statistics(snapshot.as_ref())
+            .await?;
+        Ok(statistics.and_then(|s| s.row_count))
+    }
 }
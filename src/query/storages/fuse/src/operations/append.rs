@@ -28,6 +28,7 @@ use common_functions::BUILTIN_FUNCTIONS;
 use common_pipeline_core::processors::ProcessorPtr;
 use common_pipeline_core::Pipeline;
 use common_pipeline_transforms::processors::create_dummy_items;
+use common_pipeline_transforms::processors::AccumulatingTransformer;
 use common_pipeline_transforms::processors::BlockCompactor;
 use common_pipeline_transforms::processors::BlockCompactorForCopy;
 use common_pipeline_transforms::processors::TransformCompact;
@@ -35,6 +36,8 @@ use common_pipeline_transforms::processors::TransformSortPartial;
 use common_sql::evaluator::BlockOperator;
 use common_sql::evaluator::CompoundBlockOperator;
 
+use crate::operations::bucket::is_bucket_key_type;
+use crate::operations::bucket::TransformBucketScatter;
 use crate::operations::common::TransformSerializeBlock;
 use crate::statistics::ClusterStatsGenerator;
 use crate::FuseTable;
@@ -48,6 +51,8 @@ impl FuseTable {
     ) -> Result<()> {
         let block_thresholds = self.get_block_thresholds();
 
+        self.add_check_constraints_transform(ctx.clone(), pipeline)?;
+
         match append_mode {
             AppendMode::Normal => {
                 pipeline.add_transform(|transform_input_port, transform_output_port| {
@@ -73,6 +78,16 @@ impl FuseTable {
 
         let cluster_stats_gen =
             self.cluster_gen_for_append(ctx.clone(), pipeline, block_thresholds, None)?;
+        if let Some(bucket_num) = cluster_stats_gen.bucket_num() {
+            // Split the blocks by buckets, so that the appended blocks are bucketed.
+            pipeline.add_transform(|transform_input_port, transform_output_port| {
+                Ok(ProcessorPtr::create(AccumulatingTransformer::create(
+                    transform_input_port,
+                    transform_output_port,
+                    TransformBucketScatter::new(cluster_stats_gen.clone(), bucket_num),
+                )))
+            })?;
+        }
         pipeline.add_transform(|input, output| {
             let proc = TransformSerializeBlock::try_create(
                 ctx.clone(),
@@ -247,6 +262,14 @@ impl FuseTable {
             cluster_key_index.push(index);
         }
 
+        // Only the tables clustered by plain columns of hashable types can be bucketed.
+        let bucket_num = self.bucket_num_option().filter(|_| {
+            exprs.is_empty()
+                && cluster_key_index
+                    .iter()
+                    .all(|index| is_bucket_key_type(merged[*index].data_type()))
+        });
+
         let operators = if exprs.is_empty() {
             vec![]
         } else {
@@ -266,7 +289,8 @@ impl FuseTable {
             operators,
             merged,
             ctx.get_function_context()?,
-        ))
+        )
+        .with_bucket_num(bucket_num))
     }
 
     pub fn get_option<T: FromStr>(&self, opt_key: &str, default: T) -> T {
@@ -0,0 +1,128 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::DataBlock;
+use common_pipeline_transforms::processors::AccumulatingTransform;
+use storages_common_table_meta::meta::ClusterStatistics;
+use storages_common_table_meta::meta::SegmentInfo;
+
+use crate::io::SegmentsIO;
+use crate::statistics::ClusterStatsGenerator;
+use crate::FuseTable;
+use crate::FUSE_OPT_KEY_BUCKET_NUM;
+
+impl FuseTable {
+    /// The number of buckets set by the `bucket_num` table option.
+    pub fn bucket_num_option(&self) -> Option<u32> {
+        self.table_info
+            .options()
+            .get(FUSE_OPT_KEY_BUCKET_NUM)
+            .and_then(|s| s.parse::<u32>().ok())
+            .filter(|bucket_num| *bucket_num > 0)
+    }
+
+    /// Returns the number of buckets if all the blocks of the current snapshot are
+    /// bucketed by the current cluster keys.
+    ///
+    /// The blocks written before the `bucket_num` option or the cluster keys changed
+    /// hold the rows of several buckets, such tables are not treated as bucketed.
+    #[async_backtrace::framed]
+    pub async fn do_bucket_num(&self, ctx: Arc<dyn TableContext>) -> Result<Option<u32>> {
+        let Some(cluster_key_id) = self.cluster_key_id() else {
+            return Ok(None);
+        };
+        let bucket_num = self
+            .get_cluster_stats_gen(ctx.clone(), 0, self.get_block_thresholds(), None)?
+            .bucket_num();
+        let Some(bucket_num) = bucket_num else {
+            return Ok(None);
+        };
+
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(Some(bucket_num));
+        };
+
+        let is_bucketed = |stats: &Option<ClusterStatistics>| {
+            stats.as_ref().is_some_and(|stats| {
+                stats.cluster_key_id == cluster_key_id && stats.bucket.is_some()
+            })
+        };
+
+        let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
+        let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
+        for chunk in snapshot.segments.chunks(chunk_size) {
+            let segments = segments_io
+                .read_segments::<SegmentInfo>(chunk, true)
+                .await?;
+            for segment in segments {
+                let segment = segment?;
+                // All the blocks of the segment belong to the same bucket.
+                if is_bucketed(&segment.summary.cluster_stats) {
+                    continue;
+                }
+                if !segment
+                    .blocks
+                    .iter()
+                    .all(|block| is_bucketed(&block.cluster_stats))
+                {
+                    return Ok(None);
+                }
+            }
+        }
+        Ok(Some(bucket_num))
+    }
+}
+
+/// Whether the rows can be bucketed by the hash of the cluster key of the type.
+pub fn is_bucket_key_type(data_type: &DataType) -> bool {
+    !matches!(
+        data_type.remove_nullable(),
+        DataType::Tuple(_) | DataType::Array(_) | DataType::Map(_) | DataType::Generic(_)
+    )
+}
+
+/// Splits the blocks by the buckets of the cluster keys, so that every block written
+/// into a bucketed table holds the rows of a single bucket.
+pub struct TransformBucketScatter {
+    cluster_stats_gen: ClusterStatsGenerator,
+    bucket_num: u32,
+}
+
+impl TransformBucketScatter {
+    pub fn new(cluster_stats_gen: ClusterStatsGenerator, bucket_num: u32) -> Self {
+        Self {
+            cluster_stats_gen,
+            bucket_num,
+        }
+    }
+}
+
+impl AccumulatingTransform for TransformBucketScatter {
+    const NAME: &'static str = "TransformBucketScatter";
+
+    fn transform(&mut self, data: DataBlock) -> Result<Vec<DataBlock>> {
+        let buckets = self.cluster_stats_gen.bucket_ids(&data, self.bucket_num);
+        let blocks = data.scatter(&buckets, self.bucket_num as usize)?;
+        Ok(blocks
+            .into_iter()
+            .filter(|block| !block.is_empty())
+            .collect())
+    }
+}
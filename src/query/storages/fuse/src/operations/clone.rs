@@ -0,0 +1,431 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_catalog::catalog::Catalog;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::DatabaseType;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use common_meta_types::MetaId;
+use log::info;
+use log::warn;
+use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::table::OPT_KEY_CLONED_FROM;
+use storages_common_table_meta::table::OPT_KEY_CLONE_SHARED_SNAPSHOTS;
+
+use crate::io::SnapshotsIO;
+use crate::operations::gc::LocationTuple;
+use crate::FuseTable;
+
+const MAX_UPDATE_CLONE_PINS_RETRIES: usize = 10;
+
+/// The ids of clones are not known until they are created, the snapshot of a clone being created
+/// is pinned by a pending id instead: this bit, the time of pinning in seconds, and a random
+/// number.
+const PENDING_CLONE_ID_BIT: u64 = 1 << 63;
+/// A pending pin is abandoned if the clone is not created in time, e.g. the query node crashed.
+const PENDING_CLONE_PIN_TTL_SECS: u64 = 60 * 60;
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+fn new_pending_clone_id() -> u64 {
+    PENDING_CLONE_ID_BIT | (now_secs() << 16) | rand::random::<u16>() as u64
+}
+
+fn is_pending_clone_id(clone_id: u64) -> bool {
+    clone_id & PENDING_CLONE_ID_BIT != 0
+}
+
+fn is_pending_clone_pin_expired(clone_id: u64) -> bool {
+    let pinned_at = (clone_id & !PENDING_CLONE_ID_BIT) >> 16;
+    now_secs().saturating_sub(pinned_at) >= PENDING_CLONE_PIN_TTL_SECS
+}
+
+/// Files of a table that are referenced by its zero-copy clones.
+///
+/// Purging must keep them, even if they are no longer referenced by the table itself.
+#[derive(Default)]
+pub struct CloneSharedFiles {
+    /// Shared snapshots and their table statistics files.
+    pub snapshots: HashSet<String>,
+    pub segments: HashSet<String>,
+    pub locations: LocationTuple,
+}
+
+impl CloneSharedFiles {
+    pub fn retain_unshared_segments(&self, segments: &mut HashSet<Location>) {
+        segments.retain(|(loc, _)| !self.segments.contains(loc));
+    }
+
+    pub fn retain_unshared_files(&self, files: &mut HashSet<String>) {
+        files.retain(|loc| {
+            !self.snapshots.contains(loc)
+                && !self.segments.contains(loc)
+                && !self.locations.block_location.contains(loc)
+                && !self.locations.bloom_location.contains(loc)
+        });
+    }
+}
+
+/// Snapshots of a table pinned by its zero-copy clones, with the ids of the clones referencing
+/// each of them.
+///
+/// The number of clones is the reference count of a snapshot, it is shared until the count
+/// drops to zero.
+#[derive(Default)]
+struct ClonePins(BTreeMap<String, BTreeSet<u64>>);
+
+impl ClonePins {
+    fn from_options(options: &BTreeMap<String, String>) -> Result<Self> {
+        match options.get(OPT_KEY_CLONE_SHARED_SNAPSHOTS) {
+            Some(v) => serde_json::from_str(v).map(ClonePins).map_err(|e| {
+                ErrorCode::Internal(format!(
+                    "invalid value of table option {}: {}",
+                    OPT_KEY_CLONE_SHARED_SNAPSHOTS, e
+                ))
+            }),
+            None => Ok(ClonePins::default()),
+        }
+    }
+
+    fn write_to(&self, options: &mut BTreeMap<String, String>) -> Result<()> {
+        if self.0.is_empty() {
+            options.remove(OPT_KEY_CLONE_SHARED_SNAPSHOTS);
+        } else {
+            let value = serde_json::to_string(&self.0)?;
+            options.insert(OPT_KEY_CLONE_SHARED_SNAPSHOTS.to_owned(), value);
+        }
+        Ok(())
+    }
+
+    fn pin(&mut self, snapshot_location: &str, clone_id: u64) -> bool {
+        self.0
+            .entry(snapshot_location.to_owned())
+            .or_default()
+            .insert(clone_id)
+    }
+
+    fn replace(&mut self, pending_id: u64, clone_id: u64) -> bool {
+        let mut replaced = false;
+        for clones in self.0.values_mut() {
+            if clones.remove(&pending_id) {
+                clones.insert(clone_id);
+                replaced = true;
+            }
+        }
+        replaced
+    }
+
+    fn release(&mut self, clone_id: u64) -> bool {
+        let mut released = false;
+        self.0.retain(|_, clones| {
+            released |= clones.remove(&clone_id);
+            !clones.is_empty()
+        });
+        released
+    }
+}
+
+/// Reads the table `table_id` and updates the clone pins of it with `f`, retrying on concurrent
+/// updates of the table.
+///
+/// Returns false if `f` leaves the pins unchanged.
+async fn update_clone_pins<F>(catalog: &dyn Catalog, table_id: MetaId, f: F) -> Result<bool>
+where F: Fn(&mut ClonePins) -> bool {
+    let mut retries = 0;
+    loop {
+        let (ident, table_meta) = catalog.get_table_meta_by_id(table_id).await?;
+        let mut pins = ClonePins::from_options(&table_meta.options)?;
+        if !f(&mut pins) {
+            return Ok(false);
+        }
+
+        let mut new_table_meta = table_meta.as_ref().clone();
+        pins.write_to(&mut new_table_meta.options)?;
+        let table_info = TableInfo {
+            ident: ident.clone(),
+            meta: table_meta.as_ref().clone(),
+            ..Default::default()
+        };
+        let req = UpdateTableMetaReq {
+            table_id,
+            seq: MatchSeq::Exact(ident.seq),
+            new_table_meta,
+            copied_files: None,
+            deduplicated_label: None,
+            update_stream_meta: vec![],
+        };
+        match catalog.update_table_meta(&table_info, req).await {
+            Ok(_) => return Ok(true),
+            Err(e)
+                if e.code() == ErrorCode::TABLE_VERSION_MISMATCHED
+                    && retries < MAX_UPDATE_CLONE_PINS_RETRIES =>
+            {
+                retries += 1;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+impl FuseTable {
+    /// Returns true if this table is a zero-copy clone of another table.
+    pub fn is_cloned(&self) -> bool {
+        self.table_info.options().contains_key(OPT_KEY_CLONED_FROM)
+    }
+
+    /// Returns true if the file at `location` is owned by this table.
+    ///
+    /// A clone table references the files of the table it was cloned from, until they are
+    /// rewritten (copy-on-write). Those files are located outside the storage prefix of the clone,
+    /// and must never be removed by it.
+    pub fn is_owned_location(&self, location: &str) -> bool {
+        if !self.is_cloned() {
+            return true;
+        }
+        let prefix = self.meta_location_generator.prefix();
+        prefix.is_empty()
+            || location
+                .strip_prefix(prefix)
+                .map_or(false, |path| path.starts_with('/'))
+    }
+
+    /// Checks that a zero-copy clone can be created from this table.
+    ///
+    /// A clone can't be cloned: the snapshots it shares with its clones reference the files of
+    /// its own source table, which are not pinned for them.
+    pub fn check_clone_source(&self) -> Result<()> {
+        if self.is_cloned() {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "clone of clone table {} is not supported",
+                self.table_info.desc
+            )));
+        }
+        if self.table_info.meta.storage_params.is_some()
+            || !matches!(self.table_info.db_type, DatabaseType::NormalDB)
+        {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "clone of external, attached or shared table {} is not supported",
+                self.table_info.desc
+            )));
+        }
+        Ok(())
+    }
+
+    /// Snapshot locations of this table which are shared with its clones.
+    ///
+    /// The clones whose metadata has been removed, i.e. dropped and vacuumed, no longer
+    /// reference any snapshot.
+    #[async_backtrace::framed]
+    pub async fn clone_shared_snapshots(&self, ctx: &dyn TableContext) -> Result<Vec<String>> {
+        let pins = ClonePins::from_options(self.table_info.options())?;
+        if pins.0.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let catalog = ctx.get_catalog(&self.table_info.meta.catalog).await?;
+        let mut alive_clones = HashMap::new();
+        let mut shared = vec![];
+        for (snapshot_location, clones) in pins.0 {
+            for clone_id in clones {
+                let alive = match alive_clones.get(&clone_id) {
+                    Some(alive) => *alive,
+                    None if is_pending_clone_id(clone_id) => {
+                        !is_pending_clone_pin_expired(clone_id)
+                    }
+                    None => {
+                        let alive = match catalog.get_table_meta_by_id(clone_id).await {
+                            Ok(_) => true,
+                            Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE_ID => false,
+                            Err(e) => return Err(e),
+                        };
+                        alive_clones.insert(clone_id, alive);
+                        alive
+                    }
+                };
+                if alive {
+                    shared.push(snapshot_location);
+                    break;
+                }
+            }
+        }
+        Ok(shared)
+    }
+
+    /// Pins the snapshot at `snapshot_location` of this table for a clone about to be created,
+    /// so that its segments and blocks are kept by purge until the clone releases it.
+    ///
+    /// Returns the pending id of the clone, see [`FuseTable::confirm_clone_pin`].
+    #[async_backtrace::framed]
+    pub async fn pin_snapshot_for_clone(
+        &self,
+        ctx: &dyn TableContext,
+        snapshot_location: &str,
+    ) -> Result<u64> {
+        let pending_id = new_pending_clone_id();
+        let catalog = ctx.get_catalog(&self.table_info.meta.catalog).await?;
+        update_clone_pins(catalog.as_ref(), self.get_id(), |pins| {
+            pins.pin(snapshot_location, pending_id)
+        })
+        .await?;
+
+        // The snapshot may have been purged before it was pinned.
+        if let Err(e) =
+            SnapshotsIO::read_snapshot(snapshot_location.to_owned(), self.operator.clone()).await
+        {
+            let _ = self.release_clone_pin(ctx, pending_id).await;
+            return Err(e);
+        }
+        Ok(pending_id)
+    }
+
+    /// Transfers the snapshot pinned by `pending_id` to the clone `clone_id` once it is created.
+    #[async_backtrace::framed]
+    pub async fn confirm_clone_pin(
+        &self,
+        ctx: &dyn TableContext,
+        pending_id: u64,
+        clone_id: MetaId,
+    ) -> Result<()> {
+        let catalog = ctx.get_catalog(&self.table_info.meta.catalog).await?;
+        let confirmed = update_clone_pins(catalog.as_ref(), self.get_id(), |pins| {
+            pins.replace(pending_id, clone_id)
+        })
+        .await?;
+        if !confirmed {
+            return Err(ErrorCode::Internal(format!(
+                "snapshot pinned for clone {} of table {} not found",
+                clone_id, self.table_info.desc
+            )));
+        }
+        Ok(())
+    }
+
+    /// Releases the snapshot of this table pinned for the clone `clone_id`, e.g. if the clone
+    /// failed to be created.
+    #[async_backtrace::framed]
+    pub async fn release_clone_pin(&self, ctx: &dyn TableContext, clone_id: u64) -> Result<()> {
+        let catalog = ctx.get_catalog(&self.table_info.meta.catalog).await?;
+        update_clone_pins(catalog.as_ref(), self.get_id(), |pins| {
+            pins.release(clone_id)
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Releases the snapshot of the source table pinned for this clone, if none of the snapshots
+    /// of this clone references the files of the source table any more.
+    ///
+    /// `root_snapshot_location` is the location of the current snapshot, which must only reference
+    /// files owned by this clone.
+    #[async_backtrace::framed]
+    pub async fn release_clone_source(
+        &self,
+        ctx: &dyn TableContext,
+        root_snapshot_location: &str,
+    ) -> Result<()> {
+        let Some(source_id) = self
+            .table_info
+            .options()
+            .get(OPT_KEY_CLONED_FROM)
+            .and_then(|v| v.parse::<MetaId>().ok())
+        else {
+            return Ok(());
+        };
+
+        let snapshot_files = self.list_snapshot_files().await?;
+        if snapshot_files
+            .iter()
+            .any(|location| location != root_snapshot_location)
+        {
+            return Ok(());
+        }
+
+        let clone_id = self.get_id();
+        let catalog = ctx.get_catalog(&self.table_info.meta.catalog).await?;
+        match update_clone_pins(catalog.as_ref(), source_id, |pins| pins.release(clone_id)).await {
+            Ok(true) => {
+                info!(
+                    "clone table {} released the snapshots of table {}",
+                    self.table_info.desc, source_id
+                );
+                Ok(())
+            }
+            Ok(false) => Ok(()),
+            // The source table has been dropped and vacuumed.
+            Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE_ID => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Collects the files referenced by the snapshots shared with clones of this table.
+    #[async_backtrace::framed]
+    pub async fn read_clone_shared_files(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+    ) -> Result<CloneSharedFiles> {
+        let mut shared_files = CloneSharedFiles::default();
+        for snapshot_location in self.clone_shared_snapshots(ctx.as_ref()).await? {
+            let snapshot =
+                match SnapshotsIO::read_snapshot(snapshot_location.clone(), self.operator.clone())
+                    .await
+                {
+                    Ok((snapshot, _)) => snapshot,
+                    Err(e) if e.code() == ErrorCode::STORAGE_NOT_FOUND => {
+                        warn!(
+                            "snapshot {} shared with clones not found. table: {}, ident {}",
+                            snapshot_location, self.table_info.desc, self.table_info.ident,
+                        );
+                        continue;
+                    }
+                    Err(e) => return Err(e),
+                };
+
+            let locations = self
+                .get_block_locations(ctx.clone(), &snapshot.segments, false, false)
+                .await?;
+            shared_files
+                .locations
+                .block_location
+                .extend(locations.block_location);
+            shared_files
+                .locations
+                .bloom_location
+                .extend(locations.bloom_location);
+            shared_files
+                .segments
+                .extend(snapshot.segments.iter().map(|(loc, _)| loc.clone()));
+            shared_files
+                .snapshots
+                .extend(snapshot.table_statistics_location.clone());
+            shared_files.snapshots.insert(snapshot_location);
+        }
+        Ok(shared_files)
+    }
+}
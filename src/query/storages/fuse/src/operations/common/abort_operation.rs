@@ -43,6 +43,9 @@ impl AbortOperation {
         if let Some(index) = block.bloom_filter_index_location.clone() {
             self.bloom_filter_indexes.push(index.0);
         }
+        if let Some(index) = block.point_lookup_index_location.clone() {
+            self.bloom_filter_indexes.push(index.0);
+        }
     }
 
     pub fn add_segment(&mut self, segment: String) {
@@ -79,6 +79,7 @@ impl TransformSerializeBlock {
         let bloom_columns_map = table
             .bloom_index_cols
             .bloom_index_fields(source_schema.clone(), BloomIndex::supported_type)?;
+        let point_lookup_field = table.point_lookup_field(&source_schema);
         let block_builder = BlockBuilder {
             ctx,
             meta_locations: table.meta_location_generator().clone(),
@@ -86,6 +87,7 @@ impl TransformSerializeBlock {
             write_settings: table.get_write_settings(),
             cluster_stats_gen,
             bloom_columns_map,
+            point_lookup_field,
         };
         Ok(TransformSerializeBlock {
             state: State::Consume,
@@ -283,6 +285,9 @@ impl Processor for TransformSerializeBlock {
                         );
                     }
                 }
+                if let Some(index_state) = serialized.point_lookup_index_state {
+                    write_data(index_state.data, &self.dal, &index_state.location.0).await?;
+                }
 
                 let data_block = if let Some(index) = index {
                     Self::mutation_logs(MutationLogEntry::ReplacedBlock {
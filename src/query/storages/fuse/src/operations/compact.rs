@@ -27,10 +27,13 @@ use common_pipeline_core::Pipeline;
 use common_pipeline_transforms::processors::AsyncAccumulatingTransformer;
 use common_sql::executor::physical_plans::MutationKind;
 use storages_common_table_meta::meta::TableSnapshot;
+use storages_common_table_meta::table::AggregatingColumns;
+use storages_common_table_meta::table::DedupColumns;
 
 use crate::operations::common::TableMutationAggregator;
 use crate::operations::common::TransformSerializeBlock;
 use crate::operations::mutation::BlockCompactMutator;
+use crate::operations::mutation::CompactAggregator;
 use crate::operations::mutation::CompactLazyPartInfo;
 use crate::operations::mutation::CompactSource;
 use crate::operations::mutation::SegmentCompactMutator;
@@ -168,6 +171,18 @@ impl FuseTable {
             false,
         )?;
         let schema = self.schema_with_stream();
+        let options = self.table_info.options();
+        let aggregator = match AggregatingColumns::from_options(options)? {
+            Some(columns) => Some(CompactAggregator::try_create(
+                &columns,
+                &block_reader.schema(),
+            )?),
+            None => DedupColumns::from_options(options)?
+                .map(|columns| {
+                    CompactAggregator::try_create_dedup(&columns, &block_reader.schema())
+                })
+                .transpose()?,
+        };
         // Add source pipe.
         pipeline.add_source(
             |output| {
@@ -176,6 +191,7 @@ impl FuseTable {
                     schema.clone(),
                     self.storage_format,
                     block_reader.clone(),
+                    aggregator.clone(),
                     output,
                 )
             },
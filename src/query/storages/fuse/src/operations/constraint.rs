@@ -0,0 +1,207 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::principal::OnErrorMode;
+use common_pipeline_core::processors::ProcessorPtr;
+use common_pipeline_core::Pipeline;
+use common_pipeline_transforms::processors::Transform;
+use common_pipeline_transforms::processors::Transformer;
+use common_sql::parse_computed_expr;
+use common_storage::CopyStatus;
+use common_storage::QuarantinedRecord;
+use storages_common_table_meta::table::TableConstraints;
+
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Rejects the blocks to be written if any row violates a CHECK constraint of the table.
+    ///
+    /// With `ON_ERROR = QUARANTINE`, only the violating rows are removed, and kept in
+    /// [`CopyStatus::rejected_rows`] to be written into the quarantine table.
+    ///
+    /// The input blocks hold the columns of the table except the virtual computed ones,
+    /// followed by the stream columns if any.
+    pub fn add_check_constraints_transform(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        self.add_check_constraints_transform_with_deletion(ctx, pipeline, None)
+    }
+
+    /// Same as [`FuseTable::add_check_constraints_transform`], for the input blocks of
+    /// `REPLACE INTO ... DELETE WHEN`.
+    ///
+    /// `deletion` is the offset of the delete column inserted into the input blocks, and the
+    /// predicate of the rows to be deleted instead of written, which are not checked.
+    pub fn add_check_constraints_transform_with_deletion(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        deletion: Option<(usize, Expr)>,
+    ) -> Result<()> {
+        if let Some(transform) = self.check_constraints_transform(ctx, deletion)? {
+            pipeline.add_transform(|input, output| {
+                Ok(ProcessorPtr::create(Transformer::create(
+                    input,
+                    output,
+                    transform.clone(),
+                )))
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Builds the transform checking the CHECK constraints of the table, if it has any.
+    ///
+    /// Used by the pipelines adding the transform to some of their ports only.
+    pub fn check_constraints_transform(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        deletion: Option<(usize, Expr)>,
+    ) -> Result<Option<TransformCheckConstraints>> {
+        let constraints = TableConstraints::from_options(self.table_info.options())?;
+        if constraints.checks().next().is_none() {
+            return Ok(None);
+        }
+
+        let schema = Arc::new(DataSchema::from(
+            self.schema().remove_virtual_computed_fields(),
+        ));
+        let mut checks = Vec::new();
+        for (name, expr) in constraints.checks() {
+            // The rows making the expression NULL satisfy the constraint.
+            let violation = format!("is_true(NOT ({expr}))");
+            let violation = parse_computed_expr(ctx.clone(), schema.clone(), &violation)?;
+            checks.push((name.clone(), expr.clone(), violation));
+        }
+        let func_ctx = ctx.get_function_context()?;
+        let num_fields = schema.num_fields();
+        let quarantine = matches!(ctx.get_on_error_mode(), Some(OnErrorMode::Quarantine))
+            .then(|| ctx.get_copy_status());
+        Ok(Some(TransformCheckConstraints {
+            func_ctx,
+            checks,
+            num_fields,
+            quarantine,
+            deletion,
+        }))
+    }
+}
+
+#[derive(Clone)]
+pub struct TransformCheckConstraints {
+    func_ctx: FunctionContext,
+    /// The name, the SQL text and the violation predicate of the CHECK constraints.
+    checks: Vec<(String, String, Expr)>,
+    /// The number of the table columns in the input blocks.
+    num_fields: usize,
+    /// Where to keep the violating rows if they are quarantined.
+    quarantine: Option<Arc<CopyStatus>>,
+    /// The offset of the delete column and the predicate of the rows to be deleted.
+    deletion: Option<(usize, Expr)>,
+}
+
+impl TransformCheckConstraints {
+    /// Renders the values of the table columns of the row, e.g. `(1, 'a')`.
+    fn render_row(&self, data: &DataBlock, row: usize) -> String {
+        let values = data.columns()[..self.num_fields]
+            .iter()
+            .map(|entry| match entry.value.index(row) {
+                Some(v) => v.to_string(),
+                None => "NULL".to_string(),
+            })
+            .collect::<Vec<_>>();
+        format!("({})", values.join(", "))
+    }
+}
+
+impl Transform for TransformCheckConstraints {
+    const NAME: &'static str = "TransformCheckConstraints";
+
+    fn transform(&mut self, data: DataBlock) -> Result<DataBlock> {
+        if data.is_empty() {
+            return Ok(data);
+        }
+        let num_rows = data.num_rows();
+        let (table_data, deleted) = match &self.deletion {
+            Some((offset, predicate)) => {
+                let evaluator = Evaluator::new(&data, &self.func_ctx, &BUILTIN_FUNCTIONS);
+                let deleted = evaluator
+                    .run(predicate)?
+                    .convert_to_full_column(&DataType::Boolean, num_rows);
+                let mut columns = data.columns().to_vec();
+                columns.remove(*offset);
+                let table_data = DataBlock::new(columns, num_rows);
+                (table_data, Some(deleted.into_boolean().unwrap()))
+            }
+            None => (data.clone(), None),
+        };
+
+        let mut rejected = MutableBitmap::from_len_zeroed(num_rows);
+        let evaluator = Evaluator::new(&table_data, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        for (name, expr, violation) in &self.checks {
+            let violated = evaluator
+                .run(violation)?
+                .convert_to_full_column(&DataType::Boolean, num_rows);
+            let mut violated = violated.into_boolean().unwrap();
+            if let Some(deleted) = &deleted {
+                violated = &violated & &!deleted;
+            }
+            if violated.unset_bits() == violated.len() {
+                continue;
+            }
+            let error = format!("check constraint {} is violated: {}", name, expr);
+            let Some(copy_status) = &self.quarantine else {
+                return Err(ErrorCode::CheckConstraintViolated(error));
+            };
+
+            // A row violating several constraints is quarantined only once.
+            let mut records = Vec::new();
+            for (row, violated) in violated.iter().enumerate() {
+                if violated && !rejected.get(row) {
+                    rejected.set(row, true);
+                    records.push(QuarantinedRecord {
+                        line: None,
+                        offset: None,
+                        record: Some(self.render_row(&table_data, row)),
+                        error: error.clone(),
+                    });
+                }
+            }
+            copy_status.add_rejected_rows(name, records);
+        }
+
+        if rejected.unset_bits() == num_rows {
+            return Ok(data);
+        }
+        let rejected: Bitmap = rejected.into();
+        data.filter_with_bitmap(&!&rejected)
+    }
+}
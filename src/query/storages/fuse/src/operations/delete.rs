@@ -274,14 +274,26 @@ impl FuseTable {
                 segment_locations: create_segment_location_vector(snapshot.segments.clone(), None),
                 block_count: Some(snapshot.summary.block_count as usize),
             };
-            let (partitions, info) = self
-                .do_mutation_block_pruning(ctx, filters, projection, prune_ctx, true, is_delete)
+            let (mut partitions, info) = self
+                .do_mutation_block_pruning(
+                    ctx.clone(),
+                    filters,
+                    projection,
+                    prune_ctx,
+                    true,
+                    is_delete,
+                )
                 .await?;
             if is_delete {
                 log::info!(
                     "delete pruning done, number of whole block deletion detected in pruning phase: {}",
                     info.num_whole_block_mutation
                 );
+                // The blocks out of the limit are left to the later deletions.
+                let limit = ctx.get_settings().get_deletion_block_limit()? as usize;
+                if limit > 0 && partitions.partitions.len() > limit {
+                    partitions.partitions.truncate(limit);
+                }
             }
             partitions
         };
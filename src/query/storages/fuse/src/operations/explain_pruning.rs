@@ -0,0 +1,124 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::PredicatePruning;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::Expr;
+use common_functions::BUILTIN_FUNCTIONS;
+use storages_common_pruner::RangePrunerCreator;
+
+use crate::io::SegmentsIO;
+use crate::pruning::BloomPrunerCreator;
+use crate::pruning::ClusterKeyPruner;
+use crate::pruning::ComputedColumnRewriter;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Splits the filter of the push downs into conjunctions, and checks how each of them
+    /// prunes the segments and blocks of the current snapshot by itself.
+    #[async_backtrace::framed]
+    pub async fn do_explain_pruning(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+    ) -> Result<Vec<PredicatePruning>> {
+        let Some(filter) = push_downs.as_ref().and_then(|p| p.filters.as_ref()) else {
+            return Ok(vec![]);
+        };
+        let schema = self.schema_with_stream();
+        let filter = ComputedColumnRewriter::try_create(&ctx, &schema)?
+            .rewrite(filter.filter.as_expr(&BUILTIN_FUNCTIONS))?;
+        let mut predicates = vec![];
+        split_conjunctions(filter, &mut predicates);
+
+        let func_ctx = ctx.get_function_context()?;
+        let cluster_key_history = self
+            .cluster_key_history(ctx.clone())
+            .iter()
+            .map(|(id, keys)| {
+                let keys = keys.iter().map(|k| k.as_expr(&BUILTIN_FUNCTIONS)).collect();
+                (*id, keys)
+            })
+            .collect::<Vec<_>>();
+
+        let mut range_pruners = Vec::with_capacity(predicates.len());
+        let mut result = Vec::with_capacity(predicates.len());
+        for predicate in predicates.iter() {
+            range_pruners.push(RangePrunerCreator::try_create(
+                func_ctx.clone(),
+                &schema,
+                Some(predicate),
+            )?);
+            let bloom_pruner = BloomPrunerCreator::create(
+                func_ctx.clone(),
+                &schema,
+                self.operator.clone(),
+                Some(predicate),
+                self.bloom_index_cols(),
+            )?;
+            let cluster_key_pruner =
+                ClusterKeyPruner::create(func_ctx.clone(), Some(predicate), &cluster_key_history);
+            result.push(PredicatePruning {
+                predicate: predicate.sql_display(),
+                bloom_prunable: bloom_pruner.is_some(),
+                cluster_key_prunable: cluster_key_pruner.is_some(),
+                ..Default::default()
+            });
+        }
+
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(result);
+        };
+        for location in snapshot.segments.iter() {
+            let segment = SegmentsIO::read_compact_segment(
+                self.operator.clone(),
+                location.clone(),
+                schema.clone(),
+                true,
+            )
+            .await?;
+            let block_metas = segment.block_metas()?;
+            for (pruner, pruning) in range_pruners.iter().zip(result.iter_mut()) {
+                // Same as the pruning stats, the blocks of the pruned segments are not counted.
+                if !pruner.should_keep(&segment.summary.col_stats, None) {
+                    pruning.segments_range_pruned += 1;
+                    continue;
+                }
+                pruning.blocks_range_pruned += block_metas
+                    .iter()
+                    .filter(|b| !pruner.should_keep(&b.col_stats, Some(&b.col_metas)))
+                    .count();
+            }
+        }
+        Ok(result)
+    }
+}
+
+fn split_conjunctions(expr: Expr<String>, predicates: &mut Vec<Expr<String>>) {
+    match expr {
+        Expr::FunctionCall { function, args, .. }
+            if function.signature.name == "and" || function.signature.name == "and_filters" =>
+        {
+            for arg in args {
+                split_conjunctions(arg, predicates);
+            }
+        }
+        _ => predicates.push(expr),
+    }
+}
@@ -40,6 +40,7 @@ use crate::io::SegmentsIO;
 use crate::io::SnapshotLiteExtended;
 use crate::io::SnapshotsIO;
 use crate::io::TableMetaLocationGenerator;
+use crate::operations::CloneSharedFiles;
 use crate::FuseTable;
 use crate::FUSE_TBL_SNAPSHOT_PREFIX;
 
@@ -62,7 +63,7 @@ impl FuseTable {
                 return Ok(None);
             }
         }
-        let root_snapshot_info = root_snapshot_info_op.unwrap();
+        let mut root_snapshot_info = root_snapshot_info_op.unwrap();
         if root_snapshot_info.snapshot_lite.timestamp.is_none() {
             return Err(ErrorCode::StorageOther(format!(
                 "gc: snapshot timestamp is none, snapshot location: {}",
@@ -70,6 +71,24 @@ impl FuseTable {
             )));
         }
 
+        // A clone table keeps the snapshot of its source table pinned, until the files of the
+        // source table are no longer referenced.
+        let root_snapshot_location = root_snapshot_info.snapshot_location.clone();
+        let references_clone_source =
+            self.is_cloned() && self.references_clone_source(&root_snapshot_info);
+
+        // Files shared with the clones of this table are kept, just like the ones referenced by
+        // the root snapshot.
+        let shared_files = self.read_clone_shared_files(ctx).await?;
+        root_snapshot_info
+            .referenced_locations
+            .block_location
+            .extend(shared_files.locations.block_location.iter().cloned());
+        root_snapshot_info
+            .referenced_locations
+            .bloom_location
+            .extend(shared_files.locations.bloom_location.iter().cloned());
+
         let snapshots_io = SnapshotsIO::create(ctx.clone(), self.operator.clone());
         let location_gen = self.meta_location_generator();
         let purged_snapshot_limit = limit.unwrap_or(snapshot_files.len());
@@ -147,6 +166,10 @@ impl FuseTable {
                 }
             }
 
+            shared_files.retain_unshared_segments(&mut segments_to_be_purged);
+            shared_files.retain_unshared_files(&mut ts_to_be_purged);
+            shared_files.retain_unshared_files(&mut snapshots_to_be_purged);
+
             // Refresh status.
             {
                 read_snapshot_count += chunk.len();
@@ -216,6 +239,9 @@ impl FuseTable {
                     ts_to_be_purged.insert(s.table_statistics_location.unwrap());
                 }
             }
+            shared_files.retain_unshared_segments(&mut segments_to_be_purged);
+            shared_files.retain_unshared_files(&mut ts_to_be_purged);
+            shared_files.retain_unshared_files(&mut snapshots_to_be_purged);
             if dry_run {
                 self.dry_run_purge(
                     ctx,
@@ -253,13 +279,46 @@ impl FuseTable {
                 root_snapshot_info.snapshot_lite,
                 root_snapshot_info.referenced_locations,
                 root_snapshot_info.snapshot_location,
+                &shared_files,
                 &table_agg_index_ids,
             )
             .await?;
         }
+
+        // 4. release the snapshot of the source table if this is a clone table.
+        if self.is_cloned() && !references_clone_source {
+            if let Err(e) = self
+                .release_clone_source(ctx.as_ref(), &root_snapshot_location)
+                .await
+            {
+                warn!(
+                    "gc: failed to release the snapshot of the source table. table: {}, ident {}, error: {}",
+                    self.table_info.desc, self.table_info.ident, e
+                );
+            }
+        }
         Ok(None)
     }
 
+    fn references_clone_source(&self, root_snapshot_info: &RootSnapshotInfo) -> bool {
+        let snapshot = &root_snapshot_info.snapshot_lite;
+        let locations = &root_snapshot_info.referenced_locations;
+        !self.is_owned_location(&root_snapshot_info.snapshot_location)
+            || snapshot
+                .segments
+                .iter()
+                .any(|(loc, _)| !self.is_owned_location(loc))
+            || snapshot
+                .table_statistics_location
+                .iter()
+                .any(|loc| !self.is_owned_location(loc))
+            || locations
+                .block_location
+                .iter()
+                .chain(locations.bloom_location.iter())
+                .any(|loc| !self.is_owned_location(loc))
+    }
+
     async fn read_root_snapshot(
         &self,
         ctx: &Arc<dyn TableContext>,
@@ -355,6 +414,7 @@ impl FuseTable {
         }
         purge_files.extend(ts_to_be_purged.iter().map(|loc| loc.to_string()));
         purge_files.extend(snapshots_to_be_purged.iter().map(|loc| loc.to_string()));
+        purge_files.retain(|loc| self.is_owned_location(loc));
 
         Ok(())
     }
@@ -438,22 +498,27 @@ impl FuseTable {
             .await
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn purge_root_snapshot(
         &self,
         ctx: &Arc<dyn TableContext>,
         counter: &mut PurgeCounter,
         root_snapshot: Arc<SnapshotLiteExtended>,
-        root_location_tuple: LocationTuple,
+        mut root_location_tuple: LocationTuple,
         root_snapshot_location: String,
+        shared_files: &CloneSharedFiles,
         table_agg_index_ids: &[u64],
     ) -> Result<()> {
-        let segment_locations_to_be_purged = HashSet::from_iter(
+        let mut segment_locations_to_be_purged = HashSet::from_iter(
             root_snapshot
                 .segments
                 .iter()
                 .map(|loc| loc.0.clone())
                 .collect::<Vec<_>>(),
         );
+        shared_files.retain_unshared_files(&mut segment_locations_to_be_purged);
+        shared_files.retain_unshared_files(&mut root_location_tuple.block_location);
+        shared_files.retain_unshared_files(&mut root_location_tuple.bloom_location);
 
         let mut agg_indexes_to_be_purged = HashSet::new();
         for index_id in table_agg_index_ids {
@@ -478,13 +543,11 @@ impl FuseTable {
         if let Some(ts) = root_snapshot.table_statistics_location.clone() {
             ts_to_be_purged.insert(ts);
         }
-        self.purge_ts_snapshots(
-            ctx,
-            counter,
-            ts_to_be_purged,
-            HashSet::from([root_snapshot_location]),
-        )
-        .await
+        shared_files.retain_unshared_files(&mut ts_to_be_purged);
+        let mut snapshots_to_be_purged = HashSet::from([root_snapshot_location]);
+        shared_files.retain_unshared_files(&mut snapshots_to_be_purged);
+        self.purge_ts_snapshots(ctx, counter, ts_to_be_purged, snapshots_to_be_purged)
+            .await
     }
 
     async fn purge_block_segments(
@@ -588,7 +651,12 @@ impl FuseTable {
         locations_to_be_purged: HashSet<String>,
     ) -> Result<()> {
         let fuse_file = Files::create(ctx.clone(), self.operator.clone());
-        let locations = Vec::from_iter(locations_to_be_purged);
+        // never remove the files a clone table shares with the table it was cloned from
+        let locations = Vec::from_iter(
+            locations_to_be_purged
+                .into_iter()
+                .filter(|loc| self.is_owned_location(loc)),
+        );
         fuse_file.remove_file_in_batch(&locations).await
     }
 
@@ -688,6 +756,10 @@ impl TryFrom<Arc<CompactSegmentInfo>> for LocationTuple {
             if let Some(bloom_loc) = &block_meta.bloom_filter_index_location {
                 bloom_location.insert(bloom_loc.0.clone());
             }
+            // the point lookup indexes are purged along with the bloom indexes.
+            if let Some(index_loc) = &block_meta.point_lookup_index_location {
+                bloom_location.insert(index_loc.0.clone());
+            }
         }
         Ok(Self {
             block_location,
@@ -15,10 +15,14 @@
 mod agg_index_sink;
 mod analyze;
 mod append;
+mod bucket;
+mod clone;
 mod commit;
 pub mod common;
 mod compact;
+mod constraint;
 mod delete;
+mod explain_pruning;
 mod gc;
 mod merge;
 mod merge_into;
@@ -30,18 +34,22 @@ mod read_partitions;
 mod recluster;
 mod replace;
 mod replace_into;
+mod replicate;
 mod revert;
 mod truncate;
 mod update;
 pub mod util;
 pub use agg_index_sink::AggIndexSink;
+pub use clone::CloneSharedFiles;
 pub use common::*;
 pub use compact::CompactOptions;
+pub use constraint::TransformCheckConstraints;
 pub use delete::MutationBlockPruningContext;
 pub use merge_into::*;
 pub use mutation::*;
 pub use read::build_row_fetcher_pipeline;
 pub use replace_into::*;
+pub use replicate::ReplicationStatus;
 pub use util::acquire_task_permit;
 pub use util::column_parquet_metas;
 pub use util::read_block;
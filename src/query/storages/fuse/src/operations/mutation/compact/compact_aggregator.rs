@@ -0,0 +1,186 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use bumpalo::Bump;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::BlockEntry;
+use common_expression::ColumnBuilder;
+use common_expression::DataBlock;
+use common_expression::SortColumnDescription;
+use common_expression::StateAddr;
+use common_expression::TableSchema;
+use common_expression::Value;
+use common_functions::aggregates::AggregateFunctionFactory;
+use common_functions::aggregates::AggregateFunctionRef;
+use storages_common_table_meta::table::AggregatingColumns;
+use storages_common_table_meta::table::DedupColumns;
+
+/// Merges the rows sharing the same keys of the blocks compacted together of an
+/// aggregating table, see [`AggregatingColumns`], or keeps the latest of them for a table
+/// with dedup keys, see [`DedupColumns`].
+///
+/// Only the blocks compacted together are merged, queries still need to group the rows.
+pub struct CompactAggregator {
+    key_offsets: Vec<usize>,
+    // Rows of a group are ordered by the version descending, the first one is kept.
+    version_offset: Option<usize>,
+    functions: Vec<(usize, DataType, AggregateFunctionRef)>,
+}
+
+impl CompactAggregator {
+    pub fn try_create(
+        columns: &AggregatingColumns,
+        schema: &TableSchema,
+    ) -> Result<Arc<CompactAggregator>> {
+        let key_offsets = columns
+            .keys
+            .iter()
+            .map(|key| schema.index_of(key))
+            .collect::<Result<Vec<_>>>()?;
+
+        let factory = AggregateFunctionFactory::instance();
+        let functions = columns
+            .functions
+            .iter()
+            .map(|(column, name)| {
+                let offset = schema.index_of(column)?;
+                let data_type = DataType::from(schema.field(offset).data_type());
+                let func = factory.get_or_null(name, vec![], vec![data_type.clone()], false)?;
+                let return_type = func.return_type()?;
+                if return_type != data_type {
+                    return Err(ErrorCode::TableOptionInvalid(format!(
+                        "aggregating function {name}({column}) returns {return_type}, \
+                         but the column is of type {data_type}",
+                    )));
+                }
+                Ok((offset, data_type, func))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Arc::new(CompactAggregator {
+            key_offsets,
+            version_offset: None,
+            functions,
+        }))
+    }
+
+    pub fn try_create_dedup(
+        columns: &DedupColumns,
+        schema: &TableSchema,
+    ) -> Result<Arc<CompactAggregator>> {
+        let key_offsets = columns
+            .keys
+            .iter()
+            .map(|key| schema.index_of(key))
+            .collect::<Result<Vec<_>>>()?;
+        let version_offset = schema.index_of(&columns.version_column)?;
+
+        Ok(Arc::new(CompactAggregator {
+            key_offsets,
+            version_offset: Some(version_offset),
+            functions: vec![],
+        }))
+    }
+
+    pub fn aggregate(&self, block: DataBlock) -> Result<DataBlock> {
+        let num_rows = block.num_rows();
+        if num_rows <= 1 {
+            return Ok(block);
+        }
+
+        let mut sort_desc = self
+            .key_offsets
+            .iter()
+            .map(|offset| SortColumnDescription {
+                offset: *offset,
+                asc: true,
+                nulls_first: true,
+                is_nullable: block.get_by_offset(*offset).data_type.is_nullable_or_null(),
+            })
+            .collect::<Vec<_>>();
+        if let Some(offset) = self.version_offset {
+            sort_desc.push(SortColumnDescription {
+                offset,
+                asc: false,
+                nulls_first: false,
+                is_nullable: block.get_by_offset(offset).data_type.is_nullable_or_null(),
+            });
+        }
+        let block = DataBlock::sort(&block, &sort_desc, None)?;
+
+        let keys = self
+            .key_offsets
+            .iter()
+            .map(|offset| {
+                let entry = block.get_by_offset(*offset);
+                entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, num_rows)
+            })
+            .collect::<Vec<_>>();
+
+        // The first row of every group, and the group of every row.
+        let mut first_rows = vec![0u32];
+        let mut groups = Vec::with_capacity(num_rows);
+        groups.push(0);
+        for row in 1..num_rows {
+            if keys.iter().any(|c| c.index(row) != c.index(row - 1)) {
+                first_rows.push(row as u32);
+            }
+            groups.push(first_rows.len() - 1);
+        }
+        if first_rows.len() == num_rows {
+            return Ok(block);
+        }
+
+        let mut result = block.take(&first_rows, &mut None)?;
+        let columns = result.columns_mut();
+        for (offset, data_type, func) in self.functions.iter() {
+            let arena = Bump::new();
+            let places = first_rows
+                .iter()
+                .map(|_| {
+                    let place: StateAddr = arena.alloc_layout(func.state_layout()).into();
+                    func.init_state(place);
+                    place
+                })
+                .collect::<Vec<_>>();
+            let row_places = groups.iter().map(|g| places[*g]).collect::<Vec<_>>();
+
+            let entry = block.get_by_offset(*offset);
+            let column = entry.value.convert_to_full_column(data_type, num_rows);
+            let res = func
+                .accumulate_keys(&row_places, 0, &[column], num_rows)
+                .and_then(|_| {
+                    let mut builder = ColumnBuilder::with_capacity(data_type, places.len());
+                    for place in places.iter() {
+                        func.merge_result(*place, &mut builder)?;
+                    }
+                    Ok(builder.build())
+                });
+
+            if func.need_manual_drop_state() {
+                for place in places.iter() {
+                    unsafe { func.drop_state(*place) }
+                }
+            }
+            columns[*offset] = BlockEntry::new(data_type.clone(), Value::Column(res?));
+        }
+        Ok(result)
+    }
+}
@@ -37,6 +37,7 @@ use storages_common_table_meta::meta::BlockMeta;
 
 use crate::io::BlockReader;
 use crate::io::ReadSettings;
+use crate::operations::mutation::compact::compact_aggregator::CompactAggregator;
 use crate::operations::mutation::compact::compact_part::CompactPartInfo;
 use crate::operations::mutation::mutation_meta::ClusterStatsGenType;
 use crate::operations::mutation::mutation_meta::SerializeBlock;
@@ -65,6 +66,7 @@ pub struct CompactSource {
     output: Arc<OutputPort>,
     stream_columns: Vec<StreamColumn>,
     stream_operators: Vec<BlockOperator>,
+    aggregator: Option<Arc<CompactAggregator>>,
 }
 
 impl CompactSource {
@@ -73,6 +75,7 @@ impl CompactSource {
         schema: Arc<TableSchema>,
         storage_format: FuseStorageFormat,
         block_reader: Arc<BlockReader>,
+        aggregator: Option<Arc<CompactAggregator>>,
         output: Arc<OutputPort>,
     ) -> Result<ProcessorPtr> {
         let func_ctx = ctx.get_function_context()?;
@@ -88,6 +91,7 @@ impl CompactSource {
             output,
             stream_columns,
             stream_operators,
+            aggregator,
         })))
     }
 }
@@ -178,6 +182,10 @@ impl Processor for CompactSource {
                 } else {
                     DataBlock::concat(&blocks)?
                 };
+                let block = match &self.aggregator {
+                    Some(aggregator) => aggregator.aggregate(block)?,
+                    None => block,
+                };
 
                 let meta = Box::new(SerializeDataMeta::SerializeBlock(SerializeBlock::create(
                     index,
@@ -20,6 +20,7 @@ mod recluster_aggregator;
 mod recluster_mutator;
 
 pub use compact::BlockCompactMutator;
+pub use compact::CompactAggregator;
 pub use compact::CompactExtraInfo;
 pub use compact::CompactLazyPartInfo;
 pub use compact::CompactPartInfo;
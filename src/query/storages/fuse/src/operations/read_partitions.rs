@@ -78,7 +78,10 @@ impl FuseTable {
                     nodes_num = cluster.nodes.len();
                 }
 
-                if (!dry_run && snapshot.segments.len() > nodes_num) || is_lazy {
+                // The blocks of a bucketed table are routed to the executors by their
+                // buckets, so the segments must be pruned before distributed.
+                let is_bucketed = self.bucket_num_option().is_some();
+                if (!dry_run && snapshot.segments.len() > nodes_num && !is_bucketed) || is_lazy {
                     let mut segments = Vec::with_capacity(snapshot.segments.len());
                     for (idx, segment_location) in snapshot.segments.iter().enumerate() {
                         segments.push(FuseLazyPartInfo::create(idx, segment_location.clone()))
@@ -165,7 +168,13 @@ impl FuseTable {
             }
         }
 
-        let mut pruner = if !self.is_native() || self.cluster_key_meta.is_none() {
+        let cluster_key_history = match &push_downs {
+            Some(push_downs) if push_downs.filters.is_some() => {
+                self.cluster_key_history(ctx.clone())
+            }
+            _ => vec![],
+        };
+        let mut pruner = if cluster_key_history.is_empty() {
             FusePruner::create(
                 &ctx,
                 dal.clone(),
@@ -174,15 +183,24 @@ impl FuseTable {
                 self.bloom_index_cols(),
             )?
         } else {
-            let cluster_keys = self.cluster_keys(ctx.clone());
+            // The pages are only used in native format.
+            let (cluster_key_meta, cluster_keys) = if self.is_native() {
+                (
+                    self.cluster_key_meta.clone(),
+                    self.cluster_keys(ctx.clone()),
+                )
+            } else {
+                (None, vec![])
+            };
 
             FusePruner::create_with_pages(
                 &ctx,
                 dal.clone(),
                 table_schema,
                 &push_downs,
-                self.cluster_key_meta.clone(),
+                cluster_key_meta,
                 cluster_keys,
+                cluster_key_history,
                 self.bloom_index_cols(),
             )?
         };
@@ -451,6 +469,7 @@ impl FuseTable {
         let rows_count = meta.row_count;
         let location = meta.location.0.clone();
         let create_on = meta.create_on;
+        let bucket = meta.cluster_stats.as_ref().and_then(|stats| stats.bucket);
 
         let sort_min_max = top_k.as_ref().map(|(top_k, default)| {
             meta.col_stats
@@ -467,6 +486,7 @@ impl FuseTable {
             sort_min_max,
             block_meta_index.to_owned(),
             create_on,
+            bucket,
         )
     }
 
@@ -492,6 +512,7 @@ impl FuseTable {
         let rows_count = meta.row_count;
         let location = meta.location.0.clone();
         let create_on = meta.create_on;
+        let bucket = meta.cluster_stats.as_ref().and_then(|stats| stats.bucket);
 
         let sort_min_max = top_k.map(|(top_k, default)| {
             let stat = meta.col_stats.get(&top_k.field.column_id);
@@ -510,6 +531,7 @@ impl FuseTable {
             sort_min_max,
             block_meta_index.to_owned(),
             create_on,
+            bucket,
         )
     }
 }
@@ -267,6 +267,7 @@ impl ReplaceIntoMutator {
             ScalarRef::Array(_) => "[ARRAY]".to_owned(),
             ScalarRef::Map(_) => "[MAP]".to_owned(),
             ScalarRef::Bitmap(_) => "[BITMAP]".to_owned(),
+            ScalarRef::Geometry(_) => "[GEOMETRY]".to_owned(),
             ScalarRef::Tuple(_) => "[TUPLE]".to_owned(),
             ScalarRef::Variant(_) => "[VARIANT]".to_owned(),
             // for string, return the first 5 chars
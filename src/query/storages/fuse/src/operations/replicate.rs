@@ -0,0 +1,227 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_base::runtime::execute_futures_in_parallel;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use log::info;
+use opendal::ErrorKind;
+use opendal::Operator;
+use storages_common_table_meta::meta::SnapshotId;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_READ_ONLY;
+
+use crate::io::SnapshotsIO;
+use crate::FuseTable;
+
+/// The state of a replication target, as observed from the primary table.
+#[derive(Clone, Debug, Default)]
+pub struct ReplicationStatus {
+    pub snapshot_id: Option<SnapshotId>,
+    pub snapshot_timestamp: Option<DateTime<Utc>>,
+    pub replicated_snapshot_id: Option<SnapshotId>,
+    pub replicated_timestamp: Option<DateTime<Utc>>,
+}
+
+impl ReplicationStatus {
+    /// Seconds the replica is behind the primary, `None` if nothing has been replicated yet.
+    pub fn lag_seconds(&self) -> Option<u64> {
+        if self.snapshot_id.is_some() && self.snapshot_id == self.replicated_snapshot_id {
+            return Some(0);
+        }
+        match (self.snapshot_timestamp, self.replicated_timestamp) {
+            (Some(primary), Some(replica)) => Some((primary - replica).num_seconds().max(0) as u64),
+            _ => None,
+        }
+    }
+}
+
+impl FuseTable {
+    /// Ships the current snapshot of this table, and every file it references, to `target`.
+    ///
+    /// Files are written under the same relative locations as in the primary storage, so the
+    /// replica can be attached (and later promoted) at `<target root>/<storage prefix>`.
+    /// Files which already exist in the target are skipped, the snapshot itself is written
+    /// last, followed by the last snapshot hint, so an attached replica never observes a
+    /// snapshot whose segments or blocks are still being copied.
+    ///
+    /// Returns the number of files copied.
+    #[async_backtrace::framed]
+    pub async fn do_replicate(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        target: Operator,
+    ) -> Result<usize> {
+        let Some(snapshot_location) = self.snapshot_loc().await? else {
+            return Ok(0);
+        };
+
+        if target.is_exist(&snapshot_location).await? {
+            // already replicated
+            return Ok(0);
+        }
+
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(0);
+        };
+
+        let locations = self
+            .get_block_locations(ctx.clone(), &snapshot.segments, false, false)
+            .await?;
+        let mut files = Vec::with_capacity(
+            snapshot.segments.len()
+                + locations.block_location.len()
+                + locations.bloom_location.len()
+                + 1,
+        );
+        files.extend(snapshot.segments.iter().map(|(loc, _)| loc.clone()));
+        files.extend(locations.block_location);
+        files.extend(locations.bloom_location);
+        files.extend(snapshot.table_statistics_location.clone());
+
+        let tasks = files
+            .into_iter()
+            .map(|location| Self::replicate_file(self.operator.clone(), target.clone(), location));
+        let threads_nums = ctx.get_settings().get_max_threads()? as usize;
+        let copied = execute_futures_in_parallel(
+            tasks,
+            threads_nums,
+            threads_nums * 2,
+            "fuse-replicate-files-worker".to_owned(),
+        )
+        .await?
+        .into_iter()
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .filter(|copied| *copied)
+        .count();
+
+        // do not move the replica backwards, if a newer snapshot is already there
+        let status = self.replication_status(&target).await?;
+        if let (Some(replicated), Some(current)) = (status.replicated_timestamp, snapshot.timestamp)
+        {
+            if replicated > current {
+                return Ok(copied);
+            }
+        }
+
+        Self::replicate_file(
+            self.operator.clone(),
+            target.clone(),
+            snapshot_location.clone(),
+        )
+        .await?;
+        Self::write_last_snapshot_hint(&target, &self.meta_location_generator, snapshot_location)
+            .await;
+
+        info!(
+            "replicated snapshot {} of table {} ({} files copied)",
+            snapshot.snapshot_id, self.table_info.desc, copied
+        );
+        Ok(copied + 1)
+    }
+
+    /// Compares the current snapshot of this table with the one last replicated to `target`.
+    #[async_backtrace::framed]
+    pub async fn replication_status(&self, target: &Operator) -> Result<ReplicationStatus> {
+        let mut status = ReplicationStatus::default();
+        if let Some(snapshot) = self.read_table_snapshot().await? {
+            status.snapshot_id = Some(snapshot.snapshot_id);
+            status.snapshot_timestamp = snapshot.timestamp;
+        }
+
+        let hint = self
+            .meta_location_generator
+            .gen_last_snapshot_hint_location();
+        let hint_content = match target.read(&hint).await {
+            Ok(content) => content,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(status),
+            Err(e) => return Err(e.into()),
+        };
+        let replicated_location = {
+            let full_path = String::from_utf8(hint_content)?;
+            let root = target.info().root().to_string();
+            match full_path.strip_prefix(&root) {
+                Some(location) => location.to_string(),
+                None => full_path,
+            }
+        };
+        let (replicated, _) =
+            SnapshotsIO::read_snapshot(replicated_location, target.clone()).await?;
+        status.replicated_snapshot_id = Some(replicated.snapshot_id);
+        status.replicated_timestamp = replicated.timestamp;
+        Ok(status)
+    }
+
+    /// Turns a read-only attached replica into a regular, writable table.
+    ///
+    /// The table is pinned to the snapshot recorded by the last snapshot hint, that is,
+    /// the latest snapshot which has been completely replicated.
+    #[async_backtrace::framed]
+    pub async fn do_promote_replica(&self, ctx: &dyn TableContext) -> Result<()> {
+        if !Self::is_table_attached_read_only(&self.table_info.meta.options) {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "table {} is not a read-only attached replica",
+                self.table_info.desc
+            )));
+        }
+
+        // for read-only attached tables, snapshot_loc resolves the last snapshot hint
+        let snapshot_location = self.snapshot_loc().await?.ok_or_else(|| {
+            ErrorCode::StorageNotFound(format!(
+                "no replicated snapshot found for table {}",
+                self.table_info.desc
+            ))
+        })?;
+        // make sure the snapshot is readable before promoting
+        SnapshotsIO::read_snapshot(snapshot_location.clone(), self.operator.clone()).await?;
+
+        let mut new_table_meta = self.table_info.meta.clone();
+        new_table_meta
+            .options
+            .remove(OPT_KEY_TABLE_ATTACHED_READ_ONLY);
+        new_table_meta
+            .options
+            .insert(OPT_KEY_SNAPSHOT_LOCATION.to_owned(), snapshot_location);
+
+        let catalog = ctx.get_catalog(&self.table_info.meta.catalog).await?;
+        let req = UpdateTableMetaReq {
+            table_id: self.table_info.ident.table_id,
+            seq: MatchSeq::Exact(self.table_info.ident.seq),
+            new_table_meta,
+            copied_files: None,
+            deduplicated_label: None,
+            update_stream_meta: vec![],
+        };
+        catalog.update_table_meta(&self.table_info, req).await?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn replicate_file(source: Operator, target: Operator, location: String) -> Result<bool> {
+        if target.is_exist(&location).await? {
+            return Ok(false);
+        }
+        let data = source.read(&location).await?;
+        target.write(&location, data).await?;
+        Ok(true)
+    }
+}
@@ -25,12 +25,13 @@ use common_exception::Result;
 use common_expression::BLOCK_NAME_COL_NAME;
 use common_metrics::storage::*;
 use futures_util::future;
+use storages_common_index::PointLookupResult;
 use storages_common_pruner::BlockMetaIndex;
 use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::ColumnMeta;
 use storages_common_table_meta::meta::CompactSegmentInfo;
 
 use super::SegmentLocation;
-use crate::pruning::BloomPruner;
 use crate::pruning::PruningContext;
 
 pub struct BlockPruner {
@@ -48,9 +49,9 @@ impl BlockPruner {
         segment_location: SegmentLocation,
         segment_info: &CompactSegmentInfo,
     ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
-        if let Some(bloom_pruner) = &self.pruning_ctx.bloom_pruner {
-            self.block_pruning(bloom_pruner, segment_location, segment_info)
-                .await
+        if self.pruning_ctx.bloom_pruner.is_some() || self.pruning_ctx.point_lookup_pruner.is_some()
+        {
+            self.block_pruning(segment_location, segment_info).await
         } else {
             // if no available filter pruners, just prune the blocks by
             // using zone map index, and do not spawn async tasks
@@ -58,11 +59,10 @@ impl BlockPruner {
         }
     }
 
-    // async pruning with bloom index.
+    // async pruning with bloom index and point lookup index.
     #[async_backtrace::framed]
     async fn block_pruning(
         &self,
-        bloom_pruner: &Arc<dyn BloomPruner + Send + Sync>,
         segment_location: SegmentLocation,
         segment_info: &CompactSegmentInfo,
     ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
@@ -71,7 +71,10 @@ impl BlockPruner {
         let pruning_semaphore = &self.pruning_ctx.pruning_semaphore;
         let limit_pruner = self.pruning_ctx.limit_pruner.clone();
         let range_pruner = self.pruning_ctx.range_pruner.clone();
+        let cluster_key_pruner = self.pruning_ctx.cluster_key_pruner.clone();
         let page_pruner = self.pruning_ctx.page_pruner.clone();
+        let bloom_pruner = self.pruning_ctx.bloom_pruner.clone();
+        let point_lookup_pruner = self.pruning_ctx.point_lookup_pruner.clone();
 
         let segment_block_metas = segment_info.block_metas()?;
 
@@ -112,7 +115,11 @@ impl BlockPruner {
 
                 let block_meta = block_meta.clone();
                 let row_count = block_meta.row_count;
-                if range_pruner.should_keep(&block_meta.col_stats, Some(&block_meta.col_metas)) {
+                if range_pruner.should_keep(&block_meta.col_stats, Some(&block_meta.col_metas))
+                    && cluster_key_pruner.as_ref().map_or(true, |p| {
+                        p.should_keep_block(&block_meta.cluster_stats, &pruning_stats)
+                    })
+                {
                     // Perf.
                     {
                         metrics_inc_blocks_range_pruning_after(1);
@@ -123,6 +130,7 @@ impl BlockPruner {
 
                     // not pruned by block zone map index,
                     let bloom_pruner = bloom_pruner.clone();
+                    let point_lookup_pruner = point_lookup_pruner.clone();
                     let limit_pruner = limit_pruner.clone();
                     let page_pruner = page_pruner.clone();
                     let index_location = block_meta.bloom_filter_index_location.clone();
@@ -140,10 +148,31 @@ impl BlockPruner {
                             }
 
                             let _permit = permit;
-                            let keep = bloom_pruner
-                                .should_keep(&index_location, index_size, column_ids)
-                                .await
-                                && limit_pruner.within_limit(row_count);
+                            let mut keep = match &bloom_pruner {
+                                Some(bloom_pruner) => {
+                                    bloom_pruner
+                                        .should_keep(&index_location, index_size, column_ids)
+                                        .await
+                                }
+                                None => true,
+                            };
+                            let mut lookup_range = None;
+                            if let Some(point_lookup_pruner) =
+                                point_lookup_pruner.as_ref().filter(|_| keep)
+                            {
+                                match point_lookup_pruner
+                                    .apply(&block_meta.point_lookup_index_location)
+                                    .await
+                                {
+                                    PointLookupResult::MustFalse => keep = false,
+                                    PointLookupResult::Rows(rows) => {
+                                        lookup_range = lookup_page_range(&block_meta, &rows);
+                                        keep = !rows.is_empty();
+                                    }
+                                    PointLookupResult::Uncertain => {}
+                                }
+                            }
+                            let keep = keep && limit_pruner.within_limit(row_count);
 
                             if keep {
                                 // Perf.
@@ -158,6 +187,14 @@ impl BlockPruner {
 
                                 let (keep, range) =
                                     page_pruner.should_keep(&block_meta.cluster_stats);
+                                let (keep, range) = match (range, lookup_range) {
+                                    (Some(range), Some(lookup_range)) => {
+                                        let range = range.start.max(lookup_range.start)
+                                            ..range.end.min(lookup_range.end);
+                                        (keep && !range.is_empty(), Some(range))
+                                    }
+                                    (range, lookup_range) => (keep, range.or(lookup_range)),
+                                };
                                 (block_idx, keep, range, block_meta.location.0.clone())
                             } else {
                                 (block_idx, keep, None, block_meta.location.0.clone())
@@ -228,6 +265,7 @@ impl BlockPruner {
         let pruning_stats = self.pruning_ctx.pruning_stats.clone();
         let limit_pruner = self.pruning_ctx.limit_pruner.clone();
         let range_pruner = self.pruning_ctx.range_pruner.clone();
+        let cluster_key_pruner = self.pruning_ctx.cluster_key_pruner.clone();
         let page_pruner = self.pruning_ctx.page_pruner.clone();
 
         let start = Instant::now();
@@ -262,6 +300,9 @@ impl BlockPruner {
             }
             let row_count = block_meta.row_count;
             if range_pruner.should_keep(&block_meta.col_stats, Some(&block_meta.col_metas))
+                && cluster_key_pruner.as_ref().map_or(true, |p| {
+                    p.should_keep_block(&block_meta.cluster_stats, &pruning_stats)
+                })
                 && limit_pruner.within_limit(row_count)
             {
                 // Perf.
@@ -299,3 +340,16 @@ impl BlockPruner {
         Ok(result)
     }
 }
+
+/// The pages of a native block holding the rows located by the point lookup index.
+fn lookup_page_range(block_meta: &BlockMeta, rows: &[u32]) -> Option<Range<usize>> {
+    if !matches!(
+        block_meta.col_metas.values().next(),
+        Some(ColumnMeta::Native(_))
+    ) {
+        return None;
+    }
+    let page_size = block_meta.page_size() as usize;
+    let (first, last) = (*rows.first()? as usize, *rows.last()? as usize);
+    Some(first / page_size..last / page_size + 1)
+}
@@ -0,0 +1,176 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_expression::types::DataType;
+use common_expression::ConstantFolder;
+use common_expression::Domain;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_functions::BUILTIN_FUNCTIONS;
+use storages_common_index::statistics_to_domain;
+use storages_common_table_meta::meta::ClusterStatistics;
+use storages_common_table_meta::meta::ColumnStatistics;
+
+use crate::pruning::ComputedColumnRewriter;
+use crate::pruning::FusePruningStatistics;
+
+/// Prunes segments and blocks by their cluster statistics.
+///
+/// Altering the cluster key of a table doesn't rewrite the existing blocks, their cluster
+/// statistics keep the values of the key they were written with. So the filter is rewritten
+/// against every version of the cluster key, and the statistics are checked by the filter
+/// of the version they were generated with.
+pub struct ClusterKeyPruner {
+    func_ctx: FunctionContext,
+    filters: HashMap<u32, ClusterKeyFilter>,
+}
+
+struct ClusterKeyFilter {
+    /// The filter with the cluster key expressions replaced by placeholder columns.
+    expr: Expr<String>,
+    column_refs: HashMap<String, DataType>,
+    /// The placeholder columns of the cluster key expressions, in the order of the key.
+    keys: Vec<(String, DataType)>,
+}
+
+impl ClusterKeyPruner {
+    /// `cluster_keys` are the expressions of every version of the cluster key, by the
+    /// cluster key id. Returns `None` if no version can be used to prune.
+    pub fn create(
+        func_ctx: FunctionContext,
+        filter_expr: Option<&Expr<String>>,
+        cluster_keys: &[(u32, Vec<Expr<String>>)],
+    ) -> Option<Arc<Self>> {
+        let filter_expr = filter_expr?;
+        let mut filters = HashMap::new();
+        for (cluster_key_id, exprs) in cluster_keys {
+            let keys = exprs
+                .iter()
+                .enumerate()
+                .map(|(i, expr)| (format!("#cluster_key_{i}"), expr.data_type().clone()))
+                .collect::<Vec<_>>();
+            let rewriter = ComputedColumnRewriter::create(
+                exprs
+                    .iter()
+                    .zip(keys.iter())
+                    .map(|(expr, (name, _))| (expr.clone(), name.clone()))
+                    .collect(),
+            );
+            if let Some(expr) = rewriter.replace(filter_expr) {
+                filters.insert(*cluster_key_id, ClusterKeyFilter {
+                    column_refs: expr.column_refs(),
+                    expr,
+                    keys,
+                });
+            }
+        }
+
+        if filters.is_empty() {
+            None
+        } else {
+            Some(Arc::new(Self { func_ctx, filters }))
+        }
+    }
+
+    /// Same as `should_keep`, and records the segment in the pruning stats.
+    pub fn should_keep_segment(
+        &self,
+        stats: &Option<ClusterStatistics>,
+        pruning_stats: &FusePruningStatistics,
+    ) -> bool {
+        pruning_stats.set_segments_cluster_pruning_before(1);
+        let keep = self.should_keep(stats);
+        if keep {
+            pruning_stats.set_segments_cluster_pruning_after(1);
+        }
+        keep
+    }
+
+    /// Same as `should_keep`, and records the block in the pruning stats.
+    pub fn should_keep_block(
+        &self,
+        stats: &Option<ClusterStatistics>,
+        pruning_stats: &FusePruningStatistics,
+    ) -> bool {
+        pruning_stats.set_blocks_cluster_pruning_before(1);
+        let keep = self.should_keep(stats);
+        if keep {
+            pruning_stats.set_blocks_cluster_pruning_after(1);
+        }
+        keep
+    }
+
+    // returns true, if target should NOT be pruned (false positive allowed)
+    pub fn should_keep(&self, stats: &Option<ClusterStatistics>) -> bool {
+        let Some(stats) = stats else {
+            return true;
+        };
+        let Some(filter) = self.filters.get(&stats.cluster_key_id) else {
+            return true;
+        };
+
+        let mut input_domains = HashMap::with_capacity(filter.column_refs.len());
+        for ((name, data_type), (min, max)) in filter
+            .keys
+            .iter()
+            .zip(stats.min.iter().zip(stats.max.iter()))
+        {
+            // The values of strings are truncated, and NULLs are sorted last,
+            // the statistics are not the bounds of the key anymore.
+            if matches!(data_type.remove_nullable(), DataType::String)
+                || min.is_null()
+                || max.is_null()
+            {
+                break;
+            }
+            if filter.column_refs.contains_key(name) {
+                let stat = ColumnStatistics::new(min.clone(), max.clone(), 1, 0, None);
+                input_domains.insert(name.clone(), statistics_to_domain(vec![&stat], data_type));
+            }
+            // The rows are sorted by the whole key, the following expressions are bounded
+            // only if all the leading ones are constant.
+            if min != max {
+                break;
+            }
+        }
+
+        if input_domains.is_empty() {
+            return true;
+        }
+
+        // Fill missing stats to be full domain
+        for (name, ty) in filter.column_refs.iter() {
+            if !input_domains.contains_key(name.as_str()) {
+                input_domains.insert(name.clone(), Domain::full(ty));
+            }
+        }
+
+        let (new_expr, _) = ConstantFolder::fold_with_domain(
+            &filter.expr,
+            &input_domains,
+            &self.func_ctx,
+            &BUILTIN_FUNCTIONS,
+        );
+
+        // Only return false, which means to skip this block, when the expression is folded to a constant false.
+        !matches!(new_expr, Expr::Constant {
+            scalar: Scalar::Boolean(false),
+            ..
+        })
+    }
+}
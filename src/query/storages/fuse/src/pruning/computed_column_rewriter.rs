@@ -0,0 +1,188 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::type_check::check_function;
+use common_expression::ComputedExpr;
+use common_expression::DataSchema;
+use common_expression::Expr;
+use common_expression::TableSchemaRef;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_sql::parse_computed_expr;
+
+/// Makes the stored computed columns work as zone maps of their expressions.
+///
+/// The min/max of a stored computed column, e.g. `d DATE AS (to_date(ts)) STORED`, are kept
+/// in the block statistics like any other column. If the pushed down filter contains the
+/// expression of such a column, e.g. `to_date(ts) = '2024-01-01'`, a copy of the filter
+/// referring to the column instead is appended, so the pruners can use its statistics.
+pub struct ComputedColumnRewriter {
+    /// The expressions of the stored computed columns and the names of the columns.
+    exprs: Vec<(Expr<String>, String)>,
+}
+
+impl ComputedColumnRewriter {
+    /// Replaces the given expressions by the columns of the given names.
+    pub fn create(exprs: Vec<(Expr<String>, String)>) -> Self {
+        let exprs = exprs
+            .iter()
+            .map(|(expr, name)| (normalize(expr), name.clone()))
+            .collect();
+        Self { exprs }
+    }
+
+    pub fn try_create(ctx: &Arc<dyn TableContext>, table_schema: &TableSchemaRef) -> Result<Self> {
+        let mut exprs = Vec::new();
+        if !table_schema
+            .fields()
+            .iter()
+            .any(|f| matches!(f.computed_expr(), Some(ComputedExpr::Stored(_))))
+        {
+            return Ok(Self { exprs });
+        }
+
+        let schema = Arc::new(DataSchema::from(
+            table_schema.remove_virtual_computed_fields(),
+        ));
+        for field in table_schema.fields() {
+            if let Some(ComputedExpr::Stored(sql)) = field.computed_expr() {
+                let expr = parse_computed_expr(ctx.clone(), schema.clone(), sql)?
+                    .project_column_ref(|index| schema.field(*index).name().clone());
+                exprs.push((normalize(&expr), field.name().clone()));
+            }
+        }
+        Ok(Self { exprs })
+    }
+
+    pub fn rewrite(&self, filter: Expr<String>) -> Result<Expr<String>> {
+        if self.exprs.is_empty() {
+            return Ok(filter);
+        }
+        match self.replace(&filter) {
+            Some(rewritten) => check_function(
+                None,
+                "and_filters",
+                &[],
+                &[filter, rewritten],
+                &BUILTIN_FUNCTIONS,
+            ),
+            None => Ok(filter),
+        }
+    }
+
+    /// Returns the expression with the known expressions replaced by their columns,
+    /// or `None` if there is nothing to replace.
+    pub fn replace(&self, expr: &Expr<String>) -> Option<Expr<String>> {
+        if !matches!(expr, Expr::ColumnRef { .. } | Expr::Constant { .. }) {
+            let normalized = normalize(expr);
+            if let Some((_, name)) = self.exprs.iter().find(|(e, _)| e == &normalized) {
+                return Some(Expr::ColumnRef {
+                    span: expr.span(),
+                    id: name.clone(),
+                    data_type: expr.data_type().clone(),
+                    display_name: name.clone(),
+                });
+            }
+        }
+
+        match expr {
+            Expr::Cast {
+                span,
+                is_try,
+                expr,
+                dest_type,
+            } => self.replace(expr).map(|expr| Expr::Cast {
+                span: *span,
+                is_try: *is_try,
+                expr: Box::new(expr),
+                dest_type: dest_type.clone(),
+            }),
+            Expr::FunctionCall {
+                span,
+                id,
+                function,
+                generics,
+                args,
+                return_type,
+            } => {
+                let replaced = args.iter().map(|arg| self.replace(arg)).collect::<Vec<_>>();
+                if replaced.iter().all(Option::is_none) {
+                    return None;
+                }
+                let args = replaced
+                    .into_iter()
+                    .zip(args)
+                    .map(|(replaced, arg)| replaced.unwrap_or_else(|| arg.clone()))
+                    .collect();
+                Some(Expr::FunctionCall {
+                    span: *span,
+                    id: id.clone(),
+                    function: function.clone(),
+                    generics: generics.clone(),
+                    args,
+                    return_type: return_type.clone(),
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The display names of the column references depend on how the query spells them,
+/// so they are reset to the column names before comparing expressions.
+fn normalize(expr: &Expr<String>) -> Expr<String> {
+    match expr {
+        Expr::ColumnRef {
+            span,
+            id,
+            data_type,
+            ..
+        } => Expr::ColumnRef {
+            span: *span,
+            id: id.clone(),
+            data_type: data_type.clone(),
+            display_name: id.clone(),
+        },
+        Expr::Cast {
+            span,
+            is_try,
+            expr,
+            dest_type,
+        } => Expr::Cast {
+            span: *span,
+            is_try: *is_try,
+            expr: Box::new(normalize(expr)),
+            dest_type: dest_type.clone(),
+        },
+        Expr::FunctionCall {
+            span,
+            id,
+            function,
+            generics,
+            args,
+            return_type,
+        } => Expr::FunctionCall {
+            span: *span,
+            id: id.clone(),
+            function: function.clone(),
+            generics: generics.clone(),
+            args: args.iter().map(normalize).collect(),
+            return_type: return_type.clone(),
+        },
+        _ => expr.clone(),
+    }
+}
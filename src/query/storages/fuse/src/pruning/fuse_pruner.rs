@@ -49,7 +49,10 @@ use crate::pruning::segment_pruner::SegmentPruner;
 use crate::pruning::BlockPruner;
 use crate::pruning::BloomPruner;
 use crate::pruning::BloomPrunerCreator;
+use crate::pruning::ClusterKeyPruner;
+use crate::pruning::ComputedColumnRewriter;
 use crate::pruning::FusePruningStatistics;
+use crate::pruning::PointLookupPruner;
 use crate::pruning::SegmentLocation;
 
 pub struct PruningContext {
@@ -61,7 +64,9 @@ pub struct PruningContext {
     pub limit_pruner: Arc<dyn Limiter + Send + Sync>,
     pub range_pruner: Arc<dyn RangePruner + Send + Sync>,
     pub bloom_pruner: Option<Arc<dyn BloomPruner + Send + Sync>>,
+    pub point_lookup_pruner: Option<Arc<PointLookupPruner>>,
     pub page_pruner: Arc<dyn PagePruner + Send + Sync>,
+    pub cluster_key_pruner: Option<Arc<ClusterKeyPruner>>,
     pub internal_column_pruner: Option<Arc<InternalColumnPruner>>,
 
     pub pruning_stats: Arc<FusePruningStatistics>,
@@ -76,6 +81,7 @@ impl PruningContext {
         push_down: &Option<PushDownInfo>,
         cluster_key_meta: Option<ClusterKey>,
         cluster_keys: Vec<RemoteExpr<String>>,
+        cluster_key_history: Vec<(u32, Vec<RemoteExpr<String>>)>,
         bloom_index_cols: BloomIndexColumns,
         max_concurrency: usize,
     ) -> Result<Arc<PruningContext>> {
@@ -87,6 +93,12 @@ impl PruningContext {
                 .as_ref()
                 .map(|f| f.filter.as_expr(&BUILTIN_FUNCTIONS))
         });
+        let filter_expr = match filter_expr {
+            Some(expr) => {
+                Some(ComputedColumnRewriter::try_create(ctx, &table_schema)?.rewrite(expr)?)
+            }
+            None => None,
+        };
 
         // Limit pruner.
         // if there are ordering/filter clause, ignore limit, even it has been pushed down
@@ -131,6 +143,15 @@ impl PruningContext {
             bloom_index_cols,
         )?;
 
+        // Point lookup pruner.
+        // None will be returned, if there is no `column = <constant>` in the filter.
+        let point_lookup_pruner = PointLookupPruner::create(
+            func_ctx.clone(),
+            &table_schema,
+            dal.clone(),
+            filter_expr.as_ref(),
+        )?;
+
         // Page pruner, used in native format
         let page_pruner = PagePrunerCreator::try_create(
             func_ctx.clone(),
@@ -140,6 +161,18 @@ impl PruningContext {
             cluster_keys,
         )?;
 
+        // Cluster key pruner.
+        // None will be returned, if the filter doesn't use the expressions of any cluster key.
+        let cluster_key_history = cluster_key_history
+            .iter()
+            .map(|(id, keys)| {
+                let keys = keys.iter().map(|k| k.as_expr(&BUILTIN_FUNCTIONS)).collect();
+                (*id, keys)
+            })
+            .collect::<Vec<_>>();
+        let cluster_key_pruner =
+            ClusterKeyPruner::create(func_ctx.clone(), filter_expr.as_ref(), &cluster_key_history);
+
         // Internal column pruner, if there are predicates using internal columns,
         // we can use them to prune segments and blocks.
         let internal_column_pruner =
@@ -164,7 +197,9 @@ impl PruningContext {
             limit_pruner,
             range_pruner,
             bloom_pruner,
+            point_lookup_pruner,
             page_pruner,
+            cluster_key_pruner,
             internal_column_pruner,
             pruning_stats,
         });
@@ -197,6 +232,7 @@ impl FusePruner {
             push_down,
             None,
             vec![],
+            vec![],
             bloom_index_cols,
         )
     }
@@ -209,6 +245,7 @@ impl FusePruner {
         push_down: &Option<PushDownInfo>,
         cluster_key_meta: Option<ClusterKey>,
         cluster_keys: Vec<RemoteExpr<String>>,
+        cluster_key_history: Vec<(u32, Vec<RemoteExpr<String>>)>,
         bloom_index_cols: BloomIndexColumns,
     ) -> Result<Self> {
         let max_concurrency = {
@@ -231,6 +268,7 @@ impl FusePruner {
             push_down,
             cluster_key_meta,
             cluster_keys,
+            cluster_key_history,
             bloom_index_cols,
             max_concurrency,
         )?;
@@ -417,6 +455,11 @@ impl FusePruner {
         let blocks_bloom_pruning_before = stats.get_blocks_bloom_pruning_before() as usize;
         let blocks_bloom_pruning_after = stats.get_blocks_bloom_pruning_after() as usize;
 
+        let segments_cluster_pruning_before = stats.get_segments_cluster_pruning_before() as usize;
+        let segments_cluster_pruning_after = stats.get_segments_cluster_pruning_after() as usize;
+        let blocks_cluster_pruning_before = stats.get_blocks_cluster_pruning_before() as usize;
+        let blocks_cluster_pruning_after = stats.get_blocks_cluster_pruning_after() as usize;
+
         common_catalog::plan::PruningStatistics {
             segments_range_pruning_before,
             segments_range_pruning_after,
@@ -424,6 +467,10 @@ impl FusePruner {
             blocks_range_pruning_after,
             blocks_bloom_pruning_before,
             blocks_bloom_pruning_after,
+            segments_cluster_pruning_before,
+            segments_cluster_pruning_after,
+            blocks_cluster_pruning_before,
+            blocks_cluster_pruning_after,
         }
     }
 
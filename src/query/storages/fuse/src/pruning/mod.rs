@@ -14,7 +14,10 @@
 
 mod block_pruner;
 mod bloom_pruner;
+mod cluster_key_pruner;
+mod computed_column_rewriter;
 mod fuse_pruner;
+mod point_lookup_pruner;
 mod pruner_location;
 mod pruning_statistics;
 mod segment_pruner;
@@ -22,8 +25,11 @@ mod segment_pruner;
 pub use block_pruner::BlockPruner;
 pub use bloom_pruner::BloomPruner;
 pub use bloom_pruner::BloomPrunerCreator;
+pub use cluster_key_pruner::ClusterKeyPruner;
+pub use computed_column_rewriter::ComputedColumnRewriter;
 pub use fuse_pruner::FusePruner;
 pub use fuse_pruner::PruningContext;
+pub use point_lookup_pruner::PointLookupPruner;
 pub use pruner_location::create_segment_location_vector;
 pub use pruner_location::SegmentLocation;
 pub use pruning_statistics::FusePruningStatistics;
@@ -0,0 +1,123 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::TableSchemaRef;
+use log::warn;
+use opendal::Operator;
+use storages_common_index::BloomIndex;
+use storages_common_index::Index;
+use storages_common_index::PointLookupIndex;
+use storages_common_index::PointLookupResult;
+use storages_common_table_meta::meta::Location;
+
+/// Prunes the blocks by the point lookup indexes of the key column, for the
+/// `key = <constant>` patterns of the filter.
+pub struct PointLookupPruner {
+    func_ctx: FunctionContext,
+
+    /// the expression that would be evaluate
+    filter_expression: Expr<String>,
+
+    /// pre calculated digest for the constants compared with each column
+    scalar_maps: HashMap<String, HashMap<Scalar, u64>>,
+
+    /// the data accessor
+    dal: Operator,
+
+    /// the schema of data being indexed
+    data_schema: TableSchemaRef,
+}
+
+impl PointLookupPruner {
+    /// The key column is only known once an index is loaded, so the digests of the
+    /// constants are calculated for every column that may be a key.
+    pub fn create(
+        func_ctx: FunctionContext,
+        schema: &TableSchemaRef,
+        dal: Operator,
+        filter_expr: Option<&Expr<String>>,
+    ) -> Result<Option<Arc<PointLookupPruner>>> {
+        let Some(expr) = filter_expr else {
+            return Ok(None);
+        };
+        let mut scalar_maps: HashMap<String, HashMap<Scalar, u64>> = HashMap::new();
+        for field in schema.fields() {
+            if !PointLookupIndex::supported_type(&field.data_type().into()) {
+                continue;
+            }
+            for (scalar, ty) in PointLookupIndex::find_eq_scalars(expr, field)? {
+                let digest = BloomIndex::calculate_scalar_digest(&func_ctx, &scalar, &ty)?;
+                scalar_maps
+                    .entry(field.name().clone())
+                    .or_default()
+                    .insert(scalar, digest);
+            }
+        }
+        if scalar_maps.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(Arc::new(PointLookupPruner {
+            func_ctx,
+            filter_expression: expr.clone(),
+            scalar_maps,
+            dal,
+            data_schema: schema.clone(),
+        })))
+    }
+
+    /// Apply the filter to the point lookup index of a block, the index is ignored
+    /// if it fails to be loaded.
+    #[async_backtrace::framed]
+    pub async fn apply(&self, index_location: &Option<Location>) -> PointLookupResult {
+        let Some((location, _)) = index_location else {
+            return PointLookupResult::Uncertain;
+        };
+        match self.do_apply(location).await {
+            Ok(result) => result,
+            Err(e) => {
+                warn!("failed to apply point lookup pruner, ignored. {}", e);
+                PointLookupResult::Uncertain
+            }
+        }
+    }
+
+    async fn do_apply(&self, location: &str) -> Result<PointLookupResult> {
+        let data = self.dal.read(location).await?;
+        let index = PointLookupIndex::from_bytes(&data)?;
+        let Some(key) = self
+            .data_schema
+            .fields()
+            .iter()
+            .find(|f| f.column_id() == index.column_id)
+        else {
+            return Ok(PointLookupResult::Uncertain);
+        };
+        let Some(scalar_map) = self.scalar_maps.get(key.name()) else {
+            return Ok(PointLookupResult::Uncertain);
+        };
+        index.apply(
+            &self.func_ctx,
+            &self.filter_expression,
+            key.name(),
+            scalar_map,
+        )
+    }
+}
@@ -28,6 +28,12 @@ pub struct FusePruningStatistics {
     /// Block bloom filter pruning stats.
     pub blocks_bloom_pruning_before: AtomicU64,
     pub blocks_bloom_pruning_after: AtomicU64,
+
+    /// Cluster key pruning stats.
+    pub segments_cluster_pruning_before: AtomicU64,
+    pub segments_cluster_pruning_after: AtomicU64,
+    pub blocks_cluster_pruning_before: AtomicU64,
+    pub blocks_cluster_pruning_after: AtomicU64,
 }
 
 impl FusePruningStatistics {
@@ -84,4 +90,40 @@ impl FusePruningStatistics {
     pub fn get_blocks_bloom_pruning_after(&self) -> u64 {
         self.blocks_bloom_pruning_after.load(Ordering::Relaxed)
     }
+
+    pub fn set_segments_cluster_pruning_before(&self, v: u64) {
+        self.segments_cluster_pruning_before
+            .fetch_add(v, Ordering::Relaxed);
+    }
+
+    pub fn get_segments_cluster_pruning_before(&self) -> u64 {
+        self.segments_cluster_pruning_before.load(Ordering::Relaxed)
+    }
+
+    pub fn set_segments_cluster_pruning_after(&self, v: u64) {
+        self.segments_cluster_pruning_after
+            .fetch_add(v, Ordering::Relaxed);
+    }
+
+    pub fn get_segments_cluster_pruning_after(&self) -> u64 {
+        self.segments_cluster_pruning_after.load(Ordering::Relaxed)
+    }
+
+    pub fn set_blocks_cluster_pruning_before(&self, v: u64) {
+        self.blocks_cluster_pruning_before
+            .fetch_add(v, Ordering::Relaxed);
+    }
+
+    pub fn get_blocks_cluster_pruning_before(&self) -> u64 {
+        self.blocks_cluster_pruning_before.load(Ordering::Relaxed)
+    }
+
+    pub fn set_blocks_cluster_pruning_after(&self, v: u64) {
+        self.blocks_cluster_pruning_after
+            .fetch_add(v, Ordering::Relaxed);
+    }
+
+    pub fn get_blocks_cluster_pruning_after(&self) -> u64 {
+        self.blocks_cluster_pruning_after.load(Ordering::Relaxed)
+    }
 }
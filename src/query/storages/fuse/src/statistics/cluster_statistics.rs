@@ -15,7 +15,9 @@
 use std::cmp::Ordering;
 
 use common_exception::Result;
+use common_expression::group_hash_columns;
 use common_expression::BlockThresholds;
+use common_expression::Column;
 use common_expression::DataBlock;
 use common_expression::DataField;
 use common_expression::FunctionContext;
@@ -38,6 +40,7 @@ pub struct ClusterStatsGenerator {
 
     level: i32,
     block_thresholds: BlockThresholds,
+    bucket_num: Option<u32>,
 
     pub cluster_key_index: Vec<usize>,
     pub operators: Vec<BlockOperator>,
@@ -65,12 +68,23 @@ impl ClusterStatsGenerator {
             max_page_size,
             level,
             block_thresholds,
+            bucket_num: None,
             operators,
             out_fields,
             func_ctx,
         }
     }
 
+    /// Records the bucket of the blocks whose rows belong to the same bucket.
+    pub fn with_bucket_num(mut self, bucket_num: Option<u32>) -> Self {
+        self.bucket_num = bucket_num;
+        self
+    }
+
+    pub fn bucket_num(&self) -> Option<u32> {
+        self.bucket_num
+    }
+
     pub fn is_cluster(&self) -> bool {
         !self.cluster_key_index.is_empty()
     }
@@ -117,7 +131,28 @@ impl ClusterStatsGenerator {
             .iter()
             .try_fold(block, |input, op| op.execute(&self.func_ctx, input))?;
 
-        self.clusters_statistics(&block, origin_stats.level)
+        // The remaining rows still belong to the bucket of the origin block.
+        let stats = self.clusters_statistics(&block, origin_stats.level)?;
+        Ok(stats.map(|stats| stats.with_bucket(origin_stats.bucket)))
+    }
+
+    /// Returns the bucket of every row by the cluster keys.
+    pub fn bucket_ids(&self, data_block: &DataBlock, bucket_num: u32) -> Vec<u32> {
+        let num_rows = data_block.num_rows();
+        let keys = self
+            .cluster_key_index
+            .iter()
+            .map(|key| {
+                let entry = data_block.get_by_offset(*key);
+                entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, num_rows)
+            })
+            .collect::<Vec<Column>>();
+        group_hash_columns(&keys)
+            .into_iter()
+            .map(|hash| (hash % bucket_num as u64) as u32)
+            .collect()
     }
 
     /// for string value, only use the first 8 bytes.
@@ -180,13 +215,20 @@ impl ClusterStatsGenerator {
             None
         };
 
-        Ok(Some(ClusterStatistics::new(
-            self.cluster_key_id,
-            min,
-            max,
-            level,
-            pages,
-        )))
+        let bucket = match self.bucket_num {
+            Some(bucket_num) if data_block.num_rows() > 0 => {
+                let buckets = self.bucket_ids(data_block, bucket_num);
+                buckets
+                    .iter()
+                    .all(|bucket| *bucket == buckets[0])
+                    .then_some(buckets[0])
+            }
+            _ => None,
+        };
+
+        Ok(Some(
+            ClusterStatistics::new(self.cluster_key_id, min, max, level, pages).with_bucket(bucket),
+        ))
     }
 }
 
@@ -0,0 +1,189 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::types::number::NumberScalar;
+use common_expression::Scalar;
+use storages_common_table_meta::meta::ColumnHistogram;
+use storages_common_table_meta::meta::ColumnHistogramBucket;
+
+pub const DEFAULT_HISTOGRAM_BUCKETS: usize = 100;
+
+/// The number of equal-width slots that the value range of a column is divided into,
+/// to accumulate the values of the blocks.
+const HISTOGRAM_SLOTS: usize = 4096;
+
+/// Builds the equi-depth histogram of a column from the min, max and number of values
+/// of its blocks, the values of a block are assumed to spread uniformly between its
+/// min and max value.
+///
+/// The blocks are accumulated into equal-width slots over the value range of the
+/// column, which are then cut into buckets of the same number of values.
+pub struct HistogramBuilder {
+    min: f64,
+    max: f64,
+    slot_width: f64,
+    // the number of values falling in each slot
+    slots: Vec<f64>,
+    // difference array of the values spread over whole slots, which are resolved
+    // into `slots` when the histogram is built
+    spread: Vec<f64>,
+}
+
+impl HistogramBuilder {
+    pub fn new(min: f64, max: f64) -> Self {
+        Self {
+            min,
+            max,
+            slot_width: (max - min) / HISTOGRAM_SLOTS as f64,
+            slots: vec![0.0; HISTOGRAM_SLOTS],
+            spread: vec![0.0; HISTOGRAM_SLOTS + 1],
+        }
+    }
+
+    pub fn add_block(&mut self, min: f64, max: f64, num_values: u64) {
+        if num_values == 0 || !min.is_finite() || !max.is_finite() {
+            return;
+        }
+        let num_values = num_values as f64;
+        let min = min.clamp(self.min, self.max);
+        let max = max.clamp(min, self.max);
+        let (first, last) = (self.slot_of(min), self.slot_of(max));
+        if first == last {
+            self.slots[first] += num_values;
+            return;
+        }
+
+        let density = num_values / (max - min);
+        // the partial slots at both ends
+        self.slots[first] += density * (self.slot_lower_bound(first + 1) - min).max(0.0);
+        self.slots[last] += density * (max - self.slot_lower_bound(last)).max(0.0);
+        // the whole slots in between
+        if last > first + 1 {
+            self.spread[first + 1] += density * self.slot_width;
+            self.spread[last] -= density * self.slot_width;
+        }
+    }
+
+    /// Build the histogram of at most `num_buckets` buckets, `ndv` is the number of
+    /// distinct values of the column.
+    pub fn build(&self, ndv: u64, num_buckets: usize) -> Option<ColumnHistogram> {
+        let mut slots = self.slots.clone();
+        let mut spread = 0.0;
+        for (slot, diff) in slots.iter_mut().zip(self.spread.iter()) {
+            spread += diff;
+            *slot += spread;
+        }
+        let total: f64 = slots.iter().sum();
+        if total < 1.0 || num_buckets == 0 {
+            return None;
+        }
+        let total_values = total.round() as u64;
+        if self.slot_width == 0.0 {
+            return Some(ColumnHistogram {
+                lower_bound: self.min,
+                buckets: vec![ColumnHistogramBucket {
+                    upper_bound: self.max,
+                    num_values: total_values,
+                    num_distinct: 1,
+                }],
+            });
+        }
+
+        // every bucket holds at least one value
+        let num_buckets = num_buckets
+            .min(ndv.max(1) as usize)
+            .min(total_values as usize);
+        let depth = total / num_buckets as f64;
+        let mut buckets: Vec<ColumnHistogramBucket> = Vec::with_capacity(num_buckets);
+        let mut lower_bound = self.min;
+        let mut slot = 0;
+        let mut accumulated = 0.0;
+        let mut assigned = 0;
+        for idx in 1..=num_buckets {
+            let upper_bound = if idx == num_buckets {
+                self.max
+            } else {
+                // find the value below which `idx * depth` values fall
+                let target = depth * idx as f64;
+                while slot < HISTOGRAM_SLOTS - 1 && accumulated + slots[slot] < target {
+                    accumulated += slots[slot];
+                    slot += 1;
+                }
+                let ratio = if slots[slot] > 0.0 {
+                    ((target - accumulated) / slots[slot]).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                };
+                (self.slot_lower_bound(slot) + ratio * self.slot_width).min(self.max)
+            };
+            let num_values = (depth * idx as f64).round() as u64 - assigned;
+            assigned += num_values;
+
+            match buckets.last_mut() {
+                // the buckets cut in the same value are merged, e.g. a frequent value
+                Some(last) if upper_bound <= last.upper_bound => {
+                    last.num_values += num_values;
+                }
+                _ => {
+                    let width = (upper_bound - lower_bound) / (self.max - self.min);
+                    let num_distinct = ((ndv as f64 * width).round() as u64).min(num_values).max(1);
+                    buckets.push(ColumnHistogramBucket {
+                        upper_bound,
+                        num_values,
+                        num_distinct,
+                    });
+                    lower_bound = upper_bound;
+                }
+            }
+        }
+
+        Some(ColumnHistogram {
+            lower_bound: self.min,
+            buckets,
+        })
+    }
+
+    fn slot_of(&self, value: f64) -> usize {
+        if self.slot_width == 0.0 {
+            return 0;
+        }
+        (((value - self.min) / self.slot_width) as usize).min(HISTOGRAM_SLOTS - 1)
+    }
+
+    fn slot_lower_bound(&self, slot: usize) -> f64 {
+        self.min + self.slot_width * slot as f64
+    }
+}
+
+/// The value of a numeric, date or timestamp scalar in a histogram.
+pub fn histogram_value_of(scalar: &Scalar) -> Option<f64> {
+    let value = match scalar {
+        Scalar::Number(NumberScalar::Int8(v)) => *v as f64,
+        Scalar::Number(NumberScalar::Int16(v)) => *v as f64,
+        Scalar::Number(NumberScalar::Int32(v)) => *v as f64,
+        Scalar::Number(NumberScalar::Int64(v)) => *v as f64,
+        Scalar::Number(NumberScalar::UInt8(v)) => *v as f64,
+        Scalar::Number(NumberScalar::UInt16(v)) => *v as f64,
+        Scalar::Number(NumberScalar::UInt32(v)) => *v as f64,
+        Scalar::Number(NumberScalar::UInt64(v)) => *v as f64,
+        Scalar::Number(NumberScalar::UInt128(v)) => *v as f64,
+        Scalar::Number(NumberScalar::Int128(v)) => *v as f64,
+        Scalar::Number(NumberScalar::Float32(v)) => v.into_inner() as f64,
+        Scalar::Number(NumberScalar::Float64(v)) => v.into_inner(),
+        Scalar::Date(v) => *v as f64,
+        Scalar::Timestamp(v) => *v as f64,
+        _ => return None,
+    };
+    value.is_finite().then_some(value)
+}
@@ -95,6 +95,7 @@ pub fn reduce_cluster_statistics<T: Borrow<Option<ClusterStatistics>>>(
     let mut min_stats = Vec::with_capacity(len);
     let mut max_stats = Vec::with_capacity(len);
     let mut levels = Vec::with_capacity(len);
+    let mut buckets = Vec::with_capacity(len);
 
     for cluster_stats in blocks_cluster_stats.iter() {
         if let Some(stat) = cluster_stats.borrow() {
@@ -105,6 +106,7 @@ pub fn reduce_cluster_statistics<T: Borrow<Option<ClusterStatistics>>>(
             min_stats.push(stat.min());
             max_stats.push(stat.max());
             levels.push(stat.level);
+            buckets.push(stat.bucket);
         } else {
             return None;
         }
@@ -119,14 +121,13 @@ pub fn reduce_cluster_statistics<T: Borrow<Option<ClusterStatistics>>>(
         .max_by(|x, y| x.iter().cmp_by(y.iter(), cmp_with_null))
         .unwrap();
     let level = levels.into_iter().max().unwrap_or(0);
+    let bucket = buckets
+        .iter()
+        .all(|bucket| bucket == &buckets[0])
+        .then_some(buckets[0])
+        .flatten();
 
-    Some(ClusterStatistics::new(
-        cluster_key_id,
-        min,
-        max,
-        level,
-        None,
-    ))
+    Some(ClusterStatistics::new(cluster_key_id, min, max, level, None).with_bucket(bucket))
 }
 
 pub fn merge_statistics(
@@ -73,6 +73,14 @@ pub(crate) fn str_field_to_scalar(value: &str, data_type: &DataType) -> Result<S
                 let num = value.parse::<f64>().unwrap();
                 Ok(Scalar::Number(NumberScalar::Float64(OrderedFloat(num))))
             }
+            NumberDataType::UInt128 => {
+                let num = value.parse::<u128>().unwrap();
+                Ok(Scalar::Number(NumberScalar::UInt128(num)))
+            }
+            NumberDataType::Int128 => {
+                let num = value.parse::<i128>().unwrap();
+                Ok(Scalar::Number(NumberScalar::Int128(num)))
+            }
         },
         _ => Err(ErrorCode::Unimplemented(format!(
             "generate scalar failed, {:?}",
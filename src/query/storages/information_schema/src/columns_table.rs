@@ -27,38 +27,38 @@ pub struct ColumnsTable {}
 impl ColumnsTable {
     pub fn create(table_id: u64) -> Arc<dyn Table> {
         let query = "SELECT
-            database AS table_catalog,
+            'default' AS table_catalog,
             database AS table_schema,
             table AS table_name,
             name AS column_name,
-            1 AS ordinal_position,
-            NULL AS column_default,
-            NULL AS column_comment,
-            NULL AS column_key,
+            ordinal_position AS ordinal_position,
+            NULLIF(default_expression, '') AS column_default,
+            comment AS column_comment,
+            '' AS column_key,
             case when is_nullable='NO' then 0
             when is_nullable='YES' then 1
             end as nullable,
             is_nullable AS is_nullable,
-            type AS data_type,
-            data_type AS column_type,
-            NULL AS character_maximum_length,
-            NULL AS character_octet_length,
-            NULL AS numeric_precision,
-            NULL AS numeric_precision_radix,
-            NULL AS numeric_scale,
-            NULL AS datetime_precision,
-            NULL AS character_set_catalog,
-            NULL AS character_set_schema,
-            NULL AS character_set_name,
-            NULL AS collation_catalog,
-            NULL AS collation_schema,
-            NULL AS collation_name,
-            NULL AS domain_catalog,
-            NULL AS domain_schema,
-            NULL AS domain_name,
-            NULL AS privileges,
+            lower(split_part(split_part(data_type, '(', 1), ' ', 1)) AS data_type,
+            lower(replace(data_type, ', ', ',')) AS column_type,
+            CAST(NULL AS BIGINT UNSIGNED NULL) AS character_maximum_length,
+            CAST(NULL AS BIGINT UNSIGNED NULL) AS character_octet_length,
+            numeric_precision AS numeric_precision,
+            CAST(CASE WHEN numeric_precision IS NULL THEN NULL ELSE 10 END AS BIGINT UNSIGNED NULL) AS numeric_precision_radix,
+            numeric_scale AS numeric_scale,
+            datetime_precision AS datetime_precision,
+            CAST(NULL AS VARCHAR NULL) AS character_set_catalog,
+            CAST(NULL AS VARCHAR NULL) AS character_set_schema,
+            CAST(NULL AS VARCHAR NULL) AS character_set_name,
+            CAST(NULL AS VARCHAR NULL) AS collation_catalog,
+            CAST(NULL AS VARCHAR NULL) AS collation_schema,
+            CAST(NULL AS VARCHAR NULL) AS collation_name,
+            CAST(NULL AS VARCHAR NULL) AS domain_catalog,
+            CAST(NULL AS VARCHAR NULL) AS domain_schema,
+            CAST(NULL AS VARCHAR NULL) AS domain_name,
+            'select,insert,update,references' AS privileges,
             default_expression as default,
-            NULL AS extra
+            '' AS extra
         FROM system.columns;";
 
         let mut options = BTreeMap::new();
@@ -27,18 +27,19 @@ pub struct KeyColumnUsageTable {}
 impl KeyColumnUsageTable {
     pub fn create(table_id: u64) -> Arc<dyn Table> {
         let query = "SELECT \
-        NULL as constraint_catalog, \
-        NULL as constraint_schema, \
-        NULL as constraint_name, \
-        NULL as table_catalog, \
-        NULL as table_schema, \
-        NULL as table_name, \
-        NULL as column_name, \
-        NULL as ordinal_position, \
-        NULL as position_in_unique_constraint, \
-        NULL as referenced_table_schema, \
-        NULL as referenced_table_name, \
-        NULL as referenced_column_name"
+        '' as constraint_catalog, \
+        '' as constraint_schema, \
+        '' as constraint_name, \
+        '' as table_catalog, \
+        '' as table_schema, \
+        '' as table_name, \
+        '' as column_name, \
+        CAST(0 AS BIGINT UNSIGNED) as ordinal_position, \
+        CAST(NULL AS BIGINT UNSIGNED NULL) as position_in_unique_constraint, \
+        CAST(NULL AS VARCHAR NULL) as referenced_table_schema, \
+        CAST(NULL AS VARCHAR NULL) as referenced_table_name, \
+        CAST(NULL AS VARCHAR NULL) as referenced_column_name \
+        WHERE 1 = 0"
             .to_string();
 
         let mut options = BTreeMap::new();
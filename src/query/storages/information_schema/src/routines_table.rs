@@ -0,0 +1,65 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_catalog::table::Table;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_storages_view::view_table::ViewTable;
+use common_storages_view::view_table::QUERY;
+
+pub struct RoutinesTable {}
+
+impl RoutinesTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let query = "SELECT
+            name AS specific_name,
+            'default' AS routine_catalog,
+            'default' AS routine_schema,
+            name AS routine_name,
+            'FUNCTION' AS routine_type,
+            '' AS data_type,
+            'SQL' AS routine_body,
+            definition AS routine_definition,
+            CAST(NULL AS VARCHAR NULL) AS external_name,
+            'SQL' AS external_language,
+            'SQL' AS parameter_style,
+            'NO' AS is_deterministic,
+            'CONTAINS SQL' AS sql_data_access,
+            CAST(NULL AS VARCHAR NULL) AS sql_path,
+            'DEFINER' AS security_type,
+            description AS routine_comment
+        FROM system.functions
+        WHERE NOT is_builtin;";
+
+        let mut options = BTreeMap::new();
+        options.insert(QUERY.to_string(), query.to_string());
+        let table_info = TableInfo {
+            desc: "'information_schema'.'routines'".to_string(),
+            name: "routines".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                options,
+                engine: "VIEW".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        ViewTable::create(table_info)
+    }
+}
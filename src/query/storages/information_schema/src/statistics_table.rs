@@ -27,22 +27,23 @@ pub struct StatisticsTable {}
 impl StatisticsTable {
     pub fn create(table_id: u64) -> Arc<dyn Table> {
         let query = "SELECT \
-        NULL as table_catalog, \
-        NULL as table_schema, \
-        NULL as table_name, \
-        NULL as non_unique, \
-        NULL as index_schema, \
-        NULL as index_name, \
-        NULL as seq_in_index, \
-        NULL as column_name, \
-        NULL as collation, \
-        NULL as cardinality, \
-        NULL as sub_part, \
-        NULL as packed, \
-        NULL as nullable, \
-        NULL as index_type, \
-        NULL as comment, \
-        NULL as index_comment"
+        '' as table_catalog, \
+        '' as table_schema, \
+        '' as table_name, \
+        CAST(0 AS BIGINT) as non_unique, \
+        '' as index_schema, \
+        '' as index_name, \
+        CAST(0 AS BIGINT UNSIGNED) as seq_in_index, \
+        CAST(NULL AS VARCHAR NULL) as column_name, \
+        CAST(NULL AS VARCHAR NULL) as collation, \
+        CAST(NULL AS BIGINT NULL) as cardinality, \
+        CAST(NULL AS BIGINT NULL) as sub_part, \
+        CAST(NULL AS VARCHAR NULL) as packed, \
+        '' as nullable, \
+        '' as index_type, \
+        '' as comment, \
+        '' as index_comment \
+        WHERE 1 = 0"
             .to_string();
 
         let mut options = BTreeMap::new();
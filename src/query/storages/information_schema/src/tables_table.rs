@@ -53,20 +53,31 @@ impl TablesTable {
     // +-----------------+--------------------------------------------------------------------+------+-----+---------+-------+
     pub fn create(table_id: u64) -> Arc<dyn Table> {
         let query = "SELECT
-            database AS table_catalog,
+            catalog AS table_catalog,
             database AS table_schema,
             name AS table_name,
-            'BASE TABLE' AS table_type,
+            CASE WHEN database IN ('system', 'information_schema') THEN 'SYSTEM VIEW'
+            WHEN engine = 'VIEW' THEN 'VIEW'
+            ELSE 'BASE TABLE'
+            END AS table_type,
             engine AS engine,
             created_on AS create_time,
             dropped_on AS drop_time,
             data_size AS data_length,
             index_size AS index_length,
             num_rows AS table_rows,
-            NULL AS auto_increment,
-            NULL AS table_collation,
-            NULL AS data_free,
-            '' AS table_comment
+            CAST(NULL AS BIGINT UNSIGNED NULL) AS auto_increment,
+            CAST(NULL AS VARCHAR NULL) AS table_collation,
+            CAST(NULL AS BIGINT UNSIGNED NULL) AS data_free,
+            comment AS table_comment,
+            CAST(NULL AS BIGINT UNSIGNED NULL) AS version,
+            CAST(NULL AS VARCHAR NULL) AS row_format,
+            CAST(NULL AS BIGINT UNSIGNED NULL) AS avg_row_length,
+            CAST(NULL AS BIGINT UNSIGNED NULL) AS max_data_length,
+            updated_on AS update_time,
+            CAST(NULL AS TIMESTAMP NULL) AS check_time,
+            CAST(NULL AS BIGINT NULL) AS checksum,
+            '' AS create_options
         FROM system.tables;";
 
         let mut options = BTreeMap::new();
@@ -27,18 +27,21 @@ pub struct ViewsTable {}
 impl ViewsTable {
     pub fn create(table_id: u64) -> Arc<dyn Table> {
         let query = "SELECT
-            database AS table_catalog,
+            catalog AS table_catalog,
             database AS table_schema,
             name AS table_name,
-            NULL AS view_definition,
+            view_query AS view_definition,
             'NONE' AS check_option,
-            0 AS is_updatable,
-            engine = 'MaterializedView' AS is_insertable_into,
-            0 AS is_trigger_updatable,
-            0 AS is_trigger_deletable,
-            0 AS is_trigger_insertable_into
+            'NO' AS is_updatable,
+            'NO' AS is_insertable_into,
+            'NO' AS is_trigger_updatable,
+            'NO' AS is_trigger_deletable,
+            'NO' AS is_trigger_insertable_into,
+            'DEFINER' AS security_type,
+            'utf8mb4' AS character_set_client,
+            'utf8mb4_general_ci' AS collation_connection
         FROM system.tables
-        WHERE engine LIKE '%View';";
+        WHERE engine = 'VIEW' AND database NOT IN ('system', 'information_schema');";
 
         let mut options = BTreeMap::new();
         options.insert(QUERY.to_string(), query.to_string());
@@ -185,6 +185,8 @@ impl Parquet2DeserializeTransform {
             self.copy_status.add_chunk(path, FileStatus {
                 num_rows_loaded,
                 error: None,
+                load_errors: vec![],
+                quarantined: vec![],
             })
         }
         Ok(res)
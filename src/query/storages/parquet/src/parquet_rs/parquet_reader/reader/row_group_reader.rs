@@ -83,6 +83,16 @@ impl ParquetRSRowGroupReader {
             metrics_inc_omit_filter_rowgroups(1);
             metrics_inc_omit_filter_rows(row_group.row_count() as u64);
         }
+        if let Some(row_range) = &part.row_range {
+            let range_selection = RowSelection::from_consecutive_ranges(
+                std::iter::once(row_range.clone()),
+                row_group.row_count(),
+            );
+            selection = Some(match selection {
+                Some(selection) => selection.intersection(&range_selection),
+                None => range_selection,
+            });
+        }
 
         let builder = &self.policy_builders[policy as usize];
         builder
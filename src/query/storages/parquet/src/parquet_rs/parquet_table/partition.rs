@@ -29,6 +29,7 @@ use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_storage::CopyStatus;
 use common_storage::FileStatus;
+use parquet::arrow::arrow_reader::RowSelection;
 use parquet::arrow::arrow_reader::RowSelector;
 use storages_common_index::Index;
 use storages_common_index::RangeIndex;
@@ -270,7 +271,7 @@ impl ParquetRSTable {
         })
         .unwrap_or((PartStatistics::default_exact(), vec![]));
 
-        Ok((stats, create_partitions(parts, &topk)))
+        Ok((stats, create_partitions(parts, &topk, num_threads)))
     }
 }
 
@@ -338,7 +339,7 @@ async fn prune_metas_in_parallel(
     })
     .unwrap_or((PartStatistics::default_exact(), vec![]));
 
-    Ok((stats, create_partitions(parts, &topk)))
+    Ok((stats, create_partitions(parts, &topk, num_threads)))
 }
 
 fn prune_and_generate_partitions(
@@ -414,6 +415,7 @@ fn prune_and_generate_partitions(
             parts.push(ParquetRSRowGroupPart {
                 location: location.clone(),
                 selectors: serde_selection,
+                row_range: None,
                 meta: rg_meta.clone(),
                 page_locations,
                 compressed_size,
@@ -428,6 +430,8 @@ fn prune_and_generate_partitions(
             copy_status.add_chunk(location, FileStatus {
                 num_rows_loaded: rows_read,
                 error: None,
+                load_errors: vec![],
+                quarantined: vec![],
             });
         }
     }
@@ -435,7 +439,12 @@ fn prune_and_generate_partitions(
     Ok((part_stats, parts))
 }
 
-fn create_partitions(mut parts: Vec<ParquetRSRowGroupPart>, topk: &Option<TopK>) -> Partitions {
+fn create_partitions(
+    parts: Vec<ParquetRSRowGroupPart>,
+    topk: &Option<TopK>,
+    num_threads: usize,
+) -> Partitions {
+    let mut parts = split_row_group_parts(parts, num_threads);
     if let Some(topk) = topk {
         if topk.asc {
             parts.sort_by(|a, b| {
@@ -459,3 +468,58 @@ fn create_partitions(mut parts: Vec<ParquetRSRowGroupPart>, topk: &Option<TopK>)
 
     Partitions::create_nolazy(PartitionsShuffleKind::Mod, parts)
 }
+
+/// Row groups with fewer rows than this are not split.
+const MIN_ROWS_PER_SPLIT: usize = 65536;
+
+/// If there are fewer row groups than threads (e.g. loading a single large file),
+/// split the row groups into ranges of rows, so that they can be decoded by all
+/// the threads in parallel.
+///
+/// Only the row groups with page index are split, since the reader of a range can
+/// fetch only the pages it needs. Without it, every split would fetch the whole row group.
+fn split_row_group_parts(
+    parts: Vec<ParquetRSRowGroupPart>,
+    num_threads: usize,
+) -> Vec<ParquetRSRowGroupPart> {
+    if parts.is_empty() || parts.len() >= num_threads {
+        return parts;
+    }
+
+    let splits_per_part = num_threads.div_ceil(parts.len());
+    let mut results = Vec::with_capacity(num_threads);
+    for part in parts {
+        let num_rows = part.meta.num_rows() as usize;
+        let num_splits = splits_per_part.min(num_rows / MIN_ROWS_PER_SPLIT);
+        if num_splits <= 1 || part.page_locations.is_none() {
+            results.push(part);
+            continue;
+        }
+
+        let selection = part.selectors.as_ref().map(|selectors| {
+            RowSelection::from(selectors.iter().map(RowSelector::from).collect::<Vec<_>>())
+        });
+        for i in 0..num_splits {
+            let row_range = num_rows * i / num_splits..num_rows * (i + 1) / num_splits;
+            if let Some(selection) = &selection {
+                let range_selection = RowSelection::from_consecutive_ranges(
+                    std::iter::once(row_range.clone()),
+                    num_rows,
+                );
+                if !selection.intersection(&range_selection).selects_any() {
+                    // All rows in current range are filtered out.
+                    continue;
+                }
+            }
+
+            let ratio = row_range.len() as f64 / num_rows as f64;
+            results.push(ParquetRSRowGroupPart {
+                compressed_size: (part.compressed_size as f64 * ratio) as u64,
+                uncompressed_size: (part.uncompressed_size as f64 * ratio) as u64,
+                row_range: Some(row_range),
+                ..part.clone()
+            });
+        }
+    }
+    results
+}
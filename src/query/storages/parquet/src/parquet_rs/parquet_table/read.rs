@@ -19,6 +19,7 @@ use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_expression::TableSchemaRef;
+use common_expression::FILE_NAME_COLUMN_ID;
 use common_pipeline_core::Pipeline;
 use storages_common_index::Index;
 use storages_common_index::RangeIndex;
@@ -85,6 +86,12 @@ impl ParquetRSTable {
             None
         };
 
+        let output_file_name = plan
+            .schema()
+            .fields()
+            .iter()
+            .any(|f| f.column_id() == FILE_NAME_COLUMN_ID);
+
         let topk = Arc::new(topk);
         pipeline.add_source(
             |output| {
@@ -94,6 +101,7 @@ impl ParquetRSTable {
                     row_group_reader.clone(),
                     full_file_reader.clone(),
                     topk.clone(),
+                    output_file_name,
                 )
             },
             num_threads,
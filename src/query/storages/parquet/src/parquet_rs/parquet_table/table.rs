@@ -39,8 +39,10 @@ use common_catalog::table::TableStatistics;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::ColumnId;
 use common_expression::TableField;
 use common_expression::TableSchema;
+use common_expression::FILE_NAME_COLUMN_ID;
 use common_meta_app::principal::StageInfo;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
@@ -197,6 +199,10 @@ impl Table for ParquetRSTable {
         true
     }
 
+    fn support_internal_column(&self, column_id: ColumnId) -> bool {
+        column_id == FILE_NAME_COLUMN_ID
+    }
+
     fn support_prewhere(&self) -> bool {
         self.read_options.do_prewhere()
     }
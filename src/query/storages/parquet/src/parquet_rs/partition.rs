@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::io::Cursor;
+use std::ops::Range;
 use std::sync::Arc;
 
 use common_expression::Scalar;
@@ -95,6 +96,9 @@ pub struct ParquetRSRowGroupPart {
     )]
     pub meta: RowGroupMetaData,
     pub selectors: Option<Vec<SerdeRowSelector>>,
+    /// The rows of the row group read by this part. It's set if the row group is
+    /// split into several parts to be decoded in parallel.
+    pub row_range: Option<Range<usize>>,
     pub page_locations: Option<Vec<Vec<SerdePageLocation>>>,
     // `uncompressed_size` and `compressed_size` are the sizes of the actually read columns.
     pub uncompressed_size: u64,
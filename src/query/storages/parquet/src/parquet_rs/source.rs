@@ -17,6 +17,7 @@ use std::sync::Arc;
 
 use common_base::base::Progress;
 use common_base::base::ProgressValues;
+use common_catalog::plan::generate_location_column;
 use common_catalog::plan::TopK;
 use common_catalog::query_kind::QueryKind;
 use common_catalog::table_context::TableContext;
@@ -38,7 +39,7 @@ use crate::ParquetRSRowGroupReader;
 
 enum State {
     Init,
-    ReadRowGroup(ReadPolicyImpl),
+    ReadRowGroup(ReadPolicyImpl, String),
     ReadFiles(Vec<(String, Vec<u8>)>),
 }
 
@@ -64,6 +65,8 @@ pub struct ParquetSource {
     copy_status: Arc<CopyStatus>,
     /// Pushed-down topk sorter.
     topk_sorter: Option<TopKSorter>,
+    /// Whether to append the internal column `_file_name` to the output blocks.
+    output_file_name: bool,
 }
 
 impl ParquetSource {
@@ -73,6 +76,7 @@ impl ParquetSource {
         row_group_reader: Arc<ParquetRSRowGroupReader>,
         full_file_reader: Option<Arc<ParquetRSFullReader>>,
         topk: Arc<Option<TopK>>,
+        output_file_name: bool,
     ) -> Result<ProcessorPtr> {
         let scan_progress = ctx.get_scan_progress();
         let is_copy = matches!(ctx.get_query_kind(), QueryKind::CopyIntoTable);
@@ -95,8 +99,16 @@ impl ParquetSource {
             copy_status,
             topk_sorter,
             full_file_reader,
+            output_file_name,
         })))
     }
+
+    fn add_internal_columns(&self, mut block: DataBlock, path: &str) -> DataBlock {
+        if self.output_file_name {
+            block.add_column(generate_location_column(path));
+        }
+        block
+    }
 }
 
 #[async_trait::async_trait]
@@ -127,7 +139,7 @@ impl Processor for ParquetSource {
             None => match &self.state {
                 State::Init => Ok(Event::Async),
                 State::ReadFiles(_) => Ok(Event::Sync),
-                State::ReadRowGroup(_) => Ok(Event::Sync),
+                State::ReadRowGroup(..) => Ok(Event::Sync),
             },
             Some(data_block) => {
                 let progress_values = ProgressValues {
@@ -143,10 +155,10 @@ impl Processor for ParquetSource {
 
     fn process(&mut self) -> Result<()> {
         match std::mem::replace(&mut self.state, State::Init) {
-            State::ReadRowGroup(mut reader) => {
+            State::ReadRowGroup(mut reader, location) => {
                 if let Some(block) = reader.as_mut().read_block()? {
-                    self.generated_data = Some(block);
-                    self.state = State::ReadRowGroup(reader);
+                    self.generated_data = Some(self.add_internal_columns(block, &location));
+                    self.state = State::ReadRowGroup(reader, location);
                 }
                 // Else: The reader is finished. We should try to build another reader.
             }
@@ -164,17 +176,19 @@ impl Processor for ParquetSource {
                         self.copy_status.add_chunk(path.as_str(), FileStatus {
                             num_rows_loaded: num_rows,
                             error: None,
+                            load_errors: vec![],
+                            quarantined: vec![],
                         });
-                        blocks.extend(bs);
+                        blocks.extend(bs.into_iter().map(|b| self.add_internal_columns(b, &path)));
                     }
                 } else {
-                    for (_, buffer) in buffers {
-                        blocks.extend(
-                            self.full_file_reader
-                                .as_ref()
-                                .unwrap()
-                                .read_blocks_from_binary(buffer)?,
-                        );
+                    for (path, buffer) in buffers {
+                        let bs = self
+                            .full_file_reader
+                            .as_ref()
+                            .unwrap()
+                            .read_blocks_from_binary(buffer)?;
+                        blocks.extend(bs.into_iter().map(|b| self.add_internal_columns(b, &path)));
                     }
                 }
 
@@ -195,12 +209,13 @@ impl Processor for ParquetSource {
                 if let Some(part) = self.ctx.get_partition() {
                     match ParquetPart::from_part(&part)? {
                         ParquetPart::ParquetRSRowGroup(part) => {
+                            let location = part.location.clone();
                             if let Some(reader) = self
                                 .row_group_reader
                                 .create_read_policy(part, &mut self.topk_sorter)
                                 .await?
                             {
-                                self.state = State::ReadRowGroup(reader);
+                                self.state = State::ReadRowGroup(reader, location);
                             }
                             // Else: keep in init state.
                         }
@@ -14,6 +14,7 @@
 
 use common_expression::types::decimal::Decimal;
 use common_expression::types::decimal::DecimalScalar;
+use common_expression::types::timestamp::precision_to_micros;
 use common_expression::types::DecimalDataType;
 use common_expression::types::NumberDataType;
 use common_expression::Scalar;
@@ -90,6 +91,10 @@ pub fn convert_column_statistics(s: &Statistics, typ: &TableDataType) -> ColumnS
                             Scalar::Timestamp(min * multi),
                         )
                     }
+                    TableDataType::TimestampWithPrecision(precision) => (
+                        Scalar::Timestamp(precision_to_micros(max, *precision)),
+                        Scalar::Timestamp(precision_to_micros(min, *precision)),
+                    ),
                     TableDataType::Decimal(DecimalDataType::Decimal128(size)) => (
                         Scalar::Decimal(DecimalScalar::Decimal128(i128::from(max), *size)),
                         Scalar::Decimal(DecimalScalar::Decimal128(i128::from(min), *size)),
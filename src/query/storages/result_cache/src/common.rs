@@ -17,6 +17,9 @@ use sha2::Sha256;
 
 const RESULT_CACHE_PREFIX: &str = "_result_cache";
 
+/// The name of the result cache in the cache metrics.
+pub(crate) const RESULT_CACHE_NAME: &str = "result";
+
 #[inline(always)]
 pub fn gen_result_cache_key(raw: &str) -> String {
     format!("{:x}", Sha256::digest(raw))
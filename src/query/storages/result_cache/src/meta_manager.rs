@@ -84,6 +84,28 @@ impl ResultCacheMetaManager {
         Ok(r)
     }
 
+    /// Removes all the result caches under the prefix, returns the removed ones.
+    #[async_backtrace::framed]
+    pub async fn remove_all(&self, prefix: &str) -> Result<Vec<ResultCacheValue>> {
+        let result = self.inner.prefix_list_kv(prefix).await?;
+
+        let mut r = vec![];
+        for (key, val) in result {
+            let _ = self
+                .inner
+                .upsert_kv(UpsertKV {
+                    key,
+                    seq: MatchSeq::Exact(val.seq),
+                    value: Operation::Delete,
+                    value_meta: None,
+                })
+                .await?;
+            r.push(serde_json::from_slice::<ResultCacheValue>(&val.data)?);
+        }
+
+        Ok(r)
+    }
+
     pub fn get_ttl(&self) -> u64 {
         self.ttl
     }
@@ -24,11 +24,16 @@ use common_expression::DataBlock;
 use common_expression::DataSchema;
 use common_expression::TableSchema;
 use common_meta_store::MetaStore;
+use common_metrics::cache::metrics_inc_cache_access_count;
+use common_metrics::cache::metrics_inc_cache_hit_count;
+use common_metrics::cache::metrics_inc_cache_miss_count;
 use common_storage::DataOperator;
 use opendal::Operator;
+use storages_common_cache_manager::CacheManager;
 
 use crate::common::gen_result_cache_meta_key;
 use crate::common::ResultCacheValue;
+use crate::common::RESULT_CACHE_NAME;
 use crate::meta_manager::ResultCacheMetaManager;
 
 pub struct ResultCacheReader {
@@ -80,8 +85,18 @@ impl ResultCacheReader {
 
     #[async_backtrace::framed]
     pub async fn try_read_cached_result(&self) -> Result<Option<Vec<DataBlock>>> {
-        self.try_read_cached_result_with_meta_key(self.meta_key.clone())
-            .await
+        metrics_inc_cache_access_count(1, RESULT_CACHE_NAME);
+        let result = self
+            .try_read_cached_result_with_meta_key(self.meta_key.clone())
+            .await?;
+        match &result {
+            Some(_) => metrics_inc_cache_hit_count(1, RESULT_CACHE_NAME),
+            None => metrics_inc_cache_miss_count(1, RESULT_CACHE_NAME),
+        }
+        CacheManager::instance()
+            .get_result_cache_stats()
+            .record(result.is_some());
+        Ok(result)
     }
 
     #[async_backtrace::framed]
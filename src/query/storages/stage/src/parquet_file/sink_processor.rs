@@ -30,6 +30,7 @@ use opendal::Operator;
 
 use crate::parquet_file::block_batch::BlockBatch;
 use crate::stage_table::unload_path;
+use crate::stage_table::write_unload_file;
 
 pub struct ParquetFileSink {
     input: Arc<InputPort>,
@@ -121,7 +122,7 @@ impl Processor for ParquetFileSink {
             None,
         );
         let data = mem::take(&mut self.output_data);
-        self.data_accessor.write(&path, data).await?;
+        write_unload_file(&self.table_info, &self.data_accessor, &path, data).await?;
         self.batch_id += 1;
         Ok(())
     }
@@ -31,6 +31,7 @@ use opendal::Operator;
 
 use crate::row_based_file::buffers::FileOutputBuffers;
 use crate::stage_table::unload_path;
+use crate::stage_table::write_unload_file;
 
 pub struct RowBasedFileSink {
     input: Arc<InputPort>,
@@ -133,7 +134,7 @@ impl Processor for RowBasedFileSink {
             self.compression,
         );
         let data = mem::take(&mut self.output_data);
-        self.data_accessor.write(&path, data).await?;
+        write_unload_file(&self.table_info, &self.data_accessor, &path, data).await?;
         self.batch_id += 1;
         Ok(())
     }
@@ -35,7 +35,9 @@ use common_compress::CompressAlgorithm;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::BlockThresholds;
+use common_expression::ColumnId;
 use common_expression::TableSchemaRefExt;
+use common_expression::FILE_NAME_COLUMN_ID;
 use common_meta_app::principal::FileFormatParams;
 use common_meta_app::principal::StageInfo;
 use common_meta_app::schema::TableInfo;
@@ -43,6 +45,9 @@ use common_pipeline_core::Pipeline;
 use common_pipeline_sources::input_formats::InputContext;
 use common_pipeline_sources::input_formats::SplitInfo;
 use common_storage::init_stage_operator;
+use common_storage::put_presigned_url;
+use common_storage::unload_presigned_url;
+use common_storage::StageFileFormats;
 use common_storage::StageFileInfo;
 use common_storage::STDIN_FD;
 use dashmap::DashMap;
@@ -117,6 +122,15 @@ impl Table for StageTable {
         DataSourceInfo::StageSource(self.table_info.clone())
     }
 
+    // The row-based formats output the path of the file of each row.
+    fn support_internal_column(&self, column_id: ColumnId) -> bool {
+        column_id == FILE_NAME_COLUMN_ID
+            && !matches!(
+                self.table_info.stage_info.file_format_params,
+                FileFormatParams::Parquet(_)
+            )
+    }
+
     #[async_backtrace::framed]
     async fn read_partitions(
         &self,
@@ -133,14 +147,48 @@ impl Table for StageTable {
         };
         let format = InputContext::get_input_format(&stage_info.stage_info.file_format_params)?;
         let operator = StageTable::get_op(&stage_info.stage_info)?;
-        let splits = format
-            .get_splits(
-                files,
-                &stage_info.stage_info,
-                &operator,
-                &ctx.get_settings(),
-            )
-            .await?;
+        let file_formats = StageFileFormats::try_create(
+            &stage_info.files_info,
+            &stage_info.stage_info.file_format_params,
+            &stage_info.format_overrides,
+        )?;
+        let splits = if file_formats.has_overrides() {
+            // The files matching a pattern may be compressed differently,
+            // so they are split with their own file format.
+            let mut groups: Vec<(StageInfo, Vec<StageFileInfo>)> = vec![];
+            for file in files {
+                let params = file_formats.get(&file.path);
+                match groups
+                    .iter_mut()
+                    .find(|(stage, _)| &stage.file_format_params == params)
+                {
+                    Some((_, group)) => group.push(file),
+                    None => {
+                        let mut stage = stage_info.stage_info.clone();
+                        stage.file_format_params = params.clone();
+                        groups.push((stage, vec![file]));
+                    }
+                }
+            }
+            let mut splits = vec![];
+            for (stage, group) in groups {
+                splits.extend(
+                    format
+                        .get_splits(group, &stage, &operator, &ctx.get_settings())
+                        .await?,
+                );
+            }
+            splits
+        } else {
+            format
+                .get_splits(
+                    files,
+                    &stage_info.stage_info,
+                    &operator,
+                    &ctx.get_settings(),
+                )
+                .await?
+        };
 
         let partitions = splits
             .into_iter()
@@ -213,7 +261,7 @@ impl Table for StageTable {
             bop.write(STDIN_FD, buffer)?;
         }
 
-        let input_ctx = Arc::new(InputContext::try_create_from_copy(
+        let mut input_ctx = InputContext::try_create_from_copy(
             ctx.clone(),
             operator,
             settings,
@@ -226,7 +274,15 @@ impl Table for StageTable {
             self.table_info.is_select,
             projection,
             self.table_info.default_values.clone(),
-        )?);
+            &stage_table_info.files_info,
+            &stage_table_info.format_overrides,
+        )?;
+        input_ctx.output_file_name = plan
+            .schema()
+            .fields()
+            .iter()
+            .any(|f| f.column_id() == FILE_NAME_COLUMN_ID);
+        let input_ctx = Arc::new(input_ctx);
         debug!("start copy splits feeder in {}", ctx.get_cluster().local_id);
         input_ctx.format.exec_copy(input_ctx.clone(), pipeline)?;
         Ok(())
@@ -303,6 +359,20 @@ impl Table for StageTable {
     }
 }
 
+/// Writes an unloaded file, or uploads it to the presigned url if unloading to one.
+#[async_backtrace::framed]
+pub async fn write_unload_file(
+    stage_table_info: &StageTableInfo,
+    data_accessor: &Operator,
+    path: &str,
+    data: Vec<u8>,
+) -> Result<()> {
+    match unload_presigned_url(&stage_table_info.stage_info.stage_params.storage) {
+        Some(url) => put_presigned_url(&url, data).await,
+        None => Ok(data_accessor.write(path, data).await?),
+    }
+}
+
 pub fn unload_path(
     stage_table_info: &StageTableInfo,
     uuid: &str,
@@ -75,6 +75,7 @@ impl StreamPruner {
             &push_down,
             cluster_key_meta,
             cluster_keys,
+            vec![],
             bloom_index_cols,
             max_concurrency,
         )?;
@@ -179,6 +180,11 @@ impl StreamPruner {
         let blocks_bloom_pruning_before = stats.get_blocks_bloom_pruning_before() as usize;
         let blocks_bloom_pruning_after = stats.get_blocks_bloom_pruning_after() as usize;
 
+        let segments_cluster_pruning_before = stats.get_segments_cluster_pruning_before() as usize;
+        let segments_cluster_pruning_after = stats.get_segments_cluster_pruning_after() as usize;
+        let blocks_cluster_pruning_before = stats.get_blocks_cluster_pruning_before() as usize;
+        let blocks_cluster_pruning_after = stats.get_blocks_cluster_pruning_after() as usize;
+
         common_catalog::plan::PruningStatistics {
             segments_range_pruning_before,
             segments_range_pruning_after,
@@ -186,6 +192,10 @@ impl StreamPruner {
             blocks_range_pruning_after,
             blocks_bloom_pruning_before,
             blocks_bloom_pruning_after,
+            segments_cluster_pruning_before,
+            segments_cluster_pruning_after,
+            blocks_cluster_pruning_before,
+            blocks_cluster_pruning_after,
         }
     }
 
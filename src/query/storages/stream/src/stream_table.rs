@@ -31,6 +31,7 @@ use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::ColumnId;
 use common_expression::ORIGIN_BLOCK_ID_COL_NAME;
 use common_expression::ORIGIN_BLOCK_ROW_NUM_COL_NAME;
 use common_expression::ORIGIN_VERSION_COL_NAME;
@@ -338,6 +339,11 @@ impl Table for StreamTable {
         true
     }
 
+    // Reading a stream is delegated to the underlying fuse table.
+    fn support_internal_column(&self, _column_id: ColumnId) -> bool {
+        true
+    }
+
     fn stream_columns(&self) -> Vec<StreamColumn> {
         vec![
             STREAM_COLUMN_FACTORY
@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use common_catalog::plan::PushDownInfo;
 use common_catalog::table::Table;
 use common_exception::Result;
 use common_expression::types::NumberDataType;
@@ -27,18 +28,45 @@ use common_expression::TableSchemaRefExt;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
+use common_sql::PlanCache;
 use common_storages_fuse::TableContext;
+use common_storages_result_cache::gen_result_cache_prefix;
+use common_storages_result_cache::ResultCacheMetaManager;
+use common_users::UserApiProvider;
 use storages_common_cache::CacheAccessor;
+use storages_common_cache::CacheStats;
 use storages_common_cache_manager::CacheManager;
 
-use crate::SyncOneBlockSystemTable;
-use crate::SyncSystemTable;
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
 
 pub struct CachesTable {
     table_info: TableInfo,
 }
 
-impl SyncSystemTable for CachesTable {
+#[derive(Default)]
+struct CacheRows {
+    names: Vec<&'static str>,
+    num_items: Vec<u64>,
+    size: Vec<u64>,
+    access: Vec<u64>,
+    hit: Vec<u64>,
+    miss: Vec<u64>,
+}
+
+impl CacheRows {
+    fn push(&mut self, name: &'static str, num_items: u64, size: u64, stats: &CacheStats) {
+        self.names.push(name);
+        self.num_items.push(num_items);
+        self.size.push(size);
+        self.access.push(stats.access());
+        self.hit.push(stats.hit());
+        self.miss.push(stats.miss());
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for CachesTable {
     const NAME: &'static str = "system.caches";
 
     // Allow distributed query.
@@ -48,12 +76,14 @@ impl SyncSystemTable for CachesTable {
         &self.table_info
     }
 
-    fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
         let local_node = ctx.get_cluster().local_id.clone();
-        let mut nodes = Vec::new();
-        let mut names = Vec::new();
-        let mut num_items = Vec::new();
-        let mut size = Vec::new();
+        let mut rows = CacheRows::default();
 
         let cache_manager = CacheManager::instance();
 
@@ -67,74 +97,94 @@ impl SyncSystemTable for CachesTable {
         let table_data_cache = cache_manager.get_table_data_cache();
         let table_column_array_cache = cache_manager.get_table_data_array_cache();
 
-        if let Some(table_snapshot_cache) = table_snapshot_cache {
-            nodes.push(local_node.clone().into_bytes());
-            names.push("table_snapshot_cache");
-            num_items.push(table_snapshot_cache.len() as u64);
-            size.push(table_snapshot_cache.size());
+        if let Some(c) = table_snapshot_cache {
+            rows.push("table_snapshot_cache", c.len() as u64, c.size(), c.stats());
+        }
+        if let Some(c) = table_snapshot_statistic_cache {
+            rows.push(
+                "table_snapshot_statistic_cache",
+                c.len() as u64,
+                c.size(),
+                c.stats(),
+            );
         }
-        if let Some(table_snapshot_statistic_cache) = table_snapshot_statistic_cache {
-            nodes.push(local_node.clone().into_bytes());
-            names.push("table_snapshot_statistic_cache");
-            num_items.push(table_snapshot_statistic_cache.len() as u64);
-            size.push(table_snapshot_statistic_cache.size());
+
+        if let Some(c) = segment_info_cache {
+            rows.push("segment_info_cache", c.len() as u64, c.size(), c.stats());
         }
 
-        if let Some(segment_info_cache) = segment_info_cache {
-            nodes.push(local_node.clone().into_bytes());
-            names.push("segment_info_cache");
-            num_items.push(segment_info_cache.len() as u64);
-            size.push(segment_info_cache.size());
+        if let Some(c) = bloom_index_filter_cache {
+            rows.push(
+                "bloom_index_filter_cache",
+                c.len() as u64,
+                c.size(),
+                c.stats(),
+            );
         }
 
-        if let Some(bloom_index_filter_cache) = bloom_index_filter_cache {
-            nodes.push(local_node.clone().into_bytes());
-            names.push("bloom_index_filter_cache");
-            num_items.push(bloom_index_filter_cache.len() as u64);
-            size.push(bloom_index_filter_cache.size());
+        if let Some(c) = bloom_index_meta_cache {
+            rows.push(
+                "bloom_index_meta_cache",
+                c.len() as u64,
+                c.size(),
+                c.stats(),
+            );
         }
 
-        if let Some(bloom_index_meta_cache) = bloom_index_meta_cache {
-            nodes.push(local_node.clone().into_bytes());
-            names.push("bloom_index_meta_cache");
-            num_items.push(bloom_index_meta_cache.len() as u64);
-            size.push(bloom_index_meta_cache.size());
+        if let Some(c) = prune_partitions_cache {
+            rows.push(
+                "prune_partitions_cache",
+                c.len() as u64,
+                c.size(),
+                c.stats(),
+            );
         }
 
-        if let Some(prune_partitions_cache) = prune_partitions_cache {
-            nodes.push(local_node.clone().into_bytes());
-            names.push("prune_partitions_cache");
-            num_items.push(prune_partitions_cache.len() as u64);
-            size.push(prune_partitions_cache.size());
+        if let Some(c) = file_meta_data_cache {
+            rows.push("file_meta_data_cache", c.len() as u64, c.size(), c.stats());
         }
 
-        if let Some(file_meta_data_cache) = file_meta_data_cache {
-            nodes.push(local_node.clone().into_bytes());
-            names.push("file_meta_data_cache");
-            num_items.push(file_meta_data_cache.len() as u64);
-            size.push(file_meta_data_cache.size());
+        if let Some(c) = table_data_cache {
+            rows.push("table_data_cache", c.len() as u64, c.size(), c.stats());
         }
 
-        if let Some(table_data_cache) = table_data_cache {
-            nodes.push(local_node.clone().into_bytes());
-            names.push("table_data_cache");
-            num_items.push(table_data_cache.len() as u64);
-            size.push(table_data_cache.size());
+        if let Some(c) = table_column_array_cache {
+            rows.push(
+                "table_column_array_cache",
+                c.len() as u64,
+                c.size(),
+                c.stats(),
+            );
         }
 
-        if let Some(table_column_array_cache) = table_column_array_cache {
-            nodes.push(local_node.into_bytes());
-            names.push("table_column_array_cache");
-            num_items.push(table_column_array_cache.len() as u64);
-            size.push(table_column_array_cache.size());
+        let plan_cache = PlanCache::instance();
+        if let Some(num_items) = plan_cache.num_items() {
+            let num_items = num_items as u64;
+            rows.push("plan_cache", num_items, num_items, plan_cache.stats());
         }
 
-        let names: Vec<_> = names.iter().map(|x| x.as_bytes().to_vec()).collect();
+        // The result cache is shared by the nodes of the tenant, the stats are of this node.
+        let meta_client = UserApiProvider::instance().get_meta_store_client();
+        let result_cache_mgr = ResultCacheMetaManager::create(meta_client, 0);
+        let prefix = gen_result_cache_prefix(&ctx.get_tenant());
+        let cached_values = result_cache_mgr.list(prefix.as_str()).await?;
+        rows.push(
+            "result_cache",
+            cached_values.len() as u64,
+            cached_values.iter().map(|v| v.result_size as u64).sum(),
+            &cache_manager.get_result_cache_stats(),
+        );
+
+        let nodes = vec![local_node.into_bytes(); rows.names.len()];
+        let names: Vec<_> = rows.names.iter().map(|x| x.as_bytes().to_vec()).collect();
         Ok(DataBlock::new_from_columns(vec![
             StringType::from_data(nodes),
             StringType::from_data(names),
-            UInt64Type::from_data(num_items),
-            UInt64Type::from_data(size),
+            UInt64Type::from_data(rows.num_items),
+            UInt64Type::from_data(rows.size),
+            UInt64Type::from_data(rows.access),
+            UInt64Type::from_data(rows.hit),
+            UInt64Type::from_data(rows.miss),
         ]))
     }
 }
@@ -146,6 +196,9 @@ impl CachesTable {
             TableField::new("name", TableDataType::String),
             TableField::new("num_items", TableDataType::Number(NumberDataType::UInt64)),
             TableField::new("size", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("access", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("hit", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("miss", TableDataType::Number(NumberDataType::UInt64)),
         ]);
 
         let table_info = TableInfo {
@@ -160,6 +213,6 @@ impl CachesTable {
             },
             ..Default::default()
         };
-        SyncOneBlockSystemTable::create(Self { table_info })
+        AsyncOneBlockSystemTable::create(Self { table_info })
     }
 }
@@ -0,0 +1,101 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::ColumnBuilder;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRef;
+use common_expression::TableSchemaRefExt;
+
+use crate::SystemLogElement;
+use crate::SystemLogQueue;
+use crate::SystemLogTable;
+
+/// An edge of the column lineage graph: `output_column` of a query, or of the table
+/// `target_database.target_table` it inserts into, is derived from `source_column`.
+#[derive(Clone)]
+pub struct ColumnLineageLogElement {
+    pub query_id: String,
+    pub event_time: i64,
+    pub target_database: Option<String>,
+    pub target_table: Option<String>,
+    pub output_column: String,
+    pub source_catalog: String,
+    pub source_database: String,
+    pub source_table: String,
+    pub source_column: String,
+}
+
+impl SystemLogElement for ColumnLineageLogElement {
+    const TABLE_NAME: &'static str = "column_lineage";
+
+    fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("query_id", TableDataType::String),
+            TableField::new("event_time", TableDataType::Timestamp),
+            TableField::new(
+                "target_database",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new(
+                "target_table",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new("output_column", TableDataType::String),
+            TableField::new("source_catalog", TableDataType::String),
+            TableField::new("source_database", TableDataType::String),
+            TableField::new("source_table", TableDataType::String),
+            TableField::new("source_column", TableDataType::String),
+        ])
+    }
+
+    fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()> {
+        let mut columns = columns.iter_mut();
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.query_id.as_bytes().to_vec()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Timestamp(self.event_time).as_ref());
+        for value in [&self.target_database, &self.target_table] {
+            columns.next().unwrap().push(
+                value
+                    .as_ref()
+                    .map(|v| Scalar::String(v.as_bytes().to_vec()))
+                    .unwrap_or(Scalar::Null)
+                    .as_ref(),
+            );
+        }
+        for value in [
+            &self.output_column,
+            &self.source_catalog,
+            &self.source_database,
+            &self.source_table,
+            &self.source_column,
+        ] {
+            columns
+                .next()
+                .unwrap()
+                .push(Scalar::String(value.as_bytes().to_vec()).as_ref());
+        }
+        Ok(())
+    }
+}
+
+pub type ColumnLineageQueue = SystemLogQueue<ColumnLineageLogElement>;
+pub type ColumnLineageTable = SystemLogTable<ColumnLineageLogElement>;
@@ -20,7 +20,9 @@ use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_expression::infer_table_schema;
+use common_expression::types::NumberDataType;
 use common_expression::types::StringType;
+use common_expression::types::UInt64Type;
 use common_expression::utils::FromData;
 use common_expression::DataBlock;
 use common_expression::Scalar;
@@ -67,7 +69,11 @@ impl AsyncSystemTable for ColumnsTable {
         let mut default_exprs: Vec<Vec<u8>> = Vec::with_capacity(rows.len());
         let mut is_nullables: Vec<Vec<u8>> = Vec::with_capacity(rows.len());
         let mut comments: Vec<Vec<u8>> = Vec::with_capacity(rows.len());
-        for (database_name, table_name, field) in rows.into_iter() {
+        let mut ordinal_positions: Vec<u64> = Vec::with_capacity(rows.len());
+        let mut numeric_precisions: Vec<Option<u64>> = Vec::with_capacity(rows.len());
+        let mut numeric_scales: Vec<Option<u64>> = Vec::with_capacity(rows.len());
+        let mut datetime_precisions: Vec<Option<u64>> = Vec::with_capacity(rows.len());
+        for (database_name, table_name, ordinal_position, field, comment) in rows.into_iter() {
             names.push(field.name().clone().into_bytes());
             tables.push(table_name.into_bytes());
             databases.push(database_name.into_bytes());
@@ -89,7 +95,14 @@ impl AsyncSystemTable for ColumnsTable {
                 is_nullables.push("NO".to_string().into_bytes());
             }
 
-            comments.push("".to_string().into_bytes());
+            comments.push(comment.into_bytes());
+            ordinal_positions.push(ordinal_position);
+
+            let (numeric_precision, numeric_scale, datetime_precision) =
+                type_precisions(&field.data_type().remove_nullable());
+            numeric_precisions.push(numeric_precision);
+            numeric_scales.push(numeric_scale);
+            datetime_precisions.push(datetime_precision);
         }
 
         Ok(DataBlock::new_from_columns(vec![
@@ -102,6 +115,10 @@ impl AsyncSystemTable for ColumnsTable {
             StringType::from_data(default_exprs),
             StringType::from_data(is_nullables),
             StringType::from_data(comments),
+            UInt64Type::from_data(ordinal_positions),
+            UInt64Type::from_opt_data(numeric_precisions),
+            UInt64Type::from_opt_data(numeric_scales),
+            UInt64Type::from_opt_data(datetime_precisions),
         ]))
     }
 }
@@ -120,6 +137,23 @@ impl ColumnsTable {
             TableField::new("default_expression", TableDataType::String),
             TableField::new("is_nullable", TableDataType::String),
             TableField::new("comment", TableDataType::String),
+            // 1-based position of the column in its table
+            TableField::new(
+                "ordinal_position",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "numeric_precision",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt64))),
+            ),
+            TableField::new(
+                "numeric_scale",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt64))),
+            ),
+            TableField::new(
+                "datetime_precision",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt64))),
+            ),
         ]);
 
         let table_info = TableInfo {
@@ -142,7 +176,7 @@ impl ColumnsTable {
         &self,
         ctx: Arc<dyn TableContext>,
         push_downs: Option<PushDownInfo>,
-    ) -> Result<Vec<(String, String, TableField)>> {
+    ) -> Result<Vec<(String, String, u64, TableField, String)>> {
         let tenant = ctx.get_tenant();
         let catalog = ctx.get_catalog(CATALOG_DEFAULT).await?;
 
@@ -190,7 +224,7 @@ impl ColumnsTable {
             .cloned()
             .collect();
 
-        let mut rows: Vec<(String, String, TableField)> = vec![];
+        let mut rows: Vec<(String, String, u64, TableField, String)> = vec![];
         for database in final_dbs {
             let tables = if tables.is_empty() {
                 if let Ok(table) = catalog.list_tables(tenant.as_str(), &database).await {
@@ -215,8 +249,21 @@ impl ColumnsTable {
                     table.name(),
                 ) {
                     let fields = generate_fields(&ctx, &table).await?;
-                    for field in fields {
-                        rows.push((database.clone(), table.name().into(), field.clone()))
+                    let field_comments = table.field_comments();
+                    let has_comments = field_comments.len() == fields.len();
+                    for (idx, field) in fields.into_iter().enumerate() {
+                        let comment = if has_comments {
+                            field_comments[idx].clone()
+                        } else {
+                            "".to_string()
+                        };
+                        rows.push((
+                            database.clone(),
+                            table.name().into(),
+                            idx as u64 + 1,
+                            field,
+                            comment,
+                        ))
                     }
                 }
             }
@@ -247,3 +294,31 @@ async fn generate_fields(
         vec![]
     })
 }
+
+/// Returns the `(numeric_precision, numeric_scale, datetime_precision)` reported
+/// for a column type, following the values MySQL exposes for the matching types.
+fn type_precisions(data_type: &TableDataType) -> (Option<u64>, Option<u64>, Option<u64>) {
+    match data_type {
+        TableDataType::Number(num_ty) => {
+            let precision = match num_ty {
+                NumberDataType::UInt8 | NumberDataType::Int8 => 3,
+                NumberDataType::UInt16 | NumberDataType::Int16 => 5,
+                NumberDataType::UInt32 | NumberDataType::Int32 => 10,
+                NumberDataType::UInt64 => 20,
+                NumberDataType::Int64 => 19,
+                NumberDataType::Float32 => 12,
+                NumberDataType::Float64 => 22,
+                NumberDataType::UInt128 | NumberDataType::Int128 => 39,
+            };
+            let scale = if num_ty.is_float() { None } else { Some(0) };
+            (Some(precision), scale, None)
+        }
+        TableDataType::Decimal(decimal_ty) => {
+            let size = decimal_ty.size();
+            (Some(size.precision as u64), Some(size.scale as u64), None)
+        }
+        TableDataType::Timestamp => (None, None, Some(6)),
+        TableDataType::TimestampWithPrecision(precision) => (None, None, Some(*precision as u64)),
+        _ => (None, None, None),
+    }
+}
@@ -26,6 +26,7 @@ mod caches_table;
 mod catalogs_table;
 mod clustering_history_table;
 mod clusters_table;
+mod column_lineage_table;
 mod columns_table;
 mod configs_table;
 mod contributors_table;
@@ -34,10 +35,12 @@ mod databases_table;
 mod engines_table;
 mod functions_table;
 mod indexes_table;
+mod load_errors_table;
 mod log_queue;
 mod malloc_stats_table;
 mod malloc_stats_totals_table;
 mod metrics_table;
+mod notifications_table;
 mod one_table;
 mod processes_table;
 mod processor_profile_table;
@@ -45,16 +48,20 @@ mod query_cache_table;
 mod query_log_table;
 mod query_profile_table;
 mod query_summary_table;
+mod replication_lag_table;
+mod resource_usage_table;
 mod roles_table;
 mod settings_table;
 mod stages_table;
 mod streams_table;
 mod table;
 mod table_functions_table;
+mod table_statistics_table;
 mod tables_table;
 mod task_history_table;
 mod tasks_table;
 mod temp_files_table;
+mod tenants_table;
 mod tracing_table;
 mod users_table;
 mod util;
@@ -69,6 +76,9 @@ pub use clustering_history_table::ClusteringHistoryLogElement;
 pub use clustering_history_table::ClusteringHistoryQueue;
 pub use clustering_history_table::ClusteringHistoryTable;
 pub use clusters_table::ClustersTable;
+pub use column_lineage_table::ColumnLineageLogElement;
+pub use column_lineage_table::ColumnLineageQueue;
+pub use column_lineage_table::ColumnLineageTable;
 pub use columns_table::ColumnsTable;
 pub use configs_table::ConfigsTable;
 pub use contributors_table::ContributorsTable;
@@ -77,12 +87,17 @@ pub use databases_table::DatabasesTable;
 pub use engines_table::EnginesTable;
 pub use functions_table::FunctionsTable;
 pub use indexes_table::IndexesTable;
+pub use load_errors_table::LoadErrorLogElement;
+pub use load_errors_table::LoadErrorsQueue;
+pub use load_errors_table::LoadErrorsTable;
 pub use log_queue::SystemLogElement;
 pub use log_queue::SystemLogQueue;
 pub use log_queue::SystemLogTable;
 pub use malloc_stats_table::MallocStatsTable;
 pub use malloc_stats_totals_table::MallocStatsTotalsTable;
 pub use metrics_table::MetricsTable;
+pub use notifications_table::parse_notifications_to_datablock;
+pub use notifications_table::NotificationsTable;
 pub use one_table::OneTable;
 pub use processes_table::ProcessesTable;
 pub use processor_profile_table::ProcessorProfileTable;
@@ -93,6 +108,9 @@ pub use query_log_table::QueryLogQueue;
 pub use query_log_table::QueryLogTable;
 pub use query_profile_table::QueryProfileTable;
 pub use query_summary_table::QuerySummaryTable;
+pub use replication_lag_table::ReplicationLagTable;
+pub use resource_usage_table::ResourceUsageRecorder;
+pub use resource_usage_table::ResourceUsageTable;
 pub use roles_table::RolesTable;
 pub use settings_table::SettingsTable;
 pub use stages_table::StagesTable;
@@ -100,6 +118,7 @@ pub use streams_table::StreamsTable;
 pub use table::SyncOneBlockSystemTable;
 pub use table::SyncSystemTable;
 pub use table_functions_table::TableFunctionsTable;
+pub use table_statistics_table::TableStatisticsTable;
 pub use tables_table::TablesTable;
 pub use tables_table::TablesTableWithHistory;
 pub use tables_table::TablesTableWithoutHistory;
@@ -108,5 +127,7 @@ pub use task_history_table::TaskHistoryTable;
 pub use tasks_table::parse_tasks_to_datablock;
 pub use tasks_table::TasksTable;
 pub use temp_files_table::TempFilesTable;
+pub use tenants_table::TenantUsage;
+pub use tenants_table::TenantsTable;
 pub use tracing_table::TracingTable;
 pub use users_table::UsersTable;
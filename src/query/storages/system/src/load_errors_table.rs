@@ -0,0 +1,98 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::NumberDataType;
+use common_expression::ColumnBuilder;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRef;
+use common_expression::TableSchemaRefExt;
+
+use crate::SystemLogElement;
+use crate::SystemLogQueue;
+use crate::SystemLogTable;
+
+/// An error of `COPY INTO <table>` with `ON_ERROR = SET_NULL`, the value
+/// that fails to be converted is loaded as NULL.
+#[derive(Clone)]
+pub struct LoadErrorLogElement {
+    pub query_id: String,
+    pub database: String,
+    pub table: String,
+    pub file: String,
+    pub line: u64,
+    pub column_name: Option<String>,
+    pub error: String,
+}
+
+impl SystemLogElement for LoadErrorLogElement {
+    const TABLE_NAME: &'static str = "load_errors";
+
+    fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("query_id", TableDataType::String),
+            TableField::new("database", TableDataType::String),
+            TableField::new("table", TableDataType::String),
+            TableField::new("file", TableDataType::String),
+            TableField::new("line", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "column_name",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new("error", TableDataType::String),
+        ])
+    }
+
+    fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()> {
+        let mut columns = columns.iter_mut();
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.query_id.as_bytes().to_vec()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.database.as_bytes().to_vec()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.table.as_bytes().to_vec()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.file.as_bytes().to_vec()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.line)).as_ref());
+        columns.next().unwrap().push(
+            self.column_name
+                .as_ref()
+                .map(|name| Scalar::String(name.as_bytes().to_vec()))
+                .unwrap_or(Scalar::Null)
+                .as_ref(),
+        );
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.error.as_bytes().to_vec()).as_ref());
+        Ok(())
+    }
+}
+
+pub type LoadErrorsQueue = SystemLogQueue<LoadErrorLogElement>;
+pub type LoadErrorsTable = SystemLogTable<LoadErrorLogElement>;
@@ -15,6 +15,7 @@
 use std::any::Any;
 use std::any::TypeId;
 use std::collections::HashMap;
+use std::collections::VecDeque;
 use std::marker::PhantomData;
 use std::sync::Arc;
 
@@ -43,6 +44,8 @@ use once_cell::sync::OnceCell;
 use parking_lot::RwLock;
 
 use crate::table::SystemTablePart;
+use crate::util::filter_block;
+use crate::util::pushed_limit;
 
 pub trait SystemLogElement: Send + Sync + Clone {
     const TABLE_NAME: &'static str;
@@ -177,7 +180,7 @@ impl<Event: SystemLogElement + 'static> Table for SystemLogTable<Event> {
     fn read_data(
         &self,
         ctx: Arc<dyn TableContext>,
-        _: &DataSourcePlan,
+        plan: &DataSourcePlan,
         pipeline: &mut Pipeline,
         _put_cache: bool,
     ) -> Result<()> {
@@ -192,8 +195,18 @@ impl<Event: SystemLogElement + 'static> Table for SystemLogTable<Event> {
             data_types.push(data_type);
         }
 
+        let push_downs = plan.push_downs.as_ref();
+        let limit = pushed_limit(push_downs).unwrap_or(usize::MAX);
+
         let log_queue = SystemLogQueue::<Event>::instance()?;
-        for event in log_queue.data.read().event_queue.iter().flatten() {
+        for event in log_queue
+            .data
+            .read()
+            .event_queue
+            .iter()
+            .flatten()
+            .take(limit)
+        {
             event.fill_to_data_block(&mut mutable_columns)?;
         }
 
@@ -202,15 +215,21 @@ impl<Event: SystemLogElement + 'static> Table for SystemLogTable<Event> {
             columns.push(mutable_column.build());
         }
 
+        // Filter before emitting and emit in pages, so that a large log does not
+        // flow through the pipeline as one huge block.
+        let block = filter_block(
+            &ctx,
+            DataBlock::new_from_columns(columns),
+            &schema,
+            push_downs,
+        )?;
+        let max_block_size = ctx.get_settings().get_max_block_size()? as usize;
+        let (mut blocks, tail) = block.split_by_rows(max_block_size.max(1));
+        blocks.extend(tail);
+
         // Add source pipe.
         pipeline.add_source(
-            move |output| {
-                SystemLogSource::<Event>::create(
-                    ctx.clone(),
-                    output,
-                    DataBlock::new_from_columns(columns.clone()),
-                )
-            },
+            move |output| SystemLogSource::<Event>::create(ctx.clone(), output, blocks.clone()),
             1,
         )
     }
@@ -230,7 +249,7 @@ impl<Event: SystemLogElement + 'static> Table for SystemLogTable<Event> {
 }
 
 struct SystemLogSource<Event: SystemLogElement> {
-    data: Option<DataBlock>,
+    data: VecDeque<DataBlock>,
     _phantom: PhantomData<Event>,
 }
 
@@ -238,10 +257,10 @@ impl<Event: SystemLogElement + 'static> SystemLogSource<Event> {
     pub fn create(
         ctx: Arc<dyn TableContext>,
         output: Arc<OutputPort>,
-        data: DataBlock,
+        data: Vec<DataBlock>,
     ) -> Result<ProcessorPtr> {
         SyncSourcer::create(ctx, output, Self {
-            data: Some(data),
+            data: data.into(),
             _phantom: Default::default(),
         })
     }
@@ -251,6 +270,6 @@ impl<Event: SystemLogElement + 'static> SyncSource for SystemLogSource<Event> {
     const NAME: &'static str = Event::TABLE_NAME;
 
     fn generate(&mut self) -> Result<Option<DataBlock>> {
-        Ok(self.data.take())
+        Ok(self.data.pop_front())
     }
 }
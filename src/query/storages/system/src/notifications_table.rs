@@ -0,0 +1,147 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_cloud_control::client_config::build_client_config;
+use common_cloud_control::cloud_api::CloudControlApiProvider;
+use common_cloud_control::pb::ListNotificationRequest;
+use common_cloud_control::pb::Notification;
+use common_cloud_control::task_client::make_request;
+use common_config::GlobalConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::infer_table_schema;
+use common_expression::types::BooleanType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::types::UInt64Type;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_sql::plans::notification_schema;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+pub fn parse_notifications_to_datablock(notifications: Vec<Notification>) -> Result<DataBlock> {
+    let mut created_on: Vec<i64> = Vec::with_capacity(notifications.len());
+    let mut name: Vec<Vec<u8>> = Vec::with_capacity(notifications.len());
+    let mut id: Vec<u64> = Vec::with_capacity(notifications.len());
+    let mut notification_type: Vec<Vec<u8>> = Vec::with_capacity(notifications.len());
+    let mut enabled: Vec<bool> = Vec::with_capacity(notifications.len());
+    let mut options: Vec<Vec<u8>> = Vec::with_capacity(notifications.len());
+    let mut max_retries: Vec<u64> = Vec::with_capacity(notifications.len());
+    let mut retry_interval: Vec<u64> = Vec::with_capacity(notifications.len());
+    let mut comment: Vec<Option<Vec<u8>>> = Vec::with_capacity(notifications.len());
+    let mut updated_on: Vec<i64> = Vec::with_capacity(notifications.len());
+
+    for notification in notifications {
+        let n: common_cloud_control::notification_utils::Notification = notification.try_into()?;
+        created_on.push(n.created_at.timestamp_micros());
+        name.push(n.name.clone().into_bytes());
+        id.push(n.notification_id);
+        notification_type.push(n.notification_type.to_string().into_bytes());
+        enabled.push(n.enabled);
+        options.push(n.format_options().into_bytes());
+        let retry_policy = n.retry_policy.clone().unwrap_or_default();
+        max_retries.push(retry_policy.max_retries as u64);
+        retry_interval.push(retry_policy.retry_interval_secs);
+        comment.push(n.comments.map(|s| s.into_bytes()));
+        updated_on.push(n.updated_at.timestamp_micros());
+    }
+    Ok(DataBlock::new_from_columns(vec![
+        TimestampType::from_data(created_on),
+        StringType::from_data(name),
+        UInt64Type::from_data(id),
+        StringType::from_data(notification_type),
+        BooleanType::from_data(enabled),
+        StringType::from_data(options),
+        UInt64Type::from_data(max_retries),
+        UInt64Type::from_data(retry_interval),
+        StringType::from_opt_data(comment),
+        TimestampType::from_data(updated_on),
+    ]))
+}
+
+pub struct NotificationsTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for NotificationsTable {
+    const NAME: &'static str = "system.notifications";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let config = GlobalConfig::instance();
+        if config.query.cloud_control_grpc_server_address.is_none() {
+            return Err(ErrorCode::CloudControlNotEnabled(
+                "cannot view system.notifications table without cloud control enabled, please set cloud_control_grpc_server_address in config",
+            ));
+        }
+
+        let tenant = ctx.get_tenant();
+        let query_id = ctx.get_id();
+        let user = ctx.get_current_user()?.identity().to_string();
+        let req = ListNotificationRequest {
+            tenant_id: tenant.clone(),
+        };
+
+        let cloud_api = CloudControlApiProvider::instance();
+        let notification_client = cloud_api.get_notification_client();
+        let config = build_client_config(tenant, user, query_id);
+        let req = make_request(req, config);
+
+        let resp = notification_client.list_notifications(req).await?;
+        let notifications = resp.notifications;
+
+        parse_notifications_to_datablock(notifications)
+    }
+}
+
+impl NotificationsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = infer_table_schema(&notification_schema())
+            .expect("failed to parse notification table schema");
+
+        let table_info = TableInfo {
+            desc: "'system'.'notifications'".to_string(),
+            name: "notifications".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemNotifications".to_string(),
+
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(Self { table_info })
+    }
+}
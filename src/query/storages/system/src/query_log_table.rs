@@ -103,6 +103,8 @@ pub struct QueryLogElement {
     pub tables: String,
     pub columns: String,
     pub projections: String,
+    /// The snapshots which the tables are pinned to, see `EXPLAIN SNAPSHOT`.
+    pub snapshots: String,
 
     // Stats.
     pub written_rows: u64,
@@ -119,6 +121,8 @@ pub struct QueryLogElement {
     pub result_bytes: u64,
     pub cpu_usage: u32,
     pub memory_usage: u64,
+    pub cpu_time_ms: u64,
+    pub network_bytes: u64,
     pub join_spilled_bytes: u64,
     pub join_spilled_rows: u64,
     pub agg_spilled_bytes: u64,
@@ -179,6 +183,7 @@ impl SystemLogElement for QueryLogElement {
             TableField::new("tables", TableDataType::String),
             TableField::new("columns", TableDataType::String),
             TableField::new("projections", TableDataType::String),
+            TableField::new("snapshots", TableDataType::String),
             // Stats.
             TableField::new(
                 "written_rows",
@@ -248,6 +253,11 @@ impl SystemLogElement for QueryLogElement {
                 "memory_usage",
                 TableDataType::Number(NumberDataType::UInt64),
             ),
+            TableField::new("cpu_time_ms", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "network_bytes",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
             // Client.
             TableField::new("client_info", TableDataType::String),
             TableField::new("client_address", TableDataType::String),
@@ -353,6 +363,10 @@ impl SystemLogElement for QueryLogElement {
             .next()
             .unwrap()
             .push(Scalar::String(self.projections.as_bytes().to_vec()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.snapshots.as_bytes().to_vec()).as_ref());
         // Stats.
         columns
             .next()
@@ -434,6 +448,14 @@ impl SystemLogElement for QueryLogElement {
             .next()
             .unwrap()
             .push(Scalar::Number(NumberScalar::UInt64(self.memory_usage)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.cpu_time_ms)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.network_bytes)).as_ref());
         // Client.
         columns
             .next()
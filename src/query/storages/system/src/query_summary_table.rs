@@ -70,6 +70,11 @@ fn encode_operator_attribute(attr: &OperatorAttribute) -> jsonb::Value {
             "offset": limit_attr.offset,
         }))
             .into(),
+        OperatorAttribute::LimitBy(limit_by_attr) => (&serde_json::json!({
+            "limit": limit_by_attr.limit,
+            "by": limit_by_attr.by,
+        }))
+            .into(),
         OperatorAttribute::TableScan(scan_attr) => {
             (&serde_json::json!({ "qualified_name": scan_attr.qualified_name })).into()
         }
@@ -79,6 +84,18 @@ fn encode_operator_attribute(attr: &OperatorAttribute) -> jsonb::Value {
         OperatorAttribute::Sort(sort_attr) => {
             (&serde_json::json!({ "sort_keys": sort_attr.sort_keys })).into()
         }
+        OperatorAttribute::GapFill(gap_fill_attr) => (&serde_json::json!({
+            "time_column": gap_fill_attr.time_column,
+            "partition_by": gap_fill_attr.partition_by,
+            "method": gap_fill_attr.method,
+        }))
+            .into(),
+        OperatorAttribute::ConnectBy(connect_by_attr) => (&serde_json::json!({
+            "id_column": connect_by_attr.id_column,
+            "parent_column": connect_by_attr.parent_column,
+            "max_depth": connect_by_attr.max_depth,
+        }))
+            .into(),
         OperatorAttribute::Window(window_attr) => {
             (&serde_json::json!({ "functions": window_attr.functions })).into()
         }
@@ -0,0 +1,215 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::catalog::Catalog;
+use common_catalog::catalog::CatalogManager;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::number::UInt64Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::utils::FromData;
+use common_expression::DataBlock;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRef;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_sql::binder::parse_replication_location;
+use common_storage::DataOperator;
+use common_storages_fuse::operations::ReplicationStatus;
+use common_storages_fuse::FuseTable;
+use log::warn;
+use storages_common_table_meta::table::OPT_KEY_REPLICATION_URI;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+pub struct ReplicationLagTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for ReplicationLagTable {
+    const NAME: &'static str = "system.replication_lag";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let catalog_mgr = CatalogManager::instance();
+        let ctls: Vec<(String, Arc<dyn Catalog>)> = catalog_mgr
+            .list_catalogs(&tenant)
+            .await?
+            .iter()
+            .map(|e| (e.name(), e.clone()))
+            .collect();
+
+        let mut catalogs = vec![];
+        let mut databases = vec![];
+        let mut names = vec![];
+        let mut replication_uris = vec![];
+        let mut snapshot_ids = vec![];
+        let mut snapshot_timestamps = vec![];
+        let mut replicated_snapshot_ids = vec![];
+        let mut replicated_timestamps = vec![];
+        let mut lag_seconds = vec![];
+        let mut errors = vec![];
+
+        let visibility_checker = ctx.get_visibility_checker().await?;
+
+        for (ctl_name, ctl) in ctls.into_iter() {
+            let dbs = ctl.list_databases(tenant.as_str()).await?;
+            for db in dbs {
+                if !visibility_checker.check_database_visibility(&ctl_name, db.name()) {
+                    continue;
+                }
+                let tables = match ctl.list_tables(tenant.as_str(), db.name()).await {
+                    Ok(tables) => tables,
+                    Err(err) => {
+                        warn!("list tables failed on db {}: {}", db.name(), err);
+                        continue;
+                    }
+                };
+
+                for table in tables {
+                    let Some(uri) = table.options().get(OPT_KEY_REPLICATION_URI) else {
+                        continue;
+                    };
+                    if !visibility_checker.check_table_visibility(
+                        &ctl_name,
+                        db.name(),
+                        table.name(),
+                    ) {
+                        continue;
+                    }
+
+                    let (status, error) = match Self::replication_status(&ctx, table.as_ref()).await
+                    {
+                        Ok(status) => (status, "".to_string()),
+                        Err(e) => (ReplicationStatus::default(), e.display_text()),
+                    };
+
+                    catalogs.push(ctl_name.as_bytes().to_vec());
+                    databases.push(db.name().as_bytes().to_vec());
+                    names.push(table.name().as_bytes().to_vec());
+                    replication_uris.push(uri.as_bytes().to_vec());
+                    snapshot_ids.push(
+                        status
+                            .snapshot_id
+                            .map(|v| v.simple().to_string().into_bytes()),
+                    );
+                    snapshot_timestamps
+                        .push(status.snapshot_timestamp.map(|v| v.timestamp_micros()));
+                    replicated_snapshot_ids.push(
+                        status
+                            .replicated_snapshot_id
+                            .map(|v| v.simple().to_string().into_bytes()),
+                    );
+                    replicated_timestamps
+                        .push(status.replicated_timestamp.map(|v| v.timestamp_micros()));
+                    lag_seconds.push(status.lag_seconds());
+                    errors.push(error.into_bytes());
+                }
+            }
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(catalogs),
+            StringType::from_data(databases),
+            StringType::from_data(names),
+            StringType::from_data(replication_uris),
+            StringType::from_opt_data(snapshot_ids),
+            TimestampType::from_opt_data(snapshot_timestamps),
+            StringType::from_opt_data(replicated_snapshot_ids),
+            TimestampType::from_opt_data(replicated_timestamps),
+            UInt64Type::from_opt_data(lag_seconds),
+            StringType::from_data(errors),
+        ]))
+    }
+}
+
+impl ReplicationLagTable {
+    pub fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("catalog", TableDataType::String),
+            TableField::new("database", TableDataType::String),
+            TableField::new("name", TableDataType::String),
+            TableField::new("replication_uri", TableDataType::String),
+            TableField::new(
+                "snapshot_id",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new(
+                "snapshot_timestamp",
+                TableDataType::Nullable(Box::new(TableDataType::Timestamp)),
+            ),
+            TableField::new(
+                "replicated_snapshot_id",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new(
+                "replicated_timestamp",
+                TableDataType::Nullable(Box::new(TableDataType::Timestamp)),
+            ),
+            TableField::new(
+                "lag_seconds",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt64))),
+            ),
+            TableField::new("error", TableDataType::String),
+        ])
+    }
+
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let table_info = TableInfo {
+            desc: "'system'.'replication_lag'".to_string(),
+            name: "replication_lag".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema: ReplicationLagTable::schema(),
+                engine: "SystemReplicationLag".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        AsyncOneBlockSystemTable::create(ReplicationLagTable { table_info })
+    }
+
+    async fn replication_status(
+        ctx: &Arc<dyn TableContext>,
+        table: &dyn Table,
+    ) -> Result<ReplicationStatus> {
+        let fuse_table = FuseTable::try_from_table(table)?;
+        // Safe to unwrap, only tables with replication uri are checked.
+        let sp = parse_replication_location(ctx, table.options())
+            .await?
+            .unwrap();
+        let target = DataOperator::try_create(&sp).await?.operator();
+        fuse_table.replication_status(&target).await
+    }
+}
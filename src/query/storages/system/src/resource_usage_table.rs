@@ -0,0 +1,209 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_base::base::GlobalInstance;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::number::Float64Type;
+use common_expression::types::number::UInt64Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::utils::FromData;
+use common_expression::DataBlock;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use parking_lot::RwLock;
+
+use crate::QueryLogElement;
+use crate::SyncOneBlockSystemTable;
+use crate::SyncSystemTable;
+
+const MICROS_PER_HOUR: i64 = 3_600_000_000;
+
+/// The rollup key, the hour goes first to evict the expired rollups in order.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct ResourceUsageKey {
+    hour: i64,
+    tenant_id: String,
+    cluster_id: String,
+    sql_user: String,
+}
+
+#[derive(Clone, Debug, Default)]
+struct ResourceUsage {
+    queries: u64,
+    cpu_time_ms: u64,
+    scan_bytes: u64,
+    written_bytes: u64,
+    network_bytes: u64,
+}
+
+/// Rolls up the resource consumption of the finished queries of this node by hour,
+/// tenant, cluster (warehouse) and user, for the chargeback of a shared cluster.
+pub struct ResourceUsageRecorder {
+    retention_hours: u64,
+    usages: RwLock<BTreeMap<ResourceUsageKey, ResourceUsage>>,
+}
+
+impl ResourceUsageRecorder {
+    pub fn init(retention_hours: u64) {
+        GlobalInstance::set(Arc::new(ResourceUsageRecorder {
+            retention_hours,
+            usages: RwLock::new(BTreeMap::new()),
+        }));
+    }
+
+    pub fn instance() -> Arc<ResourceUsageRecorder> {
+        GlobalInstance::get()
+    }
+
+    pub fn record(&self, event: &QueryLogElement) {
+        let hour = event.event_time - event.event_time.rem_euclid(MICROS_PER_HOUR);
+        let key = ResourceUsageKey {
+            hour,
+            tenant_id: event.tenant_id.clone(),
+            cluster_id: event.cluster_id.clone(),
+            sql_user: event.sql_user.clone(),
+        };
+
+        let mut usages = self.usages.write();
+        let usage = usages.entry(key).or_default();
+        usage.queries += 1;
+        usage.cpu_time_ms += event.cpu_time_ms;
+        usage.scan_bytes += event.scan_bytes;
+        usage.written_bytes += event.written_bytes;
+        usage.network_bytes += event.network_bytes;
+
+        // Evict the rollups older than the retention.
+        let expired = hour - self.retention_hours as i64 * MICROS_PER_HOUR;
+        if usages.keys().next().is_some_and(|k| k.hour < expired) {
+            *usages = usages.split_off(&ResourceUsageKey {
+                hour: expired,
+                tenant_id: String::new(),
+                cluster_id: String::new(),
+                sql_user: String::new(),
+            });
+        }
+    }
+
+    fn usages(&self) -> Vec<(ResourceUsageKey, ResourceUsage)> {
+        let usages = self.usages.read();
+        usages.iter().map(|(k, v)| (k.clone(), v.clone())).collect()
+    }
+}
+
+pub struct ResourceUsageTable {
+    table_info: TableInfo,
+}
+
+impl SyncSystemTable for ResourceUsageTable {
+    const NAME: &'static str = "system.resource_usage";
+
+    const IS_LOCAL: bool = false;
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let usages = ResourceUsageRecorder::instance().usages();
+        let local_node = ctx.get_cluster().local_id.clone();
+
+        let mut nodes = Vec::with_capacity(usages.len());
+        let mut hours = Vec::with_capacity(usages.len());
+        let mut tenant_ids = Vec::with_capacity(usages.len());
+        let mut cluster_ids = Vec::with_capacity(usages.len());
+        let mut sql_users = Vec::with_capacity(usages.len());
+        let mut queries = Vec::with_capacity(usages.len());
+        let mut cpu_seconds = Vec::with_capacity(usages.len());
+        let mut scan_bytes = Vec::with_capacity(usages.len());
+        let mut written_bytes = Vec::with_capacity(usages.len());
+        let mut network_bytes = Vec::with_capacity(usages.len());
+        for (key, usage) in usages {
+            nodes.push(local_node.clone().into_bytes());
+            hours.push(key.hour);
+            tenant_ids.push(key.tenant_id.into_bytes());
+            cluster_ids.push(key.cluster_id.into_bytes());
+            sql_users.push(key.sql_user.into_bytes());
+            queries.push(usage.queries);
+            cpu_seconds.push(usage.cpu_time_ms as f64 / 1000.0);
+            scan_bytes.push(usage.scan_bytes);
+            written_bytes.push(usage.written_bytes);
+            network_bytes.push(usage.network_bytes);
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(nodes),
+            TimestampType::from_data(hours),
+            StringType::from_data(tenant_ids),
+            StringType::from_data(cluster_ids),
+            StringType::from_data(sql_users),
+            UInt64Type::from_data(queries),
+            Float64Type::from_data(cpu_seconds),
+            UInt64Type::from_data(scan_bytes),
+            UInt64Type::from_data(written_bytes),
+            UInt64Type::from_data(network_bytes),
+        ]))
+    }
+}
+
+impl ResourceUsageTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("node", TableDataType::String),
+            TableField::new("hour", TableDataType::Timestamp),
+            TableField::new("tenant_id", TableDataType::String),
+            TableField::new("cluster_id", TableDataType::String),
+            TableField::new("sql_user", TableDataType::String),
+            TableField::new("queries", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "cpu_seconds",
+                TableDataType::Number(NumberDataType::Float64),
+            ),
+            TableField::new("scan_bytes", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "written_bytes",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "network_bytes",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'resource_usage'".to_string(),
+            name: "resource_usage".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemResourceUsage".to_string(),
+
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        SyncOneBlockSystemTable::create(ResourceUsageTable { table_info })
+    }
+}
@@ -0,0 +1,238 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::catalog::Catalog;
+use common_catalog::catalog::CatalogManager;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::number::Float64Type;
+use common_expression::types::number::UInt64Type;
+use common_expression::types::number::F64;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::utils::FromData;
+use common_expression::DataBlock;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRef;
+use common_expression::TableSchemaRefExt;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use log::warn;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+use crate::util::find_eq_filter;
+
+/// The size, row and block counts of the tables, as committed with their latest snapshots.
+pub struct TableStatisticsTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for TableStatisticsTable {
+    const NAME: &'static str = "system.table_statistics";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let catalog_mgr = CatalogManager::instance();
+        let ctls: Vec<(String, Arc<dyn Catalog>)> = catalog_mgr
+            .list_catalogs(&tenant)
+            .await?
+            .iter()
+            .map(|e| (e.name(), e.clone()))
+            .collect();
+
+        // only list the databases in `database = '<name>'`, if any.
+        let mut db_names: Vec<String> = vec![];
+        if let Some(filter) = push_downs.as_ref().and_then(|p| p.filters.as_ref()) {
+            let expr = filter.filter.as_expr(&BUILTIN_FUNCTIONS);
+            find_eq_filter(&expr, &mut |col_name, scalar| {
+                if col_name == "database" {
+                    if let Scalar::String(s) = scalar {
+                        if let Ok(database) = String::from_utf8(s.clone()) {
+                            if !db_names.contains(&database) {
+                                db_names.push(database);
+                            }
+                        }
+                    }
+                }
+            });
+        }
+
+        let mut catalogs = vec![];
+        let mut databases = vec![];
+        let mut names = vec![];
+        let mut table_ids = vec![];
+        let mut engines = vec![];
+        let mut num_rows = vec![];
+        let mut logical_sizes = vec![];
+        let mut compressed_sizes = vec![];
+        let mut index_sizes = vec![];
+        let mut physical_sizes = vec![];
+        let mut compression_ratios = vec![];
+        let mut number_of_segments = vec![];
+        let mut number_of_blocks = vec![];
+        let mut updated_on = vec![];
+
+        let visibility_checker = ctx.get_visibility_checker().await?;
+
+        for (ctl_name, ctl) in ctls.into_iter() {
+            let dbs = if db_names.is_empty() {
+                ctl.list_databases(tenant.as_str()).await?
+            } else {
+                let mut dbs = vec![];
+                for db_name in &db_names {
+                    if let Ok(db) = ctl.get_database(tenant.as_str(), db_name).await {
+                        dbs.push(db);
+                    }
+                }
+                dbs
+            };
+            for db in dbs {
+                if !visibility_checker.check_database_visibility(&ctl_name, db.name()) {
+                    continue;
+                }
+                let tables = match ctl.list_tables(tenant.as_str(), db.name()).await {
+                    Ok(tables) => tables,
+                    Err(err) => {
+                        warn!("list tables failed on db {}: {}", db.name(), err);
+                        continue;
+                    }
+                };
+
+                for table in tables {
+                    if table.engine() == "STREAM"
+                        || !visibility_checker.check_table_visibility(
+                            &ctl_name,
+                            db.name(),
+                            table.name(),
+                        )
+                    {
+                        continue;
+                    }
+                    // tables without statistics, e.g. views, are skipped.
+                    let stats = match table.table_statistics().await {
+                        Ok(Some(stats)) => stats,
+                        Ok(None) => continue,
+                        Err(err) => {
+                            warn!(
+                                "get statistics failed on table {}.{}: {}",
+                                db.name(),
+                                table.name(),
+                                err
+                            );
+                            continue;
+                        }
+                    };
+
+                    let info = table.get_table_info();
+                    catalogs.push(ctl_name.as_bytes().to_vec());
+                    databases.push(db.name().as_bytes().to_vec());
+                    names.push(table.name().as_bytes().to_vec());
+                    table_ids.push(info.ident.table_id);
+                    engines.push(table.engine().as_bytes().to_vec());
+                    num_rows.push(stats.num_rows);
+                    logical_sizes.push(stats.data_size);
+                    compressed_sizes.push(stats.data_size_compressed);
+                    index_sizes.push(stats.index_size);
+                    physical_sizes.push(stats.physical_size());
+                    compression_ratios.push(stats.compression_ratio().map(F64::from));
+                    number_of_segments.push(stats.number_of_segments);
+                    number_of_blocks.push(stats.number_of_blocks);
+                    updated_on.push(info.meta.updated_on.timestamp_micros());
+                }
+            }
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(catalogs),
+            StringType::from_data(databases),
+            StringType::from_data(names),
+            UInt64Type::from_data(table_ids),
+            StringType::from_data(engines),
+            UInt64Type::from_opt_data(num_rows),
+            UInt64Type::from_opt_data(logical_sizes),
+            UInt64Type::from_opt_data(compressed_sizes),
+            UInt64Type::from_opt_data(index_sizes),
+            UInt64Type::from_opt_data(physical_sizes),
+            Float64Type::from_opt_data(compression_ratios),
+            UInt64Type::from_opt_data(number_of_segments),
+            UInt64Type::from_opt_data(number_of_blocks),
+            TimestampType::from_data(updated_on),
+        ]))
+    }
+}
+
+impl TableStatisticsTable {
+    pub fn schema() -> TableSchemaRef {
+        let nullable_u64 =
+            || TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt64)));
+        TableSchemaRefExt::create(vec![
+            TableField::new("catalog", TableDataType::String),
+            TableField::new("database", TableDataType::String),
+            TableField::new("name", TableDataType::String),
+            TableField::new("table_id", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("engine", TableDataType::String),
+            TableField::new("num_rows", nullable_u64()),
+            // uncompressed size of the data
+            TableField::new("logical_size", nullable_u64()),
+            TableField::new("data_compressed_size", nullable_u64()),
+            TableField::new("index_size", nullable_u64()),
+            // `data_compressed_size` + `index_size`, taken in the storage
+            TableField::new("physical_size", nullable_u64()),
+            // `logical_size` / `data_compressed_size`
+            TableField::new(
+                "compression_ratio",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::Float64))),
+            ),
+            TableField::new("number_of_segments", nullable_u64()),
+            TableField::new("number_of_blocks", nullable_u64()),
+            // time of the last commit to the table
+            TableField::new("updated_on", TableDataType::Timestamp),
+        ])
+    }
+
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let table_info = TableInfo {
+            desc: "'system'.'table_statistics'".to_string(),
+            name: "table_statistics".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema: TableStatisticsTable::schema(),
+                engine: "SystemTableStatistics".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        AsyncOneBlockSystemTable::create(TableStatisticsTable { table_info })
+    }
+}
@@ -20,7 +20,9 @@ use common_catalog::plan::PushDownInfo;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
+use common_expression::types::number::Float64Type;
 use common_expression::types::number::UInt64Type;
+use common_expression::types::number::F64;
 use common_expression::types::NumberDataType;
 use common_expression::types::StringType;
 use common_expression::types::TimestampType;
@@ -35,11 +37,26 @@ use common_functions::BUILTIN_FUNCTIONS;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
+use common_storages_view::view_table::QUERY;
+use common_storages_view::view_table::VIEW_ENGINE;
 use log::warn;
+use storages_common_table_meta::table::OPT_KEY_COMMENT;
 
 use crate::table::AsyncOneBlockSystemTable;
 use crate::table::AsyncSystemTable;
 use crate::util::find_eq_filter;
+use crate::util::is_column_projected;
+use crate::util::pushed_limit;
+
+const STATISTICS_COLUMNS: [&str; 7] = [
+    "num_rows",
+    "data_size",
+    "data_compressed_size",
+    "index_size",
+    "number_of_segments",
+    "number_of_blocks",
+    "compression_ratio",
+];
 
 pub struct TablesTable<const WITH_HISTORY: bool> {
     table_info: TableInfo,
@@ -56,6 +73,14 @@ pub trait HistoryAware {
         tenant: &str,
         db_name: &str,
     ) -> Result<Vec<Arc<dyn Table>>>;
+
+    /// List the tables with the given names, which come from `name = '..'` filters.
+    async fn get_tables(
+        catalog: &Arc<dyn Catalog>,
+        tenant: &str,
+        db_name: &str,
+        table_names: &[String],
+    ) -> Result<Vec<Arc<dyn Table>>>;
 }
 
 #[async_trait::async_trait]
@@ -69,6 +94,21 @@ impl HistoryAware for TablesTable<true> {
     ) -> Result<Vec<Arc<dyn Table>>> {
         catalog.list_tables_history(tenant, database_name).await
     }
+
+    #[async_backtrace::framed]
+    async fn get_tables(
+        catalog: &Arc<dyn Catalog>,
+        tenant: &str,
+        database_name: &str,
+        table_names: &[String],
+    ) -> Result<Vec<Arc<dyn Table>>> {
+        // Dropped tables can not be got by name, filter the history instead.
+        let tables = catalog.list_tables_history(tenant, database_name).await?;
+        Ok(tables
+            .into_iter()
+            .filter(|t| table_names.iter().any(|n| n == t.name()))
+            .collect())
+    }
 }
 
 #[async_trait::async_trait]
@@ -82,6 +122,23 @@ impl HistoryAware for TablesTable<false> {
     ) -> Result<Vec<Arc<dyn Table>>> {
         catalog.list_tables(tenant, database_name).await
     }
+
+    #[async_backtrace::framed]
+    async fn get_tables(
+        catalog: &Arc<dyn Catalog>,
+        tenant: &str,
+        database_name: &str,
+        table_names: &[String],
+    ) -> Result<Vec<Arc<dyn Table>>> {
+        let mut tables = Vec::with_capacity(table_names.len());
+        for table_name in table_names {
+            // Unknown tables are simply not listed.
+            if let Ok(table) = catalog.get_table(tenant, database_name, table_name).await {
+                tables.push(table);
+            }
+        }
+        Ok(tables)
+    }
 }
 
 #[async_trait::async_trait]
@@ -116,30 +173,37 @@ where TablesTable<T>: HistoryAware
 
         let visibility_checker = ctx.get_visibility_checker().await?;
 
-        for (ctl_name, ctl) in ctls.into_iter() {
-            let mut dbs = Vec::new();
-            if let Some(push_downs) = &push_downs {
-                let mut db_name = Vec::new();
-                if let Some(filter) = push_downs.filters.as_ref().map(|f| &f.filter) {
-                    let expr = filter.as_expr(&BUILTIN_FUNCTIONS);
-                    find_eq_filter(&expr, &mut |col_name, scalar| {
-                        if col_name == "database" {
-                            if let Scalar::String(s) = scalar {
-                                if let Ok(database) = String::from_utf8(s.clone()) {
-                                    if !db_name.contains(&database) {
-                                        db_name.push(database);
-                                    }
-                                }
-                            }
-                        }
-                    });
-                    for db in db_name {
-                        if let Ok(database) = ctl.get_database(tenant.as_str(), db.as_str()).await {
-                            dbs.push(database);
+        let mut db_name = Vec::new();
+        let mut table_name = Vec::new();
+        if let Some(filter) = push_downs
+            .as_ref()
+            .and_then(|p| p.filters.as_ref())
+            .map(|f| &f.filter)
+        {
+            let expr = filter.as_expr(&BUILTIN_FUNCTIONS);
+            find_eq_filter(&expr, &mut |col_name, scalar| {
+                let names = match col_name {
+                    "database" => &mut db_name,
+                    "name" => &mut table_name,
+                    _ => return,
+                };
+                if let Scalar::String(s) = scalar {
+                    if let Ok(name) = String::from_utf8(s.clone()) {
+                        if !names.contains(&name) {
+                            names.push(name);
                         }
-                        // TODO(liyz): return the warnings if get_database() failed.
                     }
                 }
+            });
+        }
+
+        for (ctl_name, ctl) in ctls.into_iter() {
+            let mut dbs = Vec::new();
+            for db in db_name.iter() {
+                if let Ok(database) = ctl.get_database(tenant.as_str(), db.as_str()).await {
+                    dbs.push(database);
+                }
+                // TODO(liyz): return the warnings if get_database() failed.
             }
 
             if dbs.is_empty() {
@@ -154,7 +218,12 @@ where TablesTable<T>: HistoryAware
             for db in final_dbs {
                 let name = db.name().to_string().into_boxed_str();
                 let name: &str = Box::leak(name);
-                let tables = match Self::list_tables(&ctl, tenant.as_str(), name).await {
+                let tables = if table_name.is_empty() {
+                    Self::list_tables(&ctl, tenant.as_str(), name).await
+                } else {
+                    Self::get_tables(&ctl, tenant.as_str(), name, &table_name).await
+                };
+                let tables = match tables {
                     Ok(tables) => tables,
                     Err(err) => {
                         // swallow the errors related with remote database or tables, avoid ANY of bad table config corrupt ALL of the results.
@@ -183,6 +252,19 @@ where TablesTable<T>: HistoryAware
             }
         }
 
+        if let Some(limit) = pushed_limit(push_downs.as_ref()) {
+            catalogs.truncate(limit);
+            databases.truncate(limit);
+            database_tables.truncate(limit);
+        }
+
+        // Reading the statistics of fuse tables requires loading their snapshots,
+        // skip it if none of the statistics columns is queried.
+        let schema = self.table_info.schema();
+        let need_stats = STATISTICS_COLUMNS
+            .iter()
+            .any(|c| is_column_projected(push_downs.as_ref(), &schema, c));
+
         let mut number_of_blocks: Vec<Option<u64>> = Vec::new();
         let mut owner: Vec<Option<Vec<u8>>> = Vec::new();
         let mut number_of_segments: Vec<Option<u64>> = Vec::new();
@@ -190,6 +272,7 @@ where TablesTable<T>: HistoryAware
         let mut data_size: Vec<Option<u64>> = Vec::new();
         let mut data_compressed_size: Vec<Option<u64>> = Vec::new();
         let mut index_size: Vec<Option<u64>> = Vec::new();
+        let mut compression_ratio: Vec<Option<F64>> = Vec::new();
 
         for tbl in &database_tables {
             owner.push(
@@ -199,13 +282,23 @@ where TablesTable<T>: HistoryAware
                     .as_ref()
                     .map(|v| v.owner_role_name.as_bytes().to_vec()),
             );
-            let stats = tbl.table_statistics().await?;
+            let stats = if need_stats {
+                tbl.table_statistics().await?
+            } else {
+                None
+            };
             num_rows.push(stats.as_ref().and_then(|v| v.num_rows));
             number_of_blocks.push(stats.as_ref().and_then(|v| v.number_of_blocks));
             number_of_segments.push(stats.as_ref().and_then(|v| v.number_of_segments));
             data_size.push(stats.as_ref().and_then(|v| v.data_size));
             data_compressed_size.push(stats.as_ref().and_then(|v| v.data_size_compressed));
             index_size.push(stats.as_ref().and_then(|v| v.index_size));
+            compression_ratio.push(
+                stats
+                    .as_ref()
+                    .and_then(|v| v.compression_ratio())
+                    .map(F64::from),
+            );
         }
 
         let names: Vec<Vec<u8>> = database_tables
@@ -260,6 +353,24 @@ where TablesTable<T>: HistoryAware
                 }
             })
             .collect();
+        let comments: Vec<Vec<u8>> = database_tables
+            .iter()
+            .map(|v| {
+                let meta = &v.get_table_info().meta;
+                // table comments are kept in the options, stream comments in the meta
+                match meta.options.get(OPT_KEY_COMMENT) {
+                    Some(comment) => comment.as_bytes().to_vec(),
+                    None => meta.comment.as_bytes().to_vec(),
+                }
+            })
+            .collect();
+        let view_queries: Vec<Vec<u8>> = database_tables
+            .iter()
+            .map(|v| match v.options().get(QUERY) {
+                Some(query) if v.engine() == VIEW_ENGINE => query.as_bytes().to_vec(),
+                _ => vec![],
+            })
+            .collect();
         Ok(DataBlock::new_from_columns(vec![
             StringType::from_data(catalogs),
             StringType::from_data(databases),
@@ -278,7 +389,10 @@ where TablesTable<T>: HistoryAware
             UInt64Type::from_opt_data(index_size),
             UInt64Type::from_opt_data(number_of_segments),
             UInt64Type::from_opt_data(number_of_blocks),
+            Float64Type::from_opt_data(compression_ratio),
             StringType::from_opt_data(owner),
+            StringType::from_data(comments),
+            StringType::from_data(view_queries),
         ]))
     }
 }
@@ -326,10 +440,17 @@ where TablesTable<T>: HistoryAware
                 "number_of_blocks",
                 TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt64))),
             ),
+            TableField::new(
+                "compression_ratio",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::Float64))),
+            ),
             TableField::new(
                 "owner",
                 TableDataType::Nullable(Box::new(TableDataType::String)),
             ),
+            TableField::new("comment", TableDataType::String),
+            // the defining query of a view, empty for other engines
+            TableField::new("view_query", TableDataType::String),
         ])
     }
 
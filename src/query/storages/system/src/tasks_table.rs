@@ -53,6 +53,8 @@ pub fn parse_tasks_to_datablock(tasks: Vec<Task>) -> Result<DataBlock> {
     let mut last_committed_on: Vec<i64> = Vec::with_capacity(tasks.len());
     let mut next_schedule_time: Vec<Option<i64>> = Vec::with_capacity(tasks.len());
     let mut last_suspended_on: Vec<Option<i64>> = Vec::with_capacity(tasks.len());
+    let mut after: Vec<Vec<u8>> = Vec::with_capacity(tasks.len());
+    let mut error_integration: Vec<Option<Vec<u8>>> = Vec::with_capacity(tasks.len());
 
     for task in tasks {
         let tsk: common_cloud_control::task_utils::Task = task.try_into()?;
@@ -72,6 +74,8 @@ pub fn parse_tasks_to_datablock(tasks: Vec<Task>) -> Result<DataBlock> {
         next_schedule_time.push(tsk.next_scheduled_at.map(|t| t.timestamp_micros()));
         last_committed_on.push(tsk.updated_at.timestamp_micros());
         last_suspended_on.push(tsk.last_suspended_at.map(|t| t.timestamp_micros()));
+        after.push(tsk.after.join(", ").into_bytes());
+        error_integration.push(tsk.error_integration.map(|s| s.into_bytes()));
     }
     Ok(DataBlock::new_from_columns(vec![
         TimestampType::from_data(created_on),
@@ -87,6 +91,8 @@ pub fn parse_tasks_to_datablock(tasks: Vec<Task>) -> Result<DataBlock> {
         TimestampType::from_opt_data(next_schedule_time),
         TimestampType::from_data(last_committed_on),
         TimestampType::from_opt_data(last_suspended_on),
+        StringType::from_data(after),
+        StringType::from_opt_data(error_integration),
     ]))
 }
 
@@ -0,0 +1,177 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::catalog::CATALOG_DEFAULT;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::ProcessInfoState;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::number::UInt32Type;
+use common_expression::types::number::UInt64Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::utils::FromData;
+use common_expression::DataBlock;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_meta_types::MatchSeq;
+use common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+/// The quotas of the current tenant and how much of them is used.
+pub struct TenantsTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for TenantsTable {
+    const NAME: &'static str = "system.tenants";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let quota_api = UserApiProvider::instance().get_tenant_quota_api_client(&tenant)?;
+        let quota = quota_api.get_quota(MatchSeq::GE(0)).await?.data;
+
+        let usage = TenantUsage::collect(ctx.as_ref()).await?;
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(vec![tenant]),
+            UInt32Type::from_data(vec![quota.max_databases]),
+            UInt32Type::from_data(vec![quota.max_tables]),
+            UInt32Type::from_data(vec![quota.max_tables_per_database]),
+            UInt64Type::from_data(vec![quota.max_storage_bytes]),
+            UInt32Type::from_data(vec![quota.max_concurrent_queries]),
+            UInt32Type::from_data(vec![quota.max_stages]),
+            UInt32Type::from_data(vec![quota.max_users]),
+            UInt64Type::from_data(vec![usage.databases]),
+            UInt64Type::from_data(vec![usage.tables]),
+            UInt64Type::from_data(vec![usage.storage_bytes]),
+            UInt64Type::from_data(vec![usage.running_queries]),
+        ]))
+    }
+}
+
+impl TenantsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new(
+                "max_databases",
+                TableDataType::Number(NumberDataType::UInt32),
+            ),
+            TableField::new("max_tables", TableDataType::Number(NumberDataType::UInt32)),
+            TableField::new(
+                "max_tables_per_database",
+                TableDataType::Number(NumberDataType::UInt32),
+            ),
+            TableField::new(
+                "max_storage_bytes",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "max_concurrent_queries",
+                TableDataType::Number(NumberDataType::UInt32),
+            ),
+            TableField::new("max_stages", TableDataType::Number(NumberDataType::UInt32)),
+            TableField::new("max_users", TableDataType::Number(NumberDataType::UInt32)),
+            TableField::new("databases", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("tables", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "storage_bytes",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "running_queries",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'tenants'".to_string(),
+            name: "tenants".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemTenants".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        AsyncOneBlockSystemTable::create(TenantsTable { table_info })
+    }
+}
+
+/// What the current tenant uses of its quota.
+pub struct TenantUsage {
+    pub databases: u64,
+    pub tables: u64,
+    /// The compressed data and index bytes of the tables in the default catalog.
+    pub storage_bytes: u64,
+    /// The queries running on this query node.
+    pub running_queries: u64,
+}
+
+impl TenantUsage {
+    pub async fn collect(ctx: &dyn TableContext) -> Result<TenantUsage> {
+        let tenant = ctx.get_tenant();
+        let catalog = ctx.get_catalog(CATALOG_DEFAULT).await?;
+
+        let mut databases = 0;
+        let mut tables = 0;
+        let mut storage_bytes = 0;
+        for db in catalog.list_databases(&tenant).await? {
+            if db.get_db_info().meta.engine == "SYSTEM" {
+                continue;
+            }
+            databases += 1;
+            for table in db.list_tables().await? {
+                let statistics = &table.get_table_info().meta.statistics;
+                tables += 1;
+                storage_bytes += statistics.compressed_data_bytes + statistics.index_data_bytes;
+            }
+        }
+
+        Ok(TenantUsage {
+            databases,
+            tables,
+            storage_bytes,
+            running_queries: Self::running_queries(ctx),
+        })
+    }
+
+    pub fn running_queries(ctx: &dyn TableContext) -> u64 {
+        ctx.get_processes_info()
+            .iter()
+            .filter(|process| process.state == ProcessInfoState::Query)
+            .count() as u64
+    }
+}
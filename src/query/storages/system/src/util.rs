@@ -12,8 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
+use common_catalog::plan::Projection;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::BooleanType;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
 use common_expression::Expr;
 use common_expression::Scalar;
+use common_expression::TableSchema;
+use common_functions::BUILTIN_FUNCTIONS;
 
 pub fn find_eq_filter(expr: &Expr<String>, visitor: &mut impl FnMut(&str, &Scalar)) {
     match expr {
@@ -47,3 +58,54 @@ pub fn find_eq_filter(expr: &Expr<String>, visitor: &mut impl FnMut(&str, &Scala
         }
     }
 }
+
+/// Returns false if the projection is pushed down and `col_name` is not in it,
+/// in which case the column can be filled with cheap placeholder values.
+pub fn is_column_projected(
+    push_downs: Option<&PushDownInfo>,
+    schema: &TableSchema,
+    col_name: &str,
+) -> bool {
+    match push_downs.and_then(|p| p.projection.as_ref()) {
+        Some(Projection::Columns(indices)) => schema
+            .index_of(col_name)
+            .map_or(true, |index| indices.contains(&index)),
+        _ => true,
+    }
+}
+
+/// Returns the pushed down limit if it can be applied before filtering, i.e. there is no filter.
+pub fn pushed_limit(push_downs: Option<&PushDownInfo>) -> Option<usize> {
+    push_downs
+        .filter(|p| p.filters.is_none())
+        .and_then(|p| p.limit)
+}
+
+/// Keep the rows of `block`, which is of the full table `schema`, that match the pushed down filter.
+///
+/// The filter is still evaluated above the scan, thus it is skipped if it is not deterministic.
+pub fn filter_block(
+    ctx: &Arc<dyn TableContext>,
+    block: DataBlock,
+    schema: &TableSchema,
+    push_downs: Option<&PushDownInfo>,
+) -> Result<DataBlock> {
+    let Some(push_downs) = push_downs.filter(|p| p.is_deterministic) else {
+        return Ok(block);
+    };
+    let Some(filter) = push_downs.filters.as_ref().map(|f| &f.filter) else {
+        return Ok(block);
+    };
+
+    let expr = filter
+        .as_expr(&BUILTIN_FUNCTIONS)
+        .project_column_ref(|name| schema.index_of(name).unwrap());
+    let func_ctx = ctx.get_function_context()?;
+    let evaluator = Evaluator::new(&block, &func_ctx, &BUILTIN_FUNCTIONS);
+    let predicate = evaluator
+        .run(&expr)
+        .map_err(|e| e.add_message("eval filter failed:"))?
+        .try_downcast::<BooleanType>()
+        .unwrap();
+    block.filter_boolean_value(&predicate)
+}
@@ -0,0 +1,100 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::GrantObject;
+use common_meta_app::principal::RoleInfo;
+use common_meta_app::principal::UserInfo;
+use common_meta_app::principal::UserPrivilegeType;
+
+/// EffectivePrivileges is the result of resolving the role graph of a session: the user, the
+/// roles taking effect on validating privileges, and the roles the session can use.
+///
+/// It is resolved once per query, so validating the access to many objects doesn't walk the
+/// role graph again for each of them.
+#[derive(Clone, Debug)]
+pub struct EffectivePrivileges {
+    user: UserInfo,
+    effective_roles: Vec<RoleInfo>,
+    available_roles: Vec<RoleInfo>,
+}
+
+impl EffectivePrivileges {
+    pub fn new(
+        user: UserInfo,
+        effective_roles: Vec<RoleInfo>,
+        available_roles: Vec<RoleInfo>,
+    ) -> Self {
+        Self {
+            user,
+            effective_roles,
+            available_roles,
+        }
+    }
+
+    pub fn effective_roles(&self) -> &[RoleInfo] {
+        &self.effective_roles
+    }
+
+    pub fn available_roles(&self) -> &[RoleInfo] {
+        &self.available_roles
+    }
+
+    pub fn validate_privilege(
+        &self,
+        object: &GrantObject,
+        privilege: Vec<UserPrivilegeType>,
+    ) -> Result<()> {
+        // 1. check user's privilege set
+        if self.user.grants.verify_privilege(object, privilege.clone()) {
+            return Ok(());
+        }
+
+        // 2. check the user's roles' privilege set
+        if self
+            .effective_roles
+            .iter()
+            .any(|r| r.grants.verify_privilege(object, privilege.clone()))
+        {
+            return Ok(());
+        }
+
+        let roles_name = self
+            .effective_roles
+            .iter()
+            .map(|r| r.name.clone())
+            .collect::<Vec<_>>()
+            .join(",");
+        Err(ErrorCode::PermissionDenied(format!(
+            "Permission denied, privilege {:?} is required on {} for user {} with roles [{}]",
+            privilege,
+            object,
+            &self.user.identity(),
+            roles_name,
+        )))
+    }
+
+    /// The owner of an object has all the privileges on it, if the owner is one of the
+    /// available roles of the session.
+    pub fn validate_ownership(&self, owner_role: &str) -> Result<()> {
+        if !self.available_roles.iter().any(|r| r.name == owner_role) {
+            return Err(ErrorCode::PermissionDenied(
+                "Permission denied, current session do not have the ownership of this object"
+                    .to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
@@ -18,9 +18,11 @@
 
 extern crate core;
 
+mod effective_privileges;
 mod jwt;
 mod network_policy;
 mod role_mgr;
+mod secret_provider;
 mod user;
 mod user_api;
 mod user_mgr;
@@ -34,7 +36,11 @@ pub mod file_format;
 pub mod idm_config;
 pub mod role_cache_mgr;
 pub mod role_util;
+pub mod secret;
+pub mod sequence;
+pub mod subject_key;
 
+pub use effective_privileges::EffectivePrivileges;
 pub use jwt::*;
 pub use role_cache_mgr::RoleCacheManager;
 pub use role_mgr::BUILTIN_ROLE_ACCOUNT_ADMIN;
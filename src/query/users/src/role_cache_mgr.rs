@@ -27,6 +27,7 @@ use log::warn;
 use parking_lot::RwLock;
 
 use crate::role_util::find_all_related_roles;
+use crate::role_util::find_role_grants;
 use crate::UserApiProvider;
 
 struct CachedRoles {
@@ -145,6 +146,22 @@ impl RoleCacheManager {
         Ok(find_all_related_roles(&cached_roles.roles, roles))
     }
 
+    // find_role_grants is called on showing the role graph of an user or a role.
+    #[async_backtrace::framed]
+    pub async fn find_role_grants(
+        &self,
+        tenant: &str,
+        roles: &[String],
+    ) -> Result<Vec<(String, Option<String>)>> {
+        self.maybe_reload(tenant).await?;
+        let cached = self.cache.read();
+        let cached_roles = match cached.get(tenant) {
+            None => return Ok(vec![]),
+            Some(cached_roles) => cached_roles,
+        };
+        Ok(find_role_grants(&cached_roles.roles, roles))
+    }
+
     #[async_backtrace::framed]
     pub async fn force_reload(&self, tenant: &str) -> Result<()> {
         let data = load_roles_data(&self.user_manager, tenant).await?;
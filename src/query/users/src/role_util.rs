@@ -54,3 +54,33 @@ pub fn find_all_related_roles(
     }
     result
 }
+
+/// Find the edges of the role graph reachable from the given roles in a BFS manner, as the
+/// pairs of the granted role and the role it's granted to. The roles given are paired with
+/// `None`. The implicit grants of the PUBLIC and ACCOUNT_ADMIN roles are not included.
+pub fn find_role_grants(
+    cache: &HashMap<String, RoleInfo>,
+    role_identities: &[String],
+) -> Vec<(String, Option<String>)> {
+    let mut visited: HashSet<String> = HashSet::new();
+    let mut result: Vec<(String, Option<String>)> = vec![];
+    let mut q: VecDeque<(String, Option<String>)> =
+        role_identities.iter().map(|r| (r.clone(), None)).collect();
+    while let Some((role_identity, grantee)) = q.pop_front() {
+        if result.contains(&(role_identity.clone(), grantee.clone())) {
+            continue;
+        }
+        result.push((role_identity.clone(), grantee));
+        if !visited.insert(role_identity.clone()) {
+            continue;
+        }
+        let role = match cache.get(&role_identity) {
+            None => continue,
+            Some(role) => role,
+        };
+        for related_role in role.grants.roles() {
+            q.push_back((related_role, Some(role_identity.clone())));
+        }
+    }
+    result
+}
@@ -0,0 +1,160 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+use std::time::Instant;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::UserDefinedSecret;
+use common_meta_app::schema::CatalogOption;
+use common_meta_app::storage::StorageParams;
+use common_meta_types::MatchSeq;
+
+pub use crate::secret_provider::check_secret_params;
+use crate::secret_provider::fetch_secret_value;
+use crate::UserApiProvider;
+
+/// A secret value fetched by this node.
+pub(crate) struct CachedSecret {
+    /// The definition the value is fetched by, the value is fetched again if it's replaced.
+    secret: UserDefinedSecret,
+    value: String,
+    fetched_at: Instant,
+}
+
+/// user secret operations.
+impl UserApiProvider {
+    // Add a new secret.
+    #[async_backtrace::framed]
+    pub async fn add_secret(
+        &self,
+        tenant: &str,
+        secret: UserDefinedSecret,
+        if_not_exists: bool,
+    ) -> Result<u64> {
+        let secret_api_provider = self.get_secret_api_client(tenant)?;
+        let add_secret = secret_api_provider.add_secret(secret);
+        match add_secret.await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::SECRET_ALREADY_EXISTS {
+                    Ok(u64::MIN)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    // Get one secret from by tenant.
+    #[async_backtrace::framed]
+    pub async fn get_secret(&self, tenant: &str, secret_name: &str) -> Result<UserDefinedSecret> {
+        let secret_api_provider = self.get_secret_api_client(tenant)?;
+        let get_secret = secret_api_provider.get_secret(secret_name, MatchSeq::GE(0));
+        Ok(get_secret.await?.data)
+    }
+
+    // Get the tenant all secret list.
+    #[async_backtrace::framed]
+    pub async fn get_secrets(&self, tenant: &str) -> Result<Vec<UserDefinedSecret>> {
+        let secret_api_provider = self.get_secret_api_client(tenant)?;
+        let get_secrets = secret_api_provider.get_secrets();
+
+        match get_secrets.await {
+            Err(e) => Err(e.add_message_back(" (while get secret)")),
+            Ok(seq_secrets_info) => Ok(seq_secrets_info),
+        }
+    }
+
+    // Drop a secret by name.
+    #[async_backtrace::framed]
+    pub async fn drop_secret(&self, tenant: &str, name: &str, if_exists: bool) -> Result<()> {
+        self.secret_cache
+            .lock()
+            .remove(&(tenant.to_string(), name.to_string()));
+
+        let secret_api_provider = self.get_secret_api_client(tenant)?;
+        let drop_secret = secret_api_provider.drop_secret(name, MatchSeq::GE(1));
+        match drop_secret.await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_SECRET {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back(" (while drop secret)"))
+                }
+            }
+        }
+    }
+
+    /// Returns the value of the secret, fetched from its provider if the cached one
+    /// is older than the cache ttl of the secret, so a rotated value is picked up.
+    #[async_backtrace::framed]
+    pub async fn resolve_secret(&self, tenant: &str, name: &str) -> Result<String> {
+        let secret = self.get_secret(tenant, name).await?;
+        let key = (tenant.to_string(), name.to_string());
+        let cached = self
+            .secret_cache
+            .lock()
+            .get(&key)
+            .filter(|cached| {
+                cached.secret == secret
+                    && cached.fetched_at.elapsed() < Duration::from_secs(secret.cache_ttl_secs)
+            })
+            .map(|cached| cached.value.clone());
+        if let Some(value) = cached {
+            return Ok(value);
+        }
+
+        let value = fetch_secret_value(&secret).await?;
+        self.secret_cache.lock().insert(key, CachedSecret {
+            secret,
+            value: value.clone(),
+            fetched_at: Instant::now(),
+        });
+        Ok(value)
+    }
+
+    /// Replaces the credentials of the storage params referring to secrets
+    /// by the values of the secrets.
+    #[async_backtrace::framed]
+    pub async fn resolve_storage_secrets(
+        &self,
+        tenant: &str,
+        storage_params: &mut StorageParams,
+    ) -> Result<()> {
+        for credential in storage_params.credentials_mut() {
+            if let Some(name) = UserDefinedSecret::parse_reference(credential) {
+                let name = name.to_string();
+                *credential = self.resolve_secret(tenant, &name).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Replaces the credentials of the catalog storage params referring to secrets
+    /// by the values of the secrets.
+    #[async_backtrace::framed]
+    pub async fn resolve_catalog_secrets(
+        &self,
+        tenant: &str,
+        catalog_option: &mut CatalogOption,
+    ) -> Result<()> {
+        match catalog_option.storage_params_mut() {
+            Some(storage_params) => self.resolve_storage_secrets(tenant, storage_params).await,
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,306 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::UserDefinedSecret;
+use common_meta_app::principal::SECRET_PROVIDER_AWS_SECRETS_MANAGER;
+use common_meta_app::principal::SECRET_PROVIDER_VAULT;
+use ring::digest;
+use ring::hmac;
+use serde_json::Value;
+
+/// Checks the provider of the secret is known and the params it requires are given.
+pub fn check_secret_params(secret: &UserDefinedSecret) -> Result<()> {
+    let (required, optional): (&[&str], &[&str]) = match secret.provider.as_str() {
+        SECRET_PROVIDER_VAULT => (&["path"], &["address", "namespace", "key"]),
+        SECRET_PROVIDER_AWS_SECRETS_MANAGER => (&["region", "secret_id"], &[
+            "endpoint",
+            "version_stage",
+            "key",
+        ]),
+        other => {
+            return Err(ErrorCode::IllegalSecret(format!(
+                "unknown secret provider '{}', must be one of '{}' or '{}'",
+                other, SECRET_PROVIDER_VAULT, SECRET_PROVIDER_AWS_SECRETS_MANAGER
+            )));
+        }
+    };
+
+    for param in required {
+        if secret
+            .provider_params
+            .get(*param)
+            .map_or(true, |v| v.is_empty())
+        {
+            return Err(ErrorCode::IllegalSecret(format!(
+                "{} is required by secret provider '{}'",
+                param.to_uppercase(),
+                secret.provider
+            )));
+        }
+    }
+    for param in secret.provider_params.keys() {
+        if !required.contains(&param.as_str()) && !optional.contains(&param.as_str()) {
+            return Err(ErrorCode::IllegalSecret(format!(
+                "unknown option {} for secret provider '{}'",
+                param.to_uppercase(),
+                secret.provider
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Fetches the current value of the secret from its provider.
+#[async_backtrace::framed]
+pub async fn fetch_secret_value(secret: &UserDefinedSecret) -> Result<String> {
+    check_secret_params(secret)?;
+    match secret.provider.as_str() {
+        SECRET_PROVIDER_VAULT => fetch_from_vault(secret).await,
+        _ => fetch_from_aws_secrets_manager(secret).await,
+    }
+}
+
+/// Reads the secret from a KV secrets engine of HashiCorp Vault.
+///
+/// The token of the node is taken from the `VAULT_TOKEN` environment variable,
+/// and so is the address of Vault from `VAULT_ADDR` if `ADDRESS` is not given.
+#[async_backtrace::framed]
+async fn fetch_from_vault(secret: &UserDefinedSecret) -> Result<String> {
+    let params = &secret.provider_params;
+    let address = match params.get("address") {
+        Some(address) => address.clone(),
+        None => std::env::var("VAULT_ADDR").map_err(|_| {
+            ErrorCode::IllegalSecret(format!(
+                "secret {}: ADDRESS is not given and VAULT_ADDR is not set",
+                secret.name
+            ))
+        })?,
+    };
+    let url = format!(
+        "{}/v1/{}",
+        address.trim_end_matches('/'),
+        params["path"].trim_start_matches('/')
+    );
+
+    let mut request = reqwest::Client::new().get(&url);
+    if let Ok(token) = std::env::var("VAULT_TOKEN") {
+        request = request.header("X-Vault-Token", token);
+    }
+    if let Some(namespace) = params.get("namespace") {
+        request = request.header("X-Vault-Namespace", namespace);
+    }
+    let body = send_request(secret, request).await?;
+
+    // KV version 2 nests the fields of the secret in `data.data`.
+    let data = &body["data"];
+    let fields = if data.get("metadata").is_some() && data["data"].is_object() {
+        &data["data"]
+    } else {
+        data
+    };
+    pick_value(secret, fields)
+}
+
+/// Reads the secret by the `GetSecretValue` action of AWS Secrets Manager.
+///
+/// The request is signed by the credential of the node, taken from the
+/// `AWS_ACCESS_KEY_ID`, `AWS_SECRET_ACCESS_KEY` and `AWS_SESSION_TOKEN` environment variables.
+#[async_backtrace::framed]
+async fn fetch_from_aws_secrets_manager(secret: &UserDefinedSecret) -> Result<String> {
+    let params = &secret.provider_params;
+    let region = params["region"].as_str();
+    let endpoint = params
+        .get("endpoint")
+        .cloned()
+        .unwrap_or_else(|| format!("https://secretsmanager.{}.amazonaws.com", region));
+    let url = reqwest::Url::parse(&endpoint).map_err(|e| {
+        ErrorCode::IllegalSecret(format!(
+            "secret {}: invalid ENDPOINT '{}': {}",
+            secret.name, endpoint, e
+        ))
+    })?;
+
+    let access_key_id = std::env::var("AWS_ACCESS_KEY_ID").unwrap_or_default();
+    let secret_access_key = std::env::var("AWS_SECRET_ACCESS_KEY").unwrap_or_default();
+    if access_key_id.is_empty() || secret_access_key.is_empty() {
+        return Err(ErrorCode::SecretProviderFailure(format!(
+            "secret {}: AWS_ACCESS_KEY_ID and AWS_SECRET_ACCESS_KEY must be set to read AWS Secrets Manager",
+            secret.name
+        )));
+    }
+    let session_token = std::env::var("AWS_SESSION_TOKEN").ok();
+
+    let mut payload = serde_json::json!({ "SecretId": params["secret_id"] });
+    if let Some(version_stage) = params.get("version_stage") {
+        payload["VersionStage"] = Value::String(version_stage.clone());
+    }
+    let payload = payload.to_string();
+
+    let host = match url.port() {
+        Some(port) => format!("{}:{}", url.host_str().unwrap_or_default(), port),
+        None => url.host_str().unwrap_or_default().to_string(),
+    };
+    let now = Utc::now();
+    let date = now.format("%Y%m%d").to_string();
+    let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+    let mut headers = vec![
+        ("content-type", "application/x-amz-json-1.1".to_string()),
+        ("host", host),
+        ("x-amz-date", amz_date.clone()),
+        ("x-amz-target", "secretsmanager.GetSecretValue".to_string()),
+    ];
+    if let Some(token) = session_token {
+        headers.push(("x-amz-security-token", token));
+    }
+    headers.sort();
+
+    // AWS Signature Version 4.
+    let signed_headers = headers
+        .iter()
+        .map(|(k, _)| *k)
+        .collect::<Vec<_>>()
+        .join(";");
+    let canonical_request = format!(
+        "POST\n{}\n\n{}\n{}\n{}",
+        url.path(),
+        headers
+            .iter()
+            .map(|(k, v)| format!("{}:{}\n", k, v.trim()))
+            .collect::<String>(),
+        signed_headers,
+        hex_sha256(payload.as_bytes()),
+    );
+    let scope = format!("{}/{}/secretsmanager/aws4_request", date, region);
+    let string_to_sign = format!(
+        "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+        amz_date,
+        scope,
+        hex_sha256(canonical_request.as_bytes())
+    );
+    let mut signing_key = format!("AWS4{}", secret_access_key).into_bytes();
+    for part in [date.as_str(), region, "secretsmanager", "aws4_request"] {
+        signing_key = hmac_sha256(&signing_key, part.as_bytes());
+    }
+    let signature = hex(&hmac_sha256(&signing_key, string_to_sign.as_bytes()));
+
+    let mut request = reqwest::Client::new().post(url).body(payload);
+    for (k, v) in headers.into_iter().filter(|(k, _)| *k != "host") {
+        request = request.header(k, v);
+    }
+    request = request.header(
+        "authorization",
+        format!(
+            "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+            access_key_id, scope, signed_headers, signature
+        ),
+    );
+    let body = send_request(secret, request).await?;
+
+    let value = body["SecretString"].as_str().ok_or_else(|| {
+        ErrorCode::SecretProviderFailure(format!(
+            "secret {}: the secret has no SecretString",
+            secret.name
+        ))
+    })?;
+    match secret.provider_params.get("key") {
+        // A secret of key/value pairs is kept as a JSON object in the SecretString.
+        Some(_) => {
+            let fields = serde_json::from_str::<Value>(value).map_err(|e| {
+                ErrorCode::SecretProviderFailure(format!(
+                    "secret {}: the SecretString is not a JSON object: {}",
+                    secret.name, e
+                ))
+            })?;
+            pick_value(secret, &fields)
+        }
+        None => Ok(value.to_string()),
+    }
+}
+
+#[async_backtrace::framed]
+async fn send_request(
+    secret: &UserDefinedSecret,
+    request: reqwest::RequestBuilder,
+) -> Result<Value> {
+    let response = request.send().await.map_err(|e| {
+        ErrorCode::SecretProviderFailure(format!(
+            "secret {}: fail to request {}: {}",
+            secret.name, secret.provider, e
+        ))
+    })?;
+    let status = response.status();
+    let body = response.text().await.map_err(|e| {
+        ErrorCode::SecretProviderFailure(format!(
+            "secret {}: fail to read the response of {}: {}",
+            secret.name, secret.provider, e
+        ))
+    })?;
+    if !status.is_success() {
+        return Err(ErrorCode::SecretProviderFailure(format!(
+            "secret {}: {} responded {}: {}",
+            secret.name, secret.provider, status, body
+        )));
+    }
+    serde_json::from_str(&body).map_err(|e| {
+        ErrorCode::SecretProviderFailure(format!(
+            "secret {}: fail to parse the response of {}: {}",
+            secret.name, secret.provider, e
+        ))
+    })
+}
+
+/// Picks the field given by `KEY`, or the only field if `KEY` is not given.
+fn pick_value(secret: &UserDefinedSecret, fields: &Value) -> Result<String> {
+    let value = match (secret.provider_params.get("key"), fields) {
+        (Some(key), _) => fields.get(key).ok_or_else(|| {
+            ErrorCode::SecretProviderFailure(format!(
+                "secret {}: the secret has no key '{}'",
+                secret.name, key
+            ))
+        })?,
+        (None, Value::Object(map)) if map.len() == 1 => map.values().next().unwrap(),
+        (None, Value::Object(_)) => {
+            return Err(ErrorCode::IllegalSecret(format!(
+                "secret {}: KEY must be given, the secret has more than one key",
+                secret.name
+            )));
+        }
+        (None, value) => value,
+    };
+    match value {
+        Value::String(v) => Ok(v.clone()),
+        Value::Number(v) => Ok(v.to_string()),
+        _ => Err(ErrorCode::SecretProviderFailure(format!(
+            "secret {}: the value is not a string",
+            secret.name
+        ))),
+    }
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+    hmac::sign(&hmac::Key::new(hmac::HMAC_SHA256, key), data)
+        .as_ref()
+        .to_vec()
+}
+
+fn hex_sha256(data: &[u8]) -> String {
+    hex(digest::digest(&digest::SHA256, data).as_ref())
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
@@ -0,0 +1,124 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::UserDefinedSequence;
+use common_meta_types::MatchSeq;
+
+use crate::UserApiProvider;
+
+/// user sequence operations.
+impl UserApiProvider {
+    // Add a new sequence.
+    #[async_backtrace::framed]
+    pub async fn add_sequence(
+        &self,
+        tenant: &str,
+        sequence: UserDefinedSequence,
+        if_not_exists: bool,
+    ) -> Result<u64> {
+        let sequence_api_provider = self.get_sequence_api_client(tenant)?;
+        let add_sequence = sequence_api_provider.add_sequence(sequence);
+        match add_sequence.await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::SEQUENCE_ALREADY_EXISTS {
+                    Ok(u64::MIN)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    // Get one sequence from by tenant.
+    #[async_backtrace::framed]
+    pub async fn get_sequence(
+        &self,
+        tenant: &str,
+        sequence_name: &str,
+    ) -> Result<UserDefinedSequence> {
+        let sequence_api_provider = self.get_sequence_api_client(tenant)?;
+        let get_sequence = sequence_api_provider.get_sequence(sequence_name, MatchSeq::GE(0));
+        Ok(get_sequence.await?.data)
+    }
+
+    // Get the tenant all sequence list.
+    #[async_backtrace::framed]
+    pub async fn get_sequences(&self, tenant: &str) -> Result<Vec<UserDefinedSequence>> {
+        let sequence_api_provider = self.get_sequence_api_client(tenant)?;
+        let get_sequences = sequence_api_provider.get_sequences();
+
+        match get_sequences.await {
+            Err(e) => Err(e.add_message_back(" (while get sequences)")),
+            Ok(seq_sequences_info) => Ok(seq_sequences_info),
+        }
+    }
+
+    // Get the next `count` values of a sequence.
+    //
+    // Values are served from the range cached by this node, and a new range is reserved
+    // from the meta service when the cached one is exhausted.
+    // Values are unique across nodes, but not necessarily consecutive.
+    #[async_backtrace::framed]
+    pub async fn next_sequence_values(
+        &self,
+        tenant: &str,
+        name: &str,
+        count: u64,
+    ) -> Result<Vec<u64>> {
+        let key = (tenant.to_string(), name.to_string());
+        let mut values = Vec::with_capacity(count as usize);
+        {
+            let mut cache = self.sequence_cache.lock();
+            if let Some(range) = cache.get_mut(&key) {
+                values.extend(range.by_ref().take(count as usize));
+            }
+        }
+
+        let missing = count - values.len() as u64;
+        if missing > 0 {
+            let sequence_api_provider = self.get_sequence_api_client(tenant)?;
+            let mut range = sequence_api_provider
+                .reserve_sequence_values(name, missing)
+                .await?;
+            values.extend(range.by_ref().take(missing as usize));
+            // The values left in a range cached concurrently by another query are skipped.
+            self.sequence_cache.lock().insert(key, range);
+        }
+        Ok(values)
+    }
+
+    // Drop a sequence by name.
+    #[async_backtrace::framed]
+    pub async fn drop_sequence(&self, tenant: &str, name: &str, if_exists: bool) -> Result<()> {
+        self.sequence_cache
+            .lock()
+            .remove(&(tenant.to_string(), name.to_string()));
+
+        let sequence_api_provider = self.get_sequence_api_client(tenant)?;
+        let drop_sequence = sequence_api_provider.drop_sequence(name, MatchSeq::GE(1));
+        match drop_sequence.await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_SEQUENCE {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back(" (while drop sequence)"))
+                }
+            }
+        }
+    }
+}
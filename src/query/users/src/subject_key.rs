@@ -0,0 +1,146 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::SubjectKey;
+use ring::aead::Aad;
+use ring::aead::LessSafeKey;
+use ring::aead::Nonce;
+use ring::aead::UnboundKey;
+use ring::aead::AES_256_GCM;
+use ring::aead::NONCE_LEN;
+use ring::hmac;
+use ring::rand::SecureRandom;
+use ring::rand::SystemRandom;
+
+use crate::UserApiProvider;
+
+const KEY_LEN: usize = 32;
+
+/// subject key operations, the keys of the data of the crypto-shredded columns.
+///
+/// The data key of a subject is created when the first value of the subject is written,
+/// and it is wrapped by the key encryption key of the tenant before it is kept in meta.
+impl UserApiProvider {
+    // Get the data key of a subject, it's created if there is none yet.
+    #[async_backtrace::framed]
+    pub async fn get_or_create_subject_key(&self, tenant: &str, subject: &str) -> Result<Vec<u8>> {
+        let kek = self.get_or_create_kek(tenant).await?;
+        let subject_key_api_provider = self.get_subject_key_api_client(tenant)?;
+        if let Some(key) = subject_key_api_provider.get_subject_key(subject).await? {
+            return open_data(&kek, &key.key);
+        }
+
+        let wrapped_key = seal_data(&kek, &generate_key()?)?;
+        let key = subject_key_api_provider
+            .get_or_add_subject_key(SubjectKey::new(subject, wrapped_key))
+            .await?;
+        open_data(&kek, &key.key)
+    }
+
+    // Get the data key of a subject, None if the subject is erased or has no data.
+    #[async_backtrace::framed]
+    pub async fn get_subject_key(&self, tenant: &str, subject: &str) -> Result<Option<Vec<u8>>> {
+        let subject_key_api_provider = self.get_subject_key_api_client(tenant)?;
+        match subject_key_api_provider.get_subject_key(subject).await? {
+            Some(key) => {
+                let kek = self.get_or_create_kek(tenant).await?;
+                Ok(Some(open_data(&kek, &key.key)?))
+            }
+            None => Ok(None),
+        }
+    }
+
+    // Erase a subject by dropping its data key, returns false if there is no such key.
+    #[async_backtrace::framed]
+    pub async fn drop_subject_key(&self, tenant: &str, subject: &str) -> Result<bool> {
+        let subject_key_api_provider = self.get_subject_key_api_client(tenant)?;
+        subject_key_api_provider
+            .drop_subject_key(subject)
+            .await
+            .map_err(|e| e.add_message_back(" (while drop subject key)"))
+    }
+
+    #[async_backtrace::framed]
+    async fn get_or_create_kek(&self, tenant: &str) -> Result<Vec<u8>> {
+        let subject_key_api_provider = self.get_subject_key_api_client(tenant)?;
+        let kek = subject_key_api_provider
+            .get_or_add_kek(SubjectKey::new("", generate_key()?))
+            .await?;
+        Ok(kek.key)
+    }
+}
+
+fn generate_key() -> Result<Vec<u8>> {
+    let mut key = vec![0; KEY_LEN];
+    SystemRandom::new()
+        .fill(&mut key)
+        .map_err(|e| ErrorCode::IllegalSubjectKey(format!("fail to generate key: {e}")))?;
+    Ok(key)
+}
+
+fn aead_key(key: &[u8]) -> Result<LessSafeKey> {
+    let key = UnboundKey::new(&AES_256_GCM, key)
+        .map_err(|e| ErrorCode::IllegalSubjectKey(format!("invalid subject key: {e}")))?;
+    Ok(LessSafeKey::new(key))
+}
+
+/// Encrypts `data` by AES-256-GCM, the random nonce is prepended to the output.
+pub fn seal_data(key: &[u8], data: &[u8]) -> Result<Vec<u8>> {
+    let mut nonce = [0; NONCE_LEN];
+    SystemRandom::new()
+        .fill(&mut nonce)
+        .map_err(|e| ErrorCode::IllegalSubjectKey(format!("fail to generate nonce: {e}")))?;
+
+    let mut in_out = data.to_vec();
+    aead_key(key)?
+        .seal_in_place_append_tag(
+            Nonce::assume_unique_for_key(nonce),
+            Aad::empty(),
+            &mut in_out,
+        )
+        .map_err(|e| ErrorCode::IllegalSubjectKey(format!("fail to encrypt data: {e}")))?;
+
+    let mut sealed = Vec::with_capacity(NONCE_LEN + in_out.len());
+    sealed.extend_from_slice(&nonce);
+    sealed.extend_from_slice(&in_out);
+    Ok(sealed)
+}
+
+/// Decrypts the output of [`seal_data`].
+pub fn open_data(key: &[u8], data: &[u8]) -> Result<Vec<u8>> {
+    if data.len() < NONCE_LEN {
+        return Err(ErrorCode::IllegalSubjectKey(
+            "fail to decrypt data: data is truncated",
+        ));
+    }
+    let (nonce, sealed) = data.split_at(NONCE_LEN);
+    let nonce = Nonce::try_assume_unique_for_key(nonce)
+        .map_err(|e| ErrorCode::IllegalSubjectKey(format!("fail to decrypt data: {e}")))?;
+
+    let mut in_out = sealed.to_vec();
+    let len = aead_key(key)?
+        .open_in_place(nonce, Aad::empty(), &mut in_out)
+        .map_err(|e| ErrorCode::IllegalSubjectKey(format!("fail to decrypt data: {e}")))?
+        .len();
+    in_out.truncate(len);
+    Ok(in_out)
+}
+
+/// A stable pseudonym of `data`, the HMAC-SHA256 of it by the data key in hex.
+pub fn pseudonymize_data(key: &[u8], data: &[u8]) -> String {
+    let tag = hmac::sign(&hmac::Key::new(hmac::HMAC_SHA256, key), data);
+    tag.as_ref().iter().map(|b| format!("{:02x}", b)).collect()
+}
@@ -28,28 +28,41 @@ use common_management::QuotaApi;
 use common_management::QuotaMgr;
 use common_management::RoleApi;
 use common_management::RoleMgr;
+use common_management::SecretApi;
+use common_management::SecretMgr;
+use common_management::SequenceApi;
+use common_management::SequenceMgr;
 use common_management::SettingApi;
 use common_management::SettingMgr;
 use common_management::StageApi;
 use common_management::StageMgr;
+use common_management::SubjectKeyApi;
+use common_management::SubjectKeyMgr;
 use common_management::UdfApi;
 use common_management::UdfMgr;
 use common_management::UserApi;
 use common_management::UserMgr;
 use common_meta_app::principal::AuthInfo;
+use common_meta_app::principal::SequenceRange;
 use common_meta_app::tenant::TenantQuota;
 use common_meta_kvapi::kvapi;
 use common_meta_store::MetaStore;
 use common_meta_store::MetaStoreProvider;
 use common_meta_types::MatchSeq;
 use common_meta_types::MetaError;
+use parking_lot::Mutex;
 
 use crate::idm_config::IDMConfig;
+use crate::secret::CachedSecret;
 
 pub struct UserApiProvider {
     meta: MetaStore,
     client: Arc<dyn kvapi::KVApi<Error = MetaError> + Send + Sync>,
     idm_config: IDMConfig,
+    /// Sequence values reserved by this node but not handed out yet, keyed by (tenant, sequence name).
+    pub(crate) sequence_cache: Mutex<HashMap<(String, String), SequenceRange>>,
+    /// Secret values fetched by this node, keyed by (tenant, secret name).
+    pub(crate) secret_cache: Mutex<HashMap<(String, String), CachedSecret>>,
 }
 
 impl UserApiProvider {
@@ -79,6 +92,8 @@ impl UserApiProvider {
             meta: client.clone(),
             client: client.arc(),
             idm_config,
+            sequence_cache: Mutex::new(HashMap::new()),
+            secret_cache: Mutex::new(HashMap::new()),
         }))
     }
 
@@ -117,6 +132,21 @@ impl UserApiProvider {
         )?))
     }
 
+    pub fn get_secret_api_client(&self, tenant: &str) -> Result<Arc<dyn SecretApi>> {
+        Ok(Arc::new(SecretMgr::create(self.client.clone(), tenant)?))
+    }
+
+    pub fn get_sequence_api_client(&self, tenant: &str) -> Result<Arc<dyn SequenceApi>> {
+        Ok(Arc::new(SequenceMgr::create(self.client.clone(), tenant)?))
+    }
+
+    pub fn get_subject_key_api_client(&self, tenant: &str) -> Result<Arc<dyn SubjectKeyApi>> {
+        Ok(Arc::new(SubjectKeyMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
     pub fn get_udf_api_client(&self, tenant: &str) -> Result<Arc<dyn UdfApi>> {
         Ok(Arc::new(UdfMgr::create(self.client.clone(), tenant)?))
     }
@@ -16,5 +16,7 @@ mod jwt;
 mod role_cache_mgr;
 mod role_mgr;
 mod role_util;
+mod secret;
+mod subject_key;
 mod user_mgr;
 mod user_udf;
@@ -16,6 +16,7 @@ use std::collections::HashMap;
 
 use common_meta_app::principal::RoleInfo;
 use common_users::role_util::find_all_related_roles;
+use common_users::role_util::find_role_grants;
 use common_users::BUILTIN_ROLE_ACCOUNT_ADMIN;
 
 #[test]
@@ -58,3 +59,29 @@ fn test_find_all_related_roles() {
     assert!(!result.contains(&role1));
     assert!(result.contains(&role2));
 }
+
+#[test]
+fn test_find_role_grants() {
+    let mut role1 = RoleInfo::new("role1");
+    let mut role2 = RoleInfo::new("role2");
+    let role3 = RoleInfo::new("role3");
+    role1.grants.grant_role(role2.name.clone());
+    role2.grants.grant_role(role3.name.clone());
+    // a cycle back to role1
+    role2.grants.grant_role(role1.name.clone());
+
+    let mut cache: HashMap<String, RoleInfo> = HashMap::new();
+    cache.insert(role1.name.clone(), role1.clone());
+    cache.insert(role2.name.clone(), role2.clone());
+    cache.insert(role3.name.clone(), role3.clone());
+
+    let result = find_role_grants(&cache, &["role1".to_string()]);
+    assert_eq!(result.len(), 4);
+    assert_eq!(result[0], ("role1".to_string(), None));
+    assert!(result.contains(&("role2".to_string(), Some("role1".to_string()))));
+    assert!(result.contains(&("role3".to_string(), Some("role2".to_string()))));
+    assert!(result.contains(&("role1".to_string(), Some("role2".to_string()))));
+
+    let result = find_role_grants(&cache, &["role3".to_string()]);
+    assert_eq!(result, vec![("role3".to_string(), None)]);
+}
@@ -0,0 +1,262 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_grpc::RpcClientConf;
+use common_meta_app::principal::UserDefinedSecret;
+use common_meta_app::schema::CatalogOption;
+use common_meta_app::schema::HiveCatalogOption;
+use common_meta_app::schema::IcebergCatalogOption;
+use common_meta_app::storage::StorageParams;
+use common_meta_app::storage::StorageS3Config;
+use common_users::secret::check_secret_params;
+use common_users::UserApiProvider;
+use pretty_assertions::assert_eq;
+use wiremock::matchers::header;
+use wiremock::matchers::header_exists;
+use wiremock::matchers::method;
+use wiremock::matchers::path;
+use wiremock::Mock;
+use wiremock::MockServer;
+use wiremock::ResponseTemplate;
+
+fn vault_kv2_response(value: &str) -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(serde_json::json!({
+        "data": {
+            "data": { "secret_access_key": value },
+            "metadata": { "version": 1 }
+        }
+    }))
+}
+
+fn vault_secret(name: &str, server: &MockServer, cache_ttl_secs: u64) -> UserDefinedSecret {
+    UserDefinedSecret::new(
+        name,
+        "vault".to_string(),
+        BTreeMap::from([
+            ("address".to_string(), server.uri()),
+            ("path".to_string(), "secret/data/s3".to_string()),
+            ("key".to_string(), "secret_access_key".to_string()),
+        ]),
+        cache_ttl_secs,
+    )
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_resolve_vault_secret() -> Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v1/secret/data/s3"))
+        .respond_with(vault_kv2_response("v1"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let user_mgr = UserApiProvider::try_create_simple(RpcClientConf::default()).await?;
+    let tenant = "test";
+    user_mgr
+        .add_secret(tenant, vault_secret("s3_key", &server, 300), false)
+        .await?;
+
+    // The value is fetched once, and cached for the following uses.
+    assert_eq!(user_mgr.resolve_secret(tenant, "s3_key").await?, "v1");
+    assert_eq!(user_mgr.resolve_secret(tenant, "s3_key").await?, "v1");
+
+    let mut params = StorageParams::S3(StorageS3Config {
+        access_key_id: "ak".to_string(),
+        secret_access_key: "secret://s3_key".to_string(),
+        ..Default::default()
+    });
+    user_mgr
+        .resolve_storage_secrets(tenant, &mut params)
+        .await?;
+    let StorageParams::S3(s3) = params else {
+        unreachable!()
+    };
+    assert_eq!(s3.access_key_id, "ak");
+    assert_eq!(s3.secret_access_key, "v1");
+
+    user_mgr.drop_secret(tenant, "s3_key", false).await?;
+    match user_mgr.resolve_secret(tenant, "s3_key").await {
+        Ok(_) => panic!("Resolve a dropped secret must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), ErrorCode::UNKNOWN_SECRET),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_resolve_rotated_secret() -> Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v1/secret/data/s3"))
+        .respond_with(vault_kv2_response("v1"))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/secret/data/s3"))
+        .respond_with(vault_kv2_response("v2"))
+        .mount(&server)
+        .await;
+
+    let user_mgr = UserApiProvider::try_create_simple(RpcClientConf::default()).await?;
+    let tenant = "test";
+    user_mgr
+        .add_secret(tenant, vault_secret("rotated_key", &server, 0), false)
+        .await?;
+
+    // Nothing is cached with a zero ttl, the rotated value is used at once.
+    assert_eq!(user_mgr.resolve_secret(tenant, "rotated_key").await?, "v1");
+    assert_eq!(user_mgr.resolve_secret(tenant, "rotated_key").await?, "v2");
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_resolve_catalog_secrets() -> Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v1/secret/data/s3"))
+        .respond_with(vault_kv2_response("v1"))
+        .mount(&server)
+        .await;
+
+    let user_mgr = UserApiProvider::try_create_simple(RpcClientConf::default()).await?;
+    let tenant = "test";
+    user_mgr
+        .add_secret(tenant, vault_secret("catalog_key", &server, 300), false)
+        .await?;
+
+    let mut option = CatalogOption::Iceberg(IcebergCatalogOption {
+        storage_params: Box::new(StorageParams::S3(StorageS3Config {
+            access_key_id: "ak".to_string(),
+            secret_access_key: "secret://catalog_key".to_string(),
+            ..Default::default()
+        })),
+    });
+    user_mgr
+        .resolve_catalog_secrets(tenant, &mut option)
+        .await?;
+    let CatalogOption::Iceberg(iceberg) = option else {
+        unreachable!()
+    };
+    let StorageParams::S3(s3) = *iceberg.storage_params else {
+        unreachable!()
+    };
+    assert_eq!(s3.access_key_id, "ak");
+    assert_eq!(s3.secret_access_key, "v1");
+
+    // A hive catalog without storage params has nothing to resolve.
+    let hive = CatalogOption::Hive(HiveCatalogOption {
+        address: "127.0.0.1:9083".to_string(),
+        storage_params: None,
+    });
+    let mut option = hive.clone();
+    user_mgr
+        .resolve_catalog_secrets(tenant, &mut option)
+        .await?;
+    assert_eq!(option, hive);
+
+    // A reference to an unknown secret is reported instead of being used as a credential.
+    let mut option = CatalogOption::Hive(HiveCatalogOption {
+        address: "127.0.0.1:9083".to_string(),
+        storage_params: Some(Box::new(StorageParams::S3(StorageS3Config {
+            secret_access_key: "secret://unknown_key".to_string(),
+            ..Default::default()
+        }))),
+    });
+    match user_mgr.resolve_catalog_secrets(tenant, &mut option).await {
+        Ok(_) => panic!("Resolve an unknown secret must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), ErrorCode::UNKNOWN_SECRET),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_resolve_aws_secret() -> Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/"))
+        .and(header("x-amz-target", "secretsmanager.GetSecretValue"))
+        .and(header_exists("authorization"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "Name": "prod/s3",
+            "SecretString": "{\"access_key_id\":\"ak\",\"secret_access_key\":\"sk\"}"
+        })))
+        .mount(&server)
+        .await;
+
+    std::env::set_var("AWS_ACCESS_KEY_ID", "test");
+    std::env::set_var("AWS_SECRET_ACCESS_KEY", "test");
+
+    let user_mgr = UserApiProvider::try_create_simple(RpcClientConf::default()).await?;
+    let tenant = "test";
+    let secret = UserDefinedSecret::new(
+        "aws_key",
+        "AWS_SECRETS_MANAGER".to_string(),
+        BTreeMap::from([
+            ("REGION".to_string(), "us-east-1".to_string()),
+            ("SECRET_ID".to_string(), "prod/s3".to_string()),
+            ("ENDPOINT".to_string(), server.uri()),
+            ("KEY".to_string(), "secret_access_key".to_string()),
+        ]),
+        300,
+    );
+    check_secret_params(&secret)?;
+    user_mgr.add_secret(tenant, secret, false).await?;
+    assert_eq!(user_mgr.resolve_secret(tenant, "aws_key").await?, "sk");
+
+    Ok(())
+}
+
+#[test]
+fn test_check_secret_params() {
+    let secret = |provider: &str, params: &[(&str, &str)]| {
+        UserDefinedSecret::new(
+            "s",
+            provider.to_string(),
+            params
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            300,
+        )
+    };
+
+    assert!(check_secret_params(&secret("vault", &[("path", "secret/data/s3")])).is_ok());
+    for invalid in [
+        secret("keychain", &[("path", "secret/data/s3")]),
+        secret("vault", &[]),
+        secret("vault", &[("path", "secret/data/s3"), ("token", "t")]),
+        secret("aws_secrets_manager", &[("secret_id", "prod/s3")]),
+    ] {
+        match check_secret_params(&invalid) {
+            Ok(_) => panic!("Check an invalid secret must be return Err."),
+            Err(cause) => assert_eq!(cause.code(), ErrorCode::ILLEGAL_SECRET),
+        }
+    }
+
+    assert_eq!(
+        UserDefinedSecret::parse_reference("secret://s3_key"),
+        Some("s3_key")
+    );
+    assert_eq!(UserDefinedSecret::parse_reference("secret://"), None);
+    assert_eq!(UserDefinedSecret::parse_reference("plain"), None);
+}
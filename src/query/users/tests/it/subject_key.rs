@@ -0,0 +1,70 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_grpc::RpcClientConf;
+use common_users::subject_key::open_data;
+use common_users::subject_key::pseudonymize_data;
+use common_users::subject_key::seal_data;
+use common_users::UserApiProvider;
+use pretty_assertions::assert_eq;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_subject_key() -> Result<()> {
+    let conf = RpcClientConf::default();
+    let user_mgr = UserApiProvider::try_create_simple(conf).await?;
+
+    let tenant = "test";
+    assert_eq!(user_mgr.get_subject_key(tenant, "alice").await?, None);
+
+    let key = user_mgr.get_or_create_subject_key(tenant, "alice").await?;
+    assert_eq!(key.len(), 32);
+    assert_eq!(
+        user_mgr.get_or_create_subject_key(tenant, "alice").await?,
+        key
+    );
+    assert_eq!(
+        user_mgr.get_subject_key(tenant, "alice").await?,
+        Some(key.clone())
+    );
+
+    // The key is kept wrapped in meta.
+    let wrapped = user_mgr
+        .get_subject_key_api_client(tenant)?
+        .get_subject_key("alice")
+        .await?
+        .unwrap();
+    assert_ne!(wrapped.key, key);
+
+    let sealed = seal_data(&key, b"alice@example.com")?;
+    assert_eq!(open_data(&key, &sealed)?, b"alice@example.com");
+    let pseudonym = pseudonymize_data(&key, b"alice@example.com");
+    assert_eq!(pseudonym.len(), 64);
+    assert_eq!(pseudonymize_data(&key, b"alice@example.com"), pseudonym);
+
+    assert!(user_mgr.drop_subject_key(tenant, "alice").await?);
+    assert_eq!(user_mgr.get_subject_key(tenant, "alice").await?, None);
+    assert!(!user_mgr.drop_subject_key(tenant, "alice").await?);
+
+    // Data sealed by another key can't be opened.
+    let other = user_mgr.get_or_create_subject_key(tenant, "alice").await?;
+    match open_data(&other, &sealed) {
+        Ok(_) => panic!("Open data by another key must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), ErrorCode::ILLEGAL_SUBJECT_KEY),
+    }
+
+    Ok(())
+}
@@ -50,7 +50,7 @@ const SIMPLE_COLUMN_TYPES: [TypeName; 18] = [
         scale: 10,
     },
     TypeName::Date,
-    TypeName::Timestamp,
+    TypeName::Timestamp { precision: None },
     TypeName::String,
     TypeName::Bitmap,
     TypeName::Variant,
@@ -180,6 +180,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
             expr: None,
             comment: None,
             nullable_constraint,
+            mask_policy: None,
         }
     }
 
@@ -197,9 +198,10 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                 expr: None,
                 comment: None,
                 nullable_constraint,
+                mask_policy: None,
             };
             column_defs.push(column_def);
         }
-        CreateTableSource::Columns(column_defs)
+        CreateTableSource::Columns(column_defs, vec![])
     }
 }
@@ -270,6 +270,9 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                 }
                 NumberDataType::Float32 => Literal::Float64(self.rng.gen_range(-3.4e5..=3.4e5)),
                 NumberDataType::Float64 => Literal::Float64(self.rng.gen_range(-1.7e10..=1.7e10)),
+                NumberDataType::UInt128 | NumberDataType::Int128 => {
+                    Literal::UInt64(self.rng.gen_range(0..=18446744073709551615))
+                }
             },
             DataType::Decimal(decimal_type) => match decimal_type {
                 DecimalDataType::Decimal128(size) => Literal::Decimal256 {
@@ -672,6 +675,8 @@ fn convert_to_type_name(ty: &DataType) -> TypeName {
         DataType::Number(NumberDataType::Int64) => TypeName::Int64,
         DataType::Number(NumberDataType::Float32) => TypeName::Float32,
         DataType::Number(NumberDataType::Float64) => TypeName::Float64,
+        DataType::Number(NumberDataType::UInt128) => TypeName::UInt128,
+        DataType::Number(NumberDataType::Int128) => TypeName::Int128,
         DataType::Decimal(DecimalDataType::Decimal128(size)) => TypeName::Decimal {
             precision: size.precision,
             scale: size.scale,
@@ -681,10 +686,11 @@ fn convert_to_type_name(ty: &DataType) -> TypeName {
             scale: size.scale,
         },
         DataType::Date => TypeName::Date,
-        DataType::Timestamp => TypeName::Timestamp,
+        DataType::Timestamp => TypeName::Timestamp { precision: None },
         DataType::String => TypeName::String,
         DataType::Bitmap => TypeName::Bitmap,
         DataType::Variant => TypeName::Variant,
+        DataType::Geometry => TypeName::Geometry,
         DataType::Nullable(box inner_ty) => {
             TypeName::Nullable(Box::new(convert_to_type_name(inner_ty)))
         }
@@ -20,8 +20,10 @@ use common_ast::ast::Lambda;
 use common_ast::ast::Literal;
 use common_ast::ast::OrderByExpr;
 use common_ast::ast::Window;
+use common_ast::ast::WindowDesc;
 use common_ast::ast::WindowFrame;
 use common_ast::ast::WindowFrameBound;
+use common_ast::ast::WindowFrameExclusion;
 use common_ast::ast::WindowFrameUnits;
 use common_ast::ast::WindowRef;
 use common_ast::ast::WindowSpec;
@@ -669,6 +671,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                     units: WindowFrameUnits::Rows,
                     start_bound: WindowFrameBound::Preceding(None),
                     end_bound: WindowFrameBound::CurrentRow,
+                    exclusion: WindowFrameExclusion::NoOthers,
                 })
             },
         }
@@ -766,7 +769,10 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
             name,
             args,
             params,
-            window,
+            window: window.map(|window| WindowDesc {
+                ignore_nulls: None,
+                window,
+            }),
             lambda,
         }
     }
@@ -62,6 +62,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
             with,
             body,
             order_by,
+            limit_by: None,
             limit,
             offset,
             ignore_result: false,
@@ -121,6 +122,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
             having: None,
             window_list: None,
             qualify: None,
+            gap_fill: None,
         };
         let body = SetExpr::Select(Box::new(select));
 
@@ -129,6 +131,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
             with: None,
             body,
             order_by: vec![],
+            limit_by: None,
             limit: vec![],
             offset: None,
             ignore_result: false,
@@ -313,6 +316,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
             having: self.gen_selection(),
             window_list: self.gen_window_list(),
             qualify: None, // todo: add qualify.
+            gap_fill: None,
         }
     }
 
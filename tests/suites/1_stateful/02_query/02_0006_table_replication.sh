@@ -0,0 +1,67 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+stmt "drop table if exists table_primary;"
+stmt "drop table if exists table_replica;"
+stmt "drop connection if exists replica_conn;"
+
+stmt "create connection replica_conn storage_type = 's3' access_key_id ='minioadmin' secret_access_key ='minioadmin' endpoint_url='${STORAGE_S3_ENDPOINT_URL}'"
+stmt "create table table_primary(a int) replication_uri = 's3://testbucket/replica/' replication_connection = 'replica_conn';"
+
+comment "replication_connection requires replication_uri"
+stmt "create table table_invalid(a int) replication_connection = 'replica_conn';"
+
+table_inserts=(
+  "insert into table_primary(a) values(0)"
+  "insert into table_primary(a) values(1)"
+  "insert into table_primary(a) values(2)"
+)
+
+for i in "${table_inserts[@]}"; do
+  stmt "$i"
+done
+
+wait_for_replication() {
+  for _ in $(seq 1 30); do
+    lag=$(echo "select lag_seconds from system.replication_lag where name = 'table_primary'" | $BENDSQL_CLIENT_CONNECT)
+    if [ "$lag" == "0" ]; then
+      return 0
+    fi
+    sleep 1
+  done
+  echo "replication did not catch up"
+}
+
+wait_for_replication
+
+comment "replication lag"
+query "select name, replication_uri, lag_seconds, error from system.replication_lag where name = 'table_primary';"
+
+storage_prefix=$(mysql -uroot -h127.0.0.1 -P3307  -e "set global hide_options_in_show_create_table=0;show create table table_primary" | grep -i snapshot_location | awk -F'SNAPSHOT_LOCATION='"'"'|_ss' '{print $2}')
+
+comment "attaching replica"
+echo "attach table table_replica 's3://testbucket/replica/$storage_prefix' connection=(connection_name ='replica_conn') READ_ONLY;" | $BENDSQL_CLIENT_CONNECT
+
+query "select * from table_replica order by a;"
+
+comment "replica follows the primary"
+stmt "delete from table_primary where a = 1;"
+wait_for_replication
+query "select * from table_replica order by a;"
+
+comment "replica is read only before promoting"
+stmt "insert into table_replica(a) values(3);"
+
+comment "promote replica"
+stmt "alter table table_replica promote replica;"
+stmt "insert into table_replica(a) values(3);"
+query "select * from table_replica order by a;"
+
+comment "promote a table which is not a replica"
+stmt "alter table table_replica promote replica;"
+
+stmt "drop table if exists table_primary;"
+stmt "drop table if exists table_replica;"
+stmt "drop connection replica_conn;"
@@ -173,6 +173,8 @@ echo "select count(1) from information_schema.columns where table_schema not in
 echo "select count(1) from information_schema.columns where table_schema in ('information_schema', 'system');" | $USER_A_CONNECT
 echo "select count(1) from information_schema.tables where table_schema in ('information_schema', 'system');;" | $USER_A_CONNECT
 echo "select count(1) from information_schema.tables where table_schema not in ('information_schema', 'system');" | $USER_A_CONNECT
+echo "select table_schema, table_name, table_type from information_schema.tables where table_schema not in ('information_schema', 'system') order by table_schema;" | $USER_A_CONNECT
+echo "select table_schema, table_name, column_name, ordinal_position, data_type from information_schema.columns where table_schema not in ('information_schema', 'system') order by table_schema;" | $USER_A_CONNECT
 
 ## Drop user
 echo "drop user a" | $BENDSQL_CLIENT_CONNECT
@@ -0,0 +1,48 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+## Setup
+echo "drop database if exists test_vacuum_clone" | $BENDSQL_CLIENT_CONNECT
+
+echo "CREATE DATABASE test_vacuum_clone" | $BENDSQL_CLIENT_CONNECT
+echo "create table test_vacuum_clone.a(c int)" | $BENDSQL_CLIENT_CONNECT
+
+echo "INSERT INTO test_vacuum_clone.a VALUES (1)" | $BENDSQL_CLIENT_CONNECT
+echo "INSERT INTO test_vacuum_clone.a VALUES (2)" | $BENDSQL_CLIENT_CONNECT
+
+echo "create table test_vacuum_clone.a_clone clone test_vacuum_clone.a" | $BENDSQL_CLIENT_CONNECT
+echo "truncate table test_vacuum_clone.a" | $BENDSQL_CLIENT_CONNECT
+
+# the blocks of test_vacuum_clone.a are shared with the clone, MUST return 0
+echo "set retention_period=0;vacuum table test_vacuum_clone.a retain 0 hours dry run" | $BENDSQL_CLIENT_CONNECT | grep -c "/_b/"
+
+echo "select * from test_vacuum_clone.a_clone order by c" | $BENDSQL_CLIENT_CONNECT
+
+# once the clone is dropped and vacuumed, the blocks are no longer shared, MUST return 2
+echo "drop table test_vacuum_clone.a_clone" | $BENDSQL_CLIENT_CONNECT
+echo "set retention_period=0;vacuum drop table from test_vacuum_clone retain 0 hours" | $BENDSQL_CLIENT_CONNECT
+echo "set retention_period=0;vacuum table test_vacuum_clone.a retain 0 hours dry run" | $BENDSQL_CLIENT_CONNECT | grep -c "/_b/"
+
+echo "set retention_period=0;vacuum table test_vacuum_clone.a retain 0 hours" | $BENDSQL_CLIENT_CONNECT
+echo "set retention_period=0;vacuum table test_vacuum_clone.a retain 0 hours dry run" | $BENDSQL_CLIENT_CONNECT | grep -c "/_b/"
+
+# a dropped table is not vacuumed while its clone is alive
+echo "create table test_vacuum_clone.b(c int)" | $BENDSQL_CLIENT_CONNECT
+echo "INSERT INTO test_vacuum_clone.b VALUES (3)" | $BENDSQL_CLIENT_CONNECT
+echo "create table test_vacuum_clone.b_clone clone test_vacuum_clone.b" | $BENDSQL_CLIENT_CONNECT
+echo "drop table test_vacuum_clone.b" | $BENDSQL_CLIENT_CONNECT
+echo "set retention_period=0;vacuum drop table from test_vacuum_clone retain 0 hours" | $BENDSQL_CLIENT_CONNECT
+
+# MUST return [3]
+echo "select * from test_vacuum_clone.b_clone" | $BENDSQL_CLIENT_CONNECT
+echo "select count(*) from system.tables_with_history where database = 'test_vacuum_clone' and name = 'b'" | $BENDSQL_CLIENT_CONNECT
+
+# the first vacuum removes the clone, the second one the table it was cloned from
+echo "drop table test_vacuum_clone.b_clone" | $BENDSQL_CLIENT_CONNECT
+echo "set retention_period=0;vacuum drop table from test_vacuum_clone retain 0 hours" | $BENDSQL_CLIENT_CONNECT
+echo "set retention_period=0;vacuum drop table from test_vacuum_clone retain 0 hours" | $BENDSQL_CLIENT_CONNECT
+echo "select count(*) from system.tables_with_history where database = 'test_vacuum_clone' and name = 'b'" | $BENDSQL_CLIENT_CONNECT
+
+echo "drop database if exists test_vacuum_clone" | $BENDSQL_CLIENT_CONNECT